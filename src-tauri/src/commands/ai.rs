@@ -1,19 +1,27 @@
+use std::time::Instant;
+
 use log::info;
 
+use super::diff::resolve_diff_options;
 use crate::error::{AxisError, Result};
+use crate::events::{AiCommitMessageChunkEvent, AiExplainChunkEvent};
 use crate::models::{
-    AiProvider, DiffOptions, GenerateCommitMessageResponse, GeneratePrDescriptionResponse,
-};
-use crate::services::ai::{
-    create_provider, format_diff_for_ai, format_diff_summary, get_secret_key, OllamaProvider,
+    AiConnectionCheck, AiModelInfo, AiProvider, AiQueueEntry, AiUsageFilter, AiUsageReport,
+    CommitMessageStyle, DiffContext, DiffOptions, ExplainDiffResponse, ExplainDiffScope,
+    GenerateCommitMessageOptions, GenerateCommitMessageResponse, GeneratePrDescriptionOptions,
+    GeneratePrDescriptionResponse,
 };
+use crate::services::ai::{self, create_provider, get_secret_key};
 use crate::state::AppState;
 use tauri::State;
+use tauri_specta::Event;
 
 #[tauri::command]
 #[specta::specta]
 pub async fn generate_commit_message(
+    app_handle: tauri::AppHandle,
     state: State<'_, AppState>,
+    options: Option<GenerateCommitMessageOptions>,
 ) -> Result<GenerateCommitMessageResponse> {
     let settings = state.get_settings()?;
 
@@ -23,6 +31,16 @@ pub async fn generate_commit_message(
         ));
     }
 
+    let style = options
+        .and_then(|o| o.style)
+        .unwrap_or(if settings.conventional_commits_enabled {
+            CommitMessageStyle::Conventional
+        } else {
+            CommitMessageStyle::Plain
+        });
+
+    // The read guard is dropped at the end of this statement, so the
+    // repository stays usable while the provider request is in flight
     let diffs = state
         .get_git_service()?
         .read()
@@ -30,15 +48,7 @@ pub async fn generate_commit_message(
         .diff_staged(&DiffOptions::default())
         .await?;
 
-    let diff = format_diff_for_ai(&diffs)?;
-
-    if diff.trim().is_empty() {
-        return Err(AxisError::AiServiceError(
-            "No staged changes to generate commit message from".to_string(),
-        ));
-    }
-
-    let provider = create_provider(&settings.ai_provider);
+    let provider = state.ai_provider(&settings.ai_provider);
     let secret_key = get_secret_key(&settings.ai_provider);
 
     let api_key = if provider.requires_api_key() {
@@ -47,20 +57,30 @@ pub async fn generate_commit_message(
         None
     };
 
-    let (message, model_used) = provider
-        .generate_commit_message(
-            &diff,
-            api_key.as_deref(),
-            settings.ai_model.as_deref(),
-            settings.ai_ollama_url.as_deref(),
-            settings.conventional_commits_enabled,
-        )
-        .await?;
+    let response = ai::generate_commit_message(
+        provider.as_ref(),
+        &diffs,
+        style,
+        api_key.as_deref(),
+        settings.ai_model.as_deref(),
+        settings.ai_ollama_url.as_deref(),
+        &|chunk| {
+            let event = AiCommitMessageChunkEvent {
+                chunk: chunk.to_string(),
+            };
+            if let Err(e) = event.emit(&app_handle) {
+                log::error!("Failed to emit AI commit message chunk: {e}");
+            }
+        },
+    )
+    .await?;
+
+    info!(
+        "Generated {style} commit message with model: {}",
+        response.model_used
+    );
 
-    Ok(GenerateCommitMessageResponse {
-        message,
-        model_used,
-    })
+    Ok(response)
 }
 
 #[tauri::command]
@@ -88,41 +108,67 @@ pub async fn delete_ai_api_key(state: State<'_, AppState>, provider: AiProvider)
     state.delete_secret(&secret_key)
 }
 
+/// List the models offered by `provider`. `base_url` overrides the configured
+/// Ollama address so the settings page can probe an address before saving it.
 #[tauri::command]
 #[specta::specta]
-pub async fn test_ai_connection(state: State<'_, AppState>, provider: AiProvider) -> Result<bool> {
-    match provider {
-        AiProvider::Ollama => {
-            let settings = state.get_settings()?;
-            OllamaProvider::test_connection(settings.ai_ollama_url.as_deref()).await
-        }
-        AiProvider::OpenAi | AiProvider::Anthropic => {
-            let secret_key = get_secret_key(&provider);
-            let has_key = state.has_secret(&secret_key)?;
-            Ok(has_key)
-        }
-    }
+pub async fn ai_list_models(
+    state: State<'_, AppState>,
+    provider: AiProvider,
+    base_url: Option<String>,
+) -> Result<Vec<AiModelInfo>> {
+    let (api_key, base_url) = provider_credentials(&state, &provider, base_url)?;
+    create_provider(&provider)
+        .list_models(api_key.as_deref(), base_url.as_deref())
+        .await
 }
 
+/// Check that `provider` is reachable and accepts the stored API key
 #[tauri::command]
 #[specta::specta]
-pub async fn list_ollama_models(
+pub async fn ai_check_connection(
     state: State<'_, AppState>,
-    ollama_url: Option<String>,
-) -> Result<Vec<String>> {
-    let url = ollama_url.or_else(|| state.get_settings().ok().and_then(|s| s.ai_ollama_url));
-    OllamaProvider::list_models(url.as_deref()).await
+    provider: AiProvider,
+    base_url: Option<String>,
+) -> Result<AiConnectionCheck> {
+    let (api_key, base_url) = provider_credentials(&state, &provider, base_url)?;
+    let started = Instant::now();
+
+    create_provider(&provider)
+        .check_connection(api_key.as_deref(), base_url.as_deref())
+        .await?;
+
+    let latency_ms = u64::try_from(started.elapsed().as_millis()).unwrap_or(u64::MAX);
+    info!("{provider} connection check succeeded in {latency_ms}ms");
+    Ok(AiConnectionCheck { latency_ms })
 }
 
+fn provider_credentials(
+    state: &AppState,
+    provider: &AiProvider,
+    base_url: Option<String>,
+) -> Result<(Option<String>, Option<String>)> {
+    let api_key = if create_provider(provider).requires_api_key() {
+        state.get_secret(&get_secret_key(provider))?
+    } else {
+        None
+    };
+    let base_url = match base_url {
+        Some(url) => Some(url),
+        None => state.get_settings()?.ai_ollama_url,
+    };
+    Ok((api_key, base_url))
+}
+
+/// Explain one file diff, or one hunk of it, with the configured provider.
+/// The explanation streams as `AiExplainChunkEvent`s; cached explanations do not.
 #[tauri::command]
 #[specta::specta]
-pub async fn generate_pr_description(
+pub async fn explain_diff(
+    app_handle: tauri::AppHandle,
     state: State<'_, AppState>,
-    source_branch: String,
-    target_branch: String,
-    include_diff_summary: bool,
-    available_labels: Vec<String>,
-) -> Result<GeneratePrDescriptionResponse> {
+    scope: ExplainDiffScope,
+) -> Result<ExplainDiffResponse> {
     let settings = state.get_settings()?;
 
     if !settings.ai_enabled {
@@ -131,44 +177,90 @@ pub async fn generate_pr_description(
         ));
     }
 
-    info!("Generating PR description for {source_branch} -> {target_branch}");
+    let (context, commit_oid) = match &scope.commit_oid {
+        Some(oid) => (DiffContext::Commit, Some(oid.as_str())),
+        None => (DiffContext::Workdir, None),
+    };
+    let options = resolve_diff_options(&state, context, None, None)?;
 
-    // Use remote tracking refs so we only consider pushed commits
-    let remote_source = format!("origin/{source_branch}");
-    let remote_target = format!("origin/{target_branch}");
+    let file = {
+        let handle = state.get_git_service()?;
+        let guard = handle.read().await;
+        match commit_oid {
+            Some(oid) => {
+                guard
+                    .get_file_diff_in_commit(oid, &scope.path, &options)
+                    .await?
+            }
+            None => guard.diff_file(&scope.path, scope.staged, &options).await?,
+        }
+    }
+    .ok_or_else(|| AxisError::FileNotFound(scope.path.clone()))?;
 
-    let compare_result = state
-        .get_git_service()?
-        .read()
-        .await
-        .compare_branches(&remote_source, &remote_target)
-        .await?;
+    let provider = state.ai_provider(&settings.ai_provider);
+    let api_key = if provider.requires_api_key() {
+        state.get_secret(&get_secret_key(&settings.ai_provider))?
+    } else {
+        None
+    };
 
-    if compare_result.ahead_commits.is_empty() {
+    let response = ai::explain_diff(
+        provider.as_ref(),
+        &state.explain_cache(),
+        &file,
+        scope.hunk_index,
+        api_key.as_deref(),
+        settings.ai_model.as_deref(),
+        settings.ai_ollama_url.as_deref(),
+        &|chunk| {
+            let event = AiExplainChunkEvent {
+                path: scope.path.clone(),
+                hunk_index: scope.hunk_index,
+                chunk: chunk.to_string(),
+            };
+            if let Err(e) = event.emit(&app_handle) {
+                log::error!("Failed to emit AI explanation chunk: {e}");
+            }
+        },
+    )
+    .await?;
+
+    info!(
+        "Explained diff of {} with model: {} (cached: {})",
+        scope.path, response.model_used, response.cached
+    );
+
+    Ok(response)
+}
+
+/// Describe the commits `base_ref` has over `compare_ref` (e.g. `origin/feature`
+/// over `origin/main`) as a PR title and markdown body.
+#[tauri::command]
+#[specta::specta]
+pub async fn generate_pr_description(
+    state: State<'_, AppState>,
+    base_ref: String,
+    compare_ref: String,
+    options: Option<GeneratePrDescriptionOptions>,
+) -> Result<GeneratePrDescriptionResponse> {
+    let settings = state.get_settings()?;
+
+    if !settings.ai_enabled {
         return Err(AxisError::AiServiceError(
-            "No commits between branches to generate PR description from".to_string(),
+            "AI features are disabled".to_string(),
         ));
     }
 
-    let commits: Vec<(String, String)> = compare_result
-        .ahead_commits
-        .iter()
-        .map(|c| (c.short_oid.clone(), c.summary.clone()))
-        .collect();
-
-    let diff_summary = if include_diff_summary && !compare_result.files.is_empty() {
-        Some(format_diff_summary(&compare_result.files))
-    } else {
-        None
-    };
+    info!("Generating PR description for {base_ref} against {compare_ref}");
 
-    let labels_ref = if available_labels.is_empty() {
-        None
-    } else {
-        Some(available_labels.as_slice())
-    };
+    let compare_result = state
+        .get_git_service()?
+        .read()
+        .await
+        .compare_branches(&base_ref, &compare_ref)
+        .await?;
 
-    let provider = create_provider(&settings.ai_provider);
+    let provider = state.ai_provider(&settings.ai_provider);
     let secret_key = get_secret_key(&settings.ai_provider);
 
     let api_key = if provider.requires_api_key() {
@@ -177,23 +269,55 @@ pub async fn generate_pr_description(
         None
     };
 
-    let (title, body, labels, model_used) = provider
-        .generate_pr_description(
-            &commits,
-            diff_summary.as_deref(),
-            labels_ref,
-            api_key.as_deref(),
-            settings.ai_model.as_deref(),
-            settings.ai_ollama_url.as_deref(),
-        )
-        .await?;
+    let response = ai::generate_pr_description(
+        provider.as_ref(),
+        &compare_result,
+        &options.unwrap_or_default(),
+        api_key.as_deref(),
+        settings
+            .ai_pr_model
+            .as_deref()
+            .or(settings.ai_model.as_deref()),
+        settings.ai_ollama_url.as_deref(),
+    )
+    .await?;
+
+    info!(
+        "Generated PR description with model: {}",
+        response.model_used
+    );
+
+    Ok(response)
+}
+
+/// AI request totals per day, feature and model, with costs estimated from the
+/// configured price table
+#[tauri::command]
+#[specta::specta]
+pub async fn get_ai_usage(
+    state: State<'_, AppState>,
+    filter: Option<AiUsageFilter>,
+) -> Result<AiUsageReport> {
+    let prices = state.get_settings()?.ai_model_prices;
+    state
+        .database()
+        .get_ai_usage(&filter.unwrap_or_default(), &prices)
+}
 
-    info!("Generated PR description with model: {model_used}");
+/// AI requests waiting for or holding a provider slot, oldest first
+#[tauri::command]
+#[specta::specta]
+pub async fn get_ai_queue(state: State<'_, AppState>) -> Result<Vec<AiQueueEntry>> {
+    Ok(state.ai_limiter().queue())
+}
 
-    Ok(GeneratePrDescriptionResponse {
-        title,
-        body,
-        labels,
-        model_used,
-    })
+/// Cancel a queued or running AI request. Returns false when it already finished.
+#[tauri::command]
+#[specta::specta]
+pub async fn cancel_ai_request(state: State<'_, AppState>, id: u32) -> Result<bool> {
+    let cancelled = state.ai_limiter().cancel(id);
+    if cancelled {
+        info!("Cancelled AI request {id}");
+    }
+    Ok(cancelled)
 }
@@ -1,8 +1,10 @@
+use std::sync::Arc;
+
 use tauri::State;
 
 use crate::error::Result;
-use crate::models::{AvatarResponse, AvatarSource};
-use crate::services::AvatarService;
+use crate::models::{AvatarResponse, AvatarSource, EmailAvatar};
+use crate::services::{AvatarDirectory, AvatarLookup, AvatarService};
 use crate::state::AppState;
 
 /// Get avatar for a commit author
@@ -23,6 +25,7 @@ pub async fn get_avatar(
         return Ok(AvatarResponse {
             source: AvatarSource::Integration,
             path: Some(path),
+            data_url: None,
         });
     }
 
@@ -31,6 +34,7 @@ pub async fn get_avatar(
         return Ok(AvatarResponse {
             source: AvatarSource::Gravatar,
             path: Some(path),
+            data_url: None,
         });
     }
 
@@ -44,6 +48,7 @@ pub async fn get_avatar(
                 return Ok(AvatarResponse {
                     source: AvatarSource::Integration,
                     path: Some(path),
+                    data_url: None,
                 });
             }
         }
@@ -59,6 +64,7 @@ pub async fn get_avatar(
             return Ok(AvatarResponse {
                 source: AvatarSource::Gravatar,
                 path: Some(path),
+                data_url: None,
             });
         }
     }
@@ -67,9 +73,34 @@ pub async fn get_avatar(
     Ok(AvatarResponse {
         source: AvatarSource::Default,
         path: None,
+        data_url: None,
     })
 }
 
+/// Get avatars for many authors at once, in the order given.
+/// Every email gets an image: a cached or downloaded file path, or a generated identicon.
+#[tauri::command]
+#[specta::specta]
+pub async fn get_avatars(
+    state: State<'_, AppState>,
+    emails: Vec<String>,
+    size: u32,
+) -> Result<Vec<EmailAvatar>> {
+    let settings = state.get_settings()?;
+    let avatar_service = state.avatar_service()?;
+    let directory = state
+        .connected_repo_provider()
+        .await
+        .map(|(_, provider)| Arc::new(provider) as Arc<dyn AvatarDirectory>);
+
+    let lookup = AvatarLookup {
+        size,
+        gravatar_enabled: settings.gravatar_enabled,
+        directory,
+    };
+    Ok(avatar_service.get_avatars(emails, lookup).await)
+}
+
 /// Clear the avatar cache
 #[tauri::command]
 #[specta::specta]
@@ -1,7 +1,9 @@
 use crate::error::Result;
+use crate::events::BisectStepEvent;
 use crate::models::{BisectMarkType, BisectResult, BisectStartOptions, BisectState};
 use crate::state::AppState;
 use tauri::State;
+use tauri_specta::Event;
 
 /// Start a bisect session
 #[tauri::command]
@@ -40,14 +42,45 @@ pub async fn bisect_mark(
     state: State<'_, AppState>,
     mark: BisectMarkType,
     commit: Option<String>,
+) -> Result<BisectResult> {
+    mark_commit(&state, mark, commit.as_deref()).await
+}
+
+/// Mark a commit (HEAD by default) as good
+#[tauri::command]
+#[specta::specta]
+pub async fn bisect_good(
+    state: State<'_, AppState>,
+    commit: Option<String>,
+) -> Result<BisectResult> {
+    mark_commit(&state, BisectMarkType::Good, commit.as_deref()).await
+}
+
+/// Mark a commit (HEAD by default) as bad
+#[tauri::command]
+#[specta::specta]
+pub async fn bisect_bad(
+    state: State<'_, AppState>,
+    commit: Option<String>,
+) -> Result<BisectResult> {
+    mark_commit(&state, BisectMarkType::Bad, commit.as_deref()).await
+}
+
+/// Apply a bisect mark and emit `BisectStepEvent` when the session moves on
+async fn mark_commit(
+    state: &State<'_, AppState>,
+    mark: BisectMarkType,
+    commit: Option<&str>,
 ) -> Result<BisectResult> {
     let git_service = state.get_git_service()?;
     let guard = git_service.write().await;
 
+    let previous_commit = guard.get_bisect_state().await?.current_commit;
+
     let result = match mark {
-        BisectMarkType::Good => guard.bisect_good(commit.as_deref()).await?,
-        BisectMarkType::Bad => guard.bisect_bad(commit.as_deref()).await?,
-        BisectMarkType::Skip => guard.bisect_skip(commit.as_deref()).await?,
+        BisectMarkType::Good => guard.bisect_good(commit).await?,
+        BisectMarkType::Bad => guard.bisect_bad(commit).await?,
+        BisectMarkType::Skip => guard.bisect_skip(commit).await?,
     };
 
     // Check if bisect is complete (found the first bad commit)
@@ -66,6 +99,19 @@ pub async fn bisect_mark(
     let mut bisect_state = guard.get_bisect_state().await?;
     bisect_state.first_bad_commit = first_bad;
 
+    if bisect_state.current_commit != previous_commit || bisect_state.first_bad_commit.is_some() {
+        let event = BisectStepEvent {
+            path: state
+                .ensure_repository_open()?
+                .to_string_lossy()
+                .to_string(),
+            state: bisect_state.clone(),
+        };
+        if let Err(e) = event.emit(&state.get_app_handle()?) {
+            log::error!("Failed to emit BisectStepEvent: {e}");
+        }
+    }
+
     Ok(BisectResult {
         success: result.success || bisect_state.first_bad_commit.is_some(),
         state: bisect_state,
@@ -0,0 +1,84 @@
+use crate::error::{AxisError, Result};
+use crate::models::{Bookmark, BookmarkStatus, BookmarkTarget, ResolvedBookmark};
+use crate::state::AppState;
+use tauri::State;
+
+/// Bookmark a commit, file, comparison, or branch in the active repository
+#[tauri::command]
+#[specta::specta]
+pub async fn create_bookmark(
+    state: State<'_, AppState>,
+    target: BookmarkTarget,
+    label: String,
+    notes: Option<String>,
+) -> Result<Bookmark> {
+    let path = state.ensure_repository_open()?;
+    state
+        .database()
+        .add_bookmark(&path, &target, bookmark_label(&label)?, notes.as_deref())
+}
+
+/// Bookmarks of the active repository with the state of their targets. Broken
+/// targets are annotated rather than failing the list; with `repair`, bookmarks
+/// of renamed branches are moved to the new name and saved.
+#[tauri::command]
+#[specta::specta]
+pub async fn get_bookmarks(
+    state: State<'_, AppState>,
+    repair: Option<bool>,
+) -> Result<Vec<ResolvedBookmark>> {
+    let path = state.ensure_repository_open()?;
+    let bookmarks = state.database().get_bookmarks(&path)?;
+    let resolved = state
+        .get_git_service()?
+        .read()
+        .await
+        .resolve_bookmarks(bookmarks, repair.unwrap_or(false))
+        .await?;
+
+    for entry in &resolved {
+        if entry.status == BookmarkStatus::Repaired {
+            state
+                .database()
+                .set_bookmark_target(entry.bookmark.id, &entry.bookmark.target)?;
+        }
+    }
+
+    Ok(resolved)
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn update_bookmark(
+    state: State<'_, AppState>,
+    id: i64,
+    label: String,
+    notes: Option<String>,
+) -> Result<Bookmark> {
+    state
+        .database()
+        .update_bookmark(id, bookmark_label(&label)?, notes.as_deref())
+}
+
+/// Record that a bookmark was opened
+#[tauri::command]
+#[specta::specta]
+pub async fn visit_bookmark(state: State<'_, AppState>, id: i64) -> Result<Bookmark> {
+    state.database().visit_bookmark(id)
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn delete_bookmark(state: State<'_, AppState>, id: i64) -> Result<()> {
+    state.database().delete_bookmark(id)
+}
+
+fn bookmark_label(label: &str) -> Result<&str> {
+    let label = label.trim();
+    if label.is_empty() {
+        return Err(AxisError::Other(
+            "Bookmark label cannot be empty".to_string(),
+        ));
+    }
+    Ok(label)
+}
@@ -1,10 +1,14 @@
 use crate::error::Result;
 use crate::models::{
-    Branch, BranchCompareResult, BranchType, CheckoutOptions, CreateBranchOptions,
-    DeleteBranchOptions,
+    Branch, BranchCompareResult, BranchOperationResult, BranchType, CheckoutOptions,
+    CreateBranchOptions, DeleteBranchOptions, ExpectedBranchTarget, ListRemoteOptions,
+    OperationCategory, ProtectedBranchAction, SshCredentials, StaleBranch, StaleBranchCriteria,
+    UndoOperation, DEFAULT_ARCHIVE_TAG_PREFIX,
 };
+use crate::services::ops::RepoOperations;
 use crate::services::HookProgressEmitter;
 use crate::state::AppState;
+use serde_json::json;
 use tauri::State;
 
 /// Create a new branch
@@ -15,12 +19,19 @@ pub async fn create_branch(
     name: String,
     options: CreateBranchOptions,
 ) -> Result<Branch> {
-    state
+    let result = state
         .get_git_service()?
         .write()
         .await
         .create_branch(&name, &options)
-        .await
+        .await;
+    state.record_operation(
+        OperationCategory::Branch,
+        "create_branch",
+        json!({ "name": name, "options": options }),
+        &result,
+    );
+    result
 }
 
 /// Delete a branch
@@ -36,14 +47,99 @@ pub async fn delete_branch(
     } else {
         None
     };
-    state
+    let parameters = json!({ "name": name, "options": options });
+    let result = state
         .get_git_service()?
         .write()
         .await
         .delete_branch(&name, options, ssh_creds)
+        .await;
+    state.record_operation(
+        OperationCategory::Branch,
+        "delete_branch",
+        parameters,
+        &result,
+    );
+    result
+}
+
+/// Find branches that are old and/or already merged into a base branch
+#[tauri::command]
+#[specta::specta]
+pub async fn get_stale_branches(
+    state: State<'_, AppState>,
+    criteria: StaleBranchCriteria,
+) -> Result<Vec<StaleBranch>> {
+    state
+        .get_git_service()?
+        .read()
+        .await
+        .get_stale_branches(criteria)
         .await
 }
 
+/// Tag each branch tip (`archive/<branch>` by default) and delete the branch
+#[tauri::command]
+#[specta::specta]
+pub async fn archive_branches(
+    state: State<'_, AppState>,
+    branch_names: Vec<String>,
+    tag_prefix: Option<String>,
+    delete_remote: Option<bool>,
+) -> Result<Vec<BranchOperationResult>> {
+    let git_service = state.get_git_service()?;
+    let guard = git_service.write().await;
+    let remote_credentials = bulk_remote_credentials(&state, &guard).await?;
+    guard
+        .archive_branches(
+            branch_names,
+            tag_prefix.as_deref().unwrap_or(DEFAULT_ARCHIVE_TAG_PREFIX),
+            delete_remote.unwrap_or(false),
+            remote_credentials,
+        )
+        .await
+}
+
+/// Delete several branches, reporting success or failure per branch. Local branches that
+/// moved away from their `expected_targets` tip abort the local deletions.
+#[tauri::command]
+#[specta::specta]
+pub async fn bulk_delete_branches(
+    state: State<'_, AppState>,
+    branch_names: Vec<String>,
+    force: bool,
+    delete_remote: bool,
+    expected_targets: Option<Vec<ExpectedBranchTarget>>,
+) -> Result<Vec<BranchOperationResult>> {
+    let git_service = state.get_git_service()?;
+    let guard = git_service.write().await;
+    let remote_credentials = bulk_remote_credentials(&state, &guard).await?;
+    guard
+        .bulk_delete_branches(
+            branch_names,
+            force,
+            delete_remote,
+            expected_targets.unwrap_or_default(),
+            remote_credentials,
+        )
+        .await
+}
+
+/// SSH credentials for every remote, since a bulk delete may touch branches on several
+async fn bulk_remote_credentials(
+    state: &AppState,
+    ops: &RepoOperations,
+) -> Result<Vec<(String, Option<SshCredentials>)>> {
+    ops.list_remotes(ListRemoteOptions::default())
+        .await?
+        .into_iter()
+        .map(|remote| {
+            let credentials = state.resolve_ssh_credentials(&remote.name)?;
+            Ok((remote.name, credentials))
+        })
+        .collect()
+}
+
 /// Rename a branch
 #[tauri::command]
 #[specta::specta]
@@ -53,12 +149,19 @@ pub async fn rename_branch(
     new_name: String,
     force: Option<bool>,
 ) -> Result<Branch> {
-    state
+    let result = state
         .get_git_service()?
         .write()
         .await
         .rename_branch(&old_name, &new_name, force.unwrap_or(false))
-        .await
+        .await;
+    state.record_operation(
+        OperationCategory::Branch,
+        "rename_branch",
+        json!({ "oldName": old_name, "newName": new_name }),
+        &result,
+    );
+    result
 }
 
 /// Checkout a branch
@@ -76,8 +179,21 @@ pub async fn checkout_branch(
     // Get HEAD before checkout for post-checkout hook
     let prev_head = guard.get_head_oid().await;
 
+    if options.force {
+        state
+            .record_undo_snapshot(&guard, UndoOperation::Checkout)
+            .await;
+    }
+
     // Perform checkout
-    guard.checkout_branch(&name, &options).await?;
+    let result = guard.checkout_branch(&name, &options).await;
+    state.record_operation(
+        OperationCategory::Branch,
+        "checkout",
+        json!({ "name": name, "options": options }),
+        &result,
+    );
+    result?;
 
     // Run post-checkout hook (informational, don't fail on error)
     if !settings.bypass_hooks {
@@ -173,7 +289,16 @@ pub async fn delete_remote_branch(
     remote_name: String,
     branch_name: String,
     force: Option<bool>,
+    override_protection_check: Option<bool>,
 ) -> Result<()> {
+    state
+        .check_branch_protection(
+            &remote_name,
+            &[(branch_name.clone(), ProtectedBranchAction::Delete)],
+            override_protection_check.unwrap_or(false),
+        )
+        .await?;
+
     let ssh_creds = state.resolve_ssh_credentials(&remote_name)?;
     state
         .get_git_service()?
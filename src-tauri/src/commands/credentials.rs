@@ -0,0 +1,37 @@
+use crate::error::{AxisError, Result};
+use crate::models::CredentialHost;
+use crate::services::normalize_host;
+use crate::state::AppState;
+use tauri::State;
+
+// ==================== HTTPS Host Credentials ====================
+
+#[tauri::command]
+#[specta::specta]
+pub async fn set_host_credential(
+    state: State<'_, AppState>,
+    host: String,
+    username: String,
+    token: String,
+) -> Result<()> {
+    if token.is_empty() {
+        return Err(AxisError::Other("Token cannot be empty".to_string()));
+    }
+    let host = normalize_host(&host)?;
+    state
+        .database()
+        .set_host_credential(&host, username.trim(), &token)
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn delete_host_credential(state: State<'_, AppState>, host: String) -> Result<()> {
+    let host = normalize_host(&host)?;
+    state.database().delete_host_credential(&host)
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn list_credential_hosts(state: State<'_, AppState>) -> Result<Vec<CredentialHost>> {
+    state.database().list_credential_hosts()
+}
@@ -1,43 +1,41 @@
-use crate::error::Result;
-use crate::models::{ActionContext, ActionExecutionResult, ActionVariables, CustomAction};
-use crate::services::CustomActionsService;
+use crate::error::{AxisError, Result};
+use crate::events::CustomActionOutputEvent;
+use crate::models::{
+    ActionContext, ActionExecutionResult, ActionStorageType, ActionVariables, ConfigAlias,
+    CustomAction,
+};
+use crate::services::{alias_invocation, CustomActionsService};
 use crate::state::AppState;
-use tauri::{Manager, State};
+use tauri::State;
+use tauri_specta::Event;
 
 /// List all global actions
 #[tauri::command]
 #[specta::specta]
-pub async fn list_global_actions(app_handle: tauri::AppHandle) -> Result<Vec<CustomAction>> {
-    let app_data_dir = app_handle
-        .path()
-        .app_data_dir()
-        .map_err(|e| crate::error::AxisError::Other(format!("Failed to get app data dir: {e}")))?;
-
-    CustomActionsService::read_global_actions(&app_data_dir)
+pub async fn list_global_actions(state: State<'_, AppState>) -> Result<Vec<CustomAction>> {
+    state.database().list_custom_actions()
 }
 
-/// Save a global action (create or update)
+/// Save a global action (create or update by id). A missing id is assigned.
 #[tauri::command]
 #[specta::specta]
-pub async fn save_global_action(app_handle: tauri::AppHandle, action: CustomAction) -> Result<()> {
-    let app_data_dir = app_handle
-        .path()
-        .app_data_dir()
-        .map_err(|e| crate::error::AxisError::Other(format!("Failed to get app data dir: {e}")))?;
-
-    CustomActionsService::save_global_action(&app_data_dir, action)
+pub async fn save_custom_action(
+    state: State<'_, AppState>,
+    mut action: CustomAction,
+) -> Result<CustomAction> {
+    if action.id.trim().is_empty() {
+        action.id = uuid::Uuid::new_v4().to_string();
+    }
+    action.storage = Some(ActionStorageType::Global);
+    state.database().save_custom_action(&action)?;
+    Ok(action)
 }
 
 /// Delete a global action
 #[tauri::command]
 #[specta::specta]
-pub async fn delete_global_action(app_handle: tauri::AppHandle, action_id: String) -> Result<()> {
-    let app_data_dir = app_handle
-        .path()
-        .app_data_dir()
-        .map_err(|e| crate::error::AxisError::Other(format!("Failed to get app data dir: {e}")))?;
-
-    CustomActionsService::delete_global_action(&app_data_dir, &action_id)
+pub async fn delete_custom_action(state: State<'_, AppState>, action_id: String) -> Result<()> {
+    state.database().delete_custom_action(&action_id)
 }
 
 /// List all repository-specific actions
@@ -68,16 +66,10 @@ pub async fn delete_repo_action(state: State<'_, AppState>, action_id: String) -
 #[tauri::command]
 #[specta::specta]
 pub async fn get_actions_for_context(
-    app_handle: tauri::AppHandle,
     state: State<'_, AppState>,
     context: ActionContext,
 ) -> Result<Vec<CustomAction>> {
-    let app_data_dir = app_handle
-        .path()
-        .app_data_dir()
-        .map_err(|e| crate::error::AxisError::Other(format!("Failed to get app data dir: {e}")))?;
-
-    let global_actions = CustomActionsService::read_global_actions(&app_data_dir)?;
+    let global_actions = state.database().list_custom_actions()?;
 
     let repo_actions = if let Ok(repo_path) = state.ensure_repository_open() {
         CustomActionsService::read_repo_actions(&repo_path)?
@@ -94,16 +86,8 @@ pub async fn get_actions_for_context(
 /// Get all actions (merged global + repo, unfiltered)
 #[tauri::command]
 #[specta::specta]
-pub async fn get_all_actions(
-    app_handle: tauri::AppHandle,
-    state: State<'_, AppState>,
-) -> Result<Vec<CustomAction>> {
-    let app_data_dir = app_handle
-        .path()
-        .app_data_dir()
-        .map_err(|e| crate::error::AxisError::Other(format!("Failed to get app data dir: {e}")))?;
-
-    let global_actions = CustomActionsService::read_global_actions(&app_data_dir)?;
+pub async fn get_all_actions(state: State<'_, AppState>) -> Result<Vec<CustomAction>> {
+    let global_actions = state.database().list_custom_actions()?;
 
     let repo_actions = if let Ok(repo_path) = state.ensure_repository_open() {
         CustomActionsService::read_repo_actions(&repo_path)?
@@ -126,13 +110,8 @@ pub async fn execute_custom_action(
     action_id: String,
     variables: ActionVariables,
 ) -> Result<ActionExecutionResult> {
-    let app_data_dir = app_handle
-        .path()
-        .app_data_dir()
-        .map_err(|e| crate::error::AxisError::Other(format!("Failed to get app data dir: {e}")))?;
-
     // Find the action
-    let global_actions = CustomActionsService::read_global_actions(&app_data_dir)?;
+    let global_actions = state.database().list_custom_actions()?;
     let repo_actions = if let Ok(repo_path) = state.ensure_repository_open() {
         CustomActionsService::read_repo_actions(&repo_path)?
     } else {
@@ -144,7 +123,83 @@ pub async fn execute_custom_action(
     let action = all_actions
         .into_iter()
         .find(|a| a.id == action_id)
-        .ok_or_else(|| crate::error::AxisError::Other(format!("Action not found: {action_id}")))?;
+        .ok_or_else(|| AxisError::Other(format!("Action not found: {action_id}")))?;
+
+    // Stream output so long-running scripts show progress before they finish
+    CustomActionsService::execute_with_output(&action, &variables, |stream, line| {
+        let event = CustomActionOutputEvent {
+            action_id: action.id.clone(),
+            stream,
+            line: line.to_string(),
+        };
+        if let Err(e) = event.emit(&app_handle) {
+            log::error!("Failed to emit custom action output event: {e}");
+        }
+    })
+    .await
+}
 
-    CustomActionsService::execute(&action, &variables).await
+/// List git aliases (`alias.*`) from every config level
+#[tauri::command]
+#[specta::specta]
+pub async fn list_config_aliases(state: State<'_, AppState>) -> Result<Vec<ConfigAlias>> {
+    state
+        .get_git_service()?
+        .read()
+        .await
+        .list_config_aliases()
+        .await
+}
+
+/// Run a git alias with extra arguments, streaming output as `CustomActionOutputEvent`s
+/// with the action id `alias:<name>`.
+///
+/// Shell aliases (`!…`) run arbitrary commands and fail with
+/// `ShellAliasRequiresConfirmation` unless `confirmed` is set.
+#[tauri::command]
+#[specta::specta]
+pub async fn run_config_alias(
+    app_handle: tauri::AppHandle,
+    state: State<'_, AppState>,
+    name: String,
+    extra_args: Vec<String>,
+    confirmed: bool,
+) -> Result<ActionExecutionResult> {
+    let handle = state.get_git_service()?;
+    let alias = handle
+        .read()
+        .await
+        .list_config_aliases()
+        .await?
+        .into_iter()
+        .find(|a| !a.shadowed && a.name.eq_ignore_ascii_case(&name))
+        .ok_or_else(|| AxisError::Other(format!("Alias not found: {name}")))?;
+
+    let args = alias_invocation(&alias, extra_args, confirmed)?;
+
+    let action_id = format!("alias:{}", alias.name);
+    let on_output = |stream, line: &str| {
+        let event = CustomActionOutputEvent {
+            action_id: action_id.clone(),
+            stream,
+            line: line.to_string(),
+        };
+        if let Err(e) = event.emit(&app_handle) {
+            log::error!("Failed to emit alias output event: {e}");
+        }
+    };
+
+    if alias.refresh_after {
+        handle
+            .write()
+            .await
+            .run_git_streaming(&args, on_output)
+            .await
+    } else {
+        handle
+            .read()
+            .await
+            .run_git_streaming(&args, on_output)
+            .await
+    }
 }
@@ -1,9 +1,36 @@
 use crate::error::Result;
-use crate::models::{DiffOptions, DiffTarget, FileDiff};
+use crate::models::{
+    DiffContext, DiffOptions, DiffPreset, DiffTarget, FileContent, FileContentSource, FileDiff,
+    WorkdirRefDiffMode,
+};
 use crate::state::AppState;
 use tauri::ipc::Response;
 use tauri::State;
 
+/// Options for a diff command: the named preset, or the context's default preset,
+/// with any explicitly set fields taking precedence
+pub(crate) fn resolve_diff_options(
+    state: &AppState,
+    context: DiffContext,
+    preset: Option<&str>,
+    options: Option<DiffOptions>,
+) -> Result<DiffOptions> {
+    let preset = state.database().effective_diff_preset(context, preset)?;
+    let mut base = preset.options;
+    if preset.intra_line {
+        base.compute_word_diff.get_or_insert(true);
+    }
+    let settings = state.database().get_settings()?;
+    base.function_context
+        .get_or_insert(settings.diff_function_context);
+    base.structured_diff.get_or_insert(settings.diff_structured);
+    base.notebook_outputs
+        .get_or_insert(settings.diff_notebook_outputs);
+    base.structured_json_extensions
+        .get_or_insert(settings.diff_structured_json_extensions);
+    Ok(options.unwrap_or_default().over(&base))
+}
+
 /// Get diff based on the specified target
 #[tauri::command]
 #[specta::specta]
@@ -11,8 +38,15 @@ pub async fn get_diff(
     state: State<'_, AppState>,
     target: DiffTarget,
     options: Option<DiffOptions>,
+    preset: Option<String>,
 ) -> Result<Vec<FileDiff>> {
-    let opts = options.unwrap_or_default();
+    let context = match target {
+        DiffTarget::WorkdirToIndex | DiffTarget::IndexToHead | DiffTarget::WorkdirToHead => {
+            DiffContext::Workdir
+        }
+        DiffTarget::Commit { .. } | DiffTarget::CommitToCommit { .. } => DiffContext::Commit,
+    };
+    let opts = resolve_diff_options(&state, context, preset.as_deref(), options)?;
     let git_service = state.get_git_service()?;
     let guard = git_service.read().await;
     match target {
@@ -24,6 +58,27 @@ pub async fn get_diff(
     }
 }
 
+/// Diff the working tree, the index, or both against any ref, e.g. `origin/main`.
+/// Untracked files are included unless `include_untracked` is false.
+#[tauri::command]
+#[specta::specta]
+pub async fn diff_workdir_to_ref(
+    state: State<'_, AppState>,
+    reference: String,
+    mode: WorkdirRefDiffMode,
+    include_untracked: Option<bool>,
+    options: Option<DiffOptions>,
+    preset: Option<String>,
+) -> Result<Vec<FileDiff>> {
+    let opts = resolve_diff_options(&state, DiffContext::Workdir, preset.as_deref(), options)?;
+    state
+        .get_git_service()?
+        .read()
+        .await
+        .diff_workdir_to_ref(&reference, mode, include_untracked.unwrap_or(true), &opts)
+        .await
+}
+
 /// Get diff for a single file
 #[tauri::command]
 #[specta::specta]
@@ -32,12 +87,14 @@ pub async fn get_file_diff(
     path: String,
     staged: bool,
     options: Option<DiffOptions>,
+    preset: Option<String>,
 ) -> Result<Option<FileDiff>> {
+    let opts = resolve_diff_options(&state, DiffContext::Workdir, preset.as_deref(), options)?;
     state
         .get_git_service()?
         .read()
         .await
-        .diff_file(&path, staged, &options.unwrap_or_default())
+        .diff_file(&path, staged, &opts)
         .await
 }
 
@@ -56,3 +113,52 @@ pub async fn get_file_blob(
         .await?;
     Ok(Response::new(data))
 }
+
+/// Get file content from the working tree, the index or a commit as base64 with
+/// its detected mime type, for previews such as side-by-side images
+#[tauri::command]
+#[specta::specta]
+pub async fn get_file_content_base64(
+    state: State<'_, AppState>,
+    path: String,
+    source: FileContentSource,
+) -> Result<FileContent> {
+    state
+        .get_git_service()?
+        .read()
+        .await
+        .get_file_content(&path, source)
+        .await
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn list_diff_presets(state: State<'_, AppState>) -> Result<Vec<DiffPreset>> {
+    state.database().list_diff_presets()
+}
+
+/// Create or update a preset by name; built-in presets can be edited this way
+#[tauri::command]
+#[specta::specta]
+pub async fn save_diff_preset(
+    state: State<'_, AppState>,
+    preset: DiffPreset,
+) -> Result<DiffPreset> {
+    state.database().save_diff_preset(&preset)
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn delete_diff_preset(state: State<'_, AppState>, name: String) -> Result<()> {
+    state.database().delete_diff_preset(&name)
+}
+
+/// The preset diff commands use in `context` when no preset is named
+#[tauri::command]
+#[specta::specta]
+pub async fn get_effective_diff_options(
+    state: State<'_, AppState>,
+    context: DiffContext,
+) -> Result<DiffPreset> {
+    state.database().effective_diff_preset(context, None)
+}
@@ -65,11 +65,21 @@ pub async fn gitflow_feature_finish(
     name: String,
     options: GitFlowFinishOptions,
 ) -> Result<GitFlowResult> {
-    state
-        .get_git_service()?
-        .write()
-        .await
-        .gitflow_finish(GitFlowBranchType::Feature, &name, &options)
+    let settings = state.get_settings()?;
+    let git_service = state.get_git_service()?;
+    let guard = git_service.write().await;
+    let signing_config = if settings.sign_commits {
+        Some(guard.get_effective_signing_config().await?)
+    } else {
+        None
+    };
+    guard
+        .gitflow_finish(
+            GitFlowBranchType::Feature,
+            &name,
+            &options,
+            signing_config.as_ref(),
+        )
         .await
 }
 
@@ -125,11 +135,21 @@ pub async fn gitflow_release_finish(
     name: String,
     options: GitFlowFinishOptions,
 ) -> Result<GitFlowResult> {
-    state
-        .get_git_service()?
-        .write()
-        .await
-        .gitflow_finish(GitFlowBranchType::Release, &name, &options)
+    let settings = state.get_settings()?;
+    let git_service = state.get_git_service()?;
+    let guard = git_service.write().await;
+    let signing_config = if settings.sign_commits {
+        Some(guard.get_effective_signing_config().await?)
+    } else {
+        None
+    };
+    guard
+        .gitflow_finish(
+            GitFlowBranchType::Release,
+            &name,
+            &options,
+            signing_config.as_ref(),
+        )
         .await
 }
 
@@ -185,11 +205,21 @@ pub async fn gitflow_hotfix_finish(
     name: String,
     options: GitFlowFinishOptions,
 ) -> Result<GitFlowResult> {
-    state
-        .get_git_service()?
-        .write()
-        .await
-        .gitflow_finish(GitFlowBranchType::Hotfix, &name, &options)
+    let settings = state.get_settings()?;
+    let git_service = state.get_git_service()?;
+    let guard = git_service.write().await;
+    let signing_config = if settings.sign_commits {
+        Some(guard.get_effective_signing_config().await?)
+    } else {
+        None
+    };
+    guard
+        .gitflow_finish(
+            GitFlowBranchType::Hotfix,
+            &name,
+            &options,
+            signing_config.as_ref(),
+        )
         .await
 }
 
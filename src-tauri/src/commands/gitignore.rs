@@ -18,6 +18,21 @@ pub async fn add_to_gitignore(
         .await
 }
 
+#[tauri::command]
+#[specta::specta]
+pub async fn bulk_ignore(
+    state: State<'_, AppState>,
+    patterns: Vec<String>,
+    gitignore_path: String,
+) -> Result<Vec<IgnoreResult>> {
+    state
+        .get_git_service()?
+        .write()
+        .await
+        .bulk_ignore(patterns, &gitignore_path)
+        .await
+}
+
 #[tauri::command]
 #[specta::specta]
 pub async fn add_to_global_gitignore(
@@ -1,9 +1,14 @@
+use super::diff::resolve_diff_options;
 use crate::error::Result;
 use crate::models::{
-    BlameResult, DiffOptions, FileDiff, FileLogOptions, FileLogResult, GraphOptions, GraphResult,
-    SearchOptions, SearchResult,
+    BlameLineContext, BlameResult, Changelog, ChangelogOptions, DiffContext, DiffOptions,
+    FileCompareResult, FileDiff, FileLogOptions, FileLogResult, GraphOptions, GraphResult,
+    ListRemoteOptions, ResolvedPath, SearchOptions, SearchResult,
+};
+use crate::services::{
+    build_changelog, CommitCache, CommitCacheEntry, RepoWebLinks, PREFETCH_BUFFER,
+    PREFETCH_THRESHOLD,
 };
-use crate::services::{CommitCache, CommitCacheEntry, PREFETCH_BUFFER, PREFETCH_THRESHOLD};
 use crate::state::AppState;
 use std::sync::atomic::AtomicBool;
 use std::sync::Arc;
@@ -22,7 +27,10 @@ pub async fn build_graph(
     let cache_key = CommitCache::build_key(&repo_path, &options);
 
     let skip = options.skip.unwrap_or(0);
-    let limit = options.limit.unwrap_or(200);
+    let limit = match options.limit {
+        Some(limit) => limit,
+        None => state.performance_settings(&repo_path)?.graph_page_size as usize,
+    };
     let requested_end = skip + limit;
 
     log::debug!("[CommitCache] Request: skip={skip}, limit={limit}, key={cache_key}");
@@ -148,21 +156,76 @@ pub async fn blame_file(
         .await
 }
 
-/// Get total commit count for pagination
+/// Get what a blamed commit did to one line, for the blame popover
 #[tauri::command]
 #[specta::specta]
-pub async fn get_commit_count(
+pub async fn get_blame_line_context(
     state: State<'_, AppState>,
-    from_ref: Option<String>,
-) -> Result<usize> {
+    path: String,
+    line_number: u32,
+    commit_oid: String,
+) -> Result<BlameLineContext> {
     state
         .get_git_service()?
         .read()
         .await
-        .get_commit_count(from_ref.as_deref())
+        .get_blame_line_context(&path, line_number, &commit_oid)
         .await
 }
 
+/// Get total commit count for pagination
+#[tauri::command]
+#[specta::specta]
+pub async fn get_commit_count(
+    state: State<'_, AppState>,
+    from_ref: Option<String>,
+) -> Result<usize> {
+    let repo_path = state.ensure_repository_open()?;
+    let cache = state.commit_cache();
+    let git_handle = state.get_git_service()?;
+    let git = git_handle.read().await;
+
+    let tip = git.commit_count_tip(from_ref.as_deref()).await?;
+    let count_key = CommitCache::count_key(&repo_path, &tip);
+    if let Some(count) = cache.get_count(&count_key) {
+        return Ok(count);
+    }
+
+    let count = git.get_commit_count(Some(&tip)).await?;
+    cache.set_count(count_key, count);
+    Ok(count)
+}
+
+/// Changelog of the commits in `to_ref` but not in `from_ref`, grouped by conventional type
+#[tauri::command]
+#[specta::specta]
+pub async fn generate_changelog(
+    state: State<'_, AppState>,
+    from_ref: String,
+    to_ref: String,
+    options: ChangelogOptions,
+) -> Result<Changelog> {
+    let settings = state.get_settings()?;
+    let git_handle = state.get_git_service()?;
+    let git = git_handle.read().await;
+
+    let commits = git.commits_in_range(&from_ref, &to_ref).await?;
+    let links = if options.include_commit_links {
+        RepoWebLinks::for_remotes(&git.list_remotes(ListRemoteOptions::default()).await?)
+    } else {
+        None
+    };
+    let title = options.title.clone().unwrap_or(to_ref);
+
+    Ok(build_changelog(
+        &title,
+        &commits,
+        &settings.changelog_sections,
+        &options,
+        links.as_ref(),
+    ))
+}
+
 /// Get commit history for specific files
 #[tauri::command]
 #[specta::specta]
@@ -186,11 +249,56 @@ pub async fn get_file_diff_in_commit(
     commit_oid: String,
     path: String,
     options: Option<DiffOptions>,
+    preset: Option<String>,
 ) -> Result<Option<FileDiff>> {
+    let opts = resolve_diff_options(&state, DiffContext::Commit, preset.as_deref(), options)?;
+    state
+        .get_git_service()?
+        .read()
+        .await
+        .get_file_diff_in_commit(&commit_oid, &path, &opts)
+        .await
+}
+
+/// Find where a path as it existed at `from_ref` lives now, following renames forward
+#[tauri::command]
+#[specta::specta]
+pub async fn resolve_current_path(
+    state: State<'_, AppState>,
+    historical_path: String,
+    from_ref: String,
+) -> Result<ResolvedPath> {
+    state
+        .get_git_service()?
+        .read()
+        .await
+        .resolve_current_path(&historical_path, &from_ref)
+        .await
+}
+
+/// Compare a file between two refs, following renames across the range by default
+#[tauri::command]
+#[specta::specta]
+pub async fn get_file_diff_between(
+    state: State<'_, AppState>,
+    path: String,
+    from_ref: String,
+    to_ref: String,
+    options: Option<DiffOptions>,
+    follow_renames: Option<bool>,
+    preset: Option<String>,
+) -> Result<FileCompareResult> {
+    let opts = resolve_diff_options(&state, DiffContext::Commit, preset.as_deref(), options)?;
     state
         .get_git_service()?
         .read()
         .await
-        .get_file_diff_in_commit(&commit_oid, &path, &options.unwrap_or_default())
+        .get_file_diff_between(
+            &path,
+            &from_ref,
+            &to_ref,
+            &opts,
+            follow_renames.unwrap_or(true),
+        )
         .await
 }
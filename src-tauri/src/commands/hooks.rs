@@ -1,5 +1,9 @@
 use crate::error::Result;
-use crate::models::{GitHookType, HookDetails, HookInfo, HookTemplate};
+use crate::models::{
+    GitHookType, HookDetails, HookInfo, HookResult, HookTemplate, HookTrustDecision,
+    HookTrustRequest,
+};
+use crate::services::HookProgressEmitter;
 use crate::state::AppState;
 use tauri::State;
 
@@ -74,6 +78,48 @@ pub async fn toggle_hook(state: State<'_, AppState>, hook_type: GitHookType) ->
         .toggle_hook(hook_type)
 }
 
+/// Enable a hook
+/// Returns whether the hook state changed
+#[tauri::command]
+#[specta::specta]
+pub async fn enable_hook(state: State<'_, AppState>, hook_type: GitHookType) -> Result<bool> {
+    state
+        .get_git_service()?
+        .write()
+        .await
+        .set_hook_enabled(hook_type, true)
+}
+
+/// Disable a hook
+/// Returns whether the hook state changed
+#[tauri::command]
+#[specta::specta]
+pub async fn disable_hook(state: State<'_, AppState>, hook_type: GitHookType) -> Result<bool> {
+    state
+        .get_git_service()?
+        .write()
+        .await
+        .set_hook_enabled(hook_type, false)
+}
+
+/// Run a hook manually with optional arguments
+#[tauri::command]
+#[specta::specta]
+pub async fn run_hook(
+    state: State<'_, AppState>,
+    hook_type: GitHookType,
+    args: Option<Vec<String>>,
+) -> Result<HookResult> {
+    let app_handle = state.get_app_handle()?;
+    let emitter = HookProgressEmitter::new(app_handle, state.progress_registry());
+    Ok(state
+        .get_git_service()?
+        .read()
+        .await
+        .run_hook(hook_type, &args.unwrap_or_default(), Some(&emitter))
+        .await)
+}
+
 /// Get available hook templates
 #[tauri::command]
 #[specta::specta]
@@ -94,3 +140,31 @@ pub async fn get_hook_templates_for_type(
         .await
         .get_templates_for_type(hook_type))
 }
+
+/// Get the trust prompt for hooks in the current repository that have no decision yet
+#[tauri::command]
+#[specta::specta]
+pub async fn get_hook_trust_request(
+    state: State<'_, AppState>,
+) -> Result<Option<HookTrustRequest>> {
+    Ok(state
+        .get_git_service()?
+        .read()
+        .await
+        .pending_hook_trust_request())
+}
+
+/// Apply the user's trust decision to the hooks listed in a trust prompt
+#[tauri::command]
+#[specta::specta]
+pub async fn resolve_hook_trust(
+    state: State<'_, AppState>,
+    request: HookTrustRequest,
+    decision: HookTrustDecision,
+) -> Result<()> {
+    state
+        .get_git_service()?
+        .read()
+        .await
+        .resolve_hook_trust(&request.hooks, decision)
+}
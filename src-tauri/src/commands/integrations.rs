@@ -2,12 +2,13 @@ use tauri::{AppHandle, State};
 
 use crate::error::Result;
 use crate::models::{
-    CiRunsPage, CommitStatus, CreateIssueOptions, CreatePrOptions, DetectedProvider,
-    IntegrationLabel, IntegrationRepoInfo, IntegrationStatus, Issue, IssueDetail, IssueState,
-    IssuesPage, ListRemoteOptions, MergePrOptions, NotificationsPage, PrState, ProviderType,
-    PullRequest, PullRequestDetail, PullRequestsPage,
+    BranchType, CiRollup, CiRunsPage, CommitStatus, CreateIssueOptions, CreatePrOptions,
+    CurrentBranchPullRequest, DetectedProvider, IntegrationLabel, IntegrationRepoInfo,
+    IntegrationStatus, Issue, IssueDetail, IssueState, IssuesPage, ListRemoteOptions,
+    MergePrOptions, NotificationsPage, PrState, ProviderType, PullRequest, PullRequestDetail,
+    PullRequestsPage, UnreadNotificationCount,
 };
-use crate::services::detect_provider;
+use crate::services::{branch_ci_rollup, detect_provider};
 use crate::state::AppState;
 
 // ============================================================================
@@ -66,6 +67,20 @@ pub async fn integration_disconnect(
     service.disconnect(provider).await
 }
 
+/// Upload an ASCII-armored GPG public key (see `export_gpg_public_key`) to the connected
+/// account
+#[tauri::command]
+#[specta::specta]
+pub async fn integration_add_gpg_key(
+    state: State<'_, AppState>,
+    provider: ProviderType,
+    armored_public_key: String,
+) -> Result<()> {
+    let service = state.integration_service()?;
+    let provider_instance = service.get_provider(provider).await?;
+    provider_instance.add_gpg_key(&armored_public_key).await
+}
+
 // ============================================================================
 // Provider Detection Commands
 // ============================================================================
@@ -269,6 +284,72 @@ pub async fn integration_get_commit_status(
         .await
 }
 
+/// Aggregate CI status for the current branch's tip commit
+#[tauri::command]
+#[specta::specta]
+pub async fn integration_get_branch_ci_rollup(
+    state: State<'_, AppState>,
+    detected: DetectedProvider,
+) -> Result<CiRollup> {
+    let (branch, sha, pushed) = {
+        let git_service = state.get_git_service()?;
+        let guard = git_service.read().await;
+        let branch = guard.get_current_branch().await;
+        match (guard.get_head_oid_opt().await, &branch) {
+            // Unborn branch: nothing to build yet
+            (None, _) => (branch, String::new(), false),
+            (Some(sha), Some(name)) => {
+                let info = guard.get_branch(name, BranchType::Local).await?;
+                let pushed = info.upstream.is_some() && info.ahead.unwrap_or(0) == 0;
+                (branch, sha, pushed)
+            }
+            // Detached HEAD: no upstream to compare against, ask the provider directly
+            (Some(sha), None) => (None, sha, true),
+        }
+    };
+
+    let service = state.integration_service()?;
+    let provider = service.get_provider(detected.provider).await?;
+    branch_ci_rollup(
+        provider.as_ref(),
+        &detected.owner,
+        &detected.repo,
+        branch,
+        sha,
+        pushed,
+    )
+    .await
+}
+
+/// Open pull request for the checked-out branch, with how far the local branch diverged from
+/// its head. `None` when the repository is not on a connected provider.
+#[tauri::command]
+#[specta::specta]
+pub async fn integration_get_current_branch_pr(
+    state: State<'_, AppState>,
+) -> Result<Option<CurrentBranchPullRequest>> {
+    let service = state.integration_service()?;
+    let git_service = state.get_git_service()?;
+    let guard = git_service.read().await;
+    service.current_branch_pull_request(&guard).await
+}
+
+/// Re-run a CI workflow run, optionally only its failed jobs
+#[tauri::command]
+#[specta::specta]
+pub async fn integration_rerun_ci_run(
+    state: State<'_, AppState>,
+    detected: DetectedProvider,
+    run_id: String,
+    failed_only: bool,
+) -> Result<()> {
+    let service = state.integration_service()?;
+    let provider = service.get_provider(detected.provider).await?;
+    provider
+        .rerun_ci_run(&detected.owner, &detected.repo, &run_id, failed_only)
+        .await
+}
+
 // ============================================================================
 // Notification Commands
 // ============================================================================
@@ -318,7 +399,7 @@ pub async fn integration_mark_all_notifications_read(
 pub async fn integration_get_unread_count(
     state: State<'_, AppState>,
     detected: DetectedProvider,
-) -> Result<u32> {
+) -> Result<UnreadNotificationCount> {
     let service = state.integration_service()?;
     let provider = service.get_provider(detected.provider).await?;
     provider
@@ -1,9 +1,11 @@
 use crate::error::Result;
+use crate::events::{GitOperationType, ProgressStage};
 use crate::models::{
     GitEnvironment, LfsEnvironment, LfsFetchOptions, LfsFile, LfsMigrateOptions, LfsPruneOptions,
     LfsPruneResult, LfsPullOptions, LfsPushOptions, LfsResult, LfsStatus, LfsTrackedPattern,
 };
 use crate::services::ops::RepoOperations;
+use crate::services::ProgressContext;
 use crate::state::AppState;
 use tauri::State;
 
@@ -73,6 +75,17 @@ pub async fn lfs_list_patterns(state: State<'_, AppState>) -> Result<Vec<LfsTrac
         .await
 }
 
+/// List patterns tracked by LFS in `.gitattributes`
+#[tauri::command]
+#[specta::specta]
+pub async fn get_lfs_tracked_patterns(state: State<'_, AppState>) -> Result<Vec<String>> {
+    state
+        .get_git_service()?
+        .read()
+        .await
+        .get_lfs_tracked_patterns()
+}
+
 /// List all LFS files in the repository
 #[tauri::command]
 #[specta::specta]
@@ -100,12 +113,20 @@ pub async fn lfs_fetch(state: State<'_, AppState>, options: LfsFetchOptions) ->
 pub async fn lfs_pull(state: State<'_, AppState>, options: LfsPullOptions) -> Result<LfsResult> {
     let remote = options.remote.as_deref().unwrap_or("origin");
     let ssh_creds = state.resolve_ssh_credentials(remote)?;
-    state
+    let ctx = ProgressContext::new(state.get_app_handle()?, state.progress_registry());
+
+    ctx.emit(GitOperationType::Pull, ProgressStage::Connecting, None);
+
+    let mut cb = ctx.make_transfer_callback(GitOperationType::Pull, ProgressStage::Receiving);
+    let result = state
         .get_git_service()?
         .write()
         .await
-        .lfs_pull(&options, ssh_creds)
-        .await
+        .lfs_pull(&options, ssh_creds, &mut cb)
+        .await;
+
+    ctx.handle_result(&result, GitOperationType::Pull);
+    result
 }
 
 /// Push LFS objects to remote
@@ -114,12 +135,20 @@ pub async fn lfs_pull(state: State<'_, AppState>, options: LfsPullOptions) -> Re
 pub async fn lfs_push(state: State<'_, AppState>, options: LfsPushOptions) -> Result<LfsResult> {
     let remote = options.remote.as_deref().unwrap_or("origin");
     let ssh_creds = state.resolve_ssh_credentials(remote)?;
-    state
+    let ctx = ProgressContext::new(state.get_app_handle()?, state.progress_registry());
+
+    ctx.emit(GitOperationType::Push, ProgressStage::Connecting, None);
+
+    let mut cb = ctx.make_transfer_callback(GitOperationType::Push, ProgressStage::Writing);
+    let result = state
         .get_git_service()?
         .write()
         .await
-        .lfs_push(&options, ssh_creds)
-        .await
+        .lfs_push(&options, ssh_creds, &mut cb)
+        .await;
+
+    ctx.handle_result(&result, GitOperationType::Push);
+    result
 }
 
 /// Migrate files to/from LFS
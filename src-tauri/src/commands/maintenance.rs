@@ -0,0 +1,94 @@
+use crate::error::Result;
+use crate::events::{GitOperationProgressEvent, GitOperationType, ProgressStage};
+use crate::models::{
+    MaintenanceOptions, MaintenanceResult, MaintenanceTask, PruneDryRunResult, RepositorySizeReport,
+};
+use crate::services::ProgressContext;
+use crate::state::AppState;
+use std::sync::atomic::Ordering;
+use tauri::State;
+
+/// Number of largest blobs reported when the caller does not say
+const DEFAULT_LARGEST_BLOBS: usize = 20;
+
+// ==================== Maintenance Commands ====================
+
+/// Object store sizes, the largest blobs and reflog sizes of the open repository
+#[tauri::command]
+#[specta::specta]
+pub async fn get_repository_size_report(
+    state: State<'_, AppState>,
+    largest_blobs: Option<usize>,
+) -> Result<RepositorySizeReport> {
+    state
+        .get_git_service()?
+        .read()
+        .await
+        .get_repository_size_report(largest_blobs.unwrap_or(DEFAULT_LARGEST_BLOBS))
+        .await
+}
+
+/// List the unreachable loose objects `git prune` would delete
+#[tauri::command]
+#[specta::specta]
+pub async fn prune_dry_run(state: State<'_, AppState>) -> Result<PruneDryRunResult> {
+    state.get_git_service()?.read().await.prune_dry_run().await
+}
+
+/// Run gc, prune, repack and commit-graph tasks, streaming progress as
+/// `GitOperationProgressEvent`s. Holds the repository write lock throughout so no other
+/// operation changes refs or objects mid-gc.
+#[tauri::command]
+#[specta::specta]
+pub async fn run_maintenance(
+    state: State<'_, AppState>,
+    options: MaintenanceOptions,
+) -> Result<MaintenanceResult> {
+    let ctx = ProgressContext::new(state.get_app_handle()?, state.progress_registry());
+    let cancel_token = ctx.cancel_token();
+    let emitter = ctx.emitter();
+    let op_id = ctx.operation_id.clone();
+
+    let mut on_progress =
+        move |task: MaintenanceTask, phase: &str, current: usize, total: usize| {
+            if cancel_token.load(Ordering::SeqCst) {
+                return false;
+            }
+            let mut event = GitOperationProgressEvent::new(
+                op_id.clone(),
+                GitOperationType::Maintenance,
+                maintenance_stage(phase),
+            );
+            event.message = Some(if phase.is_empty() {
+                format!("{task:?}")
+            } else {
+                format!("{task:?}: {phase}")
+            });
+            if total > 0 {
+                event.total_objects = Some(total);
+                event.received_objects = Some(current);
+            }
+            emitter.emit_progress(&event, phase.is_empty())
+        };
+
+    let result = state
+        .get_git_service()?
+        .write()
+        .await
+        .run_maintenance(&options, &mut on_progress)
+        .await;
+
+    ctx.handle_result(&result, GitOperationType::Maintenance);
+    result
+}
+
+/// Progress stage closest to a git progress phase such as "Compressing objects"
+fn maintenance_stage(phase: &str) -> ProgressStage {
+    if phase.starts_with("Compressing") || phase.starts_with("Delta compression") {
+        ProgressStage::Compressing
+    } else if phase.starts_with("Writing") {
+        ProgressStage::Writing
+    } else {
+        ProgressStage::Counting
+    }
+}
@@ -1,14 +1,22 @@
 use crate::error::{AxisError, Result};
+use crate::events::OperationStateChangedEvent;
 use crate::models::{
-    CherryPickOptions, CherryPickResult, ConflictContent, ConflictResolution, ConflictedFile,
-    InteractiveRebaseEntry, InteractiveRebaseOptions, InteractiveRebasePreview, MergeOptions,
-    MergeResult, MergeType, OperationState, RebaseAction, RebaseOntoOptions, RebaseOptions,
-    RebasePreview, RebaseProgress, RebaseResult, ResetOptions, RevertOptions, RevertResult,
+    CherryPickOptions, CherryPickPreview, CherryPickResult, ConflictContent, ConflictHunk,
+    ConflictHunkChoice, ConflictResolution, ConflictedFile, InteractiveRebaseEntry,
+    InteractiveRebaseOptions, InteractiveRebasePreview, MergeOptions, MergePreview, MergeResult,
+    MergeType, NotebookMergeResult, OperationCategory, OperationState, RebaseAction,
+    RebaseOntoOptions, RebaseOptions, RebasePreview, RebaseProgress, RebaseResult, ResetMode,
+    ResetOptions, ResetResult, RevertOptions, RevertResult, RewordPlan, RewritePreconditions,
+    SigningConfig, UndoOperation,
 };
+use crate::services::ops::RepoOperations;
 use crate::services::HookProgressEmitter;
 use crate::state::AppState;
+use serde_json::json;
 use std::fs;
+use std::path::Path;
 use tauri::State;
+use tauri_specta::Event;
 
 // ==================== Merge Commands ====================
 
@@ -23,16 +31,63 @@ pub async fn merge_branch(
     let git_service = state.get_git_service()?;
     let guard = git_service.write().await;
 
-    let result = guard
-        .merge(
-            &options.branch,
-            options.message.as_deref(),
-            options.no_ff,
-            options.squash,
-            options.ff_only,
-            options.no_commit,
-        )
-        .await?;
+    // Git only runs hooks itself while all of them are trusted; otherwise run the
+    // pre-merge-commit hook here so a trusted one still gates the merge commit
+    let creates_commit = !options.squash && !options.no_commit && !options.ff_only;
+    if creates_commit && !settings.bypass_hooks && !guard.git_may_run_hooks() {
+        let app_handle = state.get_app_handle()?;
+        let emitter = HookProgressEmitter::new(app_handle, state.progress_registry());
+        let hook_result = guard.run_pre_merge_commit(Some(&emitter)).await;
+
+        if hook_result.is_cancelled() {
+            return Err(AxisError::Other("Hook cancelled by user".into()));
+        }
+
+        if let Some(request) = hook_result.trust_request {
+            return Err(AxisError::HookTrustRequired(request));
+        }
+
+        if !hook_result.skipped && !hook_result.success {
+            let output = if hook_result.stderr.is_empty() {
+                &hook_result.stdout
+            } else {
+                &hook_result.stderr
+            };
+            return Err(AxisError::Other(format!(
+                "Hook 'pre-merge-commit' failed:\n{}",
+                output.trim()
+            )));
+        }
+    }
+
+    state
+        .record_undo_snapshot(&guard, UndoOperation::Merge)
+        .await;
+
+    let result = if options.trailers.is_empty() || options.squash || options.no_commit {
+        guard
+            .merge(
+                &options.branch,
+                options.message.as_deref(),
+                options.no_ff,
+                options.squash,
+                options.ff_only,
+                options.no_commit,
+                options.strategy,
+            )
+            .await?
+    } else {
+        guard
+            .merge_with_trailers(
+                &options.branch,
+                options.message.as_deref(),
+                options.no_ff,
+                options.ff_only,
+                options.strategy,
+                &options.trailers,
+            )
+            .await?
+    };
 
     let merge_result = if result.success {
         // Determine merge type from output
@@ -44,31 +99,38 @@ pub async fn merge_branch(
             MergeType::Normal
         };
 
-        MergeResult {
+        Ok(MergeResult {
             success: true,
             merge_type,
             commit_oid: None,
             conflicts: Vec::new(),
             message: result.stdout.trim().to_string(),
-        }
+        })
     } else if result.stdout.contains("CONFLICT") || result.stderr.contains("Automatic merge failed")
     {
         // Merge has conflicts
         let conflicts = guard.get_conflicted_files_enriched().await?;
 
-        MergeResult {
+        Ok(MergeResult {
             success: false,
             merge_type: MergeType::Conflicted,
             commit_oid: None,
             conflicts,
             message: "Merge conflicts detected. Please resolve conflicts and commit.".to_string(),
-        }
+        })
     } else {
-        return Err(AxisError::Other(format!(
+        Err(AxisError::Other(format!(
             "Merge failed: {}",
             result.stderr.trim()
-        )));
+        )))
     };
+    state.record_operation(
+        OperationCategory::Merge,
+        "merge",
+        json!({ "options": options }),
+        &merge_result,
+    );
+    let merge_result = merge_result?;
 
     // Run post-merge hook if merge was successful (informational, don't fail)
     if merge_result.success && !settings.bypass_hooks {
@@ -96,12 +158,11 @@ pub async fn merge_abort(state: State<'_, AppState>) -> Result<()> {
 #[tauri::command]
 #[specta::specta]
 pub async fn merge_continue(state: State<'_, AppState>) -> Result<MergeResult> {
-    let result = state
-        .get_git_service()?
-        .write()
-        .await
-        .merge_continue()
-        .await?;
+    let git_service = state.get_git_service()?;
+    let guard = git_service.write().await;
+
+    let result = guard.merge_continue().await?;
+    emit_operation_state_changed(&state, &guard).await;
 
     Ok(MergeResult {
         success: result.success,
@@ -116,6 +177,21 @@ pub async fn merge_continue(state: State<'_, AppState>) -> Result<MergeResult> {
     })
 }
 
+/// Get preview information for merging a branch into the current branch
+#[tauri::command]
+#[specta::specta]
+pub async fn get_merge_preview(
+    state: State<'_, AppState>,
+    source_ref: String,
+) -> Result<MergePreview> {
+    state
+        .get_git_service()?
+        .read()
+        .await
+        .get_merge_preview(&source_ref)
+        .await
+}
+
 // ==================== Rebase Commands ====================
 
 /// Start a rebase onto a target branch
@@ -149,6 +225,10 @@ pub async fn rebase_branch(
             return Err(AxisError::Other("Hook cancelled by user".into()));
         }
 
+        if let Some(request) = hook_result.trust_request {
+            return Err(AxisError::HookTrustRequired(request));
+        }
+
         if !hook_result.skipped && !hook_result.success {
             let output = if hook_result.stderr.is_empty() {
                 &hook_result.stdout
@@ -162,9 +242,13 @@ pub async fn rebase_branch(
         }
     }
 
+    state
+        .record_undo_snapshot(&guard, UndoOperation::Rebase)
+        .await;
+
     let result = guard.rebase(&options.onto, options.interactive).await?;
 
-    if result.success {
+    let rebase_result = if result.success {
         Ok(RebaseResult {
             success: true,
             commits_rebased: 0,
@@ -190,7 +274,15 @@ pub async fn rebase_branch(
             "Rebase failed: {}",
             result.stderr.trim()
         )))
-    }
+    };
+    state.record_operation(
+        OperationCategory::Rebase,
+        "rebase",
+        json!({ "options": options }),
+        &rebase_result,
+    );
+
+    rebase_result
 }
 
 /// Rebase commits onto a new base (git rebase --onto)
@@ -222,6 +314,10 @@ pub async fn rebase_onto(
             return Err(AxisError::Other("Hook cancelled by user".into()));
         }
 
+        if let Some(request) = hook_result.trust_request {
+            return Err(AxisError::HookTrustRequired(request));
+        }
+
         if !hook_result.skipped && !hook_result.success {
             let output = if hook_result.stderr.is_empty() {
                 &hook_result.stdout
@@ -243,7 +339,7 @@ pub async fn rebase_onto(
         )
         .await?;
 
-    if result.success {
+    let rebase_result = if result.success {
         Ok(RebaseResult {
             success: true,
             commits_rebased: 0,
@@ -269,7 +365,15 @@ pub async fn rebase_onto(
             "Rebase onto failed: {}",
             result.stderr.trim()
         )))
-    }
+    };
+    state.record_operation(
+        OperationCategory::Rebase,
+        "rebase_onto",
+        json!({ "options": options }),
+        &rebase_result,
+    );
+
+    rebase_result
 }
 
 /// Abort an in-progress rebase
@@ -293,6 +397,7 @@ pub async fn rebase_continue(state: State<'_, AppState>) -> Result<RebaseResult>
     let guard = git_service.write().await;
 
     let result = guard.rebase_continue().await?;
+    emit_operation_state_changed(&state, &guard).await;
 
     if result.success {
         Ok(RebaseResult {
@@ -326,7 +431,11 @@ pub async fn rebase_continue(state: State<'_, AppState>) -> Result<RebaseResult>
 #[tauri::command]
 #[specta::specta]
 pub async fn rebase_skip(state: State<'_, AppState>) -> Result<RebaseResult> {
-    let result = state.get_git_service()?.write().await.rebase_skip().await?;
+    let git_service = state.get_git_service()?;
+    let guard = git_service.write().await;
+
+    let result = guard.rebase_skip().await?;
+    emit_operation_state_changed(&state, &guard).await;
 
     Ok(RebaseResult {
         success: result.success,
@@ -411,6 +520,10 @@ pub async fn interactive_rebase(
             return Err(AxisError::Other("Hook cancelled by user".into()));
         }
 
+        if let Some(request) = hook_result.trust_request {
+            return Err(AxisError::HookTrustRequired(request));
+        }
+
         if !hook_result.skipped && !hook_result.success {
             let output = if hook_result.stderr.is_empty() {
                 &hook_result.stdout
@@ -424,6 +537,11 @@ pub async fn interactive_rebase(
         }
     }
 
+    // Checked after the hook, right before git starts rewriting
+    if let Some(preconditions) = &options.preconditions {
+        guard.check_rewrite_preconditions(preconditions).await?;
+    }
+
     // Count non-dropped commits
     let commits_count = options
         .entries
@@ -436,7 +554,7 @@ pub async fn interactive_rebase(
         .interactive_rebase(&options.onto, &options.entries, options.autosquash)
         .await?;
 
-    if result.success {
+    let rebase_result = if result.success {
         Ok(RebaseResult {
             success: true,
             commits_rebased: commits_count,
@@ -462,7 +580,95 @@ pub async fn interactive_rebase(
             "Interactive rebase failed: {}",
             result.stderr.trim()
         )))
-    }
+    };
+    state.record_operation(
+        OperationCategory::Rebase,
+        "interactive_rebase",
+        json!({ "onto": options.onto, "commits": options.entries.len() }),
+        &rebase_result,
+    );
+
+    rebase_result
+}
+
+/// Squash the last `count` commits into a single commit with `new_message`.
+/// Returns the new HEAD OID.
+#[tauri::command]
+#[specta::specta]
+pub async fn squash_commits(
+    state: State<'_, AppState>,
+    count: u32,
+    new_message: String,
+) -> Result<String> {
+    let settings = state.get_settings()?;
+    let git_service = state.get_git_service()?;
+    let guard = git_service.write().await;
+
+    let signing_config = if settings.sign_commits {
+        Some(guard.get_effective_signing_config().await?)
+    } else {
+        None
+    };
+
+    state
+        .record_undo_snapshot(&guard, UndoOperation::Rebase)
+        .await;
+
+    guard
+        .squash_commits(count, &new_message, signing_config.as_ref())
+        .await
+}
+
+/// Plan rewording `oid`: the commits it touches and the preconditions to pass back to
+/// `reword_commit`
+#[tauri::command]
+#[specta::specta]
+pub async fn plan_reword_commit(state: State<'_, AppState>, oid: String) -> Result<RewordPlan> {
+    state
+        .get_git_service()?
+        .read()
+        .await
+        .plan_reword_commit(&oid)
+        .await
+}
+
+/// Change the message of `oid`, HEAD or any of its ancestors, without a full interactive
+/// rebase. Returns the new HEAD OID. With the preconditions of a `plan_reword_commit`
+/// plan, fails with `PreconditionFailed` if the repository changed since.
+#[tauri::command]
+#[specta::specta]
+pub async fn reword_commit(
+    state: State<'_, AppState>,
+    oid: String,
+    new_message: String,
+    preconditions: Option<RewritePreconditions>,
+) -> Result<String> {
+    let settings = state.get_settings()?;
+    let git_service = state.get_git_service()?;
+    let guard = git_service.write().await;
+
+    let signing_config = if settings.sign_commits {
+        Some(guard.get_effective_signing_config().await?)
+    } else {
+        None
+    };
+
+    let target = guard.get_commit(&oid).await?;
+    let operation = if guard.get_head_oid_opt().await.as_deref() == Some(target.oid.as_str()) {
+        UndoOperation::Amend
+    } else {
+        UndoOperation::Rebase
+    };
+    state.record_undo_snapshot(&guard, operation).await;
+
+    guard
+        .reword_commit(
+            &oid,
+            &new_message,
+            signing_config.as_ref(),
+            preconditions.as_ref(),
+        )
+        .await
 }
 
 /// Get detailed rebase progress
@@ -472,6 +678,35 @@ pub async fn get_rebase_progress(state: State<'_, AppState>) -> Result<Option<Re
     state.get_git_service()?.read().await.get_rebase_progress()
 }
 
+/// Read the remaining todo of the interactive rebase in progress
+#[tauri::command]
+#[specta::specta]
+pub async fn get_interactive_rebase_todo(
+    state: State<'_, AppState>,
+) -> Result<Vec<InteractiveRebaseEntry>> {
+    state
+        .get_git_service()?
+        .read()
+        .await
+        .get_interactive_rebase_todo()
+        .await
+}
+
+/// Reorder, drop or change actions of the commits left in an interactive rebase
+#[tauri::command]
+#[specta::specta]
+pub async fn update_interactive_rebase_todo(
+    state: State<'_, AppState>,
+    todo: Vec<InteractiveRebaseEntry>,
+) -> Result<()> {
+    state
+        .get_git_service()?
+        .write()
+        .await
+        .update_interactive_rebase_todo(todo)
+        .await
+}
+
 /// Continue rebase with a new commit message (for Reword action)
 #[tauri::command]
 #[specta::specta]
@@ -483,6 +718,7 @@ pub async fn rebase_continue_with_message(
     let guard = git_service.write().await;
 
     let result = guard.rebase_continue_with_message(&message).await?;
+    emit_operation_state_changed(&state, &guard).await;
 
     if result.success {
         Ok(RebaseResult {
@@ -514,61 +750,80 @@ pub async fn rebase_continue_with_message(
 
 // ==================== Cherry-pick Commands ====================
 
-/// Cherry-pick commits
+/// Cherry-pick commits (an explicit list or a range), pausing on the first conflict
 #[tauri::command]
 #[specta::specta]
 pub async fn cherry_pick(
     state: State<'_, AppState>,
     options: CherryPickOptions,
 ) -> Result<CherryPickResult> {
+    let path = state.ensure_repository_open()?;
     let git_service = state.get_git_service()?;
     let guard = git_service.write().await;
 
-    let mut all_success = true;
-    let mut all_conflicts = Vec::new();
-    let commit_oids = Vec::new();
+    let queue = guard.prepare_cherry_pick_queue(&options).await?;
+    let signing_config = cherry_pick_signing_config(&state, &guard, queue.no_commit).await?;
+    let picked = guard
+        .run_cherry_pick_queue(queue, signing_config.as_ref())
+        .await;
+    state.record_operation(
+        OperationCategory::Merge,
+        "cherry_pick",
+        json!({ "options": options }),
+        &picked,
+    );
+    let (result, pending) = picked?;
+
+    state.set_cherry_pick_queue(&path, pending);
+    Ok(result)
+}
 
-    for commit in &options.commits {
-        let result = guard.cherry_pick(commit, options.no_commit).await?;
+/// Preview which commits a cherry-pick would apply and which files would likely conflict
+#[tauri::command]
+#[specta::specta]
+pub async fn get_cherry_pick_preview(
+    state: State<'_, AppState>,
+    oids: Vec<String>,
+) -> Result<CherryPickPreview> {
+    state
+        .get_git_service()?
+        .read()
+        .await
+        .get_cherry_pick_preview(oids)
+        .await
+}
 
-        if !result.success {
-            all_success = false;
-            if result.stdout.contains("CONFLICT") {
-                all_conflicts.extend(guard.get_conflicted_files_enriched().await?);
-                break; // Stop on first conflict
-            }
-            return Err(AxisError::Other(format!(
-                "Cherry-pick failed: {}",
-                result.stderr.trim()
-            )));
-        }
+/// Signing config for commits created by cherry-pick, following the sign commits setting
+async fn cherry_pick_signing_config(
+    state: &AppState,
+    ops: &RepoOperations,
+    no_commit: bool,
+) -> Result<Option<SigningConfig>> {
+    if no_commit || !state.get_settings()?.sign_commits {
+        return Ok(None);
     }
-
-    Ok(CherryPickResult {
-        success: all_success,
-        commit_oids,
-        conflicts: all_conflicts,
-        message: if all_success {
-            format!(
-                "Successfully cherry-picked {} commit(s).",
-                options.commits.len()
-            )
-        } else {
-            "Cherry-pick has conflicts. Please resolve and continue.".to_string()
-        },
-    })
+    Ok(Some(ops.get_effective_signing_config().await?))
 }
 
 /// Abort an in-progress cherry-pick
 #[tauri::command]
 #[specta::specta]
 pub async fn cherry_pick_abort(state: State<'_, AppState>) -> Result<()> {
-    state
-        .get_git_service()?
-        .write()
-        .await
-        .cherry_pick_abort()
-        .await?;
+    let path = state.ensure_repository_open()?;
+    let git_service = state.get_git_service()?;
+    let guard = git_service.write().await;
+
+    match state.take_cherry_pick_queue(&path) {
+        Some(queue) => {
+            if let Err(e) = guard.abort_cherry_pick_queue(&queue).await {
+                state.set_cherry_pick_queue(&path, Some(queue));
+                return Err(e);
+            }
+        }
+        None => {
+            guard.cherry_pick_abort().await?;
+        }
+    }
     Ok(())
 }
 
@@ -576,10 +831,30 @@ pub async fn cherry_pick_abort(state: State<'_, AppState>) -> Result<()> {
 #[tauri::command]
 #[specta::specta]
 pub async fn cherry_pick_continue(state: State<'_, AppState>) -> Result<CherryPickResult> {
+    let path = state.ensure_repository_open()?;
     let git_service = state.get_git_service()?;
     let guard = git_service.write().await;
 
+    if let Some(queue) = state.take_cherry_pick_queue(&path) {
+        let signing_config = cherry_pick_signing_config(&state, &guard, queue.no_commit).await?;
+        return match guard
+            .continue_cherry_pick_queue(queue.clone(), signing_config.as_ref())
+            .await
+        {
+            Ok((result, pending)) => {
+                state.set_cherry_pick_queue(&path, pending);
+                emit_operation_state_changed(&state, &guard).await;
+                Ok(result)
+            }
+            Err(e) => {
+                state.set_cherry_pick_queue(&path, Some(queue));
+                Err(e)
+            }
+        };
+    }
+
     let result = guard.cherry_pick_continue().await?;
+    emit_operation_state_changed(&state, &guard).await;
 
     if result.success {
         Ok(CherryPickResult {
@@ -587,6 +862,7 @@ pub async fn cherry_pick_continue(state: State<'_, AppState>) -> Result<CherryPi
             commit_oids: Vec::new(),
             conflicts: Vec::new(),
             message: "Cherry-pick completed successfully.".to_string(),
+            skipped: Vec::new(),
         })
     } else if result.stdout.contains("CONFLICT") {
         let conflicts = guard.get_conflicted_files_enriched().await?;
@@ -596,6 +872,7 @@ pub async fn cherry_pick_continue(state: State<'_, AppState>) -> Result<CherryPi
             commit_oids: Vec::new(),
             conflicts,
             message: "More conflicts detected. Please resolve and continue.".to_string(),
+            skipped: Vec::new(),
         })
     } else {
         Err(AxisError::Other(format!(
@@ -609,10 +886,30 @@ pub async fn cherry_pick_continue(state: State<'_, AppState>) -> Result<CherryPi
 #[tauri::command]
 #[specta::specta]
 pub async fn cherry_pick_skip(state: State<'_, AppState>) -> Result<CherryPickResult> {
+    let path = state.ensure_repository_open()?;
     let git_service = state.get_git_service()?;
     let guard = git_service.write().await;
 
+    if let Some(queue) = state.take_cherry_pick_queue(&path) {
+        let signing_config = cherry_pick_signing_config(&state, &guard, queue.no_commit).await?;
+        return match guard
+            .skip_cherry_pick_queue(queue.clone(), signing_config.as_ref())
+            .await
+        {
+            Ok((result, pending)) => {
+                state.set_cherry_pick_queue(&path, pending);
+                emit_operation_state_changed(&state, &guard).await;
+                Ok(result)
+            }
+            Err(e) => {
+                state.set_cherry_pick_queue(&path, Some(queue));
+                Err(e)
+            }
+        };
+    }
+
     let result = guard.cherry_pick_skip().await?;
+    emit_operation_state_changed(&state, &guard).await;
 
     if result.success {
         Ok(CherryPickResult {
@@ -620,6 +917,7 @@ pub async fn cherry_pick_skip(state: State<'_, AppState>) -> Result<CherryPickRe
             commit_oids: Vec::new(),
             conflicts: Vec::new(),
             message: "Commit skipped.".to_string(),
+            skipped: Vec::new(),
         })
     } else if result.stdout.contains("CONFLICT") {
         let conflicts = guard.get_conflicted_files_enriched().await?;
@@ -629,6 +927,7 @@ pub async fn cherry_pick_skip(state: State<'_, AppState>) -> Result<CherryPickRe
             commit_oids: Vec::new(),
             conflicts,
             message: "More conflicts detected. Please resolve and continue.".to_string(),
+            skipped: Vec::new(),
         })
     } else {
         Err(AxisError::Other(format!(
@@ -698,12 +997,11 @@ pub async fn revert_abort(state: State<'_, AppState>) -> Result<()> {
 #[tauri::command]
 #[specta::specta]
 pub async fn revert_continue(state: State<'_, AppState>) -> Result<RevertResult> {
-    let result = state
-        .get_git_service()?
-        .write()
-        .await
-        .revert_continue()
-        .await?;
+    let git_service = state.get_git_service()?;
+    let guard = git_service.write().await;
+
+    let result = guard.revert_continue().await?;
+    emit_operation_state_changed(&state, &guard).await;
 
     Ok(RevertResult {
         success: result.success,
@@ -764,7 +1062,6 @@ pub async fn resolve_conflict(
     resolution: ConflictResolution,
     custom_content: Option<String>,
 ) -> Result<()> {
-    let repo_path = state.ensure_repository_open()?;
     let git_service = state.get_git_service()?;
     let guard = git_service.write().await;
 
@@ -780,21 +1077,80 @@ pub async fn resolve_conflict(
                 .await?;
         }
         ConflictResolution::Merged => {
-            // Write the custom content
-            if let Some(content) = custom_content {
-                fs::write(repo_path.join(&path), content)?;
-                guard.mark_resolved(&path).await?;
-            } else {
+            let Some(content) = custom_content else {
                 return Err(AxisError::Other(
                     "Custom content required for merged resolution".to_string(),
                 ));
-            }
+            };
+            guard.resolve_conflict_with_content(&path, &content).await?;
         }
     }
 
     Ok(())
 }
 
+/// Write `content` as the resolved version of a conflicted file and stage it
+#[tauri::command]
+#[specta::specta]
+pub async fn resolve_conflict_with_content(
+    state: State<'_, AppState>,
+    path: String,
+    content: String,
+) -> Result<()> {
+    state
+        .get_git_service()?
+        .write()
+        .await
+        .resolve_conflict_with_content(&path, &content)
+        .await
+}
+
+/// Get the clean and conflicting regions of a conflicted file
+#[tauri::command]
+#[specta::specta]
+pub async fn get_conflict_hunks(
+    state: State<'_, AppState>,
+    path: String,
+) -> Result<Vec<ConflictHunk>> {
+    state
+        .get_git_service()?
+        .read()
+        .await
+        .get_conflict_hunks(&path)
+        .await
+}
+
+/// Resolve a conflicted file block by block and stage the result
+#[tauri::command]
+#[specta::specta]
+pub async fn resolve_conflict_hunks(
+    state: State<'_, AppState>,
+    path: String,
+    resolutions: Vec<ConflictHunkChoice>,
+) -> Result<()> {
+    state
+        .get_git_service()?
+        .write()
+        .await
+        .resolve_conflict_hunks(&path, &resolutions)
+        .await
+}
+
+/// Merge a conflicted notebook cell by cell, staging it when no cell conflicts
+#[tauri::command]
+#[specta::specta]
+pub async fn merge_notebook_conflict(
+    state: State<'_, AppState>,
+    path: String,
+) -> Result<NotebookMergeResult> {
+    state
+        .get_git_service()?
+        .write()
+        .await
+        .merge_notebook_conflict(&path)
+        .await
+}
+
 /// Mark a file as resolved
 #[tauri::command]
 #[specta::specta]
@@ -827,55 +1183,131 @@ pub async fn mark_conflict_unresolved(state: State<'_, AppState>, path: String)
 #[tauri::command]
 #[specta::specta]
 pub async fn get_operation_state(state: State<'_, AppState>) -> Result<OperationState> {
+    let path = state.ensure_repository_open()?;
     let git_service = state.get_git_service()?;
     let guard = git_service.read().await;
+    current_operation_state(&state, &guard, &path).await
+}
 
-    if guard.is_rebasing()? {
-        let progress = guard.get_rebase_progress()?;
-        match progress {
-            Some(p) => Ok(OperationState::Rebasing {
-                onto: p.onto,
-                current: Some(p.current_step),
-                total: Some(p.total_steps),
-                paused_action: p.paused_action,
-                head_name: p.head_name,
-            }),
-            None => Ok(OperationState::Rebasing {
-                onto: None,
-                current: None,
-                total: None,
-                paused_action: None,
-                head_name: None,
-            }),
+/// Operation state, with cherry-pick steps taken from the paused queue when there is one
+async fn current_operation_state(
+    state: &State<'_, AppState>,
+    ops: &RepoOperations,
+    path: &Path,
+) -> Result<OperationState> {
+    let mut operation = ops.get_operation_state().await?;
+    if let OperationState::CherryPicking { current, total, .. } = &mut operation {
+        if let Some((step, steps)) = state.cherry_pick_queue_steps(path) {
+            *current = Some(step);
+            *total = Some(steps);
         }
-    } else if guard.is_merging()? {
-        Ok(OperationState::Merging { branch: None })
-    } else if guard.is_cherry_picking()? {
-        Ok(OperationState::CherryPicking { commit: None })
-    } else if guard.is_reverting()? {
-        Ok(OperationState::Reverting { commit: None })
-    } else if guard.is_bisecting()? {
-        let bisect_state = guard.get_bisect_state().await?;
-        Ok(OperationState::Bisecting {
-            current_commit: bisect_state.current_commit,
-            steps_remaining: bisect_state.steps_remaining,
-        })
-    } else {
-        Ok(OperationState::None)
+    }
+    Ok(operation)
+}
+
+/// Emit `OperationStateChangedEvent` after a continue or skip, so progress updates
+/// without polling
+async fn emit_operation_state_changed(state: &State<'_, AppState>, ops: &RepoOperations) {
+    let path = match state.ensure_repository_open() {
+        Ok(path) => path,
+        Err(e) => {
+            log::error!("Failed to emit OperationStateChangedEvent: {e}");
+            return;
+        }
+    };
+    let operation = match current_operation_state(state, ops, &path).await {
+        Ok(operation) => operation,
+        Err(e) => {
+            log::error!("Failed to read operation state: {e}");
+            return;
+        }
+    };
+    let event = OperationStateChangedEvent {
+        path: path.to_string_lossy().to_string(),
+        state: operation,
+    };
+    match state.get_app_handle() {
+        Ok(app_handle) => {
+            if let Err(e) = event.emit(&app_handle) {
+                log::error!("Failed to emit OperationStateChangedEvent: {e}");
+            }
+        }
+        Err(e) => log::error!("Failed to emit OperationStateChangedEvent: {e}"),
     }
 }
 
 // ==================== Reset Commands ====================
 
-/// Reset the repository to a specific commit
+/// Reset the current branch to a specific commit.
+/// Hard resets require `confirm_discard`; with `preview` nothing is changed and the
+/// files whose uncommitted changes would be discarded are returned.
+#[tauri::command]
+#[specta::specta]
+pub async fn reset_to_commit(
+    state: State<'_, AppState>,
+    options: ResetOptions,
+) -> Result<ResetResult> {
+    let git_service = state.get_git_service()?;
+    let guard = git_service.write().await;
+
+    let discarded_files = if options.mode == ResetMode::Hard {
+        guard.get_hard_reset_discards(&options.target).await?
+    } else {
+        Vec::new()
+    };
+
+    if options.preview {
+        return Ok(ResetResult {
+            applied: false,
+            discarded_files,
+        });
+    }
+
+    if options.mode == ResetMode::Hard && !options.confirm_discard {
+        return Err(AxisError::ResetRequiresConfirmation(discarded_files));
+    }
+
+    state
+        .record_undo_snapshot(&guard, UndoOperation::Reset)
+        .await;
+
+    guard.reset(&options.target, options.mode).await?;
+    Ok(ResetResult {
+        applied: true,
+        discarded_files,
+    })
+}
+
+/// Reset the given paths in the index to their state in a commit, keeping HEAD and the working tree
 #[tauri::command]
 #[specta::specta]
-pub async fn reset_to_commit(state: State<'_, AppState>, options: ResetOptions) -> Result<()> {
+pub async fn reset_paths(
+    state: State<'_, AppState>,
+    commit: String,
+    paths: Vec<String>,
+) -> Result<()> {
     state
         .get_git_service()?
         .write()
         .await
-        .reset(&options.target, options.mode)
-        .await?;
-    Ok(())
+        .reset_paths(&commit, &paths)
+        .await
+}
+
+/// Restore a single file from a commit into the index and working tree
+#[tauri::command]
+#[specta::specta]
+pub async fn reset_file_to_commit(
+    state: State<'_, AppState>,
+    path: String,
+    commit_oid: String,
+) -> Result<()> {
+    let git_service = state.get_git_service()?;
+    let guard = git_service.write().await;
+
+    state
+        .record_undo_snapshot(&guard, UndoOperation::Reset)
+        .await;
+
+    guard.reset_file_to_commit(&path, &commit_oid).await
 }
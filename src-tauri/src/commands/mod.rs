@@ -1,7 +1,9 @@
 mod ai;
 mod avatar;
 mod bisect;
+mod bookmarks;
 mod branches;
+mod credentials;
 mod custom_actions;
 mod diff;
 mod gitflow;
@@ -10,12 +12,15 @@ mod graph;
 mod hooks;
 mod integrations;
 mod lfs;
+mod maintenance;
 mod merge;
+mod operation_log;
 mod patches;
 mod reflog;
 mod remotes;
 mod repo_settings;
 mod repository;
+mod rewrite;
 mod search;
 mod settings;
 mod signing;
@@ -24,13 +29,16 @@ mod staging;
 mod stash;
 mod submodules;
 mod tags;
+mod undo;
 mod update;
 mod worktrees;
 
 pub use ai::*;
 pub use avatar::*;
 pub use bisect::*;
+pub use bookmarks::*;
 pub use branches::*;
+pub use credentials::*;
 pub use custom_actions::*;
 pub use diff::*;
 pub use gitflow::*;
@@ -39,12 +47,15 @@ pub use graph::*;
 pub use hooks::*;
 pub use integrations::*;
 pub use lfs::*;
+pub use maintenance::*;
 pub use merge::*;
+pub use operation_log::*;
 pub use patches::*;
 pub use reflog::*;
 pub use remotes::*;
 pub use repo_settings::*;
 pub use repository::*;
+pub use rewrite::*;
 pub use search::*;
 pub use settings::*;
 pub use signing::*;
@@ -53,5 +64,6 @@ pub use staging::*;
 pub use stash::*;
 pub use submodules::*;
 pub use tags::*;
+pub use undo::*;
 pub use update::*;
 pub use worktrees::*;
@@ -0,0 +1,28 @@
+use crate::error::Result;
+use crate::models::{OperationLogFilter, OperationLogPage};
+use crate::state::AppState;
+use tauri::State;
+
+/// Query the operation log with filters, paging and session grouping
+#[tauri::command]
+#[specta::specta]
+pub async fn get_operation_log_filtered(
+    state: State<'_, AppState>,
+    filter: OperationLogFilter,
+) -> Result<OperationLogPage> {
+    state.database().get_operation_log_filtered(&filter)
+}
+
+/// Export every operation log entry matching the filter to a JSON file
+#[tauri::command]
+#[specta::specta]
+pub async fn export_operation_log(
+    state: State<'_, AppState>,
+    filter: OperationLogFilter,
+    path: String,
+) -> Result<usize> {
+    let entries = state.database().export_operation_log(&filter)?;
+    let json = serde_json::to_string_pretty(&entries)?;
+    std::fs::write(&path, json)?;
+    Ok(entries.len())
+}
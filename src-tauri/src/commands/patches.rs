@@ -3,6 +3,7 @@ use crate::models::{
     ApplyMailboxOptions, ApplyPatchOptions, ArchiveOptions, ArchiveResult, CreatePatchOptions,
     FormatPatchOptions, PatchResult,
 };
+use crate::services::ArtifactOwner;
 use crate::state::AppState;
 use std::path::PathBuf;
 use tauri::State;
@@ -16,7 +17,16 @@ pub async fn create_archive(
     options: ArchiveOptions,
 ) -> Result<ArchiveResult> {
     let output_path = PathBuf::from(&options.output_path);
-    state
+
+    // Track the output only if we create it, so a failed archive never deletes a
+    // file the user chose to overwrite
+    let tracked = match std::fs::File::create_new(&output_path) {
+        Ok(_) => state.track_artifact(&output_path, ArtifactOwner::Archive),
+        Err(e) if e.kind() == std::io::ErrorKind::AlreadyExists => None,
+        Err(e) => return Err(e.into()),
+    };
+
+    let result = state
         .get_git_service()?
         .read()
         .await
@@ -26,7 +36,12 @@ pub async fn create_archive(
             &output_path,
             options.prefix.as_deref(),
         )
-        .await
+        .await?;
+
+    if let Some(tracked) = tracked {
+        tracked.release();
+    }
+    Ok(result)
 }
 
 // ==================== Patch Commands ====================
@@ -47,6 +62,25 @@ pub async fn format_patch(
         .await
 }
 
+/// Save the commits after `from_oid` up to `to_oid` as a numbered patch series
+/// in `output_dir`, returning the created files in order
+#[tauri::command]
+#[specta::specta]
+pub async fn create_patch_series(
+    state: State<'_, AppState>,
+    from_oid: String,
+    to_oid: String,
+    output_dir: String,
+) -> Result<Vec<String>> {
+    let output_dir = PathBuf::from(output_dir);
+    state
+        .get_git_service()?
+        .read()
+        .await
+        .format_patch_series(&from_oid, &to_oid, &output_dir)
+        .await
+}
+
 /// Create a patch from a specific commit or staged changes
 #[tauri::command]
 #[specta::specta]
@@ -1,11 +1,18 @@
 use crate::error::{AxisError, Result};
-use crate::events::{GitOperationType, ProgressStage};
+use crate::events::{GitOperationType, ProgressStage, SubmodulesOutOfSyncEvent};
 use crate::models::{
-    FetchOptions, FetchResult, ListRemoteOptions, PullOptions, PushOptions, PushResult, Remote,
+    BackgroundFetchConfig, BackgroundFetchStatus, FetchOptions, FetchResult, ListRemoteOptions,
+    OperationCategory, ProtectedBranchAction, PullOptions, PullResult, PushOptions, PushResult,
+    Remote, RemoteBranchInfo, RemoteConnectionResult, RemoteTagInfo,
+};
+use crate::services::{
+    push_protection_targets, pushed_branches, HookProgressEmitter, ProgressContext,
 };
-use crate::services::{HookProgressEmitter, ProgressContext};
 use crate::state::AppState;
+use serde_json::json;
+use std::path::PathBuf;
 use tauri::State;
+use tauri_specta::Event;
 
 #[tauri::command]
 #[specta::specta]
@@ -95,6 +102,64 @@ pub async fn set_remote_push_url(
         .await
 }
 
+/// Check that a remote can be reached with the configured credentials before a push
+#[tauri::command]
+#[specta::specta]
+pub async fn test_remote_connection(
+    state: State<'_, AppState>,
+    remote_name: String,
+) -> Result<RemoteConnectionResult> {
+    let ssh_creds = state.resolve_ssh_credentials(&remote_name)?;
+    state
+        .get_git_service()?
+        .read()
+        .await
+        .test_remote_connection(&remote_name, ssh_creds)
+        .await
+}
+
+/// Branches a remote has, listed without fetching. Results are reused for 30 seconds.
+#[tauri::command]
+#[specta::specta]
+pub async fn get_remote_branches(
+    state: State<'_, AppState>,
+    remote_name: String,
+) -> Result<Vec<RemoteBranchInfo>> {
+    let path = state.ensure_repository_open()?;
+    let cache_key = format!("{}:{remote_name}", path.display());
+    if let Some(cached) = state.remote_branches_cache().get(&cache_key) {
+        return Ok(cached);
+    }
+
+    let ssh_creds = state.resolve_ssh_credentials(&remote_name)?;
+    let branches = state
+        .get_git_service()?
+        .read()
+        .await
+        .list_remote_branches(&remote_name, ssh_creds)
+        .await?;
+    state
+        .remote_branches_cache()
+        .set(cache_key, branches.clone());
+    Ok(branches)
+}
+
+/// Tags a remote has, listed without fetching, so a subset can be fetched explicitly
+#[tauri::command]
+#[specta::specta]
+pub async fn get_remote_tags(
+    state: State<'_, AppState>,
+    remote_name: String,
+) -> Result<Vec<RemoteTagInfo>> {
+    let ssh_creds = state.resolve_ssh_credentials(&remote_name)?;
+    state
+        .get_git_service()?
+        .read()
+        .await
+        .list_remote_tags(&remote_name, ssh_creds)
+        .await
+}
+
 #[tauri::command]
 #[specta::specta]
 pub async fn fetch_remote(
@@ -117,6 +182,12 @@ pub async fn fetch_remote(
         .await;
 
     ctx.handle_result(&result, GitOperationType::Fetch);
+    state.record_operation(
+        OperationCategory::Remote,
+        "fetch",
+        json!({ "remote": remote_name, "options": options }),
+        &result,
+    );
 
     result
 }
@@ -129,11 +200,20 @@ pub async fn push_remote(
     refspecs: Vec<String>,
     options: PushOptions,
     bypass_hooks: Option<bool>,
+    override_protection_check: Option<bool>,
 ) -> Result<PushResult> {
     let settings = state.get_settings()?;
     let git_service = state.get_git_service()?;
     let ssh_creds = state.resolve_ssh_credentials(&remote_name)?;
 
+    state
+        .check_branch_protection(
+            &remote_name,
+            &push_protection_targets(&refspecs, options.force),
+            override_protection_check.unwrap_or(false),
+        )
+        .await?;
+
     // Use explicit bypass_hooks param if provided, otherwise use settings
     let skip_hooks = bypass_hooks.unwrap_or(settings.bypass_hooks);
 
@@ -168,6 +248,10 @@ pub async fn push_remote(
             return Err(AxisError::Other("Hook cancelled by user".into()));
         }
 
+        if let Some(request) = hook_result.trust_request {
+            return Err(AxisError::HookTrustRequired(request));
+        }
+
         if !hook_result.skipped && !hook_result.success {
             let output = if hook_result.stderr.is_empty() {
                 &hook_result.stdout
@@ -192,6 +276,16 @@ pub async fn push_remote(
         .await;
 
     ctx.handle_result(&result, GitOperationType::Push);
+    state.record_operation(
+        OperationCategory::Remote,
+        "push",
+        json!({ "remote": remote_name, "refspecs": refspecs, "options": options }),
+        &result,
+    );
+
+    if result.is_ok() {
+        state.refresh_pull_requests_after_push(&remote_name, pushed_branches(&refspecs));
+    }
 
     result
 }
@@ -203,11 +297,24 @@ pub async fn push_current_branch(
     remote_name: String,
     options: PushOptions,
     bypass_hooks: Option<bool>,
+    override_protection_check: Option<bool>,
 ) -> Result<PushResult> {
     let settings = state.get_settings()?;
     let git_service = state.get_git_service()?;
     let ssh_creds = state.resolve_ssh_credentials(&remote_name)?;
 
+    if options.force {
+        if let Some(branch) = git_service.read().await.get_current_branch().await {
+            state
+                .check_branch_protection(
+                    &remote_name,
+                    &[(branch, ProtectedBranchAction::ForcePush)],
+                    override_protection_check.unwrap_or(false),
+                )
+                .await?;
+        }
+    }
+
     // Use explicit bypass_hooks param if provided, otherwise use settings
     let skip_hooks = bypass_hooks.unwrap_or(settings.bypass_hooks);
 
@@ -247,6 +354,10 @@ pub async fn push_current_branch(
                 return Err(AxisError::Other("Hook cancelled by user".into()));
             }
 
+            if let Some(request) = hook_result.trust_request {
+                return Err(AxisError::HookTrustRequired(request));
+            }
+
             if !hook_result.skipped && !hook_result.success {
                 let output = if hook_result.stderr.is_empty() {
                     &hook_result.stdout
@@ -264,6 +375,7 @@ pub async fn push_current_branch(
 
     ctx.emit(GitOperationType::Push, ProgressStage::Connecting, None);
 
+    let current_branch = git_service.read().await.get_current_branch().await;
     let cb = ctx.make_send_callback(GitOperationType::Push);
     let result = git_service
         .write()
@@ -272,6 +384,16 @@ pub async fn push_current_branch(
         .await;
 
     ctx.handle_result(&result, GitOperationType::Push);
+    state.record_operation(
+        OperationCategory::Remote,
+        "push",
+        json!({ "remote": remote_name, "options": options }),
+        &result,
+    );
+
+    if result.is_ok() {
+        state.refresh_pull_requests_after_push(&remote_name, current_branch.into_iter().collect());
+    }
 
     result
 }
@@ -283,10 +405,10 @@ pub async fn pull_remote(
     remote_name: String,
     branch_name: String,
     options: PullOptions,
-) -> Result<()> {
+) -> Result<PullResult> {
     let app_handle = state.get_app_handle()?;
     let ssh_creds = state.resolve_ssh_credentials(&remote_name)?;
-    let ctx = ProgressContext::new(app_handle, state.progress_registry());
+    let ctx = ProgressContext::new(app_handle.clone(), state.progress_registry());
 
     ctx.emit(GitOperationType::Pull, ProgressStage::Connecting, None);
 
@@ -299,6 +421,27 @@ pub async fn pull_remote(
         .await;
 
     ctx.handle_result(&result, GitOperationType::Pull);
+    state.record_operation(
+        OperationCategory::Remote,
+        "pull",
+        json!({ "remote": remote_name, "branch": branch_name, "options": options }),
+        &result,
+    );
+
+    if let Ok(pull_result) = &result {
+        if !pull_result.out_of_sync_submodules.is_empty() {
+            let event = SubmodulesOutOfSyncEvent {
+                path: state
+                    .ensure_repository_open()?
+                    .to_string_lossy()
+                    .to_string(),
+                submodules: pull_result.out_of_sync_submodules.clone(),
+            };
+            if let Err(e) = event.emit(&app_handle) {
+                log::error!("Failed to emit SubmodulesOutOfSyncEvent: {e}");
+            }
+        }
+    }
 
     result
 }
@@ -345,3 +488,39 @@ pub async fn fetch_all(state: State<'_, AppState>) -> Result<Vec<FetchResult>> {
 
     Ok(results)
 }
+
+#[tauri::command]
+#[specta::specta]
+pub async fn configure_background_fetch(
+    state: State<'_, AppState>,
+    repo_path: String,
+    enabled: bool,
+    interval_minutes: u32,
+    prune: bool,
+    fetch_all_remotes: bool,
+) -> Result<BackgroundFetchStatus> {
+    if enabled && interval_minutes == 0 {
+        return Err(AxisError::Other(
+            "Background fetch interval must be at least one minute".to_string(),
+        ));
+    }
+
+    let path = PathBuf::from(repo_path);
+    let config = BackgroundFetchConfig {
+        enabled,
+        interval_minutes,
+        prune,
+        fetch_all_remotes,
+    };
+    state.configure_background_fetch(&path, &config)?;
+    state.background_fetch_status(&path)
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn get_background_fetch_status(
+    state: State<'_, AppState>,
+    repo_path: String,
+) -> Result<BackgroundFetchStatus> {
+    state.background_fetch_status(&PathBuf::from(repo_path))
+}
@@ -1,6 +1,10 @@
 use crate::error::Result;
-use crate::models::{ListRemoteOptions, RepositorySettings, SigningFormat};
+use crate::models::{
+    ConfigEntry, ConfigLevel, ListRemoteOptions, PerformanceConfig, PerformanceReadiness,
+    RepositorySettings, SigningFormat,
+};
 use crate::state::AppState;
+use std::path::PathBuf;
 use tauri::State;
 
 #[tauri::command]
@@ -47,10 +51,84 @@ pub async fn save_repository_signing_config(
     signing_format: Option<SigningFormat>,
     signing_key: Option<String>,
 ) -> Result<()> {
+    let path = state.ensure_repository_open()?;
     state
         .get_git_service()?
         .write()
         .await
         .set_repo_signing_config(signing_format.as_ref(), signing_key.as_deref())
+        .await?;
+    state
+        .database()
+        .save_signing_config(&path, signing_format.as_ref(), signing_key.as_deref())
+}
+
+/// Effective value of any git config key, or `None` when it is not set
+#[tauri::command]
+#[specta::specta]
+pub async fn get_repository_config(
+    state: State<'_, AppState>,
+    key: String,
+) -> Result<Option<String>> {
+    state
+        .get_git_service()?
+        .read()
+        .await
+        .get_config_value(&key)
+        .await
+}
+
+/// Every local, global and system config entry with the file it comes from,
+/// sorted by key, like `git config --list --show-origin`
+#[tauri::command]
+#[specta::specta]
+pub async fn list_repository_config(state: State<'_, AppState>) -> Result<Vec<ConfigEntry>> {
+    state
+        .get_git_service()?
+        .read()
+        .await
+        .list_config_entries()
+        .await
+}
+
+/// Set any git config key in the local, global or system config file
+#[tauri::command]
+#[specta::specta]
+pub async fn set_repository_config(
+    state: State<'_, AppState>,
+    key: String,
+    value: String,
+    level: ConfigLevel,
+) -> Result<()> {
+    state
+        .get_git_service()?
+        .write()
         .await
+        .set_config_value(&key, &value, level)
+        .await
+}
+
+/// The performance profile a repository runs with, the size measurements behind an
+/// `Auto` decision, and the effective value of each knob
+#[tauri::command]
+#[specta::specta]
+pub async fn get_performance_profile(
+    state: State<'_, AppState>,
+    repo_path: String,
+) -> Result<PerformanceReadiness> {
+    state.performance_readiness(&PathBuf::from(repo_path))
+}
+
+/// Choose a repository's performance profile and overrides. The file watcher picks
+/// up a new debounce when the repository is next opened.
+#[tauri::command]
+#[specta::specta]
+pub async fn set_performance_profile(
+    state: State<'_, AppState>,
+    repo_path: String,
+    config: PerformanceConfig,
+) -> Result<PerformanceReadiness> {
+    let path = PathBuf::from(repo_path);
+    state.configure_performance(&path, &config)?;
+    state.performance_readiness(&path)
 }
@@ -1,11 +1,17 @@
 use crate::error::{AxisError, Result};
 use crate::events::{GitOperationType, ProgressStage};
 use crate::models::{
-    Branch, BranchFilter, Commit, LogOptions, RecentRepository, Repository, RepositoryStatus,
-    SshCredentials,
+    AvailableTerminal, Branch, BranchFilter, BrokenHeadAction, BrokenHeadRecovery, Commit,
+    CommitHistoryPage, LogOptions, PathCopyForms, QuickOpenResults, RecentRepository,
+    RecentRepositoryWithStatus, Repository, RepositoryHealth, RepositoryStatus,
+    RepositoryStatusOptions, ShowInFolderResult, SshCredentials,
+};
+use crate::services::{
+    available_terminals, probe_recent_repositories, terminal_directory, ArtifactOwner, Git2Service,
+    ProgressContext, RevealTarget, TerminalLaunch, TerminalPlatform, RECENT_STATUS_TIMEOUT,
 };
-use crate::services::{Git2Service, ProgressContext};
 use crate::state::AppState;
+use crate::storage::RecentRepositoryRow;
 use std::path::PathBuf;
 use tauri::{AppHandle, State};
 use tauri_plugin_opener::OpenerExt;
@@ -23,15 +29,26 @@ pub async fn open_repository(state: State<'_, AppState>, path: String) -> Result
     state.switch_active_repository(&path).await?;
 
     // Get repo info from the cached service
-    let repo_info = state
-        .get_git_service()?
-        .read()
-        .await
-        .get_repository_info()
-        .await?;
+    let git_service = state.get_git_service()?;
+    let guard = git_service.read().await;
+    let repo_info = guard.get_repository_info().await?;
+
+    if !state.is_sync_folder_acknowledged(&path)? {
+        match guard.get_sync_folder_warning(false).await {
+            Ok(Some(warning)) => log::warn!(
+                "Repository {} is inside {} folder {}",
+                path.display(),
+                warning.provider,
+                warning.sync_root.display()
+            ),
+            Ok(None) => {}
+            Err(e) => log::warn!("Failed to check for cloud-synced folder: {e}"),
+        }
+    }
 
     // Add to recent repositories
     state.add_recent_repository(&path, &repo_info.name)?;
+    state.set_recent_repository_branch(&path, repo_info.current_branch.as_deref())?;
 
     Ok(repo_info)
 }
@@ -54,6 +71,7 @@ pub async fn init_repository(
 
     // Add to recent repositories
     state.add_recent_repository(&path, &repo_info.name)?;
+    state.set_recent_repository_branch(&path, repo_info.current_branch.as_deref())?;
 
     Ok(repo_info)
 }
@@ -79,6 +97,14 @@ pub async fn clone_repository(
         ));
     }
 
+    // A target created here is removed again if the clone fails or the app dies
+    let tracked = if path.exists() {
+        None
+    } else {
+        std::fs::create_dir_all(&path)?;
+        state.track_artifact(&path, ArtifactOwner::Clone)
+    };
+
     let app_handle = state.get_app_handle()?;
     let ctx = ProgressContext::new(app_handle, state.progress_registry());
 
@@ -100,18 +126,23 @@ pub async fn clone_repository(
         &path,
         Some(ctx.make_receive_callback(GitOperationType::Clone)),
         ssh_creds,
+        Some(state.host_credential_store()),
     );
 
     ctx.handle_result(&result, GitOperationType::Clone);
 
     let service = result?;
     let repo_info = service.get_repository_info()?;
+    if let Some(tracked) = tracked {
+        tracked.release();
+    }
 
     // Now add to cache via switch_active_repository
     state.switch_active_repository(&path).await?;
 
     // Add to recent repositories
     state.add_recent_repository(&path, &repo_info.name)?;
+    state.set_recent_repository_branch(&path, repo_info.current_branch.as_deref())?;
 
     Ok(repo_info)
 }
@@ -119,6 +150,9 @@ pub async fn clone_repository(
 #[tauri::command]
 #[specta::specta]
 pub async fn close_repository(state: State<'_, AppState>) -> Result<()> {
+    if let Some(path) = state.get_current_repository_path() {
+        state.remember_last_branch(&path).await;
+    }
     state.close_current_repository();
     Ok(())
 }
@@ -142,6 +176,7 @@ pub async fn switch_active_repository(
 #[specta::specta]
 pub async fn close_repository_path(state: State<'_, AppState>, path: String) -> Result<()> {
     let path = PathBuf::from(&path);
+    state.remember_last_branch(&path).await;
     state.close_repository(&path);
     Ok(())
 }
@@ -157,10 +192,47 @@ pub async fn get_repository_info(state: State<'_, AppState>) -> Result<Repositor
         .await
 }
 
+/// Recoveries offered when HEAD points to a branch that no longer exists
+#[tauri::command]
+#[specta::specta]
+pub async fn get_broken_head_recoveries(
+    state: State<'_, AppState>,
+) -> Result<Vec<BrokenHeadRecovery>> {
+    state
+        .get_git_service()?
+        .read()
+        .await
+        .get_broken_head_recoveries()
+        .await
+}
+
+/// Apply one of the recoveries from `get_broken_head_recoveries`
+#[tauri::command]
+#[specta::specta]
+pub async fn recover_broken_head(
+    state: State<'_, AppState>,
+    action: BrokenHeadAction,
+) -> Result<BrokenHeadRecovery> {
+    state
+        .get_git_service()?
+        .write()
+        .await
+        .recover_broken_head(action)
+        .await
+}
+
 #[tauri::command]
 #[specta::specta]
-pub async fn get_repository_status(state: State<'_, AppState>) -> Result<RepositoryStatus> {
-    state.get_git_service()?.read().await.status().await
+pub async fn get_repository_status(
+    state: State<'_, AppState>,
+    options: Option<RepositoryStatusOptions>,
+) -> Result<RepositoryStatus> {
+    state
+        .get_git_service()?
+        .read()
+        .await
+        .status(options.unwrap_or_default())
+        .await
 }
 
 #[tauri::command]
@@ -172,9 +244,32 @@ pub async fn get_commit_history(
     state.get_git_service()?.read().await.log(options).await
 }
 
+/// Get one page of commit history; pass the returned `next_cursor` back in
+/// `options.cursor` to continue from where the page ended
 #[tauri::command]
 #[specta::specta]
-pub async fn get_branches(state: State<'_, AppState>, filter: BranchFilter) -> Result<Vec<Branch>> {
+pub async fn get_commit_history_page(
+    state: State<'_, AppState>,
+    options: LogOptions,
+) -> Result<CommitHistoryPage> {
+    state
+        .get_git_service()?
+        .read()
+        .await
+        .log_page(options, state.commit_cache())
+        .await
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn get_branches(
+    state: State<'_, AppState>,
+    mut filter: BranchFilter,
+) -> Result<Vec<Branch>> {
+    if filter.skip_ahead_behind.is_none() {
+        let repo_path = state.ensure_repository_open()?;
+        filter.skip_ahead_behind = Some(state.performance_settings(&repo_path)?.skip_ahead_behind);
+    }
     state
         .get_git_service()?
         .read()
@@ -189,11 +284,86 @@ pub async fn get_commit(state: State<'_, AppState>, oid: String) -> Result<Commi
     state.get_git_service()?.read().await.get_commit(&oid).await
 }
 
+#[tauri::command]
+#[specta::specta]
+pub async fn get_repository_health(state: State<'_, AppState>) -> Result<RepositoryHealth> {
+    let path = state
+        .get_current_repository_path()
+        .ok_or(AxisError::NoRepositoryOpen)?;
+    let acknowledged = state.is_sync_folder_acknowledged(&path)?;
+    let shared_roots = state
+        .get_settings()?
+        .shared_object_store_roots
+        .into_iter()
+        .map(PathBuf::from)
+        .collect();
+    let git_service = state.get_git_service()?;
+    let guard = git_service.read().await;
+    let sync_folder = guard.get_sync_folder_warning(acknowledged).await?;
+    let object_sharing = guard.get_object_sharing(shared_roots).await?;
+
+    Ok(RepositoryHealth {
+        path,
+        sync_folder,
+        object_sharing,
+    })
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn acknowledge_sync_folder_warning(
+    state: State<'_, AppState>,
+    acknowledged: bool,
+) -> Result<()> {
+    let path = state
+        .get_current_repository_path()
+        .ok_or(AxisError::NoRepositoryOpen)?;
+    state.set_sync_folder_acknowledged(&path, acknowledged)
+}
+
+/// Maximum number of matches returned by `search_recent_repositories`
+const RECENT_SEARCH_LIMIT: usize = 20;
+
 #[tauri::command]
 #[specta::specta]
 pub async fn get_recent_repositories(state: State<'_, AppState>) -> Result<Vec<RecentRepository>> {
-    let rows = state.get_recent_repositories()?;
+    enrich_recent_repositories(state.get_recent_repositories()?).await
+}
 
+/// Recent repositories whose path or name contains `query`, most recent first
+#[tauri::command]
+#[specta::specta]
+pub async fn search_recent_repositories(
+    state: State<'_, AppState>,
+    query: String,
+) -> Result<Vec<RecentRepository>> {
+    let rows = state.search_recent_repositories(query.trim(), RECENT_SEARCH_LIMIT)?;
+    enrich_recent_repositories(rows).await
+}
+
+/// Quick-open matches across all known repositories: recent repositories whose
+/// path or name contains `query`, and bookmarks whose label or notes do
+#[tauri::command]
+#[specta::specta]
+pub async fn quick_open_search(
+    state: State<'_, AppState>,
+    query: String,
+) -> Result<QuickOpenResults> {
+    let query = query.trim();
+    let rows = state.search_recent_repositories(query, RECENT_SEARCH_LIMIT)?;
+    let bookmarks = state
+        .database()
+        .search_bookmarks(query, RECENT_SEARCH_LIMIT)?;
+    Ok(QuickOpenResults {
+        repositories: enrich_recent_repositories(rows).await?,
+        bookmarks,
+    })
+}
+
+/// Enrich rows with live repository data, one blocking task per repository
+async fn enrich_recent_repositories(
+    rows: Vec<RecentRepositoryRow>,
+) -> Result<Vec<RecentRepository>> {
     let handles: Vec<_> = rows
         .into_iter()
         .map(|row| tauri::async_runtime::spawn_blocking(move || RecentRepository::from_row(row)))
@@ -210,6 +380,22 @@ pub async fn get_recent_repositories(state: State<'_, AppState>) -> Result<Vec<R
     Ok(repos)
 }
 
+/// Recent repositories with their current branch, dirty-file count, and ahead/behind
+/// counts, pinned first. Missing paths are flagged rather than failing the list.
+#[tauri::command]
+#[specta::specta]
+pub async fn get_recent_repositories_with_status(
+    state: State<'_, AppState>,
+) -> Result<Vec<RecentRepositoryWithStatus>> {
+    let rows = state.get_recent_repositories()?;
+    Ok(probe_recent_repositories(
+        rows,
+        RECENT_STATUS_TIMEOUT,
+        RecentRepositoryWithStatus::probe,
+    )
+    .await)
+}
+
 #[tauri::command]
 #[specta::specta]
 pub async fn remove_recent_repository(state: State<'_, AppState>, path: String) -> Result<()> {
@@ -233,17 +419,47 @@ pub async fn unpin_repository(state: State<'_, AppState>, path: String) -> Resul
 
 #[tauri::command]
 #[specta::specta]
-pub async fn show_in_folder(app_handle: AppHandle, path: String) -> Result<()> {
+pub async fn pin_recent_repository(
+    state: State<'_, AppState>,
+    path: String,
+    pinned: bool,
+) -> Result<()> {
     let path = PathBuf::from(&path);
-
-    if !path.exists() {
-        return Err(AxisError::FileNotFound(path.display().to_string()));
+    if pinned {
+        state.pin_repository(&path)
+    } else {
+        state.unpin_repository(&path)
     }
+}
 
-    app_handle
-        .opener()
-        .reveal_item_in_dir(path)
-        .map_err(|e| AxisError::Other(e.to_string()))
+/// Show `path` (a repository or worktree root), or `subpath` inside it, selected in the
+/// file manager. A path that no longer exists opens its nearest existing folder instead.
+#[tauri::command]
+#[specta::specta]
+pub async fn show_in_folder(path: String, subpath: Option<String>) -> Result<ShowInFolderResult> {
+    let target = RevealTarget::resolve(&PathBuf::from(&path), subpath.as_deref())?;
+    let shown = target.path.display().to_string();
+    let fallback = target.fallback;
+
+    tauri::async_runtime::spawn_blocking(move || target.reveal())
+        .await
+        .map_err(|e| AxisError::Other(format!("show in folder task failed: {e}")))??;
+
+    Ok(ShowInFolderResult {
+        path: shown,
+        fallback,
+    })
+}
+
+/// Absolute, repository-relative and file URL forms of a path in the open repository
+#[tauri::command]
+#[specta::specta]
+pub async fn get_path_copy_forms(
+    state: State<'_, AppState>,
+    path: String,
+) -> Result<PathCopyForms> {
+    let repo_path = state.ensure_repository_open()?;
+    PathCopyForms::new(&repo_path, &path)
 }
 
 #[tauri::command]
@@ -255,59 +471,46 @@ pub async fn open_url(app_handle: AppHandle, url: String) -> Result<()> {
         .map_err(|e| AxisError::Other(e.to_string()))
 }
 
+/// Open the terminal chosen in the settings at `path`, or at `subpath` inside it.
+/// `command` (e.g. `git rebase --continue`) runs there first where the terminal allows.
 #[tauri::command]
 #[specta::specta]
-pub async fn open_terminal(path: String) -> Result<()> {
+pub async fn open_terminal(
+    state: State<'_, AppState>,
+    path: String,
+    subpath: Option<String>,
+    command: Option<String>,
+) -> Result<()> {
     let path = PathBuf::from(&path);
 
     if !path.exists() {
         return Err(AxisError::FileNotFound(path.display().to_string()));
     }
 
-    #[cfg(target_os = "macos")]
-    {
-        std::process::Command::new("open")
-            .args(["-a", "Terminal", path.to_str().unwrap_or(".")])
-            .spawn()
-            .map_err(|e| AxisError::Other(e.to_string()))?;
-    }
-
-    #[cfg(target_os = "windows")]
-    {
-        std::process::Command::new("cmd")
-            .args([
-                "/c",
-                "start",
-                "cmd",
-                "/k",
-                &format!("cd /d {}", path.display()),
-            ])
-            .spawn()
-            .map_err(|e| AxisError::Other(e.to_string()))?;
-    }
-
-    #[cfg(target_os = "linux")]
-    {
-        // Try common terminal emulators
-        let terminals = ["gnome-terminal", "konsole", "xterm", "x-terminal-emulator"];
-        let mut launched = false;
-        for term in terminals {
-            if std::process::Command::new(term)
-                .arg("--working-directory")
-                .arg(&path)
-                .spawn()
-                .is_ok()
-            {
-                launched = true;
-                break;
-            }
-        }
-        if !launched {
-            return Err(AxisError::Other("No terminal emulator found".to_string()));
-        }
-    }
+    let dir = terminal_directory(&path, subpath.as_deref())?;
+    let settings = state.get_settings()?;
+    let platform = TerminalPlatform::current();
+    let app = settings
+        .terminal_app
+        .or_else(|| platform.default_terminal())
+        .ok_or_else(|| AxisError::Other("No terminal emulator found".to_string()))?;
+
+    TerminalLaunch::build(
+        app,
+        platform,
+        &dir,
+        command.as_deref(),
+        settings.terminal_profile.as_deref(),
+        settings.terminal_command_template.as_deref(),
+    )?
+    .spawn()
+}
 
-    Ok(())
+/// Terminals installed on this machine, for the terminal setting
+#[tauri::command]
+#[specta::specta]
+pub async fn list_available_terminals() -> Result<Vec<AvailableTerminal>> {
+    Ok(available_terminals())
 }
 
 #[tauri::command]
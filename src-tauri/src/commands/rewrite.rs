@@ -0,0 +1,53 @@
+use tauri::State;
+
+use crate::error::Result;
+use crate::models::{RemovePathOptions, RemovePathPreview, RemovePathResult, UndoOperation};
+use crate::state::AppState;
+
+/// Preview removing paths from the unpushed commits of the current branch
+#[tauri::command]
+#[specta::specta]
+pub async fn preview_remove_path_from_history(
+    state: State<'_, AppState>,
+    paths: Vec<String>,
+) -> Result<RemovePathPreview> {
+    state
+        .get_git_service()?
+        .read()
+        .await
+        .preview_remove_path_from_history(&paths)
+        .await
+}
+
+/// Remove paths from the unpushed commits of the current branch
+#[tauri::command]
+#[specta::specta]
+pub async fn remove_path_from_unpushed_history(
+    state: State<'_, AppState>,
+    paths: Vec<String>,
+    options: RemovePathOptions,
+) -> Result<RemovePathResult> {
+    let settings = state.get_settings()?;
+    let git_service = state.get_git_service()?;
+    let guard = git_service.write().await;
+
+    // Use explicit sign option if provided, otherwise use settings
+    let signing_config = if options.sign.unwrap_or(settings.sign_commits) {
+        Some(guard.get_effective_signing_config().await?)
+    } else {
+        None
+    };
+
+    state
+        .record_undo_snapshot(&guard, UndoOperation::Rebase)
+        .await;
+
+    guard
+        .remove_path_from_unpushed_history(
+            &paths,
+            options.acknowledge_other_branches,
+            signing_config.as_ref(),
+            options.preconditions.as_ref(),
+        )
+        .await
+}
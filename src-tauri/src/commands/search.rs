@@ -1,15 +1,40 @@
 use crate::error::Result;
-use crate::models::{GrepOptions, GrepResult};
+use crate::events::GrepResultsPageEvent;
+use crate::models::{GrepDiffMatch, GrepMatch, GrepOptions, GrepResult};
 use crate::state::AppState;
 use tauri::State;
+use tauri_specta::Event;
 
 // ==================== Search Commands ====================
 
-/// Search for content in the repository working tree
+/// Search for content in the repository working tree, or in the tree of `options.ref`.
+/// With a `search_id`, matches stream as `GrepResultsPageEvent`s and the returned
+/// result only carries the totals.
 #[tauri::command]
 #[specta::specta]
-pub async fn grep_content(state: State<'_, AppState>, options: GrepOptions) -> Result<GrepResult> {
-    state.get_git_service()?.read().await.grep(&options).await
+pub async fn grep_content(
+    app_handle: tauri::AppHandle,
+    state: State<'_, AppState>,
+    options: GrepOptions,
+    search_id: Option<String>,
+) -> Result<GrepResult> {
+    let on_page = search_id.map(|search_id| {
+        Box::new(move |matches: Vec<GrepMatch>| {
+            let event = GrepResultsPageEvent {
+                search_id: search_id.clone(),
+                matches,
+            };
+            if let Err(e) = event.emit(&app_handle) {
+                log::error!("Failed to emit search results page: {e}");
+            }
+        }) as Box<dyn FnMut(Vec<GrepMatch>) + Send>
+    });
+    let handle = state.get_git_service()?;
+    let guard = handle.read().await;
+    match on_page {
+        Some(on_page) => guard.search_content(options, Some(on_page)).await,
+        None => guard.grep(&options).await,
+    }
 }
 
 /// Search for content in a specific commit
@@ -27,3 +52,38 @@ pub async fn grep_commit(
         .grep_commit(&commit_oid, &options)
         .await
 }
+
+/// Search the committed content of a branch without checking it out
+#[tauri::command]
+#[specta::specta]
+pub async fn grep_branch(
+    state: State<'_, AppState>,
+    pattern: String,
+    branch: String,
+    path_filter: Option<String>,
+) -> Result<GrepResult> {
+    state
+        .get_git_service()?
+        .read()
+        .await
+        .grep_branch(&pattern, &branch, path_filter.as_deref())
+        .await
+}
+
+/// Search the added and context lines of the staged diff, e.g. to find where a
+/// symbol is being introduced
+#[tauri::command]
+#[specta::specta]
+pub async fn grep_diff(
+    state: State<'_, AppState>,
+    pattern: String,
+    case_sensitive: bool,
+    context_lines: u32,
+) -> Result<Vec<GrepDiffMatch>> {
+    state
+        .get_git_service()?
+        .read()
+        .await
+        .grep_diff(&pattern, case_sensitive, context_lines)
+        .await
+}
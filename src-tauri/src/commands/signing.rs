@@ -1,17 +1,21 @@
-use crate::error::Result;
+use crate::error::{AxisError, Result};
+use crate::events::GpgKeyGenerationProgressEvent;
 use crate::models::{
-    GpgKey, SignatureVerification, SigningConfig, SigningFormat, SigningTestResult, SshKey,
+    CommitSignatureResult, ConfigLevel, GeneratedGpgKey, GpgKey, SignatureVerification,
+    SigningConfig, SigningFormat, SigningTestResult, SshKey,
 };
-use crate::services::{SignatureVerificationCache, SigningService};
+use crate::services::{Git2Service, SignatureVerificationCache, SigningService};
 use crate::state::AppState;
-use tauri::State;
+use tauri::{AppHandle, State};
+use tauri_specta::Event;
 
 #[tauri::command]
 #[specta::specta]
 pub async fn get_signing_config(state: State<'_, AppState>) -> Result<SigningConfig> {
     let path = state.ensure_repository_open()?;
-    let service = SigningService::new(&path);
-    service.get_config_from_git()
+    // .git/config is authoritative; the stored copy only fills in what git config leaves unset
+    let stored = state.database().get_signing_config(&path)?;
+    SigningService::new(&path).get_config_with_fallback(stored)
 }
 
 #[tauri::command]
@@ -22,6 +26,77 @@ pub async fn list_gpg_keys(state: State<'_, AppState>) -> Result<Vec<GpgKey>> {
     service.list_gpg_keys().await
 }
 
+/// Generate a new GPG signing key, emitting `GpgKeyGenerationProgressEvent` while gpg works
+#[tauri::command]
+#[specta::specta]
+pub async fn generate_gpg_key(
+    app_handle: AppHandle,
+    name: String,
+    email: String,
+    passphrase: Option<String>,
+    expiry: Option<String>,
+) -> Result<GeneratedGpgKey> {
+    SigningService::generate_gpg_key(
+        &name,
+        &email,
+        passphrase.as_deref(),
+        expiry.as_deref(),
+        |progress| {
+            let Some(event) = GpgKeyGenerationProgressEvent::from_status(progress) else {
+                return;
+            };
+            if let Err(e) = event.emit(&app_handle) {
+                log::error!("Failed to emit GPG key generation progress: {e}");
+            }
+        },
+    )
+    .await
+}
+
+/// Public key of a GPG key, ASCII-armored or base64 of the binary export
+#[tauri::command]
+#[specta::specta]
+pub async fn export_gpg_public_key(key_id: String, armored: bool) -> Result<String> {
+    SigningService::export_gpg_public_key(&key_id, armored).await
+}
+
+/// Write `user.signingkey`, `gpg.format` and `commit.gpgsign` to the open repository's
+/// .git/config (`Local`) or to the user's global config (`Global`)
+#[tauri::command]
+#[specta::specta]
+pub async fn configure_signing(
+    state: State<'_, AppState>,
+    scope: ConfigLevel,
+    format: SigningFormat,
+    key_id: String,
+    sign_commits_by_default: bool,
+) -> Result<()> {
+    let sign_commits = sign_commits_by_default.to_string();
+    match scope {
+        ConfigLevel::Local => {
+            let path = state.ensure_repository_open()?;
+            let git_service = state.get_git_service()?;
+            let guard = git_service.write().await;
+            guard
+                .set_repo_signing_config(Some(&format), Some(&key_id))
+                .await?;
+            guard
+                .set_config_value("commit.gpgsign", &sign_commits, ConfigLevel::Local)
+                .await?;
+            state
+                .database()
+                .save_signing_config(&path, Some(&format), Some(&key_id))
+        }
+        ConfigLevel::Global => {
+            Git2Service::set_global_signing_config(Some(&format), Some(&key_id))?;
+            Git2Service::set_global_config_value("commit.gpgsign", &sign_commits)
+        }
+        ConfigLevel::System | ConfigLevel::Worktree | ConfigLevel::App => Err(AxisError::Other(
+            format!("Cannot write {scope} signing config from Axis"),
+        )),
+    }
+}
+
 #[tauri::command]
 #[specta::specta]
 pub async fn list_ssh_keys(_state: State<'_, AppState>) -> Result<Vec<SshKey>> {
@@ -80,3 +155,55 @@ pub async fn verify_commit_signature(
 
     Ok(result)
 }
+
+/// Verify the signatures of many commits (e.g. the visible graph nodes) in one call.
+/// Cached results are reused; the rest are verified together on a single blocking task.
+#[tauri::command]
+#[specta::specta]
+pub async fn get_commit_signatures(
+    state: State<'_, AppState>,
+    oids: Vec<String>,
+) -> Result<Vec<CommitSignatureResult>> {
+    let repo_path = state.ensure_repository_open()?;
+    let cache = state.signature_verification_cache();
+
+    let mut results: Vec<CommitSignatureResult> = oids
+        .iter()
+        .map(|oid| CommitSignatureResult {
+            oid: oid.clone(),
+            verification: cache.get(&SignatureVerificationCache::build_key(&repo_path, oid)),
+        })
+        .collect();
+
+    let misses: Vec<String> = results
+        .iter()
+        .filter(|r| r.verification.is_none())
+        .map(|r| r.oid.clone())
+        .collect();
+    if misses.is_empty() {
+        return Ok(results);
+    }
+
+    let verified = state
+        .get_git_service()?
+        .read()
+        .await
+        .verify_commit_signatures(misses)
+        .await?;
+
+    let mut verified = verified.into_iter();
+    for result in results.iter_mut().filter(|r| r.verification.is_none()) {
+        let Some(fresh) = verified.next() else {
+            break;
+        };
+        if let Some(verification) = &fresh.verification {
+            cache.set(
+                SignatureVerificationCache::build_key(&repo_path, &fresh.oid),
+                verification.clone(),
+            );
+        }
+        *result = fresh;
+    }
+
+    Ok(results)
+}
@@ -1,7 +1,7 @@
 use crate::error::Result;
 use crate::models::{
     ExportSshKeyOptions, GenerateSshKeyOptions, ImportSshKeyOptions, RemoteSshKeyMapping,
-    SshKeyFormat, SshKeyInfo,
+    SshAgentIdentity, SshKeyFormat, SshKeyInfo,
 };
 use crate::services::SshKeyService;
 use crate::state::AppState;
@@ -51,6 +51,12 @@ pub async fn export_ssh_key(options: ExportSshKeyOptions) -> Result<()> {
     SshKeyService::export_key(&options)
 }
 
+#[tauri::command]
+#[specta::specta]
+pub async fn list_ssh_agent_identities() -> Result<Vec<SshAgentIdentity>> {
+    SshKeyService::list_agent_identities().await
+}
+
 // ==================== Per-Remote SSH Key Mapping ====================
 
 #[tauri::command]
@@ -1,11 +1,24 @@
 use std::fs;
+use std::path::Path;
+use std::sync::atomic::Ordering;
 
-use tauri::State;
+use tauri::{Manager, State};
+use tauri_specta::Event;
+
+use crate::events::CommitMessageAnalysisProgressEvent;
 
 use crate::error::{AxisError, Result};
-use crate::models::LfsCheckResult;
-use crate::services::{HookProgressEmitter, SigningService};
+use crate::models::{
+    CoAuthor, CommitLintConfig, CommitLintViolation, CommitMessageStats, CommitMessageStatsOptions,
+    CommitTrailerOptions, FileModeAudit, FileModeAuditOptions, FileModeFix, FileModeFixResult,
+    LfsCheckResult, LintSeverity, OperationCategory, SpellCheckResult, UndoOperation,
+};
+use crate::services::{
+    check_spelling, lint_commit_message, load_commit_lint_rules, HookProgressEmitter,
+    CO_AUTHOR_SCAN_DEPTH, DICTIONARIES_DIR,
+};
 use crate::state::AppState;
+use serde_json::json;
 
 #[tauri::command]
 #[specta::specta]
@@ -35,6 +48,51 @@ pub async fn stage_all(state: State<'_, AppState>) -> Result<()> {
     state.get_git_service()?.write().await.stage_all().await
 }
 
+#[tauri::command]
+#[specta::specta]
+pub async fn set_file_executable(
+    state: State<'_, AppState>,
+    path: String,
+    executable: bool,
+) -> Result<()> {
+    state
+        .get_git_service()?
+        .write()
+        .await
+        .set_file_executable(&path, executable)
+        .await
+}
+
+/// Find files whose executable bit looks wrong or keeps flipping in recent history
+#[tauri::command]
+#[specta::specta]
+pub async fn audit_file_modes(
+    state: State<'_, AppState>,
+    options: FileModeAuditOptions,
+) -> Result<FileModeAudit> {
+    state
+        .get_git_service()?
+        .read()
+        .await
+        .audit_file_modes(options)
+        .await
+}
+
+/// Apply selected audit fixes, staging them with a prepared commit message
+#[tauri::command]
+#[specta::specta]
+pub async fn fix_file_modes(
+    state: State<'_, AppState>,
+    selections: Vec<FileModeFix>,
+) -> Result<FileModeFixResult> {
+    state
+        .get_git_service()?
+        .write()
+        .await
+        .fix_file_modes(selections)
+        .await
+}
+
 #[tauri::command]
 #[specta::specta]
 pub async fn unstage_file(state: State<'_, AppState>, path: String) -> Result<()> {
@@ -66,27 +124,153 @@ pub async fn unstage_all(state: State<'_, AppState>) -> Result<()> {
 #[tauri::command]
 #[specta::specta]
 pub async fn discard_file(state: State<'_, AppState>, path: String) -> Result<()> {
+    let git_service = state.get_git_service()?;
+    let guard = git_service.write().await;
+
     state
-        .get_git_service()?
-        .write()
-        .await
-        .discard_file(&path)
-        .await
+        .record_undo_snapshot(&guard, UndoOperation::Discard)
+        .await;
+
+    guard.discard_file(&path).await
 }
 
 #[tauri::command]
 #[specta::specta]
 pub async fn discard_unstaged(state: State<'_, AppState>) -> Result<()> {
+    let git_service = state.get_git_service()?;
+    let guard = git_service.write().await;
+
     state
+        .record_undo_snapshot(&guard, UndoOperation::Discard)
+        .await;
+
+    guard.discard_unstaged().await
+}
+
+/// Violations of the commit message rules: those from settings, overridden by the
+/// repository's `.commitlintrc.json`. Empty when validation is disabled.
+fn commit_lint_violations(
+    config: &CommitLintConfig,
+    repo_root: &Path,
+    message: &str,
+) -> Vec<CommitLintViolation> {
+    if !config.enabled {
+        return Vec::new();
+    }
+    let rules = load_commit_lint_rules(repo_root, &config.rules);
+    lint_commit_message(message, &rules)
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn validate_commit_message(
+    state: State<'_, AppState>,
+    message: String,
+) -> Result<Vec<CommitLintViolation>> {
+    let path = state.ensure_repository_open()?;
+    let settings = state.get_settings()?;
+    Ok(commit_lint_violations(
+        &settings.commit_lint,
+        &path,
+        &message,
+    ))
+}
+
+/// Misspelled words in a commit message, checked with the dictionary for the app
+/// language. Code, staged paths, branch names, identifiers from the staged files and
+/// words added to the repository's dictionary are never flagged.
+#[tauri::command]
+#[specta::specta]
+pub async fn check_commit_message_spelling(
+    state: State<'_, AppState>,
+    message: String,
+) -> Result<SpellCheckResult> {
+    let path = state.ensure_repository_open()?;
+    let settings = state.get_settings()?;
+    let dictionaries_dir = state
+        .get_app_handle()?
+        .path()
+        .resource_dir()
+        .map_err(|e| AxisError::Other(format!("Failed to locate dictionaries: {e}")))?
+        .join(DICTIONARIES_DIR);
+    let dictionary = state
+        .spell_dictionaries()
+        .load(&dictionaries_dir, &settings.language)?;
+
+    let mut context = state
         .get_git_service()?
-        .write()
+        .read()
         .await
-        .discard_unstaged()
+        .commit_spelling_context(state.identifier_corpus_cache())
+        .await?;
+    context.custom_words = state.database().get_spelling_words(&path)?;
+
+    Ok(SpellCheckResult {
+        language: dictionary.language().to_string(),
+        findings: check_spelling(&message, &dictionary, &context),
+    })
+}
+
+/// Add a word to the active repository's spelling dictionary
+#[tauri::command]
+#[specta::specta]
+pub async fn add_word(state: State<'_, AppState>, word: String) -> Result<()> {
+    let path = state.ensure_repository_open()?;
+    let word = word.trim();
+    if word.is_empty() || word.contains(char::is_whitespace) {
+        return Err(AxisError::Other(format!("Not a single word: {word:?}")));
+    }
+    state.database().add_spelling_word(&path, word)
+}
+
+/// Commit message hygiene across the history selected by `options`, checked against
+/// the commit message rules whether or not validation is enabled. Progress is
+/// reported as `CommitMessageAnalysisProgressEvent`s.
+#[tauri::command]
+#[specta::specta]
+pub async fn analyze_commit_messages(
+    state: State<'_, AppState>,
+    options: CommitMessageStatsOptions,
+) -> Result<CommitMessageStats> {
+    let path = state.ensure_repository_open()?;
+    let settings = state.get_settings()?;
+    let rules = load_commit_lint_rules(&path, &settings.commit_lint.rules);
+    let app_handle = state.get_app_handle()?;
+    let registry = state.progress_registry();
+
+    let operation_id = uuid::Uuid::new_v4().to_string();
+    let cancel_token = registry.register(&operation_id);
+    let on_progress = {
+        let operation_id = operation_id.clone();
+        move |analyzed: usize| {
+            let event = CommitMessageAnalysisProgressEvent {
+                operation_id: operation_id.clone(),
+                analyzed,
+            };
+            if let Err(e) = event.emit(&app_handle) {
+                log::error!("Failed to emit commit message analysis progress: {e}");
+            }
+            !cancel_token.load(Ordering::SeqCst)
+        }
+    };
+    let result = state
+        .get_git_service()?
+        .read()
         .await
+        .analyze_commit_messages(
+            options,
+            rules,
+            state.commit_message_stats_cache(),
+            Box::new(on_progress),
+        )
+        .await;
+    registry.cleanup(&operation_id);
+    result
 }
 
 #[tauri::command]
 #[specta::specta]
+#[allow(clippy::too_many_arguments)]
 pub async fn create_commit(
     state: State<'_, AppState>,
     message: String,
@@ -94,6 +278,8 @@ pub async fn create_commit(
     author_email: Option<String>,
     sign: Option<bool>,
     bypass_hooks: Option<bool>,
+    allow_empty: Option<bool>,
+    trailers: Option<CommitTrailerOptions>,
 ) -> Result<String> {
     let path = state.ensure_repository_open()?;
     let settings = state.get_settings()?;
@@ -101,8 +287,20 @@ pub async fn create_commit(
 
     // Use explicit bypass_hooks param if provided, otherwise use settings
     let skip_hooks = bypass_hooks.unwrap_or(settings.bypass_hooks);
+    let allow_empty = allow_empty.unwrap_or(false);
 
     let guard = git_service.write().await;
+    // Trailers are added before the hooks run, as `git commit --signoff` does
+    let message = match &trailers {
+        Some(trailers) => guard.apply_commit_trailers(&message, trailers).await?,
+        None => message,
+    };
+    if settings.commit_lint.enforce_on_commit {
+        let violations = commit_lint_violations(&settings.commit_lint, &path, &message);
+        if violations.iter().any(|v| v.severity == LintSeverity::Error) {
+            return Err(AxisError::CommitMessageInvalid(violations));
+        }
+    }
     let mut final_message = message.clone();
 
     if !skip_hooks {
@@ -167,32 +365,32 @@ pub async fn create_commit(
     let should_sign = sign.unwrap_or(settings.sign_commits);
 
     let signing_config = if should_sign {
-        let signing_service = SigningService::new(&path);
-        let mut config = signing_service.get_config_from_git()?;
-
-        // Override with repo-specific signing config if present
-        let (repo_format, repo_key) = guard.get_repo_signing_config().await?;
-        if let Some(format) = repo_format {
-            config.format = format;
-        }
-        if let Some(key) = repo_key {
-            config.signing_key = Some(key);
-        }
-
-        Some(config)
+        Some(guard.get_effective_signing_config().await?)
     } else {
         None
     };
 
     // 4. Create the commit
-    let oid = guard
+    let result = guard
         .create_commit(
             &final_message,
             author_name.as_deref(),
             author_email.as_deref(),
             signing_config.as_ref(),
+            allow_empty,
         )
-        .await?;
+        .await;
+    state.record_operation(
+        OperationCategory::Commit,
+        "commit",
+        json!({
+            "summary": final_message.lines().next().unwrap_or_default(),
+            "signed": should_sign,
+            "allowEmpty": allow_empty,
+        }),
+        &result,
+    );
+    let oid = result?;
 
     // 5. Run post-commit hook (don't fail on error, just log)
     if !skip_hooks {
@@ -209,10 +407,18 @@ pub async fn create_commit(
 
 #[tauri::command]
 #[specta::specta]
+#[allow(clippy::too_many_arguments)]
 pub async fn amend_commit(
     state: State<'_, AppState>,
     message: Option<String>,
+    author_name: Option<String>,
+    author_email: Option<String>,
+    reset_author_date: Option<bool>,
+    include_staged: Option<bool>,
+    sign: Option<bool>,
     bypass_hooks: Option<bool>,
+    allow_empty: Option<bool>,
+    trailers: Option<CommitTrailerOptions>,
 ) -> Result<String> {
     let path = state.ensure_repository_open()?;
     let settings = state.get_settings()?;
@@ -226,6 +432,18 @@ pub async fn amend_commit(
     // Get old commit OID before amend for post-rewrite hook
     let old_oid = guard.get_head_oid_opt().await;
 
+    // Without a new message, trailers are added to the message being amended
+    let message = match trailers.filter(|t| !t.is_empty()) {
+        Some(trailers) => {
+            let base = match message {
+                Some(message) => message,
+                None => guard.get_commit("HEAD").await?.message,
+            };
+            Some(guard.apply_commit_trailers(&base, &trailers).await?)
+        }
+        None => message,
+    };
+
     let mut final_message = message.clone();
 
     if let (false, Some(msg)) = (skip_hooks, &final_message) {
@@ -252,8 +470,42 @@ pub async fn amend_commit(
         }
     }
 
+    // Use explicit sign param if provided, otherwise use settings
+    let should_sign = sign.unwrap_or(settings.sign_commits);
+
+    let signing_config = if should_sign {
+        Some(guard.get_effective_signing_config().await?)
+    } else {
+        None
+    };
+
+    state
+        .record_undo_snapshot(&guard, UndoOperation::Amend)
+        .await;
+
     // Amend the commit
-    let new_oid = guard.amend_commit(final_message.as_deref()).await?;
+    let result = guard
+        .amend_commit(
+            final_message.as_deref(),
+            author_name.as_deref(),
+            author_email.as_deref(),
+            reset_author_date.unwrap_or(false),
+            include_staged.unwrap_or(true),
+            signing_config.as_ref(),
+            allow_empty.unwrap_or(false),
+        )
+        .await;
+    state.record_operation(
+        OperationCategory::Commit,
+        "amend",
+        json!({
+            "oldOid": old_oid,
+            "summary": final_message.as_deref().and_then(|m| m.lines().next()),
+            "signed": should_sign,
+        }),
+        &result,
+    );
+    let new_oid = result?;
 
     // Run post-rewrite hook
     if !skip_hooks {
@@ -273,6 +525,18 @@ pub async fn amend_commit(
     Ok(new_oid)
 }
 
+/// Co-authors credited in recent history, for autocomplete
+#[tauri::command]
+#[specta::specta]
+pub async fn get_recent_co_authors(state: State<'_, AppState>) -> Result<Vec<CoAuthor>> {
+    state
+        .get_git_service()?
+        .read()
+        .await
+        .get_recent_co_authors(CO_AUTHOR_SCAN_DEPTH)
+        .await
+}
+
 #[tauri::command]
 #[specta::specta]
 pub async fn get_user_signature(state: State<'_, AppState>) -> Result<(String, String)> {
@@ -309,12 +573,14 @@ pub async fn unstage_hunk(state: State<'_, AppState>, patch: String) -> Result<(
 #[tauri::command]
 #[specta::specta]
 pub async fn discard_hunk(state: State<'_, AppState>, patch: String) -> Result<()> {
+    let git_service = state.get_git_service()?;
+    let guard = git_service.write().await;
+
     state
-        .get_git_service()?
-        .write()
-        .await
-        .discard_hunk(&patch)
-        .await
+        .record_undo_snapshot(&guard, UndoOperation::Discard)
+        .await;
+
+    guard.discard_hunk(&patch).await
 }
 
 #[tauri::command]
@@ -340,16 +606,25 @@ pub async fn check_files_for_lfs(
 
     // Get LFS status using existing CLI service
     let lfs_status = guard.lfs_status().await?;
-    let tracked_patterns = if lfs_status.is_installed {
-        guard.lfs_list_tracked_patterns().await.unwrap_or_default()
+    let tracked_patterns: Vec<String> = if lfs_status.is_installed {
+        // `git lfs track` also reports nested and `.git/info/attributes` patterns
+        guard
+            .lfs_list_tracked_patterns()
+            .await
+            .unwrap_or_default()
+            .into_iter()
+            .map(|p| p.pattern)
+            .collect()
     } else {
-        vec![]
+        guard.get_lfs_tracked_patterns().unwrap_or_else(|e| {
+            log::warn!("Failed to read LFS patterns from .gitattributes: {e}");
+            Vec::new()
+        })
     };
 
     // Check files using git2 service
-    let pattern_strings: Vec<String> = tracked_patterns.iter().map(|p| p.pattern.clone()).collect();
     let files = guard
-        .check_files_for_lfs(&paths, threshold, &pattern_strings)
+        .check_files_for_lfs(&paths, threshold, &tracked_patterns)
         .await?;
 
     Ok(LfsCheckResult {
@@ -1,6 +1,11 @@
+use crate::commands::diff::resolve_diff_options;
 use crate::error::Result;
-use crate::models::{StashApplyOptions, StashEntry, StashResult, StashSaveOptions};
+use crate::models::{
+    DiffContext, DiffOptions, FileDiff, OperationCategory, StashApplyOptions, StashEntry,
+    StashHunkSelection, StashResult, StashSaveOptions, UndoOperation,
+};
 use crate::state::AppState;
+use serde_json::json;
 use tauri::State;
 
 // ==================== Stash Commands ====================
@@ -19,11 +24,58 @@ pub async fn stash_save(
     state: State<'_, AppState>,
     options: StashSaveOptions,
 ) -> Result<StashResult> {
-    state
+    let result = state
         .get_git_service()?
         .write()
         .await
         .stash_save(&options)
+        .await;
+    state.record_operation(
+        OperationCategory::Stash,
+        "stash_save",
+        json!({ "options": options }),
+        &result,
+    );
+    result
+}
+
+/// Stash only the changes to `paths`, including untracked files among them
+#[tauri::command]
+#[specta::specta]
+pub async fn stash_save_selected_files(
+    state: State<'_, AppState>,
+    paths: Vec<String>,
+    message: Option<String>,
+) -> Result<()> {
+    state
+        .get_git_service()?
+        .write()
+        .await
+        .stash_save_selected_files(&paths, message.as_deref())
+        .await
+}
+
+/// Stash only the selected hunks of the unstaged changes, removing them from the working tree.
+/// Hunk indices refer to the workdir diff shown with `options`/`preset`.
+#[tauri::command]
+#[specta::specta]
+pub async fn stash_selection(
+    state: State<'_, AppState>,
+    selections: Vec<StashHunkSelection>,
+    message: Option<String>,
+    options: Option<DiffOptions>,
+    preset: Option<String>,
+) -> Result<StashEntry> {
+    let opts = resolve_diff_options(&state, DiffContext::Workdir, preset.as_deref(), options)?;
+    let git_service = state.get_git_service()?;
+    let guard = git_service.write().await;
+
+    state
+        .record_undo_snapshot(&guard, UndoOperation::Discard)
+        .await;
+
+    guard
+        .stash_selection(selections, message, opts.context_lines)
         .await
 }
 
@@ -34,12 +86,19 @@ pub async fn stash_apply(
     state: State<'_, AppState>,
     options: StashApplyOptions,
 ) -> Result<StashResult> {
-    state
+    let result = state
         .get_git_service()?
         .write()
         .await
         .stash_apply(&options)
-        .await
+        .await;
+    state.record_operation(
+        OperationCategory::Stash,
+        "stash_apply",
+        json!({ "options": options }),
+        &result,
+    );
+    result
 }
 
 /// Pop a stash (apply and remove from stash list)
@@ -49,24 +108,40 @@ pub async fn stash_pop(
     state: State<'_, AppState>,
     options: StashApplyOptions,
 ) -> Result<StashResult> {
+    let git_service = state.get_git_service()?;
+    let guard = git_service.write().await;
+
     state
-        .get_git_service()?
-        .write()
-        .await
-        .stash_pop(&options)
-        .await
+        .record_undo_snapshot(&guard, UndoOperation::StashPop)
+        .await;
+
+    let result = guard.stash_pop(&options).await;
+    state.record_operation(
+        OperationCategory::Stash,
+        "stash_pop",
+        json!({ "options": options }),
+        &result,
+    );
+    result
 }
 
 /// Drop a stash entry
 #[tauri::command]
 #[specta::specta]
 pub async fn stash_drop(state: State<'_, AppState>, index: Option<usize>) -> Result<StashResult> {
-    state
+    let result = state
         .get_git_service()?
         .write()
         .await
         .stash_drop(index)
-        .await
+        .await;
+    state.record_operation(
+        OperationCategory::Stash,
+        "stash_drop",
+        json!({ "index": index }),
+        &result,
+    );
+    result
 }
 
 /// Clear all stashes
@@ -92,6 +167,24 @@ pub async fn stash_show(
         .await
 }
 
+/// Diff a stash entry against the commit it was created on, without applying it
+#[tauri::command]
+#[specta::specta]
+pub async fn get_stash_diff(
+    state: State<'_, AppState>,
+    stash_index: usize,
+    options: Option<DiffOptions>,
+    preset: Option<String>,
+) -> Result<Vec<FileDiff>> {
+    let opts = resolve_diff_options(&state, DiffContext::Commit, preset.as_deref(), options)?;
+    state
+        .get_git_service()?
+        .read()
+        .await
+        .get_stash_diff(stash_index, &opts)
+        .await
+}
+
 /// Create a branch from a stash
 #[tauri::command]
 #[specta::specta]
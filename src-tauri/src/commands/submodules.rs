@@ -1,11 +1,28 @@
 use crate::error::Result;
 use crate::models::{
-    AddSubmoduleOptions, ListSubmoduleOptions, Submodule, SubmoduleResult, SyncSubmoduleOptions,
-    UpdateSubmoduleOptions,
+    AddSubmoduleOptions, ListSubmoduleOptions, OutOfSyncSubmodule, SshCredentials, Submodule,
+    SubmoduleResult, SyncSubmoduleOptions, UpdateSubmoduleOptions,
 };
 use crate::state::AppState;
 use tauri::State;
 
+/// SSH credentials of every submodule that has a key for its own remote
+async fn submodule_ssh_credentials(state: &AppState) -> Result<Vec<(String, SshCredentials)>> {
+    let remotes = state
+        .get_git_service()?
+        .read()
+        .await
+        .submodule_remotes()
+        .await?;
+    let mut credentials = Vec::new();
+    for (path, remote_name) in remotes {
+        if let Some(creds) = state.resolve_submodule_ssh_credentials(&path, &remote_name)? {
+            credentials.push((path, creds));
+        }
+    }
+    Ok(credentials)
+}
+
 // ==================== Submodule Commands ====================
 
 /// List all submodules
@@ -30,7 +47,8 @@ pub async fn submodule_add(
     state: State<'_, AppState>,
     options: AddSubmoduleOptions,
 ) -> Result<SubmoduleResult> {
-    let ssh_creds = state.resolve_ssh_credentials("origin")?;
+    // The new submodule is cloned with an `origin` remote
+    let ssh_creds = state.resolve_submodule_ssh_credentials(&options.path, "origin")?;
     state
         .get_git_service()?
         .write()
@@ -61,7 +79,7 @@ pub async fn submodule_update(
     state: State<'_, AppState>,
     options: UpdateSubmoduleOptions,
 ) -> Result<SubmoduleResult> {
-    let ssh_creds = state.resolve_ssh_credentials("origin")?;
+    let ssh_creds = submodule_ssh_credentials(&state).await?;
     state
         .get_git_service()?
         .write()
@@ -70,6 +88,33 @@ pub async fn submodule_update(
         .await
 }
 
+/// List submodules whose checked-out commit differs from the one recorded at HEAD
+#[tauri::command]
+#[specta::specta]
+pub async fn get_out_of_sync_submodules(
+    state: State<'_, AppState>,
+) -> Result<Vec<OutOfSyncSubmodule>> {
+    state
+        .get_git_service()?
+        .read()
+        .await
+        .get_out_of_sync_submodules()
+        .await
+}
+
+/// Update only the submodules that are out of sync with HEAD
+#[tauri::command]
+#[specta::specta]
+pub async fn update_out_of_sync_submodules(state: State<'_, AppState>) -> Result<SubmoduleResult> {
+    let ssh_creds = submodule_ssh_credentials(&state).await?;
+    state
+        .get_git_service()?
+        .write()
+        .await
+        .update_out_of_sync_submodules(ssh_creds)
+        .await
+}
+
 /// Sync submodule URLs from .gitmodules
 #[tauri::command]
 #[specta::specta]
@@ -1,6 +1,11 @@
 use crate::error::Result;
-use crate::models::{CreateTagOptions, ListTagsOptions, Tag, TagResult};
+use crate::models::{
+    BulkTagSpec, CreateTagOptions, DeleteTagsByPatternOptions, DeleteTagsByPatternResult,
+    ListTagsOptions, OperationCategory, Tag, TagOperationResult, TagPushOptions, TagPushResult,
+    TagResult,
+};
 use crate::state::AppState;
+use serde_json::json;
 use tauri::State;
 
 // ==================== Tag Commands ====================
@@ -28,53 +33,110 @@ pub async fn tag_create(
     name: String,
     options: CreateTagOptions,
 ) -> Result<TagResult> {
-    state
+    let result = state
         .get_git_service()?
         .write()
         .await
         .tag_create(&name, &options)
-        .await
+        .await;
+    state.record_operation(
+        OperationCategory::Tag,
+        "tag_create",
+        json!({ "name": name, "options": options }),
+        &result,
+    );
+    result
 }
 
 /// Delete a local tag
 #[tauri::command]
 #[specta::specta]
 pub async fn tag_delete(state: State<'_, AppState>, name: String) -> Result<TagResult> {
-    state
+    let result = state
         .get_git_service()?
         .write()
         .await
         .tag_delete(&name)
+        .await;
+    state.record_operation(
+        OperationCategory::Tag,
+        "tag_delete",
+        json!({ "name": name }),
+        &result,
+    );
+    result
+}
+
+/// Create several tags at once, reporting success or failure per tag
+#[tauri::command]
+#[specta::specta]
+pub async fn tag_create_bulk(
+    state: State<'_, AppState>,
+    specs: Vec<BulkTagSpec>,
+    stop_on_error: Option<bool>,
+) -> Result<Vec<TagOperationResult>> {
+    state
+        .get_git_service()?
+        .write()
+        .await
+        .tag_create_bulk(specs, stop_on_error.unwrap_or(false))
+        .await
+}
+
+/// Delete local tags matching a glob pattern, optionally on remotes too
+#[tauri::command]
+#[specta::specta]
+pub async fn tag_delete_by_pattern(
+    state: State<'_, AppState>,
+    pattern: String,
+    options: Option<DeleteTagsByPatternOptions>,
+) -> Result<DeleteTagsByPatternResult> {
+    let options = options.unwrap_or_default();
+    let remote_credentials = options
+        .remotes
+        .iter()
+        .map(|remote| Ok((remote.clone(), state.resolve_ssh_credentials(remote)?)))
+        .collect::<Result<Vec<_>>>()?;
+    state
+        .get_git_service()?
+        .write()
+        .await
+        .tag_delete_by_pattern(&pattern, options, remote_credentials)
         .await
 }
 
-/// Push a tag to a remote
+/// Push a tag to a remote, reporting whether it was pushed, already there or rejected
 #[tauri::command]
 #[specta::specta]
 pub async fn tag_push(
     state: State<'_, AppState>,
     name: String,
     remote: String,
-) -> Result<TagResult> {
+    options: Option<TagPushOptions>,
+) -> Result<TagPushResult> {
     let ssh_creds = state.resolve_ssh_credentials(&remote)?;
     state
         .get_git_service()?
         .write()
         .await
-        .tag_push(&name, &remote, ssh_creds)
+        .tag_push(&name, &remote, options.unwrap_or_default(), ssh_creds)
         .await
 }
 
-/// Push all tags to a remote
+/// Push the tags a remote is missing, with one outcome per local tag
 #[tauri::command]
 #[specta::specta]
-pub async fn tag_push_all(state: State<'_, AppState>, remote: String) -> Result<TagResult> {
+pub async fn tag_push_all(
+    state: State<'_, AppState>,
+    remote: String,
+    options: Option<TagPushOptions>,
+) -> Result<TagPushResult> {
     let ssh_creds = state.resolve_ssh_credentials(&remote)?;
     state
         .get_git_service()?
         .write()
         .await
-        .tag_push_all(&remote, ssh_creds)
+        .tag_push_all(&remote, options.unwrap_or_default(), ssh_creds)
         .await
 }
 
@@ -85,12 +147,13 @@ pub async fn tag_delete_remote(
     state: State<'_, AppState>,
     name: String,
     remote: String,
-) -> Result<TagResult> {
+    options: Option<TagPushOptions>,
+) -> Result<TagPushResult> {
     let ssh_creds = state.resolve_ssh_credentials(&remote)?;
     state
         .get_git_service()?
         .write()
         .await
-        .tag_delete_remote(&name, &remote, ssh_creds)
+        .tag_delete_remote(&name, &remote, options.unwrap_or_default(), ssh_creds)
         .await
 }
@@ -0,0 +1,67 @@
+use crate::error::{AxisError, Result};
+use crate::models::{OperationCategory, UndoEntry, UndoOperation, UndoResult};
+use crate::state::AppState;
+use tauri::State;
+
+/// Undo journal entries for the current repository, newest first
+#[tauri::command]
+#[specta::specta]
+pub async fn get_undo_history(
+    state: State<'_, AppState>,
+    limit: Option<usize>,
+) -> Result<Vec<UndoEntry>> {
+    let repo_path = state.get_repo_path_string()?;
+    state.database().get_undo_history(&repo_path, limit)
+}
+
+/// Restore the repository to the snapshot taken before the last destructive operation
+#[tauri::command]
+#[specta::specta]
+pub async fn undo_last_operation(state: State<'_, AppState>) -> Result<UndoResult> {
+    let repo_path = state.get_repo_path_string()?;
+    let entry = state
+        .database()
+        .get_undo_history(&repo_path, Some(1))?
+        .into_iter()
+        .next()
+        .ok_or_else(|| AxisError::Other("Nothing to undo".to_string()))?;
+
+    let git_handle = state.get_git_service()?;
+    let git = git_handle.write().await;
+    let result = git.restore_undo_snapshot(&entry.snapshot).await;
+    state.record_operation(
+        OperationCategory::History,
+        "undo",
+        serde_json::json!({ "operation": entry.operation, "headOid": entry.snapshot.head_oid }),
+        &result,
+    );
+
+    // Changes the restore replaced become the next entry, so the undo can be undone
+    if let Some(replaced) = result? {
+        state
+            .journal_undo_snapshot(&git, UndoOperation::Undo, &replaced)
+            .await?;
+    }
+    state.database().delete_undo_entry(entry.id)?;
+    state.prune_undo_refs(&git).await?;
+
+    let short_oid: String = entry.snapshot.head_oid.chars().take(7).collect();
+    let target = match &entry.snapshot.branch {
+        Some(branch) => format!("{branch} at {short_oid}"),
+        None => format!("detached HEAD at {short_oid}"),
+    };
+    let message = if entry.snapshot.complete {
+        format!("Undid {}: restored {target}", entry.operation)
+    } else {
+        format!(
+            "Undid {}: restored {target}. Untracked files were not part of the snapshot and could not be restored",
+            entry.operation
+        )
+    };
+
+    Ok(UndoResult {
+        complete: entry.snapshot.complete,
+        entry,
+        message,
+    })
+}
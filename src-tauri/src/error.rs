@@ -44,21 +44,120 @@ pub enum AxisError {
     #[error("Merge conflict detected")]
     MergeConflict,
 
+    #[error("Binary conflict cannot be resolved per hunk: {0}")]
+    BinaryConflict(String),
+
     #[error("Checkout conflict: uncommitted changes would be overwritten")]
     CheckoutConflict(Vec<String>),
 
+    #[error("Hard reset requires confirmation: uncommitted changes would be discarded")]
+    ResetRequiresConfirmation(Vec<String>),
+
+    #[error("HEAD is unborn: the repository has no commits yet")]
+    UnbornHead,
+
     #[error("Stash applied with conflicts")]
     StashApplyConflict(Vec<String>),
 
+    #[error("Nothing to commit: the staged tree is identical to HEAD")]
+    EmptyCommit,
+
+    #[error("Aborting commit due to empty commit message")]
+    EmptyCommitMessage,
+
+    #[error("Commit message does not follow the configured conventions")]
+    CommitMessageInvalid(Vec<crate::models::CommitLintViolation>),
+
+    #[error("Amending would produce a commit identical to HEAD")]
+    AmendUnchanged,
+
+    #[error("HEAD points to the missing branch {0}; recover HEAD before continuing")]
+    BrokenHead(String),
+
+    #[error("Branch '{branch}' is protected: {reason}")]
+    ProtectedBranch { branch: String, reason: String },
+
+    #[error("{refname} changed since the operation was planned; no refs were updated")]
+    StaleLocalRef {
+        refname: String,
+        /// OID the ref was expected to hold; `None` when it was expected not to exist
+        expected: Option<String>,
+        actual: Option<String>,
+    },
+
+    #[error(
+        "The repository changed since the operation was planned ({} precondition(s) no longer hold); nothing was rewritten",
+        .violations.len()
+    )]
+    PreconditionFailed {
+        /// Generation of the plan the preconditions were captured for
+        generation: u64,
+        violations: Vec<crate::models::PreconditionViolation>,
+    },
+
+    #[error("Alias '{name}' runs a shell command and must be confirmed: {command}")]
+    ShellAliasRequiresConfirmation { name: String, command: String },
+
+    #[error("Alternate object store {alternate} is unreachable: {message}")]
+    AlternateUnreachable { alternate: String, message: String },
+
+    #[error("Sync client interference: {0}")]
+    SyncClientInterference(String),
+
+    #[error("Repository hooks must be trusted before they can run")]
+    HookTrustRequired(crate::models::HookTrustRequest),
+
     #[error("AI service error: {0}")]
     AiServiceError(String),
 
     #[error("API key not configured for {0}")]
     ApiKeyNotConfigured(String),
 
+    #[error("API key rejected by {0}")]
+    ApiKeyRejected(String),
+
+    #[error("AI model not found: {0}")]
+    AiModelNotFound(String),
+
+    #[error("AI provider rate limit reached: {0}")]
+    AiRateLimited(String),
+
+    #[error("AI service is not running at {0}")]
+    AiDaemonNotRunning(String),
+
+    #[error("Cannot reach {0}: check your network connection")]
+    AiNetworkUnreachable(String),
+
+    #[error("AI request cancelled")]
+    AiRequestCancelled,
+
     #[error("Diff too large: {0} bytes")]
     DiffTooLarge(usize),
 
+    #[error("File too large to preview: {path} is {size} bytes, the limit is {limit}")]
+    FileTooLarge { path: String, size: u64, limit: u64 },
+
+    #[error("Binary diff cannot be explained: {0}")]
+    BinaryDiff(String),
+
+    #[error("Hunk {0} not found in the diff")]
+    HunkNotFound(u32),
+
+    #[error("Diff preset not found: {0}")]
+    DiffPresetNotFound(String),
+
+    #[error("Built-in diff preset cannot be deleted: {0}")]
+    BuiltInDiffPreset(String),
+
+    #[error("Invalid search pattern: {0}")]
+    InvalidSearchPattern(String),
+
+    #[error("Config key cannot be overridden for a single operation: {0}")]
+    ConfigOverrideNotAllowed(String),
+
+    #[error("Bookmark not found: {0}")]
+    BookmarkNotFound(i64),
+
     #[error("{0}")]
     Other(String),
 
@@ -85,10 +184,33 @@ pub enum AxisError {
 
     #[error("Invalid key filename: {0}")]
     InvalidKeyFilename(String),
+
+    #[error("SSH key requires a passphrase: {key_path}")]
+    SshPassphraseRequired { key_path: String },
+
+    #[error("GPG key error: {0}")]
+    GpgKeyError(String),
+
+    #[error("gpg not found")]
+    GpgNotFound,
+
+    #[error("GPG agent is not running: {0}")]
+    GpgAgentUnavailable(String),
 }
 
+/// Message prefix of the libgit2 error raised by the credentials callback when an
+/// encrypted key has no passphrase and no agent can unlock it
+pub(crate) const SSH_PASSPHRASE_REQUIRED_PREFIX: &str = "SSH key requires a passphrase: ";
+
 impl From<git2::Error> for AxisError {
     fn from(err: git2::Error) -> Self {
+        if err.class() == git2::ErrorClass::Ssh {
+            if let Some(key_path) = err.message().strip_prefix(SSH_PASSPHRASE_REQUIRED_PREFIX) {
+                return AxisError::SshPassphraseRequired {
+                    key_path: key_path.to_string(),
+                };
+            }
+        }
         AxisError::GitError(err.to_string())
     }
 }
@@ -203,6 +325,15 @@ mod tests {
         assert_eq!(err.to_string(), "Merge conflict detected");
     }
 
+    #[test]
+    fn test_binary_conflict_display() {
+        let err = AxisError::BinaryConflict("image.png".to_string());
+        assert_eq!(
+            err.to_string(),
+            "Binary conflict cannot be resolved per hunk: image.png"
+        );
+    }
+
     #[test]
     fn test_checkout_conflict_display() {
         let err = AxisError::CheckoutConflict(vec!["file1.rs".to_string(), "file2.rs".to_string()]);
@@ -212,12 +343,105 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_reset_requires_confirmation_display() {
+        let err = AxisError::ResetRequiresConfirmation(vec!["file.rs".to_string()]);
+        assert_eq!(
+            err.to_string(),
+            "Hard reset requires confirmation: uncommitted changes would be discarded"
+        );
+    }
+
+    #[test]
+    fn test_protected_branch_display() {
+        let err = AxisError::ProtectedBranch {
+            branch: "main".to_string(),
+            reason: "force pushes are not allowed".to_string(),
+        };
+        assert_eq!(
+            err.to_string(),
+            "Branch 'main' is protected: force pushes are not allowed"
+        );
+    }
+
+    #[test]
+    fn test_stale_local_ref_display() {
+        let err = AxisError::StaleLocalRef {
+            refname: "refs/heads/develop".to_string(),
+            expected: Some("abc123".to_string()),
+            actual: Some("def456".to_string()),
+        };
+        assert_eq!(
+            err.to_string(),
+            "refs/heads/develop changed since the operation was planned; no refs were updated"
+        );
+    }
+
+    #[test]
+    fn test_precondition_failed_serialization() {
+        let err = AxisError::PreconditionFailed {
+            generation: 7,
+            violations: vec![crate::models::PreconditionViolation::Index {
+                expected: "abc".to_string(),
+                actual: "def".to_string(),
+            }],
+        };
+        assert_eq!(
+            err.to_string(),
+            "The repository changed since the operation was planned (1 precondition(s) no longer hold); nothing was rewritten"
+        );
+        let json = serde_json::to_string(&err).expect("should serialize");
+        assert_eq!(
+            json,
+            r#"{"type":"PreconditionFailed","data":{"generation":7,"violations":[{"Index":{"expected":"abc","actual":"def"}}]}}"#
+        );
+    }
+
+    #[test]
+    fn test_shell_alias_requires_confirmation_display() {
+        let err = AxisError::ShellAliasRequiresConfirmation {
+            name: "up".to_string(),
+            command: "!git pull --rebase".to_string(),
+        };
+        assert_eq!(
+            err.to_string(),
+            "Alias 'up' runs a shell command and must be confirmed: !git pull --rebase"
+        );
+    }
+
+    #[test]
+    fn test_commit_message_invalid_display() {
+        let err = AxisError::CommitMessageInvalid(vec![]);
+        assert_eq!(
+            err.to_string(),
+            "Commit message does not follow the configured conventions"
+        );
+    }
+
     #[test]
     fn test_stash_apply_conflict_display() {
         let err = AxisError::StashApplyConflict(vec!["conflict.rs".to_string()]);
         assert_eq!(err.to_string(), "Stash applied with conflicts");
     }
 
+    #[test]
+    fn test_alternate_unreachable_display() {
+        let err = AxisError::AlternateUnreachable {
+            alternate: "/mnt/share/objects".to_string(),
+            message: "object not found".to_string(),
+        };
+        assert_eq!(
+            err.to_string(),
+            "Alternate object store /mnt/share/objects is unreachable: object not found"
+        );
+    }
+
+    #[test]
+    fn test_sync_client_interference_display() {
+        let err = AxisError::SyncClientInterference("index is locked".to_string());
+        assert_eq!(err.to_string(), "Sync client interference: index is locked");
+    }
+
     #[test]
     fn test_ai_service_error_display() {
         let err = AxisError::AiServiceError("rate limit exceeded".to_string());
@@ -230,12 +454,62 @@ mod tests {
         assert_eq!(err.to_string(), "API key not configured for OpenAI");
     }
 
+    #[test]
+    fn test_empty_commit_serializes_without_data() {
+        let json = serde_json::to_string(&AxisError::EmptyCommit).expect("should serialize");
+        assert_eq!(json, r#"{"type":"EmptyCommit"}"#);
+    }
+
+    #[test]
+    fn test_ai_provider_error_display() {
+        assert_eq!(
+            AxisError::AiModelNotFound("gpt-5".to_string()).to_string(),
+            "AI model not found: gpt-5"
+        );
+        assert_eq!(
+            AxisError::AiRateLimited("OpenAI".to_string()).to_string(),
+            "AI provider rate limit reached: OpenAI"
+        );
+        assert_eq!(
+            AxisError::ApiKeyRejected("Anthropic".to_string()).to_string(),
+            "API key rejected by Anthropic"
+        );
+    }
+
     #[test]
     fn test_diff_too_large_display() {
         let err = AxisError::DiffTooLarge(10_000_000);
         assert_eq!(err.to_string(), "Diff too large: 10000000 bytes");
     }
 
+    #[test]
+    fn test_file_too_large_display() {
+        let err = AxisError::FileTooLarge {
+            path: "assets/hero.png".to_string(),
+            size: 30_000_000,
+            limit: 20_971_520,
+        };
+        assert_eq!(
+            err.to_string(),
+            "File too large to preview: assets/hero.png is 30000000 bytes, the limit is 20971520"
+        );
+    }
+
+    #[test]
+    fn test_invalid_search_pattern_display() {
+        let err = AxisError::InvalidSearchPattern("unclosed group".to_string());
+        assert_eq!(err.to_string(), "Invalid search pattern: unclosed group");
+    }
+
+    #[test]
+    fn test_config_override_not_allowed_display() {
+        let err = AxisError::ConfigOverrideNotAllowed("core.hooksPath".to_string());
+        assert_eq!(
+            err.to_string(),
+            "Config key cannot be overridden for a single operation: core.hooksPath"
+        );
+    }
+
     #[test]
     fn test_other_error_display() {
         let err = AxisError::Other("Something went wrong".to_string());
@@ -392,6 +666,54 @@ mod tests {
         assert!(json.contains("\"type\":\"SshKeygenNotFound\""));
     }
 
+    // ==================== GPG Key Error Tests ====================
+
+    #[test]
+    fn test_gpg_not_found_serialization() {
+        let err = AxisError::GpgNotFound;
+        assert_eq!(err.to_string(), "gpg not found");
+        let json = serde_json::to_string(&err).expect("should serialize");
+        assert!(json.contains("\"type\":\"GpgNotFound\""));
+    }
+
+    #[test]
+    fn test_gpg_agent_unavailable_serialization() {
+        let err = AxisError::GpgAgentUnavailable("no gpg-agent running".to_string());
+        assert_eq!(
+            err.to_string(),
+            "GPG agent is not running: no gpg-agent running"
+        );
+        let json = serde_json::to_string(&err).expect("should serialize");
+        assert!(json.contains("\"type\":\"GpgAgentUnavailable\""));
+        assert!(json.contains("\"data\":\"no gpg-agent running\""));
+    }
+
+    #[test]
+    fn test_ssh_passphrase_required_from_git2_error() {
+        let git_err = git2::Error::new(
+            git2::ErrorCode::Auth,
+            git2::ErrorClass::Ssh,
+            format!("{SSH_PASSPHRASE_REQUIRED_PREFIX}/home/me/.ssh/id_ed25519"),
+        );
+        let err: AxisError = git_err.into();
+        assert!(matches!(
+            &err,
+            AxisError::SshPassphraseRequired { key_path } if key_path == "/home/me/.ssh/id_ed25519"
+        ));
+
+        let json = serde_json::to_string(&err).expect("should serialize");
+        assert!(json.contains("\"type\":\"SshPassphraseRequired\""));
+        assert!(json.contains("\"key_path\":\"/home/me/.ssh/id_ed25519\""));
+
+        // Other SSH auth failures stay generic
+        let other = git2::Error::new(
+            git2::ErrorCode::Auth,
+            git2::ErrorClass::Ssh,
+            "authentication failed",
+        );
+        assert!(matches!(AxisError::from(other), AxisError::GitError(_)));
+    }
+
     // ==================== Debug Tests ====================
 
     #[test]
@@ -0,0 +1,19 @@
+use serde::Serialize;
+use specta::Type;
+use tauri_specta::Event;
+
+/// A piece of a commit message as it streams from the AI provider
+#[derive(Clone, Serialize, Type, Event, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct AiCommitMessageChunkEvent {
+    pub chunk: String,
+}
+
+/// A piece of a diff explanation as it streams from the AI provider
+#[derive(Clone, Serialize, Type, Event, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct AiExplainChunkEvent {
+    pub path: String,
+    pub hunk_index: Option<u32>,
+    pub chunk: String,
+}
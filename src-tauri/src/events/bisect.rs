@@ -0,0 +1,35 @@
+use serde::Serialize;
+use specta::Type;
+use tauri_specta::Event;
+
+use crate::models::BisectState;
+
+/// A bisect mark moved the session to a new commit (or found the first bad one)
+#[derive(Clone, Serialize, Type, Event)]
+#[serde(rename_all = "camelCase")]
+pub struct BisectStepEvent {
+    pub path: String,
+    pub state: BisectState,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // ==================== BisectStepEvent Tests ====================
+
+    #[test]
+    fn test_bisect_step_event_serialization() {
+        let event = BisectStepEvent {
+            path: "/repo".to_string(),
+            state: BisectState {
+                is_active: true,
+                current_commit: Some("abc123".to_string()),
+                ..Default::default()
+            },
+        };
+        let json = serde_json::to_string(&event).expect("should serialize");
+        assert!(json.contains("\"path\":\"/repo\""));
+        assert!(json.contains("\"currentCommit\":\"abc123\""));
+    }
+}
@@ -0,0 +1,21 @@
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use strum::{Display, EnumString};
+use tauri_specta::Event;
+
+#[derive(Clone, Copy, Serialize, Deserialize, Type, Display, EnumString, Debug, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+#[strum(serialize_all = "PascalCase")]
+pub enum ActionOutputStream {
+    Stdout,
+    Stderr,
+}
+
+/// A line of output from a running custom action
+#[derive(Clone, Serialize, Type, Event, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct CustomActionOutputEvent {
+    pub action_id: String,
+    pub stream: ActionOutputStream,
+    pub line: String,
+}
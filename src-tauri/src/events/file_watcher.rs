@@ -2,6 +2,8 @@ use serde::Serialize;
 use specta::Type;
 use tauri_specta::Event;
 
+use crate::models::{OutOfSyncSubmodule, RemoteFetchOutcome};
+
 /// Files in the repository changed
 #[derive(Clone, Serialize, Type, Event)]
 #[serde(rename_all = "camelCase")]
@@ -26,6 +28,37 @@ pub struct RefChangedEvent {
 #[serde(rename_all = "camelCase")]
 pub struct HeadChangedEvent;
 
+/// Everything that changed in a repository during one debounce window
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Type, Event)]
+#[serde(rename_all = "camelCase")]
+pub struct RepositoryChangedEvent {
+    /// Repository root
+    pub path: String,
+    /// Changed worktree paths relative to the root, ignored files excluded
+    pub worktree_paths: Vec<String>,
+    /// More worktree paths changed than are listed
+    pub worktree_overflow: bool,
+    pub index_changed: bool,
+    /// HEAD moved (commit, checkout, reset)
+    pub head_changed: bool,
+    /// Loose refs that changed, e.g. `refs/heads/main`
+    pub refs_changed: Vec<String>,
+    pub packed_refs_changed: bool,
+    pub config_changed: bool,
+}
+
+impl RepositoryChangedEvent {
+    pub fn is_empty(&self) -> bool {
+        self.worktree_paths.is_empty()
+            && !self.worktree_overflow
+            && !self.index_changed
+            && !self.head_changed
+            && self.refs_changed.is_empty()
+            && !self.packed_refs_changed
+            && !self.config_changed
+    }
+}
+
 /// Watch error occurred
 #[derive(Clone, Serialize, Type, Event)]
 #[serde(rename_all = "camelCase")]
@@ -48,6 +81,23 @@ pub struct RemoteFetchedEvent {
     pub new_commits: u32,
 }
 
+/// A background fetch updated refs of a repository
+#[derive(Clone, Serialize, Type, Event)]
+#[serde(rename_all = "camelCase")]
+pub struct BackgroundFetchCompletedEvent {
+    pub path: String,
+    /// Remotes whose refs changed
+    pub remotes: Vec<RemoteFetchOutcome>,
+}
+
+/// A pull moved submodule pointers away from their checked-out commits
+#[derive(Clone, Serialize, Type, Event)]
+#[serde(rename_all = "camelCase")]
+pub struct SubmodulesOutOfSyncEvent {
+    pub path: String,
+    pub submodules: Vec<OutOfSyncSubmodule>,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -119,6 +169,24 @@ mod tests {
         assert_eq!(json, "null");
     }
 
+    // ==================== RepositoryChangedEvent Tests ====================
+
+    #[test]
+    fn test_repository_changed_event_serialization() {
+        let event = RepositoryChangedEvent {
+            path: "/repo".to_string(),
+            head_changed: true,
+            refs_changed: vec!["refs/heads/main".to_string()],
+            ..Default::default()
+        };
+
+        assert!(!event.is_empty());
+        let json = serde_json::to_string(&event).expect("should serialize");
+        assert!(json.contains("\"headChanged\":true"));
+        assert!(json.contains("\"refsChanged\":[\"refs/heads/main\"]"));
+        assert!(json.contains("\"worktreeOverflow\":false"));
+    }
+
     // ==================== WatchErrorEvent Tests ====================
 
     #[test]
@@ -11,6 +11,7 @@ pub enum GitOperationType {
     Fetch,
     Push,
     Pull,
+    Maintenance,
 }
 
 #[derive(Clone, Copy, Serialize, Deserialize, Type, Display, EnumString, Debug, PartialEq, Eq)]
@@ -28,7 +29,7 @@ pub enum ProgressStage {
     Cancelled,
 }
 
-/// Progress update for git operations (clone, fetch, push, pull)
+/// Progress update for git operations (clone, fetch, push, pull, maintenance)
 #[derive(Clone, Serialize, Type, Event, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct GitOperationProgressEvent {
@@ -78,6 +79,7 @@ mod tests {
         assert_eq!(GitOperationType::Fetch.to_string(), "Fetch");
         assert_eq!(GitOperationType::Push.to_string(), "Push");
         assert_eq!(GitOperationType::Pull.to_string(), "Pull");
+        assert_eq!(GitOperationType::Maintenance.to_string(), "Maintenance");
     }
 
     #[test]
@@ -3,7 +3,7 @@ use specta::Type;
 use strum::{Display, EnumString};
 use tauri_specta::Event;
 
-use crate::models::GitHookType;
+use crate::models::{GitHookType, HookTrustRequest};
 
 #[derive(Clone, Copy, Serialize, Deserialize, Type, Display, EnumString, Debug, PartialEq, Eq)]
 #[serde(rename_all = "PascalCase")]
@@ -38,6 +38,14 @@ impl HookProgressEvent {
     }
 }
 
+/// A hook was about to run but the repository's hooks have no trust decision yet
+#[derive(Clone, Serialize, Type, Event, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct HookTrustRequiredEvent {
+    pub operation_id: String,
+    pub request: HookTrustRequest,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
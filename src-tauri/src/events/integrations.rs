@@ -2,7 +2,7 @@ use serde::Serialize;
 use specta::Type;
 use tauri_specta::Event;
 
-use crate::models::ProviderType;
+use crate::models::{CurrentBranchPullRequest, ProviderType, PullRequestDetail};
 
 /// OAuth callback received from deep link
 #[derive(Clone, Serialize, Type, Event)]
@@ -21,6 +21,24 @@ pub struct IntegrationStatusChangedEvent {
     pub connected: bool,
 }
 
+/// A pull request was re-fetched after its source branch was pushed
+#[derive(Clone, Serialize, Type, Event)]
+#[serde(rename_all = "camelCase")]
+pub struct PullRequestRefreshedEvent {
+    pub provider: ProviderType,
+    pub owner: String,
+    pub repo: String,
+    pub pull_request: PullRequestDetail,
+}
+
+/// The pull request for the checked-out branch was re-matched after HEAD moved
+#[derive(Clone, Serialize, Type, Event)]
+#[serde(rename_all = "camelCase")]
+pub struct CurrentBranchPullRequestEvent {
+    pub path: String,
+    pub current: CurrentBranchPullRequest,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,13 +1,25 @@
+mod ai;
+mod bisect;
+mod custom_actions;
 mod file_watcher;
 mod git_progress;
 mod hook_progress;
 mod integrations;
 mod menu;
+mod operation;
+mod search;
+mod signing;
 mod update;
 
+pub use ai::*;
+pub use bisect::*;
+pub use custom_actions::*;
 pub use file_watcher::*;
 pub use git_progress::*;
 pub use hook_progress::*;
 pub use integrations::*;
 pub use menu::*;
+pub use operation::*;
+pub use search::*;
+pub use signing::*;
 pub use update::*;
@@ -0,0 +1,41 @@
+use serde::Serialize;
+use specta::Type;
+use tauri_specta::Event;
+
+use crate::models::OperationState;
+
+/// A continue or skip moved the merge, rebase, cherry-pick or revert in progress on a step
+#[derive(Clone, Serialize, Type, Event)]
+#[serde(rename_all = "camelCase")]
+pub struct OperationStateChangedEvent {
+    pub path: String,
+    pub state: OperationState,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // ==================== OperationStateChangedEvent Tests ====================
+
+    #[test]
+    fn test_operation_state_changed_event_serialization() {
+        let event = OperationStateChangedEvent {
+            path: "/repo".to_string(),
+            state: OperationState::Rebasing {
+                onto: Some("abc123".to_string()),
+                current: Some(3),
+                total: Some(3),
+                paused_action: None,
+                head_name: Some("feature".to_string()),
+                current_commit: None,
+                conflicted_paths: vec!["a.txt".to_string()],
+            },
+        };
+        let json = serde_json::to_string(&event).expect("should serialize");
+        assert!(json.contains("\"path\":\"/repo\""));
+        assert!(json.contains("\"Rebasing\":{"));
+        assert!(json.contains("\"current\":3"));
+        assert!(json.contains("\"conflicted_paths\":[\"a.txt\"]"));
+    }
+}
@@ -0,0 +1,22 @@
+use serde::Serialize;
+use specta::Type;
+use tauri_specta::Event;
+
+use crate::models::GrepMatch;
+
+/// A page of content search matches, streamed while a search is running
+#[derive(Clone, Serialize, Type, Event, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct GrepResultsPageEvent {
+    pub search_id: String,
+    pub matches: Vec<GrepMatch>,
+}
+
+/// Commits analyzed so far by `analyze_commit_messages`; pass `operation_id` to
+/// `cancel_operation` to stop it
+#[derive(Clone, Serialize, Type, Event, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct CommitMessageAnalysisProgressEvent {
+    pub operation_id: String,
+    pub analyzed: usize,
+}
@@ -0,0 +1,57 @@
+use serde::Serialize;
+use specta::Type;
+use tauri_specta::Event;
+
+/// gpg reported progress while generating a key
+#[derive(Clone, Serialize, Type, Event, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct GpgKeyGenerationProgressEvent {
+    /// What gpg is working on, e.g. `primegen` or `need_entropy`
+    pub what: String,
+    pub current: u64,
+    /// 0 when gpg cannot tell how much work is left
+    pub total: u64,
+}
+
+impl GpgKeyGenerationProgressEvent {
+    /// Parse the arguments of a `PROGRESS <what> <char> <cur> <total> [<units>]` status line
+    pub fn from_status(progress: &str) -> Option<Self> {
+        let mut fields = progress.split_whitespace();
+        let what = fields.next()?.to_string();
+        let _char = fields.next()?;
+        Some(Self {
+            what,
+            current: fields.next()?.parse().ok()?,
+            total: fields.next()?.parse().ok()?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // ==================== GpgKeyGenerationProgressEvent Tests ====================
+
+    #[test]
+    fn test_gpg_key_generation_progress_from_status() {
+        let event = GpgKeyGenerationProgressEvent::from_status("need_entropy X 30 120")
+            .expect("should parse");
+        assert_eq!(event.what, "need_entropy");
+        assert_eq!((event.current, event.total), (30, 120));
+
+        assert!(GpgKeyGenerationProgressEvent::from_status("primegen +").is_none());
+    }
+
+    #[test]
+    fn test_gpg_key_generation_progress_serialization() {
+        let event = GpgKeyGenerationProgressEvent {
+            what: "primegen".to_string(),
+            current: 0,
+            total: 0,
+        };
+        let json = serde_json::to_string(&event).expect("should serialize");
+        assert!(json.contains("\"what\":\"primegen\""));
+        assert!(json.contains("\"current\":0"));
+    }
+}
@@ -3,6 +3,9 @@
 #![allow(clippy::must_use_candidate)]
 
 mod commands;
+#[cfg(feature = "integration")]
+pub mod error;
+#[cfg(not(feature = "integration"))]
 mod error;
 mod events;
 mod menu;
@@ -17,6 +20,7 @@ mod services;
 mod state;
 mod storage;
 
+use services::CustomActionsService;
 use state::AppState;
 use storage::Database;
 use tauri::Manager;
@@ -37,30 +41,49 @@ fn get_specta_builder() -> tauri_specta::Builder {
             crate::commands::switch_active_repository,
             crate::commands::close_repository_path,
             crate::commands::get_repository_info,
+            crate::commands::get_broken_head_recoveries,
+            crate::commands::recover_broken_head,
             crate::commands::get_repository_status,
             crate::commands::get_commit_history,
+            crate::commands::get_commit_history_page,
             crate::commands::get_branches,
             crate::commands::get_commit,
+            crate::commands::get_repository_health,
+            crate::commands::acknowledge_sync_folder_warning,
             crate::commands::get_recent_repositories,
+            crate::commands::search_recent_repositories,
+            crate::commands::quick_open_search,
             crate::commands::remove_recent_repository,
             crate::commands::pin_repository,
             crate::commands::unpin_repository,
+            crate::commands::pin_recent_repository,
+            crate::commands::get_recent_repositories_with_status,
             crate::commands::show_in_folder,
+            crate::commands::get_path_copy_forms,
             crate::commands::open_url,
             crate::commands::open_terminal,
+            crate::commands::list_available_terminals,
             crate::commands::cancel_operation,
             // Staging commands
             crate::commands::stage_file,
             crate::commands::stage_files,
             crate::commands::stage_all,
+            crate::commands::set_file_executable,
+            crate::commands::audit_file_modes,
+            crate::commands::fix_file_modes,
             crate::commands::unstage_file,
             crate::commands::unstage_files,
             crate::commands::unstage_all,
             crate::commands::discard_file,
             crate::commands::discard_unstaged,
+            crate::commands::validate_commit_message,
+            crate::commands::check_commit_message_spelling,
+            crate::commands::add_word,
+            crate::commands::analyze_commit_messages,
             crate::commands::create_commit,
             crate::commands::amend_commit,
             crate::commands::get_user_signature,
+            crate::commands::get_recent_co_authors,
             crate::commands::stage_hunk,
             crate::commands::unstage_hunk,
             crate::commands::discard_hunk,
@@ -68,11 +91,20 @@ fn get_specta_builder() -> tauri_specta::Builder {
             crate::commands::check_files_for_lfs,
             // Diff commands
             crate::commands::get_diff,
+            crate::commands::diff_workdir_to_ref,
+            crate::commands::get_file_content_base64,
             crate::commands::get_file_diff,
+            crate::commands::list_diff_presets,
+            crate::commands::save_diff_preset,
+            crate::commands::delete_diff_preset,
+            crate::commands::get_effective_diff_options,
             // Branch commands
             crate::commands::create_branch,
             crate::commands::delete_branch,
             crate::commands::delete_remote_branch,
+            crate::commands::get_stale_branches,
+            crate::commands::archive_branches,
+            crate::commands::bulk_delete_branches,
             crate::commands::rename_branch,
             crate::commands::checkout_branch,
             crate::commands::checkout_remote_branch,
@@ -87,22 +119,32 @@ fn get_specta_builder() -> tauri_specta::Builder {
             crate::commands::rename_remote,
             crate::commands::set_remote_url,
             crate::commands::set_remote_push_url,
+            crate::commands::test_remote_connection,
+            crate::commands::get_remote_branches,
+            crate::commands::get_remote_tags,
             crate::commands::fetch_remote,
             crate::commands::push_remote,
             crate::commands::push_current_branch,
             crate::commands::pull_remote,
             crate::commands::fetch_all,
+            crate::commands::configure_background_fetch,
+            crate::commands::get_background_fetch_status,
             // Graph commands
             crate::commands::build_graph,
             crate::commands::search_commits,
             crate::commands::blame_file,
+            crate::commands::get_blame_line_context,
             crate::commands::get_commit_count,
+            crate::commands::generate_changelog,
             crate::commands::get_file_history,
             crate::commands::get_file_diff_in_commit,
+            crate::commands::get_file_diff_between,
+            crate::commands::resolve_current_path,
             // Merge commands
             crate::commands::merge_branch,
             crate::commands::merge_abort,
             crate::commands::merge_continue,
+            crate::commands::get_merge_preview,
             // Rebase commands
             crate::commands::rebase_branch,
             crate::commands::rebase_onto,
@@ -112,10 +154,16 @@ fn get_specta_builder() -> tauri_specta::Builder {
             crate::commands::get_rebase_preview,
             crate::commands::get_interactive_rebase_preview,
             crate::commands::interactive_rebase,
+            crate::commands::squash_commits,
+            crate::commands::plan_reword_commit,
+            crate::commands::reword_commit,
+            crate::commands::get_interactive_rebase_todo,
+            crate::commands::update_interactive_rebase_todo,
             crate::commands::get_rebase_progress,
             crate::commands::rebase_continue_with_message,
             // Cherry-pick commands
             crate::commands::cherry_pick,
+            crate::commands::get_cherry_pick_preview,
             crate::commands::cherry_pick_abort,
             crate::commands::cherry_pick_continue,
             crate::commands::cherry_pick_skip,
@@ -127,6 +175,10 @@ fn get_specta_builder() -> tauri_specta::Builder {
             crate::commands::get_conflicted_files,
             crate::commands::get_conflict_content,
             crate::commands::resolve_conflict,
+            crate::commands::resolve_conflict_with_content,
+            crate::commands::get_conflict_hunks,
+            crate::commands::resolve_conflict_hunks,
+            crate::commands::merge_notebook_conflict,
             crate::commands::mark_conflict_resolved,
             crate::commands::mark_conflict_unresolved,
             // Operation state
@@ -134,19 +186,29 @@ fn get_specta_builder() -> tauri_specta::Builder {
             // Bisect commands
             crate::commands::bisect_start,
             crate::commands::bisect_mark,
+            crate::commands::bisect_good,
+            crate::commands::bisect_bad,
             crate::commands::bisect_reset,
             crate::commands::bisect_state,
             crate::commands::bisect_log,
             // Reset commands
             crate::commands::reset_to_commit,
+            crate::commands::reset_paths,
+            crate::commands::reset_file_to_commit,
+            // History rewrite commands
+            crate::commands::preview_remove_path_from_history,
+            crate::commands::remove_path_from_unpushed_history,
             // Stash commands
             crate::commands::stash_list,
             crate::commands::stash_save,
+            crate::commands::stash_save_selected_files,
+            crate::commands::stash_selection,
             crate::commands::stash_apply,
             crate::commands::stash_pop,
             crate::commands::stash_drop,
             crate::commands::stash_clear,
             crate::commands::stash_show,
+            crate::commands::get_stash_diff,
             crate::commands::stash_branch,
             // Reflog commands
             crate::commands::reflog_list,
@@ -157,6 +219,8 @@ fn get_specta_builder() -> tauri_specta::Builder {
             crate::commands::tag_list,
             crate::commands::tag_create,
             crate::commands::tag_delete,
+            crate::commands::tag_create_bulk,
+            crate::commands::tag_delete_by_pattern,
             crate::commands::tag_push,
             crate::commands::tag_push_all,
             crate::commands::tag_delete_remote,
@@ -165,6 +229,8 @@ fn get_specta_builder() -> tauri_specta::Builder {
             crate::commands::submodule_add,
             crate::commands::submodule_init,
             crate::commands::submodule_update,
+            crate::commands::get_out_of_sync_submodules,
+            crate::commands::update_out_of_sync_submodules,
             crate::commands::submodule_sync,
             crate::commands::submodule_deinit,
             crate::commands::submodule_remove,
@@ -192,9 +258,17 @@ fn get_specta_builder() -> tauri_specta::Builder {
             crate::commands::gitflow_hotfix_finish,
             crate::commands::gitflow_hotfix_publish,
             crate::commands::gitflow_hotfix_list,
+            // Operation log commands
+            crate::commands::get_operation_log_filtered,
+            crate::commands::export_operation_log,
+            // Undo commands
+            crate::commands::get_undo_history,
+            crate::commands::undo_last_operation,
             // Search commands
             crate::commands::grep_content,
             crate::commands::grep_commit,
+            crate::commands::grep_branch,
+            crate::commands::grep_diff,
             // Settings commands
             crate::commands::get_settings,
             crate::commands::save_settings,
@@ -202,6 +276,11 @@ fn get_specta_builder() -> tauri_specta::Builder {
             crate::commands::get_repository_settings,
             crate::commands::save_repository_user_config,
             crate::commands::save_repository_signing_config,
+            crate::commands::get_repository_config,
+            crate::commands::list_repository_config,
+            crate::commands::set_repository_config,
+            crate::commands::get_performance_profile,
+            crate::commands::set_performance_profile,
             // Hook commands
             crate::commands::list_hooks,
             crate::commands::get_hook,
@@ -209,8 +288,13 @@ fn get_specta_builder() -> tauri_specta::Builder {
             crate::commands::update_hook,
             crate::commands::delete_hook,
             crate::commands::toggle_hook,
+            crate::commands::enable_hook,
+            crate::commands::disable_hook,
+            crate::commands::run_hook,
             crate::commands::get_hook_templates,
             crate::commands::get_hook_templates_for_type,
+            crate::commands::get_hook_trust_request,
+            crate::commands::resolve_hook_trust,
             // Signing commands
             crate::commands::get_signing_config,
             crate::commands::list_gpg_keys,
@@ -218,9 +302,14 @@ fn get_specta_builder() -> tauri_specta::Builder {
             crate::commands::test_signing,
             crate::commands::is_signing_available,
             crate::commands::verify_commit_signature,
+            crate::commands::get_commit_signatures,
+            crate::commands::generate_gpg_key,
+            crate::commands::export_gpg_public_key,
+            crate::commands::configure_signing,
             // Archive & Patch commands
             crate::commands::create_archive,
             crate::commands::format_patch,
+            crate::commands::create_patch_series,
             crate::commands::create_patch,
             crate::commands::apply_patch,
             crate::commands::apply_mailbox,
@@ -232,11 +321,16 @@ fn get_specta_builder() -> tauri_specta::Builder {
             crate::commands::set_ai_api_key,
             crate::commands::has_ai_api_key,
             crate::commands::delete_ai_api_key,
-            crate::commands::test_ai_connection,
-            crate::commands::list_ollama_models,
+            crate::commands::ai_list_models,
+            crate::commands::ai_check_connection,
+            crate::commands::explain_diff,
             crate::commands::generate_pr_description,
+            crate::commands::get_ai_usage,
+            crate::commands::get_ai_queue,
+            crate::commands::cancel_ai_request,
             // Gitignore commands
             crate::commands::add_to_gitignore,
+            crate::commands::bulk_ignore,
             crate::commands::add_to_global_gitignore,
             crate::commands::get_ignore_options,
             // LFS commands
@@ -247,6 +341,7 @@ fn get_specta_builder() -> tauri_specta::Builder {
             crate::commands::lfs_track,
             crate::commands::lfs_untrack,
             crate::commands::lfs_list_patterns,
+            crate::commands::get_lfs_tracked_patterns,
             crate::commands::lfs_list_files,
             crate::commands::lfs_fetch,
             crate::commands::lfs_pull,
@@ -255,12 +350,17 @@ fn get_specta_builder() -> tauri_specta::Builder {
             crate::commands::lfs_env,
             crate::commands::lfs_is_pointer,
             crate::commands::lfs_prune,
+            // Maintenance commands
+            crate::commands::get_repository_size_report,
+            crate::commands::prune_dry_run,
+            crate::commands::run_maintenance,
             // Integration commands
             crate::commands::integration_start_oauth,
             crate::commands::integration_cancel_oauth,
             crate::commands::integration_is_connected,
             crate::commands::integration_get_status,
             crate::commands::integration_disconnect,
+            crate::commands::integration_add_gpg_key,
             crate::commands::integration_detect_provider,
             crate::commands::integration_get_repo_info,
             crate::commands::integration_list_prs,
@@ -273,6 +373,9 @@ fn get_specta_builder() -> tauri_specta::Builder {
             crate::commands::integration_list_labels,
             crate::commands::integration_list_ci_runs,
             crate::commands::integration_get_commit_status,
+            crate::commands::integration_get_branch_ci_rollup,
+            crate::commands::integration_get_current_branch_pr,
+            crate::commands::integration_rerun_ci_run,
             crate::commands::integration_list_notifications,
             crate::commands::integration_mark_notification_read,
             crate::commands::integration_mark_all_notifications_read,
@@ -285,6 +388,7 @@ fn get_specta_builder() -> tauri_specta::Builder {
             crate::commands::delete_ssh_key,
             crate::commands::import_ssh_key,
             crate::commands::export_ssh_key,
+            crate::commands::list_ssh_agent_identities,
             // Per-Remote SSH Key commands
             crate::commands::get_remote_ssh_key,
             crate::commands::set_remote_ssh_key,
@@ -295,19 +399,31 @@ fn get_specta_builder() -> tauri_specta::Builder {
             crate::commands::cache_ssh_passphrase,
             crate::commands::clear_ssh_passphrase,
             crate::commands::is_ssh_passphrase_cached,
+            // HTTPS host credential commands
+            crate::commands::set_host_credential,
+            crate::commands::delete_host_credential,
+            crate::commands::list_credential_hosts,
+            crate::commands::create_bookmark,
+            crate::commands::get_bookmarks,
+            crate::commands::update_bookmark,
+            crate::commands::visit_bookmark,
+            crate::commands::delete_bookmark,
             // Avatar commands
             crate::commands::get_avatar,
+            crate::commands::get_avatars,
             crate::commands::clear_avatar_cache,
             // Custom actions commands
             crate::commands::list_global_actions,
-            crate::commands::save_global_action,
-            crate::commands::delete_global_action,
+            crate::commands::save_custom_action,
+            crate::commands::delete_custom_action,
             crate::commands::list_repo_actions,
             crate::commands::save_repo_action,
             crate::commands::delete_repo_action,
             crate::commands::get_actions_for_context,
             crate::commands::get_all_actions,
             crate::commands::execute_custom_action,
+            crate::commands::list_config_aliases,
+            crate::commands::run_config_alias,
             // Update commands
             crate::commands::check_for_update,
             crate::commands::download_and_install_update,
@@ -321,11 +437,25 @@ fn get_specta_builder() -> tauri_specta::Builder {
             crate::events::HeadChangedEvent,
             crate::events::WatchErrorEvent,
             crate::events::RepositoryDirtyEvent,
+            crate::events::RepositoryChangedEvent,
             crate::events::RemoteFetchedEvent,
+            crate::events::BackgroundFetchCompletedEvent,
+            crate::events::SubmodulesOutOfSyncEvent,
+            crate::events::BisectStepEvent,
+            crate::events::OperationStateChangedEvent,
+            crate::events::GpgKeyGenerationProgressEvent,
             crate::events::OAuthCallbackEvent,
             crate::events::IntegrationStatusChangedEvent,
+            crate::events::PullRequestRefreshedEvent,
+            crate::events::CurrentBranchPullRequestEvent,
             crate::events::GitOperationProgressEvent,
             crate::events::HookProgressEvent,
+            crate::events::HookTrustRequiredEvent,
+            crate::events::CustomActionOutputEvent,
+            crate::events::AiCommitMessageChunkEvent,
+            crate::events::AiExplainChunkEvent,
+            crate::events::GrepResultsPageEvent,
+            crate::events::CommitMessageAnalysisProgressEvent,
             crate::events::UpdateDownloadProgressEvent
         ])
 }
@@ -378,19 +508,24 @@ pub fn run() {
 
             let database = Database::new(&app_data_dir).expect("Failed to initialize database");
 
-            // Get auto_fetch_interval from settings before creating AppState
-            let auto_fetch_interval = database
-                .get_settings()
-                .map(|s| s.auto_fetch_interval)
-                .unwrap_or(5);
+            match CustomActionsService::migrate_global_actions_file(&app_data_dir, &database) {
+                Ok(0) => {}
+                Ok(count) => log::info!("Moved {count} global custom actions into the database"),
+                Err(e) => log::warn!("Failed to migrate global custom actions: {e}"),
+            }
 
             let app_state = AppState::new(database);
 
             // Set the app handle so GitService can create file watchers
             app_state.set_app_handle(app.handle().clone());
 
-            // Start background fetch service with configured interval
-            if let Err(e) = app_state.start_background_fetch(auto_fetch_interval) {
+            // Clean up transient artifacts left behind by crashed runs
+            if let Err(e) = app_state.start_janitor() {
+                log::warn!("Failed to start janitor: {e}");
+            }
+
+            // Start background fetch service
+            if let Err(e) = app_state.start_background_fetch() {
                 log::warn!("Failed to start background fetch service: {e}");
             }
 
@@ -1,3 +1,4 @@
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use specta::Type;
 use strum::{Display, EnumString};
@@ -11,13 +12,72 @@ pub enum AiProvider {
     Ollama,
 }
 
+/// Shape of a generated commit message
+#[derive(
+    Debug, Clone, Copy, Serialize, Deserialize, Type, Default, PartialEq, Eq, Display, EnumString,
+)]
+#[serde(rename_all = "PascalCase")]
+pub enum CommitMessageStyle {
+    /// `type(scope): description` per the Conventional Commits spec
+    Conventional,
+    /// A single imperative subject line
+    #[default]
+    Plain,
+    /// Subject line plus a body explaining what changed and why
+    Detailed,
+}
+
+/// A model offered by an AI provider
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct AiModelInfo {
+    pub name: String,
+    /// Size on disk in bytes, reported by local providers
+    pub size: Option<u64>,
+}
+
+/// Result of a successful provider connection check
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct AiConnectionCheck {
+    /// Round trip time of the check request in milliseconds
+    pub latency_ms: u64,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct GenerateCommitMessageOptions {
+    /// Falls back to the conventional commits setting when not set
+    pub style: Option<CommitMessageStyle>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, Type)]
 #[serde(rename_all = "camelCase")]
 pub struct GenerateCommitMessageResponse {
+    /// The full message, subject and body joined by a blank line
     pub message: String,
+    #[serde(default)]
+    pub subject: String,
+    #[serde(default)]
+    pub body: Option<String>,
     pub model_used: String,
+    /// The staged diff was too large, so only a file summary was sent
+    #[serde(default)]
+    pub from_summary: bool,
 }
 
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct GeneratePrDescriptionOptions {
+    /// Send the branch diff (or a file summary when it is too large); defaults to true
+    #[serde(default)]
+    pub include_diff: Option<bool>,
+    /// Label names the model may suggest from
+    #[serde(default)]
+    pub available_labels: Vec<String>,
+}
+
+/// Generated PR fields, matching `CreatePrOptions` so they can prefill the create dialog
 #[derive(Debug, Clone, Serialize, Deserialize, Type)]
 #[serde(rename_all = "camelCase")]
 pub struct GeneratePrDescriptionResponse {
@@ -25,6 +85,184 @@ pub struct GeneratePrDescriptionResponse {
     pub body: String,
     pub labels: Vec<String>,
     pub model_used: String,
+    /// Issue number parsed from the branch name; the body references it
+    #[serde(default)]
+    pub linked_issue: Option<u64>,
+    /// The branch diff was too large, so only a file summary was sent
+    #[serde(default)]
+    pub from_summary: bool,
+}
+
+/// The part of a diff to explain: one file, or one hunk of it
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct ExplainDiffScope {
+    pub path: String,
+    /// Explain the change `path` got in this commit instead of the working tree
+    #[serde(default)]
+    pub commit_oid: Option<String>,
+    /// Explain the staged change rather than the unstaged one; ignored with `commit_oid`
+    #[serde(default)]
+    pub staged: bool,
+    /// Explain only this hunk of the file diff
+    #[serde(default)]
+    pub hunk_index: Option<u32>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct ExplainDiffResponse {
+    pub explanation: String,
+    pub model_used: String,
+    /// Served from the in-memory cache without querying the model
+    pub cached: bool,
+}
+
+/// Concurrent provider requests allowed before new ones queue
+pub const DEFAULT_AI_CONCURRENT_REQUESTS: usize = 2;
+
+/// Feature that sent a provider request
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Type, Display, EnumString,
+)]
+#[serde(rename_all = "PascalCase")]
+#[strum(serialize_all = "snake_case")]
+pub enum AiFeature {
+    CommitMessage,
+    ExplainDiff,
+    PrDescription,
+}
+
+/// How a recorded provider request ended
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Type, Display, EnumString)]
+#[serde(rename_all = "PascalCase")]
+#[strum(serialize_all = "snake_case")]
+pub enum AiRequestOutcome {
+    Success,
+    Failure,
+    /// Cancelled from the queue while the provider was answering
+    Cancelled,
+}
+
+/// Price of a model in US dollars per million tokens
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct AiModelPrice {
+    /// Model name prefix, so `gpt-4o` also prices `gpt-4o-2024-08-06`
+    pub model: String,
+    pub input_per_million: f64,
+    pub output_per_million: f64,
+}
+
+impl AiModelPrice {
+    pub fn new(model: &str, input_per_million: f64, output_per_million: f64) -> Self {
+        Self {
+            model: model.to_string(),
+            input_per_million,
+            output_per_million,
+        }
+    }
+
+    /// List prices of the hosted models the providers offer by default
+    pub fn defaults() -> Vec<Self> {
+        vec![
+            Self::new("gpt-4o-mini", 0.15, 0.6),
+            Self::new("gpt-4o", 2.5, 10.0),
+            Self::new("gpt-4.1-nano", 0.1, 0.4),
+            Self::new("gpt-4.1-mini", 0.4, 1.6),
+            Self::new("gpt-4.1", 2.0, 8.0),
+            Self::new("o3-mini", 1.1, 4.4),
+            Self::new("o4-mini", 1.1, 4.4),
+            Self::new("claude-3-haiku", 0.25, 1.25),
+            Self::new("claude-3-5-haiku", 0.8, 4.0),
+            Self::new("claude-3-5-sonnet", 3.0, 15.0),
+            Self::new("claude-3-7-sonnet", 3.0, 15.0),
+            Self::new("claude-sonnet-4", 3.0, 15.0),
+            Self::new("claude-opus-4", 15.0, 75.0),
+        ]
+    }
+
+    /// Price of `model` in `prices`, preferring the longest matching prefix
+    pub fn find<'a>(prices: &'a [Self], model: &str) -> Option<&'a Self> {
+        prices
+            .iter()
+            .filter(|price| model.starts_with(&price.model))
+            .max_by_key(|price| price.model.len())
+    }
+
+    /// Cost in US dollars of a request with the given token counts
+    pub fn cost(&self, prompt_tokens: u64, response_tokens: u64) -> f64 {
+        (prompt_tokens as f64 * self.input_per_million
+            + response_tokens as f64 * self.output_per_million)
+            / 1_000_000.0
+    }
+}
+
+/// Filters for aggregating the AI request ledger
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct AiUsageFilter {
+    /// Only requests at or after this time
+    pub from: Option<DateTime<Utc>>,
+    /// Only requests before this time
+    pub to: Option<DateTime<Utc>>,
+    /// Only requests sent by this feature
+    pub feature: Option<AiFeature>,
+    /// Only requests to this model
+    pub model: Option<String>,
+}
+
+/// Requests of one feature to one model on one day (UTC)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct AiUsageBucket {
+    /// Day as `YYYY-MM-DD`
+    pub day: String,
+    pub feature: AiFeature,
+    pub provider: AiProvider,
+    pub model: String,
+    pub requests: u32,
+    pub failures: u32,
+    /// Estimated prompt tokens
+    pub prompt_tokens: u64,
+    /// Response tokens, estimated from the response text
+    pub response_tokens: u64,
+    pub duration_ms: u64,
+    /// Estimated cost in US dollars; `None` when the model has no price
+    pub estimated_cost: Option<f64>,
+}
+
+/// Aggregated AI usage, newest day first
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct AiUsageReport {
+    pub buckets: Vec<AiUsageBucket>,
+    pub total_requests: u32,
+    /// Sum of the priced buckets in US dollars
+    pub total_estimated_cost: f64,
+    /// Hosted models without a price, whose cost is left out of the total
+    pub unpriced_models: Vec<String>,
+}
+
+/// Whether a queued AI request is waiting for a slot or talking to the provider
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "PascalCase")]
+pub enum AiQueueState {
+    Waiting,
+    Running,
+}
+
+/// An AI request waiting for or holding a provider slot
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct AiQueueEntry {
+    /// Identifies the entry for `cancel_ai_request`
+    pub id: u32,
+    pub feature: AiFeature,
+    pub model: String,
+    pub state: AiQueueState,
+    pub queued_at: DateTime<Utc>,
+    pub started_at: Option<DateTime<Utc>>,
 }
 
 #[cfg(test)]
@@ -108,7 +346,10 @@ mod tests {
     fn test_generate_commit_message_response() {
         let response = GenerateCommitMessageResponse {
             message: "feat: add new authentication flow".to_string(),
+            subject: "feat: add new authentication flow".to_string(),
+            body: None,
             model_used: "gpt-4o-mini".to_string(),
+            from_summary: false,
         };
 
         assert!(response.message.starts_with("feat:"));
@@ -119,7 +360,10 @@ mod tests {
     fn test_generate_commit_message_response_serialization() {
         let response = GenerateCommitMessageResponse {
             message: "fix: resolve bug".to_string(),
+            subject: "fix: resolve bug".to_string(),
+            body: None,
             model_used: "claude-3-haiku".to_string(),
+            from_summary: false,
         };
 
         let json = serde_json::to_string(&response).expect("should serialize");
@@ -137,6 +381,15 @@ mod tests {
         assert_eq!(response.model_used, "llama3.2");
     }
 
+    // ==================== CommitMessageStyle Tests ====================
+
+    #[test]
+    fn test_commit_message_style_serialization() {
+        let json = serde_json::to_string(&CommitMessageStyle::Conventional).expect("serialize");
+        assert_eq!(json, "\"Conventional\"");
+        assert_eq!(CommitMessageStyle::default(), CommitMessageStyle::Plain);
+    }
+
     // ==================== GeneratePrDescriptionResponse Tests ====================
 
     #[test]
@@ -146,6 +399,8 @@ mod tests {
             body: "## Summary\nAdds OAuth2 login".to_string(),
             labels: vec!["enhancement".to_string()],
             model_used: "gpt-4o-mini".to_string(),
+            linked_issue: None,
+            from_summary: false,
         };
 
         assert_eq!(response.title, "Add authentication flow");
@@ -161,6 +416,8 @@ mod tests {
             body: "Details".to_string(),
             labels: vec![],
             model_used: "gpt-4o-mini".to_string(),
+            linked_issue: None,
+            from_summary: false,
         };
 
         assert!(response.labels.is_empty());
@@ -173,6 +430,8 @@ mod tests {
             body: "Resolves null pointer".to_string(),
             labels: vec!["bug".to_string()],
             model_used: "claude-3-haiku".to_string(),
+            linked_issue: None,
+            from_summary: false,
         };
 
         let json = serde_json::to_string(&response).expect("should serialize");
@@ -192,5 +451,36 @@ mod tests {
         assert_eq!(response.body, "Details here");
         assert_eq!(response.labels, vec!["enhancement"]);
         assert_eq!(response.model_used, "llama3.2");
+        assert_eq!(response.linked_issue, None);
+        assert!(!response.from_summary);
+    }
+
+    #[test]
+    fn test_generate_pr_description_options_defaults() {
+        let options: GeneratePrDescriptionOptions =
+            serde_json::from_str("{}").expect("should deserialize");
+
+        assert_eq!(options.include_diff, None);
+        assert!(options.available_labels.is_empty());
+    }
+
+    // ==================== AiModelPrice Tests ====================
+
+    #[test]
+    fn test_ai_model_price_prefers_longest_prefix() {
+        let prices = AiModelPrice::defaults();
+
+        let mini = AiModelPrice::find(&prices, "gpt-4o-mini-2024-07-18").expect("should price");
+        assert_eq!(mini.model, "gpt-4o-mini");
+        let full = AiModelPrice::find(&prices, "gpt-4o-2024-08-06").expect("should price");
+        assert_eq!(full.model, "gpt-4o");
+        assert!(AiModelPrice::find(&prices, "llama3.2").is_none());
+    }
+
+    #[test]
+    fn test_ai_model_price_cost() {
+        let price = AiModelPrice::new("model", 3.0, 15.0);
+        let cost = price.cost(1_000_000, 100_000);
+        assert!((cost - 4.5).abs() < 1e-9);
     }
 }
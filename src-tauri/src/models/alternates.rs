@@ -0,0 +1,25 @@
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use std::path::PathBuf;
+
+/// An object store listed in `objects/info/alternates`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct ObjectAlternate {
+    /// Absolute path of the alternate objects directory
+    pub path: PathBuf,
+    /// Whether the directory can be read right now
+    pub reachable: bool,
+}
+
+/// How the open repository shares objects with others
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct ObjectSharing {
+    /// Object stores this repository borrows from
+    pub alternates: Vec<ObjectAlternate>,
+    /// Repositories found borrowing from this one, under the configured shared-store roots
+    pub borrowers: Vec<PathBuf>,
+    /// Why gc, prune or repack are unsafe here; empty when they are safe
+    pub maintenance_warnings: Vec<String>,
+}
@@ -6,6 +6,8 @@ use specta::Type;
 pub enum AvatarSource {
     Integration,
     Gravatar,
+    /// Identicon generated from the email when no service has a picture
+    Generated,
     Default,
 }
 
@@ -14,4 +16,16 @@ pub enum AvatarSource {
 pub struct AvatarResponse {
     pub source: AvatarSource,
     pub path: Option<String>,
+    /// Inline image for generated avatars, which are never written to disk
+    #[serde(default)]
+    pub data_url: Option<String>,
+}
+
+/// Avatar resolved for one email of a batch
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct EmailAvatar {
+    pub email: String,
+    #[serde(flatten)]
+    pub avatar: AvatarResponse,
 }
@@ -0,0 +1,112 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+use super::UpdatedRef;
+
+/// Background fetch settings for one repository
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct BackgroundFetchConfig {
+    pub enabled: bool,
+    /// Minutes between successful fetches
+    pub interval_minutes: u32,
+    /// Prune remote-tracking branches that no longer exist on the remote
+    pub prune: bool,
+    /// Fetch every remote instead of only the current branch's upstream remote
+    pub fetch_all_remotes: bool,
+}
+
+impl BackgroundFetchConfig {
+    /// Settings for a repository without its own configuration, following the global
+    /// `auto_fetch_interval` (0 disables background fetch)
+    pub fn with_default_interval(interval_minutes: u32) -> Self {
+        Self {
+            enabled: interval_minutes > 0,
+            interval_minutes,
+            prune: false,
+            fetch_all_remotes: true,
+        }
+    }
+}
+
+/// Outcome of fetching one remote during a background cycle
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct RemoteFetchOutcome {
+    pub remote: String,
+    pub updated_refs: Vec<UpdatedRef>,
+    pub error: Option<String>,
+}
+
+/// Result of one background fetch of a repository
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct BackgroundFetchResult {
+    /// Remotes that were fetched; remotes needing an interactive credential are left out
+    pub remotes: Vec<RemoteFetchOutcome>,
+    /// Error that prevented fetching any remote
+    pub error: Option<String>,
+}
+
+impl BackgroundFetchResult {
+    /// Whether any remote reported updated refs
+    pub fn has_changes(&self) -> bool {
+        self.remotes.iter().any(|r| !r.updated_refs.is_empty())
+    }
+
+    /// Whether the cycle failed as a whole: nothing could be listed, or every
+    /// fetched remote failed
+    pub fn is_failure(&self) -> bool {
+        self.error.is_some()
+            || (!self.remotes.is_empty() && self.remotes.iter().all(|r| r.error.is_some()))
+    }
+}
+
+/// Background fetch state of a repository
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct BackgroundFetchStatus {
+    pub repo_path: String,
+    pub config: BackgroundFetchConfig,
+    pub last_run: Option<DateTime<Utc>>,
+    pub last_result: Option<BackgroundFetchResult>,
+    /// When the next fetch is due; `None` if disabled or not fetched yet this session
+    pub next_run: Option<DateTime<Utc>>,
+    pub consecutive_failures: u32,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn outcome(remote: &str, error: Option<&str>) -> RemoteFetchOutcome {
+        RemoteFetchOutcome {
+            remote: remote.to_string(),
+            updated_refs: Vec::new(),
+            error: error.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn test_default_config_follows_global_interval() {
+        assert!(BackgroundFetchConfig::with_default_interval(5).enabled);
+        assert!(!BackgroundFetchConfig::with_default_interval(0).enabled);
+    }
+
+    #[test]
+    fn test_result_is_failure_only_when_every_remote_failed() {
+        let partial = BackgroundFetchResult {
+            remotes: vec![outcome("origin", None), outcome("mirror", Some("offline"))],
+            error: None,
+        };
+        assert!(!partial.is_failure());
+
+        let offline = BackgroundFetchResult {
+            remotes: vec![outcome("origin", Some("offline"))],
+            error: None,
+        };
+        assert!(offline.is_failure());
+        assert!(!BackgroundFetchResult::default().is_failure());
+    }
+}
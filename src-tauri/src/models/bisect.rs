@@ -43,6 +43,8 @@ pub struct BisectState {
     pub skipped_commits: Vec<String>,
     /// The first bad commit (when bisect completes)
     pub first_bad_commit: Option<String>,
+    /// Recorded bisect actions from `git bisect log`, oldest first
+    pub log: Vec<String>,
 }
 
 /// Mark type for bisect marking operations
@@ -153,6 +155,7 @@ mod tests {
             good_commits: vec!["good456".to_string()],
             skipped_commits: vec![],
             first_bad_commit: None,
+            log: vec![],
         };
 
         assert!(state.is_active);
@@ -172,6 +175,7 @@ mod tests {
             good_commits: vec!["good456".to_string()],
             skipped_commits: vec!["skip789".to_string()],
             first_bad_commit: Some("culprit".to_string()),
+            log: vec![],
         };
 
         assert!(!state.is_active);
@@ -190,6 +194,7 @@ mod tests {
             good_commits: vec!["good1".to_string(), "good2".to_string()],
             skipped_commits: vec![],
             first_bad_commit: None,
+            log: vec!["git bisect bad bad".to_string()],
         };
 
         let json = serde_json::to_string(&state).expect("should serialize");
@@ -197,6 +202,7 @@ mod tests {
         assert!(json.contains("\"currentCommit\":\"test\""));
         assert!(json.contains("\"stepsRemaining\":5"));
         assert!(json.contains("\"goodCommits\":[\"good1\",\"good2\"]"));
+        assert!(json.contains("\"log\":[\"git bisect bad bad\"]"));
     }
 
     // ==================== BisectResult Tests ====================
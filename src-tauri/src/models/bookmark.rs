@@ -0,0 +1,86 @@
+use super::RecentRepository;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+/// What a bookmark points at
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "PascalCase")]
+pub enum BookmarkTarget {
+    /// A commit, by OID
+    Commit(String),
+    /// A file as of a commit, branch, or tag
+    FileAtRef {
+        path: String,
+        #[serde(rename = "ref")]
+        reference: String,
+    },
+    /// A comparison between two revisions
+    Compare { from: String, to: String },
+    /// Whatever a local branch currently points at
+    BranchHead(String),
+}
+
+/// A pinned commit, file, comparison, or branch in a repository
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct Bookmark {
+    /// Database row ID
+    pub id: i64,
+    pub repo_path: String,
+    pub target: BookmarkTarget,
+    pub label: String,
+    pub notes: Option<String>,
+    pub created_at: DateTime<Utc>,
+    /// When the bookmark was last opened, `None` if never
+    pub last_visited_at: Option<DateTime<Utc>>,
+}
+
+/// Whether a bookmark's target still resolves
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "PascalCase")]
+pub enum BookmarkStatus {
+    Valid,
+    /// The target was broken and has been pointed somewhere that resolves
+    Repaired,
+    Broken,
+}
+
+/// A bookmark together with the result of resolving its target
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct ResolvedBookmark {
+    pub bookmark: Bookmark,
+    pub status: BookmarkStatus,
+    /// Why the target is broken, or what was repaired
+    pub detail: Option<String>,
+}
+
+/// Quick-open matches across all known repositories
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct QuickOpenResults {
+    pub repositories: Vec<RecentRepository>,
+    /// Bookmarks whose label or notes match, from any repository
+    pub bookmarks: Vec<Bookmark>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bookmark_target_serialization() {
+        let target = BookmarkTarget::FileAtRef {
+            path: "src/main.rs".to_string(),
+            reference: "v2.3".to_string(),
+        };
+
+        let json = serde_json::to_string(&target).expect("should serialize");
+        assert_eq!(json, r#"{"FileAtRef":{"path":"src/main.rs","ref":"v2.3"}}"#);
+
+        let branch: BookmarkTarget =
+            serde_json::from_str(r#"{"BranchHead":"main"}"#).expect("should deserialize");
+        assert_eq!(branch, BookmarkTarget::BranchHead("main".to_string()));
+    }
+}
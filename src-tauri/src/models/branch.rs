@@ -2,7 +2,7 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use specta::Type;
 
-use super::{Commit, FileDiff};
+use super::{Commit, FileDiff, Signature};
 
 // Allow field name because `branch_type` is part of the API contract with the frontend.
 // Renaming to `kind` would require coordinated frontend changes.
@@ -50,6 +50,10 @@ pub struct BranchFilter {
     pub sort: BranchSortOrder,
     /// Maximum number of branches to return
     pub limit: Option<usize>,
+    /// Leave `ahead` and `behind` unset instead of comparing each branch with its
+    /// upstream; `get_branches` follows the repository's performance profile when unset
+    #[serde(default)]
+    pub skip_ahead_behind: Option<bool>,
 }
 
 /// Result of comparing two branches
@@ -74,6 +78,70 @@ pub struct BranchCompareResult {
     pub files: Vec<FileDiff>,
 }
 
+/// Criteria for finding stale branches
+#[derive(Debug, Clone, Serialize, Deserialize, Default, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct StaleBranchCriteria {
+    /// Branch used for the merged check and ahead/behind counts (defaults to the current branch)
+    pub base_branch: Option<String>,
+    /// Match branches whose tip commit is older than this many days
+    pub older_than_days: Option<u32>,
+    /// Match branches already merged into the base branch
+    #[serde(default)]
+    pub merged: bool,
+    /// Require every enabled criterion to match instead of any of them
+    #[serde(default)]
+    pub match_all: bool,
+    /// Include remote-tracking branches
+    #[serde(default)]
+    pub include_remote: bool,
+}
+
+/// A branch matching the stale criteria
+#[allow(clippy::struct_field_names)]
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct StaleBranch {
+    pub name: String,
+    pub full_name: String,
+    pub branch_type: BranchType,
+    pub target_oid: String,
+    pub last_commit_summary: String,
+    pub last_commit_time: DateTime<Utc>,
+    pub last_author: Signature,
+    /// Commits on the branch that are not on the base branch
+    pub ahead: usize,
+    /// Commits on the base branch that are not on the branch
+    pub behind: usize,
+    /// The tip is reachable from the base branch
+    pub is_merged: bool,
+    /// The tip is older than `older_than_days`
+    pub is_old: bool,
+}
+
+/// Outcome of archiving or deleting one branch in a bulk operation
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct BranchOperationResult {
+    pub name: String,
+    pub success: bool,
+    /// Tag created for the branch tip when archiving
+    pub tag_name: Option<String>,
+    pub error: Option<String>,
+}
+
+/// Tip a branch is expected to still have when a bulk operation runs, e.g. the
+/// `target_oid` of a `StaleBranch` the user picked
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct ExpectedBranchTarget {
+    pub name: String,
+    pub target_oid: String,
+}
+
+/// Default tag prefix used when archiving branches
+pub const DEFAULT_ARCHIVE_TAG_PREFIX: &str = "archive/";
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -350,4 +418,32 @@ mod tests {
         assert!(json.contains("\"compareOid\":\"bbb\""));
         assert!(json.contains("\"mergeBaseOid\":\"ccc\""));
     }
+
+    // ==================== StaleBranchCriteria Tests ====================
+
+    #[test]
+    fn test_stale_branch_criteria_deserialize_defaults() {
+        let criteria: StaleBranchCriteria =
+            serde_json::from_str(r#"{"baseBranch":null,"olderThanDays":30}"#)
+                .expect("should deserialize");
+
+        assert_eq!(criteria.older_than_days, Some(30));
+        assert!(!criteria.merged);
+        assert!(!criteria.match_all);
+        assert!(!criteria.include_remote);
+    }
+
+    #[test]
+    fn test_branch_operation_result_serialization() {
+        let result = BranchOperationResult {
+            name: "feature".to_string(),
+            success: true,
+            tag_name: Some("archive/feature".to_string()),
+            error: None,
+        };
+
+        let json = serde_json::to_string(&result).expect("should serialize");
+        assert!(json.contains("\"tagName\":\"archive/feature\""));
+        assert!(json.contains("\"error\":null"));
+    }
 }
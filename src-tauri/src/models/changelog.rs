@@ -0,0 +1,120 @@
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+use super::commit::CoAuthor;
+
+/// Options for `generate_changelog`
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct ChangelogOptions {
+    /// Heading of the release; `to_ref` when not set
+    #[serde(default)]
+    pub title: Option<String>,
+    /// Link commits and issues to the web page of the repository's main remote
+    #[serde(default)]
+    pub include_commit_links: bool,
+    /// Credit `Co-authored-by` trailers next to each entry
+    #[serde(default)]
+    pub include_co_authors: bool,
+    /// Leave merge commits out; the commits they merged are listed on their own
+    #[serde(default)]
+    pub collapse_merges: bool,
+}
+
+/// Changelog heading used for commits of one conventional type
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct ChangelogSection {
+    pub commit_type: String,
+    pub heading: String,
+}
+
+impl ChangelogSection {
+    fn new(commit_type: &str, heading: &str) -> Self {
+        Self {
+            commit_type: commit_type.to_string(),
+            heading: heading.to_string(),
+        }
+    }
+
+    /// Sections of the conventional-changelog preset, in its order
+    pub fn defaults() -> Vec<Self> {
+        vec![
+            Self::new("feat", "Features"),
+            Self::new("fix", "Bug Fixes"),
+            Self::new("perf", "Performance Improvements"),
+            Self::new("revert", "Reverts"),
+            Self::new("docs", "Documentation"),
+            Self::new("refactor", "Code Refactoring"),
+        ]
+    }
+}
+
+/// One commit in a generated changelog
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct ChangelogEntry {
+    pub oid: String,
+    pub short_oid: String,
+    /// Conventional type; `None` for commits that do not follow the convention
+    pub commit_type: Option<String>,
+    pub scope: Option<String>,
+    /// Header description, or the whole summary of a non-conventional commit
+    pub description: String,
+    /// Marked with `!` or a `BREAKING CHANGE` footer
+    pub breaking: bool,
+    /// Text of the `BREAKING CHANGE` footer
+    pub breaking_note: Option<String>,
+    /// Issue numbers referenced as `#123` in the header or in `Fixes`/`Closes` trailers
+    pub issues: Vec<u32>,
+    pub author: String,
+    /// Filled only when `ChangelogOptions::include_co_authors` is set
+    pub co_authors: Vec<CoAuthor>,
+    /// Commit page on the provider, when links were requested and the host is known
+    pub commit_url: Option<String>,
+    pub is_merge: bool,
+}
+
+/// Entries under one changelog heading
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct ChangelogGroup {
+    pub heading: String,
+    /// Type the section collects; `None` for the "Other" section
+    pub commit_type: Option<String>,
+    pub entries: Vec<ChangelogEntry>,
+}
+
+/// Changelog of the commits between two refs
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct Changelog {
+    pub title: String,
+    /// Breaking entries, also listed in their own sections
+    pub breaking_changes: Vec<ChangelogEntry>,
+    /// Sections in the configured order, empty ones left out, "Other" last
+    pub groups: Vec<ChangelogGroup>,
+    pub markdown: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_changelog_section_defaults_start_with_features() {
+        let sections = ChangelogSection::defaults();
+        assert_eq!(sections[0], ChangelogSection::new("feat", "Features"));
+        assert_eq!(sections[1].commit_type, "fix");
+    }
+
+    #[test]
+    fn test_changelog_options_deserialize_with_defaults() {
+        let options: ChangelogOptions =
+            serde_json::from_str(r#"{"includeCommitLinks":true}"#).expect("should deserialize");
+        assert!(options.include_commit_links);
+        assert!(!options.include_co_authors);
+        assert!(!options.collapse_merges);
+        assert!(options.title.is_none());
+    }
+}
@@ -118,6 +118,32 @@ pub struct LogOptions {
     pub include_remotes: bool,
     #[serde(default)]
     pub sort_order: SortOrder,
+    /// Only commits committed at or after this time
+    #[serde(default)]
+    pub since: Option<DateTime<Utc>>,
+    /// Only commits committed at or before this time
+    #[serde(default)]
+    pub until: Option<DateTime<Utc>>,
+    /// Only commits whose author name or email contains this, ignoring case
+    #[serde(default)]
+    pub author: Option<String>,
+    /// Only commits whose committer name or email contains this, ignoring case
+    #[serde(default)]
+    pub committer: Option<String>,
+    /// Skip merge commits, like `git log --no-merges`
+    #[serde(default)]
+    pub no_merges: bool,
+    /// Follow only the first parent of merges, like `git log --first-parent`
+    #[serde(default)]
+    pub first_parent: bool,
+    #[serde(default)]
+    pub min_parents: Option<usize>,
+    #[serde(default)]
+    pub max_parents: Option<usize>,
+    /// `next_cursor` from a previous page; when set the walk resumes from it
+    /// and `skip` is ignored
+    #[serde(default)]
+    pub cursor: Option<String>,
 }
 
 fn default_include_remotes() -> bool {
@@ -133,10 +159,69 @@ impl Default for LogOptions {
             branch_filter: BranchFilterType::All,
             include_remotes: true,
             sort_order: SortOrder::DateOrder,
+            since: None,
+            until: None,
+            author: None,
+            committer: None,
+            no_merges: false,
+            first_parent: false,
+            min_parents: None,
+            max_parents: None,
+            cursor: None,
         }
     }
 }
 
+/// One page of commit history
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct CommitHistoryPage {
+    pub commits: Vec<Commit>,
+    /// Opaque cursor for the next page, `None` once history is exhausted
+    pub next_cursor: Option<String>,
+    /// Commits the walk visited before filtering
+    pub scanned: usize,
+    /// Commits that passed the filters, across all pages
+    pub matched: usize,
+}
+
+/// A `Key: value` trailer at the end of a commit message
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct CommitTrailer {
+    pub key: String,
+    pub value: String,
+}
+
+/// A co-author credited with a `Co-authored-by` trailer
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct CoAuthor {
+    pub name: String,
+    pub email: String,
+}
+
+/// Trailers to add to a commit message
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct CommitTrailerOptions {
+    /// Append `Signed-off-by` for the committer
+    #[serde(default)]
+    pub sign_off: bool,
+    /// Append a `Co-authored-by` trailer for each co-author
+    #[serde(default)]
+    pub co_authors: Vec<CoAuthor>,
+    /// Arbitrary trailers, added before co-authors and sign-off
+    #[serde(default)]
+    pub trailers: Vec<CommitTrailer>,
+}
+
+impl CommitTrailerOptions {
+    pub fn is_empty(&self) -> bool {
+        !self.sign_off && self.co_authors.is_empty() && self.trailers.is_empty()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -238,6 +323,7 @@ mod tests {
         assert_eq!(opts.branch_filter, BranchFilterType::All);
         assert!(opts.include_remotes);
         assert_eq!(opts.sort_order, SortOrder::DateOrder);
+        assert_eq!(opts.cursor, None);
     }
 
     #[test]
@@ -249,6 +335,8 @@ mod tests {
             branch_filter: BranchFilterType::Current,
             include_remotes: false,
             sort_order: SortOrder::AncestorOrder,
+            cursor: None,
+            ..LogOptions::default()
         };
 
         assert_eq!(opts.limit, Some(50));
@@ -268,6 +356,11 @@ mod tests {
             branch_filter: BranchFilterType::Current,
             include_remotes: false,
             sort_order: SortOrder::AncestorOrder,
+            since: Some(Utc::now()),
+            author: Some("alice".to_string()),
+            no_merges: true,
+            max_parents: Some(1),
+            ..LogOptions::default()
         };
 
         let json = serde_json::to_string(&opts).expect("should serialize");
@@ -279,6 +372,11 @@ mod tests {
         assert_eq!(deserialized.branch_filter, opts.branch_filter);
         assert_eq!(deserialized.include_remotes, opts.include_remotes);
         assert_eq!(deserialized.sort_order, opts.sort_order);
+        assert_eq!(deserialized.since, opts.since);
+        assert_eq!(deserialized.author, opts.author);
+        assert!(deserialized.no_merges);
+        assert!(!deserialized.first_parent);
+        assert_eq!(deserialized.max_parents, Some(1));
     }
 
     #[test]
@@ -292,6 +390,10 @@ mod tests {
         assert_eq!(opts.branch_filter, BranchFilterType::default());
         assert!(opts.include_remotes); // default_include_remotes returns true
         assert_eq!(opts.sort_order, SortOrder::default());
+        assert_eq!(opts.since, None);
+        assert_eq!(opts.author, None);
+        assert!(!opts.no_merges);
+        assert!(!opts.first_parent);
     }
 
     // ==================== Signature Tests ====================
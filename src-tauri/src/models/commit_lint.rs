@@ -0,0 +1,271 @@
+use crate::models::BranchFilterType;
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+/// Types defined by the Conventional Commits convention, shared with the AI prompt
+pub const CONVENTIONAL_COMMIT_TYPES: &[&str] = &[
+    "feat", "fix", "docs", "style", "refactor", "perf", "test", "build", "ci", "chore", "revert",
+];
+
+/// How a commit lint rule is applied
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq, Type)]
+#[serde(rename_all = "PascalCase")]
+pub enum RuleLevel {
+    Off,
+    #[default]
+    Warning,
+    Error,
+}
+
+impl RuleLevel {
+    /// Severity of a violation at this level; `None` when the rule is off
+    pub fn severity(self) -> Option<LintSeverity> {
+        match self {
+            RuleLevel::Off => None,
+            RuleLevel::Warning => Some(LintSeverity::Warning),
+            RuleLevel::Error => Some(LintSeverity::Error),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Type)]
+#[serde(rename_all = "PascalCase")]
+pub enum LintSeverity {
+    /// Blocks the commit when enforcement is on
+    Error,
+    /// Reported only; never blocks
+    Warning,
+}
+
+/// Commit message rules, named after their commitlint equivalents
+#[derive(
+    Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Hash, Type,
+)]
+#[serde(rename_all = "kebab-case")]
+pub enum CommitLintRuleId {
+    /// The subject starts with one of the configured types
+    TypeEnum,
+    /// The scope, when present, matches the configured pattern
+    ScopePattern,
+    /// The subject line is not longer than the configured length
+    SubjectMaxLength,
+    /// No body line is longer than the configured length
+    BodyMaxLineLength,
+    /// The subject does not end with a period
+    SubjectFullStop,
+    /// A blank line separates the subject from the body
+    BodyLeadingBlank,
+}
+
+/// Levels and values of the commit message rules
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase", default)]
+pub struct CommitLintRules {
+    pub type_enum: RuleLevel,
+    /// Allowed types; the Conventional Commits types by default
+    pub types: Vec<String>,
+    pub scope_pattern: RuleLevel,
+    /// Regex the whole scope must match; any scope is accepted when unset
+    pub scope_regex: Option<String>,
+    pub subject_max_length: RuleLevel,
+    /// Longest subject line, in characters
+    pub max_subject_length: u32,
+    pub body_max_line_length: RuleLevel,
+    /// Longest body line, in characters
+    pub max_body_line_length: u32,
+    pub subject_full_stop: RuleLevel,
+    pub body_leading_blank: RuleLevel,
+}
+
+impl Default for CommitLintRules {
+    fn default() -> Self {
+        Self {
+            type_enum: RuleLevel::Error,
+            types: CONVENTIONAL_COMMIT_TYPES
+                .iter()
+                .map(ToString::to_string)
+                .collect(),
+            scope_pattern: RuleLevel::Off,
+            scope_regex: None,
+            subject_max_length: RuleLevel::Error,
+            max_subject_length: 72,
+            body_max_line_length: RuleLevel::Warning,
+            max_body_line_length: 100,
+            subject_full_stop: RuleLevel::Warning,
+            body_leading_blank: RuleLevel::Warning,
+        }
+    }
+}
+
+/// Commit message validation settings
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase", default)]
+pub struct CommitLintConfig {
+    pub enabled: bool,
+    /// Reject commits whose message has error-level violations
+    pub enforce_on_commit: bool,
+    /// Rules, overridden by those in the repository's `.commitlintrc.json`
+    pub rules: CommitLintRules,
+}
+
+/// A rule the commit message breaks. Lines and columns are 1-based and count
+/// characters, not bytes.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct CommitLintViolation {
+    pub rule: CommitLintRuleId,
+    pub severity: LintSeverity,
+    pub message: String,
+    pub line: u32,
+    pub column: u32,
+    /// Characters covered, starting at `column`
+    pub length: u32,
+}
+
+/// A parsed `type(scope)!: description` subject
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct ConventionalHeader {
+    pub commit_type: String,
+    pub scope: Option<String>,
+    pub breaking: bool,
+    pub description: String,
+}
+
+/// Length of the periods `CommitMessageStats::trend` groups commits by
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq, Type)]
+#[serde(rename_all = "PascalCase")]
+pub enum StatsBucket {
+    /// Weeks starting on Monday, UTC
+    Week,
+    /// Calendar months, UTC
+    #[default]
+    Month,
+}
+
+/// Which commits `analyze_commit_messages` reads and how it reports them
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct CommitMessageStatsOptions {
+    /// Start from a specific ref (overrides `branch_filter`)
+    pub from_ref: Option<String>,
+    #[serde(default)]
+    pub branch_filter: BranchFilterType,
+    #[serde(default = "default_include_remotes")]
+    pub include_remotes: bool,
+    /// Only commits made at or after this time, in seconds since the epoch
+    pub since: Option<i64>,
+    /// Only commits made at or before this time, in seconds since the epoch
+    pub until: Option<i64>,
+    /// Case-insensitive substring of the author's name or email
+    pub author: Option<String>,
+    /// Most commits analyzed; `COMMIT_STATS_DEFAULT_LIMIT` when unset and never
+    /// more than `COMMIT_STATS_MAX_LIMIT`
+    pub limit: Option<usize>,
+    #[serde(default)]
+    pub bucket: StatsBucket,
+    /// Regexes that mark an issue reference; `DEFAULT_ISSUE_PATTERNS` when unset
+    pub issue_patterns: Option<Vec<String>>,
+    /// Most entries in `worst_offenders`; `COMMIT_STATS_DEFAULT_OFFENDERS` when unset
+    pub max_offenders: Option<usize>,
+}
+
+fn default_include_remotes() -> bool {
+    true
+}
+
+impl Default for CommitMessageStatsOptions {
+    fn default() -> Self {
+        Self {
+            from_ref: None,
+            branch_filter: BranchFilterType::default(),
+            include_remotes: true,
+            since: None,
+            until: None,
+            author: None,
+            limit: None,
+            bucket: StatsBucket::default(),
+            issue_patterns: None,
+            max_offenders: None,
+        }
+    }
+}
+
+/// How many linted commits break a rule
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct RuleViolationCount {
+    pub rule: CommitLintRuleId,
+    pub commits: usize,
+}
+
+/// Subjects up to `max_length` characters that are longer than the previous bucket's
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct SubjectLengthBucket {
+    /// Inclusive upper bound; `None` for the last, open-ended bucket
+    pub max_length: Option<u32>,
+    pub commits: usize,
+}
+
+/// Distribution of subject line lengths, in characters
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct SubjectLengthStats {
+    pub average: f64,
+    pub median: u32,
+    pub max: u32,
+    pub buckets: Vec<SubjectLengthBucket>,
+}
+
+/// Commits in one period of `CommitMessageStats::trend`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct StatsTrendBucket {
+    /// Start of the period, in seconds since the epoch
+    pub start: i64,
+    pub commits: usize,
+    pub linted_commits: usize,
+    pub conforming_commits: usize,
+}
+
+/// A commit whose message breaks the rules, for drilling down from the totals
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct CommitMessageOffender {
+    pub oid: String,
+    pub summary: String,
+    pub violations: Vec<CommitLintViolation>,
+}
+
+/// Commit message hygiene across a range of history. Merge, revert and fixup
+/// commits are counted but not linted, like commitlint's default ignores.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct CommitMessageStats {
+    /// Commits that passed the filters
+    pub total_commits: usize,
+    /// Commits checked against the rules
+    pub linted_commits: usize,
+    /// Linted commits without any violation
+    pub conforming_commits: usize,
+    /// Rules broken at least once, most broken first
+    pub rule_violations: Vec<RuleViolationCount>,
+    pub subject_length: SubjectLengthStats,
+    /// Commits with a body after the subject
+    pub with_body: usize,
+    /// Commits whose message matches one of the issue patterns
+    pub with_issue_reference: usize,
+    pub merges: usize,
+    pub reverts: usize,
+    /// `fixup!`, `squash!` and `amend!` commits
+    pub fixups: usize,
+    /// Oldest period first
+    pub trend: Vec<StatsTrendBucket>,
+    /// Linted commits with the most errors, then the most violations
+    pub worst_offenders: Vec<CommitMessageOffender>,
+    /// The walk stopped at the limit before the end of the range
+    pub truncated: bool,
+    /// Served from the cache of earlier analyses
+    pub cached: bool,
+}
@@ -0,0 +1,91 @@
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use strum::{Display, EnumString};
+
+/// Git config file an entry comes from, from least to most specific
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    Serialize,
+    Deserialize,
+    Type,
+    Display,
+    EnumString,
+)]
+#[serde(rename_all = "PascalCase")]
+pub enum ConfigLevel {
+    /// System-wide, e.g. /etc/gitconfig
+    System,
+    /// User-wide, ~/.gitconfig or ~/.config/git/config
+    Global,
+    /// Repository, .git/config
+    Local,
+    /// Worktree, .git/config.worktree
+    Worktree,
+    /// Set by the application for this process
+    App,
+}
+
+impl From<git2::ConfigLevel> for ConfigLevel {
+    fn from(level: git2::ConfigLevel) -> Self {
+        match level {
+            git2::ConfigLevel::ProgramData | git2::ConfigLevel::System => Self::System,
+            git2::ConfigLevel::XDG | git2::ConfigLevel::Global => Self::Global,
+            git2::ConfigLevel::Local => Self::Local,
+            git2::ConfigLevel::Worktree => Self::Worktree,
+            git2::ConfigLevel::App | git2::ConfigLevel::Highest => Self::App,
+        }
+    }
+}
+
+/// A config entry and the file it was read from, like `git config --list --show-origin`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct ConfigEntry {
+    pub key: String,
+    pub value: String,
+    pub level: ConfigLevel,
+    /// `file:<path>` of the config file for the level
+    pub origin: String,
+}
+
+/// Axis feature that does what a git command does natively
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Type, Display, EnumString)]
+#[serde(rename_all = "PascalCase")]
+pub enum NativeCommand {
+    Status,
+    Log,
+    Diff,
+    Fetch,
+    Pull,
+    Push,
+    Stash,
+}
+
+/// A git alias (`alias.<name>`) from the config
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct ConfigAlias {
+    pub name: String,
+    /// The configured value, e.g. `log --graph --oneline` or `!git fetch && git rebase`
+    pub expansion: String,
+    pub level: ConfigLevel,
+    /// Runs through the shell (`!` prefix) and can do anything, so it needs confirmation
+    pub is_shell: bool,
+    /// Git arguments of a non-shell alias, split as git splits them
+    pub args: Vec<String>,
+    /// Refers to positional parameters (`$1`, `$@`), so it expects arguments
+    pub uses_positional_args: bool,
+    /// Axis feature to prefer over running the alias
+    pub native_equivalent: Option<NativeCommand>,
+    /// May change the repository, so views should refresh after it runs
+    pub refresh_after: bool,
+    /// Overridden by a definition at a more specific level
+    pub shadowed: bool,
+}
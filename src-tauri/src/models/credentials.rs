@@ -0,0 +1,20 @@
+use secrecy::SecretString;
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+/// Stored HTTPS credential for a host (internal only, not serializable)
+#[derive(Debug, Clone)]
+pub struct HostCredential {
+    pub username: String,
+    pub token: SecretString,
+}
+
+/// A host with a stored HTTPS credential; the token never leaves the backend
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct CredentialHost {
+    /// Host name, with the port when it is not the default
+    pub host: String,
+    /// Username sent with the token
+    pub username: String,
+}
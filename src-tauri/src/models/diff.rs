@@ -11,12 +11,58 @@ pub struct FileDiff {
     pub new_oid: Option<String>,
     pub status: DiffStatus,
     pub binary: bool,
+    /// Sizes and preview hints, set for binary files only
+    #[serde(default)]
+    pub binary_info: Option<BinaryDiffInfo>,
+    /// How the hunks were built when structured diffs were requested for this file;
+    /// `None` for a plain text diff
+    #[serde(default)]
+    pub structured: Option<StructuredDiffKind>,
     pub hunks: Vec<DiffHunk>,
     /// Summary statistics
     pub additions: usize,
     pub deletions: usize,
 }
 
+/// Source of the hunks of a file diffed as structured data
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "PascalCase")]
+pub enum StructuredDiffKind {
+    /// Jupyter notebook diffed cell by cell; hunk headers name the cell, and line
+    /// numbers count within the cell's source
+    Notebook,
+    /// JSON diffed after pretty-printing both sides with sorted keys
+    Json,
+    /// A side could not be parsed or was too large, so the raw text diff is shown
+    RawFallback,
+}
+
+/// How notebook cell outputs and execution counts appear in structured diffs
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "PascalCase")]
+pub enum NotebookOutputMode {
+    /// Outputs and execution counts never show up as changes
+    Ignore,
+    /// A changed cell output is shown as one summary line per side
+    #[default]
+    Summarize,
+}
+
+/// What is known about a binary file change without reading its content
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct BinaryDiffInfo {
+    /// Size in bytes of the old side; `None` when it does not exist or cannot be read
+    pub old_size: Option<u64>,
+    /// Size in bytes of the new side; `None` when it does not exist or cannot be read
+    pub new_size: Option<u64>,
+    /// Whether the file has an image extension, so both sides can be fetched with
+    /// `get_file_blob` or `get_file_content_base64` and shown side by side
+    pub is_image: bool,
+    /// Both sides have the same blob, so only the file mode changed
+    pub same_content: bool,
+}
+
 /// The type of change for a file in a diff
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Type)]
 #[serde(rename_all = "PascalCase")]
@@ -41,6 +87,10 @@ pub struct DiffHunk {
     pub new_start: u32,
     pub new_lines: u32,
     pub lines: Vec<DiffLine>,
+    /// Enclosing function or type declaration of the first change, found with
+    /// per-language patterns when libgit2's header context is empty or generic
+    #[serde(default)]
+    pub context: Option<String>,
 }
 
 /// A single line within a diff hunk
@@ -51,6 +101,20 @@ pub struct DiffLine {
     pub content: String,
     pub old_line_no: Option<u32>,
     pub new_line_no: Option<u32>,
+    /// Changed and unchanged parts of a paired addition or deletion, when word
+    /// diffs were requested and the line is similar enough to its partner
+    #[serde(default)]
+    pub inline_spans: Option<Vec<InlineSpan>>,
+}
+
+/// Part of a diff line. Offsets count characters (Unicode scalar values), not bytes.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct InlineSpan {
+    pub start: u32,
+    pub len: u32,
+    /// Whether this part differs from the paired line
+    pub changed: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Type)]
@@ -64,7 +128,7 @@ pub enum DiffLineType {
 }
 
 /// Options for generating diffs
-#[derive(Debug, Clone, Default, Serialize, Deserialize, Type)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, Type)]
 #[serde(rename_all = "camelCase")]
 pub struct DiffOptions {
     /// Number of context lines around changes
@@ -73,6 +137,138 @@ pub struct DiffOptions {
     pub ignore_whitespace: Option<bool>,
     /// Ignore whitespace at end of line
     pub ignore_whitespace_eol: Option<bool>,
+    /// Mark the changed parts of paired additions and deletions
+    #[serde(default)]
+    pub compute_word_diff: Option<bool>,
+    /// Extensions (without the dot, case-insensitive) of binary files the viewer can
+    /// render as images; `DEFAULT_IMAGE_EXTENSIONS` when unset
+    #[serde(default)]
+    pub image_extensions: Option<Vec<String>>,
+    /// Fill `DiffHunk::context` with the enclosing function of each hunk
+    #[serde(default)]
+    pub function_context: Option<bool>,
+    /// Diff `.ipynb` notebooks cell by cell, and files with a
+    /// `structured_json_extensions` extension as canonical JSON
+    #[serde(default)]
+    pub structured_diff: Option<bool>,
+    /// Outputs in notebook diffs; `Summarize` when unset
+    #[serde(default)]
+    pub notebook_outputs: Option<NotebookOutputMode>,
+    /// Extensions (without the dot, case-insensitive) of JSON files diffed structurally
+    #[serde(default)]
+    pub structured_json_extensions: Option<Vec<String>>,
+}
+
+/// Binary files flagged as images when `DiffOptions::image_extensions` is unset
+pub const DEFAULT_IMAGE_EXTENSIONS: &[&str] = &[
+    "png", "jpg", "jpeg", "gif", "webp", "bmp", "ico", "avif", "tif", "tiff",
+];
+
+/// Where `get_file_content_base64` reads a file from
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "PascalCase")]
+pub enum FileContentSource {
+    Workdir,
+    Index,
+    /// The file as of a commit, given as an OID or any revision
+    Commit {
+        oid: String,
+    },
+}
+
+/// Raw file content for previews, encoded for transport
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct FileContent {
+    pub content_base64: String,
+    /// Detected from the content, falling back to the extension
+    pub mime_type: String,
+    /// Size in bytes before encoding
+    pub size: u64,
+}
+
+impl DiffOptions {
+    /// Layer these options over `base`: fields set here win, unset fields keep the base value
+    pub fn over(self, base: &DiffOptions) -> DiffOptions {
+        DiffOptions {
+            context_lines: self.context_lines.or(base.context_lines),
+            ignore_whitespace: self.ignore_whitespace.or(base.ignore_whitespace),
+            ignore_whitespace_eol: self.ignore_whitespace_eol.or(base.ignore_whitespace_eol),
+            compute_word_diff: self.compute_word_diff.or(base.compute_word_diff),
+            image_extensions: self
+                .image_extensions
+                .or_else(|| base.image_extensions.clone()),
+            function_context: self.function_context.or(base.function_context),
+            structured_diff: self.structured_diff.or(base.structured_diff),
+            notebook_outputs: self.notebook_outputs.or(base.notebook_outputs),
+            structured_json_extensions: self
+                .structured_json_extensions
+                .or_else(|| base.structured_json_extensions.clone()),
+        }
+    }
+}
+
+/// Where a diff is shown; each context has its own default preset
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "PascalCase")]
+pub enum DiffContext {
+    /// Working tree and staged changes
+    Workdir,
+    /// Commit and commit range diffs
+    Commit,
+    Blame,
+}
+
+pub const DEFAULT_DIFF_PRESET: &str = "Default";
+pub const REVIEW_DIFF_PRESET: &str = "Review";
+
+/// Named diff settings that can be applied in one step
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct DiffPreset {
+    pub name: String,
+    pub options: DiffOptions,
+    /// Collapse generated files such as lockfiles
+    #[serde(default)]
+    pub collapse_generated: bool,
+    /// Highlight changed words within modified lines
+    #[serde(default)]
+    pub intra_line: bool,
+    /// Seeded presets can be edited but not deleted
+    #[serde(default)]
+    pub built_in: bool,
+}
+
+impl DiffPreset {
+    /// Presets seeded into every database
+    pub fn built_ins() -> Vec<DiffPreset> {
+        vec![
+            DiffPreset {
+                name: DEFAULT_DIFF_PRESET.to_string(),
+                options: DiffOptions::default(),
+                collapse_generated: false,
+                intra_line: false,
+                built_in: true,
+            },
+            DiffPreset {
+                name: REVIEW_DIFF_PRESET.to_string(),
+                options: DiffOptions {
+                    context_lines: Some(10),
+                    ignore_whitespace: Some(true),
+                    ignore_whitespace_eol: None,
+                    compute_word_diff: None,
+                    image_extensions: None,
+                    function_context: None,
+                    structured_diff: None,
+                    notebook_outputs: None,
+                    structured_json_extensions: None,
+                },
+                collapse_generated: true,
+                intra_line: true,
+                built_in: true,
+            },
+        ]
+    }
 }
 
 /// Types of diffs we can generate
@@ -91,10 +287,76 @@ pub enum DiffTarget {
     Commit { oid: String },
 }
 
+/// Which side of the local checkout to compare against a ref
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "PascalCase")]
+pub enum WorkdirRefDiffMode {
+    /// Working tree files against the ref, ignoring what is staged
+    WorkdirOnly,
+    /// Staged state against the ref (`git diff --cached <ref>`)
+    IndexOnly,
+    /// Working tree and index together against the ref (`git diff <ref>`)
+    #[default]
+    WorkdirWithIndex,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    // ==================== DiffOptions Tests ====================
+
+    #[test]
+    fn test_diff_options_over_prefers_explicit_fields() {
+        let preset = DiffOptions {
+            context_lines: Some(10),
+            ignore_whitespace: Some(true),
+            ignore_whitespace_eol: Some(true),
+            compute_word_diff: None,
+            image_extensions: None,
+            function_context: None,
+            structured_diff: None,
+            notebook_outputs: None,
+            structured_json_extensions: None,
+        };
+        let explicit = DiffOptions {
+            context_lines: Some(3),
+            ignore_whitespace: Some(false),
+            ignore_whitespace_eol: None,
+            compute_word_diff: None,
+            image_extensions: None,
+            function_context: None,
+            structured_diff: None,
+            notebook_outputs: None,
+            structured_json_extensions: None,
+        };
+
+        let merged = explicit.over(&preset);
+
+        assert_eq!(merged.context_lines, Some(3));
+        assert_eq!(merged.ignore_whitespace, Some(false));
+        assert_eq!(merged.ignore_whitespace_eol, Some(true));
+    }
+
+    #[test]
+    fn test_diff_options_over_empty_keeps_base() {
+        let preset = DiffPreset::built_ins()[1].options.clone();
+
+        assert_eq!(DiffOptions::default().over(&preset), preset);
+    }
+
+    #[test]
+    fn test_built_in_presets() {
+        let presets = DiffPreset::built_ins();
+
+        assert_eq!(presets.len(), 2);
+        assert!(presets.iter().all(|p| p.built_in));
+        assert_eq!(presets[0].name, DEFAULT_DIFF_PRESET);
+        assert_eq!(presets[0].options, DiffOptions::default());
+        assert_eq!(presets[1].name, REVIEW_DIFF_PRESET);
+        assert_eq!(presets[1].options.ignore_whitespace, Some(true));
+    }
+
     // ==================== DiffStatus Tests ====================
 
     #[test]
@@ -182,6 +444,12 @@ mod tests {
             context_lines: Some(5),
             ignore_whitespace: Some(true),
             ignore_whitespace_eol: Some(false),
+            compute_word_diff: None,
+            image_extensions: None,
+            function_context: None,
+            structured_diff: None,
+            notebook_outputs: None,
+            structured_json_extensions: None,
         };
 
         assert_eq!(opts.context_lines, Some(5));
@@ -195,6 +463,12 @@ mod tests {
             context_lines: Some(10),
             ignore_whitespace: Some(true),
             ignore_whitespace_eol: Some(true),
+            compute_word_diff: None,
+            image_extensions: None,
+            function_context: None,
+            structured_diff: None,
+            notebook_outputs: None,
+            structured_json_extensions: None,
         };
 
         let json = serde_json::to_string(&opts).expect("should serialize");
@@ -259,6 +533,7 @@ mod tests {
             content: "    let x = 5;".to_string(),
             old_line_no: Some(10),
             new_line_no: Some(10),
+            inline_spans: None,
         };
 
         assert_eq!(line.line_type, DiffLineType::Context);
@@ -273,6 +548,7 @@ mod tests {
             content: "+    let y = 10;".to_string(),
             old_line_no: None,
             new_line_no: Some(11),
+            inline_spans: None,
         };
 
         assert_eq!(line.line_type, DiffLineType::Addition);
@@ -287,6 +563,7 @@ mod tests {
             content: "-    let z = 15;".to_string(),
             old_line_no: Some(12),
             new_line_no: None,
+            inline_spans: None,
         };
 
         assert_eq!(line.line_type, DiffLineType::Deletion);
@@ -301,6 +578,7 @@ mod tests {
             content: "new content".to_string(),
             old_line_no: None,
             new_line_no: Some(5),
+            inline_spans: None,
         };
 
         let json = serde_json::to_string(&line).expect("should serialize");
@@ -325,14 +603,17 @@ mod tests {
                     content: " context".to_string(),
                     old_line_no: Some(10),
                     new_line_no: Some(10),
+                    inline_spans: None,
                 },
                 DiffLine {
                     line_type: DiffLineType::Addition,
                     content: "+added".to_string(),
                     old_line_no: None,
                     new_line_no: Some(11),
+                    inline_spans: None,
                 },
             ],
+            context: None,
         };
 
         assert_eq!(hunk.old_start, 10);
@@ -351,6 +632,7 @@ mod tests {
             new_start: 1,
             new_lines: 4,
             lines: vec![],
+            context: None,
         };
 
         let json = serde_json::to_string(&hunk).expect("should serialize");
@@ -371,6 +653,8 @@ mod tests {
             new_oid: Some("abc123".to_string()),
             status: DiffStatus::Added,
             binary: false,
+            binary_info: None,
+            structured: None,
             hunks: vec![],
             additions: 10,
             deletions: 0,
@@ -393,6 +677,8 @@ mod tests {
             new_oid: Some("bbb".to_string()),
             status: DiffStatus::Modified,
             binary: false,
+            binary_info: None,
+            structured: None,
             hunks: vec![],
             additions: 5,
             deletions: 3,
@@ -413,6 +699,8 @@ mod tests {
             new_oid: Some("same".to_string()),
             status: DiffStatus::Renamed,
             binary: false,
+            binary_info: None,
+            structured: None,
             hunks: vec![],
             additions: 0,
             deletions: 0,
@@ -431,6 +719,8 @@ mod tests {
             new_oid: Some("new".to_string()),
             status: DiffStatus::Modified,
             binary: true,
+            binary_info: None,
+            structured: None,
             hunks: vec![],
             additions: 0,
             deletions: 0,
@@ -449,6 +739,8 @@ mod tests {
             new_oid: Some("bbb".to_string()),
             status: DiffStatus::Modified,
             binary: false,
+            binary_info: None,
+            structured: None,
             hunks: vec![],
             additions: 10,
             deletions: 5,
@@ -0,0 +1,119 @@
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+/// Kind of suspicious file mode found by the audit
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Type)]
+#[serde(rename_all = "PascalCase")]
+pub enum FileModeIssue {
+    /// Starts with `#!` but is not executable
+    ShebangNotExecutable,
+    /// Script entry point (`.sh`, `.py` with a `__main__` guard, ...) without the executable bit
+    EntryPointNotExecutable,
+    /// Executable, but looks like data or documentation
+    ExecutableDataFile,
+    /// The executable bit was toggled repeatedly in recent history
+    ModeFlipFlop,
+}
+
+/// Options for `audit_file_modes`
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct FileModeAuditOptions {
+    /// Commit to audit (defaults to HEAD)
+    pub revision: Option<String>,
+    /// Number of first-parent commits to scan for mode flip-flops (defaults to 100)
+    pub history_depth: Option<u32>,
+    /// Mode changes within the window that count as a flip-flop (defaults to 2)
+    pub flip_threshold: Option<u32>,
+}
+
+/// A single audit finding
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct FileModeFinding {
+    pub path: String,
+    pub issue: FileModeIssue,
+    /// Whether the file is executable in the audited commit
+    pub executable: bool,
+    /// Mode the fix would apply; `None` if the current mode already looks right
+    pub suggested_executable: Option<bool>,
+    /// Executable bit changes seen in the history window
+    pub mode_changes: u32,
+}
+
+/// Result of a file mode audit
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct FileModeAudit {
+    pub findings: Vec<FileModeFinding>,
+    /// Regular files inspected in the audited tree
+    pub scanned_files: u32,
+    /// Commits walked for flip-flop detection
+    pub scanned_commits: u32,
+}
+
+/// A mode change selected for `fix_file_modes`
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct FileModeFix {
+    pub path: String,
+    pub executable: bool,
+}
+
+/// Result of applying file mode fixes
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct FileModeFixResult {
+    /// Paths whose staged mode changed
+    pub changed: Vec<String>,
+    /// Commit message describing the staged mode changes
+    pub commit_message: String,
+}
+
+impl FileModeFixResult {
+    /// Build the prepared commit message for the applied fixes
+    pub fn commit_message_for(fixes: &[FileModeFix]) -> String {
+        let mut message = String::from("Fix file modes\n");
+        if !fixes.is_empty() {
+            message.push('\n');
+        }
+        for fix in fixes {
+            if fix.executable {
+                message.push_str(&format!("- Make {} executable\n", fix.path));
+            } else {
+                message.push_str(&format!("- Remove executable bit from {}\n", fix.path));
+            }
+        }
+        message
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_file_mode_issue_serialization() {
+        let json = serde_json::to_string(&FileModeIssue::ShebangNotExecutable).expect("serialize");
+        assert_eq!(json, "\"ShebangNotExecutable\"");
+    }
+
+    #[test]
+    fn test_commit_message_for_fixes() {
+        let fixes = vec![
+            FileModeFix {
+                path: "build.sh".to_string(),
+                executable: true,
+            },
+            FileModeFix {
+                path: "README.md".to_string(),
+                executable: false,
+            },
+        ];
+
+        assert_eq!(
+            FileModeFixResult::commit_message_for(&fixes),
+            "Fix file modes\n\n- Make build.sh executable\n- Remove executable bit from README.md\n"
+        );
+    }
+}
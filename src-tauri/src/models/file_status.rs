@@ -10,6 +10,40 @@ pub struct FileStatus {
     pub unstaged_status: Option<StatusType>,
     pub is_conflict: bool,
     pub old_path: Option<String>,
+    #[serde(default)]
+    pub kind: FileStatusKind,
+    /// Untracked files inside a collapsed `Directory` entry
+    #[serde(default)]
+    pub file_count: Option<usize>,
+}
+
+/// What a status entry refers to
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq, Type)]
+#[serde(rename_all = "PascalCase")]
+pub enum FileStatusKind {
+    #[default]
+    File,
+    /// An untracked directory shown as one entry; its path ends with `/`
+    Directory,
+}
+
+/// Options for `get_repository_status`
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct RepositoryStatusOptions {
+    /// List every untracked file instead of collapsing untracked directories
+    #[serde(default)]
+    pub recurse_untracked: bool,
+}
+
+#[cfg(any(test, feature = "integration"))]
+impl RepositoryStatusOptions {
+    /// List every untracked file individually
+    pub fn recursive() -> Self {
+        Self {
+            recurse_untracked: true,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Type)]
@@ -54,6 +88,8 @@ impl FileStatus {
             unstaged_status,
             is_conflict,
             old_path,
+            kind: FileStatusKind::File,
+            file_count: None,
         }
     }
 
@@ -170,6 +206,8 @@ mod tests {
             unstaged_status: Some(StatusType::Untracked),
             is_conflict: false,
             old_path: None,
+            kind: FileStatusKind::File,
+            file_count: None,
         };
 
         assert_eq!(status.path, "new_file.rs");
@@ -189,6 +227,8 @@ mod tests {
             unstaged_status: None,
             is_conflict: false,
             old_path: None,
+            kind: FileStatusKind::File,
+            file_count: None,
         };
 
         assert_eq!(status.status, StatusType::Added);
@@ -205,6 +245,8 @@ mod tests {
             unstaged_status: Some(StatusType::Modified),
             is_conflict: false,
             old_path: None,
+            kind: FileStatusKind::File,
+            file_count: None,
         };
 
         assert_eq!(status.status, StatusType::Modified);
@@ -221,6 +263,8 @@ mod tests {
             unstaged_status: None,
             is_conflict: false,
             old_path: Some("old_name.rs".to_string()),
+            kind: FileStatusKind::File,
+            file_count: None,
         };
 
         assert_eq!(status.status, StatusType::Renamed);
@@ -236,6 +280,8 @@ mod tests {
             unstaged_status: None,
             is_conflict: true,
             old_path: None,
+            kind: FileStatusKind::File,
+            file_count: None,
         };
 
         assert_eq!(status.status, StatusType::Conflicted);
@@ -251,6 +297,8 @@ mod tests {
             unstaged_status: None,
             is_conflict: false,
             old_path: None,
+            kind: FileStatusKind::File,
+            file_count: None,
         };
 
         let json = serde_json::to_string(&status).expect("should serialize");
@@ -300,6 +348,8 @@ mod tests {
                 unstaged_status: None,
                 is_conflict: false,
                 old_path: None,
+                kind: FileStatusKind::File,
+                file_count: None,
             }],
             unstaged: vec![FileStatus {
                 path: "unstaged.rs".to_string(),
@@ -308,6 +358,8 @@ mod tests {
                 unstaged_status: Some(StatusType::Modified),
                 is_conflict: false,
                 old_path: None,
+                kind: FileStatusKind::File,
+                file_count: None,
             }],
             untracked: vec![FileStatus {
                 path: "new.rs".to_string(),
@@ -316,6 +368,8 @@ mod tests {
                 unstaged_status: Some(StatusType::Untracked),
                 is_conflict: false,
                 old_path: None,
+                kind: FileStatusKind::File,
+                file_count: None,
             }],
             conflicted: vec![],
         };
@@ -338,6 +392,8 @@ mod tests {
                 unstaged_status: Some(StatusType::Untracked),
                 is_conflict: false,
                 old_path: None,
+                kind: FileStatusKind::File,
+                file_count: None,
             }],
             conflicted: vec![],
         };
@@ -1,3 +1,4 @@
+use crate::models::DiffLine;
 use serde::{Deserialize, Serialize};
 use specta::Type;
 use strum::{AsRefStr, Display};
@@ -29,6 +30,18 @@ impl Default for GitFlowConfig {
     }
 }
 
+impl GitFlowConfig {
+    /// Branch name prefix for `branch_type`
+    pub fn prefix(&self, branch_type: GitFlowBranchType) -> &str {
+        match branch_type {
+            GitFlowBranchType::Feature => &self.feature_prefix,
+            GitFlowBranchType::Release => &self.release_prefix,
+            GitFlowBranchType::Hotfix => &self.hotfix_prefix,
+            GitFlowBranchType::Support => &self.support_prefix,
+        }
+    }
+}
+
 /// Options for initializing git-flow
 #[derive(Debug, Clone, Serialize, Deserialize, Default, Type)]
 #[serde(rename_all = "camelCase")]
@@ -110,33 +123,78 @@ pub struct GrepOptions {
     pub ignore_case: bool,
     #[serde(default)]
     pub word_regexp: bool,
+    /// Treat the pattern as a regular expression; otherwise it is matched literally
     #[serde(default)]
     pub extended_regexp: bool,
     #[serde(default)]
     pub invert_match: bool,
     #[serde(default)]
     pub show_line_numbers: bool,
+    /// Maximum matches per file
     #[serde(default)]
     pub max_count: Option<u32>,
     #[serde(default)]
     pub context_lines: Option<u32>,
+    /// Glob patterns a path must match, e.g. `*.rs`
+    #[serde(default)]
+    pub include: Vec<String>,
+    /// Glob patterns excluding paths, e.g. `vendor/**`
+    #[serde(default)]
+    pub exclude: Vec<String>,
+    /// Maximum matches in total; the result reports `has_more` when capped
+    #[serde(default)]
+    pub max_results: Option<u32>,
+    /// Commit, branch or tag whose tree is searched instead of the working tree
+    #[serde(default, rename = "ref")]
+    pub git_ref: Option<String>,
+}
+
+/// Byte range of a match within a line
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct GrepMatchRange {
+    pub start: usize,
+    pub end: usize,
 }
 
 /// A single grep match
-#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Type)]
 #[serde(rename_all = "camelCase")]
 pub struct GrepMatch {
     pub path: String,
     pub line_number: Option<usize>,
     pub content: String,
+    /// Where the pattern matched in `content`; empty for inverted matches
+    #[serde(default)]
+    pub ranges: Vec<GrepMatchRange>,
+    #[serde(default)]
+    pub context_before: Vec<String>,
+    #[serde(default)]
+    pub context_after: Vec<String>,
 }
 
 /// Result of a grep search
-#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Type)]
 #[serde(rename_all = "camelCase")]
 pub struct GrepResult {
     pub matches: Vec<GrepMatch>,
     pub total_matches: usize,
+    /// The search stopped at `max_results` with more matches left
+    #[serde(default)]
+    pub has_more: bool,
+    /// Binary files that were not searched
+    #[serde(default)]
+    pub skipped_binary_files: usize,
+}
+
+/// Lines of one staged diff hunk that match a `grep_diff` pattern
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct GrepDiffMatch {
+    pub file: String,
+    pub hunk_header: String,
+    /// Matching added and context lines, in hunk order
+    pub matching_lines: Vec<DiffLine>,
 }
 
 #[cfg(test)]
@@ -175,6 +233,16 @@ mod tests {
         assert_eq!(config.version_tag_prefix, "v");
     }
 
+    #[test]
+    fn test_git_flow_config_prefix() {
+        let config = GitFlowConfig::default();
+
+        assert_eq!(config.prefix(GitFlowBranchType::Feature), "feature/");
+        assert_eq!(config.prefix(GitFlowBranchType::Release), "release/");
+        assert_eq!(config.prefix(GitFlowBranchType::Hotfix), "hotfix/");
+        assert_eq!(config.prefix(GitFlowBranchType::Support), "support/");
+    }
+
     #[test]
     fn test_git_flow_config_serialization() {
         let config = GitFlowConfig::default();
@@ -383,6 +451,7 @@ mod tests {
             show_line_numbers: true,
             max_count: Some(100),
             context_lines: Some(3),
+            ..Default::default()
         };
 
         assert_eq!(opts.pattern, "TODO");
@@ -411,6 +480,7 @@ mod tests {
             path: "src/main.rs".to_string(),
             line_number: Some(42),
             content: "// TODO: fix this".to_string(),
+            ..Default::default()
         };
 
         assert_eq!(match_.path, "src/main.rs");
@@ -424,6 +494,7 @@ mod tests {
             path: "README.md".to_string(),
             line_number: None,
             content: "Some content".to_string(),
+            ..Default::default()
         };
 
         assert!(match_.line_number.is_none());
@@ -435,6 +506,7 @@ mod tests {
             path: "test.rs".to_string(),
             line_number: Some(10),
             content: "match".to_string(),
+            ..Default::default()
         };
 
         let json = serde_json::to_string(&match_).expect("should serialize");
@@ -449,6 +521,7 @@ mod tests {
         let result = GrepResult {
             matches: vec![],
             total_matches: 0,
+            ..Default::default()
         };
 
         assert!(result.matches.is_empty());
@@ -463,14 +536,17 @@ mod tests {
                     path: "a.rs".to_string(),
                     line_number: Some(1),
                     content: "match1".to_string(),
+                    ..Default::default()
                 },
                 GrepMatch {
                     path: "b.rs".to_string(),
                     line_number: Some(2),
                     content: "match2".to_string(),
+                    ..Default::default()
                 },
             ],
             total_matches: 2,
+            ..Default::default()
         };
 
         assert_eq!(result.matches.len(), 2);
@@ -482,6 +558,7 @@ mod tests {
         let result = GrepResult {
             matches: vec![],
             total_matches: 5,
+            ..Default::default()
         };
 
         let json = serde_json::to_string(&result).expect("should serialize");
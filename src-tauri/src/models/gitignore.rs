@@ -13,6 +13,8 @@ pub enum IgnoreSuggestionType {
     Directory,
     /// File name anywhere (e.g., "bar.txt")
     FileName,
+    /// Directory name anywhere (e.g., "build/")
+    DirectoryName,
 }
 
 /// A suggested pattern for ignoring a file
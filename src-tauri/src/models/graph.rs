@@ -1,8 +1,10 @@
+use crate::error::{AxisError, Result};
 use serde::{Deserialize, Serialize};
 use specta::Type;
 use std::collections::HashMap;
+use std::path::Path;
 
-use super::{BranchFilterType, Commit, SortOrder};
+use super::{BranchFilterType, Commit, DiffHunk, FileDiff, SortOrder};
 
 /// A commit with graph layout information for visualization
 #[derive(Debug, Clone, Serialize, Deserialize, Type)]
@@ -17,6 +19,10 @@ pub struct GraphCommit {
     pub parent_edges: Vec<GraphEdge>,
     /// Branch/tag refs pointing to this commit
     pub refs: Vec<CommitRef>,
+    /// Kept only to connect commits matching `GraphOptions::author_filter`; the
+    /// message is left out
+    #[serde(default)]
+    pub filtered: bool,
 }
 
 /// An edge connecting a commit to its parent in the graph
@@ -61,6 +67,11 @@ pub enum RefType {
     LocalBranch,
     RemoteBranch,
     Tag,
+    /// A stash entry, `stash@{index}`
+    Stash {
+        index: usize,
+        message: String,
+    },
 }
 
 /// Options for graph generation
@@ -88,6 +99,17 @@ pub struct GraphOptions {
     /// Whether to include uncommitted changes as first entry
     #[serde(default)]
     pub include_uncommitted: bool,
+    /// Show stash entries on the commits they were made from. Their index and
+    /// untracked-files commits are left out.
+    #[serde(default)]
+    pub include_stashes: bool,
+    /// Keep commits whose author name or email contains this (case-insensitive).
+    /// Other commits between two kept ones are returned with `filtered` set.
+    #[serde(default)]
+    pub author_filter: Option<String>,
+    /// Follow only the first parent of merges, like `git log --first-parent`
+    #[serde(default)]
+    pub first_parent: bool,
 }
 
 fn default_include_remotes() -> bool {
@@ -105,6 +127,9 @@ impl Default for GraphOptions {
             include_remotes: true,
             sort_order: SortOrder::default(),
             include_uncommitted: false,
+            include_stashes: false,
+            author_filter: None,
+            first_parent: false,
         }
     }
 }
@@ -195,6 +220,113 @@ pub struct FileLogResult {
     pub has_more: bool,
 }
 
+/// Maximum number of intermediate commits returned when comparing a file between refs
+pub const FILE_COMPARE_MAX_COMMITS: usize = 100;
+
+/// A file compared between two refs, with the commits in between that changed it
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct FileCompareResult {
+    /// Diff from `from_ref` to `to_ref`, or `None` when the file is identical
+    pub diff: Option<FileDiff>,
+    /// Resolved commit for `from_ref`
+    pub from_oid: String,
+    /// Resolved commit for `to_ref`
+    pub to_oid: String,
+    /// Path of the file at `from_ref` (differs from `to_path` when renamed in between)
+    pub from_path: Option<String>,
+    /// Path of the file at `to_ref`
+    pub to_path: Option<String>,
+    /// The file does not exist at `from_ref` and was created in the range
+    pub created_in_range: bool,
+    /// The file exists at `from_ref` and was deleted in the range
+    pub deleted_in_range: bool,
+    /// Commits in `from_ref..to_ref` that changed the file, newest first
+    pub commits: Vec<Commit>,
+    /// Whether more commits changed the file than were returned
+    pub has_more_commits: bool,
+}
+
+/// Maximum number of commits walked when resolving where a historical path lives now
+pub const PATH_RESOLVE_MAX_COMMITS: usize = 5000;
+
+/// Where a path that existed at some ref lives in the working tree now
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct ResolvedPath {
+    /// Current repository-relative path, or the last known one if the file was deleted
+    pub path: String,
+    /// Whether the file exists in the working tree at `path`
+    pub exists: bool,
+    /// Whether the file was renamed or moved since the ref
+    pub renamed: bool,
+    /// Commit that deleted the file, if it was deleted after being last renamed
+    pub deleted_in: Option<String>,
+    /// Set when `path` now lies inside a submodule
+    pub submodule: Option<SubmodulePathLocation>,
+    /// The walk stopped at `PATH_RESOLVE_MAX_COMMITS` before reaching HEAD
+    pub truncated: bool,
+}
+
+/// A path inside a submodule of the repository
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct SubmodulePathLocation {
+    /// Path of the submodule in the superproject
+    pub submodule_path: String,
+    /// Path of the file relative to the submodule root
+    pub relative_path: String,
+}
+
+/// Forms of a repository path suitable for copying to the clipboard
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct PathCopyForms {
+    /// Absolute path with the platform's separators
+    pub absolute: String,
+    /// Repository-relative path with forward slashes
+    pub repo_relative: String,
+    /// `file://` URL of the absolute path
+    pub file_url: String,
+}
+
+impl PathCopyForms {
+    pub fn new(repo_root: &Path, relative_path: &str) -> Result<Self> {
+        let repo_relative = relative_path
+            .replace('\\', "/")
+            .trim_matches('/')
+            .to_string();
+        let absolute = repo_relative
+            .split('/')
+            .filter(|part| !part.is_empty())
+            .fold(repo_root.to_path_buf(), |path, part| path.join(part));
+        let file_url = url::Url::from_file_path(&absolute)
+            .map_err(|()| {
+                AxisError::Other(format!(
+                    "Cannot build a file URL for {}",
+                    absolute.display()
+                ))
+            })?
+            .to_string();
+
+        Ok(PathCopyForms {
+            absolute: absolute.display().to_string(),
+            repo_relative,
+            file_url,
+        })
+    }
+}
+
+/// What `show_in_folder` showed in the file manager
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct ShowInFolderResult {
+    /// Absolute path that was shown
+    pub path: String,
+    /// The requested path no longer exists, so its nearest existing folder was opened
+    pub fallback: bool,
+}
+
 /// Blame information for a file
 #[derive(Debug, Clone, Serialize, Deserialize, Type)]
 #[serde(rename_all = "camelCase")]
@@ -219,12 +351,34 @@ pub struct BlameLine {
     pub timestamp: chrono::DateTime<chrono::Utc>,
     /// The actual line content
     pub content: String,
-    /// Original line number in the commit
+    /// Line number in the file as of the blamed commit
     pub original_line: usize,
     /// Whether this is the first line of a group from the same commit
     pub is_group_start: bool,
 }
 
+/// What a blamed commit did to one line, for the blame popover
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct BlameLineContext {
+    pub commit: Commit,
+    /// Path of the file in the blamed commit
+    pub path: String,
+    /// Line number in the blamed commit's version of the file
+    pub line_number: u32,
+    /// Hunk of the commit's diff of this file containing, or nearest to, the line
+    pub hunk: Option<DiffHunk>,
+    /// Path of the file in the parent; differs from `path` when the commit renamed it,
+    /// `None` when the commit created the file
+    pub previous_path: Option<String>,
+    /// The line as it read before the commit; `None` if the commit added it
+    pub previous_content: Option<String>,
+    /// Parent to re-run blame at for "blame before this commit"
+    pub parent_oid: Option<String>,
+    /// Line in `previous_path` at `parent_oid` corresponding to this line
+    pub previous_line_number: Option<u32>,
+}
+
 /// Internal structure for tracking lane assignments during graph building
 #[derive(Debug, Clone)]
 pub struct LaneState {
@@ -684,6 +838,16 @@ mod tests {
         assert_eq!(json, "\"LocalBranch\"");
     }
 
+    #[test]
+    fn test_ref_type_stash_serialization() {
+        let stash = RefType::Stash {
+            index: 1,
+            message: "WIP on main".to_string(),
+        };
+        let json = serde_json::to_string(&stash).expect("should serialize RefType");
+        assert_eq!(json, r#"{"Stash":{"index":1,"message":"WIP on main"}}"#);
+    }
+
     #[test]
     fn test_graph_edge_serialization() {
         let edge = GraphEdge {
@@ -721,6 +885,9 @@ mod tests {
             include_remotes: false,
             sort_order: SortOrder::AncestorOrder,
             include_uncommitted: true,
+            include_stashes: true,
+            author_filter: Some("jane".to_string()),
+            first_parent: true,
         };
 
         let json = serde_json::to_string(&opts).expect("should serialize GraphOptions");
@@ -735,6 +902,9 @@ mod tests {
         assert!(!deserialized.include_remotes);
         assert_eq!(deserialized.sort_order, SortOrder::AncestorOrder);
         assert!(deserialized.include_uncommitted);
+        assert!(deserialized.include_stashes);
+        assert_eq!(deserialized.author_filter, Some("jane".to_string()));
+        assert!(deserialized.first_parent);
     }
 
     #[test]
@@ -777,6 +947,27 @@ mod tests {
         assert!(deserialized.follow_renames);
     }
 
+    #[test]
+    fn test_file_compare_result_serialization() {
+        let result = FileCompareResult {
+            diff: None,
+            from_oid: "abc".to_string(),
+            to_oid: "def".to_string(),
+            from_path: Some("old.rs".to_string()),
+            to_path: None,
+            created_in_range: false,
+            deleted_in_range: true,
+            commits: vec![],
+            has_more_commits: false,
+        };
+
+        let json = serde_json::to_string(&result).expect("should serialize FileCompareResult");
+        assert!(json.contains("\"fromPath\":\"old.rs\""));
+        assert!(json.contains("\"toPath\":null"));
+        assert!(json.contains("\"deletedInRange\":true"));
+        assert!(json.contains("\"hasMoreCommits\":false"));
+    }
+
     // ==================== Complex Scenario Tests ====================
 
     #[test]
@@ -818,4 +1009,20 @@ mod tests {
         let p2_lane = state.get_parent_lane("P2");
         assert!(p2_lane >= 1);
     }
+
+    // ==================== PathCopyForms Tests ====================
+
+    #[test]
+    fn test_path_copy_forms() {
+        let root = std::env::temp_dir().join("axis repo");
+        let forms = PathCopyForms::new(&root, "src\\my file.rs").expect("should build forms");
+
+        assert_eq!(forms.repo_relative, "src/my file.rs");
+        assert_eq!(
+            forms.absolute,
+            root.join("src").join("my file.rs").display().to_string()
+        );
+        assert!(forms.file_url.starts_with("file:///"));
+        assert!(forms.file_url.ends_with("/axis%20repo/src/my%20file.rs"));
+    }
 }
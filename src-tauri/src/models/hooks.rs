@@ -19,6 +19,8 @@ pub enum GitHookType {
     PostCommit,
     /// Runs before push
     PrePush,
+    /// Runs before a merge commit is created
+    PreMergeCommit,
     /// Runs after merge
     PostMerge,
     /// Runs before rebase
@@ -39,6 +41,7 @@ impl GitHookType {
                 | Self::PrepareCommitMsg
                 | Self::CommitMsg
                 | Self::PrePush
+                | Self::PreMergeCommit
                 | Self::PreRebase
         )
     }
@@ -63,6 +66,12 @@ pub struct HookResult {
     /// Whether the hook was cancelled by user
     #[serde(default)]
     pub cancelled: bool,
+    /// Whether the hook was skipped because the user chose never to trust it
+    #[serde(default)]
+    pub untrusted: bool,
+    /// Set when the hook did not run because the user has not decided whether to trust it
+    #[serde(default)]
+    pub trust_request: Option<HookTrustRequest>,
 }
 
 impl HookResult {
@@ -76,6 +85,8 @@ impl HookResult {
             stderr: String::new(),
             skipped: true,
             cancelled: false,
+            untrusted: false,
+            trust_request: None,
         }
     }
 
@@ -90,6 +101,8 @@ impl HookResult {
             stderr: "Hook exists but is not executable".to_string(),
             skipped: true,
             cancelled: false,
+            untrusted: false,
+            trust_request: None,
         }
     }
 
@@ -103,6 +116,8 @@ impl HookResult {
             stderr: message.to_string(),
             skipped: false,
             cancelled: false,
+            untrusted: false,
+            trust_request: None,
         }
     }
 
@@ -116,6 +131,32 @@ impl HookResult {
             stderr: "Hook cancelled by user".to_string(),
             skipped: false,
             cancelled: true,
+            untrusted: false,
+            trust_request: None,
+        }
+    }
+
+    /// Create a result for a hook the user chose never to trust (treated as not present)
+    pub fn untrusted(hook_type: GitHookType) -> Self {
+        Self {
+            untrusted: true,
+            stderr: "Hook skipped: repository hooks are not trusted".to_string(),
+            ..Self::skipped(hook_type)
+        }
+    }
+
+    /// Create a result for a hook that is waiting on a trust decision
+    pub fn trust_required(hook_type: GitHookType, request: HookTrustRequest) -> Self {
+        Self {
+            hook_type,
+            success: false,
+            exit_code: -1,
+            stdout: String::new(),
+            stderr: "Hook not run: waiting for trust decision".to_string(),
+            skipped: false,
+            cancelled: false,
+            untrusted: false,
+            trust_request: Some(request),
         }
     }
 
@@ -126,6 +167,9 @@ impl HookResult {
 
     /// Convert a failed hook result into an `AxisError`
     pub fn to_error(&self) -> crate::error::AxisError {
+        if let Some(request) = &self.trust_request {
+            return crate::error::AxisError::HookTrustRequired(request.clone());
+        }
         let output = if !self.stderr.is_empty() {
             self.stderr.clone()
         } else if !self.stdout.is_empty() {
@@ -144,6 +188,43 @@ impl HookResult {
     }
 }
 
+/// User decision on whether a repository's hooks may run
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Type, Display, EnumString)]
+#[serde(rename_all = "PascalCase")]
+#[strum(serialize_all = "snake_case")]
+pub enum HookTrustDecision {
+    /// Run this hook content now and in the future
+    TrustAlways,
+    /// Run this hook content for the next invocation only
+    TrustOnce,
+    /// Never run this hook content; invocations are skipped
+    Never,
+}
+
+/// A hook file shown in a trust prompt
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct HookFingerprint {
+    pub hook_type: GitHookType,
+    /// Full path to the hook file
+    pub path: String,
+    /// File size in bytes
+    pub size: u64,
+    /// SHA-256 of the file content; decisions are keyed by it so edits re-prompt
+    pub content_hash: String,
+}
+
+/// Trust prompt raised before any undecided hook from a repository runs
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct HookTrustRequest {
+    pub repo_path: String,
+    /// The hook that was about to run, or `None` when the prompt was requested up front
+    pub hook_type: Option<GitHookType>,
+    /// Every hook in the repository that has no decision for its current content
+    pub hooks: Vec<HookFingerprint>,
+}
+
 /// Hook info for management UI
 #[derive(Debug, Clone, Serialize, Deserialize, Type)]
 #[serde(rename_all = "camelCase")]
@@ -353,6 +434,7 @@ mod tests {
         assert_eq!(GitHookType::CommitMsg.to_string(), "commit-msg");
         assert_eq!(GitHookType::PostCommit.to_string(), "post-commit");
         assert_eq!(GitHookType::PrePush.to_string(), "pre-push");
+        assert_eq!(GitHookType::PreMergeCommit.to_string(), "pre-merge-commit");
         assert_eq!(GitHookType::PostMerge.to_string(), "post-merge");
         assert_eq!(GitHookType::PreRebase.to_string(), "pre-rebase");
         assert_eq!(GitHookType::PostCheckout.to_string(), "post-checkout");
@@ -381,6 +463,7 @@ mod tests {
         assert!(GitHookType::PrepareCommitMsg.can_abort());
         assert!(GitHookType::CommitMsg.can_abort());
         assert!(GitHookType::PrePush.can_abort());
+        assert!(GitHookType::PreMergeCommit.can_abort());
         assert!(GitHookType::PreRebase.can_abort());
 
         assert!(!GitHookType::PostCommit.can_abort());
@@ -455,6 +538,8 @@ mod tests {
             stderr: String::new(),
             skipped: false,
             cancelled: false,
+            untrusted: false,
+            trust_request: None,
         };
 
         let json = serde_json::to_string(&result).expect("should serialize");
@@ -634,8 +719,45 @@ mod tests {
         use strum::IntoEnumIterator;
 
         let types: Vec<GitHookType> = GitHookType::iter().collect();
-        assert_eq!(types.len(), 9);
+        assert_eq!(types.len(), 10);
         assert!(types.contains(&GitHookType::PreCommit));
         assert!(types.contains(&GitHookType::PostRewrite));
     }
+
+    // ==================== Hook Trust Tests ====================
+
+    #[test]
+    fn test_hook_trust_decision_storage_names() {
+        assert_eq!(HookTrustDecision::TrustAlways.to_string(), "trust_always");
+        assert_eq!(
+            "never".parse::<HookTrustDecision>().expect("should parse"),
+            HookTrustDecision::Never
+        );
+    }
+
+    #[test]
+    fn test_hook_result_untrusted_is_skip() {
+        let result = HookResult::untrusted(GitHookType::PreCommit);
+
+        assert!(result.skipped);
+        assert!(result.success);
+        assert!(result.untrusted);
+    }
+
+    #[test]
+    fn test_hook_result_trust_required_converts_to_error() {
+        let request = HookTrustRequest {
+            repo_path: "/repo".to_string(),
+            hook_type: Some(GitHookType::PreCommit),
+            hooks: vec![],
+        };
+        let result = HookResult::trust_required(GitHookType::PreCommit, request.clone());
+
+        assert!(!result.success);
+        assert!(!result.skipped);
+        match result.to_error() {
+            crate::error::AxisError::HookTrustRequired(r) => assert_eq!(r, request),
+            other => panic!("unexpected error: {other}"),
+        }
+    }
 }
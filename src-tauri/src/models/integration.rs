@@ -51,7 +51,13 @@ pub struct PullRequest {
     pub state: PrState,
     pub author: IntegrationUser,
     pub source_branch: String,
+    /// `owner/repo` the source branch lives in; differs from the PR's repo for forks
+    #[serde(default)]
+    pub source_repo: Option<String>,
     pub target_branch: String,
+    /// Commit the source branch points at on the provider
+    #[serde(default)]
+    pub head_sha: Option<String>,
     pub draft: bool,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
@@ -70,12 +76,36 @@ pub struct PullRequestDetail {
     pub changed_files: u32,
     pub commits_count: u32,
     pub comments_count: u32,
+    /// `None` while the provider is still computing mergeability (e.g. right after a push)
     pub mergeable: Option<bool>,
     pub labels: Vec<IntegrationLabel>,
     pub assignees: Vec<IntegrationUser>,
     pub reviewers: Vec<IntegrationUser>,
 }
 
+/// Pull request opened from the checked-out branch, or what the "create pull request"
+/// action needs when there is none
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct CurrentBranchPullRequest {
+    pub provider: ProviderType,
+    /// Repository the pull request was found in, or the one a new pull request would target
+    pub owner: String,
+    pub repo: String,
+    /// `None` on a detached HEAD
+    pub branch: Option<String>,
+    pub pull_request: Option<PullRequest>,
+    /// Local commits missing from the pull request head; `None` without a pull request or
+    /// when its head commit has not been fetched
+    pub ahead: Option<usize>,
+    /// Pull request head commits missing locally
+    pub behind: Option<usize>,
+    /// Upstream of the local branch, e.g. `origin/feature`
+    pub upstream: Option<String>,
+    /// Whether the branch exists on a remote, required before a pull request can be created
+    pub pushed: bool,
+}
+
 /// Options for creating a pull request
 #[derive(Debug, Clone, Serialize, Deserialize, Type)]
 #[serde(rename_all = "camelCase")]
@@ -247,6 +277,157 @@ pub struct CommitStatus {
     pub total_count: u32,
 }
 
+/// Protection rules for a remote branch
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct BranchProtection {
+    pub protected: bool,
+    pub allows_force_pushes: bool,
+    pub allows_deletions: bool,
+    /// Status check contexts that must pass before merging
+    pub required_status_checks: Vec<String>,
+}
+
+impl BranchProtection {
+    /// A branch with no protection rules
+    pub fn unprotected() -> Self {
+        Self {
+            protected: false,
+            allows_force_pushes: true,
+            allows_deletions: true,
+            required_status_checks: Vec::new(),
+        }
+    }
+}
+
+/// Remote operations that branch protection can forbid
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProtectedBranchAction {
+    ForcePush,
+    Delete,
+}
+
+/// Maximum number of failing checks reported in a CI rollup
+pub const CI_ROLLUP_MAX_FAILING: usize = 5;
+
+/// Overall CI state for a branch tip
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "PascalCase")]
+pub enum CiRollupState {
+    /// Every finished check passed
+    Passing,
+    /// At least one check failed
+    Failing,
+    /// Nothing failed yet but some checks are still queued or running
+    Running,
+    /// The provider reports no checks for the tip
+    NoChecks,
+    /// The local tip is not on the remote yet, so it has no CI of its own
+    NotPushed,
+    /// The provider's API rate limit was hit; try again later
+    RateLimited,
+}
+
+/// A failing check surfaced in the rollup
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct CiFailingCheck {
+    pub name: String,
+    pub url: String,
+    /// Workflow run that produced the check, when it can be re-run
+    pub run_id: Option<String>,
+}
+
+/// Aggregated CI status for the current branch's head commit
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct CiRollup {
+    pub branch: Option<String>,
+    pub sha: String,
+    pub state: CiRollupState,
+    pub passed: u32,
+    pub failed: u32,
+    pub running: u32,
+    /// Skipped or neutral checks
+    pub skipped: u32,
+    /// Up to `CI_ROLLUP_MAX_FAILING` failing checks
+    pub failing_checks: Vec<CiFailingCheck>,
+}
+
+impl CiRollup {
+    fn empty(branch: Option<String>, sha: String, state: CiRollupState) -> Self {
+        Self {
+            branch,
+            sha,
+            state,
+            passed: 0,
+            failed: 0,
+            running: 0,
+            skipped: 0,
+            failing_checks: Vec::new(),
+        }
+    }
+
+    /// Rollup for a tip that has not been pushed
+    pub fn not_pushed(branch: Option<String>, sha: String) -> Self {
+        Self::empty(branch, sha, CiRollupState::NotPushed)
+    }
+
+    /// Rollup when the provider refused the request because of rate limiting
+    pub fn rate_limited(branch: Option<String>, sha: String) -> Self {
+        Self::empty(branch, sha, CiRollupState::RateLimited)
+    }
+
+    /// Count checks by state
+    pub fn from_status(branch: Option<String>, sha: String, status: &CommitStatus) -> Self {
+        let mut rollup = Self::empty(branch, sha, CiRollupState::NoChecks);
+
+        for check in &status.checks {
+            if check.status != CIRunStatus::Completed {
+                rollup.running += 1;
+                continue;
+            }
+            match check.conclusion {
+                Some(CIConclusion::Success) => rollup.passed += 1,
+                Some(
+                    CIConclusion::Failure
+                    | CIConclusion::Cancelled
+                    | CIConclusion::TimedOut
+                    | CIConclusion::ActionRequired,
+                ) => {
+                    rollup.failed += 1;
+                    if rollup.failing_checks.len() < CI_ROLLUP_MAX_FAILING {
+                        rollup.failing_checks.push(CiFailingCheck {
+                            name: check.name.clone(),
+                            url: check.url.clone(),
+                            run_id: workflow_run_id(&check.url),
+                        });
+                    }
+                }
+                Some(CIConclusion::Skipped | CIConclusion::Neutral) | None => rollup.skipped += 1,
+            }
+        }
+
+        rollup.state = if rollup.failed > 0 {
+            CiRollupState::Failing
+        } else if rollup.running > 0 {
+            CiRollupState::Running
+        } else if rollup.passed > 0 {
+            CiRollupState::Passing
+        } else {
+            CiRollupState::NoChecks
+        };
+        rollup
+    }
+}
+
+/// Extract the workflow run ID from a check URL (`.../actions/runs/<id>/job/<job>`)
+fn workflow_run_id(url: &str) -> Option<String> {
+    let rest = url.split("/actions/runs/").nth(1)?;
+    let id: String = rest.chars().take_while(char::is_ascii_digit).collect();
+    (!id.is_empty()).then_some(id)
+}
+
 /// Notification reason
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Type, EnumString)]
 #[serde(rename_all = "PascalCase")]
@@ -296,6 +477,50 @@ pub struct Notification {
     pub url: String,
 }
 
+/// Number of unread notifications for a single reason
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct NotificationReasonCount {
+    pub reason: NotificationReason,
+    pub count: u32,
+}
+
+/// Unread notification count with a per-reason breakdown
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct UnreadNotificationCount {
+    /// Exact number of unread notifications
+    pub total: u32,
+    /// Unread notifications per reason, most frequent first
+    pub by_reason: Vec<NotificationReasonCount>,
+    /// Whether the breakdown stopped at the page cap and covers fewer than `total`
+    pub capped: bool,
+}
+
+impl UnreadNotificationCount {
+    /// Count one more unread notification with the given reason
+    pub fn add(&mut self, reason: NotificationReason) {
+        self.total = self.total.saturating_add(1);
+        match self.by_reason.iter_mut().find(|c| c.reason == reason) {
+            Some(entry) => entry.count = entry.count.saturating_add(1),
+            None => self
+                .by_reason
+                .push(NotificationReasonCount { reason, count: 1 }),
+        }
+    }
+
+    /// Remove one unread notification (reason unknown only adjusts the total)
+    pub fn decrement(&mut self, reason: Option<NotificationReason>) {
+        self.total = self.total.saturating_sub(1);
+        if let Some(reason) = reason {
+            if let Some(entry) = self.by_reason.iter_mut().find(|c| c.reason == reason) {
+                entry.count = entry.count.saturating_sub(1);
+            }
+            self.by_reason.retain(|c| c.count > 0);
+        }
+    }
+}
+
 /// User from integration provider
 #[derive(Debug, Clone, Serialize, Deserialize, Type)]
 #[serde(rename_all = "camelCase")]
@@ -684,6 +909,59 @@ mod tests {
         assert!(page.has_more);
     }
 
+    // ==================== UnreadNotificationCount Tests ====================
+
+    #[test]
+    fn test_unread_count_add() {
+        let mut count = UnreadNotificationCount::default();
+        count.add(NotificationReason::Mention);
+        count.add(NotificationReason::ReviewRequested);
+        count.add(NotificationReason::Mention);
+
+        assert_eq!(count.total, 3);
+        assert_eq!(
+            count.by_reason,
+            vec![
+                NotificationReasonCount {
+                    reason: NotificationReason::Mention,
+                    count: 2,
+                },
+                NotificationReasonCount {
+                    reason: NotificationReason::ReviewRequested,
+                    count: 1,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_unread_count_decrement() {
+        let mut count = UnreadNotificationCount::default();
+        count.add(NotificationReason::Mention);
+        count.add(NotificationReason::CiActivity);
+
+        count.decrement(Some(NotificationReason::Mention));
+        assert_eq!(count.total, 1);
+        assert_eq!(count.by_reason.len(), 1);
+        assert_eq!(count.by_reason[0].reason, NotificationReason::CiActivity);
+
+        // Unknown reason only touches the total, which never underflows
+        count.decrement(None);
+        count.decrement(None);
+        assert_eq!(count.total, 0);
+    }
+
+    #[test]
+    fn test_unread_count_serialization() {
+        let mut count = UnreadNotificationCount::default();
+        count.add(NotificationReason::ReviewRequested);
+        let json = serde_json::to_string(&count).expect("serialize");
+        assert_eq!(
+            json,
+            r#"{"total":1,"byReason":[{"reason":"ReviewRequested","count":1}],"capped":false}"#
+        );
+    }
+
     // ==================== CreatePrOptions Tests ====================
 
     #[test]
@@ -786,4 +1064,113 @@ mod tests {
         assert!(opts.labels.is_empty());
         assert!(opts.body.is_none());
     }
+
+    // ==================== CiRollup Tests ====================
+
+    fn check(name: &str, status: CIRunStatus, conclusion: Option<CIConclusion>) -> CIRun {
+        CIRun {
+            provider: ProviderType::GitHub,
+            id: name.to_string(),
+            name: name.to_string(),
+            status,
+            conclusion,
+            commit_sha: "abc".to_string(),
+            branch: None,
+            event: "check_run".to_string(),
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+            url: format!("https://github.com/o/r/actions/runs/42/job/{name}"),
+        }
+    }
+
+    fn status_of(checks: Vec<CIRun>) -> CommitStatus {
+        CommitStatus {
+            state: CommitStatusState::Pending,
+            total_count: 0,
+            checks,
+        }
+    }
+
+    #[test]
+    fn test_ci_rollup_counts_mixed_states() {
+        let status = status_of(vec![
+            check("lint", CIRunStatus::Completed, Some(CIConclusion::Success)),
+            check("test", CIRunStatus::Completed, Some(CIConclusion::Failure)),
+            check("e2e", CIRunStatus::InProgress, None),
+            check("docs", CIRunStatus::Completed, Some(CIConclusion::Skipped)),
+            check(
+                "build",
+                CIRunStatus::Completed,
+                Some(CIConclusion::TimedOut),
+            ),
+        ]);
+
+        let rollup = CiRollup::from_status(Some("main".to_string()), "abc".to_string(), &status);
+
+        assert_eq!(rollup.state, CiRollupState::Failing);
+        assert_eq!(
+            (rollup.passed, rollup.failed, rollup.running, rollup.skipped),
+            (1, 2, 1, 1)
+        );
+        let names: Vec<_> = rollup
+            .failing_checks
+            .iter()
+            .map(|c| c.name.as_str())
+            .collect();
+        assert_eq!(names, vec!["test", "build"]);
+        assert_eq!(rollup.failing_checks[0].run_id.as_deref(), Some("42"));
+    }
+
+    #[test]
+    fn test_ci_rollup_state_precedence() {
+        let running = status_of(vec![
+            check("lint", CIRunStatus::Completed, Some(CIConclusion::Success)),
+            check("test", CIRunStatus::Queued, None),
+        ]);
+        let passing = status_of(vec![check(
+            "lint",
+            CIRunStatus::Completed,
+            Some(CIConclusion::Success),
+        )]);
+
+        assert_eq!(
+            CiRollup::from_status(None, "a".to_string(), &running).state,
+            CiRollupState::Running
+        );
+        assert_eq!(
+            CiRollup::from_status(None, "a".to_string(), &passing).state,
+            CiRollupState::Passing
+        );
+        assert_eq!(
+            CiRollup::from_status(None, "a".to_string(), &status_of(vec![])).state,
+            CiRollupState::NoChecks
+        );
+    }
+
+    #[test]
+    fn test_ci_rollup_caps_failing_checks() {
+        let checks = (0..8)
+            .map(|i| {
+                check(
+                    &format!("job{i}"),
+                    CIRunStatus::Completed,
+                    Some(CIConclusion::Failure),
+                )
+            })
+            .collect();
+
+        let rollup = CiRollup::from_status(None, "a".to_string(), &status_of(checks));
+
+        assert_eq!(rollup.failed, 8);
+        assert_eq!(rollup.failing_checks.len(), CI_ROLLUP_MAX_FAILING);
+    }
+
+    #[test]
+    fn test_workflow_run_id_from_url() {
+        assert_eq!(
+            workflow_run_id("https://github.com/o/r/actions/runs/123/job/456"),
+            Some("123".to_string())
+        );
+        assert_eq!(workflow_run_id("https://ci.example.com/build/9"), None);
+    }
 }
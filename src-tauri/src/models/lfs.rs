@@ -15,6 +15,12 @@ pub struct LfsStatus {
     pub tracked_patterns_count: usize,
     /// Number of LFS files in the repository
     pub lfs_files_count: usize,
+    /// LFS files whose objects are missing from the local store (incomplete clone or pull)
+    #[serde(default)]
+    pub missing_objects: Vec<String>,
+    /// LFS files whose objects are present in the local store
+    #[serde(default)]
+    pub cached_objects: Vec<String>,
 }
 
 /// A tracked LFS pattern
@@ -77,6 +83,9 @@ pub struct LfsFetchOptions {
 pub struct LfsPullOptions {
     /// Remote to pull from
     pub remote: Option<String>,
+    /// Only pull objects for paths matching these patterns (all objects when empty)
+    #[serde(default)]
+    pub include: Vec<String>,
 }
 
 /// Options for LFS push operation
@@ -229,6 +238,8 @@ mod tests {
             is_initialized: true,
             tracked_patterns_count: 5,
             lfs_files_count: 100,
+            missing_objects: vec![],
+            cached_objects: vec![],
         };
 
         assert!(status.is_installed);
@@ -246,6 +257,8 @@ mod tests {
             is_initialized: false,
             tracked_patterns_count: 0,
             lfs_files_count: 0,
+            missing_objects: vec![],
+            cached_objects: vec![],
         };
 
         assert!(!status.is_installed);
@@ -260,12 +273,23 @@ mod tests {
             is_initialized: true,
             tracked_patterns_count: 3,
             lfs_files_count: 50,
+            missing_objects: vec![],
+            cached_objects: vec![],
         };
 
         let json = serde_json::to_string(&status).expect("should serialize");
         assert!(json.contains("\"isInstalled\":true"));
         assert!(json.contains("\"isInitialized\":true"));
         assert!(json.contains("\"trackedPatternsCount\":3"));
+        assert!(json.contains("\"missingObjects\":[]"));
+    }
+
+    #[test]
+    fn test_lfs_status_deserialize_without_object_lists() {
+        let json = r#"{"isInstalled":true,"version":null,"isInitialized":true,"trackedPatternsCount":1,"lfsFilesCount":2}"#;
+        let status: LfsStatus = serde_json::from_str(json).expect("should deserialize");
+        assert!(status.missing_objects.is_empty());
+        assert!(status.cached_objects.is_empty());
     }
 
     // ==================== LfsTrackedPattern Tests ====================
@@ -410,8 +434,17 @@ mod tests {
     fn test_lfs_pull_options_with_remote() {
         let opts = LfsPullOptions {
             remote: Some("upstream".to_string()),
+            include: vec!["assets/**".to_string()],
         };
         assert_eq!(opts.remote, Some("upstream".to_string()));
+        assert_eq!(opts.include, vec!["assets/**"]);
+    }
+
+    #[test]
+    fn test_lfs_pull_options_include_defaults_to_empty() {
+        let opts: LfsPullOptions =
+            serde_json::from_str(r#"{"remote":null}"#).expect("should deserialize");
+        assert!(opts.include.is_empty());
     }
 
     // ==================== LfsPushOptions Tests ====================
@@ -0,0 +1,134 @@
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+/// On-disk size of a repository's own object store (alternates are not included)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct ObjectStoreSize {
+    pub loose_objects: u64,
+    /// Bytes used by loose object files
+    pub loose_size: u64,
+    pub packs: u64,
+    /// Objects listed in the pack index files
+    pub packed_objects: u64,
+    /// Bytes used by pack and pack index files
+    pub pack_size: u64,
+}
+
+impl ObjectStoreSize {
+    pub fn total_size(&self) -> u64 {
+        self.loose_size + self.pack_size
+    }
+}
+
+/// One of the largest blobs in the repository
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct LargeBlob {
+    pub oid: String,
+    /// Uncompressed size in bytes
+    pub size: u64,
+    /// Path of the blob in HEAD's tree; `None` when HEAD does not contain it
+    pub path: Option<String>,
+}
+
+/// Number of reflog entries kept for one ref
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct ReflogSize {
+    pub ref_name: String,
+    pub entries: usize,
+}
+
+/// Result of `get_repository_size_report`
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct RepositorySizeReport {
+    pub objects: ObjectStoreSize,
+    /// Largest blobs, biggest first
+    pub largest_blobs: Vec<LargeBlob>,
+    /// Refs with reflog entries, most entries first
+    pub reflogs: Vec<ReflogSize>,
+}
+
+/// A step of `run_maintenance`, run in the order given
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "PascalCase")]
+pub enum MaintenanceTask {
+    /// `git gc`
+    Gc,
+    /// `git prune`: delete unreachable loose objects
+    Prune,
+    /// `git repack -a -d`: combine all packs into one
+    Repack,
+    /// `git commit-graph write --reachable`
+    CommitGraph,
+}
+
+/// Options for `run_maintenance`
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct MaintenanceOptions {
+    pub tasks: Vec<MaintenanceTask>,
+    /// Pass `--aggressive` to gc (much slower, smaller packs)
+    #[serde(default)]
+    pub aggressive: bool,
+}
+
+/// Result of `run_maintenance`
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct MaintenanceResult {
+    /// Tasks that ran, in order
+    pub tasks: Vec<MaintenanceTask>,
+    pub before: ObjectStoreSize,
+    pub after: ObjectStoreSize,
+}
+
+/// A loose object `git prune` would delete
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct UnreachableObject {
+    pub oid: String,
+    /// Object type as reported by git (`blob`, `tree`, `commit` or `tag`)
+    pub object_type: String,
+    /// Bytes used by the loose object file
+    pub size: u64,
+}
+
+/// Result of `prune_dry_run`
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct PruneDryRunResult {
+    pub objects: Vec<UnreachableObject>,
+    /// Bytes that pruning would free
+    pub total_size: u64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_object_store_total_size() {
+        let size = ObjectStoreSize {
+            loose_objects: 3,
+            loose_size: 300,
+            packs: 1,
+            packed_objects: 10,
+            pack_size: 1000,
+        };
+        assert_eq!(size.total_size(), 1300);
+    }
+
+    #[test]
+    fn test_maintenance_options_deserialize_defaults() {
+        let options: MaintenanceOptions =
+            serde_json::from_str(r#"{"tasks":["Gc","CommitGraph"]}"#).expect("should parse");
+        assert_eq!(
+            options.tasks,
+            vec![MaintenanceTask::Gc, MaintenanceTask::CommitGraph]
+        );
+        assert!(!options.aggressive);
+    }
+}
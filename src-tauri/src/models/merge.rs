@@ -2,7 +2,9 @@ use serde::{Deserialize, Serialize};
 use specta::Type;
 use strum::{Display, EnumString};
 
-use super::commit::Commit;
+use super::commit::{Commit, CommitTrailerOptions};
+use super::diff::FileDiff;
+use super::rewrite::RewritePreconditions;
 
 /// Options for merge operations
 // Allow excessive bools: these map directly to git merge CLI flags
@@ -22,6 +24,41 @@ pub struct MergeOptions {
     pub ff_only: bool,
     /// If true, don't create a commit (stage changes only)
     pub no_commit: bool,
+    /// Trailers for the merge commit
+    #[serde(default)]
+    pub trailers: CommitTrailerOptions,
+    /// Strategy or strategy option; git's default (`ort`) when unset
+    #[serde(default)]
+    pub strategy: Option<MergeStrategy>,
+}
+
+/// Merge strategy, or conflict-resolution option of the default strategy
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Type)]
+#[serde(rename_all = "PascalCase")]
+pub enum MergeStrategy {
+    /// `--strategy=ours`: record the merge but keep the current branch's tree as is
+    OursStrategy,
+    /// `--strategy-option=ours`: merge, taking the current branch's side of conflicting hunks
+    Ours,
+    /// `--strategy-option=theirs`: merge, taking the merged branch's side of conflicting hunks
+    Theirs,
+    /// `--strategy=recursive`
+    Recursive,
+    /// `--strategy=octopus`, for merging more than two heads
+    Octopus,
+}
+
+impl MergeStrategy {
+    /// Argument passed to `git merge`
+    pub fn cli_arg(self) -> &'static str {
+        match self {
+            Self::OursStrategy => "--strategy=ours",
+            Self::Ours => "--strategy-option=ours",
+            Self::Theirs => "--strategy-option=theirs",
+            Self::Recursive => "--strategy=recursive",
+            Self::Octopus => "--strategy=octopus",
+        }
+    }
 }
 
 /// Result of a merge operation
@@ -110,6 +147,39 @@ pub struct RebasePreview {
     pub target: RebaseTarget,
     /// Number of commits on target since merge-base
     pub target_commits_ahead: usize,
+    /// HEAD and the target as they were when the preview was computed
+    pub preconditions: RewritePreconditions,
+}
+
+/// Preview data for a merge operation, computed without touching the working tree
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct MergePreview {
+    /// Commits that would be merged (reachable from source but not from HEAD)
+    pub incoming_commits: Vec<Commit>,
+    /// The merge-base commit, if the histories are related
+    pub merge_base: Option<Commit>,
+    /// Source branch/commit info
+    pub source: RebaseTarget,
+    /// Whether HEAD already contains the source
+    pub is_up_to_date: bool,
+    /// Whether the merge would be a fast-forward
+    pub is_fast_forward: bool,
+    /// Files that would conflict
+    pub conflicts: Vec<ConflictedFile>,
+    /// Changes the merge would apply cleanly on top of HEAD
+    pub diff: Vec<FileDiff>,
+}
+
+/// Preview of cherry-picking a list of commits onto HEAD, computed without touching
+/// the working tree
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct CherryPickPreview {
+    /// Commits that would be picked, in order
+    pub commits: Vec<Commit>,
+    /// Paths expected to conflict, in the order they are first hit
+    pub estimated_conflicts: Vec<String>,
 }
 
 /// Target information for rebase preview
@@ -190,6 +260,10 @@ pub struct InteractiveRebaseOptions {
     pub entries: Vec<InteractiveRebaseEntry>,
     /// Whether to autosquash fixup! commits
     pub autosquash: bool,
+    /// Preconditions of the preview the entries were planned from; the rebase does not
+    /// start if any no longer holds
+    #[serde(default)]
+    pub preconditions: Option<RewritePreconditions>,
 }
 
 /// Extended rebase preview with interactive entries
@@ -202,16 +276,64 @@ pub struct InteractiveRebasePreview {
     pub entries: Vec<InteractiveRebaseEntry>,
 }
 
+/// What rewording a commit will touch, computed before anything is rewritten
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct RewordPlan {
+    /// The commit being reworded
+    pub commit: Commit,
+    /// Commits after it that are replayed unchanged, oldest first; empty when the commit
+    /// is HEAD and is amended
+    pub replayed: Vec<Commit>,
+    /// HEAD, its branch and the index as they were when the plan was made
+    pub preconditions: RewritePreconditions,
+}
+
 /// Options for cherry-pick operations
 #[derive(Debug, Clone, Serialize, Deserialize, Default, Type)]
 #[serde(rename_all = "camelCase")]
 pub struct CherryPickOptions {
-    /// Commit(s) to cherry-pick
+    /// Commit(s) to cherry-pick, in the order they are applied
     pub commits: Vec<String>,
+    /// Range expression (e.g. `abc123..def456`) expanded oldest-first; used instead of `commits`
+    #[serde(default)]
+    pub range: Option<String>,
+    /// Parent number (1-based) to diff against when a picked commit is a merge
+    #[serde(default)]
+    pub mainline: Option<u32>,
     /// If true, don't create commits (stage changes only)
     pub no_commit: bool,
     /// If true, allow empty commits
     pub allow_empty: bool,
+    /// If true, picks that would be empty (already applied) are skipped instead of stopping
+    #[serde(default)]
+    pub skip_empty: bool,
+}
+
+/// Cherry-pick picks still to apply, kept while a conflict is being resolved
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CherryPickQueue {
+    /// HEAD before the first pick, restored on abort
+    pub orig_head: String,
+    /// Commits not yet picked, oldest first
+    pub remaining: Vec<String>,
+    /// Number of commits in the whole queue
+    pub total: usize,
+    /// Parent number used for merge commits
+    pub mainline: Option<u32>,
+    /// Apply picks to the index/worktree without committing
+    pub no_commit: bool,
+    /// Allow picks that produce no changes
+    pub allow_empty: bool,
+    /// Skip picks that produce no changes
+    pub skip_empty: bool,
+    /// Commits created so far
+    pub picked: Vec<String>,
+    /// Source commits skipped because they were empty
+    pub skipped: Vec<String>,
+    /// Commit whose pick failed without leaving a cherry-pick in progress;
+    /// retried on continue and dropped on skip
+    pub failed: Option<String>,
 }
 
 /// Result of a cherry-pick operation
@@ -226,6 +348,9 @@ pub struct CherryPickResult {
     pub conflicts: Vec<ConflictedFile>,
     /// Informational message
     pub message: String,
+    /// Source commits skipped because picking them would have been empty
+    #[serde(default)]
+    pub skipped: Vec<String>,
 }
 
 /// Options for revert operations
@@ -310,6 +435,77 @@ pub enum ConflictResolution {
     Merged,
 }
 
+/// A region of a conflicted file, as produced by a three-way merge of its index stages
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Type)]
+#[serde(rename_all = "PascalCase")]
+pub enum ConflictHunk {
+    /// Lines that merged cleanly
+    Context {
+        /// Merged lines
+        lines: Vec<String>,
+    },
+    /// Lines where both sides changed the same region
+    Conflict {
+        /// Index of this conflict block within the file (0-based)
+        index: usize,
+        /// Ours (current branch) lines
+        ours: Vec<String>,
+        /// Theirs (incoming) lines
+        theirs: Vec<String>,
+        /// Base (ancestor) lines
+        base: Vec<String>,
+    },
+}
+
+/// How to resolve a single conflict block
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Type)]
+#[serde(rename_all = "PascalCase")]
+pub enum ConflictHunkChoice {
+    /// Keep our lines
+    Ours,
+    /// Keep their lines
+    Theirs,
+    /// Keep both, ours first
+    BothOursFirst,
+    /// Keep both, theirs first
+    BothTheirsFirst,
+    /// Replace the block with custom text
+    Custom {
+        /// Replacement text
+        content: String,
+    },
+}
+
+/// A notebook cell both sides of a merge changed differently, by 1-based cell
+/// number in each version; `None` where that version lacks the cell
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct NotebookCellConflict {
+    pub base_cell: Option<u32>,
+    pub ours_cell: Option<u32>,
+    pub theirs_cell: Option<u32>,
+}
+
+/// Outcome of a cell-level three-way merge of a conflicted notebook
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct NotebookMergeResult {
+    /// Every cell merged cleanly; the notebook was written and staged
+    pub resolved: bool,
+    /// Cells to resolve by taking a side; empty when `resolved`
+    pub conflicts: Vec<NotebookCellConflict>,
+}
+
+/// Commit an operation in progress is applying
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct OperationCommit {
+    /// Full commit OID
+    pub oid: String,
+    /// First line of the commit message
+    pub summary: String,
+}
+
 /// Operation currently in progress
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Default, Type)]
 #[serde(rename_all = "PascalCase")]
@@ -321,6 +517,12 @@ pub enum OperationState {
     Merging {
         /// Branch being merged
         branch: Option<String>,
+        /// Commit being merged (`MERGE_HEAD`)
+        current_commit: Option<OperationCommit>,
+        /// Branch the merge is going into
+        original_branch: Option<String>,
+        /// Paths with unresolved conflicts
+        conflicted_paths: Vec<String>,
     },
     /// Rebase in progress
     Rebasing {
@@ -336,16 +538,40 @@ pub enum OperationState {
         /// Branch being rebased (stripped of refs/heads/ prefix)
         #[serde(skip_serializing_if = "Option::is_none")]
         head_name: Option<String>,
+        /// Commit the rebase stopped at
+        current_commit: Option<OperationCommit>,
+        /// Paths with unresolved conflicts
+        conflicted_paths: Vec<String>,
     },
     /// Cherry-pick in progress
     CherryPicking {
         /// Commit being cherry-picked
         commit: Option<String>,
+        /// Commit being cherry-picked, with its summary
+        current_commit: Option<OperationCommit>,
+        /// Current step of a multi-commit pick
+        current: Option<usize>,
+        /// Total steps of a multi-commit pick
+        total: Option<usize>,
+        /// Branch the picks are applied to
+        original_branch: Option<String>,
+        /// Paths with unresolved conflicts
+        conflicted_paths: Vec<String>,
     },
     /// Revert in progress
     Reverting {
         /// Commit being reverted
         commit: Option<String>,
+        /// Commit being reverted, with its summary
+        current_commit: Option<OperationCommit>,
+        /// Current step of a multi-commit revert
+        current: Option<usize>,
+        /// Total steps of a multi-commit revert
+        total: Option<usize>,
+        /// Branch the reverts are applied to
+        original_branch: Option<String>,
+        /// Paths with unresolved conflicts
+        conflicted_paths: Vec<String>,
     },
     /// Bisect in progress
     Bisecting {
@@ -354,6 +580,8 @@ pub enum OperationState {
         /// Approximate steps remaining
         steps_remaining: Option<usize>,
     },
+    /// An operation is in progress but its state files are missing or unreadable
+    Unknown,
 }
 
 /// Reset mode for reset operations
@@ -367,6 +595,8 @@ pub enum ResetMode {
     Mixed,
     /// Discard all changes
     Hard,
+    /// Move HEAD but keep local changes, aborting if they touch files that differ
+    Keep,
 }
 
 /// Options for reset operations
@@ -375,8 +605,24 @@ pub enum ResetMode {
 pub struct ResetOptions {
     /// Target commit/ref to reset to
     pub target: String,
-    /// Reset mode (soft, mixed, hard)
+    /// Reset mode (soft, mixed, hard, keep)
     pub mode: ResetMode,
+    /// Required for hard resets: acknowledge that uncommitted changes will be discarded
+    #[serde(default)]
+    pub confirm_discard: bool,
+    /// Only report what would be discarded, without resetting
+    #[serde(default)]
+    pub preview: bool,
+}
+
+/// Result of a reset (or a reset preview)
+#[derive(Debug, Clone, Serialize, Deserialize, Default, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct ResetResult {
+    /// Whether the reset was performed (false for previews)
+    pub applied: bool,
+    /// Files whose uncommitted changes are destroyed by a hard reset
+    pub discarded_files: Vec<String>,
 }
 
 #[cfg(test)]
@@ -425,6 +671,28 @@ mod tests {
         assert!(!opts.squash);
         assert!(!opts.ff_only);
         assert!(!opts.no_commit);
+        assert!(opts.strategy.is_none());
+    }
+
+    #[test]
+    fn test_merge_options_strategy_deserialization() {
+        let opts: MergeOptions = serde_json::from_str(
+            r#"{"branch":"feature","message":null,"noFf":false,"squash":false,"ffOnly":false,"noCommit":false,"strategy":"Theirs"}"#,
+        )
+        .expect("should deserialize");
+        assert_eq!(opts.strategy, Some(MergeStrategy::Theirs));
+        assert_eq!(
+            opts.strategy.map(MergeStrategy::cli_arg),
+            Some("--strategy-option=theirs")
+        );
+    }
+
+    #[test]
+    fn test_merge_strategy_cli_args() {
+        assert_eq!(MergeStrategy::OursStrategy.cli_arg(), "--strategy=ours");
+        assert_eq!(MergeStrategy::Ours.cli_arg(), "--strategy-option=ours");
+        assert_eq!(MergeStrategy::Recursive.cli_arg(), "--strategy=recursive");
+        assert_eq!(MergeStrategy::Octopus.cli_arg(), "--strategy=octopus");
     }
 
     #[test]
@@ -436,6 +704,8 @@ mod tests {
             squash: false,
             ff_only: false,
             no_commit: false,
+            trailers: CommitTrailerOptions::default(),
+            strategy: None,
         };
         assert!(opts.no_ff);
         assert_eq!(opts.branch, "feature");
@@ -653,12 +923,21 @@ mod tests {
         let opts = CherryPickOptions {
             commits: vec!["abc".to_string(), "def".to_string()],
             no_commit: true,
-            allow_empty: false,
+            ..Default::default()
         };
         assert_eq!(opts.commits.len(), 2);
         assert!(opts.no_commit);
     }
 
+    #[test]
+    fn test_cherry_pick_options_range_and_mainline_optional() {
+        let opts: CherryPickOptions =
+            serde_json::from_str(r#"{"commits":["abc"],"noCommit":false,"allowEmpty":false}"#)
+                .expect("should deserialize");
+        assert!(opts.range.is_none());
+        assert!(opts.mainline.is_none());
+    }
+
     // ==================== RevertOptions Tests ====================
 
     #[test]
@@ -706,6 +985,9 @@ mod tests {
     fn test_operation_state_merging() {
         let state = OperationState::Merging {
             branch: Some("feature".to_string()),
+            current_commit: None,
+            original_branch: Some("main".to_string()),
+            conflicted_paths: Vec::new(),
         };
         assert!(matches!(state, OperationState::Merging { .. }));
     }
@@ -718,6 +1000,8 @@ mod tests {
             total: Some(5),
             paused_action: None,
             head_name: None,
+            current_commit: None,
+            conflicted_paths: Vec::new(),
         };
         if let OperationState::Rebasing { current, total, .. } = state {
             assert_eq!(current, Some(3));
@@ -735,6 +1019,8 @@ mod tests {
             total: Some(5),
             paused_action: Some(RebaseAction::Edit),
             head_name: Some("feature-branch".to_string()),
+            current_commit: None,
+            conflicted_paths: Vec::new(),
         };
         if let OperationState::Rebasing {
             paused_action,
@@ -757,6 +1043,8 @@ mod tests {
             total: Some(3),
             paused_action: None,
             head_name: None,
+            current_commit: None,
+            conflicted_paths: Vec::new(),
         };
         let json = serde_json::to_string(&state).expect("should serialize");
         // paused_action and head_name should be skipped when None
@@ -773,6 +1061,8 @@ mod tests {
             total: Some(5),
             paused_action: Some(RebaseAction::Reword),
             head_name: Some("feature".to_string()),
+            current_commit: None,
+            conflicted_paths: Vec::new(),
         };
         let json = serde_json::to_string(&state).expect("should serialize");
         assert!(json.contains("Reword"));
@@ -833,9 +1123,19 @@ mod tests {
     fn test_operation_state_serialization() {
         let state = OperationState::CherryPicking {
             commit: Some("abc123".to_string()),
+            current_commit: Some(OperationCommit {
+                oid: "abc123".to_string(),
+                summary: "Fix parser".to_string(),
+            }),
+            current: Some(2),
+            total: Some(3),
+            original_branch: Some("main".to_string()),
+            conflicted_paths: vec!["src/lib.rs".to_string()],
         };
         let json = serde_json::to_string(&state).expect("should serialize");
         assert!(json.contains("CherryPicking"));
+        assert!(json.contains("\"current_commit\":{\"oid\":\"abc123\",\"summary\":\"Fix parser\"}"));
+        assert!(json.contains("\"conflicted_paths\":[\"src/lib.rs\"]"));
     }
 
     // ==================== ResetMode Tests ====================
@@ -884,11 +1184,22 @@ mod tests {
         let opts = ResetOptions {
             target: "HEAD~1".to_string(),
             mode: ResetMode::Hard,
+            confirm_discard: true,
+            preview: false,
         };
         assert_eq!(opts.target, "HEAD~1");
         assert_eq!(opts.mode, ResetMode::Hard);
     }
 
+    #[test]
+    fn test_reset_options_flags_default_to_false() {
+        let json = r#"{"target":"HEAD~1","mode":"Keep"}"#;
+        let opts: ResetOptions = serde_json::from_str(json).expect("should deserialize");
+        assert_eq!(opts.mode, ResetMode::Keep);
+        assert!(!opts.confirm_discard);
+        assert!(!opts.preview);
+    }
+
     // ==================== ConflictContent Tests ====================
 
     #[test]
@@ -916,4 +1227,36 @@ mod tests {
         };
         assert!(content.base.is_none());
     }
+
+    // ==================== ConflictHunk Tests ====================
+
+    #[test]
+    fn test_conflict_hunk_serialization() {
+        let hunk = ConflictHunk::Conflict {
+            index: 1,
+            ours: vec!["ours".to_string()],
+            theirs: vec!["theirs".to_string()],
+            base: vec![],
+        };
+        let json = serde_json::to_string(&hunk).expect("should serialize");
+        assert!(json.contains("\"Conflict\""));
+        assert!(json.contains("\"index\":1"));
+        assert!(json.contains("\"theirs\":[\"theirs\"]"));
+    }
+
+    #[test]
+    fn test_conflict_hunk_choice_deserialization() {
+        let choice: ConflictHunkChoice =
+            serde_json::from_str("\"BothTheirsFirst\"").expect("should deserialize");
+        assert_eq!(choice, ConflictHunkChoice::BothTheirsFirst);
+
+        let json = r#"{"Custom":{"content":"merged"}}"#;
+        let choice: ConflictHunkChoice = serde_json::from_str(json).expect("should deserialize");
+        assert_eq!(
+            choice,
+            ConflictHunkChoice::Custom {
+                content: "merged".to_string()
+            }
+        );
+    }
 }
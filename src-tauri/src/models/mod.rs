@@ -1,10 +1,18 @@
 mod ai;
+mod alternates;
 mod avatar;
+mod background_fetch;
 mod bisect;
+mod bookmark;
 mod branch;
+mod changelog;
 mod commit;
+mod commit_lint;
+mod config;
+mod credentials;
 mod custom_actions;
 mod diff;
+mod file_mode;
 mod file_status;
 mod gitflow;
 mod gitignore;
@@ -12,28 +20,44 @@ mod graph;
 mod hooks;
 mod integration;
 mod lfs;
+mod maintenance;
 mod merge;
+mod operation_log;
 mod patches;
+mod performance;
 mod reflog;
 mod remote;
 mod repo_settings;
 mod repository;
+mod rewrite;
 mod settings;
 mod signing;
+mod spell_check;
 mod ssh_keys;
 mod stash;
 mod submodule;
+mod sync_folder;
 mod tag;
+mod terminal;
+mod undo;
 mod update;
 mod worktree;
 
 pub use ai::*;
+pub use alternates::*;
 pub use avatar::*;
+pub use background_fetch::*;
 pub use bisect::*;
+pub use bookmark::*;
 pub use branch::*;
+pub use changelog::*;
 pub use commit::*;
+pub use commit_lint::*;
+pub use config::*;
+pub use credentials::*;
 pub use custom_actions::*;
 pub use diff::*;
+pub use file_mode::*;
 pub use file_status::*;
 pub use gitflow::*;
 pub use gitignore::*;
@@ -41,17 +65,25 @@ pub use graph::*;
 pub use hooks::*;
 pub use integration::*;
 pub use lfs::*;
+pub use maintenance::*;
 pub use merge::*;
+pub use operation_log::*;
 pub use patches::*;
+pub use performance::*;
 pub use reflog::*;
 pub use remote::*;
 pub use repo_settings::*;
 pub use repository::*;
+pub use rewrite::*;
 pub use settings::*;
 pub use signing::*;
+pub use spell_check::*;
 pub use ssh_keys::*;
 pub use stash::*;
 pub use submodule::*;
+pub use sync_folder::*;
 pub use tag::*;
+pub use terminal::*;
+pub use undo::*;
 pub use update::*;
 pub use worktree::*;
@@ -0,0 +1,163 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use strum::{Display, EnumString};
+
+/// Default inactivity gap (in minutes) that splits the log into sessions
+pub const DEFAULT_SESSION_GAP_MINUTES: u32 = 30;
+
+/// Default number of entries returned per page
+pub const DEFAULT_OPERATION_LOG_LIMIT: usize = 100;
+
+/// Category of a logged operation
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Type, Display, EnumString,
+)]
+#[serde(rename_all = "PascalCase")]
+#[strum(serialize_all = "snake_case")]
+pub enum OperationCategory {
+    Commit,
+    Branch,
+    Merge,
+    Rebase,
+    Remote,
+    Stash,
+    Tag,
+    History,
+    Hook,
+    Other,
+}
+
+/// Whether a logged operation succeeded
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Type, Display, EnumString)]
+#[serde(rename_all = "PascalCase")]
+#[strum(serialize_all = "snake_case")]
+pub enum OperationOutcome {
+    Success,
+    Failure,
+}
+
+/// A single entry in the operation log
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct OperationLogEntry {
+    /// Database row ID
+    pub id: i64,
+    /// When the operation finished
+    pub timestamp: DateTime<Utc>,
+    /// Repository the operation ran in
+    pub repo_path: String,
+    /// Operation category
+    pub category: OperationCategory,
+    /// Operation name (e.g. "push", "pull")
+    pub operation: String,
+    /// Operation parameters as JSON, with credentials redacted
+    pub parameters: String,
+    /// Whether the operation succeeded
+    pub outcome: OperationOutcome,
+    /// Error message for failed operations
+    pub error: Option<String>,
+}
+
+/// Filters for querying the operation log
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct OperationLogFilter {
+    /// Only entries at or after this time
+    pub from: Option<DateTime<Utc>>,
+    /// Only entries before this time
+    pub to: Option<DateTime<Utc>>,
+    /// Only entries in these categories (empty means all)
+    #[serde(default)]
+    pub categories: Vec<OperationCategory>,
+    /// Only entries with this outcome
+    pub outcome: Option<OperationOutcome>,
+    /// Only entries for this repository
+    pub repo_path: Option<String>,
+    /// Free-text search over operation names and redacted parameters
+    pub search: Option<String>,
+    /// Inactivity gap in minutes that starts a new session (default 30)
+    pub session_gap_minutes: Option<u32>,
+    /// Maximum number of entries to return (default 100)
+    pub limit: Option<usize>,
+    /// Number of entries to skip
+    pub skip: Option<usize>,
+}
+
+/// A run of operations without a gap longer than the session threshold
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct OperationSession {
+    /// Time of the first operation in the session
+    pub start: DateTime<Utc>,
+    /// Time of the last operation in the session
+    pub end: DateTime<Utc>,
+    /// Session length in seconds
+    pub duration_seconds: i64,
+    /// Number of operations in the session
+    pub operation_count: u32,
+    /// Number of failed operations in the session
+    pub failure_count: u32,
+    /// Repositories touched during the session
+    pub repositories: Vec<String>,
+}
+
+/// Paginated operation log response
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct OperationLogPage {
+    /// Entries on this page, newest first
+    pub items: Vec<OperationLogEntry>,
+    /// Whether more entries match the filter
+    pub has_more: bool,
+    /// Total number of entries matching the filter
+    pub total_count: u32,
+    /// Sessions across all matching entries, newest first
+    pub sessions: Vec<OperationSession>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    // ==================== OperationCategory Tests ====================
+
+    #[test]
+    fn test_operation_category_storage_roundtrip() {
+        assert_eq!(OperationCategory::Remote.to_string(), "remote");
+        assert_eq!(
+            OperationCategory::from_str("history").expect("should parse"),
+            OperationCategory::History
+        );
+    }
+
+    #[test]
+    fn test_operation_outcome_serialization() {
+        let json = serde_json::to_string(&OperationOutcome::Failure).expect("should serialize");
+        assert_eq!(json, "\"Failure\"");
+        assert_eq!(OperationOutcome::Failure.to_string(), "failure");
+    }
+
+    // ==================== OperationLogFilter Tests ====================
+
+    #[test]
+    fn test_operation_log_filter_defaults() {
+        let filter: OperationLogFilter = serde_json::from_str("{}").expect("should deserialize");
+        assert!(filter.categories.is_empty());
+        assert!(filter.search.is_none());
+        assert!(filter.limit.is_none());
+    }
+
+    #[test]
+    fn test_operation_log_filter_deserialization() {
+        let json = r#"{"categories":["Remote","Commit"],"outcome":"Failure","search":"origin"}"#;
+        let filter: OperationLogFilter = serde_json::from_str(json).expect("should deserialize");
+        assert_eq!(
+            filter.categories,
+            vec![OperationCategory::Remote, OperationCategory::Commit]
+        );
+        assert_eq!(filter.outcome, Some(OperationOutcome::Failure));
+        assert_eq!(filter.search.as_deref(), Some("origin"));
+    }
+}
@@ -0,0 +1,92 @@
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+/// Bundle of defaults a repository runs with
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, Type)]
+#[serde(rename_all = "PascalCase")]
+pub enum PerformanceProfile {
+    /// `Standard` or `LargeRepo`, picked from the repository's size when it is opened
+    #[default]
+    Auto,
+    Standard,
+    /// Cheaper defaults for repositories with very many files, refs, or objects
+    LargeRepo,
+    /// `Standard` defaults, tuned knob by knob through the repository's overrides
+    Custom,
+}
+
+/// Values set for one repository; each wins over the profile and global settings
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct PerformanceOverrides {
+    #[serde(default)]
+    pub file_watcher_debounce_ms: Option<u32>,
+    #[serde(default)]
+    pub background_fetch: Option<bool>,
+    #[serde(default)]
+    pub skip_ahead_behind: Option<bool>,
+    #[serde(default)]
+    pub graph_page_size: Option<u32>,
+}
+
+/// Performance settings stored for one repository
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct PerformanceConfig {
+    pub profile: PerformanceProfile,
+    #[serde(default)]
+    pub overrides: PerformanceOverrides,
+}
+
+/// Size measurements `Auto` decides from, cheap enough to take on every open
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct RepositorySizeSignals {
+    /// Entries in the index, read from its header
+    pub tracked_files: u64,
+    /// References of every kind, loose and packed
+    pub refs: u64,
+    /// Bytes used by pack and pack index files
+    pub pack_bytes: u64,
+}
+
+/// Effective value of every knob a profile sets
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct PerformanceSettings {
+    /// Quiet period before file changes are reported; applies when the watcher starts
+    pub file_watcher_debounce_ms: u32,
+    /// Whether background fetch runs when the repository has no fetch settings of its own
+    pub background_fetch: bool,
+    /// Leave branch ahead/behind counts unset instead of walking each branch
+    pub skip_ahead_behind: bool,
+    /// Commits per graph page when a request sets no limit
+    pub graph_page_size: u32,
+}
+
+/// Which profile a repository runs with and why
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct PerformanceReadiness {
+    pub config: PerformanceConfig,
+    /// The profile in effect; never `Auto`
+    pub effective_profile: PerformanceProfile,
+    /// Measurements taken when the repository was opened, if `Auto` needed them
+    pub signals: Option<RepositorySizeSignals>,
+    /// Why `effective_profile` was chosen
+    pub reason: String,
+    pub settings: PerformanceSettings,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_performance_config_deserializes_without_overrides() {
+        let config: PerformanceConfig =
+            serde_json::from_str(r#"{"profile":"LargeRepo"}"#).expect("should deserialize");
+        assert_eq!(config.profile, PerformanceProfile::LargeRepo);
+        assert_eq!(config.overrides, PerformanceOverrides::default());
+    }
+}
@@ -1,6 +1,9 @@
 use serde::{Deserialize, Serialize};
 use specta::Type;
 
+use super::commit::CommitTrailerOptions;
+use super::submodule::OutOfSyncSubmodule;
+
 /// Sort order for remote listing
 #[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, Type)]
 #[serde(rename_all = "PascalCase")]
@@ -52,6 +55,8 @@ pub struct FetchResult {
     pub remote: String,
     pub updated_refs: Vec<UpdatedRef>,
     pub stats: FetchProgress,
+    /// Submodule paths whose new commits were fetched along with the superproject
+    pub fetched_submodules: Vec<String>,
 }
 
 /// An updated reference from fetch/push
@@ -101,6 +106,39 @@ pub enum PushStatus {
     RemoteRejected,
 }
 
+/// Result of checking that a remote can be reached with the configured credentials
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct RemoteConnectionResult {
+    /// The remote accepted the connection
+    pub success: bool,
+    /// Time spent connecting, in milliseconds
+    pub latency_ms: u64,
+    /// Why the connection failed
+    pub error: Option<String>,
+}
+
+/// A branch advertised by a remote, listed without fetching
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct RemoteBranchInfo {
+    /// Branch name without the `refs/heads/` prefix
+    pub name: String,
+    pub oid: String,
+    /// Summary of the tip commit; `None` until the commit has been fetched
+    pub last_commit_summary: Option<String>,
+}
+
+/// A tag advertised by a remote, listed without fetching
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct RemoteTagInfo {
+    /// Tag name without the `refs/tags/` prefix
+    pub name: String,
+    /// Object the tag ref points to: the tag object for annotated tags
+    pub oid: String,
+}
+
 /// Options for checkout operations
 #[derive(Debug, Clone, Serialize, Deserialize, Default, Type)]
 #[serde(rename_all = "camelCase")]
@@ -145,6 +183,33 @@ pub struct FetchOptions {
     pub tags: bool,
     /// Depth for shallow fetch (None for full fetch)
     pub depth: Option<u32>,
+    /// Override `fetch.recurseSubmodules` for this fetch
+    pub recurse_submodules: Option<FetchRecurseSubmodules>,
+}
+
+/// Whether fetching should also fetch submodules (mirrors `fetch.recurseSubmodules`)
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Default, PartialEq, Eq, Type)]
+#[serde(rename_all = "PascalCase")]
+pub enum FetchRecurseSubmodules {
+    /// Never fetch submodules
+    No,
+    /// Fetch every initialized submodule
+    Yes,
+    /// Fetch only submodules whose recorded commit changed in the fetched history
+    #[default]
+    OnDemand,
+}
+
+impl FetchRecurseSubmodules {
+    /// Parse a `fetch.recurseSubmodules` config value
+    pub fn from_config_value(value: &str) -> Option<Self> {
+        match value.trim().to_lowercase().as_str() {
+            "on-demand" => Some(Self::OnDemand),
+            "true" | "yes" | "on" | "1" => Some(Self::Yes),
+            "false" | "no" | "off" | "0" => Some(Self::No),
+            _ => None,
+        }
+    }
 }
 
 /// Options for push operations
@@ -167,6 +232,21 @@ pub struct PullOptions {
     pub rebase: bool,
     /// Fast-forward only
     pub ff_only: bool,
+    /// Override `fetch.recurseSubmodules` for the fetch step
+    pub recurse_submodules: Option<FetchRecurseSubmodules>,
+    /// Trailers for the merge commit created when the pull cannot fast-forward
+    #[serde(default)]
+    pub trailers: CommitTrailerOptions,
+}
+
+/// Result of a pull operation
+#[derive(Debug, Clone, Serialize, Deserialize, Default, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct PullResult {
+    /// Submodule paths whose new commits were fetched along with the superproject
+    pub fetched_submodules: Vec<String>,
+    /// Submodules whose checkout no longer matches the commit recorded after the pull
+    pub out_of_sync_submodules: Vec<OutOfSyncSubmodule>,
 }
 
 #[cfg(test)]
@@ -332,6 +412,7 @@ mod tests {
                 status: RefUpdateStatus::FastForward,
             }],
             stats: FetchProgress::default(),
+            fetched_submodules: Vec::new(),
         };
 
         assert_eq!(result.remote, "origin");
@@ -453,6 +534,7 @@ mod tests {
         assert!(!opts.prune);
         assert!(!opts.tags);
         assert!(opts.depth.is_none());
+        assert!(opts.recurse_submodules.is_none());
     }
 
     #[test]
@@ -461,6 +543,7 @@ mod tests {
             prune: true,
             tags: true,
             depth: Some(1),
+            recurse_submodules: Some(FetchRecurseSubmodules::No),
         };
 
         assert!(opts.prune);
@@ -468,6 +551,33 @@ mod tests {
         assert_eq!(opts.depth, Some(1));
     }
 
+    // ==================== FetchRecurseSubmodules Tests ====================
+
+    #[test]
+    fn test_fetch_recurse_submodules_default() {
+        assert_eq!(
+            FetchRecurseSubmodules::default(),
+            FetchRecurseSubmodules::OnDemand
+        );
+    }
+
+    #[test]
+    fn test_fetch_recurse_submodules_from_config_value() {
+        assert_eq!(
+            FetchRecurseSubmodules::from_config_value("on-demand"),
+            Some(FetchRecurseSubmodules::OnDemand)
+        );
+        assert_eq!(
+            FetchRecurseSubmodules::from_config_value("true"),
+            Some(FetchRecurseSubmodules::Yes)
+        );
+        assert_eq!(
+            FetchRecurseSubmodules::from_config_value("False"),
+            Some(FetchRecurseSubmodules::No)
+        );
+        assert_eq!(FetchRecurseSubmodules::from_config_value("sometimes"), None);
+    }
+
     // ==================== PushOptions Tests ====================
 
     #[test]
@@ -504,6 +614,8 @@ mod tests {
         let opts = PullOptions {
             rebase: true,
             ff_only: false,
+            recurse_submodules: None,
+            trailers: CommitTrailerOptions::default(),
         };
 
         assert!(opts.rebase);
@@ -515,6 +627,8 @@ mod tests {
         let opts = PullOptions {
             rebase: false,
             ff_only: true,
+            recurse_submodules: None,
+            trailers: CommitTrailerOptions::default(),
         };
 
         assert!(!opts.rebase);
@@ -14,6 +14,9 @@ pub struct Repository {
     pub is_unborn: bool,
     pub current_branch: Option<String>,
     pub state: RepositoryState,
+    /// Ref HEAD points to when that ref no longer exists (state is `BrokenHead`)
+    #[serde(default)]
+    pub broken_head: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Type)]
@@ -28,6 +31,8 @@ pub enum RepositoryState {
     Bisecting,
     ApplyMailbox,
     ApplyMailboxOrRebase,
+    /// HEAD is a symref to a branch that was deleted outside the app
+    BrokenHead,
 }
 
 impl From<git2::RepositoryState> for RepositoryState {
@@ -52,6 +57,27 @@ impl From<git2::RepositoryState> for RepositoryState {
     }
 }
 
+/// Ways out of a `BrokenHead` state
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Type)]
+#[serde(rename_all = "PascalCase")]
+pub enum BrokenHeadAction {
+    /// Re-create the missing branch at its last known tip
+    RecreateBranch,
+    /// Point HEAD at an existing branch, keeping the working tree and index as they are
+    SwitchBranch,
+}
+
+/// A recovery offered for a `BrokenHead` state, computed from the repository
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct BrokenHeadRecovery {
+    pub action: BrokenHeadAction,
+    /// Branch that HEAD will point to afterwards
+    pub branch: String,
+    /// Commit the re-created branch will point to
+    pub oid: Option<String>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, Type)]
 #[serde(rename_all = "camelCase")]
 pub struct RecentRepository {
@@ -62,6 +88,9 @@ pub struct RecentRepository {
     pub current_branch: Option<String>,
     pub is_pinned: bool,
     pub display_path: String,
+    /// Branch checked out when the repository was last opened or closed in Axis
+    #[serde(default)]
+    pub last_branch: Option<String>,
 }
 
 impl RecentRepository {
@@ -89,6 +118,135 @@ impl RecentRepository {
             current_branch,
             is_pinned: row.is_pinned,
             display_path,
+            last_branch: row.last_branch,
+        }
+    }
+
+    /// Build a `RecentRepository` from a database row without touching the filesystem,
+    /// for entries that could not be inspected in time. The path is assumed to exist.
+    pub fn without_probe(row: RecentRepositoryRow) -> Self {
+        let display_path = make_display_path(&row.path);
+        Self {
+            path: row.path,
+            name: row.name,
+            last_opened: row.last_opened,
+            exists: true,
+            current_branch: None,
+            is_pinned: row.is_pinned,
+            display_path,
+            last_branch: row.last_branch,
+        }
+    }
+}
+
+/// Working tree and upstream summary of a recent repository
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct RecentRepositoryStatus {
+    /// Files with staged, unstaged, or untracked changes
+    pub dirty_files: u32,
+    /// Upstream of the current branch, if any
+    pub upstream: Option<String>,
+    pub ahead: u32,
+    pub behind: u32,
+}
+
+impl RecentRepositoryStatus {
+    /// Read the status of an open repository; untracked directories are not recursed
+    pub fn read(repo: &git2::Repository) -> Option<Self> {
+        if repo.is_bare() {
+            return Some(Self::default());
+        }
+
+        let mut opts = git2::StatusOptions::new();
+        opts.include_untracked(true)
+            .recurse_untracked_dirs(false)
+            .exclude_submodules(true);
+        let dirty_files = repo
+            .statuses(Some(&mut opts))
+            .ok()?
+            .iter()
+            .filter(|entry| entry.status() != git2::Status::IGNORED)
+            .count();
+
+        let mut status = Self {
+            dirty_files: u32::try_from(dirty_files).unwrap_or(u32::MAX),
+            ..Self::default()
+        };
+
+        let Some(branch) = repo
+            .head()
+            .ok()
+            .filter(git2::Reference::is_branch)
+            .map(git2::Branch::wrap)
+        else {
+            return Some(status);
+        };
+        if let Ok(upstream) = branch.upstream() {
+            status.upstream = upstream.name().ok().flatten().map(String::from);
+            if let (Some(local), Some(remote)) = (branch.get().target(), upstream.get().target()) {
+                if let Ok((ahead, behind)) = repo.graph_ahead_behind(local, remote) {
+                    status.ahead = u32::try_from(ahead).unwrap_or(u32::MAX);
+                    status.behind = u32::try_from(behind).unwrap_or(u32::MAX);
+                }
+            }
+        }
+        Some(status)
+    }
+}
+
+/// A recent repository with its live status
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct RecentRepositoryWithStatus {
+    #[serde(flatten)]
+    pub repository: RecentRepository,
+    /// `None` if the repository is missing, unreadable, or timed out
+    pub status: Option<RecentRepositoryStatus>,
+    /// Whether inspecting the repository took too long (e.g. an unreachable network drive)
+    pub timed_out: bool,
+}
+
+impl RecentRepositoryWithStatus {
+    /// Inspect a recent repository: current branch, dirty files, and ahead/behind counts
+    pub fn probe(row: RecentRepositoryRow) -> Self {
+        let exists = row.path.exists();
+        let repo = if exists {
+            git2::Repository::open(&row.path).ok()
+        } else {
+            None
+        };
+
+        let current_branch = repo.as_ref().and_then(|repo| {
+            repo.head()
+                .ok()
+                .and_then(|head| head.shorthand().map(String::from))
+        });
+        let status = repo.as_ref().and_then(RecentRepositoryStatus::read);
+
+        let display_path = make_display_path(&row.path);
+        Self {
+            repository: RecentRepository {
+                path: row.path,
+                name: row.name,
+                last_opened: row.last_opened,
+                exists,
+                current_branch,
+                is_pinned: row.is_pinned,
+                display_path,
+                last_branch: row.last_branch,
+            },
+            status,
+            timed_out: false,
+        }
+    }
+
+    /// Entry for a repository whose probe failed or did not finish in time
+    pub fn unprobed(row: RecentRepositoryRow, timed_out: bool) -> Self {
+        Self {
+            repository: RecentRepository::without_probe(row),
+            status: None,
+            timed_out,
         }
     }
 }
@@ -232,6 +390,7 @@ mod tests {
             is_unborn: false,
             current_branch: Some("main".to_string()),
             state: RepositoryState::Clean,
+            broken_head: None,
         };
 
         assert_eq!(repo.id, "test-repo-id");
@@ -252,6 +411,7 @@ mod tests {
             is_unborn: false,
             current_branch: None,
             state: RepositoryState::Clean,
+            broken_head: None,
         };
 
         assert!(repo.is_bare);
@@ -268,6 +428,7 @@ mod tests {
             is_unborn: true,
             current_branch: Some("main".to_string()),
             state: RepositoryState::Clean,
+            broken_head: None,
         };
 
         assert!(repo.is_unborn);
@@ -283,6 +444,7 @@ mod tests {
             is_unborn: false,
             current_branch: Some("feature".to_string()),
             state: RepositoryState::Merging,
+            broken_head: None,
         };
 
         assert_eq!(repo.state, RepositoryState::Merging);
@@ -298,6 +460,7 @@ mod tests {
             is_unborn: false,
             current_branch: Some("develop".to_string()),
             state: RepositoryState::Clean,
+            broken_head: None,
         };
 
         let json = serde_json::to_string(&repo).expect("should serialize");
@@ -321,6 +484,7 @@ mod tests {
             current_branch: Some("main".to_string()),
             is_pinned: false,
             display_path: "~/project".to_string(),
+            last_branch: None,
         };
 
         assert_eq!(recent.name, "project");
@@ -341,6 +505,7 @@ mod tests {
             current_branch: None,
             is_pinned: false,
             display_path: "/deleted/repo".to_string(),
+            last_branch: None,
         };
 
         assert!(!recent.exists);
@@ -357,6 +522,7 @@ mod tests {
             current_branch: Some("develop".to_string()),
             is_pinned: true,
             display_path: "~/project".to_string(),
+            last_branch: Some("develop".to_string()),
         };
 
         assert!(recent.is_pinned);
@@ -374,6 +540,7 @@ mod tests {
             current_branch: Some("main".to_string()),
             is_pinned: true,
             display_path: "~/path".to_string(),
+            last_branch: None,
         };
 
         let json = serde_json::to_string(&recent).expect("should serialize");
@@ -0,0 +1,164 @@
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+use crate::models::Commit;
+
+/// Options for removing paths from unpushed history
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct RemovePathOptions {
+    /// Proceed even if other local branches contain the commits being rewritten
+    #[serde(default)]
+    pub acknowledge_other_branches: bool,
+    /// Sign the rewritten commits (falls back to the `sign_commits` setting)
+    pub sign: Option<bool>,
+    /// Preconditions returned with the preview; the removal aborts if any no longer holds
+    #[serde(default)]
+    pub preconditions: Option<RewritePreconditions>,
+}
+
+/// A path removed from a single commit's tree
+#[derive(Debug, Clone, Serialize, Deserialize, Type, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct RemovedPathEntry {
+    /// Repository-relative path
+    pub path: String,
+    /// Size in bytes of the content at this path (sum of blobs for directories)
+    pub size: u64,
+}
+
+/// A commit whose tree contains one of the paths being removed
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct AffectedCommit {
+    /// The commit as it exists today
+    pub commit: Commit,
+    /// Paths that will be removed from this commit's tree
+    pub removed: Vec<RemovedPathEntry>,
+}
+
+/// Preview of a path removal from unpushed history
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct RemovePathPreview {
+    /// Branch that will be rewritten
+    pub branch: String,
+    /// Upstream used as the pushed boundary, if configured
+    pub upstream: Option<String>,
+    /// Number of unpushed commits on the branch
+    pub unpushed_count: usize,
+    /// Unpushed commits that contain the paths, oldest first
+    pub affected_commits: Vec<AffectedCommit>,
+    /// Total size in bytes of the distinct blobs being removed
+    pub total_bytes: u64,
+    /// Other local branches that also contain commits in the rewritten range
+    pub other_branches: Vec<String>,
+    /// State the preview was computed from
+    pub preconditions: RewritePreconditions,
+}
+
+/// Mapping of a rewritten commit to its replacement
+#[derive(Debug, Clone, Serialize, Deserialize, Type, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct RewrittenCommit {
+    /// Original commit OID
+    pub old_oid: String,
+    /// Replacement commit OID
+    pub new_oid: String,
+}
+
+/// Result of removing paths from unpushed history
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct RemovePathResult {
+    /// Branch that was rewritten
+    pub branch: String,
+    /// Branch tip before the rewrite
+    pub old_head: String,
+    /// Branch tip after the rewrite
+    pub new_head: String,
+    /// Old to new OID mapping for every commit that changed, oldest first
+    pub rewritten: Vec<RewrittenCommit>,
+    /// Whether the removed content is absent from every commit reachable from the branch
+    pub removed_from_branch: bool,
+    /// Where the removed content may still exist and how to purge it
+    pub note: String,
+}
+
+/// OID a ref held when a rewrite was planned
+#[derive(Debug, Clone, Serialize, Deserialize, Type, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct RefPrecondition {
+    /// Full ref name, or `HEAD`
+    pub refname: String,
+    /// OID the ref pointed at; `None` when it did not exist
+    pub oid: Option<String>,
+}
+
+/// Repository state a multi-phase rewrite was planned against. Returned with the plan
+/// and passed back on execution, which aborts if any of it changed in between.
+#[derive(Debug, Clone, Serialize, Deserialize, Type, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct RewritePreconditions {
+    /// Stamp of the plan these were captured for, unique within a session
+    pub generation: u64,
+    pub refs: Vec<RefPrecondition>,
+    /// Hash of the index entries when the rewrite depends on the index; all zeros when
+    /// the repository had no index
+    pub index_checksum: Option<String>,
+}
+
+/// A precondition that no longer holds when a rewrite is executed
+#[derive(Debug, Clone, Serialize, Deserialize, Type, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+pub enum PreconditionViolation {
+    /// A ref moved, appeared or disappeared
+    Ref {
+        refname: String,
+        /// OID recorded when planning; `None` when the ref did not exist
+        expected: Option<String>,
+        /// OID the ref holds now; `None` when it no longer exists
+        actual: Option<String>,
+        /// Commits reachable from `actual` but not from `expected`
+        ahead: Option<usize>,
+        /// Commits reachable from `expected` but not from `actual`
+        behind: Option<usize>,
+    },
+    /// The index was written, e.g. by an external `git add`
+    Index { expected: String, actual: String },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // ==================== RemovePathOptions Tests ====================
+
+    #[test]
+    fn test_remove_path_options_default() {
+        let options: RemovePathOptions = serde_json::from_str("{}").expect("should deserialize");
+        assert!(!options.acknowledge_other_branches);
+        assert!(options.sign.is_none());
+    }
+
+    #[test]
+    fn test_remove_path_options_deserialization() {
+        let json = r#"{"acknowledgeOtherBranches":true,"sign":false}"#;
+        let options: RemovePathOptions = serde_json::from_str(json).expect("should deserialize");
+        assert!(options.acknowledge_other_branches);
+        assert_eq!(options.sign, Some(false));
+        assert!(options.preconditions.is_none());
+    }
+
+    // ==================== RewrittenCommit Tests ====================
+
+    #[test]
+    fn test_rewritten_commit_serialization() {
+        let mapping = RewrittenCommit {
+            old_oid: "abc".to_string(),
+            new_oid: "def".to_string(),
+        };
+        let json = serde_json::to_string(&mapping).expect("should serialize");
+        assert_eq!(json, r#"{"oldOid":"abc","newOid":"def"}"#);
+    }
+}
@@ -1,4 +1,7 @@
-use crate::models::{AiProvider, SigningFormat};
+use crate::models::{
+    AiModelPrice, AiProvider, ChangelogSection, CommitLintConfig, NotebookOutputMode,
+    SigningFormat, TerminalApp,
+};
 use serde::{Deserialize, Serialize};
 use specta::Type;
 use strum::{Display, EnumString};
@@ -20,6 +23,13 @@ pub struct AppSettings {
     pub confirm_before_discard: bool,
     pub sign_commits: bool,
     pub bypass_hooks: bool, // Skip git hooks by default
+    /// Quiet period before file changes are reported, in milliseconds; 300 when unset
+    #[serde(default)]
+    pub file_watcher_debounce_ms: Option<u32>,
+
+    /// Directories holding repositories that may borrow objects from the open one
+    #[serde(default)]
+    pub shared_object_store_roots: Vec<String>,
 
     // Signing
     pub signing_format: SigningFormat,
@@ -31,21 +41,67 @@ pub struct AppSettings {
     pub diff_context_lines: u32,
     pub diff_word_wrap: bool,
     pub diff_side_by_side: bool,
+    /// Preset for working tree diffs when a command names none; `Default` when unset
+    #[serde(default)]
+    pub diff_preset_workdir: Option<String>,
+    /// Preset for commit diffs when a command names none; `Default` when unset
+    #[serde(default)]
+    pub diff_preset_commit: Option<String>,
+    /// Preset for blame when none is chosen; `Default` when unset
+    #[serde(default)]
+    pub diff_preset_blame: Option<String>,
+    /// Show the enclosing function of each hunk, detected from the file's language
+    #[serde(default = "default_diff_function_context")]
+    pub diff_function_context: bool,
+    /// Diff Jupyter notebooks cell by cell, and structured JSON files with
+    /// normalized formatting, instead of as raw text
+    #[serde(default = "default_diff_structured")]
+    pub diff_structured: bool,
+    /// How cell outputs appear in notebook diffs
+    #[serde(default)]
+    pub diff_notebook_outputs: NotebookOutputMode,
+    /// Extensions of JSON files diffed with normalized formatting and key order
+    #[serde(default)]
+    pub diff_structured_json_extensions: Vec<String>,
 
     // Commit
     pub spell_check_commit_messages: bool,
     pub conventional_commits_enabled: bool,
     pub conventional_commits_scopes: Option<Vec<String>>,
+    /// Commit message rules; disabled by default
+    #[serde(default)]
+    pub commit_lint: CommitLintConfig,
+    /// Changelog headings per conventional type, in output order
+    #[serde(default = "ChangelogSection::defaults")]
+    pub changelog_sections: Vec<ChangelogSection>,
 
     // AI
     pub ai_enabled: bool,
     pub ai_provider: AiProvider,
     pub ai_model: Option<String>,
+    /// Model for PR descriptions; falls back to `ai_model` when not set
+    #[serde(default)]
+    pub ai_pr_model: Option<String>,
     pub ai_ollama_url: Option<String>,
+    /// Per-model prices used to estimate the cost of AI requests
+    #[serde(default = "AiModelPrice::defaults")]
+    pub ai_model_prices: Vec<AiModelPrice>,
 
     // SSH
     pub default_ssh_key: Option<String>,
 
+    // Terminal
+    /// Terminal opened by `open_terminal`; the platform's default when unset
+    #[serde(default)]
+    pub terminal_app: Option<TerminalApp>,
+    /// Command line run for `TerminalApp::Custom`, with `{path}` standing for the directory
+    #[serde(default)]
+    pub terminal_command_template: Option<String>,
+    /// Profile to open, for terminals that have them (Windows Terminal, iTerm2, Konsole,
+    /// GNOME Terminal)
+    #[serde(default)]
+    pub terminal_profile: Option<String>,
+
     // Notifications
     pub notification_history_capacity: u32,
 
@@ -60,6 +116,14 @@ pub struct AppSettings {
     pub large_binary_threshold: u64, // in bytes, default 10MB
 }
 
+fn default_diff_function_context() -> bool {
+    true
+}
+
+fn default_diff_structured() -> bool {
+    true
+}
+
 #[derive(Debug, Clone, Display, EnumString, Serialize, Deserialize, PartialEq, Default, Type)]
 #[serde(rename_all = "PascalCase")]
 #[strum(serialize_all = "lowercase")]
@@ -84,6 +148,8 @@ impl Default for AppSettings {
             confirm_before_discard: true,
             sign_commits: false,
             bypass_hooks: false,
+            file_watcher_debounce_ms: None,
+            shared_object_store_roots: Vec::new(),
 
             // Signing
             signing_format: SigningFormat::default(),
@@ -95,21 +161,37 @@ impl Default for AppSettings {
             diff_context_lines: 3,
             diff_word_wrap: false,
             diff_side_by_side: false,
+            diff_preset_workdir: None,
+            diff_preset_commit: None,
+            diff_preset_blame: None,
+            diff_function_context: true,
+            diff_structured: true,
+            diff_notebook_outputs: NotebookOutputMode::default(),
+            diff_structured_json_extensions: Vec::new(),
 
             // Commit
             spell_check_commit_messages: false,
             conventional_commits_enabled: false,
             conventional_commits_scopes: None,
+            commit_lint: CommitLintConfig::default(),
+            changelog_sections: ChangelogSection::defaults(),
 
             // AI
             ai_enabled: false,
             ai_provider: AiProvider::default(),
             ai_model: None,
+            ai_pr_model: None,
             ai_ollama_url: None,
+            ai_model_prices: AiModelPrice::defaults(),
 
             // SSH
             default_ssh_key: None,
 
+            // Terminal
+            terminal_app: None,
+            terminal_command_template: None,
+            terminal_profile: None,
+
             // Notifications
             notification_history_capacity: 50,
 
@@ -227,7 +309,9 @@ mod tests {
         // Commit
         assert!(!settings.spell_check_commit_messages);
         assert!(!settings.conventional_commits_enabled);
+        assert!(!settings.commit_lint.enabled);
         assert!(settings.conventional_commits_scopes.is_none());
+        assert_eq!(settings.changelog_sections, ChangelogSection::defaults());
 
         // AI
         assert!(!settings.ai_enabled);
@@ -263,6 +347,8 @@ mod tests {
             confirm_before_discard: false,
             sign_commits: true,
             bypass_hooks: true,
+            file_watcher_debounce_ms: Some(500),
+            shared_object_store_roots: Vec::new(),
             signing_format: SigningFormat::Ssh,
             signing_key: Some("~/.ssh/id_ed25519".to_string()),
             gpg_program: None,
@@ -270,14 +356,28 @@ mod tests {
             diff_context_lines: 5,
             diff_word_wrap: true,
             diff_side_by_side: true,
+            diff_preset_workdir: None,
+            diff_preset_commit: Some("Review".to_string()),
+            diff_preset_blame: None,
+            diff_function_context: false,
+            diff_structured: false,
+            diff_notebook_outputs: NotebookOutputMode::Ignore,
+            diff_structured_json_extensions: vec!["json".to_string()],
             spell_check_commit_messages: true,
             conventional_commits_enabled: true,
             conventional_commits_scopes: Some(vec!["ui".to_string(), "api".to_string()]),
+            commit_lint: CommitLintConfig::default(),
+            changelog_sections: ChangelogSection::defaults(),
             ai_enabled: true,
             ai_provider: AiProvider::OpenAi,
             ai_model: Some("gpt-4".to_string()),
+            ai_pr_model: None,
             ai_ollama_url: None,
+            ai_model_prices: Vec::new(),
             default_ssh_key: Some("~/.ssh/id_work".to_string()),
+            terminal_app: Some(TerminalApp::WezTerm),
+            terminal_command_template: None,
+            terminal_profile: None,
             notification_history_capacity: 100,
             gravatar_enabled: true,
             auto_update_enabled: false,
@@ -360,6 +460,7 @@ mod tests {
             ai_enabled: true,
             ai_provider: AiProvider::Ollama,
             ai_model: Some("llama2".to_string()),
+            ai_pr_model: None,
             ai_ollama_url: Some("http://localhost:11434".to_string()),
             ..AppSettings::default()
         };
@@ -14,6 +14,20 @@ pub enum SigningFormat {
     Ssh,
 }
 
+impl SigningFormat {
+    /// Format of an armored signature, judged by its header
+    pub fn detect(signature: &str) -> Self {
+        if signature
+            .trim_start()
+            .starts_with("-----BEGIN SSH SIGNATURE-----")
+        {
+            Self::Ssh
+        } else {
+            Self::Gpg
+        }
+    }
+}
+
 /// Configuration for commit signing (how to sign, not whether to sign)
 #[derive(Debug, Clone, Serialize, Deserialize, Default, Type)]
 #[serde(rename_all = "camelCase")]
@@ -42,6 +56,15 @@ pub struct GpgKey {
     pub is_default: bool,
 }
 
+/// A key created by `generate_gpg_key`
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct GeneratedGpgKey {
+    /// Long key ID (last 16 hex digits of the fingerprint)
+    pub key_id: String,
+    pub fingerprint: String,
+}
+
 /// Represents an SSH key available for signing
 #[derive(Debug, Clone, Serialize, Deserialize, Type)]
 #[serde(rename_all = "camelCase")]
@@ -76,6 +99,15 @@ pub struct SignatureVerification {
     pub signer: Option<String>,
 }
 
+/// Signature verification for one commit of a batch
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct CommitSignatureResult {
+    pub oid: String,
+    /// `None` if the commit is unsigned or could not be read
+    pub verification: Option<SignatureVerification>,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -244,6 +276,19 @@ mod tests {
         assert!(json.contains("\"isDefault\":false"));
     }
 
+    // ==================== GeneratedGpgKey Tests ====================
+
+    #[test]
+    fn test_generated_gpg_key_serialization() {
+        let key = GeneratedGpgKey {
+            key_id: "E34A26F4D54547F9".to_string(),
+            fingerprint: "83AECEC2EBF7A763148156D3E34A26F4D54547F9".to_string(),
+        };
+        let json = serde_json::to_string(&key).expect("should serialize");
+        assert!(json.contains("\"keyId\":\"E34A26F4D54547F9\""));
+        assert!(json.contains("\"fingerprint\":\"83AECEC2EBF7A763148156D3E34A26F4D54547F9\""));
+    }
+
     // ==================== SshKey Tests ====================
 
     #[test]
@@ -325,6 +370,18 @@ mod tests {
         assert!(json.contains("\"programUsed\":\"gpg\""));
     }
 
+    #[test]
+    fn test_signing_format_detect() {
+        assert_eq!(
+            SigningFormat::detect("-----BEGIN SSH SIGNATURE-----\nabc"),
+            SigningFormat::Ssh
+        );
+        assert_eq!(
+            SigningFormat::detect("-----BEGIN PGP SIGNATURE-----\nabc"),
+            SigningFormat::Gpg
+        );
+    }
+
     // ==================== SignatureVerification Tests ====================
 
     #[test]
@@ -0,0 +1,28 @@
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+/// Suggestions returned per misspelled word
+pub const SPELLING_MAX_SUGGESTIONS: usize = 5;
+
+/// A word in a commit message the dictionary does not know. Lines and columns are
+/// 1-based and count characters, not bytes.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct SpellingFinding {
+    pub word: String,
+    pub line: u32,
+    pub column: u32,
+    /// Characters covered, starting at `column`
+    pub length: u32,
+    /// Replacements, best first
+    pub suggestions: Vec<String>,
+}
+
+/// Outcome of spell checking a commit message
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct SpellCheckResult {
+    /// Dictionary the message was checked with, e.g. `en_US`
+    pub language: String,
+    pub findings: Vec<SpellingFinding>,
+}
@@ -54,6 +54,18 @@ pub struct SshKeyInfo {
     pub created_at: Option<String>,
 }
 
+/// An identity currently loaded in the SSH agent
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct SshAgentIdentity {
+    /// Key fingerprint (SHA256)
+    pub fingerprint: String,
+    /// Comment the key was added with (usually the key path or email)
+    pub comment: String,
+    /// Key type as reported by the agent (`ED25519`, `RSA`, ...)
+    pub key_type: String,
+}
+
 /// Options for generating a new SSH key
 #[derive(Debug, Clone, Serialize, Deserialize, Type)]
 #[serde(rename_all = "camelCase")]
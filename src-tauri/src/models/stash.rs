@@ -38,6 +38,15 @@ pub struct StashSaveOptions {
     pub include_ignored: bool,
 }
 
+/// Hunks of one file's unstaged diff to stash
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct StashHunkSelection {
+    pub path: String,
+    /// Indices into the file's workdir diff hunks, as shown in the diff view
+    pub hunk_indices: Vec<usize>,
+}
+
 /// Options for applying/popping a stash
 #[derive(Debug, Clone, Serialize, Deserialize, Default, Type)]
 #[serde(rename_all = "camelCase")]
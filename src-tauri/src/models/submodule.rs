@@ -23,6 +23,20 @@ pub struct Submodule {
     pub status: SubmoduleStatus,
 }
 
+/// A submodule whose checked-out commit differs from the one recorded by the superproject
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct OutOfSyncSubmodule {
+    /// Submodule name
+    pub name: String,
+    /// Path within the parent repository
+    pub path: String,
+    /// Commit recorded by the superproject's HEAD
+    pub recorded_oid: String,
+    /// Commit currently checked out in the submodule
+    pub checked_out_oid: String,
+}
+
 /// Status of a submodule
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Default, Type)]
 #[serde(rename_all = "PascalCase")]
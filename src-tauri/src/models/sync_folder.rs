@@ -0,0 +1,68 @@
+use crate::models::ObjectSharing;
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use std::path::PathBuf;
+use strum::Display;
+
+/// Cloud file sync client that owns a folder
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Type, Display)]
+#[serde(rename_all = "PascalCase")]
+pub enum SyncProvider {
+    Dropbox,
+    #[strum(serialize = "OneDrive")]
+    OneDrive,
+    #[strum(serialize = "iCloud Drive")]
+    ICloudDrive,
+    #[strum(serialize = "Google Drive")]
+    GoogleDrive,
+}
+
+/// Warning for a repository stored inside a cloud-synced folder
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct SyncFolderWarning {
+    /// Sync client that owns the folder
+    pub provider: SyncProvider,
+    /// Root of the synced folder containing the repository
+    pub sync_root: PathBuf,
+    /// Whether the `.git` directory itself is synced (the main corruption risk)
+    pub git_dir_inside: bool,
+    /// What can go wrong
+    pub risks: Vec<String>,
+    /// How to avoid it
+    pub remediations: Vec<String>,
+    /// Whether the user dismissed this warning for the repository
+    pub acknowledged: bool,
+}
+
+/// Health report for the open repository
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct RepositoryHealth {
+    /// Repository working directory
+    pub path: PathBuf,
+    /// Set when the repository lives inside a cloud-synced folder
+    pub sync_folder: Option<SyncFolderWarning>,
+    /// Object stores borrowed from and by other repositories
+    pub object_sharing: ObjectSharing,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // ==================== SyncProvider Tests ====================
+
+    #[test]
+    fn test_sync_provider_display() {
+        assert_eq!(SyncProvider::Dropbox.to_string(), "Dropbox");
+        assert_eq!(SyncProvider::ICloudDrive.to_string(), "iCloud Drive");
+        assert_eq!(SyncProvider::GoogleDrive.to_string(), "Google Drive");
+    }
+
+    #[test]
+    fn test_sync_provider_serialization() {
+        let json = serde_json::to_string(&SyncProvider::OneDrive).expect("should serialize");
+        assert_eq!(json, "\"OneDrive\"");
+    }
+}
@@ -81,6 +81,177 @@ pub struct TagResult {
     pub tag: Option<Tag>,
 }
 
+/// One tag to create in `tag_create_bulk`
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct BulkTagSpec {
+    pub name: String,
+    /// Commit or ref to tag; takes precedence over `options.target`
+    pub target: String,
+    #[serde(default)]
+    pub options: CreateTagOptions,
+}
+
+/// Options for `tag_delete_by_pattern`
+#[derive(Debug, Clone, Serialize, Deserialize, Default, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct DeleteTagsByPatternOptions {
+    /// Only list the matching tags so the user can confirm; nothing is deleted
+    #[serde(default)]
+    pub confirm_required: bool,
+    /// Remotes to delete the matching tags from as well
+    #[serde(default)]
+    pub remotes: Vec<String>,
+}
+
+/// Outcome for one tag, locally or on one remote
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct TagOperationResult {
+    pub name: String,
+    /// Remote the result applies to; `None` for the local repository
+    pub remote: Option<String>,
+    pub success: bool,
+    pub error: Option<String>,
+}
+
+impl TagOperationResult {
+    pub fn succeeded(name: &str, remote: Option<&str>) -> Self {
+        Self {
+            name: name.to_string(),
+            remote: remote.map(str::to_string),
+            success: true,
+            error: None,
+        }
+    }
+
+    pub fn failed(name: &str, remote: Option<&str>, error: impl Into<String>) -> Self {
+        Self {
+            name: name.to_string(),
+            remote: remote.map(str::to_string),
+            success: false,
+            error: Some(error.into()),
+        }
+    }
+}
+
+/// Result of `tag_delete_by_pattern`
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct DeleteTagsByPatternResult {
+    /// Local tags matching the pattern, in natural order
+    pub matched: Vec<String>,
+    /// Whether a deletion was attempted (false when only confirming)
+    pub deleted: bool,
+    /// Local results first, then one result per tag and remote
+    pub results: Vec<TagOperationResult>,
+}
+
+/// Options for pushing tags to, or deleting them from, a remote
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct TagPushOptions {
+    /// Update every tag or none of them (`git push --atomic`)
+    #[serde(default)]
+    pub atomic: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "PascalCase")]
+pub enum TagPushStatus {
+    Pushed,
+    Deleted,
+    /// The remote already had the tag at the same object (or, for deletes, not at all)
+    UpToDate,
+    Rejected,
+}
+
+/// Outcome for one tag of a remote tag push or delete
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct TagPushOutcome {
+    pub name: String,
+    pub status: TagPushStatus,
+    /// Reason given for a rejected or skipped tag
+    pub message: Option<String>,
+}
+
+impl TagPushOutcome {
+    pub fn new(name: &str, status: TagPushStatus, message: Option<String>) -> Self {
+        Self {
+            name: name.to_string(),
+            status,
+            message,
+        }
+    }
+}
+
+/// Result of `tag_push`, `tag_push_all` and `tag_delete_remote`
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct TagPushResult {
+    pub remote: String,
+    /// False when any tag was rejected
+    pub success: bool,
+    pub outcomes: Vec<TagPushOutcome>,
+}
+
+impl TagPushResult {
+    pub fn new(remote: &str, outcomes: Vec<TagPushOutcome>) -> Self {
+        Self {
+            remote: remote.to_string(),
+            success: outcomes.iter().all(|o| o.status != TagPushStatus::Rejected),
+            outcomes,
+        }
+    }
+}
+
+/// A tag ref still to be sent to the remote
+#[derive(Debug, Clone)]
+pub struct PendingTagRef {
+    pub name: String,
+    /// `refs/tags/<name>:refs/tags/<name>`, or `:refs/tags/<name>` to delete
+    pub refspec: String,
+    /// Object the remote had when the push was planned; `None` when it lacked the tag
+    pub remote_oid: Option<String>,
+}
+
+impl PendingTagRef {
+    pub fn is_delete(&self) -> bool {
+        self.refspec.starts_with(':')
+    }
+}
+
+/// Tags to push or delete, split into those settled by comparing with the remote's
+/// refs and those that still have to be sent
+#[derive(Debug, Clone, Default)]
+pub struct TagPushPlan {
+    pub settled: Vec<TagPushOutcome>,
+    pub pending: Vec<PendingTagRef>,
+}
+
+impl TagPushPlan {
+    /// Whether some tag was rejected before anything was sent
+    pub fn has_rejections(&self) -> bool {
+        self.settled
+            .iter()
+            .any(|o| o.status == TagPushStatus::Rejected)
+    }
+
+    /// Reject every pending tag without sending it, as an atomic push would
+    pub fn abort(self, remote: &str) -> TagPushResult {
+        let mut outcomes = self.settled;
+        outcomes.extend(self.pending.iter().map(|p| {
+            TagPushOutcome::new(
+                &p.name,
+                TagPushStatus::Rejected,
+                Some("atomic push aborted: another tag was rejected".to_string()),
+            )
+        }));
+        TagPushResult::new(remote, outcomes)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -336,4 +507,45 @@ mod tests {
         assert!(json.contains("\"success\":true"));
         assert!(json.contains("\"message\":\"OK\""));
     }
+
+    #[test]
+    fn test_tag_push_plan_abort_rejects_pending_tags() {
+        let plan = TagPushPlan {
+            settled: vec![
+                TagPushOutcome::new("v1", TagPushStatus::UpToDate, None),
+                TagPushOutcome::new(
+                    "v2",
+                    TagPushStatus::Rejected,
+                    Some("already exists".to_string()),
+                ),
+            ],
+            pending: vec![PendingTagRef {
+                name: "v3".to_string(),
+                refspec: "refs/tags/v3:refs/tags/v3".to_string(),
+                remote_oid: None,
+            }],
+        };
+        assert!(plan.has_rejections());
+
+        let result = plan.abort("origin");
+        assert!(!result.success);
+        assert_eq!(result.outcomes.len(), 3);
+        assert_eq!(result.outcomes[2].name, "v3");
+        assert_eq!(result.outcomes[2].status, TagPushStatus::Rejected);
+    }
+
+    #[test]
+    fn test_tag_push_result_success_ignores_up_to_date() {
+        let result = TagPushResult::new(
+            "origin",
+            vec![
+                TagPushOutcome::new("v1", TagPushStatus::UpToDate, None),
+                TagPushOutcome::new("v2", TagPushStatus::Pushed, None),
+            ],
+        );
+        assert!(result.success);
+
+        let json = serde_json::to_string(&result).expect("should serialize");
+        assert!(json.contains("\"status\":\"UpToDate\""));
+    }
 }
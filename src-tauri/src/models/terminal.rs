@@ -0,0 +1,58 @@
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use strum::Display;
+
+/// Terminal emulator `open_terminal` launches
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Type, Display)]
+#[serde(rename_all = "PascalCase")]
+pub enum TerminalApp {
+    /// macOS Terminal.app
+    Terminal,
+    #[strum(to_string = "iTerm2")]
+    ITerm2,
+    WezTerm,
+    Alacritty,
+    #[strum(to_string = "kitty")]
+    Kitty,
+    #[strum(to_string = "GNOME Terminal")]
+    GnomeTerminal,
+    Konsole,
+    #[strum(to_string = "Windows Terminal")]
+    WindowsTerminal,
+    #[strum(to_string = "Command Prompt")]
+    Cmd,
+    PowerShell,
+    /// `terminal_command_template` from the settings
+    Custom,
+}
+
+/// A terminal found on this machine
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct AvailableTerminal {
+    pub app: TerminalApp,
+    /// Display name, e.g. "Windows Terminal"
+    pub name: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // ==================== TerminalApp Tests ====================
+
+    #[test]
+    fn test_terminal_app_display() {
+        assert_eq!(TerminalApp::ITerm2.to_string(), "iTerm2");
+        assert_eq!(TerminalApp::GnomeTerminal.to_string(), "GNOME Terminal");
+        assert_eq!(TerminalApp::WezTerm.to_string(), "WezTerm");
+    }
+
+    #[test]
+    fn test_terminal_app_serialization() {
+        let json = serde_json::to_string(&TerminalApp::WindowsTerminal).expect("should serialize");
+        assert_eq!(json, "\"WindowsTerminal\"");
+        let app: TerminalApp = serde_json::from_str("\"ITerm2\"").expect("should deserialize");
+        assert_eq!(app, TerminalApp::ITerm2);
+    }
+}
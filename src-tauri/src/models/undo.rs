@@ -0,0 +1,101 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use strum::{Display, EnumString};
+
+/// Maximum number of undo journal entries kept per repository
+pub const UNDO_JOURNAL_MAX_ENTRIES: usize = 50;
+
+/// Refs that keep snapshot commits reachable, one per journal entry (`refs/axis/undo/<id>`)
+pub const UNDO_REF_PREFIX: &str = "refs/axis/undo/";
+
+/// Destructive operation that recorded an undo snapshot
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Type, Display, EnumString)]
+#[serde(rename_all = "PascalCase")]
+#[strum(serialize_all = "snake_case")]
+pub enum UndoOperation {
+    Merge,
+    Rebase,
+    Reset,
+    Checkout,
+    Discard,
+    StashPop,
+    Amend,
+    Undo,
+}
+
+/// Repository state captured before a destructive operation
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct UndoSnapshot {
+    /// HEAD commit before the operation
+    pub head_oid: String,
+    /// Checked out branch, or `None` when HEAD was detached
+    pub branch: Option<String>,
+    /// Dangling stash commit (`git stash create`) holding uncommitted changes
+    pub stash_oid: Option<String>,
+    /// Whether the snapshot covers the whole working tree (false if untracked files existed)
+    pub complete: bool,
+}
+
+/// An entry in the undo journal
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct UndoEntry {
+    /// Database row ID
+    pub id: i64,
+    /// When the snapshot was taken
+    pub timestamp: DateTime<Utc>,
+    /// Repository the snapshot belongs to
+    pub repo_path: String,
+    /// Operation the snapshot was taken for
+    pub operation: UndoOperation,
+    /// Captured repository state
+    pub snapshot: UndoSnapshot,
+}
+
+/// Result of undoing the last operation
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct UndoResult {
+    /// The journal entry that was undone
+    pub entry: UndoEntry,
+    /// Whether the full pre-operation state was restored
+    pub complete: bool,
+    /// Human-readable summary, including what could not be restored
+    pub message: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    // ==================== UndoOperation Tests ====================
+
+    #[test]
+    fn test_undo_operation_storage_roundtrip() {
+        assert_eq!(UndoOperation::StashPop.to_string(), "stash_pop");
+        assert_eq!(
+            UndoOperation::from_str("discard").expect("should parse"),
+            UndoOperation::Discard
+        );
+    }
+
+    // ==================== UndoSnapshot Tests ====================
+
+    #[test]
+    fn test_undo_snapshot_serialization() {
+        let snapshot = UndoSnapshot {
+            head_oid: "abc".to_string(),
+            branch: Some("main".to_string()),
+            stash_oid: None,
+            complete: true,
+        };
+        let json = serde_json::to_string(&snapshot).expect("should serialize");
+        assert_eq!(
+            json,
+            r#"{"headOid":"abc","branch":"main","stashOid":null,"complete":true}"#
+        );
+    }
+}
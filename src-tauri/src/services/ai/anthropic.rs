@@ -2,8 +2,14 @@ use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
 
 use crate::error::{AxisError, Result};
-use crate::services::ai::prompt::{build_pr_prompt, build_prompt, parse_pr_response};
-use crate::services::ai::provider::AiProviderTrait;
+use crate::models::{AiModelInfo, CommitMessageStyle};
+use crate::services::ai::prompt::{
+    build_explain_prompt, build_pr_prompt, build_prompt, parse_pr_response, CommitChanges,
+    PrPromptInput,
+};
+use crate::services::ai::provider::{
+    error_from_response, read_lines, request_error, AiProviderTrait, ChunkCallback,
+};
 
 pub struct AnthropicProvider;
 
@@ -13,6 +19,8 @@ struct AnthropicRequest {
     max_tokens: u32,
     system: String,
     messages: Vec<AnthropicMessage>,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    stream: bool,
 }
 
 #[derive(Serialize)]
@@ -31,30 +39,91 @@ struct AnthropicContent {
     text: String,
 }
 
-#[async_trait]
-impl AiProviderTrait for AnthropicProvider {
-    async fn generate_commit_message(
+#[derive(Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum AnthropicStreamEvent {
+    ContentBlockDelta {
+        delta: AnthropicDelta,
+    },
+    Error {
+        error: AnthropicStreamError,
+    },
+    #[serde(other)]
+    Other,
+}
+
+#[derive(Deserialize)]
+struct AnthropicDelta {
+    #[serde(default)]
+    text: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct AnthropicStreamError {
+    #[serde(rename = "type")]
+    kind: String,
+    message: String,
+}
+
+/// Extract the text delta from one server-sent event line
+fn parse_stream_line(line: &str) -> Result<Option<String>> {
+    let Some(data) = line.strip_prefix("data:") else {
+        return Ok(None);
+    };
+    match serde_json::from_str::<AnthropicStreamEvent>(data.trim()) {
+        Ok(AnthropicStreamEvent::ContentBlockDelta { delta }) => Ok(delta.text),
+        Ok(AnthropicStreamEvent::Error { error }) => match error.kind.as_str() {
+            "rate_limit_error" | "overloaded_error" => {
+                Err(AxisError::AiRateLimited("Anthropic".to_string()))
+            }
+            _ => Err(AxisError::AiServiceError(format!(
+                "Anthropic API error ({}): {}",
+                error.kind, error.message
+            ))),
+        },
+        Ok(AnthropicStreamEvent::Other) => Ok(None),
+        Err(e) => {
+            log::warn!("Skipping unparsable Anthropic stream event: {e}");
+            Ok(None)
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct AnthropicModelsResponse {
+    data: Vec<AnthropicModel>,
+}
+
+#[derive(Deserialize)]
+struct AnthropicModel {
+    id: String,
+}
+
+impl AnthropicProvider {
+    /// Stream a chat response to `on_chunk`, returning the full text and the model used
+    async fn stream_chat(
         &self,
-        diff: &str,
+        system_prompt: String,
+        user_prompt: String,
+        max_tokens: u32,
         api_key: Option<&str>,
         model: Option<&str>,
-        _base_url: Option<&str>,
-        conventional_commits: bool,
+        on_chunk: ChunkCallback<'_>,
     ) -> Result<(String, String)> {
         let api_key =
             api_key.ok_or_else(|| AxisError::ApiKeyNotConfigured("Anthropic".to_string()))?;
 
         let model = model.unwrap_or(self.default_model()).to_string();
-        let (system_prompt, user_prompt) = build_prompt(diff, conventional_commits);
 
         let request = AnthropicRequest {
             model: model.clone(),
-            max_tokens: 500,
+            max_tokens,
             system: system_prompt,
             messages: vec![AnthropicMessage {
                 role: "user".to_string(),
                 content: user_prompt,
             }],
+            stream: true,
         };
 
         let client = reqwest::Client::new();
@@ -66,38 +135,65 @@ impl AiProviderTrait for AnthropicProvider {
             .json(&request)
             .send()
             .await
-            .map_err(|e| AxisError::AiServiceError(format!("Request failed: {e}")))?;
+            .map_err(|e| request_error("Anthropic", false, &e))?;
 
         if !response.status().is_success() {
-            let status = response.status();
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|_| "Unknown error".to_string());
-            return Err(AxisError::AiServiceError(format!(
-                "Anthropic API error ({status}): {error_text}"
-            )));
+            return Err(error_from_response("Anthropic", &model, response).await);
         }
 
-        let response: AnthropicResponse = response
-            .json()
-            .await
-            .map_err(|e| AxisError::AiServiceError(format!("Failed to parse response: {e}")))?;
-
-        let message = response
-            .content
-            .first()
-            .map(|c| c.text.trim().to_string())
-            .ok_or_else(|| AxisError::AiServiceError("No response from Anthropic".to_string()))?;
+        let mut message = String::new();
+        read_lines(response, |line| {
+            if let Some(delta) = parse_stream_line(line)? {
+                on_chunk(&delta);
+                message.push_str(&delta);
+            }
+            Ok(())
+        })
+        .await?;
+
+        let message = message.trim().to_string();
+        if message.is_empty() {
+            return Err(AxisError::AiServiceError(
+                "No response from Anthropic".to_string(),
+            ));
+        }
 
         Ok((message, model))
     }
+}
+
+#[async_trait]
+impl AiProviderTrait for AnthropicProvider {
+    async fn generate_commit_message(
+        &self,
+        changes: CommitChanges<'_>,
+        style: CommitMessageStyle,
+        api_key: Option<&str>,
+        model: Option<&str>,
+        _base_url: Option<&str>,
+        on_chunk: ChunkCallback<'_>,
+    ) -> Result<(String, String)> {
+        let (system_prompt, user_prompt) = build_prompt(changes, style);
+        self.stream_chat(system_prompt, user_prompt, 500, api_key, model, on_chunk)
+            .await
+    }
+
+    async fn explain_diff(
+        &self,
+        diff: &str,
+        api_key: Option<&str>,
+        model: Option<&str>,
+        _base_url: Option<&str>,
+        on_chunk: ChunkCallback<'_>,
+    ) -> Result<(String, String)> {
+        let (system_prompt, user_prompt) = build_explain_prompt(diff);
+        self.stream_chat(system_prompt, user_prompt, 1000, api_key, model, on_chunk)
+            .await
+    }
 
     async fn generate_pr_description(
         &self,
-        commits: &[(String, String)],
-        diff_summary: Option<&str>,
-        available_labels: Option<&[String]>,
+        input: &PrPromptInput<'_>,
         api_key: Option<&str>,
         model: Option<&str>,
         _base_url: Option<&str>,
@@ -106,7 +202,7 @@ impl AiProviderTrait for AnthropicProvider {
             api_key.ok_or_else(|| AxisError::ApiKeyNotConfigured("Anthropic".to_string()))?;
 
         let model = model.unwrap_or(self.default_model()).to_string();
-        let (system_prompt, user_prompt) = build_pr_prompt(commits, diff_summary, available_labels);
+        let (system_prompt, user_prompt) = build_pr_prompt(input);
 
         let request = AnthropicRequest {
             model: model.clone(),
@@ -116,6 +212,7 @@ impl AiProviderTrait for AnthropicProvider {
                 role: "user".to_string(),
                 content: user_prompt,
             }],
+            stream: false,
         };
 
         let client = reqwest::Client::new();
@@ -127,17 +224,10 @@ impl AiProviderTrait for AnthropicProvider {
             .json(&request)
             .send()
             .await
-            .map_err(|e| AxisError::AiServiceError(format!("Request failed: {e}")))?;
+            .map_err(|e| request_error("Anthropic", false, &e))?;
 
         if !response.status().is_success() {
-            let status = response.status();
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|_| "Unknown error".to_string());
-            return Err(AxisError::AiServiceError(format!(
-                "Anthropic API error ({status}): {error_text}"
-            )));
+            return Err(error_from_response("Anthropic", &model, response).await);
         }
 
         let response: AnthropicResponse = response
@@ -155,6 +245,42 @@ impl AiProviderTrait for AnthropicProvider {
         Ok((title, body, labels, model))
     }
 
+    async fn list_models(
+        &self,
+        api_key: Option<&str>,
+        _base_url: Option<&str>,
+    ) -> Result<Vec<AiModelInfo>> {
+        let api_key =
+            api_key.ok_or_else(|| AxisError::ApiKeyNotConfigured("Anthropic".to_string()))?;
+
+        let client = reqwest::Client::new();
+        let response = client
+            .get("https://api.anthropic.com/v1/models")
+            .header("x-api-key", api_key)
+            .header("anthropic-version", "2023-06-01")
+            .send()
+            .await
+            .map_err(|e| request_error("Anthropic", false, &e))?;
+
+        if !response.status().is_success() {
+            return Err(error_from_response("Anthropic", "", response).await);
+        }
+
+        let response: AnthropicModelsResponse = response
+            .json()
+            .await
+            .map_err(|e| AxisError::AiServiceError(format!("Failed to parse response: {e}")))?;
+
+        Ok(response
+            .data
+            .into_iter()
+            .map(|m| AiModelInfo {
+                name: m.id,
+                size: None,
+            })
+            .collect())
+    }
+
     fn default_model(&self) -> &'static str {
         "claude-3-5-haiku-latest"
     }
@@ -195,6 +321,7 @@ mod tests {
                 role: "user".to_string(),
                 content: "Hello".to_string(),
             }],
+            stream: false,
         };
 
         let json = serde_json::to_string(&request).expect("should serialize");
@@ -253,13 +380,48 @@ mod tests {
         assert_eq!(content.text, "Some response text");
     }
 
+    // ==================== Streaming Tests ====================
+
+    #[test]
+    fn test_parse_stream_line_text_delta() {
+        let line = r#"data: {"type":"content_block_delta","index":0,"delta":{"type":"text_delta","text":"Fix"}}"#;
+        assert_eq!(
+            parse_stream_line(line).expect("should parse").as_deref(),
+            Some("Fix")
+        );
+
+        let start = r#"data: {"type":"message_start","message":{}}"#;
+        assert!(parse_stream_line(start).expect("should parse").is_none());
+        assert!(parse_stream_line("event: ping")
+            .expect("should parse")
+            .is_none());
+    }
+
+    #[test]
+    fn test_parse_stream_line_overloaded_is_rate_limited() {
+        let line =
+            r#"data: {"type":"error","error":{"type":"overloaded_error","message":"Overloaded"}}"#;
+
+        assert!(matches!(
+            parse_stream_line(line),
+            Err(AxisError::AiRateLimited(_))
+        ));
+    }
+
     // ==================== API Key Validation Tests ====================
 
     #[tokio::test]
     async fn test_generate_commit_message_no_api_key() {
         let provider = AnthropicProvider;
         let result = provider
-            .generate_commit_message("diff content", None, None, None, false)
+            .generate_commit_message(
+                CommitChanges::Diff("diff content"),
+                CommitMessageStyle::Plain,
+                None,
+                None,
+                None,
+                &|_| {},
+            )
             .await;
 
         assert!(result.is_err());
@@ -273,7 +435,15 @@ mod tests {
         let provider = AnthropicProvider;
         let commits = vec![("abc".to_string(), "test commit".to_string())];
         let result = provider
-            .generate_pr_description(&commits, None, None, None, None, None)
+            .generate_pr_description(
+                &PrPromptInput {
+                    commits: &commits,
+                    ..Default::default()
+                },
+                None,
+                None,
+                None,
+            )
             .await;
 
         assert!(result.is_err());
@@ -281,4 +451,14 @@ mod tests {
         assert!(err.to_string().contains("API key not configured"));
         assert!(err.to_string().contains("Anthropic"));
     }
+
+    #[tokio::test]
+    async fn test_list_models_no_api_key() {
+        let err = AnthropicProvider
+            .list_models(None, None)
+            .await
+            .expect_err("should be error");
+
+        assert!(matches!(err, AxisError::ApiKeyNotConfigured(p) if p == "Anthropic"));
+    }
 }
@@ -3,16 +3,28 @@ mod ollama;
 mod openai;
 mod prompt;
 mod provider;
+mod tokens;
+mod usage;
 
 pub use anthropic::AnthropicProvider;
 pub use ollama::OllamaProvider;
 pub use openai::OpenAiProvider;
-pub use provider::AiProviderTrait;
+pub use prompt::{CommitChanges, PrPromptInput};
+pub use provider::{AiProviderTrait, ChunkCallback};
+pub use tokens::estimate_tokens;
+pub use usage::{AiRequestLimiter, AiRequestRecord, AiUsageStore, TrackedProvider};
 
+use std::collections::HashMap;
 use std::fmt::Write;
 
-use crate::error::AxisError;
-use crate::models::{AiProvider, DiffLineType, FileDiff};
+use parking_lot::Mutex;
+
+use crate::error::{AxisError, Result};
+use crate::models::{
+    AiProvider, BranchCompareResult, CommitMessageStyle, DiffLineType, ExplainDiffResponse,
+    FileDiff, GenerateCommitMessageResponse, GeneratePrDescriptionOptions,
+    GeneratePrDescriptionResponse,
+};
 
 pub fn create_provider(provider: &AiProvider) -> Box<dyn AiProviderTrait> {
     match provider {
@@ -34,7 +46,7 @@ const MAX_DIFF_SIZE: usize = 100_000;
 const MAX_FILES_IN_SUMMARY: usize = 30;
 
 /// Format file diffs as a unified diff string suitable for AI consumption.
-pub fn format_diff_for_ai(diffs: &[FileDiff]) -> crate::error::Result<String> {
+pub fn format_diff_for_ai(diffs: &[FileDiff]) -> Result<String> {
     let mut output = String::new();
 
     for file_diff in diffs {
@@ -97,9 +109,877 @@ pub fn format_diff_summary(files: &[FileDiff]) -> String {
     summary
 }
 
+/// Generate a commit message for the staged diffs. When the diff is over the
+/// size limit the provider gets a file summary instead.
+pub async fn generate_commit_message(
+    provider: &dyn AiProviderTrait,
+    diffs: &[FileDiff],
+    style: CommitMessageStyle,
+    api_key: Option<&str>,
+    model: Option<&str>,
+    base_url: Option<&str>,
+    on_chunk: ChunkCallback<'_>,
+) -> Result<GenerateCommitMessageResponse> {
+    let (text, from_summary) = match format_diff_for_ai(diffs) {
+        Ok(diff) => (diff, false),
+        Err(AxisError::DiffTooLarge(size)) => {
+            log::info!("Staged diff is {size} bytes, sending a file summary instead");
+            (format_diff_summary(diffs), true)
+        }
+        Err(e) => return Err(e),
+    };
+
+    if text.trim().is_empty() {
+        return Err(AxisError::AiServiceError(
+            "No staged changes to generate commit message from".to_string(),
+        ));
+    }
+
+    let changes = if from_summary {
+        CommitChanges::Summary(&text)
+    } else {
+        CommitChanges::Diff(&text)
+    };
+
+    let (raw, model_used) = provider
+        .generate_commit_message(changes, style, api_key, model, base_url, on_chunk)
+        .await?;
+
+    let (subject, body) = prompt::parse_commit_message(&raw);
+    if subject.is_empty() {
+        return Err(AxisError::AiServiceError(format!(
+            "{model_used} returned an empty commit message"
+        )));
+    }
+
+    if style == CommitMessageStyle::Conventional
+        && crate::services::parse_conventional_header(&subject).is_none()
+    {
+        log::warn!("{model_used} returned a subject that is not a conventional commit: {subject}");
+    }
+
+    let message = match &body {
+        Some(body) => format!("{subject}\n\n{body}"),
+        None => subject.clone(),
+    };
+
+    Ok(GenerateCommitMessageResponse {
+        message,
+        subject,
+        body,
+        model_used,
+        from_summary,
+    })
+}
+
+/// Explanations kept before the cache starts over
+const MAX_CACHED_EXPLANATIONS: usize = 256;
+
+/// Identifies an explained slice: the file's blob oids, the hunk header when a
+/// single hunk was explained, and the model that explained it
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct ExplainCacheKey {
+    old_oid: Option<String>,
+    new_oid: String,
+    hunk_header: Option<String>,
+    model: String,
+}
+
+/// In-memory cache of diff explanations, so re-opening a hunk does not re-query the model
+pub struct ExplainCache {
+    entries: Mutex<HashMap<ExplainCacheKey, (String, String)>>,
+}
+
+impl ExplainCache {
+    pub fn new() -> Self {
+        Self {
+            entries: Mutex::new(HashMap::new()),
+        }
+    }
+
+    fn get(&self, key: &ExplainCacheKey) -> Option<(String, String)> {
+        self.entries.lock().get(key).cloned()
+    }
+
+    fn insert(&self, key: ExplainCacheKey, explanation: (String, String)) {
+        let mut entries = self.entries.lock();
+        if entries.len() >= MAX_CACHED_EXPLANATIONS {
+            entries.clear();
+        }
+        entries.insert(key, explanation);
+    }
+}
+
+impl Default for ExplainCache {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Format one file diff, or only its hunk at `hunk_index`, like `format_diff_for_ai`
+pub fn format_diff_slice_for_ai(file: &FileDiff, hunk_index: Option<u32>) -> Result<String> {
+    let path = file
+        .new_path
+        .as_ref()
+        .or(file.old_path.as_ref())
+        .map_or("unknown", std::string::String::as_str);
+    if file.binary {
+        return Err(AxisError::BinaryDiff(path.to_string()));
+    }
+
+    let hunks = match hunk_index {
+        Some(index) => {
+            let hunk = usize::try_from(index)
+                .ok()
+                .and_then(|i| file.hunks.get(i))
+                .ok_or(AxisError::HunkNotFound(index))?;
+            vec![hunk.clone()]
+        }
+        None => file.hunks.clone(),
+    };
+
+    format_diff_for_ai(&[FileDiff {
+        hunks,
+        ..file.clone()
+    }])
+}
+
+/// Explain one file diff or one of its hunks, reusing a cached explanation of the
+/// same blobs and hunk when there is one
+#[allow(clippy::too_many_arguments)]
+pub async fn explain_diff(
+    provider: &dyn AiProviderTrait,
+    cache: &ExplainCache,
+    file: &FileDiff,
+    hunk_index: Option<u32>,
+    api_key: Option<&str>,
+    model: Option<&str>,
+    base_url: Option<&str>,
+    on_chunk: ChunkCallback<'_>,
+) -> Result<ExplainDiffResponse> {
+    let diff = format_diff_slice_for_ai(file, hunk_index)?;
+
+    let key = ExplainCacheKey {
+        old_oid: file.old_oid.clone(),
+        // Working tree files have no blob yet, so hash the diff text instead
+        new_oid: file.new_oid.clone().unwrap_or_else(|| {
+            git2::Oid::hash_object(git2::ObjectType::Blob, diff.as_bytes())
+                .map(|oid| oid.to_string())
+                .unwrap_or_else(|_| diff.clone())
+        }),
+        hunk_header: hunk_index
+            .and_then(|i| file.hunks.get(usize::try_from(i).ok()?))
+            .map(|hunk| hunk.header.clone()),
+        model: model.unwrap_or(provider.default_model()).to_string(),
+    };
+
+    if let Some((explanation, model_used)) = cache.get(&key) {
+        return Ok(ExplainDiffResponse {
+            explanation,
+            model_used,
+            cached: true,
+        });
+    }
+
+    let (explanation, model_used) = provider
+        .explain_diff(&diff, api_key, model, base_url, on_chunk)
+        .await?;
+    let explanation = explanation.trim().to_string();
+    if explanation.is_empty() {
+        return Err(AxisError::AiServiceError(format!(
+            "{model_used} returned an empty explanation"
+        )));
+    }
+
+    cache.insert(key, (explanation.clone(), model_used.clone()));
+    Ok(ExplainDiffResponse {
+        explanation,
+        model_used,
+        cached: false,
+    })
+}
+
+/// Issue number from a branch name such as `feature/1234-add-search`.
+/// Only the last path segment is considered, so remote prefixes are ignored.
+pub fn linked_issue_number(branch: &str) -> Option<u64> {
+    let name = branch.rsplit('/').next()?;
+    let digits_end = name
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(name.len());
+    if !matches!(name[digits_end..].chars().next(), None | Some('-' | '_')) {
+        return None;
+    }
+    name[..digits_end].parse().ok().filter(|issue| *issue > 0)
+}
+
+/// Generate a PR title and body for the commits `base_ref` has over `compare_ref`.
+/// When the aggregate diff is over the size limit the provider gets a file summary instead.
+pub async fn generate_pr_description(
+    provider: &dyn AiProviderTrait,
+    compare: &BranchCompareResult,
+    options: &GeneratePrDescriptionOptions,
+    api_key: Option<&str>,
+    model: Option<&str>,
+    base_url: Option<&str>,
+) -> Result<GeneratePrDescriptionResponse> {
+    if compare.ahead_commits.is_empty() {
+        return Err(AxisError::AiServiceError(
+            "No commits between branches to generate PR description from".to_string(),
+        ));
+    }
+
+    let commits: Vec<(String, String)> = compare
+        .ahead_commits
+        .iter()
+        .map(|c| (c.short_oid.clone(), c.summary.clone()))
+        .collect();
+
+    let (text, from_summary) = if options.include_diff.unwrap_or(true) && !compare.files.is_empty()
+    {
+        match format_diff_for_ai(&compare.files) {
+            Ok(diff) => (Some(diff), false),
+            Err(AxisError::DiffTooLarge(size)) => {
+                log::info!("Branch diff is {size} bytes, sending a file summary instead");
+                (Some(format_diff_summary(&compare.files)), true)
+            }
+            Err(e) => return Err(e),
+        }
+    } else {
+        (None, false)
+    };
+
+    let changes = text.as_deref().map(|text| {
+        if from_summary {
+            CommitChanges::Summary(text)
+        } else {
+            CommitChanges::Diff(text)
+        }
+    });
+
+    let linked_issue = linked_issue_number(&compare.base_ref);
+    let input = PrPromptInput {
+        commits: &commits,
+        changes,
+        available_labels: (!options.available_labels.is_empty())
+            .then_some(options.available_labels.as_slice()),
+        linked_issue,
+    };
+
+    let (title, mut body, labels, model_used) = provider
+        .generate_pr_description(&input, api_key, model, base_url)
+        .await?;
+
+    if let Some(issue) = linked_issue {
+        if !body.contains(&format!("#{issue}")) {
+            let _ = write!(body, "\n\nCloses #{issue}");
+        }
+    }
+
+    Ok(GeneratePrDescriptionResponse {
+        title,
+        body,
+        labels,
+        model_used,
+        linked_issue,
+        from_summary,
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::models::{AiModelInfo, Commit, DiffHunk, DiffLine, DiffStatus, Signature};
+    use async_trait::async_trait;
+
+    /// Records the prompt it was given and replies with a canned message
+    struct MockProvider {
+        reply: &'static str,
+        prompt: Mutex<Option<(String, String)>>,
+        summary_used: Mutex<bool>,
+        explain_calls: Mutex<usize>,
+    }
+
+    impl MockProvider {
+        fn new(reply: &'static str) -> Self {
+            Self {
+                reply,
+                prompt: Mutex::new(None),
+                summary_used: Mutex::new(false),
+                explain_calls: Mutex::new(0),
+            }
+        }
+
+        fn system_prompt(&self) -> String {
+            self.prompt
+                .lock()
+                .as_ref()
+                .map(|(system, _)| system.clone())
+                .unwrap_or_default()
+        }
+
+        fn user_prompt(&self) -> String {
+            self.prompt
+                .lock()
+                .as_ref()
+                .map(|(_, user)| user.clone())
+                .unwrap_or_default()
+        }
+    }
+
+    #[async_trait]
+    impl AiProviderTrait for MockProvider {
+        async fn generate_commit_message(
+            &self,
+            changes: CommitChanges<'_>,
+            style: CommitMessageStyle,
+            _api_key: Option<&str>,
+            _model: Option<&str>,
+            _base_url: Option<&str>,
+            on_chunk: ChunkCallback<'_>,
+        ) -> Result<(String, String)> {
+            *self.summary_used.lock() = matches!(changes, CommitChanges::Summary(_));
+            *self.prompt.lock() = Some(prompt::build_prompt(changes, style));
+            for line in self.reply.split_inclusive('\n') {
+                on_chunk(line);
+            }
+            Ok((self.reply.to_string(), "mock-model".to_string()))
+        }
+
+        async fn explain_diff(
+            &self,
+            diff: &str,
+            _api_key: Option<&str>,
+            _model: Option<&str>,
+            _base_url: Option<&str>,
+            on_chunk: ChunkCallback<'_>,
+        ) -> Result<(String, String)> {
+            *self.explain_calls.lock() += 1;
+            *self.prompt.lock() = Some(prompt::build_explain_prompt(diff));
+            on_chunk(self.reply);
+            Ok((self.reply.to_string(), "mock-model".to_string()))
+        }
+
+        async fn generate_pr_description(
+            &self,
+            input: &PrPromptInput<'_>,
+            _api_key: Option<&str>,
+            _model: Option<&str>,
+            _base_url: Option<&str>,
+        ) -> Result<(String, String, Vec<String>, String)> {
+            *self.summary_used.lock() = matches!(input.changes, Some(CommitChanges::Summary(_)));
+            *self.prompt.lock() = Some(prompt::build_pr_prompt(input));
+            let (title, body, labels) = prompt::parse_pr_response(self.reply);
+            Ok((title, body, labels, "mock-model".to_string()))
+        }
+
+        async fn list_models(
+            &self,
+            _api_key: Option<&str>,
+            _base_url: Option<&str>,
+        ) -> Result<Vec<AiModelInfo>> {
+            Ok(vec![AiModelInfo {
+                name: "mock-model".to_string(),
+                size: None,
+            }])
+        }
+
+        fn default_model(&self) -> &'static str {
+            "mock-model"
+        }
+
+        fn name(&self) -> &'static str {
+            "Mock"
+        }
+    }
+
+    fn file_diff(path: &str, content: &str) -> FileDiff {
+        FileDiff {
+            old_path: Some(path.to_string()),
+            new_path: Some(path.to_string()),
+            old_oid: None,
+            new_oid: None,
+            status: DiffStatus::Modified,
+            binary: false,
+            binary_info: None,
+            structured: None,
+            hunks: vec![DiffHunk {
+                header: "@@ -1 +1 @@".to_string(),
+                old_start: 1,
+                old_lines: 1,
+                new_start: 1,
+                new_lines: 1,
+                lines: vec![DiffLine {
+                    line_type: DiffLineType::Addition,
+                    content: content.to_string(),
+                    old_line_no: None,
+                    new_line_no: Some(1),
+                    inline_spans: None,
+                }],
+                context: None,
+            }],
+            additions: 1,
+            deletions: 0,
+        }
+    }
+
+    // ==================== explain_diff Tests ====================
+
+    fn two_hunk_diff() -> FileDiff {
+        let hunk = |header: &str, content: &str| DiffHunk {
+            header: header.to_string(),
+            old_start: 1,
+            old_lines: 1,
+            new_start: 1,
+            new_lines: 1,
+            lines: vec![DiffLine {
+                line_type: DiffLineType::Addition,
+                content: content.to_string(),
+                old_line_no: None,
+                new_line_no: Some(1),
+                inline_spans: None,
+            }],
+            context: None,
+        };
+        FileDiff {
+            old_oid: Some("1111111".to_string()),
+            new_oid: Some("2222222".to_string()),
+            hunks: vec![
+                hunk("@@ -1 +1 @@", "first_change();"),
+                hunk("@@ -40 +40 @@", "second_change();"),
+            ],
+            ..file_diff("src/lib.rs", "")
+        }
+    }
+
+    #[tokio::test]
+    async fn test_explain_diff_sends_only_requested_hunk() {
+        let provider = MockProvider::new("Calls the second helper");
+        let cache = ExplainCache::new();
+        let chunks = Mutex::new(String::new());
+
+        let response = explain_diff(
+            &provider,
+            &cache,
+            &two_hunk_diff(),
+            Some(1),
+            None,
+            None,
+            None,
+            &|chunk| chunks.lock().push_str(chunk),
+        )
+        .await
+        .expect("should explain");
+
+        let user = provider.user_prompt();
+        assert!(user.contains("@@ -40 +40 @@"));
+        assert!(user.contains("second_change();"));
+        assert!(!user.contains("first_change();"));
+        assert_eq!(response.explanation, "Calls the second helper");
+        assert_eq!(*chunks.lock(), "Calls the second helper");
+        assert!(!response.cached);
+    }
+
+    #[tokio::test]
+    async fn test_explain_diff_whole_file() {
+        let provider = MockProvider::new("Two changes");
+
+        explain_diff(
+            &provider,
+            &ExplainCache::new(),
+            &two_hunk_diff(),
+            None,
+            None,
+            None,
+            None,
+            &|_| {},
+        )
+        .await
+        .expect("should explain");
+
+        let user = provider.user_prompt();
+        assert!(user.contains("first_change();"));
+        assert!(user.contains("second_change();"));
+    }
+
+    #[tokio::test]
+    async fn test_explain_diff_cache_hits() {
+        let provider = MockProvider::new("Explained");
+        let cache = ExplainCache::new();
+        let diff = two_hunk_diff();
+        let explain =
+            |hunk| explain_diff(&provider, &cache, &diff, hunk, None, None, None, &|_| {});
+
+        assert!(!explain(Some(0)).await.expect("should explain").cached);
+        let again = explain(Some(0)).await.expect("should explain");
+        assert!(again.cached);
+        assert_eq!(again.explanation, "Explained");
+        assert_eq!(*provider.explain_calls.lock(), 1);
+
+        // A different hunk of the same blobs is a miss
+        assert!(!explain(Some(1)).await.expect("should explain").cached);
+        assert_eq!(*provider.explain_calls.lock(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_explain_diff_workdir_cache_follows_content() {
+        let provider = MockProvider::new("Explained");
+        let cache = ExplainCache::new();
+
+        for content in ["a();", "a();", "b();"] {
+            explain_diff(
+                &provider,
+                &cache,
+                &file_diff("src/main.rs", content),
+                Some(0),
+                None,
+                None,
+                None,
+                &|_| {},
+            )
+            .await
+            .expect("should explain");
+        }
+
+        assert_eq!(*provider.explain_calls.lock(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_explain_diff_rejects_binary_and_missing_hunk() {
+        let provider = MockProvider::new("unused");
+        let cache = ExplainCache::new();
+        let binary = FileDiff {
+            binary: true,
+            hunks: Vec::new(),
+            ..file_diff("logo.png", "")
+        };
+
+        let err = explain_diff(&provider, &cache, &binary, None, None, None, None, &|_| {})
+            .await
+            .expect_err("should reject binary");
+        assert!(matches!(err, AxisError::BinaryDiff(p) if p == "logo.png"));
+
+        let err = explain_diff(
+            &provider,
+            &cache,
+            &two_hunk_diff(),
+            Some(5),
+            None,
+            None,
+            None,
+            &|_| {},
+        )
+        .await
+        .expect_err("should reject missing hunk");
+        assert!(matches!(err, AxisError::HunkNotFound(5)));
+
+        let big = file_diff("big.txt", &"x".repeat(MAX_DIFF_SIZE + 1));
+        let err = explain_diff(&provider, &cache, &big, Some(0), None, None, None, &|_| {})
+            .await
+            .expect_err("should reject oversized hunk");
+        assert!(matches!(err, AxisError::DiffTooLarge(_)));
+        assert_eq!(*provider.explain_calls.lock(), 0);
+    }
+
+    // ==================== generate_commit_message Tests ====================
+
+    #[tokio::test]
+    async fn test_generate_commit_message_sends_diff() {
+        let provider = MockProvider::new("Add greeting");
+        let diffs = vec![file_diff("src/main.rs", "println!(\"hi\");")];
+
+        let response = generate_commit_message(
+            &provider,
+            &diffs,
+            CommitMessageStyle::Plain,
+            None,
+            None,
+            None,
+            &|_| {},
+        )
+        .await
+        .expect("should generate");
+
+        assert!(!*provider.summary_used.lock());
+        assert!(provider.user_prompt().contains("```diff"));
+        assert!(provider.user_prompt().contains("println!"));
+        assert!(!response.from_summary);
+        assert_eq!(response.subject, "Add greeting");
+        assert_eq!(response.body, None);
+        assert_eq!(response.message, "Add greeting");
+        assert_eq!(response.model_used, "mock-model");
+    }
+
+    #[tokio::test]
+    async fn test_generate_commit_message_falls_back_to_summary_when_too_large() {
+        let provider = MockProvider::new("Regenerate fixtures");
+        let diffs = vec![
+            file_diff("fixtures/big.json", &"x".repeat(MAX_DIFF_SIZE + 1)),
+            file_diff("src/lib.rs", "fn a() {}"),
+        ];
+
+        let response = generate_commit_message(
+            &provider,
+            &diffs,
+            CommitMessageStyle::Plain,
+            None,
+            None,
+            None,
+            &|_| {},
+        )
+        .await
+        .expect("should fall back to the summary");
+
+        assert!(response.from_summary);
+        assert!(*provider.summary_used.lock());
+        let user = provider.user_prompt();
+        assert!(user.contains("fixtures/big.json"));
+        assert!(user.contains("src/lib.rs"));
+        assert!(!user.contains("xxxx"));
+    }
+
+    #[tokio::test]
+    async fn test_generate_commit_message_prompt_per_style() {
+        let diffs = vec![file_diff("a.txt", "a")];
+        let cases = [
+            (CommitMessageStyle::Conventional, "Conventional Commits"),
+            (CommitMessageStyle::Plain, "concise git commit messages"),
+            (CommitMessageStyle::Detailed, "subject line and a body"),
+        ];
+
+        for (style, expected) in cases {
+            let provider = MockProvider::new("Update a");
+            generate_commit_message(&provider, &diffs, style, None, None, None, &|_| {})
+                .await
+                .expect("should generate");
+
+            assert!(
+                provider.system_prompt().contains(expected),
+                "{style} prompt should contain {expected:?}"
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_generate_commit_message_splits_body_and_streams() {
+        let provider = MockProvider::new("Fix login\n\nSessions expired early.");
+        let diffs = vec![file_diff("auth.rs", "fix")];
+        let chunks = Mutex::new(Vec::new());
+
+        let response = generate_commit_message(
+            &provider,
+            &diffs,
+            CommitMessageStyle::Detailed,
+            None,
+            None,
+            None,
+            &|chunk| chunks.lock().push(chunk.to_string()),
+        )
+        .await
+        .expect("should generate");
+
+        assert_eq!(response.subject, "Fix login");
+        assert_eq!(response.body.as_deref(), Some("Sessions expired early."));
+        assert_eq!(response.message, "Fix login\n\nSessions expired early.");
+        assert_eq!(
+            chunks.lock().concat(),
+            "Fix login\n\nSessions expired early."
+        );
+    }
+
+    #[tokio::test]
+    async fn test_generate_commit_message_no_staged_changes() {
+        let provider = MockProvider::new("unused");
+
+        let result = generate_commit_message(
+            &provider,
+            &[],
+            CommitMessageStyle::Plain,
+            None,
+            None,
+            None,
+            &|_| {},
+        )
+        .await;
+
+        assert!(matches!(result, Err(AxisError::AiServiceError(_))));
+        assert!(provider.prompt.lock().is_none());
+    }
+
+    // ==================== generate_pr_description Tests ====================
+
+    fn compare_result(
+        branch: &str,
+        summaries: &[&str],
+        files: Vec<FileDiff>,
+    ) -> BranchCompareResult {
+        BranchCompareResult {
+            base_ref: branch.to_string(),
+            compare_ref: "origin/main".to_string(),
+            base_oid: "b".repeat(40),
+            compare_oid: "c".repeat(40),
+            merge_base_oid: None,
+            ahead_commits: summaries
+                .iter()
+                .enumerate()
+                .map(|(i, summary)| {
+                    let author = Signature {
+                        name: "Test".to_string(),
+                        email: "test@example.com".to_string(),
+                        timestamp: chrono::Utc::now(),
+                    };
+                    Commit {
+                        oid: format!("{i:0>40}"),
+                        short_oid: format!("{i:0>7}"),
+                        message: (*summary).to_string(),
+                        summary: (*summary).to_string(),
+                        author: author.clone(),
+                        committer: author,
+                        parent_oids: Vec::new(),
+                        timestamp: chrono::Utc::now(),
+                        is_merge: false,
+                        signature: None,
+                    }
+                })
+                .collect(),
+            behind_commits: Vec::new(),
+            files,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_generate_pr_description_sends_commits_and_diff() {
+        let provider = MockProvider::new(
+            "TITLE: Add search\nBODY:\n## Summary\nAdds search.\nLABELS: enhancement",
+        );
+        let compare = compare_result(
+            "origin/feature/search",
+            &["Add search index", "Wire up search box"],
+            vec![file_diff("src/search.rs", "fn search() {}")],
+        );
+        let options = GeneratePrDescriptionOptions {
+            include_diff: None,
+            available_labels: vec!["enhancement".to_string()],
+        };
+
+        let response = generate_pr_description(&provider, &compare, &options, None, None, None)
+            .await
+            .expect("should generate");
+
+        let user = provider.user_prompt();
+        assert!(user.contains("Add search index"));
+        assert!(user.contains("Wire up search box"));
+        assert!(user.contains("```diff"));
+        assert!(user.contains("fn search() {}"));
+        assert!(user.contains("Available labels: enhancement"));
+        assert!(provider.system_prompt().contains("## Testing"));
+        assert!(!response.from_summary);
+        assert_eq!(response.title, "Add search");
+        assert_eq!(response.body, "## Summary\nAdds search.");
+        assert_eq!(response.labels, vec!["enhancement"]);
+        assert_eq!(response.linked_issue, None);
+    }
+
+    #[tokio::test]
+    async fn test_generate_pr_description_falls_back_to_summary_when_too_large() {
+        let provider = MockProvider::new("TITLE: Regenerate fixtures\nBODY:\nDone");
+        let compare = compare_result(
+            "feature/fixtures",
+            &["Regenerate fixtures"],
+            vec![
+                file_diff("fixtures/big.json", &"x".repeat(MAX_DIFF_SIZE + 1)),
+                file_diff("src/lib.rs", "fn a() {}"),
+            ],
+        );
+
+        let response = generate_pr_description(
+            &provider,
+            &compare,
+            &GeneratePrDescriptionOptions::default(),
+            None,
+            None,
+            None,
+        )
+        .await
+        .expect("should fall back to the summary");
+
+        assert!(response.from_summary);
+        assert!(*provider.summary_used.lock());
+        let user = provider.user_prompt();
+        assert!(user.contains("Regenerate fixtures"));
+        assert!(user.contains("fixtures/big.json"));
+        assert!(user.contains("src/lib.rs"));
+        assert!(!user.contains("xxxx"));
+    }
+
+    #[tokio::test]
+    async fn test_generate_pr_description_without_diff() {
+        let provider = MockProvider::new("TITLE: Fix crash\nBODY:\nFixes it");
+        let compare = compare_result("fix", &["Fix crash"], vec![file_diff("a.rs", "fix")]);
+        let options = GeneratePrDescriptionOptions {
+            include_diff: Some(false),
+            ..Default::default()
+        };
+
+        generate_pr_description(&provider, &compare, &options, None, None, None)
+            .await
+            .expect("should generate");
+
+        let user = provider.user_prompt();
+        assert!(user.contains("Fix crash"));
+        assert!(!user.contains("```diff"));
+        assert!(!user.contains("Changed files"));
+    }
+
+    #[tokio::test]
+    async fn test_generate_pr_description_links_issue_from_branch() {
+        let provider = MockProvider::new("TITLE: Fix login\nBODY:\n## Summary\nFixes login.");
+        let compare = compare_result("origin/feature/1234-fix-login", &["Fix login"], Vec::new());
+
+        let response = generate_pr_description(
+            &provider,
+            &compare,
+            &GeneratePrDescriptionOptions::default(),
+            None,
+            None,
+            None,
+        )
+        .await
+        .expect("should generate");
+
+        assert!(provider.user_prompt().contains("Linked issue: #1234"));
+        assert_eq!(response.linked_issue, Some(1234));
+        assert!(response.body.ends_with("Closes #1234"));
+    }
+
+    #[tokio::test]
+    async fn test_generate_pr_description_no_commits() {
+        let provider = MockProvider::new("unused");
+        let compare = compare_result("feature", &[], Vec::new());
+
+        let result = generate_pr_description(
+            &provider,
+            &compare,
+            &GeneratePrDescriptionOptions::default(),
+            None,
+            None,
+            None,
+        )
+        .await;
+
+        assert!(matches!(result, Err(AxisError::AiServiceError(_))));
+        assert!(provider.prompt.lock().is_none());
+    }
+
+    #[test]
+    fn test_linked_issue_number() {
+        assert_eq!(linked_issue_number("feature/1234-add-search"), Some(1234));
+        assert_eq!(linked_issue_number("origin/fix/42_crash"), Some(42));
+        assert_eq!(linked_issue_number("99"), Some(99));
+        assert_eq!(linked_issue_number("feature/add-search"), None);
+        assert_eq!(linked_issue_number("release/1.2"), None);
+        assert_eq!(linked_issue_number("feature/0-zero"), None);
+        assert_eq!(linked_issue_number("feature/"), None);
+    }
 
     // ==================== create_provider Tests ====================
 
@@ -2,8 +2,14 @@ use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
 
 use crate::error::{AxisError, Result};
-use crate::services::ai::prompt::{build_pr_prompt, build_prompt, parse_pr_response};
-use crate::services::ai::provider::AiProviderTrait;
+use crate::models::{AiModelInfo, CommitMessageStyle};
+use crate::services::ai::prompt::{
+    build_explain_prompt, build_pr_prompt, build_prompt, parse_pr_response, CommitChanges,
+    PrPromptInput,
+};
+use crate::services::ai::provider::{
+    error_from_response, read_lines, request_error, AiProviderTrait, ChunkCallback,
+};
 
 const DEFAULT_OLLAMA_URL: &str = "http://localhost:11434";
 
@@ -42,9 +48,40 @@ struct OllamaMessageResponse {
     content: String,
 }
 
+/// One line of a streamed `/api/chat` response
 #[derive(Deserialize)]
-pub struct OllamaModel {
-    pub name: String,
+struct OllamaStreamChunk {
+    #[serde(default)]
+    message: Option<OllamaMessageResponse>,
+    #[serde(default)]
+    error: Option<String>,
+}
+
+/// Extract the text delta from one streamed JSON line
+fn parse_stream_line(line: &str, model: &str) -> Result<Option<String>> {
+    match serde_json::from_str::<OllamaStreamChunk>(line) {
+        Ok(OllamaStreamChunk {
+            error: Some(error), ..
+        }) => {
+            if error.contains("not found") {
+                Err(AxisError::AiModelNotFound(model.to_string()))
+            } else {
+                Err(AxisError::AiServiceError(format!("Ollama error: {error}")))
+            }
+        }
+        Ok(chunk) => Ok(chunk.message.map(|m| m.content)),
+        Err(e) => {
+            log::warn!("Skipping unparsable Ollama stream line: {e}");
+            Ok(None)
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct OllamaModel {
+    name: String,
+    #[serde(default)]
+    size: Option<u64>,
 }
 
 #[derive(Deserialize)]
@@ -52,55 +89,36 @@ struct OllamaModelsResponse {
     models: Vec<OllamaModel>,
 }
 
-impl OllamaProvider {
-    pub async fn list_models(base_url: Option<&str>) -> Result<Vec<String>> {
-        let base_url = base_url.unwrap_or(DEFAULT_OLLAMA_URL);
-        let url = format!("{base_url}/api/tags");
-
-        let client = reqwest::Client::new();
-        let response =
-            client.get(&url).send().await.map_err(|e| {
-                AxisError::AiServiceError(format!("Failed to connect to Ollama: {e}"))
-            })?;
-
-        if !response.status().is_success() {
-            return Err(AxisError::AiServiceError(
-                "Failed to list Ollama models".to_string(),
-            ));
-        }
-
-        let response: OllamaModelsResponse = response
-            .json()
-            .await
-            .map_err(|e| AxisError::AiServiceError(format!("Failed to parse response: {e}")))?;
-
-        Ok(response.models.into_iter().map(|m| m.name).collect())
+/// Normalize a user supplied daemon address: `localhost:11434` and `host:port/`
+/// become `http://localhost:11434` and `http://host:port`. Empty means the default.
+fn normalize_base_url(base_url: &str) -> String {
+    let trimmed = base_url.trim().trim_end_matches('/');
+    if trimmed.is_empty() {
+        DEFAULT_OLLAMA_URL.to_string()
+    } else if trimmed.contains("://") {
+        trimmed.to_string()
+    } else {
+        format!("http://{trimmed}")
     }
+}
 
-    pub async fn test_connection(base_url: Option<&str>) -> Result<bool> {
-        let base_url = base_url.unwrap_or(DEFAULT_OLLAMA_URL);
-        let url = format!("{base_url}/api/tags");
-
-        let client = reqwest::Client::new();
-        let response = client.get(&url).send().await;
-
-        Ok(response.is_ok() && response.map(|r| r.status().is_success()).unwrap_or(false))
+impl OllamaProvider {
+    fn resolve_base_url(&self, base_url: Option<&str>) -> String {
+        normalize_base_url(base_url.unwrap_or(&self.base_url))
     }
-}
 
-#[async_trait]
-impl AiProviderTrait for OllamaProvider {
-    async fn generate_commit_message(
+    /// Stream a chat response to `on_chunk`, returning the full text and the model used
+    async fn stream_chat(
         &self,
-        diff: &str,
+        system_prompt: String,
+        user_prompt: String,
         _api_key: Option<&str>,
         model: Option<&str>,
         base_url: Option<&str>,
-        conventional_commits: bool,
+        on_chunk: ChunkCallback<'_>,
     ) -> Result<(String, String)> {
-        let base_url = base_url.unwrap_or(&self.base_url);
+        let base_url = self.resolve_base_url(base_url);
         let model = model.unwrap_or(self.default_model()).to_string();
-        let (system_prompt, user_prompt) = build_prompt(diff, conventional_commits);
 
         let request = OllamaRequest {
             model: model.clone(),
@@ -114,7 +132,7 @@ impl AiProviderTrait for OllamaProvider {
                     content: user_prompt,
                 },
             ],
-            stream: false,
+            stream: true,
         };
 
         let url = format!("{base_url}/api/chat");
@@ -125,41 +143,79 @@ impl AiProviderTrait for OllamaProvider {
             .json(&request)
             .send()
             .await
-            .map_err(|e| AxisError::AiServiceError(format!("Failed to connect to Ollama: {e}")))?;
+            .map_err(|e| request_error(&base_url, true, &e))?;
 
         if !response.status().is_success() {
-            let status = response.status();
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|_| "Unknown error".to_string());
-            return Err(AxisError::AiServiceError(format!(
-                "Ollama API error ({status}): {error_text}"
-            )));
+            return Err(error_from_response("Ollama", &model, response).await);
         }
 
-        let response: OllamaResponse = response
-            .json()
-            .await
-            .map_err(|e| AxisError::AiServiceError(format!("Failed to parse response: {e}")))?;
+        let mut message = String::new();
+        read_lines(response, |line| {
+            if let Some(delta) = parse_stream_line(line, &model)? {
+                on_chunk(&delta);
+                message.push_str(&delta);
+            }
+            Ok(())
+        })
+        .await?;
 
-        let message = response.message.content.trim().to_string();
+        Ok((message.trim().to_string(), model))
+    }
+}
 
-        Ok((message, model))
+#[async_trait]
+impl AiProviderTrait for OllamaProvider {
+    async fn generate_commit_message(
+        &self,
+        changes: CommitChanges<'_>,
+        style: CommitMessageStyle,
+        api_key: Option<&str>,
+        model: Option<&str>,
+        base_url: Option<&str>,
+        on_chunk: ChunkCallback<'_>,
+    ) -> Result<(String, String)> {
+        let (system_prompt, user_prompt) = build_prompt(changes, style);
+        self.stream_chat(
+            system_prompt,
+            user_prompt,
+            api_key,
+            model,
+            base_url,
+            on_chunk,
+        )
+        .await
+    }
+
+    async fn explain_diff(
+        &self,
+        diff: &str,
+        api_key: Option<&str>,
+        model: Option<&str>,
+        base_url: Option<&str>,
+        on_chunk: ChunkCallback<'_>,
+    ) -> Result<(String, String)> {
+        let (system_prompt, user_prompt) = build_explain_prompt(diff);
+        self.stream_chat(
+            system_prompt,
+            user_prompt,
+            api_key,
+            model,
+            base_url,
+            on_chunk,
+        )
+        .await
     }
 
     async fn generate_pr_description(
         &self,
-        commits: &[(String, String)],
-        diff_summary: Option<&str>,
-        available_labels: Option<&[String]>,
+        input: &PrPromptInput<'_>,
         _api_key: Option<&str>,
         model: Option<&str>,
         base_url: Option<&str>,
     ) -> Result<(String, String, Vec<String>, String)> {
-        let base_url = base_url.unwrap_or(&self.base_url);
+        let base_url = self.resolve_base_url(base_url);
         let model = model.unwrap_or(self.default_model()).to_string();
-        let (system_prompt, user_prompt) = build_pr_prompt(commits, diff_summary, available_labels);
+        let (system_prompt, user_prompt) = build_pr_prompt(input);
 
         let request = OllamaRequest {
             model: model.clone(),
@@ -184,17 +240,10 @@ impl AiProviderTrait for OllamaProvider {
             .json(&request)
             .send()
             .await
-            .map_err(|e| AxisError::AiServiceError(format!("Failed to connect to Ollama: {e}")))?;
+            .map_err(|e| request_error(&base_url, true, &e))?;
 
         if !response.status().is_success() {
-            let status = response.status();
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|_| "Unknown error".to_string());
-            return Err(AxisError::AiServiceError(format!(
-                "Ollama API error ({status}): {error_text}"
-            )));
+            return Err(error_from_response("Ollama", &model, response).await);
         }
 
         let response: OllamaResponse = response
@@ -207,6 +256,45 @@ impl AiProviderTrait for OllamaProvider {
         Ok((title, body, labels, model))
     }
 
+    async fn list_models(
+        &self,
+        _api_key: Option<&str>,
+        base_url: Option<&str>,
+    ) -> Result<Vec<AiModelInfo>> {
+        let base_url = self.resolve_base_url(base_url);
+        let url = format!("{base_url}/api/tags");
+
+        let client = reqwest::Client::new();
+        let response = client
+            .get(&url)
+            .send()
+            .await
+            .map_err(|e| request_error(&base_url, true, &e))?;
+
+        if response.status() == reqwest::StatusCode::NOT_FOUND {
+            return Err(AxisError::AiServiceError(format!(
+                "No Ollama API found at {base_url}"
+            )));
+        }
+        if !response.status().is_success() {
+            return Err(error_from_response("Ollama", "", response).await);
+        }
+
+        let response: OllamaModelsResponse = response
+            .json()
+            .await
+            .map_err(|e| AxisError::AiServiceError(format!("Failed to parse response: {e}")))?;
+
+        Ok(response
+            .models
+            .into_iter()
+            .map(|m| AiModelInfo {
+                name: m.name,
+                size: m.size,
+            })
+            .collect())
+    }
+
     fn default_model(&self) -> &'static str {
         "llama3.2"
     }
@@ -224,6 +312,8 @@ impl AiProviderTrait for OllamaProvider {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
 
     // ==================== OllamaProvider Tests ====================
 
@@ -370,10 +460,182 @@ mod tests {
 
         let model: OllamaModel = serde_json::from_str(json).expect("should deserialize");
         assert_eq!(model.name, "llama3.2:7b");
+        assert_eq!(model.size, None);
+    }
+
+    // ==================== Base URL Tests ====================
+
+    #[test]
+    fn test_normalize_base_url() {
+        assert_eq!(
+            normalize_base_url("localhost:11434"),
+            "http://localhost:11434"
+        );
+        assert_eq!(normalize_base_url("gpu-box:8080/"), "http://gpu-box:8080");
+        assert_eq!(
+            normalize_base_url("https://ollama.example.com/"),
+            "https://ollama.example.com"
+        );
+        assert_eq!(normalize_base_url("  "), DEFAULT_OLLAMA_URL);
+    }
+
+    // ==================== Discovery Tests ====================
+
+    #[tokio::test]
+    async fn test_list_models_with_sizes() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/api/tags"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "models": [
+                    {"name": "llama3.2:latest", "size": 2019393189u64},
+                    {"name": "mistral"}
+                ]
+            })))
+            .mount(&server)
+            .await;
+
+        let models = OllamaProvider::default()
+            .list_models(None, Some(&format!("{}/", server.uri())))
+            .await
+            .expect("should list models");
+
+        assert_eq!(
+            models,
+            vec![
+                AiModelInfo {
+                    name: "llama3.2:latest".to_string(),
+                    size: Some(2019393189),
+                },
+                AiModelInfo {
+                    name: "mistral".to_string(),
+                    size: None,
+                },
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_check_connection_uses_configured_url() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/api/tags"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "models": []
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let provider = OllamaProvider {
+            base_url: server.uri(),
+        };
+        provider
+            .check_connection(None, None)
+            .await
+            .expect("should connect");
+    }
+
+    #[tokio::test]
+    async fn test_list_models_wrong_endpoint() {
+        let server = MockServer::start().await;
+
+        let err = OllamaProvider::default()
+            .list_models(None, Some(&server.uri()))
+            .await
+            .expect_err("should fail");
+
+        assert!(matches!(err, AxisError::AiServiceError(m) if m.contains("No Ollama API")));
+    }
+
+    #[tokio::test]
+    async fn test_list_models_server_error() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/api/tags"))
+            .respond_with(ResponseTemplate::new(500).set_body_string("boom"))
+            .mount(&server)
+            .await;
+
+        let err = OllamaProvider::default()
+            .check_connection(None, Some(&server.uri()))
+            .await
+            .expect_err("should fail");
+
+        assert!(matches!(err, AxisError::AiServiceError(m) if m.contains("boom")));
+    }
+
+    #[tokio::test]
+    async fn test_check_connection_daemon_not_running() {
+        // Bind then drop a listener so nothing is accepting on the port
+        let port = std::net::TcpListener::bind("127.0.0.1:0")
+            .and_then(|l| l.local_addr())
+            .expect("should bind")
+            .port();
+        let url = format!("127.0.0.1:{port}");
+
+        let err = OllamaProvider::default()
+            .check_connection(None, Some(&url))
+            .await
+            .expect_err("should fail");
+
+        assert!(matches!(err, AxisError::AiDaemonNotRunning(u) if u == format!("http://{url}")));
+    }
+
+    #[tokio::test]
+    async fn test_generate_commit_message_missing_model() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/api/chat"))
+            .respond_with(
+                ResponseTemplate::new(404).set_body_string(r#"{"error":"model not found"}"#),
+            )
+            .mount(&server)
+            .await;
+
+        let err = OllamaProvider::default()
+            .generate_commit_message(
+                CommitChanges::Diff("diff"),
+                CommitMessageStyle::Plain,
+                None,
+                Some("nope"),
+                Some(&server.uri()),
+                &|_| {},
+            )
+            .await
+            .expect_err("should fail");
+
+        assert!(matches!(err, AxisError::AiModelNotFound(m) if m == "nope"));
     }
 
     // ==================== DEFAULT_OLLAMA_URL Tests ====================
 
+    #[test]
+    fn test_parse_stream_line() {
+        let line = r#"{"message":{"role":"assistant","content":"Add"},"done":false}"#;
+        assert_eq!(
+            parse_stream_line(line, "llama3.2")
+                .expect("should parse")
+                .as_deref(),
+            Some("Add")
+        );
+
+        let done = r#"{"done":true,"total_duration":1}"#;
+        assert!(parse_stream_line(done, "llama3.2")
+            .expect("should parse")
+            .is_none());
+    }
+
+    #[test]
+    fn test_parse_stream_line_missing_model() {
+        let line = r#"{"error":"model 'nope' not found"}"#;
+
+        assert!(matches!(
+            parse_stream_line(line, "nope"),
+            Err(AxisError::AiModelNotFound(m)) if m == "nope"
+        ));
+    }
+
     #[test]
     fn test_default_ollama_url() {
         assert_eq!(DEFAULT_OLLAMA_URL, "http://localhost:11434");
@@ -2,8 +2,14 @@ use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
 
 use crate::error::{AxisError, Result};
-use crate::services::ai::prompt::{build_pr_prompt, build_prompt, parse_pr_response};
-use crate::services::ai::provider::AiProviderTrait;
+use crate::models::{AiModelInfo, CommitMessageStyle};
+use crate::services::ai::prompt::{
+    build_explain_prompt, build_pr_prompt, build_prompt, parse_pr_response, CommitChanges,
+    PrPromptInput,
+};
+use crate::services::ai::provider::{
+    error_from_response, read_lines, request_error, AiProviderTrait, ChunkCallback,
+};
 
 pub struct OpenAiProvider;
 
@@ -13,6 +19,8 @@ struct OpenAiRequest {
     messages: Vec<OpenAiMessage>,
     max_tokens: u32,
     temperature: f32,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    stream: bool,
 }
 
 #[derive(Serialize)]
@@ -36,21 +44,68 @@ struct OpenAiMessageResponse {
     content: String,
 }
 
-#[async_trait]
-impl AiProviderTrait for OpenAiProvider {
-    async fn generate_commit_message(
+#[derive(Deserialize)]
+struct OpenAiStreamChunk {
+    choices: Vec<OpenAiStreamChoice>,
+}
+
+#[derive(Deserialize)]
+struct OpenAiStreamChoice {
+    delta: OpenAiDelta,
+}
+
+#[derive(Deserialize)]
+struct OpenAiDelta {
+    #[serde(default)]
+    content: Option<String>,
+}
+
+/// Extract the text delta from one server-sent event line
+fn parse_stream_line(line: &str) -> Option<String> {
+    let data = line.strip_prefix("data:")?.trim();
+    if data == "[DONE]" {
+        return None;
+    }
+    match serde_json::from_str::<OpenAiStreamChunk>(data) {
+        Ok(chunk) => chunk.choices.into_iter().next()?.delta.content,
+        Err(e) => {
+            log::warn!("Skipping unparsable OpenAI stream event: {e}");
+            None
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct OpenAiModelsResponse {
+    data: Vec<OpenAiModel>,
+}
+
+#[derive(Deserialize)]
+struct OpenAiModel {
+    id: String,
+}
+
+/// Whether a model id from `/v1/models` can be used for chat completions
+fn is_chat_model(id: &str) -> bool {
+    id.starts_with("gpt-")
+        || (id.starts_with('o') && id[1..].starts_with(|c: char| c.is_ascii_digit()))
+}
+
+impl OpenAiProvider {
+    /// Stream a chat response to `on_chunk`, returning the full text and the model used
+    async fn stream_chat(
         &self,
-        diff: &str,
+        system_prompt: String,
+        user_prompt: String,
+        max_tokens: u32,
         api_key: Option<&str>,
         model: Option<&str>,
-        _base_url: Option<&str>,
-        conventional_commits: bool,
+        on_chunk: ChunkCallback<'_>,
     ) -> Result<(String, String)> {
         let api_key =
             api_key.ok_or_else(|| AxisError::ApiKeyNotConfigured("OpenAI".to_string()))?;
 
         let model = model.unwrap_or(self.default_model()).to_string();
-        let (system_prompt, user_prompt) = build_prompt(diff, conventional_commits);
 
         let request = OpenAiRequest {
             model: model.clone(),
@@ -64,8 +119,9 @@ impl AiProviderTrait for OpenAiProvider {
                     content: user_prompt,
                 },
             ],
-            max_tokens: 500,
+            max_tokens,
             temperature: 0.3,
+            stream: true,
         };
 
         let client = reqwest::Client::new();
@@ -76,38 +132,65 @@ impl AiProviderTrait for OpenAiProvider {
             .json(&request)
             .send()
             .await
-            .map_err(|e| AxisError::AiServiceError(format!("Request failed: {e}")))?;
+            .map_err(|e| request_error("OpenAI", false, &e))?;
 
         if !response.status().is_success() {
-            let status = response.status();
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|_| "Unknown error".to_string());
-            return Err(AxisError::AiServiceError(format!(
-                "OpenAI API error ({status}): {error_text}"
-            )));
+            return Err(error_from_response("OpenAI", &model, response).await);
         }
 
-        let response: OpenAiResponse = response
-            .json()
-            .await
-            .map_err(|e| AxisError::AiServiceError(format!("Failed to parse response: {e}")))?;
-
-        let message = response
-            .choices
-            .first()
-            .map(|c| c.message.content.trim().to_string())
-            .ok_or_else(|| AxisError::AiServiceError("No response from OpenAI".to_string()))?;
+        let mut message = String::new();
+        read_lines(response, |line| {
+            if let Some(delta) = parse_stream_line(line) {
+                on_chunk(&delta);
+                message.push_str(&delta);
+            }
+            Ok(())
+        })
+        .await?;
+
+        let message = message.trim().to_string();
+        if message.is_empty() {
+            return Err(AxisError::AiServiceError(
+                "No response from OpenAI".to_string(),
+            ));
+        }
 
         Ok((message, model))
     }
+}
+
+#[async_trait]
+impl AiProviderTrait for OpenAiProvider {
+    async fn generate_commit_message(
+        &self,
+        changes: CommitChanges<'_>,
+        style: CommitMessageStyle,
+        api_key: Option<&str>,
+        model: Option<&str>,
+        _base_url: Option<&str>,
+        on_chunk: ChunkCallback<'_>,
+    ) -> Result<(String, String)> {
+        let (system_prompt, user_prompt) = build_prompt(changes, style);
+        self.stream_chat(system_prompt, user_prompt, 500, api_key, model, on_chunk)
+            .await
+    }
+
+    async fn explain_diff(
+        &self,
+        diff: &str,
+        api_key: Option<&str>,
+        model: Option<&str>,
+        _base_url: Option<&str>,
+        on_chunk: ChunkCallback<'_>,
+    ) -> Result<(String, String)> {
+        let (system_prompt, user_prompt) = build_explain_prompt(diff);
+        self.stream_chat(system_prompt, user_prompt, 1000, api_key, model, on_chunk)
+            .await
+    }
 
     async fn generate_pr_description(
         &self,
-        commits: &[(String, String)],
-        diff_summary: Option<&str>,
-        available_labels: Option<&[String]>,
+        input: &PrPromptInput<'_>,
         api_key: Option<&str>,
         model: Option<&str>,
         _base_url: Option<&str>,
@@ -116,7 +199,7 @@ impl AiProviderTrait for OpenAiProvider {
             api_key.ok_or_else(|| AxisError::ApiKeyNotConfigured("OpenAI".to_string()))?;
 
         let model = model.unwrap_or(self.default_model()).to_string();
-        let (system_prompt, user_prompt) = build_pr_prompt(commits, diff_summary, available_labels);
+        let (system_prompt, user_prompt) = build_pr_prompt(input);
 
         let request = OpenAiRequest {
             model: model.clone(),
@@ -132,6 +215,7 @@ impl AiProviderTrait for OpenAiProvider {
             ],
             max_tokens: 1000,
             temperature: 0.3,
+            stream: false,
         };
 
         let client = reqwest::Client::new();
@@ -142,17 +226,10 @@ impl AiProviderTrait for OpenAiProvider {
             .json(&request)
             .send()
             .await
-            .map_err(|e| AxisError::AiServiceError(format!("Request failed: {e}")))?;
+            .map_err(|e| request_error("OpenAI", false, &e))?;
 
         if !response.status().is_success() {
-            let status = response.status();
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|_| "Unknown error".to_string());
-            return Err(AxisError::AiServiceError(format!(
-                "OpenAI API error ({status}): {error_text}"
-            )));
+            return Err(error_from_response("OpenAI", &model, response).await);
         }
 
         let response: OpenAiResponse = response
@@ -170,6 +247,45 @@ impl AiProviderTrait for OpenAiProvider {
         Ok((title, body, labels, model))
     }
 
+    async fn list_models(
+        &self,
+        api_key: Option<&str>,
+        _base_url: Option<&str>,
+    ) -> Result<Vec<AiModelInfo>> {
+        let api_key =
+            api_key.ok_or_else(|| AxisError::ApiKeyNotConfigured("OpenAI".to_string()))?;
+
+        let client = reqwest::Client::new();
+        let response = client
+            .get("https://api.openai.com/v1/models")
+            .header("Authorization", format!("Bearer {api_key}"))
+            .send()
+            .await
+            .map_err(|e| request_error("OpenAI", false, &e))?;
+
+        if !response.status().is_success() {
+            return Err(error_from_response("OpenAI", "", response).await);
+        }
+
+        let response: OpenAiModelsResponse = response
+            .json()
+            .await
+            .map_err(|e| AxisError::AiServiceError(format!("Failed to parse response: {e}")))?;
+
+        let mut names: Vec<String> = response
+            .data
+            .into_iter()
+            .map(|m| m.id)
+            .filter(|id| is_chat_model(id))
+            .collect();
+        names.sort();
+
+        Ok(names
+            .into_iter()
+            .map(|name| AiModelInfo { name, size: None })
+            .collect())
+    }
+
     fn default_model(&self) -> &'static str {
         "gpt-4o-mini"
     }
@@ -216,9 +332,11 @@ mod tests {
             ],
             max_tokens: 500,
             temperature: 0.3,
+            stream: false,
         };
 
         let json = serde_json::to_string(&request).expect("should serialize");
+        assert!(!json.contains("\"stream\""));
         assert!(json.contains("\"model\":\"gpt-4o-mini\""));
         assert!(json.contains("\"role\":\"system\""));
         assert!(json.contains("\"role\":\"user\""));
@@ -281,13 +399,33 @@ mod tests {
         assert_eq!(response.choices.len(), 2);
     }
 
+    // ==================== Streaming Tests ====================
+
+    #[test]
+    fn test_parse_stream_line() {
+        let line = r#"data: {"choices":[{"delta":{"content":"Add"}}]}"#;
+        assert_eq!(parse_stream_line(line).as_deref(), Some("Add"));
+
+        let role_only = r#"data: {"choices":[{"delta":{"role":"assistant"}}]}"#;
+        assert!(parse_stream_line(role_only).is_none());
+        assert!(parse_stream_line("data: [DONE]").is_none());
+        assert!(parse_stream_line(": keep-alive").is_none());
+    }
+
     // ==================== API Key Validation Tests ====================
 
     #[tokio::test]
     async fn test_generate_commit_message_no_api_key() {
         let provider = OpenAiProvider;
         let result = provider
-            .generate_commit_message("diff content", None, None, None, false)
+            .generate_commit_message(
+                CommitChanges::Diff("diff content"),
+                CommitMessageStyle::Plain,
+                None,
+                None,
+                None,
+                &|_| {},
+            )
             .await;
 
         assert!(result.is_err());
@@ -300,11 +438,38 @@ mod tests {
         let provider = OpenAiProvider;
         let commits = vec![("abc".to_string(), "test commit".to_string())];
         let result = provider
-            .generate_pr_description(&commits, None, None, None, None, None)
+            .generate_pr_description(
+                &PrPromptInput {
+                    commits: &commits,
+                    ..Default::default()
+                },
+                None,
+                None,
+                None,
+            )
             .await;
 
         assert!(result.is_err());
         let err = result.expect_err("should be error");
         assert!(err.to_string().contains("API key not configured"));
     }
+
+    #[tokio::test]
+    async fn test_list_models_no_api_key() {
+        let err = OpenAiProvider
+            .list_models(None, None)
+            .await
+            .expect_err("should be error");
+
+        assert!(matches!(err, AxisError::ApiKeyNotConfigured(p) if p == "OpenAI"));
+    }
+
+    #[test]
+    fn test_is_chat_model() {
+        assert!(is_chat_model("gpt-4o-mini"));
+        assert!(is_chat_model("o3-mini"));
+        assert!(!is_chat_model("text-embedding-3-small"));
+        assert!(!is_chat_model("omni-moderation-latest"));
+        assert!(!is_chat_model("whisper-1"));
+    }
 }
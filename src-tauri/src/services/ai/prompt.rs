@@ -1,5 +1,7 @@
 use std::fmt::Write;
 
+use crate::models::CommitMessageStyle;
+
 const SYSTEM_PROMPT: &str = r"You are a helpful assistant that generates concise git commit messages.
 
 Given the following diff of staged changes, generate a commit message following these guidelines:
@@ -37,13 +39,27 @@ Rules:
 
 Return ONLY the commit message, nothing else."#;
 
+const SYSTEM_PROMPT_DETAILED: &str = r"You are a helpful assistant that generates descriptive git commit messages.
+
+Given the following staged changes, generate a commit message with a subject line and a body:
+1. Start the subject with a verb in imperative mood (Add, Fix, Update, Remove, Refactor, etc.)
+2. Keep the subject line under 72 characters and do not end it with a period
+3. Leave one blank line between the subject and the body
+4. In the body, explain WHAT changed and WHY in a few short sentences or bullet points
+5. Wrap body lines at 72 characters
+
+Return ONLY the commit message, nothing else.";
+
 const PR_SYSTEM_PROMPT: &str = r"You are a helpful assistant that generates concise pull request titles and descriptions.
 
-Given a list of commits and an optional summary of changed files, generate:
+Given a list of commits and the changes they make (a diff, or a list of changed files when the diff is too large), generate:
 1. A short, descriptive PR title (under 72 characters, imperative mood)
 2. A PR body in GitHub-flavored markdown with:
    - A summary section (## Summary) explaining what the PR does (1-3 sentences)
    - A changes section (## Changes) with a bullet list of key changes
+   - A testing section (## Testing) describing how the changes can be verified
+   - A notes section (## Notes) for reviewer notes, follow-ups or breaking changes; omit it if there is nothing to note
+   - If a linked issue is given, end the summary section with `Closes #<number>`
    - Use proper markdown: headings, bold, code spans, lists
 3. If available labels are provided, suggest the most relevant ones (0-3 labels)
 
@@ -56,23 +72,53 @@ LABELS: <comma-separated label names, or empty if none>
 Be specific but concise. Focus on the user-facing impact of changes.
 Return ONLY the formatted response, nothing else.";
 
-pub fn build_pr_prompt(
-    commits: &[(String, String)],
-    diff_summary: Option<&str>,
-    available_labels: Option<&[String]>,
-) -> (String, String) {
+const EXPLAIN_SYSTEM_PROMPT: &str = r"You are a helpful assistant that explains code changes to a reviewer.
+
+Given a unified diff of one file or one hunk, explain:
+1. What changed, in plain language (1-3 sentences)
+2. Why it might matter: behavior changes, risks, edge cases or follow-ups a reviewer should check
+
+Be concise and specific to the diff; do not restate it line by line.
+Use GitHub-flavored markdown. Return ONLY the explanation, nothing else.";
+
+/// Everything the model sees when describing a pull request
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PrPromptInput<'a> {
+    /// `(short_oid, summary)` of each commit, oldest first
+    pub commits: &'a [(String, String)],
+    /// The aggregate diff, or a file summary when the diff is too large
+    pub changes: Option<CommitChanges<'a>>,
+    pub available_labels: Option<&'a [String]>,
+    /// Issue number parsed from the branch name
+    pub linked_issue: Option<u64>,
+}
+
+pub fn build_pr_prompt(input: &PrPromptInput<'_>) -> (String, String) {
     let mut user_prompt =
         String::from("Generate a PR title and description for these commits:\n\n");
 
-    for (short_oid, summary) in commits {
+    for (short_oid, summary) in input.commits {
         let _ = writeln!(user_prompt, "- {short_oid}: {summary}");
     }
 
-    if let Some(summary) = diff_summary {
-        let _ = writeln!(user_prompt, "\nChanged files:\n{summary}");
+    match input.changes {
+        Some(CommitChanges::Diff(diff)) => {
+            let _ = writeln!(user_prompt, "\nChanges:\n```diff\n{diff}\n```");
+        }
+        Some(CommitChanges::Summary(summary)) => {
+            let _ = writeln!(
+                user_prompt,
+                "\nThe diff is too large to include. Changed files:\n{summary}"
+            );
+        }
+        None => {}
     }
 
-    if let Some(labels) = available_labels {
+    if let Some(issue) = input.linked_issue {
+        let _ = writeln!(user_prompt, "\nLinked issue: #{issue}");
+    }
+
+    if let Some(labels) = input.available_labels {
         if !labels.is_empty() {
             let _ = writeln!(user_prompt, "\nAvailable labels: {}", labels.join(", "));
         }
@@ -142,18 +188,54 @@ fn parse_labels(labels_str: &str) -> Vec<String> {
         .collect()
 }
 
-pub fn build_prompt(diff: &str, conventional_commits: bool) -> (String, String) {
-    let system = if conventional_commits {
-        SYSTEM_PROMPT_CONVENTIONAL
-    } else {
-        SYSTEM_PROMPT
+/// What the model sees of the staged changes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CommitChanges<'a> {
+    /// Unified diff of the staged changes
+    Diff(&'a str),
+    /// List of changed files, used when the diff is too large to send
+    Summary(&'a str),
+}
+
+pub fn build_prompt(changes: CommitChanges<'_>, style: CommitMessageStyle) -> (String, String) {
+    let system = match style {
+        CommitMessageStyle::Conventional => SYSTEM_PROMPT_CONVENTIONAL,
+        CommitMessageStyle::Plain => SYSTEM_PROMPT,
+        CommitMessageStyle::Detailed => SYSTEM_PROMPT_DETAILED,
     };
 
-    let user_prompt =
-        format!("Generate a commit message for the following changes:\n\n```diff\n{diff}\n```");
+    let user_prompt = match changes {
+        CommitChanges::Diff(diff) => format!(
+            "Generate a commit message for the following changes:\n\n```diff\n{diff}\n```"
+        ),
+        CommitChanges::Summary(summary) => format!(
+            "The staged diff is too large to include. Generate a commit message from this list of changed files:\n\n{summary}"
+        ),
+    };
     (system.to_string(), user_prompt)
 }
 
+pub fn build_explain_prompt(diff: &str) -> (String, String) {
+    let user_prompt = format!("Explain the following change:\n\n```diff\n{diff}\n```");
+    (EXPLAIN_SYSTEM_PROMPT.to_string(), user_prompt)
+}
+
+/// Split a generated message into its subject line and optional body,
+/// dropping any code fence the model wrapped it in.
+pub fn parse_commit_message(response: &str) -> (String, Option<String>) {
+    let lines: Vec<&str> = response
+        .trim()
+        .lines()
+        .filter(|line| !line.trim_start().starts_with("```"))
+        .collect();
+
+    let mut lines = lines.into_iter().skip_while(|line| line.trim().is_empty());
+    let subject = lines.next().unwrap_or("").trim().to_string();
+    let body = lines.collect::<Vec<_>>().join("\n").trim().to_string();
+
+    (subject, (!body.is_empty()).then_some(body))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -163,7 +245,7 @@ mod tests {
     #[test]
     fn test_build_prompt_standard() {
         let diff = "+ fn new_function() {}";
-        let (system, user) = build_prompt(diff, false);
+        let (system, user) = build_prompt(CommitChanges::Diff(diff), CommitMessageStyle::Plain);
 
         assert!(system.contains("imperative mood"));
         assert!(system.contains("72 characters"));
@@ -175,7 +257,8 @@ mod tests {
     #[test]
     fn test_build_prompt_conventional_commits() {
         let diff = "- old_line\n+ new_line";
-        let (system, user) = build_prompt(diff, true);
+        let (system, user) =
+            build_prompt(CommitChanges::Diff(diff), CommitMessageStyle::Conventional);
 
         assert!(system.contains("Conventional Commits"));
         assert!(system.contains("feat:"));
@@ -188,7 +271,7 @@ mod tests {
 
     #[test]
     fn test_build_prompt_empty_diff() {
-        let (system, user) = build_prompt("", false);
+        let (system, user) = build_prompt(CommitChanges::Diff(""), CommitMessageStyle::Plain);
 
         assert!(!system.is_empty());
         assert!(user.contains("```diff\n\n```"));
@@ -204,7 +287,7 @@ mod tests {
 +    println!("Hello");
  }"#;
 
-        let (system, user) = build_prompt(diff, false);
+        let (system, user) = build_prompt(CommitChanges::Diff(diff), CommitMessageStyle::Plain);
 
         assert!(user.contains("diff --git"));
         assert!(user.contains("println!"));
@@ -213,7 +296,7 @@ mod tests {
 
     #[test]
     fn test_build_prompt_standard_contains_guidelines() {
-        let (system, _) = build_prompt("test", false);
+        let (system, _) = build_prompt(CommitChanges::Diff("test"), CommitMessageStyle::Plain);
 
         assert!(system.contains("Add, Fix, Update, Remove, Refactor"));
         assert!(system.contains("WHAT changed and WHY"));
@@ -222,24 +305,22 @@ mod tests {
 
     #[test]
     fn test_build_prompt_conventional_contains_all_types() {
-        let (system, _) = build_prompt("test", true);
+        let (system, _) = build_prompt(
+            CommitChanges::Diff("test"),
+            CommitMessageStyle::Conventional,
+        );
 
-        assert!(system.contains("feat:"));
-        assert!(system.contains("fix:"));
-        assert!(system.contains("docs:"));
-        assert!(system.contains("style:"));
-        assert!(system.contains("refactor:"));
-        assert!(system.contains("perf:"));
-        assert!(system.contains("test:"));
-        assert!(system.contains("build:"));
-        assert!(system.contains("ci:"));
-        assert!(system.contains("chore:"));
-        assert!(system.contains("revert:"));
+        for commit_type in crate::models::CONVENTIONAL_COMMIT_TYPES {
+            assert!(system.contains(&format!("{commit_type}:")));
+        }
     }
 
     #[test]
     fn test_build_prompt_conventional_breaking_change() {
-        let (system, _) = build_prompt("test", true);
+        let (system, _) = build_prompt(
+            CommitChanges::Diff("test"),
+            CommitMessageStyle::Conventional,
+        );
 
         assert!(system.contains("breaking change"));
         assert!(system.contains('!'));
@@ -248,7 +329,7 @@ mod tests {
     #[test]
     fn test_build_prompt_user_prompt_format() {
         let diff = "test diff content";
-        let (_, user) = build_prompt(diff, false);
+        let (_, user) = build_prompt(CommitChanges::Diff(diff), CommitMessageStyle::Plain);
 
         assert!(user.starts_with("Generate a commit message"));
         assert!(user.contains("```diff"));
@@ -261,21 +342,94 @@ mod tests {
 + let regex = r"\d+";
 + let path = "C:\\Users\\test";"#;
 
-        let (_, user) = build_prompt(diff, false);
+        let (_, user) = build_prompt(CommitChanges::Diff(diff), CommitMessageStyle::Plain);
 
         assert!(user.contains(r#"\"world\""#));
         assert!(user.contains(r"\d+"));
     }
 
+    #[test]
+    fn test_build_prompt_detailed_asks_for_body() {
+        let (system, user) = build_prompt(CommitChanges::Diff("+ x"), CommitMessageStyle::Detailed);
+
+        assert!(system.contains("subject line and a body"));
+        assert!(system.contains("blank line"));
+        assert!(!system.contains("Conventional Commits"));
+        assert!(user.contains("```diff"));
+    }
+
+    #[test]
+    fn test_build_prompt_summary_has_no_diff_fence() {
+        let summary = "- Modified: src/lib.rs\n";
+        let (_, user) = build_prompt(CommitChanges::Summary(summary), CommitMessageStyle::Plain);
+
+        assert!(user.contains("too large"));
+        assert!(user.contains("src/lib.rs"));
+        assert!(!user.contains("```diff"));
+    }
+
+    // ==================== build_explain_prompt Tests ====================
+
+    #[test]
+    fn test_build_explain_prompt() {
+        let (system, user) = build_explain_prompt("@@ -1 +1 @@\n-a\n+b");
+
+        assert!(system.contains("Why it might matter"));
+        assert!(user.contains("```diff\n@@ -1 +1 @@\n-a\n+b\n```"));
+    }
+
+    // ==================== parse_commit_message Tests ====================
+
+    #[test]
+    fn test_parse_commit_message_subject_only() {
+        let (subject, body) = parse_commit_message("  Add login page\n");
+
+        assert_eq!(subject, "Add login page");
+        assert!(body.is_none());
+    }
+
+    #[test]
+    fn test_parse_commit_message_with_body() {
+        let (subject, body) =
+            parse_commit_message("Fix token refresh\n\nTokens expired early.\n- Use server time");
+
+        assert_eq!(subject, "Fix token refresh");
+        assert_eq!(
+            body.as_deref(),
+            Some("Tokens expired early.\n- Use server time")
+        );
+    }
+
+    #[test]
+    fn test_parse_commit_message_strips_code_fence() {
+        let (subject, body) = parse_commit_message("```\nfeat: add search\n```");
+
+        assert_eq!(subject, "feat: add search");
+        assert!(body.is_none());
+    }
+
     // ==================== build_pr_prompt Tests ====================
 
+    fn pr_prompt(
+        commits: &[(String, String)],
+        changes: Option<CommitChanges<'_>>,
+        available_labels: Option<&[String]>,
+    ) -> (String, String) {
+        build_pr_prompt(&PrPromptInput {
+            commits,
+            changes,
+            available_labels,
+            linked_issue: None,
+        })
+    }
+
     #[test]
     fn test_build_pr_prompt_with_commits() {
         let commits = vec![
             ("abc123".to_string(), "Add login page".to_string()),
             ("def456".to_string(), "Fix validation".to_string()),
         ];
-        let (system, user) = build_pr_prompt(&commits, None, None);
+        let (system, user) = pr_prompt(&commits, None, None);
 
         assert!(system.contains("pull request"));
         assert!(system.contains("TITLE:"));
@@ -289,7 +443,7 @@ mod tests {
     fn test_build_pr_prompt_with_diff_summary() {
         let commits = vec![("abc123".to_string(), "Update auth".to_string())];
         let summary = "- Modified: src/auth.rs\n- Added: src/login.rs";
-        let (_, user) = build_pr_prompt(&commits, Some(summary), None);
+        let (_, user) = pr_prompt(&commits, Some(CommitChanges::Summary(summary)), None);
 
         assert!(user.contains("Changed files:"));
         assert!(user.contains("src/auth.rs"));
@@ -299,7 +453,7 @@ mod tests {
     #[test]
     fn test_build_pr_prompt_empty_commits() {
         let commits: Vec<(String, String)> = vec![];
-        let (system, user) = build_pr_prompt(&commits, None, None);
+        let (system, user) = pr_prompt(&commits, None, None);
 
         assert!(!system.is_empty());
         assert!(user.contains("Generate a PR title"));
@@ -308,7 +462,7 @@ mod tests {
     #[test]
     fn test_build_pr_prompt_no_diff_summary() {
         let commits = vec![("abc".to_string(), "Test".to_string())];
-        let (_, user) = build_pr_prompt(&commits, None, None);
+        let (_, user) = pr_prompt(&commits, None, None);
 
         assert!(!user.contains("Changed files:"));
     }
@@ -321,7 +475,7 @@ mod tests {
             "enhancement".to_string(),
             "docs".to_string(),
         ];
-        let (_, user) = build_pr_prompt(&commits, None, Some(&labels));
+        let (_, user) = pr_prompt(&commits, None, Some(&labels));
 
         assert!(user.contains("Available labels: bug, enhancement, docs"));
     }
@@ -330,7 +484,7 @@ mod tests {
     fn test_build_pr_prompt_with_empty_labels() {
         let commits = vec![("abc".to_string(), "Test".to_string())];
         let labels: Vec<String> = vec![];
-        let (_, user) = build_pr_prompt(&commits, None, Some(&labels));
+        let (_, user) = pr_prompt(&commits, None, Some(&labels));
 
         assert!(!user.contains("Available labels:"));
     }
@@ -338,7 +492,7 @@ mod tests {
     #[test]
     fn test_build_pr_prompt_without_labels() {
         let commits = vec![("abc".to_string(), "Test".to_string())];
-        let (_, user) = build_pr_prompt(&commits, None, None);
+        let (_, user) = pr_prompt(&commits, None, None);
 
         assert!(!user.contains("Available labels:"));
     }
@@ -346,9 +500,32 @@ mod tests {
     #[test]
     fn test_build_pr_prompt_system_prompt_mentions_markdown() {
         let commits = vec![("abc".to_string(), "Test".to_string())];
-        let (system, _) = build_pr_prompt(&commits, None, None);
+        let (system, _) = pr_prompt(&commits, None, None);
 
         assert!(system.contains("GitHub-flavored markdown"));
+        assert!(system.contains("## Testing"));
+        assert!(system.contains("## Notes"));
+    }
+
+    #[test]
+    fn test_build_pr_prompt_with_diff() {
+        let commits = vec![("abc".to_string(), "Add search".to_string())];
+        let (_, user) = pr_prompt(&commits, Some(CommitChanges::Diff("+fn search() {}")), None);
+
+        assert!(user.contains("```diff\n+fn search() {}"));
+        assert!(!user.contains("too large"));
+    }
+
+    #[test]
+    fn test_build_pr_prompt_with_linked_issue() {
+        let commits = vec![("abc".to_string(), "Fix crash".to_string())];
+        let (_, user) = build_pr_prompt(&PrPromptInput {
+            commits: &commits,
+            linked_issue: Some(1234),
+            ..Default::default()
+        });
+
+        assert!(user.contains("Linked issue: #1234"));
     }
 
     // ==================== parse_pr_response Tests ====================
@@ -1,28 +1,60 @@
 use async_trait::async_trait;
+use reqwest::StatusCode;
 
-use crate::error::Result;
+use crate::error::{AxisError, Result};
+use crate::models::{AiModelInfo, CommitMessageStyle};
+use crate::services::ai::prompt::{CommitChanges, PrPromptInput};
+
+/// Receives partial output while a response streams in
+pub type ChunkCallback<'a> = &'a (dyn Fn(&str) + Send + Sync);
 
 #[async_trait]
 pub trait AiProviderTrait: Send + Sync {
+    /// Generate a commit message, passing each streamed piece to `on_chunk`.
+    /// Returns the full message and the model that produced it.
     async fn generate_commit_message(
+        &self,
+        changes: CommitChanges<'_>,
+        style: CommitMessageStyle,
+        api_key: Option<&str>,
+        model: Option<&str>,
+        base_url: Option<&str>,
+        on_chunk: ChunkCallback<'_>,
+    ) -> Result<(String, String)>;
+
+    /// Explain a diff of one file or hunk, passing each streamed piece to `on_chunk`.
+    /// Returns the full explanation and the model that produced it.
+    async fn explain_diff(
         &self,
         diff: &str,
         api_key: Option<&str>,
         model: Option<&str>,
         base_url: Option<&str>,
-        conventional_commits: bool,
+        on_chunk: ChunkCallback<'_>,
     ) -> Result<(String, String)>;
 
+    /// Generate a PR title, body and suggested labels.
+    /// Returns them with the model that produced them.
     async fn generate_pr_description(
         &self,
-        commits: &[(String, String)],
-        diff_summary: Option<&str>,
-        available_labels: Option<&[String]>,
+        input: &PrPromptInput<'_>,
         api_key: Option<&str>,
         model: Option<&str>,
         base_url: Option<&str>,
     ) -> Result<(String, String, Vec<String>, String)>;
 
+    /// Models offered by the provider
+    async fn list_models(
+        &self,
+        api_key: Option<&str>,
+        base_url: Option<&str>,
+    ) -> Result<Vec<AiModelInfo>>;
+
+    /// Confirm the provider is reachable and accepts the key with a cheap request
+    async fn check_connection(&self, api_key: Option<&str>, base_url: Option<&str>) -> Result<()> {
+        self.list_models(api_key, base_url).await.map(|_| ())
+    }
+
     fn default_model(&self) -> &'static str;
 
     #[cfg(test)]
@@ -32,3 +64,129 @@ pub trait AiProviderTrait: Send + Sync {
         true
     }
 }
+
+/// Map an unsuccessful provider response to a typed error
+pub(super) fn api_error(provider: &str, model: &str, status: StatusCode, body: &str) -> AxisError {
+    match status {
+        StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN => {
+            AxisError::ApiKeyRejected(provider.to_string())
+        }
+        StatusCode::NOT_FOUND => AxisError::AiModelNotFound(model.to_string()),
+        StatusCode::TOO_MANY_REQUESTS => AxisError::AiRateLimited(provider.to_string()),
+        _ => AxisError::AiServiceError(format!("{provider} API error ({status}): {body}")),
+    }
+}
+
+/// Map a request that never got a response to a typed error. `target` names the
+/// provider or URL. A refused connection to a local daemon means it is not running;
+/// other connection failures and timeouts mean the host is unreachable.
+pub(super) fn request_error(target: &str, local_daemon: bool, e: &reqwest::Error) -> AxisError {
+    let refused = std::iter::successors(Some(e as &(dyn std::error::Error + 'static)), |err| {
+        err.source()
+    })
+    .filter_map(|err| err.downcast_ref::<std::io::Error>())
+    .any(|err| err.kind() == std::io::ErrorKind::ConnectionRefused);
+
+    if refused && local_daemon {
+        AxisError::AiDaemonNotRunning(target.to_string())
+    } else if e.is_connect() || e.is_timeout() {
+        AxisError::AiNetworkUnreachable(target.to_string())
+    } else {
+        AxisError::AiServiceError(format!("Request to {target} failed: {e}"))
+    }
+}
+
+/// Read the error body of an unsuccessful response and map it with `api_error`
+pub(super) async fn error_from_response(
+    provider: &str,
+    model: &str,
+    response: reqwest::Response,
+) -> AxisError {
+    let status = response.status();
+    let body = response
+        .text()
+        .await
+        .unwrap_or_else(|_| "Unknown error".to_string());
+    api_error(provider, model, status, &body)
+}
+
+/// Feed each non-empty line of a streaming response body to `on_line`
+pub(super) async fn read_lines(
+    mut response: reqwest::Response,
+    mut on_line: impl FnMut(&str) -> Result<()> + Send,
+) -> Result<()> {
+    let mut buffer: Vec<u8> = Vec::new();
+
+    while let Some(chunk) = response
+        .chunk()
+        .await
+        .map_err(|e| AxisError::AiServiceError(format!("Failed to read response: {e}")))?
+    {
+        buffer.extend_from_slice(&chunk);
+        while let Some(pos) = buffer.iter().position(|b| *b == b'\n') {
+            let line: Vec<u8> = buffer.drain(..=pos).collect();
+            let line = String::from_utf8_lossy(&line);
+            let line = line.trim();
+            if !line.is_empty() {
+                on_line(line)?;
+            }
+        }
+    }
+
+    let rest = String::from_utf8_lossy(&buffer);
+    let rest = rest.trim();
+    if !rest.is_empty() {
+        on_line(rest)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_api_error_maps_known_statuses() {
+        assert!(matches!(
+            api_error("OpenAI", "gpt-4o", StatusCode::UNAUTHORIZED, ""),
+            AxisError::ApiKeyRejected(p) if p == "OpenAI"
+        ));
+        assert!(matches!(
+            api_error("Ollama", "llama9", StatusCode::NOT_FOUND, "model not found"),
+            AxisError::AiModelNotFound(m) if m == "llama9"
+        ));
+        assert!(matches!(
+            api_error("Anthropic", "claude", StatusCode::TOO_MANY_REQUESTS, ""),
+            AxisError::AiRateLimited(p) if p == "Anthropic"
+        ));
+    }
+
+    #[test]
+    fn test_api_error_keeps_body_for_other_statuses() {
+        let err = api_error("OpenAI", "gpt-4o", StatusCode::BAD_REQUEST, "bad input");
+
+        assert!(matches!(&err, AxisError::AiServiceError(msg) if msg.contains("bad input")));
+    }
+
+    #[tokio::test]
+    async fn test_request_error_refused_daemon() {
+        // Bind then drop a listener so the port is free and connections are refused
+        let port = std::net::TcpListener::bind("127.0.0.1:0")
+            .and_then(|l| l.local_addr())
+            .expect("should bind")
+            .port();
+        let url = format!("http://127.0.0.1:{port}");
+        let err = reqwest::get(&url)
+            .await
+            .expect_err("should fail to connect");
+
+        assert!(matches!(
+            request_error(&url, true, &err),
+            AxisError::AiDaemonNotRunning(u) if u == url
+        ));
+        assert!(matches!(
+            request_error("OpenAI", false, &err),
+            AxisError::AiNetworkUnreachable(p) if p == "OpenAI"
+        ));
+    }
+}
@@ -0,0 +1,113 @@
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use crate::models::AiProvider;
+
+/// Splits text the way BPE tokenizers pre-tokenize it: contractions, words with one
+/// leading non-letter, numbers of up to three digits, punctuation runs, and whitespace
+static PIECE_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r"'(?:s|t|re|ve|m|ll|d)|[^\r\n\p{L}\p{N}]?\p{L}+|\p{N}{1,3}| ?[^\s\p{L}\p{N}]+[\r\n]*|\s*[\r\n]+|\s+",
+    )
+    .expect("Invalid regex pattern")
+});
+
+/// ASCII letters a single BPE token covers before a word splits
+const ASCII_LETTERS_PER_TOKEN: usize = 8;
+
+/// Punctuation characters a single BPE token covers, e.g. `();` or `!("`
+const PUNCTUATION_PER_TOKEN: usize = 3;
+
+/// Characters per token for tokenizers we cannot approximate
+const CHARS_PER_TOKEN: usize = 4;
+
+/// Estimated number of tokens `text` costs with `provider`'s tokenizer.
+/// OpenAI and Anthropic use tiktoken-style BPE; Ollama models vary, so they get a
+/// character-based estimate.
+pub fn estimate_tokens(provider: &AiProvider, text: &str) -> u32 {
+    let tokens = match provider {
+        AiProvider::OpenAi | AiProvider::Anthropic => PIECE_REGEX
+            .find_iter(text)
+            .map(|piece| piece_tokens(piece.as_str()))
+            .sum(),
+        AiProvider::Ollama => text.chars().count().div_ceil(CHARS_PER_TOKEN),
+    };
+    u32::try_from(tokens).unwrap_or(u32::MAX)
+}
+
+fn piece_tokens(piece: &str) -> usize {
+    let body = piece.trim();
+    let Some(first) = body.chars().next() else {
+        // Whitespace runs merge into one token
+        return 1;
+    };
+
+    let letters = body.chars().filter(|c| c.is_alphabetic()).count();
+    if letters > 0 {
+        if body.is_ascii() {
+            1 + (letters - 1) / ASCII_LETTERS_PER_TOKEN
+        } else {
+            // Non-Latin scripts get roughly one token per character
+            letters
+        }
+    } else if first.is_numeric() {
+        1
+    } else {
+        body.chars().count().div_ceil(PUNCTUATION_PER_TOKEN)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Fixture prompts with their token counts under OpenAI's `cl100k_base` encoding
+    const FIXTURES: &[(&str, u32)] = &[
+        ("Hello, world!", 4),
+        ("The quick brown fox jumps over the lazy dog.", 10),
+        (
+            "--- a/src/main.rs\n+++ b/src/main.rs\n@@ -1,3 +1,3 @@\n fn main() {\n-    println!(\"Hello\");\n+    println!(\"Hello, world!\");\n }\n",
+            43,
+        ),
+    ];
+
+    fn within_tolerance(estimate: u32, expected: u32) -> bool {
+        let tolerance = (f64::from(expected) * 0.15).ceil();
+        (f64::from(estimate) - f64::from(expected)).abs() <= tolerance
+    }
+
+    #[test]
+    fn test_estimate_tokens_bpe_fixtures() {
+        for (text, expected) in FIXTURES {
+            for provider in [AiProvider::OpenAi, AiProvider::Anthropic] {
+                let estimate = estimate_tokens(&provider, text);
+                assert!(
+                    within_tolerance(estimate, *expected),
+                    "estimated {estimate} tokens for {text:?}, expected about {expected}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_estimate_tokens_splits_long_identifiers() {
+        assert_eq!(
+            estimate_tokens(&AiProvider::OpenAi, "format_diff_for_ai"),
+            4
+        );
+        assert_eq!(
+            estimate_tokens(&AiProvider::OpenAi, "internationalization"),
+            3
+        );
+    }
+
+    #[test]
+    fn test_estimate_tokens_ollama_counts_characters() {
+        assert_eq!(estimate_tokens(&AiProvider::Ollama, ""), 0);
+        assert_eq!(estimate_tokens(&AiProvider::Ollama, "abcd"), 1);
+        assert_eq!(estimate_tokens(&AiProvider::Ollama, "Hello, world!"), 4);
+        // Characters, not bytes
+        assert_eq!(estimate_tokens(&AiProvider::Ollama, "日本語です"), 2);
+    }
+}
@@ -0,0 +1,561 @@
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::Arc;
+use std::time::Instant;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use parking_lot::Mutex;
+use tokio::sync::{Notify, Semaphore, SemaphorePermit};
+
+use super::prompt::{self, CommitChanges, PrPromptInput};
+use super::provider::{AiProviderTrait, ChunkCallback};
+use super::tokens::estimate_tokens;
+use crate::error::{AxisError, Result};
+use crate::models::{
+    AiFeature, AiModelInfo, AiProvider, AiQueueEntry, AiQueueState, AiRequestOutcome,
+    CommitMessageStyle,
+};
+
+/// A provider request to record in the AI request ledger
+#[derive(Debug, Clone, PartialEq)]
+pub struct AiRequestRecord {
+    pub timestamp: DateTime<Utc>,
+    pub feature: AiFeature,
+    pub provider: AiProvider,
+    pub model: String,
+    pub prompt_tokens: u32,
+    /// Estimated from the response text; `None` when there was no response
+    pub response_tokens: Option<u32>,
+    pub duration_ms: u64,
+    pub outcome: AiRequestOutcome,
+    pub error: Option<String>,
+}
+
+/// Persistent ledger of provider requests
+pub trait AiUsageStore: Send + Sync {
+    fn record_ai_request(&self, record: &AiRequestRecord) -> Result<()>;
+}
+
+/// In-memory `AiUsageStore` for tests
+#[cfg(test)]
+#[derive(Default)]
+pub struct MemoryAiUsageStore {
+    records: Mutex<Vec<AiRequestRecord>>,
+}
+
+#[cfg(test)]
+impl MemoryAiUsageStore {
+    pub fn records(&self) -> Vec<AiRequestRecord> {
+        self.records.lock().clone()
+    }
+}
+
+#[cfg(test)]
+impl AiUsageStore for MemoryAiUsageStore {
+    fn record_ai_request(&self, record: &AiRequestRecord) -> Result<()> {
+        self.records.lock().push(record.clone());
+        Ok(())
+    }
+}
+
+struct QueuedRequest {
+    entry: AiQueueEntry,
+    cancel: Arc<Notify>,
+}
+
+/// Limits concurrent provider requests. Requests over the limit wait in a visible
+/// queue, in arrival order, and any entry can be cancelled.
+pub struct AiRequestLimiter {
+    slots: Semaphore,
+    next_id: AtomicU32,
+    entries: Mutex<Vec<QueuedRequest>>,
+}
+
+impl AiRequestLimiter {
+    pub fn new(max_concurrent: usize) -> Self {
+        Self {
+            slots: Semaphore::new(max_concurrent.max(1)),
+            next_id: AtomicU32::new(1),
+            entries: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Wait for a free slot. Fails with `AiRequestCancelled` when the entry is
+    /// cancelled while it waits.
+    pub async fn acquire(&self, feature: AiFeature, model: &str) -> Result<AiRequestSlot<'_>> {
+        let id = self.next_id.fetch_add(1, Ordering::SeqCst);
+        let cancel = Arc::new(Notify::new());
+        self.entries.lock().push(QueuedRequest {
+            entry: AiQueueEntry {
+                id,
+                feature,
+                model: model.to_string(),
+                state: AiQueueState::Waiting,
+                queued_at: Utc::now(),
+                started_at: None,
+            },
+            cancel: Arc::clone(&cancel),
+        });
+        // Removes the entry however this request ends, including when the caller is dropped
+        let entry = QueueEntryGuard { limiter: self, id };
+
+        let permit = tokio::select! {
+            permit = self.slots.acquire() => {
+                permit.map_err(|_| AxisError::Other("AI request limiter closed".to_string()))?
+            }
+            () = cancel.notified() => return Err(AxisError::AiRequestCancelled),
+        };
+
+        if let Some(queued) = self.entries.lock().iter_mut().find(|q| q.entry.id == id) {
+            queued.entry.state = AiQueueState::Running;
+            queued.entry.started_at = Some(Utc::now());
+        }
+
+        Ok(AiRequestSlot {
+            _entry: entry,
+            cancel,
+            _permit: permit,
+        })
+    }
+
+    /// Waiting and running requests, oldest first
+    pub fn queue(&self) -> Vec<AiQueueEntry> {
+        self.entries
+            .lock()
+            .iter()
+            .map(|queued| queued.entry.clone())
+            .collect()
+    }
+
+    /// Cancel a waiting or running request. Returns false when no entry has this id.
+    pub fn cancel(&self, id: u32) -> bool {
+        match self.entries.lock().iter().find(|q| q.entry.id == id) {
+            Some(queued) => {
+                queued.cancel.notify_one();
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+struct QueueEntryGuard<'a> {
+    limiter: &'a AiRequestLimiter,
+    id: u32,
+}
+
+impl Drop for QueueEntryGuard<'_> {
+    fn drop(&mut self) {
+        self.limiter
+            .entries
+            .lock()
+            .retain(|queued| queued.entry.id != self.id);
+    }
+}
+
+/// A held limiter slot; dropping it frees the slot and removes the queue entry
+pub struct AiRequestSlot<'a> {
+    _entry: QueueEntryGuard<'a>,
+    cancel: Arc<Notify>,
+    _permit: SemaphorePermit<'a>,
+}
+
+impl AiRequestSlot<'_> {
+    /// Resolves when the request is cancelled from the queue
+    pub async fn cancelled(&self) {
+        self.cancel.notified().await;
+    }
+}
+
+/// Wraps a provider so each request waits for a limiter slot and lands in the ledger.
+/// Model listing and connection checks pass through untracked.
+pub struct TrackedProvider {
+    inner: Box<dyn AiProviderTrait>,
+    provider: AiProvider,
+    limiter: Arc<AiRequestLimiter>,
+    store: Arc<dyn AiUsageStore>,
+}
+
+impl TrackedProvider {
+    pub fn new(
+        inner: Box<dyn AiProviderTrait>,
+        provider: AiProvider,
+        limiter: Arc<AiRequestLimiter>,
+        store: Arc<dyn AiUsageStore>,
+    ) -> Self {
+        Self {
+            inner,
+            provider,
+            limiter,
+            store,
+        }
+    }
+
+    /// Run `request` in a limiter slot and record it. `response_text` picks the
+    /// generated text out of a successful result for the response token estimate.
+    async fn track<T>(
+        &self,
+        feature: AiFeature,
+        model: Option<&str>,
+        (system, user): (String, String),
+        response_text: impl Fn(&T) -> String,
+        request: impl std::future::Future<Output = Result<T>>,
+    ) -> Result<T> {
+        let model = model.unwrap_or(self.inner.default_model()).to_string();
+        let prompt_tokens = estimate_tokens(&self.provider, &system)
+            .saturating_add(estimate_tokens(&self.provider, &user));
+
+        // Requests cancelled before they reach the provider cost nothing, so they are not recorded
+        let slot = self.limiter.acquire(feature, &model).await?;
+        let started = Instant::now();
+        let result = tokio::select! {
+            result = request => result,
+            () = slot.cancelled() => Err(AxisError::AiRequestCancelled),
+        };
+        let duration_ms = u64::try_from(started.elapsed().as_millis()).unwrap_or(u64::MAX);
+        drop(slot);
+
+        let (outcome, response_tokens, error) = match &result {
+            Ok(value) => (
+                AiRequestOutcome::Success,
+                Some(estimate_tokens(&self.provider, &response_text(value))),
+                None,
+            ),
+            Err(AxisError::AiRequestCancelled) => (AiRequestOutcome::Cancelled, None, None),
+            Err(e) => (AiRequestOutcome::Failure, None, Some(e.to_string())),
+        };
+        let record = AiRequestRecord {
+            timestamp: Utc::now(),
+            feature,
+            provider: self.provider.clone(),
+            model,
+            prompt_tokens,
+            response_tokens,
+            duration_ms,
+            outcome,
+            error,
+        };
+        if let Err(e) = self.store.record_ai_request(&record) {
+            log::error!("Failed to record {feature} request in the AI ledger: {e}");
+        }
+
+        result
+    }
+}
+
+#[async_trait]
+impl AiProviderTrait for TrackedProvider {
+    async fn generate_commit_message(
+        &self,
+        changes: CommitChanges<'_>,
+        style: CommitMessageStyle,
+        api_key: Option<&str>,
+        model: Option<&str>,
+        base_url: Option<&str>,
+        on_chunk: ChunkCallback<'_>,
+    ) -> Result<(String, String)> {
+        self.track(
+            AiFeature::CommitMessage,
+            model,
+            prompt::build_prompt(changes, style),
+            |(message, _)| message.clone(),
+            self.inner
+                .generate_commit_message(changes, style, api_key, model, base_url, on_chunk),
+        )
+        .await
+    }
+
+    async fn explain_diff(
+        &self,
+        diff: &str,
+        api_key: Option<&str>,
+        model: Option<&str>,
+        base_url: Option<&str>,
+        on_chunk: ChunkCallback<'_>,
+    ) -> Result<(String, String)> {
+        self.track(
+            AiFeature::ExplainDiff,
+            model,
+            prompt::build_explain_prompt(diff),
+            |(explanation, _)| explanation.clone(),
+            self.inner
+                .explain_diff(diff, api_key, model, base_url, on_chunk),
+        )
+        .await
+    }
+
+    async fn generate_pr_description(
+        &self,
+        input: &PrPromptInput<'_>,
+        api_key: Option<&str>,
+        model: Option<&str>,
+        base_url: Option<&str>,
+    ) -> Result<(String, String, Vec<String>, String)> {
+        self.track(
+            AiFeature::PrDescription,
+            model,
+            prompt::build_pr_prompt(input),
+            |(title, body, labels, _)| format!("{title}\n{body}\n{}", labels.join(", ")),
+            self.inner
+                .generate_pr_description(input, api_key, model, base_url),
+        )
+        .await
+    }
+
+    async fn list_models(
+        &self,
+        api_key: Option<&str>,
+        base_url: Option<&str>,
+    ) -> Result<Vec<AiModelInfo>> {
+        self.inner.list_models(api_key, base_url).await
+    }
+
+    async fn check_connection(&self, api_key: Option<&str>, base_url: Option<&str>) -> Result<()> {
+        self.inner.check_connection(api_key, base_url).await
+    }
+
+    fn default_model(&self) -> &'static str {
+        self.inner.default_model()
+    }
+
+    #[cfg(test)]
+    fn name(&self) -> &'static str {
+        self.inner.name()
+    }
+
+    fn requires_api_key(&self) -> bool {
+        self.inner.requires_api_key()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    /// Replies with `reply`, or fails when it is `None`
+    struct StubProvider {
+        reply: Option<&'static str>,
+    }
+
+    #[async_trait]
+    impl AiProviderTrait for StubProvider {
+        async fn generate_commit_message(
+            &self,
+            _changes: CommitChanges<'_>,
+            _style: CommitMessageStyle,
+            _api_key: Option<&str>,
+            model: Option<&str>,
+            _base_url: Option<&str>,
+            _on_chunk: ChunkCallback<'_>,
+        ) -> Result<(String, String)> {
+            let model = model.unwrap_or(self.default_model()).to_string();
+            match self.reply {
+                Some(reply) => Ok((reply.to_string(), model)),
+                None => Err(AxisError::AiRateLimited("Stub".to_string())),
+            }
+        }
+
+        async fn explain_diff(
+            &self,
+            _diff: &str,
+            _api_key: Option<&str>,
+            _model: Option<&str>,
+            _base_url: Option<&str>,
+            _on_chunk: ChunkCallback<'_>,
+        ) -> Result<(String, String)> {
+            // Never answers, so only cancellation ends the request
+            std::future::pending().await
+        }
+
+        async fn generate_pr_description(
+            &self,
+            _input: &PrPromptInput<'_>,
+            _api_key: Option<&str>,
+            _model: Option<&str>,
+            _base_url: Option<&str>,
+        ) -> Result<(String, String, Vec<String>, String)> {
+            Err(AxisError::AiServiceError("unused".to_string()))
+        }
+
+        async fn list_models(
+            &self,
+            _api_key: Option<&str>,
+            _base_url: Option<&str>,
+        ) -> Result<Vec<AiModelInfo>> {
+            Ok(Vec::new())
+        }
+
+        fn default_model(&self) -> &'static str {
+            "gpt-4o-mini"
+        }
+
+        fn name(&self) -> &'static str {
+            "Stub"
+        }
+    }
+
+    fn tracked(
+        reply: Option<&'static str>,
+        limiter: &Arc<AiRequestLimiter>,
+    ) -> (TrackedProvider, Arc<MemoryAiUsageStore>) {
+        let store = Arc::new(MemoryAiUsageStore::default());
+        let provider = TrackedProvider::new(
+            Box::new(StubProvider { reply }),
+            AiProvider::OpenAi,
+            Arc::clone(limiter),
+            Arc::clone(&store) as Arc<dyn AiUsageStore>,
+        );
+        (provider, store)
+    }
+
+    async fn commit_message(provider: &TrackedProvider) -> Result<(String, String)> {
+        provider
+            .generate_commit_message(
+                CommitChanges::Diff("+fn main() {}\n"),
+                CommitMessageStyle::Plain,
+                None,
+                None,
+                None,
+                &|_| {},
+            )
+            .await
+    }
+
+    // ==================== Ledger Tests ====================
+
+    #[tokio::test]
+    async fn test_tracked_provider_records_success() {
+        let limiter = Arc::new(AiRequestLimiter::new(1));
+        let (provider, store) = tracked(Some("Add main function"), &limiter);
+
+        commit_message(&provider).await.expect("should generate");
+
+        let records = store.records();
+        assert_eq!(records.len(), 1);
+        let record = &records[0];
+        assert_eq!(record.feature, AiFeature::CommitMessage);
+        assert_eq!(record.provider, AiProvider::OpenAi);
+        assert_eq!(record.model, "gpt-4o-mini");
+        assert_eq!(record.outcome, AiRequestOutcome::Success);
+        assert!(record.prompt_tokens > 0);
+        assert_eq!(record.response_tokens, Some(3));
+        assert_eq!(record.error, None);
+        assert!(limiter.queue().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_tracked_provider_records_failure() {
+        let limiter = Arc::new(AiRequestLimiter::new(1));
+        let (provider, store) = tracked(None, &limiter);
+
+        let result = commit_message(&provider).await;
+
+        assert!(matches!(result, Err(AxisError::AiRateLimited(_))));
+        let records = store.records();
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].outcome, AiRequestOutcome::Failure);
+        assert_eq!(records[0].response_tokens, None);
+        assert_eq!(
+            records[0].error.as_deref(),
+            Some("AI provider rate limit reached: Stub")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_cancel_running_request_records_cancellation() {
+        let limiter = Arc::new(AiRequestLimiter::new(1));
+        let (provider, store) = tracked(None, &limiter);
+
+        let cancel = async {
+            while limiter.queue().is_empty() {
+                tokio::task::yield_now().await;
+            }
+            let entry = &limiter.queue()[0];
+            assert_eq!(entry.feature, AiFeature::ExplainDiff);
+            assert_eq!(entry.state, AiQueueState::Running);
+            assert!(limiter.cancel(entry.id));
+        };
+        let (result, ()) = tokio::join!(
+            provider.explain_diff("+x\n", None, None, None, &|_| {}),
+            cancel
+        );
+
+        assert!(matches!(result, Err(AxisError::AiRequestCancelled)));
+        assert_eq!(store.records()[0].outcome, AiRequestOutcome::Cancelled);
+        assert!(limiter.queue().is_empty());
+    }
+
+    // ==================== Limiter Tests ====================
+
+    #[tokio::test]
+    async fn test_limiter_holds_second_request_until_first_completes() {
+        let limiter = Arc::new(AiRequestLimiter::new(1));
+        let first = limiter
+            .acquire(AiFeature::CommitMessage, "gpt-4o-mini")
+            .await
+            .expect("should get a slot");
+
+        let (started_tx, mut started_rx) = tokio::sync::oneshot::channel();
+        let second = tokio::spawn({
+            let limiter = Arc::clone(&limiter);
+            async move {
+                let _slot = limiter
+                    .acquire(AiFeature::ExplainDiff, "gpt-4o-mini")
+                    .await
+                    .expect("should get a slot");
+                started_tx.send(()).expect("should signal start");
+            }
+        });
+
+        while limiter.queue().len() < 2 {
+            tokio::task::yield_now().await;
+        }
+        let queue = limiter.queue();
+        assert_eq!(queue[0].state, AiQueueState::Running);
+        assert_eq!(queue[1].state, AiQueueState::Waiting);
+        assert_eq!(queue[1].feature, AiFeature::ExplainDiff);
+
+        assert!(
+            tokio::time::timeout(Duration::from_millis(50), &mut started_rx)
+                .await
+                .is_err(),
+            "second request should wait for the first"
+        );
+
+        drop(first);
+        started_rx.await.expect("second request should start");
+        second.await.expect("second request should finish");
+        assert!(limiter.queue().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_limiter_cancel_waiting_request() {
+        let limiter = Arc::new(AiRequestLimiter::new(1));
+        let _first = limiter
+            .acquire(AiFeature::CommitMessage, "gpt-4o-mini")
+            .await
+            .expect("should get a slot");
+
+        let second = tokio::spawn({
+            let limiter = Arc::clone(&limiter);
+            async move {
+                limiter
+                    .acquire(AiFeature::PrDescription, "gpt-4o-mini")
+                    .await
+                    .map(|_| ())
+            }
+        });
+        while limiter.queue().len() < 2 {
+            tokio::task::yield_now().await;
+        }
+
+        let waiting = limiter.queue()[1].id;
+        assert!(limiter.cancel(waiting));
+        let result = second.await.expect("task should finish");
+
+        assert!(matches!(result, Err(AxisError::AiRequestCancelled)));
+        assert_eq!(limiter.queue().len(), 1);
+        assert!(!limiter.cancel(waiting));
+    }
+}
@@ -0,0 +1,211 @@
+use std::path::{Path, PathBuf};
+
+use crate::error::AxisError;
+use crate::models::{ObjectAlternate, ObjectSharing};
+
+/// File listing borrowed object stores, relative to the objects directory
+const ALTERNATES_FILE: &str = "info/alternates";
+
+/// Error message fragments libgit2 produces for an object missing from every store
+const MISSING_OBJECT_MARKERS: &[&str] = &["object not found", "no match for id"];
+
+/// Object stores listed in `<objects_dir>/info/alternates`, in order.
+/// Relative entries are resolved against `objects_dir`, as git does.
+pub fn read_alternates(objects_dir: &Path) -> Vec<ObjectAlternate> {
+    let file = objects_dir.join(ALTERNATES_FILE);
+    let contents = match std::fs::read_to_string(&file) {
+        Ok(contents) => contents,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Vec::new(),
+        Err(e) => {
+            log::warn!("Failed to read {}: {e}", file.display());
+            return Vec::new();
+        }
+    };
+
+    contents
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(|line| {
+            let path = objects_dir.join(line);
+            ObjectAlternate {
+                reachable: path.is_dir(),
+                path,
+            }
+        })
+        .collect()
+}
+
+/// Repositories directly inside (or at) each of `roots` whose alternates point at
+/// `objects_dir`. Best effort: unreadable directories are skipped.
+pub fn find_borrowers(objects_dir: &Path, roots: &[PathBuf]) -> Vec<PathBuf> {
+    let Ok(own) = objects_dir.canonicalize() else {
+        return Vec::new();
+    };
+
+    let mut borrowers = Vec::new();
+    for root in roots {
+        let children = std::fs::read_dir(root)
+            .map(|entries| {
+                entries
+                    .filter_map(std::result::Result::ok)
+                    .map(|entry| entry.path())
+                    .filter(|path| path.is_dir())
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_else(|e| {
+                log::debug!("Skipping shared-store root {}: {e}", root.display());
+                Vec::new()
+            });
+
+        for candidate in std::iter::once(root.clone()).chain(children) {
+            // Work trees keep objects in .git; bare repositories at the top level
+            let objects = [candidate.join(".git/objects"), candidate.join("objects")]
+                .into_iter()
+                .find(|dir| dir.join(ALTERNATES_FILE).is_file());
+            let Some(objects) = objects else {
+                continue;
+            };
+            let borrows = read_alternates(&objects)
+                .iter()
+                .filter_map(|alternate| alternate.path.canonicalize().ok())
+                .any(|path| path == own);
+            if borrows && !borrowers.contains(&candidate) {
+                borrowers.push(candidate);
+            }
+        }
+    }
+    borrowers
+}
+
+/// Sharing report for the repository whose objects live in `objects_dir`
+pub fn object_sharing(objects_dir: &Path, shared_roots: &[PathBuf]) -> ObjectSharing {
+    let alternates = read_alternates(objects_dir);
+    let borrowers = find_borrowers(objects_dir, shared_roots);
+
+    let mut maintenance_warnings = Vec::new();
+    for alternate in &alternates {
+        if alternate.reachable {
+            maintenance_warnings.push(format!(
+                "Objects are borrowed from {}. gc does not copy them here, so pruning or moving that store breaks this repository; run `git repack -a -d` and remove objects/info/alternates to make it standalone",
+                alternate.path.display()
+            ));
+        } else {
+            maintenance_warnings.push(format!(
+                "Alternate object store {} is unreachable. gc and repack cannot see the objects borrowed from it; reconnect it before running maintenance",
+                alternate.path.display()
+            ));
+        }
+    }
+    if !borrowers.is_empty() {
+        let names: Vec<String> = borrowers.iter().map(|p| p.display().to_string()).collect();
+        maintenance_warnings.push(format!(
+            "{} repositories borrow objects from this one ({}); pruning can delete objects they still need",
+            borrowers.len(),
+            names.join(", ")
+        ));
+    }
+
+    ObjectSharing {
+        alternates,
+        borrowers,
+        maintenance_warnings,
+    }
+}
+
+/// Explain a missing-object error when an alternate of `objects_dir` is unreachable,
+/// the likely cause. Other errors are returned unchanged.
+pub fn explain_missing_object(err: AxisError, objects_dir: &Path) -> AxisError {
+    let AxisError::GitError(message) = &err else {
+        return err;
+    };
+    let lowered = message.to_lowercase();
+    if !MISSING_OBJECT_MARKERS.iter().any(|m| lowered.contains(m))
+        && !is_missing_oid_revspec(&lowered)
+    {
+        return err;
+    }
+
+    match read_alternates(objects_dir)
+        .into_iter()
+        .find(|a| !a.reachable)
+    {
+        Some(alternate) => AxisError::AlternateUnreachable {
+            alternate: alternate.path.display().to_string(),
+            message: message.clone(),
+        },
+        None => err,
+    }
+}
+
+/// Whether `message` reports a full object id that did not resolve, which means
+/// the object itself is missing rather than a misspelt ref
+fn is_missing_oid_revspec(message: &str) -> bool {
+    message
+        .split_once("revspec '")
+        .and_then(|(_, rest)| rest.split_once("' not found"))
+        .is_some_and(|(spec, _)| {
+            matches!(spec.len(), 40 | 64) && spec.chars().all(|c| c.is_ascii_hexdigit())
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn write_alternates(objects_dir: &Path, contents: &str) {
+        std::fs::create_dir_all(objects_dir.join("info")).expect("should create info dir");
+        std::fs::write(objects_dir.join(ALTERNATES_FILE), contents)
+            .expect("should write alternates");
+    }
+
+    #[test]
+    fn test_read_alternates_resolves_relative_paths() {
+        let tmp = TempDir::new().expect("should create temp dir");
+        let objects = tmp.path().join("repo/.git/objects");
+        let shared = tmp.path().join("shared");
+        std::fs::create_dir_all(&shared).expect("should create shared dir");
+        write_alternates(
+            &objects,
+            &format!("# comment\n{}\n\n../../../gone\n", shared.display()),
+        );
+
+        let alternates = read_alternates(&objects);
+        assert_eq!(alternates.len(), 2);
+        assert_eq!(alternates[0].path, shared);
+        assert!(alternates[0].reachable);
+        assert_eq!(alternates[1].path, objects.join("../../../gone"));
+        assert!(!alternates[1].reachable);
+
+        assert!(read_alternates(&tmp.path().join("none")).is_empty());
+    }
+
+    #[test]
+    fn test_explain_missing_object_only_with_unreachable_alternate() {
+        let tmp = TempDir::new().expect("should create temp dir");
+        let objects = tmp.path().join("objects");
+        let missing = || AxisError::GitError("object not found - no match for id (abc)".into());
+
+        assert!(matches!(
+            explain_missing_object(missing(), &objects),
+            AxisError::GitError(_)
+        ));
+
+        write_alternates(&objects, "/nonexistent/objects\n");
+        assert!(matches!(
+            explain_missing_object(missing(), &objects),
+            AxisError::AlternateUnreachable { .. }
+        ));
+        let missing_oid = AxisError::GitError(format!("revspec '{}' not found", "a".repeat(40)));
+        assert!(matches!(
+            explain_missing_object(missing_oid, &objects),
+            AxisError::AlternateUnreachable { .. }
+        ));
+        let missing_ref = AxisError::GitError("revspec 'feature' not found".into());
+        assert!(matches!(
+            explain_missing_object(missing_ref, &objects),
+            AxisError::GitError(_)
+        ));
+    }
+}
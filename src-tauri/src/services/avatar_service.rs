@@ -1,17 +1,117 @@
+use std::collections::HashMap;
 use std::fs;
+use std::io::Write;
 use std::path::{Path, PathBuf};
+use std::sync::Arc;
 use std::time::{Duration, SystemTime};
 
+use async_trait::async_trait;
+use base64::Engine;
 use md5::{Digest, Md5};
+use parking_lot::Mutex;
+use tokio::sync::{OnceCell, Semaphore};
+use tokio::task::JoinSet;
 
 use crate::error::{AxisError, Result};
-use crate::models::AvatarSource;
+use crate::models::{AvatarResponse, AvatarSource, EmailAvatar};
+use crate::services::{ArtifactOwner, IntegrationProvider, Janitor};
 
 const AVATAR_TTL_HOURS: u64 = 24;
 
+/// How long a partial avatar download may stay on disk
+const AVATAR_DOWNLOAD_LIFETIME: Duration = Duration::from_secs(10 * 60);
+
+/// Provider searches and downloads running at once, shared by all batches
+const MAX_CONCURRENT_LOOKUPS: usize = 4;
+
+/// Cached avatars beyond this total size are evicted, least recently fetched first
+const AVATAR_CACHE_MAX_BYTES: u64 = 50 * 1024 * 1024;
+
+/// Extension of the marker recording that no service has a picture for an email
+const NO_AVATAR_EXTENSION: &str = "none";
+
+/// Downloaded avatar image
+pub struct AvatarImage {
+    pub bytes: Vec<u8>,
+    pub content_type: String,
+}
+
+/// HTTP layer used to download avatars
+#[async_trait]
+pub trait AvatarHttp: Send + Sync {
+    /// Download `url`; `None` when the server has no image (Gravatar answers 404)
+    async fn get(&self, url: &str) -> Result<Option<AvatarImage>>;
+}
+
+struct ReqwestAvatarHttp(reqwest::Client);
+
+#[async_trait]
+impl AvatarHttp for ReqwestAvatarHttp {
+    async fn get(&self, url: &str) -> Result<Option<AvatarImage>> {
+        let response = self
+            .0
+            .get(url)
+            .send()
+            .await
+            .map_err(|e| AxisError::Other(format!("Failed to fetch avatar: {e}")))?;
+
+        if response.status() == reqwest::StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
+        if !response.status().is_success() {
+            return Err(AxisError::Other(format!(
+                "Avatar fetch failed with status: {}",
+                response.status()
+            )));
+        }
+
+        let content_type = response
+            .headers()
+            .get(reqwest::header::CONTENT_TYPE)
+            .and_then(|v| v.to_str().ok())
+            .unwrap_or("image/png")
+            .to_string();
+        let bytes = response
+            .bytes()
+            .await
+            .map_err(|e| AxisError::Other(format!("Failed to read avatar bytes: {e}")))?;
+
+        Ok(Some(AvatarImage {
+            bytes: bytes.to_vec(),
+            content_type,
+        }))
+    }
+}
+
+/// Finds avatar URLs by email, e.g. through a connected integration's user search
+#[async_trait]
+pub trait AvatarDirectory: Send + Sync {
+    async fn avatar_url(&self, email: &str) -> Result<Option<String>>;
+}
+
+#[async_trait]
+impl AvatarDirectory for Arc<dyn IntegrationProvider> {
+    async fn avatar_url(&self, email: &str) -> Result<Option<String>> {
+        self.find_user_avatar(email).await
+    }
+}
+
+/// Where a batch may look for avatars
+#[derive(Clone)]
+pub struct AvatarLookup {
+    pub size: u32,
+    pub gravatar_enabled: bool,
+    pub directory: Option<Arc<dyn AvatarDirectory>>,
+}
+
 pub struct AvatarService {
     cache_dir: PathBuf,
-    client: reqwest::Client,
+    http: Arc<dyn AvatarHttp>,
+    janitor: Option<Arc<Janitor>>,
+    /// Resolutions in progress, keyed by normalized email and size, so concurrent
+    /// requests for one author share a single lookup
+    in_flight: Mutex<HashMap<String, Arc<OnceCell<AvatarResponse>>>>,
+    network_slots: Semaphore,
 }
 
 impl AvatarService {
@@ -24,15 +124,32 @@ impl AvatarService {
 
         Self {
             cache_dir,
-            client: reqwest::Client::new(),
+            http: Arc::new(ReqwestAvatarHttp(reqwest::Client::new())),
+            janitor: None,
+            in_flight: Mutex::new(HashMap::new()),
+            network_slots: Semaphore::new(MAX_CONCURRENT_LOOKUPS),
         }
     }
 
+    /// Download avatars through `http` instead of the network
+    #[cfg(test)]
+    fn with_http(mut self, http: Arc<dyn AvatarHttp>) -> Self {
+        self.http = http;
+        self
+    }
+
+    /// Register partial downloads with the janitor
+    pub fn with_janitor(mut self, janitor: Arc<Janitor>) -> Self {
+        self.janitor = Some(janitor);
+        self
+    }
+
     /// Get the source prefix for cache filenames
     fn source_prefix(source: &AvatarSource) -> &'static str {
         match source {
             AvatarSource::Integration => "integration",
             AvatarSource::Gravatar => "gravatar",
+            AvatarSource::Generated => "generated",
             AvatarSource::Default => "default",
         }
     }
@@ -89,41 +206,55 @@ impl AvatarService {
         url: &str,
         cache_key: &str,
     ) -> Result<String> {
-        let response = self
-            .client
-            .get(url)
-            .send()
-            .await
-            .map_err(|e| AxisError::Other(format!("Failed to fetch avatar: {e}")))?;
-
-        if !response.status().is_success() {
-            return Err(AxisError::Other(format!(
-                "Avatar fetch failed with status: {}",
-                response.status()
-            )));
-        }
-
-        let content_type = response
-            .headers()
-            .get(reqwest::header::CONTENT_TYPE)
-            .and_then(|v| v.to_str().ok())
-            .unwrap_or("image/png");
-
-        let ext = Self::extension_from_content_type(content_type);
+        self.download(source, url, cache_key)
+            .await?
+            .ok_or_else(|| AxisError::Other("Avatar fetch failed with status: 404".to_string()))
+    }
 
-        let bytes = response
-            .bytes()
-            .await
-            .map_err(|e| AxisError::Other(format!("Failed to read avatar bytes: {e}")))?;
+    /// Download an avatar into the cache; `None` when the server has no image
+    async fn download(
+        &self,
+        source: &AvatarSource,
+        url: &str,
+        cache_key: &str,
+    ) -> Result<Option<String>> {
+        let Some(image) = self.http.get(url).await? else {
+            return Ok(None);
+        };
 
+        let ext = Self::extension_from_content_type(&image.content_type);
         let prefix = Self::source_prefix(source);
         let file_name = format!("{prefix}_{cache_key}.{ext}");
         let file_path = self.cache_dir.join(&file_name);
 
-        fs::write(&file_path, &bytes)
+        self.write_cache_file(&file_name, &file_path, &image.bytes)
             .map_err(|e| AxisError::Other(format!("Failed to write avatar cache: {e}")))?;
 
-        Ok(file_path.to_string_lossy().to_string())
+        Ok(Some(file_path.to_string_lossy().to_string()))
+    }
+
+    /// Write through a hidden `.part` file so a crash never leaves a truncated image
+    /// where `get_cached` would find it
+    fn write_cache_file(&self, file_name: &str, file_path: &Path, bytes: &[u8]) -> Result<()> {
+        let partial_path = self.cache_dir.join(format!(".{file_name}.part"));
+        let mut file = fs::File::create(&partial_path)?;
+        let tracked = match &self.janitor {
+            Some(janitor) => Some(janitor.track(
+                &partial_path,
+                ArtifactOwner::AvatarDownload,
+                Some(AVATAR_DOWNLOAD_LIFETIME),
+            )?),
+            None => None,
+        };
+
+        file.write_all(bytes)?;
+        drop(file);
+        fs::rename(&partial_path, file_path)?;
+
+        if let Some(tracked) = tracked {
+            tracked.release();
+        }
+        Ok(())
     }
 
     /// Get file extension from content type
@@ -153,6 +284,235 @@ impl AvatarService {
         format!("{result:x}")
     }
 
+    /// Resolve avatars for many emails at once, in the order given. Duplicates are looked up
+    /// once; every email gets an image, falling back to a generated identicon.
+    pub async fn get_avatars(
+        self: &Arc<Self>,
+        emails: Vec<String>,
+        lookup: AvatarLookup,
+    ) -> Vec<EmailAvatar> {
+        let mut tasks = JoinSet::new();
+        let mut pending = Vec::new();
+        for email in &emails {
+            let normalized = Self::normalize_email(email);
+            if pending.contains(&normalized) {
+                continue;
+            }
+            pending.push(normalized.clone());
+            let service = Arc::clone(self);
+            let lookup = lookup.clone();
+            tasks.spawn(async move {
+                let avatar = service.resolve_shared(&normalized, &lookup).await;
+                (normalized, avatar)
+            });
+        }
+
+        let mut resolved = HashMap::new();
+        while let Some(result) = tasks.join_next().await {
+            match result {
+                Ok((email, avatar)) => {
+                    resolved.insert(email, avatar);
+                }
+                Err(e) => log::warn!("Avatar lookup task failed: {e}"),
+            }
+        }
+
+        if let Err(e) = self.evict(AVATAR_CACHE_MAX_BYTES) {
+            log::warn!("Failed to evict avatar cache: {e}");
+        }
+
+        emails
+            .into_iter()
+            .map(|email| {
+                let normalized = Self::normalize_email(&email);
+                let avatar = resolved
+                    .get(&normalized)
+                    .cloned()
+                    .unwrap_or_else(|| Self::generated(&normalized));
+                EmailAvatar { email, avatar }
+            })
+            .collect()
+    }
+
+    /// Join a resolution of the same email already in progress, or start one
+    async fn resolve_shared(&self, email: &str, lookup: &AvatarLookup) -> AvatarResponse {
+        let key = format!("{email}:{}", lookup.size);
+        let cell = Arc::clone(self.in_flight.lock().entry(key.clone()).or_default());
+        let avatar = cell
+            .get_or_init(|| self.resolve(email, lookup))
+            .await
+            .clone();
+
+        let mut in_flight = self.in_flight.lock();
+        if in_flight
+            .get(&key)
+            .is_some_and(|current| Arc::ptr_eq(current, &cell))
+        {
+            in_flight.remove(&key);
+        }
+        avatar
+    }
+
+    /// Disk cache, then the directory, then Gravatar, then a generated identicon
+    async fn resolve(&self, email: &str, lookup: &AvatarLookup) -> AvatarResponse {
+        let cache_key = format!("{}_{}", Self::md5_hash(email), lookup.size);
+        for source in [AvatarSource::Integration, AvatarSource::Gravatar] {
+            if let Some(path) = self.get_cached(&source, &cache_key) {
+                return Self::cached(source, path);
+            }
+        }
+        if self
+            .get_cached(&AvatarSource::Generated, &cache_key)
+            .is_some()
+        {
+            return Self::generated(email);
+        }
+
+        let _slot = self.network_slots.acquire().await.ok();
+        let mut failed = false;
+
+        if let Some(directory) = &lookup.directory {
+            let found = match directory.avatar_url(email).await {
+                Ok(Some(url)) => {
+                    let url = Self::sized_url(&url, lookup.size);
+                    self.download(&AvatarSource::Integration, &url, &cache_key)
+                        .await
+                }
+                Ok(None) => Ok(None),
+                Err(e) => Err(e),
+            };
+            match found {
+                Ok(Some(path)) => return Self::cached(AvatarSource::Integration, path),
+                Ok(None) => {}
+                Err(e) => {
+                    log::debug!("Integration avatar lookup failed for {email}: {e}");
+                    failed = true;
+                }
+            }
+        }
+
+        if lookup.gravatar_enabled {
+            let url = Self::gravatar_url(email, lookup.size);
+            match self
+                .download(&AvatarSource::Gravatar, &url, &cache_key)
+                .await
+            {
+                Ok(Some(path)) => return Self::cached(AvatarSource::Gravatar, path),
+                Ok(None) => {}
+                Err(e) => {
+                    log::debug!("Gravatar lookup failed for {email}: {e}");
+                    failed = true;
+                }
+            }
+        }
+
+        // Remember a definite miss so the next batch skips the network until it expires;
+        // after an error the lookup is retried instead
+        if !failed {
+            let marker = self.cache_dir.join(format!(
+                "{}_{cache_key}.{NO_AVATAR_EXTENSION}",
+                Self::source_prefix(&AvatarSource::Generated)
+            ));
+            if let Err(e) = fs::write(&marker, b"") {
+                log::warn!("Failed to record missing avatar for {email}: {e}");
+            }
+        }
+        Self::generated(email)
+    }
+
+    fn cached(source: AvatarSource, path: String) -> AvatarResponse {
+        AvatarResponse {
+            source,
+            path: Some(path),
+            data_url: None,
+        }
+    }
+
+    fn generated(email: &str) -> AvatarResponse {
+        AvatarResponse {
+            source: AvatarSource::Generated,
+            path: None,
+            data_url: Some(Self::identicon_data_url(email)),
+        }
+    }
+
+    fn normalize_email(email: &str) -> String {
+        email.trim().to_lowercase()
+    }
+
+    /// Ask the avatar host for `size` pixels; GitHub and Gravatar both read `s`
+    fn sized_url(url: &str, size: u32) -> String {
+        match url::Url::parse(url) {
+            Ok(mut parsed) => {
+                parsed.query_pairs_mut().append_pair("s", &size.to_string());
+                parsed.to_string()
+            }
+            Err(_) => url.to_string(),
+        }
+    }
+
+    /// Deterministic 5x5 mirrored identicon for `email` as an SVG data URL
+    pub fn identicon_data_url(email: &str) -> String {
+        let hash = Md5::digest(Self::normalize_email(email).as_bytes());
+        let color = format!("#{:02x}{:02x}{:02x}", hash[0], hash[1], hash[2]);
+        let bits = u32::from_le_bytes([hash[3], hash[4], hash[5], 0]);
+
+        let mut cells = String::new();
+        for row in 0..5u32 {
+            for col in 0..3u32 {
+                if bits >> (row * 3 + col) & 1 == 0 {
+                    continue;
+                }
+                for x in [col, 4 - col] {
+                    cells.push_str(&format!("<rect x='{x}' y='{row}' width='1' height='1'/>"));
+                    if col == 2 {
+                        break;
+                    }
+                }
+            }
+        }
+
+        let svg = format!(
+            "<svg xmlns='http://www.w3.org/2000/svg' viewBox='0 0 5 5' shape-rendering='crispEdges'>\
+             <rect width='5' height='5' fill='#f0f0f0'/><g fill='{color}'>{cells}</g></svg>"
+        );
+        format!(
+            "data:image/svg+xml;base64,{}",
+            base64::engine::general_purpose::STANDARD.encode(svg)
+        )
+    }
+
+    /// Drop expired avatars, then the least recently fetched ones until the cache fits in
+    /// `max_bytes`
+    pub fn evict(&self, max_bytes: u64) -> Result<()> {
+        let mut entries = Vec::new();
+        for entry in fs::read_dir(&self.cache_dir)?.flatten() {
+            let path = entry.path();
+            // Partial downloads belong to the janitor
+            if entry.file_name().to_string_lossy().starts_with('.') {
+                continue;
+            }
+            if !Self::is_cache_valid(&path) {
+                let _ = fs::remove_file(&path);
+                continue;
+            }
+            if let Ok(metadata) = entry.metadata() {
+                let modified = metadata.modified().unwrap_or(SystemTime::UNIX_EPOCH);
+                entries.push((modified, metadata.len(), path));
+            }
+        }
+
+        entries.sort_by_key(|entry| std::cmp::Reverse(entry.0));
+        let mut total = 0;
+        for (_, size, path) in entries {
+            total += size;
+            if total > max_bytes {
+                let _ = fs::remove_file(&path);
+            }
+        }
+        Ok(())
+    }
+
     /// Clear all cached avatars
     pub fn clear_cache(&self) -> Result<()> {
         if self.cache_dir.exists() {
@@ -437,4 +797,177 @@ mod tests {
         let file_path = service.cache_dir.join("nonexistent.png");
         assert!(!AvatarService::is_cache_valid(&file_path));
     }
+
+    // ==================== Batch Resolution Tests ====================
+
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    /// Serves a PNG for URLs containing any of `hosts`, 404 otherwise
+    struct MockHttp {
+        hosts: Vec<&'static str>,
+        requests: Mutex<Vec<String>>,
+        delay: Duration,
+    }
+
+    impl MockHttp {
+        fn serving(hosts: &[&'static str]) -> Self {
+            Self {
+                hosts: hosts.to_vec(),
+                requests: Mutex::new(Vec::new()),
+                delay: Duration::ZERO,
+            }
+        }
+
+        fn requests(&self) -> Vec<String> {
+            self.requests.lock().clone()
+        }
+    }
+
+    #[async_trait]
+    impl AvatarHttp for MockHttp {
+        async fn get(&self, url: &str) -> Result<Option<AvatarImage>> {
+            self.requests.lock().push(url.to_string());
+            tokio::time::sleep(self.delay).await;
+            Ok(self
+                .hosts
+                .iter()
+                .any(|host| url.contains(host))
+                .then(|| AvatarImage {
+                    bytes: b"png".to_vec(),
+                    content_type: "image/png".to_string(),
+                }))
+        }
+    }
+
+    struct MockDirectory {
+        known: &'static str,
+        lookups: AtomicUsize,
+    }
+
+    #[async_trait]
+    impl AvatarDirectory for MockDirectory {
+        async fn avatar_url(&self, email: &str) -> Result<Option<String>> {
+            self.lookups.fetch_add(1, Ordering::SeqCst);
+            Ok((email == self.known).then(|| "https://avatars.example.com/u/1?v=4".to_string()))
+        }
+    }
+
+    fn batch_service(tmp: &TempDir, http: Arc<MockHttp>) -> Arc<AvatarService> {
+        Arc::new(AvatarService::new(tmp.path()).with_http(http))
+    }
+
+    fn lookup(directory: Option<Arc<MockDirectory>>) -> AvatarLookup {
+        AvatarLookup {
+            size: 64,
+            gravatar_enabled: true,
+            directory: directory.map(|d| d as Arc<dyn AvatarDirectory>),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_get_avatars_walks_fallback_chain() {
+        let tmp = TempDir::new().expect("should create temp directory");
+        let http = Arc::new(MockHttp::serving(&[
+            "avatars.example.com",
+            "gravatar.com/avatar/",
+        ]));
+        let service = batch_service(&tmp, Arc::clone(&http));
+        let directory = Arc::new(MockDirectory {
+            known: "dev@example.com",
+            lookups: AtomicUsize::new(0),
+        });
+
+        let avatars = service
+            .get_avatars(
+                vec!["Dev@Example.com".to_string(), "fan@example.com".to_string()],
+                lookup(Some(directory)),
+            )
+            .await;
+
+        assert_eq!(avatars[0].email, "Dev@Example.com");
+        assert!(matches!(
+            avatars[0].avatar.source,
+            AvatarSource::Integration
+        ));
+        let path = avatars[0].avatar.path.clone().expect("should be cached");
+        assert!(Path::new(&path).exists());
+        assert!(matches!(avatars[1].avatar.source, AvatarSource::Gravatar));
+        assert!(http
+            .requests()
+            .contains(&"https://avatars.example.com/u/1?v=4&s=64".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_get_avatars_generates_identicon_and_remembers_miss() {
+        let tmp = TempDir::new().expect("should create temp directory");
+        let http = Arc::new(MockHttp::serving(&[]));
+        let service = batch_service(&tmp, Arc::clone(&http));
+
+        let first = service
+            .get_avatars(vec!["nobody@example.com".to_string()], lookup(None))
+            .await;
+        let avatar = &first[0].avatar;
+        assert!(matches!(avatar.source, AvatarSource::Generated));
+        assert!(avatar.path.is_none());
+        let data_url = avatar.data_url.clone().expect("should inline identicon");
+        assert!(data_url.starts_with("data:image/svg+xml;base64,"));
+        assert_eq!(
+            data_url,
+            AvatarService::identicon_data_url(" Nobody@example.com")
+        );
+        assert_eq!(http.requests().len(), 1);
+
+        let second = service
+            .get_avatars(vec!["nobody@example.com".to_string()], lookup(None))
+            .await;
+        assert!(matches!(second[0].avatar.source, AvatarSource::Generated));
+        assert_eq!(http.requests().len(), 1, "miss is served from disk");
+    }
+
+    #[tokio::test]
+    async fn test_get_avatars_shares_simultaneous_lookups() {
+        let tmp = TempDir::new().expect("should create temp directory");
+        let http = Arc::new(MockHttp {
+            delay: Duration::from_millis(50),
+            ..MockHttp::serving(&["gravatar.com"])
+        });
+        let service = batch_service(&tmp, Arc::clone(&http));
+        let directory = Arc::new(MockDirectory {
+            known: "",
+            lookups: AtomicUsize::new(0),
+        });
+
+        let emails = vec!["dev@example.com".to_string(), "DEV@example.com".to_string()];
+        let (a, b) = tokio::join!(
+            service.get_avatars(emails.clone(), lookup(Some(Arc::clone(&directory)))),
+            service.get_avatars(emails, lookup(Some(Arc::clone(&directory)))),
+        );
+
+        assert_eq!(directory.lookups.load(Ordering::SeqCst), 1);
+        assert_eq!(http.requests().len(), 1);
+        assert_eq!(a.len(), 2);
+        assert_eq!(a[0].avatar.path, b[1].avatar.path);
+        assert!(service.in_flight.lock().is_empty());
+    }
+
+    #[test]
+    fn test_evict_drops_oldest_beyond_size_limit() {
+        let tmp = TempDir::new().expect("should create temp directory");
+        let service = AvatarService::new(tmp.path());
+        let now = SystemTime::now();
+        for (name, age_secs) in [("gravatar_old.png", 300), ("gravatar_new.png", 10)] {
+            let path = service.cache_dir.join(name);
+            fs::write(&path, [0u8; 100]).expect("should write file");
+            fs::File::options()
+                .write(true)
+                .open(&path)
+                .and_then(|f| f.set_modified(now - Duration::from_secs(age_secs)))
+                .expect("should set mtime");
+        }
+
+        service.evict(150).expect("should evict");
+
+        assert!(service.cache_dir.join("gravatar_new.png").exists());
+        assert!(!service.cache_dir.join("gravatar_old.png").exists());
+    }
 }
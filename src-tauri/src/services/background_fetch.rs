@@ -1,159 +1,306 @@
-use crate::events::RemoteFetchedEvent;
-use crate::models::{FetchOptions, ListRemoteOptions, SshCredentials, SshKeyFormat};
+use crate::events::{BackgroundFetchCompletedEvent, RemoteFetchedEvent};
+use crate::models::{
+    BackgroundFetchConfig, BackgroundFetchResult, BackgroundFetchStatus, BranchFilter,
+    FetchOptions, ListRemoteOptions, RemoteFetchOutcome, SshCredentials, SshKeyFormat,
+};
+use crate::services::ops::RepoOperations;
 use crate::services::SshKeyService;
-use crate::state::{AppState, RepositoryCache};
+use crate::state::{AppState, GitServiceHandle, RepositoryCache};
+use chrono::{DateTime, Utc};
 use parking_lot::Mutex;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use std::time::Duration;
 use tauri::async_runtime::JoinHandle;
 use tauri::{AppHandle, Manager};
 use tauri_specta::Event;
 
+/// How often the scheduler checks which repositories are due
+pub const BACKGROUND_FETCH_TICK: Duration = Duration::from_secs(60);
+
+/// Longest delay backoff may push a fetch out to, unless the interval itself is longer
+const MAX_BACKOFF_MINUTES: i64 = 6 * 60;
+
+/// Failures after which the delay stops doubling
+const MAX_BACKOFF_DOUBLINGS: u32 = 6;
+
+/// Source of the current time, replaceable in tests
+pub trait Clock: Send + Sync {
+    fn now(&self) -> DateTime<Utc>;
+}
+
+/// Wall clock
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+}
+
+/// How to authenticate a remote during a background fetch
+pub enum RemoteAuth {
+    Credentials(Option<SshCredentials>),
+    /// The remote needs input nobody is around to give (e.g. an uncached passphrase)
+    Skip,
+}
+
+#[derive(Default)]
+struct RepoFetchState {
+    last_run: Option<DateTime<Utc>>,
+    last_result: Option<BackgroundFetchResult>,
+    consecutive_failures: u32,
+}
+
+/// Decides when each repository is fetched and remembers how the last fetch went.
+///
+/// A repository is due right away the first time it is seen, then `interval_minutes`
+/// after its last fetch. Each consecutive failure doubles the delay, so an offline
+/// machine is not retried every interval.
+pub struct FetchScheduler {
+    clock: Arc<dyn Clock>,
+    repos: Mutex<HashMap<PathBuf, RepoFetchState>>,
+}
+
+impl FetchScheduler {
+    pub fn new(clock: Arc<dyn Clock>) -> Self {
+        Self {
+            clock,
+            repos: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Fetch `path` if it is enabled, due, and not locked by a write operation.
+    /// Returns `None` when nothing was attempted.
+    pub async fn run_if_due<F>(
+        &self,
+        path: &Path,
+        handle: &GitServiceHandle,
+        config: &BackgroundFetchConfig,
+        auth: F,
+    ) -> Option<BackgroundFetchResult>
+    where
+        F: Fn(&str) -> RemoteAuth,
+    {
+        if !config.enabled || !self.is_due(path, config) {
+            return None;
+        }
+
+        // Never queue behind (or in front of) a user-initiated write
+        let Some(guard) = handle.try_read() else {
+            log::debug!(
+                "Background fetch: {} is busy, skipping this cycle",
+                path.display()
+            );
+            return None;
+        };
+        let result = fetch_repository(&guard, config, auth).await;
+        drop(guard);
+
+        self.record(path, &result);
+        Some(result)
+    }
+
+    pub fn is_due(&self, path: &Path, config: &BackgroundFetchConfig) -> bool {
+        let repos = self.repos.lock();
+        let next_run = repos
+            .get(path)
+            .and_then(|state| next_run(state, config.interval_minutes));
+        next_run.is_none_or(|next_run| self.clock.now() >= next_run)
+    }
+
+    pub fn record(&self, path: &Path, result: &BackgroundFetchResult) {
+        let mut repos = self.repos.lock();
+        let state = repos.entry(path.to_path_buf()).or_default();
+        state.last_run = Some(self.clock.now());
+        state.consecutive_failures = if result.is_failure() {
+            state.consecutive_failures.saturating_add(1)
+        } else {
+            0
+        };
+        state.last_result = Some(result.clone());
+    }
+
+    pub fn status(&self, path: &Path, config: &BackgroundFetchConfig) -> BackgroundFetchStatus {
+        let repos = self.repos.lock();
+        let state = repos.get(path);
+
+        BackgroundFetchStatus {
+            repo_path: path.to_string_lossy().to_string(),
+            config: config.clone(),
+            last_run: state.and_then(|state| state.last_run),
+            last_result: state.and_then(|state| state.last_result.clone()),
+            next_run: state
+                .filter(|_| config.enabled)
+                .and_then(|state| next_run(state, config.interval_minutes)),
+            consecutive_failures: state.map_or(0, |state| state.consecutive_failures),
+        }
+    }
+}
+
+fn next_run(state: &RepoFetchState, interval_minutes: u32) -> Option<DateTime<Utc>> {
+    let last_run = state.last_run?;
+    Some(last_run + retry_delay(interval_minutes, state.consecutive_failures))
+}
+
+/// Delay before the next fetch: the interval, doubled for every consecutive failure
+fn retry_delay(interval_minutes: u32, consecutive_failures: u32) -> chrono::Duration {
+    let interval = i64::from(interval_minutes.max(1));
+    let backoff = interval << consecutive_failures.min(MAX_BACKOFF_DOUBLINGS);
+    chrono::Duration::minutes(backoff.min(MAX_BACKOFF_MINUTES.max(interval)))
+}
+
+/// Fetch the configured remotes of a repository, one outcome per remote
+pub async fn fetch_repository<F>(
+    ops: &RepoOperations,
+    config: &BackgroundFetchConfig,
+    auth: F,
+) -> BackgroundFetchResult
+where
+    F: Fn(&str) -> RemoteAuth,
+{
+    let remotes = match ops.list_remotes(ListRemoteOptions::default()).await {
+        Ok(remotes) => remotes,
+        Err(e) => {
+            return BackgroundFetchResult {
+                remotes: Vec::new(),
+                error: Some(format!("Failed to list remotes: {e}")),
+            };
+        }
+    };
+    let mut names: Vec<String> = remotes.into_iter().map(|remote| remote.name).collect();
+    if !config.fetch_all_remotes {
+        names = upstream_remote(ops, &names).await.into_iter().collect();
+    }
+
+    let options = FetchOptions {
+        prune: config.prune,
+        ..FetchOptions::default()
+    };
+    let mut result = BackgroundFetchResult::default();
+
+    for remote in names {
+        let ssh_credentials = match auth(&remote) {
+            RemoteAuth::Credentials(credentials) => credentials,
+            RemoteAuth::Skip => continue,
+        };
+
+        let outcome = match ops
+            .fetch(
+                &remote,
+                &options,
+                None,
+                None::<fn(&git2::Progress<'_>) -> bool>,
+                ssh_credentials,
+            )
+            .await
+        {
+            Ok(fetched) => RemoteFetchOutcome {
+                remote,
+                updated_refs: fetched.updated_refs,
+                error: None,
+            },
+            Err(e) => {
+                log::warn!("Background fetch of remote {remote} failed: {e}");
+                RemoteFetchOutcome {
+                    remote,
+                    updated_refs: Vec::new(),
+                    error: Some(e.to_string()),
+                }
+            }
+        };
+        result.remotes.push(outcome);
+    }
+
+    result
+}
+
+/// Remote of the current branch's upstream, falling back to `origin`, then the only remote
+async fn upstream_remote(ops: &RepoOperations, remotes: &[String]) -> Option<String> {
+    let filter = BranchFilter {
+        include_local: true,
+        ..BranchFilter::default()
+    };
+    let upstream = match ops.list_branches(filter).await {
+        Ok(branches) => branches
+            .into_iter()
+            .find(|branch| branch.is_head)
+            .and_then(|branch| branch.upstream),
+        Err(e) => {
+            log::warn!("Background fetch: failed to read upstream branch: {e}");
+            None
+        }
+    };
+
+    // Remote names may contain slashes, so prefer the longest matching one
+    let from_upstream = upstream.and_then(|upstream| {
+        remotes
+            .iter()
+            .filter(|name| upstream.starts_with(&format!("{name}/")))
+            .max_by_key(|name| name.len())
+            .cloned()
+    });
+
+    from_upstream
+        .or_else(|| remotes.iter().find(|name| *name == "origin").cloned())
+        .or_else(|| (remotes.len() == 1).then(|| remotes[0].clone()))
+}
+
 /// Background service for periodically fetching all cached repositories
 pub struct BackgroundFetchService {
     interval_handle: Mutex<Option<JoinHandle<()>>>,
+    scheduler: Arc<FetchScheduler>,
 }
 
 impl BackgroundFetchService {
     pub fn new() -> Self {
         Self {
             interval_handle: Mutex::new(None),
+            scheduler: Arc::new(FetchScheduler::new(Arc::new(SystemClock))),
         }
     }
 
-    /// Start the background fetch task
-    /// `interval_minutes`: fetch interval in minutes (0 = disabled)
-    // Allow many lines: this is a self-contained async background task that processes
-    // repositories in a loop. Extracting parts would obscure the task's structure.
-    #[allow(clippy::too_many_lines)]
-    pub fn start(&self, cache: Arc<RepositoryCache>, app_handle: AppHandle, interval_minutes: u32) {
-        if interval_minutes == 0 {
-            log::info!("Background fetch disabled (interval is 0)");
-            return;
-        }
-
-        log::info!("Starting background fetch service with {interval_minutes} minute interval");
+    /// Start the background fetch task. Repositories without their own configuration
+    /// follow the global `auto_fetch_interval`, which is read on every check.
+    pub fn start(&self, cache: Arc<RepositoryCache>, app_handle: AppHandle) {
+        log::info!("Starting background fetch service");
 
+        let scheduler = Arc::clone(&self.scheduler);
         let handle = tauri::async_runtime::spawn(async move {
-            let mut interval =
-                tokio::time::interval(Duration::from_secs(u64::from(interval_minutes) * 60));
+            let mut interval = tokio::time::interval(BACKGROUND_FETCH_TICK);
 
             loop {
                 interval.tick().await;
 
-                log::debug!("Background fetch: checking cached repositories");
-
-                // Get all cached repository paths
-                let paths = cache.list_paths();
-
-                for path in paths {
-                    // Get the service handle from cache
-                    if let Some(handle) = cache.get(&path) {
-                        let app_state = app_handle.state::<AppState>();
-                        let repo_path_str = path.to_string_lossy().to_string();
-                        let default_ssh_key = app_state
-                            .get_settings()
-                            .map(|s| s.default_ssh_key)
-                            .unwrap_or(None);
-
-                        // List remotes (read lock)
-                        let remotes = match handle
-                            .read()
-                            .await
-                            .list_remotes(ListRemoteOptions::default())
-                            .await
-                        {
-                            Ok(remotes) => remotes,
-                            Err(e) => {
-                                log::warn!(
-                                    "Background fetch: failed to list remotes for {}: {e}",
-                                    path.display()
-                                );
-                                continue;
-                            }
-                        };
-
-                        let options = FetchOptions::default();
-                        let mut total_updates = 0u32;
-
-                        for remote in remotes {
-                            let ssh_key = SshKeyService::resolve_ssh_key(
-                                app_state.database(),
-                                &repo_path_str,
-                                &remote.name,
-                                default_ssh_key.as_ref(),
+                let app_state = app_handle.state::<AppState>();
+                for path in cache.list_paths() {
+                    let Some(handle) = cache.get(&path) else {
+                        continue;
+                    };
+                    let config = match app_state.background_fetch_config(&path) {
+                        Ok(config) => config,
+                        Err(e) => {
+                            log::warn!(
+                                "Background fetch: failed to load settings for {}: {e}",
+                                path.display()
                             );
-
-                            // Skip encrypted keys when no cached passphrase is available
-                            if let Some(key_path) = &ssh_key {
-                                let format = SshKeyService::check_key_format_optional(
-                                    std::path::Path::new(key_path),
-                                );
-                                if let Some(
-                                    SshKeyFormat::EncryptedPem | SshKeyFormat::EncryptedOpenSsh,
-                                ) = format
-                                {
-                                    if app_state.get_cached_ssh_passphrase(key_path).is_none() {
-                                        log::debug!(
-                                            "Background fetch: skipping remote {} (encrypted key, no cached passphrase)",
-                                            remote.name
-                                        );
-                                        continue;
-                                    }
-                                }
-                            }
-
-                            let ssh_creds = ssh_key.map(|key_path| {
-                                let passphrase = app_state.get_cached_ssh_passphrase(&key_path);
-                                SshCredentials {
-                                    key_path,
-                                    passphrase,
-                                }
-                            });
-
-                            // Fetch (write lock, per remote)
-                            match handle
-                                .write()
-                                .await
-                                .fetch(
-                                    &remote.name,
-                                    &options,
-                                    None,
-                                    None::<fn(&git2::Progress<'_>) -> bool>,
-                                    ssh_creds,
-                                )
-                                .await
-                            {
-                                Ok(result) => {
-                                    // Count updated refs as new commits
-                                    total_updates += u32::try_from(result.updated_refs.len())
-                                        .unwrap_or(u32::MAX);
-                                }
-                                Err(e) => {
-                                    log::warn!(
-                                        "Background fetch failed for {} remote {}: {e}",
-                                        path.display(),
-                                        remote.name
-                                    );
-                                }
-                            }
+                            continue;
                         }
+                    };
 
-                        if total_updates > 0 {
-                            log::info!(
-                                "Background fetch: {total_updates} updates in {}",
-                                path.display()
-                            );
+                    let repo_path = path.to_string_lossy().to_string();
+                    let Some(result) = scheduler
+                        .run_if_due(&path, &handle, &config, |remote| {
+                            remote_auth(&app_state, &repo_path, remote)
+                        })
+                        .await
+                    else {
+                        continue;
+                    };
 
-                            // Emit event for this repo
-                            let event = RemoteFetchedEvent {
-                                path: path.to_string_lossy().to_string(),
-                                new_commits: total_updates,
-                            };
-                            if let Err(e) = event.emit(&app_handle) {
-                                log::error!("Failed to emit RemoteFetchedEvent: {e}");
-                            }
-                        }
+                    if result.has_changes() {
+                        emit_fetched(&app_handle, repo_path, result);
                     }
                 }
             }
@@ -162,6 +309,7 @@ impl BackgroundFetchService {
         *self.interval_handle.lock() = Some(handle);
     }
 
+    #[cfg(test)]
     /// Stop the background fetch task
     pub fn stop(&self) {
         if let Some(handle) = self.interval_handle.lock().take() {
@@ -170,15 +318,9 @@ impl BackgroundFetchService {
         }
     }
 
-    /// Restart the background fetch task with a new interval
-    pub fn restart(
-        &self,
-        cache: Arc<RepositoryCache>,
-        app_handle: AppHandle,
-        interval_minutes: u32,
-    ) {
-        self.stop();
-        self.start(cache, app_handle, interval_minutes);
+    /// Schedule and last result of a repository
+    pub fn status(&self, path: &Path, config: &BackgroundFetchConfig) -> BackgroundFetchStatus {
+        self.scheduler.status(path, config)
     }
 
     #[cfg(test)]
@@ -193,3 +335,243 @@ impl Default for BackgroundFetchService {
         Self::new()
     }
 }
+
+/// Resolve the SSH key of a remote, skipping encrypted keys without a cached passphrase
+fn remote_auth(app_state: &AppState, repo_path: &str, remote: &str) -> RemoteAuth {
+    let default_ssh_key = app_state
+        .get_settings()
+        .map(|s| s.default_ssh_key)
+        .unwrap_or(None);
+    let ssh_key = SshKeyService::resolve_ssh_key(
+        app_state.database(),
+        repo_path,
+        remote,
+        default_ssh_key.as_ref(),
+    );
+
+    let Some(key_path) = ssh_key else {
+        return RemoteAuth::Credentials(None);
+    };
+    let passphrase = app_state.get_cached_ssh_passphrase(&key_path);
+    let format = SshKeyService::check_key_format_optional(Path::new(&key_path));
+    if let Some(SshKeyFormat::EncryptedPem | SshKeyFormat::EncryptedOpenSsh) = format {
+        if passphrase.is_none() {
+            log::debug!(
+                "Background fetch: skipping remote {remote} (encrypted key, no cached passphrase)"
+            );
+            return RemoteAuth::Skip;
+        }
+    }
+
+    RemoteAuth::Credentials(Some(SshCredentials {
+        key_path,
+        passphrase,
+    }))
+}
+
+fn emit_fetched(app_handle: &AppHandle, path: String, result: BackgroundFetchResult) {
+    let remotes: Vec<RemoteFetchOutcome> = result
+        .remotes
+        .into_iter()
+        .filter(|remote| !remote.updated_refs.is_empty())
+        .collect();
+    let total_updates: usize = remotes.iter().map(|r| r.updated_refs.len()).sum();
+    log::info!("Background fetch: {total_updates} updates in {path}");
+
+    let event = RemoteFetchedEvent {
+        path: path.clone(),
+        new_commits: u32::try_from(total_updates).unwrap_or(u32::MAX),
+    };
+    if let Err(e) = event.emit(app_handle) {
+        log::error!("Failed to emit RemoteFetchedEvent: {e}");
+    }
+
+    if let Err(e) = (BackgroundFetchCompletedEvent { path, remotes }).emit(app_handle) {
+        log::error!("Failed to emit BackgroundFetchCompletedEvent: {e}");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::GitService;
+    use std::process::Command;
+    use tempfile::TempDir;
+
+    struct ManualClock(Mutex<DateTime<Utc>>);
+
+    impl ManualClock {
+        fn advance(&self, minutes: i64) {
+            *self.0.lock() += chrono::Duration::minutes(minutes);
+        }
+    }
+
+    impl Clock for ManualClock {
+        fn now(&self) -> DateTime<Utc> {
+            *self.0.lock()
+        }
+    }
+
+    fn git(dir: &Path, args: &[&str]) {
+        let status = Command::new("git")
+            .args(args)
+            .current_dir(dir)
+            .status()
+            .expect("should run git");
+        assert!(status.success(), "git {args:?} failed");
+    }
+
+    fn commit(dir: &Path, file: &str) {
+        std::fs::write(dir.join(file), file).expect("should write");
+        git(dir, &["add", "."]);
+        git(dir, &["commit", "-m", file]);
+    }
+
+    struct Fixture {
+        tmp: TempDir,
+        handle: GitServiceHandle,
+        clock: Arc<ManualClock>,
+        scheduler: FetchScheduler,
+    }
+
+    impl Fixture {
+        fn clone_path(&self) -> PathBuf {
+            self.tmp.path().join("clone")
+        }
+
+        fn upstream(&self) -> PathBuf {
+            self.tmp.path().join("upstream")
+        }
+
+        async fn run(&self, config: &BackgroundFetchConfig) -> Option<BackgroundFetchResult> {
+            self.scheduler
+                .run_if_due(&self.clone_path(), &self.handle, config, |_| {
+                    RemoteAuth::Credentials(None)
+                })
+                .await
+        }
+    }
+
+    /// A clone of a local bare remote, plus a second working copy that pushes to it
+    fn fixture() -> Fixture {
+        let tmp = TempDir::new().expect("should create temp dir");
+        let root = tmp.path();
+        git(root, &["init", "--bare", "-b", "main", "remote.git"]);
+        git(root, &["clone", "remote.git", "upstream"]);
+        let upstream = root.join("upstream");
+        git(&upstream, &["config", "user.email", "test@test.com"]);
+        git(&upstream, &["config", "user.name", "Test User"]);
+        git(&upstream, &["symbolic-ref", "HEAD", "refs/heads/main"]);
+        commit(&upstream, "a.txt");
+        git(&upstream, &["push", "origin", "main"]);
+        git(root, &["clone", "remote.git", "clone"]);
+
+        let service = GitService::new_for_test(&root.join("clone")).expect("should open");
+        let clock = Arc::new(ManualClock(Mutex::new(Utc::now())));
+        let scheduler = FetchScheduler::new(Arc::clone(&clock) as Arc<dyn Clock>);
+        Fixture {
+            tmp,
+            handle: GitServiceHandle::new(service),
+            clock,
+            scheduler,
+        }
+    }
+
+    fn config(interval_minutes: u32) -> BackgroundFetchConfig {
+        BackgroundFetchConfig::with_default_interval(interval_minutes)
+    }
+
+    #[tokio::test]
+    async fn test_fetches_on_interval_and_reports_updated_refs() {
+        let fx = fixture();
+        let config = config(10);
+
+        let first = fx
+            .run(&config)
+            .await
+            .expect("first fetch is due immediately");
+        assert!(!first.has_changes());
+
+        commit(&fx.upstream(), "b.txt");
+        git(&fx.upstream(), &["push", "origin", "main"]);
+
+        fx.clock.advance(9);
+        assert!(fx.run(&config).await.is_none());
+
+        fx.clock.advance(1);
+        let second = fx.run(&config).await.expect("fetch is due after interval");
+        assert!(second.has_changes());
+        assert_eq!(second.remotes[0].remote, "origin");
+        assert_eq!(
+            second.remotes[0].updated_refs[0].ref_name,
+            "refs/remotes/origin/main"
+        );
+
+        let status = fx.scheduler.status(&fx.clone_path(), &config);
+        assert_eq!(status.last_run, Some(fx.clock.now()));
+        assert_eq!(
+            status.next_run,
+            Some(fx.clock.now() + chrono::Duration::minutes(10))
+        );
+        assert_eq!(status.consecutive_failures, 0);
+    }
+
+    #[tokio::test]
+    async fn test_skips_cycle_while_write_lock_is_held() {
+        let fx = fixture();
+        let config = config(10);
+
+        let guard = fx.handle.write().await;
+        assert!(fx.run(&config).await.is_none());
+        drop(guard);
+
+        let status = fx.scheduler.status(&fx.clone_path(), &config);
+        assert!(status.last_run.is_none());
+        assert!(fx.run(&config).await.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_backs_off_after_failures_and_resets_on_success() {
+        let fx = fixture();
+        let config = config(10);
+        let remote = fx.tmp.path().join("remote.git");
+        let offline = fx.tmp.path().join("offline.git");
+        std::fs::rename(&remote, &offline).expect("should take remote offline");
+
+        let result = fx.run(&config).await.expect("should attempt fetch");
+        assert!(result.is_failure());
+        assert!(result.remotes[0].error.is_some());
+
+        fx.clock.advance(10);
+        assert!(fx.run(&config).await.is_none());
+        fx.clock.advance(10);
+        assert!(fx.run(&config).await.is_some());
+        let status = fx.scheduler.status(&fx.clone_path(), &config);
+        assert_eq!(status.consecutive_failures, 2);
+        assert_eq!(
+            status.next_run,
+            Some(fx.clock.now() + chrono::Duration::minutes(40))
+        );
+
+        fx.clock.advance(39);
+        assert!(fx.run(&config).await.is_none());
+
+        std::fs::rename(&offline, &remote).expect("should bring remote back");
+        fx.clock.advance(1);
+        let result = fx.run(&config).await.expect("should retry after backoff");
+        assert!(!result.is_failure());
+        let status = fx.scheduler.status(&fx.clone_path(), &config);
+        assert_eq!(status.consecutive_failures, 0);
+    }
+
+    #[test]
+    fn test_retry_delay_is_capped() {
+        assert_eq!(retry_delay(5, 0), chrono::Duration::minutes(5));
+        assert_eq!(retry_delay(5, 3), chrono::Duration::minutes(40));
+        assert_eq!(
+            retry_delay(10, 30),
+            chrono::Duration::minutes(MAX_BACKOFF_MINUTES)
+        );
+        assert_eq!(retry_delay(600, 3), chrono::Duration::minutes(600));
+    }
+}
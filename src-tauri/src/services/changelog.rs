@@ -0,0 +1,648 @@
+use regex::{Captures, Regex};
+use std::collections::HashSet;
+use std::fmt::Write;
+use std::sync::LazyLock;
+use url::Url;
+
+use super::{co_authors_from_message, detect_provider, parse_conventional_header};
+use crate::models::{
+    Changelog, ChangelogEntry, ChangelogGroup, ChangelogOptions, ChangelogSection, Commit,
+    ProviderType, Remote,
+};
+
+/// Heading of the section holding non-conventional commits and types without a section
+pub const CHANGELOG_OTHER_HEADING: &str = "Other";
+
+const BREAKING_CHANGES_HEADING: &str = "⚠ BREAKING CHANGES";
+
+/// `#123` not preceded by a word character, so `abc#1` and `&#39;` are not issues
+static ISSUE_REF_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(^|[^\w&])#(\d+)\b").expect("Invalid regex pattern"));
+
+/// `/issues/123` in an issue URL given as a trailer value
+static ISSUE_URL_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"/issues/(\d+)\b").expect("Invalid regex pattern"));
+
+static ISSUE_FOOTER_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?im)^(?:fixes|closes|resolves)\s*:\s*(.+)$").expect("Invalid regex pattern")
+});
+
+static BREAKING_FOOTER_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^BREAKING[ -]CHANGE:\s*(.*)$").expect("Invalid regex pattern"));
+
+/// Start of any other `Token: value` or `Token #value` footer
+static FOOTER_START_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^[A-Za-z][\w-]*(?::\s| #)").expect("Invalid regex pattern"));
+
+/// Web pages of a repository on its hosting provider
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RepoWebLinks {
+    provider: ProviderType,
+    /// `https://host/owner/repo`
+    base: String,
+}
+
+impl RepoWebLinks {
+    /// Links for a remote on a known provider; `None` for other hosts
+    pub fn from_remote_url(remote_url: &str) -> Option<Self> {
+        let detected = detect_provider(remote_url)?;
+        let host = match Url::parse(remote_url) {
+            Ok(url) => url.host_str()?.to_lowercase(),
+            // scp-like `git@host:owner/repo.git`
+            Err(_) => remote_url
+                .split_once('@')?
+                .1
+                .split_once(':')?
+                .0
+                .to_lowercase(),
+        };
+        Some(Self {
+            provider: detected.provider,
+            base: format!("https://{host}/{}/{}", detected.owner, detected.repo),
+        })
+    }
+
+    /// Links for the repository's main remote: origin, else the first remote
+    pub fn for_remotes(remotes: &[Remote]) -> Option<Self> {
+        remotes
+            .iter()
+            .find(|r| r.name == "origin")
+            .or_else(|| remotes.first())
+            .and_then(|r| r.url.as_deref())
+            .and_then(Self::from_remote_url)
+    }
+
+    pub fn commit_url(&self, oid: &str) -> String {
+        match self.provider {
+            ProviderType::GitHub | ProviderType::Gitea => format!("{}/commit/{oid}", self.base),
+            ProviderType::GitLab => format!("{}/-/commit/{oid}", self.base),
+            ProviderType::Bitbucket => format!("{}/commits/{oid}", self.base),
+        }
+    }
+
+    pub fn issue_url(&self, number: u32) -> String {
+        match self.provider {
+            ProviderType::GitLab => format!("{}/-/issues/{number}", self.base),
+            _ => format!("{}/issues/{number}", self.base),
+        }
+    }
+}
+
+/// Changelog of `commits` (newest first), grouped by `sections` in their order
+pub fn build_changelog(
+    title: &str,
+    commits: &[Commit],
+    sections: &[ChangelogSection],
+    options: &ChangelogOptions,
+    links: Option<&RepoWebLinks>,
+) -> Changelog {
+    let entries: Vec<ChangelogEntry> = commits
+        .iter()
+        .filter(|commit| !(options.collapse_merges && commit.is_merge))
+        .map(|commit| changelog_entry(commit, options, links))
+        .collect();
+
+    let mut placed = vec![false; entries.len()];
+    let mut groups = Vec::new();
+    for section in sections {
+        let mut group_entries = Vec::new();
+        for (entry, placed) in entries.iter().zip(placed.iter_mut()) {
+            let matches = entry
+                .commit_type
+                .as_deref()
+                .is_some_and(|t| t.eq_ignore_ascii_case(&section.commit_type));
+            if matches && !*placed {
+                *placed = true;
+                group_entries.push(entry.clone());
+            }
+        }
+        if !group_entries.is_empty() {
+            groups.push(ChangelogGroup {
+                heading: section.heading.clone(),
+                commit_type: Some(section.commit_type.clone()),
+                entries: group_entries,
+            });
+        }
+    }
+
+    let other: Vec<ChangelogEntry> = entries
+        .iter()
+        .zip(&placed)
+        .filter(|(_, placed)| !**placed)
+        .map(|(entry, _)| entry.clone())
+        .collect();
+    if !other.is_empty() {
+        groups.push(ChangelogGroup {
+            heading: CHANGELOG_OTHER_HEADING.to_string(),
+            commit_type: None,
+            entries: other,
+        });
+    }
+
+    let breaking_changes: Vec<ChangelogEntry> =
+        entries.into_iter().filter(|entry| entry.breaking).collect();
+    let markdown = render_changelog(title, &breaking_changes, &groups, links);
+
+    Changelog {
+        title: title.to_string(),
+        breaking_changes,
+        groups,
+        markdown,
+    }
+}
+
+/// Parse `commit` into a changelog entry
+pub fn changelog_entry(
+    commit: &Commit,
+    options: &ChangelogOptions,
+    links: Option<&RepoWebLinks>,
+) -> ChangelogEntry {
+    let header = parse_conventional_header(&commit.summary);
+    let description = header
+        .as_ref()
+        .map_or_else(|| commit.summary.clone(), |h| h.description.clone());
+
+    let body = commit
+        .message
+        .replace("\r\n", "\n")
+        .split_once('\n')
+        .map(|(_, body)| body.to_string())
+        .unwrap_or_default();
+    let breaking_note = breaking_note(&body);
+
+    let mut issues: Vec<u32> = Vec::new();
+    let mut push_issue = |number: &str| {
+        if let Ok(number) = number.parse::<u32>() {
+            if !issues.contains(&number) {
+                issues.push(number);
+            }
+        }
+    };
+    for caps in ISSUE_REF_REGEX.captures_iter(&commit.summary) {
+        push_issue(&caps[2]);
+    }
+    for footer in ISSUE_FOOTER_REGEX.captures_iter(&body) {
+        for caps in ISSUE_REF_REGEX.captures_iter(&footer[1]) {
+            push_issue(&caps[2]);
+        }
+        for caps in ISSUE_URL_REGEX.captures_iter(&footer[1]) {
+            push_issue(&caps[1]);
+        }
+    }
+
+    ChangelogEntry {
+        oid: commit.oid.clone(),
+        short_oid: commit.short_oid.clone(),
+        commit_type: header.as_ref().map(|h| h.commit_type.to_lowercase()),
+        scope: header.as_ref().and_then(|h| h.scope.clone()),
+        description,
+        breaking: header.as_ref().is_some_and(|h| h.breaking) || breaking_note.is_some(),
+        breaking_note,
+        issues,
+        author: commit.author.name.clone(),
+        co_authors: if options.include_co_authors {
+            co_authors_from_message(&commit.message)
+        } else {
+            Vec::new()
+        },
+        commit_url: links.map(|links| links.commit_url(&commit.oid)),
+        is_merge: commit.is_merge,
+    }
+}
+
+/// Text of a `BREAKING CHANGE:` footer, including the lines that continue it up to a
+/// blank line or the next footer
+fn breaking_note(body: &str) -> Option<String> {
+    let mut lines = body
+        .lines()
+        .skip_while(|line| !BREAKING_FOOTER_REGEX.is_match(line));
+    let first = BREAKING_FOOTER_REGEX.captures(lines.next()?)?[1]
+        .trim()
+        .to_string();
+    let note = std::iter::once(first)
+        .chain(
+            lines
+                .take_while(|line| !line.trim().is_empty() && !FOOTER_START_REGEX.is_match(line))
+                .map(|line| line.trim().to_string()),
+        )
+        .filter(|line| !line.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ");
+    (!note.is_empty()).then_some(note)
+}
+
+/// Markdown for a changelog, starting with a level-2 `title` heading
+pub fn render_changelog(
+    title: &str,
+    breaking_changes: &[ChangelogEntry],
+    groups: &[ChangelogGroup],
+    links: Option<&RepoWebLinks>,
+) -> String {
+    let mut out = format!("## {title}\n");
+
+    if !breaking_changes.is_empty() {
+        let _ = write!(out, "\n### {BREAKING_CHANGES_HEADING}\n\n");
+        for entry in breaking_changes {
+            let text = entry.breaking_note.as_deref().unwrap_or(&entry.description);
+            out.push_str(&render_entry(entry, text, false, links));
+        }
+    }
+
+    for group in groups {
+        let _ = write!(out, "\n### {}\n\n", group.heading);
+        for entry in &group.entries {
+            let show_type = group.commit_type.is_none();
+            out.push_str(&render_entry(entry, &entry.description, show_type, links));
+        }
+    }
+
+    out
+}
+
+fn render_entry(
+    entry: &ChangelogEntry,
+    text: &str,
+    show_type: bool,
+    links: Option<&RepoWebLinks>,
+) -> String {
+    let mut line = String::from("- ");
+    let label = match (
+        show_type.then_some(entry.commit_type.as_deref()).flatten(),
+        &entry.scope,
+    ) {
+        (Some(commit_type), Some(scope)) => Some(format!("{commit_type}({scope})")),
+        (Some(commit_type), None) => Some(commit_type.to_string()),
+        (None, Some(scope)) => Some(scope.clone()),
+        (None, None) => None,
+    };
+    if let Some(label) = label {
+        let _ = write!(line, "**{label}:** ");
+    }
+
+    match links {
+        Some(links) => line.push_str(&link_issue_refs(text, links)),
+        None => line.push_str(text),
+    }
+
+    match &entry.commit_url {
+        Some(url) => {
+            let _ = write!(line, " ([{}]({url}))", entry.short_oid);
+        }
+        None => {
+            let _ = write!(line, " ({})", entry.short_oid);
+        }
+    }
+
+    let mentioned: HashSet<u32> = ISSUE_REF_REGEX
+        .captures_iter(text)
+        .filter_map(|caps| caps[2].parse().ok())
+        .collect();
+    let closes: Vec<String> = entry
+        .issues
+        .iter()
+        .filter(|number| !mentioned.contains(number))
+        .map(|&number| match links {
+            Some(links) => format!("[#{number}]({})", links.issue_url(number)),
+            None => format!("#{number}"),
+        })
+        .collect();
+    if !closes.is_empty() {
+        let _ = write!(line, ", closes {}", closes.join(", "));
+    }
+
+    if !entry.co_authors.is_empty() {
+        let names: Vec<&str> = entry.co_authors.iter().map(|co| co.name.as_str()).collect();
+        let _ = write!(line, ", co-authored by {}", names.join(", "));
+    }
+
+    line.push('\n');
+    line
+}
+
+/// `text` with each `#123` turned into a link to the issue
+fn link_issue_refs(text: &str, links: &RepoWebLinks) -> String {
+    ISSUE_REF_REGEX
+        .replace_all(text, |caps: &Captures| match caps[2].parse::<u32>() {
+            Ok(number) => format!("{}[#{number}]({})", &caps[1], links.issue_url(number)),
+            Err(_) => caps[0].to_string(),
+        })
+        .into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{CoAuthor, Signature};
+    use chrono::Utc;
+
+    /// Commit whose OID is `id` repeated
+    fn commit(id: char, message: &str) -> Commit {
+        let signature = Signature {
+            name: "Alice".to_string(),
+            email: "alice@example.com".to_string(),
+            timestamp: Utc::now(),
+        };
+        Commit {
+            oid: id.to_string().repeat(40),
+            short_oid: id.to_string().repeat(7),
+            message: message.to_string(),
+            summary: message.lines().next().unwrap_or_default().to_string(),
+            author: signature.clone(),
+            committer: signature,
+            parent_oids: Vec::new(),
+            timestamp: Utc::now(),
+            is_merge: message.starts_with("Merge "),
+            signature: None,
+        }
+    }
+
+    fn github() -> RepoWebLinks {
+        RepoWebLinks::from_remote_url("git@github.com:owner/repo.git").expect("should detect")
+    }
+
+    /// Newest first, as the history walk returns them
+    fn history() -> Vec<Commit> {
+        vec![
+            commit('a', "Merge pull request #20 from owner/feature"),
+            commit(
+                'b',
+                "feat(api)!: drop the v1 endpoints\n\nBREAKING CHANGE: clients must use /v2\nfrom now on.\nFixes: #18",
+            ),
+            commit('c', "fix(ui): align the toolbar (#17)"),
+            commit('d', "Update README"),
+            commit(
+                'e',
+                "feat: add dark mode\n\nCloses: https://github.com/owner/repo/issues/15\nCo-authored-by: Bob <bob@example.com>",
+            ),
+            commit('f', "chore(deps): bump serde"),
+        ]
+    }
+
+    // ==================== changelog_entry Tests ====================
+
+    #[test]
+    fn test_entry_parses_header_breaking_footer_and_issues() {
+        let entry = changelog_entry(&history()[1], &ChangelogOptions::default(), None);
+        assert_eq!(entry.commit_type.as_deref(), Some("feat"));
+        assert_eq!(entry.scope.as_deref(), Some("api"));
+        assert_eq!(entry.description, "drop the v1 endpoints");
+        assert!(entry.breaking);
+        assert_eq!(
+            entry.breaking_note.as_deref(),
+            Some("clients must use /v2 from now on.")
+        );
+        assert_eq!(entry.issues, [18]);
+        assert!(entry.commit_url.is_none());
+    }
+
+    #[test]
+    fn test_entry_breaking_footer_without_bang() {
+        let entry = changelog_entry(
+            &commit(
+                'a',
+                "refactor: rename config\n\nBREAKING-CHANGE: `path` is now `root`",
+            ),
+            &ChangelogOptions::default(),
+            None,
+        );
+        assert!(entry.breaking);
+        assert_eq!(entry.breaking_note.as_deref(), Some("`path` is now `root`"));
+    }
+
+    #[test]
+    fn test_entry_collects_issues_from_header_and_trailer_urls() {
+        let entries: Vec<ChangelogEntry> = history()
+            .iter()
+            .map(|c| changelog_entry(c, &ChangelogOptions::default(), None))
+            .collect();
+        assert_eq!(entries[0].issues, [20]);
+        assert_eq!(entries[2].issues, [17]);
+        assert!(entries[3].issues.is_empty());
+        assert_eq!(entries[4].issues, [15]);
+    }
+
+    #[test]
+    fn test_entry_ignores_non_issue_hashes() {
+        let entry = changelog_entry(
+            &commit('a', "fix: escape &#39; in titles and C#7 samples"),
+            &ChangelogOptions::default(),
+            None,
+        );
+        assert!(entry.issues.is_empty());
+    }
+
+    #[test]
+    fn test_entry_non_conventional_keeps_summary() {
+        let entry = changelog_entry(&history()[3], &ChangelogOptions::default(), None);
+        assert!(entry.commit_type.is_none());
+        assert_eq!(entry.description, "Update README");
+        assert!(!entry.breaking);
+    }
+
+    #[test]
+    fn test_entry_co_authors_only_when_requested() {
+        let commit = &history()[4];
+        let without = changelog_entry(commit, &ChangelogOptions::default(), None);
+        assert!(without.co_authors.is_empty());
+
+        let options = ChangelogOptions {
+            include_co_authors: true,
+            ..Default::default()
+        };
+        let with = changelog_entry(commit, &options, None);
+        assert_eq!(
+            with.co_authors,
+            [CoAuthor {
+                name: "Bob".to_string(),
+                email: "bob@example.com".to_string(),
+            }]
+        );
+    }
+
+    // ==================== build_changelog Tests ====================
+
+    #[test]
+    fn test_build_groups_in_section_order_with_other_last() {
+        let changelog = build_changelog(
+            "v2.0.0",
+            &history(),
+            &ChangelogSection::defaults(),
+            &ChangelogOptions::default(),
+            None,
+        );
+
+        let headings: Vec<&str> = changelog
+            .groups
+            .iter()
+            .map(|g| g.heading.as_str())
+            .collect();
+        assert_eq!(headings, ["Features", "Bug Fixes", "Other"]);
+        assert_eq!(changelog.groups[0].entries.len(), 2);
+
+        // Merge, non-conventional and unmapped types all end up in "Other", newest first
+        let other: Vec<&str> = changelog.groups[2]
+            .entries
+            .iter()
+            .map(|e| e.description.as_str())
+            .collect();
+        assert_eq!(
+            other,
+            [
+                "Merge pull request #20 from owner/feature",
+                "Update README",
+                "bump serde"
+            ]
+        );
+        assert_eq!(changelog.breaking_changes.len(), 1);
+        assert_eq!(changelog.breaking_changes[0].scope.as_deref(), Some("api"));
+    }
+
+    #[test]
+    fn test_build_collapse_merges() {
+        let options = ChangelogOptions {
+            collapse_merges: true,
+            ..Default::default()
+        };
+        let changelog = build_changelog(
+            "v2.0.0",
+            &history(),
+            &ChangelogSection::defaults(),
+            &options,
+            None,
+        );
+        let all: Vec<&ChangelogEntry> = changelog.groups.iter().flat_map(|g| &g.entries).collect();
+        assert_eq!(all.len(), 5);
+        assert!(all.iter().all(|e| !e.is_merge));
+    }
+
+    #[test]
+    fn test_build_custom_section_headings() {
+        let sections = vec![
+            ChangelogSection {
+                commit_type: "fix".to_string(),
+                heading: "Fixed".to_string(),
+            },
+            ChangelogSection {
+                commit_type: "chore".to_string(),
+                heading: "Maintenance".to_string(),
+            },
+        ];
+        let changelog = build_changelog(
+            "Next",
+            &history(),
+            &sections,
+            &ChangelogOptions::default(),
+            None,
+        );
+        let headings: Vec<&str> = changelog
+            .groups
+            .iter()
+            .map(|g| g.heading.as_str())
+            .collect();
+        assert_eq!(headings, ["Fixed", "Maintenance", "Other"]);
+        assert_eq!(changelog.groups[2].entries.len(), 4);
+    }
+
+    #[test]
+    fn test_render_markdown_without_links() {
+        let changelog = build_changelog(
+            "v2.0.0",
+            &history(),
+            &ChangelogSection::defaults(),
+            &ChangelogOptions::default(),
+            None,
+        );
+        assert_eq!(
+            changelog.markdown,
+            "## v2.0.0\n\
+             \n### ⚠ BREAKING CHANGES\n\n\
+             - **api:** clients must use /v2 from now on. (bbbbbbb), closes #18\n\
+             \n### Features\n\n\
+             - **api:** drop the v1 endpoints (bbbbbbb), closes #18\n\
+             - add dark mode (eeeeeee), closes #15\n\
+             \n### Bug Fixes\n\n\
+             - **ui:** align the toolbar (#17) (ccccccc)\n\
+             \n### Other\n\n\
+             - Merge pull request #20 from owner/feature (aaaaaaa)\n\
+             - Update README (ddddddd)\n\
+             - **chore(deps):** bump serde (fffffff)\n"
+        );
+    }
+
+    #[test]
+    fn test_render_markdown_with_links_and_co_authors() {
+        let links = github();
+        let options = ChangelogOptions {
+            include_commit_links: true,
+            include_co_authors: true,
+            collapse_merges: true,
+            ..Default::default()
+        };
+        let commits = history();
+        let changelog = build_changelog(
+            "v2.0.0",
+            &commits[2..=4],
+            &ChangelogSection::defaults(),
+            &options,
+            Some(&links),
+        );
+        let base = "https://github.com/owner/repo";
+        assert_eq!(
+            changelog.markdown,
+            format!(
+                "## v2.0.0\n\
+                 \n### Features\n\n\
+                 - add dark mode ([eeeeeee]({base}/commit/{e})), closes [#15]({base}/issues/15), co-authored by Bob\n\
+                 \n### Bug Fixes\n\n\
+                 - **ui:** align the toolbar ([#17]({base}/issues/17)) ([ccccccc]({base}/commit/{c}))\n\
+                 \n### Other\n\n\
+                 - Update README ([ddddddd]({base}/commit/{d}))\n",
+                e = "e".repeat(40),
+                c = "c".repeat(40),
+                d = "d".repeat(40),
+            )
+        );
+    }
+
+    // ==================== RepoWebLinks Tests ====================
+
+    #[test]
+    fn test_repo_web_links_per_provider() {
+        let github = github();
+        assert_eq!(
+            github.commit_url("abc"),
+            "https://github.com/owner/repo/commit/abc"
+        );
+        assert_eq!(
+            github.issue_url(3),
+            "https://github.com/owner/repo/issues/3"
+        );
+
+        let gitlab =
+            RepoWebLinks::from_remote_url("https://gitlab.com/group/project.git").expect("gitlab");
+        assert_eq!(
+            gitlab.commit_url("abc"),
+            "https://gitlab.com/group/project/-/commit/abc"
+        );
+        assert_eq!(
+            gitlab.issue_url(3),
+            "https://gitlab.com/group/project/-/issues/3"
+        );
+
+        let bitbucket = RepoWebLinks::from_remote_url("https://bitbucket.org/team/repo.git")
+            .expect("bitbucket");
+        assert_eq!(
+            bitbucket.commit_url("abc"),
+            "https://bitbucket.org/team/repo/commits/abc"
+        );
+
+        let gitea =
+            RepoWebLinks::from_remote_url("git@gitea.example.com:me/tool.git").expect("gitea");
+        assert_eq!(
+            gitea.commit_url("abc"),
+            "https://gitea.example.com/me/tool/commit/abc"
+        );
+
+        assert!(RepoWebLinks::from_remote_url("https://example.com/me/tool.git").is_none());
+    }
+}
@@ -7,6 +7,7 @@ use std::collections::HashMap;
 use std::hash::{Hash, Hasher};
 use std::path::Path;
 use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
 
 /// Buffer size: fetch this many extra commits beyond what's requested
 pub const PREFETCH_BUFFER: usize = 500;
@@ -14,9 +15,29 @@ pub const PREFETCH_BUFFER: usize = 500;
 /// Threshold: trigger prefetch when within this many commits of cache end
 pub const PREFETCH_THRESHOLD: usize = 100;
 
+/// Commit counts kept before the count cache is cleared
+pub const COMMIT_COUNT_CACHE_MAX_ENTRIES: usize = 256;
+
+/// History walks kept for cursor pagination before the walk cache is cleared
+pub const LOG_WALK_CACHE_MAX_ENTRIES: usize = 8;
+
 /// Cache for commit graph data with automatic prefetching
 pub struct CommitCache {
     entries: RwLock<HashMap<String, CommitCacheEntry>>,
+    /// Commit counts keyed by repo and tip commit; a count never changes for a
+    /// given tip, so these survive `invalidate_repo`
+    counts: RwLock<HashMap<String, usize>>,
+    /// History walks, keyed by repo and walk fingerprint
+    walks: RwLock<HashMap<String, Arc<LogWalk>>>,
+}
+
+/// A filtered history walk
+#[derive(Debug, Default)]
+pub struct LogWalk {
+    /// Commits that passed the filters, in walk order
+    pub commits: Vec<git2::Oid>,
+    /// Commits visited, matching or not
+    pub scanned: usize,
 }
 
 /// A cached set of commits for a specific repo and filter combination
@@ -35,6 +56,8 @@ impl CommitCache {
     pub fn new() -> Self {
         Self {
             entries: RwLock::new(HashMap::new()),
+            counts: RwLock::new(HashMap::new()),
+            walks: RwLock::new(HashMap::new()),
         }
     }
 
@@ -97,6 +120,47 @@ impl CommitCache {
         self.entries
             .write()
             .retain(|key, _| !key.starts_with(&prefix));
+        self.walks
+            .write()
+            .retain(|key, _| !key.starts_with(&prefix));
+    }
+
+    /// Cached commit count for a key from `count_key`
+    pub fn get_count(&self, count_key: &str) -> Option<usize> {
+        self.counts.read().get(count_key).copied()
+    }
+
+    /// Remember a commit count, clearing the cache once it is full
+    pub fn set_count(&self, count_key: String, count: usize) {
+        let mut counts = self.counts.write();
+        if counts.len() >= COMMIT_COUNT_CACHE_MAX_ENTRIES {
+            counts.clear();
+        }
+        counts.insert(count_key, count);
+    }
+
+    /// Build a commit count key from repo path and the commit counted from
+    pub fn count_key(repo_path: &Path, tip_oid: &str) -> String {
+        format!("{}:{tip_oid}", repo_path.display())
+    }
+
+    /// Cached history walk for a key from `walk_key`
+    pub fn get_walk(&self, walk_key: &str) -> Option<Arc<LogWalk>> {
+        self.walks.read().get(walk_key).cloned()
+    }
+
+    /// Remember a history walk, clearing the cache once it is full
+    pub fn set_walk(&self, walk_key: String, walk: Arc<LogWalk>) {
+        let mut walks = self.walks.write();
+        if walks.len() >= LOG_WALK_CACHE_MAX_ENTRIES {
+            walks.clear();
+        }
+        walks.insert(walk_key, walk);
+    }
+
+    /// Build a history walk key from repo path and walk fingerprint
+    pub fn walk_key(repo_path: &Path, fingerprint: u64) -> String {
+        format!("{}:walk:{fingerprint:016x}", repo_path.display())
     }
 
     /// Build a cache key from repo path and options
@@ -224,6 +288,13 @@ fn compute_options_hash(options: &GraphOptions) -> u64 {
         crate::models::SortOrder::AncestorOrder => "ancestor".hash(&mut hasher),
     }
     options.include_uncommitted.hash(&mut hasher);
+    options.include_stashes.hash(&mut hasher);
+    options
+        .author_filter
+        .as_deref()
+        .map(str::to_lowercase)
+        .hash(&mut hasher);
+    options.first_parent.hash(&mut hasher);
 
     hasher.finish()
 }
@@ -310,4 +381,55 @@ mod tests {
         assert!(cache.get(&key2).is_none());
         assert!(cache.get(&key3).is_some());
     }
+
+    #[test]
+    fn test_count_cache_survives_invalidate_repo() {
+        let cache = CommitCache::new();
+        let path = PathBuf::from("/test/repo");
+        let key = CommitCache::count_key(&path, "abc123");
+
+        assert_eq!(cache.get_count(&key), None);
+        cache.set_count(key.clone(), 42);
+        cache.invalidate_repo(&path);
+
+        assert_eq!(cache.get_count(&key), Some(42));
+        assert_eq!(
+            cache.get_count(&CommitCache::count_key(&path, "def456")),
+            None
+        );
+    }
+
+    #[test]
+    fn test_invalidate_repo_drops_walks() {
+        let cache = CommitCache::new();
+        let path = PathBuf::from("/test/repo");
+        let key = CommitCache::walk_key(&path, 1);
+        let other = CommitCache::walk_key(&PathBuf::from("/other/repo"), 1);
+
+        let walk = Arc::new(LogWalk {
+            commits: vec![git2::Oid::zero()],
+            scanned: 1,
+        });
+        cache.set_walk(key.clone(), Arc::clone(&walk));
+        cache.set_walk(other.clone(), walk);
+        cache.invalidate_repo(&path);
+
+        assert!(cache.get_walk(&key).is_none());
+        assert!(cache.get_walk(&other).is_some());
+    }
+
+    #[test]
+    fn test_count_cache_clears_when_full() {
+        let cache = CommitCache::new();
+        let path = PathBuf::from("/test/repo");
+
+        for i in 0..COMMIT_COUNT_CACHE_MAX_ENTRIES {
+            cache.set_count(CommitCache::count_key(&path, &i.to_string()), i);
+        }
+        let newest = CommitCache::count_key(&path, "newest");
+        cache.set_count(newest.clone(), 7);
+
+        assert_eq!(cache.get_count(&newest), Some(7));
+        assert_eq!(cache.get_count(&CommitCache::count_key(&path, "0")), None);
+    }
 }
@@ -0,0 +1,431 @@
+use crate::models::{
+    CommitLintRuleId, CommitLintRules, CommitLintViolation, ConventionalHeader, RuleLevel,
+};
+use regex::Regex;
+use std::path::Path;
+use std::sync::LazyLock;
+
+/// Repository file whose rules override the ones from settings
+pub const COMMITLINT_CONFIG_FILE: &str = ".commitlintrc.json";
+
+static HEADER_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"^([A-Za-z]+)(?:\(([^()]*)\))?(!)?: (\S.*)$").expect("Invalid regex pattern")
+});
+
+/// Parse a `type(scope)!: description` subject; `None` when it is not one
+pub fn parse_conventional_header(subject: &str) -> Option<ConventionalHeader> {
+    let caps = HEADER_REGEX.captures(subject.trim_end())?;
+    Some(ConventionalHeader {
+        commit_type: caps[1].to_string(),
+        scope: caps.get(2).map(|m| m.as_str().to_string()),
+        breaking: caps.get(3).is_some(),
+        description: caps[4].to_string(),
+    })
+}
+
+/// Check `message` against `rules`. An empty message has no violations; it is
+/// rejected on its own when committing.
+pub fn lint_commit_message(message: &str, rules: &CommitLintRules) -> Vec<CommitLintViolation> {
+    let normalized = message.replace("\r\n", "\n");
+    let lines: Vec<&str> = normalized.trim_end().lines().collect();
+    let Some(subject) = lines.first().map(|line| line.trim_end()) else {
+        return Vec::new();
+    };
+    let mut violations = Vec::new();
+    let header = parse_conventional_header(subject);
+
+    match &header {
+        None => report(
+            &mut violations,
+            rules.type_enum,
+            CommitLintRuleId::TypeEnum,
+            "Subject should start with a type, such as `feat: ...`".to_string(),
+            (1, 1, char_len(subject)),
+        ),
+        Some(header) if !rules.types.contains(&header.commit_type) => report(
+            &mut violations,
+            rules.type_enum,
+            CommitLintRuleId::TypeEnum,
+            format!(
+                "Type `{}` is not one of: {}",
+                header.commit_type,
+                rules.types.join(", ")
+            ),
+            (1, 1, char_len(&header.commit_type)),
+        ),
+        Some(_) => {}
+    }
+
+    if let (Some(scope), Some(pattern)) = (
+        header.as_ref().and_then(|h| h.scope.as_deref()),
+        rules.scope_regex.as_deref(),
+    ) {
+        match Regex::new(&format!("^(?:{pattern})$")) {
+            Ok(re) if !re.is_match(scope) => {
+                let column = header.as_ref().map_or(1, |h| char_len(&h.commit_type) + 2);
+                report(
+                    &mut violations,
+                    rules.scope_pattern,
+                    CommitLintRuleId::ScopePattern,
+                    format!("Scope `{scope}` does not match `{pattern}`"),
+                    (1, column, char_len(scope)),
+                );
+            }
+            Ok(_) => {}
+            Err(e) => log::warn!("Ignoring invalid commit scope pattern `{pattern}`: {e}"),
+        }
+    }
+
+    let subject_len = char_len(subject);
+    if subject_len > rules.max_subject_length {
+        report(
+            &mut violations,
+            rules.subject_max_length,
+            CommitLintRuleId::SubjectMaxLength,
+            format!(
+                "Subject is {subject_len} characters, longer than {}",
+                rules.max_subject_length
+            ),
+            (
+                1,
+                rules.max_subject_length + 1,
+                subject_len - rules.max_subject_length,
+            ),
+        );
+    }
+
+    if subject.ends_with('.') {
+        report(
+            &mut violations,
+            rules.subject_full_stop,
+            CommitLintRuleId::SubjectFullStop,
+            "Subject should not end with a period".to_string(),
+            (1, subject_len, 1),
+        );
+    }
+
+    if lines.get(1).is_some_and(|line| !line.trim().is_empty()) {
+        report(
+            &mut violations,
+            rules.body_leading_blank,
+            CommitLintRuleId::BodyLeadingBlank,
+            "Leave a blank line between the subject and the body".to_string(),
+            (2, 1, char_len(lines[1])),
+        );
+    }
+
+    for (index, line) in lines.iter().enumerate().skip(1) {
+        let len = char_len(line.trim_end());
+        if len > rules.max_body_line_length {
+            report(
+                &mut violations,
+                rules.body_max_line_length,
+                CommitLintRuleId::BodyMaxLineLength,
+                format!(
+                    "Line is {len} characters, longer than {}",
+                    rules.max_body_line_length
+                ),
+                (
+                    u32::try_from(index + 1).unwrap_or(u32::MAX),
+                    rules.max_body_line_length + 1,
+                    len - rules.max_body_line_length,
+                ),
+            );
+        }
+    }
+
+    violations
+}
+
+/// `base` with the rules from the repository's `.commitlintrc.json` applied;
+/// `base` unchanged when the file is missing or unreadable
+pub fn load_commit_lint_rules(repo_root: &Path, base: &CommitLintRules) -> CommitLintRules {
+    let path = repo_root.join(COMMITLINT_CONFIG_FILE);
+    let contents = match std::fs::read_to_string(&path) {
+        Ok(contents) => contents,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return base.clone(),
+        Err(e) => {
+            log::warn!("Failed to read {}: {e}", path.display());
+            return base.clone();
+        }
+    };
+    parse_commitlint_config(&contents, base).unwrap_or_else(|e| {
+        log::warn!("Ignoring malformed {}: {e}", path.display());
+        base.clone()
+    })
+}
+
+/// Apply commitlint-style rules (`"rule": [level, "always", value]`, level 0–2)
+/// to `base`. Only the level and value are read; unknown rules are ignored.
+/// `header-max-length` is accepted for `subject-max-length`.
+pub fn parse_commitlint_config(
+    contents: &str,
+    base: &CommitLintRules,
+) -> serde_json::Result<CommitLintRules> {
+    let config: serde_json::Value = serde_json::from_str(contents)?;
+    let mut rules = base.clone();
+    let Some(entries) = config.get("rules").and_then(|r| r.as_object()) else {
+        return Ok(rules);
+    };
+
+    for (name, entry) in entries {
+        let Some(level) = entry
+            .get(0)
+            .and_then(serde_json::Value::as_u64)
+            .and_then(rule_level)
+        else {
+            log::warn!("Ignoring commit lint rule `{name}` without a level of 0, 1 or 2");
+            continue;
+        };
+        let value = entry.get(2);
+        let length = value
+            .and_then(serde_json::Value::as_u64)
+            .and_then(|n| u32::try_from(n).ok());
+
+        match name.as_str() {
+            "type-enum" => {
+                rules.type_enum = level;
+                if let Some(types) = value.and_then(|v| v.as_array()) {
+                    rules.types = types
+                        .iter()
+                        .filter_map(|t| t.as_str().map(str::to_string))
+                        .collect();
+                }
+            }
+            "scope-pattern" => {
+                rules.scope_pattern = level;
+                if let Some(pattern) = value.and_then(|v| v.as_str()) {
+                    rules.scope_regex = Some(pattern.to_string());
+                }
+            }
+            "subject-max-length" | "header-max-length" => {
+                rules.subject_max_length = level;
+                if let Some(length) = length {
+                    rules.max_subject_length = length;
+                }
+            }
+            "body-max-line-length" => {
+                rules.body_max_line_length = level;
+                if let Some(length) = length {
+                    rules.max_body_line_length = length;
+                }
+            }
+            "subject-full-stop" => rules.subject_full_stop = level,
+            "body-leading-blank" => rules.body_leading_blank = level,
+            _ => {}
+        }
+    }
+    Ok(rules)
+}
+
+fn rule_level(level: u64) -> Option<RuleLevel> {
+    match level {
+        0 => Some(RuleLevel::Off),
+        1 => Some(RuleLevel::Warning),
+        2 => Some(RuleLevel::Error),
+        _ => None,
+    }
+}
+
+/// Record a violation of `rule` at (line, column, length) unless the rule is off
+fn report(
+    violations: &mut Vec<CommitLintViolation>,
+    level: RuleLevel,
+    rule: CommitLintRuleId,
+    message: String,
+    (line, column, length): (u32, u32, u32),
+) {
+    if let Some(severity) = level.severity() {
+        violations.push(CommitLintViolation {
+            rule,
+            severity,
+            message,
+            line,
+            column,
+            length,
+        });
+    }
+}
+
+fn char_len(s: &str) -> u32 {
+    u32::try_from(s.chars().count()).unwrap_or(u32::MAX)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::LintSeverity;
+
+    fn rules_of(violations: &[CommitLintViolation]) -> Vec<CommitLintRuleId> {
+        violations.iter().map(|v| v.rule).collect()
+    }
+
+    #[test]
+    fn test_parse_conventional_header() {
+        let header = parse_conventional_header("feat(ui)!: add dark mode").expect("should parse");
+        assert_eq!(header.commit_type, "feat");
+        assert_eq!(header.scope.as_deref(), Some("ui"));
+        assert!(header.breaking);
+        assert_eq!(header.description, "add dark mode");
+
+        let header = parse_conventional_header("fix: typo").expect("should parse");
+        assert_eq!(header.scope, None);
+        assert!(!header.breaking);
+
+        assert!(parse_conventional_header("Fix the parser").is_none());
+        assert!(parse_conventional_header("feat:missing space").is_none());
+        assert!(parse_conventional_header("feat(ui: unclosed").is_none());
+    }
+
+    #[test]
+    fn test_valid_message_has_no_violations() {
+        let message = "feat(api): add pagination\n\nCursor based, see #12.\n";
+        assert!(lint_commit_message(message, &CommitLintRules::default()).is_empty());
+        assert!(lint_commit_message("", &CommitLintRules::default()).is_empty());
+    }
+
+    #[test]
+    fn test_type_enum() {
+        let rules = CommitLintRules::default();
+
+        let violations = lint_commit_message("feature: add thing", &rules);
+        assert_eq!(rules_of(&violations), vec![CommitLintRuleId::TypeEnum]);
+        assert_eq!(violations[0].severity, LintSeverity::Error);
+        assert_eq!((violations[0].column, violations[0].length), (1, 7));
+
+        let violations = lint_commit_message("Add thing", &rules);
+        assert_eq!(rules_of(&violations), vec![CommitLintRuleId::TypeEnum]);
+        assert_eq!(violations[0].length, 9);
+    }
+
+    #[test]
+    fn test_scope_pattern() {
+        let rules = CommitLintRules {
+            scope_pattern: RuleLevel::Error,
+            scope_regex: Some("[a-z]+".to_string()),
+            ..CommitLintRules::default()
+        };
+
+        let violations = lint_commit_message("fix(UI-2): align buttons", &rules);
+        assert_eq!(rules_of(&violations), vec![CommitLintRuleId::ScopePattern]);
+        assert_eq!((violations[0].column, violations[0].length), (5, 4));
+
+        // The pattern must match the whole scope; no scope is fine
+        assert!(lint_commit_message("fix(ui): align buttons", &rules).is_empty());
+        assert!(lint_commit_message("fix: align buttons", &rules).is_empty());
+
+        // An invalid pattern is skipped
+        let rules = CommitLintRules {
+            scope_regex: Some("[".to_string()),
+            ..rules
+        };
+        assert!(lint_commit_message("fix(UI-2): align buttons", &rules).is_empty());
+    }
+
+    #[test]
+    fn test_subject_max_length_counts_characters() {
+        let rules = CommitLintRules {
+            max_subject_length: 12,
+            ..CommitLintRules::default()
+        };
+
+        assert!(lint_commit_message("fix: äöüäöü", &rules).is_empty());
+
+        let violations = lint_commit_message("fix: äöüäöüäöü", &rules);
+        assert_eq!(
+            rules_of(&violations),
+            vec![CommitLintRuleId::SubjectMaxLength]
+        );
+        assert_eq!((violations[0].column, violations[0].length), (13, 2));
+    }
+
+    #[test]
+    fn test_subject_full_stop() {
+        let violations = lint_commit_message("docs: update readme.", &CommitLintRules::default());
+        assert_eq!(
+            rules_of(&violations),
+            vec![CommitLintRuleId::SubjectFullStop]
+        );
+        assert_eq!(violations[0].severity, LintSeverity::Warning);
+        assert_eq!((violations[0].line, violations[0].column), (1, 20));
+    }
+
+    #[test]
+    fn test_body_leading_blank() {
+        let violations = lint_commit_message(
+            "fix: crash on start\r\nNull check was missing\r\n",
+            &CommitLintRules::default(),
+        );
+        assert_eq!(
+            rules_of(&violations),
+            vec![CommitLintRuleId::BodyLeadingBlank]
+        );
+        assert_eq!((violations[0].line, violations[0].length), (2, 22));
+    }
+
+    #[test]
+    fn test_body_max_line_length() {
+        let rules = CommitLintRules {
+            max_body_line_length: 10,
+            ..CommitLintRules::default()
+        };
+
+        let violations = lint_commit_message("fix: crash\n\nshort\nthis line is long\n", &rules);
+        assert_eq!(
+            rules_of(&violations),
+            vec![CommitLintRuleId::BodyMaxLineLength]
+        );
+        assert_eq!(
+            (
+                violations[0].line,
+                violations[0].column,
+                violations[0].length
+            ),
+            (4, 11, 7)
+        );
+    }
+
+    #[test]
+    fn test_disabled_rules_report_nothing() {
+        let rules = CommitLintRules {
+            type_enum: RuleLevel::Off,
+            scope_pattern: RuleLevel::Off,
+            scope_regex: Some("x".to_string()),
+            subject_max_length: RuleLevel::Off,
+            max_subject_length: 5,
+            body_max_line_length: RuleLevel::Off,
+            max_body_line_length: 5,
+            subject_full_stop: RuleLevel::Off,
+            body_leading_blank: RuleLevel::Off,
+            ..CommitLintRules::default()
+        };
+
+        let message = "Whatever(y): a long subject.\nno blank line and a long body\n";
+        assert!(lint_commit_message(message, &rules).is_empty());
+    }
+
+    #[test]
+    fn test_parse_commitlint_config() {
+        let contents = r#"{
+            "extends": ["@commitlint/config-conventional"],
+            "rules": {
+                "type-enum": [2, "always", ["feat", "fix"]],
+                "header-max-length": [1, "always", 50],
+                "subject-full-stop": [0, "never", "."],
+                "body-leading-blank": ["bad"],
+                "footer-leading-blank": [2, "always"]
+            }
+        }"#;
+        let base = CommitLintRules::default();
+        let rules = parse_commitlint_config(contents, &base).expect("should parse");
+
+        assert_eq!(rules.types, vec!["feat".to_string(), "fix".to_string()]);
+        assert_eq!(rules.subject_max_length, RuleLevel::Warning);
+        assert_eq!(rules.max_subject_length, 50);
+        assert_eq!(rules.subject_full_stop, RuleLevel::Off);
+        assert_eq!(rules.body_leading_blank, base.body_leading_blank);
+        assert_eq!(rules.body_max_line_length, base.body_max_line_length);
+
+        assert!(parse_commitlint_config("{ not json", &base).is_err());
+    }
+}
@@ -0,0 +1,438 @@
+use chrono::{DateTime, Datelike, Duration};
+use parking_lot::Mutex;
+use regex::Regex;
+use std::collections::{BTreeMap, HashMap};
+
+use crate::error::{AxisError, Result};
+use crate::models::{
+    CommitLintRuleId, CommitLintRules, CommitMessageOffender, CommitMessageStats,
+    CommitMessageStatsOptions, LintSeverity, RuleViolationCount, StatsBucket, StatsTrendBucket,
+    SubjectLengthBucket, SubjectLengthStats,
+};
+use crate::services::lint_commit_message;
+
+/// Commits analyzed when `CommitMessageStatsOptions::limit` is unset
+pub const COMMIT_STATS_DEFAULT_LIMIT: usize = 5_000;
+
+/// Most commits a single analysis reads, whatever the requested limit
+pub const COMMIT_STATS_MAX_LIMIT: usize = 50_000;
+
+/// Commits walked between progress reports
+pub const COMMIT_STATS_PROGRESS_INTERVAL: usize = 200;
+
+/// Offenders returned when `CommitMessageStatsOptions::max_offenders` is unset
+pub const COMMIT_STATS_DEFAULT_OFFENDERS: usize = 20;
+
+/// `#123` and Jira-style `ABC-123` references
+pub const DEFAULT_ISSUE_PATTERNS: &[&str] = &[r"#\d+\b", r"\b[A-Z][A-Z0-9]+-\d+\b"];
+
+/// Upper bounds of the subject length buckets; one more bucket holds the rest
+const SUBJECT_LENGTH_BOUNDS: &[u32] = &[50, 72, 100];
+
+/// Analyses kept before the cache starts over
+const MAX_CACHED_ANALYSES: usize = 32;
+
+/// In-memory cache of commit message statistics, keyed by the walked tips, the
+/// options and the rules, so re-opening a dashboard does not walk history again
+pub struct CommitMessageStatsCache {
+    entries: Mutex<HashMap<String, CommitMessageStats>>,
+}
+
+impl CommitMessageStatsCache {
+    pub fn new() -> Self {
+        Self {
+            entries: Mutex::new(HashMap::new()),
+        }
+    }
+
+    pub fn get(&self, key: &str) -> Option<CommitMessageStats> {
+        self.entries.lock().get(key).cloned()
+    }
+
+    pub fn insert(&self, key: String, stats: CommitMessageStats) {
+        let mut entries = self.entries.lock();
+        if entries.len() >= MAX_CACHED_ANALYSES {
+            entries.clear();
+        }
+        entries.insert(key, stats);
+    }
+}
+
+impl Default for CommitMessageStatsCache {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// How a commit is treated by the analysis
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum MessageKind {
+    Regular,
+    Merge,
+    Revert,
+    Fixup,
+}
+
+fn message_kind(subject: &str, parent_count: usize) -> MessageKind {
+    if parent_count > 1 {
+        MessageKind::Merge
+    } else if subject.starts_with("Revert \"") {
+        MessageKind::Revert
+    } else if ["fixup! ", "squash! ", "amend! "]
+        .iter()
+        .any(|prefix| subject.starts_with(prefix))
+    {
+        MessageKind::Fixup
+    } else {
+        MessageKind::Regular
+    }
+}
+
+/// Start of the `bucket` period containing `time`, both in seconds since the epoch
+pub fn stats_bucket_start(time: i64, bucket: StatsBucket) -> i64 {
+    let Some(date) = DateTime::from_timestamp(time, 0).map(|t| t.date_naive()) else {
+        return time;
+    };
+    let start = match bucket {
+        StatsBucket::Week => {
+            date - Duration::days(i64::from(date.weekday().num_days_from_monday()))
+        }
+        StatsBucket::Month => date.with_day(1).unwrap_or(date),
+    };
+    start
+        .and_hms_opt(0, 0, 0)
+        .map_or(time, |t| t.and_utc().timestamp())
+}
+
+#[derive(Default)]
+struct TrendCounts {
+    commits: usize,
+    linted: usize,
+    conforming: usize,
+}
+
+/// Accumulates commit messages, one at a time, into `CommitMessageStats`
+pub struct CommitMessageStatsBuilder {
+    rules: CommitLintRules,
+    issue_patterns: Vec<Regex>,
+    bucket: StatsBucket,
+    max_offenders: usize,
+    stats: CommitMessageStats,
+    rule_commits: HashMap<CommitLintRuleId, usize>,
+    subject_lengths: Vec<u32>,
+    trend: BTreeMap<i64, TrendCounts>,
+    offenders: Vec<CommitMessageOffender>,
+}
+
+impl CommitMessageStatsBuilder {
+    /// Fails with `InvalidSearchPattern` when an issue pattern is not a valid regex
+    pub fn new(rules: CommitLintRules, options: &CommitMessageStatsOptions) -> Result<Self> {
+        let issue_patterns = match &options.issue_patterns {
+            Some(patterns) => patterns
+                .iter()
+                .map(|p| Regex::new(p).map_err(|e| AxisError::InvalidSearchPattern(e.to_string())))
+                .collect::<Result<Vec<_>>>()?,
+            None => DEFAULT_ISSUE_PATTERNS
+                .iter()
+                .map(|p| Regex::new(p).expect("Invalid regex pattern"))
+                .collect(),
+        };
+        Ok(Self {
+            rules,
+            issue_patterns,
+            bucket: options.bucket,
+            max_offenders: options
+                .max_offenders
+                .unwrap_or(COMMIT_STATS_DEFAULT_OFFENDERS),
+            stats: CommitMessageStats::default(),
+            rule_commits: HashMap::new(),
+            subject_lengths: Vec::new(),
+            trend: BTreeMap::new(),
+            offenders: Vec::new(),
+        })
+    }
+
+    /// Add a commit made at `time` (seconds since the epoch)
+    pub fn add(&mut self, oid: &str, message: &str, time: i64, parent_count: usize) {
+        let normalized = message.replace("\r\n", "\n");
+        let mut lines = normalized.trim_end().lines();
+        let subject = lines.next().unwrap_or_default().trim_end();
+        let kind = message_kind(subject, parent_count);
+
+        self.stats.total_commits += 1;
+        self.subject_lengths
+            .push(u32::try_from(subject.chars().count()).unwrap_or(u32::MAX));
+        if lines.any(|line| !line.trim().is_empty()) {
+            self.stats.with_body += 1;
+        }
+        if self
+            .issue_patterns
+            .iter()
+            .any(|re| re.is_match(&normalized))
+        {
+            self.stats.with_issue_reference += 1;
+        }
+        match kind {
+            MessageKind::Merge => self.stats.merges += 1,
+            MessageKind::Revert => self.stats.reverts += 1,
+            MessageKind::Fixup => self.stats.fixups += 1,
+            MessageKind::Regular => {}
+        }
+
+        let trend = self
+            .trend
+            .entry(stats_bucket_start(time, self.bucket))
+            .or_default();
+        trend.commits += 1;
+        if kind != MessageKind::Regular {
+            return;
+        }
+
+        let violations = lint_commit_message(&normalized, &self.rules);
+        self.stats.linted_commits += 1;
+        trend.linted += 1;
+        if violations.is_empty() {
+            self.stats.conforming_commits += 1;
+            trend.conforming += 1;
+            return;
+        }
+
+        let mut rules: Vec<CommitLintRuleId> = violations.iter().map(|v| v.rule).collect();
+        rules.sort_unstable();
+        rules.dedup();
+        for rule in rules {
+            *self.rule_commits.entry(rule).or_default() += 1;
+        }
+        self.offenders.push(CommitMessageOffender {
+            oid: oid.to_string(),
+            summary: subject.to_string(),
+            violations,
+        });
+    }
+
+    /// The statistics so far; `truncated` when the walk stopped at its limit
+    pub fn finish(mut self, truncated: bool) -> CommitMessageStats {
+        let mut rule_violations: Vec<RuleViolationCount> = self
+            .rule_commits
+            .into_iter()
+            .map(|(rule, commits)| RuleViolationCount { rule, commits })
+            .collect();
+        rule_violations.sort_by(|a, b| b.commits.cmp(&a.commits).then(a.rule.cmp(&b.rule)));
+
+        // Stable, so offenders with equal counts keep the walk order
+        let errors = |o: &CommitMessageOffender| {
+            o.violations
+                .iter()
+                .filter(|v| v.severity == LintSeverity::Error)
+                .count()
+        };
+        self.offenders.sort_by(|a, b| {
+            errors(b)
+                .cmp(&errors(a))
+                .then(b.violations.len().cmp(&a.violations.len()))
+        });
+        self.offenders.truncate(self.max_offenders);
+
+        CommitMessageStats {
+            rule_violations,
+            subject_length: subject_length_stats(&mut self.subject_lengths),
+            trend: self
+                .trend
+                .into_iter()
+                .map(|(start, counts)| StatsTrendBucket {
+                    start,
+                    commits: counts.commits,
+                    linted_commits: counts.linted,
+                    conforming_commits: counts.conforming,
+                })
+                .collect(),
+            worst_offenders: self.offenders,
+            truncated,
+            ..self.stats
+        }
+    }
+}
+
+fn subject_length_stats(lengths: &mut [u32]) -> SubjectLengthStats {
+    let mut buckets: Vec<SubjectLengthBucket> = SUBJECT_LENGTH_BOUNDS
+        .iter()
+        .map(|&bound| Some(bound))
+        .chain(std::iter::once(None))
+        .map(|max_length| SubjectLengthBucket {
+            max_length,
+            commits: 0,
+        })
+        .collect();
+    if lengths.is_empty() {
+        return SubjectLengthStats {
+            buckets,
+            ..SubjectLengthStats::default()
+        };
+    }
+
+    for &length in lengths.iter() {
+        if let Some(bucket) = buckets
+            .iter_mut()
+            .find(|b| b.max_length.is_none_or(|max| length <= max))
+        {
+            bucket.commits += 1;
+        }
+    }
+    lengths.sort_unstable();
+    let total: u64 = lengths.iter().map(|&l| u64::from(l)).sum();
+
+    #[allow(clippy::cast_precision_loss)]
+    let average = total as f64 / lengths.len() as f64;
+    SubjectLengthStats {
+        average,
+        median: lengths[(lengths.len() - 1) / 2],
+        max: lengths[lengths.len() - 1],
+        buckets,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn builder() -> CommitMessageStatsBuilder {
+        CommitMessageStatsBuilder::new(
+            CommitLintRules::default(),
+            &CommitMessageStatsOptions::default(),
+        )
+        .expect("should create builder")
+    }
+
+    #[test]
+    fn test_stats_bucket_start() {
+        // Wednesday 2024-03-13 15:30:00 UTC
+        let time = 1_710_343_800;
+        // Monday 2024-03-11
+        assert_eq!(stats_bucket_start(time, StatsBucket::Week), 1_710_115_200);
+        // 2024-03-01
+        assert_eq!(stats_bucket_start(time, StatsBucket::Month), 1_709_251_200);
+        // A Monday at midnight starts its own week
+        assert_eq!(
+            stats_bucket_start(1_710_115_200, StatsBucket::Week),
+            1_710_115_200
+        );
+    }
+
+    #[test]
+    fn test_message_kinds_are_counted_but_not_linted() {
+        let mut b = builder();
+        b.add("a", "Merge branch 'topic'", 0, 2);
+        b.add(
+            "b",
+            "Revert \"feat: add thing\"\n\nThis reverts commit abc.",
+            0,
+            1,
+        );
+        b.add("c", "fixup! feat: add thing", 0, 1);
+        b.add("d", "feat: add thing", 0, 1);
+
+        let stats = b.finish(false);
+        assert_eq!(stats.total_commits, 4);
+        assert_eq!(stats.linted_commits, 1);
+        assert_eq!(stats.conforming_commits, 1);
+        assert_eq!((stats.merges, stats.reverts, stats.fixups), (1, 1, 1));
+        assert_eq!(stats.with_body, 1);
+        assert!(stats.worst_offenders.is_empty());
+    }
+
+    #[test]
+    fn test_issue_patterns() {
+        let mut b = builder();
+        b.add("a", "fix: crash (#12)", 0, 1);
+        b.add("b", "fix: crash\n\nRefs: AX-7", 0, 1);
+        b.add("c", "fix: crash in step 2", 0, 1);
+        assert_eq!(b.finish(false).with_issue_reference, 2);
+
+        let options = CommitMessageStatsOptions {
+            issue_patterns: Some(vec![r"gh-\d+".to_string()]),
+            ..CommitMessageStatsOptions::default()
+        };
+        let mut b = CommitMessageStatsBuilder::new(CommitLintRules::default(), &options)
+            .expect("should create builder");
+        b.add("a", "fix: crash (#12)", 0, 1);
+        b.add("b", "fix: crash, gh-3", 0, 1);
+        assert_eq!(b.finish(false).with_issue_reference, 1);
+
+        let options = CommitMessageStatsOptions {
+            issue_patterns: Some(vec!["(".to_string()]),
+            ..CommitMessageStatsOptions::default()
+        };
+        assert!(matches!(
+            CommitMessageStatsBuilder::new(CommitLintRules::default(), &options),
+            Err(AxisError::InvalidSearchPattern(_))
+        ));
+    }
+
+    #[test]
+    fn test_subject_length_stats() {
+        let mut lengths = vec![10, 60, 20, 120, 72];
+        let stats = subject_length_stats(&mut lengths);
+        assert!((stats.average - 56.4).abs() < f64::EPSILON);
+        assert_eq!(stats.median, 60);
+        assert_eq!(stats.max, 120);
+        let counts: Vec<(Option<u32>, usize)> = stats
+            .buckets
+            .iter()
+            .map(|b| (b.max_length, b.commits))
+            .collect();
+        assert_eq!(
+            counts,
+            vec![(Some(50), 2), (Some(72), 2), (Some(100), 0), (None, 1)]
+        );
+
+        let empty = subject_length_stats(&mut []);
+        assert_eq!(empty.max, 0);
+        assert_eq!(empty.buckets.len(), 4);
+    }
+
+    #[test]
+    fn test_offenders_ranked_and_capped() {
+        let options = CommitMessageStatsOptions {
+            max_offenders: Some(2),
+            ..CommitMessageStatsOptions::default()
+        };
+        let mut b = CommitMessageStatsBuilder::new(CommitLintRules::default(), &options)
+            .expect("should create builder");
+        b.add("warn", "fix: one warning.", 0, 1);
+        b.add("error", "Add thing", 0, 1);
+        b.add("both", "Add thing.", 0, 1);
+
+        let stats = b.finish(false);
+        let oids: Vec<&str> = stats
+            .worst_offenders
+            .iter()
+            .map(|o| o.oid.as_str())
+            .collect();
+        assert_eq!(oids, vec!["both", "error"]);
+        assert_eq!(
+            stats.rule_violations,
+            vec![
+                RuleViolationCount {
+                    rule: CommitLintRuleId::TypeEnum,
+                    commits: 2
+                },
+                RuleViolationCount {
+                    rule: CommitLintRuleId::SubjectFullStop,
+                    commits: 2
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_cache_starts_over_when_full() {
+        let cache = CommitMessageStatsCache::new();
+        for i in 0..MAX_CACHED_ANALYSES {
+            cache.insert(i.to_string(), CommitMessageStats::default());
+        }
+        assert!(cache.get("0").is_some());
+        cache.insert("new".to_string(), CommitMessageStats::default());
+        assert!(cache.get("0").is_none());
+        assert!(cache.get("new").is_some());
+    }
+}
@@ -0,0 +1,290 @@
+use crate::error::{AxisError, Result};
+use crate::models::{ConfigAlias, ConfigLevel, NativeCommand};
+use std::collections::HashMap;
+
+/// Git commands that only read the repository
+const READ_ONLY_COMMANDS: &[&str] = &[
+    "status",
+    "log",
+    "diff",
+    "show",
+    "blame",
+    "shortlog",
+    "grep",
+    "describe",
+    "whatchanged",
+];
+
+/// Read every `alias.*` entry of `config`, across all of its levels.
+///
+/// An alias defined at several levels is listed once per level; all but the most
+/// specific definition are marked `shadowed`.
+pub fn aliases_from_config(config: &git2::Config) -> Result<Vec<ConfigAlias>> {
+    let mut aliases = Vec::new();
+    let mut entries = config.entries(Some(r"^alias\..+"))?;
+    while let Some(entry) = entries.next() {
+        let entry = entry?;
+        let (Some(name), Some(value)) = (entry.name(), entry.value()) else {
+            log::warn!("Skipping alias with a non UTF-8 name or value");
+            continue;
+        };
+        let Some(name) = name.strip_prefix("alias.") else {
+            continue;
+        };
+        match parse_alias(name, value, entry.level().into()) {
+            Ok(alias) => aliases.push(alias),
+            Err(e) => log::warn!("Skipping alias {name}: {e}"),
+        }
+    }
+
+    // Config names are case-insensitive; within a level the last definition wins too
+    let mut effective: HashMap<String, usize> = HashMap::new();
+    for (i, alias) in aliases.iter().enumerate() {
+        let key = alias.name.to_lowercase();
+        match effective.get(&key) {
+            Some(&current) if aliases[current].level > alias.level => {}
+            _ => {
+                effective.insert(key, i);
+            }
+        }
+    }
+    for (i, alias) in aliases.iter_mut().enumerate() {
+        alias.shadowed = effective.get(&alias.name.to_lowercase()) != Some(&i);
+    }
+    aliases.sort_by(|a, b| a.name.cmp(&b.name).then(b.level.cmp(&a.level)));
+    Ok(aliases)
+}
+
+/// Git arguments that run `alias` with `extra_args` appended.
+///
+/// Shell aliases run arbitrary commands, so they are refused unless `confirmed`.
+/// Git itself runs them, so positional parameters behave as on the command line.
+pub fn alias_invocation(
+    alias: &ConfigAlias,
+    extra_args: Vec<String>,
+    confirmed: bool,
+) -> Result<Vec<String>> {
+    let mut args = if alias.is_shell {
+        if !confirmed {
+            return Err(AxisError::ShellAliasRequiresConfirmation {
+                name: alias.name.clone(),
+                command: alias.expansion.clone(),
+            });
+        }
+        vec![alias.name.clone()]
+    } else {
+        alias.args.clone()
+    };
+    args.extend(extra_args);
+    Ok(args)
+}
+
+/// Interpret one alias value the way git does
+pub fn parse_alias(name: &str, expansion: &str, level: ConfigLevel) -> Result<ConfigAlias> {
+    let trimmed = expansion.trim();
+    let (is_shell, args) = match trimmed.strip_prefix('!') {
+        Some(_) => (true, Vec::new()),
+        None => (false, split_alias_args(trimmed)?),
+    };
+    let command = args.first().map(String::as_str);
+
+    Ok(ConfigAlias {
+        name: name.to_string(),
+        expansion: expansion.to_string(),
+        level,
+        is_shell,
+        uses_positional_args: uses_positional_args(trimmed),
+        native_equivalent: command.and_then(native_equivalent),
+        refresh_after: is_shell || !command.is_some_and(|c| READ_ONLY_COMMANDS.contains(&c)),
+        args,
+        shadowed: false,
+    })
+}
+
+/// Split a non-shell alias into arguments, following git's `split_cmdline`: whitespace
+/// separates arguments, single and double quotes group them and a backslash escapes
+/// the next character outside single quotes
+pub fn split_alias_args(expansion: &str) -> Result<Vec<String>> {
+    let mut args = Vec::new();
+    let mut current = String::new();
+    let mut in_arg = false;
+    let mut quote: Option<char> = None;
+    let mut chars = expansion.chars();
+
+    while let Some(c) = chars.next() {
+        match (c, quote) {
+            ('\\', q) if q != Some('\'') => {
+                let escaped = chars.next().ok_or_else(|| {
+                    AxisError::Other("Alias ends with an unfinished escape".to_string())
+                })?;
+                current.push(escaped);
+                in_arg = true;
+            }
+            (c, Some(open)) if c == open => quote = None,
+            (c, Some(_)) => current.push(c),
+            ('\'' | '"', None) => {
+                quote = Some(c);
+                in_arg = true;
+            }
+            (c, None) if c.is_whitespace() => {
+                if in_arg {
+                    args.push(std::mem::take(&mut current));
+                    in_arg = false;
+                }
+            }
+            (c, None) => {
+                current.push(c);
+                in_arg = true;
+            }
+        }
+    }
+
+    if quote.is_some() {
+        return Err(AxisError::Other("Alias has an unclosed quote".to_string()));
+    }
+    if in_arg {
+        args.push(current);
+    }
+    Ok(args)
+}
+
+/// Whether the alias refers to shell positional parameters (`$1`, `${2}`, `$@`, `$*`)
+pub fn uses_positional_args(expansion: &str) -> bool {
+    let bytes = expansion.as_bytes();
+    bytes.windows(2).enumerate().any(|(i, pair)| {
+        pair[0] == b'$'
+            && (matches!(pair[1], b'1'..=b'9' | b'@' | b'*' | b'#')
+                || (pair[1] == b'{' && bytes.get(i + 2).is_some_and(u8::is_ascii_digit)))
+    })
+}
+
+/// Axis feature matching a git command
+pub fn native_equivalent(command: &str) -> Option<NativeCommand> {
+    match command {
+        "status" => Some(NativeCommand::Status),
+        "log" => Some(NativeCommand::Log),
+        "diff" => Some(NativeCommand::Diff),
+        "fetch" => Some(NativeCommand::Fetch),
+        "pull" => Some(NativeCommand::Pull),
+        "push" => Some(NativeCommand::Push),
+        "stash" => Some(NativeCommand::Stash),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_alias_args_handles_quotes_and_escapes() {
+        let args = split_alias_args(r#"log --format="%h %s" --author='Jane Doe' a\ b"#)
+            .expect("should split");
+        assert_eq!(
+            args,
+            vec!["log", "--format=%h %s", "--author=Jane Doe", "a b"]
+        );
+
+        assert_eq!(
+            split_alias_args(r#"commit -m """#).expect("should split"),
+            vec!["commit", "-m", ""]
+        );
+        assert_eq!(
+            split_alias_args(r"grep 'a\b'").expect("should split"),
+            vec!["grep", r"a\b"]
+        );
+        assert!(split_alias_args(r#"log --format="%h"#).is_err());
+    }
+
+    #[test]
+    fn test_parse_alias_shell_and_positional() {
+        let alias = parse_alias(
+            "fixup",
+            r#"!f() { git commit --fixup="$1"; }; f"#,
+            ConfigLevel::Global,
+        )
+        .expect("should parse");
+        assert!(alias.is_shell);
+        assert!(alias.args.is_empty());
+        assert!(alias.uses_positional_args);
+        assert!(alias.refresh_after);
+        assert!(alias.native_equivalent.is_none());
+
+        assert!(!uses_positional_args("log --format=$HOME"));
+        assert!(uses_positional_args("!echo ${1}"));
+    }
+
+    #[test]
+    fn test_parse_alias_native_equivalent() {
+        let lg =
+            parse_alias("lg", "log --graph --oneline", ConfigLevel::Local).expect("should parse");
+        assert_eq!(lg.native_equivalent, Some(NativeCommand::Log));
+        assert!(!lg.refresh_after);
+
+        let st = parse_alias("st", "status -sb", ConfigLevel::Local).expect("should parse");
+        assert_eq!(st.native_equivalent, Some(NativeCommand::Status));
+
+        let co = parse_alias("co", "checkout", ConfigLevel::Local).expect("should parse");
+        assert!(co.native_equivalent.is_none());
+        assert!(co.refresh_after);
+    }
+
+    #[test]
+    fn test_alias_invocation_gates_shell_aliases() {
+        let shell =
+            parse_alias("up", "!git pull --rebase", ConfigLevel::Global).expect("should parse");
+        let err = alias_invocation(&shell, Vec::new(), false).expect_err("should need consent");
+        assert!(matches!(
+            err,
+            AxisError::ShellAliasRequiresConfirmation { ref name, .. } if name == "up"
+        ));
+        assert_eq!(
+            alias_invocation(&shell, vec!["x".to_string()], true).expect("confirmed"),
+            vec!["up", "x"]
+        );
+
+        let lg = parse_alias("lg", "log --oneline", ConfigLevel::Global).expect("should parse");
+        assert_eq!(
+            alias_invocation(&lg, vec!["-3".to_string()], false).expect("no consent needed"),
+            vec!["log", "--oneline", "-3"]
+        );
+    }
+
+    #[test]
+    fn test_aliases_from_config_across_levels() {
+        let tmp = tempfile::TempDir::new().expect("should create temp dir");
+        let global = tmp.path().join("global");
+        let local = tmp.path().join("local");
+        std::fs::write(
+            &global,
+            "[alias]\n\tst = status\n\tlg = log --oneline\n\tup = !git pull --rebase\n",
+        )
+        .expect("should write");
+        std::fs::write(&local, "[alias]\n\tst = status -sb\n").expect("should write");
+
+        let mut config = git2::Config::new().expect("should create config");
+        config
+            .add_file(&global, git2::ConfigLevel::Global, false)
+            .expect("should add global");
+        config
+            .add_file(&local, git2::ConfigLevel::Local, false)
+            .expect("should add local");
+
+        let aliases = aliases_from_config(&config).expect("should list");
+        let summary: Vec<(&str, ConfigLevel, bool)> = aliases
+            .iter()
+            .map(|a| (a.name.as_str(), a.level, a.shadowed))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("lg", ConfigLevel::Global, false),
+                ("st", ConfigLevel::Local, false),
+                ("st", ConfigLevel::Global, true),
+                ("up", ConfigLevel::Global, false),
+            ]
+        );
+        assert_eq!(aliases[1].args, vec!["status", "-sb"]);
+        assert!(aliases[3].is_shell);
+    }
+}
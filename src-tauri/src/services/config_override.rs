@@ -0,0 +1,201 @@
+use std::path::{Path, PathBuf};
+
+use git2::{Config, ConfigLevel, Repository};
+
+use crate::error::{AxisError, Result};
+
+/// Keys a single operation may override. Credentials, hooks and anything that
+/// runs programs (`core.sshCommand`, `*.helper`, `core.hooksPath`) are never listed.
+pub const OVERRIDABLE_CONFIG_KEYS: &[&str] = &[
+    "merge.conflictStyle",
+    "http.sslVerify",
+    "user.name",
+    "user.email",
+    "diff.algorithm",
+    "diff.renames",
+    "merge.renames",
+];
+
+/// Journal of the values a scoped override replaced, relative to the git directory.
+/// It only exists while an override is applied, or after a crash left one behind.
+const OVERRIDE_JOURNAL_FILE: &str = "axis-config-override.json";
+
+/// A `key=value` pair applied to one operation without touching the user's config
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfigOverride {
+    pub key: String,
+    pub value: String,
+}
+
+impl ConfigOverride {
+    /// Fails with `ConfigOverrideNotAllowed` unless `key` is in `OVERRIDABLE_CONFIG_KEYS`.
+    /// Section and variable names are case-insensitive, as in git.
+    pub fn new(key: &str, value: &str) -> Result<Self> {
+        let allowed = OVERRIDABLE_CONFIG_KEYS
+            .iter()
+            .find(|allowed| allowed.eq_ignore_ascii_case(key))
+            .ok_or_else(|| AxisError::ConfigOverrideNotAllowed(key.to_string()))?;
+        if value.contains(['\n', '\0']) {
+            return Err(AxisError::Other(format!(
+                "Invalid value for config override {allowed}"
+            )));
+        }
+        Ok(Self {
+            key: (*allowed).to_string(),
+            value: value.to_string(),
+        })
+    }
+
+    /// `merge.conflictStyle=diff3`, so conflict markers carry the base section
+    pub fn diff3_conflict_style() -> Self {
+        Self {
+            key: "merge.conflictStyle".to_string(),
+            value: "diff3".to_string(),
+        }
+    }
+}
+
+/// `-c key=value` arguments for the git CLI, to go before the subcommand
+pub fn config_override_args(overrides: &[ConfigOverride]) -> Vec<String> {
+    overrides
+        .iter()
+        .flat_map(|o| ["-c".to_string(), format!("{}={}", o.key, o.value)])
+        .collect()
+}
+
+/// Applies overrides to the repository-local config and restores the previous
+/// values when dropped, including while unwinding from a panic.
+///
+/// libgit2 reads config through the repository's config files, so the overrides
+/// are written to `.git/config` for the lifetime of the guard. Keep the scope to a
+/// single operation. Before anything is changed the replaced values are written to
+/// a journal in the git directory; if the process dies before the guard drops, the
+/// next `apply` on that repository restores them first.
+pub struct ScopedConfigOverride {
+    config: Config,
+    journal: PathBuf,
+    previous: Vec<(String, Option<String>)>,
+}
+
+impl ScopedConfigOverride {
+    pub fn apply(repo: &Repository, overrides: &[ConfigOverride]) -> Result<Self> {
+        let journal = repo.path().join(OVERRIDE_JOURNAL_FILE);
+        let mut config = repo.config()?.open_level(ConfigLevel::Local)?;
+        restore_from_journal(&mut config, &journal)?;
+
+        let mut previous = Vec::new();
+        for o in overrides {
+            let current = config.get_string(&o.key).ok();
+            if current.as_deref() != Some(o.value.as_str()) {
+                previous.push((o.key.clone(), current));
+            }
+        }
+
+        let mut guard = Self {
+            config,
+            journal,
+            previous: Vec::new(),
+        };
+        if previous.is_empty() {
+            return Ok(guard);
+        }
+
+        std::fs::write(&guard.journal, serde_json::to_vec(&previous)?)?;
+        guard.previous = previous;
+        for o in overrides {
+            if guard.previous.iter().any(|(key, _)| key == &o.key) {
+                // On failure the guard drops here and restores what was already set
+                guard.config.set_str(&o.key, &o.value)?;
+            }
+        }
+        Ok(guard)
+    }
+}
+
+impl Drop for ScopedConfigOverride {
+    fn drop(&mut self) {
+        if self.previous.is_empty() {
+            return;
+        }
+        match restore_values(&mut self.config, &self.previous) {
+            Ok(()) => {
+                if let Err(e) = std::fs::remove_file(&self.journal) {
+                    log::warn!("Failed to remove {}: {e}", self.journal.display());
+                }
+            }
+            // Leave the journal so the next override restores the values
+            Err(e) => log::error!("Failed to restore overridden config: {e}"),
+        }
+    }
+}
+
+/// Put back the values recorded by an override that never dropped
+fn restore_from_journal(config: &mut Config, journal: &Path) -> Result<()> {
+    let contents = match std::fs::read(journal) {
+        Ok(contents) => contents,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(()),
+        Err(e) => return Err(e.into()),
+    };
+    let previous: Vec<(String, Option<String>)> = serde_json::from_slice(&contents)?;
+    log::warn!("Restoring config left overridden by an interrupted operation");
+    restore_values(config, &previous)?;
+    std::fs::remove_file(journal)?;
+    Ok(())
+}
+
+fn restore_values(config: &mut Config, previous: &[(String, Option<String>)]) -> Result<()> {
+    for (key, value) in previous {
+        match value {
+            Some(value) => config.set_str(key, value)?,
+            None => match config.remove(key) {
+                Err(e) if e.code() != git2::ErrorCode::NotFound => return Err(e.into()),
+                _ => {}
+            },
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_accepts_allowlisted_key_case_insensitively() {
+        let o = ConfigOverride::new("MERGE.CONFLICTSTYLE", "zdiff3").expect("should be allowed");
+        assert_eq!(o.key, "merge.conflictStyle");
+        assert_eq!(o.value, "zdiff3");
+    }
+
+    #[test]
+    fn test_new_rejects_keys_outside_allowlist() {
+        for key in ["core.hooksPath", "credential.helper", "core.sshCommand"] {
+            assert!(matches!(
+                ConfigOverride::new(key, "x"),
+                Err(AxisError::ConfigOverrideNotAllowed(k)) if k == key
+            ));
+        }
+    }
+
+    #[test]
+    fn test_new_rejects_multiline_value() {
+        assert!(ConfigOverride::new("user.name", "a\nb").is_err());
+    }
+
+    #[test]
+    fn test_config_override_args() {
+        let overrides = [
+            ConfigOverride::diff3_conflict_style(),
+            ConfigOverride::new("user.name", "Jane Doe").expect("should be allowed"),
+        ];
+        assert_eq!(
+            config_override_args(&overrides),
+            vec![
+                "-c",
+                "merge.conflictStyle=diff3",
+                "-c",
+                "user.name=Jane Doe"
+            ]
+        );
+    }
+}
@@ -0,0 +1,117 @@
+use url::Url;
+
+use crate::error::{AxisError, Result};
+use crate::models::HostCredential;
+
+/// Prefix of the secret keys holding HTTPS host credentials
+pub const HOST_CREDENTIAL_KEY_PREFIX: &str = "host_credential:";
+
+/// HTTPS credentials keyed by host, consulted before the git credential helper
+pub trait HostCredentialStore: Send + Sync {
+    /// The stored credential for `host`, as returned by `host_from_url`
+    fn host_credential(&self, host: &str) -> Result<Option<HostCredential>>;
+}
+
+/// Secret key under which the credential for `host` is stored
+pub fn host_credential_key(host: &str) -> String {
+    format!("{HOST_CREDENTIAL_KEY_PREFIX}{host}")
+}
+
+/// Lowercase host of an HTTP(S) remote URL, with the port when it is not the default
+pub fn host_from_url(url: &str) -> Option<String> {
+    let parsed = Url::parse(url).ok()?;
+    if !matches!(parsed.scheme(), "http" | "https") {
+        return None;
+    }
+    let host = parsed.host_str()?.to_lowercase();
+    Some(match parsed.port() {
+        Some(port) => format!("{host}:{port}"),
+        None => host,
+    })
+}
+
+/// Normalize a host entered by the user; a full URL is reduced to its host
+pub fn normalize_host(host: &str) -> Result<String> {
+    let trimmed = host.trim().trim_end_matches('/');
+    let normalized = if trimmed.contains("://") {
+        host_from_url(trimmed)
+    } else {
+        host_from_url(&format!("https://{trimmed}"))
+    };
+    normalized.ok_or_else(|| AxisError::Other(format!("Invalid host: {host}")))
+}
+
+/// In-memory `HostCredentialStore` for tests
+#[cfg(test)]
+#[derive(Default)]
+pub struct MemoryHostCredentialStore {
+    credentials: parking_lot::Mutex<std::collections::HashMap<String, HostCredential>>,
+    lookups: parking_lot::Mutex<Vec<String>>,
+}
+
+#[cfg(test)]
+impl MemoryHostCredentialStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn insert(&self, host: &str, username: &str, token: &str) {
+        self.credentials.lock().insert(
+            host.to_string(),
+            HostCredential {
+                username: username.to_string(),
+                token: token.into(),
+            },
+        );
+    }
+
+    /// Hosts looked up so far, in order
+    pub fn lookups(&self) -> Vec<String> {
+        self.lookups.lock().clone()
+    }
+}
+
+#[cfg(test)]
+impl HostCredentialStore for MemoryHostCredentialStore {
+    fn host_credential(&self, host: &str) -> Result<Option<HostCredential>> {
+        self.lookups.lock().push(host.to_string());
+        Ok(self.credentials.lock().get(host).cloned())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_host_from_url() {
+        assert_eq!(
+            host_from_url("https://GitHub.com/owner/repo.git"),
+            Some("github.com".to_string())
+        );
+        assert_eq!(
+            host_from_url("https://user@git.example.com:8443/repo.git"),
+            Some("git.example.com:8443".to_string())
+        );
+        assert_eq!(
+            host_from_url("https://gitlab.com:443/group/repo"),
+            Some("gitlab.com".to_string())
+        );
+        assert_eq!(host_from_url("ssh://git@github.com/owner/repo.git"), None);
+        assert_eq!(host_from_url("git@github.com:owner/repo.git"), None);
+    }
+
+    #[test]
+    fn test_normalize_host() {
+        assert_eq!(
+            normalize_host(" GitHub.com/ ").expect("should normalize host"),
+            "github.com"
+        );
+        assert_eq!(
+            normalize_host("https://git.example.com:8443/owner/repo")
+                .expect("should normalize URL"),
+            "git.example.com:8443"
+        );
+        assert!(normalize_host("").is_err());
+    }
+}
@@ -1,13 +1,16 @@
 use crate::error::Result;
+use crate::events::ActionOutputStream;
 use crate::models::{
     ActionContext, ActionExecutionResult, ActionStorageType, ActionVariables, CustomAction,
     RepoActionsFile,
 };
+use crate::storage::Database;
 use regex::Regex;
 use std::path::{Path, PathBuf};
-use std::process::Stdio;
+use std::process::{ExitStatus, Stdio};
 use std::sync::LazyLock;
 use std::time::Instant;
+use tokio::io::{AsyncBufReadExt, AsyncRead, BufReader};
 use tokio::process::Command;
 
 /// Directory name for per-repository Axis config
@@ -80,11 +83,15 @@ impl CustomActionsService {
         VARIABLE_REGEX.replace_all(&result, "").to_string()
     }
 
-    /// Execute a custom action
-    pub async fn execute(
+    /// Execute a custom action, reporting each line of output as it is produced
+    pub async fn execute_with_output<F>(
         action: &CustomAction,
         variables: &ActionVariables,
-    ) -> Result<ActionExecutionResult> {
+        on_output: F,
+    ) -> Result<ActionExecutionResult>
+    where
+        F: Fn(ActionOutputStream, &str),
+    {
         let command = Self::substitute_variables(&action.command, variables);
 
         let working_dir = action.working_dir.as_ref().map_or_else(
@@ -95,21 +102,17 @@ impl CustomActionsService {
         let start = Instant::now();
 
         // Execute command based on platform
-        let output = Self::run_shell_command(&command, &working_dir).await;
+        let output = Self::run_shell_command(&command, &working_dir, &on_output).await;
 
         let duration_ms = u64::try_from(start.elapsed().as_millis()).unwrap_or(u64::MAX);
 
         match output {
-            Ok(output) => {
-                let stdout = String::from_utf8_lossy(&output.stdout).to_string();
-                let stderr = String::from_utf8_lossy(&output.stderr).to_string();
-                let exit_code = output.status.code().unwrap_or(-1);
-
-                if output.status.success() {
+            Ok((status, stdout, stderr)) => {
+                if status.success() {
                     Ok(ActionExecutionResult::success(stdout, stderr, duration_ms))
                 } else {
                     Ok(ActionExecutionResult::failure(
-                        exit_code,
+                        status.code().unwrap_or(-1),
                         stdout,
                         stderr,
                         duration_ms,
@@ -120,34 +123,78 @@ impl CustomActionsService {
         }
     }
 
-    /// Run a shell command with platform-specific shell
-    async fn run_shell_command(
+    /// Run a shell command with platform-specific shell, streaming stdout and stderr
+    async fn run_shell_command<F>(
         command: &str,
         working_dir: &str,
-    ) -> std::io::Result<std::process::Output> {
+        on_output: &F,
+    ) -> std::io::Result<(ExitStatus, String, String)>
+    where
+        F: Fn(ActionOutputStream, &str),
+    {
         #[cfg(windows)]
-        {
+        let mut cmd = {
             const CREATE_NO_WINDOW: u32 = 0x08000000;
-            Command::new("cmd")
-                .args(["/C", command])
-                .current_dir(working_dir)
-                .stdout(Stdio::piped())
-                .stderr(Stdio::piped())
-                .creation_flags(CREATE_NO_WINDOW)
-                .output()
-                .await
-        }
+            let mut cmd = Command::new("cmd");
+            cmd.args(["/C", command]).creation_flags(CREATE_NO_WINDOW);
+            cmd
+        };
 
         #[cfg(not(windows))]
-        {
-            Command::new("sh")
-                .args(["-c", command])
-                .current_dir(working_dir)
-                .stdout(Stdio::piped())
-                .stderr(Stdio::piped())
-                .output()
-                .await
+        let mut cmd = {
+            let mut cmd = Command::new("sh");
+            cmd.args(["-c", command]);
+            cmd
+        };
+
+        let mut child = cmd
+            .current_dir(working_dir)
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()?;
+
+        let (stdout, stderr) = tokio::join!(
+            Self::read_output(child.stdout.take(), ActionOutputStream::Stdout, on_output),
+            Self::read_output(child.stderr.take(), ActionOutputStream::Stderr, on_output),
+        );
+        let status = child.wait().await?;
+
+        Ok((status, stdout, stderr))
+    }
+
+    /// Read a child output pipe to the end, reporting each line
+    pub(crate) async fn read_output<R, F>(
+        pipe: Option<R>,
+        stream: ActionOutputStream,
+        on_output: &F,
+    ) -> String
+    where
+        R: AsyncRead + Unpin,
+        F: Fn(ActionOutputStream, &str),
+    {
+        let Some(pipe) = pipe else {
+            return String::new();
+        };
+
+        let mut reader = BufReader::new(pipe);
+        let mut output = String::new();
+        let mut buf = Vec::new();
+        loop {
+            buf.clear();
+            match reader.read_until(b'\n', &mut buf).await {
+                Ok(0) => break,
+                Ok(_) => {
+                    let line = String::from_utf8_lossy(&buf);
+                    on_output(stream, line.trim_end_matches(['\r', '\n']));
+                    output.push_str(&line);
+                }
+                Err(e) => {
+                    log::warn!("Failed to read custom action {stream}: {e}");
+                    break;
+                }
+            }
         }
+        output
     }
 
     // ==================== Global Actions ====================
@@ -157,7 +204,7 @@ impl CustomActionsService {
         app_data_dir.join(ACTIONS_FILE)
     }
 
-    /// Read global actions from app data directory
+    /// Read global actions from the legacy app data file
     pub fn read_global_actions(app_data_dir: &Path) -> Result<Vec<CustomAction>> {
         let actions_path = Self::get_global_actions_file_path(app_data_dir);
 
@@ -181,37 +228,21 @@ impl CustomActionsService {
         Ok(actions)
     }
 
-    /// Write global actions to app data directory
-    pub fn write_global_actions(app_data_dir: &Path, actions: &[CustomAction]) -> Result<()> {
+    /// Move actions from the legacy global actions.json into the database.
+    /// The file is renamed afterwards so the import runs only once.
+    pub fn migrate_global_actions_file(app_data_dir: &Path, database: &Database) -> Result<usize> {
         let actions_path = Self::get_global_actions_file_path(app_data_dir);
-
-        let file = RepoActionsFile::v1(actions.to_vec());
-
-        let content = serde_json::to_string_pretty(&file)?;
-        std::fs::write(&actions_path, content)?;
-
-        Ok(())
-    }
-
-    /// Save a single global action
-    pub fn save_global_action(app_data_dir: &Path, action: CustomAction) -> Result<()> {
-        let mut actions = Self::read_global_actions(app_data_dir)?;
-
-        // Find and update or insert
-        if let Some(existing) = actions.iter_mut().find(|a| a.id == action.id) {
-            *existing = action;
-        } else {
-            actions.push(action);
+        if !actions_path.exists() {
+            return Ok(0);
         }
 
-        Self::write_global_actions(app_data_dir, &actions)
-    }
+        let actions = Self::read_global_actions(app_data_dir)?;
+        for action in &actions {
+            database.save_custom_action(action)?;
+        }
 
-    /// Delete a global action
-    pub fn delete_global_action(app_data_dir: &Path, action_id: &str) -> Result<()> {
-        let mut actions = Self::read_global_actions(app_data_dir)?;
-        actions.retain(|a| a.id != action_id);
-        Self::write_global_actions(app_data_dir, &actions)
+        std::fs::rename(&actions_path, actions_path.with_extension("json.migrated"))?;
+        Ok(actions.len())
     }
 
     // ==================== Repository Actions ====================
@@ -375,6 +406,44 @@ mod tests {
         assert_eq!(result, "echo /path/to/repo  ");
     }
 
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_execute_with_output_streams_lines() {
+        let tmp = tempfile::TempDir::new().expect("should create temp dir");
+        let action = CustomAction::new(
+            "Lines".to_string(),
+            "echo one; echo two >&2; echo three; exit 3".to_string(),
+            vec![ActionContext::Repository],
+        );
+        let vars = ActionVariables {
+            repo_path: tmp.path().to_string_lossy().to_string(),
+            ..Default::default()
+        };
+
+        let lines = std::sync::Mutex::new(Vec::new());
+        let result = CustomActionsService::execute_with_output(&action, &vars, |stream, line| {
+            lines
+                .lock()
+                .expect("should lock")
+                .push((stream, line.to_string()));
+        })
+        .await
+        .expect("should execute");
+
+        assert_eq!(result.exit_code, 3);
+        assert_eq!(result.stdout, "one\nthree\n");
+        assert_eq!(result.stderr, "two\n");
+
+        let lines = lines.into_inner().expect("should unlock");
+        let stdout_lines: Vec<_> = lines
+            .iter()
+            .filter(|(s, _)| *s == ActionOutputStream::Stdout)
+            .map(|(_, l)| l.as_str())
+            .collect();
+        assert_eq!(stdout_lines, vec!["one", "three"]);
+        assert!(lines.contains(&(ActionOutputStream::Stderr, "two".to_string())));
+    }
+
     #[test]
     fn test_filter_by_context() {
         let actions = vec![
@@ -405,4 +474,34 @@ mod tests {
         let tag_actions = CustomActionsService::filter_by_context(&actions, ActionContext::Tag);
         assert_eq!(tag_actions.len(), 0);
     }
+
+    #[test]
+    fn test_migrate_global_actions_file() {
+        let tmp = tempfile::TempDir::new().expect("should create temp dir");
+        let db = Database::open_in_memory().expect("should open db");
+        let action = CustomAction::new(
+            "Lint".to_string(),
+            "npm run lint".to_string(),
+            vec![ActionContext::Repository],
+        );
+        let file = RepoActionsFile::v1(vec![action.clone()]);
+        std::fs::write(
+            CustomActionsService::get_global_actions_file_path(tmp.path()),
+            serde_json::to_string(&file).expect("should serialize"),
+        )
+        .expect("should write legacy file");
+
+        let migrated = CustomActionsService::migrate_global_actions_file(tmp.path(), &db)
+            .expect("should migrate");
+        assert_eq!(migrated, 1);
+        assert_eq!(
+            db.list_custom_actions().expect("should list")[0].id,
+            action.id
+        );
+
+        // The legacy file is moved aside, so a second run imports nothing
+        let again = CustomActionsService::migrate_global_actions_file(tmp.path(), &db)
+            .expect("should be a no-op");
+        assert_eq!(again, 0);
+    }
 }
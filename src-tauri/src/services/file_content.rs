@@ -0,0 +1,187 @@
+use base64::Engine;
+use std::path::Path;
+
+use crate::error::{AxisError, Result};
+use crate::models::{
+    BinaryDiffInfo, DiffOptions, DiffStatus, FileContent, FileDiff, DEFAULT_IMAGE_EXTENSIONS,
+};
+
+/// Largest file `get_file_content_base64` returns, in bytes
+pub const FILE_CONTENT_MAX_BYTES: u64 = 20 * 1024 * 1024;
+
+/// Magic numbers of the formats the viewer can render, checked before the extension
+const MAGIC_MIME_TYPES: &[(&[u8], &str)] = &[
+    (b"\x89PNG\r\n\x1a\n", "image/png"),
+    (b"\xff\xd8\xff", "image/jpeg"),
+    (b"GIF87a", "image/gif"),
+    (b"GIF89a", "image/gif"),
+    (b"BM", "image/bmp"),
+    (b"\x00\x00\x01\x00", "image/x-icon"),
+    (b"II*\x00", "image/tiff"),
+    (b"MM\x00*", "image/tiff"),
+    (b"%PDF-", "application/pdf"),
+];
+
+/// Fail with `FileTooLarge` when `size` is over `FILE_CONTENT_MAX_BYTES`
+pub fn check_content_size(path: &str, size: u64) -> Result<()> {
+    if size > FILE_CONTENT_MAX_BYTES {
+        return Err(AxisError::FileTooLarge {
+            path: path.to_string(),
+            size,
+            limit: FILE_CONTENT_MAX_BYTES,
+        });
+    }
+    Ok(())
+}
+
+/// Base64-encode `content` and detect its mime type
+pub fn encode_file_content(path: &str, content: &[u8]) -> FileContent {
+    FileContent {
+        content_base64: base64::engine::general_purpose::STANDARD.encode(content),
+        mime_type: detect_mime_type(path, content).to_string(),
+        size: content.len() as u64,
+    }
+}
+
+/// Mime type from the content's magic number, then from the extension. Unknown
+/// content is `text/plain` when it is valid UTF-8.
+pub fn detect_mime_type(path: &str, content: &[u8]) -> &'static str {
+    if let Some((_, mime)) = MAGIC_MIME_TYPES
+        .iter()
+        .find(|(magic, _)| content.starts_with(magic))
+    {
+        return mime;
+    }
+    if content.len() >= 12 && &content[..4] == b"RIFF" && &content[8..12] == b"WEBP" {
+        return "image/webp";
+    }
+    if content.len() >= 12
+        && &content[4..8] == b"ftyp"
+        && matches!(&content[8..12], b"avif" | b"avis")
+    {
+        return "image/avif";
+    }
+
+    match extension(path).as_deref() {
+        Some("svg") => "image/svg+xml",
+        Some("png") => "image/png",
+        Some("jpg" | "jpeg") => "image/jpeg",
+        Some("gif") => "image/gif",
+        Some("webp") => "image/webp",
+        Some("avif") => "image/avif",
+        Some("bmp") => "image/bmp",
+        Some("ico") => "image/x-icon",
+        Some("tif" | "tiff") => "image/tiff",
+        Some("pdf") => "application/pdf",
+        _ if std::str::from_utf8(content).is_ok() => "text/plain",
+        _ => "application/octet-stream",
+    }
+}
+
+/// Whether `path` has one of `extensions`, or of `DEFAULT_IMAGE_EXTENSIONS` when unset
+pub fn is_image_path(path: &str, extensions: Option<&[String]>) -> bool {
+    let Some(ext) = extension(path) else {
+        return false;
+    };
+    match extensions {
+        Some(extensions) => extensions
+            .iter()
+            .any(|e| e.trim_start_matches('.').eq_ignore_ascii_case(&ext)),
+        None => DEFAULT_IMAGE_EXTENSIONS.contains(&ext.as_str()),
+    }
+}
+
+fn extension(path: &str) -> Option<String> {
+    Path::new(path)
+        .extension()
+        .and_then(|e| e.to_str())
+        .map(str::to_ascii_lowercase)
+}
+
+/// Fill `binary_info` for the binary files in `files`. Sizes come from the object
+/// database, or from the working tree for a new side that was never written as a blob.
+pub fn annotate_binary_files(
+    repo: &git2::Repository,
+    files: &mut [FileDiff],
+    options: &DiffOptions,
+) {
+    let odb = match repo.odb() {
+        Ok(odb) => odb,
+        Err(e) => {
+            log::warn!("Cannot open object database for binary sizes: {e}");
+            return;
+        }
+    };
+    let blob_size = |oid: Option<&str>| {
+        let oid = git2::Oid::from_str(oid?).ok()?;
+        odb.read_header(oid).ok().map(|(size, _)| size as u64)
+    };
+
+    for file in files.iter_mut().filter(|f| f.binary) {
+        let old_size = blob_size(file.old_oid.as_deref());
+        let new_size = match file.status {
+            DiffStatus::Deleted => None,
+            _ => blob_size(file.new_oid.as_deref()).or_else(|| {
+                let path = file.new_path.as_deref()?;
+                let metadata = std::fs::metadata(repo.workdir()?.join(path)).ok()?;
+                Some(metadata.len())
+            }),
+        };
+        let is_image = [file.new_path.as_deref(), file.old_path.as_deref()]
+            .into_iter()
+            .flatten()
+            .next()
+            .is_some_and(|path| is_image_path(path, options.image_extensions.as_deref()));
+        let same_content = file.old_oid.is_some() && file.old_oid == file.new_oid;
+
+        file.binary_info = Some(BinaryDiffInfo {
+            old_size,
+            new_size,
+            is_image,
+            same_content,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PNG_HEADER: &[u8] = b"\x89PNG\r\n\x1a\n\x00\x00\x00\rIHDR";
+
+    #[test]
+    fn test_detect_mime_type_prefers_magic_number() {
+        assert_eq!(detect_mime_type("logo.jpg", PNG_HEADER), "image/png");
+        assert_eq!(
+            detect_mime_type("a.bin", b"RIFF\x00\x00\x00\x00WEBPVP8 "),
+            "image/webp"
+        );
+        assert_eq!(detect_mime_type("icon.svg", b"<svg/>"), "image/svg+xml");
+        assert_eq!(detect_mime_type("notes.md", b"# Notes"), "text/plain");
+        assert_eq!(
+            detect_mime_type("data", b"\x00\xff\xfe"),
+            "application/octet-stream"
+        );
+    }
+
+    #[test]
+    fn test_is_image_path_uses_configured_extensions() {
+        assert!(is_image_path("assets/Logo.PNG", None));
+        assert!(!is_image_path("assets/model.psd", None));
+        assert!(!is_image_path("Makefile", None));
+
+        let custom = vec![".psd".to_string()];
+        assert!(is_image_path("assets/model.psd", Some(&custom)));
+        assert!(!is_image_path("assets/logo.png", Some(&custom)));
+    }
+
+    #[test]
+    fn test_check_content_size() {
+        assert!(check_content_size("small.png", FILE_CONTENT_MAX_BYTES).is_ok());
+        let err = check_content_size("big.png", FILE_CONTENT_MAX_BYTES + 1)
+            .expect_err("should reject oversized file");
+        assert!(
+            matches!(err, AxisError::FileTooLarge { size, .. } if size == FILE_CONTENT_MAX_BYTES + 1)
+        );
+    }
+}
@@ -1,19 +1,33 @@
 use crate::events::{
-    FilesChangedEvent, HeadChangedEvent, IndexChangedEvent, RefChangedEvent, RepositoryDirtyEvent,
-    WatchErrorEvent,
+    FilesChangedEvent, HeadChangedEvent, IndexChangedEvent, RefChangedEvent,
+    RepositoryChangedEvent, RepositoryDirtyEvent, WatchErrorEvent,
 };
 use crate::state::AppState;
+use git2::Repository as Git2Repository;
+use notify::event::{CreateKind, EventKind};
 use notify::{Config, Event, RecommendedWatcher, RecursiveMode, Watcher};
 use parking_lot::Mutex;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::mpsc::{channel, Receiver};
 use std::sync::Arc;
 use std::thread;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 use tauri::{AppHandle, Manager};
 use tauri_specta::Event as _;
 
+/// Default quiet period before a batch of changes is reported
+pub const DEFAULT_WATCHER_DEBOUNCE: Duration = Duration::from_millis(300);
+
+/// Worktree paths listed per batch before `worktree_overflow` is set
+pub const MAX_WATCHER_WORKTREE_PATHS: usize = 500;
+
+/// A continuous stream of changes is still reported after this many debounce windows
+const MAX_WAIT_WINDOWS: u32 = 5;
+
+/// How long the event loop sleeps when nothing is pending
+const IDLE_POLL: Duration = Duration::from_secs(1);
+
 /// Per-repository file watcher that emits events based on active status.
 /// Active repos get detailed events; inactive repos get a single `RepositoryDirtyEvent`.
 pub struct FileWatcher {
@@ -24,7 +38,7 @@ pub struct FileWatcher {
 
 impl FileWatcher {
     /// Create a dummy file watcher for testing (no actual watching)
-    #[cfg(feature = "integration")]
+    #[cfg(any(test, feature = "integration"))]
     pub fn dummy() -> Self {
         Self {
             is_active: Arc::new(AtomicBool::new(false)),
@@ -37,20 +51,29 @@ impl FileWatcher {
     pub fn new(repo_path: PathBuf, app_handle: AppHandle, is_active: bool) -> notify::Result<Self> {
         let is_active_flag = Arc::new(AtomicBool::new(is_active));
 
-        let (tx, rx) = channel::<notify::Result<Event>>();
+        let debounce = app_handle
+            .try_state::<AppState>()
+            .and_then(|state| state.performance_settings(&repo_path).ok())
+            .map_or(DEFAULT_WATCHER_DEBOUNCE, |settings| {
+                Duration::from_millis(u64::from(settings.file_watcher_debounce_ms))
+            });
+        let coalescer = ChangeCoalescer::new(&repo_path, debounce);
 
-        // Create watcher with debouncing
+        let (tx, rx) = channel::<notify::Result<Event>>();
         let config = Config::default().with_poll_interval(Duration::from_millis(500));
         let mut watcher = RecommendedWatcher::new(tx, config)?;
-
-        // Watch the repository directory
-        watcher.watch(&repo_path, RecursiveMode::Recursive)?;
+        coalescer.watch(&mut watcher)?;
 
         let watcher_arc = Arc::new(Mutex::new(Some(watcher)));
 
         // Spawn thread to handle events
-        let handle =
-            Self::spawn_event_handler(rx, repo_path, app_handle, Arc::clone(&is_active_flag));
+        let handle = Self::spawn_event_handler(
+            rx,
+            coalescer,
+            Arc::clone(&watcher_arc),
+            app_handle,
+            Arc::clone(&is_active_flag),
+        );
         let handle_arc = Arc::new(Mutex::new(Some(handle)));
 
         Ok(Self {
@@ -78,137 +101,83 @@ impl FileWatcher {
 
     fn spawn_event_handler(
         rx: Receiver<notify::Result<Event>>,
-        repo_path: PathBuf,
+        mut coalescer: ChangeCoalescer,
+        watcher: Arc<Mutex<Option<RecommendedWatcher>>>,
         app_handle: AppHandle,
         is_active: Arc<AtomicBool>,
     ) -> thread::JoinHandle<()> {
-        let git_dir = repo_path.join(".git");
-
-        thread::spawn(move || {
-            // Debouncing: collect events for a short period before emitting
-            let mut pending_changes: Vec<PathBuf> = Vec::new();
-            let mut last_emit = std::time::Instant::now();
-            let debounce_duration = Duration::from_millis(100);
-
-            // Track if we've already emitted a dirty event (for inactive repos)
-            let mut dirty_emitted = false;
-
-            // Track pending git events for debouncing
-            let mut pending_index_changed = false;
-            let mut pending_head_changed = false;
-            let mut pending_refs: Vec<String> = Vec::new();
-
-            loop {
-                // Use timeout to allow periodic flushing
-                match rx.recv_timeout(debounce_duration) {
-                    Ok(Ok(event)) => {
-                        let active = is_active.load(Ordering::SeqCst);
-
-                        for path in event.paths {
-                            // Skip paths outside this repo
-                            if !path.starts_with(&repo_path) {
-                                continue;
-                            }
+        thread::spawn(move || loop {
+            let timeout = coalescer
+                .time_until_flush(Instant::now())
+                .unwrap_or(IDLE_POLL);
 
-                            if active {
-                                // Active repo: emit detailed events
-                                if path.starts_with(&git_dir) {
-                                    let relative = path.strip_prefix(&git_dir).unwrap_or(&path);
-                                    let relative_str = relative.to_string_lossy();
-
-                                    if relative_str == "index" || relative_str == "index.lock" {
-                                        pending_index_changed = true;
-                                    } else if relative_str == "HEAD" || relative_str == "HEAD.lock"
-                                    {
-                                        pending_head_changed = true;
-                                    } else if relative_str.starts_with("refs/") {
-                                        let ref_name = relative_str.to_string();
-                                        if !pending_refs.contains(&ref_name) {
-                                            pending_refs.push(ref_name);
-                                        }
-                                    }
-                                } else {
-                                    pending_changes.push(path);
-                                }
-                            } else {
-                                // Inactive repo: emit dirty event once per batch
-                                if !dirty_emitted {
-                                    let _ = RepositoryDirtyEvent {
-                                        path: repo_path.to_string_lossy().to_string(),
-                                    }
-                                    .emit(&app_handle);
-                                    dirty_emitted = true;
-                                }
+            match rx.recv_timeout(timeout) {
+                Ok(Ok(event)) => {
+                    let created_dir = matches!(event.kind, EventKind::Create(CreateKind::Folder));
+                    for path in event.paths {
+                        if created_dir {
+                            if let Some(w) = watcher.lock().as_mut() {
+                                coalescer.watch_new_top_level_dir(w, &path);
                             }
                         }
-                    }
-                    Ok(Err(e)) => {
-                        let _ = WatchErrorEvent {
-                            message: e.to_string(),
-                        }
-                        .emit(&app_handle);
-                    }
-                    Err(std::sync::mpsc::RecvTimeoutError::Timeout) => {
-                        // Timeout - flush pending changes if any and reset dirty flag
-                        dirty_emitted = false;
-                    }
-                    Err(std::sync::mpsc::RecvTimeoutError::Disconnected) => {
-                        // Channel closed, exit the thread
-                        break;
+                        coalescer.record(&path, Instant::now());
                     }
                 }
-
-                // Emit pending events if debounce period has passed
-                let has_pending = !pending_changes.is_empty()
-                    || pending_index_changed
-                    || pending_head_changed
-                    || !pending_refs.is_empty();
-
-                if has_pending && last_emit.elapsed() >= debounce_duration {
-                    // Emit git events
-                    if pending_index_changed {
-                        let _ = IndexChangedEvent {}.emit(&app_handle);
-                        pending_index_changed = false;
-                    }
-                    if pending_head_changed {
-                        let _ = HeadChangedEvent {}.emit(&app_handle);
-                        pending_head_changed = false;
-
-                        // Invalidate commit cache on HEAD change
-                        if let Some(state) = app_handle.try_state::<AppState>() {
-                            state.commit_cache().invalidate_repo(&repo_path);
-                        }
-                    }
-                    if !pending_refs.is_empty() {
-                        // Invalidate commit cache on ref changes
-                        if let Some(state) = app_handle.try_state::<AppState>() {
-                            state.commit_cache().invalidate_repo(&repo_path);
-                        }
-                    }
-                    for ref_name in pending_refs.drain(..) {
-                        let _ = RefChangedEvent { ref_name }.emit(&app_handle);
+                Ok(Err(e)) => {
+                    let _ = WatchErrorEvent {
+                        message: e.to_string(),
                     }
+                    .emit(&app_handle);
+                }
+                Err(std::sync::mpsc::RecvTimeoutError::Timeout) => {}
+                Err(std::sync::mpsc::RecvTimeoutError::Disconnected) => {
+                    // Channel closed, exit the thread
+                    break;
+                }
+            }
 
-                    // Emit file changes
-                    if !pending_changes.is_empty() {
-                        let paths: Vec<String> = pending_changes
-                            .drain(..)
-                            .filter_map(|p| {
-                                p.strip_prefix(&repo_path)
-                                    .ok()
-                                    .map(|r| r.to_string_lossy().to_string())
-                            })
-                            .collect();
-
-                        if !paths.is_empty() {
-                            let _ = FilesChangedEvent { paths }.emit(&app_handle);
-                        }
-                    }
-                    last_emit = std::time::Instant::now();
+            if let Some(changes) = coalescer.poll(Instant::now()) {
+                if is_active.load(Ordering::SeqCst) {
+                    Self::emit_changes(&app_handle, coalescer.repo_path(), changes);
+                } else {
+                    let _ = RepositoryDirtyEvent { path: changes.path }.emit(&app_handle);
                 }
             }
         })
     }
+
+    /// Emit a coalesced batch, plus the per-kind events older listeners subscribe to
+    fn emit_changes(app_handle: &AppHandle, repo_path: &Path, changes: RepositoryChangedEvent) {
+        if changes.head_changed || !changes.refs_changed.is_empty() || changes.packed_refs_changed {
+            if let Some(state) = app_handle.try_state::<AppState>() {
+                state.commit_cache().invalidate_repo(repo_path);
+            }
+        }
+
+        if changes.index_changed {
+            let _ = IndexChangedEvent {}.emit(app_handle);
+        }
+        if changes.head_changed {
+            let _ = HeadChangedEvent {}.emit(app_handle);
+            if let Some(state) = app_handle.try_state::<AppState>() {
+                state.refresh_current_branch_pr(repo_path);
+            }
+        }
+        for ref_name in &changes.refs_changed {
+            let _ = RefChangedEvent {
+                ref_name: ref_name.clone(),
+            }
+            .emit(app_handle);
+        }
+        if !changes.worktree_paths.is_empty() {
+            let _ = FilesChangedEvent {
+                paths: changes.worktree_paths.clone(),
+            }
+            .emit(app_handle);
+        }
+
+        let _ = changes.emit(app_handle);
+    }
 }
 
 impl Drop for FileWatcher {
@@ -216,3 +185,331 @@ impl Drop for FileWatcher {
         self.stop();
     }
 }
+
+/// What a single changed path means for the repository
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum PathChange {
+    Worktree(String),
+    Index,
+    Head,
+    Ref(String),
+    PackedRefs,
+    Config,
+}
+
+/// Classifies raw file system paths and coalesces them into one
+/// `RepositoryChangedEvent` per quiet period.
+///
+/// Inside the git directory only HEAD, the index, refs, packed-refs and config
+/// matter; object and log churn is dropped. Worktree paths ignored by
+/// `.gitignore` are dropped too.
+pub struct ChangeCoalescer {
+    repo_path: PathBuf,
+    git_dir: PathBuf,
+    /// Used for ignore rules; `None` if the repository could not be opened
+    repo: Option<Git2Repository>,
+    debounce: Duration,
+    pending: RepositoryChangedEvent,
+    first_change: Option<Instant>,
+    last_change: Option<Instant>,
+}
+
+impl ChangeCoalescer {
+    pub fn new(repo_path: &Path, debounce: Duration) -> Self {
+        let repo = match Git2Repository::open(repo_path) {
+            Ok(repo) => Some(repo),
+            Err(e) => {
+                log::warn!(
+                    "File watcher cannot read ignore rules for {}: {e}",
+                    repo_path.display()
+                );
+                None
+            }
+        };
+        let git_dir = repo
+            .as_ref()
+            .map_or_else(|| repo_path.join(".git"), |repo| repo.path().to_path_buf());
+
+        Self {
+            repo_path: repo_path.to_path_buf(),
+            git_dir,
+            repo,
+            debounce,
+            pending: RepositoryChangedEvent {
+                path: repo_path.to_string_lossy().to_string(),
+                ..Default::default()
+            },
+            first_change: None,
+            last_change: None,
+        }
+    }
+
+    pub fn repo_path(&self) -> &Path {
+        &self.repo_path
+    }
+
+    /// Register the watches for this repository: the root and the git directory
+    /// non-recursively, `refs` and every non-ignored top-level worktree directory
+    /// recursively. Objects and ignored build output are never watched.
+    pub fn watch<W: Watcher>(&self, watcher: &mut W) -> notify::Result<()> {
+        watcher.watch(&self.repo_path, RecursiveMode::NonRecursive)?;
+        watcher.watch(&self.git_dir, RecursiveMode::NonRecursive)?;
+
+        let refs = self.git_dir.join("refs");
+        if refs.is_dir() {
+            watcher.watch(&refs, RecursiveMode::Recursive)?;
+        }
+
+        match std::fs::read_dir(&self.repo_path) {
+            Ok(entries) => {
+                for entry in entries.flatten() {
+                    let path = entry.path();
+                    if entry.file_type().is_ok_and(|t| t.is_dir()) {
+                        self.watch_top_level_dir(watcher, &path);
+                    }
+                }
+            }
+            Err(e) => log::warn!("File watcher cannot list {}: {e}", self.repo_path.display()),
+        }
+        Ok(())
+    }
+
+    /// Start watching a directory created at the top level of the worktree
+    pub fn watch_new_top_level_dir<W: Watcher>(&self, watcher: &mut W, path: &Path) {
+        if path.parent() == Some(self.repo_path.as_path()) {
+            self.watch_top_level_dir(watcher, path);
+        }
+    }
+
+    fn watch_top_level_dir<W: Watcher>(&self, watcher: &mut W, path: &Path) {
+        if path.starts_with(&self.git_dir) || path.file_name().is_some_and(|n| n == ".git") {
+            return;
+        }
+        if self.is_ignored(path) {
+            return;
+        }
+        if let Err(e) = watcher.watch(path, RecursiveMode::Recursive) {
+            log::warn!("File watcher cannot watch {}: {e}", path.display());
+        }
+    }
+
+    /// Record a changed path seen at `now`
+    pub fn record(&mut self, path: &Path, now: Instant) {
+        let Some(change) = self.classify(path) else {
+            return;
+        };
+
+        let pending = &mut self.pending;
+        match change {
+            PathChange::Worktree(relative) => {
+                if pending.worktree_paths.contains(&relative) {
+                    // Already listed
+                } else if pending.worktree_paths.len() < MAX_WATCHER_WORKTREE_PATHS {
+                    pending.worktree_paths.push(relative);
+                } else {
+                    pending.worktree_overflow = true;
+                }
+            }
+            PathChange::Index => pending.index_changed = true,
+            PathChange::Head => pending.head_changed = true,
+            PathChange::Ref(name) => {
+                if !pending.refs_changed.contains(&name) {
+                    pending.refs_changed.push(name);
+                }
+            }
+            PathChange::PackedRefs => pending.packed_refs_changed = true,
+            PathChange::Config => pending.config_changed = true,
+        }
+
+        self.first_change.get_or_insert(now);
+        self.last_change = Some(now);
+    }
+
+    /// Time left until the pending batch is due, `None` if nothing is pending
+    pub fn time_until_flush(&self, now: Instant) -> Option<Duration> {
+        let first = self.first_change?;
+        let last = self.last_change?;
+        let quiet_at = last + self.debounce;
+        let deadline_at = first + self.debounce * MAX_WAIT_WINDOWS;
+        Some(quiet_at.min(deadline_at).saturating_duration_since(now))
+    }
+
+    /// Take the pending batch once the debounce window passed without new changes,
+    /// or once changes kept arriving for `MAX_WAIT_WINDOWS` windows
+    pub fn poll(&mut self, now: Instant) -> Option<RepositoryChangedEvent> {
+        if !self.time_until_flush(now)?.is_zero() {
+            return None;
+        }
+
+        self.first_change = None;
+        self.last_change = None;
+        let path = self.pending.path.clone();
+        let changes = std::mem::replace(
+            &mut self.pending,
+            RepositoryChangedEvent {
+                path,
+                ..Default::default()
+            },
+        );
+        (!changes.is_empty()).then_some(changes)
+    }
+
+    fn classify(&self, path: &Path) -> Option<PathChange> {
+        if let Ok(relative) = path.strip_prefix(&self.git_dir) {
+            let relative = relative.to_string_lossy().replace('\\', "/");
+            let relative = relative.strip_suffix(".lock").unwrap_or(&relative);
+            return match relative {
+                "index" => Some(PathChange::Index),
+                "HEAD" => Some(PathChange::Head),
+                "packed-refs" => Some(PathChange::PackedRefs),
+                "config" => Some(PathChange::Config),
+                r if r.starts_with("refs/") => Some(PathChange::Ref(r.to_string())),
+                _ => None,
+            };
+        }
+
+        let relative = path.strip_prefix(&self.repo_path).ok()?;
+        if relative.as_os_str().is_empty()
+            || relative.components().any(|c| c.as_os_str() == ".git")
+            || self.is_ignored(path)
+        {
+            return None;
+        }
+        Some(PathChange::Worktree(
+            relative.to_string_lossy().replace('\\', "/"),
+        ))
+    }
+
+    fn is_ignored(&self, path: &Path) -> bool {
+        let (Some(repo), Ok(relative)) = (&self.repo, path.strip_prefix(&self.repo_path)) else {
+            return false;
+        };
+        repo.status_should_ignore(relative).unwrap_or(false)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::process::Command;
+    use tempfile::TempDir;
+
+    fn git(dir: &Path, args: &[&str]) {
+        let status = Command::new("git")
+            .args(args)
+            .current_dir(dir)
+            .status()
+            .expect("should run git");
+        assert!(status.success(), "git {args:?} failed");
+    }
+
+    fn init_repo() -> TempDir {
+        let tmp = TempDir::new().expect("should create temp dir");
+        git(tmp.path(), &["init", "-q", "-b", "main"]);
+        git(tmp.path(), &["config", "user.email", "test@test.com"]);
+        git(tmp.path(), &["config", "user.name", "Test User"]);
+        std::fs::write(tmp.path().join(".gitignore"), "target/\n").expect("should write");
+        git(tmp.path(), &["add", "."]);
+        git(tmp.path(), &["commit", "-q", "-m", "init"]);
+        tmp
+    }
+
+    #[test]
+    fn test_burst_of_writes_coalesces_into_one_batch() {
+        let tmp = init_repo();
+        let root = tmp.path();
+        let mut coalescer = ChangeCoalescer::new(root, DEFAULT_WATCHER_DEBOUNCE);
+        let start = Instant::now();
+
+        for i in 0..(MAX_WATCHER_WORKTREE_PATHS + 100) {
+            let at = start + Duration::from_micros(i as u64 * 100);
+            coalescer.record(&root.join(format!("src/file{i}.rs")), at);
+            coalescer.record(&root.join(format!("target/debug/out{i}.o")), at);
+            coalescer.record(&root.join(".git/objects/ab/cdef"), at);
+        }
+        let last = start + Duration::from_micros((MAX_WATCHER_WORKTREE_PATHS as u64 + 99) * 100);
+
+        assert!(coalescer.poll(last).is_none(), "still inside the window");
+        let changes = coalescer
+            .poll(last + DEFAULT_WATCHER_DEBOUNCE)
+            .expect("should flush one batch");
+        assert!(coalescer
+            .poll(last + DEFAULT_WATCHER_DEBOUNCE * 2)
+            .is_none());
+
+        assert_eq!(changes.worktree_paths.len(), MAX_WATCHER_WORKTREE_PATHS);
+        assert!(changes.worktree_overflow);
+        assert!(changes.worktree_paths.iter().all(|p| p.starts_with("src/")));
+        assert!(!changes.index_changed);
+        assert!(!changes.head_changed);
+        assert!(changes.refs_changed.is_empty());
+    }
+
+    #[test]
+    fn test_steady_stream_flushes_after_max_wait() {
+        let tmp = init_repo();
+        let mut coalescer = ChangeCoalescer::new(tmp.path(), DEFAULT_WATCHER_DEBOUNCE);
+        let start = Instant::now();
+        let step = DEFAULT_WATCHER_DEBOUNCE / 2;
+
+        let mut flushed = None;
+        for i in 0..20 {
+            let now = start + step * i;
+            coalescer.record(&tmp.path().join("busy.log"), now);
+            if let Some(changes) = coalescer.poll(now) {
+                flushed = Some((i, changes));
+                break;
+            }
+        }
+
+        let (i, changes) = flushed.expect("should flush while changes keep coming");
+        assert_eq!(step * i, DEFAULT_WATCHER_DEBOUNCE * MAX_WAIT_WINDOWS);
+        assert_eq!(changes.worktree_paths, vec!["busy.log"]);
+    }
+
+    #[test]
+    fn test_only_ignored_changes_produce_no_batch() {
+        let tmp = init_repo();
+        let mut coalescer = ChangeCoalescer::new(tmp.path(), DEFAULT_WATCHER_DEBOUNCE);
+        let now = Instant::now();
+
+        coalescer.record(&tmp.path().join("target/release/app"), now);
+        coalescer.record(&tmp.path().join(".git/logs/HEAD"), now);
+
+        assert!(coalescer.time_until_flush(now).is_none());
+        assert!(coalescer.poll(now + DEFAULT_WATCHER_DEBOUNCE).is_none());
+    }
+
+    #[test]
+    fn test_branch_switch_is_classified_as_ref_change() {
+        let tmp = init_repo();
+        let root = tmp.path().to_path_buf();
+        let debounce = Duration::from_millis(200);
+        let mut coalescer = ChangeCoalescer::new(&root, debounce);
+
+        let (tx, rx) = channel::<notify::Result<Event>>();
+        let mut watcher =
+            RecommendedWatcher::new(tx, Config::default()).expect("should create watcher");
+        coalescer.watch(&mut watcher).expect("should watch");
+
+        git(&root, &["checkout", "-b", "feature"]);
+
+        let deadline = Instant::now() + Duration::from_secs(5);
+        let mut changes = None;
+        while changes.is_none() && Instant::now() < deadline {
+            if let Ok(Ok(event)) = rx.recv_timeout(Duration::from_millis(50)) {
+                for path in event.paths {
+                    coalescer.record(&path, Instant::now());
+                }
+            }
+            changes = coalescer.poll(Instant::now());
+        }
+
+        let changes = changes.expect("should report the checkout");
+        assert!(changes.head_changed);
+        assert!(changes
+            .refs_changed
+            .contains(&"refs/heads/feature".to_string()));
+        assert!(changes.worktree_paths.is_empty());
+    }
+}
@@ -0,0 +1,435 @@
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use crate::models::{DiffHunk, DiffLineType, FileDiff};
+
+/// Old blobs larger than this (in bytes) are not scanned for function context
+pub const FUNCTION_CONTEXT_MAX_BLOB_BYTES: usize = 1024 * 1024;
+
+/// Longest context kept, in characters; git cuts its hunk header context at 80 bytes
+const FUNCTION_CONTEXT_MAX_CHARS: usize = 80;
+
+/// `xfuncname` patterns per language, adapted from git's `userdiff.c`. As in git,
+/// patterns are tried in order and a leading `!` rejects the line when it matches.
+/// The first capture group is the context shown.
+const LANGUAGE_PATTERNS: &[(&str, &[&str])] = &[
+    (
+        "rust",
+        &[
+            r#"^[\t ]*((pub(\([^\)]+\))?[\t ]+)?((async|const|unsafe|extern([\t ]+"[^"]+"))[\t ]+)?(struct|enum|union|mod|trait|fn|impl|macro_rules!)[< \t]+[^;]*)$"#,
+        ],
+    ),
+    ("python", &[r"^[ \t]*((class|(async[ \t]+)?def)[ \t].*)$"]),
+    (
+        "golang",
+        &[
+            r"^[ \t]*(func[ \t]*.*(\{[ \t]*)?)$",
+            r"^[ \t]*(type[ \t].*(struct|interface)[ \t]*(\{[ \t]*)?)$",
+        ],
+    ),
+    (
+        "java",
+        &[
+            r"!^[ \t]*(catch|do|for|if|instanceof|new|return|switch|throw|while)",
+            r"^[ \t]*(([a-z-]+[ \t]+)*(class|enum|interface|record)[ \t]+.*)$",
+            r"^[ \t]*(([A-Za-z_<>&\]\[][\]\[?&<>.,A-Za-z_0-9]*[ \t]+)+[A-Za-z_][A-Za-z_0-9]*[ \t]*\([^;]*)$",
+        ],
+    ),
+    (
+        "cpp",
+        &[
+            r"!^[ \t]*[A-Za-z_][A-Za-z_0-9]*:[[:space:]]*($|/[/*])",
+            r"^((::[[:space:]]*)?[A-Za-z_].*)$",
+        ],
+    ),
+    // git ships no JavaScript driver; these follow the style of the others
+    (
+        "javascript",
+        &[
+            r"!^[ \t]*(catch|do|else|for|if|return|switch|while)\b",
+            r"^[ \t]*((export[ \t]+)?(default[ \t]+)?(abstract[ \t]+)?(class|interface|enum|namespace)[ \t]+.*)$",
+            r"^[ \t]*((export[ \t]+)?(default[ \t]+)?(async[ \t]+)?function\b.*)$",
+            r"^[ \t]*((export[ \t]+)?(const|let|var)[ \t]+[A-Za-z_$][A-Za-z0-9_$]*[ \t]*(:[^=]*)?=[ \t]*(async[ \t]+)?(function\b|\([^)]*\)[^=]*=>|[A-Za-z_$][A-Za-z0-9_$]*[ \t]*=>).*)$",
+            r"^[ \t]*(((public|private|protected|static|async|readonly|get|set)[ \t]+)*[A-Za-z_$][A-Za-z0-9_$]*[ \t]*\([^;]*\)[ \t]*(:[^;{]*)?\{[ \t]*)$",
+        ],
+    ),
+];
+
+/// Compiled patterns by language; `true` marks a rejecting pattern
+static COMPILED_PATTERNS: LazyLock<HashMap<&'static str, Vec<(bool, Regex)>>> =
+    LazyLock::new(|| {
+        LANGUAGE_PATTERNS
+            .iter()
+            .map(|(language, patterns)| {
+                let compiled = patterns
+                    .iter()
+                    .map(|pattern| match pattern.strip_prefix('!') {
+                        Some(pattern) => (true, pattern),
+                        None => (false, *pattern),
+                    })
+                    .map(|(negate, pattern)| {
+                        (
+                            negate,
+                            Regex::new(pattern).expect("Invalid xfuncname pattern"),
+                        )
+                    })
+                    .collect();
+                (*language, compiled)
+            })
+            .collect()
+    });
+
+/// The `LANGUAGE_PATTERNS` entry for `path`, from its extension
+fn language_for_path(path: &str) -> Option<&'static str> {
+    let ext = Path::new(path).extension()?.to_str()?.to_ascii_lowercase();
+    let language = match ext.as_str() {
+        "rs" => "rust",
+        "py" | "pyi" => "python",
+        "go" => "golang",
+        "java" => "java",
+        "c" | "h" | "cc" | "cpp" | "cxx" | "hh" | "hpp" | "hxx" => "cpp",
+        "js" | "jsx" | "mjs" | "cjs" | "ts" | "tsx" | "mts" | "cts" => "javascript",
+        _ => return None,
+    };
+    Some(language)
+}
+
+/// Fill `context` for the hunks of text files in a language with known patterns,
+/// from the old side of each file. Binary files, new files and old blobs over
+/// `FUNCTION_CONTEXT_MAX_BLOB_BYTES` are skipped.
+pub fn annotate_function_context(repo: &git2::Repository, files: &mut [FileDiff]) {
+    let odb = match repo.odb() {
+        Ok(odb) => odb,
+        Err(e) => {
+            log::warn!("Cannot open object database for function context: {e}");
+            return;
+        }
+    };
+
+    for file in files
+        .iter_mut()
+        .filter(|f| !f.binary && !f.hunks.is_empty())
+    {
+        let Some(language) = file.old_path.as_deref().and_then(language_for_path) else {
+            continue;
+        };
+        let Some(oid) = file
+            .old_oid
+            .as_deref()
+            .and_then(|oid| git2::Oid::from_str(oid).ok())
+        else {
+            continue;
+        };
+        match odb.read_header(oid) {
+            Ok((size, _)) if size <= FUNCTION_CONTEXT_MAX_BLOB_BYTES => {}
+            _ => continue,
+        }
+        let Ok(blob) = repo.find_blob(oid) else {
+            continue;
+        };
+
+        let content = String::from_utf8_lossy(blob.content());
+        let lines: Vec<&str> = content.lines().collect();
+        for hunk in &mut file.hunks {
+            hunk.context = find_function_context(&lines, hunk, language);
+        }
+    }
+}
+
+/// The nearest declaration above the first change of `hunk` that encloses it.
+///
+/// Like git, lines are matched against the language's patterns scanning upward.
+/// Unlike git, a match only counts when it is indented less than every line between
+/// it and the change, so functions that closed before the change are skipped.
+pub fn find_function_context(
+    old_lines: &[&str],
+    hunk: &DiffHunk,
+    language: &str,
+) -> Option<String> {
+    let patterns = COMPILED_PATTERNS.get(language)?;
+    let (position, changed) = first_change(hunk)?;
+
+    let mut min_indent = indent_width(changed).unwrap_or(usize::MAX);
+    let above = old_lines.get(..position.saturating_sub(1))?;
+    for line in above.iter().rev() {
+        if min_indent == 0 {
+            return None;
+        }
+        let Some(indent) = indent_width(line) else {
+            continue;
+        };
+        if indent < min_indent {
+            if let Some(context) = match_declaration(line, patterns) {
+                return Some(context);
+            }
+        }
+        min_indent = min_indent.min(indent);
+    }
+    None
+}
+
+/// 1-based old line number of the first added or deleted line, and its content.
+/// An addition sits before the old line that follows it.
+fn first_change(hunk: &DiffHunk) -> Option<(usize, &str)> {
+    let mut next_old = if hunk.old_lines == 0 {
+        hunk.old_start as usize + 1
+    } else {
+        hunk.old_start as usize
+    };
+    for line in &hunk.lines {
+        match line.line_type {
+            DiffLineType::Addition | DiffLineType::Deletion => {
+                let position = line.old_line_no.map_or(next_old, |n| n as usize);
+                return Some((position, &line.content));
+            }
+            _ => {
+                if let Some(n) = line.old_line_no {
+                    next_old = n as usize + 1;
+                }
+            }
+        }
+    }
+    None
+}
+
+/// Leading whitespace width with tabs counted as four columns. `None` for blank
+/// lines and lines of only brackets, such as a brace on its own line, which say
+/// nothing about nesting.
+fn indent_width(line: &str) -> Option<usize> {
+    if line
+        .trim()
+        .chars()
+        .all(|c| matches!(c, '{' | '}' | '(' | ')' | '[' | ']' | ';' | ','))
+    {
+        return None;
+    }
+    Some(
+        line.chars()
+            .take_while(|c| c.is_whitespace())
+            .map(|c| if c == '\t' { 4 } else { 1 })
+            .sum(),
+    )
+}
+
+fn match_declaration(line: &str, patterns: &[(bool, Regex)]) -> Option<String> {
+    let line = line.trim_end();
+    for (negate, pattern) in patterns {
+        let Some(captures) = pattern.captures(line) else {
+            continue;
+        };
+        if *negate {
+            return None;
+        }
+        let matched = captures.get(1).or_else(|| captures.get(0))?.as_str().trim();
+        return Some(matched.chars().take(FUNCTION_CONTEXT_MAX_CHARS).collect());
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::DiffLine;
+
+    const RUST_SOURCE: &str = "\
+mod parser {
+    pub struct Parser;
+
+    impl Parser {
+        pub fn parse(&self) -> u32 {
+            fn helper(x: u32) -> u32 {
+                x + 1
+            }
+            let a = helper(1);
+            a
+        }
+    }
+}
+";
+
+    const PYTHON_SOURCE: &str = "\
+class Loader:
+    def load(self):
+        def read(path):
+            return open(path).read()
+
+        data = read('a')
+        return data
+
+
+def main():
+    Loader().load()
+";
+
+    const TS_SOURCE: &str = "\
+export class Store {
+  private items: string[] = [];
+
+  add(item: string): void {
+    if (item) {
+      this.items.push(item);
+    }
+  }
+}
+
+export const reset = async (store: Store) => {
+  store.clear();
+};
+";
+
+    const GO_SOURCE: &str = "\
+package main
+
+func (s *Server) Start() error {
+\thandler := func() {
+\t\tlog.Println(\"ok\")
+\t}
+\treturn nil
+}
+";
+
+    const JAVA_SOURCE: &str = "\
+public class Greeter {
+    public String greet(String name) {
+        if (name == null) {
+            return \"hi\";
+        }
+        return \"hi \" + name;
+    }
+}
+";
+
+    const C_SOURCE: &str = "\
+static int counter = 0;
+
+int increment(int by)
+{
+    counter += by;
+    return counter;
+}
+";
+
+    /// A hunk deleting old line `line` of `source`, with no context lines
+    fn deletion_hunk(source: &str, line: u32) -> DiffHunk {
+        let content = source
+            .lines()
+            .nth(line as usize - 1)
+            .expect("line should exist")
+            .to_string();
+        DiffHunk {
+            header: format!("@@ -{line},1 +{line},0 @@"),
+            old_start: line,
+            old_lines: 1,
+            new_start: line,
+            new_lines: 0,
+            lines: vec![DiffLine {
+                line_type: DiffLineType::Deletion,
+                content,
+                old_line_no: Some(line),
+                new_line_no: None,
+                inline_spans: None,
+            }],
+            context: None,
+        }
+    }
+
+    fn context_at(source: &str, language: &str, line: u32) -> Option<String> {
+        let lines: Vec<&str> = source.lines().collect();
+        find_function_context(&lines, &deletion_hunk(source, line), language)
+    }
+
+    #[test]
+    fn test_rust_nested_function() {
+        assert_eq!(
+            context_at(RUST_SOURCE, "rust", 7).as_deref(),
+            Some("fn helper(x: u32) -> u32 {")
+        );
+        // After the nested function closes, the outer method encloses the change
+        assert_eq!(
+            context_at(RUST_SOURCE, "rust", 9).as_deref(),
+            Some("pub fn parse(&self) -> u32 {")
+        );
+        assert_eq!(
+            context_at(RUST_SOURCE, "rust", 2).as_deref(),
+            Some("mod parser {")
+        );
+    }
+
+    #[test]
+    fn test_python_nested_function_and_class() {
+        assert_eq!(
+            context_at(PYTHON_SOURCE, "python", 4).as_deref(),
+            Some("def read(path):")
+        );
+        assert_eq!(
+            context_at(PYTHON_SOURCE, "python", 6).as_deref(),
+            Some("def load(self):")
+        );
+        assert_eq!(
+            context_at(PYTHON_SOURCE, "python", 11).as_deref(),
+            Some("def main():")
+        );
+    }
+
+    #[test]
+    fn test_typescript_method_skips_control_flow() {
+        assert_eq!(
+            context_at(TS_SOURCE, "javascript", 6).as_deref(),
+            Some("add(item: string): void {")
+        );
+        assert_eq!(
+            context_at(TS_SOURCE, "javascript", 2).as_deref(),
+            Some("export class Store {")
+        );
+        assert_eq!(
+            context_at(TS_SOURCE, "javascript", 12).as_deref(),
+            Some("export const reset = async (store: Store) => {")
+        );
+    }
+
+    #[test]
+    fn test_go_closure_inside_method() {
+        // Like git, only lines starting with `func` are declarations
+        assert_eq!(
+            context_at(GO_SOURCE, "golang", 5).as_deref(),
+            Some("func (s *Server) Start() error {")
+        );
+        assert_eq!(
+            context_at(GO_SOURCE, "golang", 7).as_deref(),
+            Some("func (s *Server) Start() error {")
+        );
+    }
+
+    #[test]
+    fn test_java_method_skips_if() {
+        assert_eq!(
+            context_at(JAVA_SOURCE, "java", 4).as_deref(),
+            Some("public String greet(String name) {")
+        );
+    }
+
+    #[test]
+    fn test_c_function() {
+        assert_eq!(
+            context_at(C_SOURCE, "cpp", 5).as_deref(),
+            Some("int increment(int by)")
+        );
+    }
+
+    #[test]
+    fn test_top_level_change_has_no_context() {
+        assert_eq!(context_at(PYTHON_SOURCE, "python", 1), None);
+    }
+
+    #[test]
+    fn test_language_for_path() {
+        assert_eq!(language_for_path("src/main.RS"), Some("rust"));
+        assert_eq!(language_for_path("web/app.tsx"), Some("javascript"));
+        assert_eq!(language_for_path("include/util.hpp"), Some("cpp"));
+        assert_eq!(language_for_path("README.md"), None);
+        assert_eq!(language_for_path("Makefile"), None);
+    }
+}
@@ -1,17 +1,20 @@
 use crate::error::{AxisError, Result};
+use crate::models::CommitTrailer;
 use crate::models::ResetMode;
 use crate::models::SshCredentials;
 use crate::models::{
     AddSubmoduleOptions, AddWorktreeOptions, ArchiveResult, BisectState, GitEnvironment,
-    GitFlowBranchType, GitFlowConfig, GitFlowFinishOptions, GitFlowInitOptions, GitFlowResult,
-    GrepMatch, GrepOptions, GrepResult, LfsEnvironment, LfsFetchOptions, LfsFile, LfsFileStatus,
-    LfsMigrateMode, LfsMigrateOptions, LfsPruneOptions, LfsPruneResult, LfsPullOptions,
-    LfsPushOptions, LfsResult, LfsStatus, LfsTrackedPattern, ListSubmoduleOptions, PatchResult,
+    GitFlowBranchType, GitFlowConfig, GitFlowInitOptions, GitFlowResult, LfsEnvironment,
+    LfsFetchOptions, LfsFile, LfsFileStatus, LfsMigrateMode, LfsMigrateOptions, LfsPruneOptions,
+    LfsPruneResult, LfsPullOptions, LfsPushOptions, LfsResult, LfsStatus, LfsTrackedPattern,
+    ListSubmoduleOptions, MaintenanceTask, MergeStrategy, PatchResult, PruneDryRunResult,
     RemoveWorktreeOptions, StashApplyOptions, StashEntry, StashResult, StashSaveOptions, Submodule,
-    SubmoduleResult, SubmoduleSortOrder, SubmoduleStatus, SyncSubmoduleOptions, TagResult,
-    UpdateSubmoduleOptions, Worktree, WorktreeResult,
+    SubmoduleResult, SubmoduleSortOrder, SubmoduleStatus, SyncSubmoduleOptions, TagPushOutcome,
+    TagPushPlan, TagPushResult, TagPushStatus, UpdateSubmoduleOptions, Worktree, WorktreeResult,
 };
 use crate::models::{InteractiveRebaseEntry, RebaseAction, RebaseProgress};
+use crate::models::{SigningConfig, SigningFormat};
+use crate::models::{UndoSnapshot, UNDO_REF_PREFIX};
 use chrono::{DateTime, Utc};
 use secrecy::ExposeSecret;
 use std::fs;
@@ -19,15 +22,27 @@ use std::fs::File;
 use std::io::Write;
 use std::path::Path;
 use std::process::{Output, Stdio};
-
-use crate::services::create_command;
-use tempfile::NamedTempFile;
-use tokio::io::AsyncWriteExt;
+use std::sync::{Arc, OnceLock};
+use std::time::Instant;
+
+use crate::events::ActionOutputStream;
+use crate::models::ActionExecutionResult;
+use crate::services::{
+    append_trailers, config_override_args, create_command, parse_maintenance_progress,
+    parse_prune_dry_run, ConfigOverride, CustomActionsService, HookService,
+};
+use tempfile::{NamedTempFile, TempDir};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::process::Command;
 
 use bzip2::write::BzEncoder;
 use flate2::write::GzEncoder;
 use flate2::Compression;
 
+/// Only prune unreachable objects older than this, matching `gc.pruneExpire`'s default,
+/// so objects a concurrent git process just wrote are left alone
+const PRUNE_EXPIRE: &str = "--expire=2.weeks.ago";
+
 /// Service for Git operations that require the system Git CLI.
 /// Used for operations that libgit2 doesn't support well:
 /// - Interactive rebase
@@ -36,6 +51,10 @@ use flate2::Compression;
 /// - Revert operations
 pub struct GitCliService {
     repo_path: std::path::PathBuf,
+    /// Decides whether git may run the repository's hooks itself
+    hook_gate: Option<Arc<HookService>>,
+    /// Empty directory used as `core.hooksPath` while the gate is closed
+    empty_hooks_dir: OnceLock<TempDir>,
 }
 
 #[cfg(test)]
@@ -59,14 +78,68 @@ impl GitCliService {
     pub fn new(repo_path: &Path) -> Self {
         GitCliService {
             repo_path: repo_path.to_path_buf(),
+            hook_gate: None,
+            empty_hooks_dir: OnceLock::new(),
+        }
+    }
+
+    /// Only let git run the repository's hooks while `hooks` trusts all of them.
+    /// Otherwise git sees an empty hooks directory and Axis runs the trusted hooks itself.
+    #[must_use]
+    pub fn with_hook_gate(mut self, hooks: Arc<HookService>) -> Self {
+        self.hook_gate = Some(hooks);
+        self
+    }
+
+    /// A git command in the repository, with hooks disabled unless all of them are trusted
+    fn git_command(&self) -> Result<Command> {
+        let mut cmd = create_command("git");
+        if self
+            .hook_gate
+            .as_ref()
+            .is_some_and(|hooks| !hooks.git_may_run_hooks())
+        {
+            let empty_dir = match self.empty_hooks_dir.get() {
+                Some(dir) => dir,
+                None => {
+                    let dir = tempfile::Builder::new()
+                        .prefix("axis-no-hooks")
+                        .tempdir()
+                        .map_err(AxisError::from)?;
+                    self.empty_hooks_dir.get_or_init(|| dir)
+                }
+            };
+            cmd.arg("-c").arg(format!(
+                "core.hooksPath={}",
+                empty_dir.path().to_string_lossy()
+            ));
         }
+        cmd.current_dir(&self.repo_path);
+        Ok(cmd)
     }
 
     /// Execute a git command and return the result
     async fn execute(&self, args: &[&str]) -> Result<GitCommandResult> {
-        let output = create_command("git")
+        let output = self
+            .git_command()?
+            .args(args)
+            .output()
+            .await
+            .map_err(AxisError::from)?;
+
+        Ok(GitCommandResult::from(output))
+    }
+
+    /// Execute a git command with `-c key=value` overrides for this invocation only
+    async fn execute_with_config(
+        &self,
+        args: &[&str],
+        overrides: &[ConfigOverride],
+    ) -> Result<GitCommandResult> {
+        let output = self
+            .git_command()?
+            .args(config_override_args(overrides))
             .args(args)
-            .current_dir(&self.repo_path)
             .output()
             .await
             .map_err(AxisError::from)?;
@@ -114,20 +187,35 @@ impl GitCliService {
         args: &[&str],
         ssh_credentials: Option<&SshCredentials>,
     ) -> Result<GitCommandResult> {
-        let Some(creds) = ssh_credentials else {
+        if ssh_credentials.is_none() {
             return self.execute(args).await;
+        }
+
+        // Hold the temp file in scope so it lives until the command completes
+        let (mut cmd, _askpass_file) = self.command_with_ssh_credentials(args, ssh_credentials)?;
+        let output = cmd.output().await.map_err(AxisError::from)?;
+        Ok(GitCommandResult::from(output))
+    }
+
+    /// Build a git command with optional SSH credentials.
+    /// The returned askpass file (if any) must be kept alive until the command finishes.
+    fn command_with_ssh_credentials(
+        &self,
+        args: &[&str],
+        ssh_credentials: Option<&SshCredentials>,
+    ) -> Result<(Command, Option<NamedTempFile>)> {
+        let mut cmd = self.git_command()?;
+        cmd.args(args);
+
+        let Some(creds) = ssh_credentials else {
+            return Ok((cmd, None));
         };
 
         let expanded = shellexpand::tilde(&creds.key_path).to_string();
         let ssh_command = format!("ssh -i {expanded} -o IdentitiesOnly=yes");
+        cmd.env("GIT_SSH_COMMAND", &ssh_command);
 
-        let mut cmd = create_command("git");
-        cmd.args(args)
-            .current_dir(&self.repo_path)
-            .env("GIT_SSH_COMMAND", &ssh_command);
-
-        // Hold the temp file in scope so it lives until the command completes
-        let _askpass_file = if let Some(passphrase) = &creds.passphrase {
+        let askpass_file = if let Some(passphrase) = &creds.passphrase {
             let askpass = Self::create_askpass_script(passphrase.expose_secret())?;
             let askpass_path = askpass.path().to_string_lossy().to_string();
 
@@ -141,8 +229,103 @@ impl GitCliService {
             None
         };
 
-        let output = cmd.output().await.map_err(AxisError::from)?;
-        Ok(GitCommandResult::from(output))
+        Ok((cmd, askpass_file))
+    }
+
+    /// Run a `git lfs` transfer command, reporting its progress meter through `on_progress`.
+    /// `on_progress(current, total, bytes)` returns false to cancel, which kills the process.
+    /// Progress lines are stripped from the returned stderr.
+    async fn execute_lfs_transfer(
+        &self,
+        args: &[&str],
+        ssh_credentials: Option<&SshCredentials>,
+        on_progress: &mut (dyn FnMut(usize, usize, usize) -> bool + Send),
+    ) -> Result<GitCommandResult> {
+        let (mut cmd, _askpass_file) = self.command_with_ssh_credentials(args, ssh_credentials)?;
+        // git-lfs only draws its progress meter on a terminal unless forced
+        cmd.env("GIT_LFS_FORCE_PROGRESS", "1");
+
+        Self::execute_with_stderr_progress(cmd, "LFS transfer", |line| {
+            Self::parse_lfs_progress(line)
+                .map(|(current, total, bytes)| on_progress(current, total, bytes))
+        })
+        .await
+    }
+
+    /// Run `cmd`, passing every stderr line (split on `\r` and `\n`) to `on_line`. `on_line`
+    /// returns `None` for ordinary messages, which are kept in the returned stderr, and
+    /// `Some(continue)` for progress lines, which are dropped. `Some(false)` kills the process
+    /// and fails with "`<what>` cancelled".
+    async fn execute_with_stderr_progress(
+        mut cmd: Command,
+        what: &str,
+        mut on_line: impl FnMut(&str) -> Option<bool> + Send,
+    ) -> Result<GitCommandResult> {
+        cmd.stdout(Stdio::piped()).stderr(Stdio::piped());
+
+        let mut child = cmd.spawn().map_err(AxisError::from)?;
+        let mut stdout = child
+            .stdout
+            .take()
+            .ok_or_else(|| AxisError::Other(format!("Failed to capture {what} stdout")))?;
+        let mut stderr = child
+            .stderr
+            .take()
+            .ok_or_else(|| AxisError::Other(format!("Failed to capture {what} stderr")))?;
+
+        let read_stdout = async {
+            let mut buf = Vec::new();
+            stdout.read_to_end(&mut buf).await.map(|_| buf)
+        };
+        let read_stderr = async {
+            let mut messages = Vec::new();
+            let mut line = Vec::new();
+            let mut chunk = [0u8; 4096];
+            let mut cancelled = false;
+            loop {
+                let n = stderr.read(&mut chunk).await?;
+                if n == 0 {
+                    break;
+                }
+                for &byte in &chunk[..n] {
+                    if byte != b'\r' && byte != b'\n' {
+                        line.push(byte);
+                        continue;
+                    }
+                    let text = String::from_utf8_lossy(&line).to_string();
+                    line.clear();
+                    match on_line(&text) {
+                        Some(keep_going) => {
+                            if !cancelled && !keep_going {
+                                cancelled = true;
+                                if let Err(e) = child.start_kill() {
+                                    log::warn!("Failed to kill cancelled {what} process: {e}");
+                                }
+                            }
+                        }
+                        None if !text.trim().is_empty() => messages.push(text),
+                        None => {}
+                    }
+                }
+            }
+            if !line.is_empty() {
+                messages.push(String::from_utf8_lossy(&line).to_string());
+            }
+            Ok::<_, std::io::Error>((messages, cancelled))
+        };
+
+        let (stdout, (messages, cancelled)) = tokio::try_join!(read_stdout, read_stderr)?;
+        let status = child.wait().await?;
+
+        if cancelled {
+            return Err(AxisError::Other(format!("{what} cancelled")));
+        }
+
+        Ok(GitCommandResult {
+            success: status.success(),
+            stdout: String::from_utf8_lossy(&stdout).to_string(),
+            stderr: messages.join("\n"),
+        })
     }
 
     /// Execute a git command, returning an error if it fails
@@ -159,9 +342,9 @@ impl GitCliService {
 
     /// Execute a git command with `GIT_EDITOR` disabled (for non-interactive operations)
     async fn execute_no_editor(&self, args: &[&str]) -> Result<GitCommandResult> {
-        let output = create_command("git")
+        let output = self
+            .git_command()?
             .args(args)
-            .current_dir(&self.repo_path)
             .env("GIT_EDITOR", "true")
             .output()
             .await
@@ -177,11 +360,55 @@ impl GitCliService {
         Ok(result)
     }
 
+    /// Run a git command that may prompt or open an editor non-interactively,
+    /// reporting each line of output as it is produced
+    pub async fn execute_streaming<F>(
+        &self,
+        args: &[String],
+        on_output: F,
+    ) -> Result<ActionExecutionResult>
+    where
+        F: Fn(ActionOutputStream, &str),
+    {
+        let start = Instant::now();
+        let mut child = self
+            .git_command()?
+            .args(args)
+            .env("GIT_EDITOR", "true")
+            .env("GIT_TERMINAL_PROMPT", "0")
+            .stdin(Stdio::null())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(AxisError::from)?;
+
+        let (stdout, stderr) = tokio::join!(
+            CustomActionsService::read_output(
+                child.stdout.take(),
+                ActionOutputStream::Stdout,
+                &on_output
+            ),
+            CustomActionsService::read_output(
+                child.stderr.take(),
+                ActionOutputStream::Stderr,
+                &on_output
+            ),
+        );
+        let status = child.wait().await.map_err(AxisError::from)?;
+        let duration_ms = u64::try_from(start.elapsed().as_millis()).unwrap_or(u64::MAX);
+
+        Ok(if status.success() {
+            ActionExecutionResult::success(stdout, stderr, duration_ms)
+        } else {
+            ActionExecutionResult::failure(status.code().unwrap_or(-1), stdout, stderr, duration_ms)
+        })
+    }
+
     // ==================== Merge Operations ====================
 
     /// Merge a branch into the current branch
-    // Allow excessive bools: these map directly to git merge CLI flags
-    #[allow(clippy::fn_params_excessive_bools)]
+    // Allow excessive bools and arguments: these map directly to git merge CLI flags
+    #[allow(clippy::fn_params_excessive_bools, clippy::too_many_arguments)]
     pub async fn merge(
         &self,
         branch: &str,
@@ -190,6 +417,7 @@ impl GitCliService {
         squash: bool,
         ff_only: bool,
         no_commit: bool,
+        strategy: Option<MergeStrategy>,
     ) -> Result<GitCommandResult> {
         let mut args = vec!["merge"];
 
@@ -200,6 +428,10 @@ impl GitCliService {
             args.push("--no-ff");
         }
 
+        if let Some(strategy) = strategy {
+            args.push(strategy.cli_arg());
+        }
+
         if squash {
             args.push("--squash");
         }
@@ -218,6 +450,54 @@ impl GitCliService {
         self.execute(&args).await
     }
 
+    /// Merge `branch`, adding `trailers` to the merge commit message.
+    ///
+    /// Without a custom message the merge stops before committing, so the trailers
+    /// can be added to git's own message in `.git/MERGE_MSG` before the commit is made.
+    pub async fn merge_with_trailers(
+        &self,
+        branch: &str,
+        message: Option<&str>,
+        no_ff: bool,
+        ff_only: bool,
+        strategy: Option<MergeStrategy>,
+        trailers: &[CommitTrailer],
+    ) -> Result<GitCommandResult> {
+        if let Some(message) = message {
+            let message = append_trailers(message, trailers);
+            return self
+                .merge(
+                    branch,
+                    Some(&message),
+                    no_ff,
+                    false,
+                    ff_only,
+                    false,
+                    strategy,
+                )
+                .await;
+        }
+
+        let result = self
+            .merge(branch, None, no_ff, false, ff_only, true, strategy)
+            .await?;
+        // Fast-forwards and failed merges leave nothing to commit
+        if !result.success || !self.is_merging()? {
+            return Ok(result);
+        }
+
+        let merge_msg = self.repo_path.join(".git/MERGE_MSG");
+        let message = fs::read_to_string(&merge_msg)?;
+        fs::write(&merge_msg, append_trailers(&message, trailers))?;
+        let commit = self.merge_continue().await?;
+
+        Ok(GitCommandResult {
+            success: commit.success,
+            stdout: format!("{}{}", result.stdout, commit.stdout),
+            stderr: format!("{}{}", result.stderr, commit.stderr),
+        })
+    }
+
     /// Abort an in-progress merge
     pub async fn merge_abort(&self) -> Result<GitCommandResult> {
         self.execute_checked(&["merge", "--abort"]).await
@@ -281,15 +561,42 @@ impl GitCliService {
         entries: &[InteractiveRebaseEntry],
         autosquash: bool,
     ) -> Result<GitCommandResult> {
-        // Build the todo file content (dropped commits are simply omitted)
+        self.run_interactive_rebase(onto, entries, autosquash, None)
+            .await
+    }
+
+    /// Execute an interactive rebase whose `reword` entries all get `message`
+    pub async fn interactive_rebase_rewording(
+        &self,
+        onto: &str,
+        entries: &[InteractiveRebaseEntry],
+        message: &str,
+    ) -> Result<GitCommandResult> {
+        self.run_interactive_rebase(onto, entries, false, Some(message))
+            .await
+    }
+
+    async fn run_interactive_rebase(
+        &self,
+        onto: &str,
+        entries: &[InteractiveRebaseEntry],
+        autosquash: bool,
+        reword_message: Option<&str>,
+    ) -> Result<GitCommandResult> {
+        // Build the todo file content (dropped commits are simply omitted).
+        // Full OIDs keep the todo unambiguous however short the abbreviations are.
         let todo_content = entries
             .iter()
             .filter(|e| e.action != RebaseAction::Drop)
             .map(|entry| {
+                let oid = if entry.oid.is_empty() {
+                    &entry.short_oid
+                } else {
+                    &entry.oid
+                };
                 format!(
-                    "{} {} {}",
+                    "{} {oid} {}",
                     entry.action.to_string().to_lowercase(),
-                    entry.short_oid,
                     entry.summary
                 )
             })
@@ -316,17 +623,34 @@ impl GitCliService {
         args.push(onto);
 
         // Execute with custom editor that replaces the todo file
-        let output = create_command("git")
+        let mut command = self.git_command()?;
+        command
             .args(&args)
-            .current_dir(&self.repo_path)
             .env("GIT_SEQUENCE_EDITOR", &editor_cmd)
-            .stdin(Stdio::null())
-            .output()
-            .await
-            .map_err(AxisError::from)?;
+            .stdin(Stdio::null());
+
+        // Reworded commits get their message the same way: copied over the file git edits
+        let message_file = match reword_message {
+            Some(message) => {
+                let mut message_file = NamedTempFile::new().map_err(AxisError::from)?;
+                write!(message_file, "{message}").map_err(AxisError::from)?;
+                let message_path = message_file.path().to_string_lossy().to_string();
+                #[cfg(windows)]
+                let message_cmd =
+                    format!("cmd /c copy /y \"{}\" ", message_path.replace('/', "\\"));
+                #[cfg(not(windows))]
+                let message_cmd = format!("cp \"{message_path}\" ");
+                command.env("GIT_EDITOR", message_cmd);
+                Some(message_file)
+            }
+            None => None,
+        };
+
+        let output = command.output().await.map_err(AxisError::from)?;
 
-        // Keep temp file alive until command completes
+        // Keep temp files alive until command completes
         drop(todo_file);
+        drop(message_file);
 
         Ok(GitCommandResult::from(output))
     }
@@ -339,21 +663,22 @@ impl GitCliService {
         let rebase_merge = self.repo_path.join(".git/rebase-merge");
         let rebase_apply = self.repo_path.join(".git/rebase-apply");
 
-        let state_dir = if rebase_merge.exists() {
-            rebase_merge
+        // The merge backend counts steps in msgnum/end, the apply backend in next/last
+        let (state_dir, step_file, total_file) = if rebase_merge.exists() {
+            (rebase_merge, "msgnum", "end")
         } else if rebase_apply.exists() {
-            rebase_apply
+            (rebase_apply, "next", "last")
         } else {
             return Ok(None);
         };
 
-        // Parse current step (msgnum) and total steps (end)
-        let current_step = fs::read_to_string(state_dir.join("msgnum"))
+        // Parse current step and total steps
+        let current_step = fs::read_to_string(state_dir.join(step_file))
             .ok()
             .and_then(|s| s.trim().parse::<usize>().ok())
             .unwrap_or(0);
 
-        let total_steps = fs::read_to_string(state_dir.join("end"))
+        let total_steps = fs::read_to_string(state_dir.join(total_file))
             .ok()
             .and_then(|s| s.trim().parse::<usize>().ok())
             .unwrap_or(0);
@@ -434,9 +759,9 @@ impl GitCliService {
             .map_err(|e| AxisError::IoError(format!("Failed to write rebase message: {e}")))?;
 
         // Continue rebase with GIT_EDITOR=true to skip the editor
-        let output = create_command("git")
+        let output = self
+            .git_command()?
             .args(["rebase", "--continue"])
-            .current_dir(&self.repo_path)
             .env("GIT_EDITOR", "true")
             .stdin(Stdio::null())
             .output()
@@ -449,18 +774,74 @@ impl GitCliService {
     // ==================== Cherry-pick Operations ====================
 
     /// Cherry-pick a single commit
+    #[cfg(any(test, feature = "integration"))]
     pub async fn cherry_pick(&self, commit: &str, no_commit: bool) -> Result<GitCommandResult> {
-        let mut args = vec!["cherry-pick"];
+        self.cherry_pick_with(commit, no_commit, None, false, None)
+            .await
+    }
+
+    /// Cherry-pick a single commit with a mainline parent for merges, signing the new commit
+    /// with `signing_config` when given
+    pub async fn cherry_pick_with(
+        &self,
+        commit: &str,
+        no_commit: bool,
+        mainline: Option<u32>,
+        allow_empty: bool,
+        signing_config: Option<&SigningConfig>,
+    ) -> Result<GitCommandResult> {
+        let mut args = Self::signing_config_args(signing_config);
+        args.push("cherry-pick".to_string());
 
         if no_commit {
-            args.push("-n");
+            args.push("-n".to_string());
         }
 
-        args.push(commit);
+        if let Some(parent) = mainline {
+            args.push("-m".to_string());
+            args.push(parent.to_string());
+        }
 
+        if allow_empty {
+            args.push("--allow-empty".to_string());
+        }
+
+        args.push(commit.to_string());
+
+        let args: Vec<&str> = args.iter().map(String::as_str).collect();
         self.execute(&args).await
     }
 
+    /// `-c` overrides that make git sign new commits with the given config
+    fn signing_config_args(signing_config: Option<&SigningConfig>) -> Vec<String> {
+        let Some(config) = signing_config else {
+            return Vec::new();
+        };
+
+        let format = match config.format {
+            SigningFormat::Gpg => "openpgp",
+            SigningFormat::Ssh => "ssh",
+        };
+        let mut overrides = vec![
+            "commit.gpgsign=true".to_string(),
+            format!("gpg.format={format}"),
+        ];
+        if let Some(key) = &config.signing_key {
+            overrides.push(format!("user.signingkey={key}"));
+        }
+        if let Some(program) = &config.gpg_program {
+            overrides.push(format!("gpg.program={program}"));
+        }
+        if let Some(program) = &config.ssh_program {
+            overrides.push(format!("gpg.ssh.program={program}"));
+        }
+
+        overrides
+            .into_iter()
+            .flat_map(|o| ["-c".to_string(), o])
+            .collect()
+    }
+
     #[cfg(test)]
     /// Cherry-pick a range of commits
     pub async fn cherry_pick_range(
@@ -491,6 +872,27 @@ impl GitCliService {
         self.execute_checked(&["cherry-pick", "--continue"]).await
     }
 
+    /// Commit the resolved cherry-pick, keeping the original message and author
+    pub async fn cherry_pick_continue_with(
+        &self,
+        signing_config: Option<&SigningConfig>,
+    ) -> Result<GitCommandResult> {
+        let mut args = Self::signing_config_args(signing_config);
+        args.extend(["cherry-pick".to_string(), "--continue".to_string()]);
+        let args: Vec<&str> = args.iter().map(String::as_str).collect();
+        self.execute_no_editor(&args).await
+    }
+
+    /// Forget the in-progress cherry-pick without touching the index or worktree
+    pub async fn cherry_pick_quit(&self) -> Result<GitCommandResult> {
+        self.execute_checked(&["cherry-pick", "--quit"]).await
+    }
+
+    /// Reset HEAD to `target`, keeping local changes unrelated to the reset (`git reset --merge`)
+    pub async fn reset_merge(&self, target: &str) -> Result<GitCommandResult> {
+        self.execute_checked(&["reset", "--merge", target]).await
+    }
+
     /// Skip the current commit during cherry-pick
     pub async fn cherry_pick_skip(&self) -> Result<GitCommandResult> {
         self.execute_checked(&["cherry-pick", "--skip"]).await
@@ -530,8 +932,13 @@ impl GitCliService {
 
     /// Mark a file as unmerged (undo resolve)
     pub async fn mark_unresolved(&self, path: &str) -> Result<GitCommandResult> {
-        // Reset the file to re-create conflict markers
-        self.execute(&["checkout", "-m", path]).await
+        // Reset the file to re-create conflict markers, with the base section the
+        // conflict hunk parser reads
+        self.execute_with_config(
+            &["checkout", "-m", path],
+            &[ConfigOverride::diff3_conflict_style()],
+        )
+        .await
     }
 
     /// Get the base version of a conflicted file
@@ -669,11 +1076,164 @@ impl GitCliService {
             ResetMode::Soft => "--soft",
             ResetMode::Mixed => "--mixed",
             ResetMode::Hard => "--hard",
+            ResetMode::Keep => "--keep",
         };
 
         self.execute_checked(&["reset", mode_arg, target]).await
     }
 
+    // ==================== Undo Snapshots ====================
+
+    /// Name of the checked out branch, or `None` when HEAD is detached
+    async fn symbolic_head(&self) -> Result<Option<String>> {
+        let result = self
+            .execute(&["symbolic-ref", "--quiet", "--short", "HEAD"])
+            .await?;
+        let branch = result.stdout.trim();
+        Ok((result.success && !branch.is_empty()).then(|| branch.to_string()))
+    }
+
+    /// Capture HEAD, the current branch and uncommitted changes.
+    /// Changes are saved with `git stash create`, so the stash list is left untouched.
+    pub async fn create_undo_snapshot(&self) -> Result<UndoSnapshot> {
+        let head_oid = self
+            .execute_checked(&["rev-parse", "--verify", "HEAD"])
+            .await?
+            .stdout
+            .trim()
+            .to_string();
+        let branch = self.symbolic_head().await?;
+
+        let stash = self.execute(&["stash", "create"]).await?;
+        let stash_saved = stash.success;
+        if !stash_saved {
+            log::warn!(
+                "Could not snapshot working tree changes: {}",
+                stash.stderr.trim()
+            );
+        }
+        let stash_oid = Some(stash.stdout.trim().to_string()).filter(|oid| !oid.is_empty());
+
+        // Untracked files are not part of `stash create`, so they can't be restored
+        let untracked = self
+            .execute_checked(&["ls-files", "--others", "--exclude-standard"])
+            .await?;
+
+        Ok(UndoSnapshot {
+            head_oid,
+            branch,
+            stash_oid,
+            complete: stash_saved && untracked.stdout.trim().is_empty(),
+        })
+    }
+
+    /// Keep a snapshot's commits reachable under `refs/axis/undo/<id>`, so gc can't
+    /// prune the dangling `stash create` commit while the journal entry exists
+    pub async fn anchor_undo_snapshot(&self, id: i64, snapshot: &UndoSnapshot) -> Result<()> {
+        let target = snapshot.stash_oid.as_deref().unwrap_or(&snapshot.head_oid);
+        let refname = format!("{UNDO_REF_PREFIX}{id}");
+        self.execute_checked(&["update-ref", &refname, target])
+            .await?;
+        Ok(())
+    }
+
+    /// Delete the snapshot refs of journal entries that are no longer in `live_ids`
+    pub async fn prune_undo_refs(&self, live_ids: &[i64]) -> Result<()> {
+        let refs = self
+            .execute_checked(&[
+                "for-each-ref",
+                "--format=%(refname)",
+                UNDO_REF_PREFIX.trim_end_matches('/'),
+            ])
+            .await?;
+        for refname in refs.stdout.lines() {
+            let live = refname
+                .strip_prefix(UNDO_REF_PREFIX)
+                .and_then(|id| id.parse::<i64>().ok())
+                .is_some_and(|id| live_ids.contains(&id));
+            if !live {
+                self.execute_checked(&["update-ref", "-d", refname]).await?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Restore a snapshot: abandon an in-progress rebase, return to the recorded branch,
+    /// reset it to the recorded commit and re-apply saved working tree changes.
+    ///
+    /// Uncommitted changes to tracked files are snapshotted first and returned, so the
+    /// caller can journal them. Changes that can't be saved abort the restore, unless
+    /// they belong to a merge, rebase, cherry-pick or revert that the restore abandons.
+    pub async fn restore_undo_snapshot(
+        &self,
+        snapshot: &UndoSnapshot,
+    ) -> Result<Option<UndoSnapshot>> {
+        let head_commit = format!("{}^{{commit}}", snapshot.head_oid);
+        self.execute_checked(&["cat-file", "-e", &head_commit])
+            .await
+            .map_err(|_| {
+                AxisError::Other(format!(
+                    "Snapshot commit {} no longer exists",
+                    snapshot.head_oid
+                ))
+            })?;
+        if let Some(stash_oid) = &snapshot.stash_oid {
+            let stash_commit = format!("{stash_oid}^{{commit}}");
+            self.execute_checked(&["cat-file", "-e", &stash_commit])
+                .await
+                .map_err(|_| {
+                    AxisError::Other(format!("Snapshot changes {stash_oid} no longer exist"))
+                })?;
+        }
+
+        let status = self
+            .execute_checked(&["status", "--porcelain", "--untracked-files=no"])
+            .await?;
+        let replaced = if status.stdout.trim().is_empty() {
+            None
+        } else {
+            let current = self.create_undo_snapshot().await?;
+            let in_progress = self.is_merging()?
+                || self.is_rebasing()?
+                || self.is_cherry_picking()?
+                || self.is_reverting()?;
+            if current.stash_oid.is_none() && !in_progress {
+                return Err(AxisError::Other(
+                    "Uncommitted changes could not be saved. Commit or stash them before undoing"
+                        .to_string(),
+                ));
+            }
+            Some(current)
+        };
+
+        if self.is_rebasing()? {
+            self.execute_checked(&["rebase", "--quit"]).await?;
+        }
+
+        match &snapshot.branch {
+            Some(branch) => {
+                if self.symbolic_head().await?.as_deref() != Some(branch.as_str()) {
+                    self.execute_checked(&["checkout", "--force", branch])
+                        .await?;
+                }
+            }
+            None => {
+                self.execute_checked(&["checkout", "--force", "--detach", &snapshot.head_oid])
+                    .await?;
+            }
+        }
+
+        self.execute_checked(&["reset", "--hard", &snapshot.head_oid])
+            .await?;
+
+        if let Some(stash_oid) = &snapshot.stash_oid {
+            self.execute_checked(&["stash", "apply", "--index", stash_oid])
+                .await?;
+        }
+
+        Ok(replaced)
+    }
+
     // ==================== Stash Operations ====================
 
     /// List all stash entries
@@ -731,6 +1291,51 @@ impl GitCliService {
         Ok(entries)
     }
 
+    /// Stash only `paths`, including untracked files among them. The paths are
+    /// staged first so the stash records them; other files keep their staged and
+    /// unstaged changes. The index is restored if the stash cannot be created.
+    pub async fn stash_save_selected_files(
+        &self,
+        paths: &[String],
+        message: Option<&str>,
+    ) -> Result<()> {
+        const NO_CHANGES: &str = "No local changes to save";
+
+        if paths.is_empty() {
+            return Err(AxisError::Other("No files selected to stash".to_string()));
+        }
+        let original_index = self.execute_checked(&["write-tree"]).await?;
+        let original_index = original_index.stdout.trim();
+
+        let mut add_args = vec!["add", "-A", "--"];
+        add_args.extend(paths.iter().map(String::as_str));
+        let mut stash_args = vec!["stash", "push"];
+        if let Some(message) = message {
+            stash_args.push("-m");
+            stash_args.push(message);
+        }
+        stash_args.push("--");
+        stash_args.extend(paths.iter().map(String::as_str));
+
+        let stashed = match self.execute_checked(&add_args).await {
+            Ok(_) => self.execute(&stash_args).await,
+            Err(e) => Err(e),
+        };
+        let error = match stashed {
+            Ok(result) if format!("{}{}", result.stdout, result.stderr).contains(NO_CHANGES) => {
+                AxisError::Other("The selected files have no changes to stash".to_string())
+            }
+            Ok(result) if result.success => return Ok(()),
+            Ok(result) => AxisError::GitError(result.stderr.trim().to_string()),
+            Err(e) => e,
+        };
+
+        if let Err(e) = self.execute_checked(&["read-tree", original_index]).await {
+            log::error!("Failed to restore the index after a failed partial stash: {e}");
+        }
+        Err(error)
+    }
+
     /// Create a new stash
     pub async fn stash_save(&self, options: &StashSaveOptions) -> Result<StashResult> {
         let mut args = vec!["stash", "push"];
@@ -902,75 +1507,115 @@ impl GitCliService {
     // ==================== Tag Operations (Remote Only) ====================
     // Local tag operations (list, create, delete) are handled by Git2Service
 
-    /// Push a tag to remote
-    pub async fn tag_push(
+    /// Push or delete the pending tags of a plan with `git push --atomic`, so the remote
+    /// updates every tag or none. Each ref carries a lease on the object the remote had when
+    /// the plan was made, so a tag re-created or moved since then fails the whole push.
+    pub async fn push_tags_atomic(
         &self,
-        name: &str,
         remote: &str,
+        plan: TagPushPlan,
         ssh_credentials: Option<&SshCredentials>,
-    ) -> Result<TagResult> {
+    ) -> Result<TagPushResult> {
+        let mut outcomes = plan.settled;
+        if plan.pending.is_empty() {
+            return Ok(TagPushResult::new(remote, outcomes));
+        }
+
+        let leases: Vec<String> = plan
+            .pending
+            .iter()
+            .map(|p| {
+                format!(
+                    "--force-with-lease=refs/tags/{}:{}",
+                    p.name,
+                    p.remote_oid.as_deref().unwrap_or_default()
+                )
+            })
+            .collect();
+        let mut args = vec!["push", "--atomic", "--porcelain"];
+        args.extend(leases.iter().map(String::as_str));
+        args.push(remote);
+        args.extend(plan.pending.iter().map(|p| p.refspec.as_str()));
+
         let result = self
-            .execute_with_ssh_credentials(
-                &["push", remote, &format!("refs/tags/{name}")],
-                ssh_credentials,
-            )
+            .execute_with_ssh_credentials(&args, ssh_credentials)
             .await?;
-
-        Ok(TagResult {
-            success: result.success,
-            message: if result.success {
-                format!("Tag '{name}' pushed to '{remote}'")
-            } else {
-                result.stderr.trim().to_string()
-            },
-            tag: None,
-        })
+        let reported = parse_push_porcelain(&result.stdout);
+
+        outcomes.extend(plan.pending.iter().map(|pending| {
+            let ref_name = format!("refs/tags/{}", pending.name);
+            match reported.iter().find(|r| r.ref_name == ref_name) {
+                Some(r) if r.flag == '!' => TagPushOutcome::new(
+                    &pending.name,
+                    TagPushStatus::Rejected,
+                    Some(r.summary.clone()),
+                ),
+                Some(r) if r.flag == '=' => {
+                    TagPushOutcome::new(&pending.name, TagPushStatus::UpToDate, None)
+                }
+                _ if !result.success => TagPushOutcome::new(
+                    &pending.name,
+                    TagPushStatus::Rejected,
+                    Some(result.stderr.trim().to_string()),
+                ),
+                _ if pending.is_delete() => {
+                    TagPushOutcome::new(&pending.name, TagPushStatus::Deleted, None)
+                }
+                _ => TagPushOutcome::new(&pending.name, TagPushStatus::Pushed, None),
+            }
+        }));
+        Ok(TagPushResult::new(remote, outcomes))
     }
 
-    /// Push all tags to remote
-    pub async fn tag_push_all(
+    // ==================== Maintenance Operations ====================
+
+    /// Run one maintenance task, reporting git's progress meter as `(phase, current, total)`.
+    /// `on_progress` returns false to cancel, which kills the git process.
+    pub async fn run_maintenance_task(
         &self,
-        remote: &str,
-        ssh_credentials: Option<&SshCredentials>,
-    ) -> Result<TagResult> {
-        let result = self
-            .execute_with_ssh_credentials(&["push", remote, "--tags"], ssh_credentials)
-            .await?;
+        task: MaintenanceTask,
+        aggressive: bool,
+        on_progress: &mut (dyn FnMut(&str, usize, usize) -> bool + Send),
+    ) -> Result<()> {
+        let args: &[&str] = match task {
+            MaintenanceTask::Gc if aggressive => &["gc", "--aggressive"],
+            MaintenanceTask::Gc => &["gc"],
+            MaintenanceTask::Prune => &["prune", PRUNE_EXPIRE, "--progress"],
+            MaintenanceTask::Repack => &["repack", "-a", "-d"],
+            MaintenanceTask::CommitGraph => &["commit-graph", "write", "--reachable", "--progress"],
+        };
+        let mut cmd = self.git_command()?;
+        cmd.args(args).env("GIT_TERMINAL_PROMPT", "0");
 
-        Ok(TagResult {
-            success: result.success,
-            message: if result.success {
-                format!("All tags pushed to '{remote}'")
-            } else {
-                result.stderr.trim().to_string()
-            },
-            tag: None,
+        let result = Self::execute_with_stderr_progress(cmd, "Maintenance", |line| {
+            parse_maintenance_progress(line)
+                .map(|(phase, current, total)| on_progress(&phase, current, total))
         })
+        .await?;
+
+        if result.success {
+            Ok(())
+        } else {
+            Err(AxisError::GitError(format!(
+                "git {} failed: {}",
+                args.join(" "),
+                result.stderr.trim()
+            )))
+        }
     }
 
-    /// Delete a remote tag
-    pub async fn tag_delete_remote(
-        &self,
-        name: &str,
-        remote: &str,
-        ssh_credentials: Option<&SshCredentials>,
-    ) -> Result<TagResult> {
+    /// Unreachable loose objects `git prune` would delete, with their on-disk size
+    pub async fn prune_dry_run(&self) -> Result<PruneDryRunResult> {
         let result = self
-            .execute_with_ssh_credentials(
-                &["push", remote, "--delete", &format!("refs/tags/{name}")],
-                ssh_credentials,
-            )
+            .execute_checked(&["prune", PRUNE_EXPIRE, "--dry-run"])
             .await?;
-
-        Ok(TagResult {
-            success: result.success,
-            message: if result.success {
-                format!("Remote tag '{name}' deleted from '{remote}'")
-            } else {
-                result.stderr.trim().to_string()
-            },
-            tag: None,
-        })
+        let objects_dir = self
+            .execute_checked(&["rev-parse", "--git-path", "objects"])
+            .await?;
+        Ok(parse_prune_dry_run(
+            &result.stdout,
+            &self.repo_path.join(objects_dir.stdout.trim()),
+        ))
     }
 
     // ==================== Submodule Operations ====================
@@ -1491,123 +2136,6 @@ impl GitCliService {
         })
     }
 
-    /// Finish a feature/release/hotfix branch
-    pub async fn gitflow_finish(
-        &self,
-        branch_type: GitFlowBranchType,
-        name: &str,
-        options: &GitFlowFinishOptions,
-    ) -> Result<GitFlowResult> {
-        let config = self
-            .gitflow_config()
-            .await?
-            .ok_or_else(|| AxisError::GitError("Git-flow is not initialized".to_string()))?;
-
-        let prefix = match branch_type {
-            GitFlowBranchType::Feature => &config.feature_prefix,
-            GitFlowBranchType::Release => &config.release_prefix,
-            GitFlowBranchType::Hotfix => &config.hotfix_prefix,
-            GitFlowBranchType::Support => &config.support_prefix,
-        };
-
-        let branch_name = format!("{prefix}{name}");
-        let target_branch = match branch_type {
-            GitFlowBranchType::Feature => config.develop.clone(),
-            GitFlowBranchType::Release | GitFlowBranchType::Hotfix | GitFlowBranchType::Support => {
-                config.master.clone()
-            }
-        };
-
-        // Checkout target branch
-        let checkout = self.execute(&["checkout", &target_branch]).await?;
-        if !checkout.success {
-            return Ok(GitFlowResult {
-                success: false,
-                message: format!(
-                    "Failed to checkout {target_branch}: {}",
-                    checkout.stderr.trim()
-                ),
-                branch: None,
-            });
-        }
-
-        // Merge the branch
-        let mut merge_args = vec!["merge"];
-        if options.no_ff {
-            merge_args.push("--no-ff");
-        }
-        if options.squash {
-            merge_args.push("--squash");
-        }
-        if let Some(ref msg) = options.message {
-            merge_args.push("-m");
-            merge_args.push(msg);
-        }
-        merge_args.push(&branch_name);
-
-        let merge = self.execute(&merge_args).await?;
-        if !merge.success {
-            return Ok(GitFlowResult {
-                success: false,
-                message: format!("Failed to merge: {}", merge.stderr.trim()),
-                branch: None,
-            });
-        }
-
-        // For release/hotfix, also merge to develop and create tag
-        if matches!(
-            branch_type,
-            GitFlowBranchType::Release | GitFlowBranchType::Hotfix
-        ) {
-            // Create tag
-            let tag_name = format!("{}{name}", config.version_tag_prefix);
-            let mut tag_args = vec!["tag", "-a", &tag_name];
-            let tag_msg = options
-                .tag_message
-                .clone()
-                .unwrap_or_else(|| format!("Release {name}"));
-            tag_args.push("-m");
-            tag_args.push(&tag_msg);
-
-            let tag_result = self.execute(&tag_args).await?;
-            if !tag_result.success {
-                return Ok(GitFlowResult {
-                    success: false,
-                    message: format!("Failed to create tag: {}", tag_result.stderr.trim()),
-                    branch: None,
-                });
-            }
-
-            // Merge to develop if not already on develop
-            if target_branch != config.develop {
-                self.execute(&["checkout", &config.develop]).await?;
-                let merge_develop = self.execute(&["merge", "--no-ff", &branch_name]).await?;
-                if !merge_develop.success {
-                    return Ok(GitFlowResult {
-                        success: false,
-                        message: format!(
-                            "Failed to merge to develop: {}",
-                            merge_develop.stderr.trim()
-                        ),
-                        branch: None,
-                    });
-                }
-            }
-        }
-
-        // Delete the branch unless keep is set
-        if !options.keep {
-            let delete_flag = if options.force_delete { "-D" } else { "-d" };
-            self.execute(&["branch", delete_flag, &branch_name]).await?;
-        }
-
-        Ok(GitFlowResult {
-            success: true,
-            message: format!("Finished {branch_type} '{name}'"),
-            branch: Some(target_branch),
-        })
-    }
-
     /// Publish a branch to remote
     pub async fn gitflow_publish(
         &self,
@@ -1668,157 +2196,15 @@ impl GitCliService {
             return Ok(Vec::new());
         }
 
-        let branches: Vec<String> = result
-            .stdout
-            .lines()
-            .map(|line| line.trim().trim_start_matches("* ").to_string())
-            .filter(|name| !name.is_empty())
-            .map(|name| name.strip_prefix(prefix).unwrap_or(&name).to_string())
-            .collect();
-
-        Ok(branches)
-    }
-
-    // ==================== Content Search (grep) ====================
-
-    /// Search for content in the repository
-    pub async fn grep(&self, options: &GrepOptions) -> Result<GrepResult> {
-        let mut args = vec!["grep"];
-
-        if options.ignore_case {
-            args.push("-i");
-        }
-        if options.word_regexp {
-            args.push("-w");
-        }
-        if options.extended_regexp {
-            args.push("-E");
-        }
-        if options.invert_match {
-            args.push("-v");
-        }
-        if options.show_line_numbers {
-            args.push("-n");
-        }
-
-        let max_count_str;
-        if let Some(max) = options.max_count {
-            max_count_str = format!("-m{max}");
-            args.push(&max_count_str);
-        }
-
-        let context_str;
-        if let Some(ctx) = options.context_lines {
-            context_str = format!("-C{ctx}");
-            args.push(&context_str);
-        }
-
-        args.push(&options.pattern);
-
-        for path in &options.paths {
-            args.push(path);
-        }
-
-        let result = self.execute(&args).await?;
-
-        // Parse the output
-        let mut matches = Vec::new();
-        for line in result.stdout.lines() {
-            if line.is_empty() {
-                continue;
-            }
-
-            // Format: path:line_number:content or path:content
-            let parts: Vec<&str> = line.splitn(3, ':').collect();
-            match parts.len() {
-                2 => {
-                    matches.push(GrepMatch {
-                        path: parts[0].to_string(),
-                        line_number: None,
-                        content: parts[1].to_string(),
-                    });
-                }
-                3 => {
-                    let line_num = parts[1].parse().ok();
-                    matches.push(GrepMatch {
-                        path: parts[0].to_string(),
-                        line_number: line_num,
-                        content: parts[2].to_string(),
-                    });
-                }
-                _ => {}
-            }
-        }
-
-        let total = matches.len();
-        Ok(GrepResult {
-            matches,
-            total_matches: total,
-        })
-    }
-
-    /// Search for content in a specific commit or tree
-    pub async fn grep_commit(&self, commit_oid: &str, options: &GrepOptions) -> Result<GrepResult> {
-        let mut args = vec!["grep"];
-
-        if options.ignore_case {
-            args.push("-i");
-        }
-        if options.word_regexp {
-            args.push("-w");
-        }
-        if options.extended_regexp {
-            args.push("-E");
-        }
-        if options.show_line_numbers {
-            args.push("-n");
-        }
-
-        let max_count_str;
-        if let Some(max) = options.max_count {
-            max_count_str = format!("-m{max}");
-            args.push(&max_count_str);
-        }
-
-        args.push(&options.pattern);
-        args.push(commit_oid);
-
-        for path in &options.paths {
-            args.push("--");
-            args.push(path);
-        }
-
-        let result = self.execute(&args).await?;
-
-        let mut matches = Vec::new();
-        for line in result.stdout.lines() {
-            if line.is_empty() {
-                continue;
-            }
-
-            // Format: commit:path:line_number:content
-            let parts: Vec<&str> = line.splitn(4, ':').collect();
-            if parts.len() >= 3 {
-                let line_num = if parts.len() == 4 {
-                    parts[2].parse().ok()
-                } else {
-                    None
-                };
-                let content = if parts.len() == 4 { parts[3] } else { parts[2] };
-
-                matches.push(GrepMatch {
-                    path: parts[1].to_string(),
-                    line_number: line_num,
-                    content: content.to_string(),
-                });
-            }
-        }
-
-        let total = matches.len();
-        Ok(GrepResult {
-            matches,
-            total_matches: total,
-        })
+        let branches: Vec<String> = result
+            .stdout
+            .lines()
+            .map(|line| line.trim().trim_start_matches("* ").to_string())
+            .filter(|name| !name.is_empty())
+            .map(|name| name.strip_prefix(prefix).unwrap_or(&name).to_string())
+            .collect();
+
+        Ok(branches)
     }
 
     // ==================== Hunk Staging Operations ====================
@@ -1826,12 +2212,12 @@ impl GitCliService {
     /// Stage a specific hunk from a file using git apply
     /// The patch parameter should be a valid unified diff patch for the hunk
     pub async fn stage_hunk(&self, patch: &str) -> Result<()> {
-        let mut child = create_command("git")
+        let mut child = self
+            .git_command()?
             .args(["apply", "--cached", "--unidiff-zero", "-"])
             .stdin(Stdio::piped())
             .stdout(Stdio::piped())
             .stderr(Stdio::piped())
-            .current_dir(&self.repo_path)
             .spawn()
             .map_err(AxisError::from)?;
 
@@ -1858,12 +2244,12 @@ impl GitCliService {
     /// Unstage a specific hunk from the index using git apply -R
     /// The patch parameter should be a valid unified diff patch for the hunk
     pub async fn unstage_hunk(&self, patch: &str) -> Result<()> {
-        let mut child = create_command("git")
+        let mut child = self
+            .git_command()?
             .args(["apply", "--cached", "--unidiff-zero", "-R", "-"])
             .stdin(Stdio::piped())
             .stdout(Stdio::piped())
             .stderr(Stdio::piped())
-            .current_dir(&self.repo_path)
             .spawn()
             .map_err(AxisError::from)?;
 
@@ -1890,12 +2276,12 @@ impl GitCliService {
     /// Discard a specific hunk from the working directory using git apply -R
     /// The patch parameter should be a valid unified diff patch for the hunk
     pub async fn discard_hunk(&self, patch: &str) -> Result<()> {
-        let mut child = create_command("git")
+        let mut child = self
+            .git_command()?
             .args(["apply", "--unidiff-zero", "-R", "-"])
             .stdin(Stdio::piped())
             .stdout(Stdio::piped())
             .stderr(Stdio::piped())
-            .current_dir(&self.repo_path)
             .spawn()
             .map_err(AxisError::from)?;
 
@@ -1975,9 +2361,9 @@ impl GitCliService {
             tar_args.push(reference);
 
             // TODO: streaming implementation to avoid loading entire archive into memory
-            let child = create_command("git")
+            let child = self
+                .git_command()?
                 .args(&tar_args)
-                .current_dir(&self.repo_path)
                 .stdout(Stdio::piped())
                 .stderr(Stdio::piped())
                 .spawn()
@@ -2058,6 +2444,49 @@ impl GitCliService {
         })
     }
 
+    /// Write the commits after `from_oid` up to and including `to_oid` as a numbered
+    /// series of patch files in `output_dir`, returning their paths in order
+    pub async fn format_patch_series(
+        &self,
+        from_oid: &str,
+        to_oid: &str,
+        output_dir: &Path,
+    ) -> Result<Vec<String>> {
+        if from_oid.starts_with('-') || to_oid.starts_with('-') {
+            return Err(AxisError::InvalidReference(format!("{from_oid}..{to_oid}")));
+        }
+
+        std::fs::create_dir_all(output_dir).map_err(AxisError::from)?;
+
+        let output_str = output_dir.to_string_lossy();
+        let range = format!("{from_oid}..{to_oid}");
+        let args = ["format-patch", "--numbered", "-o", &output_str, &range];
+
+        let result = self.execute(&args).await?;
+
+        if !result.success {
+            return Err(AxisError::GitError(format!(
+                "Failed to create patch series: {}",
+                result.stderr.trim()
+            )));
+        }
+
+        let patches: Vec<String> = result
+            .stdout
+            .lines()
+            .filter(|line| !line.is_empty())
+            .map(std::string::ToString::to_string)
+            .collect();
+
+        if patches.is_empty() {
+            return Err(AxisError::Other(format!(
+                "No commits between {from_oid} and {to_oid}"
+            )));
+        }
+
+        Ok(patches)
+    }
+
     /// Create a single patch from staged changes or specific commit
     pub async fn create_patch_from_diff(
         &self,
@@ -2302,6 +2731,7 @@ impl GitCliService {
                 good_commits: Vec::new(),
                 skipped_commits: Vec::new(),
                 first_bad_commit: None,
+                log: Vec::new(),
             });
         }
 
@@ -2356,6 +2786,20 @@ impl GitCliService {
             (None, None)
         };
 
+        // Recorded actions, without the comment lines git adds for context
+        let log_result = self.bisect_log().await?;
+        let log = if log_result.success {
+            log_result
+                .stdout
+                .lines()
+                .map(str::trim)
+                .filter(|l| !l.is_empty() && !l.starts_with('#'))
+                .map(std::string::ToString::to_string)
+                .collect()
+        } else {
+            Vec::new()
+        };
+
         Ok(BisectState {
             is_active,
             current_commit,
@@ -2365,6 +2809,7 @@ impl GitCliService {
             good_commits,
             skipped_commits,
             first_bad_commit: None,
+            log,
         })
     }
 
@@ -2701,6 +3146,8 @@ impl GitCliService {
                 is_initialized: false,
                 tracked_patterns_count: 0,
                 lfs_files_count: 0,
+                missing_objects: Vec::new(),
+                cached_objects: Vec::new(),
             });
         }
 
@@ -2722,6 +3169,8 @@ impl GitCliService {
             is_initialized,
             tracked_patterns_count,
             lfs_files_count,
+            missing_objects: Vec::new(),
+            cached_objects: Vec::new(),
         })
     }
 
@@ -2743,40 +3192,97 @@ impl GitCliService {
         }
     }
 
-    /// Track a pattern with LFS
+    /// Track a pattern with LFS.
+    /// Uses `git lfs track` when git-lfs is installed, otherwise writes `.gitattributes` directly.
     pub async fn lfs_track(&self, pattern: &str) -> Result<LfsResult> {
-        let result = self.execute(&["lfs", "track", pattern]).await?;
+        let (lfs_installed, _) = Self::lfs_check_installed().await?;
 
-        if result.success {
-            Ok(LfsResult {
-                success: true,
-                message: format!("Tracking pattern: {pattern}"),
-                affected_files: vec![".gitattributes".to_string()],
-            })
+        if lfs_installed {
+            let result = self.execute(&["lfs", "track", pattern]).await?;
+            if !result.success {
+                return Err(AxisError::GitError(format!(
+                    "Failed to track pattern: {}",
+                    result.stderr.trim()
+                )));
+            }
         } else {
-            Err(AxisError::GitError(format!(
-                "Failed to track pattern: {}",
-                result.stderr.trim()
-            )))
+            self.track_in_gitattributes(pattern)?;
         }
+
+        Ok(LfsResult {
+            success: true,
+            message: format!("Tracking pattern: {pattern}"),
+            affected_files: vec![".gitattributes".to_string()],
+        })
     }
 
-    /// Untrack a pattern from LFS
+    /// Untrack a pattern from LFS.
+    /// Uses `git lfs untrack` when git-lfs is installed, otherwise edits `.gitattributes` directly.
     pub async fn lfs_untrack(&self, pattern: &str) -> Result<LfsResult> {
-        let result = self.execute(&["lfs", "untrack", pattern]).await?;
+        let (lfs_installed, _) = Self::lfs_check_installed().await?;
 
-        if result.success {
-            Ok(LfsResult {
-                success: true,
-                message: format!("Untracked pattern: {pattern}"),
-                affected_files: vec![".gitattributes".to_string()],
-            })
+        if lfs_installed {
+            let result = self.execute(&["lfs", "untrack", pattern]).await?;
+            if !result.success {
+                return Err(AxisError::GitError(format!(
+                    "Failed to untrack pattern: {}",
+                    result.stderr.trim()
+                )));
+            }
         } else {
-            Err(AxisError::GitError(format!(
-                "Failed to untrack pattern: {}",
-                result.stderr.trim()
-            )))
+            self.untrack_in_gitattributes(pattern)?;
+        }
+
+        Ok(LfsResult {
+            success: true,
+            message: format!("Untracked pattern: {pattern}"),
+            affected_files: vec![".gitattributes".to_string()],
+        })
+    }
+
+    /// `lfs_track` fallback: append the pattern to the root `.gitattributes`
+    fn track_in_gitattributes(&self, pattern: &str) -> Result<()> {
+        let escaped = escape_lfs_pattern(pattern);
+        let path = self.repo_path.join(".gitattributes");
+        let mut content = if path.exists() {
+            fs::read_to_string(&path)?
+        } else {
+            String::new()
+        };
+
+        if !parse_lfs_patterns(&content).contains(&escaped) {
+            if !content.is_empty() && !content.ends_with('\n') {
+                content.push('\n');
+            }
+            content.push_str(&format!("{escaped} {LFS_ATTRIBUTES}\n"));
+            fs::write(&path, content)?;
+        }
+        Ok(())
+    }
+
+    /// `lfs_untrack` fallback: remove the pattern's LFS lines from the root `.gitattributes`
+    fn untrack_in_gitattributes(&self, pattern: &str) -> Result<()> {
+        let escaped = escape_lfs_pattern(pattern);
+        let path = self.repo_path.join(".gitattributes");
+        if path.exists() {
+            let content = fs::read_to_string(&path)?;
+            let kept: String = content
+                .lines()
+                .filter(|line| parse_lfs_pattern_line(line).as_deref() != Some(&escaped))
+                .map(|line| format!("{line}\n"))
+                .collect();
+            fs::write(&path, kept)?;
+        }
+        Ok(())
+    }
+
+    /// Patterns with `filter=lfs` in the repository's root `.gitattributes`
+    pub fn get_lfs_tracked_patterns(&self) -> Result<Vec<String>> {
+        let path = self.repo_path.join(".gitattributes");
+        if !path.exists() {
+            return Ok(Vec::new());
         }
+        Ok(parse_lfs_patterns(&fs::read_to_string(&path)?))
     }
 
     /// List all tracked patterns
@@ -2878,6 +3384,36 @@ impl GitCliService {
         Ok(files)
     }
 
+    /// Raw `git lfs ls-files --long` output, one `<oid> <*|-> <path>` line per LFS file
+    pub async fn lfs_ls_files_long(&self) -> Result<String> {
+        let result = self.execute_checked(&["lfs", "ls-files", "--long"]).await?;
+        Ok(result.stdout)
+    }
+
+    /// Parse a git-lfs progress meter line like
+    /// "Downloading LFS objects:  50% (1/2), 1.5 MB | 300 KB/s" into (current, total, bytes)
+    fn parse_lfs_progress(line: &str) -> Option<(usize, usize, usize)> {
+        let line = line.trim();
+        if !line.contains("LFS objects:") {
+            return None;
+        }
+
+        let open = line.find('(')?;
+        let close = open + line[open..].find(')')?;
+        let (current, total) = line[open + 1..close].split_once('/')?;
+        let current = current.trim().parse().ok()?;
+        let total = total.trim().parse().ok()?;
+
+        let bytes = line[close + 1..]
+            .trim_start_matches(',')
+            .split(['|', ','])
+            .next()
+            .map(|size| Self::parse_size(size.trim()))
+            .unwrap_or(0);
+
+        Some((current, total, usize::try_from(bytes).unwrap_or(usize::MAX)))
+    }
+
     /// Parse size string like "1.5 MB" to bytes
     fn parse_size(size_str: &str) -> u64 {
         let parts: Vec<&str> = size_str.split_whitespace().collect();
@@ -2955,22 +3491,27 @@ impl GitCliService {
         })
     }
 
-    /// Pull LFS objects (fetch + checkout)
+    /// Pull LFS objects (fetch + checkout), reporting transfer progress
     pub async fn lfs_pull(
         &self,
         options: &LfsPullOptions,
         ssh_credentials: Option<&SshCredentials>,
+        on_progress: &mut (dyn FnMut(usize, usize, usize) -> bool + Send),
     ) -> Result<LfsResult> {
         let mut args = vec!["lfs", "pull"];
 
-        let remote_owned: String;
+        let include = options.include.join(",");
+        if !include.is_empty() {
+            args.push("--include");
+            args.push(&include);
+        }
+
         if let Some(ref remote) = options.remote {
-            remote_owned = remote.clone();
-            args.push(&remote_owned);
+            args.push(remote);
         }
 
         let result = self
-            .execute_with_ssh_credentials(&args, ssh_credentials)
+            .execute_lfs_transfer(&args, ssh_credentials, on_progress)
             .await?;
 
         Ok(LfsResult {
@@ -2984,11 +3525,12 @@ impl GitCliService {
         })
     }
 
-    /// Push LFS objects to remote
+    /// Push LFS objects to remote, reporting transfer progress
     pub async fn lfs_push(
         &self,
         options: &LfsPushOptions,
         ssh_credentials: Option<&SshCredentials>,
+        on_progress: &mut (dyn FnMut(usize, usize, usize) -> bool + Send),
     ) -> Result<LfsResult> {
         let mut args = vec!["lfs", "push"];
 
@@ -3000,19 +3542,13 @@ impl GitCliService {
             args.push("--dry-run");
         }
 
-        let remote_owned: String;
-        if let Some(ref remote) = options.remote {
-            remote_owned = remote.clone();
-            args.push(&remote_owned);
-        } else {
-            args.push("origin");
-        }
+        args.push(options.remote.as_deref().unwrap_or("origin"));
 
         // Need to specify the branch
         args.push("HEAD");
 
         let result = self
-            .execute_with_ssh_credentials(&args, ssh_credentials)
+            .execute_lfs_transfer(&args, ssh_credentials, on_progress)
             .await?;
 
         Ok(LfsResult {
@@ -3192,6 +3728,60 @@ pub enum ConflictVersion {
     Theirs,
 }
 
+/// Attributes written by `git lfs track`
+const LFS_ATTRIBUTES: &str = "filter=lfs diff=lfs merge=lfs -text";
+
+/// Escape whitespace in a pattern the same way `git lfs track` does
+fn escape_lfs_pattern(pattern: &str) -> String {
+    pattern.trim().replace(' ', "[[:space:]]")
+}
+
+/// One ref line of `git push --porcelain` output
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct PorcelainPushRef {
+    /// `*` new, ` ` fast-forward, `+` forced, `-` deleted, `=` up to date, `!` rejected
+    flag: char,
+    /// Destination ref on the remote
+    ref_name: String,
+    summary: String,
+}
+
+/// Parse the `<flag>\t<from>:<to>\t<summary>` lines of `git push --porcelain`
+fn parse_push_porcelain(stdout: &str) -> Vec<PorcelainPushRef> {
+    stdout
+        .lines()
+        .filter_map(|line| {
+            let mut fields = line.splitn(3, '\t');
+            let flag = fields.next()?.chars().next()?;
+            let (_, to) = fields.next()?.split_once(':')?;
+            Some(PorcelainPushRef {
+                flag,
+                ref_name: to.to_string(),
+                summary: fields.next().unwrap_or_default().to_string(),
+            })
+        })
+        .collect()
+}
+
+/// Pattern of a `.gitattributes` line if it enables the LFS filter
+fn parse_lfs_pattern_line(line: &str) -> Option<String> {
+    let line = line.trim();
+    if line.is_empty() || line.starts_with('#') {
+        return None;
+    }
+
+    let mut parts = line.split_whitespace();
+    let pattern = parts.next()?;
+    parts
+        .any(|attr| attr == "filter=lfs")
+        .then(|| pattern.trim_matches('"').to_string())
+}
+
+/// All LFS-tracked patterns in `.gitattributes` content, in file order
+fn parse_lfs_patterns(content: &str) -> Vec<String> {
+    content.lines().filter_map(parse_lfs_pattern_line).collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -3288,6 +3878,59 @@ mod tests {
             .expect("should create commit");
     }
 
+    #[tokio::test]
+    async fn test_execute_with_config_injects_override_for_one_invocation() {
+        let (_tmp, service) = setup_test_repo();
+        let overrides = [ConfigOverride::new("user.name", "One Off").expect("should be allowed")];
+
+        let result = service
+            .execute_with_config(&["config", "user.name"], &overrides)
+            .await
+            .expect("should run git config");
+        assert_eq!(result.stdout.trim(), "One Off");
+
+        let result = service
+            .execute(&["config", "user.name"])
+            .await
+            .expect("should run git config");
+        assert_eq!(result.stdout.trim(), "Test User");
+    }
+
+    #[tokio::test]
+    async fn test_mark_unresolved_recreates_diff3_markers() {
+        let (tmp, service) = setup_test_repo();
+        create_initial_commit(&tmp);
+        let default_branch = get_default_branch(&tmp);
+
+        create_branch(&tmp, "feature");
+        checkout_branch(&tmp, "feature");
+        add_commit(&tmp, "README.md", "# Feature", "Change on feature");
+        checkout_branch(&tmp, &default_branch);
+        add_commit(&tmp, "README.md", "# Main", "Change on main");
+
+        let result = service
+            .merge("feature", None, false, false, false, false, None)
+            .await
+            .expect("should run merge");
+        assert!(!result.success);
+        service
+            .mark_resolved("README.md")
+            .await
+            .expect("should mark resolved");
+
+        service
+            .mark_unresolved("README.md")
+            .await
+            .expect("should mark unresolved");
+
+        let content = fs::read_to_string(tmp.path().join("README.md")).expect("should read");
+        assert!(
+            content.contains("|||||||"),
+            "missing base section: {content}"
+        );
+        assert!(content.contains("# Test"));
+    }
+
     #[tokio::test]
     async fn test_merge_fast_forward() {
         let (tmp, service) = setup_test_repo();
@@ -3304,7 +3947,7 @@ mod tests {
         // Go back to default branch and merge
         checkout_branch(&tmp, &default_branch);
         let result = service
-            .merge("feature", None, false, false, false, false)
+            .merge("feature", None, false, false, false, false, None)
             .await
             .expect("should merge feature branch");
 
@@ -3332,6 +3975,7 @@ mod tests {
                 false,
                 false,
                 false,
+                None,
             )
             .await
             .expect("should merge with no-ff");
@@ -3856,101 +4500,6 @@ mod tests {
         assert!(features.is_empty());
     }
 
-    // ==================== Grep Tests ====================
-
-    #[tokio::test]
-    async fn test_grep_basic() {
-        let (tmp, service) = setup_test_repo();
-        create_initial_commit(&tmp);
-
-        // Create a file with searchable content
-        fs::write(
-            tmp.path().join("search.txt"),
-            "hello world\ntest line\nhello again",
-        )
-        .expect("should write search.txt");
-        Command::new("git")
-            .args(["add", "search.txt"])
-            .current_dir(tmp.path())
-            .output()
-            .expect("should add search.txt");
-        Command::new("git")
-            .args(["commit", "-m", "Add search file"])
-            .current_dir(tmp.path())
-            .output()
-            .expect("should commit search file");
-
-        let result = service
-            .grep(&GrepOptions {
-                pattern: "hello".to_string(),
-                show_line_numbers: true,
-                ..Default::default()
-            })
-            .await
-            .expect("should grep for pattern");
-
-        assert_eq!(result.total_matches, 2);
-        assert!(result
-            .matches
-            .iter()
-            .any(|m| m.content.contains("hello world")));
-        assert!(result
-            .matches
-            .iter()
-            .any(|m| m.content.contains("hello again")));
-    }
-
-    #[tokio::test]
-    async fn test_grep_case_insensitive() {
-        let (tmp, service) = setup_test_repo();
-        create_initial_commit(&tmp);
-
-        fs::write(
-            tmp.path().join("case.txt"),
-            "Hello World\nHELLO CAPS\nhello lower",
-        )
-        .expect("should write case.txt");
-        Command::new("git")
-            .args(["add", "case.txt"])
-            .current_dir(tmp.path())
-            .output()
-            .expect("should add case.txt");
-        Command::new("git")
-            .args(["commit", "-m", "Add case file"])
-            .current_dir(tmp.path())
-            .output()
-            .expect("should commit case file");
-
-        let result = service
-            .grep(&GrepOptions {
-                pattern: "hello".to_string(),
-                ignore_case: true,
-                show_line_numbers: true,
-                ..Default::default()
-            })
-            .await
-            .expect("should grep case insensitive");
-
-        assert_eq!(result.total_matches, 3);
-    }
-
-    #[tokio::test]
-    async fn test_grep_no_matches() {
-        let (tmp, service) = setup_test_repo();
-        create_initial_commit(&tmp);
-
-        let result = service
-            .grep(&GrepOptions {
-                pattern: "nonexistent_pattern_xyz".to_string(),
-                ..Default::default()
-            })
-            .await
-            .expect("should grep for nonexistent pattern");
-
-        assert_eq!(result.total_matches, 0);
-        assert!(result.matches.is_empty());
-    }
-
     #[tokio::test]
     async fn test_stage_hunk() {
         let (tmp, service) = setup_test_repo();
@@ -4245,9 +4794,19 @@ mod tests {
             .expect("should create tag");
 
         // Try to push without remote (should fail)
-        let result = service.tag_push("v1.0.0", "origin", None).await;
+        let plan = TagPushPlan {
+            settled: Vec::new(),
+            pending: vec![crate::models::PendingTagRef {
+                name: "v1.0.0".to_string(),
+                refspec: "refs/tags/v1.0.0:refs/tags/v1.0.0".to_string(),
+                remote_oid: None,
+            }],
+        };
+        let result = service.push_tags_atomic("origin", plan, None).await;
         // Expect error since no remote exists
-        assert!(result.is_err() || !result.expect("should get result").success);
+        let result = result.expect("should get result");
+        assert!(!result.success);
+        assert_eq!(result.outcomes[0].status, TagPushStatus::Rejected);
     }
 
     // ==================== Archive Tests ====================
@@ -4457,6 +5016,31 @@ mod tests {
 
     // ==================== Cherry-pick Range Tests ====================
 
+    #[test]
+    fn test_signing_config_args() {
+        assert!(GitCliService::signing_config_args(None).is_empty());
+
+        let config = SigningConfig {
+            format: SigningFormat::Ssh,
+            signing_key: Some("~/.ssh/id_ed25519.pub".to_string()),
+            gpg_program: None,
+            ssh_program: Some("ssh-keygen".to_string()),
+        };
+        assert_eq!(
+            GitCliService::signing_config_args(Some(&config)),
+            vec![
+                "-c",
+                "commit.gpgsign=true",
+                "-c",
+                "gpg.format=ssh",
+                "-c",
+                "user.signingkey=~/.ssh/id_ed25519.pub",
+                "-c",
+                "gpg.ssh.program=ssh-keygen",
+            ]
+        );
+    }
+
     #[tokio::test]
     async fn test_cherry_pick_range() {
         let (tmp, service) = setup_test_repo();
@@ -4575,6 +5159,85 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_parse_lfs_patterns() {
+        let content = "# binaries\n*.psd filter=lfs diff=lfs merge=lfs -text\n*.txt text eol=lf\n\n\"assets/big[[:space:]]file.bin\" filter=lfs -text\n*.zip -filter\n";
+        assert_eq!(
+            parse_lfs_patterns(content),
+            vec!["*.psd", "assets/big[[:space:]]file.bin"]
+        );
+    }
+
+    #[test]
+    fn test_parse_push_porcelain() {
+        let stdout = "To /tmp/remote.git\n*\trefs/tags/v2:refs/tags/v2\t[new tag]\n-\t:refs/tags/old\t[deleted]\n!\trefs/tags/v1:refs/tags/v1\t[rejected] (stale info)\nDone\n";
+        let refs = parse_push_porcelain(stdout);
+
+        assert_eq!(refs.len(), 3);
+        assert_eq!(refs[0].flag, '*');
+        assert_eq!(refs[0].ref_name, "refs/tags/v2");
+        assert_eq!(refs[1].flag, '-');
+        assert_eq!(refs[1].ref_name, "refs/tags/old");
+        assert_eq!(refs[2].flag, '!');
+        assert_eq!(refs[2].summary, "[rejected] (stale info)");
+    }
+
+    #[test]
+    fn test_parse_lfs_progress() {
+        assert_eq!(
+            GitCliService::parse_lfs_progress(
+                "Downloading LFS objects:  50% (1/2), 1.5 MB | 300 KB/s"
+            ),
+            Some((1, 2, 1_572_864))
+        );
+        assert_eq!(
+            GitCliService::parse_lfs_progress(
+                "Uploading LFS objects: 100% (3/3), 12 B | 0 B/s, done."
+            ),
+            Some((3, 3, 12))
+        );
+        assert_eq!(
+            GitCliService::parse_lfs_progress("Downloading LFS objects:   0% (0/4)"),
+            Some((0, 4, 0))
+        );
+        assert_eq!(
+            GitCliService::parse_lfs_progress("batch response: Repository not found"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_lfs_track_untrack_fallback_edits_gitattributes() {
+        let (tmp, service) = setup_test_repo();
+        fs::write(tmp.path().join(".gitattributes"), "*.txt text").expect("should write");
+
+        service
+            .track_in_gitattributes("*.psd")
+            .expect("should track");
+        service
+            .track_in_gitattributes("*.psd")
+            .expect("should track again");
+        service
+            .track_in_gitattributes("big file.bin")
+            .expect("should track");
+        assert_eq!(
+            service.get_lfs_tracked_patterns().expect("should list"),
+            vec!["*.psd", "big[[:space:]]file.bin"]
+        );
+
+        service
+            .untrack_in_gitattributes("*.psd")
+            .expect("should untrack");
+        assert_eq!(
+            service.get_lfs_tracked_patterns().expect("should list"),
+            vec!["big[[:space:]]file.bin"]
+        );
+        assert_eq!(
+            fs::read_to_string(tmp.path().join(".gitattributes")).expect("should read"),
+            "*.txt text\nbig[[:space:]]file.bin filter=lfs diff=lfs merge=lfs -text\n"
+        );
+    }
+
     // ==================== Operation Type Tests ====================
 
     #[test]
@@ -1,6 +1,9 @@
 use crate::error::{AxisError, Result};
-use crate::services::{FileWatcher, Git2Service, GitCliService, HookService};
+use crate::services::{
+    FileWatcher, Git2Service, GitCliService, HookService, HookTrustStore, HostCredentialStore,
+};
 use std::path::Path;
+use std::sync::Arc;
 use tauri::AppHandle;
 
 /// Unified service for Git operations, combining:
@@ -11,17 +14,30 @@ use tauri::AppHandle;
 pub struct GitService {
     git2: Git2Service,
     git_cli: GitCliService,
-    hook: HookService,
+    hook: Arc<HookService>,
     watcher: FileWatcher,
 }
 
 impl GitService {
-    /// Create `GitService` for testing (no `FileWatcher`/`AppHandle` required)
-    #[cfg(feature = "integration")]
+    /// Create `GitService` for testing (no `FileWatcher`/`AppHandle` required).
+    /// Every hook is trusted; use `new_for_test_with_hook_trust` to exercise the trust gate.
+    #[cfg(any(test, feature = "integration"))]
     pub fn new_for_test(path: &Path) -> Result<Self> {
+        Self::new_for_test_with_hook_trust(
+            path,
+            Arc::new(crate::services::MemoryHookTrustStore::trusting()),
+        )
+    }
+
+    /// Create `GitService` for testing with the given hook trust store
+    #[cfg(any(test, feature = "integration"))]
+    pub fn new_for_test_with_hook_trust(
+        path: &Path,
+        hook_trust: Arc<dyn HookTrustStore>,
+    ) -> Result<Self> {
         let git2 = Git2Service::open(path)?;
-        let git_cli = GitCliService::new(path);
-        let hook = HookService::new(&git2.repo()?);
+        let hook = Arc::new(HookService::new(&git2.repo()?, hook_trust));
+        let git_cli = GitCliService::new(path).with_hook_gate(hook.clone());
 
         Ok(Self {
             git2,
@@ -32,10 +48,16 @@ impl GitService {
     }
 
     /// Open a repository and create all associated services
-    pub fn open(path: &Path, app_handle: AppHandle, is_active: bool) -> Result<Self> {
-        let git2 = Git2Service::open(path)?;
-        let git_cli = GitCliService::new(path);
-        let hook = HookService::new(&git2.repo()?);
+    pub fn open(
+        path: &Path,
+        app_handle: AppHandle,
+        is_active: bool,
+        hook_trust: Arc<dyn HookTrustStore>,
+        host_credentials: Arc<dyn HostCredentialStore>,
+    ) -> Result<Self> {
+        let git2 = Git2Service::open(path)?.with_host_credentials(host_credentials);
+        let hook = Arc::new(HookService::new(&git2.repo()?, hook_trust));
+        let git_cli = GitCliService::new(path).with_hook_gate(hook.clone());
         let watcher = FileWatcher::new(path.to_path_buf(), app_handle, is_active)
             .map_err(|e| AxisError::Other(format!("Failed to create file watcher: {e}")))?;
 
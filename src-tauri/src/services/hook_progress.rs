@@ -4,8 +4,8 @@ use std::sync::Arc;
 use tauri::AppHandle;
 use tauri_specta::Event;
 
-use crate::events::{HookProgressEvent, HookStage};
-use crate::models::GitHookType;
+use crate::events::{HookProgressEvent, HookStage, HookTrustRequiredEvent};
+use crate::models::{GitHookType, HookTrustRequest};
 use crate::services::ProgressRegistry;
 
 /// Emitter for hook progress events with cancellation support
@@ -61,6 +61,17 @@ impl HookProgressEmitter {
         }
     }
 
+    /// Emit a trust prompt for a hook that is waiting on the user's decision
+    pub fn emit_trust_required(&self, request: &HookTrustRequest) {
+        let event = HookTrustRequiredEvent {
+            operation_id: self.operation_id.clone(),
+            request: request.clone(),
+        };
+        if let Err(e) = event.emit(&self.app_handle) {
+            log::error!("Failed to emit hook trust required event: {e}");
+        }
+    }
+
     /// Emit a cancelled event
     pub fn emit_cancelled(&self, hook_type: GitHookType) {
         let event =
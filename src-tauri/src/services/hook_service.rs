@@ -1,34 +1,44 @@
+use std::collections::HashSet;
 use std::fs;
 use std::io::Write;
 use std::path::{Path, PathBuf};
 use std::process::Stdio;
+use std::sync::Arc;
 use std::time::Duration;
 
+use parking_lot::Mutex;
+
 use strum::IntoEnumIterator;
 use tokio::io::{AsyncReadExt, AsyncWriteExt};
 use tokio::process::Command;
 
 use crate::error::{AxisError, Result};
 use crate::models::{
-    get_hook_templates, GitHookType, HookDetails, HookInfo, HookResult, HookTemplate,
+    get_hook_templates, GitHookType, HookDetails, HookFingerprint, HookInfo, HookResult,
+    HookTemplate, HookTrustDecision, HookTrustRequest,
 };
-use crate::services::{create_command, HookProgressEmitter};
+use crate::services::{create_command, hook_content_hash, HookProgressEmitter, HookTrustStore};
 
 /// Service for Git hook execution and management.
 /// Created once per repository when the repo is opened.
 pub struct HookService {
     repo_path: PathBuf,
     hooks_path: PathBuf,
+    trust_store: Arc<dyn HookTrustStore>,
+    /// `TrustOnce` grants as (hook name, content hash), consumed by the next run
+    trust_once: Mutex<HashSet<(String, String)>>,
 }
 
 impl HookService {
     /// Create a new `HookService` for a repository
-    pub fn new(repo: &git2::Repository) -> Self {
+    pub fn new(repo: &git2::Repository, trust_store: Arc<dyn HookTrustStore>) -> Self {
         let repo_path = repo.workdir().unwrap_or_else(|| repo.path()).to_path_buf();
         let hooks_path = Self::resolve_hooks_path(repo);
         Self {
             repo_path,
             hooks_path,
+            trust_store,
+            trust_once: Mutex::new(HashSet::new()),
         }
     }
 
@@ -107,6 +117,23 @@ impl HookService {
             }
         }
 
+        // Every execution goes through the trust gate
+        match self.check_trust(hook_type, &hook_path) {
+            TrustCheck::Trusted => {}
+            TrustCheck::Never(hash) => {
+                log::info!("Skipping untrusted {hook_type} hook");
+                self.trust_store
+                    .record_untrusted_hook_skip(&self.repo_key(), hook_type, &hash);
+                return HookResult::untrusted(hook_type);
+            }
+            TrustCheck::Undecided(request) => {
+                if let Some(e) = emitter {
+                    e.emit_trust_required(&request);
+                }
+                return HookResult::trust_required(hook_type, request);
+            }
+        }
+
         // Emit running event
         if let Some(e) = emitter {
             e.emit_running(hook_type);
@@ -159,6 +186,8 @@ impl HookService {
                             stderr: String::from_utf8_lossy(&output.stderr).to_string(),
                             skipped: false,
                             cancelled: false,
+                            untrusted: false,
+                            trust_request: None,
                         },
                         Err(e) => {
                             HookResult::error(hook_type, &format!("Failed to wait for hook: {e}"))
@@ -219,6 +248,8 @@ impl HookService {
                         stderr,
                         skipped: false,
                         cancelled: false,
+                        untrusted: false,
+                        trust_request: None,
                     };
                 }
                 Ok(Err(e)) => {
@@ -251,8 +282,191 @@ impl HookService {
         }
     }
 
+    // ==================== Hook Trust ====================
+
+    /// Repository key used for trust decisions
+    fn repo_key(&self) -> String {
+        self.repo_path
+            .to_string_lossy()
+            .trim_end_matches(['/', '\\'])
+            .to_string()
+    }
+
+    /// Decide whether an existing, executable hook may run
+    fn check_trust(&self, hook_type: GitHookType, hook_path: &Path) -> TrustCheck {
+        let hook_name = hook_type.to_string();
+        let hash = match hook_content_hash(hook_path) {
+            Ok(hash) => hash,
+            Err(e) => {
+                // Unreadable content cannot be vouched for, so ask again
+                log::warn!("Failed to hash {hook_name} hook: {e}");
+                return TrustCheck::Undecided(self.trust_request(Some(hook_type)));
+            }
+        };
+
+        if self
+            .trust_once
+            .lock()
+            .remove(&(hook_name.clone(), hash.clone()))
+        {
+            return TrustCheck::Trusted;
+        }
+
+        match self.stored_decision(&hook_name, &hash) {
+            Some(HookTrustDecision::TrustAlways | HookTrustDecision::TrustOnce) => {
+                TrustCheck::Trusted
+            }
+            Some(HookTrustDecision::Never) => TrustCheck::Never(hash),
+            None => TrustCheck::Undecided(self.trust_request(Some(hook_type))),
+        }
+    }
+
+    fn stored_decision(&self, hook_name: &str, hash: &str) -> Option<HookTrustDecision> {
+        self.trust_store
+            .hook_trust_decision(&self.repo_key(), hook_name, hash)
+            .unwrap_or_else(|e| {
+                log::warn!("Failed to read trust decision for {hook_name} hook: {e}");
+                None
+            })
+    }
+
+    /// Enabled hooks whose current content has no decision yet
+    fn undecided_hooks(&self) -> Vec<HookFingerprint> {
+        GitHookType::iter()
+            .filter_map(|hook_type| {
+                let path = self.hooks_path.join(hook_type.to_string());
+                let size = path.metadata().ok().filter(|m| m.is_file())?.len();
+                let content_hash = hook_content_hash(&path).ok()?;
+                self.stored_decision(&hook_type.to_string(), &content_hash)
+                    .is_none()
+                    .then(|| HookFingerprint {
+                        hook_type,
+                        path: path.to_string_lossy().to_string(),
+                        size,
+                        content_hash,
+                    })
+            })
+            .collect()
+    }
+
+    /// Whether git may run the hooks directory itself: every hook git would run is one
+    /// Axis knows and its current content is trusted. Hook names never contain a dot, so
+    /// samples and disabled copies are ignored.
+    pub fn git_may_run_hooks(&self) -> bool {
+        let Ok(entries) = fs::read_dir(&self.hooks_path) else {
+            return true;
+        };
+        entries.flatten().all(|entry| {
+            let path = entry.path();
+            let name = entry.file_name().to_string_lossy().to_string();
+            if name.contains('.') || !path.is_file() {
+                return true;
+            }
+            #[cfg(unix)]
+            {
+                use std::os::unix::fs::PermissionsExt;
+                if path
+                    .metadata()
+                    .is_ok_and(|meta| meta.permissions().mode() & 0o111 == 0)
+                {
+                    return true;
+                }
+            }
+            if name.parse::<GitHookType>().is_err() {
+                return false;
+            }
+            matches!(
+                hook_content_hash(&path)
+                    .ok()
+                    .and_then(|hash| self.stored_decision(&name, &hash)),
+                Some(HookTrustDecision::TrustAlways | HookTrustDecision::TrustOnce)
+            )
+        })
+    }
+
+    /// Build the trust prompt for a hook that is about to run
+    fn trust_request(&self, hook_type: Option<GitHookType>) -> HookTrustRequest {
+        HookTrustRequest {
+            repo_path: self.repo_key(),
+            hook_type,
+            hooks: self.undecided_hooks(),
+        }
+    }
+
+    /// The trust prompt for this repository, or `None` when every hook has a decision
+    pub fn pending_trust_request(&self) -> Option<HookTrustRequest> {
+        let request = self.trust_request(None);
+        (!request.hooks.is_empty()).then_some(request)
+    }
+
+    /// Apply the user's decision to the hooks listed in a trust prompt. Hooks whose content
+    /// changed since the prompt are left undecided so they prompt again.
+    pub fn resolve_hook_trust(
+        &self,
+        hooks: &[HookFingerprint],
+        decision: HookTrustDecision,
+    ) -> Result<()> {
+        let repo_key = self.repo_key();
+        for hook in hooks {
+            let hook_name = hook.hook_type.to_string();
+            let path = self.hooks_path.join(&hook_name);
+            match hook_content_hash(&path) {
+                Ok(hash) if hash == hook.content_hash => {}
+                Ok(_) => {
+                    log::warn!("{hook_name} hook changed since the trust prompt, ignoring");
+                    continue;
+                }
+                Err(e) => {
+                    log::warn!("Failed to hash {hook_name} hook: {e}");
+                    continue;
+                }
+            }
+
+            if decision == HookTrustDecision::TrustOnce {
+                self.trust_once
+                    .lock()
+                    .insert((hook_name, hook.content_hash.clone()));
+            } else {
+                self.trust_store.set_hook_trust_decision(
+                    &repo_key,
+                    &hook_name,
+                    &hook.content_hash,
+                    decision,
+                )?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Trust content the user wrote through Axis itself
+    fn trust_authored_hook(&self, hook_type: GitHookType, path: &Path) {
+        let hook_name = hook_type.to_string();
+        let result = hook_content_hash(path).and_then(|hash| {
+            self.trust_store.set_hook_trust_decision(
+                &self.repo_key(),
+                &hook_name,
+                &hash,
+                HookTrustDecision::TrustAlways,
+            )
+        });
+        if let Err(e) = result {
+            log::warn!("Failed to trust edited {hook_name} hook: {e}");
+        }
+    }
+
     // ==================== Hook Runners ====================
 
+    /// Run a hook manually with the given arguments
+    pub async fn run_hook(
+        &self,
+        hook_type: GitHookType,
+        args: &[String],
+        emitter: Option<&HookProgressEmitter>,
+    ) -> HookResult {
+        let args: Vec<&str> = args.iter().map(String::as_str).collect();
+        self.execute_hook(hook_type, &args, None, emitter).await
+    }
+
     /// Run pre-commit hook with optional progress emitter
     pub async fn run_pre_commit(&self, emitter: Option<&HookProgressEmitter>) -> HookResult {
         self.execute_hook(GitHookType::PreCommit, &[], None, emitter)
@@ -319,6 +533,12 @@ impl HookService {
         .await
     }
 
+    /// Run pre-merge-commit hook with optional progress emitter
+    pub async fn run_pre_merge_commit(&self, emitter: Option<&HookProgressEmitter>) -> HookResult {
+        self.execute_hook(GitHookType::PreMergeCommit, &[], None, emitter)
+            .await
+    }
+
     /// Run post-merge hook with optional progress emitter
     pub async fn run_post_merge(
         &self,
@@ -469,6 +689,7 @@ impl HookService {
             fs::set_permissions(&hook_path, perms).map_err(AxisError::from)?;
         }
 
+        self.trust_authored_hook(hook_type, &hook_path);
         Ok(())
     }
 
@@ -496,6 +717,7 @@ impl HookService {
             }
         }
 
+        self.trust_authored_hook(hook_type, Path::new(&info.path));
         Ok(())
     }
 
@@ -530,6 +752,21 @@ impl HookService {
         }
     }
 
+    /// Enable or disable a hook, returning whether the state changed
+    pub fn set_hook_enabled(&self, hook_type: GitHookType, enabled: bool) -> Result<bool> {
+        let info = self.get_hook_info(hook_type);
+
+        if !info.exists {
+            return Err(AxisError::Other(format!("Hook {hook_type} does not exist")));
+        }
+        if info.enabled == enabled {
+            return Ok(false);
+        }
+
+        self.toggle_hook(hook_type)?;
+        Ok(true)
+    }
+
     /// Get available hook templates
     #[must_use]
     pub fn get_templates() -> Vec<HookTemplate> {
@@ -546,9 +783,18 @@ impl HookService {
     }
 }
 
+/// Outcome of the trust gate for one hook invocation
+enum TrustCheck {
+    Trusted,
+    /// Never trusted; carries the content hash for the operation log
+    Never(String),
+    Undecided(HookTrustRequest),
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::services::MemoryHookTrustStore;
     use tempfile::TempDir;
 
     fn setup_test_repo() -> (TempDir, git2::Repository) {
@@ -562,7 +808,7 @@ mod tests {
     #[test]
     fn test_hook_service_new() {
         let (_tmp, repo) = setup_test_repo();
-        let service = HookService::new(&repo);
+        let service = HookService::new(&repo, Arc::new(MemoryHookTrustStore::new()));
 
         // Use canonical paths to handle symlinks (e.g., /var -> /private/var on macOS)
         let expected_path = repo.workdir().expect("should have workdir");
@@ -582,7 +828,7 @@ mod tests {
     #[test]
     fn test_hooks_path_default() {
         let (_tmp, repo) = setup_test_repo();
-        let service = HookService::new(&repo);
+        let service = HookService::new(&repo, Arc::new(MemoryHookTrustStore::new()));
 
         // Default hooks path should be .git/hooks
         let expected = repo.path().join("hooks");
@@ -594,7 +840,7 @@ mod tests {
     #[test]
     fn test_list_hooks_empty_repo() {
         let (_tmp, repo) = setup_test_repo();
-        let service = HookService::new(&repo);
+        let service = HookService::new(&repo, Arc::new(MemoryHookTrustStore::new()));
 
         let hooks = service.list_hooks();
 
@@ -611,7 +857,7 @@ mod tests {
     #[test]
     fn test_list_hooks_contains_all_types() {
         let (_tmp, repo) = setup_test_repo();
-        let service = HookService::new(&repo);
+        let service = HookService::new(&repo, Arc::new(MemoryHookTrustStore::new()));
 
         let hooks = service.list_hooks();
         let hook_types: Vec<_> = hooks.iter().map(|h| h.hook_type).collect();
@@ -627,7 +873,7 @@ mod tests {
     #[test]
     fn test_get_hook_info_nonexistent() {
         let (_tmp, repo) = setup_test_repo();
-        let service = HookService::new(&repo);
+        let service = HookService::new(&repo, Arc::new(MemoryHookTrustStore::new()));
 
         let info = service.get_hook_info(GitHookType::PreCommit);
 
@@ -640,7 +886,7 @@ mod tests {
     #[test]
     fn test_get_hook_info_existing() {
         let (_tmp, repo) = setup_test_repo();
-        let service = HookService::new(&repo);
+        let service = HookService::new(&repo, Arc::new(MemoryHookTrustStore::new()));
 
         // Create hooks directory and hook file
         fs::create_dir_all(&service.hooks_path).expect("should create hooks dir");
@@ -668,7 +914,7 @@ mod tests {
     #[test]
     fn test_get_hook_info_disabled() {
         let (_tmp, repo) = setup_test_repo();
-        let service = HookService::new(&repo);
+        let service = HookService::new(&repo, Arc::new(MemoryHookTrustStore::new()));
 
         // Create hooks directory and disabled hook file
         fs::create_dir_all(&service.hooks_path).expect("should create hooks dir");
@@ -686,7 +932,7 @@ mod tests {
     #[test]
     fn test_get_hook_details_nonexistent() {
         let (_tmp, repo) = setup_test_repo();
-        let service = HookService::new(&repo);
+        let service = HookService::new(&repo, Arc::new(MemoryHookTrustStore::new()));
 
         let result = service.get_hook_details(GitHookType::PreCommit);
         let details = result.expect("should get details");
@@ -698,7 +944,7 @@ mod tests {
     #[test]
     fn test_get_hook_details_existing() {
         let (_tmp, repo) = setup_test_repo();
-        let service = HookService::new(&repo);
+        let service = HookService::new(&repo, Arc::new(MemoryHookTrustStore::new()));
 
         fs::create_dir_all(&service.hooks_path).expect("should create hooks dir");
         let hook_content = "#!/bin/sh\necho 'Hello from hook'";
@@ -717,7 +963,7 @@ mod tests {
     #[test]
     fn test_create_hook() {
         let (_tmp, repo) = setup_test_repo();
-        let service = HookService::new(&repo);
+        let service = HookService::new(&repo, Arc::new(MemoryHookTrustStore::new()));
 
         let content = "#!/bin/sh\nexit 0";
         let result = service.create_hook(GitHookType::PreCommit, content);
@@ -743,7 +989,7 @@ mod tests {
     #[test]
     fn test_create_hook_already_exists() {
         let (_tmp, repo) = setup_test_repo();
-        let service = HookService::new(&repo);
+        let service = HookService::new(&repo, Arc::new(MemoryHookTrustStore::new()));
 
         // Create first hook
         let content = "#!/bin/sh\nexit 0";
@@ -765,7 +1011,7 @@ mod tests {
     #[test]
     fn test_update_hook() {
         let (_tmp, repo) = setup_test_repo();
-        let service = HookService::new(&repo);
+        let service = HookService::new(&repo, Arc::new(MemoryHookTrustStore::new()));
 
         // Create hook first
         let initial = "#!/bin/sh\nexit 0";
@@ -786,7 +1032,7 @@ mod tests {
     #[test]
     fn test_update_hook_nonexistent() {
         let (_tmp, repo) = setup_test_repo();
-        let service = HookService::new(&repo);
+        let service = HookService::new(&repo, Arc::new(MemoryHookTrustStore::new()));
 
         let result = service.update_hook(GitHookType::PreCommit, "content");
         assert!(result.is_err());
@@ -801,7 +1047,7 @@ mod tests {
     #[test]
     fn test_delete_hook() {
         let (_tmp, repo) = setup_test_repo();
-        let service = HookService::new(&repo);
+        let service = HookService::new(&repo, Arc::new(MemoryHookTrustStore::new()));
 
         // Create hook first
         service
@@ -820,7 +1066,7 @@ mod tests {
     #[test]
     fn test_delete_hook_nonexistent() {
         let (_tmp, repo) = setup_test_repo();
-        let service = HookService::new(&repo);
+        let service = HookService::new(&repo, Arc::new(MemoryHookTrustStore::new()));
 
         let result = service.delete_hook(GitHookType::PreCommit);
         assert!(result.is_err());
@@ -835,7 +1081,7 @@ mod tests {
     #[test]
     fn test_toggle_hook_disable() {
         let (_tmp, repo) = setup_test_repo();
-        let service = HookService::new(&repo);
+        let service = HookService::new(&repo, Arc::new(MemoryHookTrustStore::new()));
 
         // Create enabled hook
         service
@@ -856,7 +1102,7 @@ mod tests {
     #[test]
     fn test_toggle_hook_enable() {
         let (_tmp, repo) = setup_test_repo();
-        let service = HookService::new(&repo);
+        let service = HookService::new(&repo, Arc::new(MemoryHookTrustStore::new()));
 
         // Create disabled hook
         fs::create_dir_all(&service.hooks_path).expect("should create dir");
@@ -876,12 +1122,58 @@ mod tests {
     #[test]
     fn test_toggle_hook_nonexistent() {
         let (_tmp, repo) = setup_test_repo();
-        let service = HookService::new(&repo);
+        let service = HookService::new(&repo, Arc::new(MemoryHookTrustStore::new()));
 
         let result = service.toggle_hook(GitHookType::PreCommit);
         assert!(result.is_err());
     }
 
+    // ==================== Set Hook Enabled Tests ====================
+
+    #[test]
+    fn test_set_hook_enabled_disables_and_enables() {
+        let (_tmp, repo) = setup_test_repo();
+        let service = HookService::new(&repo, Arc::new(MemoryHookTrustStore::new()));
+
+        service
+            .create_hook(GitHookType::PreCommit, "#!/bin/sh\nexit 0")
+            .expect("should create hook");
+
+        assert!(service
+            .set_hook_enabled(GitHookType::PreCommit, false)
+            .expect("should disable"));
+        assert!(!service.get_hook_info(GitHookType::PreCommit).enabled);
+
+        assert!(service
+            .set_hook_enabled(GitHookType::PreCommit, true)
+            .expect("should enable"));
+        assert!(service.get_hook_info(GitHookType::PreCommit).enabled);
+    }
+
+    #[test]
+    fn test_set_hook_enabled_is_idempotent() {
+        let (_tmp, repo) = setup_test_repo();
+        let service = HookService::new(&repo, Arc::new(MemoryHookTrustStore::new()));
+
+        service
+            .create_hook(GitHookType::PreCommit, "#!/bin/sh\nexit 0")
+            .expect("should create hook");
+
+        assert!(!service
+            .set_hook_enabled(GitHookType::PreCommit, true)
+            .expect("should be a no-op"));
+        assert!(service.get_hook_info(GitHookType::PreCommit).enabled);
+    }
+
+    #[test]
+    fn test_set_hook_enabled_nonexistent() {
+        let (_tmp, repo) = setup_test_repo();
+        let service = HookService::new(&repo, Arc::new(MemoryHookTrustStore::new()));
+
+        let result = service.set_hook_enabled(GitHookType::PreCommit, true);
+        assert!(result.is_err());
+    }
+
     // ==================== Templates Tests ====================
 
     #[test]
@@ -913,7 +1205,7 @@ mod tests {
     #[test]
     fn test_hook_exists_false() {
         let (_tmp, repo) = setup_test_repo();
-        let service = HookService::new(&repo);
+        let service = HookService::new(&repo, Arc::new(MemoryHookTrustStore::new()));
 
         assert!(!service.hook_exists(GitHookType::PreCommit));
     }
@@ -921,7 +1213,7 @@ mod tests {
     #[test]
     fn test_hook_exists_true() {
         let (_tmp, repo) = setup_test_repo();
-        let service = HookService::new(&repo);
+        let service = HookService::new(&repo, Arc::new(MemoryHookTrustStore::new()));
 
         service
             .create_hook(GitHookType::PreCommit, "#!/bin/sh\nexit 0")
@@ -933,7 +1225,7 @@ mod tests {
     #[test]
     fn test_hook_exists_directory_not_file() {
         let (_tmp, repo) = setup_test_repo();
-        let service = HookService::new(&repo);
+        let service = HookService::new(&repo, Arc::new(MemoryHookTrustStore::new()));
 
         // Create a directory with the hook name
         fs::create_dir_all(service.hooks_path.join("pre-commit")).expect("should create dir");
@@ -946,7 +1238,7 @@ mod tests {
     #[tokio::test]
     async fn test_execute_hook_nonexistent() {
         let (_tmp, repo) = setup_test_repo();
-        let service = HookService::new(&repo);
+        let service = HookService::new(&repo, Arc::new(MemoryHookTrustStore::new()));
 
         let result = service.run_pre_commit(None).await;
 
@@ -958,7 +1250,7 @@ mod tests {
     #[cfg(unix)]
     async fn test_execute_hook_success() {
         let (_tmp, repo) = setup_test_repo();
-        let service = HookService::new(&repo);
+        let service = HookService::new(&repo, Arc::new(MemoryHookTrustStore::new()));
 
         // Create a simple hook that succeeds
         let hook_content = "#!/bin/sh\necho 'Success'\nexit 0";
@@ -978,7 +1270,7 @@ mod tests {
     #[cfg(unix)]
     async fn test_execute_hook_failure() {
         let (_tmp, repo) = setup_test_repo();
-        let service = HookService::new(&repo);
+        let service = HookService::new(&repo, Arc::new(MemoryHookTrustStore::new()));
 
         // Create a hook that fails
         let hook_content = "#!/bin/sh\necho 'Error' >&2\nexit 1";
@@ -994,13 +1286,36 @@ mod tests {
         assert!(result.stderr.contains("Error"));
     }
 
+    #[tokio::test]
+    #[cfg(unix)]
+    async fn test_run_hook_passes_arguments() {
+        let (_tmp, repo) = setup_test_repo();
+        let service = HookService::new(&repo, Arc::new(MemoryHookTrustStore::new()));
+
+        service
+            .create_hook(
+                GitHookType::PostCheckout,
+                "#!/bin/sh\necho \"$1 $2 $3\"\nexit 0",
+            )
+            .expect("should create hook");
+
+        let args = vec!["abc".to_string(), "def".to_string(), "1".to_string()];
+        let result = service
+            .run_hook(GitHookType::PostCheckout, &args, None)
+            .await;
+
+        assert!(!result.skipped);
+        assert!(result.success);
+        assert!(result.stdout.contains("abc def 1"));
+    }
+
     #[test]
     #[cfg(unix)]
     fn test_hook_not_executable_info() {
         use std::os::unix::fs::PermissionsExt;
 
         let (_tmp, repo) = setup_test_repo();
-        let service = HookService::new(&repo);
+        let service = HookService::new(&repo, Arc::new(MemoryHookTrustStore::new()));
 
         // Create hooks directory and non-executable hook
         fs::create_dir_all(&service.hooks_path).expect("should create hooks dir");
@@ -1028,7 +1343,7 @@ mod tests {
     #[tokio::test]
     async fn test_run_post_merge() {
         let (_tmp, repo) = setup_test_repo();
-        let service = HookService::new(&repo);
+        let service = HookService::new(&repo, Arc::new(MemoryHookTrustStore::new()));
 
         // No hook exists, should be skipped
         let result = service.run_post_merge(false, None).await;
@@ -1038,7 +1353,7 @@ mod tests {
     #[tokio::test]
     async fn test_run_post_checkout() {
         let (_tmp, repo) = setup_test_repo();
-        let service = HookService::new(&repo);
+        let service = HookService::new(&repo, Arc::new(MemoryHookTrustStore::new()));
 
         let result = service
             .run_post_checkout("abc123", "def456", true, None)
@@ -1049,7 +1364,7 @@ mod tests {
     #[tokio::test]
     async fn test_run_pre_rebase() {
         let (_tmp, repo) = setup_test_repo();
-        let service = HookService::new(&repo);
+        let service = HookService::new(&repo, Arc::new(MemoryHookTrustStore::new()));
 
         let result = service
             .run_pre_rebase("upstream", Some("feature"), None)
@@ -1060,7 +1375,7 @@ mod tests {
     #[tokio::test]
     async fn test_run_pre_push() {
         let (_tmp, repo) = setup_test_repo();
-        let service = HookService::new(&repo);
+        let service = HookService::new(&repo, Arc::new(MemoryHookTrustStore::new()));
 
         let refs = "refs/heads/main abc123 refs/heads/main def456\n";
         let result = service
@@ -1072,7 +1387,7 @@ mod tests {
     #[tokio::test]
     async fn test_run_post_rewrite() {
         let (_tmp, repo) = setup_test_repo();
-        let service = HookService::new(&repo);
+        let service = HookService::new(&repo, Arc::new(MemoryHookTrustStore::new()));
 
         let rewrites = "abc123 def456\n";
         let result = service.run_post_rewrite("rebase", rewrites, None).await;
@@ -0,0 +1,160 @@
+use std::path::Path;
+
+use sha2::{Digest, Sha256};
+
+use crate::error::Result;
+use crate::models::{GitHookType, HookTrustDecision};
+
+/// Persistent hook trust decisions, keyed by repository, hook name and hook content hash.
+/// `TrustOnce` grants are never stored here; `HookService` keeps them in memory.
+pub trait HookTrustStore: Send + Sync {
+    /// The stored decision for this exact hook content, if any
+    fn hook_trust_decision(
+        &self,
+        repo_path: &str,
+        hook_name: &str,
+        content_hash: &str,
+    ) -> Result<Option<HookTrustDecision>>;
+
+    /// Persist a `TrustAlways` or `Never` decision
+    fn set_hook_trust_decision(
+        &self,
+        repo_path: &str,
+        hook_name: &str,
+        content_hash: &str,
+        decision: HookTrustDecision,
+    ) -> Result<()>;
+
+    /// Record that an invocation was skipped because the hook is never trusted
+    fn record_untrusted_hook_skip(
+        &self,
+        repo_path: &str,
+        hook_type: GitHookType,
+        content_hash: &str,
+    );
+}
+
+/// SHA-256 of a hook file, hex encoded
+pub fn hook_content_hash(path: &Path) -> Result<String> {
+    let content = std::fs::read(path)?;
+    Ok(format!("{:x}", Sha256::digest(&content)))
+}
+
+/// In-memory `HookTrustStore` for tests
+#[cfg(any(test, feature = "integration"))]
+#[derive(Default)]
+pub struct MemoryHookTrustStore {
+    trust_all: bool,
+    decisions:
+        parking_lot::Mutex<std::collections::HashMap<(String, String, String), HookTrustDecision>>,
+    skips: parking_lot::Mutex<Vec<(GitHookType, String)>>,
+}
+
+#[cfg(any(test, feature = "integration"))]
+impl MemoryHookTrustStore {
+    /// A store with no decisions: every hook prompts
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// A store that trusts every hook, for tests that only exercise hook execution
+    pub fn trusting() -> Self {
+        Self {
+            trust_all: true,
+            ..Self::default()
+        }
+    }
+
+    /// Hooks skipped as untrusted, with their content hashes
+    pub fn untrusted_skips(&self) -> Vec<(GitHookType, String)> {
+        self.skips.lock().clone()
+    }
+}
+
+#[cfg(any(test, feature = "integration"))]
+impl HookTrustStore for MemoryHookTrustStore {
+    fn hook_trust_decision(
+        &self,
+        repo_path: &str,
+        hook_name: &str,
+        content_hash: &str,
+    ) -> Result<Option<HookTrustDecision>> {
+        if self.trust_all {
+            return Ok(Some(HookTrustDecision::TrustAlways));
+        }
+        let key = (
+            repo_path.to_string(),
+            hook_name.to_string(),
+            content_hash.to_string(),
+        );
+        Ok(self.decisions.lock().get(&key).copied())
+    }
+
+    fn set_hook_trust_decision(
+        &self,
+        repo_path: &str,
+        hook_name: &str,
+        content_hash: &str,
+        decision: HookTrustDecision,
+    ) -> Result<()> {
+        let key = (
+            repo_path.to_string(),
+            hook_name.to_string(),
+            content_hash.to_string(),
+        );
+        self.decisions.lock().insert(key, decision);
+        Ok(())
+    }
+
+    fn record_untrusted_hook_skip(
+        &self,
+        _repo_path: &str,
+        hook_type: GitHookType,
+        content_hash: &str,
+    ) {
+        self.skips
+            .lock()
+            .push((hook_type, content_hash.to_string()));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_hook_content_hash_changes_with_content() {
+        let tmp = TempDir::new().expect("should create temp dir");
+        let path = tmp.path().join("pre-commit");
+
+        std::fs::write(&path, "#!/bin/sh\nexit 0\n").expect("should write");
+        let first = hook_content_hash(&path).expect("should hash");
+        std::fs::write(&path, "#!/bin/sh\nexit 1\n").expect("should write");
+        let second = hook_content_hash(&path).expect("should hash");
+
+        assert_eq!(first.len(), 64);
+        assert_ne!(first, second);
+    }
+
+    #[test]
+    fn test_memory_store_keys_by_hash() {
+        let store = MemoryHookTrustStore::new();
+        store
+            .set_hook_trust_decision("/repo", "pre-commit", "aaa", HookTrustDecision::Never)
+            .expect("should store");
+
+        assert_eq!(
+            store
+                .hook_trust_decision("/repo", "pre-commit", "aaa")
+                .expect("should read"),
+            Some(HookTrustDecision::Never)
+        );
+        assert_eq!(
+            store
+                .hook_trust_decision("/repo", "pre-commit", "bbb")
+                .expect("should read"),
+            None
+        );
+    }
+}
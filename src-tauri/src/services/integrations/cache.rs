@@ -51,7 +51,6 @@ impl<T: Clone> TtlCache<T> {
         );
     }
 
-    #[cfg(test)]
     /// Remove a value from the cache
     pub fn remove(&self, key: &str) {
         self.entries.write().remove(key);
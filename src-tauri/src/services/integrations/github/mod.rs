@@ -8,16 +8,18 @@ use octocrab::params;
 use octocrab::Octocrab;
 use parking_lot::RwLock;
 use serde::de::DeserializeOwned;
+use std::future::Future;
 use std::sync::Arc;
 use std::time::Duration;
 
 use crate::error::{AxisError, Result};
 use crate::models::{
-    CIRun, CIRunStatus, CiRunsPage, CommitStatus, CommitStatusState, CreateIssueOptions,
-    CreatePrOptions, IntegrationCommit, IntegrationLabel, IntegrationRepoInfo, IntegrationStatus,
-    IntegrationUser, Issue, IssueDetail, IssueState, IssuesPage, MergeMethod, MergePrOptions,
-    Notification, NotificationReason, NotificationSubjectType, NotificationsPage, PrState,
-    ProviderType, PullRequest, PullRequestDetail, PullRequestsPage,
+    BranchProtection, CIConclusion, CIRun, CIRunStatus, CiRunsPage, CommitStatus,
+    CommitStatusState, CreateIssueOptions, CreatePrOptions, IntegrationCommit, IntegrationLabel,
+    IntegrationRepoInfo, IntegrationStatus, IntegrationUser, Issue, IssueDetail, IssueState,
+    IssuesPage, MergeMethod, MergePrOptions, Notification, NotificationReason,
+    NotificationSubjectType, NotificationsPage, PrState, ProviderType, PullRequest,
+    PullRequestDetail, PullRequestsPage, UnreadNotificationCount,
 };
 use crate::services::integrations::{IntegrationProvider, TtlCache};
 
@@ -30,6 +32,10 @@ const CACHE_TTL_SHORT: Duration = Duration::from_secs(30); // For frequently cha
 const CACHE_TTL_MEDIUM: Duration = Duration::from_secs(60); // For PRs, issues, CI runs
 const CACHE_TTL_LONG: Duration = Duration::from_secs(300); // For repo info
 
+// Unread counting: GitHub caps notification pages at 50 items
+const UNREAD_PER_PAGE: u8 = 50;
+const UNREAD_MAX_PAGES: u32 = 10;
+
 /// Type alias for the delete secret callback function
 type DeleteSecretFn = Box<dyn Fn(&str) -> Result<()> + Send + Sync>;
 
@@ -101,7 +107,9 @@ impl From<octocrab::models::pulls::PullRequest> for PullRequest {
             },
             author: pr.user.map(|a| (*a).into()).unwrap_or_default(),
             source_branch: pr.head.ref_field.clone(),
+            source_repo: pr.head.repo.as_ref().and_then(|r| r.full_name.clone()),
             target_branch: pr.base.ref_field.clone(),
+            head_sha: Some(pr.head.sha.clone()),
             draft: pr.draft.unwrap_or(false),
             created_at: pr.created_at.unwrap_or_else(Utc::now),
             updated_at: pr.updated_at.unwrap_or_else(Utc::now),
@@ -250,6 +258,7 @@ pub struct GitHubProvider {
     delete_secret: DeleteSecretFn,
     // Caches
     pr_cache: TtlCache<PullRequestsPage>,
+    pr_detail_cache: TtlCache<PullRequestDetail>,
     issue_cache: TtlCache<IssuesPage>,
     ci_cache: TtlCache<CiRunsPage>,
     notification_cache: TtlCache<NotificationsPage>,
@@ -257,6 +266,10 @@ pub struct GitHubProvider {
     commit_cache: TtlCache<IntegrationCommit>,
     commit_status_cache: TtlCache<CommitStatus>,
     label_cache: TtlCache<Vec<IntegrationLabel>>,
+    protection_cache: TtlCache<BranchProtection>,
+    unread_count_cache: TtlCache<UnreadNotificationCount>,
+    /// Unread thread ID -> (owner/repo, reason), used to adjust cached counts on mark-read
+    unread_threads: TtlCache<(String, NotificationReason)>,
 }
 
 impl GitHubProvider {
@@ -278,6 +291,7 @@ impl GitHubProvider {
             client: RwLock::new(client),
             delete_secret: Box::new(delete_secret),
             pr_cache: TtlCache::new(CACHE_TTL_MEDIUM),
+            pr_detail_cache: TtlCache::new(CACHE_TTL_MEDIUM),
             issue_cache: TtlCache::new(CACHE_TTL_MEDIUM),
             ci_cache: TtlCache::new(CACHE_TTL_MEDIUM),
             notification_cache: TtlCache::new(CACHE_TTL_SHORT),
@@ -285,12 +299,16 @@ impl GitHubProvider {
             commit_cache: TtlCache::new(CACHE_TTL_LONG),
             commit_status_cache: TtlCache::new(CACHE_TTL_SHORT),
             label_cache: TtlCache::new(CACHE_TTL_LONG),
+            protection_cache: TtlCache::new(CACHE_TTL_LONG),
+            unread_count_cache: TtlCache::new(CACHE_TTL_SHORT),
+            unread_threads: TtlCache::new(CACHE_TTL_LONG),
         }
     }
 
     /// Clear all caches (called after mutations or disconnect)
     pub fn clear_caches(&self) {
         self.pr_cache.clear();
+        self.pr_detail_cache.clear();
         self.issue_cache.clear();
         self.ci_cache.clear();
         self.notification_cache.clear();
@@ -298,12 +316,17 @@ impl GitHubProvider {
         self.commit_cache.clear();
         self.commit_status_cache.clear();
         self.label_cache.clear();
+        self.protection_cache.clear();
+        self.unread_count_cache.clear();
+        self.unread_threads.clear();
     }
 
     /// Invalidate PR cache (called after create/merge)
     pub fn invalidate_pr_cache(&self, owner: &str, repo: &str) {
         self.pr_cache
             .remove_by_prefix(&format!("{owner}/{repo}/prs/"));
+        self.pr_detail_cache
+            .remove_by_prefix(&format!("{owner}/{repo}/pr/"));
     }
 
     /// Invalidate issue cache (called after create)
@@ -318,12 +341,116 @@ impl GitHubProvider {
             .remove_by_prefix(&format!("{owner}/{repo}/notifications/"));
     }
 
+    /// Invalidate CI runs cache (called after re-running a workflow)
+    pub fn invalidate_ci_cache(&self, owner: &str, repo: &str) {
+        self.ci_cache
+            .remove_by_prefix(&format!("{owner}/{repo}/ci/"));
+    }
+
     /// Invalidate commit status cache (called after actions that trigger CI)
     pub fn invalidate_commit_status_cache(&self, owner: &str, repo: &str) {
         self.commit_status_cache
             .remove_by_prefix(&format!("{owner}/{repo}/status/"));
     }
 
+    /// Remember unread threads so mark-read can adjust the cached count for their repo
+    fn track_unread_threads(&self, owner: &str, repo: &str, notifications: &[Notification]) {
+        for notification in notifications.iter().filter(|n| n.unread) {
+            self.unread_threads.set(
+                notification.id.clone(),
+                (format!("{owner}/{repo}"), notification.reason),
+            );
+        }
+    }
+
+    /// Optimistically drop a thread from the cached unread count of its repository
+    fn apply_notification_read(&self, thread_id: &str) {
+        let Some((repo_key, reason)) = self.unread_threads.get(thread_id) else {
+            // Unknown thread: we can't tell which count it belongs to, so drop them all
+            self.unread_count_cache.clear();
+            return;
+        };
+        self.unread_threads.remove(thread_id);
+
+        let cache_key = format!("{repo_key}/unread");
+        if let Some(mut count) = self.unread_count_cache.get(&cache_key) {
+            count.decrement(Some(reason));
+            self.unread_count_cache.set(cache_key, count);
+        }
+    }
+
+    /// Count unread notifications page by page.
+    ///
+    /// Pages are read until the last one or `max_pages`; past the cap the breakdown is
+    /// partial and `fetch_total` supplies the exact total. Returns the counted notifications.
+    async fn count_unread_pages<P, PFut, T, TFut>(
+        mut fetch_page: P,
+        fetch_total: T,
+        max_pages: u32,
+    ) -> Result<(UnreadNotificationCount, Vec<Notification>)>
+    where
+        P: FnMut(u32) -> PFut,
+        PFut: Future<Output = Result<NotificationsPage>>,
+        T: FnOnce() -> TFut,
+        TFut: Future<Output = Result<Option<u32>>>,
+    {
+        let mut count = UnreadNotificationCount::default();
+        let mut counted = Vec::new();
+        let mut has_more = false;
+
+        for page in 1..=max_pages {
+            let result = fetch_page(page).await?;
+            for notification in result.items.into_iter().filter(|n| n.unread) {
+                count.add(notification.reason);
+                counted.push(notification);
+            }
+            has_more = result.has_more;
+            if !has_more {
+                break;
+            }
+        }
+
+        if has_more {
+            count.capped = true;
+            if let Some(total) = fetch_total().await? {
+                count.total = count.total.max(total);
+            }
+        }
+
+        count.by_reason.sort_by_key(|c| std::cmp::Reverse(c.count));
+
+        Ok((count, counted))
+    }
+
+    /// Map a combined-status entry (`/commits/{sha}/status`) to a check
+    fn commit_status_to_check(&self, status: &serde_json::Value, sha: &str) -> CIRun {
+        let (run_status, conclusion) = match status["state"].as_str() {
+            Some("success") => (CIRunStatus::Completed, Some(CIConclusion::Success)),
+            Some("failure" | "error") => (CIRunStatus::Completed, Some(CIConclusion::Failure)),
+            _ => (CIRunStatus::InProgress, None),
+        };
+        let timestamp = |field: &str| {
+            status[field]
+                .as_str()
+                .and_then(|s| DateTime::parse_from_rfc3339(s).ok())
+                .map_or_else(Utc::now, |dt| dt.with_timezone(&Utc))
+        };
+
+        CIRun {
+            provider: self.provider_type(),
+            id: status["id"].as_u64().unwrap_or(0).to_string(),
+            name: status["context"].as_str().unwrap_or("").to_string(),
+            status: run_status,
+            conclusion,
+            commit_sha: sha.to_string(),
+            branch: None,
+            event: "status".to_string(),
+            created_at: timestamp("created_at"),
+            updated_at: timestamp("updated_at"),
+            url: status["target_url"].as_str().unwrap_or("").to_string(),
+        }
+    }
+
     fn get_client(&self) -> Result<Arc<Octocrab>> {
         self.client
             .read()
@@ -465,6 +592,17 @@ impl IntegrationProvider for GitHubProvider {
         Ok(result)
     }
 
+    async fn find_user_avatar(&self, email: &str) -> Result<Option<String>> {
+        let client = self.get_client()?;
+        let users = client
+            .search()
+            .users(&format!("{email} in:email"))
+            .per_page(1u8)
+            .send()
+            .await?;
+        Ok(users.items.first().map(|u| u.avatar_url.to_string()))
+    }
+
     async fn list_pull_requests(
         &self,
         owner: &str,
@@ -501,13 +639,18 @@ impl IntegrationProvider for GitHubProvider {
         repo: &str,
         number: u32,
     ) -> Result<PullRequestDetail> {
+        let cache_key = format!("{owner}/{repo}/pr/{number}");
+        if let Some(cached) = self.pr_detail_cache.get(&cache_key) {
+            return Ok(cached);
+        }
+
         let client = self.get_client()?;
 
         let pr = client.pulls(owner, repo).get(u64::from(number)).await?;
 
         let base: PullRequest = pr.clone().into();
 
-        Ok(PullRequestDetail {
+        let detail = PullRequestDetail {
             base,
             body: pr.body,
             additions: u32::try_from(pr.additions.unwrap_or(0)).unwrap_or(u32::MAX),
@@ -534,7 +677,20 @@ impl IntegrationProvider for GitHubProvider {
                 .into_iter()
                 .map(Into::into)
                 .collect(),
-        })
+        };
+
+        // Mergeability still being computed is not worth caching
+        if detail.mergeable.is_some() {
+            self.pr_detail_cache.set(cache_key, detail.clone());
+        }
+        Ok(detail)
+    }
+
+    fn invalidate_pull_request(&self, owner: &str, repo: &str, number: u32) {
+        self.pr_detail_cache
+            .remove(&format!("{owner}/{repo}/pr/{number}"));
+        self.pr_cache
+            .remove_by_prefix(&format!("{owner}/{repo}/prs/"));
     }
 
     async fn create_pull_request(
@@ -726,6 +882,8 @@ impl IntegrationProvider for GitHubProvider {
         let http_response = client._get(&route).await.map_err(|e| {
             AxisError::IntegrationError(format!("Failed to get commit status: {e:?}"))
         })?;
+        // Surface API errors (e.g. rate limiting) instead of parsing the error body as a status
+        let http_response = octocrab::map_github_error(http_response).await?;
 
         let response: serde_json::Value = Self::parse_response(http_response).await?;
 
@@ -747,7 +905,7 @@ impl IntegrationProvider for GitHubProvider {
             }
         };
 
-        let checks: Vec<CIRun> = checks_response["check_runs"]
+        let mut checks: Vec<CIRun> = checks_response["check_runs"]
             .as_array()
             .unwrap_or(&Vec::new())
             .iter()
@@ -781,6 +939,15 @@ impl IntegrationProvider for GitHubProvider {
             })
             .collect();
 
+        // Legacy commit statuses (external CI services) alongside check runs
+        checks.extend(
+            response["statuses"]
+                .as_array()
+                .unwrap_or(&Vec::new())
+                .iter()
+                .map(|status| self.commit_status_to_check(status, sha)),
+        );
+
         let result = CommitStatus {
             state,
             checks,
@@ -793,6 +960,119 @@ impl IntegrationProvider for GitHubProvider {
         Ok(result)
     }
 
+    async fn rerun_ci_run(
+        &self,
+        owner: &str,
+        repo: &str,
+        run_id: &str,
+        failed_only: bool,
+    ) -> Result<()> {
+        let run_id: u64 = run_id
+            .parse()
+            .map_err(|e| AxisError::IntegrationError(format!("Invalid run ID format: {e:?}")))?;
+        let client = self.get_client()?;
+
+        let action = if failed_only {
+            "rerun-failed-jobs"
+        } else {
+            "rerun"
+        };
+        let route = format!("/repos/{owner}/{repo}/actions/runs/{run_id}/{action}");
+        let result = match client._post(&route, None::<&()>).await {
+            Ok(response) => octocrab::map_github_error(response).await.map(drop),
+            Err(e) => Err(e),
+        };
+
+        // The run's checks restart, so cached CI state is stale either way
+        self.invalidate_ci_cache(owner, repo);
+        self.invalidate_commit_status_cache(owner, repo);
+
+        result.map_err(Into::into)
+    }
+
+    async fn get_branch_protection(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+    ) -> Result<BranchProtection> {
+        let cache_key = format!("{owner}/{repo}/protection/{branch}");
+
+        if let Some(cached) = self.protection_cache.get(&cache_key) {
+            return Ok(cached);
+        }
+
+        let client = self.get_client()?;
+
+        // The branch endpoint only needs read access; `/protection` needs admin rights
+        let route = format!("/repos/{owner}/{repo}/branches/{branch}");
+        let branch_response = match client._get(&route).await {
+            Ok(response) => octocrab::map_github_error(response).await,
+            Err(e) => Err(e),
+        };
+        let branch_info: serde_json::Value = match branch_response {
+            Ok(response) => Self::parse_response(response).await?,
+            // Not on the remote yet, so nothing protects it
+            Err(octocrab::Error::GitHub { source, .. })
+                if source.status_code == http::StatusCode::NOT_FOUND =>
+            {
+                let protection = BranchProtection::unprotected();
+                self.protection_cache.set(cache_key, protection.clone());
+                return Ok(protection);
+            }
+            Err(e) => return Err(e.into()),
+        };
+
+        let protection = if branch_info["protected"].as_bool().unwrap_or(false) {
+            let required_status_checks = branch_info["protection"]["required_status_checks"]
+                ["contexts"]
+                .as_array()
+                .map(|contexts| {
+                    contexts
+                        .iter()
+                        .filter_map(|c| c.as_str().map(str::to_string))
+                        .collect()
+                })
+                .unwrap_or_default();
+
+            // Without admin access the detailed rules are hidden; GitHub blocks force
+            // pushes and deletions on protected branches unless explicitly allowed
+            let rules_route = format!("{route}/protection");
+            let rules: Option<serde_json::Value> = match client._get(&rules_route).await {
+                Ok(response) => match octocrab::map_github_error(response).await {
+                    Ok(response) => Self::parse_response(response).await.ok(),
+                    Err(e) => {
+                        log::debug!("Branch protection rules unavailable for {branch}: {e}");
+                        None
+                    }
+                },
+                Err(e) => {
+                    log::debug!("Branch protection rules unavailable for {branch}: {e}");
+                    None
+                }
+            };
+            let rule_enabled = |name: &str| {
+                rules
+                    .as_ref()
+                    .and_then(|r| r[name]["enabled"].as_bool())
+                    .unwrap_or(false)
+            };
+
+            BranchProtection {
+                protected: true,
+                allows_force_pushes: rule_enabled("allow_force_pushes"),
+                allows_deletions: rule_enabled("allow_deletions"),
+                required_status_checks,
+            }
+        } else {
+            BranchProtection::unprotected()
+        };
+
+        self.protection_cache.set(cache_key, protection.clone());
+
+        Ok(protection)
+    }
+
     async fn list_notifications(
         &self,
         owner: &str,
@@ -819,6 +1099,7 @@ impl IntegrationProvider for GitHubProvider {
             .await?;
 
         let page_result: NotificationsPage = result.into();
+        self.track_unread_threads(owner, repo, &page_result.items);
         self.notification_cache.set(cache_key, page_result.clone());
 
         Ok(page_result)
@@ -830,35 +1111,98 @@ impl IntegrationProvider for GitHubProvider {
         let id: u64 = thread_id
             .parse()
             .map_err(|e| AxisError::IntegrationError(format!("Invalid thread ID format: {e:?}")))?;
-        client
+
+        self.apply_notification_read(thread_id);
+
+        let result = client
             .activity()
             .notifications()
             .mark_as_read(id.into())
-            .await?;
+            .await;
 
         // Clear all notification cache since we don't know which repo this belongs to
         self.notification_cache.clear();
 
+        if let Err(e) = result {
+            // Roll back the optimistic count by refetching next time
+            self.unread_count_cache.clear();
+            return Err(e.into());
+        }
+
         Ok(())
     }
 
     async fn mark_all_notifications_read(&self, owner: &str, repo: &str) -> Result<()> {
         let client = self.get_client()?;
 
-        client
+        let cache_key = format!("{owner}/{repo}/unread");
+        self.unread_count_cache
+            .set(cache_key.clone(), UnreadNotificationCount::default());
+
+        let result = client
             .activity()
             .notifications()
             .mark_repo_as_read(owner, repo, None)
-            .await?;
+            .await;
 
         self.invalidate_notification_cache(owner, repo);
 
+        if let Err(e) = result {
+            self.unread_count_cache.remove(&cache_key);
+            return Err(e.into());
+        }
+
         Ok(())
     }
 
-    async fn get_unread_count(&self, owner: &str, repo: &str) -> Result<u32> {
-        let page = self.list_notifications(owner, repo, false, 1).await?;
-        Ok(u32::try_from(page.items.len()).unwrap_or(u32::MAX))
+    async fn get_unread_count(&self, owner: &str, repo: &str) -> Result<UnreadNotificationCount> {
+        let cache_key = format!("{owner}/{repo}/unread");
+
+        if let Some(cached) = self.unread_count_cache.get(&cache_key) {
+            return Ok(cached);
+        }
+
+        let client = self.get_client()?;
+
+        let fetch_page = |page: u32| {
+            let client = Arc::clone(&client);
+            async move {
+                let result = client
+                    .activity()
+                    .notifications()
+                    .list_for_repo(owner, repo)
+                    .all(false)
+                    .per_page(UNREAD_PER_PAGE)
+                    .page(u8::try_from(page).unwrap_or(u8::MAX))
+                    .send()
+                    .await?;
+                Ok(NotificationsPage::from(result))
+            }
+        };
+
+        // With one item per page, the `last` link's page number is the unread total
+        let fetch_total = || {
+            let client = Arc::clone(&client);
+            async move {
+                let result = client
+                    .activity()
+                    .notifications()
+                    .list_for_repo(owner, repo)
+                    .all(false)
+                    .per_page(1)
+                    .send()
+                    .await?;
+                Ok(result.number_of_pages())
+            }
+        };
+
+        let (count, counted) =
+            Self::count_unread_pages(fetch_page, fetch_total, UNREAD_MAX_PAGES).await?;
+
+        self.track_unread_threads(owner, repo, &counted);
+        self.unread_count_cache.set(cache_key, count.clone());
+
+        Ok(count)
     }
 
     async fn list_labels(&self, owner: &str, repo: &str) -> Result<Vec<IntegrationLabel>> {
@@ -883,4 +1227,437 @@ impl IntegrationProvider for GitHubProvider {
 
         Ok(labels)
     }
+
+    async fn add_gpg_key(&self, armored_public_key: &str) -> Result<()> {
+        let client = self.get_client()?;
+
+        let body = serde_json::json!({ "armored_public_key": armored_public_key });
+        match client._post("/user/gpg_keys", Some(&body)).await {
+            Ok(response) => octocrab::map_github_error(response).await.map(drop),
+            Err(e) => Err(e),
+        }
+        .map_err(Into::into)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::CiRollupState;
+    use crate::services::integrations::branch_ci_rollup;
+
+    fn notification(id: usize, reason: NotificationReason, unread: bool) -> Notification {
+        Notification {
+            provider: ProviderType::GitHub,
+            id: id.to_string(),
+            reason,
+            unread,
+            subject_title: format!("Notification {id}"),
+            subject_type: NotificationSubjectType::PullRequest,
+            subject_url: None,
+            repository: "owner/repo".to_string(),
+            updated_at: Utc::now(),
+            url: String::new(),
+        }
+    }
+
+    /// Unread notifications cycling through mention, review request and CI reasons
+    fn unread_notifications(total: usize) -> Vec<Notification> {
+        let reasons = [
+            NotificationReason::Mention,
+            NotificationReason::ReviewRequested,
+            NotificationReason::CiActivity,
+        ];
+        (0..total)
+            .map(|i| notification(i, reasons[i % reasons.len()], true))
+            .collect()
+    }
+
+    /// Serve `notifications` in pages of `per_page`, like the paginated endpoint
+    fn page_of(notifications: &[Notification], per_page: usize, page: u32) -> NotificationsPage {
+        let start = (page as usize - 1) * per_page;
+        let items: Vec<Notification> = notifications
+            .iter()
+            .skip(start)
+            .take(per_page)
+            .cloned()
+            .collect();
+        NotificationsPage {
+            has_more: start + items.len() < notifications.len(),
+            items,
+        }
+    }
+
+    async fn count(
+        notifications: &[Notification],
+        per_page: usize,
+        max_pages: u32,
+    ) -> (UnreadNotificationCount, usize) {
+        let total = u32::try_from(notifications.len()).expect("small count");
+        let mut pages_fetched = 0;
+        let (count, counted) = GitHubProvider::count_unread_pages(
+            |page| {
+                pages_fetched += 1;
+                let page = page_of(notifications, per_page, page);
+                async move { Ok(page) }
+            },
+            || async move { Ok(Some(total)) },
+            max_pages,
+        )
+        .await
+        .expect("should count");
+        let breakdown: u32 = count.by_reason.iter().map(|c| c.count).sum();
+        assert_eq!(
+            u32::try_from(counted.len()).expect("small count"),
+            breakdown
+        );
+        (count, pages_fetched)
+    }
+
+    fn reason_count(count: &UnreadNotificationCount, reason: NotificationReason) -> u32 {
+        count
+            .by_reason
+            .iter()
+            .find(|c| c.reason == reason)
+            .map_or(0, |c| c.count)
+    }
+
+    // ==================== Unread Count Tests ====================
+
+    #[tokio::test]
+    async fn test_count_unread_none() {
+        let (count, pages) = count(&[], 50, 10).await;
+        assert_eq!(count, UnreadNotificationCount::default());
+        assert_eq!(pages, 1);
+    }
+
+    #[tokio::test]
+    async fn test_count_unread_single_page() {
+        let (count, pages) = count(&unread_notifications(17), 50, 10).await;
+        assert_eq!(count.total, 17);
+        assert!(!count.capped);
+        assert_eq!(pages, 1);
+        assert_eq!(reason_count(&count, NotificationReason::Mention), 6);
+        assert_eq!(reason_count(&count, NotificationReason::ReviewRequested), 6);
+        assert_eq!(reason_count(&count, NotificationReason::CiActivity), 5);
+        // Most frequent first
+        assert_eq!(count.by_reason[2].reason, NotificationReason::CiActivity);
+    }
+
+    #[tokio::test]
+    async fn test_count_unread_beyond_first_page() {
+        // The old implementation stopped at the first page of 30
+        let (count, pages) = count(&unread_notifications(70), 30, 10).await;
+        assert_eq!(count.total, 70);
+        assert!(!count.capped);
+        assert_eq!(pages, 3);
+        assert_eq!(reason_count(&count, NotificationReason::Mention), 24);
+        assert_eq!(
+            reason_count(&count, NotificationReason::ReviewRequested),
+            23
+        );
+        assert_eq!(reason_count(&count, NotificationReason::CiActivity), 23);
+    }
+
+    #[tokio::test]
+    async fn test_count_unread_cap_uses_exact_total() {
+        let (count, pages) = count(&unread_notifications(70), 30, 2).await;
+        assert_eq!(pages, 2);
+        assert!(count.capped);
+        // Total comes from the last-page lookup, the breakdown covers what was read
+        assert_eq!(count.total, 70);
+        assert_eq!(count.by_reason.iter().map(|c| c.count).sum::<u32>(), 60);
+    }
+
+    #[tokio::test]
+    async fn test_count_unread_ignores_read_items() {
+        let mut notifications = unread_notifications(5);
+        notifications.push(notification(99, NotificationReason::Mention, false));
+        let (count, _) = count(&notifications, 50, 10).await;
+        assert_eq!(count.total, 5);
+        assert_eq!(reason_count(&count, NotificationReason::Mention), 2);
+    }
+
+    // ==================== Mark Read Tests ====================
+
+    fn provider() -> GitHubProvider {
+        GitHubProvider::new(|_| Ok(None), |_| Ok(()))
+    }
+
+    fn seeded_count(provider: &GitHubProvider, notifications: &[Notification]) {
+        let mut count = UnreadNotificationCount::default();
+        for n in notifications {
+            count.add(n.reason);
+        }
+        provider
+            .unread_count_cache
+            .set("owner/repo/unread".to_string(), count);
+        provider.track_unread_threads("owner", "repo", notifications);
+    }
+
+    #[test]
+    fn test_mark_read_decrements_cached_count() {
+        let provider = provider();
+        let notifications = unread_notifications(17);
+        seeded_count(&provider, &notifications);
+
+        // Thread 0 is a mention
+        provider.apply_notification_read("0");
+        let count = provider
+            .unread_count_cache
+            .get("owner/repo/unread")
+            .expect("count stays cached");
+        assert_eq!(count.total, 16);
+        assert_eq!(reason_count(&count, NotificationReason::Mention), 5);
+
+        // The thread is no longer tracked, so a repeat can't be attributed and
+        // the count is refetched instead of being decremented twice
+        provider.apply_notification_read("0");
+        assert!(provider
+            .unread_count_cache
+            .get("owner/repo/unread")
+            .is_none());
+    }
+
+    #[test]
+    fn test_mark_read_unknown_thread_invalidates_count() {
+        let provider = provider();
+        seeded_count(&provider, &unread_notifications(3));
+
+        provider.apply_notification_read("12345");
+        assert!(provider
+            .unread_count_cache
+            .get("owner/repo/unread")
+            .is_none());
+    }
+
+    #[tokio::test]
+    async fn test_unread_count_served_from_cache() {
+        let provider = provider();
+        seeded_count(&provider, &unread_notifications(4));
+
+        // No client is configured, so this only succeeds from the cache
+        let count = provider
+            .get_unread_count("owner", "repo")
+            .await
+            .expect("should use cached count");
+        assert_eq!(count.total, 4);
+        assert!(provider.get_unread_count("other", "repo").await.is_err());
+    }
+
+    // ==================== CI Rollup Tests ====================
+
+    type Route = (&'static str, u16, &'static str);
+
+    /// Serve canned JSON responses by path prefix, recording each request line
+    async fn mock_github(routes: Vec<Route>) -> (String, Arc<parking_lot::Mutex<Vec<String>>>) {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0")
+            .await
+            .expect("should bind");
+        let base = format!(
+            "http://{}",
+            listener.local_addr().expect("should have addr")
+        );
+        let requests = Arc::new(parking_lot::Mutex::new(Vec::new()));
+        let recorded = Arc::clone(&requests);
+
+        tokio::spawn(async move {
+            while let Ok((mut socket, _)) = listener.accept().await {
+                let mut buf = vec![0u8; 16 * 1024];
+                let n = socket.read(&mut buf).await.unwrap_or(0);
+                let request = String::from_utf8_lossy(&buf[..n]).to_string();
+                let line = request.lines().next().unwrap_or_default().to_string();
+                let path = line
+                    .split_whitespace()
+                    .nth(1)
+                    .unwrap_or_default()
+                    .to_string();
+                recorded.lock().push(line);
+
+                let (code, body) = routes
+                    .iter()
+                    .find(|(prefix, _, _)| path.starts_with(prefix))
+                    .map_or((404, r#"{"message":"Not Found"}"#), |(_, code, body)| {
+                        (*code, *body)
+                    });
+                let response = format!(
+                    "HTTP/1.1 {code} Mock\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{body}",
+                    body.len()
+                );
+                let _ = socket.write_all(response.as_bytes()).await;
+            }
+        });
+
+        (base, requests)
+    }
+
+    fn connected_provider(base: &str) -> GitHubProvider {
+        let provider = provider();
+        let client = Octocrab::builder()
+            .base_uri(base)
+            .expect("should set base uri")
+            .personal_token("token".to_string())
+            .build()
+            .expect("should build client");
+        *provider.client.write() = Some(Arc::new(client));
+        provider
+    }
+
+    const STATUS_ROUTE: &str = "/repos/o/r/commits/abc/status";
+    const CHECKS_ROUTE: &str = "/repos/o/r/commits/abc/check-runs";
+
+    #[tokio::test]
+    async fn test_ci_rollup_aggregates_checks_and_statuses() {
+        let (base, _) = mock_github(vec![
+            (
+                STATUS_ROUTE,
+                200,
+                r#"{"state":"failure","total_count":1,"statuses":[
+                    {"id":9,"state":"failure","context":"ci/jenkins","target_url":"https://jenkins.example.com/9"}
+                ]}"#,
+            ),
+            (
+                CHECKS_ROUTE,
+                200,
+                r#"{"check_runs":[
+                    {"id":1,"name":"lint","status":"completed","conclusion":"success","html_url":"https://github.com/o/r/actions/runs/77/job/1"},
+                    {"id":2,"name":"test","status":"completed","conclusion":"failure","html_url":"https://github.com/o/r/actions/runs/77/job/2"},
+                    {"id":3,"name":"e2e","status":"in_progress","conclusion":null,"html_url":"https://github.com/o/r/actions/runs/78/job/3"}
+                ]}"#,
+            ),
+        ])
+        .await;
+        let provider = connected_provider(&base);
+
+        let rollup = branch_ci_rollup(
+            &provider,
+            "o",
+            "r",
+            Some("main".to_string()),
+            "abc".to_string(),
+            true,
+        )
+        .await
+        .expect("should build rollup");
+
+        assert_eq!(rollup.state, CiRollupState::Failing);
+        assert_eq!((rollup.passed, rollup.failed, rollup.running), (1, 2, 1));
+        let failing: Vec<_> = rollup
+            .failing_checks
+            .iter()
+            .map(|c| (c.name.as_str(), c.run_id.as_deref()))
+            .collect();
+        assert_eq!(failing, vec![("test", Some("77")), ("ci/jenkins", None)]);
+    }
+
+    #[tokio::test]
+    async fn test_ci_rollup_not_pushed_skips_provider() {
+        // No client: any API call would fail
+        let provider = provider();
+
+        let rollup = branch_ci_rollup(
+            &provider,
+            "o",
+            "r",
+            Some("feature".to_string()),
+            "abc".to_string(),
+            false,
+        )
+        .await
+        .expect("should not query the provider");
+
+        assert_eq!(rollup.state, CiRollupState::NotPushed);
+        assert_eq!(rollup.sha, "abc");
+        assert!(rollup.failing_checks.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_ci_rollup_reports_rate_limit() {
+        let (base, _) = mock_github(vec![(
+            STATUS_ROUTE,
+            403,
+            r#"{"message":"API rate limit exceeded for 127.0.0.1.","documentation_url":"https://docs.github.com"}"#,
+        )])
+        .await;
+        let provider = connected_provider(&base);
+
+        let rollup = branch_ci_rollup(&provider, "o", "r", None, "abc".to_string(), true)
+            .await
+            .expect("rate limit is not an error");
+
+        assert_eq!(rollup.state, CiRollupState::RateLimited);
+    }
+
+    #[tokio::test]
+    async fn test_rerun_failed_jobs_invalidates_ci_caches() {
+        let (base, requests) = mock_github(vec![(
+            "/repos/o/r/actions/runs/77/rerun-failed-jobs",
+            201,
+            "{}",
+        )])
+        .await;
+        let provider = connected_provider(&base);
+        provider.commit_status_cache.set(
+            "o/r/status/abc".to_string(),
+            CommitStatus {
+                state: CommitStatusState::Failure,
+                checks: vec![],
+                total_count: 0,
+            },
+        );
+        provider.ci_cache.set(
+            "o/r/ci/1".to_string(),
+            CiRunsPage {
+                runs: vec![],
+                has_more: false,
+            },
+        );
+
+        provider
+            .rerun_ci_run("o", "r", "77", true)
+            .await
+            .expect("should re-run");
+
+        assert_eq!(
+            requests.lock().as_slice(),
+            ["POST /repos/o/r/actions/runs/77/rerun-failed-jobs HTTP/1.1"]
+        );
+        assert!(provider.commit_status_cache.get("o/r/status/abc").is_none());
+        assert!(provider.ci_cache.get("o/r/ci/1").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_add_gpg_key_posts_to_user_keys() {
+        let (base, requests) = mock_github(vec![("/user/gpg_keys", 201, r#"{"id":3}"#)]).await;
+        let provider = connected_provider(&base);
+
+        provider
+            .add_gpg_key("-----BEGIN PGP PUBLIC KEY BLOCK-----")
+            .await
+            .expect("should add key");
+
+        assert_eq!(requests.lock().as_slice(), ["POST /user/gpg_keys HTTP/1.1"]);
+    }
+
+    #[tokio::test]
+    async fn test_add_gpg_key_reports_validation_error() {
+        let (base, _) = mock_github(vec![(
+            "/user/gpg_keys",
+            422,
+            r#"{"message":"Validation Failed","documentation_url":"https://docs.github.com"}"#,
+        )])
+        .await;
+        let provider = connected_provider(&base);
+
+        assert!(provider.add_gpg_key("not a key").await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_rerun_rejects_invalid_run_id() {
+        let provider = provider();
+        let result = provider.rerun_ci_run("o", "r", "../x", false).await;
+        assert!(result.is_err());
+    }
 }
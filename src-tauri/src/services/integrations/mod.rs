@@ -7,9 +7,19 @@ pub use cache::TtlCache;
 pub use provider::IntegrationProvider;
 pub use service::IntegrationService;
 
+use std::time::Duration;
+
 use url::Url;
 
-use crate::models::{DetectedProvider, ProviderType};
+use crate::error::{AxisError, Result};
+use crate::models::{
+    BranchType, CiRollup, CurrentBranchPullRequest, DetectedProvider, PrState,
+    ProtectedBranchAction, ProviderType, PullRequest, PullRequestDetail, Remote,
+};
+use crate::services::ops::RepoOperations;
+
+/// Open pull request pages scanned for a pushed branch
+const MAX_PR_PAGES_ON_PUSH: u32 = 5;
 
 /// Parse a remote URL and detect the provider type
 pub fn detect_provider(remote_url: &str) -> Option<DetectedProvider> {
@@ -96,6 +106,421 @@ fn detect_provider_from_host(host: &str) -> Option<ProviderType> {
     }
 }
 
+/// Whether a provider error was caused by API rate limiting
+pub fn is_rate_limit_error(err: &AxisError) -> bool {
+    matches!(err, AxisError::IntegrationError(msg) if msg.to_lowercase().contains("rate limit"))
+}
+
+/// Aggregate CI checks for a branch tip.
+///
+/// A tip that is not on the remote yet reports `NotPushed` instead of querying the provider,
+/// which would otherwise have nothing (or an older commit's checks) to show.
+pub async fn branch_ci_rollup(
+    provider: &dyn IntegrationProvider,
+    owner: &str,
+    repo: &str,
+    branch: Option<String>,
+    sha: String,
+    pushed: bool,
+) -> Result<CiRollup> {
+    if !pushed {
+        return Ok(CiRollup::not_pushed(branch, sha));
+    }
+
+    match provider.get_commit_status(owner, repo, &sha).await {
+        Ok(status) => Ok(CiRollup::from_status(branch, sha, &status)),
+        Err(e) if is_rate_limit_error(&e) => {
+            log::warn!("CI rollup for {owner}/{repo} hit the rate limit: {e}");
+            Ok(CiRollup::rate_limited(branch, sha))
+        }
+        Err(e) => Err(e),
+    }
+}
+
+/// Remote branches a push would force-update or delete, from its refspecs.
+/// Plain fast-forward pushes yield nothing, so they never wait on a protection lookup.
+pub fn push_protection_targets(
+    refspecs: &[String],
+    force: bool,
+) -> Vec<(String, ProtectedBranchAction)> {
+    refspecs
+        .iter()
+        .filter_map(|refspec| {
+            let (forced, spec) = match refspec.strip_prefix('+') {
+                Some(spec) => (true, spec),
+                None => (force, refspec.as_str()),
+            };
+            let (src, dst) = spec.split_once(':').unwrap_or((spec, spec));
+            let branch = dst.strip_prefix("refs/heads/").unwrap_or(dst);
+            if branch.is_empty() || branch.starts_with("refs/") {
+                return None;
+            }
+            let action = if src.is_empty() {
+                ProtectedBranchAction::Delete
+            } else if forced {
+                ProtectedBranchAction::ForcePush
+            } else {
+                return None;
+            };
+            Some((branch.to_string(), action))
+        })
+        .collect()
+}
+
+/// Refuse force pushes or remote branch deletions that the branches' protection rules forbid.
+///
+/// `override_check` skips the lookup entirely. Fails open: if the rules can't be fetched
+/// the operation proceeds, since the server still enforces them.
+pub async fn guard_branch_protection(
+    provider: &dyn IntegrationProvider,
+    owner: &str,
+    repo: &str,
+    targets: &[(String, ProtectedBranchAction)],
+    override_check: bool,
+) -> Result<()> {
+    if override_check {
+        return Ok(());
+    }
+
+    for (branch, action) in targets {
+        let protection = match provider.get_branch_protection(owner, repo, branch).await {
+            Ok(protection) => protection,
+            Err(e) => {
+                log::warn!("Skipping protection check for {owner}/{repo}:{branch}: {e}");
+                continue;
+            }
+        };
+
+        if !protection.protected {
+            continue;
+        }
+
+        let reason = match action {
+            ProtectedBranchAction::ForcePush if !protection.allows_force_pushes => {
+                "force pushes are not allowed"
+            }
+            ProtectedBranchAction::Delete if !protection.allows_deletions => {
+                "deleting it is not allowed"
+            }
+            _ => continue,
+        };
+
+        return Err(AxisError::ProtectedBranch {
+            branch: branch.clone(),
+            reason: reason.to_string(),
+        });
+    }
+
+    Ok(())
+}
+
+/// Remote branches a push updated, from its refspecs.
+/// Uses the destination side so pushes to a differently named remote branch are matched
+/// by the name the provider sees; deletions and non-branch refs are skipped.
+pub fn pushed_branches(refspecs: &[String]) -> Vec<String> {
+    let mut branches: Vec<String> = refspecs
+        .iter()
+        .filter_map(|refspec| {
+            let spec = refspec.strip_prefix('+').unwrap_or(refspec);
+            let (src, dst) = spec.split_once(':').unwrap_or((spec, spec));
+            let branch = dst.strip_prefix("refs/heads/").unwrap_or(dst);
+            if src.is_empty() || branch.is_empty() || branch.starts_with("refs/") {
+                return None;
+            }
+            Some(branch.to_string())
+        })
+        .collect();
+    branches.dedup();
+    branches
+}
+
+/// How long to keep asking a provider for a pull request's mergeability after a push
+#[derive(Debug, Clone, Copy)]
+pub struct MergeabilityPoll {
+    pub initial_delay: Duration,
+    pub max_delay: Duration,
+    pub max_attempts: u32,
+}
+
+impl Default for MergeabilityPoll {
+    fn default() -> Self {
+        Self {
+            initial_delay: Duration::from_secs(1),
+            max_delay: Duration::from_secs(8),
+            max_attempts: 6,
+        }
+    }
+}
+
+impl MergeabilityPoll {
+    /// Delay before the given retry, doubling from `initial_delay` up to `max_delay`
+    pub fn delay(&self, attempt: u32) -> Duration {
+        self.initial_delay
+            .saturating_mul(2u32.saturating_pow(attempt))
+            .min(self.max_delay)
+    }
+}
+
+/// Re-fetch a pull request until the provider has computed its mergeability.
+///
+/// `on_refresh` receives the first fresh copy and every later one whose mergeability
+/// changed. Gives up after `poll.max_attempts` fetches, returning the last known value
+/// (`None` if the provider was still computing).
+pub async fn poll_mergeability(
+    provider: &dyn IntegrationProvider,
+    owner: &str,
+    repo: &str,
+    number: u32,
+    poll: &MergeabilityPoll,
+    on_refresh: &mut (dyn FnMut(PullRequestDetail) + Send),
+) -> Result<Option<bool>> {
+    let mut last: Option<Option<bool>> = None;
+
+    for attempt in 0..poll.max_attempts {
+        if attempt > 0 {
+            tokio::time::sleep(poll.delay(attempt - 1)).await;
+        }
+
+        provider.invalidate_pull_request(owner, repo, number);
+        let detail = provider.get_pull_request(owner, repo, number).await?;
+        let mergeable = detail.mergeable;
+
+        if last != Some(mergeable) {
+            on_refresh(detail);
+        }
+        last = Some(mergeable);
+
+        if mergeable.is_some() {
+            break;
+        }
+    }
+
+    Ok(last.flatten())
+}
+
+/// Refresh the open pull requests whose source branch was just pushed.
+///
+/// `source_repo` is the `owner/repo` that received the push, so pushes to a fork only
+/// match pull requests opened from that fork. Returns how many pull requests were refreshed.
+pub async fn refresh_pushed_pull_requests(
+    provider: &dyn IntegrationProvider,
+    owner: &str,
+    repo: &str,
+    source_repo: &str,
+    branches: &[String],
+    poll: &MergeabilityPoll,
+    on_refresh: &mut (dyn FnMut(PullRequestDetail) + Send),
+) -> Result<usize> {
+    if branches.is_empty() {
+        return Ok(0);
+    }
+
+    let mut matched = Vec::new();
+    for page in 1..=MAX_PR_PAGES_ON_PUSH {
+        let prs = provider
+            .list_pull_requests(owner, repo, PrState::Open, page)
+            .await?;
+        matched.extend(
+            prs.items
+                .into_iter()
+                .filter(|pr| branches.contains(&pr.source_branch))
+                .filter(|pr| {
+                    pr.source_repo.as_deref().map_or_else(
+                        || source_repo == format!("{owner}/{repo}"),
+                        |pr_repo| pr_repo.eq_ignore_ascii_case(source_repo),
+                    )
+                })
+                .map(|pr| pr.number),
+        );
+        if !prs.has_more {
+            break;
+        }
+    }
+
+    for number in &matched {
+        poll_mergeability(provider, owner, repo, *number, poll, on_refresh).await?;
+    }
+
+    Ok(matched.len())
+}
+
+/// Provider of the repository's main remote: origin, else the first remote
+pub fn primary_provider(remotes: &[Remote]) -> Option<DetectedProvider> {
+    remotes
+        .iter()
+        .find(|r| r.name == "origin")
+        .or_else(|| remotes.first())
+        .and_then(|r| r.url.as_deref())
+        .and_then(detect_provider)
+}
+
+/// `primary` followed by every other repository in `remote_urls` hosted on the same provider,
+/// so a fork's upstream is searched too
+pub fn same_provider_repos(
+    primary: &DetectedProvider,
+    remote_urls: &[String],
+) -> Vec<DetectedProvider> {
+    let mut repos = vec![primary.clone()];
+    for detected in remote_urls.iter().filter_map(|url| detect_provider(url)) {
+        let known = repos
+            .iter()
+            .any(|r| r.owner == detected.owner && r.repo == detected.repo);
+        if detected.provider == primary.provider && !known {
+            repos.push(detected);
+        }
+    }
+    repos
+}
+
+/// How a pull request's source branch matches a local branch
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum BranchMatch {
+    Exact,
+    IgnoringCase,
+}
+
+/// Match a pull request's source against `branch` pushed to one of `source_repos`.
+///
+/// Fork pull requests may report their head as an `owner:branch` label; the owner then has
+/// to be the owner of one of `source_repos`.
+fn match_source_branch(
+    pr: &PullRequest,
+    target: &DetectedProvider,
+    source_repos: &[String],
+    branch: &str,
+) -> Option<BranchMatch> {
+    let (label_owner, name) = match pr.source_branch.split_once(':') {
+        Some((owner, name)) => (Some(owner), name),
+        None => (None, pr.source_branch.as_str()),
+    };
+    let branch_match = if name == branch {
+        BranchMatch::Exact
+    } else if name.eq_ignore_ascii_case(branch) {
+        BranchMatch::IgnoringCase
+    } else {
+        return None;
+    };
+
+    let from_source = match (pr.source_repo.as_deref(), label_owner) {
+        (Some(pr_repo), _) => source_repos.iter().any(|s| s.eq_ignore_ascii_case(pr_repo)),
+        (None, Some(owner)) => source_repos.iter().any(|s| {
+            s.split_once('/')
+                .is_some_and(|(s_owner, _)| s_owner.eq_ignore_ascii_case(owner))
+        }),
+        (None, None) => {
+            let target_repo = format!("{}/{}", target.owner, target.repo);
+            source_repos
+                .iter()
+                .any(|s| s.eq_ignore_ascii_case(&target_repo))
+        }
+    };
+    from_source.then_some(branch_match)
+}
+
+/// Find the open pull request whose source is `branch` in one of `source_repos`
+/// (`owner/repo`), searching `targets` in order.
+///
+/// An exact branch name match wins over one that only differs in case. Lists come from the
+/// provider's pull request cache when warm.
+pub async fn find_branch_pull_request(
+    provider: &dyn IntegrationProvider,
+    targets: &[DetectedProvider],
+    source_repos: &[String],
+    branch: &str,
+) -> Result<Option<(DetectedProvider, PullRequest)>> {
+    let mut fallback = None;
+    for target in targets {
+        for page in 1..=MAX_PR_PAGES_ON_PUSH {
+            let prs = provider
+                .list_pull_requests(&target.owner, &target.repo, PrState::Open, page)
+                .await?;
+            for pr in prs.items {
+                match match_source_branch(&pr, target, source_repos, branch) {
+                    Some(BranchMatch::Exact) => return Ok(Some((target.clone(), pr))),
+                    Some(BranchMatch::IgnoringCase) if fallback.is_none() => {
+                        fallback = Some((target.clone(), pr));
+                    }
+                    _ => {}
+                }
+            }
+            if !prs.has_more {
+                break;
+            }
+        }
+    }
+    Ok(fallback)
+}
+
+/// Match the checked-out branch of `ops` to its open pull request.
+///
+/// Returns `None` when no remote is hosted on a known provider. Without a pull request the
+/// result still reports whether the branch is pushed, for offering to create one.
+pub async fn current_branch_pull_request(
+    provider: &dyn IntegrationProvider,
+    ops: &RepoOperations,
+    remotes: &[Remote],
+) -> Result<Option<CurrentBranchPullRequest>> {
+    let Some(primary) = primary_provider(remotes) else {
+        return Ok(None);
+    };
+    let remote_urls: Vec<String> = remotes.iter().filter_map(|r| r.url.clone()).collect();
+    let targets = same_provider_repos(&primary, &remote_urls);
+
+    let mut current = CurrentBranchPullRequest {
+        provider: primary.provider,
+        owner: primary.owner.clone(),
+        repo: primary.repo.clone(),
+        branch: ops.get_current_branch().await,
+        pull_request: None,
+        ahead: None,
+        behind: None,
+        upstream: None,
+        pushed: false,
+    };
+    let Some(branch) = current.branch.clone() else {
+        return Ok(Some(current));
+    };
+
+    // An unborn branch has nothing to look up yet
+    let Ok(info) = ops.get_branch(&branch, BranchType::Local).await else {
+        return Ok(Some(current));
+    };
+    current.pushed = info.upstream.is_some();
+    current.upstream = info.upstream;
+
+    // Only the repository the branch is pushed to can be a pull request's source
+    let upstream_repo = current.upstream.as_deref().and_then(|upstream| {
+        remotes
+            .iter()
+            .filter(|r| upstream.starts_with(&format!("{}/", r.name)))
+            .max_by_key(|r| r.name.len())
+            .and_then(|r| r.url.as_deref())
+            .and_then(detect_provider)
+    });
+    let source_repos: Vec<String> = match upstream_repo {
+        Some(repo) => vec![format!("{}/{}", repo.owner, repo.repo)],
+        None => targets
+            .iter()
+            .map(|t| format!("{}/{}", t.owner, t.repo))
+            .collect(),
+    };
+
+    if let Some((target, pr)) =
+        find_branch_pull_request(provider, &targets, &source_repos, &branch).await?
+    {
+        if let Some(sha) = &pr.head_sha {
+            if let Some((ahead, behind)) = ops.branch_divergence(&branch, sha).await? {
+                current.ahead = Some(ahead);
+                current.behind = Some(behind);
+            }
+        }
+        current.owner = target.owner;
+        current.repo = target.repo;
+        current.pull_request = Some(pr);
+    }
+    Ok(Some(current))
+}
+
 /// Get the secret key for storing provider token
 pub fn get_provider_token_key(provider: ProviderType) -> String {
     match provider {
@@ -109,6 +534,24 @@ pub fn get_provider_token_key(provider: ProviderType) -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::events::CurrentBranchPullRequestEvent;
+    use crate::models::{
+        BranchProtection, CiRunsPage, CommitStatus, CreateIssueOptions, CreatePrOptions,
+        IntegrationCommit, IntegrationLabel, IntegrationRepoInfo, IntegrationStatus,
+        IntegrationUser, Issue, IssueDetail, IssueState, IssuesPage, ListRemoteOptions,
+        MergePrOptions, NotificationsPage, PrState, PullRequest, PullRequestDetail,
+        PullRequestsPage, UnreadNotificationCount,
+    };
+    use crate::services::GitService;
+    use crate::storage::Database;
+    use async_trait::async_trait;
+    use chrono::Utc;
+    use std::collections::VecDeque;
+    use std::path::Path;
+    use std::process::Command;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::{Arc, Mutex};
+    use tempfile::TempDir;
 
     #[test]
     fn test_detect_github_https() {
@@ -155,4 +598,640 @@ mod tests {
         let result = detect_provider("https://unknown.com/owner/repo.git");
         assert!(result.is_none());
     }
+
+    // ==================== Branch Protection Tests ====================
+
+    /// Provider that only answers branch protection and pull request lookups
+    struct MockProvider {
+        protection: Option<BranchProtection>,
+        lookups: AtomicUsize,
+        prs: Vec<PullRequest>,
+        /// Mergeability returned by successive detail fetches; the last value repeats
+        mergeable: Mutex<VecDeque<Option<bool>>>,
+        detail_fetches: AtomicUsize,
+        invalidations: AtomicUsize,
+    }
+
+    impl MockProvider {
+        fn new(protection: Option<BranchProtection>) -> Self {
+            Self {
+                protection,
+                lookups: AtomicUsize::new(0),
+                prs: Vec::new(),
+                mergeable: Mutex::new(VecDeque::new()),
+                detail_fetches: AtomicUsize::new(0),
+                invalidations: AtomicUsize::new(0),
+            }
+        }
+
+        fn with_prs(prs: Vec<PullRequest>, mergeable: &[Option<bool>]) -> Self {
+            let provider = Self::new(None);
+            *provider.mergeable.lock().expect("lock") = mergeable.iter().copied().collect();
+            Self { prs, ..provider }
+        }
+
+        fn protected(allows_force_pushes: bool, allows_deletions: bool) -> Self {
+            Self::new(Some(BranchProtection {
+                protected: true,
+                allows_force_pushes,
+                allows_deletions,
+                required_status_checks: vec!["ci".to_string()],
+            }))
+        }
+    }
+
+    #[async_trait]
+    impl IntegrationProvider for MockProvider {
+        fn provider_type(&self) -> ProviderType {
+            ProviderType::GitHub
+        }
+        async fn is_connected(&self) -> bool {
+            true
+        }
+        async fn get_status(&self) -> Result<IntegrationStatus> {
+            Err(AxisError::Other(
+                "Not supported by MockProvider".to_string(),
+            ))
+        }
+        async fn disconnect(&self) -> Result<()> {
+            Err(AxisError::Other(
+                "Not supported by MockProvider".to_string(),
+            ))
+        }
+        async fn get_repo_info(&self, _: &str, _: &str) -> Result<IntegrationRepoInfo> {
+            Err(AxisError::Other(
+                "Not supported by MockProvider".to_string(),
+            ))
+        }
+        async fn get_commit(&self, _: &str, _: &str, _: &str) -> Result<IntegrationCommit> {
+            Err(AxisError::Other(
+                "Not supported by MockProvider".to_string(),
+            ))
+        }
+        async fn list_pull_requests(
+            &self,
+            _: &str,
+            _: &str,
+            _: PrState,
+            _: u32,
+        ) -> Result<PullRequestsPage> {
+            Ok(PullRequestsPage {
+                items: self.prs.clone(),
+                has_more: false,
+            })
+        }
+        async fn get_pull_request(
+            &self,
+            _: &str,
+            _: &str,
+            number: u32,
+        ) -> Result<PullRequestDetail> {
+            self.detail_fetches.fetch_add(1, Ordering::SeqCst);
+            let mergeable = {
+                let mut queue = self.mergeable.lock().expect("lock");
+                if queue.len() > 1 {
+                    queue.pop_front().flatten()
+                } else {
+                    queue.front().copied().flatten()
+                }
+            };
+            let base = self
+                .prs
+                .iter()
+                .find(|pr| pr.number == number)
+                .cloned()
+                .ok_or_else(|| AxisError::IntegrationError("not found".to_string()))?;
+            Ok(PullRequestDetail {
+                base,
+                body: None,
+                additions: 0,
+                deletions: 0,
+                changed_files: 0,
+                commits_count: 1,
+                comments_count: 0,
+                mergeable,
+                labels: Vec::new(),
+                assignees: Vec::new(),
+                reviewers: Vec::new(),
+            })
+        }
+        fn invalidate_pull_request(&self, _: &str, _: &str, _: u32) {
+            self.invalidations.fetch_add(1, Ordering::SeqCst);
+        }
+        async fn create_pull_request(
+            &self,
+            _: &str,
+            _: &str,
+            _: CreatePrOptions,
+        ) -> Result<PullRequest> {
+            Err(AxisError::Other(
+                "Not supported by MockProvider".to_string(),
+            ))
+        }
+        async fn merge_pull_request(
+            &self,
+            _: &str,
+            _: &str,
+            _: u32,
+            _: MergePrOptions,
+        ) -> Result<()> {
+            Err(AxisError::Other(
+                "Not supported by MockProvider".to_string(),
+            ))
+        }
+        async fn list_issues(&self, _: &str, _: &str, _: IssueState, _: u32) -> Result<IssuesPage> {
+            Err(AxisError::Other(
+                "Not supported by MockProvider".to_string(),
+            ))
+        }
+        async fn get_issue(&self, _: &str, _: &str, _: u32) -> Result<IssueDetail> {
+            Err(AxisError::Other(
+                "Not supported by MockProvider".to_string(),
+            ))
+        }
+        async fn create_issue(&self, _: &str, _: &str, _: CreateIssueOptions) -> Result<Issue> {
+            Err(AxisError::Other(
+                "Not supported by MockProvider".to_string(),
+            ))
+        }
+        async fn list_ci_runs(&self, _: &str, _: &str, _: u32) -> Result<CiRunsPage> {
+            Err(AxisError::Other(
+                "Not supported by MockProvider".to_string(),
+            ))
+        }
+        async fn get_commit_status(&self, _: &str, _: &str, _: &str) -> Result<CommitStatus> {
+            Err(AxisError::Other(
+                "Not supported by MockProvider".to_string(),
+            ))
+        }
+        async fn rerun_ci_run(&self, _: &str, _: &str, _: &str, _: bool) -> Result<()> {
+            Err(AxisError::Other(
+                "Not supported by MockProvider".to_string(),
+            ))
+        }
+        async fn get_branch_protection(
+            &self,
+            _: &str,
+            _: &str,
+            _: &str,
+        ) -> Result<BranchProtection> {
+            self.lookups.fetch_add(1, Ordering::SeqCst);
+            self.protection
+                .clone()
+                .ok_or_else(|| AxisError::IntegrationError("API unavailable".to_string()))
+        }
+        async fn list_notifications(
+            &self,
+            _: &str,
+            _: &str,
+            _: bool,
+            _: u32,
+        ) -> Result<NotificationsPage> {
+            Err(AxisError::Other(
+                "Not supported by MockProvider".to_string(),
+            ))
+        }
+        async fn mark_notification_read(&self, _: &str) -> Result<()> {
+            Err(AxisError::Other(
+                "Not supported by MockProvider".to_string(),
+            ))
+        }
+        async fn mark_all_notifications_read(&self, _: &str, _: &str) -> Result<()> {
+            Err(AxisError::Other(
+                "Not supported by MockProvider".to_string(),
+            ))
+        }
+        async fn get_unread_count(&self, _: &str, _: &str) -> Result<UnreadNotificationCount> {
+            Err(AxisError::Other(
+                "Not supported by MockProvider".to_string(),
+            ))
+        }
+        async fn list_labels(&self, _: &str, _: &str) -> Result<Vec<IntegrationLabel>> {
+            Err(AxisError::Other(
+                "Not supported by MockProvider".to_string(),
+            ))
+        }
+    }
+
+    fn force_push(branch: &str) -> Vec<(String, ProtectedBranchAction)> {
+        vec![(branch.to_string(), ProtectedBranchAction::ForcePush)]
+    }
+
+    #[tokio::test]
+    async fn test_guard_warns_on_protected_force_push() {
+        let provider = MockProvider::protected(false, false);
+
+        let result = guard_branch_protection(&provider, "o", "r", &force_push("main"), false).await;
+
+        match result {
+            Err(AxisError::ProtectedBranch { branch, reason }) => {
+                assert_eq!(branch, "main");
+                assert!(reason.contains("force"));
+            }
+            other => panic!("expected ProtectedBranch, got {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_guard_warns_on_protected_delete() {
+        let provider = MockProvider::protected(true, false);
+        let targets = vec![("main".to_string(), ProtectedBranchAction::Delete)];
+
+        let result = guard_branch_protection(&provider, "o", "r", &targets, false).await;
+
+        assert!(matches!(result, Err(AxisError::ProtectedBranch { .. })));
+    }
+
+    #[tokio::test]
+    async fn test_guard_allows_permitted_force_push() {
+        let provider = MockProvider::protected(true, false);
+
+        guard_branch_protection(&provider, "o", "r", &force_push("main"), false)
+            .await
+            .expect("force pushes are allowed");
+    }
+
+    #[tokio::test]
+    async fn test_guard_allows_unprotected_branch() {
+        let provider = MockProvider::new(Some(BranchProtection::unprotected()));
+
+        guard_branch_protection(&provider, "o", "r", &force_push("feature"), false)
+            .await
+            .expect("unprotected branch");
+    }
+
+    #[tokio::test]
+    async fn test_guard_override_skips_lookup() {
+        let provider = MockProvider::protected(false, false);
+
+        guard_branch_protection(&provider, "o", "r", &force_push("main"), true)
+            .await
+            .expect("override proceeds");
+
+        assert_eq!(provider.lookups.load(Ordering::SeqCst), 0);
+    }
+
+    #[tokio::test]
+    async fn test_guard_fails_open_on_api_error() {
+        let provider = MockProvider::new(None);
+
+        guard_branch_protection(&provider, "o", "r", &force_push("main"), false)
+            .await
+            .expect("lookup errors proceed");
+
+        assert_eq!(provider.lookups.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn test_push_protection_targets() {
+        let refspecs = |specs: &[&str]| specs.iter().map(|s| (*s).to_string()).collect::<Vec<_>>();
+
+        // Plain pushes are never checked
+        assert!(push_protection_targets(&refspecs(&["main", "refs/heads/dev"]), false).is_empty());
+
+        assert_eq!(
+            push_protection_targets(&refspecs(&["refs/heads/main:refs/heads/main"]), true),
+            force_push("main")
+        );
+        assert_eq!(
+            push_protection_targets(&refspecs(&["+feature:release", "dev"]), false),
+            force_push("release")
+        );
+        assert_eq!(
+            push_protection_targets(&refspecs(&[":old", "refs/tags/v1"]), false),
+            vec![("old".to_string(), ProtectedBranchAction::Delete)]
+        );
+    }
+
+    // ==================== Pull Request Refresh Tests ====================
+
+    fn open_pr(number: u32, source_branch: &str, source_repo: Option<&str>) -> PullRequest {
+        PullRequest {
+            provider: ProviderType::GitHub,
+            number,
+            title: format!("PR {number}"),
+            state: PrState::Open,
+            author: IntegrationUser::default(),
+            source_branch: source_branch.to_string(),
+            source_repo: source_repo.map(str::to_string),
+            target_branch: "main".to_string(),
+            head_sha: None,
+            draft: false,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+            url: String::new(),
+        }
+    }
+
+    fn fast_poll(max_attempts: u32) -> MergeabilityPoll {
+        MergeabilityPoll {
+            initial_delay: Duration::from_millis(1),
+            max_delay: Duration::from_millis(2),
+            max_attempts,
+        }
+    }
+
+    async fn refresh(
+        provider: &MockProvider,
+        source_repo: &str,
+        branches: &[&str],
+        poll: &MergeabilityPoll,
+    ) -> (usize, Vec<PullRequestDetail>) {
+        let branches: Vec<String> = branches.iter().map(|b| (*b).to_string()).collect();
+        let mut refreshed = Vec::new();
+        let count = refresh_pushed_pull_requests(
+            provider,
+            "o",
+            "r",
+            source_repo,
+            &branches,
+            poll,
+            &mut |detail| refreshed.push(detail),
+        )
+        .await
+        .expect("refresh");
+        (count, refreshed)
+    }
+
+    #[tokio::test]
+    async fn test_push_invalidates_matching_pull_request() {
+        let provider = MockProvider::with_prs(
+            vec![
+                open_pr(1, "feature", Some("o/r")),
+                open_pr(2, "other", None),
+            ],
+            &[Some(true)],
+        );
+
+        let (count, refreshed) = refresh(&provider, "o/r", &["feature"], &fast_poll(3)).await;
+
+        assert_eq!(count, 1);
+        assert_eq!(provider.invalidations.load(Ordering::SeqCst), 1);
+        assert_eq!(refreshed.len(), 1);
+        assert_eq!(refreshed[0].base.number, 1);
+        assert_eq!(refreshed[0].mergeable, Some(true));
+    }
+
+    #[tokio::test]
+    async fn test_mergeability_polls_until_computed() {
+        let provider =
+            MockProvider::with_prs(vec![open_pr(1, "feature", None)], &[None, None, Some(true)]);
+
+        let (_, refreshed) = refresh(&provider, "o/r", &["feature"], &fast_poll(6)).await;
+
+        assert_eq!(provider.detail_fetches.load(Ordering::SeqCst), 3);
+        let states: Vec<_> = refreshed.iter().map(|d| d.mergeable).collect();
+        assert_eq!(states, vec![None, Some(true)]);
+    }
+
+    #[tokio::test]
+    async fn test_mergeability_poll_stops_at_bound() {
+        let provider = MockProvider::with_prs(vec![open_pr(1, "feature", None)], &[None]);
+
+        let mut refreshed = Vec::new();
+        let mergeable = poll_mergeability(&provider, "o", "r", 1, &fast_poll(4), &mut |d| {
+            refreshed.push(d);
+        })
+        .await
+        .expect("poll");
+
+        assert_eq!(mergeable, None);
+        assert_eq!(provider.detail_fetches.load(Ordering::SeqCst), 4);
+        assert_eq!(refreshed.len(), 1);
+    }
+
+    #[test]
+    fn test_mergeability_poll_backoff_is_capped() {
+        let poll = MergeabilityPoll::default();
+        let delays: Vec<_> = (0..6)
+            .map(|attempt| poll.delay(attempt).as_secs())
+            .collect();
+        assert_eq!(delays, vec![1, 2, 4, 8, 8, 8]);
+    }
+
+    #[tokio::test]
+    async fn test_push_without_pull_request_does_nothing() {
+        let provider = MockProvider::with_prs(vec![open_pr(1, "feature", None)], &[Some(true)]);
+
+        let (count, refreshed) = refresh(&provider, "o/r", &["unrelated"], &fast_poll(3)).await;
+
+        assert_eq!(count, 0);
+        assert!(refreshed.is_empty());
+        assert_eq!(provider.detail_fetches.load(Ordering::SeqCst), 0);
+        assert_eq!(provider.invalidations.load(Ordering::SeqCst), 0);
+    }
+
+    #[tokio::test]
+    async fn test_push_to_fork_matches_fork_pull_request_only() {
+        let provider = MockProvider::with_prs(
+            vec![
+                open_pr(1, "feature", Some("me/r")),
+                open_pr(2, "feature", Some("someone/r")),
+            ],
+            &[Some(false)],
+        );
+
+        let (count, refreshed) = refresh(&provider, "Me/r", &["feature"], &fast_poll(3)).await;
+
+        assert_eq!(count, 1);
+        assert_eq!(refreshed[0].base.number, 1);
+    }
+
+    #[test]
+    fn test_pushed_branches_uses_destination_names() {
+        let refspecs = |specs: &[&str]| specs.iter().map(|s| (*s).to_string()).collect::<Vec<_>>();
+
+        assert_eq!(
+            pushed_branches(&refspecs(&[
+                "main",
+                "+local:refs/heads/renamed",
+                ":deleted",
+                "refs/tags/v1",
+            ])),
+            vec!["main".to_string(), "renamed".to_string()]
+        );
+    }
+
+    // ==================== Current Branch Pull Request Tests ====================
+
+    fn git(dir: &Path, args: &[&str]) -> String {
+        let output = Command::new("git")
+            .args(args)
+            .current_dir(dir)
+            .output()
+            .expect("should run git");
+        assert!(output.status.success(), "git {args:?} failed");
+        String::from_utf8_lossy(&output.stdout).trim().to_string()
+    }
+
+    fn commit(dir: &Path, file: &str) -> String {
+        std::fs::write(dir.join(file), file).expect("should write");
+        git(dir, &["add", "."]);
+        git(dir, &["commit", "-m", file]);
+        git(dir, &["rev-parse", "HEAD"])
+    }
+
+    fn pr_with_head(number: u32, source_branch: &str, head_sha: &str) -> PullRequest {
+        PullRequest {
+            head_sha: Some(head_sha.to_string()),
+            ..open_pr(number, source_branch, Some("owner/repo"))
+        }
+    }
+
+    fn github(owner: &str, repo: &str) -> DetectedProvider {
+        DetectedProvider {
+            provider: ProviderType::GitHub,
+            owner: owner.to_string(),
+            repo: repo.to_string(),
+        }
+    }
+
+    /// Repository with a GitHub origin and `feature` checked out, tracking `origin/feature`
+    fn pushed_feature_repo() -> (TempDir, RepoOperations) {
+        let tmp = TempDir::new().expect("should create temp dir");
+        let dir = tmp.path();
+        git(dir, &["init", "-b", "main"]);
+        git(dir, &["config", "user.email", "test@test.com"]);
+        git(dir, &["config", "user.name", "Test User"]);
+        commit(dir, "a.txt");
+        git(
+            dir,
+            &[
+                "remote",
+                "add",
+                "origin",
+                "https://github.com/owner/repo.git",
+            ],
+        );
+        git(dir, &["checkout", "-b", "feature"]);
+        commit(dir, "b.txt");
+        git(dir, &["update-ref", "refs/remotes/origin/feature", "HEAD"]);
+        git(dir, &["branch", "--set-upstream-to=origin/feature"]);
+
+        let service = GitService::new_for_test(dir).expect("should open");
+        (tmp, RepoOperations::new(Arc::new(service)))
+    }
+
+    async fn current(provider: &MockProvider, ops: &RepoOperations) -> CurrentBranchPullRequest {
+        let remotes = ops
+            .list_remotes(ListRemoteOptions::default())
+            .await
+            .expect("should list remotes");
+        current_branch_pull_request(provider, ops, &remotes)
+            .await
+            .expect("should match")
+            .expect("origin is on GitHub")
+    }
+
+    #[tokio::test]
+    async fn test_find_branch_pull_request_accepts_fork_labels_and_case() {
+        let provider = MockProvider::with_prs(
+            vec![
+                open_pr(1, "Feature", Some("owner/repo")),
+                open_pr(2, "contributor:feature", None),
+                open_pr(3, "feature", Some("other/repo")),
+                open_pr(4, "feature", Some("owner/repo")),
+            ],
+            &[],
+        );
+        let targets = [github("owner", "repo")];
+        let find = |source_repo: &str| {
+            let source_repos = vec![source_repo.to_string()];
+            let provider = &provider;
+            let targets = &targets;
+            async move {
+                find_branch_pull_request(provider, targets, &source_repos, "feature")
+                    .await
+                    .expect("should list")
+                    .map(|(_, pr)| pr.number)
+            }
+        };
+
+        assert_eq!(find("Contributor/repo").await, Some(2));
+        // Exact name wins over the earlier case-insensitive match
+        assert_eq!(find("owner/repo").await, Some(4));
+        assert_eq!(find("nobody/repo").await, None);
+
+        let case_only = MockProvider::with_prs(vec![open_pr(1, "Feature", None)], &[]);
+        let found =
+            find_branch_pull_request(&case_only, &targets, &["owner/repo".to_string()], "feature")
+                .await
+                .expect("should list");
+        assert_eq!(found.map(|(_, pr)| pr.number), Some(1));
+    }
+
+    #[tokio::test]
+    async fn test_current_branch_pr_reports_divergence_from_pr_head() {
+        let (tmp, ops) = pushed_feature_repo();
+        let head = git(tmp.path(), &["rev-parse", "HEAD"]);
+        let provider = MockProvider::with_prs(vec![pr_with_head(7, "feature", &head)], &[]);
+
+        let event = CurrentBranchPullRequestEvent {
+            path: tmp.path().to_string_lossy().to_string(),
+            current: current(&provider, &ops).await,
+        };
+        let payload = serde_json::to_value(&event).expect("should serialize");
+        assert_eq!(payload["current"]["branch"], "feature");
+        assert_eq!(payload["current"]["pullRequest"]["number"], 7);
+        assert_eq!(payload["current"]["pullRequest"]["headSha"], head.as_str());
+        assert_eq!(payload["current"]["ahead"], 0);
+        assert_eq!(payload["current"]["behind"], 0);
+        assert_eq!(payload["current"]["pushed"], true);
+
+        commit(tmp.path(), "c.txt");
+        let advanced = current(&provider, &ops).await;
+        assert_eq!(advanced.ahead, Some(1));
+        assert_eq!(advanced.behind, Some(0));
+
+        // A head commit that was never fetched leaves the divergence unknown
+        let unfetched = "0123456789abcdef0123456789abcdef01234567";
+        let provider = MockProvider::with_prs(vec![pr_with_head(7, "feature", unfetched)], &[]);
+        let unknown = current(&provider, &ops).await;
+        assert!(unknown.pull_request.is_some());
+        assert_eq!(unknown.ahead, None);
+    }
+
+    #[tokio::test]
+    async fn test_current_branch_without_pr_reports_pushed_state() {
+        let (tmp, ops) = pushed_feature_repo();
+        let head = git(tmp.path(), &["rev-parse", "HEAD"]);
+        let provider = MockProvider::with_prs(vec![pr_with_head(7, "feature", &head)], &[]);
+
+        git(tmp.path(), &["checkout", "-b", "topic"]);
+        let local_only = current(&provider, &ops).await;
+        assert_eq!(local_only.branch.as_deref(), Some("topic"));
+        assert!(local_only.pull_request.is_none());
+        assert!(!local_only.pushed);
+        assert_eq!(
+            (local_only.owner.as_str(), local_only.repo.as_str()),
+            ("owner", "repo")
+        );
+
+        git(
+            tmp.path(),
+            &["update-ref", "refs/remotes/origin/topic", "HEAD"],
+        );
+        git(tmp.path(), &["branch", "--set-upstream-to=origin/topic"]);
+        let pushed = current(&provider, &ops).await;
+        assert!(pushed.pull_request.is_none());
+        assert!(pushed.pushed);
+        assert_eq!(pushed.upstream.as_deref(), Some("origin/topic"));
+    }
+
+    #[tokio::test]
+    async fn test_settle_head_change_keeps_only_latest() {
+        let database = Database::open_in_memory().expect("should open database");
+        let service = Arc::new(IntegrationService::new(Arc::new(database)));
+        let path = Path::new("/repo");
+
+        let first = tokio::spawn({
+            let service = Arc::clone(&service);
+            async move { service.settle_head_change(path).await }
+        });
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        let second = service.settle_head_change(path).await;
+
+        assert!(!first.await.expect("should join"));
+        assert!(second);
+    }
 }
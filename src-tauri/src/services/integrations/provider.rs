@@ -1,11 +1,11 @@
 use async_trait::async_trait;
 
-use crate::error::Result;
+use crate::error::{AxisError, Result};
 use crate::models::{
-    CiRunsPage, CommitStatus, CreateIssueOptions, CreatePrOptions, IntegrationCommit,
-    IntegrationLabel, IntegrationRepoInfo, IntegrationStatus, Issue, IssueDetail, IssueState,
-    IssuesPage, MergePrOptions, NotificationsPage, PrState, ProviderType, PullRequest,
-    PullRequestDetail, PullRequestsPage,
+    BranchProtection, CiRunsPage, CommitStatus, CreateIssueOptions, CreatePrOptions,
+    IntegrationCommit, IntegrationLabel, IntegrationRepoInfo, IntegrationStatus, Issue,
+    IssueDetail, IssueState, IssuesPage, MergePrOptions, NotificationsPage, PrState, ProviderType,
+    PullRequest, PullRequestDetail, PullRequestsPage, UnreadNotificationCount,
 };
 
 /// Trait for integration providers (GitHub, GitLab, Bitbucket, Gitea)
@@ -34,6 +34,12 @@ pub trait IntegrationProvider: Send + Sync {
     /// Get commit information (for avatar fetching)
     async fn get_commit(&self, owner: &str, repo: &str, sha: &str) -> Result<IntegrationCommit>;
 
+    /// Avatar URL of the account with the public email `email`; providers without user search
+    /// find nobody
+    async fn find_user_avatar(&self, _email: &str) -> Result<Option<String>> {
+        Ok(None)
+    }
+
     // Pull Request operations
     /// List pull requests with pagination
     async fn list_pull_requests(
@@ -52,6 +58,9 @@ pub trait IntegrationProvider: Send + Sync {
         number: u32,
     ) -> Result<PullRequestDetail>;
 
+    /// Drop cached data for one pull request, e.g. after its source branch was pushed
+    fn invalidate_pull_request(&self, _owner: &str, _repo: &str, _number: u32) {}
+
     /// Create a pull request
     async fn create_pull_request(
         &self,
@@ -97,6 +106,24 @@ pub trait IntegrationProvider: Send + Sync {
     /// Get commit status (combined check runs)
     async fn get_commit_status(&self, owner: &str, repo: &str, sha: &str) -> Result<CommitStatus>;
 
+    /// Re-run a CI workflow run, or only its failed jobs
+    async fn rerun_ci_run(
+        &self,
+        owner: &str,
+        repo: &str,
+        run_id: &str,
+        failed_only: bool,
+    ) -> Result<()>;
+
+    // Branch operations
+    /// Get protection rules for a branch; unprotected or unknown branches report no rules
+    async fn get_branch_protection(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+    ) -> Result<BranchProtection>;
+
     // Notification operations
     /// List notifications with pagination
     async fn list_notifications(
@@ -113,10 +140,20 @@ pub trait IntegrationProvider: Send + Sync {
     /// Mark all notifications as read
     async fn mark_all_notifications_read(&self, owner: &str, repo: &str) -> Result<()>;
 
-    /// Get unread notification count
-    async fn get_unread_count(&self, owner: &str, repo: &str) -> Result<u32>;
+    /// Get the exact unread notification count with a per-reason breakdown
+    async fn get_unread_count(&self, owner: &str, repo: &str) -> Result<UnreadNotificationCount>;
 
     // Label operations
     /// List repository labels
     async fn list_labels(&self, owner: &str, repo: &str) -> Result<Vec<IntegrationLabel>>;
+
+    // Account operations
+    /// Add an ASCII-armored GPG public key to the connected account, so the provider shows
+    /// commits signed with it as verified
+    async fn add_gpg_key(&self, _armored_public_key: &str) -> Result<()> {
+        Err(AxisError::IntegrationError(format!(
+            "{} does not support adding GPG keys",
+            self.provider_type()
+        )))
+    }
 }
@@ -1,15 +1,31 @@
 use std::collections::HashMap;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
+use std::time::Duration;
 
+use parking_lot::Mutex;
 use tauri::AppHandle;
+use tauri_specta::Event;
 use tokio::sync::RwLock;
 
 use crate::error::{AxisError, Result};
-use crate::models::ProviderType;
+use crate::events::PullRequestRefreshedEvent;
+use crate::models::{
+    CurrentBranchPullRequest, ListRemoteOptions, ProtectedBranchAction, ProviderType,
+};
+use crate::services::ops::RepoOperations;
 use crate::storage::Database;
 
 use super::github::{GitHubProvider, OAuthFlow};
-use super::{get_provider_token_key, IntegrationProvider};
+use super::{
+    current_branch_pull_request, detect_provider, get_provider_token_key, guard_branch_protection,
+    primary_provider, refresh_pushed_pull_requests, same_provider_repos, IntegrationProvider,
+    MergeabilityPoll,
+};
+
+/// Quiet period after HEAD moves before the current branch's pull request is looked up again,
+/// so a rebase or a burst of checkouts costs a single lookup
+const HEAD_CHANGE_DEBOUNCE: Duration = Duration::from_millis(500);
 
 /// Central service for managing integration providers.
 /// Handles OAuth flows, token storage, and provider lifecycle.
@@ -17,6 +33,8 @@ pub struct IntegrationService {
     database: Arc<Database>,
     providers: RwLock<HashMap<ProviderType, Arc<dyn IntegrationProvider>>>,
     oauth_flow: RwLock<Option<OAuthFlow>>,
+    /// Latest HEAD change per repository, for debouncing pull request lookups
+    head_changes: Mutex<HashMap<PathBuf, u64>>,
 }
 
 impl IntegrationService {
@@ -26,6 +44,7 @@ impl IntegrationService {
             database,
             providers: RwLock::new(HashMap::new()),
             oauth_flow: RwLock::new(None),
+            head_changes: Mutex::new(HashMap::new()),
         }
     }
 
@@ -149,6 +168,122 @@ impl IntegrationService {
         }
     }
 
+    /// Check remote branch protection before a force push or deletion.
+    /// Remotes without a supported provider, or without a connected integration, are not checked.
+    pub async fn check_branch_protection(
+        &self,
+        remote_url: &str,
+        targets: &[(String, ProtectedBranchAction)],
+        override_check: bool,
+    ) -> Result<()> {
+        if targets.is_empty() || override_check {
+            return Ok(());
+        }
+        let Some(detected) = detect_provider(remote_url) else {
+            return Ok(());
+        };
+        let Ok(provider) = self.get_provider(detected.provider).await else {
+            return Ok(());
+        };
+
+        guard_branch_protection(
+            provider.as_ref(),
+            &detected.owner,
+            &detected.repo,
+            targets,
+            override_check,
+        )
+        .await
+    }
+
+    /// Refresh pull requests whose source branch was pushed to `pushed_url`, emitting
+    /// `PullRequestRefreshedEvent` for each update.
+    ///
+    /// Pull requests are looked up in every repository in `remote_urls` on the same
+    /// provider, so a push to a fork refreshes the pull request opened against upstream.
+    pub async fn refresh_pull_requests_after_push(
+        &self,
+        app_handle: &AppHandle,
+        pushed_url: &str,
+        remote_urls: &[String],
+        branches: &[String],
+    ) -> Result<()> {
+        let Some(pushed) = detect_provider(pushed_url) else {
+            return Ok(());
+        };
+        let provider = self.get_provider(pushed.provider).await?;
+        if !provider.is_connected().await {
+            return Ok(());
+        }
+        let source_repo = format!("{}/{}", pushed.owner, pushed.repo);
+
+        let targets = same_provider_repos(&pushed, remote_urls);
+
+        let poll = MergeabilityPoll::default();
+        for target in targets {
+            let mut emit = |pull_request| {
+                let event = PullRequestRefreshedEvent {
+                    provider: target.provider,
+                    owner: target.owner.clone(),
+                    repo: target.repo.clone(),
+                    pull_request,
+                };
+                if let Err(e) = event.emit(app_handle) {
+                    log::warn!("Failed to emit pull request refresh: {e}");
+                }
+            };
+            let refreshed = refresh_pushed_pull_requests(
+                provider.as_ref(),
+                &target.owner,
+                &target.repo,
+                &source_repo,
+                branches,
+                &poll,
+                &mut emit,
+            )
+            .await?;
+            if refreshed > 0 {
+                log::debug!(
+                    "Refreshed {refreshed} pull request(s) in {}/{} after push",
+                    target.owner,
+                    target.repo
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Match the checked-out branch to its open pull request. `None` when the repository is
+    /// not hosted on a known provider or the provider is not connected.
+    pub async fn current_branch_pull_request(
+        &self,
+        ops: &RepoOperations,
+    ) -> Result<Option<CurrentBranchPullRequest>> {
+        let remotes = ops.list_remotes(ListRemoteOptions::default()).await?;
+        let Some(primary) = primary_provider(&remotes) else {
+            return Ok(None);
+        };
+        let provider = self.get_provider(primary.provider).await?;
+        if !provider.is_connected().await {
+            return Ok(None);
+        }
+        current_branch_pull_request(provider.as_ref(), ops, &remotes).await
+    }
+
+    /// Wait out the debounce period after a HEAD change in `repo_path`. Returns `false` when
+    /// a newer change arrived meanwhile, which then does the lookup instead.
+    pub async fn settle_head_change(&self, repo_path: &Path) -> bool {
+        let generation = {
+            let mut changes = self.head_changes.lock();
+            let generation = changes.entry(repo_path.to_path_buf()).or_default();
+            *generation += 1;
+            *generation
+        };
+        tokio::time::sleep(HEAD_CHANGE_DEBOUNCE).await;
+        self.head_changes.lock().get(repo_path) == Some(&generation)
+    }
+
     /// Create a provider instance
     fn create_provider(&self, provider_type: ProviderType) -> Result<Arc<dyn IntegrationProvider>> {
         match provider_type {
@@ -0,0 +1,382 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::Utc;
+use strum::Display;
+
+use crate::error::Result;
+use crate::storage::{Database, TransientArtifactRow};
+
+/// How often the janitor sweeps the artifact manifest while the app runs
+pub const JANITOR_SWEEP_INTERVAL: Duration = Duration::from_secs(60 * 60);
+
+/// Feature that created a transient artifact
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Display)]
+pub enum ArtifactOwner {
+    /// Output file of `create_archive` while it is being written
+    Archive,
+    /// Target directory of a clone in progress
+    Clone,
+    /// Avatar image being downloaded into the cache
+    AvatarDownload,
+}
+
+/// What a sweep did
+#[derive(Debug, Default)]
+pub struct JanitorReport {
+    /// Paths that were deleted
+    pub reclaimed: Vec<PathBuf>,
+    /// Paths left alone because they no longer look like ours
+    pub refused: Vec<PathBuf>,
+}
+
+/// Tracks transient on-disk artifacts in the database so that files left behind by a
+/// crash or force-quit are cleaned up by a later run.
+///
+/// Each process gets a fresh instance id; rows recorded by another instance, or past
+/// their lifetime, are orphans. An orphan is only deleted if it lives under the app
+/// data directory or still has the identity it had when it was registered.
+pub struct Janitor {
+    database: Arc<Database>,
+    app_data_dir: PathBuf,
+    instance_id: String,
+}
+
+impl Janitor {
+    pub fn new(database: Arc<Database>, app_data_dir: &Path) -> Self {
+        Self {
+            database,
+            app_data_dir: app_data_dir.to_path_buf(),
+            instance_id: uuid::Uuid::new_v4().to_string(),
+        }
+    }
+
+    /// Register an existing file or directory. Dropping the returned guard deletes it
+    /// unless it was released first.
+    pub fn track(
+        &self,
+        path: &Path,
+        owner: ArtifactOwner,
+        lifetime: Option<Duration>,
+    ) -> Result<TrackedArtifact> {
+        let artifact = TransientArtifactRow {
+            path: path.to_path_buf(),
+            owner: owner.to_string(),
+            instance_id: self.instance_id.clone(),
+            created_at: Utc::now(),
+            lifetime_secs: lifetime.map(|lifetime| lifetime.as_secs()),
+            fingerprint: fingerprint(path),
+        };
+        self.database.register_transient_artifact(&artifact)?;
+
+        Ok(TrackedArtifact {
+            database: Arc::clone(&self.database),
+            path: path.to_path_buf(),
+            owner,
+            released: false,
+        })
+    }
+
+    /// Delete orphaned and expired artifacts from the manifest
+    pub fn sweep(&self) -> Result<JanitorReport> {
+        let now = Utc::now();
+        let mut report = JanitorReport::default();
+
+        for artifact in self.database.list_transient_artifacts()? {
+            let orphaned = artifact.instance_id != self.instance_id;
+            let expired = artifact.lifetime_secs.is_some_and(|secs| {
+                let age = now.signed_duration_since(artifact.created_at);
+                age.num_seconds() >= i64::try_from(secs).unwrap_or(i64::MAX)
+            });
+            if !orphaned && !expired {
+                continue;
+            }
+
+            let path = &artifact.path;
+            if fs::symlink_metadata(path).is_err() {
+                self.database.remove_transient_artifact(path)?;
+                continue;
+            }
+
+            if !self.is_ours(&artifact) {
+                log::warn!(
+                    "Janitor: refusing to delete {} artifact {}: it no longer looks like ours",
+                    artifact.owner,
+                    path.display()
+                );
+                report.refused.push(path.clone());
+                self.database.remove_transient_artifact(path)?;
+                continue;
+            }
+
+            match remove_path(path) {
+                Ok(()) => {
+                    log::info!(
+                        "Janitor: reclaimed {} artifact {}",
+                        artifact.owner,
+                        path.display()
+                    );
+                    report.reclaimed.push(path.clone());
+                    self.database.remove_transient_artifact(path)?;
+                }
+                Err(e) => {
+                    log::warn!("Janitor: failed to delete {}: {e}", path.display());
+                }
+            }
+        }
+
+        Ok(report)
+    }
+
+    /// Whether an artifact's path is safe to delete: inside the app data directory,
+    /// or with the same identity it had when registered.
+    fn is_ours(&self, artifact: &TransientArtifactRow) -> bool {
+        if let (Some(path), Ok(root)) = (
+            canonical_parent_join(&artifact.path),
+            self.app_data_dir.canonicalize(),
+        ) {
+            if path != root && path.starts_with(&root) {
+                return true;
+            }
+        }
+
+        match (&artifact.fingerprint, fingerprint(&artifact.path)) {
+            (Some(recorded), Some(current)) => *recorded == current,
+            _ => false,
+        }
+    }
+}
+
+/// Guard for a registered artifact. Dropping it without `release` deletes the
+/// artifact, so error paths clean up after themselves.
+pub struct TrackedArtifact {
+    database: Arc<Database>,
+    path: PathBuf,
+    owner: ArtifactOwner,
+    released: bool,
+}
+
+impl TrackedArtifact {
+    #[cfg(test)]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Keep the artifact on disk and drop it from the manifest
+    pub fn release(mut self) {
+        self.released = true;
+        self.forget_row();
+    }
+
+    fn forget_row(&self) {
+        if let Err(e) = self.database.remove_transient_artifact(&self.path) {
+            log::warn!(
+                "Failed to unregister {} artifact {}: {e}",
+                self.owner,
+                self.path.display()
+            );
+        }
+    }
+}
+
+impl Drop for TrackedArtifact {
+    fn drop(&mut self) {
+        if self.released {
+            return;
+        }
+        match remove_path(&self.path) {
+            Ok(()) => self.forget_row(),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => self.forget_row(),
+            // Keep the row so the next sweep retries
+            Err(e) => log::warn!(
+                "Failed to clean up {} artifact {}: {e}",
+                self.owner,
+                self.path.display()
+            ),
+        }
+    }
+}
+
+/// Identity of a path that survives renames of its parents but not replacement
+fn fingerprint(path: &Path) -> Option<String> {
+    let metadata = fs::symlink_metadata(path).ok()?;
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::MetadataExt;
+        Some(format!("{}:{}", metadata.dev(), metadata.ino()))
+    }
+
+    #[cfg(not(unix))]
+    {
+        let created = metadata.created().ok()?;
+        let nanos = created
+            .duration_since(std::time::UNIX_EPOCH)
+            .ok()?
+            .as_nanos();
+        Some(format!("created:{nanos}:{}", metadata.is_dir()))
+    }
+}
+
+/// Canonicalize the parent so a symlink at `path` itself is not followed
+fn canonical_parent_join(path: &Path) -> Option<PathBuf> {
+    let parent = path.parent()?.canonicalize().ok()?;
+    Some(parent.join(path.file_name()?))
+}
+
+/// Delete a file, symlink or directory tree
+fn remove_path(path: &Path) -> std::io::Result<()> {
+    let metadata = fs::symlink_metadata(path)?;
+    if metadata.is_dir() {
+        fs::remove_dir_all(path)
+    } else {
+        fs::remove_file(path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    struct Fixture {
+        database: Arc<Database>,
+        app_data: TempDir,
+        user: TempDir,
+    }
+
+    fn fixture() -> Fixture {
+        Fixture {
+            database: Arc::new(Database::open_in_memory().expect("should open db")),
+            app_data: TempDir::new().expect("should create temp dir"),
+            user: TempDir::new().expect("should create temp dir"),
+        }
+    }
+
+    impl Fixture {
+        fn janitor(&self) -> Janitor {
+            Janitor::new(Arc::clone(&self.database), self.app_data.path())
+        }
+    }
+
+    /// Track one artifact per subsystem the way each creator registers it:
+    /// an archive output chosen by the user, a clone target and an avatar download.
+    fn track_all(fx: &Fixture, janitor: &Janitor) -> Vec<TrackedArtifact> {
+        let archive = fx.user.path().join("export.zip");
+        fs::write(&archive, "partial").expect("should write");
+
+        let clone = fx.user.path().join("cloned");
+        fs::create_dir_all(clone.join(".git")).expect("should create dir");
+        fs::write(clone.join(".git/HEAD"), "ref: refs/heads/main").expect("should write");
+
+        let avatars = fx.app_data.path().join("avatars");
+        fs::create_dir_all(&avatars).expect("should create dir");
+        let avatar = avatars.join(".gravatar_abc.png.part");
+        fs::write(&avatar, "png").expect("should write");
+
+        vec![
+            janitor
+                .track(&archive, ArtifactOwner::Archive, None)
+                .expect("should track"),
+            janitor
+                .track(&clone, ArtifactOwner::Clone, None)
+                .expect("should track"),
+            janitor
+                .track(
+                    &avatar,
+                    ArtifactOwner::AvatarDownload,
+                    Some(Duration::from_secs(600)),
+                )
+                .expect("should track"),
+        ]
+    }
+
+    #[test]
+    fn test_sweep_reclaims_artifacts_of_crashed_instance() {
+        let fx = fixture();
+        let crashed = fx.janitor();
+        let artifacts = track_all(&fx, &crashed);
+        let paths: Vec<PathBuf> = artifacts.iter().map(|a| a.path().to_path_buf()).collect();
+        // A crash never runs the guards' destructors
+        artifacts.into_iter().for_each(std::mem::forget);
+
+        let mut report = fx.janitor().sweep().expect("should sweep");
+
+        report.reclaimed.sort();
+        let mut expected = paths.clone();
+        expected.sort();
+        assert_eq!(report.reclaimed, expected);
+        assert!(report.refused.is_empty());
+        assert!(paths.iter().all(|path| !path.exists()));
+        assert!(fx
+            .database
+            .list_transient_artifacts()
+            .expect("should list")
+            .is_empty());
+    }
+
+    #[test]
+    fn test_sweep_refuses_replaced_path_outside_app_data() {
+        let fx = fixture();
+        let crashed = fx.janitor();
+        let artifacts = track_all(&fx, &crashed);
+        let archive = artifacts[0].path().to_path_buf();
+        artifacts.into_iter().for_each(std::mem::forget);
+
+        // The user saved their own file over the abandoned archive
+        let replacement = fx.user.path().join("mine.zip");
+        fs::write(&replacement, "user data").expect("should write");
+        fs::rename(&replacement, &archive).expect("should rename");
+
+        let report = fx.janitor().sweep().expect("should sweep");
+
+        assert_eq!(report.refused, vec![archive.clone()]);
+        assert_eq!(report.reclaimed.len(), 2);
+        assert_eq!(
+            fs::read_to_string(&archive).expect("should keep file"),
+            "user data"
+        );
+    }
+
+    #[test]
+    fn test_sweep_leaves_live_artifacts_alone() {
+        let fx = fixture();
+        let janitor = fx.janitor();
+        let artifacts = track_all(&fx, &janitor);
+
+        let report = janitor.sweep().expect("should sweep");
+
+        assert!(report.reclaimed.is_empty());
+        assert!(report.refused.is_empty());
+        assert!(artifacts.iter().all(|a| a.path().exists()));
+        assert_eq!(
+            fx.database
+                .list_transient_artifacts()
+                .expect("should list")
+                .len(),
+            3
+        );
+    }
+
+    #[test]
+    fn test_guard_release_keeps_file_and_drop_deletes_it() {
+        let fx = fixture();
+        let janitor = fx.janitor();
+        let mut artifacts = track_all(&fx, &janitor);
+        let avatar = artifacts.pop().expect("avatar artifact");
+        let clone = artifacts.pop().expect("clone artifact");
+        let avatar_path = avatar.path().to_path_buf();
+        let clone_path = clone.path().to_path_buf();
+
+        avatar.release();
+        drop(clone);
+
+        assert!(avatar_path.exists());
+        assert!(!clone_path.exists());
+        let rows = fx.database.list_transient_artifacts().expect("should list");
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0].owner, "Archive");
+    }
+}
@@ -0,0 +1,200 @@
+use std::path::Path;
+
+use crate::models::{ObjectStoreSize, PruneDryRunResult, UnreachableObject};
+
+/// Magic number opening a version 2 pack index (`\377tOc`)
+const PACK_INDEX_V2_MAGIC: [u8; 4] = [0xff, 0x74, 0x4f, 0x63];
+
+/// Count and size the loose objects and packs directly in `objects_dir`. Packed object
+/// counts come from the last fanout entry of each `.idx` file, so packs are not opened.
+pub fn object_store_size(objects_dir: &Path) -> ObjectStoreSize {
+    let mut size = ObjectStoreSize::default();
+
+    for entry in read_dir(objects_dir) {
+        let name = entry.file_name();
+        let is_fanout_dir = name.len() == 2
+            && name
+                .to_str()
+                .is_some_and(|n| n.chars().all(|c| c.is_ascii_hexdigit()));
+        if !is_fanout_dir {
+            continue;
+        }
+        for object in read_dir(&entry.path()) {
+            if let Ok(metadata) = object.metadata() {
+                if metadata.is_file() {
+                    size.loose_objects += 1;
+                    size.loose_size += metadata.len();
+                }
+            }
+        }
+    }
+
+    for entry in read_dir(&objects_dir.join("pack")) {
+        let path = entry.path();
+        let Some(extension) = path.extension().and_then(|e| e.to_str()) else {
+            continue;
+        };
+        let len = entry.metadata().map(|m| m.len()).unwrap_or(0);
+        match extension {
+            "pack" => {
+                size.packs += 1;
+                size.pack_size += len;
+            }
+            "idx" => {
+                size.pack_size += len;
+                size.packed_objects += std::fs::read(&path)
+                    .ok()
+                    .and_then(|bytes| pack_index_object_count(&bytes))
+                    .unwrap_or(0);
+            }
+            _ => {}
+        }
+    }
+
+    size
+}
+
+/// Entries of `dir`; empty when it cannot be read
+fn read_dir(dir: &Path) -> Vec<std::fs::DirEntry> {
+    std::fs::read_dir(dir)
+        .map(|entries| entries.filter_map(std::result::Result::ok).collect())
+        .unwrap_or_default()
+}
+
+/// Number of objects in a pack index: the last of its 256 cumulative fanout counts
+pub fn pack_index_object_count(index: &[u8]) -> Option<u64> {
+    let fanout_start = if index.starts_with(&PACK_INDEX_V2_MAGIC) {
+        8
+    } else {
+        0
+    };
+    let last = fanout_start + 255 * 4;
+    let bytes: [u8; 4] = index.get(last..last + 4)?.try_into().ok()?;
+    Some(u64::from(u32::from_be_bytes(bytes)))
+}
+
+/// Parse `git prune --dry-run` output (`<oid> <type>` per line), sizing each loose
+/// object file under `objects_dir`
+pub fn parse_prune_dry_run(stdout: &str, objects_dir: &Path) -> PruneDryRunResult {
+    let objects: Vec<UnreachableObject> = stdout
+        .lines()
+        .filter_map(|line| {
+            let (oid, object_type) = line.trim().split_once(' ')?;
+            let size = oid
+                .get(..2)
+                .zip(oid.get(2..))
+                .and_then(|(dir, file)| std::fs::metadata(objects_dir.join(dir).join(file)).ok())
+                .map_or(0, |m| m.len());
+            Some(UnreachableObject {
+                oid: oid.to_string(),
+                object_type: object_type.trim().to_string(),
+                size,
+            })
+        })
+        .collect();
+
+    PruneDryRunResult {
+        total_size: objects.iter().map(|o| o.size).sum(),
+        objects,
+    }
+}
+
+/// Parse a git progress line such as `Writing objects:  50% (3/6)` into its phase and
+/// counts. Lines without a `(current/total)` part report a total of zero.
+pub fn parse_maintenance_progress(line: &str) -> Option<(String, usize, usize)> {
+    let (phase, rest) = line.trim().split_once(": ")?;
+    if let Some(open) = rest.find('(') {
+        let close = open + rest[open..].find(')')?;
+        let (current, total) = rest[open + 1..close].split_once('/')?;
+        return Some((
+            phase.to_string(),
+            current.trim().parse().ok()?,
+            total.trim().parse().ok()?,
+        ));
+    }
+    // `Enumerating objects: 12, done.` has a running count only
+    let count = rest.split([',', ' ']).next()?.parse().ok()?;
+    Some((phase.to_string(), count, 0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn pack_index_v2(objects: u32) -> Vec<u8> {
+        let mut index = PACK_INDEX_V2_MAGIC.to_vec();
+        index.extend(2u32.to_be_bytes());
+        for _ in 0..255 {
+            index.extend(0u32.to_be_bytes());
+        }
+        index.extend(objects.to_be_bytes());
+        index
+    }
+
+    #[test]
+    fn test_pack_index_object_count() {
+        assert_eq!(pack_index_object_count(&pack_index_v2(42)), Some(42));
+
+        let mut v1 = vec![0u8; 255 * 4];
+        v1.extend(7u32.to_be_bytes());
+        assert_eq!(pack_index_object_count(&v1), Some(7));
+
+        assert_eq!(pack_index_object_count(&[0xff, 0x74]), None);
+    }
+
+    #[test]
+    fn test_object_store_size_counts_loose_and_packed() {
+        let tmp = TempDir::new().expect("should create temp dir");
+        let objects = tmp.path();
+        std::fs::create_dir_all(objects.join("ab")).expect("should create fanout dir");
+        std::fs::create_dir_all(objects.join("pack")).expect("should create pack dir");
+        std::fs::create_dir_all(objects.join("info")).expect("should create info dir");
+        std::fs::write(objects.join("ab/cdef"), [0u8; 10]).expect("should write object");
+        std::fs::write(objects.join("ab/0123"), [0u8; 5]).expect("should write object");
+        std::fs::write(objects.join("info/packs"), "P pack-1.pack\n").expect("should write");
+        std::fs::write(objects.join("pack/pack-1.pack"), [0u8; 100]).expect("should write pack");
+        let index = pack_index_v2(9);
+        std::fs::write(objects.join("pack/pack-1.idx"), &index).expect("should write index");
+
+        let size = object_store_size(objects);
+        assert_eq!(size.loose_objects, 2);
+        assert_eq!(size.loose_size, 15);
+        assert_eq!(size.packs, 1);
+        assert_eq!(size.packed_objects, 9);
+        assert_eq!(size.pack_size, 100 + index.len() as u64);
+    }
+
+    #[test]
+    fn test_parse_prune_dry_run() {
+        let tmp = TempDir::new().expect("should create temp dir");
+        let oid = "ab".to_string() + &"c".repeat(38);
+        std::fs::create_dir_all(tmp.path().join("ab")).expect("should create fanout dir");
+        std::fs::write(tmp.path().join("ab").join(&oid[2..]), [0u8; 21])
+            .expect("should write object");
+
+        let result = parse_prune_dry_run(&format!("{oid} blob\n"), tmp.path());
+        assert_eq!(
+            result.objects,
+            vec![UnreachableObject {
+                oid,
+                object_type: "blob".to_string(),
+                size: 21,
+            }]
+        );
+        assert_eq!(result.total_size, 21);
+    }
+
+    #[test]
+    fn test_parse_maintenance_progress() {
+        assert_eq!(
+            parse_maintenance_progress("Writing objects:  50% (3/6)"),
+            Some(("Writing objects".to_string(), 3, 6))
+        );
+        assert_eq!(
+            parse_maintenance_progress("Enumerating objects: 12, done."),
+            Some(("Enumerating objects".to_string(), 12, 0))
+        );
+        assert_eq!(parse_maintenance_progress("warning: nothing"), None);
+    }
+}
@@ -1,38 +1,84 @@
 pub mod ai;
+mod alternates;
 mod avatar_service;
 mod background_fetch;
+mod changelog;
 mod commit_cache;
+mod commit_lint;
+mod commit_message_stats;
+mod config_alias;
+mod config_override;
+mod credentials;
 mod custom_actions_service;
+mod file_content;
 mod file_watcher;
+mod function_context;
 mod git2_service;
 mod git_cli_service;
 mod git_service;
 mod hook_progress;
 mod hook_service;
+mod hook_trust;
 mod integrations;
+mod janitor;
+mod maintenance;
 #[cfg(feature = "integration")]
 pub mod ops;
 #[cfg(not(feature = "integration"))]
 pub(crate) mod ops;
+mod performance_profile;
+mod preconditions;
 mod process_utils;
 mod progress_emitter;
+mod recent_repositories;
+mod ref_transaction;
+mod reveal;
 mod signature_cache;
 mod signing_service;
+mod spell_check;
 mod ssh_key_service;
+mod structured_diff;
+mod sync_folder;
+mod terminal;
+mod trailers;
+mod word_diff;
 
+pub use alternates::*;
 pub use avatar_service::*;
 pub use background_fetch::*;
+pub use changelog::*;
 pub use commit_cache::*;
+pub use commit_lint::*;
+pub use commit_message_stats::*;
+pub use config_alias::*;
+pub use config_override::*;
+pub use credentials::*;
 pub use custom_actions_service::*;
+pub use file_content::*;
 pub use file_watcher::*;
+pub use function_context::*;
 pub use git2_service::*;
 pub use git_cli_service::*;
 pub use git_service::*;
 pub use hook_progress::*;
 pub use hook_service::*;
+pub use hook_trust::*;
 pub use integrations::*;
+pub use janitor::*;
+pub use maintenance::*;
+pub use performance_profile::*;
+pub use preconditions::*;
 pub use process_utils::*;
 pub use progress_emitter::*;
+pub use recent_repositories::*;
+pub use ref_transaction::*;
+pub use reveal::*;
 pub use signature_cache::*;
 pub use signing_service::*;
+pub use spell_check::*;
 pub use ssh_key_service::*;
+pub use structured_diff::*;
+pub use sync_folder::*;
+pub use terminal::*;
+pub use trailers::*;
+pub use word_diff::*;
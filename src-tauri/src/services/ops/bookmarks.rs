@@ -0,0 +1,17 @@
+use crate::error::Result;
+use crate::models::{Bookmark, ResolvedBookmark};
+
+use super::RepoOperations;
+
+/// Bookmark resolution.
+impl RepoOperations {
+    /// See `Git2Service::resolve_bookmarks`
+    pub async fn resolve_bookmarks(
+        &self,
+        bookmarks: Vec<Bookmark>,
+        repair: bool,
+    ) -> Result<Vec<ResolvedBookmark>> {
+        self.git2(move |g| g.resolve_bookmarks(bookmarks, repair))
+            .await
+    }
+}
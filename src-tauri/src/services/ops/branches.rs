@@ -1,7 +1,8 @@
 use crate::error::Result;
 use crate::models::{
-    Branch, BranchCompareResult, BranchFilter, BranchType, CheckoutOptions, CreateBranchOptions,
-    DeleteBranchOptions, SshCredentials,
+    Branch, BranchCompareResult, BranchFilter, BranchOperationResult, BranchType, CheckoutOptions,
+    CreateBranchOptions, DeleteBranchOptions, ExpectedBranchTarget, SshCredentials, StaleBranch,
+    StaleBranchCriteria,
 };
 
 use super::RepoOperations;
@@ -44,6 +45,52 @@ impl RepoOperations {
         .await
     }
 
+    pub async fn get_stale_branches(
+        &self,
+        criteria: StaleBranchCriteria,
+    ) -> Result<Vec<StaleBranch>> {
+        self.git2(move |g| g.get_stale_branches(&criteria)).await
+    }
+
+    pub async fn archive_branches(
+        &self,
+        branch_names: Vec<String>,
+        tag_prefix: &str,
+        delete_remote: bool,
+        remote_credentials: Vec<(String, Option<SshCredentials>)>,
+    ) -> Result<Vec<BranchOperationResult>> {
+        let tag_prefix = tag_prefix.to_string();
+        self.git2(move |g| {
+            g.archive_branches(
+                &branch_names,
+                &tag_prefix,
+                delete_remote,
+                &remote_credentials,
+            )
+        })
+        .await
+    }
+
+    pub async fn bulk_delete_branches(
+        &self,
+        branch_names: Vec<String>,
+        force: bool,
+        delete_remote: bool,
+        expected_targets: Vec<ExpectedBranchTarget>,
+        remote_credentials: Vec<(String, Option<SshCredentials>)>,
+    ) -> Result<Vec<BranchOperationResult>> {
+        self.git2(move |g| {
+            g.bulk_delete_branches(
+                &branch_names,
+                force,
+                delete_remote,
+                &expected_targets,
+                &remote_credentials,
+            )
+        })
+        .await
+    }
+
     pub async fn rename_branch(
         &self,
         old_name: &str,
@@ -83,6 +130,12 @@ impl RepoOperations {
         self.git2(move |g| g.get_branch(&name, &branch_type)).await
     }
 
+    pub async fn branch_divergence(&self, name: &str, oid: &str) -> Result<Option<(usize, usize)>> {
+        let name = name.to_string();
+        let oid = oid.to_string();
+        self.git2(move |g| g.branch_divergence(&name, &oid)).await
+    }
+
     pub async fn compare_branches(
         &self,
         base_ref: &str,
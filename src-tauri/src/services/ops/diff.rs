@@ -1,5 +1,8 @@
 use crate::error::Result;
-use crate::models::{DiffOptions, FileDiff, FileLogOptions, FileLogResult};
+use crate::models::{
+    DiffOptions, FileCompareResult, FileDiff, FileLogOptions, FileLogResult, ResolvedPath,
+    WorkdirRefDiffMode,
+};
 
 use super::RepoOperations;
 
@@ -7,23 +10,24 @@ use super::RepoOperations;
 impl RepoOperations {
     pub async fn diff_workdir(&self, options: &DiffOptions) -> Result<Vec<FileDiff>> {
         let options = options.clone();
-        self.git2(move |g| g.diff_workdir(&options)).await
+        self.git2_objects(move |g| g.diff_workdir(&options)).await
     }
 
     pub async fn diff_staged(&self, options: &DiffOptions) -> Result<Vec<FileDiff>> {
         let options = options.clone();
-        self.git2(move |g| g.diff_staged(&options)).await
+        self.git2_objects(move |g| g.diff_staged(&options)).await
     }
 
     pub async fn diff_head(&self, options: &DiffOptions) -> Result<Vec<FileDiff>> {
         let options = options.clone();
-        self.git2(move |g| g.diff_head(&options)).await
+        self.git2_objects(move |g| g.diff_head(&options)).await
     }
 
     pub async fn diff_commit(&self, oid_str: &str, options: &DiffOptions) -> Result<Vec<FileDiff>> {
         let oid_str = oid_str.to_string();
         let options = options.clone();
-        self.git2(move |g| g.diff_commit(&oid_str, &options)).await
+        self.git2_objects(move |g| g.diff_commit(&oid_str, &options))
+            .await
     }
 
     pub async fn diff_commits(
@@ -35,10 +39,25 @@ impl RepoOperations {
         let from_oid = from_oid.to_string();
         let to_oid = to_oid.to_string();
         let options = options.clone();
-        self.git2(move |g| g.diff_commits(&from_oid, &to_oid, &options))
+        self.git2_objects(move |g| g.diff_commits(&from_oid, &to_oid, &options))
             .await
     }
 
+    pub async fn diff_workdir_to_ref(
+        &self,
+        reference: &str,
+        mode: WorkdirRefDiffMode,
+        include_untracked: bool,
+        options: &DiffOptions,
+    ) -> Result<Vec<FileDiff>> {
+        let reference = reference.to_string();
+        let options = options.clone();
+        self.git2_objects(move |g| {
+            g.diff_workdir_to_ref(&reference, mode, include_untracked, &options)
+        })
+        .await
+    }
+
     pub async fn diff_file(
         &self,
         path: &str,
@@ -47,12 +66,13 @@ impl RepoOperations {
     ) -> Result<Option<FileDiff>> {
         let path = path.to_string();
         let options = options.clone();
-        self.git2(move |g| g.diff_file(&path, staged, &options))
+        self.git2_objects(move |g| g.diff_file(&path, staged, &options))
             .await
     }
 
     pub async fn get_file_history(&self, options: FileLogOptions) -> Result<FileLogResult> {
-        self.git2(move |g| g.get_file_history(&options)).await
+        self.git2_objects(move |g| g.get_file_history(&options))
+            .await
     }
 
     pub async fn get_file_diff_in_commit(
@@ -64,7 +84,36 @@ impl RepoOperations {
         let commit_oid = commit_oid.to_string();
         let path = path.to_string();
         let options = options.clone();
-        self.git2(move |g| g.get_file_diff_in_commit(&commit_oid, &path, &options))
+        self.git2_objects(move |g| g.get_file_diff_in_commit(&commit_oid, &path, &options))
+            .await
+    }
+
+    pub async fn get_file_diff_between(
+        &self,
+        path: &str,
+        from_ref: &str,
+        to_ref: &str,
+        options: &DiffOptions,
+        follow_renames: bool,
+    ) -> Result<FileCompareResult> {
+        let path = path.to_string();
+        let from_ref = from_ref.to_string();
+        let to_ref = to_ref.to_string();
+        let options = options.clone();
+        self.git2_objects(move |g| {
+            g.get_file_diff_between(&path, &from_ref, &to_ref, &options, follow_renames)
+        })
+        .await
+    }
+
+    pub async fn resolve_current_path(
+        &self,
+        historical_path: &str,
+        from_ref: &str,
+    ) -> Result<ResolvedPath> {
+        let historical_path = historical_path.to_string();
+        let from_ref = from_ref.to_string();
+        self.git2_objects(move |g| g.resolve_current_path(&historical_path, &from_ref))
             .await
     }
 }
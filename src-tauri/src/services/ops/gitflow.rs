@@ -1,7 +1,7 @@
-use crate::error::Result;
+use crate::error::{AxisError, Result};
 use crate::models::{
     GitFlowBranchType, GitFlowConfig, GitFlowFinishOptions, GitFlowInitOptions, GitFlowResult,
-    SshCredentials,
+    SigningConfig, SshCredentials,
 };
 
 use super::RepoOperations;
@@ -37,11 +37,25 @@ impl RepoOperations {
         branch_type: GitFlowBranchType,
         name: &str,
         options: &GitFlowFinishOptions,
+        signing_config: Option<&SigningConfig>,
     ) -> Result<GitFlowResult> {
-        self.service
-            .git_cli()
-            .gitflow_finish(branch_type, name, options)
-            .await
+        let config = self
+            .gitflow_config()
+            .await?
+            .ok_or_else(|| AxisError::GitError("Git-flow is not initialized".to_string()))?;
+        let name = name.to_string();
+        let options = options.clone();
+        let signing_config = signing_config.cloned();
+        self.git2(move |g| {
+            g.gitflow_finish(
+                &config,
+                branch_type,
+                &name,
+                &options,
+                signing_config.as_ref(),
+            )
+        })
+        .await
     }
 
     pub async fn gitflow_publish(
@@ -16,6 +16,16 @@ impl RepoOperations {
             .await
     }
 
+    pub async fn bulk_ignore(
+        &self,
+        patterns: Vec<String>,
+        gitignore_rel_path: &str,
+    ) -> Result<Vec<IgnoreResult>> {
+        let gitignore_rel_path = gitignore_rel_path.to_string();
+        self.git2(move |g| g.add_patterns_to_gitignore(&patterns, &gitignore_rel_path))
+            .await
+    }
+
     pub async fn add_to_global_gitignore(&self, pattern: &str) -> Result<IgnoreResult> {
         let pattern = pattern.to_string();
         self.git2(move |g| g.add_to_global_gitignore(&pattern))
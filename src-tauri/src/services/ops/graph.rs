@@ -1,5 +1,10 @@
 use crate::error::Result;
-use crate::models::{BlameResult, GraphOptions, GraphResult, SearchOptions, SearchResult};
+use crate::models::{
+    BlameLineContext, BlameResult, Commit, CommitLintRules, CommitMessageStats,
+    CommitMessageStatsOptions, GraphOptions, GraphResult, SearchOptions, SearchResult,
+};
+use crate::services::CommitMessageStatsCache;
+use std::sync::Arc;
 
 use super::RepoOperations;
 
@@ -13,6 +18,20 @@ impl RepoOperations {
         self.git2(move |g| g.search_commits(&options)).await
     }
 
+    /// Aggregate commit message hygiene; see `Git2Service::analyze_commit_messages`
+    pub async fn analyze_commit_messages(
+        &self,
+        options: CommitMessageStatsOptions,
+        rules: CommitLintRules,
+        cache: Arc<CommitMessageStatsCache>,
+        mut on_progress: Box<dyn FnMut(usize) -> bool + Send>,
+    ) -> Result<CommitMessageStats> {
+        self.git2_objects(move |g| {
+            g.analyze_commit_messages(&options, &rules, &cache, on_progress.as_mut())
+        })
+        .await
+    }
+
     pub async fn blame_file(&self, path: &str, commit_oid: Option<&str>) -> Result<BlameResult> {
         let path = path.to_string();
         let commit_oid = commit_oid.map(std::string::ToString::to_string);
@@ -20,6 +39,31 @@ impl RepoOperations {
             .await
     }
 
+    pub async fn get_blame_line_context(
+        &self,
+        path: &str,
+        line_number: u32,
+        commit_oid: &str,
+    ) -> Result<BlameLineContext> {
+        let path = path.to_string();
+        let commit_oid = commit_oid.to_string();
+        self.git2(move |g| g.get_blame_line_context(&path, line_number, &commit_oid))
+            .await
+    }
+
+    pub async fn commit_count_tip(&self, from_ref: Option<&str>) -> Result<String> {
+        let from_ref = from_ref.map(std::string::ToString::to_string);
+        self.git2(move |g| g.commit_count_tip(from_ref.as_deref()))
+            .await
+    }
+
+    pub async fn commits_in_range(&self, from_ref: &str, to_ref: &str) -> Result<Vec<Commit>> {
+        let from_ref = from_ref.to_string();
+        let to_ref = to_ref.to_string();
+        self.git2(move |g| g.commits_in_range(&from_ref, &to_ref))
+            .await
+    }
+
     pub async fn get_commit_count(&self, from_ref: Option<&str>) -> Result<usize> {
         let from_ref = from_ref.map(std::string::ToString::to_string);
         self.git2(move |g| g.get_commit_count(from_ref.as_deref()))
@@ -1,18 +1,58 @@
 use crate::error::Result;
-use crate::models::{GrepOptions, GrepResult};
+use crate::models::{GrepDiffMatch, GrepMatch, GrepOptions, GrepResult};
 
 use super::RepoOperations;
 
 /// Grep operations.
 impl RepoOperations {
     pub async fn grep(&self, options: &GrepOptions) -> Result<GrepResult> {
-        self.service.git_cli().grep(options).await
+        self.search_content(options.clone(), None).await
     }
 
     pub async fn grep_commit(&self, commit_oid: &str, options: &GrepOptions) -> Result<GrepResult> {
-        self.service
-            .git_cli()
-            .grep_commit(commit_oid, options)
+        let options = GrepOptions {
+            git_ref: Some(commit_oid.to_string()),
+            ..options.clone()
+        };
+        self.search_content(options, None).await
+    }
+
+    /// Search with the built-in engine; see `Git2Service::search_content`
+    pub async fn search_content(
+        &self,
+        options: GrepOptions,
+        mut on_page: Option<Box<dyn FnMut(Vec<GrepMatch>) + Send>>,
+    ) -> Result<GrepResult> {
+        self.git2(move |g| {
+            let on_page = on_page
+                .as_mut()
+                .map(|f| f.as_mut() as &mut dyn FnMut(Vec<GrepMatch>));
+            g.search_content(&options, on_page)
+        })
+        .await
+    }
+
+    pub async fn grep_branch(
+        &self,
+        pattern: &str,
+        branch: &str,
+        path_filter: Option<&str>,
+    ) -> Result<GrepResult> {
+        let pattern = pattern.to_string();
+        let branch = branch.to_string();
+        let path_filter = path_filter.map(str::to_string);
+        self.git2(move |g| g.grep_branch(&pattern, &branch, path_filter.as_deref()))
+            .await
+    }
+
+    pub async fn grep_diff(
+        &self,
+        pattern: &str,
+        case_sensitive: bool,
+        context_lines: u32,
+    ) -> Result<Vec<GrepDiffMatch>> {
+        let pattern = pattern.to_string();
+        self.git2_objects(move |g| g.grep_diff(&pattern, case_sensitive, context_lines))
             .await
     }
 }
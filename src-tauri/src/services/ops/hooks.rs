@@ -1,7 +1,10 @@
 use std::path::Path;
 
 use crate::error::Result;
-use crate::models::{GitHookType, HookDetails, HookInfo, HookResult, HookTemplate};
+use crate::models::{
+    GitHookType, HookDetails, HookFingerprint, HookInfo, HookResult, HookTemplate,
+    HookTrustDecision, HookTrustRequest,
+};
 use crate::services::{HookProgressEmitter, HookService};
 
 use super::RepoOperations;
@@ -52,6 +55,10 @@ impl RepoOperations {
             .await
     }
 
+    pub async fn run_pre_merge_commit(&self, emitter: Option<&HookProgressEmitter>) -> HookResult {
+        self.service.hook().run_pre_merge_commit(emitter).await
+    }
+
     pub async fn run_post_merge(
         &self,
         is_squash: bool,
@@ -97,8 +104,21 @@ impl RepoOperations {
             .await
     }
 
+    pub async fn run_hook(
+        &self,
+        hook_type: GitHookType,
+        args: &[String],
+        emitter: Option<&HookProgressEmitter>,
+    ) -> HookResult {
+        self.service.hook().run_hook(hook_type, args, emitter).await
+    }
+
     // ---- Management (sync) ----
 
+    pub fn git_may_run_hooks(&self) -> bool {
+        self.service.hook().git_may_run_hooks()
+    }
+
     pub fn list_hooks(&self) -> Vec<HookInfo> {
         self.service.hook().list_hooks()
     }
@@ -123,6 +143,24 @@ impl RepoOperations {
         self.service.hook().toggle_hook(hook_type)
     }
 
+    pub fn set_hook_enabled(&self, hook_type: GitHookType, enabled: bool) -> Result<bool> {
+        self.service.hook().set_hook_enabled(hook_type, enabled)
+    }
+
+    // ---- Trust (sync) ----
+
+    pub fn pending_hook_trust_request(&self) -> Option<HookTrustRequest> {
+        self.service.hook().pending_trust_request()
+    }
+
+    pub fn resolve_hook_trust(
+        &self,
+        hooks: &[HookFingerprint],
+        decision: HookTrustDecision,
+    ) -> Result<()> {
+        self.service.hook().resolve_hook_trust(hooks, decision)
+    }
+
     // Allow unused_self: these methods keep &self for API consistency with other RepoOperations methods.
     // Callers access templates through the ops layer rather than HookService directly.
     #[must_use]
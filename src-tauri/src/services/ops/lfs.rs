@@ -5,6 +5,7 @@ use crate::models::{
     SshCredentials,
 };
 use crate::services::GitCliService;
+use std::path::{Path, PathBuf};
 
 use super::RepoOperations;
 
@@ -19,7 +20,37 @@ impl RepoOperations {
     }
 
     pub async fn lfs_status(&self) -> Result<LfsStatus> {
-        self.service.git_cli().lfs_status().await
+        let cli = self.service.git_cli();
+        let mut status = cli.lfs_status().await?;
+        if !status.is_initialized {
+            return Ok(status);
+        }
+
+        let media_dir = match cli.lfs_env().await {
+            Ok(env) => env.storage_path.map(PathBuf::from),
+            Err(e) => {
+                log::warn!("Failed to read LFS environment, using default object store: {e}");
+                None
+            }
+        }
+        .unwrap_or_else(|| {
+            self.service
+                .git2()
+                .path()
+                .join(".git")
+                .join("lfs")
+                .join("objects")
+        });
+
+        let listing = cli.lfs_ls_files_long().await?;
+        for (oid, path) in parse_lfs_ls_files(&listing) {
+            if lfs_object_path(&media_dir, oid).is_some_and(|p| p.is_file()) {
+                status.cached_objects.push(path.to_string());
+            } else {
+                status.missing_objects.push(path.to_string());
+            }
+        }
+        Ok(status)
     }
 
     pub async fn lfs_install(&self) -> Result<LfsResult> {
@@ -34,6 +65,10 @@ impl RepoOperations {
         self.service.git_cli().lfs_untrack(pattern).await
     }
 
+    pub fn get_lfs_tracked_patterns(&self) -> Result<Vec<String>> {
+        self.service.git_cli().get_lfs_tracked_patterns()
+    }
+
     pub async fn lfs_list_tracked_patterns(&self) -> Result<Vec<LfsTrackedPattern>> {
         self.service.git_cli().lfs_list_tracked_patterns().await
     }
@@ -57,10 +92,11 @@ impl RepoOperations {
         &self,
         options: &LfsPullOptions,
         ssh_credentials: Option<SshCredentials>,
+        on_progress: &mut (dyn FnMut(usize, usize, usize) -> bool + Send),
     ) -> Result<LfsResult> {
         self.service
             .git_cli()
-            .lfs_pull(options, ssh_credentials.as_ref())
+            .lfs_pull(options, ssh_credentials.as_ref(), on_progress)
             .await
     }
 
@@ -68,10 +104,11 @@ impl RepoOperations {
         &self,
         options: &LfsPushOptions,
         ssh_credentials: Option<SshCredentials>,
+        on_progress: &mut (dyn FnMut(usize, usize, usize) -> bool + Send),
     ) -> Result<LfsResult> {
         self.service
             .git_cli()
-            .lfs_push(options, ssh_credentials.as_ref())
+            .lfs_push(options, ssh_credentials.as_ref(), on_progress)
             .await
     }
 
@@ -91,3 +128,56 @@ impl RepoOperations {
         self.service.git_cli().lfs_prune(options).await
     }
 }
+
+/// Parse `git lfs ls-files --long` lines (`<oid> <*|-> <path>`) into `(oid, path)` pairs.
+/// The `*`/`-` marker only says whether the working tree file is smudged, so it is ignored.
+fn parse_lfs_ls_files(output: &str) -> Vec<(&str, &str)> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let (oid, rest) = line.trim_end().split_once(' ')?;
+            let path = rest
+                .strip_prefix("* ")
+                .or_else(|| rest.strip_prefix("- "))?;
+            (!oid.is_empty() && !path.is_empty()).then_some((oid, path))
+        })
+        .collect()
+}
+
+/// Location of an object in the LFS store: `<media_dir>/ab/cd/abcd...`
+fn lfs_object_path(media_dir: &Path, oid: &str) -> Option<PathBuf> {
+    if oid.len() < 4 || !oid.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return None;
+    }
+    Some(media_dir.join(&oid[0..2]).join(&oid[2..4]).join(oid))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_lfs_ls_files() {
+        let output = "\
+4d7a214614ab2935c943f9e0ff69d22eadbb8f32b1258daaa5e2ca24d17e2393 * assets/logo.png
+a5e2ca24d17e23934d7a214614ab2935c943f9e0ff69d22eadbb8f32b1258daa - data/with space.bin
+not a valid line
+";
+        let entries = parse_lfs_ls_files(output);
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].1, "assets/logo.png");
+        assert_eq!(entries[1].1, "data/with space.bin");
+        assert!(entries[1].0.starts_with("a5e2"));
+    }
+
+    #[test]
+    fn test_lfs_object_path() {
+        let dir = Path::new("/repo/.git/lfs/objects");
+        assert_eq!(
+            lfs_object_path(dir, "4d7a2146"),
+            Some(dir.join("4d").join("7a").join("4d7a2146"))
+        );
+        assert_eq!(lfs_object_path(dir, "../etc"), None);
+        assert_eq!(lfs_object_path(dir, "ab"), None);
+    }
+}
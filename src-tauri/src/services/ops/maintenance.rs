@@ -0,0 +1,51 @@
+use crate::error::{AxisError, Result};
+use crate::models::{
+    MaintenanceOptions, MaintenanceResult, MaintenanceTask, PruneDryRunResult, RepositorySizeReport,
+};
+
+use super::RepoOperations;
+
+/// Repository maintenance (size report from the object store; gc, prune, repack via CLI).
+impl RepoOperations {
+    pub async fn get_repository_size_report(
+        &self,
+        largest_blobs: usize,
+    ) -> Result<RepositorySizeReport> {
+        self.git2(move |g| g.get_repository_size_report(largest_blobs))
+            .await
+    }
+
+    pub async fn prune_dry_run(&self) -> Result<PruneDryRunResult> {
+        self.service.git_cli().prune_dry_run().await
+    }
+
+    /// Run `options.tasks` in order. `on_progress(task, phase, current, total)` is called
+    /// with an empty phase as each task starts; returning false cancels the run.
+    pub async fn run_maintenance(
+        &self,
+        options: &MaintenanceOptions,
+        on_progress: &mut (dyn FnMut(MaintenanceTask, &str, usize, usize) -> bool + Send),
+    ) -> Result<MaintenanceResult> {
+        let before = self.git2(|g| g.object_store_size()).await?;
+
+        for &task in &options.tasks {
+            if !on_progress(task, "", 0, 0) {
+                return Err(AxisError::Other("Maintenance cancelled".into()));
+            }
+            let mut task_progress = |phase: &str, current: usize, total: usize| {
+                on_progress(task, phase, current, total)
+            };
+            self.service
+                .git_cli()
+                .run_maintenance_task(task, options.aggressive, &mut task_progress)
+                .await?;
+        }
+
+        let after = self.git2(|g| g.object_store_size()).await?;
+        Ok(MaintenanceResult {
+            tasks: options.tasks.clone(),
+            before,
+            after,
+        })
+    }
+}
@@ -1,8 +1,11 @@
-use crate::error::Result;
+use crate::error::{AxisError, Result};
 use crate::models::{
-    ConflictType, ConflictedFile, InteractiveRebaseEntry, RebasePreview, RebaseProgress,
+    CherryPickOptions, CherryPickPreview, CherryPickQueue, CherryPickResult, CommitTrailerOptions,
+    ConflictHunk, ConflictHunkChoice, ConflictType, ConflictedFile, InteractiveRebaseEntry,
+    MergePreview, MergeStrategy, NotebookMergeResult, OperationState, RebaseAction, RebasePreview,
+    RebaseProgress, RewordPlan, RewritePreconditions, SigningConfig,
 };
-use crate::services::{ConflictVersion, GitCommandResult};
+use crate::services::{resolve_trailers, ConflictVersion, GitCommandResult};
 
 use super::RepoOperations;
 
@@ -10,8 +13,8 @@ use super::RepoOperations;
 impl RepoOperations {
     // ---- Merge ----
 
-    // Allow excessive bools: these map directly to git merge CLI flags
-    #[allow(clippy::fn_params_excessive_bools)]
+    // Allow excessive bools and arguments: these map directly to git merge CLI flags
+    #[allow(clippy::fn_params_excessive_bools, clippy::too_many_arguments)]
     pub async fn merge(
         &self,
         branch: &str,
@@ -20,10 +23,35 @@ impl RepoOperations {
         squash: bool,
         ff_only: bool,
         no_commit: bool,
+        strategy: Option<MergeStrategy>,
     ) -> Result<GitCommandResult> {
         self.service
             .git_cli()
-            .merge(branch, message, no_ff, squash, ff_only, no_commit)
+            .merge(branch, message, no_ff, squash, ff_only, no_commit, strategy)
+            .await
+    }
+
+    /// Merge `branch` with a merge commit carrying the trailers requested by `trailers`
+    pub async fn merge_with_trailers(
+        &self,
+        branch: &str,
+        message: Option<&str>,
+        no_ff: bool,
+        ff_only: bool,
+        strategy: Option<MergeStrategy>,
+        trailers: &CommitTrailerOptions,
+    ) -> Result<GitCommandResult> {
+        let committer = self.get_user_signature().await?;
+        self.service
+            .git_cli()
+            .merge_with_trailers(
+                branch,
+                message,
+                no_ff,
+                ff_only,
+                strategy,
+                &resolve_trailers(trailers, &committer),
+            )
             .await
     }
 
@@ -81,6 +109,18 @@ impl RepoOperations {
         self.service.git_cli().get_rebase_progress()
     }
 
+    pub async fn get_interactive_rebase_todo(&self) -> Result<Vec<InteractiveRebaseEntry>> {
+        self.git2(|g| g.get_interactive_rebase_todo()).await
+    }
+
+    pub async fn update_interactive_rebase_todo(
+        &self,
+        entries: Vec<InteractiveRebaseEntry>,
+    ) -> Result<()> {
+        self.git2(move |g| g.update_interactive_rebase_todo(&entries))
+            .await
+    }
+
     pub async fn rebase_continue_with_message(&self, message: &str) -> Result<GitCommandResult> {
         self.service
             .git_cli()
@@ -88,13 +128,217 @@ impl RepoOperations {
             .await
     }
 
+    /// Squash the last `count` commits into one with `message` by running an
+    /// interactive rebase of pick + fixups, and return the new HEAD OID
+    pub async fn squash_commits(
+        &self,
+        count: u32,
+        message: &str,
+        signing_config: Option<&SigningConfig>,
+    ) -> Result<String> {
+        if count < 2 {
+            return Err(AxisError::Other(
+                "Squashing needs at least two commits".to_string(),
+            ));
+        }
+        if message.trim().is_empty() {
+            return Err(AxisError::EmptyCommitMessage);
+        }
+
+        let onto = format!("HEAD~{count}");
+        let preview = self.get_rebase_preview(&onto).await?;
+        if preview.commits_to_rebase.len() != count as usize {
+            return Err(AxisError::Other(format!(
+                "The last {count} commits are not a linear history and cannot be squashed"
+            )));
+        }
+
+        let entries: Vec<InteractiveRebaseEntry> = preview
+            .commits_to_rebase
+            .iter()
+            .enumerate()
+            .map(|(i, commit)| InteractiveRebaseEntry {
+                action: if i == 0 {
+                    RebaseAction::Pick
+                } else {
+                    RebaseAction::Fixup
+                },
+                short_oid: commit.short_oid.clone(),
+                oid: commit.oid.clone(),
+                summary: commit.summary.clone(),
+                original_index: i,
+            })
+            .collect();
+
+        let result = self.interactive_rebase(&onto, &entries, false).await?;
+        if !result.success {
+            if self.get_rebase_progress()?.is_some() {
+                if let Err(e) = self.rebase_abort().await {
+                    log::warn!("Failed to abort squash rebase: {e}");
+                }
+            }
+            return Err(AxisError::Other(format!(
+                "Squash failed: {}",
+                result.stderr.trim()
+            )));
+        }
+
+        // Fixups keep the first commit's message; replace it with the requested one
+        self.amend_commit(
+            Some(message),
+            None,
+            None,
+            false,
+            false,
+            signing_config,
+            true,
+        )
+        .await
+    }
+
+    /// Work out which commits rewording `oid` touches. HEAD is amended; an older commit
+    /// is reworded by an interactive rebase that replays the commits after it unchanged.
+    pub async fn plan_reword_commit(&self, oid: &str) -> Result<RewordPlan> {
+        // Captured first, so anything that moves while the plan is built is caught too
+        let preconditions = self.capture_rewrite_preconditions(true).await?;
+        let commit = self.get_commit(oid).await?;
+        if self.get_head_oid_opt().await.as_deref() == Some(commit.oid.as_str()) {
+            return Ok(RewordPlan {
+                commit,
+                replayed: Vec::new(),
+                preconditions,
+            });
+        }
+
+        let Some(parent) = commit.parent_oids.first() else {
+            return Err(AxisError::Other(
+                "The root commit cannot be reworded".to_string(),
+            ));
+        };
+        let mut replayed = self.get_rebase_preview(parent).await?.commits_to_rebase;
+        let Some(position) = replayed.iter().position(|c| c.oid == commit.oid) else {
+            return Err(AxisError::Other(format!(
+                "Commit {} is not an ancestor of HEAD",
+                commit.short_oid
+            )));
+        };
+        replayed.remove(position);
+        // A plain interactive rebase would flatten merges
+        if commit.is_merge || replayed.iter().any(|c| c.is_merge) {
+            return Err(AxisError::Other(format!(
+                "Merge commits follow {} and cannot be replayed",
+                commit.short_oid
+            )));
+        }
+
+        Ok(RewordPlan {
+            commit,
+            replayed,
+            preconditions,
+        })
+    }
+
+    /// Replace the message of `oid`, HEAD or one of its ancestors, and return the new HEAD OID.
+    /// With `preconditions` from `plan_reword_commit`, nothing is rewritten if HEAD, its
+    /// branch or the index changed since the plan was made.
+    pub async fn reword_commit(
+        &self,
+        oid: &str,
+        message: &str,
+        signing_config: Option<&SigningConfig>,
+        preconditions: Option<&RewritePreconditions>,
+    ) -> Result<String> {
+        if message.trim().is_empty() {
+            return Err(AxisError::EmptyCommitMessage);
+        }
+        if let Some(preconditions) = preconditions {
+            self.check_rewrite_preconditions(preconditions).await?;
+        }
+
+        let plan = self.plan_reword_commit(oid).await?;
+        if plan.replayed.is_empty() {
+            return self
+                .amend_commit(
+                    Some(message),
+                    None,
+                    None,
+                    false,
+                    false,
+                    signing_config,
+                    true,
+                )
+                .await;
+        }
+
+        let entries: Vec<InteractiveRebaseEntry> = std::iter::once(&plan.commit)
+            .chain(&plan.replayed)
+            .enumerate()
+            .map(|(i, commit)| InteractiveRebaseEntry {
+                action: if i == 0 {
+                    RebaseAction::Reword
+                } else {
+                    RebaseAction::Pick
+                },
+                short_oid: commit.short_oid.clone(),
+                oid: commit.oid.clone(),
+                summary: commit.summary.clone(),
+                original_index: i,
+            })
+            .collect();
+
+        let parent = plan.commit.parent_oids.first().cloned().unwrap_or_default();
+        let result = self
+            .service
+            .git_cli()
+            .interactive_rebase_rewording(&parent, &entries, message)
+            .await?;
+        if !result.success {
+            if self.get_rebase_progress()?.is_some() {
+                if let Err(e) = self.rebase_abort().await {
+                    log::warn!("Failed to abort reword rebase: {e}");
+                }
+            }
+            return Err(AxisError::Other(format!(
+                "Reword failed: {}",
+                result.stderr.trim()
+            )));
+        }
+
+        Ok(self.get_head_oid().await)
+    }
+
+    /// Record HEAD, its branch and optionally the index for a rewrite being planned
+    pub async fn capture_rewrite_preconditions(
+        &self,
+        with_index: bool,
+    ) -> Result<RewritePreconditions> {
+        self.git2(move |g| g.capture_rewrite_preconditions(with_index))
+            .await
+    }
+
+    /// Fail with `PreconditionFailed` if anything recorded in `preconditions` changed
+    pub async fn check_rewrite_preconditions(
+        &self,
+        preconditions: &RewritePreconditions,
+    ) -> Result<()> {
+        let preconditions = preconditions.clone();
+        self.git2(move |g| g.check_rewrite_preconditions(&preconditions))
+            .await
+    }
+
     pub async fn get_rebase_preview(&self, onto: &str) -> Result<RebasePreview> {
         let onto = onto.to_string();
         self.git2(move |g| g.get_rebase_preview(&onto)).await
     }
 
+    pub async fn get_merge_preview(&self, source_ref: &str) -> Result<MergePreview> {
+        let source_ref = source_ref.to_string();
+        self.git2(move |g| g.get_merge_preview(&source_ref)).await
+    }
+
     // ---- Cherry-pick ----
 
+    #[cfg(feature = "integration")]
     pub async fn cherry_pick(&self, commit: &str, no_commit: bool) -> Result<GitCommandResult> {
         self.service.git_cli().cherry_pick(commit, no_commit).await
     }
@@ -111,6 +355,260 @@ impl RepoOperations {
         self.service.git_cli().cherry_pick_skip().await
     }
 
+    pub async fn get_cherry_pick_preview(&self, oids: Vec<String>) -> Result<CherryPickPreview> {
+        self.git2(move |g| g.get_cherry_pick_preview(&oids)).await
+    }
+
+    pub async fn expand_commit_range(&self, range: &str) -> Result<Vec<String>> {
+        let range = range.to_string();
+        self.git2(move |g| g.expand_commit_range(&range)).await
+    }
+
+    /// Resolve the commits to pick (explicit list or range) into a queue.
+    /// Fails before anything is picked if a merge commit has no mainline parent.
+    pub async fn prepare_cherry_pick_queue(
+        &self,
+        options: &CherryPickOptions,
+    ) -> Result<CherryPickQueue> {
+        let commits = match &options.range {
+            Some(range) => self.expand_commit_range(range).await?,
+            None => options.commits.clone(),
+        };
+        if commits.is_empty() {
+            return Err(AxisError::Other("No commits to cherry-pick".into()));
+        }
+
+        let mut remaining = Vec::with_capacity(commits.len());
+        for oid in &commits {
+            let commit = self.get_commit(oid).await?;
+            if commit.is_merge && options.mainline.is_none() {
+                return Err(AxisError::Other(format!(
+                    "Commit {} is a merge; choose a mainline parent to cherry-pick it",
+                    commit.short_oid
+                )));
+            }
+            remaining.push(commit.oid);
+        }
+
+        Ok(CherryPickQueue {
+            orig_head: self.get_head_oid().await,
+            total: remaining.len(),
+            remaining,
+            mainline: options.mainline,
+            no_commit: options.no_commit,
+            allow_empty: options.allow_empty,
+            skip_empty: options.skip_empty,
+            picked: Vec::new(),
+            skipped: Vec::new(),
+            failed: None,
+        })
+    }
+
+    /// Pick queued commits in order. On conflict the pick is left in progress and the
+    /// queue is returned so the rest can be resumed with `continue_cherry_pick_queue`.
+    /// Any other failure after the first pick also returns the queue, so earlier picks can
+    /// be kept by continuing or undone with `abort_cherry_pick_queue`.
+    pub async fn run_cherry_pick_queue(
+        &self,
+        mut queue: CherryPickQueue,
+        signing_config: Option<&SigningConfig>,
+    ) -> Result<(CherryPickResult, Option<CherryPickQueue>)> {
+        while !queue.remaining.is_empty() {
+            let oid = queue.remaining.remove(0);
+            let error = match self
+                .pick_queued_commit(&mut queue, &oid, signing_config)
+                .await
+            {
+                Ok(None) => continue,
+                Ok(Some(conflicted)) => return Ok((conflicted, Some(queue))),
+                Err(e) => e,
+            };
+
+            // Nothing has been applied yet, so there is nothing to keep or roll back
+            if queue.remaining.len() + 1 == queue.total {
+                return Err(error);
+            }
+            // Continue finishes an in-progress pick itself; otherwise it retries this one
+            if !self.is_cherry_picking()? {
+                queue.failed = Some(oid);
+            }
+            let message = format!(
+                "{error}. Fix the problem and continue, or abort to restore the original HEAD ({} commit(s) remaining).",
+                queue.remaining.len() + usize::from(queue.failed.is_some())
+            );
+            return Ok((
+                CherryPickResult {
+                    success: false,
+                    commit_oids: queue.picked.clone(),
+                    conflicts: Vec::new(),
+                    message,
+                    skipped: queue.skipped.clone(),
+                },
+                Some(queue),
+            ));
+        }
+
+        let mut message = if queue.no_commit {
+            "Cherry-picked changes applied without committing.".to_string()
+        } else {
+            format!(
+                "Successfully cherry-picked {} commit(s).",
+                queue.picked.len()
+            )
+        };
+        if !queue.skipped.is_empty() {
+            message.push_str(&format!(
+                " Skipped {} commit(s) that were already applied.",
+                queue.skipped.len()
+            ));
+        }
+        Ok((
+            CherryPickResult {
+                success: true,
+                commit_oids: queue.picked,
+                conflicts: Vec::new(),
+                message,
+                skipped: queue.skipped,
+            },
+            None,
+        ))
+    }
+
+    /// Pick one queued commit, recording it as picked or skipped.
+    /// Returns the result to report when the pick stopped on conflicts.
+    async fn pick_queued_commit(
+        &self,
+        queue: &mut CherryPickQueue,
+        oid: &str,
+        signing_config: Option<&SigningConfig>,
+    ) -> Result<Option<CherryPickResult>> {
+        let commit = self.get_commit(oid).await?;
+        let mainline = if commit.is_merge {
+            queue.mainline
+        } else {
+            None
+        };
+
+        let result = self
+            .service
+            .git_cli()
+            .cherry_pick_with(
+                oid,
+                queue.no_commit,
+                mainline,
+                queue.allow_empty,
+                signing_config,
+            )
+            .await?;
+
+        if result.success {
+            if !queue.no_commit {
+                queue.picked.push(self.get_head_oid().await);
+            }
+            return Ok(None);
+        }
+
+        if queue.skip_empty
+            && !result.stdout.contains("CONFLICT")
+            && self.is_cherry_picking()?
+            && self.index_matches_head().await?
+        {
+            log::info!("Skipping empty cherry-pick of {}", commit.short_oid);
+            self.service.git_cli().cherry_pick_skip().await?;
+            queue.skipped.push(oid.to_string());
+            return Ok(None);
+        }
+
+        if result.stdout.contains("CONFLICT") {
+            let conflicts = self.get_conflicted_files_enriched().await?;
+            let message = format!(
+                "Cherry-pick of {} has conflicts. Resolve them and continue ({} commit(s) remaining).",
+                commit.short_oid,
+                queue.remaining.len()
+            );
+            return Ok(Some(CherryPickResult {
+                success: false,
+                commit_oids: queue.picked.clone(),
+                conflicts,
+                message,
+                skipped: queue.skipped.clone(),
+            }));
+        }
+
+        Err(AxisError::Other(format!(
+            "Cherry-pick of {} failed: {}",
+            commit.short_oid,
+            result.stderr.trim()
+        )))
+    }
+
+    /// Finish the conflicted pick (or retry the failed one), then pick the rest of the queue
+    pub async fn continue_cherry_pick_queue(
+        &self,
+        mut queue: CherryPickQueue,
+        signing_config: Option<&SigningConfig>,
+    ) -> Result<(CherryPickResult, Option<CherryPickQueue>)> {
+        if let Some(oid) = queue.failed.take() {
+            queue.remaining.insert(0, oid);
+            return self.run_cherry_pick_queue(queue, signing_config).await;
+        }
+
+        let conflicts = self.get_conflicted_files_enriched().await?;
+        if !conflicts.is_empty() {
+            return Ok((
+                CherryPickResult {
+                    success: false,
+                    commit_oids: queue.picked.clone(),
+                    conflicts,
+                    message: "Resolve all conflicts before continuing.".to_string(),
+                    skipped: queue.skipped.clone(),
+                },
+                Some(queue),
+            ));
+        }
+
+        if self.is_cherry_picking()? {
+            let git_cli = self.service.git_cli();
+            if queue.no_commit {
+                // Keep the resolution staged instead of committing it
+                git_cli.cherry_pick_quit().await?;
+            } else {
+                git_cli.cherry_pick_continue_with(signing_config).await?;
+                queue.picked.push(self.get_head_oid().await);
+            }
+        }
+
+        self.run_cherry_pick_queue(queue, signing_config).await
+    }
+
+    /// Skip the conflicted or failed pick, then pick the rest of the queue
+    pub async fn skip_cherry_pick_queue(
+        &self,
+        mut queue: CherryPickQueue,
+        signing_config: Option<&SigningConfig>,
+    ) -> Result<(CherryPickResult, Option<CherryPickQueue>)> {
+        // A failed pick that left nothing in progress has nothing to clean up
+        if queue.failed.take().is_none() {
+            if self.is_cherry_picking()? {
+                self.service.git_cli().cherry_pick_skip().await?;
+            } else {
+                // `--no-commit` picks leave no cherry-pick state; drop the conflicted changes
+                self.service.git_cli().reset_merge("HEAD").await?;
+            }
+        }
+        self.run_cherry_pick_queue(queue, signing_config).await
+    }
+
+    /// Drop the queue and restore HEAD, index and worktree to before the first pick
+    pub async fn abort_cherry_pick_queue(&self, queue: &CherryPickQueue) -> Result<()> {
+        let git_cli = self.service.git_cli();
+        git_cli.reset_merge(&queue.orig_head).await?;
+        if self.is_cherry_picking()? {
+            git_cli.cherry_pick_quit().await?;
+        }
+        Ok(())
+    }
+
     // ---- Revert ----
 
     pub async fn revert(&self, commit: &str, no_commit: bool) -> Result<GitCommandResult> {
@@ -158,6 +656,34 @@ impl RepoOperations {
             .await
     }
 
+    pub async fn get_conflict_hunks(&self, path: &str) -> Result<Vec<ConflictHunk>> {
+        let path = path.to_string();
+        self.git2(move |g| g.get_conflict_hunks(&path)).await
+    }
+
+    pub async fn resolve_conflict_hunks(
+        &self,
+        path: &str,
+        choices: &[ConflictHunkChoice],
+    ) -> Result<()> {
+        let path = path.to_string();
+        let choices = choices.to_vec();
+        self.git2(move |g| g.resolve_conflict_hunks(&path, &choices))
+            .await
+    }
+
+    pub async fn resolve_conflict_with_content(&self, path: &str, content: &str) -> Result<()> {
+        let path = path.to_string();
+        let content = content.to_string();
+        self.git2(move |g| g.resolve_conflict_with_content(&path, &content))
+            .await
+    }
+
+    pub async fn merge_notebook_conflict(&self, path: &str) -> Result<NotebookMergeResult> {
+        let path = path.to_string();
+        self.git2(move |g| g.merge_notebook_conflict(&path)).await
+    }
+
     pub async fn get_conflicted_files(&self) -> Result<Vec<String>> {
         self.service.git_cli().get_conflicted_files().await
     }
@@ -192,4 +718,99 @@ impl RepoOperations {
     pub fn is_reverting(&self) -> Result<bool> {
         self.service.git_cli().is_reverting()
     }
+
+    /// Operation in progress with its current commit, steps, branch and conflicts.
+    /// State files that are missing or unreadable give `Unknown` rather than an error.
+    pub async fn get_operation_state(&self) -> Result<OperationState> {
+        if self.is_rebasing()? {
+            let Some(progress) = self
+                .get_rebase_progress()?
+                .filter(|p| p.current_step > 0 && p.total_steps > 0)
+            else {
+                return Ok(OperationState::Unknown);
+            };
+            let stopped_sha = progress.stopped_sha;
+            let current_commit = self
+                .git2(move |g| {
+                    g.operation_commit("REBASE_HEAD")
+                        .or_else(|| stopped_sha.and_then(|oid| g.operation_commit(&oid)))
+                })
+                .await;
+            return Ok(OperationState::Rebasing {
+                onto: progress.onto,
+                current: Some(progress.current_step),
+                total: Some(progress.total_steps),
+                paused_action: progress.paused_action,
+                head_name: progress.head_name,
+                current_commit,
+                conflicted_paths: self.get_conflicted_files().await?,
+            });
+        }
+
+        if self.is_merging()? {
+            let (current_commit, branch) = self
+                .git2(|g| (g.operation_commit("MERGE_HEAD"), g.merge_branch_name()))
+                .await;
+            if current_commit.is_none() {
+                return Ok(OperationState::Unknown);
+            }
+            return Ok(OperationState::Merging {
+                branch,
+                current_commit,
+                original_branch: self.get_current_branch().await,
+                conflicted_paths: self.get_conflicted_files().await?,
+            });
+        }
+
+        let picking = self.is_cherry_picking()?;
+        if picking || self.is_reverting()? {
+            let head_file = if picking {
+                "CHERRY_PICK_HEAD"
+            } else {
+                "REVERT_HEAD"
+            };
+            let (current_commit, steps) = self
+                .git2(move |g| (g.operation_commit(head_file), g.sequencer_progress()))
+                .await;
+            let Some(current_commit) = current_commit else {
+                return Ok(OperationState::Unknown);
+            };
+            let (current, total) = steps.unwrap_or((1, 1));
+            let commit = Some(current_commit.oid.clone());
+            let current_commit = Some(current_commit);
+            let current = Some(current);
+            let total = Some(total);
+            let original_branch = self.get_current_branch().await;
+            let conflicted_paths = self.get_conflicted_files().await?;
+            return Ok(if picking {
+                OperationState::CherryPicking {
+                    commit,
+                    current_commit,
+                    current,
+                    total,
+                    original_branch,
+                    conflicted_paths,
+                }
+            } else {
+                OperationState::Reverting {
+                    commit,
+                    current_commit,
+                    current,
+                    total,
+                    original_branch,
+                    conflicted_paths,
+                }
+            });
+        }
+
+        if self.is_bisecting()? {
+            let bisect_state = self.get_bisect_state().await?;
+            return Ok(OperationState::Bisecting {
+                current_commit: bisect_state.current_commit,
+                steps_remaining: bisect_state.steps_remaining,
+            });
+        }
+
+        Ok(OperationState::None)
+    }
 }
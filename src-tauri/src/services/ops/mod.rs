@@ -1,4 +1,5 @@
 mod bisect;
+mod bookmarks;
 mod branches;
 mod diff;
 mod gitflow;
@@ -7,17 +8,21 @@ mod graph;
 mod grep;
 mod hooks;
 mod lfs;
+mod maintenance;
 mod merge;
 mod patches;
 mod reflog;
 mod remotes;
 mod repository;
+mod rewrite;
 mod staging;
 mod stash;
 mod submodules;
 mod tags;
+mod undo;
 mod worktrees;
 
+use crate::error::Result;
 use crate::services::{Git2Service, GitService};
 use std::sync::Arc;
 
@@ -47,4 +52,15 @@ impl RepoOperations {
             .await
             .unwrap_or_else(|e| panic!("git2 task panicked: {e}"))
     }
+
+    /// Run a `git2` operation that reads objects. A missing object is reported as
+    /// `AlternateUnreachable` when an alternate object store is unreachable.
+    async fn git2_objects<F, T>(&self, f: F) -> Result<T>
+    where
+        F: FnOnce(&Git2Service) -> Result<T> + Send + 'static,
+        T: Send + 'static,
+    {
+        self.git2(move |g| f(g).map_err(|e| g.explain_missing_object(e)))
+            .await
+    }
 }
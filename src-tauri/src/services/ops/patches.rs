@@ -23,6 +23,18 @@ impl RepoOperations {
         self.service.git_cli().format_patch(range, output_dir).await
     }
 
+    pub async fn format_patch_series(
+        &self,
+        from_oid: &str,
+        to_oid: &str,
+        output_dir: &Path,
+    ) -> Result<Vec<String>> {
+        self.service
+            .git_cli()
+            .format_patch_series(from_oid, to_oid, output_dir)
+            .await
+    }
+
     pub async fn create_patch_from_diff(
         &self,
         commit_oid: Option<&str>,
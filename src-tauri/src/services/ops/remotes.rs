@@ -1,7 +1,7 @@
 use crate::error::Result;
 use crate::models::{
-    FetchOptions, FetchResult, ListRemoteOptions, PullOptions, PushOptions, PushResult, Remote,
-    SshCredentials,
+    FetchOptions, FetchResult, ListRemoteOptions, PullOptions, PullResult, PushOptions, PushResult,
+    Remote, RemoteBranchInfo, RemoteConnectionResult, RemoteTagInfo, SshCredentials,
 };
 
 use super::RepoOperations;
@@ -47,6 +47,36 @@ impl RepoOperations {
         self.git2(move |g| g.set_remote_push_url(&name, &url)).await
     }
 
+    pub async fn test_remote_connection(
+        &self,
+        remote_name: &str,
+        ssh_credentials: Option<SshCredentials>,
+    ) -> Result<RemoteConnectionResult> {
+        let remote_name = remote_name.to_string();
+        self.git2(move |g| g.test_remote_connection(&remote_name, ssh_credentials))
+            .await
+    }
+
+    pub async fn list_remote_branches(
+        &self,
+        remote_name: &str,
+        ssh_credentials: Option<SshCredentials>,
+    ) -> Result<Vec<RemoteBranchInfo>> {
+        let remote_name = remote_name.to_string();
+        self.git2(move |g| g.list_remote_branches(&remote_name, ssh_credentials))
+            .await
+    }
+
+    pub async fn list_remote_tags(
+        &self,
+        remote_name: &str,
+        ssh_credentials: Option<SshCredentials>,
+    ) -> Result<Vec<RemoteTagInfo>> {
+        let remote_name = remote_name.to_string();
+        self.git2(move |g| g.list_remote_tags(&remote_name, ssh_credentials))
+            .await
+    }
+
     /// Fetch from a remote with optional progress callback.
     /// The callback receives progress stats and returns true to continue or false to cancel.
     pub async fn fetch<F>(
@@ -134,7 +164,7 @@ impl RepoOperations {
         options: &PullOptions,
         progress_cb: Option<F>,
         ssh_credentials: Option<SshCredentials>,
-    ) -> Result<()>
+    ) -> Result<PullResult>
     where
         F: FnMut(&git2::Progress<'_>) -> bool + Send + 'static,
     {
@@ -1,7 +1,14 @@
 use crate::error::Result;
+use crate::events::ActionOutputStream;
 use crate::models::{
-    LogOptions, Repository, RepositoryStatus, SignatureVerification, SigningFormat,
+    ActionExecutionResult, BrokenHeadAction, BrokenHeadRecovery, CommitHistoryPage,
+    CommitSignatureResult, ConfigAlias, ConfigEntry, ConfigLevel, LogOptions, ObjectSharing,
+    Repository, RepositoryStatus, RepositoryStatusOptions, SignatureVerification, SigningConfig,
+    SigningFormat, SyncFolderWarning,
 };
+use crate::services::{CommitCache, SigningService};
+use std::path::PathBuf;
+use std::sync::Arc;
 
 use super::RepoOperations;
 
@@ -12,6 +19,31 @@ impl RepoOperations {
             .await
     }
 
+    pub async fn get_broken_head_recoveries(&self) -> Result<Vec<BrokenHeadRecovery>> {
+        self.git2(super::super::git2_service::Git2Service::get_broken_head_recoveries)
+            .await
+    }
+
+    pub async fn recover_broken_head(
+        &self,
+        action: BrokenHeadAction,
+    ) -> Result<BrokenHeadRecovery> {
+        self.git2(move |g| g.recover_broken_head(action)).await
+    }
+
+    pub async fn get_sync_folder_warning(
+        &self,
+        acknowledged: bool,
+    ) -> Result<Option<SyncFolderWarning>> {
+        self.git2(move |g| g.get_sync_folder_warning(acknowledged))
+            .await
+    }
+
+    pub async fn get_object_sharing(&self, shared_roots: Vec<PathBuf>) -> Result<ObjectSharing> {
+        self.git2(move |g| g.get_object_sharing(&shared_roots))
+            .await
+    }
+
     pub async fn get_current_branch(&self) -> Option<String> {
         self.git2(super::super::git2_service::Git2Service::get_current_branch)
             .await
@@ -27,13 +59,22 @@ impl RepoOperations {
             .await
     }
 
-    pub async fn status(&self) -> Result<RepositoryStatus> {
-        self.git2(super::super::git2_service::Git2Service::status)
-            .await
+    pub async fn status(&self, options: RepositoryStatusOptions) -> Result<RepositoryStatus> {
+        self.git2(move |g| g.status(&options)).await
     }
 
     pub async fn log(&self, options: LogOptions) -> Result<Vec<crate::models::Commit>> {
-        self.git2(move |g| g.log(&options)).await
+        self.git2_objects(move |g| g.log(&options)).await
+    }
+
+    /// See `Git2Service::log_page`
+    pub async fn log_page(
+        &self,
+        options: LogOptions,
+        cache: Arc<CommitCache>,
+    ) -> Result<CommitHistoryPage> {
+        self.git2_objects(move |g| g.log_page(&options, &cache))
+            .await
     }
 
     pub async fn get_user_signature(&self) -> Result<(String, String)> {
@@ -51,6 +92,43 @@ impl RepoOperations {
             .await
     }
 
+    pub async fn get_config_value(&self, key: &str) -> Result<Option<String>> {
+        let key = key.to_string();
+        self.git2(move |g| g.get_config_value(&key)).await
+    }
+
+    pub async fn set_config_value(&self, key: &str, value: &str, level: ConfigLevel) -> Result<()> {
+        let key = key.to_string();
+        let value = value.to_string();
+        self.git2(move |g| g.set_config_value(&key, &value, level))
+            .await
+    }
+
+    pub async fn list_config_entries(&self) -> Result<Vec<ConfigEntry>> {
+        self.git2(super::super::git2_service::Git2Service::list_config_entries)
+            .await
+    }
+
+    pub async fn list_config_aliases(&self) -> Result<Vec<ConfigAlias>> {
+        self.git2(super::super::git2_service::Git2Service::list_config_aliases)
+            .await
+    }
+
+    /// Run git with the given arguments, streaming its output
+    pub async fn run_git_streaming<F>(
+        &self,
+        args: &[String],
+        on_output: F,
+    ) -> Result<ActionExecutionResult>
+    where
+        F: Fn(ActionOutputStream, &str),
+    {
+        self.service
+            .git_cli()
+            .execute_streaming(args, on_output)
+            .await
+    }
+
     pub async fn set_repo_user_config(
         &self,
         name: Option<&str>,
@@ -67,6 +145,22 @@ impl RepoOperations {
             .await
     }
 
+    /// Signing config from git config, overridden by repo-specific settings
+    pub async fn get_effective_signing_config(&self) -> Result<SigningConfig> {
+        let signing_service = SigningService::new(self.service.git2().path());
+        let mut config = signing_service.get_config_from_git()?;
+
+        let (repo_format, repo_key) = self.get_repo_signing_config().await?;
+        if let Some(format) = repo_format {
+            config.format = format;
+        }
+        if let Some(key) = repo_key {
+            config.signing_key = Some(key);
+        }
+
+        Ok(config)
+    }
+
     pub async fn set_repo_signing_config(
         &self,
         format: Option<&SigningFormat>,
@@ -100,4 +194,11 @@ impl RepoOperations {
         self.git2(move |g| g.verify_commit_signature(&oid_str, &format))
             .await
     }
+
+    pub async fn verify_commit_signatures(
+        &self,
+        oids: Vec<String>,
+    ) -> Result<Vec<CommitSignatureResult>> {
+        self.git2(move |g| g.verify_commit_signatures(&oids)).await
+    }
 }
@@ -0,0 +1,37 @@
+use crate::error::Result;
+use crate::models::{RemovePathPreview, RemovePathResult, RewritePreconditions, SigningConfig};
+
+use super::RepoOperations;
+
+/// History rewrite operations.
+impl RepoOperations {
+    pub async fn preview_remove_path_from_history(
+        &self,
+        paths: &[String],
+    ) -> Result<RemovePathPreview> {
+        let paths = paths.to_vec();
+        self.git2(move |g| g.preview_remove_path_from_history(&paths))
+            .await
+    }
+
+    pub async fn remove_path_from_unpushed_history(
+        &self,
+        paths: &[String],
+        acknowledge_other_branches: bool,
+        signing_config: Option<&SigningConfig>,
+        preconditions: Option<&RewritePreconditions>,
+    ) -> Result<RemovePathResult> {
+        let paths = paths.to_vec();
+        let signing_config = signing_config.cloned();
+        let preconditions = preconditions.cloned();
+        self.git2(move |g| {
+            g.remove_path_from_unpushed_history(
+                &paths,
+                acknowledge_other_branches,
+                signing_config.as_ref(),
+                preconditions.as_ref(),
+            )
+        })
+        .await
+    }
+}
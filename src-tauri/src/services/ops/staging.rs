@@ -1,5 +1,13 @@
 use crate::error::Result;
-use crate::models::{Commit, LargeBinaryFileInfo, ResetMode, SigningConfig};
+use crate::models::{
+    CoAuthor, Commit, CommitTrailerOptions, FileContent, FileContentSource, FileModeAudit,
+    FileModeAuditOptions, FileModeFix, FileModeFixResult, LargeBinaryFileInfo, ResetMode,
+    SigningConfig,
+};
+use crate::services::{
+    append_trailers, resolve_trailers, CommitSpellingContext, IdentifierCorpusCache,
+};
+use std::sync::Arc;
 
 use super::RepoOperations;
 
@@ -20,6 +28,28 @@ impl RepoOperations {
             .await
     }
 
+    /// See `Git2Service::commit_spelling_context`
+    pub async fn commit_spelling_context(
+        &self,
+        cache: Arc<IdentifierCorpusCache>,
+    ) -> Result<CommitSpellingContext> {
+        self.git2(move |g| g.commit_spelling_context(&cache)).await
+    }
+
+    pub async fn set_file_executable(&self, path: &str, executable: bool) -> Result<()> {
+        let path = path.to_string();
+        self.git2(move |g| g.set_file_executable(&path, executable))
+            .await
+    }
+
+    pub async fn audit_file_modes(&self, options: FileModeAuditOptions) -> Result<FileModeAudit> {
+        self.git2(move |g| g.audit_file_modes(&options)).await
+    }
+
+    pub async fn fix_file_modes(&self, fixes: Vec<FileModeFix>) -> Result<FileModeFixResult> {
+        self.git2(move |g| g.fix_file_modes(&fixes)).await
+    }
+
     pub async fn unstage_file(&self, path: &str) -> Result<()> {
         let path = path.to_string();
         self.git2(move |g| g.unstage_file(&path)).await
@@ -50,12 +80,34 @@ impl RepoOperations {
         self.git2(move |g| g.delete_file(&path)).await
     }
 
+    /// `message` with the trailers requested by `options`; the sign-off uses the
+    /// configured user
+    pub async fn apply_commit_trailers(
+        &self,
+        message: &str,
+        options: &CommitTrailerOptions,
+    ) -> Result<String> {
+        if options.is_empty() {
+            return Ok(message.to_string());
+        }
+        let committer = self.get_user_signature().await?;
+        Ok(append_trailers(
+            message,
+            &resolve_trailers(options, &committer),
+        ))
+    }
+
+    pub async fn get_recent_co_authors(&self, limit: usize) -> Result<Vec<CoAuthor>> {
+        self.git2(move |g| g.get_recent_co_authors(limit)).await
+    }
+
     pub async fn create_commit(
         &self,
         message: &str,
         author_name: Option<&str>,
         author_email: Option<&str>,
         signing_config: Option<&SigningConfig>,
+        allow_empty: bool,
     ) -> Result<String> {
         let message = message.to_string();
         let author_name = author_name.map(std::string::ToString::to_string);
@@ -67,14 +119,44 @@ impl RepoOperations {
                 author_name.as_deref(),
                 author_email.as_deref(),
                 signing_config.as_ref(),
+                allow_empty,
             )
         })
         .await
     }
 
-    pub async fn amend_commit(&self, message: Option<&str>) -> Result<String> {
+    #[allow(clippy::too_many_arguments)]
+    pub async fn amend_commit(
+        &self,
+        message: Option<&str>,
+        author_name: Option<&str>,
+        author_email: Option<&str>,
+        reset_author_date: bool,
+        include_staged: bool,
+        signing_config: Option<&SigningConfig>,
+        allow_empty: bool,
+    ) -> Result<String> {
         let message = message.map(std::string::ToString::to_string);
-        self.git2(move |g| g.amend_commit(message.as_deref())).await
+        let author_name = author_name.map(std::string::ToString::to_string);
+        let author_email = author_email.map(std::string::ToString::to_string);
+        let signing_config = signing_config.cloned();
+        self.git2(move |g| {
+            g.amend_commit(
+                message.as_deref(),
+                author_name.as_deref(),
+                author_email.as_deref(),
+                reset_author_date,
+                include_staged,
+                signing_config.as_ref(),
+                allow_empty,
+            )
+        })
+        .await
+    }
+
+    pub async fn index_matches_head(&self) -> Result<bool> {
+        self.git2(super::super::git2_service::Git2Service::index_matches_head)
+            .await
     }
 
     pub async fn get_commit(&self, oid_str: &str) -> Result<Commit> {
@@ -85,7 +167,17 @@ impl RepoOperations {
     pub async fn get_file_blob(&self, path: &str, commit_oid: Option<&str>) -> Result<Vec<u8>> {
         let path = path.to_string();
         let commit_oid = commit_oid.map(std::string::ToString::to_string);
-        self.git2(move |g| g.get_file_blob(&path, commit_oid.as_deref()))
+        self.git2_objects(move |g| g.get_file_blob(&path, commit_oid.as_deref()))
+            .await
+    }
+
+    pub async fn get_file_content(
+        &self,
+        path: &str,
+        source: FileContentSource,
+    ) -> Result<FileContent> {
+        let path = path.to_string();
+        self.git2_objects(move |g| g.get_file_content(&path, &source))
             .await
     }
 
@@ -124,4 +216,22 @@ impl RepoOperations {
     ) -> Result<crate::services::GitCommandResult> {
         self.service.git_cli().reset(target, mode).await
     }
+
+    pub async fn get_hard_reset_discards(&self, target: &str) -> Result<Vec<String>> {
+        let target = target.to_string();
+        self.git2(move |g| g.get_hard_reset_discards(&target)).await
+    }
+
+    pub async fn reset_paths(&self, commit: &str, paths: &[String]) -> Result<()> {
+        let commit = commit.to_string();
+        let paths = paths.to_vec();
+        self.git2(move |g| g.reset_paths(&commit, &paths)).await
+    }
+
+    pub async fn reset_file_to_commit(&self, path: &str, commit: &str) -> Result<()> {
+        let path = path.to_string();
+        let commit = commit.to_string();
+        self.git2(move |g| g.reset_file_to_commit(&path, &commit))
+            .await
+    }
 }
@@ -1,5 +1,8 @@
-use crate::error::Result;
-use crate::models::{StashApplyOptions, StashEntry, StashResult, StashSaveOptions};
+use crate::error::{AxisError, Result};
+use crate::models::{
+    DiffOptions, FileDiff, StashApplyOptions, StashEntry, StashHunkSelection, StashResult,
+    StashSaveOptions,
+};
 
 use super::RepoOperations;
 
@@ -13,6 +16,42 @@ impl RepoOperations {
         self.service.git_cli().stash_save(options).await
     }
 
+    pub async fn stash_save_selected_files(
+        &self,
+        paths: &[String],
+        message: Option<&str>,
+    ) -> Result<()> {
+        self.service
+            .git_cli()
+            .stash_save_selected_files(paths, message)
+            .await
+    }
+
+    pub async fn get_stash_diff(
+        &self,
+        index: usize,
+        options: &DiffOptions,
+    ) -> Result<Vec<FileDiff>> {
+        let options = options.clone();
+        self.git2(move |g| g.get_stash_diff(index, &options)).await
+    }
+
+    /// Stash the selected hunks of the unstaged changes and return the new entry
+    pub async fn stash_selection(
+        &self,
+        selections: Vec<StashHunkSelection>,
+        message: Option<String>,
+        context_lines: Option<u32>,
+    ) -> Result<StashEntry> {
+        self.git2(move |g| g.stash_selection(&selections, message.as_deref(), context_lines))
+            .await?;
+        self.stash_list()
+            .await?
+            .into_iter()
+            .next()
+            .ok_or_else(|| AxisError::Other("Stash entry was not created".to_string()))
+    }
+
     pub async fn stash_apply(&self, options: &StashApplyOptions) -> Result<StashResult> {
         self.service.git_cli().stash_apply(options).await
     }
@@ -1,7 +1,7 @@
 use crate::error::Result;
 use crate::models::{
-    AddSubmoduleOptions, ListSubmoduleOptions, SshCredentials, Submodule, SubmoduleResult,
-    SyncSubmoduleOptions, UpdateSubmoduleOptions,
+    AddSubmoduleOptions, ListSubmoduleOptions, OutOfSyncSubmodule, SshCredentials, Submodule,
+    SubmoduleResult, SyncSubmoduleOptions, UpdateSubmoduleOptions,
 };
 
 use super::RepoOperations;
@@ -27,17 +27,101 @@ impl RepoOperations {
         self.service.git_cli().submodule_init(paths).await
     }
 
+    /// Update submodules. `ssh_credentials` maps submodule paths to the key of their own
+    /// remote; submodules sharing a key are updated by one `git submodule update`.
     pub async fn submodule_update(
         &self,
         options: &UpdateSubmoduleOptions,
-        ssh_credentials: Option<SshCredentials>,
+        ssh_credentials: Vec<(String, SshCredentials)>,
     ) -> Result<SubmoduleResult> {
-        self.service
-            .git_cli()
-            .submodule_update(options, ssh_credentials.as_ref())
+        let cli = self.service.git_cli();
+        if ssh_credentials.is_empty() {
+            return cli.submodule_update(options, None).await;
+        }
+
+        let paths: Vec<String> = if options.paths.is_empty() {
+            self.submodule_remotes()
+                .await?
+                .into_iter()
+                .map(|(path, _)| path)
+                .collect()
+        } else {
+            options.paths.clone()
+        };
+
+        let mut groups: Vec<(Option<&SshCredentials>, Vec<String>)> = Vec::new();
+        for path in paths {
+            let creds = ssh_credentials
+                .iter()
+                .find(|(sm_path, _)| *sm_path == path)
+                .map(|(_, creds)| creds);
+            let key_path = creds.map(|creds| creds.key_path.as_str());
+            match groups
+                .iter_mut()
+                .find(|(group_creds, _)| group_creds.map(|c| c.key_path.as_str()) == key_path)
+            {
+                Some((_, group_paths)) => group_paths.push(path),
+                None => groups.push((creds, vec![path])),
+            }
+        }
+
+        let mut failures = Vec::new();
+        let mut submodules = Vec::new();
+        for (creds, paths) in groups {
+            let group_options = UpdateSubmoduleOptions {
+                paths,
+                ..options.clone()
+            };
+            let result = cli.submodule_update(&group_options, creds).await?;
+            if !result.success {
+                failures.push(result.message);
+            }
+            submodules.extend(group_options.paths);
+        }
+
+        Ok(SubmoduleResult {
+            success: failures.is_empty(),
+            message: if failures.is_empty() {
+                "Submodules updated".to_string()
+            } else {
+                failures.join("\n")
+            },
+            submodules,
+        })
+    }
+
+    /// Remote of every submodule; see `Git2Service::submodule_remotes`
+    pub async fn submodule_remotes(&self) -> Result<Vec<(String, String)>> {
+        self.git2(super::super::git2_service::Git2Service::submodule_remotes)
             .await
     }
 
+    pub async fn get_out_of_sync_submodules(&self) -> Result<Vec<OutOfSyncSubmodule>> {
+        self.git2(super::super::git2_service::Git2Service::get_out_of_sync_submodules)
+            .await
+    }
+
+    /// Check out the recorded commit in every submodule that is out of sync with HEAD.
+    pub async fn update_out_of_sync_submodules(
+        &self,
+        ssh_credentials: Vec<(String, SshCredentials)>,
+    ) -> Result<SubmoduleResult> {
+        let out_of_sync = self.get_out_of_sync_submodules().await?;
+        if out_of_sync.is_empty() {
+            return Ok(SubmoduleResult {
+                success: true,
+                message: "All submodules are in sync".to_string(),
+                submodules: Vec::new(),
+            });
+        }
+
+        let options = UpdateSubmoduleOptions {
+            paths: out_of_sync.into_iter().map(|sm| sm.path).collect(),
+            ..Default::default()
+        };
+        self.submodule_update(&options, ssh_credentials).await
+    }
+
     pub async fn submodule_sync(&self, options: &SyncSubmoduleOptions) -> Result<SubmoduleResult> {
         self.service.git_cli().submodule_sync(options).await
     }
@@ -1,9 +1,13 @@
 use crate::error::Result;
-use crate::models::{CreateTagOptions, ListTagsOptions, SshCredentials, Tag, TagResult};
+use crate::models::{
+    BulkTagSpec, CreateTagOptions, DeleteTagsByPatternOptions, DeleteTagsByPatternResult,
+    ListTagsOptions, SshCredentials, Tag, TagOperationResult, TagPushOptions, TagPushPlan,
+    TagPushResult, TagResult,
+};
 
 use super::RepoOperations;
 
-/// Tag operations (git2 + CLI for remote ops).
+/// Tag operations (git2; CLI for atomic remote pushes).
 impl RepoOperations {
     pub async fn tag_list(&self, options: ListTagsOptions) -> Result<Vec<Tag>> {
         self.git2(move |g| g.tag_list(&options, None)).await
@@ -20,28 +24,58 @@ impl RepoOperations {
         self.git2(move |g| g.tag_delete(&name)).await
     }
 
-    // --- CLI-based remote tag ops ---
+    pub async fn tag_create_bulk(
+        &self,
+        specs: Vec<BulkTagSpec>,
+        stop_on_error: bool,
+    ) -> Result<Vec<TagOperationResult>> {
+        self.git2(move |g| g.tag_create_bulk(&specs, stop_on_error))
+            .await
+    }
+
+    pub async fn tag_delete_by_pattern(
+        &self,
+        pattern: &str,
+        options: DeleteTagsByPatternOptions,
+        remote_credentials: Vec<(String, Option<SshCredentials>)>,
+    ) -> Result<DeleteTagsByPatternResult> {
+        let pattern = pattern.to_string();
+        self.git2(move |g| g.tag_delete_by_pattern(&pattern, &options, &remote_credentials))
+            .await
+    }
+
+    // --- Remote tag ops (git2, CLI for atomic pushes) ---
 
     pub async fn tag_push(
         &self,
         name: &str,
         remote: &str,
+        options: TagPushOptions,
         ssh_credentials: Option<SshCredentials>,
-    ) -> Result<TagResult> {
-        self.service
-            .git_cli()
-            .tag_push(name, remote, ssh_credentials.as_ref())
+    ) -> Result<TagPushResult> {
+        let names = Some(vec![name.to_string()]);
+        let remote_name = remote.to_string();
+        let creds = ssh_credentials.clone();
+        let plan = self
+            .git2(move |g| g.plan_tag_push(&remote_name, names, creds))
+            .await?;
+        self.push_tag_plan(remote, plan, &options, ssh_credentials)
             .await
     }
 
+    /// Push the local tags the remote lacks; tags it already has are reported up to date
     pub async fn tag_push_all(
         &self,
         remote: &str,
+        options: TagPushOptions,
         ssh_credentials: Option<SshCredentials>,
-    ) -> Result<TagResult> {
-        self.service
-            .git_cli()
-            .tag_push_all(remote, ssh_credentials.as_ref())
+    ) -> Result<TagPushResult> {
+        let remote_name = remote.to_string();
+        let creds = ssh_credentials.clone();
+        let plan = self
+            .git2(move |g| g.plan_tag_push(&remote_name, None, creds))
+            .await?;
+        self.push_tag_plan(remote, plan, &options, ssh_credentials)
             .await
     }
 
@@ -49,11 +83,38 @@ impl RepoOperations {
         &self,
         name: &str,
         remote: &str,
+        options: TagPushOptions,
+        ssh_credentials: Option<SshCredentials>,
+    ) -> Result<TagPushResult> {
+        let names = vec![name.to_string()];
+        let remote_name = remote.to_string();
+        let creds = ssh_credentials.clone();
+        let plan = self
+            .git2(move |g| g.plan_tag_delete(&remote_name, names, creds))
+            .await?;
+        self.push_tag_plan(remote, plan, &options, ssh_credentials)
+            .await
+    }
+
+    async fn push_tag_plan(
+        &self,
+        remote: &str,
+        plan: TagPushPlan,
+        options: &TagPushOptions,
         ssh_credentials: Option<SshCredentials>,
-    ) -> Result<TagResult> {
+    ) -> Result<TagPushResult> {
+        if !options.atomic {
+            let remote = remote.to_string();
+            return self
+                .git2(move |g| Ok(g.push_tag_plan(&remote, plan, ssh_credentials)))
+                .await;
+        }
+        if plan.has_rejections() {
+            return Ok(plan.abort(remote));
+        }
         self.service
             .git_cli()
-            .tag_delete_remote(name, remote, ssh_credentials.as_ref())
+            .push_tags_atomic(remote, plan, ssh_credentials.as_ref())
             .await
     }
 }
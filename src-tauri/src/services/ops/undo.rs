@@ -0,0 +1,30 @@
+use crate::error::Result;
+use crate::models::UndoSnapshot;
+
+use super::RepoOperations;
+
+/// Undo snapshot operations.
+impl RepoOperations {
+    pub async fn create_undo_snapshot(&self) -> Result<UndoSnapshot> {
+        self.service.git_cli().create_undo_snapshot().await
+    }
+
+    /// Restore `snapshot`; see `GitCliService::restore_undo_snapshot`
+    pub async fn restore_undo_snapshot(
+        &self,
+        snapshot: &UndoSnapshot,
+    ) -> Result<Option<UndoSnapshot>> {
+        self.service.git_cli().restore_undo_snapshot(snapshot).await
+    }
+
+    pub async fn anchor_undo_snapshot(&self, id: i64, snapshot: &UndoSnapshot) -> Result<()> {
+        self.service
+            .git_cli()
+            .anchor_undo_snapshot(id, snapshot)
+            .await
+    }
+
+    pub async fn prune_undo_refs(&self, live_ids: &[i64]) -> Result<()> {
+        self.service.git_cli().prune_undo_refs(live_ids).await
+    }
+}
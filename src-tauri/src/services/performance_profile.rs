@@ -0,0 +1,268 @@
+use std::io::Read;
+use std::path::Path;
+
+use crate::error::Result;
+use crate::models::{
+    AppSettings, PerformanceConfig, PerformanceProfile, PerformanceReadiness, PerformanceSettings,
+    RepositorySizeSignals,
+};
+use crate::services::{object_store_size, DEFAULT_WATCHER_DEBOUNCE};
+
+/// Tracked files at which `Auto` picks `LargeRepo`
+pub const LARGE_REPO_TRACKED_FILES: u64 = 100_000;
+
+/// References at which `Auto` picks `LargeRepo`
+pub const LARGE_REPO_REFS: u64 = 10_000;
+
+/// Pack bytes at which `Auto` picks `LargeRepo`
+pub const LARGE_REPO_PACK_BYTES: u64 = 2 * 1024 * 1024 * 1024;
+
+/// Signature opening an index file
+const INDEX_SIGNATURE: &[u8; 4] = b"DIRC";
+
+/// Measure what `Auto` decides from without loading the index or building branch
+/// models: the index entry count comes from its header, refs are counted by name,
+/// and packs are sized from the directory listing.
+pub fn measure_size_signals(repo_path: &Path) -> Result<RepositorySizeSignals> {
+    let repo = git2::Repository::open(repo_path)?;
+    let tracked_files = std::fs::File::open(repo.path().join("index"))
+        .ok()
+        .and_then(|mut file| {
+            let mut header = [0u8; 12];
+            file.read_exact(&mut header).ok()?;
+            index_entry_count(&header)
+        })
+        .unwrap_or(0);
+    let refs = repo.references()?.names().count() as u64;
+    let pack_bytes = object_store_size(&repo.commondir().join("objects")).pack_size;
+
+    Ok(RepositorySizeSignals {
+        tracked_files,
+        refs,
+        pack_bytes,
+    })
+}
+
+/// Number of entries in an index, from its 12-byte header: signature, version and
+/// entry count, all big-endian
+pub fn index_entry_count(header: &[u8]) -> Option<u64> {
+    if !header.starts_with(INDEX_SIGNATURE) {
+        return None;
+    }
+    let bytes: [u8; 4] = header.get(8..12)?.try_into().ok()?;
+    Some(u64::from(u32::from_be_bytes(bytes)))
+}
+
+/// The profile `configured` resolves to and why. `Auto` needs `signals`; without
+/// them it falls back to `Standard`.
+pub fn decide_profile(
+    configured: PerformanceProfile,
+    signals: Option<&RepositorySizeSignals>,
+) -> (PerformanceProfile, String) {
+    if configured != PerformanceProfile::Auto {
+        return (configured, "Chosen in repository settings".to_string());
+    }
+    let Some(signals) = signals else {
+        return (
+            PerformanceProfile::Standard,
+            "Repository size could not be measured".to_string(),
+        );
+    };
+
+    let mut exceeded = Vec::new();
+    if signals.tracked_files >= LARGE_REPO_TRACKED_FILES {
+        exceeded.push(format!("{} tracked files", signals.tracked_files));
+    }
+    if signals.refs >= LARGE_REPO_REFS {
+        exceeded.push(format!("{} refs", signals.refs));
+    }
+    if signals.pack_bytes >= LARGE_REPO_PACK_BYTES {
+        exceeded.push(format!(
+            "{} MiB of packs",
+            signals.pack_bytes / (1024 * 1024)
+        ));
+    }
+
+    if exceeded.is_empty() {
+        (
+            PerformanceProfile::Standard,
+            "Below every large repository threshold".to_string(),
+        )
+    } else {
+        (
+            PerformanceProfile::LargeRepo,
+            format!("Large repository: {}", exceeded.join(", ")),
+        )
+    }
+}
+
+/// Defaults a profile applies before any explicit setting
+pub fn profile_defaults(profile: PerformanceProfile) -> PerformanceSettings {
+    match profile {
+        PerformanceProfile::LargeRepo => PerformanceSettings {
+            file_watcher_debounce_ms: 1500,
+            background_fetch: false,
+            skip_ahead_behind: true,
+            graph_page_size: 100,
+        },
+        PerformanceProfile::Auto | PerformanceProfile::Standard | PerformanceProfile::Custom => {
+            PerformanceSettings {
+                file_watcher_debounce_ms: u32::try_from(DEFAULT_WATCHER_DEBOUNCE.as_millis())
+                    .unwrap_or(u32::MAX),
+                background_fetch: true,
+                skip_ahead_behind: false,
+                graph_page_size: 200,
+            }
+        }
+    }
+}
+
+/// Resolve the effective settings of a repository. Each knob takes the first of:
+/// the repository's override, an explicit global setting, the profile's default.
+pub fn resolve_performance(
+    config: PerformanceConfig,
+    signals: Option<RepositorySizeSignals>,
+    global: &AppSettings,
+) -> PerformanceReadiness {
+    let (effective_profile, reason) = decide_profile(config.profile, signals.as_ref());
+    let defaults = profile_defaults(effective_profile);
+    let overrides = &config.overrides;
+
+    let settings = PerformanceSettings {
+        file_watcher_debounce_ms: overrides
+            .file_watcher_debounce_ms
+            .or(global.file_watcher_debounce_ms)
+            .unwrap_or(defaults.file_watcher_debounce_ms),
+        background_fetch: overrides
+            .background_fetch
+            .unwrap_or(defaults.background_fetch),
+        skip_ahead_behind: overrides
+            .skip_ahead_behind
+            .unwrap_or(defaults.skip_ahead_behind),
+        graph_page_size: overrides
+            .graph_page_size
+            .unwrap_or(defaults.graph_page_size),
+    };
+
+    PerformanceReadiness {
+        config,
+        effective_profile,
+        signals,
+        reason,
+        settings,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::PerformanceOverrides;
+
+    fn signals(tracked_files: u64, refs: u64, pack_bytes: u64) -> RepositorySizeSignals {
+        RepositorySizeSignals {
+            tracked_files,
+            refs,
+            pack_bytes,
+        }
+    }
+
+    #[test]
+    fn test_index_entry_count_reads_header() {
+        let mut header = b"DIRC".to_vec();
+        header.extend(2u32.to_be_bytes());
+        header.extend(1234u32.to_be_bytes());
+        assert_eq!(index_entry_count(&header), Some(1234));
+
+        assert_eq!(index_entry_count(&header[..10]), None);
+        header[0] = b'X';
+        assert_eq!(index_entry_count(&header), None);
+    }
+
+    #[test]
+    fn test_auto_picks_large_repo_at_each_threshold() {
+        let below = signals(
+            LARGE_REPO_TRACKED_FILES - 1,
+            LARGE_REPO_REFS - 1,
+            LARGE_REPO_PACK_BYTES - 1,
+        );
+        let (profile, reason) = decide_profile(PerformanceProfile::Auto, Some(&below));
+        assert_eq!(profile, PerformanceProfile::Standard);
+        assert_eq!(reason, "Below every large repository threshold");
+
+        for large in [
+            signals(LARGE_REPO_TRACKED_FILES, 0, 0),
+            signals(0, LARGE_REPO_REFS, 0),
+            signals(0, 0, LARGE_REPO_PACK_BYTES),
+        ] {
+            let (profile, _) = decide_profile(PerformanceProfile::Auto, Some(&large));
+            assert_eq!(profile, PerformanceProfile::LargeRepo, "{large:?}");
+        }
+
+        let (_, reason) = decide_profile(
+            PerformanceProfile::Auto,
+            Some(&signals(250_000, 12, LARGE_REPO_PACK_BYTES)),
+        );
+        assert_eq!(
+            reason,
+            "Large repository: 250000 tracked files, 2048 MiB of packs"
+        );
+    }
+
+    #[test]
+    fn test_auto_without_signals_and_explicit_profiles() {
+        let (profile, _) = decide_profile(PerformanceProfile::Auto, None);
+        assert_eq!(profile, PerformanceProfile::Standard);
+
+        let huge = signals(u64::MAX, u64::MAX, u64::MAX);
+        let (profile, _) = decide_profile(PerformanceProfile::Standard, Some(&huge));
+        assert_eq!(profile, PerformanceProfile::Standard);
+        let (profile, _) = decide_profile(PerformanceProfile::LargeRepo, None);
+        assert_eq!(profile, PerformanceProfile::LargeRepo);
+    }
+
+    #[test]
+    fn test_large_repo_bundle_changes_every_subsystem() {
+        let standard = profile_defaults(PerformanceProfile::Standard);
+        let large = profile_defaults(PerformanceProfile::LargeRepo);
+
+        assert!(large.file_watcher_debounce_ms > standard.file_watcher_debounce_ms);
+        assert!(standard.background_fetch && !large.background_fetch);
+        assert!(!standard.skip_ahead_behind && large.skip_ahead_behind);
+        assert!(large.graph_page_size < standard.graph_page_size);
+        assert_eq!(profile_defaults(PerformanceProfile::Custom), standard);
+    }
+
+    #[test]
+    fn test_overrides_win_over_global_settings_and_profile() {
+        let global = AppSettings {
+            file_watcher_debounce_ms: Some(800),
+            ..AppSettings::default()
+        };
+        let config = PerformanceConfig {
+            profile: PerformanceProfile::LargeRepo,
+            overrides: PerformanceOverrides {
+                background_fetch: Some(true),
+                ..PerformanceOverrides::default()
+            },
+        };
+
+        let resolved = resolve_performance(config.clone(), None, &global);
+        assert_eq!(resolved.effective_profile, PerformanceProfile::LargeRepo);
+        // Explicit global setting beats the bundle, repository override beats both
+        assert_eq!(resolved.settings.file_watcher_debounce_ms, 800);
+        assert!(resolved.settings.background_fetch);
+        // Knobs nobody set come from the bundle
+        assert!(resolved.settings.skip_ahead_behind);
+        assert_eq!(resolved.settings.graph_page_size, 100);
+
+        let config = PerformanceConfig {
+            overrides: PerformanceOverrides {
+                file_watcher_debounce_ms: Some(2000),
+                ..config.overrides
+            },
+            ..config
+        };
+        let resolved = resolve_performance(config, None, &global);
+        assert_eq!(resolved.settings.file_watcher_debounce_ms, 2000);
+    }
+}
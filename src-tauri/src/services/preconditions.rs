@@ -0,0 +1,223 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use git2::{Index, ObjectType, Oid, Repository};
+
+use crate::error::{AxisError, Result};
+use crate::models::{PreconditionViolation, RefPrecondition, RewritePreconditions};
+use crate::services::{RefTransaction, StaleRef};
+
+/// Source of `RewritePreconditions::generation`
+static NEXT_GENERATION: AtomicU64 = AtomicU64::new(1);
+
+/// Record what each of `refnames` points at now, and the index checksum when `with_index`
+/// is set, under a fresh generation
+pub fn capture_preconditions(
+    repo: &Repository,
+    refnames: &[String],
+    with_index: bool,
+) -> Result<RewritePreconditions> {
+    let mut refs: Vec<RefPrecondition> = Vec::with_capacity(refnames.len());
+    for refname in refnames {
+        if refs.iter().any(|r| &r.refname == refname) {
+            continue;
+        }
+        refs.push(RefPrecondition {
+            refname: refname.clone(),
+            oid: repo.refname_to_id(refname).ok().map(|oid| oid.to_string()),
+        });
+    }
+    let index_checksum = if with_index {
+        Some(index_checksum(repo)?)
+    } else {
+        None
+    };
+
+    Ok(RewritePreconditions {
+        generation: NEXT_GENERATION.fetch_add(1, Ordering::Relaxed),
+        refs,
+        index_checksum,
+    })
+}
+
+/// Hash of the index entries (path, stage, mode and object id), read from disk. Stat data
+/// is left out, so the index being rewritten by a refresh does not change it. All zeros
+/// when the repository has no index.
+pub fn index_checksum(repo: &Repository) -> Result<String> {
+    let index_path = repo.path().join("index");
+    if !index_path.exists() {
+        return Ok(Oid::zero().to_string());
+    }
+
+    let mut entries = Vec::new();
+    for entry in Index::open(&index_path)?.iter() {
+        entries.extend_from_slice(&entry.path);
+        let stage = (entry.flags >> 12) & 0x3;
+        entries.extend_from_slice(format!("\0{:o} {stage} {}\n", entry.mode, entry.id).as_bytes());
+    }
+    Ok(Oid::hash_object(ObjectType::Blob, &entries)?.to_string())
+}
+
+/// Commit `transaction` only if every precondition still holds. The refs are locked and
+/// compared through the transaction's own expectations, the index checksum under the same
+/// locks; any mismatch fails with `PreconditionFailed` naming everything that moved.
+pub fn commit_with_preconditions(
+    repo: &Repository,
+    preconditions: &RewritePreconditions,
+    transaction: &RefTransaction,
+) -> Result<()> {
+    let mut guarded = transaction.clone();
+    for precondition in &preconditions.refs {
+        if guarded.touches(&precondition.refname) {
+            continue;
+        }
+        let expected = precondition.oid.as_deref().map(Oid::from_str).transpose()?;
+        guarded.verify(&precondition.refname, expected);
+    }
+
+    guarded.commit_checked(repo, |stale| {
+        let mut violations: Vec<PreconditionViolation> =
+            stale.iter().map(|s| ref_violation(repo, s)).collect();
+        if let Some(expected) = &preconditions.index_checksum {
+            let actual = index_checksum(repo)?;
+            if &actual != expected {
+                violations.push(PreconditionViolation::Index {
+                    expected: expected.clone(),
+                    actual,
+                });
+            }
+        }
+
+        if violations.is_empty() {
+            Ok(())
+        } else {
+            Err(AxisError::PreconditionFailed {
+                generation: preconditions.generation,
+                violations,
+            })
+        }
+    })
+}
+
+/// Check every precondition without updating anything, for operations whose refs are
+/// written by git itself
+pub fn check_preconditions(repo: &Repository, preconditions: &RewritePreconditions) -> Result<()> {
+    commit_with_preconditions(repo, preconditions, &RefTransaction::new())
+}
+
+fn ref_violation(repo: &Repository, stale: &StaleRef) -> PreconditionViolation {
+    let counts = match (stale.actual, stale.expected) {
+        (Some(actual), Some(expected)) => repo.graph_ahead_behind(actual, expected).ok(),
+        _ => None,
+    };
+    PreconditionViolation::Ref {
+        refname: stale.refname.clone(),
+        expected: stale.expected.map(|oid| oid.to_string()),
+        actual: stale.actual.map(|oid| oid.to_string()),
+        ahead: counts.map(|(ahead, _)| ahead),
+        behind: counts.map(|(_, behind)| behind),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn commit_file(repo: &Repository, name: &str) -> Oid {
+        let workdir = repo.workdir().expect("should have workdir");
+        std::fs::write(workdir.join(name), name).expect("should write file");
+        let mut index = repo.index().expect("should open index");
+        index
+            .add_path(std::path::Path::new(name))
+            .expect("should add file");
+        index.write().expect("should write index");
+        let tree_id = index.write_tree().expect("should write tree");
+        let tree = repo.find_tree(tree_id).expect("should find tree");
+        let sig = git2::Signature::now("Test User", "test@test.com").expect("should create sig");
+        let parent = repo.head().ok().and_then(|h| h.peel_to_commit().ok());
+        let parents: Vec<&git2::Commit> = parent.iter().collect();
+        repo.commit(Some("HEAD"), &sig, &sig, name, &tree, &parents)
+            .expect("should commit")
+    }
+
+    #[test]
+    fn test_moved_ref_is_reported_with_commit_counts() {
+        let tmp = TempDir::new().expect("should create temp dir");
+        let repo = Repository::init(tmp.path()).expect("should init repo");
+        let planned = commit_file(&repo, "a.txt");
+        let refnames = vec!["HEAD".to_string(), "refs/heads/missing".to_string()];
+
+        let preconditions = capture_preconditions(&repo, &refnames, false).expect("should capture");
+        assert_eq!(preconditions.refs[0].oid, Some(planned.to_string()));
+        assert_eq!(preconditions.refs[1].oid, None);
+        check_preconditions(&repo, &preconditions).expect("should hold");
+
+        commit_file(&repo, "b.txt");
+        let moved = commit_file(&repo, "c.txt");
+        let err = check_preconditions(&repo, &preconditions).expect_err("should fail");
+        match err {
+            AxisError::PreconditionFailed {
+                generation,
+                violations,
+            } => {
+                assert_eq!(generation, preconditions.generation);
+                assert_eq!(
+                    violations,
+                    vec![PreconditionViolation::Ref {
+                        refname: "HEAD".to_string(),
+                        expected: Some(planned.to_string()),
+                        actual: Some(moved.to_string()),
+                        ahead: Some(2),
+                        behind: Some(0),
+                    }]
+                );
+            }
+            other => panic!("expected PreconditionFailed, got {other:?}"),
+        }
+
+        let later = capture_preconditions(&repo, &refnames, false).expect("should capture");
+        assert!(later.generation > preconditions.generation);
+    }
+
+    #[test]
+    fn test_index_checksum_changes_when_index_content_changes() {
+        let tmp = TempDir::new().expect("should create temp dir");
+        let repo = Repository::init(tmp.path()).expect("should init repo");
+        assert_eq!(
+            index_checksum(&repo).expect("should read checksum"),
+            Oid::zero().to_string()
+        );
+
+        commit_file(&repo, "a.txt");
+        let preconditions = capture_preconditions(&repo, &[], true).expect("should capture");
+        check_preconditions(&repo, &preconditions).expect("should hold");
+
+        // Re-adding an unchanged file with new stat data rewrites the index but not its entries
+        std::fs::File::options()
+            .write(true)
+            .open(tmp.path().join("a.txt"))
+            .expect("should open file")
+            .set_modified(std::time::SystemTime::UNIX_EPOCH)
+            .expect("should set mtime");
+        let mut index = repo.index().expect("should open index");
+        index
+            .add_path(std::path::Path::new("a.txt"))
+            .expect("should refresh file");
+        index.write().expect("should write index");
+        check_preconditions(&repo, &preconditions).expect("should hold after a stat-only rewrite");
+
+        std::fs::write(tmp.path().join("b.txt"), "b").expect("should write file");
+        let mut index = repo.index().expect("should open index");
+        index
+            .add_path(std::path::Path::new("b.txt"))
+            .expect("should add file");
+        index.write().expect("should write index");
+
+        let err = check_preconditions(&repo, &preconditions).expect_err("should fail");
+        assert!(matches!(
+            err,
+            AxisError::PreconditionFailed { ref violations, .. }
+                if matches!(violations.as_slice(), [PreconditionViolation::Index { .. }])
+        ));
+    }
+}
@@ -15,7 +15,7 @@ const CREATE_NO_WINDOW: u32 = 0x08000000;
 
 /// Get extended PATH that includes common tool installation directories.
 /// This is needed because GUI apps on macOS don't inherit shell PATH.
-fn get_extended_path() -> String {
+pub(crate) fn get_extended_path() -> String {
     let current_path = env::var("PATH").unwrap_or_default();
 
     #[cfg(target_os = "macos")]
@@ -270,6 +270,24 @@ impl ProgressContext {
             emitter.emit_with_counts(&op_id, operation_type, stage, current, total, bytes)
         }
     }
+
+    /// Create an object count callback for transfers driven by CLI output (e.g. `git lfs`)
+    pub fn make_transfer_callback(
+        &self,
+        operation_type: GitOperationType,
+        stage: ProgressStage,
+    ) -> impl FnMut(usize, usize, usize) -> bool + Send {
+        let cancel_token = self.cancel_token();
+        let emitter = self.emitter();
+        let op_id = self.operation_id.clone();
+
+        move |current: usize, total: usize, bytes: usize| {
+            if cancel_token.load(Ordering::SeqCst) {
+                return false;
+            }
+            emitter.emit_with_counts(&op_id, operation_type, stage, current, total, bytes)
+        }
+    }
 }
 
 impl Drop for ProgressContext {
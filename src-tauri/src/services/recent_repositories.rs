@@ -0,0 +1,149 @@
+use std::time::Duration;
+
+use crate::models::RecentRepositoryWithStatus;
+use crate::storage::RecentRepositoryRow;
+
+/// How long the whole recent-repositories status list may take
+pub const RECENT_STATUS_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// Probe every recent repository concurrently, one blocking task each.
+///
+/// All probes share a single deadline, so a repository on an unreachable network drive
+/// is reported as timed out instead of holding up the list. Its task is left to finish
+/// in the background. Results keep the order of `rows`.
+pub async fn probe_recent_repositories<F>(
+    rows: Vec<RecentRepositoryRow>,
+    timeout: Duration,
+    probe: F,
+) -> Vec<RecentRepositoryWithStatus>
+where
+    F: Fn(RecentRepositoryRow) -> RecentRepositoryWithStatus + Clone + Send + 'static,
+{
+    let deadline = tokio::time::Instant::now() + timeout;
+
+    let handles: Vec<_> = rows
+        .into_iter()
+        .map(|row| {
+            let probe = probe.clone();
+            let fallback = row.clone();
+            let handle = tauri::async_runtime::spawn_blocking(move || probe(row));
+            (fallback, handle)
+        })
+        .collect();
+
+    let mut results = Vec::with_capacity(handles.len());
+    for (row, handle) in handles {
+        let path = row.path.display().to_string();
+        let result = match tokio::time::timeout_at(deadline, handle).await {
+            Ok(Ok(result)) => result,
+            Ok(Err(e)) => {
+                log::warn!("Failed to read status of recent repository {path}: {e}");
+                RecentRepositoryWithStatus::unprobed(row, false)
+            }
+            Err(_) => {
+                log::warn!("Timed out reading status of recent repository {path}");
+                RecentRepositoryWithStatus::unprobed(row, true)
+            }
+        };
+        results.push(result);
+    }
+    results
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+    use std::path::PathBuf;
+    use std::process::Command;
+    use tempfile::TempDir;
+
+    fn row(path: PathBuf, name: &str) -> RecentRepositoryRow {
+        RecentRepositoryRow {
+            path,
+            name: name.to_string(),
+            last_opened: Utc::now(),
+            is_pinned: false,
+            last_branch: None,
+        }
+    }
+
+    fn git(dir: &std::path::Path, args: &[&str]) {
+        let status = Command::new("git")
+            .args(args)
+            .current_dir(dir)
+            .status()
+            .expect("should run git");
+        assert!(status.success(), "git {args:?} failed");
+    }
+
+    #[tokio::test]
+    async fn test_probe_reports_branch_and_dirty_files() {
+        let tmp = TempDir::new().expect("should create temp dir");
+        git(tmp.path(), &["init", "-b", "trunk"]);
+        git(tmp.path(), &["config", "user.email", "test@test.com"]);
+        git(tmp.path(), &["config", "user.name", "Test User"]);
+        std::fs::write(tmp.path().join("a.txt"), "a").expect("should write");
+        git(tmp.path(), &["add", "."]);
+        git(tmp.path(), &["commit", "-m", "init"]);
+        std::fs::write(tmp.path().join("a.txt"), "changed").expect("should write");
+        std::fs::write(tmp.path().join("b.txt"), "new").expect("should write");
+
+        let results = probe_recent_repositories(
+            vec![row(tmp.path().to_path_buf(), "repo")],
+            RECENT_STATUS_TIMEOUT,
+            RecentRepositoryWithStatus::probe,
+        )
+        .await;
+
+        let repo = &results[0];
+        assert!(repo.repository.exists);
+        assert!(!repo.timed_out);
+        assert_eq!(repo.repository.current_branch.as_deref(), Some("trunk"));
+        let status = repo.status.as_ref().expect("should have status");
+        assert_eq!(status.dirty_files, 2);
+        assert_eq!(status.upstream, None);
+    }
+
+    #[tokio::test]
+    async fn test_probe_marks_missing_path() {
+        let tmp = TempDir::new().expect("should create temp dir");
+        let missing = tmp.path().join("gone");
+
+        let results = probe_recent_repositories(
+            vec![row(missing, "gone")],
+            RECENT_STATUS_TIMEOUT,
+            RecentRepositoryWithStatus::probe,
+        )
+        .await;
+
+        assert_eq!(results.len(), 1);
+        assert!(!results[0].repository.exists);
+        assert!(!results[0].timed_out);
+        assert!(results[0].status.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_slow_probe_times_out_without_blocking_others() {
+        let rows = vec![
+            row(PathBuf::from("/slow"), "slow"),
+            row(PathBuf::from("/fast"), "fast"),
+        ];
+
+        let started = std::time::Instant::now();
+        let results = probe_recent_repositories(rows, Duration::from_millis(100), |row| {
+            if row.name == "slow" {
+                std::thread::sleep(Duration::from_secs(2));
+            }
+            RecentRepositoryWithStatus::unprobed(row, false)
+        })
+        .await;
+
+        assert!(started.elapsed() < Duration::from_secs(1));
+        let names: Vec<_> = results
+            .iter()
+            .map(|r| (r.repository.name.as_str(), r.timed_out))
+            .collect();
+        assert_eq!(names, vec![("slow", true), ("fast", false)]);
+    }
+}
@@ -0,0 +1,366 @@
+use std::collections::HashSet;
+
+use git2::{Oid, Repository};
+
+use crate::error::{AxisError, Result};
+
+/// Value a ref must have when a `RefTransaction` is committed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Expected {
+    Missing,
+    At(Oid),
+}
+
+#[derive(Debug, Clone)]
+enum RefChange {
+    Set {
+        target: Oid,
+        message: String,
+    },
+    Delete,
+    /// Locked and checked, left unchanged
+    Keep,
+}
+
+#[derive(Debug, Clone)]
+struct RefUpdate {
+    name: String,
+    expected: Expected,
+    change: RefChange,
+}
+
+/// A ref that no longer had its expected value when a transaction was committed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StaleRef {
+    pub refname: String,
+    /// `None` when the ref was expected not to exist
+    pub expected: Option<Oid>,
+    pub actual: Option<Oid>,
+}
+
+/// Ref updates that are applied together or not at all.
+///
+/// Each update carries the value the ref is expected to have. `commit` locks every ref,
+/// checks all expectations and only then writes, so a ref moved by someone else since
+/// the updates were planned fails with `StaleLocalRef` and nothing is changed. libgit2
+/// writes the locked refs one by one; once all locks are held and checked only an I/O
+/// error can stop it halfway.
+#[derive(Debug, Clone, Default)]
+pub struct RefTransaction {
+    updates: Vec<RefUpdate>,
+}
+
+impl RefTransaction {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create `name` at `target`; fails if the ref already exists
+    pub fn create(&mut self, name: &str, target: Oid, message: &str) -> &mut Self {
+        self.push(
+            name,
+            Expected::Missing,
+            RefChange::Set {
+                target,
+                message: message.to_string(),
+            },
+        )
+    }
+
+    /// Move `name` from `expected` to `target`
+    pub fn update(&mut self, name: &str, expected: Oid, target: Oid, message: &str) -> &mut Self {
+        self.push(
+            name,
+            Expected::At(expected),
+            RefChange::Set {
+                target,
+                message: message.to_string(),
+            },
+        )
+    }
+
+    /// Delete `name`, which must still point at `expected`
+    pub fn delete(&mut self, name: &str, expected: Oid) -> &mut Self {
+        self.push(name, Expected::At(expected), RefChange::Delete)
+    }
+
+    /// Leave `name` alone, but apply nothing unless it still points at `expected`
+    /// (`None`: does not exist)
+    pub fn verify(&mut self, name: &str, expected: Option<Oid>) -> &mut Self {
+        let expected = expected.map_or(Expected::Missing, Expected::At);
+        self.push(name, expected, RefChange::Keep)
+    }
+
+    /// Whether the transaction already has an entry for `name`
+    pub fn touches(&self, name: &str) -> bool {
+        self.updates.iter().any(|u| u.name == name)
+    }
+
+    fn push(&mut self, name: &str, expected: Expected, change: RefChange) -> &mut Self {
+        self.updates.push(RefUpdate {
+            name: name.to_string(),
+            expected,
+            change,
+        });
+        self
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.updates.is_empty()
+    }
+
+    /// Lock all refs, check every expectation and apply the updates. Nothing is written
+    /// when a ref is locked by another process or no longer has its expected value.
+    pub fn commit(&self, repo: &Repository) -> Result<()> {
+        self.commit_checked(repo, |_| Ok(()))
+    }
+
+    /// Like `commit`, but once every ref is locked `check` is handed all refs that moved,
+    /// so it can report them or veto the transaction for reasons of its own. Stale refs
+    /// that `check` lets through still fail with `StaleLocalRef`.
+    pub fn commit_checked<F>(&self, repo: &Repository, check: F) -> Result<()>
+    where
+        F: FnOnce(&[StaleRef]) -> Result<()>,
+    {
+        let mut seen = HashSet::new();
+        if let Some(update) = self.updates.iter().find(|u| !seen.insert(u.name.as_str())) {
+            return Err(AxisError::InvalidReference(format!(
+                "{} is updated twice in one transaction",
+                update.name
+            )));
+        }
+
+        let mut transaction = repo.transaction()?;
+        for update in &self.updates {
+            transaction.lock_ref(&update.name)?;
+        }
+
+        // Checked under the locks: nothing can move the refs from here on
+        let mut stale = Vec::new();
+        for update in &self.updates {
+            let actual = repo.refname_to_id(&update.name).ok();
+            let expected = match update.expected {
+                Expected::Missing => None,
+                Expected::At(oid) => Some(oid),
+            };
+            if actual != expected {
+                stale.push(StaleRef {
+                    refname: update.name.clone(),
+                    expected,
+                    actual,
+                });
+            }
+        }
+        check(&stale)?;
+        if let Some(stale) = stale.first() {
+            return Err(AxisError::StaleLocalRef {
+                refname: stale.refname.clone(),
+                expected: stale.expected.map(|oid| oid.to_string()),
+                actual: stale.actual.map(|oid| oid.to_string()),
+            });
+        }
+
+        for update in &self.updates {
+            match &update.change {
+                RefChange::Set { target, message } => {
+                    transaction.set_target(&update.name, *target, None, message)?;
+                }
+                RefChange::Delete => transaction.remove(&update.name)?,
+                RefChange::Keep => {}
+            }
+        }
+        transaction.commit()?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn setup() -> (TempDir, Repository, Oid, Oid) {
+        let tmp = TempDir::new().expect("should create temp dir");
+        let repo = Repository::init(tmp.path()).expect("should init repo");
+        let sig = git2::Signature::now("Test User", "test@test.com").expect("should create sig");
+        let tree_id = repo
+            .index()
+            .expect("should open index")
+            .write_tree()
+            .expect("should write tree");
+        let first = {
+            let tree = repo.find_tree(tree_id).expect("should find tree");
+            repo.commit(Some("HEAD"), &sig, &sig, "first", &tree, &[])
+                .expect("should commit")
+        };
+        let second = {
+            let tree = repo.find_tree(tree_id).expect("should find tree");
+            let parent = repo.find_commit(first).expect("should find commit");
+            repo.commit(Some("HEAD"), &sig, &sig, "second", &tree, &[&parent])
+                .expect("should commit")
+        };
+        (tmp, repo, first, second)
+    }
+
+    fn target(repo: &Repository, name: &str) -> Option<Oid> {
+        repo.refname_to_id(name).ok()
+    }
+
+    #[test]
+    fn test_commit_applies_all_updates() {
+        let (_tmp, repo, first, second) = setup();
+        repo.reference("refs/heads/old", first, false, "test")
+            .expect("should create ref");
+        repo.reference("refs/heads/moved", first, false, "test")
+            .expect("should create ref");
+
+        let mut transaction = RefTransaction::new();
+        transaction
+            .create("refs/heads/new", second, "test: create")
+            .update("refs/heads/moved", first, second, "test: move")
+            .delete("refs/heads/old", first);
+        transaction
+            .commit(&repo)
+            .expect("should commit transaction");
+
+        assert_eq!(target(&repo, "refs/heads/new"), Some(second));
+        assert_eq!(target(&repo, "refs/heads/moved"), Some(second));
+        assert_eq!(target(&repo, "refs/heads/old"), None);
+
+        let reflog = repo.reflog("refs/heads/moved").expect("should read reflog");
+        assert_eq!(
+            reflog
+                .get(0)
+                .and_then(|entry| entry.message().map(str::to_string)),
+            Some("test: move".to_string())
+        );
+    }
+
+    #[test]
+    fn test_stale_expectation_applies_nothing() {
+        let (_tmp, repo, first, second) = setup();
+        repo.reference("refs/heads/a", first, false, "test")
+            .expect("should create ref");
+        repo.reference("refs/heads/b", second, false, "test")
+            .expect("should create ref");
+
+        let mut transaction = RefTransaction::new();
+        transaction
+            .update("refs/heads/a", first, second, "test")
+            .delete("refs/heads/b", first);
+        let err = transaction
+            .commit(&repo)
+            .expect_err("should reject the stale expectation");
+
+        match err {
+            AxisError::StaleLocalRef {
+                refname,
+                expected,
+                actual,
+            } => {
+                assert_eq!(refname, "refs/heads/b");
+                assert_eq!(expected, Some(first.to_string()));
+                assert_eq!(actual, Some(second.to_string()));
+            }
+            other => panic!("expected StaleLocalRef, got {other:?}"),
+        }
+        assert_eq!(target(&repo, "refs/heads/a"), Some(first));
+        assert_eq!(target(&repo, "refs/heads/b"), Some(second));
+    }
+
+    #[test]
+    fn test_create_fails_when_ref_exists() {
+        let (_tmp, repo, first, second) = setup();
+        repo.reference("refs/heads/base", second, false, "test")
+            .expect("should create ref");
+        repo.reference("refs/tags/v1", first, false, "test")
+            .expect("should create ref");
+
+        let mut transaction = RefTransaction::new();
+        transaction
+            .update("refs/heads/base", second, first, "test")
+            .create("refs/tags/v1", second, "test");
+        let err = transaction
+            .commit(&repo)
+            .expect_err("should reject the existing ref");
+
+        assert!(matches!(
+            err,
+            AxisError::StaleLocalRef { expected: None, .. }
+        ));
+        assert_eq!(target(&repo, "refs/heads/base"), Some(second));
+    }
+
+    #[test]
+    fn test_verify_reports_every_stale_ref_and_writes_nothing() {
+        let (_tmp, repo, first, second) = setup();
+        repo.reference("refs/heads/kept", first, false, "test")
+            .expect("should create ref");
+        repo.reference("refs/heads/moved", second, false, "test")
+            .expect("should create ref");
+
+        let mut transaction = RefTransaction::new();
+        transaction
+            .verify("refs/heads/kept", Some(first))
+            .verify("refs/heads/moved", Some(first))
+            .verify("refs/heads/gone", Some(first))
+            .create("refs/heads/new", second, "test");
+        assert!(transaction.touches("refs/heads/new"));
+        assert!(!transaction.touches("refs/heads/other"));
+
+        let mut reported = Vec::new();
+        let err = transaction
+            .commit_checked(&repo, |stale| {
+                reported = stale.to_vec();
+                Ok(())
+            })
+            .expect_err("should reject the stale refs");
+
+        assert!(matches!(err, AxisError::StaleLocalRef { .. }));
+        assert_eq!(
+            reported,
+            vec![
+                StaleRef {
+                    refname: "refs/heads/moved".to_string(),
+                    expected: Some(first),
+                    actual: Some(second),
+                },
+                StaleRef {
+                    refname: "refs/heads/gone".to_string(),
+                    expected: Some(first),
+                    actual: None,
+                },
+            ]
+        );
+        assert_eq!(target(&repo, "refs/heads/new"), None);
+
+        let mut transaction = RefTransaction::new();
+        transaction
+            .verify("refs/heads/kept", Some(first))
+            .verify("refs/heads/gone", None)
+            .create("refs/heads/new", second, "test");
+        transaction
+            .commit(&repo)
+            .expect("should commit when every ref is as expected");
+        assert_eq!(target(&repo, "refs/heads/kept"), Some(first));
+        assert_eq!(target(&repo, "refs/heads/new"), Some(second));
+    }
+
+    #[test]
+    fn test_duplicate_ref_is_rejected() {
+        let (_tmp, repo, first, second) = setup();
+        repo.reference("refs/heads/base", second, false, "test")
+            .expect("should create ref");
+
+        let mut transaction = RefTransaction::new();
+        transaction
+            .update("refs/heads/base", second, first, "test")
+            .delete("refs/heads/base", second);
+
+        assert!(matches!(
+            transaction.commit(&repo),
+            Err(AxisError::InvalidReference(_))
+        ));
+        assert_eq!(target(&repo, "refs/heads/base"), Some(second));
+    }
+}
@@ -0,0 +1,349 @@
+use std::ffi::OsString;
+use std::path::{Component, Path, PathBuf};
+
+use crate::error::{AxisError, Result};
+
+/// Path `show_in_folder` shows in the file manager
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RevealTarget {
+    pub path: PathBuf,
+    /// The requested path is gone, and `path` is its nearest existing ancestor directory
+    pub fallback: bool,
+}
+
+impl RevealTarget {
+    /// Resolves `subpath` (repository-relative, either separator) inside `root`. A
+    /// deleted or renamed path falls back to its nearest ancestor that still exists.
+    pub fn resolve(root: &Path, subpath: Option<&str>) -> Result<Self> {
+        if !root.is_dir() {
+            return Err(AxisError::FileNotFound(root.display().to_string()));
+        }
+        let Some(subpath) = subpath.filter(|s| !s.is_empty()) else {
+            return Ok(RevealTarget {
+                path: root.to_path_buf(),
+                fallback: false,
+            });
+        };
+
+        let mut path = root.to_path_buf();
+        for part in subpath.split(['/', '\\']).filter(|part| !part.is_empty()) {
+            if !matches!(
+                Path::new(part).components().next(),
+                Some(Component::Normal(_) | Component::CurDir)
+            ) {
+                return Err(AxisError::Other(format!(
+                    "Path must stay inside the repository: {subpath}"
+                )));
+            }
+            path.push(part);
+        }
+
+        // `symlink_metadata` so a dangling symlink is still selected rather than skipped
+        if path.symlink_metadata().is_ok() {
+            return Ok(RevealTarget {
+                path,
+                fallback: false,
+            });
+        }
+        let ancestor = path
+            .ancestors()
+            .skip(1)
+            .take_while(|dir| dir.starts_with(root))
+            .find(|dir| dir.is_dir())
+            .unwrap_or(root);
+        Ok(RevealTarget {
+            path: ancestor.to_path_buf(),
+            fallback: true,
+        })
+    }
+
+    /// Shows the target in the platform's file manager, selecting it unless it is a
+    /// fallback directory. Blocks until the file manager has been asked.
+    pub fn reveal(&self) -> Result<()> {
+        if self.fallback {
+            return RevealCommand::open_dir(&self.path).run();
+        }
+        let selected = RevealCommand::select(&self.path).and_then(|cmd| cmd.run());
+        if cfg!(any(target_os = "macos", windows)) {
+            return selected;
+        }
+        // Not every Linux desktop implements `org.freedesktop.FileManager1`
+        selected.or_else(|e| {
+            log::debug!("ShowItems failed, opening the parent instead: {e}");
+            let parent = self.path.parent().unwrap_or(&self.path);
+            RevealCommand::open_dir(parent).run()
+        })
+    }
+}
+
+/// File manager invocation that shows a path
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RevealCommand {
+    pub program: &'static str,
+    /// Passed verbatim on Windows, where Explorer does its own argument parsing
+    pub args: Vec<OsString>,
+}
+
+#[cfg(target_os = "macos")]
+impl RevealCommand {
+    /// Finder window with `path` selected
+    pub fn select(path: &Path) -> Result<Self> {
+        Ok(RevealCommand {
+            program: "open",
+            args: vec!["-R".into(), path.into()],
+        })
+    }
+
+    pub fn open_dir(dir: &Path) -> Self {
+        RevealCommand {
+            program: "open",
+            args: vec![dir.into()],
+        }
+    }
+}
+
+#[cfg(windows)]
+impl RevealCommand {
+    /// Explorer window with `path` selected. Explorer only recognises `/select,` when the
+    /// path after the comma is quoted on its own, so the argument is built by hand.
+    pub fn select(path: &Path) -> Result<Self> {
+        let mut arg = OsString::from("/select,\"");
+        arg.push(path);
+        arg.push("\"");
+        Ok(RevealCommand {
+            program: "explorer",
+            args: vec![arg],
+        })
+    }
+
+    pub fn open_dir(dir: &Path) -> Self {
+        let mut arg = OsString::from("\"");
+        arg.push(dir);
+        arg.push("\"");
+        RevealCommand {
+            program: "explorer",
+            args: vec![arg],
+        }
+    }
+}
+
+#[cfg(all(unix, not(target_os = "macos")))]
+impl RevealCommand {
+    /// `org.freedesktop.FileManager1.ShowItems`, which file managers answer by opening
+    /// the folder with `path` selected
+    pub fn select(path: &Path) -> Result<Self> {
+        let uri = url::Url::from_file_path(path).map_err(|()| {
+            AxisError::Other(format!("Cannot build a file URL for {}", path.display()))
+        })?;
+        // `dbus-send` splits array elements on commas
+        let uri = uri.as_str().replace(',', "%2C");
+        Ok(RevealCommand {
+            program: "dbus-send",
+            args: vec![
+                "--session".into(),
+                "--print-reply".into(),
+                "--dest=org.freedesktop.FileManager1".into(),
+                "--type=method_call".into(),
+                "/org/freedesktop/FileManager1".into(),
+                "org.freedesktop.FileManager1.ShowItems".into(),
+                format!("array:string:{uri}").into(),
+                "string:".into(),
+            ],
+        })
+    }
+
+    pub fn open_dir(dir: &Path) -> Self {
+        RevealCommand {
+            program: "xdg-open",
+            args: vec![dir.into()],
+        }
+    }
+}
+
+impl RevealCommand {
+    fn run(&self) -> Result<()> {
+        let mut cmd = std::process::Command::new(self.program);
+        #[cfg(windows)]
+        {
+            use std::os::windows::process::CommandExt;
+            for arg in &self.args {
+                cmd.raw_arg(arg);
+            }
+        }
+        #[cfg(not(windows))]
+        cmd.args(&self.args);
+
+        let status = cmd
+            .status()
+            .map_err(|e| AxisError::Other(format!("Failed to run {}: {e}", self.program)))?;
+        // Explorer exits with 1 even when the window opened
+        if status.success() || cfg!(windows) {
+            Ok(())
+        } else {
+            Err(AxisError::Other(format!(
+                "{} exited with {status}",
+                self.program
+            )))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn tree() -> TempDir {
+        let tmp = TempDir::new().expect("should create temp dir");
+        std::fs::create_dir_all(tmp.path().join("src/naïve dir")).expect("should create dirs");
+        std::fs::write(tmp.path().join("src/naïve dir/my file.rs"), "").expect("should write");
+        tmp
+    }
+
+    // ==================== RevealTarget Tests ====================
+
+    #[test]
+    fn test_resolve_existing_file() {
+        let tmp = tree();
+        let target = RevealTarget::resolve(tmp.path(), Some("src/naïve dir/my file.rs"))
+            .expect("should resolve");
+        assert_eq!(target.path, tmp.path().join("src/naïve dir/my file.rs"));
+        assert!(!target.fallback);
+    }
+
+    #[test]
+    fn test_resolve_without_subpath_is_root() {
+        let tmp = tree();
+        let target = RevealTarget::resolve(tmp.path(), None).expect("should resolve");
+        assert_eq!(target.path, tmp.path());
+        assert!(!target.fallback);
+    }
+
+    #[test]
+    fn test_resolve_accepts_backslashes() {
+        let tmp = tree();
+        let target = RevealTarget::resolve(tmp.path(), Some("src\\naïve dir\\my file.rs"))
+            .expect("should resolve");
+        assert_eq!(target.path, tmp.path().join("src/naïve dir/my file.rs"));
+    }
+
+    #[test]
+    fn test_resolve_deleted_file_falls_back_to_parent() {
+        let tmp = tree();
+        let target = RevealTarget::resolve(tmp.path(), Some("src/naïve dir/deleted.rs"))
+            .expect("should resolve");
+        assert_eq!(target.path, tmp.path().join("src/naïve dir"));
+        assert!(target.fallback);
+    }
+
+    #[test]
+    fn test_resolve_renamed_directory_falls_back_to_nearest_ancestor() {
+        let tmp = tree();
+        let target = RevealTarget::resolve(tmp.path(), Some("src/old name/deep/file.rs"))
+            .expect("should resolve");
+        assert_eq!(target.path, tmp.path().join("src"));
+        assert!(target.fallback);
+
+        let target =
+            RevealTarget::resolve(tmp.path(), Some("gone/file.rs")).expect("should resolve");
+        assert_eq!(target.path, tmp.path());
+        assert!(target.fallback);
+    }
+
+    #[test]
+    fn test_resolve_rejects_escaping_paths() {
+        let tmp = tree();
+        assert!(RevealTarget::resolve(tmp.path(), Some("../outside")).is_err());
+        assert!(RevealTarget::resolve(tmp.path(), Some("src/../../outside")).is_err());
+    }
+
+    #[test]
+    fn test_resolve_missing_root() {
+        let tmp = tree();
+        let result = RevealTarget::resolve(&tmp.path().join("missing"), Some("file.rs"));
+        assert!(matches!(result, Err(AxisError::FileNotFound(_))));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_resolve_through_symlinked_root() {
+        let tmp = tree();
+        let link = tmp.path().join("link");
+        std::os::unix::fs::symlink(tmp.path().join("src"), &link).expect("should symlink");
+
+        let target =
+            RevealTarget::resolve(&link, Some("naïve dir/my file.rs")).expect("should resolve");
+        assert_eq!(target.path, link.join("naïve dir/my file.rs"));
+        assert!(!target.fallback);
+
+        let target =
+            RevealTarget::resolve(&link, Some("naïve dir/gone.rs")).expect("should resolve");
+        assert_eq!(target.path, link.join("naïve dir"));
+        assert!(target.fallback);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_resolve_dangling_symlink_is_selected() {
+        let tmp = tree();
+        std::os::unix::fs::symlink(tmp.path().join("nowhere"), tmp.path().join("dangling"))
+            .expect("should symlink");
+        let target = RevealTarget::resolve(tmp.path(), Some("dangling")).expect("should resolve");
+        assert_eq!(target.path, tmp.path().join("dangling"));
+        assert!(!target.fallback);
+    }
+
+    // ==================== RevealCommand Tests ====================
+
+    #[cfg(target_os = "macos")]
+    #[test]
+    fn test_select_command_macos() {
+        let path = Path::new("/Users/me/My Repo/naïve.rs");
+        let cmd = RevealCommand::select(path).expect("should build");
+        assert_eq!(cmd.program, "open");
+        assert_eq!(cmd.args, [OsString::from("-R"), OsString::from(path)]);
+        assert_eq!(
+            RevealCommand::open_dir(Path::new("/Users/me/My Repo")).args,
+            [OsString::from("/Users/me/My Repo")]
+        );
+    }
+
+    #[cfg(windows)]
+    #[test]
+    fn test_select_command_windows() {
+        let cmd = RevealCommand::select(Path::new(r"C:\Users\me\My Repo\naïve.rs"))
+            .expect("should build");
+        assert_eq!(cmd.program, "explorer");
+        assert_eq!(
+            cmd.args,
+            [OsString::from(r#"/select,"C:\Users\me\My Repo\naïve.rs""#)]
+        );
+        assert_eq!(
+            RevealCommand::open_dir(Path::new(r"C:\Users\me\My Repo")).args,
+            [OsString::from(r#""C:\Users\me\My Repo""#)]
+        );
+    }
+
+    #[cfg(all(unix, not(target_os = "macos")))]
+    #[test]
+    fn test_select_command_linux() {
+        let cmd = RevealCommand::select(Path::new("/home/me/My Repo/naïve, draft.rs"))
+            .expect("should build");
+        assert_eq!(cmd.program, "dbus-send");
+        assert_eq!(
+            cmd.args.last().expect("should have args"),
+            &OsString::from("string:")
+        );
+        assert_eq!(
+            cmd.args[cmd.args.len() - 2],
+            OsString::from("array:string:file:///home/me/My%20Repo/na%C3%AFve%2C%20draft.rs")
+        );
+        assert_eq!(
+            RevealCommand::open_dir(Path::new("/home/me/My Repo")),
+            RevealCommand {
+                program: "xdg-open",
+                args: vec![OsString::from("/home/me/My Repo")],
+            }
+        );
+    }
+}
@@ -0,0 +1,521 @@
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, LazyLock};
+
+use parking_lot::Mutex;
+use regex::Regex;
+
+use crate::error::{AxisError, Result};
+use crate::models::{SpellingFinding, SPELLING_MAX_SUGGESTIONS};
+use crate::services::trailer_line_count;
+
+/// Resource folder holding Hunspell dictionaries as `<language>.aff` and `<language>.dic`
+pub const DICTIONARIES_DIR: &str = "dictionaries";
+
+/// Dictionaries tried when none matches the configured language
+const FALLBACK_LANGUAGES: &[&str] = &["en_US", "en"];
+
+/// Staged files larger than this are left out of the identifier corpus
+pub const CORPUS_MAX_FILE_SIZE: usize = 1024 * 1024;
+
+/// Repositories whose identifier corpus is kept before the cache is reset
+const MAX_CACHED_CORPORA: usize = 16;
+
+static IDENTIFIER_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"[A-Za-z_][A-Za-z0-9_]*").expect("Invalid regex pattern"));
+
+static INLINE_CODE_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"`[^`]*`").expect("Invalid regex pattern"));
+
+static CHUNK_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"\S+").expect("Invalid regex pattern"));
+
+/// Letters, digits and underscores, with apostrophes only inside a word (`don't`)
+static WORD_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"[\p{L}\p{N}_]+(?:'\p{L}+)*").expect("Invalid regex pattern"));
+
+/// Lowercase identifiers of a repository's source, and the words they are made of
+#[derive(Debug, Default)]
+pub struct IdentifierCorpus {
+    words: HashSet<String>,
+}
+
+impl IdentifierCorpus {
+    pub fn build<S: AsRef<str>>(sources: impl IntoIterator<Item = S>) -> Self {
+        let mut words = HashSet::new();
+        for source in sources {
+            for identifier in IDENTIFIER_REGEX.find_iter(source.as_ref()) {
+                let identifier = identifier.as_str();
+                words.insert(identifier.to_lowercase());
+                words.extend(
+                    identifier_parts(identifier)
+                        .into_iter()
+                        .filter(|part| part.chars().count() > 1),
+                );
+            }
+        }
+        Self { words }
+    }
+
+    pub fn contains(&self, word: &str) -> bool {
+        self.words.contains(&word.to_lowercase())
+    }
+
+    pub fn len(&self) -> usize {
+        self.words.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.words.is_empty()
+    }
+}
+
+/// Lowercase words of an identifier: `parseHTTPResponse_v2` is `parse`, `http`,
+/// `response` and `v`, `2`
+fn identifier_parts(identifier: &str) -> Vec<String> {
+    let mut parts = Vec::new();
+    for segment in identifier.split('_').filter(|s| !s.is_empty()) {
+        let chars: Vec<char> = segment.chars().collect();
+        let mut start = 0;
+        for i in 1..chars.len() {
+            let (prev, cur) = (chars[i - 1], chars[i]);
+            let next_is_lower = chars.get(i + 1).is_some_and(|c| c.is_lowercase());
+            let boundary = (prev.is_lowercase() && cur.is_uppercase())
+                || (prev.is_uppercase() && cur.is_uppercase() && next_is_lower)
+                || prev.is_alphabetic() != cur.is_alphabetic();
+            if boundary {
+                parts.push(chars[start..i].iter().collect::<String>().to_lowercase());
+                start = i;
+            }
+        }
+        parts.push(chars[start..].iter().collect::<String>().to_lowercase());
+    }
+    parts
+}
+
+/// Identifier corpus of each repository's staged files. A corpus is reused until the
+/// staged content changes.
+pub struct IdentifierCorpusCache {
+    entries: Mutex<HashMap<PathBuf, (String, Arc<IdentifierCorpus>)>>,
+    builds: AtomicUsize,
+}
+
+impl IdentifierCorpusCache {
+    pub fn new() -> Self {
+        Self {
+            entries: Mutex::new(HashMap::new()),
+            builds: AtomicUsize::new(0),
+        }
+    }
+
+    /// The corpus of `repo_path` for the staged content identified by `fingerprint`,
+    /// built with `build` when the cached one is missing or stale
+    pub fn get_or_build(
+        &self,
+        repo_path: &Path,
+        fingerprint: &str,
+        build: impl FnOnce() -> IdentifierCorpus,
+    ) -> Arc<IdentifierCorpus> {
+        if let Some((cached_fingerprint, corpus)) = self.entries.lock().get(repo_path) {
+            if cached_fingerprint == fingerprint {
+                return Arc::clone(corpus);
+            }
+        }
+
+        let corpus = Arc::new(build());
+        self.builds.fetch_add(1, Ordering::Relaxed);
+
+        let mut entries = self.entries.lock();
+        if entries.len() >= MAX_CACHED_CORPORA && !entries.contains_key(repo_path) {
+            entries.clear();
+        }
+        entries.insert(
+            repo_path.to_path_buf(),
+            (fingerprint.to_string(), Arc::clone(&corpus)),
+        );
+        corpus
+    }
+
+    /// Corpora built so far; cache hits are not counted
+    pub fn builds(&self) -> usize {
+        self.builds.load(Ordering::Relaxed)
+    }
+}
+
+impl Default for IdentifierCorpusCache {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// What a commit message is checked against besides the dictionary
+#[derive(Debug, Default)]
+pub struct CommitSpellingContext {
+    /// Paths of the staged changes, relative to the repository root
+    pub staged_paths: Vec<String>,
+    /// Local and remote-tracking branch names
+    pub branch_names: Vec<String>,
+    pub corpus: Arc<IdentifierCorpus>,
+    /// Words accepted into the repository's dictionary, lowercase
+    pub custom_words: HashSet<String>,
+}
+
+impl CommitSpellingContext {
+    /// Whether `token` names a staged file, its file name, or a directory containing one
+    fn is_staged_path(&self, token: &str) -> bool {
+        let token = token.trim_start_matches("./").trim_end_matches('/');
+        !token.is_empty()
+            && self.staged_paths.iter().any(|path| {
+                path == token
+                    || path
+                        .strip_suffix(token)
+                        .is_some_and(|rest| rest.ends_with('/'))
+                    || path
+                        .strip_prefix(token)
+                        .is_some_and(|rest| rest.starts_with('/'))
+            })
+    }
+
+    fn is_branch_name(&self, token: &str) -> bool {
+        self.branch_names.iter().any(|name| name == token)
+    }
+
+    fn is_known_word(&self, word: &str) -> bool {
+        self.corpus.contains(word) || self.custom_words.contains(&word.to_lowercase())
+    }
+}
+
+/// A Hunspell dictionary
+pub struct SpellDictionary {
+    language: String,
+    dictionary: spellbook::Dictionary,
+}
+
+impl SpellDictionary {
+    pub fn new(language: &str, aff: &str, dic: &str) -> Result<Self> {
+        let dictionary = spellbook::Dictionary::new(aff, dic)
+            .map_err(|e| AxisError::Other(format!("Invalid {language} dictionary: {e}")))?;
+        Ok(Self {
+            language: language.to_string(),
+            dictionary,
+        })
+    }
+
+    pub fn language(&self) -> &str {
+        &self.language
+    }
+
+    pub fn check(&self, word: &str) -> bool {
+        self.dictionary.check(word)
+    }
+
+    pub fn suggest(&self, word: &str) -> Vec<String> {
+        let mut suggestions = Vec::new();
+        self.dictionary.suggest(word, &mut suggestions);
+        suggestions.truncate(SPELLING_MAX_SUGGESTIONS);
+        suggestions
+    }
+}
+
+/// Loaded dictionaries, by language; parsing one takes long enough to be worth keeping
+#[derive(Default)]
+pub struct SpellDictionaryCache {
+    dictionaries: Mutex<HashMap<String, Arc<SpellDictionary>>>,
+}
+
+impl SpellDictionaryCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The dictionary in `dir` for the app language setting `language` (`system`,
+    /// `de`, `pt-BR`, ...), falling back to English
+    pub fn load(&self, dir: &Path, language: &str) -> Result<Arc<SpellDictionary>> {
+        for candidate in dictionary_candidates(language) {
+            let cached = self.dictionaries.lock().get(&candidate).cloned();
+            if let Some(dictionary) = cached {
+                return Ok(dictionary);
+            }
+
+            let aff_path = dir.join(format!("{candidate}.aff"));
+            let dic_path = dir.join(format!("{candidate}.dic"));
+            if !aff_path.is_file() || !dic_path.is_file() {
+                continue;
+            }
+            let dictionary = Arc::new(SpellDictionary::new(
+                &candidate,
+                &std::fs::read_to_string(aff_path)?,
+                &std::fs::read_to_string(dic_path)?,
+            )?);
+            self.dictionaries
+                .lock()
+                .insert(candidate, Arc::clone(&dictionary));
+            return Ok(dictionary);
+        }
+        Err(AxisError::Other(format!(
+            "No spelling dictionary found for {language}"
+        )))
+    }
+}
+
+/// Dictionary names to try for an app language setting, most specific first
+fn dictionary_candidates(language: &str) -> Vec<String> {
+    let tag = if language == "system" {
+        system_locale()
+    } else {
+        Some(language.to_string())
+    };
+
+    let mut candidates = Vec::new();
+    if let Some(tag) = tag {
+        // `de_DE.UTF-8@euro` and `de-DE` both name the `de_DE` dictionary
+        let tag = tag
+            .split(['.', '@'])
+            .next()
+            .unwrap_or_default()
+            .replace('-', "_");
+        if !tag.is_empty() && tag != "C" && tag != "POSIX" {
+            if let Some((primary, _)) = tag.split_once('_') {
+                candidates.push(primary.to_string());
+            }
+            candidates.insert(0, tag);
+        }
+    }
+    for fallback in FALLBACK_LANGUAGES {
+        if !candidates.iter().any(|c| c == fallback) {
+            candidates.push((*fallback).to_string());
+        }
+    }
+    candidates
+}
+
+fn system_locale() -> Option<String> {
+    ["LC_ALL", "LC_MESSAGES", "LANG"]
+        .iter()
+        .find_map(|var| std::env::var(var).ok().filter(|value| !value.is_empty()))
+}
+
+/// Words in `message` that are neither in `dictionary` nor known from `context`.
+///
+/// Never checked: comment lines, fenced and inline code, the trailer block, URLs,
+/// e-mail addresses, staged paths, branch names, and code-like words (snake_case,
+/// camelCase, acronyms, words with digits).
+pub fn check_spelling(
+    message: &str,
+    dictionary: &SpellDictionary,
+    context: &CommitSpellingContext,
+) -> Vec<SpellingFinding> {
+    let body = message.trim_end();
+    let lines: Vec<&str> = body.lines().collect();
+    let trailer_start = lines.len() - trailer_line_count(body).min(lines.len());
+
+    let mut findings = Vec::new();
+    let mut in_fence = false;
+    for (index, line) in lines.iter().enumerate() {
+        if line.trim_start().starts_with("```") {
+            in_fence = !in_fence;
+            continue;
+        }
+        if in_fence || line.starts_with('#') || index >= trailer_start {
+            continue;
+        }
+
+        // Blank out inline code without moving anything else
+        let masked = INLINE_CODE_REGEX
+            .replace_all(line, |caps: &regex::Captures<'_>| " ".repeat(caps[0].len()));
+        for chunk in CHUNK_REGEX.find_iter(&masked) {
+            if is_excluded_chunk(chunk.as_str(), context) {
+                continue;
+            }
+            for word in WORD_REGEX.find_iter(chunk.as_str()) {
+                let text = word.as_str();
+                if is_code_word(text) || context.is_known_word(text) || dictionary.check(text) {
+                    continue;
+                }
+                let start = chunk.start() + word.start();
+                findings.push(SpellingFinding {
+                    word: text.to_string(),
+                    line: u32::try_from(index + 1).unwrap_or(u32::MAX),
+                    column: char_count(&line[..start]) + 1,
+                    length: char_count(text),
+                    suggestions: dictionary.suggest(text),
+                });
+            }
+        }
+    }
+    findings
+}
+
+/// URLs, e-mail addresses, staged paths and branch names
+fn is_excluded_chunk(chunk: &str, context: &CommitSpellingContext) -> bool {
+    if chunk.contains("://") || chunk.contains('@') {
+        return true;
+    }
+    let token = chunk.trim_matches(|c: char| {
+        !(c.is_alphanumeric() || matches!(c, '_' | '-' | '.' | '/' | '\\'))
+    });
+    let token = token.trim_end_matches('.');
+    context.is_staged_path(token) || context.is_branch_name(token)
+}
+
+/// Identifiers and other tokens no dictionary has
+fn is_code_word(word: &str) -> bool {
+    let mut chars = word.chars();
+    chars.next().is_none()
+        || word.chars().count() == 1
+        || word.contains('_')
+        || word.chars().any(char::is_numeric)
+        // camelCase, PascalCase beyond the first letter, and acronyms
+        || chars.any(char::is_uppercase)
+}
+
+fn char_count(s: &str) -> u32 {
+    u32::try_from(s.chars().count()).unwrap_or(u32::MAX)
+}
+
+/// A small English dictionary for tests
+#[cfg(test)]
+pub fn test_dictionary() -> SpellDictionary {
+    const AFF: &str = "SET UTF-8\nTRY esianrtolcdugmphbyfvkwz\n\nSFX S Y 1\nSFX S 0 s .\n\nSFX D Y 2\nSFX D 0 d e\nSFX D 0 ed [^e]\n";
+    const DIC: &str = "14\na\nand\nconfig/S\ncrash/D\nfail/D\nfile/S\nfix/D\nfor\nhandler/S\nin\nis\nthe\nupdate/D\nwhen\n";
+    SpellDictionary::new("en_US", AFF, DIC).expect("should parse test dictionary")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn context(staged_paths: &[&str], branch_names: &[&str]) -> CommitSpellingContext {
+        CommitSpellingContext {
+            staged_paths: staged_paths.iter().map(ToString::to_string).collect(),
+            branch_names: branch_names.iter().map(ToString::to_string).collect(),
+            ..CommitSpellingContext::default()
+        }
+    }
+
+    fn flagged(message: &str, context: &CommitSpellingContext) -> Vec<String> {
+        check_spelling(message, &test_dictionary(), context)
+            .into_iter()
+            .map(|finding| finding.word)
+            .collect()
+    }
+
+    #[test]
+    fn test_identifier_parts() {
+        assert_eq!(
+            identifier_parts("parseHTTPResponse_v2"),
+            vec!["parse", "http", "response", "v", "2"]
+        );
+        assert_eq!(identifier_parts("MAX_RETRIES"), vec!["max", "retries"]);
+        assert_eq!(identifier_parts("tokenizer"), vec!["tokenizer"]);
+    }
+
+    #[test]
+    fn test_corpus_contains_identifiers_and_their_words() {
+        let corpus = IdentifierCorpus::build(["fn parseConfig(raw_input: &str) {}"]);
+        assert!(corpus.contains("parseConfig"));
+        assert!(corpus.contains("raw_input"));
+        assert!(corpus.contains("Parse"));
+        assert!(corpus.contains("input"));
+        assert!(!corpus.contains("output"));
+    }
+
+    #[test]
+    fn test_check_spelling_reports_position_and_suggestions() {
+        let findings = check_spelling(
+            "Fix crash\n\nThe hanlder is fixed",
+            &test_dictionary(),
+            &CommitSpellingContext::default(),
+        );
+        assert_eq!(findings.len(), 1);
+        let finding = &findings[0];
+        assert_eq!(finding.word, "hanlder");
+        assert_eq!((finding.line, finding.column, finding.length), (3, 5, 7));
+        assert!(finding.suggestions.iter().any(|s| s == "handler"));
+    }
+
+    #[test]
+    fn test_check_spelling_skips_code_tokens() {
+        let context = context(&["src/config.rs", "docs/guide.md"], &["feature/parsr"]);
+        let message = "Fix crash in parseConfig and MAX_RETRIES for src/config.rs\n\
+                       \n\
+                       Update `confg_path` in guide.md and the docs/ file when HTTP v2 fials\n\
+                       feature/parsr https://example.com/isue user@example.com\n\
+                       # a commnt\n\
+                       ```\n\
+                       lett x = 1\n\
+                       ```\n\
+                       \n\
+                       Signed-off-by: Jane Doe <jane@example.com>\n";
+        assert_eq!(flagged(message, &context), vec!["fials"]);
+    }
+
+    #[test]
+    fn test_check_spelling_uses_corpus_and_custom_words() {
+        let mut context = context(&[], &[]);
+        assert_eq!(
+            flagged("Fix tokenizer crash in axis", &context),
+            vec!["tokenizer", "axis"]
+        );
+
+        context.corpus = Arc::new(IdentifierCorpus::build([
+            "let tokenizer = Tokenizer::new();",
+        ]));
+        context.custom_words = HashSet::from(["axis".to_string()]);
+        assert!(flagged("Fix tokenizer crash in Axis", &context).is_empty());
+    }
+
+    #[test]
+    fn test_corpus_cache_rebuilds_only_when_fingerprint_changes() {
+        let cache = IdentifierCorpusCache::new();
+        let repo = Path::new("/repos/axis");
+
+        let first = cache.get_or_build(repo, "a.rs:1", || IdentifierCorpus::build(["alpha"]));
+        let second = cache.get_or_build(repo, "a.rs:1", || IdentifierCorpus::build(["beta"]));
+        assert!(Arc::ptr_eq(&first, &second));
+        assert_eq!(cache.builds(), 1);
+
+        let changed = cache.get_or_build(repo, "a.rs:2", || IdentifierCorpus::build(["beta"]));
+        assert!(changed.contains("beta"));
+        assert_eq!(cache.builds(), 2);
+
+        // Each repository has its own entry
+        cache.get_or_build(
+            Path::new("/repos/other"),
+            "a.rs:2",
+            IdentifierCorpus::default,
+        );
+        assert_eq!(cache.builds(), 3);
+    }
+
+    #[test]
+    fn test_dictionary_candidates() {
+        assert_eq!(
+            dictionary_candidates("pt-BR"),
+            vec!["pt_BR", "pt", "en_US", "en"]
+        );
+        assert_eq!(dictionary_candidates("en"), vec!["en", "en_US"]);
+        assert_eq!(
+            dictionary_candidates("de_DE.UTF-8"),
+            vec!["de_DE", "de", "en_US", "en"]
+        );
+    }
+
+    #[test]
+    fn test_load_dictionary_falls_back_to_english() {
+        let tmp = tempfile::TempDir::new().expect("should create temp dir");
+        std::fs::write(tmp.path().join("en.aff"), "SET UTF-8\n").expect("should write aff");
+        std::fs::write(tmp.path().join("en.dic"), "1\nhello\n").expect("should write dic");
+
+        let cache = SpellDictionaryCache::new();
+        let dictionary = cache.load(tmp.path(), "fr").expect("should fall back");
+        assert_eq!(dictionary.language(), "en");
+        assert!(dictionary.check("hello"));
+
+        let empty = tempfile::TempDir::new().expect("should create temp dir");
+        assert!(SpellDictionaryCache::new()
+            .load(empty.path(), "en")
+            .is_err());
+    }
+}
@@ -1,7 +1,7 @@
 use crate::error::{AxisError, Result};
 use crate::models::{
-    ExportSshKeyOptions, GenerateSshKeyOptions, ImportSshKeyOptions, SshKeyAlgorithm, SshKeyFormat,
-    SshKeyInfo,
+    ExportSshKeyOptions, GenerateSshKeyOptions, ImportSshKeyOptions, SshAgentIdentity,
+    SshKeyAlgorithm, SshKeyFormat, SshKeyInfo,
 };
 use crate::services::create_command;
 use crate::storage::Database;
@@ -47,6 +47,55 @@ impl SshKeyService {
         Err(AxisError::SshKeygenNotFound)
     }
 
+    /// List the identities loaded in the running SSH agent (`ssh-add -l`)
+    pub async fn list_agent_identities() -> Result<Vec<SshAgentIdentity>> {
+        #[cfg(target_os = "windows")]
+        let ssh_add = "ssh-add.exe";
+        #[cfg(not(target_os = "windows"))]
+        let ssh_add = "ssh-add";
+
+        let output = create_command(ssh_add)
+            .args(["-l", "-E", "sha256"])
+            .output()
+            .await
+            .map_err(|e| AxisError::SshKeyError(format!("Failed to execute ssh-add: {e}")))?;
+
+        match output.status.code() {
+            Some(0) => Ok(Self::parse_agent_identities(&String::from_utf8_lossy(
+                &output.stdout,
+            ))),
+            // The agent is running but holds no identities
+            Some(1) => Ok(Vec::new()),
+            _ => Err(AxisError::SshKeyError(format!(
+                "Could not connect to the SSH agent: {}",
+                String::from_utf8_lossy(&output.stderr).trim()
+            ))),
+        }
+    }
+
+    /// Parse `ssh-add -l` output: `256 SHA256:... comment (ED25519)` per identity
+    fn parse_agent_identities(stdout: &str) -> Vec<SshAgentIdentity> {
+        stdout
+            .lines()
+            .filter_map(|line| {
+                let mut parts = line.trim().splitn(3, ' ');
+                let _bits = parts.next()?;
+                let fingerprint = parts.next().filter(|f| f.contains(':'))?;
+                let rest = parts.next().unwrap_or_default().trim();
+                let (comment, key_type) =
+                    match rest.strip_suffix(')').and_then(|r| r.rsplit_once('(')) {
+                        Some((comment, key_type)) => (comment.trim(), key_type),
+                        None => (rest, ""),
+                    };
+                Some(SshAgentIdentity {
+                    fingerprint: fingerprint.to_string(),
+                    comment: comment.to_string(),
+                    key_type: key_type.to_string(),
+                })
+            })
+            .collect()
+    }
+
     /// Validate a key filename (no path traversal, no absolute paths)
     pub fn validate_filename(name: &str) -> Result<()> {
         if name.is_empty() {
@@ -803,6 +852,40 @@ mod tests {
 
     // ==================== list_keys Tests ====================
 
+    #[test]
+    fn test_parse_agent_identities() {
+        let stdout = "256 SHA256:abcDEF123 user@example.com (ED25519)\n\
+                      3072 SHA256:xyz789 /home/user/.ssh/id_rsa (work) (RSA)\n\
+                      256 SHA256:noComment  (ECDSA)\n";
+        let identities = SshKeyService::parse_agent_identities(stdout);
+        assert_eq!(
+            identities,
+            vec![
+                SshAgentIdentity {
+                    fingerprint: "SHA256:abcDEF123".to_string(),
+                    comment: "user@example.com".to_string(),
+                    key_type: "ED25519".to_string(),
+                },
+                SshAgentIdentity {
+                    fingerprint: "SHA256:xyz789".to_string(),
+                    comment: "/home/user/.ssh/id_rsa (work)".to_string(),
+                    key_type: "RSA".to_string(),
+                },
+                SshAgentIdentity {
+                    fingerprint: "SHA256:noComment".to_string(),
+                    comment: String::new(),
+                    key_type: "ECDSA".to_string(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_agent_identities_skips_messages() {
+        let identities = SshKeyService::parse_agent_identities("The agent has no identities.\n\n");
+        assert!(identities.is_empty());
+    }
+
     #[tokio::test]
     async fn test_list_keys_no_ssh_dir() {
         // This test just verifies no panic; actual keys depend on system
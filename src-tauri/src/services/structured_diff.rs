@@ -0,0 +1,873 @@
+use std::path::Path;
+
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::error::{AxisError, Result};
+use crate::models::{
+    DiffHunk, DiffLine, DiffLineType, DiffOptions, DiffStatus, FileDiff, NotebookCellConflict,
+    NotebookOutputMode, StructuredDiffKind,
+};
+use crate::services::FILE_CONTENT_MAX_BYTES;
+
+/// Extension of Jupyter notebooks, always diffed cell by cell when structured diffs are on
+pub const NOTEBOOK_EXTENSION: &str = "ipynb";
+
+/// Largest cell-alignment table computed; bigger notebooks are aligned by position
+const NOTEBOOK_MAX_ALIGN_CELLS: usize = 4_000_000;
+
+/// One notebook cell with the fields the diff looks at
+#[derive(Debug, Clone)]
+struct Cell {
+    value: Value,
+    cell_type: String,
+    source: String,
+    id: Option<String>,
+}
+
+impl Cell {
+    fn from_value(value: Value) -> Result<Self> {
+        let cell_type = value
+            .get("cell_type")
+            .and_then(Value::as_str)
+            .ok_or_else(|| AxisError::Other("Notebook cell has no cell_type".to_string()))?
+            .to_string();
+        let source = match value.get("source") {
+            Some(Value::String(source)) => source.clone(),
+            Some(Value::Array(parts)) => parts.iter().filter_map(Value::as_str).collect(),
+            _ => String::new(),
+        };
+        let id = value.get("id").and_then(Value::as_str).map(str::to_string);
+        Ok(Self {
+            value,
+            cell_type,
+            source,
+            id,
+        })
+    }
+
+    /// Whether two cells are the same cell: by id when both have one (nbformat 4.5+),
+    /// otherwise by type and source
+    fn same_cell(&self, other: &Cell) -> bool {
+        match (&self.id, &other.id) {
+            (Some(a), Some(b)) => a == b,
+            _ => self.cell_type == other.cell_type && self.source == other.source,
+        }
+    }
+
+    /// Whether a changed cell on one side can be shown as a modification of one on the other
+    fn pairs_with(&self, other: &Cell) -> bool {
+        self.cell_type == other.cell_type
+            && !matches!((&self.id, &other.id), (Some(a), Some(b)) if a != b)
+    }
+
+    /// Outputs with execution counts removed, for comparing what a cell printed
+    fn outputs(&self) -> Vec<Value> {
+        let Some(Value::Array(outputs)) = self.value.get("outputs") else {
+            return Vec::new();
+        };
+        outputs
+            .iter()
+            .cloned()
+            .map(|mut output| {
+                if let Some(output) = output.as_object_mut() {
+                    output.remove("execution_count");
+                }
+                output
+            })
+            .collect()
+    }
+
+    /// `Out[3]: 2 outputs (stream, image/png), 1204 bytes`
+    fn output_summary(&self) -> String {
+        let outputs = self.outputs();
+        let count = match self.value.get("execution_count").and_then(Value::as_u64) {
+            Some(count) => count.to_string(),
+            None => " ".to_string(),
+        };
+        let mut kinds: Vec<String> = Vec::new();
+        for output in &outputs {
+            let output_kinds = match output.get("data").and_then(Value::as_object) {
+                Some(data) => data.keys().cloned().collect(),
+                None => vec![output
+                    .get("output_type")
+                    .and_then(Value::as_str)
+                    .unwrap_or("output")
+                    .to_string()],
+            };
+            for kind in output_kinds {
+                if !kinds.contains(&kind) {
+                    kinds.push(kind);
+                }
+            }
+        }
+        let bytes = serde_json::to_string(&outputs).map_or(0, |s| s.len());
+        let plural = if outputs.len() == 1 { "" } else { "s" };
+        format!(
+            "Out[{count}]: {} output{plural} ({}), {bytes} bytes",
+            outputs.len(),
+            kinds.join(", ")
+        )
+    }
+}
+
+/// A parsed notebook: the whole document and its cells
+struct Notebook {
+    document: Value,
+    cells: Vec<Cell>,
+}
+
+/// Parse notebook JSON. Empty content is a notebook without cells, for the missing
+/// side of an added or deleted file.
+fn parse_notebook(content: &[u8]) -> Result<Notebook> {
+    if content.iter().all(u8::is_ascii_whitespace) {
+        return Ok(Notebook {
+            document: Value::Object(serde_json::Map::new()),
+            cells: Vec::new(),
+        });
+    }
+    let document: Value = serde_json::from_slice(content)?;
+    let cells = document
+        .get("cells")
+        .and_then(Value::as_array)
+        .ok_or_else(|| AxisError::Other("Notebook has no cells array".to_string()))?
+        .iter()
+        .cloned()
+        .map(Cell::from_value)
+        .collect::<Result<Vec<_>>>()?;
+    Ok(Notebook { document, cells })
+}
+
+/// How a cell of one version maps onto the other
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CellAlignment {
+    /// Same cell in both, possibly edited
+    Matched(usize, usize),
+    Removed(usize),
+    Added(usize),
+}
+
+/// Align the cells of two versions in order. Cells that are the same on both sides
+/// anchor the alignment (longest common subsequence); between anchors, removed and
+/// added cells of the same type pair up by position as edits of one another.
+fn align_cells(old: &[Cell], new: &[Cell]) -> Vec<CellAlignment> {
+    let anchors = if old.len().saturating_mul(new.len()) > NOTEBOOK_MAX_ALIGN_CELLS {
+        Vec::new()
+    } else {
+        common_cells(old, new)
+    };
+
+    let mut alignment = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    for (anchor_old, anchor_new) in anchors
+        .into_iter()
+        .chain(std::iter::once((old.len(), new.len())))
+    {
+        let (removed, added) = (anchor_old - i, anchor_new - j);
+        for k in 0..removed.max(added) {
+            if k < removed && k < added && old[i + k].pairs_with(&new[j + k]) {
+                alignment.push(CellAlignment::Matched(i + k, j + k));
+                continue;
+            }
+            if k < removed {
+                alignment.push(CellAlignment::Removed(i + k));
+            }
+            if k < added {
+                alignment.push(CellAlignment::Added(j + k));
+            }
+        }
+        if anchor_old < old.len() {
+            alignment.push(CellAlignment::Matched(anchor_old, anchor_new));
+        }
+        (i, j) = (anchor_old + 1, anchor_new + 1);
+    }
+    alignment
+}
+
+/// Index pairs of the longest common subsequence of cells under `Cell::same_cell`
+fn common_cells(old: &[Cell], new: &[Cell]) -> Vec<(usize, usize)> {
+    let (n, m) = (old.len(), new.len());
+    let mut table = vec![vec![0u32; m + 1]; n + 1];
+    for i in (0..n).rev() {
+        for j in (0..m).rev() {
+            table[i][j] = if old[i].same_cell(&new[j]) {
+                table[i + 1][j + 1] + 1
+            } else {
+                table[i + 1][j].max(table[i][j + 1])
+            };
+        }
+    }
+
+    let mut pairs = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < n && j < m {
+        if old[i].same_cell(&new[j]) {
+            pairs.push((i, j));
+            i += 1;
+            j += 1;
+        } else if table[i + 1][j] >= table[i][j + 1] {
+            i += 1;
+        } else {
+            j += 1;
+        }
+    }
+    pairs
+}
+
+/// Header naming a cell by its 1-based number, after git's range part
+fn cell_header(range: &str, number: usize, cell: &Cell, change: &str) -> String {
+    format!("{range} cell {number} [{}] {change}", cell.cell_type)
+}
+
+fn line(line_type: DiffLineType, content: &str, old: Option<u32>, new: Option<u32>) -> DiffLine {
+    DiffLine {
+        line_type,
+        content: content.to_string(),
+        old_line_no: old,
+        new_line_no: new,
+        inline_spans: None,
+    }
+}
+
+/// A hunk holding every source line of a cell that was added or removed
+fn whole_cell_hunk(
+    number: usize,
+    cell: &Cell,
+    added: bool,
+    outputs: NotebookOutputMode,
+) -> DiffHunk {
+    let source: Vec<&str> = cell.source.lines().collect();
+    let count = u32::try_from(source.len()).unwrap_or(u32::MAX);
+    let (range, change, line_type) = if added {
+        (
+            format!("@@ -0,0 +1,{count} @@"),
+            "added",
+            DiffLineType::Addition,
+        )
+    } else {
+        (
+            format!("@@ -1,{count} +0,0 @@"),
+            "removed",
+            DiffLineType::Deletion,
+        )
+    };
+    let mut lines: Vec<DiffLine> = source
+        .iter()
+        .zip(1u32..)
+        .map(|(content, n)| {
+            let (old, new) = if added {
+                (None, Some(n))
+            } else {
+                (Some(n), None)
+            };
+            line(line_type.clone(), content, old, new)
+        })
+        .collect();
+    if outputs == NotebookOutputMode::Summarize && !cell.outputs().is_empty() {
+        lines.push(line(line_type, &cell.output_summary(), None, None));
+    }
+    DiffHunk {
+        header: cell_header(&range, number, cell, change),
+        old_start: if added { 0 } else { 1 },
+        old_lines: if added { 0 } else { count },
+        new_start: if added { 1 } else { 0 },
+        new_lines: if added { count } else { 0 },
+        lines,
+        context: None,
+    }
+}
+
+/// Line diff of two texts, each hunk's header followed by `label`
+fn line_diff(old: &str, new: &str, context_lines: u32, label: &str) -> Result<Vec<DiffHunk>> {
+    // A missing final newline would add "no newline" marker lines
+    let with_newline = |text: &str| {
+        let mut text = text.to_string();
+        if !text.is_empty() && !text.ends_with('\n') {
+            text.push('\n');
+        }
+        text
+    };
+    let (old, new) = (with_newline(old), with_newline(new));
+    let mut opts = git2::DiffOptions::new();
+    opts.context_lines(context_lines);
+    let patch =
+        git2::Patch::from_buffers(old.as_bytes(), None, new.as_bytes(), None, Some(&mut opts))?;
+
+    let mut hunks = Vec::new();
+    for h in 0..patch.num_hunks() {
+        let (hunk, line_count) = patch.hunk(h)?;
+        let range = format!(
+            "@@ -{},{} +{},{} @@",
+            hunk.old_start(),
+            hunk.old_lines(),
+            hunk.new_start(),
+            hunk.new_lines()
+        );
+        let mut lines = Vec::with_capacity(line_count);
+        for l in 0..line_count {
+            let diff_line = patch.line_in_hunk(h, l)?;
+            let line_type = match diff_line.origin() {
+                '+' => DiffLineType::Addition,
+                '-' => DiffLineType::Deletion,
+                _ => DiffLineType::Context,
+            };
+            let content = String::from_utf8_lossy(diff_line.content());
+            lines.push(line(
+                line_type,
+                content.trim_end_matches(['\r', '\n']),
+                diff_line.old_lineno(),
+                diff_line.new_lineno(),
+            ));
+        }
+        hunks.push(DiffHunk {
+            header: if label.is_empty() {
+                range
+            } else {
+                format!("{range} {label}")
+            },
+            old_start: hunk.old_start(),
+            old_lines: hunk.old_lines(),
+            new_start: hunk.new_start(),
+            new_lines: hunk.new_lines(),
+            lines,
+            context: None,
+        });
+    }
+    Ok(hunks)
+}
+
+/// Diff two notebooks cell by cell, as hunks named after the cells they touch.
+/// Source edits of a matched cell become line hunks numbered within the cell;
+/// added and removed cells become one hunk each. Outputs follow `outputs`.
+pub fn diff_notebooks(
+    old: &[u8],
+    new: &[u8],
+    context_lines: u32,
+    outputs: NotebookOutputMode,
+) -> Result<Vec<DiffHunk>> {
+    let old = parse_notebook(old)?;
+    let new = parse_notebook(new)?;
+
+    let mut hunks = Vec::new();
+    for alignment in align_cells(&old.cells, &new.cells) {
+        match alignment {
+            CellAlignment::Removed(i) => {
+                hunks.push(whole_cell_hunk(i + 1, &old.cells[i], false, outputs));
+            }
+            CellAlignment::Added(j) => {
+                hunks.push(whole_cell_hunk(j + 1, &new.cells[j], true, outputs));
+            }
+            CellAlignment::Matched(i, j) => {
+                let (before, after) = (&old.cells[i], &new.cells[j]);
+                let label = cell_header("", j + 1, after, "modified");
+                if before.source != after.source {
+                    hunks.extend(line_diff(
+                        &before.source,
+                        &after.source,
+                        context_lines,
+                        label.trim_start(),
+                    )?);
+                }
+                if outputs == NotebookOutputMode::Summarize && before.outputs() != after.outputs() {
+                    let mut lines = Vec::new();
+                    if !before.outputs().is_empty() {
+                        let summary = before.output_summary();
+                        lines.push(line(DiffLineType::Deletion, &summary, None, None));
+                    }
+                    if !after.outputs().is_empty() {
+                        let summary = after.output_summary();
+                        lines.push(line(DiffLineType::Addition, &summary, None, None));
+                    }
+                    hunks.push(DiffHunk {
+                        header: cell_header("@@ -0,0 +0,0 @@", j + 1, after, "outputs changed"),
+                        old_start: 0,
+                        old_lines: 0,
+                        new_start: 0,
+                        new_lines: 0,
+                        lines,
+                        context: None,
+                    });
+                }
+            }
+        }
+    }
+    Ok(hunks)
+}
+
+/// Diff two JSON documents after pretty-printing them with sorted keys, so
+/// reformatting and key order do not show up as changes
+pub fn diff_json(old: &[u8], new: &[u8], context_lines: u32) -> Result<Vec<DiffHunk>> {
+    let canonical = |content: &[u8]| -> Result<String> {
+        if content.iter().all(u8::is_ascii_whitespace) {
+            return Ok(String::new());
+        }
+        let value: Value = serde_json::from_slice(content)?;
+        Ok(serde_json::to_string_pretty(&value)? + "\n")
+    };
+    line_diff(&canonical(old)?, &canonical(new)?, context_lines, "")
+}
+
+/// Replace the hunks of notebooks, and of JSON files with an extension in
+/// `options.structured_json_extensions`, with structured hunks. A file whose sides
+/// cannot be read or parsed keeps its text diff and is marked `RawFallback`.
+pub fn annotate_structured_diffs(
+    repo: &git2::Repository,
+    files: &mut [FileDiff],
+    options: &DiffOptions,
+) {
+    let json_extensions = options.structured_json_extensions.as_deref().unwrap_or(&[]);
+    let context_lines = options.context_lines.unwrap_or(3);
+    let outputs = options.notebook_outputs.unwrap_or_default();
+
+    for file in files.iter_mut().filter(|f| !f.binary) {
+        let Some(path) = file.new_path.clone().or_else(|| file.old_path.clone()) else {
+            continue;
+        };
+        let Some(ext) = Path::new(&path).extension().and_then(|e| e.to_str()) else {
+            continue;
+        };
+        let kind = if ext.eq_ignore_ascii_case(NOTEBOOK_EXTENSION) {
+            StructuredDiffKind::Notebook
+        } else if json_extensions
+            .iter()
+            .any(|e| e.trim_start_matches('.').eq_ignore_ascii_case(ext))
+        {
+            StructuredDiffKind::Json
+        } else {
+            continue;
+        };
+
+        let old = match file.status {
+            DiffStatus::Added | DiffStatus::Untracked => Some(Vec::new()),
+            _ => read_side(repo, file.old_oid.as_deref(), None),
+        };
+        let new = match file.status {
+            DiffStatus::Deleted => Some(Vec::new()),
+            _ => read_side(repo, file.new_oid.as_deref(), file.new_path.as_deref()),
+        };
+        let (Some(old), Some(new)) = (old, new) else {
+            file.structured = Some(StructuredDiffKind::RawFallback);
+            continue;
+        };
+
+        let hunks = match kind {
+            StructuredDiffKind::Notebook => diff_notebooks(&old, &new, context_lines, outputs),
+            _ => diff_json(&old, &new, context_lines),
+        };
+        match hunks {
+            Ok(hunks) => {
+                let count = |line_type: DiffLineType| {
+                    hunks
+                        .iter()
+                        .flat_map(|h| &h.lines)
+                        .filter(|l| l.line_type == line_type)
+                        .count()
+                };
+                file.additions = count(DiffLineType::Addition);
+                file.deletions = count(DiffLineType::Deletion);
+                file.hunks = hunks;
+                file.structured = Some(kind);
+            }
+            Err(e) => {
+                log::debug!("Showing text diff for {path}: {e}");
+                file.structured = Some(StructuredDiffKind::RawFallback);
+            }
+        }
+    }
+}
+
+/// Content of one side of a diff: the blob `oid`, or the working tree file at
+/// `workdir_path` when the blob was never written. `None` when unreadable or over
+/// `FILE_CONTENT_MAX_BYTES`.
+fn read_side(
+    repo: &git2::Repository,
+    oid: Option<&str>,
+    workdir_path: Option<&str>,
+) -> Option<Vec<u8>> {
+    let limit = usize::try_from(FILE_CONTENT_MAX_BYTES).unwrap_or(usize::MAX);
+    let blob = oid
+        .and_then(|oid| git2::Oid::from_str(oid).ok())
+        .and_then(|oid| repo.find_blob(oid).ok());
+    if let Some(blob) = blob {
+        return (blob.size() <= limit).then(|| blob.content().to_vec());
+    }
+    let file = repo.workdir()?.join(workdir_path?);
+    if std::fs::metadata(&file).ok()?.len() > FILE_CONTENT_MAX_BYTES {
+        return None;
+    }
+    std::fs::read(file).ok()
+}
+
+/// A cell-level three-way merge of a notebook
+#[derive(Debug)]
+pub struct NotebookMerge {
+    /// Merged notebook; cells in conflict keep our version
+    pub content: String,
+    pub conflicts: Vec<NotebookCellConflict>,
+}
+
+/// What one side did to a base cell
+#[derive(Debug, Clone, Copy)]
+enum CellEdit {
+    Kept(usize),
+    Changed(usize),
+    Deleted,
+}
+
+/// Per-base-cell edits of one side, and the cells it inserted before each base
+/// cell (the last slot holds cells appended after all of them)
+fn cell_edits(base: &[Cell], side: &[Cell]) -> (Vec<CellEdit>, Vec<Vec<usize>>) {
+    let mut edits = vec![CellEdit::Deleted; base.len()];
+    let mut inserted = vec![Vec::new(); base.len() + 1];
+    let mut slot = 0;
+    for alignment in align_cells(base, side) {
+        match alignment {
+            CellAlignment::Matched(b, s) => {
+                edits[b] = if base[b].value == side[s].value {
+                    CellEdit::Kept(s)
+                } else {
+                    CellEdit::Changed(s)
+                };
+                slot = b + 1;
+            }
+            CellAlignment::Removed(b) => slot = b + 1,
+            CellAlignment::Added(s) => inserted[slot].push(s),
+        }
+    }
+    (edits, inserted)
+}
+
+/// Merge notebooks cell by cell. Cells changed, added or removed on one side only
+/// are taken from that side; cells both sides changed differently are conflicts.
+/// Notebook metadata is taken from theirs when only they changed it.
+pub fn merge_notebooks(base: &[u8], ours: &[u8], theirs: &[u8]) -> Result<NotebookMerge> {
+    let base = parse_notebook(base)?;
+    let ours = parse_notebook(ours)?;
+    let theirs = parse_notebook(theirs)?;
+    let (our_edits, our_inserts) = cell_edits(&base.cells, &ours.cells);
+    let (their_edits, their_inserts) = cell_edits(&base.cells, &theirs.cells);
+
+    let number = |i: usize| u32::try_from(i + 1).ok();
+    let mut cells: Vec<Value> = Vec::new();
+    let mut conflicts = Vec::new();
+    for slot in 0..=base.cells.len() {
+        let (mine, other) = (&our_inserts[slot], &their_inserts[slot]);
+        let same_inserts = mine.len() == other.len()
+            && mine
+                .iter()
+                .zip(other)
+                .all(|(&a, &b)| ours.cells[a].value == theirs.cells[b].value);
+        if !mine.is_empty() && !other.is_empty() && !same_inserts {
+            conflicts.push(NotebookCellConflict {
+                base_cell: None,
+                ours_cell: number(mine[0]),
+                theirs_cell: number(other[0]),
+            });
+        }
+        if mine.is_empty() {
+            cells.extend(other.iter().map(|&i| theirs.cells[i].value.clone()));
+        } else {
+            cells.extend(mine.iter().map(|&i| ours.cells[i].value.clone()));
+        }
+
+        if slot == base.cells.len() {
+            break;
+        }
+        let conflict =
+            |ours_cell: Option<usize>, theirs_cell: Option<usize>| NotebookCellConflict {
+                base_cell: number(slot),
+                ours_cell: ours_cell.and_then(number),
+                theirs_cell: theirs_cell.and_then(number),
+            };
+        match (our_edits[slot], their_edits[slot]) {
+            (CellEdit::Kept(o), CellEdit::Kept(_)) | (CellEdit::Changed(o), CellEdit::Kept(_)) => {
+                cells.push(ours.cells[o].value.clone());
+            }
+            (CellEdit::Kept(_), CellEdit::Changed(t)) => cells.push(theirs.cells[t].value.clone()),
+            (CellEdit::Changed(o), CellEdit::Changed(t)) => {
+                if ours.cells[o].value != theirs.cells[t].value {
+                    conflicts.push(conflict(Some(o), Some(t)));
+                }
+                cells.push(ours.cells[o].value.clone());
+            }
+            (CellEdit::Changed(o), CellEdit::Deleted) => {
+                conflicts.push(conflict(Some(o), None));
+                cells.push(ours.cells[o].value.clone());
+            }
+            (CellEdit::Deleted, CellEdit::Changed(t)) => {
+                conflicts.push(conflict(None, Some(t)));
+                cells.push(theirs.cells[t].value.clone());
+            }
+            (CellEdit::Kept(_) | CellEdit::Deleted, CellEdit::Deleted)
+            | (CellEdit::Deleted, CellEdit::Kept(_)) => {}
+        }
+    }
+
+    let mut document = ours.document.clone();
+    if ours.document.get("metadata") == base.document.get("metadata") {
+        if let (Some(document), Some(metadata)) =
+            (document.as_object_mut(), theirs.document.get("metadata"))
+        {
+            document.insert("metadata".to_string(), metadata.clone());
+        }
+    }
+    if let Some(document) = document.as_object_mut() {
+        document.insert("cells".to_string(), Value::Array(cells));
+    }
+
+    Ok(NotebookMerge {
+        content: to_notebook_json(&document)?,
+        conflicts,
+    })
+}
+
+/// Serialize like Jupyter: one-space indent, sorted keys, trailing newline
+fn to_notebook_json(document: &Value) -> Result<String> {
+    let mut out = Vec::new();
+    let formatter = serde_json::ser::PrettyFormatter::with_indent(b" ");
+    let mut serializer = serde_json::Serializer::with_formatter(&mut out, formatter);
+    document.serialize(&mut serializer)?;
+    out.push(b'\n');
+    String::from_utf8(out).map_err(|e| AxisError::Other(e.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    /// Notebook fixture from `(cell_type, source, outputs)` triples
+    fn notebook(cells: &[(&str, &str, Value)]) -> Vec<u8> {
+        let cells: Vec<Value> = cells
+            .iter()
+            .map(|(cell_type, source, outputs)| {
+                let mut cell = json!({
+                    "cell_type": cell_type,
+                    "metadata": {},
+                    "source": source.split_inclusive('\n').collect::<Vec<_>>(),
+                });
+                if *cell_type == "code" {
+                    cell["outputs"] = outputs.clone();
+                    cell["execution_count"] = json!(1);
+                }
+                cell
+            })
+            .collect();
+        serde_json::to_vec(&json!({
+            "cells": cells,
+            "metadata": {"kernelspec": {"name": "python3"}},
+            "nbformat": 4,
+            "nbformat_minor": 4,
+        }))
+        .expect("should serialize")
+    }
+
+    fn stream(text: &str) -> Value {
+        json!([{"output_type": "stream", "name": "stdout", "text": [text]}])
+    }
+
+    fn image() -> Value {
+        json!([{
+            "output_type": "display_data",
+            "data": {"image/png": "iVBORw0KGgoAAAANSUhEUg==", "text/plain": ["<Figure>"]},
+            "metadata": {},
+        }])
+    }
+
+    fn headers(hunks: &[DiffHunk]) -> Vec<&str> {
+        hunks.iter().map(|h| h.header.as_str()).collect()
+    }
+
+    #[test]
+    fn test_diff_notebooks_detects_cell_changes() {
+        let old = notebook(&[
+            ("markdown", "# Analysis\n", json!(null)),
+            (
+                "code",
+                "import pandas as pd\ndf = pd.read_csv('a.csv')\n",
+                json!([]),
+            ),
+            ("code", "df.plot()\n", json!([])),
+        ]);
+        let new = notebook(&[
+            ("markdown", "# Analysis\n", json!(null)),
+            (
+                "code",
+                "import pandas as pd\ndf = pd.read_csv('b.csv')\n",
+                json!([]),
+            ),
+            ("markdown", "## Results\n", json!(null)),
+        ]);
+
+        let hunks = diff_notebooks(&old, &new, 3, NotebookOutputMode::Ignore).expect("should diff");
+
+        assert_eq!(
+            headers(&hunks),
+            vec![
+                "@@ -1,2 +1,2 @@ cell 2 [code] modified",
+                "@@ -1,1 +0,0 @@ cell 3 [code] removed",
+                "@@ -0,0 +1,1 @@ cell 3 [markdown] added",
+            ]
+        );
+        let modified: Vec<(DiffLineType, &str)> = hunks[0]
+            .lines
+            .iter()
+            .map(|l| (l.line_type.clone(), l.content.as_str()))
+            .collect();
+        assert_eq!(
+            modified,
+            vec![
+                (DiffLineType::Context, "import pandas as pd"),
+                (DiffLineType::Deletion, "df = pd.read_csv('a.csv')"),
+                (DiffLineType::Addition, "df = pd.read_csv('b.csv')"),
+            ]
+        );
+        assert_eq!(hunks[0].lines[2].new_line_no, Some(2));
+    }
+
+    #[test]
+    fn test_diff_notebooks_matches_moved_cells_by_id() {
+        let cell = |id: &str, source: &str| {
+            json!({"cell_type": "code", "id": id, "metadata": {}, "source": source,
+                   "outputs": [], "execution_count": null})
+        };
+        let doc = |cells: Vec<Value>| {
+            serde_json::to_vec(&json!({"cells": cells})).expect("should serialize")
+        };
+        let old = doc(vec![cell("a", "x = 1\n"), cell("b", "y = 2\n")]);
+        let new = doc(vec![
+            cell("a", "x = 10\n"),
+            cell("c", "z = 3\n"),
+            cell("b", "y = 2\n"),
+        ]);
+
+        let hunks = diff_notebooks(&old, &new, 3, NotebookOutputMode::Ignore).expect("should diff");
+        assert_eq!(
+            headers(&hunks),
+            vec![
+                "@@ -1,1 +1,1 @@ cell 1 [code] modified",
+                "@@ -0,0 +1,1 @@ cell 2 [code] added",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_diff_notebooks_output_modes() {
+        let old = notebook(&[("code", "plot()\n", stream("done\n"))]);
+        let new = notebook(&[("code", "plot()\n", image())]);
+
+        let ignored =
+            diff_notebooks(&old, &new, 3, NotebookOutputMode::Ignore).expect("should diff");
+        assert!(ignored.is_empty(), "Output-only changes should be hidden");
+
+        let summarized =
+            diff_notebooks(&old, &new, 3, NotebookOutputMode::Summarize).expect("should diff");
+        assert_eq!(
+            headers(&summarized),
+            vec!["@@ -0,0 +0,0 @@ cell 1 [code] outputs changed"]
+        );
+        let lines = &summarized[0].lines;
+        assert_eq!(lines.len(), 2);
+        assert!(lines[0].content.starts_with("Out[1]: 1 output (stream)"));
+        assert!(lines[1]
+            .content
+            .starts_with("Out[1]: 1 output (image/png, text/plain)"));
+        assert!(
+            !lines[1].content.contains("iVBOR"),
+            "Base64 payloads must not be shown"
+        );
+    }
+
+    #[test]
+    fn test_diff_notebooks_ignores_execution_count_only_changes() {
+        let old = notebook(&[("code", "1 + 1\n", stream("2\n"))]);
+        let mut new: Value = serde_json::from_slice(&old).expect("should parse");
+        new["cells"][0]["execution_count"] = json!(7);
+        let new = serde_json::to_vec(&new).expect("should serialize");
+
+        let hunks =
+            diff_notebooks(&old, &new, 3, NotebookOutputMode::Summarize).expect("should diff");
+        assert!(hunks.is_empty());
+    }
+
+    #[test]
+    fn test_diff_notebooks_malformed_json_fails() {
+        let old = notebook(&[("code", "x\n", json!([]))]);
+        assert!(diff_notebooks(&old, b"{\"cells\": [", 3, NotebookOutputMode::Ignore).is_err());
+        assert!(diff_notebooks(&old, b"{\"nbformat\": 4}", 3, NotebookOutputMode::Ignore).is_err());
+    }
+
+    #[test]
+    fn test_diff_json_ignores_formatting_and_key_order() {
+        let old = br#"{"b": 1, "a": [1, 2]}"#;
+        let new = b"{\n  \"a\": [1, 2],\n  \"b\": 2\n}\n";
+
+        let hunks = diff_json(old, new, 0).expect("should diff");
+        assert_eq!(hunks.len(), 1);
+        let changed: Vec<&str> = hunks[0].lines.iter().map(|l| l.content.as_str()).collect();
+        assert_eq!(changed, vec!["  \"b\": 1", "  \"b\": 2"]);
+    }
+
+    #[test]
+    fn test_merge_notebooks_auto_merges_one_sided_cell_changes() {
+        let base = notebook(&[
+            ("code", "a = 1\n", json!([])),
+            ("code", "b = 2\n", json!([])),
+            ("code", "c = 3\n", json!([])),
+        ]);
+        let ours = notebook(&[
+            ("code", "a = 10\n", json!([])),
+            ("code", "b = 2\n", json!([])),
+            ("code", "c = 3\n", json!([])),
+        ]);
+        let theirs = notebook(&[
+            ("code", "a = 1\n", json!([])),
+            ("code", "b = 2\n", json!([])),
+            ("code", "c = 30\n", json!([])),
+            ("markdown", "Done\n", json!(null)),
+        ]);
+
+        let merged = merge_notebooks(&base, &ours, &theirs).expect("should merge");
+        assert!(merged.conflicts.is_empty());
+        let merged = parse_notebook(merged.content.as_bytes()).expect("should parse");
+        let sources: Vec<&str> = merged.cells.iter().map(|c| c.source.as_str()).collect();
+        assert_eq!(sources, vec!["a = 10\n", "b = 2\n", "c = 30\n", "Done\n"]);
+    }
+
+    #[test]
+    fn test_merge_notebooks_reports_cells_changed_on_both_sides() {
+        let base = notebook(&[
+            ("code", "a = 1\n", json!([])),
+            ("code", "b = 2\n", json!([])),
+        ]);
+        let ours = notebook(&[
+            ("code", "a = 10\n", json!([])),
+            ("code", "b = 2\n", json!([])),
+        ]);
+        let theirs = notebook(&[
+            ("code", "a = 11\n", json!([])),
+            ("code", "b = 20\n", json!([])),
+        ]);
+
+        let merged = merge_notebooks(&base, &ours, &theirs).expect("should merge");
+        assert_eq!(
+            merged.conflicts,
+            vec![NotebookCellConflict {
+                base_cell: Some(1),
+                ours_cell: Some(1),
+                theirs_cell: Some(1),
+            }]
+        );
+        // The clean cell is still merged; the conflicting one keeps ours
+        let merged = parse_notebook(merged.content.as_bytes()).expect("should parse");
+        let sources: Vec<&str> = merged.cells.iter().map(|c| c.source.as_str()).collect();
+        assert_eq!(sources, vec!["a = 10\n", "b = 20\n"]);
+    }
+
+    #[test]
+    fn test_to_notebook_json_uses_one_space_indent() {
+        let json =
+            to_notebook_json(&json!({"cells": [], "nbformat": 4})).expect("should serialize");
+        assert_eq!(json, "{\n \"cells\": [],\n \"nbformat\": 4\n}\n");
+    }
+}
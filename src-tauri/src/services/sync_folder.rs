@@ -0,0 +1,516 @@
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use crate::error::{AxisError, Result};
+use crate::models::{SyncFolderWarning, SyncProvider};
+
+/// How a sync client's folder is recognised
+struct SyncRootRule {
+    provider: SyncProvider,
+    /// Folder paths relative to the home directory; a trailing `*` matches any suffix
+    home_paths: &'static [&'static str],
+    /// Files or directories the client keeps at the root of its folder
+    markers: &'static [&'static str],
+    /// Environment variables holding the root path (set by the Windows clients)
+    env_vars: &'static [&'static str],
+}
+
+const SYNC_ROOT_RULES: &[SyncRootRule] = &[
+    SyncRootRule {
+        provider: SyncProvider::Dropbox,
+        home_paths: &["Dropbox", "Dropbox (*", "Library/CloudStorage/Dropbox*"],
+        markers: &[".dropbox", ".dropbox.cache"],
+        env_vars: &[],
+    },
+    SyncRootRule {
+        provider: SyncProvider::OneDrive,
+        home_paths: &["OneDrive", "OneDrive - *", "Library/CloudStorage/OneDrive*"],
+        markers: &[],
+        env_vars: &["OneDrive", "OneDriveCommercial", "OneDriveConsumer"],
+    },
+    SyncRootRule {
+        provider: SyncProvider::ICloudDrive,
+        home_paths: &["Library/Mobile Documents", "iCloudDrive"],
+        markers: &[],
+        env_vars: &[],
+    },
+    SyncRootRule {
+        provider: SyncProvider::GoogleDrive,
+        home_paths: &[
+            "Google Drive",
+            "My Drive",
+            "Library/CloudStorage/GoogleDrive*",
+        ],
+        markers: &[".tmp.drivedownload", ".tmp.driveupload"],
+        env_vars: &[],
+    },
+];
+
+/// Error message fragments produced when a sync client holds a file open
+const SYNC_LOCK_MARKERS: &[&str] = &[
+    "sharing violation",
+    "being used by another process",
+    "lock violation",
+];
+
+/// Windows error codes for a file held open by another process
+/// (`ERROR_SHARING_VIOLATION`, `ERROR_LOCK_VIOLATION`)
+#[cfg(windows)]
+const SYNC_LOCK_OS_ERRORS: &[i32] = &[32, 33];
+
+/// A sync client folder that contains a path
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SyncFolderMatch {
+    pub provider: SyncProvider,
+    pub root: PathBuf,
+}
+
+/// Find the sync client folder containing `path`, if any.
+/// `home` and `env` are injected so detection can be tested against fixture layouts.
+pub fn detect_sync_folder(
+    path: &Path,
+    home: Option<&Path>,
+    env: &dyn Fn(&str) -> Option<String>,
+) -> Option<SyncFolderMatch> {
+    for rule in SYNC_ROOT_RULES {
+        for var in rule.env_vars {
+            if let Some(root) = env(var).filter(|r| !r.is_empty()).map(PathBuf::from) {
+                if path.starts_with(&root) {
+                    return Some(SyncFolderMatch {
+                        provider: rule.provider,
+                        root,
+                    });
+                }
+            }
+        }
+    }
+
+    if let Some(relative) = home.and_then(|h| path.strip_prefix(h).ok()) {
+        let components: Vec<String> = relative
+            .components()
+            .map(|c| c.as_os_str().to_string_lossy().to_string())
+            .collect();
+        for rule in SYNC_ROOT_RULES {
+            for pattern in rule.home_paths {
+                let parts: Vec<&str> = pattern.split('/').collect();
+                if components.len() >= parts.len()
+                    && parts
+                        .iter()
+                        .zip(&components)
+                        .all(|(p, c)| component_matches(p, c))
+                {
+                    let root = home.map(|h| h.join(components[..parts.len()].join("/")))?;
+                    return Some(SyncFolderMatch {
+                        provider: rule.provider,
+                        root,
+                    });
+                }
+            }
+        }
+    }
+
+    for ancestor in path.ancestors() {
+        for rule in SYNC_ROOT_RULES {
+            if rule.markers.iter().any(|m| ancestor.join(m).exists()) {
+                return Some(SyncFolderMatch {
+                    provider: rule.provider,
+                    root: ancestor.to_path_buf(),
+                });
+            }
+        }
+    }
+
+    None
+}
+
+/// Find the sync client folder containing `path` using the real home directory and environment
+pub fn detect_sync_folder_for(path: &Path) -> Option<SyncFolderMatch> {
+    detect_sync_folder(path, dirs::home_dir().as_deref(), &|var| {
+        std::env::var(var).ok()
+    })
+}
+
+fn component_matches(pattern: &str, component: &str) -> bool {
+    match pattern.strip_suffix('*') {
+        Some(prefix) => component.to_lowercase().starts_with(&prefix.to_lowercase()),
+        None => component.eq_ignore_ascii_case(pattern),
+    }
+}
+
+/// Build the health warning for a repository whose workdir or git dir is synced
+pub fn sync_folder_warning(
+    workdir_match: Option<SyncFolderMatch>,
+    git_dir_match: Option<SyncFolderMatch>,
+    acknowledged: bool,
+) -> Option<SyncFolderWarning> {
+    let git_dir_inside = git_dir_match.is_some();
+    let found = git_dir_match.or(workdir_match)?;
+    let provider = found.provider;
+
+    let mut risks = vec![
+        format!("{provider} can upload or replace files in .git while Git is writing them, corrupting the index, refs or objects"),
+        format!("{provider} may create conflicted copies of refs and lock files that Git does not understand"),
+    ];
+    if matches!(
+        provider,
+        SyncProvider::ICloudDrive | SyncProvider::OneDrive | SyncProvider::GoogleDrive
+    ) {
+        risks.push(format!(
+            "{provider} can evict files to online-only placeholders, making objects unreadable offline"
+        ));
+    }
+
+    let mut remediations = vec![
+        "Move the repository to a folder that is not synced and use a Git remote for backup"
+            .to_string(),
+    ];
+    match provider {
+        SyncProvider::Dropbox => {
+            remediations
+                .push("Exclude the .git folder with Dropbox's \"Ignore\" option".to_string());
+        }
+        SyncProvider::OneDrive | SyncProvider::GoogleDrive => {
+            remediations.push(format!(
+                "Mark the repository as \"Always keep on this device\" in {provider}"
+            ));
+        }
+        SyncProvider::ICloudDrive => {
+            remediations
+                .push("Disable \"Optimize Mac Storage\" so files are never evicted".to_string());
+        }
+    }
+
+    Some(SyncFolderWarning {
+        provider,
+        sync_root: found.root,
+        git_dir_inside,
+        risks,
+        remediations,
+        acknowledged,
+    })
+}
+
+/// Whether an error means a file is locked by another process (typically a sync client)
+pub fn is_sync_lock_error(err: &AxisError) -> bool {
+    let message = err.to_string().to_lowercase();
+    SYNC_LOCK_MARKERS.iter().any(|m| message.contains(m))
+}
+
+/// Whether an I/O error means a file is locked by another process.
+/// The codes are only meaningful on Windows; elsewhere the same numbers are unrelated errors.
+pub fn is_sync_lock_io_error(err: &std::io::Error) -> bool {
+    #[cfg(windows)]
+    if err
+        .raw_os_error()
+        .is_some_and(|code| SYNC_LOCK_OS_ERRORS.contains(&code))
+    {
+        return true;
+    }
+    let message = err.to_string().to_lowercase();
+    SYNC_LOCK_MARKERS.iter().any(|m| message.contains(m))
+}
+
+/// Verified, retrying writes for repositories inside cloud-synced folders.
+///
+/// When enabled, each write is retried with backoff while the target is locked by
+/// another process, then re-read to confirm it stuck; a mismatch is retried once.
+/// Both failure modes surface as `AxisError::SyncClientInterference`.
+#[derive(Debug, Clone)]
+pub struct SyncWriteGuard {
+    enabled: bool,
+    lock_retries: u32,
+    backoff: Duration,
+}
+
+impl SyncWriteGuard {
+    const DEFAULT_LOCK_RETRIES: u32 = 3;
+    const DEFAULT_BACKOFF: Duration = Duration::from_millis(100);
+
+    pub fn new(enabled: bool, lock_retries: u32, backoff: Duration) -> Self {
+        Self {
+            enabled,
+            lock_retries,
+            backoff,
+        }
+    }
+
+    /// Guard for a repository, enabled only when it lives inside a synced folder
+    pub fn for_path(path: &Path) -> Self {
+        let sync_folder = detect_sync_folder_for(path);
+        if let Some(found) = &sync_folder {
+            log::info!(
+                "Repository {} is inside {} folder {}; verifying index and ref writes",
+                path.display(),
+                found.provider,
+                found.root.display()
+            );
+        }
+        Self::new(
+            sync_folder.is_some(),
+            Self::DEFAULT_LOCK_RETRIES,
+            Self::DEFAULT_BACKOFF,
+        )
+    }
+
+    /// Run `write`, then `verify` that the result reached disk.
+    /// Without the guard enabled this is just `write()`.
+    pub fn run<T>(
+        &self,
+        target: &str,
+        mut write: impl FnMut() -> Result<T>,
+        mut verify: impl FnMut(&T) -> Result<bool>,
+    ) -> Result<T> {
+        if !self.enabled {
+            return write();
+        }
+
+        let value = self.write_with_backoff(target, &mut write)?;
+        if verify(&value)? {
+            return Ok(value);
+        }
+
+        log::warn!("{target} did not match after writing, retrying once");
+        let value = self.write_with_backoff(target, &mut write)?;
+        if verify(&value)? {
+            return Ok(value);
+        }
+
+        Err(AxisError::SyncClientInterference(format!(
+            "{target} was changed by another process right after it was written"
+        )))
+    }
+
+    fn write_with_backoff<T>(
+        &self,
+        target: &str,
+        write: &mut impl FnMut() -> Result<T>,
+    ) -> Result<T> {
+        let mut attempt = 0;
+        loop {
+            match write() {
+                Err(e) if is_sync_lock_error(&e) => {
+                    if attempt >= self.lock_retries {
+                        return Err(AxisError::SyncClientInterference(format!(
+                            "{target} is locked by another process: {e}"
+                        )));
+                    }
+                    attempt += 1;
+                    log::warn!(
+                        "{target} is locked, retrying ({attempt}/{})",
+                        self.lock_retries
+                    );
+                    std::thread::sleep(self.backoff * attempt);
+                }
+                result => return result,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+    use tempfile::TempDir;
+
+    fn no_env(_: &str) -> Option<String> {
+        None
+    }
+
+    fn sharing_violation() -> AxisError {
+        AxisError::GitError(
+            "could not open 'index.lock' for writing: sharing violation".to_string(),
+        )
+    }
+
+    // ==================== Detection Tests ====================
+
+    #[test]
+    fn test_detect_home_relative_layouts() {
+        let home = Path::new("/home/user");
+        let cases = [
+            ("Dropbox/code/app", SyncProvider::Dropbox, "Dropbox"),
+            (
+                "Dropbox (Acme)/app",
+                SyncProvider::Dropbox,
+                "Dropbox (Acme)",
+            ),
+            (
+                "Library/CloudStorage/OneDrive-Acme/app",
+                SyncProvider::OneDrive,
+                "Library/CloudStorage/OneDrive-Acme",
+            ),
+            (
+                "Library/Mobile Documents/com~apple~CloudDocs/app",
+                SyncProvider::ICloudDrive,
+                "Library/Mobile Documents",
+            ),
+            (
+                "Library/CloudStorage/GoogleDrive-me@example.com/My Drive/app",
+                SyncProvider::GoogleDrive,
+                "Library/CloudStorage/GoogleDrive-me@example.com",
+            ),
+        ];
+
+        for (relative, provider, root) in cases {
+            let found = detect_sync_folder(&home.join(relative), Some(home), &no_env)
+                .unwrap_or_else(|| panic!("should detect {relative}"));
+            assert_eq!(found.provider, provider, "{relative}");
+            assert_eq!(found.root, home.join(root), "{relative}");
+        }
+    }
+
+    #[test]
+    fn test_detect_ignores_unsynced_paths() {
+        let home = Path::new("/home/user");
+        assert!(detect_sync_folder(&home.join("code/app"), Some(home), &no_env).is_none());
+        // Only the folder directly under home counts, not a nested "Dropbox" directory
+        assert!(detect_sync_folder(&home.join("code/Dropbox/app"), Some(home), &no_env).is_none());
+    }
+
+    #[test]
+    fn test_detect_env_root() {
+        let env =
+            |var: &str| (var == "OneDriveCommercial").then(|| "/data/OneDrive - Acme".to_string());
+        let found = detect_sync_folder(Path::new("/data/OneDrive - Acme/app"), None, &env)
+            .expect("should detect");
+        assert_eq!(found.provider, SyncProvider::OneDrive);
+        assert_eq!(found.root, PathBuf::from("/data/OneDrive - Acme"));
+    }
+
+    #[test]
+    fn test_detect_marker_file_fixture() {
+        let tmp = TempDir::new().expect("should create temp dir");
+        let root = tmp.path().join("Work");
+        let repo = root.join("projects/app");
+        std::fs::create_dir_all(&repo).expect("should create dirs");
+        std::fs::write(root.join(".dropbox"), "{}").expect("should write marker");
+
+        let found = detect_sync_folder(&repo, None, &no_env).expect("should detect");
+        assert_eq!(found.provider, SyncProvider::Dropbox);
+        assert_eq!(found.root, root);
+    }
+
+    // ==================== Warning Tests ====================
+
+    #[test]
+    fn test_sync_folder_warning_prefers_git_dir_match() {
+        let found = SyncFolderMatch {
+            provider: SyncProvider::ICloudDrive,
+            root: PathBuf::from("/icloud"),
+        };
+        let warning = sync_folder_warning(None, Some(found), true).expect("should build warning");
+        assert!(warning.git_dir_inside);
+        assert!(warning.acknowledged);
+        assert!(warning.risks.iter().any(|r| r.contains("online-only")));
+        assert!(sync_folder_warning(None, None, false).is_none());
+    }
+
+    // ==================== Write Guard Tests ====================
+
+    #[test]
+    fn test_is_sync_lock_error() {
+        assert!(is_sync_lock_error(&sharing_violation()));
+        assert!(is_sync_lock_error(&AxisError::GitError(
+            "failed to write index: The process cannot access the file because it is being used by another process.".to_string()
+        )));
+        assert!(!is_sync_lock_error(&AxisError::IoError(
+            "permission denied".to_string()
+        )));
+    }
+
+    #[test]
+    fn test_is_sync_lock_io_error_uses_windows_codes_only() {
+        let code_32 = std::io::Error::from_raw_os_error(32);
+        let code_33 = std::io::Error::from_raw_os_error(33);
+        // 32 and 33 are EPIPE and EDOM outside Windows
+        assert_eq!(is_sync_lock_io_error(&code_32), cfg!(windows));
+        assert_eq!(is_sync_lock_io_error(&code_33), cfg!(windows));
+        assert!(!is_sync_lock_io_error(&std::io::Error::from(
+            std::io::ErrorKind::PermissionDenied
+        )));
+    }
+
+    #[test]
+    fn test_guard_retries_locked_write() {
+        let guard = SyncWriteGuard::new(true, 3, Duration::ZERO);
+        let attempts = Cell::new(0);
+
+        let result = guard.run(
+            "index",
+            || {
+                attempts.set(attempts.get() + 1);
+                if attempts.get() < 3 {
+                    Err(sharing_violation())
+                } else {
+                    Ok(())
+                }
+            },
+            |()| Ok(true),
+        );
+
+        assert!(result.is_ok());
+        assert_eq!(attempts.get(), 3);
+    }
+
+    #[test]
+    fn test_guard_maps_persistent_lock_to_sync_error() {
+        let guard = SyncWriteGuard::new(true, 2, Duration::ZERO);
+        let attempts = Cell::new(0);
+
+        let err = guard
+            .run(
+                "index",
+                || -> Result<()> {
+                    attempts.set(attempts.get() + 1);
+                    Err(sharing_violation())
+                },
+                |()| Ok(true),
+            )
+            .expect_err("should give up");
+
+        assert!(matches!(err, AxisError::SyncClientInterference(_)));
+        assert_eq!(attempts.get(), 3, "initial attempt plus two retries");
+    }
+
+    #[test]
+    fn test_guard_retries_once_on_verification_mismatch() {
+        let guard = SyncWriteGuard::new(true, 0, Duration::ZERO);
+        let writes = Cell::new(0);
+        let verifies = Cell::new(0);
+
+        guard
+            .run(
+                "HEAD",
+                || {
+                    writes.set(writes.get() + 1);
+                    Ok(())
+                },
+                |()| {
+                    verifies.set(verifies.get() + 1);
+                    Ok(verifies.get() > 1)
+                },
+            )
+            .expect("second write should verify");
+        assert_eq!(writes.get(), 2);
+
+        let err = guard
+            .run("HEAD", || Ok(()), |()| Ok(false))
+            .expect_err("should give up after one retry");
+        assert!(matches!(err, AxisError::SyncClientInterference(_)));
+    }
+
+    #[test]
+    fn test_disabled_guard_passes_errors_through() {
+        let guard = SyncWriteGuard::new(false, 3, Duration::ZERO);
+        let err = guard
+            .run(
+                "index",
+                || -> Result<()> { Err(sharing_violation()) },
+                |()| Ok(false),
+            )
+            .expect_err("should fail");
+        assert!(matches!(err, AxisError::IoError(_)));
+        assert!(guard.run("index", || Ok(()), |()| Ok(false)).is_ok());
+    }
+}
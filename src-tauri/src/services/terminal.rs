@@ -0,0 +1,818 @@
+use std::path::{Component, Path, PathBuf};
+
+use super::process_utils::get_extended_path;
+use crate::error::{AxisError, Result};
+use crate::models::{AvailableTerminal, TerminalApp};
+
+/// Operating system a terminal command line is built for
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TerminalPlatform {
+    MacOs,
+    Windows,
+    Linux,
+}
+
+impl TerminalPlatform {
+    pub fn current() -> Self {
+        if cfg!(target_os = "macos") {
+            Self::MacOs
+        } else if cfg!(target_os = "windows") {
+            Self::Windows
+        } else {
+            Self::Linux
+        }
+    }
+
+    /// Presets offered on this platform, in the order a default is picked on Linux
+    pub fn terminals(self) -> &'static [TerminalApp] {
+        match self {
+            Self::MacOs => &[
+                TerminalApp::Terminal,
+                TerminalApp::ITerm2,
+                TerminalApp::WezTerm,
+                TerminalApp::Alacritty,
+                TerminalApp::Kitty,
+            ],
+            Self::Windows => &[
+                TerminalApp::WindowsTerminal,
+                TerminalApp::Cmd,
+                TerminalApp::PowerShell,
+                TerminalApp::WezTerm,
+                TerminalApp::Alacritty,
+            ],
+            Self::Linux => &[
+                TerminalApp::GnomeTerminal,
+                TerminalApp::Konsole,
+                TerminalApp::WezTerm,
+                TerminalApp::Alacritty,
+                TerminalApp::Kitty,
+            ],
+        }
+    }
+
+    /// Terminal used when the settings name none: Terminal.app on macOS, Command Prompt
+    /// on Windows, and the first installed preset on Linux
+    pub fn default_terminal(self) -> Option<TerminalApp> {
+        match self {
+            Self::MacOs => Some(TerminalApp::Terminal),
+            Self::Windows => Some(TerminalApp::Cmd),
+            Self::Linux => self
+                .terminals()
+                .iter()
+                .copied()
+                .find(|app| is_terminal_installed(*app, self)),
+        }
+    }
+}
+
+/// A program that opens a terminal, with arguments passed as-is (no shell in between),
+/// so paths with spaces need no quoting unless they end up inside a script
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TerminalLaunch {
+    pub program: String,
+    pub args: Vec<String>,
+    pub current_dir: PathBuf,
+    /// Console programs (cmd, PowerShell) need a console window of their own on Windows
+    pub new_console: bool,
+}
+
+impl TerminalLaunch {
+    fn new(program: &str, current_dir: &Path) -> Self {
+        Self {
+            program: program.to_string(),
+            args: Vec::new(),
+            current_dir: current_dir.to_path_buf(),
+            new_console: false,
+        }
+    }
+
+    fn arg(mut self, arg: impl Into<String>) -> Self {
+        self.args.push(arg.into());
+        self
+    }
+
+    fn args<I, S>(mut self, args: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.args.extend(args.into_iter().map(Into::into));
+        self
+    }
+
+    /// Terminals distributed as app bundles on macOS are started through `open`, which
+    /// finds the bundle wherever it is installed
+    fn bundled(platform: TerminalPlatform, app_name: &str, binary: &str, dir: &Path) -> Self {
+        match platform {
+            TerminalPlatform::MacOs => Self::new("open", dir).args(["-na", app_name, "--args"]),
+            TerminalPlatform::Windows | TerminalPlatform::Linux => Self::new(binary, dir),
+        }
+    }
+
+    fn osascript(dir: &Path, lines: &[String]) -> Self {
+        lines
+            .iter()
+            .fold(Self::new("osascript", dir), |launch, line| {
+                launch.args(["-e", line.as_str()])
+            })
+    }
+
+    /// Command line opening `app` in `dir`. `command` runs there first, leaving an
+    /// interactive shell open; terminals that cannot run one (custom templates) just
+    /// open at the path.
+    pub fn build(
+        app: TerminalApp,
+        platform: TerminalPlatform,
+        dir: &Path,
+        command: Option<&str>,
+        profile: Option<&str>,
+        template: Option<&str>,
+    ) -> Result<Self> {
+        if app != TerminalApp::Custom && !platform.terminals().contains(&app) {
+            return Err(AxisError::Other(format!(
+                "{app} is not available on this platform"
+            )));
+        }
+
+        let dir_arg = dir.to_string_lossy().to_string();
+        let command = command.map(str::trim).filter(|c| !c.is_empty());
+        let profile = profile.map(str::trim).filter(|p| !p.is_empty());
+
+        let launch = match app {
+            TerminalApp::Terminal => match command {
+                None => Self::new("open", dir).args(["-a", "Terminal", dir_arg.as_str()]),
+                Some(command) => {
+                    let script = format!("cd {} && {command}", sh_quote(&dir_arg));
+                    Self::osascript(
+                        dir,
+                        &[
+                            "tell application \"Terminal\"".to_string(),
+                            "activate".to_string(),
+                            format!("do script {}", applescript_string(&script)),
+                            "end tell".to_string(),
+                        ],
+                    )
+                }
+            },
+            TerminalApp::ITerm2 => {
+                let window = match profile {
+                    Some(profile) => {
+                        format!("create window with profile {}", applescript_string(profile))
+                    }
+                    None => "create window with default profile".to_string(),
+                };
+                let mut script = format!("cd {}", sh_quote(&dir_arg));
+                if let Some(command) = command {
+                    script.push_str(" && ");
+                    script.push_str(command);
+                }
+                Self::osascript(
+                    dir,
+                    &[
+                        "tell application \"iTerm\"".to_string(),
+                        "activate".to_string(),
+                        format!("set newWindow to ({window})"),
+                        format!(
+                            "tell current session of newWindow to write text {}",
+                            applescript_string(&script)
+                        ),
+                        "end tell".to_string(),
+                    ],
+                )
+            }
+            TerminalApp::WezTerm => {
+                let launch = Self::bundled(platform, "WezTerm", "wezterm", dir).args([
+                    "start",
+                    "--cwd",
+                    dir_arg.as_str(),
+                ]);
+                match command {
+                    Some(command) => launch.arg("--").args(shell_then(platform, command)),
+                    None => launch,
+                }
+            }
+            TerminalApp::Alacritty => {
+                let launch = Self::bundled(platform, "Alacritty", "alacritty", dir)
+                    .args(["--working-directory", dir_arg.as_str()]);
+                match command {
+                    Some(command) => launch.arg("-e").args(shell_then(platform, command)),
+                    None => launch,
+                }
+            }
+            TerminalApp::Kitty => {
+                let launch = Self::bundled(platform, "kitty", "kitty", dir)
+                    .args(["--directory", dir_arg.as_str()]);
+                match command {
+                    Some(command) => launch.args(shell_then(platform, command)),
+                    None => launch,
+                }
+            }
+            TerminalApp::GnomeTerminal => {
+                let mut launch =
+                    Self::new("gnome-terminal", dir).arg(format!("--working-directory={dir_arg}"));
+                if let Some(profile) = profile {
+                    launch = launch.arg(format!("--profile={profile}"));
+                }
+                match command {
+                    Some(command) => launch.arg("--").args(shell_then(platform, command)),
+                    None => launch,
+                }
+            }
+            TerminalApp::Konsole => {
+                let mut launch = Self::new("konsole", dir).args(["--workdir", dir_arg.as_str()]);
+                if let Some(profile) = profile {
+                    launch = launch.args(["--profile", profile]);
+                }
+                match command {
+                    Some(command) => launch.arg("-e").args(shell_then(platform, command)),
+                    None => launch,
+                }
+            }
+            TerminalApp::WindowsTerminal => {
+                let mut launch = Self::new("wt", dir);
+                if let Some(profile) = profile {
+                    launch = launch.args(["-p".to_string(), wt_escape(profile)]);
+                }
+                launch = launch.args(["-d".to_string(), wt_escape(&dir_arg)]);
+                match command {
+                    Some(command) => {
+                        launch.args(["cmd".to_string(), "/k".to_string(), wt_escape(command)])
+                    }
+                    None => launch,
+                }
+            }
+            TerminalApp::Cmd => {
+                let launch = Self::new("cmd", dir);
+                match command {
+                    Some(command) => launch.args(["/k", command]),
+                    None => launch,
+                }
+            }
+            TerminalApp::PowerShell => {
+                let launch = Self::new("powershell", dir).arg("-NoExit");
+                match command {
+                    Some(command) => launch.args(["-Command", command]),
+                    None => launch,
+                }
+            }
+            TerminalApp::Custom => {
+                let template = template
+                    .map(str::trim)
+                    .filter(|t| !t.is_empty())
+                    .ok_or_else(|| {
+                        AxisError::Other("No custom terminal command is configured".to_string())
+                    })?;
+                let mut words = split_command_template(template)?
+                    .into_iter()
+                    .map(|word| word.replace("{path}", &dir_arg));
+                let program = words.next().ok_or_else(|| {
+                    AxisError::Other("No custom terminal command is configured".to_string())
+                })?;
+                Self::new(&program, dir).args(words)
+            }
+        };
+
+        Ok(Self {
+            new_console: matches!(app, TerminalApp::Cmd | TerminalApp::PowerShell),
+            ..launch
+        })
+    }
+
+    pub fn spawn(&self) -> Result<()> {
+        let mut cmd = std::process::Command::new(&self.program);
+        cmd.args(&self.args)
+            .current_dir(&self.current_dir)
+            .env("PATH", get_extended_path());
+
+        #[cfg(windows)]
+        if self.new_console {
+            use std::os::windows::process::CommandExt;
+            const CREATE_NEW_CONSOLE: u32 = 0x00000010;
+            cmd.creation_flags(CREATE_NEW_CONSOLE);
+        }
+
+        cmd.spawn()
+            .map(drop)
+            .map_err(|e| AxisError::Other(format!("Failed to open {}: {e}", self.program)))
+    }
+}
+
+/// Directory a terminal opens in: `subpath` inside `root`, or the folder holding it when
+/// it names a file
+pub fn terminal_directory(root: &Path, subpath: Option<&str>) -> Result<PathBuf> {
+    let Some(subpath) = subpath.filter(|s| !s.is_empty()) else {
+        return Ok(root.to_path_buf());
+    };
+
+    let relative = Path::new(subpath);
+    if !relative
+        .components()
+        .all(|c| matches!(c, Component::Normal(_) | Component::CurDir))
+    {
+        return Err(AxisError::Other(format!(
+            "Path must stay inside the repository: {subpath}"
+        )));
+    }
+
+    let path = root.join(relative);
+    if path.is_dir() {
+        Ok(path)
+    } else if path.exists() {
+        Ok(path
+            .parent()
+            .map_or_else(|| root.to_path_buf(), Path::to_path_buf))
+    } else {
+        Err(AxisError::FileNotFound(path.display().to_string()))
+    }
+}
+
+/// Presets of the current platform that are installed, for the settings dropdown
+pub fn available_terminals() -> Vec<AvailableTerminal> {
+    let platform = TerminalPlatform::current();
+    platform
+        .terminals()
+        .iter()
+        .copied()
+        .filter(|app| is_terminal_installed(*app, platform))
+        .map(|app| AvailableTerminal {
+            app,
+            name: app.to_string(),
+        })
+        .collect()
+}
+
+/// Whether `app` is installed: an app bundle on macOS, a program on PATH elsewhere
+fn is_terminal_installed(app: TerminalApp, platform: TerminalPlatform) -> bool {
+    match (platform, app) {
+        (_, TerminalApp::Custom) => false,
+        (TerminalPlatform::MacOs, TerminalApp::Terminal)
+        | (TerminalPlatform::Windows, TerminalApp::Cmd | TerminalApp::PowerShell) => true,
+        (TerminalPlatform::MacOs, _) => mac_app_bundle(app).is_some_and(mac_app_installed),
+        _ => terminal_binary(app).is_some_and(|name| find_in_path(name, platform)),
+    }
+}
+
+fn mac_app_bundle(app: TerminalApp) -> Option<&'static str> {
+    match app {
+        TerminalApp::Terminal => Some("Terminal.app"),
+        TerminalApp::ITerm2 => Some("iTerm.app"),
+        TerminalApp::WezTerm => Some("WezTerm.app"),
+        TerminalApp::Alacritty => Some("Alacritty.app"),
+        TerminalApp::Kitty => Some("kitty.app"),
+        _ => None,
+    }
+}
+
+fn mac_app_installed(bundle: &str) -> bool {
+    let user_apps = dirs::home_dir().map(|home| home.join("Applications"));
+    [Some(PathBuf::from("/Applications")), user_apps]
+        .into_iter()
+        .flatten()
+        .any(|dir| dir.join(bundle).is_dir())
+}
+
+fn terminal_binary(app: TerminalApp) -> Option<&'static str> {
+    match app {
+        TerminalApp::WezTerm => Some("wezterm"),
+        TerminalApp::Alacritty => Some("alacritty"),
+        TerminalApp::Kitty => Some("kitty"),
+        TerminalApp::GnomeTerminal => Some("gnome-terminal"),
+        TerminalApp::Konsole => Some("konsole"),
+        TerminalApp::WindowsTerminal => Some("wt"),
+        TerminalApp::Cmd => Some("cmd"),
+        TerminalApp::PowerShell => Some("powershell"),
+        TerminalApp::Terminal | TerminalApp::ITerm2 | TerminalApp::Custom => None,
+    }
+}
+
+fn find_in_path(name: &str, platform: TerminalPlatform) -> bool {
+    let file_name = match platform {
+        TerminalPlatform::Windows => format!("{name}.exe"),
+        TerminalPlatform::MacOs | TerminalPlatform::Linux => name.to_string(),
+    };
+    std::env::split_paths(&get_extended_path()).any(|dir| dir.join(&file_name).is_file())
+}
+
+/// Arguments that run `command` in a shell and then leave an interactive shell open
+fn shell_then(platform: TerminalPlatform, command: &str) -> Vec<String> {
+    match platform {
+        TerminalPlatform::Windows => vec!["cmd".to_string(), "/k".to_string(), command.to_string()],
+        TerminalPlatform::MacOs | TerminalPlatform::Linux => vec![
+            "sh".to_string(),
+            "-c".to_string(),
+            format!("{command}; exec \"${{SHELL:-sh}}\""),
+        ],
+    }
+}
+
+/// Split a command template into words the way a shell would: whitespace separates
+/// words, and single or double quotes group them. Backslashes are kept (Windows paths)
+/// except before a double quote inside double quotes.
+fn split_command_template(template: &str) -> Result<Vec<String>> {
+    let mut words = Vec::new();
+    let mut word = String::new();
+    let mut in_word = false;
+    let mut chars = template.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '\'' | '"' => {
+                in_word = true;
+                loop {
+                    match chars.next() {
+                        Some(next) if next == c => break,
+                        Some('\\') if c == '"' && chars.peek() == Some(&'"') => {
+                            word.push('"');
+                            chars.next();
+                        }
+                        Some(next) => word.push(next),
+                        None => {
+                            return Err(AxisError::Other(format!(
+                                "Unterminated quote in terminal command: {template}"
+                            )))
+                        }
+                    }
+                }
+            }
+            c if c.is_whitespace() => {
+                if in_word {
+                    words.push(std::mem::take(&mut word));
+                    in_word = false;
+                }
+            }
+            c => {
+                in_word = true;
+                word.push(c);
+            }
+        }
+    }
+    if in_word {
+        words.push(word);
+    }
+
+    Ok(words)
+}
+
+/// POSIX shell single-quoted string
+fn sh_quote(value: &str) -> String {
+    format!("'{}'", value.replace('\'', r"'\''"))
+}
+
+/// String literal in an `osascript` script
+fn applescript_string(value: &str) -> String {
+    format!("\"{}\"", value.replace('\\', r"\\").replace('"', "\\\""))
+}
+
+/// `wt` splits its command line into subcommands at `;`, so literal ones are escaped
+fn wt_escape(value: &str) -> String {
+    value.replace(';', r"\;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn build(
+        app: TerminalApp,
+        platform: TerminalPlatform,
+        dir: &str,
+        command: Option<&str>,
+    ) -> TerminalLaunch {
+        TerminalLaunch::build(app, platform, Path::new(dir), command, None, None)
+            .expect("should build command")
+    }
+
+    // ==================== Preset Tests ====================
+
+    #[test]
+    fn test_terminal_app_open_at_path() {
+        let launch = build(
+            TerminalApp::Terminal,
+            TerminalPlatform::MacOs,
+            "/Users/me/My Repo",
+            None,
+        );
+        assert_eq!(launch.program, "open");
+        assert_eq!(launch.args, ["-a", "Terminal", "/Users/me/My Repo"]);
+    }
+
+    #[test]
+    fn test_terminal_app_runs_command_in_quoted_directory() {
+        let launch = build(
+            TerminalApp::Terminal,
+            TerminalPlatform::MacOs,
+            r#"/Users/me/it's "here""#,
+            Some("git rebase --continue"),
+        );
+        assert_eq!(launch.program, "osascript");
+        assert!(launch.args.contains(
+            &r#"do script "cd '/Users/me/it'\\''s \"here\"' && git rebase --continue""#.to_string()
+        ));
+    }
+
+    #[test]
+    fn test_iterm_uses_profile() {
+        let launch = TerminalLaunch::build(
+            TerminalApp::ITerm2,
+            TerminalPlatform::MacOs,
+            Path::new("/Users/me/My Repo"),
+            None,
+            Some("Work"),
+            None,
+        )
+        .expect("should build command");
+        assert!(launch
+            .args
+            .contains(&r#"set newWindow to (create window with profile "Work")"#.to_string()));
+        assert!(launch.args.contains(
+            &r#"tell current session of newWindow to write text "cd '/Users/me/My Repo'""#
+                .to_string()
+        ));
+    }
+
+    #[test]
+    fn test_wezterm_per_platform() {
+        let linux = build(
+            TerminalApp::WezTerm,
+            TerminalPlatform::Linux,
+            "/home/me/My Repo",
+            Some("git status"),
+        );
+        assert_eq!(linux.program, "wezterm");
+        assert_eq!(
+            linux.args,
+            [
+                "start",
+                "--cwd",
+                "/home/me/My Repo",
+                "--",
+                "sh",
+                "-c",
+                "git status; exec \"${SHELL:-sh}\""
+            ]
+        );
+
+        let mac = build(
+            TerminalApp::WezTerm,
+            TerminalPlatform::MacOs,
+            "/Users/me/My Repo",
+            None,
+        );
+        assert_eq!(mac.program, "open");
+        assert_eq!(
+            mac.args,
+            [
+                "-na",
+                "WezTerm",
+                "--args",
+                "start",
+                "--cwd",
+                "/Users/me/My Repo"
+            ]
+        );
+
+        let windows = build(
+            TerminalApp::WezTerm,
+            TerminalPlatform::Windows,
+            r"C:\My Repo",
+            Some("git status"),
+        );
+        assert_eq!(windows.program, "wezterm");
+        assert_eq!(
+            windows.args,
+            [
+                "start",
+                "--cwd",
+                r"C:\My Repo",
+                "--",
+                "cmd",
+                "/k",
+                "git status"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_alacritty_and_kitty() {
+        let alacritty = build(
+            TerminalApp::Alacritty,
+            TerminalPlatform::Linux,
+            "/home/me/My Repo",
+            None,
+        );
+        assert_eq!(alacritty.args, ["--working-directory", "/home/me/My Repo"]);
+
+        let kitty = build(
+            TerminalApp::Kitty,
+            TerminalPlatform::Linux,
+            "/home/me/My Repo",
+            Some("git log"),
+        );
+        assert_eq!(kitty.program, "kitty");
+        assert_eq!(&kitty.args[..3], ["--directory", "/home/me/My Repo", "sh"]);
+    }
+
+    #[test]
+    fn test_gnome_terminal_and_konsole_profiles() {
+        let gnome = TerminalLaunch::build(
+            TerminalApp::GnomeTerminal,
+            TerminalPlatform::Linux,
+            Path::new("/home/me/My Repo"),
+            Some("git status"),
+            Some("Dark"),
+            None,
+        )
+        .expect("should build command");
+        assert_eq!(
+            gnome.args,
+            [
+                "--working-directory=/home/me/My Repo",
+                "--profile=Dark",
+                "--",
+                "sh",
+                "-c",
+                "git status; exec \"${SHELL:-sh}\""
+            ]
+        );
+
+        let konsole = TerminalLaunch::build(
+            TerminalApp::Konsole,
+            TerminalPlatform::Linux,
+            Path::new("/home/me/My Repo"),
+            None,
+            Some("Dark"),
+            None,
+        )
+        .expect("should build command");
+        assert_eq!(
+            konsole.args,
+            ["--workdir", "/home/me/My Repo", "--profile", "Dark"]
+        );
+    }
+
+    #[test]
+    fn test_windows_terminal_escapes_semicolons() {
+        let launch = TerminalLaunch::build(
+            TerminalApp::WindowsTerminal,
+            TerminalPlatform::Windows,
+            Path::new(r"C:\Users\me\a;b repo"),
+            Some("git fetch; git status"),
+            Some("Git Bash"),
+            None,
+        )
+        .expect("should build command");
+        assert_eq!(launch.program, "wt");
+        assert_eq!(
+            launch.args,
+            [
+                "-p",
+                "Git Bash",
+                "-d",
+                r"C:\Users\me\a\;b repo",
+                "cmd",
+                "/k",
+                r"git fetch\; git status"
+            ]
+        );
+        assert!(!launch.new_console);
+    }
+
+    #[test]
+    fn test_cmd_and_powershell_open_in_their_own_console() {
+        let cmd = build(
+            TerminalApp::Cmd,
+            TerminalPlatform::Windows,
+            r"C:\My Repo",
+            Some("git status"),
+        );
+        assert_eq!(cmd.program, "cmd");
+        assert_eq!(cmd.args, ["/k", "git status"]);
+        assert_eq!(cmd.current_dir, PathBuf::from(r"C:\My Repo"));
+        assert!(cmd.new_console);
+
+        let powershell = build(
+            TerminalApp::PowerShell,
+            TerminalPlatform::Windows,
+            r"C:\My Repo",
+            None,
+        );
+        assert_eq!(powershell.args, ["-NoExit"]);
+        assert!(powershell.new_console);
+    }
+
+    #[test]
+    fn test_blank_command_opens_at_path() {
+        let launch = build(
+            TerminalApp::Cmd,
+            TerminalPlatform::Windows,
+            r"C:\r",
+            Some("  "),
+        );
+        assert!(launch.args.is_empty());
+    }
+
+    #[test]
+    fn test_terminal_from_another_platform_is_rejected() {
+        let result = TerminalLaunch::build(
+            TerminalApp::Kitty,
+            TerminalPlatform::Windows,
+            Path::new(r"C:\r"),
+            None,
+            None,
+            None,
+        );
+        assert!(result.is_err());
+    }
+
+    // ==================== Custom Template Tests ====================
+
+    #[test]
+    fn test_custom_template_substitutes_path_as_one_argument() {
+        let launch = TerminalLaunch::build(
+            TerminalApp::Custom,
+            TerminalPlatform::Linux,
+            Path::new("/home/me/My Repo"),
+            Some("git status"),
+            None,
+            Some("foot --working-directory={path} --title 'Axis shell'"),
+        )
+        .expect("should build command");
+        assert_eq!(launch.program, "foot");
+        assert_eq!(
+            launch.args,
+            [
+                "--working-directory=/home/me/My Repo",
+                "--title",
+                "Axis shell"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_custom_template_with_quoted_windows_program() {
+        let launch = TerminalLaunch::build(
+            TerminalApp::Custom,
+            TerminalPlatform::Windows,
+            Path::new(r"C:\My Repo"),
+            None,
+            None,
+            Some(r#""C:\Program Files\Git\git-bash.exe" "--cd={path}""#),
+        )
+        .expect("should build command");
+        assert_eq!(launch.program, r"C:\Program Files\Git\git-bash.exe");
+        assert_eq!(launch.args, [r"--cd=C:\My Repo"]);
+    }
+
+    #[test]
+    fn test_custom_template_errors() {
+        for template in [None, Some("  "), Some("term \"{path}")] {
+            let result = TerminalLaunch::build(
+                TerminalApp::Custom,
+                TerminalPlatform::Linux,
+                Path::new("/r"),
+                None,
+                None,
+                template,
+            );
+            assert!(result.is_err(), "{template:?} should be rejected");
+        }
+    }
+
+    #[test]
+    fn test_split_command_template() {
+        assert_eq!(
+            split_command_template(r#"a  'b c' "d \"e\"" f""g"#).expect("should split"),
+            ["a", "b c", "d \"e\"", "fg"]
+        );
+        assert_eq!(split_command_template("''").expect("should split"), [""]);
+    }
+
+    // ==================== Directory Tests ====================
+
+    #[test]
+    fn test_terminal_directory() {
+        let tmp = TempDir::new().expect("should create temp dir");
+        std::fs::create_dir_all(tmp.path().join("src/sub dir")).expect("should create dir");
+        std::fs::write(tmp.path().join("src/main.rs"), "").expect("should write file");
+
+        assert_eq!(
+            terminal_directory(tmp.path(), None).expect("should resolve"),
+            tmp.path()
+        );
+        assert_eq!(
+            terminal_directory(tmp.path(), Some("src/sub dir")).expect("should resolve"),
+            tmp.path().join("src/sub dir")
+        );
+        assert_eq!(
+            terminal_directory(tmp.path(), Some("src/main.rs")).expect("should resolve"),
+            tmp.path().join("src")
+        );
+        assert!(matches!(
+            terminal_directory(tmp.path(), Some("missing")),
+            Err(AxisError::FileNotFound(_))
+        ));
+        assert!(terminal_directory(tmp.path(), Some("../outside")).is_err());
+        assert!(terminal_directory(tmp.path(), Some("/etc")).is_err());
+    }
+}
@@ -0,0 +1,259 @@
+use crate::models::{CoAuthor, CommitTrailer, CommitTrailerOptions};
+use std::collections::HashSet;
+
+pub const SIGNED_OFF_BY: &str = "Signed-off-by";
+pub const CO_AUTHORED_BY: &str = "Co-authored-by";
+
+/// Commits scanned for co-authors to suggest
+pub const CO_AUTHOR_SCAN_DEPTH: usize = 500;
+
+/// Trailers in the last paragraph of `message`, when that paragraph is a trailer block.
+///
+/// Like git, the subject paragraph is never a trailer block and indented lines
+/// continue the previous trailer's value.
+pub fn parse_trailers(message: &str) -> Vec<CommitTrailer> {
+    let normalized = message.replace("\r\n", "\n");
+    let Some(block) = trailer_block(normalized.trim_end()) else {
+        return Vec::new();
+    };
+
+    let mut trailers: Vec<CommitTrailer> = Vec::new();
+    for line in block {
+        if line.starts_with(char::is_whitespace) {
+            if let Some(last) = trailers.last_mut() {
+                last.value.push(' ');
+                last.value.push_str(line.trim());
+            }
+        } else if let Some(trailer) = parse_trailer_line(line) {
+            trailers.push(trailer);
+        }
+    }
+    trailers
+}
+
+/// Append `trailers` to `message`, after the body.
+///
+/// New trailers join an existing trailer block or start one after a blank line.
+/// Trailers already present (same key, ignoring case, and value) are not repeated.
+/// CRLF line endings and the presence of a final newline are preserved.
+pub fn append_trailers(message: &str, trailers: &[CommitTrailer]) -> String {
+    let crlf = message.contains("\r\n");
+    let normalized = message.replace("\r\n", "\n");
+    let body = normalized.trim_end();
+
+    let mut seen: HashSet<(String, String)> =
+        parse_trailers(body).iter().map(trailer_identity).collect();
+    let new: Vec<&CommitTrailer> = trailers
+        .iter()
+        .filter(|t| seen.insert(trailer_identity(t)))
+        .collect();
+    if new.is_empty() {
+        return message.to_string();
+    }
+
+    let mut out = body.to_string();
+    if trailer_block(body).is_some() {
+        out.push('\n');
+    } else if !body.is_empty() {
+        out.push_str("\n\n");
+    }
+    let lines: Vec<String> = new
+        .iter()
+        .map(|t| format!("{}: {}", t.key, t.value))
+        .collect();
+    out.push_str(&lines.join("\n"));
+    if normalized.ends_with('\n') {
+        out.push('\n');
+    }
+
+    if crlf {
+        out.replace('\n', "\r\n")
+    } else {
+        out
+    }
+}
+
+/// Trailers requested by `options`: custom trailers, then co-authors, then the
+/// sign-off for `committer` (name, email)
+pub fn resolve_trailers(
+    options: &CommitTrailerOptions,
+    committer: &(String, String),
+) -> Vec<CommitTrailer> {
+    let mut trailers = options.trailers.clone();
+    trailers.extend(options.co_authors.iter().map(|co| CommitTrailer {
+        key: CO_AUTHORED_BY.to_string(),
+        value: format!("{} <{}>", co.name, co.email),
+    }));
+    if options.sign_off {
+        trailers.push(CommitTrailer {
+            key: SIGNED_OFF_BY.to_string(),
+            value: format!("{} <{}>", committer.0, committer.1),
+        });
+    }
+    trailers
+}
+
+/// Co-authors credited in `message`
+pub fn co_authors_from_message(message: &str) -> Vec<CoAuthor> {
+    parse_trailers(message)
+        .iter()
+        .filter(|t| t.key.eq_ignore_ascii_case(CO_AUTHORED_BY))
+        .filter_map(|t| parse_identity(&t.value))
+        .collect()
+}
+
+/// Split `Name <email>` into a co-author
+fn parse_identity(value: &str) -> Option<CoAuthor> {
+    let (name, rest) = value.split_once('<')?;
+    let email = rest.strip_suffix('>')?.trim();
+    let name = name.trim();
+    if name.is_empty() || email.is_empty() {
+        return None;
+    }
+    Some(CoAuthor {
+        name: name.to_string(),
+        email: email.to_string(),
+    })
+}
+
+/// Number of lines in the trailer block at the end of `message`; 0 when it has none
+pub fn trailer_line_count(message: &str) -> usize {
+    let normalized = message.replace("\r\n", "\n");
+    trailer_block(normalized.trim_end()).map_or(0, |block| block.len())
+}
+
+/// Lines of the last paragraph of `body` if they form a trailer block
+fn trailer_block(body: &str) -> Option<Vec<&str>> {
+    let lines: Vec<&str> = body.lines().collect();
+    let start = lines.iter().rposition(|line| line.trim().is_empty())? + 1;
+    let block = &lines[start..];
+
+    let first_is_trailer = block
+        .first()
+        .is_some_and(|line| parse_trailer_line(line).is_some());
+    let all_trailers = block
+        .iter()
+        .all(|line| line.starts_with(char::is_whitespace) || parse_trailer_line(line).is_some());
+    (first_is_trailer && all_trailers).then(|| block.to_vec())
+}
+
+/// Parse a `Key: value` line; keys are letters, digits and dashes
+fn parse_trailer_line(line: &str) -> Option<CommitTrailer> {
+    let (key, value) = line.split_once(':')?;
+    let key = key.trim_end();
+    if key.is_empty() || !key.chars().all(|c| c.is_ascii_alphanumeric() || c == '-') {
+        return None;
+    }
+    Some(CommitTrailer {
+        key: key.to_string(),
+        value: value.trim().to_string(),
+    })
+}
+
+fn trailer_identity(trailer: &CommitTrailer) -> (String, String) {
+    (trailer.key.to_lowercase(), trailer.value.trim().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn trailer(key: &str, value: &str) -> CommitTrailer {
+        CommitTrailer {
+            key: key.to_string(),
+            value: value.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_append_trailers_after_body() {
+        let message = "Fix parser\n\nFirst paragraph.\n\nSecond paragraph: still prose.\n";
+        let out = append_trailers(message, &[trailer("Signed-off-by", "A <a@x.io>")]);
+        assert_eq!(
+            out,
+            "Fix parser\n\nFirst paragraph.\n\nSecond paragraph: still prose.\n\nSigned-off-by: A <a@x.io>\n"
+        );
+
+        // The subject alone is never a trailer block
+        let out = append_trailers("Fixes: typo", &[trailer("Refs", "#12")]);
+        assert_eq!(out, "Fixes: typo\n\nRefs: #12");
+    }
+
+    #[test]
+    fn test_append_trailers_joins_existing_block_without_duplicates() {
+        let message =
+            "Add feature\n\nBody.\n\nCo-authored-by: B <b@x.io>\nReviewed-by: C <c@x.io>\n";
+        let out = append_trailers(
+            message,
+            &[
+                trailer("co-authored-by", "B <b@x.io>"),
+                trailer("Signed-off-by", "A <a@x.io>"),
+                trailer("Signed-off-by", "A <a@x.io>"),
+            ],
+        );
+        assert_eq!(
+            out,
+            "Add feature\n\nBody.\n\nCo-authored-by: B <b@x.io>\nReviewed-by: C <c@x.io>\nSigned-off-by: A <a@x.io>\n"
+        );
+
+        // Nothing new leaves the message untouched
+        assert_eq!(
+            append_trailers(message, &[trailer("Reviewed-by", "C <c@x.io>")]),
+            message
+        );
+    }
+
+    #[test]
+    fn test_append_trailers_preserves_crlf() {
+        let message = "Subject\r\n\r\nBody line\r\n\r\nRefs: #1\r\n";
+        let out = append_trailers(message, &[trailer("Signed-off-by", "A <a@x.io>")]);
+        assert_eq!(
+            out,
+            "Subject\r\n\r\nBody line\r\n\r\nRefs: #1\r\nSigned-off-by: A <a@x.io>\r\n"
+        );
+        assert_eq!(parse_trailers(&out).len(), 2);
+    }
+
+    #[test]
+    fn test_parse_trailers_continuation_and_prose() {
+        let message = "Subject\n\nBody\n\nCo-authored-by: Jane Doe\n <jane@x.io>\nRefs: #3\n";
+        assert_eq!(
+            parse_trailers(message),
+            vec![
+                trailer("Co-authored-by", "Jane Doe <jane@x.io>"),
+                trailer("Refs", "#3")
+            ]
+        );
+        assert_eq!(
+            co_authors_from_message(message),
+            vec![CoAuthor {
+                name: "Jane Doe".to_string(),
+                email: "jane@x.io".to_string()
+            }]
+        );
+
+        // A last paragraph mixing prose and trailers is not a trailer block
+        assert!(parse_trailers("Subject\n\nSee below\nRefs: #3\n").is_empty());
+    }
+
+    #[test]
+    fn test_resolve_trailers_order() {
+        let options = CommitTrailerOptions {
+            sign_off: true,
+            co_authors: vec![CoAuthor {
+                name: "B".to_string(),
+                email: "b@x.io".to_string(),
+            }],
+            trailers: vec![trailer("Refs", "#9")],
+        };
+        let resolved = resolve_trailers(&options, &("A".to_string(), "a@x.io".to_string()));
+        assert_eq!(
+            resolved,
+            vec![
+                trailer("Refs", "#9"),
+                trailer("Co-authored-by", "B <b@x.io>"),
+                trailer("Signed-off-by", "A <a@x.io>"),
+            ]
+        );
+    }
+}
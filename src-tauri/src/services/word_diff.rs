@@ -0,0 +1,302 @@
+use crate::models::{DiffHunk, DiffLineType, FileDiff, InlineSpan};
+
+/// Lines longer than this (in characters) get no intra-line highlighting
+pub const WORD_DIFF_MAX_LINE_CHARS: usize = 10_000;
+
+/// Share of characters two paired lines must have in common to be highlighted;
+/// below it the lines are treated as rewritten rather than edited
+const WORD_DIFF_MIN_SIMILARITY: f64 = 0.4;
+
+/// Largest token-by-token table computed for one line pair
+const WORD_DIFF_MAX_CELLS: usize = 4_000_000;
+
+/// A word, a run of whitespace, or a single punctuation character
+#[derive(Debug, Clone, Copy)]
+struct Token<'a> {
+    text: &'a str,
+    /// Offset in characters from the start of the line
+    start: usize,
+    /// Length in characters
+    len: usize,
+}
+
+/// Fill `inline_spans` for the changed lines of every hunk in `files`
+pub fn annotate_inline_spans(files: &mut [FileDiff]) {
+    for hunk in files.iter_mut().flat_map(|file| file.hunks.iter_mut()) {
+        annotate_hunk(hunk);
+    }
+}
+
+/// Pair each run of deletions with the additions that follow it, line by line,
+/// and highlight the pairs that are similar enough
+fn annotate_hunk(hunk: &mut DiffHunk) {
+    let lines = &mut hunk.lines;
+    let mut i = 0;
+    while i < lines.len() {
+        if lines[i].line_type != DiffLineType::Deletion {
+            i += 1;
+            continue;
+        }
+        let deletions_start = i;
+        while i < lines.len() && lines[i].line_type == DiffLineType::Deletion {
+            i += 1;
+        }
+        let additions_start = i;
+        while i < lines.len() && lines[i].line_type == DiffLineType::Addition {
+            i += 1;
+        }
+
+        let pairs = (additions_start - deletions_start).min(i - additions_start);
+        for offset in 0..pairs {
+            let (old_idx, new_idx) = (deletions_start + offset, additions_start + offset);
+            if let Some((old_spans, new_spans)) =
+                inline_spans(&lines[old_idx].content, &lines[new_idx].content)
+            {
+                lines[old_idx].inline_spans = Some(old_spans);
+                lines[new_idx].inline_spans = Some(new_spans);
+            }
+        }
+    }
+}
+
+/// Spans covering `old` and `new`, marking the tokens that differ. `None` when
+/// either line is too long or the lines are too different to be worth highlighting.
+pub fn inline_spans(old: &str, new: &str) -> Option<(Vec<InlineSpan>, Vec<InlineSpan>)> {
+    let old_chars = old.chars().count();
+    let new_chars = new.chars().count();
+    if old_chars > WORD_DIFF_MAX_LINE_CHARS || new_chars > WORD_DIFF_MAX_LINE_CHARS {
+        return None;
+    }
+
+    let old_tokens = tokenize(old);
+    let new_tokens = tokenize(new);
+    if (old_tokens.len() + 1).saturating_mul(new_tokens.len() + 1) > WORD_DIFF_MAX_CELLS {
+        return None;
+    }
+
+    let (old_kept, new_kept) = common_tokens(&old_tokens, &new_tokens);
+    let kept_chars: usize = old_tokens
+        .iter()
+        .zip(&old_kept)
+        .filter(|(_, kept)| **kept)
+        .map(|(token, _)| token.len)
+        .sum();
+    let total = old_chars + new_chars;
+    if total == 0 {
+        return None;
+    }
+    #[allow(clippy::cast_precision_loss)]
+    let similarity = (2 * kept_chars) as f64 / total as f64;
+    if similarity < WORD_DIFF_MIN_SIMILARITY || kept_chars * 2 == total {
+        // Too different to highlight, or identical apart from line endings
+        return None;
+    }
+
+    Some((
+        merge_spans(&old_tokens, &old_kept),
+        merge_spans(&new_tokens, &new_kept),
+    ))
+}
+
+fn tokenize(line: &str) -> Vec<Token<'_>> {
+    #[derive(PartialEq, Clone, Copy)]
+    enum Class {
+        Word,
+        Space,
+        Other,
+    }
+    let class = |c: char| {
+        if c.is_alphanumeric() || c == '_' {
+            Class::Word
+        } else if c.is_whitespace() {
+            Class::Space
+        } else {
+            Class::Other
+        }
+    };
+
+    let mut tokens: Vec<Token<'_>> = Vec::new();
+    let mut current: Option<(Class, usize, usize)> = None; // class, byte start, char start
+    for (char_idx, (byte_idx, c)) in line.char_indices().enumerate() {
+        let cls = class(c);
+        match current {
+            Some((prev, _, _)) if prev == cls && cls != Class::Other => {}
+            Some((_, byte_start, char_start)) => {
+                tokens.push(Token {
+                    text: &line[byte_start..byte_idx],
+                    start: char_start,
+                    len: char_idx - char_start,
+                });
+                current = Some((cls, byte_idx, char_idx));
+            }
+            None => current = Some((cls, byte_idx, char_idx)),
+        }
+    }
+    if let Some((_, byte_start, char_start)) = current {
+        let text = &line[byte_start..];
+        tokens.push(Token {
+            text,
+            start: char_start,
+            len: text.chars().count(),
+        });
+    }
+    tokens
+}
+
+/// Which tokens on each side belong to a longest common subsequence
+fn common_tokens(old: &[Token<'_>], new: &[Token<'_>]) -> (Vec<bool>, Vec<bool>) {
+    let width = new.len() + 1;
+    // lengths[i * width + j]: LCS length of old[i..] and new[j..]
+    let mut lengths = vec![0u32; (old.len() + 1) * width];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            lengths[i * width + j] = if old[i].text == new[j].text {
+                lengths[(i + 1) * width + j + 1] + 1
+            } else {
+                lengths[(i + 1) * width + j].max(lengths[i * width + j + 1])
+            };
+        }
+    }
+
+    let mut old_kept = vec![false; old.len()];
+    let mut new_kept = vec![false; new.len()];
+    let (mut i, mut j) = (0, 0);
+    while i < old.len() && j < new.len() {
+        if old[i].text == new[j].text {
+            old_kept[i] = true;
+            new_kept[j] = true;
+            i += 1;
+            j += 1;
+        } else if lengths[(i + 1) * width + j] >= lengths[i * width + j + 1] {
+            i += 1;
+        } else {
+            j += 1;
+        }
+    }
+    (old_kept, new_kept)
+}
+
+/// Join neighbouring tokens with the same state into spans
+fn merge_spans(tokens: &[Token<'_>], kept: &[bool]) -> Vec<InlineSpan> {
+    let mut spans: Vec<InlineSpan> = Vec::new();
+    for (token, kept) in tokens.iter().zip(kept) {
+        let changed = !kept;
+        let len = u32::try_from(token.len).unwrap_or(u32::MAX);
+        match spans.last_mut() {
+            Some(last) if last.changed == changed => last.len += len,
+            _ => spans.push(InlineSpan {
+                start: u32::try_from(token.start).unwrap_or(u32::MAX),
+                len,
+                changed,
+            }),
+        }
+    }
+    spans
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::DiffLine;
+
+    fn span(start: u32, len: u32, changed: bool) -> InlineSpan {
+        InlineSpan {
+            start,
+            len,
+            changed,
+        }
+    }
+
+    fn line(line_type: DiffLineType, content: &str) -> DiffLine {
+        DiffLine {
+            line_type,
+            content: content.to_string(),
+            old_line_no: None,
+            new_line_no: None,
+            inline_spans: None,
+        }
+    }
+
+    #[test]
+    fn test_inline_spans_modified_line() {
+        let (old, new) =
+            inline_spans("let count = 1;", "let total = 1;").expect("should highlight");
+
+        assert_eq!(
+            old,
+            vec![span(0, 4, false), span(4, 5, true), span(9, 5, false)]
+        );
+        assert_eq!(
+            new,
+            vec![span(0, 4, false), span(4, 5, true), span(9, 5, false)]
+        );
+    }
+
+    #[test]
+    fn test_inline_spans_insertion_only_marks_new_side() {
+        let (old, new) = inline_spans("call(a)", "call(a, b)").expect("should highlight");
+
+        assert_eq!(old, vec![span(0, 7, false)]);
+        assert_eq!(
+            new,
+            vec![span(0, 6, false), span(6, 3, true), span(9, 1, false)]
+        );
+    }
+
+    #[test]
+    fn test_inline_spans_fully_rewritten_line() {
+        assert!(inline_spans("return cache.get(key);", "panic!(\"unreachable\")").is_none());
+        assert!(inline_spans("same", "same").is_none());
+    }
+
+    #[test]
+    fn test_inline_spans_multibyte_offsets_are_characters() {
+        let (old, new) =
+            inline_spans("größe = \"äpfel\"", "größe = \"birnen\"").expect("should highlight");
+
+        // "größe = \"" is 9 characters but 11 bytes
+        assert_eq!(
+            old,
+            vec![span(0, 9, false), span(9, 5, true), span(14, 1, false)]
+        );
+        assert_eq!(
+            new,
+            vec![span(0, 9, false), span(9, 6, true), span(15, 1, false)]
+        );
+    }
+
+    #[test]
+    fn test_inline_spans_skips_long_lines() {
+        let long = "a ".repeat(WORD_DIFF_MAX_LINE_CHARS);
+        assert!(inline_spans(&long, &format!("{long}b")).is_none());
+    }
+
+    #[test]
+    fn test_annotate_hunk_pairs_runs_in_order() {
+        let mut hunk = DiffHunk {
+            header: "@@ -1,3 +1,3 @@".to_string(),
+            old_start: 1,
+            old_lines: 3,
+            new_start: 1,
+            new_lines: 3,
+            lines: vec![
+                line(DiffLineType::Context, "fn main() {"),
+                line(DiffLineType::Deletion, "    let x = 1;"),
+                line(DiffLineType::Deletion, "    let y = 2;"),
+                line(DiffLineType::Addition, "    let x = 10;"),
+                line(DiffLineType::Addition, "    completely different"),
+                line(DiffLineType::Addition, "    extra();"),
+            ],
+            context: None,
+        };
+
+        annotate_hunk(&mut hunk);
+
+        let highlighted: Vec<bool> = hunk
+            .lines
+            .iter()
+            .map(|l| l.inline_spans.is_some())
+            .collect();
+        assert_eq!(highlighted, vec![false, true, false, true, false, false]);
+    }
+}
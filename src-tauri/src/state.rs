@@ -1,19 +1,34 @@
 use crate::error::{AxisError, Result};
-use crate::models::{AppSettings, ListRemoteOptions, Repository, SshCredentials};
+use crate::events::CurrentBranchPullRequestEvent;
+use crate::models::{
+    AiProvider, AppSettings, BackgroundFetchConfig, BackgroundFetchStatus, CherryPickQueue,
+    DetectedProvider, ListRemoteOptions, OperationCategory, OperationOutcome, PerformanceConfig,
+    PerformanceProfile, PerformanceReadiness, PerformanceSettings, ProtectedBranchAction,
+    RemoteBranchInfo, Repository, RepositorySizeSignals, SshCredentials, UndoOperation,
+    UndoSnapshot, DEFAULT_AI_CONCURRENT_REQUESTS,
+};
+use crate::services::ai::{
+    create_provider, AiProviderTrait, AiRequestLimiter, AiUsageStore, ExplainCache, TrackedProvider,
+};
 use crate::services::ops::RepoOperations;
 use crate::services::{
-    AvatarService, BackgroundFetchService, CommitCache, GitService, IntegrationService,
-    ProgressRegistry, SignatureVerificationCache, SshKeyService,
+    measure_size_signals, resolve_performance, ArtifactOwner, AvatarService,
+    BackgroundFetchService, CommitCache, CommitMessageStatsCache, GitService, HookTrustStore,
+    HostCredentialStore, IdentifierCorpusCache, IntegrationProvider, IntegrationService, Janitor,
+    ProgressRegistry, SignatureVerificationCache, SpellDictionaryCache, SshKeyService,
+    TrackedArtifact, TtlCache, JANITOR_SWEEP_INTERVAL,
 };
 use crate::storage::Database;
-use crate::storage::RecentRepositoryRow;
+use crate::storage::{OperationLogRecord, RecentRepositoryRow};
 use parking_lot::{Mutex, RwLock};
 use secrecy::SecretString;
 use std::collections::HashMap;
 use std::ops::Deref;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
+use std::time::Duration;
 use tauri::{AppHandle, Manager};
+use tauri_specta::Event;
 
 /// Wrapper that holds an `Arc<GitService>` and a shared `RwLock` for read/write coordination.
 ///
@@ -73,6 +88,16 @@ impl GitServiceHandle {
         }
     }
 
+    /// Acquire shared read access without waiting; `None` while a writer holds or
+    /// is waiting for the lock
+    pub fn try_read(&self) -> Option<RepoReadGuard<'_>> {
+        let guard = self.rw_lock.try_read().ok()?;
+        Some(RepoReadGuard {
+            _guard: guard,
+            ops: RepoOperations::new(self.service.clone()),
+        })
+    }
+
     /// Acquire exclusive write access (blocks all other access)
     pub async fn write(&self) -> RepoWriteGuard<'_> {
         let guard = self.rw_lock.write().await;
@@ -113,6 +138,8 @@ impl RepositoryCache {
         path: &Path,
         app_handle: &AppHandle,
         is_active: bool,
+        hook_trust: Arc<dyn HookTrustStore>,
+        host_credentials: Arc<dyn HostCredentialStore>,
     ) -> Result<GitServiceHandle> {
         // Check if already cached
         {
@@ -123,7 +150,13 @@ impl RepositoryCache {
         }
 
         // Open and cache
-        let service = GitService::open(path, app_handle.clone(), is_active)?;
+        let service = GitService::open(
+            path,
+            app_handle.clone(),
+            is_active,
+            hook_trust,
+            host_credentials,
+        )?;
         let handle = GitServiceHandle::new(service);
 
         let mut repos = self.repos.write();
@@ -175,21 +208,38 @@ impl Default for RepositoryCache {
     }
 }
 
+/// How long a remote's branch listing is reused before connecting again
+const REMOTE_BRANCHES_TTL: Duration = Duration::from_secs(30);
+
 pub struct AppState {
     active_repository_path: RwLock<Option<PathBuf>>,
     repository_cache: Arc<RepositoryCache>,
     commit_cache: Arc<CommitCache>,
     signature_verification_cache: Arc<SignatureVerificationCache>,
+    explain_cache: Arc<ExplainCache>,
+    /// Limits concurrent AI provider requests and queues the rest
+    ai_limiter: Arc<AiRequestLimiter>,
+    commit_message_stats_cache: Arc<CommitMessageStatsCache>,
+    identifier_corpus_cache: Arc<IdentifierCorpusCache>,
+    spell_dictionaries: Arc<SpellDictionaryCache>,
     database: Arc<Database>,
     app_handle: RwLock<Option<AppHandle>>,
     background_fetch: BackgroundFetchService,
     avatar_service: RwLock<Option<Arc<AvatarService>>>,
+    /// Cleans up transient artifacts; needs the app data dir, so set with the app handle
+    janitor: RwLock<Option<Arc<Janitor>>>,
     integration_service: RwLock<Option<Arc<IntegrationService>>>,
     progress_registry: Arc<ProgressRegistry>,
     /// In-memory cache for SSH key passphrases (`SecretString` zeroes memory on drop)
     ssh_passphrase_cache: RwLock<HashMap<String, SecretString>>,
+    /// Cherry-picks waiting on conflict resolution, per repository path
+    cherry_pick_queues: Mutex<HashMap<PathBuf, CherryPickQueue>>,
     /// Pending update ready to download & install
     pending_update: Mutex<Option<tauri_plugin_updater::Update>>,
+    /// Size measurements of repositories with an `Auto` profile, taken when opened
+    size_signals: RwLock<HashMap<PathBuf, RepositorySizeSignals>>,
+    /// Branches listed from remotes, keyed by "`repo_path:remote_name`"
+    remote_branches_cache: TtlCache<Vec<RemoteBranchInfo>>,
 }
 
 impl AppState {
@@ -202,22 +252,34 @@ impl AppState {
             repository_cache: Arc::new(RepositoryCache::new()),
             commit_cache: Arc::new(CommitCache::new()),
             signature_verification_cache: Arc::new(SignatureVerificationCache::new()),
+            explain_cache: Arc::new(ExplainCache::new()),
+            ai_limiter: Arc::new(AiRequestLimiter::new(DEFAULT_AI_CONCURRENT_REQUESTS)),
+            commit_message_stats_cache: Arc::new(CommitMessageStatsCache::new()),
+            identifier_corpus_cache: Arc::new(IdentifierCorpusCache::new()),
+            spell_dictionaries: Arc::new(SpellDictionaryCache::new()),
             database,
             app_handle: RwLock::new(None),
             background_fetch: BackgroundFetchService::new(),
             avatar_service: RwLock::new(None),
+            janitor: RwLock::new(None),
             integration_service: RwLock::new(Some(Arc::new(integration_service))),
             progress_registry: Arc::new(ProgressRegistry::new()),
             ssh_passphrase_cache: RwLock::new(HashMap::new()),
+            cherry_pick_queues: Mutex::new(HashMap::new()),
             pending_update: Mutex::new(None),
+            size_signals: RwLock::new(HashMap::new()),
+            remote_branches_cache: TtlCache::new(REMOTE_BRANCHES_TTL),
         }
     }
 
     /// Set the app handle (must be called after Tauri setup)
     pub fn set_app_handle(&self, app_handle: AppHandle) {
-        // Initialize avatar service with app data dir
+        // Initialize the janitor and avatar service with app data dir
         if let Ok(app_data_dir) = app_handle.path().app_data_dir() {
-            let avatar_service = AvatarService::new(&app_data_dir);
+            let janitor = Arc::new(Janitor::new(Arc::clone(&self.database), &app_data_dir));
+            let avatar_service =
+                AvatarService::new(&app_data_dir).with_janitor(Arc::clone(&janitor));
+            *self.janitor.write() = Some(janitor);
             *self.avatar_service.write() = Some(Arc::new(avatar_service));
         }
 
@@ -247,6 +309,41 @@ impl AppState {
         Arc::clone(&self.signature_verification_cache)
     }
 
+    /// Cache of branches listed from remotes, see `REMOTE_BRANCHES_TTL`
+    pub fn remote_branches_cache(&self) -> &TtlCache<Vec<RemoteBranchInfo>> {
+        &self.remote_branches_cache
+    }
+
+    pub fn explain_cache(&self) -> Arc<ExplainCache> {
+        Arc::clone(&self.explain_cache)
+    }
+
+    pub fn ai_limiter(&self) -> Arc<AiRequestLimiter> {
+        Arc::clone(&self.ai_limiter)
+    }
+
+    /// Provider whose requests go through the AI request limiter and ledger
+    pub fn ai_provider(&self, provider: &AiProvider) -> Box<dyn AiProviderTrait> {
+        Box::new(TrackedProvider::new(
+            create_provider(provider),
+            provider.clone(),
+            Arc::clone(&self.ai_limiter),
+            Arc::clone(&self.database) as Arc<dyn AiUsageStore>,
+        ))
+    }
+
+    pub fn commit_message_stats_cache(&self) -> Arc<CommitMessageStatsCache> {
+        Arc::clone(&self.commit_message_stats_cache)
+    }
+
+    pub fn identifier_corpus_cache(&self) -> Arc<IdentifierCorpusCache> {
+        Arc::clone(&self.identifier_corpus_cache)
+    }
+
+    pub fn spell_dictionaries(&self) -> Arc<SpellDictionaryCache> {
+        Arc::clone(&self.spell_dictionaries)
+    }
+
     /// Get the avatar service
     pub fn avatar_service(&self) -> Result<Arc<AvatarService>> {
         self.avatar_service
@@ -255,6 +352,54 @@ impl AppState {
             .ok_or_else(|| AxisError::Other("Avatar service not initialized".to_string()))
     }
 
+    /// Get the janitor that tracks transient artifacts
+    pub fn janitor(&self) -> Result<Arc<Janitor>> {
+        self.janitor
+            .read()
+            .clone()
+            .ok_or_else(|| AxisError::Other("Janitor not initialized".to_string()))
+    }
+
+    /// Register an artifact this run just created, until the owning operation ends.
+    /// Tracking is best effort: failures are logged and the operation goes on untracked.
+    pub fn track_artifact(&self, path: &Path, owner: ArtifactOwner) -> Option<TrackedArtifact> {
+        let tracked = self
+            .janitor()
+            .and_then(|janitor| janitor.track(path, owner, None));
+        match tracked {
+            Ok(tracked) => Some(tracked),
+            Err(e) => {
+                log::warn!("Failed to track {owner} artifact {}: {e}", path.display());
+                None
+            }
+        }
+    }
+
+    /// Sweep artifacts left by earlier runs now, then every `JANITOR_SWEEP_INTERVAL`
+    pub fn start_janitor(&self) -> Result<()> {
+        let janitor = self.janitor()?;
+        tauri::async_runtime::spawn(async move {
+            let mut interval = tokio::time::interval(JANITOR_SWEEP_INTERVAL);
+            loop {
+                interval.tick().await;
+                let janitor = Arc::clone(&janitor);
+                match tauri::async_runtime::spawn_blocking(move || janitor.sweep()).await {
+                    Ok(Ok(report)) if !report.refused.is_empty() => log::warn!(
+                        "Janitor reclaimed {} artifacts and refused {}",
+                        report.reclaimed.len(),
+                        report.refused.len()
+                    ),
+                    Ok(Ok(report)) => {
+                        log::debug!("Janitor reclaimed {} artifacts", report.reclaimed.len())
+                    }
+                    Ok(Err(e)) => log::warn!("Janitor sweep failed: {e}"),
+                    Err(e) => log::warn!("Janitor sweep task failed: {e}"),
+                }
+            }
+        });
+        Ok(())
+    }
+
     /// Get the integration service
     pub fn integration_service(&self) -> Result<Arc<IntegrationService>> {
         self.integration_service
@@ -263,6 +408,119 @@ impl AppState {
             .ok_or_else(|| AxisError::Other("Integration service not initialized".to_string()))
     }
 
+    /// Warn before a force push or deletion that the remote's branch protection forbids.
+    /// Skipped when there is nothing to check or the check is overridden, so plain pushes
+    /// never wait on the provider.
+    pub async fn check_branch_protection(
+        &self,
+        remote_name: &str,
+        targets: &[(String, ProtectedBranchAction)],
+        override_check: bool,
+    ) -> Result<()> {
+        if targets.is_empty() || override_check {
+            return Ok(());
+        }
+        let remote_url = self
+            .get_git_service()?
+            .read()
+            .await
+            .get_remote(remote_name)
+            .await
+            .ok()
+            .and_then(|r| r.url);
+        let (Some(remote_url), Ok(service)) = (remote_url, self.integration_service()) else {
+            return Ok(());
+        };
+        service
+            .check_branch_protection(&remote_url, targets, override_check)
+            .await
+    }
+
+    /// After a successful push, refresh the provider's pull requests for the pushed branches
+    /// in the background. Failures are only logged since the push itself already succeeded.
+    pub fn refresh_pull_requests_after_push(&self, remote_name: &str, branches: Vec<String>) {
+        if branches.is_empty() {
+            return;
+        }
+        let (Ok(git_service), Ok(service), Ok(app_handle)) = (
+            self.get_git_service(),
+            self.integration_service(),
+            self.get_app_handle(),
+        ) else {
+            return;
+        };
+        let remote_name = remote_name.to_string();
+
+        tauri::async_runtime::spawn(async move {
+            let remotes = match git_service
+                .read()
+                .await
+                .list_remotes(ListRemoteOptions::default())
+                .await
+            {
+                Ok(remotes) => remotes,
+                Err(e) => {
+                    log::warn!("Pull request refresh: failed to list remotes: {e}");
+                    return;
+                }
+            };
+            let Some(pushed_url) = remotes
+                .iter()
+                .find(|r| r.name == remote_name)
+                .and_then(|r| r.url.clone())
+            else {
+                return;
+            };
+            let remote_urls: Vec<String> = remotes.into_iter().filter_map(|r| r.url).collect();
+
+            if let Err(e) = service
+                .refresh_pull_requests_after_push(&app_handle, &pushed_url, &remote_urls, &branches)
+                .await
+            {
+                log::warn!("Failed to refresh pull requests after push to {remote_name}: {e}");
+            }
+        });
+    }
+
+    /// After HEAD moves in `repo_path`, re-match the checked-out branch to its pull request once
+    /// things settle and emit `CurrentBranchPullRequestEvent`. Nothing is emitted when no
+    /// provider is connected; failures are only logged.
+    pub fn refresh_current_branch_pr(&self, repo_path: &Path) {
+        let (Some(git_service), Ok(service), Ok(app_handle)) = (
+            self.repository_cache.get(repo_path),
+            self.integration_service(),
+            self.get_app_handle(),
+        ) else {
+            return;
+        };
+        let repo_path = repo_path.to_path_buf();
+
+        tauri::async_runtime::spawn(async move {
+            if !service.settle_head_change(&repo_path).await {
+                return;
+            }
+            let guard = git_service.read().await;
+            let result = service.current_branch_pull_request(&guard).await;
+            drop(guard);
+            match result {
+                Ok(Some(current)) => {
+                    let event = CurrentBranchPullRequestEvent {
+                        path: repo_path.to_string_lossy().to_string(),
+                        current,
+                    };
+                    if let Err(e) = event.emit(&app_handle) {
+                        log::warn!("Failed to emit current branch pull request: {e}");
+                    }
+                }
+                Ok(None) => {}
+                Err(e) => log::warn!(
+                    "Failed to match pull request for current branch in {}: {e}",
+                    repo_path.display()
+                ),
+            }
+        });
+    }
+
     /// Get the progress registry for operation cancellation
     pub fn progress_registry(&self) -> Arc<ProgressRegistry> {
         self.progress_registry.clone()
@@ -272,8 +530,25 @@ impl AppState {
     pub async fn switch_active_repository(&self, path: &Path) -> Result<Repository> {
         let app_handle = self.get_app_handle()?;
 
+        // Settle an `Auto` profile before the file watcher starts and reads its debounce
+        match self.performance_readiness(path) {
+            Ok(readiness) => log::debug!(
+                "Repository {} uses the {:?} profile: {}",
+                path.display(),
+                readiness.effective_profile,
+                readiness.reason
+            ),
+            Err(e) => log::warn!("Failed to resolve performance profile: {e}"),
+        }
+
         // Ensure the repo is cached
-        let handle = self.repository_cache.get_or_open(path, &app_handle, true)?;
+        let handle = self.repository_cache.get_or_open(
+            path,
+            &app_handle,
+            true,
+            self.hook_trust_store(),
+            self.host_credential_store(),
+        )?;
 
         // Update active flags
         self.repository_cache.set_active(path);
@@ -299,6 +574,8 @@ impl AppState {
         self.repository_cache.remove(path);
         self.commit_cache.invalidate_repo(path);
         self.signature_verification_cache.invalidate_repo(path);
+        // Measured again on the next open
+        self.size_signals.write().remove(path);
 
         // Clear active if this was it
         let mut active = self.active_repository_path.write();
@@ -316,7 +593,13 @@ impl AppState {
     pub fn get_git_service(&self) -> Result<GitServiceHandle> {
         let path = self.ensure_repository_open()?;
         let app_handle = self.get_app_handle()?;
-        self.repository_cache.get_or_open(&path, &app_handle, true)
+        self.repository_cache.get_or_open(
+            &path,
+            &app_handle,
+            true,
+            self.hook_trust_store(),
+            self.host_credential_store(),
+        )
     }
 
     pub fn add_recent_repository(&self, path: &Path, name: &str) -> Result<()> {
@@ -327,10 +610,33 @@ impl AppState {
         self.database.get_recent_repositories()
     }
 
+    pub fn search_recent_repositories(
+        &self,
+        query: &str,
+        limit: usize,
+    ) -> Result<Vec<RecentRepositoryRow>> {
+        self.database.search_recent_repositories(query, limit)
+    }
+
     pub fn remove_recent_repository(&self, path: &Path) -> Result<()> {
         self.database.remove_recent_repository(path)
     }
 
+    pub fn set_recent_repository_branch(&self, path: &Path, branch: Option<&str>) -> Result<()> {
+        self.database.set_recent_repository_branch(path, branch)
+    }
+
+    /// Remember the branch checked out in a cached repository before it is closed
+    pub async fn remember_last_branch(&self, path: &Path) {
+        let Some(handle) = self.repository_cache.get(path) else {
+            return;
+        };
+        let branch = handle.read().await.get_current_branch().await;
+        if let Err(e) = self.set_recent_repository_branch(path, branch.as_deref()) {
+            log::warn!("Failed to remember branch of {}: {e}", path.display());
+        }
+    }
+
     pub fn pin_repository(&self, path: &Path) -> Result<()> {
         self.database.pin_repository(path)
     }
@@ -339,6 +645,15 @@ impl AppState {
         self.database.unpin_repository(path)
     }
 
+    pub fn is_sync_folder_acknowledged(&self, path: &Path) -> Result<bool> {
+        self.database.is_sync_folder_acknowledged(path)
+    }
+
+    pub fn set_sync_folder_acknowledged(&self, path: &Path, acknowledged: bool) -> Result<()> {
+        self.database
+            .set_sync_folder_acknowledged(path, acknowledged)
+    }
+
     pub fn get_settings(&self) -> Result<AppSettings> {
         self.database.get_settings()
     }
@@ -364,10 +679,10 @@ impl AppState {
     }
 
     /// Start the background fetch service
-    pub fn start_background_fetch(&self, interval_minutes: u32) -> Result<()> {
+    pub fn start_background_fetch(&self) -> Result<()> {
         let app_handle = self.get_app_handle()?;
         self.background_fetch
-            .start(self.repository_cache(), app_handle, interval_minutes);
+            .start(self.repository_cache(), app_handle);
         Ok(())
     }
 
@@ -377,12 +692,33 @@ impl AppState {
         self.background_fetch.stop();
     }
 
-    /// Restart the background fetch service with a new interval
-    pub fn restart_background_fetch(&self, interval_minutes: u32) -> Result<()> {
-        let app_handle = self.get_app_handle()?;
-        self.background_fetch
-            .restart(self.repository_cache(), app_handle, interval_minutes);
-        Ok(())
+    /// Background fetch settings of a repository, falling back to the global interval
+    /// unless its performance profile turns background fetch off
+    pub fn background_fetch_config(&self, repo_path: &Path) -> Result<BackgroundFetchConfig> {
+        match self.database.get_background_fetch_config(repo_path)? {
+            Some(config) => Ok(config),
+            None => {
+                let mut config = BackgroundFetchConfig::with_default_interval(
+                    self.get_settings()?.auto_fetch_interval,
+                );
+                config.enabled &= self.performance_settings(repo_path)?.background_fetch;
+                Ok(config)
+            }
+        }
+    }
+
+    pub fn configure_background_fetch(
+        &self,
+        repo_path: &Path,
+        config: &BackgroundFetchConfig,
+    ) -> Result<()> {
+        self.database
+            .save_background_fetch_config(repo_path, config)
+    }
+
+    pub fn background_fetch_status(&self, repo_path: &Path) -> Result<BackgroundFetchStatus> {
+        let config = self.background_fetch_config(repo_path)?;
+        Ok(self.background_fetch.status(repo_path, &config))
     }
 
     #[cfg(test)]
@@ -391,11 +727,143 @@ impl AppState {
         self.background_fetch.is_running()
     }
 
+    /// The performance profile a repository runs with, why, and the effective value of
+    /// each knob. An `Auto` profile is decided from size measurements taken the first
+    /// time this is asked after the repository is opened.
+    pub fn performance_readiness(&self, repo_path: &Path) -> Result<PerformanceReadiness> {
+        let config = self
+            .database
+            .get_performance_config(repo_path)?
+            .unwrap_or_default();
+
+        let signals = if config.profile == PerformanceProfile::Auto {
+            let cached = self.size_signals.read().get(repo_path).copied();
+            cached.or_else(|| match measure_size_signals(repo_path) {
+                Ok(signals) => {
+                    self.size_signals
+                        .write()
+                        .insert(repo_path.to_path_buf(), signals);
+                    Some(signals)
+                }
+                Err(e) => {
+                    log::warn!("Failed to measure {}: {e}", repo_path.display());
+                    None
+                }
+            })
+        } else {
+            None
+        };
+
+        Ok(resolve_performance(config, signals, &self.get_settings()?))
+    }
+
+    pub fn performance_settings(&self, repo_path: &Path) -> Result<PerformanceSettings> {
+        Ok(self.performance_readiness(repo_path)?.settings)
+    }
+
+    pub fn configure_performance(
+        &self,
+        repo_path: &Path,
+        config: &PerformanceConfig,
+    ) -> Result<()> {
+        self.database.save_performance_config(repo_path, config)
+    }
+
     /// Get a reference to the database
     pub fn database(&self) -> &Database {
         &self.database
     }
 
+    /// Hook trust decisions are persisted in the database
+    fn hook_trust_store(&self) -> Arc<dyn HookTrustStore> {
+        Arc::clone(&self.database) as Arc<dyn HookTrustStore>
+    }
+
+    /// HTTPS host credentials live in the database's secret storage
+    pub fn host_credential_store(&self) -> Arc<dyn HostCredentialStore> {
+        Arc::clone(&self.database) as Arc<dyn HostCredentialStore>
+    }
+
+    /// Record the outcome of an operation on the active repository in the operation log
+    pub fn record_operation<T>(
+        &self,
+        category: OperationCategory,
+        operation: &str,
+        parameters: serde_json::Value,
+        result: &Result<T>,
+    ) {
+        let Some(repo_path) = self.get_current_repository_path() else {
+            return;
+        };
+
+        let (outcome, error) = match result {
+            Ok(_) => (OperationOutcome::Success, None),
+            Err(e) => (OperationOutcome::Failure, Some(e.to_string())),
+        };
+
+        let record = OperationLogRecord {
+            timestamp: chrono::Utc::now(),
+            repo_path: repo_path.to_string_lossy().to_string(),
+            category,
+            operation: operation.to_string(),
+            parameters,
+            outcome,
+            error,
+        };
+
+        if let Err(e) = self.database.record_operation(&record) {
+            log::error!("Failed to record {operation} in operation log: {e}");
+        }
+    }
+
+    /// Snapshot the active repository before a destructive operation so it can be undone.
+    /// Failures are logged and never block the operation itself.
+    pub async fn record_undo_snapshot(&self, ops: &RepoOperations, operation: UndoOperation) {
+        if self.get_current_repository_path().is_none() {
+            return;
+        }
+
+        let snapshot = match ops.create_undo_snapshot().await {
+            Ok(snapshot) => snapshot,
+            Err(e) => {
+                log::warn!("Failed to snapshot repository before {operation}: {e}");
+                return;
+            }
+        };
+
+        if let Err(e) = self.journal_undo_snapshot(ops, operation, &snapshot).await {
+            log::error!("Failed to record undo snapshot for {operation}: {e}");
+        }
+    }
+
+    /// Add a snapshot to the undo journal and anchor its commits with a ref. Refs of
+    /// entries pruned from the journal are deleted.
+    pub async fn journal_undo_snapshot(
+        &self,
+        ops: &RepoOperations,
+        operation: UndoOperation,
+        snapshot: &UndoSnapshot,
+    ) -> Result<()> {
+        let repo_path = self.get_repo_path_string()?;
+        let id = self
+            .database
+            .record_undo_snapshot(&repo_path, operation, snapshot)?;
+        ops.anchor_undo_snapshot(id, snapshot).await?;
+        self.prune_undo_refs(ops).await
+    }
+
+    /// Delete the snapshot refs of entries no longer in the undo journal
+    pub async fn prune_undo_refs(&self, ops: &RepoOperations) -> Result<()> {
+        let repo_path = self.get_repo_path_string()?;
+        let live_ids: Vec<i64> = self
+            .database
+            .get_undo_history(&repo_path, None)?
+            .iter()
+            .map(|entry| entry.id)
+            .collect();
+        ops.prune_undo_refs(&live_ids).await
+    }
+
     /// Get the current repository path as a string
     pub fn get_repo_path_string(&self) -> Result<String> {
         let path = self.ensure_repository_open()?;
@@ -417,13 +885,35 @@ impl AppState {
     /// Resolve SSH credentials (key path + cached passphrase) for a remote
     pub fn resolve_ssh_credentials(&self, remote_name: &str) -> Result<Option<SshCredentials>> {
         let ssh_key = self.resolve_ssh_key_for_remote(remote_name)?;
-        Ok(ssh_key.map(|key_path| {
+        Ok(self.ssh_credentials_for_key(ssh_key))
+    }
+
+    /// Resolve SSH credentials for the remote of a submodule, using the keys configured
+    /// for the submodule's own repository
+    pub fn resolve_submodule_ssh_credentials(
+        &self,
+        submodule_path: &str,
+        remote_name: &str,
+    ) -> Result<Option<SshCredentials>> {
+        let settings = self.get_settings()?;
+        let repo_path = self.ensure_repository_open()?.join(submodule_path);
+        let ssh_key = SshKeyService::resolve_ssh_key(
+            &self.database,
+            &repo_path.to_string_lossy(),
+            remote_name,
+            settings.default_ssh_key.as_ref(),
+        );
+        Ok(self.ssh_credentials_for_key(ssh_key))
+    }
+
+    fn ssh_credentials_for_key(&self, ssh_key: Option<String>) -> Option<SshCredentials> {
+        ssh_key.map(|key_path| {
             let passphrase = self.get_cached_ssh_passphrase(&key_path);
             SshCredentials {
                 key_path,
                 passphrase,
             }
-        }))
+        })
     }
 
     // ==================== Pending Update ====================
@@ -460,6 +950,32 @@ impl AppState {
         log::debug!("Cleared cached passphrase for SSH key: {key_path}");
     }
 
+    /// Store (or clear, with `None`) the cherry-pick queue paused on a conflict
+    pub fn set_cherry_pick_queue(&self, path: &Path, queue: Option<CherryPickQueue>) {
+        let mut queues = self.cherry_pick_queues.lock();
+        match queue {
+            Some(queue) => {
+                queues.insert(path.to_path_buf(), queue);
+            }
+            None => {
+                queues.remove(path);
+            }
+        }
+    }
+
+    /// Step of the paused cherry-pick queue for a repository as `(current, total)`, if any
+    pub fn cherry_pick_queue_steps(&self, path: &Path) -> Option<(usize, usize)> {
+        self.cherry_pick_queues
+            .lock()
+            .get(path)
+            .map(|queue| (queue.total - queue.remaining.len(), queue.total))
+    }
+
+    /// Take the paused cherry-pick queue for a repository, if any
+    pub fn take_cherry_pick_queue(&self, path: &Path) -> Option<CherryPickQueue> {
+        self.cherry_pick_queues.lock().remove(path)
+    }
+
     #[cfg(test)]
     /// Clear all cached passphrases (all `SecretStrings` zeroed on drop)
     pub fn clear_all_ssh_passphrases(&self) {
@@ -467,8 +983,10 @@ impl AppState {
         log::debug!("Cleared all cached SSH passphrases");
     }
 
-    /// Fetch a commit author's avatar URL from the integration provider.
-    pub async fn get_integration_commit_avatar(&self, sha: &str) -> Option<String> {
+    /// Provider of the active repository's main remote, if that provider is connected
+    pub async fn connected_repo_provider(
+        &self,
+    ) -> Option<(DetectedProvider, Arc<dyn IntegrationProvider>)> {
         let remotes = self
             .get_git_service()
             .ok()?
@@ -477,17 +995,19 @@ impl AppState {
             .list_remotes(ListRemoteOptions::default())
             .await
             .ok()?;
-
-        let remote_url = remotes
-            .iter()
-            .find(|r| r.name == "origin")
-            .and_then(|r| r.url.clone())
-            .or_else(|| remotes.first().and_then(|r| r.url.clone()))?;
-
-        let detected = crate::services::detect_provider(&remote_url)?;
+        let detected = crate::services::primary_provider(&remotes)?;
 
         let service = self.integration_service().ok()?;
         let provider = service.get_provider(detected.provider).await.ok()?;
+        provider
+            .is_connected()
+            .await
+            .then_some((detected, provider))
+    }
+
+    /// Fetch a commit author's avatar URL from the integration provider.
+    pub async fn get_integration_commit_avatar(&self, sha: &str) -> Option<String> {
+        let (detected, provider) = self.connected_repo_provider().await?;
 
         let commit = provider
             .get_commit(&detected.owner, &detected.repo, sha)
@@ -342,6 +342,34 @@ async fn test_get_bisect_state_tracks_progress() {
     git_bisect(tmp.path(), &["reset"]);
 }
 
+#[tokio::test]
+async fn test_get_bisect_state_includes_log() {
+    let (tmp, ops) = setup_test_repo();
+
+    create_bisect_history(tmp.path(), 8);
+    let good = git_cmd(tmp.path(), &["rev-parse", "HEAD~7"]);
+
+    ops.bisect_start(Some("HEAD"), &good)
+        .await
+        .expect("should start bisect");
+    ops.bisect_good(None).await.expect("should mark good");
+
+    let state = ops.get_bisect_state().await.expect("should get state");
+
+    // Verify: every recorded action is listed, comments are dropped
+    let cli_actions: Vec<String> = git_cmd(tmp.path(), &["bisect", "log"])
+        .lines()
+        .filter(|l| !l.starts_with('#'))
+        .map(std::string::ToString::to_string)
+        .collect();
+    assert_eq!(state.log, cli_actions);
+    assert!(state.log.iter().any(|l| l.starts_with("git bisect good")));
+    assert!(state.log.iter().all(|l| !l.starts_with('#')));
+
+    // Cleanup
+    git_bisect(tmp.path(), &["reset"]);
+}
+
 // ==================== CLI sets up → Ops reads Tests ====================
 
 #[tokio::test]
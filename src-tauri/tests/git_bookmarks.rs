@@ -0,0 +1,186 @@
+#![cfg(feature = "integration")]
+
+mod common;
+
+use common::{git_cmd, setup_test_repo};
+
+use axis_lib::models::{Bookmark, BookmarkStatus, BookmarkTarget, ResolvedBookmark};
+use axis_lib::services::ops::RepoOperations;
+
+// ==================== Helpers ====================
+
+fn bookmark(id: i64, target: BookmarkTarget) -> Bookmark {
+    Bookmark {
+        id,
+        repo_path: String::new(),
+        target,
+        label: format!("Bookmark {id}"),
+        notes: None,
+        created_at: chrono::Utc::now(),
+        last_visited_at: None,
+    }
+}
+
+async fn resolve(ops: &RepoOperations, targets: Vec<BookmarkTarget>) -> Vec<ResolvedBookmark> {
+    let bookmarks = targets
+        .into_iter()
+        .zip(1..)
+        .map(|(target, id)| bookmark(id, target))
+        .collect();
+    ops.resolve_bookmarks(bookmarks, true)
+        .await
+        .expect("should resolve bookmarks")
+}
+
+fn commit_file(path: &std::path::Path, name: &str, message: &str) {
+    std::fs::write(path.join(name), message).expect("should write");
+    git_cmd(path, &["add", "."]);
+    git_cmd(path, &["commit", "-m", message]);
+}
+
+// ==================== Validation Tests ====================
+
+#[tokio::test]
+async fn test_resolve_bookmarks_valid_targets() {
+    let (tmp, ops) = setup_test_repo();
+    commit_file(tmp.path(), "parser.rs", "Add parser");
+    git_cmd(tmp.path(), &["tag", "v1.0"]);
+    git_cmd(tmp.path(), &["branch", "feature"]);
+    let head = git_cmd(tmp.path(), &["rev-parse", "HEAD"]);
+
+    let resolved = resolve(
+        &ops,
+        vec![
+            BookmarkTarget::Commit(head),
+            BookmarkTarget::FileAtRef {
+                path: "parser.rs".to_string(),
+                reference: "v1.0".to_string(),
+            },
+            BookmarkTarget::Compare {
+                from: "HEAD~1".to_string(),
+                to: "v1.0".to_string(),
+            },
+            BookmarkTarget::BranchHead("feature".to_string()),
+        ],
+    )
+    .await;
+
+    for entry in &resolved {
+        assert_eq!(
+            entry.status,
+            BookmarkStatus::Valid,
+            "{:?}",
+            entry.bookmark.target
+        );
+        assert_eq!(entry.detail, None);
+    }
+}
+
+#[tokio::test]
+async fn test_resolve_bookmarks_annotates_broken_targets() {
+    let (tmp, ops) = setup_test_repo();
+    commit_file(tmp.path(), "parser.rs", "Add parser");
+    let doomed = git_cmd(tmp.path(), &["rev-parse", "HEAD"]);
+
+    // Aggressive rewrite: drop the commit and prune it from the object store
+    git_cmd(tmp.path(), &["reset", "--hard", "HEAD~1"]);
+    git_cmd(tmp.path(), &["reflog", "expire", "--expire=now", "--all"]);
+    git_cmd(tmp.path(), &["gc", "--prune=now", "--quiet"]);
+
+    let resolved = resolve(
+        &ops,
+        vec![
+            BookmarkTarget::Commit(doomed.clone()),
+            BookmarkTarget::FileAtRef {
+                path: "parser.rs".to_string(),
+                reference: "HEAD".to_string(),
+            },
+            BookmarkTarget::Compare {
+                from: "HEAD".to_string(),
+                to: "v9.9".to_string(),
+            },
+            BookmarkTarget::BranchHead("gone".to_string()),
+        ],
+    )
+    .await;
+
+    assert!(resolved
+        .iter()
+        .all(|entry| entry.status == BookmarkStatus::Broken));
+    let details: Vec<&str> = resolved
+        .iter()
+        .map(|entry| {
+            entry
+                .detail
+                .as_deref()
+                .expect("broken targets have a detail")
+        })
+        .collect();
+    let missing_commit = format!("Commit {doomed} no longer exists");
+    assert_eq!(
+        details,
+        vec![
+            missing_commit.as_str(),
+            "parser.rs does not exist at HEAD",
+            "v9.9 does not resolve to a commit",
+            "Branch gone no longer exists",
+        ]
+    );
+}
+
+// ==================== Repair Tests ====================
+
+#[tokio::test]
+async fn test_resolve_bookmarks_repairs_renamed_branch() {
+    let (tmp, ops) = setup_test_repo();
+    git_cmd(tmp.path(), &["branch", "feat"]);
+    git_cmd(tmp.path(), &["branch", "-m", "feat", "feature"]);
+    ops.rename_branch("feature", "parser-feature", false)
+        .await
+        .expect("should rename branch");
+
+    let resolved = resolve(&ops, vec![BookmarkTarget::BranchHead("feat".to_string())]).await;
+
+    assert_eq!(resolved[0].status, BookmarkStatus::Repaired);
+    assert_eq!(
+        resolved[0].bookmark.target,
+        BookmarkTarget::BranchHead("parser-feature".to_string())
+    );
+    assert_eq!(
+        resolved[0].detail.as_deref(),
+        Some("Branch feat was renamed to parser-feature")
+    );
+}
+
+#[tokio::test]
+async fn test_resolve_bookmarks_without_repair_leaves_renamed_branch_broken() {
+    let (tmp, ops) = setup_test_repo();
+    git_cmd(tmp.path(), &["branch", "feat"]);
+    git_cmd(tmp.path(), &["branch", "-m", "feat", "feature"]);
+
+    let resolved = ops
+        .resolve_bookmarks(
+            vec![bookmark(1, BookmarkTarget::BranchHead("feat".to_string()))],
+            false,
+        )
+        .await
+        .expect("should resolve bookmarks");
+
+    assert_eq!(resolved[0].status, BookmarkStatus::Broken);
+    assert_eq!(
+        resolved[0].bookmark.target,
+        BookmarkTarget::BranchHead("feat".to_string())
+    );
+}
+
+#[tokio::test]
+async fn test_resolve_bookmarks_does_not_repair_deleted_rename_target() {
+    let (tmp, ops) = setup_test_repo();
+    git_cmd(tmp.path(), &["branch", "feat"]);
+    git_cmd(tmp.path(), &["branch", "-m", "feat", "feature"]);
+    git_cmd(tmp.path(), &["branch", "-D", "feature"]);
+
+    let resolved = resolve(&ops, vec![BookmarkTarget::BranchHead("feat".to_string())]).await;
+
+    assert_eq!(resolved[0].status, BookmarkStatus::Broken);
+}
@@ -6,6 +6,7 @@ use common::{git_cmd, setup_test_repo};
 
 use axis_lib::models::{
     BranchFilter, BranchType, CheckoutOptions, CreateBranchOptions, DeleteBranchOptions,
+    ExpectedBranchTarget, StaleBranchCriteria,
 };
 
 // ==================== Helpers ====================
@@ -32,6 +33,35 @@ fn git_branch_exists(path: &std::path::Path, name: &str) -> bool {
     git_branch_list(path).contains(&name.to_string())
 }
 
+/// Commit an empty change with both author and committer dates set to `date`
+fn git_commit_dated(path: &std::path::Path, message: &str, date: &str) {
+    let output = std::process::Command::new("git")
+        .args(["commit", "--allow-empty", "-m", message])
+        .env("GIT_AUTHOR_DATE", date)
+        .env("GIT_COMMITTER_DATE", date)
+        .current_dir(path)
+        .output()
+        .expect("should execute git");
+    assert!(output.status.success(), "dated commit should succeed");
+}
+
+/// Create `merged` (at the current tip), `unmerged` (one commit ahead) and `ancient`
+/// (an old unmerged commit), then move the current branch forward
+fn setup_stale_branches(path: &std::path::Path) -> String {
+    let main = git_current_branch(path);
+    git_cmd(path, &["branch", "merged"]);
+
+    git_cmd(path, &["checkout", "-b", "unmerged"]);
+    git_cmd(path, &["commit", "--allow-empty", "-m", "Unmerged work"]);
+
+    git_cmd(path, &["checkout", "-b", "ancient", &main]);
+    git_commit_dated(path, "Old work", "2001-01-01T00:00:00Z");
+
+    git_cmd(path, &["checkout", &main]);
+    git_cmd(path, &["commit", "--allow-empty", "-m", "Main moves on"]);
+    main
+}
+
 // ==================== Happy Path Tests ====================
 
 #[tokio::test]
@@ -256,3 +286,333 @@ async fn test_set_branch_upstream() {
     // Verify
     assert!(result.is_ok(), "Setting upstream should succeed");
 }
+
+#[tokio::test]
+async fn test_list_branches_skip_ahead_behind() {
+    let (tmp, ops) = setup_test_repo();
+
+    // Setup: the current branch tracks a remote and is one commit ahead of it
+    let bare_path = tmp.path().join("bare.git");
+    git_cmd(
+        tmp.path(),
+        &["clone", "--bare", ".", bare_path.to_str().expect("path")],
+    );
+    git_cmd(
+        tmp.path(),
+        &["remote", "add", "origin", bare_path.to_str().expect("path")],
+    );
+    git_cmd(tmp.path(), &["push", "-u", "origin", "HEAD"]);
+    git_cmd(tmp.path(), &["commit", "--allow-empty", "-m", "Ahead"]);
+    let current = git_current_branch(tmp.path());
+
+    let list = |skip_ahead_behind| {
+        ops.list_branches(BranchFilter {
+            include_local: true,
+            skip_ahead_behind,
+            ..Default::default()
+        })
+    };
+    let counted = list(None).await.expect("should list branches");
+    let skipped = list(Some(true)).await.expect("should list branches");
+
+    let find = |branches: &[axis_lib::models::Branch]| {
+        branches
+            .iter()
+            .find(|b| b.name == current)
+            .map(|b| (b.ahead, b.behind))
+            .expect("should list the current branch")
+    };
+    assert_eq!(find(&counted), (Some(1), Some(0)));
+    assert_eq!(find(&skipped), (None, None));
+}
+
+// ==================== Branch Archiving Tests ====================
+
+#[tokio::test]
+async fn test_get_stale_branches_classification() {
+    let (tmp, ops) = setup_test_repo();
+    let main = setup_stale_branches(tmp.path());
+
+    // Action: merged or older than 30 days
+    let stale = ops
+        .get_stale_branches(StaleBranchCriteria {
+            older_than_days: Some(30),
+            merged: true,
+            ..Default::default()
+        })
+        .await
+        .expect("should find stale branches");
+
+    let names: Vec<&str> = stale.iter().map(|b| b.name.as_str()).collect();
+    assert!(names.contains(&"merged"));
+    assert!(names.contains(&"ancient"));
+    assert!(!names.contains(&"unmerged"), "recent unmerged is not stale");
+    assert!(
+        !names.contains(&main.as_str()),
+        "current branch is protected"
+    );
+
+    let merged = stale.iter().find(|b| b.name == "merged").expect("merged");
+    assert!(merged.is_merged);
+    assert!(!merged.is_old);
+    assert_eq!((merged.ahead, merged.behind), (0, 1));
+    assert_eq!(merged.last_author.name, "Test User");
+
+    let ancient = stale.iter().find(|b| b.name == "ancient").expect("ancient");
+    assert!(ancient.is_old);
+    assert!(!ancient.is_merged);
+    assert_eq!(ancient.ahead, 1);
+
+    // Action: requiring both criteria leaves nothing
+    let both = ops
+        .get_stale_branches(StaleBranchCriteria {
+            older_than_days: Some(30),
+            merged: true,
+            match_all: true,
+            ..Default::default()
+        })
+        .await
+        .expect("should find stale branches");
+    assert!(both.is_empty());
+}
+
+#[tokio::test]
+async fn test_get_stale_branches_protects_gitflow_bases() {
+    let (tmp, ops) = setup_test_repo();
+    setup_stale_branches(tmp.path());
+    git_cmd(tmp.path(), &["config", "gitflow.branch.develop", "merged"]);
+
+    let stale = ops
+        .get_stale_branches(StaleBranchCriteria {
+            merged: true,
+            ..Default::default()
+        })
+        .await
+        .expect("should find stale branches");
+
+    assert!(
+        stale.iter().all(|b| b.name != "merged"),
+        "gitflow develop branch must not be offered"
+    );
+}
+
+#[tokio::test]
+async fn test_get_stale_branches_requires_criterion() {
+    let (_tmp, ops) = setup_test_repo();
+
+    let result = ops.get_stale_branches(StaleBranchCriteria::default()).await;
+
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn test_archive_branches_creates_tags_and_reports_failures() {
+    let (tmp, ops) = setup_test_repo();
+    let main = setup_stale_branches(tmp.path());
+    let unmerged_tip = git_cmd(tmp.path(), &["rev-parse", "unmerged"]);
+
+    // Action: archive two branches, the protected current branch and a missing one
+    let results = ops
+        .archive_branches(
+            vec![
+                "merged".to_string(),
+                main.clone(),
+                "unmerged".to_string(),
+                "missing".to_string(),
+            ],
+            "archive/",
+            false,
+            Vec::new(),
+        )
+        .await
+        .expect("should archive");
+
+    assert_eq!(results.len(), 4);
+    assert!(results[0].success);
+    assert_eq!(results[0].tag_name.as_deref(), Some("archive/merged"));
+    assert!(!results[1].success, "current branch is protected");
+    assert!(results[2].success, "unmerged branches are archived too");
+    assert!(!results[3].success);
+    assert!(results[3].error.is_some());
+
+    // Verify: tags point at the old tips and the branches are gone
+    assert_eq!(
+        git_cmd(tmp.path(), &["rev-parse", "archive/unmerged^{commit}"]),
+        unmerged_tip
+    );
+    assert!(!git_branch_exists(tmp.path(), "merged"));
+    assert!(!git_branch_exists(tmp.path(), "unmerged"));
+    assert!(git_branch_exists(tmp.path(), &main));
+}
+
+#[tokio::test]
+async fn test_archive_branches_existing_tag_fails_without_deleting() {
+    let (tmp, ops) = setup_test_repo();
+    setup_stale_branches(tmp.path());
+    git_cmd(tmp.path(), &["tag", "archive/merged"]);
+
+    let results = ops
+        .archive_branches(vec!["merged".to_string()], "archive/", false, Vec::new())
+        .await
+        .expect("should run");
+
+    assert!(!results[0].success);
+    assert!(git_branch_exists(tmp.path(), "merged"));
+}
+
+#[tokio::test]
+async fn test_bulk_delete_branches_partial_failure() {
+    let (tmp, ops) = setup_test_repo();
+    setup_stale_branches(tmp.path());
+
+    // Action: non-forced delete of a merged and an unmerged branch
+    let results = ops
+        .bulk_delete_branches(
+            vec!["unmerged".to_string(), "merged".to_string()],
+            false,
+            false,
+            Vec::new(),
+            Vec::new(),
+        )
+        .await
+        .expect("should run");
+
+    // Verify: the unmerged failure does not stop the merged deletion
+    assert!(!results[0].success);
+    assert!(results[0]
+        .error
+        .as_deref()
+        .is_some_and(|e| e.contains("not fully merged")));
+    assert!(results[1].success);
+    assert!(git_branch_exists(tmp.path(), "unmerged"));
+    assert!(!git_branch_exists(tmp.path(), "merged"));
+
+    // Action: forced delete succeeds
+    let results = ops
+        .bulk_delete_branches(
+            vec!["unmerged".to_string()],
+            true,
+            false,
+            Vec::new(),
+            Vec::new(),
+        )
+        .await
+        .expect("should run");
+    assert!(results[0].success);
+    assert!(!git_branch_exists(tmp.path(), "unmerged"));
+}
+
+#[tokio::test]
+async fn test_bulk_delete_branches_stale_expectation_deletes_nothing() {
+    let (tmp, ops) = setup_test_repo();
+    let main = setup_stale_branches(tmp.path());
+    let merged_tip = git_cmd(tmp.path(), &["rev-parse", "merged"]);
+    // Selected while `unmerged` still pointed at main's old tip
+    let old_tip = git_cmd(tmp.path(), &["rev-parse", &format!("{main}~1")]);
+
+    let results = ops
+        .bulk_delete_branches(
+            vec!["merged".to_string(), "unmerged".to_string()],
+            true,
+            false,
+            vec![
+                ExpectedBranchTarget {
+                    name: "merged".to_string(),
+                    target_oid: merged_tip,
+                },
+                ExpectedBranchTarget {
+                    name: "unmerged".to_string(),
+                    target_oid: old_tip,
+                },
+            ],
+            Vec::new(),
+        )
+        .await
+        .expect("should run");
+
+    // Verify: the stale branch is reported and the batch left every branch in place
+    assert!(!results[0].success);
+    assert!(results[0]
+        .error
+        .as_deref()
+        .is_some_and(|e| e.contains("Not deleted")));
+    assert!(!results[1].success);
+    assert!(results[1]
+        .error
+        .as_deref()
+        .is_some_and(|e| e.contains("refs/heads/unmerged changed")));
+    assert!(git_branch_exists(tmp.path(), "merged"));
+    assert!(git_branch_exists(tmp.path(), "unmerged"));
+}
+
+#[tokio::test]
+async fn test_bulk_delete_remote_tracking_branch() {
+    let (tmp, ops) = setup_test_repo();
+    let bare_path = tmp.path().join("bare.git");
+    let bare = bare_path.to_str().expect("path");
+    git_cmd(tmp.path(), &["init", "--bare", bare]);
+    git_cmd(tmp.path(), &["remote", "add", "origin", bare]);
+    git_cmd(tmp.path(), &["branch", "old-feature"]);
+    git_cmd(tmp.path(), &["push", "origin", "old-feature"]);
+    git_cmd(tmp.path(), &["branch", "-D", "old-feature"]);
+
+    // Action: without delete_remote only the tracking ref goes
+    let results = ops
+        .bulk_delete_branches(
+            vec!["origin/old-feature".to_string()],
+            false,
+            false,
+            Vec::new(),
+            Vec::new(),
+        )
+        .await
+        .expect("should run");
+
+    assert!(results[0].success, "{:?}", results[0].error);
+    assert!(git_cmd(tmp.path(), &["branch", "-r"]).is_empty());
+    assert!(!git_cmd(&bare_path, &["branch", "--list", "old-feature"]).is_empty());
+
+    // Action: with delete_remote the branch is deleted on the remote too
+    git_cmd(tmp.path(), &["fetch", "origin"]);
+    let results = ops
+        .bulk_delete_branches(
+            vec!["origin/old-feature".to_string()],
+            false,
+            true,
+            Vec::new(),
+            Vec::new(),
+        )
+        .await
+        .expect("should run");
+
+    assert!(results[0].success, "{:?}", results[0].error);
+    assert!(git_cmd(&bare_path, &["branch", "--list", "old-feature"]).is_empty());
+    assert!(git_cmd(tmp.path(), &["branch", "-r"]).is_empty());
+}
+
+#[tokio::test]
+async fn test_bulk_delete_remote_tracking_branch_remote_name_with_slash() {
+    let (tmp, ops) = setup_test_repo();
+    let bare_path = tmp.path().join("bare.git");
+    let bare = bare_path.to_str().expect("path");
+    git_cmd(tmp.path(), &["init", "--bare", bare]);
+    git_cmd(tmp.path(), &["remote", "add", "team/upstream", bare]);
+    git_cmd(tmp.path(), &["branch", "old-feature"]);
+    git_cmd(tmp.path(), &["push", "team/upstream", "old-feature"]);
+    git_cmd(tmp.path(), &["branch", "-D", "old-feature"]);
+
+    let results = ops
+        .bulk_delete_branches(
+            vec!["team/upstream/old-feature".to_string()],
+            false,
+            true,
+            Vec::new(),
+            Vec::new(),
+        )
+        .await
+        .expect("should run");
+
+    assert!(results[0].success, "{:?}", results[0].error);
+    assert!(git_cmd(&bare_path, &["branch", "--list", "old-feature"]).is_empty());
+    assert!(git_cmd(tmp.path(), &["branch", "-r"]).is_empty());
+}
@@ -4,7 +4,12 @@ mod common;
 
 use common::{git_cmd, setup_test_repo};
 
-use axis_lib::models::{DiffOptions, FileDiff, FileLogOptions};
+use axis_lib::error::AxisError;
+use axis_lib::models::{
+    DiffOptions, FileContentSource, FileDiff, FileLogOptions, NotebookOutputMode,
+    StructuredDiffKind, WorkdirRefDiffMode,
+};
+use base64::Engine;
 
 // ==================== Helpers ====================
 
@@ -296,6 +301,160 @@ async fn test_diff_commit_cli_created_verified_by_ops() {
     );
 }
 
+#[tokio::test]
+async fn test_diff_commit_word_diff_spans() {
+    let (tmp, ops) = setup_test_repo();
+    std::fs::write(tmp.path().join("README.md"), "# Test readme\n").expect("should write");
+    git_cmd(tmp.path(), &["commit", "-am", "Expand heading"]);
+    std::fs::write(tmp.path().join("README.md"), "# Axis readme\n").expect("should write");
+    git_cmd(tmp.path(), &["commit", "-am", "Rename heading"]);
+    let commit_oid = git_head_oid(tmp.path());
+
+    let plain = ops
+        .diff_commit(&commit_oid, &DiffOptions::default())
+        .await
+        .expect("should get diff");
+    assert!(plain[0].hunks[0]
+        .lines
+        .iter()
+        .all(|line| line.inline_spans.is_none()));
+
+    let options = DiffOptions {
+        compute_word_diff: Some(true),
+        ..DiffOptions::default()
+    };
+    let diff = ops
+        .diff_commit(&commit_oid, &options)
+        .await
+        .expect("should get diff");
+    let spans: Vec<Vec<(u32, u32, bool)>> = diff[0].hunks[0]
+        .lines
+        .iter()
+        .filter_map(|line| line.inline_spans.as_ref())
+        .map(|spans| spans.iter().map(|s| (s.start, s.len, s.changed)).collect())
+        .collect();
+    assert_eq!(
+        spans,
+        vec![
+            vec![(0, 2, false), (2, 4, true), (6, 7, false)],
+            vec![(0, 2, false), (2, 4, true), (6, 7, false)],
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_diff_workdir_function_context() {
+    let (tmp, ops) = setup_test_repo();
+    let source = "\
+class Loader:
+    def load(self):
+        def read(path):
+            return open(path).read()
+
+        data = read('a')
+        return data
+";
+    std::fs::write(tmp.path().join("loader.py"), source).expect("should write");
+    std::fs::write(tmp.path().join("notes.txt"), "one\ntwo\n").expect("should write");
+    git_cmd(tmp.path(), &["add", "."]);
+    git_cmd(tmp.path(), &["commit", "-m", "Add loader"]);
+
+    std::fs::write(
+        tmp.path().join("loader.py"),
+        source.replace("open(path).read()", "open(path).read().strip()"),
+    )
+    .expect("should write");
+    std::fs::write(tmp.path().join("notes.txt"), "one\nthree\n").expect("should write");
+
+    let options = DiffOptions {
+        context_lines: Some(0),
+        function_context: Some(true),
+        ..DiffOptions::default()
+    };
+    let diff = ops.diff_workdir(&options).await.expect("should get diff");
+    let context = |path: &str| {
+        diff.iter()
+            .find(|f| f.new_path.as_deref() == Some(path))
+            .expect("file should be in diff")
+            .hunks[0]
+            .context
+            .clone()
+    };
+    assert_eq!(context("loader.py").as_deref(), Some("def read(path):"));
+    assert_eq!(
+        context("notes.txt"),
+        None,
+        "Unknown languages get no context"
+    );
+
+    let plain = ops
+        .diff_workdir(&DiffOptions::default())
+        .await
+        .expect("should get diff");
+    assert!(plain
+        .iter()
+        .flat_map(|f| &f.hunks)
+        .all(|h| h.context.is_none()));
+}
+
+#[tokio::test]
+async fn test_diff_workdir_structured_notebook() {
+    let (tmp, ops) = setup_test_repo();
+    let notebook = |second: &str, output: &str| {
+        serde_json::json!({
+            "cells": [
+                {"cell_type": "markdown", "metadata": {}, "source": ["# Title"]},
+                {
+                    "cell_type": "code",
+                    "execution_count": 1,
+                    "metadata": {},
+                    "outputs": [{"output_type": "stream", "name": "stdout", "text": [output]}],
+                    "source": [second],
+                },
+            ],
+            "metadata": {},
+            "nbformat": 4,
+            "nbformat_minor": 4,
+        })
+        .to_string()
+    };
+    std::fs::write(tmp.path().join("nb.ipynb"), notebook("print(1)", "1\n")).expect("should write");
+    std::fs::write(tmp.path().join("broken.ipynb"), "{\"cells\": []}\n").expect("should write");
+    git_cmd(tmp.path(), &["add", "."]);
+    git_cmd(tmp.path(), &["commit", "-m", "Add notebooks"]);
+
+    std::fs::write(tmp.path().join("nb.ipynb"), notebook("print(2)", "2\n")).expect("should write");
+    std::fs::write(tmp.path().join("broken.ipynb"), "{\"cells\": [\n").expect("should write");
+
+    let options = DiffOptions {
+        structured_diff: Some(true),
+        notebook_outputs: Some(NotebookOutputMode::Ignore),
+        ..DiffOptions::default()
+    };
+    let diff = ops.diff_workdir(&options).await.expect("should get diff");
+    let file = |path: &str| -> &FileDiff {
+        diff.iter()
+            .find(|f| f.new_path.as_deref() == Some(path))
+            .expect("file should be in diff")
+    };
+
+    let nb = file("nb.ipynb");
+    assert_eq!(nb.structured, Some(StructuredDiffKind::Notebook));
+    assert_eq!(nb.hunks.len(), 1);
+    assert_eq!(nb.hunks[0].header, "@@ -1,1 +1,1 @@ cell 2 [code] modified");
+    assert_eq!((nb.additions, nb.deletions), (1, 1));
+
+    let broken = file("broken.ipynb");
+    assert_eq!(broken.structured, Some(StructuredDiffKind::RawFallback));
+    assert!(!broken.hunks.is_empty(), "Fallback keeps the text diff");
+
+    let plain = ops
+        .diff_workdir(&DiffOptions::default())
+        .await
+        .expect("should get diff");
+    assert!(plain.iter().all(|f| f.structured.is_none()));
+}
+
 // ==================== diff_commits Tests ====================
 
 #[tokio::test]
@@ -359,6 +518,260 @@ async fn test_diff_commits_reverse_direction() {
     assert!(diff_has_file(&reverse, "added.txt"));
 }
 
+// ==================== diff_workdir_to_ref Tests ====================
+
+/// Sorted paths touched by `diffs`
+fn diff_paths(diffs: &[FileDiff]) -> Vec<String> {
+    let mut paths: Vec<String> = diffs
+        .iter()
+        .filter_map(|d| diff_path(d).map(ToString::to_string))
+        .collect();
+    paths.sort();
+    paths
+}
+
+/// Diverge from `origin/main` with a local commit, a staged-only change,
+/// an unstaged edit, an unstaged removal from the index and an untracked file
+fn diverge_from_origin(path: &std::path::Path) {
+    for name in ["a.txt", "b.txt", "gone.txt"] {
+        std::fs::write(path.join(name), format!("{name}\n")).expect("should write");
+    }
+    git_cmd(path, &["add", "."]);
+    git_cmd(path, &["commit", "-m", "Base"]);
+    git_cmd(path, &["update-ref", "refs/remotes/origin/main", "HEAD"]);
+
+    std::fs::write(path.join("a.txt"), "a.txt\nlocal commit\n").expect("should write");
+    git_cmd(path, &["commit", "-am", "Local commit"]);
+
+    std::fs::write(path.join("b.txt"), "b.txt\nstaged only\n").expect("should write");
+    git_cmd(path, &["add", "b.txt"]);
+    std::fs::write(path.join("b.txt"), "b.txt\n").expect("should write");
+
+    std::fs::write(path.join("README.md"), "# Test\nunstaged\n").expect("should write");
+    git_cmd(path, &["rm", "--cached", "--quiet", "gone.txt"]);
+    std::fs::write(path.join("new.txt"), "untracked\n").expect("should write");
+}
+
+#[tokio::test]
+async fn test_diff_workdir_to_ref_modes_match_cli() {
+    let (tmp, ops) = setup_test_repo();
+    diverge_from_origin(tmp.path());
+    let options = DiffOptions::default();
+
+    let combined = ops
+        .diff_workdir_to_ref(
+            "origin/main",
+            WorkdirRefDiffMode::WorkdirWithIndex,
+            false,
+            &options,
+        )
+        .await
+        .expect("should diff");
+    let mut cli = git_diff_files(tmp.path(), &["origin/main"]);
+    cli.sort();
+    assert_eq!(diff_paths(&combined), cli);
+    assert_eq!(cli, vec!["README.md", "a.txt", "gone.txt"]);
+
+    let index = ops
+        .diff_workdir_to_ref("origin/main", WorkdirRefDiffMode::IndexOnly, true, &options)
+        .await
+        .expect("should diff");
+    let mut cli = git_diff_files(tmp.path(), &["--cached", "origin/main"]);
+    cli.sort();
+    assert_eq!(diff_paths(&index), cli);
+    assert_eq!(cli, vec!["a.txt", "b.txt", "gone.txt"]);
+
+    // The working tree alone still has gone.txt, and the staged b.txt was reverted
+    let workdir = ops
+        .diff_workdir_to_ref(
+            "origin/main",
+            WorkdirRefDiffMode::WorkdirOnly,
+            false,
+            &options,
+        )
+        .await
+        .expect("should diff");
+    assert_eq!(diff_paths(&workdir), vec!["README.md", "a.txt"]);
+}
+
+#[tokio::test]
+async fn test_diff_workdir_to_ref_untracked_files() {
+    let (tmp, ops) = setup_test_repo();
+    diverge_from_origin(tmp.path());
+    let options = DiffOptions::default();
+
+    let with_untracked = ops
+        .diff_workdir_to_ref(
+            "origin/main",
+            WorkdirRefDiffMode::WorkdirWithIndex,
+            true,
+            &options,
+        )
+        .await
+        .expect("should diff");
+    assert_eq!(
+        diff_paths(&with_untracked),
+        vec!["README.md", "a.txt", "gone.txt", "new.txt"]
+    );
+
+    let err = ops
+        .diff_workdir_to_ref(
+            "origin/nope",
+            WorkdirRefDiffMode::WorkdirOnly,
+            true,
+            &options,
+        )
+        .await
+        .expect_err("unknown ref should fail");
+    assert!(err.to_string().contains("origin/nope"));
+}
+
+// ==================== Binary and Image Tests ====================
+
+/// A PNG signature followed by `body`, so git treats it as binary
+fn png_bytes(body: &[u8]) -> Vec<u8> {
+    let mut bytes = b"\x89PNG\r\n\x1a\n\x00\x00\x00\rIHDR".to_vec();
+    bytes.extend_from_slice(body);
+    bytes
+}
+
+fn decode(content_base64: &str) -> Vec<u8> {
+    base64::engine::general_purpose::STANDARD
+        .decode(content_base64)
+        .expect("should be valid base64")
+}
+
+#[tokio::test]
+async fn test_diff_workdir_added_png_has_binary_info() {
+    let (tmp, ops) = setup_test_repo();
+    let png = png_bytes(&[0, 1, 2, 3, 0, 255]);
+    std::fs::write(tmp.path().join("logo.png"), &png).expect("should write png");
+
+    let diff = ops
+        .diff_workdir(&DiffOptions::default())
+        .await
+        .expect("should get diff");
+    let file = diff
+        .iter()
+        .find(|d| diff_path(d) == Some("logo.png"))
+        .expect("png should be in diff");
+    assert!(file.binary);
+    let info = file.binary_info.as_ref().expect("should have binary info");
+    assert_eq!(info.old_size, None);
+    assert_eq!(info.new_size, Some(png.len() as u64));
+    assert!(info.is_image);
+    assert!(!info.same_content);
+
+    let content = ops
+        .get_file_content("logo.png", FileContentSource::Workdir)
+        .await
+        .expect("should read workdir content");
+    assert_eq!(content.mime_type, "image/png");
+    assert_eq!(content.size, png.len() as u64);
+    assert_eq!(decode(&content.content_base64), png);
+
+    // Not staged, so the index has no entry yet
+    let result = ops
+        .get_file_content("logo.png", FileContentSource::Index)
+        .await;
+    assert!(matches!(result, Err(AxisError::FileNotFound(_))));
+}
+
+#[tokio::test]
+async fn test_diff_commits_modified_png_sizes_and_content() {
+    let (tmp, ops) = setup_test_repo();
+    let old_png = png_bytes(&[0, 1, 2]);
+    let new_png = png_bytes(&[0, 9, 8, 7, 6, 5]);
+
+    std::fs::write(tmp.path().join("icon.png"), &old_png).expect("should write png");
+    git_cmd(tmp.path(), &["add", "icon.png"]);
+    git_cmd(tmp.path(), &["commit", "-m", "Add icon"]);
+    let from = git_head_oid(tmp.path());
+    std::fs::write(tmp.path().join("icon.png"), &new_png).expect("should write png");
+    git_cmd(tmp.path(), &["commit", "-am", "Redraw icon"]);
+    let to = git_head_oid(tmp.path());
+
+    let diff = ops
+        .diff_commits(&from, &to, &DiffOptions::default())
+        .await
+        .expect("should get diff");
+    assert_eq!(diff.len(), 1);
+    let info = diff[0]
+        .binary_info
+        .as_ref()
+        .expect("should have binary info");
+    assert_eq!(info.old_size, Some(old_png.len() as u64));
+    assert_eq!(info.new_size, Some(new_png.len() as u64));
+    assert!(info.is_image);
+    assert!(!info.same_content);
+
+    for (oid, expected) in [(&from, &old_png), (&to, &new_png)] {
+        let content = ops
+            .get_file_content("icon.png", FileContentSource::Commit { oid: oid.clone() })
+            .await
+            .expect("should read committed content");
+        assert_eq!(content.mime_type, "image/png");
+        assert_eq!(&decode(&content.content_base64), expected);
+    }
+
+    // Extensions outside the configured set are not flagged as images
+    let options = DiffOptions {
+        image_extensions: Some(vec!["psd".to_string()]),
+        ..DiffOptions::default()
+    };
+    let diff = ops
+        .diff_commits(&from, &to, &options)
+        .await
+        .expect("should get diff");
+    let info = diff[0]
+        .binary_info
+        .as_ref()
+        .expect("should have binary info");
+    assert!(!info.is_image);
+}
+
+#[cfg(unix)]
+#[tokio::test]
+async fn test_diff_binary_mode_change_is_same_content() {
+    let (tmp, ops) = setup_test_repo();
+    std::fs::write(tmp.path().join("tool.png"), png_bytes(&[0, 1])).expect("should write png");
+    git_cmd(tmp.path(), &["add", "tool.png"]);
+    git_cmd(tmp.path(), &["commit", "-m", "Add tool"]);
+    git_cmd(tmp.path(), &["update-index", "--chmod=+x", "tool.png"]);
+    git_cmd(tmp.path(), &["commit", "-m", "Make executable"]);
+    let head = git_head_oid(tmp.path());
+
+    let diff = ops
+        .diff_commit(&head, &DiffOptions::default())
+        .await
+        .expect("should get diff");
+    assert_eq!(diff.len(), 1);
+    if let Some(info) = &diff[0].binary_info {
+        assert!(info.same_content);
+    } else {
+        // Mode-only changes carry no content, so git may not load it as binary
+        assert_eq!(diff[0].old_oid, diff[0].new_oid);
+    }
+}
+
+#[tokio::test]
+async fn test_get_file_content_over_size_cap_fails() {
+    let (tmp, ops) = setup_test_repo();
+    let file = std::fs::File::create(tmp.path().join("huge.bin")).expect("should create file");
+    // Sparse, so the test does not write the bytes
+    file.set_len(axis_lib::services::FILE_CONTENT_MAX_BYTES + 1)
+        .expect("should extend file");
+
+    let err = ops
+        .get_file_content("huge.bin", FileContentSource::Workdir)
+        .await
+        .expect_err("should refuse oversized file");
+    assert!(
+        matches!(err, AxisError::FileTooLarge { size, .. } if size == axis_lib::services::FILE_CONTENT_MAX_BYTES + 1),
+        "{err}"
+    );
+}
+
 // ==================== diff_file Tests ====================
 
 #[tokio::test]
@@ -561,6 +974,228 @@ async fn test_get_file_diff_in_commit_file_not_in_commit() {
     );
 }
 
+// ==================== get_file_diff_between Tests ====================
+
+#[tokio::test]
+async fn test_get_file_diff_between_follows_rename() {
+    let (tmp, ops) = setup_test_repo();
+    let content: String = (1..=20).map(|i| format!("line {i}\n")).collect();
+
+    std::fs::write(tmp.path().join("old.txt"), &content).expect("should write");
+    git_cmd(tmp.path(), &["add", "old.txt"]);
+    git_cmd(tmp.path(), &["commit", "-m", "Add old"]);
+    let from = git_head_oid(tmp.path());
+
+    git_cmd(tmp.path(), &["mv", "old.txt", "new.txt"]);
+    git_cmd(tmp.path(), &["commit", "-m", "Rename"]);
+    std::fs::write(tmp.path().join("new.txt"), format!("{content}line 21\n"))
+        .expect("should write");
+    git_cmd(tmp.path(), &["commit", "-am", "Append"]);
+
+    // Action: compare under the new name
+    let result = ops
+        .get_file_diff_between("new.txt", &from, "HEAD", &DiffOptions::default(), true)
+        .await
+        .expect("should compare");
+
+    // Verify: traced to the old name and diffed as a rename
+    assert_eq!(result.from_path.as_deref(), Some("old.txt"));
+    assert_eq!(result.to_path.as_deref(), Some("new.txt"));
+    assert!(!result.created_in_range);
+    assert!(!result.deleted_in_range);
+    let diff = result.diff.expect("should have diff");
+    assert_eq!(diff.old_path.as_deref(), Some("old.txt"));
+    assert_eq!(diff.new_path.as_deref(), Some("new.txt"));
+    assert_eq!(diff.additions, 1);
+    assert_eq!(result.commits.len(), 2);
+    assert_eq!(result.commits[0].summary, "Append");
+    assert_eq!(result.commits[1].summary, "Rename");
+}
+
+#[tokio::test]
+async fn test_get_file_diff_between_without_follow_treats_as_created() {
+    let (tmp, ops) = setup_test_repo();
+    std::fs::write(tmp.path().join("old.txt"), "same content\n").expect("should write");
+    git_cmd(tmp.path(), &["add", "old.txt"]);
+    git_cmd(tmp.path(), &["commit", "-m", "Add old"]);
+    let from = git_head_oid(tmp.path());
+    git_cmd(tmp.path(), &["mv", "old.txt", "new.txt"]);
+    git_cmd(tmp.path(), &["commit", "-m", "Rename"]);
+
+    let result = ops
+        .get_file_diff_between("new.txt", &from, "HEAD", &DiffOptions::default(), false)
+        .await
+        .expect("should compare");
+
+    assert!(result.created_in_range);
+    assert_eq!(result.from_path, None);
+}
+
+#[tokio::test]
+async fn test_get_file_diff_between_created_in_range() {
+    let (tmp, ops) = setup_test_repo();
+    let from = git_head_oid(tmp.path());
+
+    std::fs::write(tmp.path().join("fresh.txt"), "a\nb\n").expect("should write");
+    git_cmd(tmp.path(), &["add", "fresh.txt"]);
+    git_cmd(tmp.path(), &["commit", "-m", "Create fresh"]);
+
+    let result = ops
+        .get_file_diff_between("fresh.txt", &from, "HEAD", &DiffOptions::default(), true)
+        .await
+        .expect("should compare");
+
+    assert!(result.created_in_range);
+    assert!(!result.deleted_in_range);
+    assert_eq!(result.from_path, None);
+    let diff = result.diff.expect("should have diff");
+    assert_eq!(diff.additions, 2);
+    assert_eq!(result.commits.len(), 1);
+}
+
+#[tokio::test]
+async fn test_get_file_diff_between_deleted_in_range() {
+    let (tmp, ops) = setup_test_repo();
+    std::fs::write(tmp.path().join("doomed.txt"), "x\n").expect("should write");
+    git_cmd(tmp.path(), &["add", "doomed.txt"]);
+    git_cmd(tmp.path(), &["commit", "-m", "Add doomed"]);
+    let from = git_head_oid(tmp.path());
+
+    git_cmd(tmp.path(), &["rm", "doomed.txt"]);
+    git_cmd(tmp.path(), &["commit", "-m", "Delete doomed"]);
+
+    let result = ops
+        .get_file_diff_between("doomed.txt", &from, "HEAD", &DiffOptions::default(), true)
+        .await
+        .expect("should compare");
+
+    assert!(result.deleted_in_range);
+    assert!(!result.created_in_range);
+    assert_eq!(result.to_path, None);
+    assert_eq!(result.diff.expect("should have diff").deletions, 1);
+    assert_eq!(result.commits.len(), 1);
+    assert_eq!(result.commits[0].summary, "Delete doomed");
+}
+
+#[tokio::test]
+async fn test_get_file_diff_between_commits_match_file_history() {
+    let (tmp, ops) = setup_test_repo();
+    std::fs::write(tmp.path().join("tracked.txt"), "v0").expect("should write");
+    git_cmd(tmp.path(), &["add", "tracked.txt"]);
+    git_cmd(tmp.path(), &["commit", "-m", "v0"]);
+    let from = git_head_oid(tmp.path());
+
+    for i in 1..=3 {
+        std::fs::write(tmp.path().join("tracked.txt"), format!("v{i}")).expect("should write");
+        git_cmd(tmp.path(), &["commit", "-am", &format!("v{i}")]);
+        std::fs::write(tmp.path().join(format!("other{i}.txt")), "x").expect("should write");
+        git_cmd(tmp.path(), &["add", "."]);
+        git_cmd(tmp.path(), &["commit", "-m", &format!("other {i}")]);
+    }
+
+    let result = ops
+        .get_file_diff_between("tracked.txt", &from, "HEAD", &DiffOptions::default(), true)
+        .await
+        .expect("should compare");
+    let history = ops
+        .get_file_history(FileLogOptions {
+            paths: vec!["tracked.txt".to_string()],
+            limit: Some(3),
+            ..Default::default()
+        })
+        .await
+        .expect("should get history");
+
+    let compared: Vec<&str> = result.commits.iter().map(|c| c.oid.as_str()).collect();
+    let logged: Vec<&str> = history.commits.iter().map(|c| c.oid.as_str()).collect();
+    assert_eq!(compared, logged);
+    assert!(!result.has_more_commits);
+}
+
+#[tokio::test]
+async fn test_get_file_diff_between_unknown_path_fails() {
+    let (tmp, ops) = setup_test_repo();
+    let head = git_head_oid(tmp.path());
+
+    let err = ops
+        .get_file_diff_between("missing.txt", &head, "HEAD", &DiffOptions::default(), true)
+        .await
+        .expect_err("should fail");
+
+    assert!(err.to_string().contains("missing.txt"));
+}
+
+// ==================== resolve_current_path Tests ====================
+
+#[tokio::test]
+async fn test_resolve_current_path_follows_two_renames() {
+    let (tmp, ops) = setup_test_repo();
+    let content: String = (1..=20).map(|i| format!("line {i}\n")).collect();
+
+    std::fs::write(tmp.path().join("first.txt"), &content).expect("should write");
+    git_cmd(tmp.path(), &["add", "first.txt"]);
+    git_cmd(tmp.path(), &["commit", "-m", "Add first"]);
+    let from = git_head_oid(tmp.path());
+
+    git_cmd(tmp.path(), &["mv", "first.txt", "second.txt"]);
+    git_cmd(tmp.path(), &["commit", "-m", "Rename once"]);
+    std::fs::create_dir_all(tmp.path().join("docs")).expect("should create dir");
+    git_cmd(tmp.path(), &["mv", "second.txt", "docs/third.txt"]);
+    git_cmd(tmp.path(), &["commit", "-m", "Move into docs"]);
+
+    // Action: resolve the name the file had at the old ref
+    let resolved = ops
+        .resolve_current_path("first.txt", &from)
+        .await
+        .expect("should resolve");
+
+    // Verify: the current location after both renames
+    assert_eq!(resolved.path, "docs/third.txt");
+    assert!(resolved.exists);
+    assert!(resolved.renamed);
+    assert!(resolved.deleted_in.is_none());
+    assert!(resolved.submodule.is_none());
+    assert!(!resolved.truncated);
+}
+
+#[tokio::test]
+async fn test_resolve_current_path_deleted_file() {
+    let (tmp, ops) = setup_test_repo();
+    let content: String = (1..=20).map(|i| format!("line {i}\n")).collect();
+
+    std::fs::write(tmp.path().join("gone.txt"), &content).expect("should write");
+    git_cmd(tmp.path(), &["add", "gone.txt"]);
+    git_cmd(tmp.path(), &["commit", "-m", "Add gone"]);
+    let from = git_head_oid(tmp.path());
+
+    git_cmd(tmp.path(), &["mv", "gone.txt", "last.txt"]);
+    git_cmd(tmp.path(), &["commit", "-m", "Rename"]);
+    git_cmd(tmp.path(), &["rm", "last.txt"]);
+    git_cmd(tmp.path(), &["commit", "-m", "Delete"]);
+    let deleting_commit = git_head_oid(tmp.path());
+
+    let resolved = ops
+        .resolve_current_path("gone.txt", &from)
+        .await
+        .expect("should resolve");
+
+    // Verify: last known location and the commit that removed it
+    assert_eq!(resolved.path, "last.txt");
+    assert!(!resolved.exists);
+    assert_eq!(resolved.deleted_in, Some(deleting_commit));
+}
+
+#[tokio::test]
+async fn test_resolve_current_path_missing_at_ref() {
+    let (_tmp, ops) = setup_test_repo();
+
+    let err = ops
+        .resolve_current_path("never-existed.txt", "HEAD")
+        .await
+        .expect_err("should fail for unknown path");
+    assert!(err.to_string().contains("never-existed.txt"));
+}
+
 // ==================== Edge Cases ====================
 
 #[tokio::test]
@@ -9,6 +9,8 @@ mod common;
 
 use common::*;
 
+use axis_lib::models::{FileStatusKind, IgnoreSuggestionType, RepositoryStatusOptions};
+
 // ==================== Local Helper Functions ====================
 
 /// Read .gitignore file content
@@ -511,3 +513,118 @@ async fn test_add_to_gitignore_handles_no_trailing_newline() {
     assert!(lines.contains(&"*.tmp"), "Original pattern should exist");
     assert!(lines.contains(&"*.log"), "New pattern should exist");
 }
+
+#[tokio::test]
+async fn test_status_collapses_untracked_directory() {
+    let (tmp, ops) = setup_test_repo();
+
+    // Setup: untracked directory with many files
+    std::fs::create_dir_all(tmp.path().join("build/nested")).expect("should create dirs");
+    for i in 0..30 {
+        std::fs::write(tmp.path().join(format!("build/out{i}.o")), "obj").expect("should write");
+    }
+    std::fs::write(tmp.path().join("build/nested/deep.o"), "obj").expect("should write");
+
+    // Action: default status collapses the directory
+    let status = ops
+        .status(RepositoryStatusOptions::default())
+        .await
+        .expect("should get status");
+
+    // Verify: one directory entry counting every file below it
+    assert_eq!(status.untracked.len(), 1);
+    let entry = &status.untracked[0];
+    assert_eq!(entry.path, "build/");
+    assert_eq!(entry.kind, FileStatusKind::Directory);
+    assert_eq!(entry.file_count, Some(31));
+
+    // Verify: recursive status lists every file
+    let status = ops
+        .status(RepositoryStatusOptions::recursive())
+        .await
+        .expect("should get status");
+    assert_eq!(status.untracked.len(), 31);
+    assert!(status
+        .untracked
+        .iter()
+        .all(|f| f.kind == FileStatusKind::File && f.file_count.is_none()));
+}
+
+#[tokio::test]
+async fn test_bulk_ignore_writes_once_and_dedupes() {
+    let (tmp, ops) = setup_test_repo();
+
+    // Setup: untracked directory and log file, one pattern already ignored
+    std::fs::create_dir_all(tmp.path().join("build")).expect("should create dir");
+    std::fs::write(tmp.path().join("build/a.o"), "obj").expect("should write");
+    std::fs::write(tmp.path().join("debug.log"), "log").expect("should write");
+    std::fs::write(tmp.path().join(".gitignore"), "*.tmp\n").expect("should write");
+
+    // Action: ignore several patterns at once, with duplicates
+    let results = ops
+        .bulk_ignore(
+            vec![
+                "build/".to_string(),
+                "*.log".to_string(),
+                "*.tmp".to_string(),
+                "build/".to_string(),
+            ],
+            ".gitignore",
+        )
+        .await
+        .expect("should bulk ignore");
+
+    // Verify: one result per pattern, duplicates reported as existing
+    let existed: Vec<bool> = results.iter().map(|r| r.already_existed).collect();
+    assert_eq!(existed, vec![false, false, true, true]);
+    assert_eq!(
+        read_gitignore(tmp.path(), ".gitignore"),
+        "*.tmp\nbuild/\n*.log\n"
+    );
+
+    // Verify: ignored entries disappear from the next status
+    let status = ops
+        .status(RepositoryStatusOptions::default())
+        .await
+        .expect("should get status");
+    let untracked: Vec<&str> = status.untracked.iter().map(|f| f.path.as_str()).collect();
+    assert_eq!(untracked, vec![".gitignore"]);
+}
+
+#[tokio::test]
+async fn test_get_ignore_options_directory() {
+    let (tmp, ops) = setup_test_repo();
+
+    // Setup: nested untracked directory with a .gitignore next to it
+    std::fs::create_dir_all(tmp.path().join("web/node_modules/pkg")).expect("should create dirs");
+    std::fs::write(tmp.path().join("web/node_modules/pkg/index.js"), "").expect("should write");
+    std::fs::write(tmp.path().join("web/.gitignore"), "").expect("should write");
+
+    // Action: get options for the collapsed directory entry
+    let options = ops
+        .get_ignore_options("web/node_modules/")
+        .await
+        .expect("should get ignore options");
+
+    // Verify: directory patterns only, closest .gitignore is default
+    assert_eq!(options.default_gitignore, "web/.gitignore");
+    let patterns: Vec<(&str, IgnoreSuggestionType)> = options
+        .suggestions
+        .iter()
+        .map(|s| (s.pattern.as_str(), s.suggestion_type))
+        .collect();
+    assert_eq!(
+        patterns,
+        vec![
+            ("/web/node_modules/", IgnoreSuggestionType::Directory),
+            ("node_modules/", IgnoreSuggestionType::DirectoryName),
+        ]
+    );
+
+    // Verify: an existing directory without a trailing slash is detected too
+    let options = ops
+        .get_ignore_options("web/node_modules")
+        .await
+        .expect("should get ignore options");
+    assert_eq!(options.suggestions[1].pattern, "node_modules/");
+}
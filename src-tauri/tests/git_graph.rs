@@ -7,8 +7,16 @@
 
 mod common;
 
-use axis_lib::models::{GraphOptions, SearchOptions};
+use axis_lib::error::AxisError;
+use axis_lib::models::{
+    BranchFilterType, ChangelogOptions, ChangelogSection, CommitLintRuleId, CommitLintRules,
+    CommitMessageStats, CommitMessageStatsOptions, GraphOptions, RefType, RuleViolationCount,
+    SearchOptions, StatsBucket,
+};
+use axis_lib::services::ops::RepoOperations;
+use axis_lib::services::{build_changelog, CommitMessageStatsCache, CHANGELOG_OTHER_HEADING};
 use common::*;
+use std::sync::Arc;
 
 // ==================== Local Helper Functions ====================
 
@@ -77,6 +85,96 @@ fn git_blame_line_count(path: &std::path::Path, file: &str) -> usize {
     String::from_utf8_lossy(&output.stdout).lines().count()
 }
 
+/// Run a git command with the author and committer dates set to `date`
+fn git_cmd_at(path: &std::path::Path, date: &str, args: &[&str]) {
+    let output = std::process::Command::new("git")
+        .args(args)
+        .current_dir(path)
+        .env("GIT_AUTHOR_DATE", date)
+        .env("GIT_COMMITTER_DATE", date)
+        .output()
+        .expect("should execute git");
+    if !output.status.success() {
+        panic!(
+            "git {} failed: {}",
+            args.join(" "),
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+}
+
+/// Commit `message` at `date`, touching a file named after the date
+fn commit_message_at(path: &std::path::Path, date: &str, message: &str) {
+    let file = format!("{}.txt", &date[..10]);
+    std::fs::write(path.join(&file), message).expect("should write");
+    git_cmd(path, &["add", &file]);
+    git_cmd_at(path, date, &["commit", "-m", message]);
+}
+
+/// Commits from March and April 2024 with known good and bad messages: three
+/// conforming, two breaking rules, one fixup, one revert and one merge
+fn create_commit_message_fixture(path: &std::path::Path) {
+    commit_message_at(path, "2024-03-04T10:00:00Z", "feat: add login");
+    commit_message_at(
+        path,
+        "2024-03-12T10:00:00Z",
+        "fix: handle empty password\n\nCloses #12",
+    );
+    commit_message_at(path, "2024-03-20T10:00:00Z", "Update stuff.");
+    commit_message_at(path, "2024-04-02T10:00:00Z", "fixup! feat: add login");
+    commit_message_at(
+        path,
+        "2024-04-09T10:00:00Z",
+        "docs: describe the login flow for the new onboarding screen and the settings page",
+    );
+    commit_message_at(
+        path,
+        "2024-04-16T10:00:00Z",
+        "Revert \"feat: add login\"\n\nThis reverts the login commit.",
+    );
+
+    git_cmd(path, &["checkout", "-b", "topic"]);
+    std::fs::write(path.join("topic.txt"), "refresh").expect("should write");
+    git_cmd(path, &["add", "topic.txt"]);
+    git_cmd_at(
+        path,
+        "2024-04-18T10:00:00Z",
+        &[
+            "-c",
+            "user.name=Alice",
+            "-c",
+            "user.email=alice@example.com",
+            "commit",
+            "-m",
+            "feat(api): add token refresh\n\nRefs AX-7",
+        ],
+    );
+    git_cmd(path, &["checkout", "-"]);
+    git_cmd_at(
+        path,
+        "2024-04-20T10:00:00Z",
+        &["merge", "--no-ff", "-m", "Merge branch 'topic'", "topic"],
+    );
+}
+
+/// End of April 2024, leaving out the initial commit made now
+const FIXTURE_END: i64 = 1_714_521_599;
+
+async fn analyze(
+    ops: &RepoOperations,
+    options: CommitMessageStatsOptions,
+    cache: &Arc<CommitMessageStatsCache>,
+) -> CommitMessageStats {
+    ops.analyze_commit_messages(
+        options,
+        CommitLintRules::default(),
+        Arc::clone(cache),
+        Box::new(|_| true),
+    )
+    .await
+    .expect("should analyze commit messages")
+}
+
 // ==================== build_graph Tests ====================
 
 #[tokio::test]
@@ -192,6 +290,149 @@ async fn test_build_graph_verified_by_cli_count() {
     );
 }
 
+#[tokio::test]
+async fn test_build_graph_includes_stashes() {
+    let (tmp, ops) = setup_test_repo();
+
+    // Setup: two stashes, the second with an untracked file
+    std::fs::write(tmp.path().join("README.md"), "# First").expect("should write");
+    git_cmd(tmp.path(), &["stash", "push", "-m", "first stash"]);
+    std::fs::write(tmp.path().join("README.md"), "# Second").expect("should write");
+    std::fs::write(tmp.path().join("new.txt"), "untracked").expect("should write");
+    git_cmd(tmp.path(), &["stash", "push", "-u", "-m", "second stash"]);
+
+    let options = GraphOptions {
+        limit: Some(100),
+        include_stashes: true,
+        ..Default::default()
+    };
+    let result = ops.build_graph(options).await.expect("should build graph");
+
+    // Verify: initial commit plus the two stash commits; index and untracked
+    // commits are left out
+    assert_eq!(result.commits.len(), 3);
+    let head = git_cmd(tmp.path(), &["rev-parse", "HEAD"]);
+    for (index, message) in [(0, "second stash"), (1, "first stash")] {
+        let oid = git_cmd(tmp.path(), &["rev-parse", &format!("stash@{{{index}}}")]);
+        let commit = result
+            .commits
+            .iter()
+            .find(|c| c.commit.oid == oid)
+            .expect("stash commit should be in the graph");
+        let stash_ref = commit
+            .refs
+            .iter()
+            .find(|r| r.name == format!("stash@{{{index}}}"))
+            .expect("stash commit should carry its stash ref");
+        match &stash_ref.ref_type {
+            RefType::Stash {
+                index: ref_index,
+                message: ref_message,
+            } => {
+                assert_eq!(*ref_index, index);
+                assert!(ref_message.contains(message), "{ref_message}");
+            }
+            other => panic!("expected a stash ref, got {other:?}"),
+        }
+        assert_eq!(commit.commit.parent_oids, vec![head.clone()]);
+    }
+
+    // Without the option stashes stay hidden
+    let result = ops
+        .build_graph(GraphOptions {
+            limit: Some(100),
+            ..Default::default()
+        })
+        .await
+        .expect("should build graph");
+    assert_eq!(result.commits.len(), 1);
+}
+
+#[tokio::test]
+async fn test_build_graph_author_filter_keeps_connecting_commits() {
+    let (tmp, ops) = setup_test_repo();
+
+    // Setup: Initial, alice, bob x2, alice.smith, carol
+    create_commits_by_author(tmp.path(), "alice", 1);
+    create_commits_by_author(tmp.path(), "bob", 2);
+    create_commits_by_author(tmp.path(), "alice.smith", 1);
+    create_commits_by_author(tmp.path(), "carol", 1);
+
+    let options = GraphOptions {
+        limit: Some(100),
+        author_filter: Some("ALICE".to_string()),
+        ..Default::default()
+    };
+    let result = ops.build_graph(options).await.expect("should build graph");
+
+    // Verify: carol and the initial commit are dropped, bob's commits connect
+    // the two alice commits
+    let authors: Vec<(&str, bool)> = result
+        .commits
+        .iter()
+        .map(|c| (c.commit.author.name.as_str(), c.filtered))
+        .collect();
+    assert_eq!(
+        authors,
+        vec![
+            ("alice.smith", false),
+            ("bob", true),
+            ("bob", true),
+            ("alice", false),
+        ]
+    );
+    assert_eq!(result.total_count, 4);
+
+    for pair in result.commits.windows(2) {
+        assert_eq!(pair[0].commit.parent_oids, vec![pair[1].commit.oid.clone()]);
+        assert_eq!(pair[0].parent_edges.len(), 1);
+        assert_eq!(pair[0].parent_edges[0].parent_oid, pair[1].commit.oid);
+    }
+    let oldest = result.commits.last().expect("should have commits");
+    assert!(oldest.commit.parent_oids.is_empty());
+    assert!(oldest.parent_edges.is_empty());
+
+    for commit in result.commits.iter().filter(|c| c.filtered) {
+        assert!(commit.commit.summary.is_empty());
+        assert!(commit.commit.message.is_empty());
+    }
+}
+
+#[tokio::test]
+async fn test_build_graph_first_parent_matches_cli() {
+    let (tmp, ops) = setup_test_repo();
+
+    // Setup: main moves on while a feature branch is worked on, then merges it
+    git_cmd(tmp.path(), &["checkout", "-b", "feature"]);
+    create_commits_by_author(tmp.path(), "bob", 2);
+    git_cmd(tmp.path(), &["checkout", "-"]);
+    create_commits_by_author(tmp.path(), "alice", 1);
+    git_cmd(
+        tmp.path(),
+        &["merge", "--no-ff", "-m", "Merge feature", "feature"],
+    );
+
+    let options = GraphOptions {
+        limit: Some(100),
+        branch_filter: BranchFilterType::Current,
+        first_parent: true,
+        ..Default::default()
+    };
+    let result = ops.build_graph(options).await.expect("should build graph");
+
+    let cli_count: usize = git_cmd(
+        tmp.path(),
+        &["rev-list", "--count", "--first-parent", "HEAD"],
+    )
+    .parse()
+    .expect("should parse count");
+    assert_eq!(result.total_count, cli_count);
+    assert!(result.commits.iter().all(|c| c.commit.author.name != "bob"));
+    // The merge keeps only its first-parent edge, so everything stays on one lane
+    assert_eq!(result.max_lane, 0);
+    assert!(result.commits.iter().all(|c| c.parent_edges.len() <= 1));
+}
+
 // ==================== search_commits Tests ====================
 
 #[tokio::test]
@@ -404,6 +645,113 @@ async fn test_blame_file_at_specific_commit() {
     assert_eq!(result.lines.len(), 1, "Should show file as it was at v1");
 }
 
+// ==================== get_blame_line_context Tests ====================
+
+/// Follow "blame before this commit" from HEAD and collect each step's context
+async fn walk_blame_back(
+    ops: &axis_lib::services::ops::RepoOperations,
+    path: &str,
+    line: usize,
+) -> Vec<axis_lib::models::BlameLineContext> {
+    let mut steps = Vec::new();
+    let mut at = Some(("HEAD".to_string(), path.to_string(), line));
+    while let Some((revision, path, line)) = at.take() {
+        let blame = ops
+            .blame_file(&path, Some(&revision))
+            .await
+            .expect("should blame");
+        let blamed = &blame.lines[line - 1];
+        let context = ops
+            .get_blame_line_context(
+                &path,
+                u32::try_from(blamed.original_line).expect("line fits"),
+                &blamed.commit_oid,
+            )
+            .await
+            .expect("should get line context");
+        if let (Some(parent), Some(previous_path), Some(previous_line)) = (
+            &context.parent_oid,
+            &context.previous_path,
+            context.previous_line_number,
+        ) {
+            at = Some((
+                parent.clone(),
+                previous_path.clone(),
+                previous_line as usize,
+            ));
+        }
+        steps.push(context);
+    }
+    steps
+}
+
+#[tokio::test]
+async fn test_blame_line_context_walks_back_across_rename() {
+    let (tmp, ops) = setup_test_repo();
+    let rev = |name: &str| git_cmd(tmp.path(), &["rev-parse", name]).trim().to_string();
+
+    std::fs::write(
+        tmp.path().join("old.txt"),
+        "alpha\nbeta\ngamma\nvalue = 1\ndelta\nepsilon\nzeta\neta\n",
+    )
+    .expect("should write");
+    git_cmd(tmp.path(), &["add", "old.txt"]);
+    git_cmd(tmp.path(), &["commit", "-m", "Add old.txt"]);
+    let first = rev("HEAD");
+
+    git_cmd(tmp.path(), &["mv", "old.txt", "new.txt"]);
+    std::fs::write(
+        tmp.path().join("new.txt"),
+        "header\nalpha\nbeta\ngamma\nvalue = 2\ndelta\nepsilon\nzeta\neta\n",
+    )
+    .expect("should write");
+    git_cmd(tmp.path(), &["add", "-A"]);
+    git_cmd(tmp.path(), &["commit", "-m", "Rename and bump value"]);
+    let second = rev("HEAD");
+
+    std::fs::write(
+        tmp.path().join("new.txt"),
+        "header\nintro\nmore intro\nalpha\nbeta\ngamma\nvalue = 3\ndelta\nepsilon\nzeta\neta\n",
+    )
+    .expect("should write");
+    git_cmd(tmp.path(), &["commit", "-am", "Bump value again"]);
+    let third = rev("HEAD");
+
+    let steps = walk_blame_back(&ops, "new.txt", 7).await;
+    let commits: Vec<&str> = steps.iter().map(|s| s.commit.oid.as_str()).collect();
+    assert_eq!(
+        commits,
+        vec![third.as_str(), second.as_str(), first.as_str()]
+    );
+
+    // The hunk shown for each step contains the blamed line as an addition
+    for step in &steps {
+        let hunk = step.hunk.as_ref().expect("should find hunk");
+        let added = hunk
+            .lines
+            .iter()
+            .find(|l| l.new_line_no == Some(step.line_number))
+            .expect("hunk should contain the line");
+        assert!(added.content.starts_with("value = "));
+    }
+
+    assert_eq!(steps[0].path, "new.txt");
+    assert_eq!(steps[0].previous_path.as_deref(), Some("new.txt"));
+    assert_eq!(steps[0].previous_content.as_deref(), Some("value = 2"));
+    assert_eq!(steps[0].previous_line_number, Some(5));
+
+    assert_eq!(steps[1].previous_path.as_deref(), Some("old.txt"));
+    assert_eq!(steps[1].previous_content.as_deref(), Some("value = 1"));
+    assert_eq!(steps[1].previous_line_number, Some(4));
+    assert_eq!(steps[1].parent_oid.as_deref(), Some(first.as_str()));
+
+    // The first commit created the file, so there is nothing before it
+    assert_eq!(steps[2].path, "old.txt");
+    assert!(steps[2].previous_path.is_none());
+    assert!(steps[2].previous_content.is_none());
+    assert!(steps[2].parent_oid.is_none());
+}
+
 // ==================== get_commit_count Tests ====================
 
 #[tokio::test]
@@ -440,6 +788,259 @@ async fn test_get_commit_count_from_ref() {
     assert!(count < total, "Count from ref should be less than total");
 }
 
+// ==================== analyze_commit_messages Tests ====================
+
+#[tokio::test]
+async fn test_analyze_commit_messages_aggregates() {
+    let (tmp, ops) = setup_test_repo();
+    create_commit_message_fixture(tmp.path());
+    let cache = Arc::new(CommitMessageStatsCache::new());
+
+    let options = CommitMessageStatsOptions {
+        branch_filter: BranchFilterType::Current,
+        until: Some(FIXTURE_END),
+        ..Default::default()
+    };
+    let stats = analyze(&ops, options, &cache).await;
+
+    assert_eq!(stats.total_commits, 8);
+    assert_eq!(stats.linted_commits, 5);
+    assert_eq!(stats.conforming_commits, 3);
+    assert_eq!(stats.with_body, 3);
+    assert_eq!(stats.with_issue_reference, 2);
+    assert_eq!((stats.merges, stats.reverts, stats.fixups), (1, 1, 1));
+    assert!(!stats.truncated);
+    assert!(!stats.cached);
+
+    let violation = |rule| RuleViolationCount { rule, commits: 1 };
+    assert_eq!(
+        stats.rule_violations,
+        vec![
+            violation(CommitLintRuleId::TypeEnum),
+            violation(CommitLintRuleId::SubjectMaxLength),
+            violation(CommitLintRuleId::SubjectFullStop),
+        ]
+    );
+
+    // Subjects of 13, 15, 20, 22, 24, 26, 28 and 81 characters
+    assert!((stats.subject_length.average - 28.625).abs() < f64::EPSILON);
+    assert_eq!(stats.subject_length.median, 22);
+    assert_eq!(stats.subject_length.max, 81);
+    let buckets: Vec<(Option<u32>, usize)> = stats
+        .subject_length
+        .buckets
+        .iter()
+        .map(|b| (b.max_length, b.commits))
+        .collect();
+    assert_eq!(
+        buckets,
+        vec![(Some(50), 7), (Some(72), 0), (Some(100), 1), (None, 0)]
+    );
+
+    let offenders: Vec<(&str, usize)> = stats
+        .worst_offenders
+        .iter()
+        .map(|o| (o.summary.as_str(), o.violations.len()))
+        .collect();
+    assert_eq!(
+        offenders,
+        vec![
+            ("Update stuff.", 2),
+            (
+                "docs: describe the login flow for the new onboarding screen and the settings page",
+                1
+            ),
+        ]
+    );
+    let oid = git_cmd(tmp.path(), &["log", "--format=%H", "--grep=^Update stuff"]);
+    assert_eq!(stats.worst_offenders[0].oid, oid);
+
+    // March 2024, then April 2024
+    let trend: Vec<(i64, usize, usize, usize)> = stats
+        .trend
+        .iter()
+        .map(|b| (b.start, b.commits, b.linted_commits, b.conforming_commits))
+        .collect();
+    assert_eq!(
+        trend,
+        vec![(1_709_251_200, 3, 3, 2), (1_711_929_600, 5, 2, 1)]
+    );
+}
+
+#[tokio::test]
+async fn test_analyze_commit_messages_weekly_trend() {
+    let (tmp, ops) = setup_test_repo();
+    create_commit_message_fixture(tmp.path());
+    let cache = Arc::new(CommitMessageStatsCache::new());
+
+    let options = CommitMessageStatsOptions {
+        until: Some(FIXTURE_END),
+        bucket: StatsBucket::Week,
+        ..Default::default()
+    };
+    let stats = analyze(&ops, options, &cache).await;
+
+    // Mondays of the weeks with commits
+    let trend: Vec<(i64, usize, usize, usize)> = stats
+        .trend
+        .iter()
+        .map(|b| (b.start, b.commits, b.linted_commits, b.conforming_commits))
+        .collect();
+    assert_eq!(
+        trend,
+        vec![
+            (1_709_510_400, 1, 1, 1),
+            (1_710_115_200, 1, 1, 1),
+            (1_710_720_000, 1, 1, 0),
+            (1_711_929_600, 1, 0, 0),
+            (1_712_534_400, 1, 1, 0),
+            (1_713_139_200, 3, 1, 1),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_analyze_commit_messages_filters_and_limit() {
+    let (tmp, ops) = setup_test_repo();
+    create_commit_message_fixture(tmp.path());
+    let cache = Arc::new(CommitMessageStatsCache::new());
+
+    let by_author = CommitMessageStatsOptions {
+        author: Some("ALICE@example".to_string()),
+        ..Default::default()
+    };
+    let stats = analyze(&ops, by_author, &cache).await;
+    assert_eq!(stats.total_commits, 1);
+    assert_eq!(stats.conforming_commits, 1);
+
+    // April 2024 only
+    let april = CommitMessageStatsOptions {
+        since: Some(1_711_929_600),
+        until: Some(FIXTURE_END),
+        ..Default::default()
+    };
+    let stats = analyze(&ops, april, &cache).await;
+    assert_eq!(stats.total_commits, 5);
+    assert_eq!(stats.linted_commits, 2);
+
+    let limited = CommitMessageStatsOptions {
+        until: Some(FIXTURE_END),
+        limit: Some(2),
+        ..Default::default()
+    };
+    let stats = analyze(&ops, limited, &cache).await;
+    assert_eq!(stats.total_commits, 2);
+    assert!(stats.truncated);
+}
+
+#[tokio::test]
+async fn test_analyze_commit_messages_cache_hit() {
+    let (tmp, ops) = setup_test_repo();
+    create_commit_message_fixture(tmp.path());
+    let cache = Arc::new(CommitMessageStatsCache::new());
+    let options = CommitMessageStatsOptions {
+        until: Some(FIXTURE_END),
+        ..Default::default()
+    };
+
+    let first = analyze(&ops, options.clone(), &cache).await;
+    let second = analyze(&ops, options.clone(), &cache).await;
+    assert!(!first.cached);
+    assert!(second.cached);
+    assert_eq!(
+        CommitMessageStats {
+            cached: false,
+            ..second
+        },
+        first
+    );
+
+    // A new commit moves the tip, so the history is walked again
+    commit_message_at(tmp.path(), "2024-04-25T10:00:00Z", "test: cover login");
+    let third = analyze(&ops, options, &cache).await;
+    assert!(!third.cached);
+    assert_eq!(third.total_commits, first.total_commits + 1);
+}
+
+#[tokio::test]
+async fn test_analyze_commit_messages_cancelled() {
+    let (tmp, ops) = setup_test_repo();
+    create_commit_message_fixture(tmp.path());
+
+    let result = ops
+        .analyze_commit_messages(
+            CommitMessageStatsOptions::default(),
+            CommitLintRules::default(),
+            Arc::new(CommitMessageStatsCache::new()),
+            Box::new(|_| false),
+        )
+        .await;
+    assert!(matches!(result, Err(AxisError::Other(_))));
+}
+
+// ==================== Changelog Tests ====================
+
+#[tokio::test]
+async fn test_changelog_between_tags() {
+    let (tmp, ops) = setup_test_repo();
+    git_cmd(tmp.path(), &["tag", "v1.0.0"]);
+    create_searchable_commits(tmp.path());
+    std::fs::write(tmp.path().join("api.txt"), "v2").expect("should write");
+    git_cmd(tmp.path(), &["add", "api.txt"]);
+    git_cmd(
+        tmp.path(),
+        &[
+            "commit",
+            "-m",
+            "feat(api)!: drop v1 endpoints\n\nBREAKING CHANGE: clients must use /v2",
+        ],
+    );
+
+    let commits = ops
+        .commits_in_range("v1.0.0", "HEAD")
+        .await
+        .expect("should list commits");
+    assert_eq!(commits.len(), 6);
+
+    let changelog = build_changelog(
+        "v2.0.0",
+        &commits,
+        &ChangelogSection::defaults(),
+        &ChangelogOptions::default(),
+        None,
+    );
+    let headings: Vec<&str> = changelog
+        .groups
+        .iter()
+        .map(|group| group.heading.as_str())
+        .collect();
+    assert_eq!(
+        headings,
+        [
+            "Features",
+            "Bug Fixes",
+            "Documentation",
+            CHANGELOG_OTHER_HEADING
+        ]
+    );
+    assert_eq!(changelog.groups[0].entries.len(), 3);
+    assert_eq!(changelog.breaking_changes.len(), 1);
+    assert_eq!(
+        changelog.breaking_changes[0].breaking_note.as_deref(),
+        Some("clients must use /v2")
+    );
+    assert!(changelog.markdown.starts_with("## v2.0.0"));
+}
+
+#[tokio::test]
+async fn test_commits_in_range_invalid_ref() {
+    let (_tmp, ops) = setup_test_repo();
+
+    let result = ops.commits_in_range("no-such-tag", "HEAD").await;
+
+    assert!(matches!(result, Err(AxisError::InvalidReference(_))));
+}
+
 // ==================== Edge Cases ====================
 
 #[tokio::test]
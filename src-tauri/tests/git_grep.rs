@@ -4,7 +4,8 @@ mod common;
 
 use common::{git_cmd, setup_test_repo};
 
-use axis_lib::models::GrepOptions;
+use axis_lib::error::AxisError;
+use axis_lib::models::{DiffLineType, GrepOptions};
 
 // ==================== Helpers ====================
 
@@ -538,3 +539,298 @@ async fn test_grep_multiple_files() {
         "Should find same number of files as CLI"
     );
 }
+
+// ==================== search_content Tests ====================
+
+/// Write a file, creating parent directories
+fn write_file(path: &std::path::Path, name: &str, content: &str) {
+    let file = path.join(name);
+    if let Some(parent) = file.parent() {
+        std::fs::create_dir_all(parent).expect("should create dir");
+    }
+    std::fs::write(file, content).expect("should write");
+}
+
+#[tokio::test]
+async fn test_search_content_regex_with_globs() {
+    let (tmp, ops) = setup_test_repo();
+    write_file(
+        tmp.path(),
+        "src/lib.rs",
+        "// header\nfn alpha() {}\n// footer\n",
+    );
+    write_file(tmp.path(), "src/notes.txt", "fn beta() {}\n");
+    write_file(tmp.path(), "vendor/dep/lib.rs", "fn gamma() {}\n");
+    git_cmd(tmp.path(), &["add", "."]);
+    git_cmd(tmp.path(), &["commit", "-m", "Add sources"]);
+
+    let options = GrepOptions {
+        pattern: r"fn \w+\(".to_string(),
+        extended_regexp: true,
+        show_line_numbers: true,
+        context_lines: Some(1),
+        include: vec!["*.rs".to_string()],
+        exclude: vec!["vendor/**".to_string()],
+        ..Default::default()
+    };
+    let result = ops
+        .search_content(options, None)
+        .await
+        .expect("should search");
+
+    assert_eq!(result.total_matches, 1);
+    assert!(!result.has_more);
+    let m = &result.matches[0];
+    assert_eq!(m.path, "src/lib.rs");
+    assert_eq!(m.line_number, Some(2));
+    assert_eq!(m.content, "fn alpha() {}");
+    assert_eq!(m.ranges.len(), 1);
+    assert_eq!(&m.content[m.ranges[0].start..m.ranges[0].end], "fn alpha(");
+    assert_eq!(m.context_before, vec!["// header"]);
+    assert_eq!(m.context_after, vec!["// footer"]);
+}
+
+#[tokio::test]
+async fn test_search_content_at_older_commit() {
+    let (tmp, ops) = setup_test_repo();
+    write_file(tmp.path(), "config.toml", "timeout = 30\nretries = 3\n");
+    git_cmd(tmp.path(), &["add", "."]);
+    git_cmd(tmp.path(), &["commit", "-m", "Add config"]);
+    let old_oid = git_head_oid(tmp.path());
+    write_file(tmp.path(), "config.toml", "timeout = 60\n");
+    git_cmd(tmp.path(), &["commit", "-am", "Tune config"]);
+
+    let options = GrepOptions {
+        pattern: "TIMEOUT = [0-9]+|retries".to_string(),
+        extended_regexp: true,
+        ignore_case: true,
+        ..Default::default()
+    };
+    let current = ops
+        .search_content(options.clone(), None)
+        .await
+        .expect("should search worktree");
+    let old = ops
+        .search_content(
+            GrepOptions {
+                git_ref: Some(old_oid),
+                ..options
+            },
+            None,
+        )
+        .await
+        .expect("should search commit");
+
+    let contents = |r: &axis_lib::models::GrepResult| -> Vec<String> {
+        r.matches.iter().map(|m| m.content.clone()).collect()
+    };
+    assert_eq!(contents(&current), vec!["timeout = 60"]);
+    assert_eq!(contents(&old), vec!["timeout = 30", "retries = 3"]);
+}
+
+#[tokio::test]
+async fn test_search_content_literal_by_default() {
+    let (tmp, ops) = setup_test_repo();
+    write_file(tmp.path(), "file.txt", "a.b\naxb\n");
+    git_cmd(tmp.path(), &["add", "."]);
+    git_cmd(tmp.path(), &["commit", "-m", "Add file"]);
+
+    let options = GrepOptions {
+        pattern: "a.b".to_string(),
+        ..Default::default()
+    };
+    let result = ops
+        .search_content(options, None)
+        .await
+        .expect("should search");
+
+    assert_eq!(result.total_matches, 1);
+    assert_eq!(result.matches[0].content, "a.b");
+}
+
+#[tokio::test]
+async fn test_search_content_invalid_regex_fails() {
+    let (_tmp, ops) = setup_test_repo();
+
+    let options = GrepOptions {
+        pattern: "fn (".to_string(),
+        extended_regexp: true,
+        ..Default::default()
+    };
+    let result = ops.search_content(options, None).await;
+
+    let err = result.expect_err("unbalanced group should be rejected");
+    assert!(
+        err.to_string().starts_with("Invalid search pattern"),
+        "unexpected error: {err}"
+    );
+}
+
+#[tokio::test]
+async fn test_search_content_skips_binary_and_caps_results() {
+    let (tmp, ops) = setup_test_repo();
+    std::fs::write(tmp.path().join("asset.bin"), b"needle\x00needle").expect("should write");
+    write_file(tmp.path(), "b.txt", "needle\nneedle\n");
+    write_file(tmp.path(), "c.txt", "needle\n");
+    git_cmd(tmp.path(), &["add", "."]);
+    git_cmd(tmp.path(), &["commit", "-m", "Add files"]);
+
+    let options = GrepOptions {
+        pattern: "needle".to_string(),
+        max_results: Some(2),
+        ..Default::default()
+    };
+    let mut pages = Vec::new();
+    let (tx, rx) = std::sync::mpsc::channel();
+    let result = ops
+        .search_content(
+            options,
+            Some(Box::new(move |page| {
+                tx.send(page).expect("should send page");
+            })),
+        )
+        .await
+        .expect("should search");
+    pages.extend(rx.try_iter());
+
+    assert_eq!(result.skipped_binary_files, 1);
+    assert_eq!(result.total_matches, 2);
+    assert!(result.has_more);
+    assert!(
+        result.matches.is_empty(),
+        "Streamed matches are not buffered"
+    );
+    assert_eq!(pages.concat().len(), 2);
+}
+
+// ==================== grep_diff Tests ====================
+
+#[tokio::test]
+async fn test_grep_diff_matches_added_and_context_lines() {
+    let (tmp, ops) = setup_test_repo();
+    write_file(
+        tmp.path(),
+        "src/lib.rs",
+        "fn parse_config() {}\nfn other() {}\nfn legacy_config() {}\n",
+    );
+    git_cmd(tmp.path(), &["add", "."]);
+    git_cmd(tmp.path(), &["commit", "-m", "Add lib"]);
+
+    // Remove one match, add another, and stage a new file
+    write_file(
+        tmp.path(),
+        "src/lib.rs",
+        "fn parse_config() {}\nfn other() {}\nfn load_config() {}\n",
+    );
+    write_file(tmp.path(), "src/new.rs", "use crate::CONFIG;\n");
+    write_file(tmp.path(), "unstaged.rs", "fn config() {}\n");
+    git_cmd(tmp.path(), &["add", "src"]);
+
+    let matches = ops
+        .grep_diff("config", true, 3)
+        .await
+        .expect("should grep diff");
+
+    let lib = matches
+        .iter()
+        .find(|m| m.file == "src/lib.rs")
+        .expect("should match lib.rs");
+    assert!(lib.hunk_header.starts_with("@@"));
+    let lines: Vec<(&str, DiffLineType)> = lib
+        .matching_lines
+        .iter()
+        .map(|l| (l.content.trim_end(), l.line_type.clone()))
+        .collect();
+    assert_eq!(
+        lines,
+        vec![
+            ("fn parse_config() {}", DiffLineType::Context),
+            ("fn load_config() {}", DiffLineType::Addition),
+        ]
+    );
+
+    // Case-sensitive, so only the lowercase occurrences in lib.rs match
+    assert_eq!(matches.len(), 1);
+
+    let matches = ops
+        .grep_diff("config", false, 3)
+        .await
+        .expect("should grep diff");
+    let files: Vec<&str> = matches.iter().map(|m| m.file.as_str()).collect();
+    assert_eq!(files, vec!["src/lib.rs", "src/new.rs"]);
+}
+
+#[tokio::test]
+async fn test_grep_diff_context_lines_limit_scope() {
+    let (tmp, ops) = setup_test_repo();
+    let content: String = (1..=10).map(|i| format!("line {i}\n")).collect();
+    write_file(tmp.path(), "notes.txt", &format!("marker\n{content}"));
+    git_cmd(tmp.path(), &["add", "."]);
+    git_cmd(tmp.path(), &["commit", "-m", "Add notes"]);
+
+    write_file(tmp.path(), "notes.txt", &format!("marker\n{content}end\n"));
+    git_cmd(tmp.path(), &["add", "."]);
+
+    // The marker is 11 lines above the change, outside a 3-line context
+    let matches = ops
+        .grep_diff("marker", true, 3)
+        .await
+        .expect("should grep diff");
+    assert!(matches.is_empty());
+
+    let matches = ops
+        .grep_diff("marker", true, 20)
+        .await
+        .expect("should grep diff");
+    assert_eq!(matches.len(), 1);
+    assert_eq!(
+        matches[0].matching_lines[0].line_type,
+        DiffLineType::Context
+    );
+}
+
+#[tokio::test]
+async fn test_grep_diff_invalid_pattern_fails() {
+    let (_tmp, ops) = setup_test_repo();
+    let result = ops.grep_diff("(", true, 3).await;
+    assert!(matches!(result, Err(AxisError::InvalidSearchPattern(_))));
+}
+
+#[tokio::test]
+async fn test_grep_branch_searches_without_checkout() {
+    let (tmp, ops) = setup_test_repo();
+    let main = git_cmd(tmp.path(), &["rev-parse", "--abbrev-ref", "HEAD"]);
+    git_cmd(tmp.path(), &["checkout", "-b", "feature"]);
+    write_file(tmp.path(), "src/lib.rs", "fn feature_flag() {}\n");
+    write_file(tmp.path(), "docs/notes.md", "feature_flag docs\n");
+    git_cmd(tmp.path(), &["add", "."]);
+    git_cmd(tmp.path(), &["commit", "-m", "Add feature flag"]);
+    git_cmd(tmp.path(), &["checkout", &main]);
+
+    let result = ops
+        .grep_branch("feature_fl[a-z]+", "feature", None)
+        .await
+        .expect("should search branch");
+    let mut paths: Vec<_> = result.matches.iter().map(|m| m.path.as_str()).collect();
+    paths.sort_unstable();
+    assert_eq!(paths, vec!["docs/notes.md", "src/lib.rs"]);
+    assert_eq!(result.matches[0].line_number, Some(1));
+    assert!(
+        !tmp.path().join("src/lib.rs").exists(),
+        "Working tree should stay on {main}"
+    );
+
+    let filtered = ops
+        .grep_branch("feature_flag", "feature", Some("src"))
+        .await
+        .expect("should search branch");
+    assert_eq!(filtered.total_matches, 1);
+    assert_eq!(filtered.matches[0].path, "src/lib.rs");
+}
+
+#[tokio::test]
+async fn test_grep_branch_unknown_branch_fails() {
+    let (_tmp, ops) = setup_test_repo();
+    let result = ops.grep_branch("x", "missing", None).await;
+    assert!(matches!(result, Err(AxisError::BranchNotFound(name)) if name == "missing"));
+}
@@ -7,7 +7,11 @@
 
 mod common;
 
-use axis_lib::models::GitHookType;
+use std::sync::Arc;
+
+use axis_lib::models::{GitHookType, HookTrustDecision};
+use axis_lib::services::ops::RepoOperations;
+use axis_lib::services::{GitService, MemoryHookTrustStore};
 use common::*;
 
 // ==================== Local Helper Functions ====================
@@ -67,6 +71,35 @@ fn hook_is_executable(_path: &std::path::Path, _hook_type: GitHookType) -> bool
     true // Windows doesn't have executable permission
 }
 
+/// Open ops over an existing repo with a trust store that has no decisions
+fn untrusted_ops(path: &std::path::Path) -> (RepoOperations, Arc<MemoryHookTrustStore>) {
+    let store = Arc::new(MemoryHookTrustStore::new());
+    let service = GitService::new_for_test_with_hook_trust(path, store.clone())
+        .expect("should create GitService");
+    (RepoOperations::new(Arc::new(service)), store)
+}
+
+/// Write a pre-commit hook that appends to a marker file each time it runs
+fn write_marker_hook(path: &std::path::Path, extra: &str) -> std::path::PathBuf {
+    let marker = path.join("hook-ran");
+    write_hook(
+        path,
+        GitHookType::PreCommit,
+        &format!(
+            "#!/bin/sh\necho ran >> '{}'\n{extra}exit 0\n",
+            marker.display()
+        ),
+    );
+    marker
+}
+
+/// Number of times the marker hook ran
+fn marker_runs(marker: &std::path::Path) -> usize {
+    std::fs::read_to_string(marker)
+        .map(|s| s.lines().count())
+        .unwrap_or(0)
+}
+
 // ==================== list_hooks Tests ====================
 
 #[tokio::test]
@@ -525,3 +558,218 @@ async fn test_hook_with_output() {
         "Should capture hook output"
     );
 }
+
+// ==================== Hook Trust Tests ====================
+
+#[tokio::test]
+async fn test_untrusted_hook_pauses_without_running() {
+    let (tmp, _) = setup_test_repo();
+    let marker = write_marker_hook(tmp.path(), "");
+    let (ops, _store) = untrusted_ops(tmp.path());
+
+    // Action: run pre-commit with no decision recorded
+    let result = ops.run_pre_commit(None).await;
+
+    // Verify: paused with a prompt, hook not executed
+    assert!(!result.success);
+    assert!(!result.skipped);
+    let request = result.trust_request.expect("should request trust");
+    assert_eq!(request.hook_type, Some(GitHookType::PreCommit));
+    assert_eq!(request.hooks.len(), 1);
+    assert_eq!(request.hooks[0].hook_type, GitHookType::PreCommit);
+    assert_eq!(marker_runs(&marker), 0);
+    assert_eq!(
+        ops.pending_hook_trust_request().map(|r| r.hooks.len()),
+        Some(1)
+    );
+}
+
+#[tokio::test]
+async fn test_trust_always_runs_hook() {
+    let (tmp, _) = setup_test_repo();
+    let marker = write_marker_hook(tmp.path(), "");
+    let (ops, _store) = untrusted_ops(tmp.path());
+
+    let request = ops
+        .run_pre_commit(None)
+        .await
+        .trust_request
+        .expect("should request trust");
+
+    // Action: trust always, then run twice
+    ops.resolve_hook_trust(&request.hooks, HookTrustDecision::TrustAlways)
+        .expect("should resolve trust");
+    let first = ops.run_pre_commit(None).await;
+    let second = ops.run_pre_commit(None).await;
+
+    // Verify: both runs executed the hook
+    assert!(first.success && second.success);
+    assert_eq!(marker_runs(&marker), 2);
+    assert!(ops.pending_hook_trust_request().is_none());
+}
+
+#[tokio::test]
+async fn test_modified_hook_prompts_again() {
+    let (tmp, _) = setup_test_repo();
+    write_marker_hook(tmp.path(), "");
+    let (ops, _store) = untrusted_ops(tmp.path());
+
+    let request = ops
+        .run_pre_commit(None)
+        .await
+        .trust_request
+        .expect("should request trust");
+    ops.resolve_hook_trust(&request.hooks, HookTrustDecision::TrustAlways)
+        .expect("should resolve trust");
+
+    // Action: change the hook content after trusting it
+    let marker = write_marker_hook(tmp.path(), "echo changed\n");
+    let result = ops.run_pre_commit(None).await;
+
+    // Verify: new content needs a new decision
+    let new_request = result.trust_request.expect("should request trust again");
+    assert_ne!(
+        new_request.hooks[0].content_hash,
+        request.hooks[0].content_hash
+    );
+    assert_eq!(marker_runs(&marker), 0);
+}
+
+#[tokio::test]
+async fn test_never_trusted_hook_is_skipped_and_logged() {
+    let (tmp, _) = setup_test_repo();
+    let marker = write_marker_hook(tmp.path(), "");
+    let (ops, store) = untrusted_ops(tmp.path());
+
+    let request = ops
+        .run_pre_commit(None)
+        .await
+        .trust_request
+        .expect("should request trust");
+    ops.resolve_hook_trust(&request.hooks, HookTrustDecision::Never)
+        .expect("should resolve trust");
+
+    // Action: run again
+    let result = ops.run_pre_commit(None).await;
+
+    // Verify: skipped without running, skip recorded
+    assert!(result.skipped);
+    assert!(result.untrusted);
+    assert!(result.trust_request.is_none());
+    assert_eq!(marker_runs(&marker), 0);
+    assert_eq!(
+        store.untrusted_skips(),
+        vec![(
+            GitHookType::PreCommit,
+            request.hooks[0].content_hash.clone()
+        )]
+    );
+}
+
+#[tokio::test]
+async fn test_trust_once_runs_single_time() {
+    let (tmp, _) = setup_test_repo();
+    let marker = write_marker_hook(tmp.path(), "");
+    let (ops, _store) = untrusted_ops(tmp.path());
+
+    let request = ops
+        .run_pre_commit(None)
+        .await
+        .trust_request
+        .expect("should request trust");
+    ops.resolve_hook_trust(&request.hooks, HookTrustDecision::TrustOnce)
+        .expect("should resolve trust");
+
+    // Action: run twice
+    let first = ops.run_pre_commit(None).await;
+    let second = ops.run_pre_commit(None).await;
+
+    // Verify: first run executed, second prompts again
+    assert!(first.success);
+    assert!(second.trust_request.is_some());
+    assert_eq!(marker_runs(&marker), 1);
+}
+
+#[tokio::test]
+async fn test_hook_created_through_axis_is_trusted() {
+    let (tmp, _) = setup_test_repo();
+    let (ops, _store) = untrusted_ops(tmp.path());
+
+    // Action: author the hook through ops, then run it
+    ops.create_hook(GitHookType::PreCommit, "#!/bin/sh\nexit 0\n")
+        .expect("should create hook");
+    let result = ops.run_pre_commit(None).await;
+
+    // Verify: ran without a prompt
+    assert!(result.success);
+    assert!(result.trust_request.is_none());
+}
+
+// ==================== Hooks Run By Git Tests ====================
+
+/// Diverge `feature` from the current branch so merging it creates a merge commit,
+/// and add a pre-merge-commit hook that records each run
+fn setup_merge_with_hook(path: &std::path::Path) -> std::path::PathBuf {
+    let main = git_cmd(path, &["branch", "--show-current"]);
+    git_cmd(path, &["checkout", "-b", "feature"]);
+    std::fs::write(path.join("feature.txt"), "feature").expect("should write");
+    git_cmd(path, &["add", "feature.txt"]);
+    git_cmd(path, &["commit", "-m", "Feature"]);
+    git_cmd(path, &["checkout", &main]);
+    std::fs::write(path.join("main.txt"), "main").expect("should write");
+    git_cmd(path, &["add", "main.txt"]);
+    git_cmd(path, &["commit", "-m", "Main"]);
+
+    let marker = path.join("merge-hook-ran");
+    write_hook(
+        path,
+        GitHookType::PreMergeCommit,
+        &format!("#!/bin/sh\necho ran >> '{}'\nexit 0\n", marker.display()),
+    );
+    marker
+}
+
+#[tokio::test]
+async fn test_untrusted_pre_merge_commit_hook_does_not_run_during_merge() {
+    let (tmp, _) = setup_test_repo();
+    let marker = setup_merge_with_hook(tmp.path());
+    let (ops, _store) = untrusted_ops(tmp.path());
+
+    // Action: merge through the git CLI with the hook undecided
+    let result = ops
+        .merge("feature", None, true, false, false, false, None)
+        .await
+        .expect("should run merge");
+
+    // Verify: merge commit created, hook not executed by git
+    assert!(result.success, "{}", result.stderr);
+    assert_eq!(
+        git_cmd(tmp.path(), &["rev-list", "--count", "--merges", "HEAD"]),
+        "1"
+    );
+    assert_eq!(marker_runs(&marker), 0);
+    assert!(!ops.git_may_run_hooks());
+}
+
+#[tokio::test]
+async fn test_trusted_pre_merge_commit_hook_runs_during_merge() {
+    let (tmp, _) = setup_test_repo();
+    let marker = setup_merge_with_hook(tmp.path());
+    let (ops, _store) = untrusted_ops(tmp.path());
+    let request = ops
+        .pending_hook_trust_request()
+        .expect("should request trust");
+    ops.resolve_hook_trust(&request.hooks, HookTrustDecision::TrustAlways)
+        .expect("should resolve trust");
+
+    // Action: merge once every hook is trusted
+    let result = ops
+        .merge("feature", None, true, false, false, false, None)
+        .await
+        .expect("should run merge");
+
+    // Verify: git ran the hook from the real hooks directory
+    assert!(result.success, "{}", result.stderr);
+    assert!(ops.git_may_run_hooks());
+    assert_eq!(marker_runs(&marker), 1);
+}
@@ -0,0 +1,161 @@
+#![cfg(feature = "integration")]
+
+mod common;
+
+use common::{git_cmd, setup_test_repo};
+
+use axis_lib::models::{MaintenanceOptions, MaintenanceTask};
+
+// ==================== Helper ====================
+
+/// Commit `count` small files, one commit each, leaving their objects loose
+fn make_small_commits(path: &std::path::Path, count: usize) {
+    for i in 0..count {
+        std::fs::write(path.join(format!("file{i}.txt")), format!("content {i}\n"))
+            .expect("should write file");
+        git_cmd(path, &["add", "."]);
+        git_cmd(path, &["commit", "-m", &format!("Commit {i}")]);
+    }
+}
+
+// ==================== Size Report Tests ====================
+
+#[tokio::test]
+async fn test_size_report_counts_loose_objects_and_reflogs() {
+    let (tmp, ops) = setup_test_repo();
+    git_cmd(tmp.path(), &["config", "gc.auto", "0"]);
+    make_small_commits(tmp.path(), 10);
+    std::fs::write(tmp.path().join("big.bin"), vec![b'x'; 64 * 1024]).expect("should write");
+    git_cmd(tmp.path(), &["add", "."]);
+    git_cmd(tmp.path(), &["commit", "-m", "Add big file"]);
+
+    let report = ops
+        .get_repository_size_report(3)
+        .await
+        .expect("should build size report");
+
+    // Initial commit (3 objects), 10 commits and the big file commit (3 objects each)
+    assert_eq!(report.objects.loose_objects, 36);
+    assert!(report.objects.loose_size > 0);
+    assert_eq!(report.objects.packs, 0);
+    assert_eq!(report.objects.packed_objects, 0);
+
+    assert_eq!(report.largest_blobs.len(), 3);
+    assert_eq!(report.largest_blobs[0].size, 64 * 1024);
+    assert_eq!(report.largest_blobs[0].path.as_deref(), Some("big.bin"));
+    assert!(report.largest_blobs[1].size <= report.largest_blobs[0].size);
+
+    let head = report
+        .reflogs
+        .iter()
+        .find(|r| r.ref_name == "HEAD")
+        .expect("should report the HEAD reflog");
+    assert_eq!(head.entries, 12);
+}
+
+// ==================== Maintenance Tests ====================
+
+#[tokio::test]
+async fn test_run_maintenance_packs_loose_objects() {
+    let (tmp, ops) = setup_test_repo();
+    git_cmd(tmp.path(), &["config", "gc.auto", "0"]);
+    make_small_commits(tmp.path(), 20);
+
+    let mut phases = Vec::new();
+    let result = ops
+        .run_maintenance(
+            &MaintenanceOptions {
+                tasks: vec![MaintenanceTask::Gc, MaintenanceTask::CommitGraph],
+                aggressive: false,
+            },
+            &mut |task, phase, _, _| {
+                phases.push((task, phase.to_string()));
+                true
+            },
+        )
+        .await
+        .expect("should run maintenance");
+
+    assert_eq!(
+        result.tasks,
+        vec![MaintenanceTask::Gc, MaintenanceTask::CommitGraph]
+    );
+    assert_eq!(result.before.loose_objects, 63);
+    assert!(result.after.loose_objects < result.before.loose_objects);
+    assert_eq!(result.after.packs, 1);
+    assert_eq!(result.after.packed_objects, 63);
+    assert!(phases.contains(&(MaintenanceTask::Gc, String::new())));
+    assert!(phases.contains(&(MaintenanceTask::CommitGraph, String::new())));
+    assert!(tmp.path().join(".git/objects/info/commit-graph").exists());
+}
+
+#[tokio::test]
+async fn test_run_maintenance_cancelled_before_start_changes_nothing() {
+    let (tmp, ops) = setup_test_repo();
+    git_cmd(tmp.path(), &["config", "gc.auto", "0"]);
+    make_small_commits(tmp.path(), 2);
+
+    let result = ops
+        .run_maintenance(
+            &MaintenanceOptions {
+                tasks: vec![MaintenanceTask::Gc],
+                aggressive: false,
+            },
+            &mut |_, _, _, _| false,
+        )
+        .await;
+
+    assert!(result.is_err());
+    let report = ops
+        .get_repository_size_report(0)
+        .await
+        .expect("should build size report");
+    assert_eq!(report.objects.packs, 0);
+    assert!(report.largest_blobs.is_empty());
+}
+
+/// Backdate a loose object past prune's two-week expiry
+#[cfg(unix)]
+fn age_loose_object(path: &std::path::Path, oid: &str) {
+    let object = path.join(".git/objects").join(&oid[..2]).join(&oid[2..]);
+    std::fs::File::open(object)
+        .expect("should open loose object")
+        .set_modified(std::time::SystemTime::now() - std::time::Duration::from_secs(30 * 86_400))
+        .expect("should backdate loose object");
+}
+
+#[cfg(unix)]
+#[tokio::test]
+async fn test_prune_dry_run_lists_unreachable_objects() {
+    let (tmp, ops) = setup_test_repo();
+    std::fs::write(tmp.path().join("orphan.txt"), "nobody references me\n")
+        .expect("should write file");
+    let orphan = git_cmd(tmp.path(), &["hash-object", "-w", "orphan.txt"]);
+    age_loose_object(tmp.path(), &orphan);
+
+    let result = ops.prune_dry_run().await.expect("should list candidates");
+
+    let object = result
+        .objects
+        .iter()
+        .find(|o| o.oid == orphan)
+        .expect("should list the unreachable blob");
+    assert_eq!(object.object_type, "blob");
+    assert!(object.size > 0);
+    assert!(result.total_size >= object.size);
+
+    // A dry run deletes nothing
+    git_cmd(tmp.path(), &["cat-file", "-e", &orphan]);
+}
+
+#[tokio::test]
+async fn test_prune_dry_run_skips_recent_objects() {
+    let (tmp, ops) = setup_test_repo();
+    std::fs::write(tmp.path().join("fresh.txt"), "written just now\n").expect("should write file");
+    let fresh = git_cmd(tmp.path(), &["hash-object", "-w", "fresh.txt"]);
+
+    let result = ops.prune_dry_run().await.expect("should list candidates");
+
+    // Within the expiry window, like `git gc`'s own prune
+    assert!(result.objects.iter().all(|o| o.oid != fresh));
+}
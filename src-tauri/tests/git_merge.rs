@@ -4,7 +4,13 @@ mod common;
 
 use common::{git_cmd, setup_test_repo};
 
-use axis_lib::services::ConflictVersion;
+use axis_lib::error::AxisError;
+use axis_lib::models::{
+    CherryPickOptions, CommitTrailerOptions, ConflictHunk, ConflictHunkChoice, ConflictType,
+    InteractiveRebaseEntry, MergeStrategy, OperationCommit, OperationState, PreconditionViolation,
+    RebaseAction,
+};
+use axis_lib::services::{ConfigOverride, ConflictVersion, ScopedConfigOverride};
 
 // ==================== Helpers ====================
 
@@ -77,6 +83,15 @@ fn create_feature_branch(path: &std::path::Path, branch_name: &str, file: &str,
     git_cmd(path, &["checkout", &default_branch]);
 }
 
+/// Snapshot HEAD, index and working tree state via CLI
+fn git_worktree_snapshot(path: &std::path::Path) -> (String, String, String) {
+    (
+        git_head_oid(path),
+        git_cmd(path, &["ls-files", "--stage"]),
+        git_cmd(path, &["status", "--porcelain", "--untracked-files=all"]),
+    )
+}
+
 // ==================== Merge Tests ====================
 
 #[tokio::test]
@@ -90,7 +105,7 @@ async fn test_merge_fast_forward_verified_by_cli() {
 
     // Action: RepoOperations merges
     let result = ops
-        .merge("feature", None, false, false, false, false)
+        .merge("feature", None, false, false, false, false, None)
         .await
         .expect("should merge");
 
@@ -117,7 +132,15 @@ async fn test_merge_no_ff_creates_merge_commit() {
 
     // Action: merge with --no-ff
     let result = ops
-        .merge("feature", Some("Merge feature"), true, false, false, false)
+        .merge(
+            "feature",
+            Some("Merge feature"),
+            true,
+            false,
+            false,
+            false,
+            None,
+        )
         .await
         .expect("should merge");
 
@@ -156,7 +179,7 @@ async fn test_merge_with_conflict_detected() {
 
     // Action: merge (should conflict)
     let result = ops
-        .merge("feature", None, false, false, false, false)
+        .merge("feature", None, false, false, false, false, None)
         .await
         .expect("should complete");
 
@@ -177,6 +200,108 @@ async fn test_merge_with_conflict_detected() {
     );
 }
 
+/// Commit `conflict.txt` on the current branch and a conflicting version on `feature`
+fn setup_conflicting_merge(path: &std::path::Path) {
+    let base = git_head_oid(path);
+    std::fs::write(path.join("conflict.txt"), "main content\n").expect("should write");
+    std::fs::write(path.join("main-only.txt"), "main\n").expect("should write");
+    git_cmd(path, &["add", "."]);
+    git_cmd(path, &["commit", "-m", "Main changes"]);
+    create_conflicting_branch_from(path, "feature", &base, "conflict.txt", "feature content\n");
+}
+
+#[tokio::test]
+async fn test_merge_strategy_option_theirs_resolves_conflicts() {
+    let (tmp, ops) = setup_test_repo();
+    setup_conflicting_merge(tmp.path());
+
+    let result = ops
+        .merge(
+            "feature",
+            None,
+            false,
+            false,
+            false,
+            false,
+            Some(MergeStrategy::Theirs),
+        )
+        .await
+        .expect("should run merge");
+
+    assert!(result.success, "stderr: {}", result.stderr);
+    assert!(!git_is_merging(tmp.path()));
+    assert_eq!(
+        std::fs::read_to_string(tmp.path().join("conflict.txt")).expect("should read"),
+        "feature content\n"
+    );
+    assert!(tmp.path().join("main-only.txt").exists());
+    assert_eq!(
+        git_cmd(tmp.path(), &["rev-list", "--count", "--merges", "HEAD"]),
+        "1"
+    );
+}
+
+#[tokio::test]
+async fn test_merge_strategy_option_ours_resolves_conflicts() {
+    let (tmp, ops) = setup_test_repo();
+    setup_conflicting_merge(tmp.path());
+
+    let result = ops
+        .merge(
+            "feature",
+            None,
+            false,
+            false,
+            false,
+            false,
+            Some(MergeStrategy::Ours),
+        )
+        .await
+        .expect("should run merge");
+
+    assert!(result.success, "stderr: {}", result.stderr);
+    assert_eq!(
+        std::fs::read_to_string(tmp.path().join("conflict.txt")).expect("should read"),
+        "main content\n"
+    );
+}
+
+#[tokio::test]
+async fn test_merge_ours_strategy_keeps_current_tree() {
+    let (tmp, ops) = setup_test_repo();
+    setup_conflicting_merge(tmp.path());
+    std::fs::write(tmp.path().join("feature-only.txt"), "feature\n").expect("should write");
+    git_cmd(tmp.path(), &["checkout", "-q", "feature"]);
+    git_cmd(tmp.path(), &["add", "feature-only.txt"]);
+    git_cmd(tmp.path(), &["commit", "-m", "Feature-only file"]);
+    git_cmd(tmp.path(), &["checkout", "-q", "-"]);
+    let tree_before = git_cmd(tmp.path(), &["rev-parse", "HEAD^{tree}"]);
+
+    let result = ops
+        .merge(
+            "feature",
+            None,
+            false,
+            false,
+            false,
+            false,
+            Some(MergeStrategy::OursStrategy),
+        )
+        .await
+        .expect("should run merge");
+
+    assert!(result.success, "stderr: {}", result.stderr);
+    assert_eq!(
+        git_cmd(tmp.path(), &["rev-parse", "HEAD^{tree}"]),
+        tree_before
+    );
+    assert!(!tmp.path().join("feature-only.txt").exists());
+    assert_eq!(
+        git_cmd(tmp.path(), &["rev-parse", "HEAD^2"]),
+        git_cmd(tmp.path(), &["rev-parse", "feature"])
+    );
+}
+
 #[tokio::test]
 async fn test_merge_abort_verified_by_cli() {
     let (tmp, ops) = setup_test_repo();
@@ -195,7 +320,9 @@ async fn test_merge_abort_verified_by_cli() {
         "feature content\n",
     );
 
-    let _ = ops.merge("feature", None, false, false, false, false).await;
+    let _ = ops
+        .merge("feature", None, false, false, false, false, None)
+        .await;
     assert!(git_is_merging(tmp.path()), "Should be merging");
 
     // Action: abort merge
@@ -232,13 +359,156 @@ async fn test_is_merging_verified_by_cli() {
     assert!(!git_is_merging(tmp.path()), "CLI should show not merging");
 
     // Start merge
-    let _ = ops.merge("feature", None, false, false, false, false).await;
+    let _ = ops
+        .merge("feature", None, false, false, false, false, None)
+        .await;
 
     // Verify: both ops and CLI agree on merge state
     assert!(ops.is_merging().expect("should check"), "Should be merging");
     assert!(git_is_merging(tmp.path()), "CLI should show merging");
 }
 
+#[tokio::test]
+async fn test_merge_with_trailers_keeps_default_message() {
+    let (tmp, ops) = setup_test_repo();
+    create_feature_branch(tmp.path(), "feature", "feature.txt", "feature content");
+
+    let trailers = CommitTrailerOptions {
+        sign_off: true,
+        ..Default::default()
+    };
+    let result = ops
+        .merge_with_trailers("feature", None, true, false, None, &trailers)
+        .await
+        .expect("should merge");
+    assert!(result.success);
+
+    // Verify: git's own merge message, followed by the sign-off
+    let message = git_cmd(tmp.path(), &["log", "-1", "--format=%B"]);
+    assert!(message.starts_with("Merge branch 'feature'"), "{message}");
+    assert!(
+        message.ends_with("\n\nSigned-off-by: Test User <test@test.com>"),
+        "{message}"
+    );
+    let parents = git_cmd(tmp.path(), &["log", "-1", "--format=%P"]);
+    assert_eq!(parents.split_whitespace().count(), 2);
+    assert!(!git_is_merging(tmp.path()));
+}
+
+// ==================== Merge Preview Tests ====================
+
+#[tokio::test]
+async fn test_merge_preview_fast_forward() {
+    let (tmp, ops) = setup_test_repo();
+    create_feature_branch(tmp.path(), "feature", "feature.txt", "feature content\n");
+    let before = git_worktree_snapshot(tmp.path());
+
+    let preview = ops
+        .get_merge_preview("feature")
+        .await
+        .expect("should preview");
+
+    assert!(preview.is_fast_forward, "Should be a fast-forward");
+    assert!(!preview.is_up_to_date);
+    assert_eq!(preview.incoming_commits.len(), 1);
+    assert_eq!(preview.source.name, "feature");
+    assert_eq!(
+        preview.merge_base.as_ref().map(|c| c.oid.clone()),
+        Some(before.0.clone())
+    );
+    assert!(preview.conflicts.is_empty());
+    assert_eq!(preview.diff.len(), 1);
+    assert_eq!(preview.diff[0].new_path.as_deref(), Some("feature.txt"));
+
+    assert_eq!(git_worktree_snapshot(tmp.path()), before);
+}
+
+#[tokio::test]
+async fn test_merge_preview_clean_three_way() {
+    let (tmp, ops) = setup_test_repo();
+    create_feature_branch(tmp.path(), "feature", "feature.txt", "feature content\n");
+    std::fs::write(tmp.path().join("main.txt"), "main content\n").expect("should write");
+    git_cmd(tmp.path(), &["add", "main.txt"]);
+    git_cmd(tmp.path(), &["commit", "-m", "Add main.txt"]);
+    let before = git_worktree_snapshot(tmp.path());
+
+    let preview = ops
+        .get_merge_preview("feature")
+        .await
+        .expect("should preview");
+
+    assert!(!preview.is_fast_forward, "Should need a merge commit");
+    assert_eq!(preview.incoming_commits.len(), 1);
+    assert!(preview.conflicts.is_empty());
+    let paths: Vec<Option<&str>> = preview.diff.iter().map(|f| f.new_path.as_deref()).collect();
+    assert_eq!(paths, vec![Some("feature.txt")]);
+
+    assert_eq!(git_worktree_snapshot(tmp.path()), before);
+}
+
+#[tokio::test]
+async fn test_merge_preview_conflicting() {
+    let (tmp, ops) = setup_test_repo();
+    let base = git_head_oid(tmp.path());
+    std::fs::write(tmp.path().join("conflict.txt"), "main content\n").expect("should write");
+    git_cmd(tmp.path(), &["add", "conflict.txt"]);
+    git_cmd(tmp.path(), &["commit", "-m", "Add conflict.txt on main"]);
+    create_conflicting_branch_from(
+        tmp.path(),
+        "feature",
+        &base,
+        "conflict.txt",
+        "feature content\n",
+    );
+    git_cmd(tmp.path(), &["checkout", "feature"]);
+    std::fs::write(tmp.path().join("clean.txt"), "clean\n").expect("should write");
+    git_cmd(tmp.path(), &["add", "clean.txt"]);
+    git_cmd(tmp.path(), &["commit", "-m", "Add clean.txt"]);
+    git_cmd(tmp.path(), &["checkout", "-"]);
+
+    // Uncommitted local changes must survive the preview
+    std::fs::write(tmp.path().join("README.md"), "# Local edit").expect("should write");
+    std::fs::write(tmp.path().join("untracked.txt"), "untracked").expect("should write");
+    let before = git_worktree_snapshot(tmp.path());
+
+    let preview = ops
+        .get_merge_preview("feature")
+        .await
+        .expect("should preview");
+
+    assert_eq!(preview.incoming_commits.len(), 2);
+    assert_eq!(preview.conflicts.len(), 1);
+    assert_eq!(preview.conflicts[0].path, "conflict.txt");
+    assert_eq!(preview.conflicts[0].conflict_type, ConflictType::AddAdd);
+    let paths: Vec<Option<&str>> = preview.diff.iter().map(|f| f.new_path.as_deref()).collect();
+    assert_eq!(paths, vec![Some("clean.txt")]);
+
+    assert_eq!(git_worktree_snapshot(tmp.path()), before);
+    assert!(
+        !git_is_merging(tmp.path()),
+        "Preview must not start a merge"
+    );
+}
+
+#[tokio::test]
+async fn test_merge_preview_up_to_date() {
+    let (tmp, ops) = setup_test_repo();
+    git_cmd(tmp.path(), &["branch", "behind"]);
+    std::fs::write(tmp.path().join("main.txt"), "main\n").expect("should write");
+    git_cmd(tmp.path(), &["add", "main.txt"]);
+    git_cmd(tmp.path(), &["commit", "-m", "Add main.txt"]);
+
+    let preview = ops
+        .get_merge_preview("behind")
+        .await
+        .expect("should preview");
+
+    assert!(preview.is_up_to_date);
+    assert!(!preview.is_fast_forward);
+    assert!(preview.incoming_commits.is_empty());
+    assert!(preview.diff.is_empty());
+}
+
 // ==================== Rebase Tests ====================
 
 #[tokio::test]
@@ -526,6 +796,413 @@ async fn test_is_cherry_picking_verified_by_cli() {
     );
 }
 
+// ==================== Cherry-pick Queue Tests ====================
+
+/// Commit a file on the current branch and return the new HEAD
+fn commit_file(path: &std::path::Path, file: &str, content: &str, message: &str) -> String {
+    std::fs::write(path.join(file), content).expect("should write");
+    git_cmd(path, &["add", file]);
+    git_cmd(path, &["commit", "-m", message]);
+    git_head_oid(path)
+}
+
+#[tokio::test]
+async fn test_cherry_pick_range_expands_oldest_first() {
+    let (tmp, ops) = setup_test_repo();
+    let base = git_head_oid(tmp.path());
+    git_cmd(tmp.path(), &["checkout", "-b", "feature"]);
+    let first = commit_file(tmp.path(), "a.txt", "a", "A");
+    let second = commit_file(tmp.path(), "b.txt", "b", "B");
+    let third = commit_file(tmp.path(), "c.txt", "c", "C");
+
+    let expanded = ops
+        .expand_commit_range(&format!("{base}..{third}"))
+        .await
+        .expect("should expand range");
+
+    assert_eq!(expanded, vec![first, second, third]);
+    assert_eq!(
+        expanded.join("\n"),
+        git_cmd(
+            tmp.path(),
+            &["rev-list", "--reverse", &format!("{base}..feature")]
+        )
+    );
+    assert!(ops.expand_commit_range("feature").await.is_err());
+}
+
+#[tokio::test]
+async fn test_cherry_pick_queue_applies_range_in_order() {
+    let (tmp, ops) = setup_test_repo();
+    let main = git_current_branch(tmp.path());
+    let base = git_head_oid(tmp.path());
+    git_cmd(tmp.path(), &["checkout", "-b", "feature"]);
+    commit_file(tmp.path(), "a.txt", "a", "A");
+    commit_file(tmp.path(), "b.txt", "b", "B");
+    git_cmd(tmp.path(), &["checkout", &main]);
+
+    let queue = ops
+        .prepare_cherry_pick_queue(&CherryPickOptions {
+            range: Some(format!("{base}..feature")),
+            ..Default::default()
+        })
+        .await
+        .expect("should prepare queue");
+    let (result, pending) = ops
+        .run_cherry_pick_queue(queue, None)
+        .await
+        .expect("should cherry-pick");
+
+    assert!(result.success);
+    assert!(pending.is_none());
+    assert_eq!(result.commit_oids.len(), 2);
+    assert_eq!(result.commit_oids[1], git_head_oid(tmp.path()));
+    assert_eq!(git_cmd(tmp.path(), &["log", "-2", "--format=%s"]), "B\nA");
+}
+
+#[tokio::test]
+async fn test_cherry_pick_queue_skips_already_applied_commit() {
+    let (tmp, ops) = setup_test_repo();
+    let main = git_current_branch(tmp.path());
+    git_cmd(tmp.path(), &["checkout", "-b", "feature"]);
+    commit_file(tmp.path(), "a.txt", "a", "A");
+    let picked = git_head_oid(tmp.path());
+    git_cmd(tmp.path(), &["checkout", &main]);
+    commit_file(tmp.path(), "a.txt", "a", "A again");
+    let head = git_head_oid(tmp.path());
+
+    let queue = ops
+        .prepare_cherry_pick_queue(&CherryPickOptions {
+            commits: vec![picked.clone()],
+            skip_empty: true,
+            ..Default::default()
+        })
+        .await
+        .expect("should prepare queue");
+    let (result, pending) = ops
+        .run_cherry_pick_queue(queue, None)
+        .await
+        .expect("should cherry-pick");
+
+    assert!(result.success);
+    assert!(pending.is_none());
+    assert!(result.commit_oids.is_empty());
+    assert_eq!(result.skipped, vec![picked]);
+    assert_eq!(git_head_oid(tmp.path()), head);
+    assert!(!tmp.path().join(".git/CHERRY_PICK_HEAD").exists());
+}
+
+#[tokio::test]
+async fn test_cherry_pick_merge_commit_with_mainline() {
+    let (tmp, ops) = setup_test_repo();
+    let main = git_current_branch(tmp.path());
+    git_cmd(tmp.path(), &["checkout", "-b", "target"]);
+    git_cmd(tmp.path(), &["checkout", &main]);
+    git_cmd(tmp.path(), &["checkout", "-b", "topic"]);
+    commit_file(tmp.path(), "topic.txt", "topic", "Topic");
+    git_cmd(tmp.path(), &["checkout", &main]);
+    commit_file(tmp.path(), "main.txt", "main", "Main");
+    git_cmd(
+        tmp.path(),
+        &["merge", "--no-ff", "-m", "Merge topic", "topic"],
+    );
+    let merge_oid = git_head_oid(tmp.path());
+    git_cmd(tmp.path(), &["checkout", "target"]);
+
+    let err = ops
+        .prepare_cherry_pick_queue(&CherryPickOptions {
+            commits: vec![merge_oid.clone()],
+            ..Default::default()
+        })
+        .await
+        .expect_err("merge without mainline should be rejected");
+    assert!(err.to_string().contains("mainline"));
+
+    let queue = ops
+        .prepare_cherry_pick_queue(&CherryPickOptions {
+            commits: vec![merge_oid],
+            mainline: Some(1),
+            ..Default::default()
+        })
+        .await
+        .expect("should prepare queue");
+    let (result, _) = ops
+        .run_cherry_pick_queue(queue, None)
+        .await
+        .expect("should cherry-pick merge");
+
+    assert!(result.success);
+    // Mainline 1 replays the topic side only
+    assert!(tmp.path().join("topic.txt").exists());
+    assert!(!tmp.path().join("main.txt").exists());
+    assert_eq!(
+        git_cmd(tmp.path(), &["log", "-1", "--format=%s"]),
+        "Merge topic"
+    );
+}
+
+/// Branch `feature` with A (conflicts with main), B and C
+fn setup_conflicting_queue(path: &std::path::Path) -> (String, String) {
+    let main = git_current_branch(path);
+    let base = git_head_oid(path);
+    git_cmd(path, &["checkout", "-b", "feature"]);
+    commit_file(path, "conflict.txt", "feature", "A");
+    commit_file(path, "b.txt", "b", "B");
+    commit_file(path, "c.txt", "c", "C");
+    git_cmd(path, &["checkout", &main]);
+    let orig_head = commit_file(path, "conflict.txt", "main", "Main change");
+    (format!("{base}..feature"), orig_head)
+}
+
+#[tokio::test]
+async fn test_cherry_pick_queue_resumes_after_conflict() {
+    let (tmp, ops) = setup_test_repo();
+    let (range, _) = setup_conflicting_queue(tmp.path());
+
+    let queue = ops
+        .prepare_cherry_pick_queue(&CherryPickOptions {
+            range: Some(range),
+            ..Default::default()
+        })
+        .await
+        .expect("should prepare queue");
+    let (result, pending) = ops
+        .run_cherry_pick_queue(queue, None)
+        .await
+        .expect("should stop on conflict");
+
+    assert!(!result.success);
+    assert_eq!(git_conflicted_files(tmp.path()), vec!["conflict.txt"]);
+    let pending = pending.expect("queue should be kept");
+    assert_eq!(pending.remaining.len(), 2);
+
+    // Continuing with unresolved conflicts keeps the queue
+    let (result, pending) = ops
+        .continue_cherry_pick_queue(pending, None)
+        .await
+        .expect("should report conflicts");
+    assert!(!result.success);
+    let pending = pending.expect("queue should be kept");
+
+    std::fs::write(tmp.path().join("conflict.txt"), "resolved").expect("should write");
+    git_cmd(tmp.path(), &["add", "conflict.txt"]);
+
+    let (result, pending) = ops
+        .continue_cherry_pick_queue(pending, None)
+        .await
+        .expect("should continue");
+
+    assert!(result.success, "{}", result.message);
+    assert!(pending.is_none());
+    assert_eq!(result.commit_oids.len(), 3);
+    assert!(!git_is_cherry_picking(tmp.path()));
+    assert_eq!(
+        git_cmd(tmp.path(), &["log", "-3", "--format=%s"]),
+        "C\nB\nA"
+    );
+    assert_eq!(
+        git_cmd(tmp.path(), &["show", "HEAD~2:conflict.txt"]),
+        "resolved"
+    );
+}
+
+#[tokio::test]
+async fn test_cherry_pick_queue_abort_restores_original_head() {
+    let (tmp, ops) = setup_test_repo();
+    let (range, orig_head) = setup_conflicting_queue(tmp.path());
+
+    let queue = ops
+        .prepare_cherry_pick_queue(&CherryPickOptions {
+            range: Some(range),
+            ..Default::default()
+        })
+        .await
+        .expect("should prepare queue");
+    let (_, pending) = ops
+        .run_cherry_pick_queue(queue, None)
+        .await
+        .expect("should stop on conflict");
+
+    ops.abort_cherry_pick_queue(&pending.expect("queue should be kept"))
+        .await
+        .expect("should abort");
+
+    assert_eq!(git_head_oid(tmp.path()), orig_head);
+    assert!(!git_is_cherry_picking(tmp.path()));
+    assert_eq!(git_cmd(tmp.path(), &["status", "--porcelain"]), "");
+}
+
+#[tokio::test]
+async fn test_cherry_pick_queue_keeps_queue_after_mid_queue_failure() {
+    let (tmp, ops) = setup_test_repo();
+    let main = git_current_branch(tmp.path());
+    let orig_head = git_head_oid(tmp.path());
+    git_cmd(tmp.path(), &["checkout", "-b", "feature"]);
+    commit_file(tmp.path(), "a.txt", "a", "A");
+    commit_file(tmp.path(), "b.txt", "b", "B");
+    let third = commit_file(tmp.path(), "c.txt", "c", "C");
+    git_cmd(tmp.path(), &["checkout", &main]);
+    // An untracked file in the way makes the third pick fail without a conflict
+    std::fs::write(tmp.path().join("c.txt"), "untracked").expect("should write");
+
+    let queue = ops
+        .prepare_cherry_pick_queue(&CherryPickOptions {
+            range: Some(format!("{orig_head}..feature")),
+            ..Default::default()
+        })
+        .await
+        .expect("should prepare queue");
+    let (result, pending) = ops
+        .run_cherry_pick_queue(queue, None)
+        .await
+        .expect("should report the failure with the queue");
+
+    assert!(!result.success);
+    assert!(result.conflicts.is_empty());
+    assert_eq!(result.commit_oids.len(), 2);
+    let pending = pending.expect("queue should be kept");
+    assert_eq!(pending.failed.as_deref(), Some(third.as_str()));
+    assert!(pending.remaining.is_empty());
+
+    // Aborting drops the earlier picks
+    ops.abort_cherry_pick_queue(&pending)
+        .await
+        .expect("should abort");
+    assert_eq!(git_head_oid(tmp.path()), orig_head);
+
+    // Continuing after fixing the problem retries the failed pick
+    let queue = ops
+        .prepare_cherry_pick_queue(&CherryPickOptions {
+            range: Some(format!("{orig_head}..feature")),
+            ..Default::default()
+        })
+        .await
+        .expect("should prepare queue");
+    let (_, pending) = ops
+        .run_cherry_pick_queue(queue, None)
+        .await
+        .expect("should report the failure with the queue");
+    std::fs::remove_file(tmp.path().join("c.txt")).expect("should remove");
+
+    let (result, pending) = ops
+        .continue_cherry_pick_queue(pending.expect("queue should be kept"), None)
+        .await
+        .expect("should continue");
+    assert!(result.success, "{}", result.message);
+    assert!(pending.is_none());
+    assert_eq!(result.commit_oids.len(), 3);
+    assert_eq!(
+        git_cmd(tmp.path(), &["log", "-3", "--format=%s"]),
+        "C\nB\nA"
+    );
+}
+
+#[tokio::test]
+async fn test_cherry_pick_queue_no_commit_stages_all_picks() {
+    let (tmp, ops) = setup_test_repo();
+    let main = git_current_branch(tmp.path());
+    let head = git_head_oid(tmp.path());
+    git_cmd(tmp.path(), &["checkout", "-b", "feature"]);
+    let first = commit_file(tmp.path(), "a.txt", "a", "A");
+    let second = commit_file(tmp.path(), "b.txt", "b", "B");
+    git_cmd(tmp.path(), &["checkout", &main]);
+
+    let queue = ops
+        .prepare_cherry_pick_queue(&CherryPickOptions {
+            commits: vec![first, second],
+            no_commit: true,
+            ..Default::default()
+        })
+        .await
+        .expect("should prepare queue");
+    let (result, _) = ops
+        .run_cherry_pick_queue(queue, None)
+        .await
+        .expect("should apply picks");
+
+    assert!(result.success);
+    assert!(result.commit_oids.is_empty());
+    assert_eq!(git_head_oid(tmp.path()), head);
+    assert_eq!(
+        git_cmd(tmp.path(), &["diff", "--cached", "--name-only"]),
+        "a.txt\nb.txt"
+    );
+}
+
+// ==================== Cherry-pick Preview Tests ====================
+
+#[tokio::test]
+async fn test_cherry_pick_preview_chains_dependent_commits() {
+    let (tmp, ops) = setup_test_repo();
+    let default_branch = git_current_branch(tmp.path());
+
+    // Setup: second commit modifies the file added by the first
+    git_cmd(tmp.path(), &["checkout", "-b", "feature"]);
+    std::fs::write(tmp.path().join("feature.txt"), "one\n").expect("should write");
+    git_cmd(tmp.path(), &["add", "feature.txt"]);
+    git_cmd(tmp.path(), &["commit", "-m", "Add feature.txt"]);
+    let first = git_head_oid(tmp.path());
+    std::fs::write(tmp.path().join("feature.txt"), "one\ntwo\n").expect("should write");
+    git_cmd(tmp.path(), &["commit", "-am", "Extend feature.txt"]);
+    let second = git_head_oid(tmp.path());
+    git_cmd(tmp.path(), &["checkout", &default_branch]);
+    let before = git_worktree_snapshot(tmp.path());
+
+    let preview = ops
+        .get_cherry_pick_preview(vec![first.clone(), second.clone()])
+        .await
+        .expect("should preview");
+
+    let oids: Vec<&str> = preview.commits.iter().map(|c| c.oid.as_str()).collect();
+    assert_eq!(oids, vec![first.as_str(), second.as_str()]);
+    assert!(preview.estimated_conflicts.is_empty());
+    assert_eq!(git_worktree_snapshot(tmp.path()), before);
+}
+
+#[tokio::test]
+async fn test_cherry_pick_preview_reports_conflicts() {
+    let (tmp, ops) = setup_test_repo();
+    let base = git_head_oid(tmp.path());
+    std::fs::write(tmp.path().join("conflict.txt"), "main content\n").expect("should write");
+    git_cmd(tmp.path(), &["add", "conflict.txt"]);
+    git_cmd(tmp.path(), &["commit", "-m", "Add conflict.txt on main"]);
+    create_conflicting_branch_from(
+        tmp.path(),
+        "feature",
+        &base,
+        "conflict.txt",
+        "feature content\n",
+    );
+    let conflicting = git_cmd(tmp.path(), &["rev-parse", "feature"]);
+    create_feature_branch(tmp.path(), "clean", "clean.txt", "clean\n");
+    let clean = git_cmd(tmp.path(), &["rev-parse", "clean"]);
+    let before = git_worktree_snapshot(tmp.path());
+
+    let preview = ops
+        .get_cherry_pick_preview(vec![conflicting, clean])
+        .await
+        .expect("should preview");
+
+    assert_eq!(preview.commits.len(), 2);
+    assert_eq!(
+        preview.estimated_conflicts,
+        vec!["conflict.txt".to_string()]
+    );
+    assert!(!git_is_cherry_picking(tmp.path()));
+    assert_eq!(git_worktree_snapshot(tmp.path()), before);
+}
+
+#[tokio::test]
+async fn test_cherry_pick_preview_invalid_commit() {
+    let (_tmp, ops) = setup_test_repo();
+
+    let err = ops
+        .get_cherry_pick_preview(vec!["does-not-exist".to_string()])
+        .await
+        .expect_err("should reject unknown commit");
+    assert!(err.to_string().contains("does-not-exist"));
+}
+
 // ==================== Revert Tests ====================
 
 #[tokio::test]
@@ -660,7 +1337,9 @@ async fn test_get_conflicted_files_verified_by_cli() {
         "feature content\n",
     );
 
-    let _ = ops.merge("feature", None, false, false, false, false).await;
+    let _ = ops
+        .merge("feature", None, false, false, false, false, None)
+        .await;
 
     // Get conflicted files from both
     let cli_conflicts = git_conflicted_files(tmp.path());
@@ -702,7 +1381,9 @@ async fn test_mark_resolved_verified_by_cli() {
         "feature content\n",
     );
 
-    let _ = ops.merge("feature", None, false, false, false, false).await;
+    let _ = ops
+        .merge("feature", None, false, false, false, false, None)
+        .await;
     assert!(
         !git_conflicted_files(tmp.path()).is_empty(),
         "Should have conflicts"
@@ -747,7 +1428,9 @@ async fn test_mark_unresolved_verified_by_cli() {
         "feature content\n",
     );
 
-    let _ = ops.merge("feature", None, false, false, false, false).await;
+    let _ = ops
+        .merge("feature", None, false, false, false, false, None)
+        .await;
     assert!(
         !git_conflicted_files(tmp.path()).is_empty(),
         "Should have conflicts"
@@ -803,7 +1486,9 @@ async fn test_resolve_with_ours_verified_by_cli() {
         "theirs content\n",
     );
 
-    let _ = ops.merge("feature", None, false, false, false, false).await;
+    let _ = ops
+        .merge("feature", None, false, false, false, false, None)
+        .await;
     assert!(git_is_merging(tmp.path()), "Should be merging");
 
     // Action: resolve with ours
@@ -843,7 +1528,9 @@ async fn test_resolve_with_theirs_verified_by_cli() {
         "theirs content\n",
     );
 
-    let _ = ops.merge("feature", None, false, false, false, false).await;
+    let _ = ops
+        .merge("feature", None, false, false, false, false, None)
+        .await;
     assert!(git_is_merging(tmp.path()), "Should be merging");
 
     // Action: resolve with theirs
@@ -883,7 +1570,9 @@ async fn test_get_conflict_versions() {
         "theirs content\n",
     );
 
-    let _ = ops.merge("feature", None, false, false, false, false).await;
+    let _ = ops
+        .merge("feature", None, false, false, false, false, None)
+        .await;
     assert!(git_is_merging(tmp.path()), "Should be merging");
 
     // Action: get conflict versions
@@ -901,15 +1590,411 @@ async fn test_get_conflict_versions() {
     assert_eq!(theirs.trim(), "theirs content", "Theirs should match");
 }
 
-// ==================== Edge Cases ====================
+/// Start a merge where `multi.txt` conflicts in two separate regions
+fn setup_two_region_conflict(path: &std::path::Path) {
+    std::fs::write(path.join("multi.txt"), "a\nb\nc\nd\ne\nf\ng\nh\ni\n").expect("should write");
+    git_cmd(path, &["add", "."]);
+    git_cmd(path, &["commit", "-m", "base"]);
+    let base = git_head_oid(path);
+
+    std::fs::write(
+        path.join("multi.txt"),
+        "a\nOURS1\nc\nd\ne\nf\ng\nOURS2\ni\n",
+    )
+    .expect("should write");
+    git_cmd(path, &["add", "."]);
+    git_cmd(path, &["commit", "-m", "ours"]);
+
+    create_conflicting_branch_from(
+        path,
+        "feature",
+        &base,
+        "multi.txt",
+        "a\nTHEIRS1\nc\nd\ne\nf\ng\nTHEIRS2\ni\n",
+    );
+}
 
 #[tokio::test]
-async fn test_merge_nonexistent_branch_fails() {
-    let (_tmp, ops) = setup_test_repo();
+async fn test_get_conflict_hunks_two_regions() {
+    let (tmp, ops) = setup_test_repo();
+    setup_two_region_conflict(tmp.path());
 
-    // Action: try to merge non-existent branch
-    let result = ops
-        .merge("nonexistent", None, false, false, false, false)
+    let _ = ops
+        .merge("feature", None, false, false, false, false, None)
+        .await;
+    assert!(git_is_merging(tmp.path()), "Should be merging");
+
+    let hunks = ops
+        .get_conflict_hunks("multi.txt")
+        .await
+        .expect("should get hunks");
+
+    let conflicts: Vec<_> = hunks
+        .iter()
+        .filter_map(|h| match h {
+            ConflictHunk::Conflict {
+                ours, theirs, base, ..
+            } => Some((ours.clone(), theirs.clone(), base.clone())),
+            ConflictHunk::Context { .. } => None,
+        })
+        .collect();
+
+    assert_eq!(conflicts.len(), 2, "Should find two conflict regions");
+    assert_eq!(conflicts[0].0, vec!["OURS1"]);
+    assert_eq!(conflicts[0].1, vec!["THEIRS1"]);
+    assert_eq!(conflicts[0].2, vec!["b"]);
+    assert_eq!(conflicts[1].0, vec!["OURS2"]);
+    assert_eq!(conflicts[1].1, vec!["THEIRS2"]);
+}
+
+#[tokio::test]
+async fn test_resolve_conflict_hunks_different_choices() {
+    let (tmp, ops) = setup_test_repo();
+    setup_two_region_conflict(tmp.path());
+
+    let _ = ops
+        .merge("feature", None, false, false, false, false, None)
+        .await;
+    assert!(git_is_merging(tmp.path()), "Should be merging");
+
+    ops.resolve_conflict_hunks(
+        "multi.txt",
+        &[
+            ConflictHunkChoice::Ours,
+            ConflictHunkChoice::BothTheirsFirst,
+        ],
+    )
+    .await
+    .expect("should resolve hunks");
+
+    let content = std::fs::read_to_string(tmp.path().join("multi.txt")).expect("should read");
+    assert_eq!(
+        content.replace("\r\n", "\n"),
+        "a\nOURS1\nc\nd\ne\nf\ng\nTHEIRS2\nOURS2\ni\n"
+    );
+
+    // Verify: CLI shows the file is no longer conflicted and is staged
+    assert!(
+        git_conflicted_files(tmp.path()).is_empty(),
+        "No files should remain conflicted"
+    );
+    let staged = git_cmd(tmp.path(), &["diff", "--cached", "--name-only"]);
+    assert!(
+        staged.contains("multi.txt"),
+        "Resolved file should be staged"
+    );
+}
+
+#[tokio::test]
+async fn test_resolve_conflict_hunks_wrong_choice_count_fails() {
+    let (tmp, ops) = setup_test_repo();
+    setup_two_region_conflict(tmp.path());
+
+    let _ = ops
+        .merge("feature", None, false, false, false, false, None)
+        .await;
+
+    let result = ops
+        .resolve_conflict_hunks("multi.txt", &[ConflictHunkChoice::Theirs])
+        .await;
+    assert!(result.is_err(), "Should require one choice per conflict");
+    assert_eq!(git_conflicted_files(tmp.path()), vec!["multi.txt"]);
+}
+
+#[tokio::test]
+async fn test_resolve_conflict_with_content_writes_and_stages() {
+    let (tmp, ops) = setup_test_repo();
+    setup_two_region_conflict(tmp.path());
+
+    let _ = ops
+        .merge("feature", None, false, false, false, false, None)
+        .await;
+    assert_eq!(git_conflicted_files(tmp.path()), vec!["multi.txt"]);
+
+    ops.resolve_conflict_with_content("multi.txt", "hand\nmerged\n")
+        .await
+        .expect("should resolve with content");
+
+    let content = std::fs::read_to_string(tmp.path().join("multi.txt")).expect("should read");
+    assert_eq!(content, "hand\nmerged\n");
+    assert!(
+        git_conflicted_files(tmp.path()).is_empty(),
+        "No files should remain conflicted"
+    );
+    let staged = git_cmd(tmp.path(), &["show", ":multi.txt"]);
+    assert_eq!(staged, "hand\nmerged");
+}
+
+#[tokio::test]
+async fn test_resolve_conflict_with_content_requires_conflict() {
+    let (tmp, ops) = setup_test_repo();
+
+    let result = ops
+        .resolve_conflict_with_content("README.md", "overwritten\n")
+        .await;
+
+    assert!(
+        result.is_err(),
+        "Should refuse a file that is not conflicted"
+    );
+    let content = std::fs::read_to_string(tmp.path().join("README.md")).expect("should read");
+    assert_ne!(content, "overwritten\n");
+}
+
+#[tokio::test]
+async fn test_get_conflict_hunks_binary_conflict() {
+    let (tmp, ops) = setup_test_repo();
+
+    let base = git_head_oid(tmp.path());
+    std::fs::write(tmp.path().join("blob.bin"), b"\x00\x01ours").expect("should write");
+    git_cmd(tmp.path(), &["add", "."]);
+    git_cmd(tmp.path(), &["commit", "-m", "ours"]);
+
+    let current = git_current_branch(tmp.path());
+    git_cmd(tmp.path(), &["checkout", "-b", "feature", &base]);
+    std::fs::write(tmp.path().join("blob.bin"), b"\x00\x02theirs").expect("should write");
+    git_cmd(tmp.path(), &["add", "."]);
+    git_cmd(tmp.path(), &["commit", "-m", "theirs"]);
+    git_cmd(tmp.path(), &["checkout", &current]);
+
+    let _ = ops
+        .merge("feature", None, false, false, false, false, None)
+        .await;
+
+    let err = ops
+        .get_conflict_hunks("blob.bin")
+        .await
+        .expect_err("binary conflict should not be split into hunks");
+    assert!(err.to_string().contains("Binary conflict"));
+}
+
+#[tokio::test]
+async fn test_get_conflict_hunks_ignores_merge_conflict_style() {
+    let (tmp, ops) = setup_test_repo();
+    setup_two_region_conflict(tmp.path());
+    git_cmd(tmp.path(), &["config", "merge.conflictStyle", "merge"]);
+
+    let _ = ops
+        .merge("feature", None, false, false, false, false, None)
+        .await;
+    let config_before =
+        std::fs::read_to_string(tmp.path().join(".git/config")).expect("should read config");
+    let hunks = ops
+        .get_conflict_hunks("multi.txt")
+        .await
+        .expect("should get hunks");
+
+    let bases: Vec<_> = hunks
+        .iter()
+        .filter_map(|h| match h {
+            ConflictHunk::Conflict { base, .. } => Some(base.clone()),
+            ConflictHunk::Context { .. } => None,
+        })
+        .collect();
+    assert_eq!(bases, vec![vec!["b"], vec!["h"]]);
+    assert_eq!(
+        std::fs::read_to_string(tmp.path().join(".git/config")).expect("should read config"),
+        config_before,
+        "Reading conflict hunks should not touch the repository config"
+    );
+}
+
+// ==================== Notebook Merge ====================
+
+/// Single-line notebook JSON, so any two edits conflict as text
+fn notebook_json(sources: &[&str]) -> String {
+    let cells: Vec<serde_json::Value> = sources
+        .iter()
+        .map(|source| {
+            serde_json::json!({
+                "cell_type": "code",
+                "execution_count": null,
+                "metadata": {},
+                "outputs": [],
+                "source": [source],
+            })
+        })
+        .collect();
+    serde_json::json!({"cells": cells, "metadata": {}, "nbformat": 4, "nbformat_minor": 4})
+        .to_string()
+}
+
+fn setup_notebook_conflict(path: &std::path::Path, ours: &[&str], theirs: &[&str]) {
+    std::fs::write(
+        path.join("analysis.ipynb"),
+        notebook_json(&["a = 1", "b = 2"]),
+    )
+    .expect("should write");
+    git_cmd(path, &["add", "."]);
+    git_cmd(path, &["commit", "-m", "base"]);
+    let base = git_head_oid(path);
+
+    std::fs::write(path.join("analysis.ipynb"), notebook_json(ours)).expect("should write");
+    git_cmd(path, &["add", "."]);
+    git_cmd(path, &["commit", "-m", "ours"]);
+
+    create_conflicting_branch_from(
+        path,
+        "feature",
+        &base,
+        "analysis.ipynb",
+        &notebook_json(theirs),
+    );
+}
+
+#[tokio::test]
+async fn test_merge_notebook_conflict_merges_cells_changed_on_one_side() {
+    let (tmp, ops) = setup_test_repo();
+    setup_notebook_conflict(tmp.path(), &["a = 10", "b = 2"], &["a = 1", "b = 20"]);
+
+    let _ = ops
+        .merge("feature", None, false, false, false, false, None)
+        .await;
+    assert_eq!(git_conflicted_files(tmp.path()), vec!["analysis.ipynb"]);
+
+    let result = ops
+        .merge_notebook_conflict("analysis.ipynb")
+        .await
+        .expect("should merge notebook");
+
+    assert!(result.resolved);
+    assert!(git_conflicted_files(tmp.path()).is_empty());
+    let merged: serde_json::Value = serde_json::from_str(
+        &std::fs::read_to_string(tmp.path().join("analysis.ipynb")).expect("should read"),
+    )
+    .expect("merged notebook should be JSON");
+    let sources: Vec<&str> = merged["cells"]
+        .as_array()
+        .expect("should have cells")
+        .iter()
+        .map(|c| c["source"][0].as_str().expect("should have source"))
+        .collect();
+    assert_eq!(sources, vec!["a = 10", "b = 20"]);
+}
+
+#[tokio::test]
+async fn test_merge_notebook_conflict_reports_conflicting_cells() {
+    let (tmp, ops) = setup_test_repo();
+    setup_notebook_conflict(tmp.path(), &["a = 10", "b = 2"], &["a = 11", "b = 2"]);
+
+    let _ = ops
+        .merge("feature", None, false, false, false, false, None)
+        .await;
+    let before = std::fs::read_to_string(tmp.path().join("analysis.ipynb")).expect("should read");
+
+    let result = ops
+        .merge_notebook_conflict("analysis.ipynb")
+        .await
+        .expect("should merge notebook");
+
+    assert!(!result.resolved);
+    assert_eq!(result.conflicts.len(), 1);
+    assert_eq!(result.conflicts[0].base_cell, Some(1));
+    assert_eq!(git_conflicted_files(tmp.path()), vec!["analysis.ipynb"]);
+    assert_eq!(
+        std::fs::read_to_string(tmp.path().join("analysis.ipynb")).expect("should read"),
+        before,
+        "A notebook with conflicts should be left for take-side resolution"
+    );
+}
+
+// ==================== Config Overrides ====================
+
+#[test]
+fn test_scoped_config_override_applies_and_rolls_back() {
+    let (tmp, _ops) = setup_test_repo();
+    git_cmd(tmp.path(), &["config", "user.name", "Original"]);
+    let repo = git2::Repository::open(tmp.path()).expect("should open repo");
+
+    {
+        let _guard = ScopedConfigOverride::apply(
+            &repo,
+            &[
+                ConfigOverride::new("user.name", "One Off").expect("should be allowed"),
+                ConfigOverride::diff3_conflict_style(),
+            ],
+        )
+        .expect("should apply overrides");
+        assert_eq!(git_cmd(tmp.path(), &["config", "user.name"]), "One Off");
+        assert_eq!(
+            git_cmd(tmp.path(), &["config", "merge.conflictStyle"]),
+            "diff3"
+        );
+    }
+
+    assert_eq!(git_cmd(tmp.path(), &["config", "user.name"]), "Original");
+    let status = std::process::Command::new("git")
+        .args(["config", "--local", "merge.conflictStyle"])
+        .current_dir(tmp.path())
+        .status()
+        .expect("should execute git");
+    assert_eq!(
+        status.code(),
+        Some(1),
+        "Key absent before the override should be removed"
+    );
+    assert!(!tmp.path().join(".git/axis-config-override.json").exists());
+}
+
+#[test]
+fn test_scoped_config_override_rolls_back_on_panic() {
+    let (tmp, _ops) = setup_test_repo();
+    git_cmd(tmp.path(), &["config", "user.name", "Original"]);
+    let path = tmp.path().to_path_buf();
+
+    let result = std::panic::catch_unwind(|| {
+        let repo = git2::Repository::open(&path).expect("should open repo");
+        let _guard = ScopedConfigOverride::apply(
+            &repo,
+            &[ConfigOverride::new("user.name", "One Off").expect("should be allowed")],
+        )
+        .expect("should apply override");
+        assert_eq!(git_cmd(&path, &["config", "user.name"]), "One Off");
+        panic!("operation failed mid-way");
+    });
+
+    assert!(result.is_err());
+    assert_eq!(git_cmd(tmp.path(), &["config", "user.name"]), "Original");
+}
+
+#[test]
+fn test_scoped_config_override_recovers_interrupted_override() {
+    let (tmp, _ops) = setup_test_repo();
+    git_cmd(tmp.path(), &["config", "user.name", "Original"]);
+    let repo = git2::Repository::open(tmp.path()).expect("should open repo");
+
+    // Simulate a crash: the guard never drops
+    std::mem::forget(
+        ScopedConfigOverride::apply(
+            &repo,
+            &[ConfigOverride::new("user.name", "One Off").expect("should be allowed")],
+        )
+        .expect("should apply override"),
+    );
+    assert_eq!(git_cmd(tmp.path(), &["config", "user.name"]), "One Off");
+
+    drop(ScopedConfigOverride::apply(&repo, &[]).expect("should recover"));
+    assert_eq!(git_cmd(tmp.path(), &["config", "user.name"]), "Original");
+}
+
+#[test]
+fn test_config_override_rejects_non_allowlisted_key() {
+    let err = ConfigOverride::new("core.hooksPath", "/tmp/hooks").expect_err("should reject");
+    assert_eq!(
+        err.to_string(),
+        "Config key cannot be overridden for a single operation: core.hooksPath"
+    );
+}
+
+// ==================== Edge Cases ====================
+
+#[tokio::test]
+async fn test_merge_nonexistent_branch_fails() {
+    let (_tmp, ops) = setup_test_repo();
+
+    // Action: try to merge non-existent branch
+    let result = ops
+        .merge("nonexistent", None, false, false, false, false, None)
         .await
         .expect("should complete");
 
@@ -938,7 +2023,7 @@ async fn test_merge_squash_verified_by_cli() {
 
     // Action: squash merge
     let result = ops
-        .merge("feature", None, false, true, false, false)
+        .merge("feature", None, false, true, false, false, None)
         .await
         .expect("should merge");
 
@@ -1076,7 +2161,9 @@ async fn test_merge_continue_after_resolve() {
         "feature content\n",
     );
 
-    let _ = ops.merge("feature", None, false, false, false, false).await;
+    let _ = ops
+        .merge("feature", None, false, false, false, false, None)
+        .await;
     assert!(git_is_merging(tmp.path()), "Should be merging");
 
     // Resolve conflict
@@ -1090,3 +2177,529 @@ async fn test_merge_continue_after_resolve() {
     assert!(result.success, "Merge continue should succeed");
     assert!(!git_is_merging(tmp.path()), "Merge should be complete");
 }
+
+// ==================== Operation State Tests ====================
+
+/// Branch `feature` with commits A, B and C on top of the initial commit, where B and C
+/// conflict with the default branch. Returns the default branch name.
+fn create_three_commit_feature(path: &std::path::Path) -> String {
+    let default_branch = git_current_branch(path);
+    git_cmd(path, &["checkout", "-b", "feature"]);
+    commit_file(path, "a.txt", "feature a\n", "A");
+    commit_file(path, "b.txt", "feature b\n", "B");
+    commit_file(path, "c.txt", "feature c\n", "C");
+
+    git_cmd(path, &["checkout", &default_branch]);
+    commit_file(path, "b.txt", "main b\n", "main b");
+    commit_file(path, "c.txt", "main c\n", "main c");
+    git_cmd(path, &["checkout", "feature"]);
+    default_branch
+}
+
+/// Run git, ignoring a non-zero exit (a command stopping on conflicts)
+fn git_cmd_allow_failure(path: &std::path::Path, args: &[&str]) {
+    std::process::Command::new("git")
+        .args(args)
+        .current_dir(path)
+        .output()
+        .expect("should execute git");
+}
+
+/// Resolve a conflicted `file` with `content` and stage it
+fn resolve_file(path: &std::path::Path, file: &str, content: &str) {
+    std::fs::write(path.join(file), content).expect("should write");
+    git_cmd(path, &["add", file]);
+}
+
+#[tokio::test]
+async fn test_operation_state_tracks_rebase_steps_across_continue() {
+    let (tmp, ops) = setup_test_repo();
+    let default_branch = create_three_commit_feature(tmp.path());
+    let onto = git_cmd(tmp.path(), &["rev-parse", &default_branch]);
+    let commit_b = git_cmd(tmp.path(), &["rev-parse", "feature~1"]);
+    let commit_c = git_head_oid(tmp.path());
+
+    let _ = ops.rebase(&default_branch, false).await;
+    assert!(git_is_rebasing(tmp.path()), "Should stop on B");
+
+    match ops.get_operation_state().await.expect("should get state") {
+        OperationState::Rebasing {
+            onto: state_onto,
+            current,
+            total,
+            head_name,
+            current_commit,
+            conflicted_paths,
+            ..
+        } => {
+            assert_eq!(state_onto, Some(onto.clone()));
+            assert_eq!((current, total), (Some(2), Some(3)));
+            assert_eq!(head_name, Some("feature".to_string()));
+            assert_eq!(
+                current_commit,
+                Some(OperationCommit {
+                    oid: commit_b,
+                    summary: "B".to_string(),
+                })
+            );
+            assert_eq!(conflicted_paths, vec!["b.txt".to_string()]);
+        }
+        other => panic!("expected Rebasing, got {other:?}"),
+    }
+
+    resolve_file(tmp.path(), "b.txt", "feature b\n");
+    let result = ops
+        .rebase_continue_with_message("B")
+        .await
+        .expect("should continue");
+    assert!(!result.success, "Should stop again on C");
+
+    match ops.get_operation_state().await.expect("should get state") {
+        OperationState::Rebasing {
+            current,
+            total,
+            current_commit,
+            conflicted_paths,
+            ..
+        } => {
+            assert_eq!((current, total), (Some(3), Some(3)));
+            assert_eq!(
+                current_commit,
+                Some(OperationCommit {
+                    oid: commit_c,
+                    summary: "C".to_string(),
+                })
+            );
+            assert_eq!(conflicted_paths, vec!["c.txt".to_string()]);
+        }
+        other => panic!("expected Rebasing, got {other:?}"),
+    }
+
+    resolve_file(tmp.path(), "c.txt", "feature c\n");
+    let result = ops
+        .rebase_continue_with_message("C")
+        .await
+        .expect("should continue");
+    assert!(result.success, "continue failed: {}", result.stderr);
+    assert_eq!(
+        ops.get_operation_state().await.expect("should get state"),
+        OperationState::None
+    );
+}
+
+#[tokio::test]
+async fn test_operation_state_reads_apply_backend_rebase() {
+    let (tmp, ops) = setup_test_repo();
+    let default_branch = create_three_commit_feature(tmp.path());
+
+    git_cmd_allow_failure(tmp.path(), &["rebase", "--apply", &default_branch]);
+    assert!(tmp.path().join(".git/rebase-apply").exists());
+
+    match ops.get_operation_state().await.expect("should get state") {
+        OperationState::Rebasing {
+            current,
+            total,
+            current_commit,
+            conflicted_paths,
+            ..
+        } => {
+            assert_eq!((current, total), (Some(2), Some(3)));
+            assert_eq!(current_commit.map(|c| c.summary), Some("B".to_string()));
+            assert_eq!(conflicted_paths, vec!["b.txt".to_string()]);
+        }
+        other => panic!("expected Rebasing, got {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn test_operation_state_unknown_for_corrupt_rebase_state() {
+    let (tmp, ops) = setup_test_repo();
+    let default_branch = create_three_commit_feature(tmp.path());
+
+    let _ = ops.rebase(&default_branch, false).await;
+    std::fs::write(tmp.path().join(".git/rebase-merge/msgnum"), "not a number")
+        .expect("should write");
+
+    assert_eq!(
+        ops.get_operation_state().await.expect("should not error"),
+        OperationState::Unknown
+    );
+}
+
+#[tokio::test]
+async fn test_operation_state_counts_sequencer_cherry_picks() {
+    let (tmp, ops) = setup_test_repo();
+    let default_branch = create_three_commit_feature(tmp.path());
+    git_cmd(tmp.path(), &["checkout", &default_branch]);
+
+    git_cmd_allow_failure(
+        tmp.path(),
+        &["cherry-pick", "feature~2", "feature~1", "feature"],
+    );
+    assert!(git_is_cherry_picking(tmp.path()), "Should stop on B");
+
+    match ops.get_operation_state().await.expect("should get state") {
+        OperationState::CherryPicking {
+            current,
+            total,
+            current_commit,
+            original_branch,
+            conflicted_paths,
+            ..
+        } => {
+            assert_eq!((current, total), (Some(2), Some(3)));
+            assert_eq!(current_commit.map(|c| c.summary), Some("B".to_string()));
+            assert_eq!(original_branch, Some(default_branch));
+            assert_eq!(conflicted_paths, vec!["b.txt".to_string()]);
+        }
+        other => panic!("expected CherryPicking, got {other:?}"),
+    }
+}
+
+// ==================== Interactive Rebase Tests ====================
+
+#[tokio::test]
+async fn test_interactive_rebase_reorders_fixes_up_and_drops() {
+    let (tmp, ops) = setup_test_repo();
+    for i in 1..=4 {
+        std::fs::write(tmp.path().join(format!("file{i}.txt")), i.to_string())
+            .expect("should write");
+        git_cmd(tmp.path(), &["add", "."]);
+        git_cmd(tmp.path(), &["commit", "-m", &format!("Commit {i}")]);
+    }
+    let base = git_cmd(tmp.path(), &["rev-parse", "HEAD~4"]);
+    let oid = |rev: &str| git_cmd(tmp.path(), &["rev-parse", rev]);
+    let entry = |action: RebaseAction, rev: &str, summary: &str, index: usize| {
+        let oid = oid(rev);
+        InteractiveRebaseEntry {
+            action,
+            short_oid: oid[..7].to_string(),
+            oid,
+            summary: summary.to_string(),
+            original_index: index,
+        }
+    };
+
+    // Commit 4 first, commit 3 folded into commit 1, commit 2 dropped
+    let entries = vec![
+        entry(RebaseAction::Pick, "HEAD", "Commit 4", 3),
+        entry(RebaseAction::Pick, "HEAD~3", "Commit 1", 0),
+        entry(RebaseAction::Fixup, "HEAD~1", "Commit 3", 2),
+        entry(RebaseAction::Drop, "HEAD~2", "Commit 2", 1),
+    ];
+
+    let result = ops
+        .interactive_rebase(&base, &entries, false)
+        .await
+        .expect("should run rebase");
+
+    assert!(result.success, "rebase failed: {}", result.stderr);
+    assert!(!git_is_rebasing(tmp.path()));
+    let log = git_cmd(
+        tmp.path(),
+        &["log", "--format=%s", &format!("{base}..HEAD")],
+    );
+    assert_eq!(
+        log.lines().collect::<Vec<_>>(),
+        vec!["Commit 1", "Commit 4"]
+    );
+    let files = git_cmd(tmp.path(), &["ls-files"]);
+    assert!(files.contains("file3.txt"));
+    assert!(!files.contains("file2.txt"));
+}
+
+#[tokio::test]
+async fn test_interactive_rebase_todo_can_be_read_and_edited_mid_rebase() {
+    let (tmp, ops) = setup_test_repo();
+    for i in 1..=4 {
+        std::fs::write(tmp.path().join(format!("file{i}.txt")), i.to_string())
+            .expect("should write");
+        git_cmd(tmp.path(), &["add", "."]);
+        git_cmd(tmp.path(), &["commit", "-m", &format!("Commit {i}")]);
+    }
+    let base = git_cmd(tmp.path(), &["rev-parse", "HEAD~4"]);
+    let entries: Vec<InteractiveRebaseEntry> = (0..4)
+        .map(|i| {
+            let oid = git_cmd(tmp.path(), &["rev-parse", &format!("HEAD~{}", 3 - i)]);
+            InteractiveRebaseEntry {
+                // Stop after the first commit
+                action: if i == 0 {
+                    RebaseAction::Edit
+                } else {
+                    RebaseAction::Pick
+                },
+                short_oid: oid[..7].to_string(),
+                oid,
+                summary: format!("Commit {}", i + 1),
+                original_index: i,
+            }
+        })
+        .collect();
+
+    ops.interactive_rebase(&base, &entries, false)
+        .await
+        .expect("should start rebase");
+    assert!(git_is_rebasing(tmp.path()));
+
+    let mut todo = ops
+        .get_interactive_rebase_todo()
+        .await
+        .expect("should read todo");
+    let summaries: Vec<_> = todo.iter().map(|e| e.summary.as_str()).collect();
+    assert_eq!(summaries, vec!["Commit 2", "Commit 3", "Commit 4"]);
+    assert_eq!(todo[0].oid, entries[1].oid);
+    assert!(todo.iter().all(|e| e.action == RebaseAction::Pick));
+
+    // Drop commit 3 and apply commit 4 before commit 2
+    todo[1].action = RebaseAction::Drop;
+    todo.swap(0, 2);
+    ops.update_interactive_rebase_todo(todo)
+        .await
+        .expect("should update todo");
+
+    let result = ops.rebase_continue().await.expect("should continue");
+    assert!(result.success, "continue failed: {}", result.stderr);
+    assert!(!git_is_rebasing(tmp.path()));
+    let log = git_cmd(
+        tmp.path(),
+        &["log", "--format=%s", &format!("{base}..HEAD")],
+    );
+    assert_eq!(
+        log.lines().collect::<Vec<_>>(),
+        vec!["Commit 2", "Commit 4", "Commit 1"]
+    );
+}
+
+#[tokio::test]
+async fn test_interactive_rebase_todo_requires_rebase_in_progress() {
+    let (_tmp, ops) = setup_test_repo();
+
+    let err = ops
+        .get_interactive_rebase_todo()
+        .await
+        .expect_err("should fail without a rebase");
+    assert!(err
+        .to_string()
+        .contains("No interactive rebase in progress"));
+}
+
+#[tokio::test]
+async fn test_squash_commits_combines_last_commits_with_new_message() {
+    let (tmp, ops) = setup_test_repo();
+    let base = git_head_oid(tmp.path());
+    commit_file(tmp.path(), "a.txt", "a", "Add a");
+    commit_file(tmp.path(), "b.txt", "b", "Add b");
+    commit_file(tmp.path(), "c.txt", "c", "Add c");
+
+    let new_oid = ops
+        .squash_commits(3, "Add a, b and c", None)
+        .await
+        .expect("should squash");
+
+    assert_eq!(new_oid, git_head_oid(tmp.path()));
+    assert!(!git_is_rebasing(tmp.path()));
+    assert_eq!(
+        git_cmd(tmp.path(), &["rev-parse", "HEAD~1"]).trim(),
+        base,
+        "squashed commit should sit on the original base"
+    );
+    assert_eq!(
+        git_cmd(tmp.path(), &["log", "-1", "--format=%B"]).trim(),
+        "Add a, b and c"
+    );
+    let files = git_cmd(tmp.path(), &["show", "--name-only", "--format=", "HEAD"]);
+    assert_eq!(
+        files.lines().collect::<Vec<_>>(),
+        vec!["a.txt", "b.txt", "c.txt"]
+    );
+}
+
+#[tokio::test]
+async fn test_squash_commits_rejects_invalid_requests() {
+    let (tmp, ops) = setup_test_repo();
+    commit_file(tmp.path(), "a.txt", "a", "Add a");
+    let head = git_head_oid(tmp.path());
+
+    let err = ops
+        .squash_commits(1, "Message", None)
+        .await
+        .expect_err("one commit cannot be squashed");
+    assert!(err.to_string().contains("at least two commits"));
+
+    ops.squash_commits(2, "  ", None)
+        .await
+        .expect_err("empty message should fail");
+
+    // Only two commits exist, so HEAD~5 does not resolve
+    ops.squash_commits(5, "Message", None)
+        .await
+        .expect_err("count beyond history should fail");
+
+    assert_eq!(git_head_oid(tmp.path()), head);
+}
+
+#[tokio::test]
+async fn test_reword_commit_changes_ancestor_message_and_replays_descendants() {
+    let (tmp, ops) = setup_test_repo();
+    let target = commit_file(tmp.path(), "a.txt", "a", "Add a");
+    commit_file(tmp.path(), "b.txt", "b", "Add b");
+
+    let new_oid = ops
+        .reword_commit(&target, "Add file a\n\nWith a body", None, None)
+        .await
+        .expect("should reword");
+
+    assert_eq!(new_oid, git_head_oid(tmp.path()));
+    assert!(!git_is_rebasing(tmp.path()));
+    assert_eq!(
+        git_cmd(tmp.path(), &["log", "-1", "--format=%B", "HEAD~1"]).trim(),
+        "Add file a\n\nWith a body"
+    );
+    assert_eq!(
+        git_cmd(tmp.path(), &["log", "-1", "--format=%s"]).trim(),
+        "Add b"
+    );
+    assert_ne!(git_cmd(tmp.path(), &["rev-parse", "HEAD~1"]).trim(), target);
+    assert_eq!(git_cmd(tmp.path(), &["show", "HEAD:a.txt"]).trim(), "a");
+}
+
+#[tokio::test]
+async fn test_reword_commit_amends_head() {
+    let (tmp, ops) = setup_test_repo();
+    let parent = git_head_oid(tmp.path());
+    let head = commit_file(tmp.path(), "a.txt", "a", "Add a");
+
+    let new_oid = ops
+        .reword_commit(&head, "Reworded", None, None)
+        .await
+        .expect("should amend HEAD");
+
+    assert_eq!(new_oid, git_head_oid(tmp.path()));
+    assert_eq!(
+        git_cmd(tmp.path(), &["log", "-1", "--format=%s"]).trim(),
+        "Reworded"
+    );
+    assert_eq!(git_cmd(tmp.path(), &["rev-parse", "HEAD~1"]).trim(), parent);
+}
+
+#[tokio::test]
+async fn test_reword_commit_with_plan_succeeds_when_nothing_moved() {
+    let (tmp, ops) = setup_test_repo();
+    let target = commit_file(tmp.path(), "a.txt", "a", "Add a");
+    let head = commit_file(tmp.path(), "b.txt", "b", "Add b");
+
+    let plan = ops
+        .plan_reword_commit(&target)
+        .await
+        .expect("should plan reword");
+    assert_eq!(plan.commit.oid, target);
+    assert_eq!(
+        plan.replayed
+            .iter()
+            .map(|c| c.oid.clone())
+            .collect::<Vec<_>>(),
+        vec![head]
+    );
+    assert!(plan.preconditions.index_checksum.is_some());
+
+    ops.reword_commit(&target, "Add file a", None, Some(&plan.preconditions))
+        .await
+        .expect("should reword");
+    assert_eq!(
+        git_cmd(tmp.path(), &["log", "-1", "--format=%s", "HEAD~1"]).trim(),
+        "Add file a"
+    );
+}
+
+#[tokio::test]
+async fn test_reword_commit_fails_when_branch_moved_after_plan() {
+    let (tmp, ops) = setup_test_repo();
+    let target = commit_file(tmp.path(), "a.txt", "a", "Add a");
+    let planned_head = commit_file(tmp.path(), "b.txt", "b", "Add b");
+    let branch_ref = format!("refs/heads/{}", git_current_branch(tmp.path()));
+
+    let plan = ops
+        .plan_reword_commit(&target)
+        .await
+        .expect("should plan reword");
+
+    // The user commits twice from a terminal before confirming the reword
+    commit_file(tmp.path(), "c.txt", "c", "Add c");
+    let moved_head = commit_file(tmp.path(), "d.txt", "d", "Add d");
+
+    let err = ops
+        .reword_commit(&target, "Add file a", None, Some(&plan.preconditions))
+        .await
+        .expect_err("should refuse the stale plan");
+    let moved = |refname: &str| PreconditionViolation::Ref {
+        refname: refname.to_string(),
+        expected: Some(planned_head.clone()),
+        actual: Some(moved_head.clone()),
+        ahead: Some(2),
+        behind: Some(0),
+    };
+    match err {
+        AxisError::PreconditionFailed {
+            generation,
+            violations,
+        } => {
+            assert_eq!(generation, plan.preconditions.generation);
+            assert_eq!(violations, vec![moved("HEAD"), moved(&branch_ref)]);
+        }
+        other => panic!("expected PreconditionFailed, got {other:?}"),
+    }
+
+    // Nothing was rewritten
+    assert_eq!(git_head_oid(tmp.path()), moved_head);
+    assert!(!git_is_rebasing(tmp.path()));
+    assert_eq!(
+        git_cmd(tmp.path(), &["log", "-1", "--format=%s", "HEAD~3"]).trim(),
+        "Add a"
+    );
+}
+
+#[tokio::test]
+async fn test_reword_commit_fails_when_index_changed_after_plan() {
+    let (tmp, ops) = setup_test_repo();
+    let head = commit_file(tmp.path(), "a.txt", "a", "Add a");
+
+    let plan = ops.plan_reword_commit(&head).await.expect("should plan");
+    assert!(plan.replayed.is_empty());
+
+    std::fs::write(tmp.path().join("b.txt"), "b").expect("should write");
+    git_cmd(tmp.path(), &["add", "b.txt"]);
+
+    let err = ops
+        .reword_commit(&head, "Reworded", None, Some(&plan.preconditions))
+        .await
+        .expect_err("should refuse the stale plan");
+    assert!(
+        matches!(
+            &err,
+            AxisError::PreconditionFailed { violations, .. }
+                if matches!(violations.as_slice(), [PreconditionViolation::Index { expected, actual }] if expected != actual)
+        ),
+        "unexpected error: {err:?}"
+    );
+    assert_eq!(git_head_oid(tmp.path()), head);
+}
+
+#[tokio::test]
+async fn test_reword_commit_rejects_root_and_empty_message() {
+    let (tmp, ops) = setup_test_repo();
+    let root = git_head_oid(tmp.path());
+    let target = commit_file(tmp.path(), "a.txt", "a", "Add a");
+    commit_file(tmp.path(), "b.txt", "b", "Add b");
+    let head = git_head_oid(tmp.path());
+
+    ops.reword_commit(&target, " ", None, None)
+        .await
+        .expect_err("empty message should fail");
+    let err = ops
+        .reword_commit(&root, "New root", None, None)
+        .await
+        .expect_err("root commit cannot be reworded");
+    assert!(err.to_string().contains("root commit"));
+
+    assert_eq!(git_head_oid(tmp.path()), head);
+}
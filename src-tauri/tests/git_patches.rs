@@ -237,6 +237,68 @@ async fn test_format_patch_verified_by_cli() {
     );
 }
 
+// ==================== format_patch_series Tests ====================
+
+#[tokio::test]
+async fn test_format_patch_series_numbered_in_order() {
+    let (tmp, ops) = setup_test_repo();
+
+    let from = get_head_sha(tmp.path());
+    create_commit_with_content(tmp.path(), "one.txt", "1", "First change");
+    create_commit_with_content(tmp.path(), "two.txt", "2", "Second change");
+    create_commit_with_content(tmp.path(), "three.txt", "3", "Third change");
+    let to = get_head_sha(tmp.path());
+
+    // Output directory does not exist yet
+    let output_dir = tmp.path().join("out/series");
+
+    let patches = ops
+        .format_patch_series(&from, &to, &output_dir)
+        .await
+        .expect("should create patch series");
+
+    assert_eq!(patches.len(), 3);
+    for (i, (patch, subject)) in patches
+        .iter()
+        .zip(["First change", "Second change", "Third change"])
+        .enumerate()
+    {
+        let path = std::path::Path::new(patch);
+        assert!(file_exists(path), "{patch} should exist");
+        assert!(path.starts_with(&output_dir));
+        let name = path
+            .file_name()
+            .and_then(|n| n.to_str())
+            .expect("should have file name");
+        assert!(name.starts_with(&format!("{:04}-", i + 1)), "{name}");
+        assert!(read_file(path).contains(&format!("Subject: [PATCH {}/3] {subject}", i + 1)));
+    }
+}
+
+#[tokio::test]
+async fn test_format_patch_series_empty_range_fails() {
+    let (tmp, ops) = setup_test_repo();
+    let head = get_head_sha(tmp.path());
+
+    let result = ops
+        .format_patch_series(&head, &head, &tmp.path().join("patches"))
+        .await;
+
+    let err = result.expect_err("empty range should fail");
+    assert!(err.to_string().contains("No commits between"), "{err}");
+}
+
+#[tokio::test]
+async fn test_format_patch_series_rejects_option_like_oids() {
+    let (tmp, ops) = setup_test_repo();
+
+    let result = ops
+        .format_patch_series("--stdout", "HEAD", &tmp.path().join("patches"))
+        .await;
+
+    assert!(result.is_err());
+}
+
 // ==================== create_patch_from_diff Tests ====================
 
 #[tokio::test]
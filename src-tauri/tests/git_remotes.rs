@@ -308,6 +308,46 @@ async fn test_fetch_from_local_remote_verified_by_cli() {
     );
 }
 
+#[tokio::test]
+async fn test_remote_connection_to_local_remote_succeeds() {
+    let (tmp, ops) = setup_test_repo();
+    let bare_path = tmp.path().join("bare.git");
+    git_cmd(
+        tmp.path(),
+        &["clone", "--bare", ".", bare_path.to_str().expect("path")],
+    );
+    git_cmd(
+        tmp.path(),
+        &["remote", "add", "origin", bare_path.to_str().expect("path")],
+    );
+
+    let result = ops
+        .test_remote_connection("origin", None)
+        .await
+        .expect("should test connection");
+
+    assert!(result.success, "Connection failed: {:?}", result.error);
+    assert!(result.error.is_none());
+}
+
+#[tokio::test]
+async fn test_remote_connection_to_missing_remote_reports_error() {
+    let (tmp, ops) = setup_test_repo();
+    let missing = tmp.path().join("does-not-exist.git");
+    git_cmd(
+        tmp.path(),
+        &["remote", "add", "origin", missing.to_str().expect("path")],
+    );
+
+    let result = ops
+        .test_remote_connection("origin", None)
+        .await
+        .expect("should report the failure in the result");
+
+    assert!(!result.success);
+    assert!(result.error.is_some(), "Should explain why it failed");
+}
+
 #[tokio::test]
 async fn test_push_to_local_remote_verified_by_cli() {
     let (tmp, ops) = setup_test_repo();
@@ -397,6 +437,117 @@ async fn test_get_nonexistent_remote_fails() {
     assert!(result.is_err(), "Getting non-existent remote should fail");
 }
 
+#[tokio::test]
+async fn test_remote_connection_unknown_remote_fails() {
+    let (_tmp, ops) = setup_test_repo();
+
+    let result = ops.test_remote_connection("nonexistent", None).await;
+
+    assert!(result.is_err(), "Testing a non-existent remote should fail");
+}
+
+#[tokio::test]
+async fn test_list_remote_branches_without_fetching() {
+    let (tmp, ops) = setup_test_repo();
+    git_cmd(tmp.path(), &["branch", "feature"]);
+    git_cmd(tmp.path(), &["tag", "v1.0"]);
+    let bare_path = tmp.path().join("bare.git");
+    let bare = bare_path.to_str().expect("path");
+    git_cmd(tmp.path(), &["clone", "--bare", ".", bare]);
+    git_cmd(tmp.path(), &["remote", "add", "origin", bare]);
+
+    // A commit that only the remote has
+    let remote_only = git_cmd(
+        &bare_path,
+        &[
+            "-c",
+            "user.name=Remote",
+            "-c",
+            "user.email=remote@test.com",
+            "commit-tree",
+            "HEAD^{tree}",
+            "-p",
+            "HEAD",
+            "-m",
+            "Remote only",
+        ],
+    );
+    git_cmd(
+        &bare_path,
+        &["update-ref", "refs/heads/remote-only", &remote_only],
+    );
+
+    let branches = ops
+        .list_remote_branches("origin", None)
+        .await
+        .expect("should list remote branches");
+
+    let default_branch = git_cmd(tmp.path(), &["rev-parse", "--abbrev-ref", "HEAD"]);
+    let mut expected = vec![
+        "feature".to_string(),
+        default_branch.clone(),
+        "remote-only".to_string(),
+    ];
+    expected.sort();
+    let names: Vec<&str> = branches.iter().map(|b| b.name.as_str()).collect();
+    assert_eq!(names, expected);
+
+    let local = branches
+        .iter()
+        .find(|b| b.name == default_branch)
+        .expect("should list the default branch");
+    assert_eq!(local.oid, git_cmd(tmp.path(), &["rev-parse", "HEAD"]));
+    assert_eq!(local.last_commit_summary.as_deref(), Some("Initial commit"));
+
+    let remote = branches
+        .iter()
+        .find(|b| b.name == "remote-only")
+        .expect("should list the remote-only branch");
+    assert_eq!(remote.oid, remote_only);
+    assert!(
+        remote.last_commit_summary.is_none(),
+        "Objects must not be downloaded"
+    );
+    assert!(
+        git_cmd(tmp.path(), &["branch", "-r"]).is_empty(),
+        "Listing must not create remote-tracking branches"
+    );
+}
+
+#[tokio::test]
+async fn test_list_remote_tags_without_fetching() {
+    let (tmp, ops) = setup_test_repo();
+    git_cmd(tmp.path(), &["tag", "v1.0"]);
+    git_cmd(tmp.path(), &["tag", "-a", "v2.0", "-m", "Release 2.0"]);
+    git_cmd(tmp.path(), &["branch", "feature"]);
+    let bare_path = tmp.path().join("bare.git");
+    let bare = bare_path.to_str().expect("path");
+    git_cmd(tmp.path(), &["clone", "--bare", ".", bare]);
+    git_cmd(tmp.path(), &["remote", "add", "origin", bare]);
+    git_cmd(&bare_path, &["tag", "remote-only"]);
+
+    let tags = ops
+        .list_remote_tags("origin", None)
+        .await
+        .expect("should list remote tags");
+
+    let names: Vec<&str> = tags.iter().map(|t| t.name.as_str()).collect();
+    assert_eq!(names, ["remote-only", "v1.0", "v2.0"]);
+
+    let head = git_cmd(tmp.path(), &["rev-parse", "HEAD"]);
+    let lightweight = tags
+        .iter()
+        .find(|t| t.name == "v1.0")
+        .expect("should list v1.0");
+    assert_eq!(lightweight.oid, head);
+    let annotated = tags
+        .iter()
+        .find(|t| t.name == "v2.0")
+        .expect("should list v2.0");
+    assert_eq!(annotated.oid, git_cmd(tmp.path(), &["rev-parse", "v2.0"]));
+    assert_ne!(annotated.oid, head);
+}
+
 #[tokio::test]
 async fn test_remove_nonexistent_remote_fails() {
     let (_tmp, ops) = setup_test_repo();
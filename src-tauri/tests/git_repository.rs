@@ -4,7 +4,14 @@ mod common;
 
 use common::{git_cmd, setup_test_repo};
 
-use axis_lib::models::LogOptions;
+use axis_lib::models::{
+    BranchFilterType, BrokenHeadAction, ConfigLevel, LogOptions, NativeCommand, RepositoryState,
+    RepositoryStatusOptions,
+};
+use axis_lib::services::ops::RepoOperations;
+use axis_lib::services::{alias_invocation, measure_size_signals};
+use axis_lib::services::{CommitCache, GitService};
+use std::sync::Arc;
 
 // ==================== Helpers ====================
 
@@ -207,7 +214,10 @@ async fn test_status_clean_verified_by_cli() {
     assert!(cli_status.is_empty(), "CLI should show clean status");
 
     // Action: RepoOperations gets status
-    let status = ops.status().await.expect("should get status");
+    let status = ops
+        .status(RepositoryStatusOptions::recursive())
+        .await
+        .expect("should get status");
 
     // Verify: no changes
     assert!(status.staged.is_empty(), "Should have no staged files");
@@ -229,7 +239,10 @@ async fn test_status_staged_verified_by_cli() {
     assert!(git_is_staged(tmp.path(), "staged.txt"));
 
     // Action: RepoOperations gets status
-    let status = ops.status().await.expect("should get status");
+    let status = ops
+        .status(RepositoryStatusOptions::recursive())
+        .await
+        .expect("should get status");
 
     // Verify: sees staged file
     assert!(
@@ -248,7 +261,10 @@ async fn test_status_modified_verified_by_cli() {
     assert!(git_is_modified(tmp.path(), "README.md"));
 
     // Action: RepoOperations gets status
-    let status = ops.status().await.expect("should get status");
+    let status = ops
+        .status(RepositoryStatusOptions::recursive())
+        .await
+        .expect("should get status");
 
     // Verify: sees modified file
     assert!(
@@ -267,7 +283,10 @@ async fn test_status_untracked_verified_by_cli() {
     assert!(git_is_untracked(tmp.path(), "untracked.txt"));
 
     // Action: RepoOperations gets status
-    let status = ops.status().await.expect("should get status");
+    let status = ops
+        .status(RepositoryStatusOptions::recursive())
+        .await
+        .expect("should get status");
 
     // Verify: sees untracked file
     assert!(
@@ -289,7 +308,10 @@ async fn test_status_mixed_changes() {
     std::fs::write(tmp.path().join("untracked.txt"), "untracked").expect("should write");
 
     // Action: RepoOperations gets status
-    let status = ops.status().await.expect("should get status");
+    let status = ops
+        .status(RepositoryStatusOptions::recursive())
+        .await
+        .expect("should get status");
 
     // Verify: all types present
     assert!(!status.staged.is_empty(), "Should have staged");
@@ -369,6 +391,373 @@ async fn test_log_with_limit() {
     assert_eq!(log.len(), 3, "Log should be limited to 3 entries");
 }
 
+#[tokio::test]
+async fn test_log_page_cursor_matches_full_walk() {
+    let (tmp, ops) = setup_test_repo();
+    let cache = Arc::new(CommitCache::new());
+
+    // Setup: a side branch so the walk has more than one tip
+    for i in 1..=4 {
+        std::fs::write(tmp.path().join(format!("main{i}.txt")), "main").expect("should write");
+        git_cmd(tmp.path(), &["add", "."]);
+        git_cmd(tmp.path(), &["commit", "-m", &format!("Main {i}")]);
+    }
+    git_cmd(tmp.path(), &["checkout", "-b", "side", "HEAD~2"]);
+    for i in 1..=3 {
+        std::fs::write(tmp.path().join(format!("side{i}.txt")), "side").expect("should write");
+        git_cmd(tmp.path(), &["add", "."]);
+        git_cmd(tmp.path(), &["commit", "-m", &format!("Side {i}")]);
+    }
+
+    let full = ops
+        .log(LogOptions {
+            limit: Some(1000),
+            ..Default::default()
+        })
+        .await
+        .expect("should get full log");
+    assert_eq!(full.len(), 8);
+
+    // Action: page through with cursors
+    let mut paged = Vec::new();
+    let mut cursor = None;
+    loop {
+        let page = ops
+            .log_page(
+                LogOptions {
+                    limit: Some(3),
+                    cursor: cursor.take(),
+                    ..Default::default()
+                },
+                Arc::clone(&cache),
+            )
+            .await
+            .expect("should get page");
+        assert!(page.commits.len() <= 3);
+        paged.extend(page.commits);
+        match page.next_cursor {
+            Some(next) => cursor = Some(next),
+            None => break,
+        }
+    }
+
+    // Verify: same commits in the same order as a single walk
+    let full_oids: Vec<&str> = full.iter().map(|c| c.oid.as_str()).collect();
+    let paged_oids: Vec<&str> = paged.iter().map(|c| c.oid.as_str()).collect();
+    assert_eq!(paged_oids, full_oids);
+}
+
+#[tokio::test]
+async fn test_log_page_new_commit_invalidates_cursor() {
+    let (tmp, ops) = setup_test_repo();
+    let cache = Arc::new(CommitCache::new());
+
+    for i in 1..=4 {
+        std::fs::write(tmp.path().join(format!("file{i}.txt")), "content").expect("should write");
+        git_cmd(tmp.path(), &["add", "."]);
+        git_cmd(tmp.path(), &["commit", "-m", &format!("Commit {i}")]);
+    }
+
+    let first = ops
+        .log_page(
+            LogOptions {
+                limit: Some(2),
+                ..Default::default()
+            },
+            Arc::clone(&cache),
+        )
+        .await
+        .expect("should get first page");
+    let stale_cursor = first.next_cursor.expect("should have more commits");
+
+    // Action: a new commit moves HEAD, then resume with the old cursor
+    std::fs::write(tmp.path().join("new.txt"), "new").expect("should write");
+    git_cmd(tmp.path(), &["add", "."]);
+    git_cmd(tmp.path(), &["commit", "-m", "New commit"]);
+
+    let second = ops
+        .log_page(
+            LogOptions {
+                limit: Some(2),
+                cursor: Some(stale_cursor.clone()),
+                ..Default::default()
+            },
+            Arc::clone(&cache),
+        )
+        .await
+        .expect("stale cursor should fall back to a fresh walk");
+
+    // Verify: the fresh walk continues at the same offset of the new history
+    let fresh = ops
+        .log(LogOptions {
+            limit: Some(2),
+            skip: Some(2),
+            ..Default::default()
+        })
+        .await
+        .expect("should get log");
+    let second_oids: Vec<&str> = second.commits.iter().map(|c| c.oid.as_str()).collect();
+    let fresh_oids: Vec<&str> = fresh.iter().map(|c| c.oid.as_str()).collect();
+    assert_eq!(second_oids, fresh_oids);
+
+    let next_cursor = second.next_cursor.expect("should have more commits");
+    assert_ne!(next_cursor, stale_cursor);
+}
+
+#[tokio::test]
+async fn test_log_page_without_cursor_uses_skip() {
+    let (tmp, ops) = setup_test_repo();
+
+    for i in 1..=3 {
+        std::fs::write(tmp.path().join(format!("file{i}.txt")), "content").expect("should write");
+        git_cmd(tmp.path(), &["add", "."]);
+        git_cmd(tmp.path(), &["commit", "-m", &format!("Commit {i}")]);
+    }
+
+    let page = ops
+        .log_page(
+            LogOptions {
+                limit: Some(1),
+                skip: Some(1),
+                ..Default::default()
+            },
+            Arc::new(CommitCache::new()),
+        )
+        .await
+        .expect("should get page");
+
+    let log = ops
+        .log(LogOptions {
+            limit: Some(1),
+            skip: Some(1),
+            ..Default::default()
+        })
+        .await
+        .expect("should get log");
+    assert_eq!(page.commits.len(), 1);
+    assert_eq!(page.commits[0].oid, log[0].oid);
+    assert!(page.next_cursor.is_some());
+}
+
+/// Two feature branches, each merged into main with `--no-ff` after main moved on
+fn create_merge_history(path: &std::path::Path) {
+    let commit = |name: &str| {
+        std::fs::write(path.join(format!("{name}.txt")), name).expect("should write");
+        git_cmd(path, &["add", "."]);
+        git_cmd(path, &["commit", "-m", name]);
+    };
+    for branch in ["feature-a", "feature-b"] {
+        git_cmd(path, &["checkout", "-b", branch]);
+        commit(&format!("{branch} one"));
+        commit(&format!("{branch} two"));
+        git_cmd(path, &["checkout", "-"]);
+        commit(&format!("main before {branch}"));
+        git_cmd(
+            path,
+            &["merge", "--no-ff", "-m", &format!("Merge {branch}"), branch],
+        );
+    }
+}
+
+fn rev_list_oids(path: &std::path::Path, args: &[&str]) -> Vec<String> {
+    let mut command = vec!["rev-list"];
+    command.extend_from_slice(args);
+    command.push("HEAD");
+    git_cmd(path, &command)
+        .lines()
+        .map(str::to_string)
+        .collect()
+}
+
+fn current_branch_log(options: LogOptions) -> LogOptions {
+    LogOptions {
+        limit: Some(1000),
+        branch_filter: BranchFilterType::Current,
+        ..options
+    }
+}
+
+#[tokio::test]
+async fn test_log_no_merges_and_first_parent_match_cli() {
+    let (tmp, ops) = setup_test_repo();
+    create_merge_history(tmp.path());
+
+    let no_merges = ops
+        .log(current_branch_log(LogOptions {
+            no_merges: true,
+            ..Default::default()
+        }))
+        .await
+        .expect("should get log without merges");
+    let first_parent = ops
+        .log(current_branch_log(LogOptions {
+            first_parent: true,
+            ..Default::default()
+        }))
+        .await
+        .expect("should get first-parent log");
+    let merges_only = ops
+        .log(current_branch_log(LogOptions {
+            min_parents: Some(2),
+            ..Default::default()
+        }))
+        .await
+        .expect("should get merges");
+
+    let cli_no_merges = rev_list_oids(tmp.path(), &["--no-merges"]);
+    let cli_first_parent = rev_list_oids(tmp.path(), &["--first-parent"]);
+    assert_eq!(no_merges.len(), cli_no_merges.len());
+    assert!(no_merges.iter().all(|c| cli_no_merges.contains(&c.oid)));
+    assert_eq!(first_parent.len(), cli_first_parent.len());
+    assert!(first_parent
+        .iter()
+        .all(|c| cli_first_parent.contains(&c.oid)));
+    assert_eq!(
+        merges_only.len(),
+        rev_list_oids(tmp.path(), &["--min-parents=2"]).len()
+    );
+    assert!(merges_only.iter().all(|c| c.is_merge));
+}
+
+#[tokio::test]
+async fn test_log_skip_and_limit_apply_after_filters() {
+    let (tmp, ops) = setup_test_repo();
+    create_merge_history(tmp.path());
+
+    let full = ops
+        .log(current_branch_log(LogOptions::default()))
+        .await
+        .expect("should get full log");
+    let filtered = ops
+        .log(LogOptions {
+            skip: Some(2),
+            limit: Some(3),
+            no_merges: true,
+            branch_filter: BranchFilterType::Current,
+            ..Default::default()
+        })
+        .await
+        .expect("should get filtered log");
+
+    // Verify: the page is taken from the non-merge commits, not the raw walk
+    let expected: Vec<&str> = full
+        .iter()
+        .filter(|c| !c.is_merge)
+        .skip(2)
+        .take(3)
+        .map(|c| c.oid.as_str())
+        .collect();
+    let oids: Vec<&str> = filtered.iter().map(|c| c.oid.as_str()).collect();
+    assert_eq!(oids, expected);
+}
+
+#[tokio::test]
+async fn test_log_author_filter_matches_name_or_email() {
+    let (tmp, ops) = setup_test_repo();
+    for (i, author) in [
+        "Alice <alice@example.com>",
+        "Bob <bob@ALICE.dev>",
+        "Carol <carol@example.com>",
+    ]
+    .into_iter()
+    .enumerate()
+    {
+        std::fs::write(tmp.path().join(format!("file{i}.txt")), author).expect("should write");
+        git_cmd(tmp.path(), &["add", "."]);
+        git_cmd(tmp.path(), &["commit", "-m", "Change", "--author", author]);
+    }
+
+    let log = ops
+        .log(current_branch_log(LogOptions {
+            author: Some("alice".to_string()),
+            ..Default::default()
+        }))
+        .await
+        .expect("should get log");
+
+    let mut authors: Vec<&str> = log.iter().map(|c| c.author.name.as_str()).collect();
+    authors.sort_unstable();
+    assert_eq!(authors, vec!["Alice", "Bob"]);
+}
+
+#[tokio::test]
+async fn test_log_page_reports_scanned_and_matched() {
+    let (tmp, ops) = setup_test_repo();
+    create_merge_history(tmp.path());
+
+    let page = ops
+        .log_page(
+            LogOptions {
+                limit: Some(2),
+                no_merges: true,
+                branch_filter: BranchFilterType::Current,
+                ..Default::default()
+            },
+            Arc::new(CommitCache::new()),
+        )
+        .await
+        .expect("should get page");
+
+    assert_eq!(page.commits.len(), 2);
+    assert_eq!(page.scanned, rev_list_oids(tmp.path(), &[]).len());
+    assert_eq!(
+        page.matched,
+        rev_list_oids(tmp.path(), &["--no-merges"]).len()
+    );
+    assert!(page.commits.iter().all(|c| !c.is_merge));
+}
+
+// ==================== Size Signal Tests ====================
+
+fn cli_line_count(path: &std::path::Path, args: &[&str]) -> u64 {
+    git_cmd(path, args).lines().count() as u64
+}
+
+#[test]
+fn test_measure_size_signals_matches_cli() {
+    let (tmp, _ops) = setup_test_repo();
+
+    // Setup: files in nested directories, branches and tags, some refs packed
+    for i in 0..40 {
+        let dir = tmp.path().join(format!("pkg{}", i % 4));
+        std::fs::create_dir_all(&dir).expect("should create dir");
+        std::fs::write(dir.join(format!("file{i}.txt")), i.to_string()).expect("should write");
+    }
+    git_cmd(tmp.path(), &["add", "."]);
+    git_cmd(tmp.path(), &["commit", "-m", "Add files"]);
+    for i in 0..5 {
+        git_cmd(tmp.path(), &["branch", &format!("topic-{i}")]);
+        git_cmd(tmp.path(), &["tag", &format!("v0.{i}")]);
+    }
+    git_cmd(tmp.path(), &["pack-refs", "--all"]);
+    git_cmd(tmp.path(), &["branch", "loose-after-pack"]);
+
+    let loose = measure_size_signals(tmp.path()).expect("should measure");
+    assert_eq!(
+        loose.tracked_files,
+        cli_line_count(tmp.path(), &["ls-files"])
+    );
+    assert_eq!(loose.refs, cli_line_count(tmp.path(), &["for-each-ref"]));
+    assert_eq!(loose.pack_bytes, 0);
+
+    // Action: pack every object
+    git_cmd(tmp.path(), &["gc", "--quiet"]);
+    let packed = measure_size_signals(tmp.path()).expect("should measure");
+    assert_eq!(packed.tracked_files, loose.tracked_files);
+    assert_eq!(packed.refs, loose.refs);
+    assert!(packed.pack_bytes > 0);
+}
+
+#[test]
+fn test_measure_size_signals_without_index() {
+    let tmp = tempfile::TempDir::new().expect("should create temp dir");
+    git_cmd(tmp.path(), &["init", "--bare", "--quiet"]);
+
+    let signals = measure_size_signals(tmp.path()).expect("should measure bare repository");
+    assert_eq!(signals.tracked_files, 0);
+    assert_eq!(signals.refs, 0);
+}
+
 #[tokio::test]
 async fn test_cli_commit_appears_in_ops_log() {
     let (tmp, ops) = setup_test_repo();
@@ -449,6 +838,78 @@ async fn test_cli_config_read_by_ops() {
     assert_eq!(email, Some("cli@example.com".to_string()));
 }
 
+#[tokio::test]
+async fn test_set_repository_config_local_verified_by_cli() {
+    let (tmp, ops) = setup_test_repo();
+
+    ops.set_config_value("pull.rebase", "true", ConfigLevel::Local)
+        .await
+        .expect("should set config");
+
+    assert_eq!(
+        git_cmd(tmp.path(), &["config", "--local", "--get", "pull.rebase"]),
+        "true"
+    );
+    assert_eq!(
+        ops.get_config_value("pull.rebase")
+            .await
+            .expect("should get config"),
+        Some("true".to_string())
+    );
+}
+
+#[tokio::test]
+async fn test_get_repository_config_missing_key() {
+    let (_tmp, ops) = setup_test_repo();
+
+    let value = ops
+        .get_config_value("axis.doesNotExist")
+        .await
+        .expect("should get config");
+    assert_eq!(value, None);
+}
+
+#[tokio::test]
+async fn test_set_repository_config_rejects_worktree_level() {
+    let (_tmp, ops) = setup_test_repo();
+
+    let result = ops
+        .set_config_value("pull.rebase", "true", ConfigLevel::Worktree)
+        .await;
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn test_list_repository_config_sorted_with_origin() {
+    let (tmp, ops) = setup_test_repo();
+    git_cmd(tmp.path(), &["config", "--local", "zeta.setting", "last"]);
+    git_cmd(tmp.path(), &["config", "--local", "alpha.setting", "first"]);
+
+    let entries = ops.list_config_entries().await.expect("should list config");
+
+    let keys: Vec<&str> = entries.iter().map(|e| e.key.as_str()).collect();
+    let mut sorted = keys.clone();
+    sorted.sort_unstable();
+    assert_eq!(keys, sorted, "entries should be sorted by key");
+
+    let alpha = entries
+        .iter()
+        .find(|e| e.key == "alpha.setting")
+        .expect("local entry should be listed");
+    assert_eq!(alpha.value, "first");
+    assert_eq!(alpha.level, ConfigLevel::Local);
+    assert!(alpha.origin.starts_with("file:"));
+    assert!(
+        std::path::Path::new(&alpha.origin["file:".len()..]).ends_with(".git/config"),
+        "unexpected origin {}",
+        alpha.origin
+    );
+    assert!(entries.iter().all(|e| matches!(
+        e.level,
+        ConfigLevel::System | ConfigLevel::Global | ConfigLevel::Local
+    )));
+}
+
 #[tokio::test]
 async fn test_get_user_signature_verified_by_cli() {
     let (tmp, ops) = setup_test_repo();
@@ -467,6 +928,60 @@ async fn test_get_user_signature_verified_by_cli() {
     assert_eq!(email, cli_email);
 }
 
+// ==================== Config Alias Tests ====================
+
+#[tokio::test]
+async fn test_list_config_aliases_reads_local_config() {
+    let (tmp, ops) = setup_test_repo();
+    git_cmd(
+        tmp.path(),
+        &["config", "alias.lg", "log --oneline --format='%s by %an'"],
+    );
+    git_cmd(tmp.path(), &["config", "alias.hello", "!echo hello"]);
+
+    let aliases = ops.list_config_aliases().await.expect("should list");
+    let lg = aliases
+        .iter()
+        .find(|a| a.name == "lg" && a.level == ConfigLevel::Local)
+        .expect("lg should be listed");
+    assert_eq!(lg.args, vec!["log", "--oneline", "--format=%s by %an"]);
+    assert_eq!(lg.native_equivalent, Some(NativeCommand::Log));
+    assert!(!lg.shadowed);
+
+    let hello = aliases
+        .iter()
+        .find(|a| a.name == "hello" && a.level == ConfigLevel::Local)
+        .expect("hello should be listed");
+    assert!(hello.is_shell);
+}
+
+#[tokio::test]
+async fn test_run_config_alias_captures_output() {
+    let (tmp, ops) = setup_test_repo();
+    git_cmd(tmp.path(), &["config", "alias.subjects", "log --format=%s"]);
+
+    let alias = ops
+        .list_config_aliases()
+        .await
+        .expect("should list")
+        .into_iter()
+        .find(|a| a.name == "subjects" && !a.shadowed)
+        .expect("alias should be listed");
+    let args = alias_invocation(&alias, vec!["-1".to_string()], false).expect("should expand");
+
+    let lines = std::sync::Mutex::new(Vec::new());
+    let result = ops
+        .run_git_streaming(&args, |_, line| {
+            lines.lock().expect("lock").push(line.to_string());
+        })
+        .await
+        .expect("should run");
+
+    assert_eq!(result.exit_code, 0);
+    assert_eq!(result.stdout.trim(), "Initial commit");
+    assert_eq!(*lines.lock().expect("lock"), vec!["Initial commit"]);
+}
+
 // ==================== resolve_ref Tests ====================
 
 #[tokio::test]
@@ -536,3 +1051,228 @@ async fn test_resolve_ref_short_oid() {
     // Verify: resolves to full OID
     assert_eq!(resolved, Some(full_oid));
 }
+
+// ==================== Broken HEAD Tests ====================
+
+/// Check out `feature` with one commit, then delete its ref file behind git's back.
+/// Returns the original branch name and the deleted branch's tip.
+fn break_head(path: &std::path::Path) -> (String, String) {
+    let original = git_current_branch(path);
+    git_cmd(path, &["checkout", "-b", "feature"]);
+    std::fs::write(path.join("feature.txt"), "feature").expect("should write file");
+    git_cmd(path, &["add", "."]);
+    git_cmd(path, &["commit", "-m", "Feature commit"]);
+    let tip = git_head_oid(path);
+
+    std::fs::remove_file(path.join(".git/refs/heads/feature")).expect("should delete ref");
+    (original, tip)
+}
+
+#[tokio::test]
+async fn test_broken_head_detected() {
+    let (tmp, ops) = setup_test_repo();
+    break_head(tmp.path());
+
+    let info = ops.get_repository_info().await.expect("should get info");
+
+    assert_eq!(info.state, RepositoryState::BrokenHead);
+    assert_eq!(info.broken_head.as_deref(), Some("refs/heads/feature"));
+    assert!(!info.is_unborn);
+}
+
+#[tokio::test]
+async fn test_fresh_repository_is_unborn_not_broken() {
+    let tmp = tempfile::TempDir::new().expect("should create temp dir");
+    git_cmd(tmp.path(), &["init"]);
+    let service = std::sync::Arc::new(
+        axis_lib::services::GitService::new_for_test(tmp.path()).expect("should open"),
+    );
+    let ops = axis_lib::services::ops::RepoOperations::new(service);
+
+    let info = ops.get_repository_info().await.expect("should get info");
+
+    assert_eq!(info.state, RepositoryState::Clean);
+    assert!(info.broken_head.is_none());
+    assert!(info.is_unborn);
+}
+
+#[tokio::test]
+async fn test_broken_head_recoveries() {
+    let (tmp, ops) = setup_test_repo();
+    let (original, tip) = break_head(tmp.path());
+
+    let recoveries = ops
+        .get_broken_head_recoveries()
+        .await
+        .expect("should list recoveries");
+
+    assert_eq!(recoveries.len(), 2);
+    assert_eq!(recoveries[0].action, BrokenHeadAction::RecreateBranch);
+    assert_eq!(recoveries[0].branch, "feature");
+    assert_eq!(recoveries[0].oid.as_deref(), Some(tip.as_str()));
+    assert_eq!(recoveries[1].action, BrokenHeadAction::SwitchBranch);
+    assert_eq!(recoveries[1].branch, original);
+}
+
+#[tokio::test]
+async fn test_recover_broken_head_from_head_reflog() {
+    let (tmp, ops) = setup_test_repo();
+    let (_original, tip) = break_head(tmp.path());
+    // `git branch -D` removes the branch reflog too; HEAD's reflog still has the tip
+    std::fs::remove_file(tmp.path().join(".git/logs/refs/heads/feature"))
+        .expect("should delete reflog");
+
+    let recovery = ops
+        .recover_broken_head(BrokenHeadAction::RecreateBranch)
+        .await
+        .expect("should recover");
+
+    assert_eq!(recovery.oid.as_deref(), Some(tip.as_str()));
+    assert_eq!(git_current_branch(tmp.path()), "feature");
+    assert_eq!(git_head_oid(tmp.path()), tip);
+    let info = ops.get_repository_info().await.expect("should get info");
+    assert_eq!(info.state, RepositoryState::Clean);
+}
+
+#[tokio::test]
+async fn test_recover_broken_head_switch_branch() {
+    let (tmp, ops) = setup_test_repo();
+    let (original, _tip) = break_head(tmp.path());
+    let original_oid = git_cmd(tmp.path(), &["rev-parse", &original]);
+
+    ops.recover_broken_head(BrokenHeadAction::SwitchBranch)
+        .await
+        .expect("should recover");
+
+    assert_eq!(git_current_branch(tmp.path()), original);
+    assert_eq!(git_head_oid(tmp.path()), original_oid);
+    // The working tree is left alone, so the feature file shows up as a change
+    assert!(tmp.path().join("feature.txt").exists());
+    assert!(git_cmd(tmp.path(), &["status", "--porcelain"]).contains("feature.txt"));
+}
+
+#[tokio::test]
+async fn test_create_commit_fails_fast_on_broken_head() {
+    let (tmp, ops) = setup_test_repo();
+    break_head(tmp.path());
+    std::fs::write(tmp.path().join("more.txt"), "more").expect("should write file");
+    ops.stage_file("more.txt").await.expect("should stage");
+
+    let err = ops
+        .create_commit("More", None, None, None, false)
+        .await
+        .expect_err("should refuse to commit");
+
+    assert!(err.to_string().contains("refs/heads/feature"));
+    // No orphan root commit was written to the missing branch
+    assert!(!tmp.path().join(".git/refs/heads/feature").exists());
+}
+
+// ==================== Alternates Tests ====================
+
+/// Clone `lender` with `git clone --shared` to `borrower`, so the clone borrows
+/// every object through `objects/info/alternates`
+fn shared_clone(lender: &std::path::Path, borrower: &std::path::Path) -> RepoOperations {
+    git_cmd(
+        lender,
+        &[
+            "clone",
+            "--shared",
+            "--quiet",
+            ".",
+            &borrower.display().to_string(),
+        ],
+    );
+    let service = GitService::new_for_test(borrower).expect("should open borrower");
+    RepoOperations::new(Arc::new(service))
+}
+
+#[tokio::test]
+async fn test_object_sharing_detects_alternates_and_borrowers() {
+    let root = tempfile::TempDir::new().expect("should create temp dir");
+    let lender = root.path().join("lender");
+    std::fs::create_dir(&lender).expect("should create lender");
+    git_cmd(&lender, &["init", "--quiet"]);
+    git_cmd(&lender, &["config", "user.email", "test@test.com"]);
+    git_cmd(&lender, &["config", "user.name", "Test User"]);
+    git_cmd(
+        &lender,
+        &["commit", "--allow-empty", "-m", "Initial commit"],
+    );
+    let borrower_ops = shared_clone(&lender, &root.path().join("borrower"));
+
+    let sharing = borrower_ops
+        .get_object_sharing(vec![])
+        .await
+        .expect("should report sharing");
+    assert_eq!(sharing.alternates.len(), 1);
+    assert!(sharing.alternates[0].reachable);
+    assert_eq!(
+        sharing.alternates[0]
+            .path
+            .canonicalize()
+            .expect("should canonicalize"),
+        lender
+            .join(".git/objects")
+            .canonicalize()
+            .expect("should canonicalize")
+    );
+    assert_eq!(sharing.maintenance_warnings.len(), 1);
+
+    let lender_service = GitService::new_for_test(&lender).expect("should open lender");
+    let lender_ops = RepoOperations::new(Arc::new(lender_service));
+    let sharing = lender_ops
+        .get_object_sharing(vec![root.path().to_path_buf()])
+        .await
+        .expect("should report sharing");
+    assert!(sharing.alternates.is_empty());
+    assert_eq!(sharing.borrowers, vec![root.path().join("borrower")]);
+    // gc in the lender could prune objects the borrower needs
+    assert!(sharing.maintenance_warnings[0].contains("pruning can delete objects"));
+
+    // Without shared-store roots nobody is scanned
+    let sharing = lender_ops
+        .get_object_sharing(vec![])
+        .await
+        .expect("should report sharing");
+    assert!(sharing.borrowers.is_empty());
+    assert!(sharing.maintenance_warnings.is_empty());
+}
+
+#[tokio::test]
+async fn test_unreachable_alternate_maps_missing_object_error() {
+    let (lender, _ops) = setup_test_repo();
+    let borrower = tempfile::TempDir::new().expect("should create temp dir");
+    let borrower_ops = shared_clone(lender.path(), borrower.path());
+
+    let head = git_head_oid(lender.path());
+
+    // Simulate the shared store going offline
+    let objects = lender.path().join(".git/objects");
+    let offline = lender.path().join(".git/objects-offline");
+    std::fs::rename(&objects, &offline).expect("should move objects");
+
+    let sharing = borrower_ops
+        .get_object_sharing(vec![])
+        .await
+        .expect("should report sharing");
+    assert!(!sharing.alternates[0].reachable);
+    assert!(sharing.maintenance_warnings[0].contains("unreachable"));
+
+    let err = borrower_ops
+        .get_file_blob("README.md", Some(&head))
+        .await
+        .expect_err("blob should be unreadable");
+    assert!(
+        err.to_string().starts_with("Alternate object store")
+            && err.to_string().contains("is unreachable"),
+        "unexpected error: {err}"
+    );
+
+    std::fs::rename(&offline, &objects).expect("should restore objects");
+    let blob = borrower_ops
+        .get_file_blob("README.md", Some(&head))
+        .await
+        .expect("should read blob once reconnected");
+    assert_eq!(blob, b"# Test");
+}
@@ -0,0 +1,261 @@
+#![cfg(feature = "integration")]
+
+mod common;
+
+use common::{git_cmd, setup_test_repo};
+
+use axis_lib::error::AxisError;
+use axis_lib::models::{PreconditionViolation, RewrittenCommit};
+use tempfile::TempDir;
+
+// ==================== Helpers ====================
+
+/// Add a bare remote, push the initial commit and set it as upstream
+fn setup_remote(path: &std::path::Path) -> TempDir {
+    let remote = TempDir::new().expect("should create remote dir");
+    git_cmd(remote.path(), &["init", "--bare"]);
+    git_cmd(
+        path,
+        &[
+            "remote",
+            "add",
+            "origin",
+            remote.path().to_str().expect("valid path"),
+        ],
+    );
+    git_cmd(path, &["push", "-u", "origin", "HEAD"]);
+    remote
+}
+
+/// Write files, stage everything and commit via CLI
+fn commit_files(path: &std::path::Path, files: &[(&str, &str)], message: &str) -> String {
+    for (name, content) in files {
+        std::fs::write(path.join(name), content).expect("should write file");
+    }
+    git_cmd(path, &["add", "-A"]);
+    git_cmd(path, &["commit", "-m", message]);
+    git_cmd(path, &["rev-parse", "HEAD"])
+}
+
+/// Four unpushed commits where `.env` exists in the second and third
+fn setup_secret_history(path: &std::path::Path) -> Vec<String> {
+    let c1 = commit_files(path, &[("a.txt", "a\n")], "Add a");
+    let c2 = commit_files(path, &[(".env", "SECRET=one\n"), ("b.txt", "b\n")], "Add b");
+    let c3 = commit_files(path, &[(".env", "SECRET=two\n"), ("c.txt", "c\n")], "Add c");
+    std::fs::remove_file(path.join(".env")).expect("should remove file");
+    let c4 = commit_files(path, &[("d.txt", "d\n")], "Add d");
+    vec![c1, c2, c3, c4]
+}
+
+fn tree_of(path: &std::path::Path, rev: &str) -> String {
+    git_cmd(path, &["rev-parse", &format!("{rev}^{{tree}}")])
+}
+
+fn ls_tree(path: &std::path::Path, rev: &str) -> Vec<String> {
+    git_cmd(path, &["ls-tree", "-r", "--name-only", rev])
+        .lines()
+        .map(std::string::ToString::to_string)
+        .collect()
+}
+
+// ==================== Remove Path Tests ====================
+
+#[tokio::test]
+async fn test_preview_remove_path_lists_affected_commits() {
+    let (tmp, ops) = setup_test_repo();
+    let _remote = setup_remote(tmp.path());
+    let commits = setup_secret_history(tmp.path());
+
+    let preview = ops
+        .preview_remove_path_from_history(&[".env".to_string()])
+        .await
+        .expect("should preview");
+
+    assert_eq!(preview.unpushed_count, 4);
+    assert!(preview.upstream.is_some());
+    let affected: Vec<&str> = preview
+        .affected_commits
+        .iter()
+        .map(|c| c.commit.oid.as_str())
+        .collect();
+    assert_eq!(affected, vec![commits[1].as_str(), commits[2].as_str()]);
+    assert_eq!(preview.affected_commits[0].removed[0].path, ".env");
+    assert_eq!(preview.affected_commits[0].removed[0].size, 11);
+    assert_eq!(preview.total_bytes, 22);
+    assert!(preview.other_branches.is_empty());
+}
+
+#[tokio::test]
+async fn test_remove_path_from_unpushed_history() {
+    let (tmp, ops) = setup_test_repo();
+    let _remote = setup_remote(tmp.path());
+    let commits = setup_secret_history(tmp.path());
+    let old_d_tree = tree_of(tmp.path(), &commits[3]);
+    let old_author = git_cmd(
+        tmp.path(),
+        &["log", "-1", "--format=%an <%ae> %at", &commits[2]],
+    );
+
+    let result = ops
+        .remove_path_from_unpushed_history(&[".env".to_string()], false, None, None)
+        .await
+        .expect("should rewrite history");
+
+    // The commit before the secret keeps its hash
+    let new_log: Vec<String> = git_cmd(tmp.path(), &["rev-list", "--reverse", "HEAD~4..HEAD"])
+        .lines()
+        .map(std::string::ToString::to_string)
+        .collect();
+    assert_eq!(new_log.len(), 4);
+    assert_eq!(new_log[0], commits[0]);
+
+    // Mapping covers exactly the rewritten commits
+    let expected: Vec<RewrittenCommit> = (1..4)
+        .map(|i| RewrittenCommit {
+            old_oid: commits[i].clone(),
+            new_oid: new_log[i].clone(),
+        })
+        .collect();
+    assert_eq!(result.rewritten, expected);
+    assert_eq!(result.old_head, commits[3]);
+    assert_eq!(result.new_head, new_log[3]);
+    assert!(result.removed_from_branch);
+    assert!(result.note.contains("reflog"));
+
+    // The path is gone from the whole range, everything else is intact
+    for rev in &new_log {
+        assert!(!ls_tree(tmp.path(), rev).contains(&".env".to_string()));
+    }
+    assert_eq!(
+        ls_tree(tmp.path(), &new_log[2]),
+        vec!["README.md", "a.txt", "b.txt", "c.txt"]
+    );
+
+    // The last commit never contained the path, so its tree is unchanged
+    assert_eq!(tree_of(tmp.path(), &new_log[3]), old_d_tree);
+
+    // Messages and authorship are preserved
+    assert_eq!(
+        git_cmd(tmp.path(), &["log", "-1", "--format=%s", &new_log[2]]),
+        "Add c"
+    );
+    assert_eq!(
+        git_cmd(
+            tmp.path(),
+            &["log", "-1", "--format=%an <%ae> %at", &new_log[2]]
+        ),
+        old_author
+    );
+
+    // Reflog records the rewrite
+    let branch = git_cmd(tmp.path(), &["rev-parse", "--abbrev-ref", "HEAD"]);
+    let reflog = git_cmd(tmp.path(), &["reflog", "-1", "--format=%gs", &branch]);
+    assert!(
+        reflog.contains("remove-path"),
+        "unexpected reflog: {reflog}"
+    );
+}
+
+#[tokio::test]
+async fn test_remove_path_keeps_file_untracked() {
+    let (tmp, ops) = setup_test_repo();
+    let _remote = setup_remote(tmp.path());
+    commit_files(tmp.path(), &[(".env", "SECRET=1\n")], "Add env");
+
+    ops.remove_path_from_unpushed_history(&[".env".to_string()], false, None, None)
+        .await
+        .expect("should rewrite history");
+
+    assert!(tmp.path().join(".env").exists());
+    let status = git_cmd(tmp.path(), &["status", "--porcelain"]);
+    assert_eq!(status, "?? .env");
+}
+
+#[tokio::test]
+async fn test_remove_path_rejects_pushed_history() {
+    let (tmp, ops) = setup_test_repo();
+    commit_files(tmp.path(), &[(".env", "SECRET=1\n")], "Add env");
+    let _remote = setup_remote(tmp.path());
+    commit_files(tmp.path(), &[("a.txt", "a\n")], "Add a");
+
+    let err = ops
+        .remove_path_from_unpushed_history(&[".env".to_string()], false, None, None)
+        .await
+        .expect_err("should refuse to rewrite pushed history");
+    assert!(err.to_string().contains("pushed commit"));
+}
+
+#[tokio::test]
+async fn test_remove_path_requires_acknowledging_other_branches() {
+    let (tmp, ops) = setup_test_repo();
+    let _remote = setup_remote(tmp.path());
+    setup_secret_history(tmp.path());
+    git_cmd(tmp.path(), &["branch", "copy", "HEAD~1"]);
+
+    let preview = ops
+        .preview_remove_path_from_history(&[".env".to_string()])
+        .await
+        .expect("should preview");
+    assert_eq!(preview.other_branches, vec!["copy".to_string()]);
+
+    let old_head = git_cmd(tmp.path(), &["rev-parse", "HEAD"]);
+    ops.remove_path_from_unpushed_history(&[".env".to_string()], false, None, None)
+        .await
+        .expect_err("should require acknowledgement");
+    assert_eq!(git_cmd(tmp.path(), &["rev-parse", "HEAD"]), old_head);
+
+    ops.remove_path_from_unpushed_history(&[".env".to_string()], true, None, None)
+        .await
+        .expect("should rewrite after acknowledgement");
+    assert_ne!(git_cmd(tmp.path(), &["rev-parse", "HEAD"]), old_head);
+}
+
+#[tokio::test]
+async fn test_remove_path_fails_when_index_changed_after_preview() {
+    let (tmp, ops) = setup_test_repo();
+    let _remote = setup_remote(tmp.path());
+    let commits = setup_secret_history(tmp.path());
+
+    let preview = ops
+        .preview_remove_path_from_history(&[".env".to_string()])
+        .await
+        .expect("should preview");
+
+    std::fs::write(tmp.path().join("e.txt"), "e\n").expect("should write file");
+    git_cmd(tmp.path(), &["add", "e.txt"]);
+
+    let err = ops
+        .remove_path_from_unpushed_history(
+            &[".env".to_string()],
+            false,
+            None,
+            Some(&preview.preconditions),
+        )
+        .await
+        .expect_err("should refuse the stale preview");
+    assert!(
+        matches!(
+            &err,
+            AxisError::PreconditionFailed { generation, violations }
+                if *generation == preview.preconditions.generation
+                    && matches!(violations.as_slice(), [PreconditionViolation::Index { .. }])
+        ),
+        "unexpected error: {err:?}"
+    );
+    assert_eq!(git_cmd(tmp.path(), &["rev-parse", "HEAD"]), commits[3]);
+
+    // A fresh preview picks up the new index and goes through
+    let preview = ops
+        .preview_remove_path_from_history(&[".env".to_string()])
+        .await
+        .expect("should preview");
+    ops.remove_path_from_unpushed_history(
+        &[".env".to_string()],
+        false,
+        None,
+        Some(&preview.preconditions),
+    )
+    .await
+    .expect("should rewrite history");
+    assert!(!ls_tree(tmp.path(), "HEAD").contains(&".env".to_string()));
+}
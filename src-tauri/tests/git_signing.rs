@@ -0,0 +1,100 @@
+#![cfg(feature = "integration")]
+
+mod common;
+
+use std::process::Command;
+
+use axis_lib::models::{ConfigLevel, SigningFormat};
+use axis_lib::services::SigningService;
+use common::{git_cmd, setup_test_repo};
+use tempfile::TempDir;
+
+// ==================== GPG Key Generation Tests ====================
+
+/// Generates a key into a throwaway GNUPGHOME, then writes it as the repository's
+/// signing key. One test owns GNUPGHOME because the variable is process-wide.
+#[tokio::test]
+async fn test_generate_gpg_key_and_configure_repo_signing() {
+    if SigningService::find_gpg_program().await.is_none() {
+        return;
+    }
+
+    let gnupg_home = TempDir::new().expect("should create GNUPGHOME");
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        std::fs::set_permissions(gnupg_home.path(), std::fs::Permissions::from_mode(0o700))
+            .expect("should restrict GNUPGHOME");
+    }
+    std::env::set_var("GNUPGHOME", gnupg_home.path());
+
+    let key = SigningService::generate_gpg_key(
+        "Axis Test",
+        "axis-test@example.com",
+        None,
+        Some("1y"),
+        |_| {},
+    )
+    .await
+    .expect("should generate key");
+
+    assert_eq!(key.fingerprint.len(), 40);
+    assert!(key.fingerprint.ends_with(&key.key_id));
+    assert_eq!(key.key_id.len(), 16);
+
+    let listing = Command::new("gpg")
+        .args(["--batch", "--list-secret-keys", "--with-colons"])
+        .output()
+        .expect("should list keys");
+    assert!(String::from_utf8_lossy(&listing.stdout).contains(&key.fingerprint));
+
+    let armored = SigningService::export_gpg_public_key(&key.key_id, true)
+        .await
+        .expect("should export armored key");
+    assert!(armored.starts_with("-----BEGIN PGP PUBLIC KEY BLOCK-----"));
+    let binary = SigningService::export_gpg_public_key(&key.key_id, false)
+        .await
+        .expect("should export binary key");
+    assert!(!binary.is_empty() && !binary.contains("-----BEGIN"));
+    assert!(
+        SigningService::export_gpg_public_key("0000000000000000", true)
+            .await
+            .is_err()
+    );
+
+    // configure_signing with the repository scope
+    let (tmp, ops) = setup_test_repo();
+    ops.set_repo_signing_config(Some(&SigningFormat::Gpg), Some(&key.key_id))
+        .await
+        .expect("should set signing config");
+    ops.set_config_value("commit.gpgsign", "true", ConfigLevel::Local)
+        .await
+        .expect("should set commit.gpgsign");
+
+    assert_eq!(
+        git_cmd(tmp.path(), &["config", "--local", "user.signingkey"]),
+        key.key_id
+    );
+    assert_eq!(
+        git_cmd(tmp.path(), &["config", "--local", "gpg.format"]),
+        "gpg"
+    );
+    assert_eq!(
+        git_cmd(
+            tmp.path(),
+            &["config", "--local", "--bool", "commit.gpgsign"]
+        ),
+        "true"
+    );
+
+    // A commit made by git with that config is signed by the new key
+    std::fs::write(tmp.path().join("signed.txt"), "signed").expect("should write file");
+    git_cmd(tmp.path(), &["add", "signed.txt"]);
+    git_cmd(tmp.path(), &["commit", "-m", "Signed"]);
+    let raw = git_cmd(tmp.path(), &["cat-file", "commit", "HEAD"]);
+    assert!(raw.contains("gpgsig -----BEGIN PGP SIGNATURE-----"));
+
+    let _ = Command::new("gpgconf")
+        .args(["--kill", "gpg-agent"])
+        .status();
+}
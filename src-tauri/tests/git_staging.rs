@@ -2,9 +2,18 @@
 
 mod common;
 
+use std::process::Command;
+
 use common::{git_cmd, setup_test_repo};
 
-use axis_lib::models::ResetMode;
+use axis_lib::models::{
+    CoAuthor, CommitTrailer, CommitTrailerOptions, FileModeAuditOptions, FileModeFix,
+    FileModeIssue, ResetMode, SigningConfig, SigningFormat,
+};
+use axis_lib::services::ops::RepoOperations;
+use axis_lib::services::GitService;
+use std::sync::Arc;
+use tempfile::TempDir;
 
 // ==================== Helpers ====================
 
@@ -138,7 +147,7 @@ async fn test_cli_staged_read_by_ops_commit() {
 
     // Action: RepoOperations creates commit
     let oid = ops
-        .create_commit("Commit CLI staged file", None, None, None)
+        .create_commit("Commit CLI staged file", None, None, None, false)
         .await
         .expect("should create commit");
 
@@ -308,7 +317,7 @@ async fn test_create_commit_verified_by_cli() {
 
     // Action: RepoOperations creates commit
     let oid = ops
-        .create_commit("Test commit message", None, None, None)
+        .create_commit("Test commit message", None, None, None, false)
         .await
         .expect("should create commit");
 
@@ -321,6 +330,87 @@ async fn test_create_commit_verified_by_cli() {
     assert_eq!(message, "Test commit message");
 }
 
+#[tokio::test]
+async fn test_create_commit_rejects_empty_commit() {
+    let (tmp, ops) = setup_test_repo();
+    let head = git_head_oid(tmp.path());
+
+    let err = ops
+        .create_commit("Nothing here", None, None, None, false)
+        .await
+        .expect_err("should refuse an empty commit");
+
+    assert!(err.to_string().contains("Nothing to commit"));
+    assert_eq!(git_head_oid(tmp.path()), head, "HEAD should not move");
+}
+
+#[tokio::test]
+async fn test_create_commit_allow_empty_verified_by_cli() {
+    let (tmp, ops) = setup_test_repo();
+    let head = git_head_oid(tmp.path());
+
+    let oid = ops
+        .create_commit("Empty marker", None, None, None, true)
+        .await
+        .expect("should create empty commit");
+
+    assert_eq!(oid, git_head_oid(tmp.path()));
+    assert_eq!(git_cmd(tmp.path(), &["rev-parse", "HEAD^"]), head);
+    let files = git_cmd(tmp.path(), &["show", "--name-only", "--format=", &oid]);
+    assert!(files.is_empty(), "Empty commit should change no files");
+}
+
+#[tokio::test]
+async fn test_create_commit_on_unborn_head_is_never_empty() {
+    let tmp = TempDir::new().expect("should create temp dir");
+    git_cmd(tmp.path(), &["init"]);
+    git_cmd(tmp.path(), &["config", "user.email", "test@test.com"]);
+    git_cmd(tmp.path(), &["config", "user.name", "Test User"]);
+    let service = Arc::new(GitService::new_for_test(tmp.path()).expect("should create GitService"));
+    let ops = RepoOperations::new(service);
+
+    let oid = ops
+        .create_commit("Root commit", None, None, None, false)
+        .await
+        .expect("should create root commit");
+
+    assert_eq!(oid, git_head_oid(tmp.path()));
+}
+
+#[tokio::test]
+async fn test_create_commit_rejects_blank_message() {
+    let (tmp, ops) = setup_test_repo();
+    std::fs::write(tmp.path().join("new.txt"), "new").expect("should write");
+    git_cmd(tmp.path(), &["add", "new.txt"]);
+
+    let err = ops
+        .create_commit("  \n\n ", None, None, None, false)
+        .await
+        .expect_err("should refuse a blank message");
+
+    assert!(err.to_string().contains("empty commit message"));
+    assert_eq!(git_staged_files(tmp.path()), vec!["new.txt"]);
+}
+
+#[tokio::test]
+async fn test_amend_commit_rejects_unchanged_amend() {
+    let (tmp, ops) = setup_test_repo();
+    let head = git_head_oid(tmp.path());
+
+    let err = ops
+        .amend_commit(None, None, None, false, true, None, false)
+        .await
+        .expect_err("should refuse an unchanged amend");
+    assert!(err.to_string().contains("identical to HEAD"));
+    assert_eq!(git_head_oid(tmp.path()), head);
+
+    let oid = ops
+        .amend_commit(None, None, None, false, true, None, true)
+        .await
+        .expect("should amend when allowed");
+    assert_eq!(oid, git_head_oid(tmp.path()));
+}
+
 #[tokio::test]
 async fn test_amend_commit_verified_by_cli() {
     let (tmp, ops) = setup_test_repo();
@@ -337,7 +427,15 @@ async fn test_amend_commit_verified_by_cli() {
 
     // Action: RepoOperations amends commit
     let oid = ops
-        .amend_commit(Some("Amended message"))
+        .amend_commit(
+            Some("Amended message"),
+            None,
+            None,
+            false,
+            true,
+            None,
+            false,
+        )
         .await
         .expect("should amend commit");
 
@@ -355,6 +453,234 @@ async fn test_amend_commit_verified_by_cli() {
     assert!(files.contains("second.txt"));
 }
 
+#[tokio::test]
+async fn test_amend_commit_author_only() {
+    let (tmp, ops) = setup_test_repo();
+    let before = git_cmd(tmp.path(), &["log", "-1", "--format=%B%x00%at%x00%T"]);
+
+    let oid = ops
+        .amend_commit(
+            None,
+            Some("Other Author"),
+            Some("other@test.com"),
+            false,
+            true,
+            None,
+            false,
+        )
+        .await
+        .expect("should amend commit");
+
+    assert_eq!(
+        git_cmd(tmp.path(), &["log", "-1", "--format=%an <%ae>", &oid]),
+        "Other Author <other@test.com>"
+    );
+    // Message, author date and tree are untouched
+    assert_eq!(
+        git_cmd(tmp.path(), &["log", "-1", "--format=%B%x00%at%x00%T", &oid]),
+        before
+    );
+    assert_eq!(
+        git_cmd(tmp.path(), &["log", "-1", "--format=%cn <%ce>", &oid]),
+        "Test User <test@test.com>"
+    );
+}
+
+#[tokio::test]
+async fn test_amend_commit_reset_author_date() {
+    let (tmp, ops) = setup_test_repo();
+    git_cmd(
+        tmp.path(),
+        &[
+            "commit",
+            "--amend",
+            "--no-edit",
+            "--date=2000-01-01T00:00:00Z",
+        ],
+    );
+
+    let kept = ops
+        .amend_commit(Some("Keep date"), None, None, false, true, None, false)
+        .await
+        .expect("should amend commit");
+    assert_eq!(
+        git_cmd(tmp.path(), &["log", "-1", "--format=%at", &kept]),
+        "946684800"
+    );
+
+    let reset = ops
+        .amend_commit(Some("Reset date"), None, None, true, true, None, false)
+        .await
+        .expect("should amend commit");
+    let author_time: i64 = git_cmd(tmp.path(), &["log", "-1", "--format=%at", &reset])
+        .parse()
+        .expect("should parse timestamp");
+    assert!(author_time > 946_684_800);
+}
+
+#[tokio::test]
+async fn test_amend_commit_message_without_staged_changes() {
+    let (tmp, ops) = setup_test_repo();
+    let old_tree = git_cmd(tmp.path(), &["rev-parse", "HEAD^{tree}"]);
+
+    std::fs::write(tmp.path().join("staged.txt"), "staged").expect("should write");
+    git_cmd(tmp.path(), &["add", "staged.txt"]);
+
+    let oid = ops
+        .amend_commit(Some("Reworded"), None, None, false, false, None, false)
+        .await
+        .expect("should amend commit");
+
+    assert_eq!(git_commit_message(tmp.path(), &oid), "Reworded");
+    assert_eq!(
+        git_cmd(tmp.path(), &["rev-parse", &format!("{oid}^{{tree}}")]),
+        old_tree
+    );
+    // The staged change is still staged against the amended commit
+    assert_eq!(git_staged_files(tmp.path()), vec!["staged.txt"]);
+}
+
+#[tokio::test]
+async fn test_amend_merge_commit_preserves_parents() {
+    let (tmp, ops) = setup_test_repo();
+    let main = git_cmd(tmp.path(), &["rev-parse", "--abbrev-ref", "HEAD"]);
+
+    git_cmd(tmp.path(), &["checkout", "-b", "feature"]);
+    std::fs::write(tmp.path().join("feature.txt"), "feature").expect("should write");
+    git_cmd(tmp.path(), &["add", "feature.txt"]);
+    git_cmd(tmp.path(), &["commit", "-m", "Feature"]);
+
+    git_cmd(tmp.path(), &["checkout", &main]);
+    std::fs::write(tmp.path().join("main.txt"), "main").expect("should write");
+    git_cmd(tmp.path(), &["add", "main.txt"]);
+    git_cmd(tmp.path(), &["commit", "-m", "Main"]);
+    git_cmd(
+        tmp.path(),
+        &["merge", "--no-ff", "-m", "Merge feature", "feature"],
+    );
+
+    let parents_before = git_cmd(tmp.path(), &["rev-parse", "HEAD^1", "HEAD^2"]);
+
+    let oid = ops
+        .amend_commit(
+            Some("Merge feature (amended)"),
+            None,
+            None,
+            false,
+            true,
+            None,
+            false,
+        )
+        .await
+        .expect("should amend merge commit");
+
+    assert_eq!(
+        git_cmd(
+            tmp.path(),
+            &["rev-parse", &format!("{oid}^1"), &format!("{oid}^2")]
+        ),
+        parents_before
+    );
+}
+
+#[tokio::test]
+async fn test_amend_commit_re_signs() {
+    if Command::new("ssh-keygen").arg("-?").output().is_err() {
+        return;
+    }
+
+    let (tmp, ops) = setup_test_repo();
+    let key_dir = TempDir::new().expect("should create key dir");
+    let key_path = key_dir.path().join("id_ed25519");
+    let status = Command::new("ssh-keygen")
+        .args(["-q", "-t", "ed25519", "-N", "", "-f"])
+        .arg(&key_path)
+        .status()
+        .expect("should run ssh-keygen");
+    assert!(status.success());
+
+    let config = SigningConfig {
+        format: SigningFormat::Ssh,
+        signing_key: Some(key_path.to_string_lossy().to_string()),
+        gpg_program: None,
+        ssh_program: None,
+    };
+
+    let oid = ops
+        .amend_commit(
+            Some("Signed amend"),
+            None,
+            None,
+            false,
+            true,
+            Some(&config),
+            false,
+        )
+        .await
+        .expect("should amend and sign commit");
+
+    let raw = git_cmd(tmp.path(), &["cat-file", "commit", &oid]);
+    assert!(
+        raw.contains("gpgsig -----BEGIN SSH SIGNATURE-----"),
+        "missing signature header: {raw}"
+    );
+    assert_eq!(git_commit_message(tmp.path(), &oid), "Signed amend");
+}
+
+#[tokio::test]
+async fn test_verify_commit_signatures_batch() {
+    if Command::new("ssh-keygen").arg("-?").output().is_err() {
+        return;
+    }
+
+    let (tmp, ops) = setup_test_repo();
+    let unsigned = git_head_oid(tmp.path());
+    let key_dir = TempDir::new().expect("should create key dir");
+    let key_path = key_dir.path().join("id_ed25519");
+    let status = Command::new("ssh-keygen")
+        .args(["-q", "-t", "ed25519", "-N", "", "-f"])
+        .arg(&key_path)
+        .status()
+        .expect("should run ssh-keygen");
+    assert!(status.success());
+
+    let config = SigningConfig {
+        format: SigningFormat::Ssh,
+        signing_key: Some(key_path.to_string_lossy().to_string()),
+        gpg_program: None,
+        ssh_program: None,
+    };
+    let signed = ops
+        .amend_commit(
+            Some("Signed amend"),
+            None,
+            None,
+            false,
+            true,
+            Some(&config),
+            false,
+        )
+        .await
+        .expect("should amend and sign commit");
+    let missing = "0".repeat(40);
+
+    let results = ops
+        .verify_commit_signatures(vec![signed.clone(), unsigned.clone(), missing.clone()])
+        .await
+        .expect("should verify signatures");
+
+    let oids: Vec<_> = results.iter().map(|r| r.oid.clone()).collect();
+    assert_eq!(oids, vec![signed, unsigned, missing]);
+    // No allowed signers file is configured, so the SSH signature is found but not trusted
+    let verification = results[0]
+        .verification
+        .as_ref()
+        .expect("signed commit should be verified");
+    assert!(!verification.verified);
+    assert!(results[1].verification.is_none());
+    assert!(results[2].verification.is_none());
+}
+
 #[tokio::test]
 async fn test_get_commit_verified_by_cli() {
     let (tmp, ops) = setup_test_repo();
@@ -373,6 +699,76 @@ async fn test_get_commit_verified_by_cli() {
     assert!(commit.message.contains(&cli_message));
 }
 
+#[tokio::test]
+async fn test_commit_with_trailers_verified_by_cli() {
+    let (tmp, ops) = setup_test_repo();
+    std::fs::write(tmp.path().join("trailers.txt"), "content").expect("should write");
+    git_cmd(tmp.path(), &["add", "trailers.txt"]);
+
+    let options = CommitTrailerOptions {
+        sign_off: true,
+        co_authors: vec![CoAuthor {
+            name: "Jane Doe".to_string(),
+            email: "jane@example.com".to_string(),
+        }],
+        trailers: vec![CommitTrailer {
+            key: "Refs".to_string(),
+            value: "#42".to_string(),
+        }],
+    };
+    // Action: trailers already in the message are not repeated
+    let message = ops
+        .apply_commit_trailers("Add trailers\n\nBody text.\n\nRefs: #42\n", &options)
+        .await
+        .expect("should apply trailers");
+    let oid = ops
+        .create_commit(&message, None, None, None, false)
+        .await
+        .expect("should commit");
+
+    // Verify: git parses the same trailers
+    let trailers = git_cmd(
+        tmp.path(),
+        &["log", "-1", "--format=%(trailers:only,unfold)", &oid],
+    );
+    assert_eq!(
+        trailers,
+        "Refs: #42\nCo-authored-by: Jane Doe <jane@example.com>\nSigned-off-by: Test User <test@test.com>"
+    );
+    assert_eq!(git_commit_message(tmp.path(), &oid), "Add trailers");
+}
+
+#[tokio::test]
+async fn test_get_recent_co_authors() {
+    let (tmp, ops) = setup_test_repo();
+    for (i, trailers) in [
+        "Co-authored-by: Jane Doe <jane@example.com>",
+        "Co-authored-by: Bob <bob@example.com>\nCo-authored-by: Jane D <JANE@example.com>",
+    ]
+    .iter()
+    .enumerate()
+    {
+        git_cmd(
+            tmp.path(),
+            &[
+                "commit",
+                "--allow-empty",
+                "-m",
+                &format!("Pair commit {i}\n\n{trailers}"),
+            ],
+        );
+    }
+
+    let co_authors = ops
+        .get_recent_co_authors(10)
+        .await
+        .expect("should scan history");
+
+    // Verify: most recent first, one entry per email
+    let names: Vec<&str> = co_authors.iter().map(|c| c.name.as_str()).collect();
+    assert_eq!(names, vec!["Bob", "Jane D"]);
+}
+
 // ==================== Reset Tests ====================
 
 #[tokio::test]
@@ -453,6 +849,216 @@ async fn test_reset_hard_verified_by_cli() {
     );
 }
 
+/// Two commits plus local changes that don't touch files changed by the last commit:
+/// `c.txt` modified in the worktree, `d.txt` staged, `e.txt` untracked
+fn setup_reset_scenario(path: &std::path::Path) {
+    std::fs::write(path.join("a.txt"), "a1").expect("should write");
+    std::fs::write(path.join("c.txt"), "c1").expect("should write");
+    git_cmd(path, &["add", "-A"]);
+    git_cmd(path, &["commit", "-m", "Add a and c"]);
+
+    std::fs::write(path.join("a.txt"), "a2").expect("should write");
+    std::fs::write(path.join("b.txt"), "b").expect("should write");
+    git_cmd(path, &["add", "-A"]);
+    git_cmd(path, &["commit", "-m", "Change a, add b"]);
+
+    std::fs::write(path.join("c.txt"), "c2").expect("should write");
+    std::fs::write(path.join("d.txt"), "d").expect("should write");
+    git_cmd(path, &["add", "d.txt"]);
+    std::fs::write(path.join("e.txt"), "e").expect("should write");
+}
+
+fn git_status(path: &std::path::Path) -> String {
+    git_cmd(path, &["status", "--porcelain"])
+}
+
+/// Reset with `ops` and with the git CLI in identical repos and compare the results
+async fn assert_reset_matches_cli(mode: ResetMode, cli_flag: &str) {
+    let (ours, ops) = setup_test_repo();
+    let (theirs, _) = setup_test_repo();
+    setup_reset_scenario(ours.path());
+    setup_reset_scenario(theirs.path());
+
+    ops.reset("HEAD~1", mode).await.expect("should reset");
+    git_cmd(theirs.path(), &["reset", cli_flag, "HEAD~1"]);
+
+    assert_eq!(git_status(ours.path()), git_status(theirs.path()));
+    assert_eq!(
+        git_cmd(ours.path(), &["log", "-1", "--format=%s"]),
+        git_cmd(theirs.path(), &["log", "-1", "--format=%s"])
+    );
+    assert_eq!(
+        git_cmd(ours.path(), &["write-tree"]),
+        git_cmd(theirs.path(), &["write-tree"]),
+        "index should match"
+    );
+}
+
+#[tokio::test]
+async fn test_reset_modes_match_cli() {
+    assert_reset_matches_cli(ResetMode::Soft, "--soft").await;
+    assert_reset_matches_cli(ResetMode::Mixed, "--mixed").await;
+    assert_reset_matches_cli(ResetMode::Hard, "--hard").await;
+    assert_reset_matches_cli(ResetMode::Keep, "--keep").await;
+}
+
+#[tokio::test]
+async fn test_reset_keep_refuses_to_overwrite_local_changes() {
+    let (tmp, ops) = setup_test_repo();
+    setup_reset_scenario(tmp.path());
+    std::fs::write(tmp.path().join("a.txt"), "a3").expect("should write");
+    let head = git_head_oid(tmp.path());
+
+    ops.reset("HEAD~1", ResetMode::Keep)
+        .await
+        .expect_err("keep reset should abort");
+
+    assert_eq!(git_head_oid(tmp.path()), head);
+    assert_eq!(
+        std::fs::read_to_string(tmp.path().join("a.txt")).expect("should read"),
+        "a3"
+    );
+}
+
+#[tokio::test]
+async fn test_hard_reset_discards_lists_uncommitted_changes() {
+    let (tmp, ops) = setup_test_repo();
+    setup_reset_scenario(tmp.path());
+
+    let discards = ops
+        .get_hard_reset_discards("HEAD~1")
+        .await
+        .expect("should preview");
+
+    // e.txt is untracked and not in the target, so a hard reset leaves it alone
+    assert_eq!(discards, vec!["c.txt", "d.txt"]);
+    assert!(
+        git_status(tmp.path()).contains("?? e.txt"),
+        "preview must not modify the repo"
+    );
+}
+
+#[tokio::test]
+async fn test_hard_reset_discards_ignores_changes_matching_target() {
+    let (tmp, ops) = setup_test_repo();
+    setup_reset_scenario(tmp.path());
+    git_cmd(tmp.path(), &["reset", "--hard"]);
+    // Reverting a.txt to the target's content loses nothing
+    std::fs::write(tmp.path().join("a.txt"), "a1").expect("should write");
+
+    let discards = ops
+        .get_hard_reset_discards("HEAD~1")
+        .await
+        .expect("should preview");
+
+    assert!(discards.is_empty(), "unexpected discards: {discards:?}");
+}
+
+#[tokio::test]
+async fn test_hard_reset_discards_unborn_head_is_typed_error() {
+    let tmp = TempDir::new().expect("should create temp dir");
+    git_cmd(tmp.path(), &["init"]);
+    let service = Arc::new(GitService::new_for_test(tmp.path()).expect("should create service"));
+    let ops = RepoOperations::new(service);
+
+    let err = ops
+        .get_hard_reset_discards("HEAD")
+        .await
+        .expect_err("should fail on unborn HEAD");
+
+    assert!(
+        err.to_string().contains("HEAD is unborn"),
+        "unexpected error: {err}"
+    );
+}
+
+#[tokio::test]
+async fn test_reset_paths_matches_cli() {
+    let (ours, ops) = setup_test_repo();
+    let (theirs, _) = setup_test_repo();
+    setup_reset_scenario(ours.path());
+    setup_reset_scenario(theirs.path());
+    let head = git_head_oid(ours.path());
+
+    ops.reset_paths("HEAD~1", &["a.txt".to_string(), "b.txt".to_string()])
+        .await
+        .expect("should reset paths");
+    git_cmd(theirs.path(), &["reset", "HEAD~1", "--", "a.txt", "b.txt"]);
+
+    assert_eq!(git_head_oid(ours.path()), head, "HEAD should not move");
+    assert_eq!(git_status(ours.path()), git_status(theirs.path()));
+    assert_eq!(
+        git_cmd(ours.path(), &["write-tree"]),
+        git_cmd(theirs.path(), &["write-tree"])
+    );
+    assert_eq!(git_cmd(ours.path(), &["show", ":a.txt"]), "a1");
+}
+
+#[tokio::test]
+async fn test_reset_paths_stages_deletion_of_file_missing_in_commit() {
+    let (tmp, ops) = setup_test_repo();
+    setup_reset_scenario(tmp.path());
+
+    ops.reset_paths("HEAD~1", &["b.txt".to_string()])
+        .await
+        .expect("should reset paths");
+
+    let status = git_status(tmp.path());
+    assert!(status.contains("D  b.txt"), "unexpected status: {status}");
+    assert!(status.contains("?? b.txt"), "unexpected status: {status}");
+    assert!(file_exists(tmp.path(), "b.txt"));
+}
+
+#[tokio::test]
+async fn test_reset_file_to_commit_matches_cli() {
+    let (ours, ops) = setup_test_repo();
+    let (theirs, _) = setup_test_repo();
+    for path in [ours.path(), theirs.path()] {
+        setup_reset_scenario(path);
+        // Staged and unstaged edits are both overwritten
+        std::fs::write(path.join("a.txt"), "staged").expect("should write");
+        git_cmd(path, &["add", "a.txt"]);
+        std::fs::write(path.join("a.txt"), "unstaged").expect("should write");
+    }
+    let head = git_head_oid(ours.path());
+    let commit = git_cmd(ours.path(), &["rev-parse", "HEAD~1"]);
+
+    ops.reset_file_to_commit("a.txt", &commit)
+        .await
+        .expect("should restore file");
+    git_cmd(theirs.path(), &["checkout", "HEAD~1", "--", "a.txt"]);
+
+    assert_eq!(git_head_oid(ours.path()), head, "HEAD should not move");
+    assert_eq!(git_status(ours.path()), git_status(theirs.path()));
+    assert_eq!(
+        std::fs::read_to_string(ours.path().join("a.txt")).expect("should read"),
+        "a1"
+    );
+    assert_eq!(git_cmd(ours.path(), &["show", ":a.txt"]), "a1");
+    // Other files keep their changes
+    assert_eq!(
+        std::fs::read_to_string(ours.path().join("c.txt")).expect("should read"),
+        "c2"
+    );
+}
+
+#[tokio::test]
+async fn test_reset_file_to_commit_rejects_missing_path() {
+    let (tmp, ops) = setup_test_repo();
+    setup_reset_scenario(tmp.path());
+
+    let err = ops
+        .reset_file_to_commit("b.txt", "HEAD~1")
+        .await
+        .expect_err("b.txt does not exist in HEAD~1");
+    assert!(err.to_string().contains("b.txt"), "unexpected error: {err}");
+    assert!(file_exists(tmp.path(), "b.txt"));
+
+    ops.reset_file_to_commit("a.txt", "not-a-commit")
+        .await
+        .expect_err("should reject unknown commit");
+}
+
 // ==================== Edge Cases ====================
 
 #[tokio::test]
@@ -488,3 +1094,158 @@ async fn test_discard_untracked_file() {
     // Verify: file removed
     assert!(!file_exists(tmp.path(), "untracked.txt"));
 }
+
+// ==================== File Mode Audit Tests ====================
+
+/// Commit `content` at `path` with the given executable bit
+fn commit_with_mode(path: &std::path::Path, file: &str, content: &str, executable: bool) {
+    std::fs::write(path.join(file), content).expect("should write file");
+    git_cmd(path, &["add", file]);
+    let chmod = if executable {
+        "--chmod=+x"
+    } else {
+        "--chmod=-x"
+    };
+    git_cmd(path, &["update-index", chmod, file]);
+    git_cmd(path, &["commit", "-m", &format!("Update {file}")]);
+}
+
+fn staged_mode(path: &std::path::Path, file: &str) -> String {
+    git_cmd(path, &["ls-files", "-s", file])
+        .split_whitespace()
+        .next()
+        .unwrap_or_default()
+        .to_string()
+}
+
+#[tokio::test]
+async fn test_audit_file_modes_finds_each_issue() {
+    let (tmp, ops) = setup_test_repo();
+    commit_with_mode(tmp.path(), "run", "#!/bin/sh\necho hi\n", false);
+    commit_with_mode(tmp.path(), "deploy.sh", "echo deploy\n", false);
+    commit_with_mode(
+        tmp.path(),
+        "tool.py",
+        "def main():\n    pass\n\nif __name__ == \"__main__\":\n    main()\n",
+        false,
+    );
+    commit_with_mode(tmp.path(), "lib.py", "def helper():\n    pass\n", false);
+    commit_with_mode(tmp.path(), "NOTES.md", "# Notes\n", true);
+    commit_with_mode(tmp.path(), "build.sh", "#!/bin/sh\nmake\n", true);
+
+    let audit = ops
+        .audit_file_modes(FileModeAuditOptions::default())
+        .await
+        .expect("should audit modes");
+
+    let mut found: Vec<_> = audit
+        .findings
+        .iter()
+        .map(|f| (f.path.as_str(), f.issue, f.suggested_executable))
+        .collect();
+    found.sort_by_key(|f| f.0);
+    assert_eq!(
+        found,
+        vec![
+            ("NOTES.md", FileModeIssue::ExecutableDataFile, Some(false)),
+            (
+                "deploy.sh",
+                FileModeIssue::EntryPointNotExecutable,
+                Some(true)
+            ),
+            ("run", FileModeIssue::ShebangNotExecutable, Some(true)),
+            (
+                "tool.py",
+                FileModeIssue::EntryPointNotExecutable,
+                Some(true)
+            ),
+        ]
+    );
+    assert_eq!(audit.scanned_files, 7);
+}
+
+#[tokio::test]
+async fn test_audit_file_modes_detects_flip_flops_within_window() {
+    let (tmp, ops) = setup_test_repo();
+    commit_with_mode(tmp.path(), "ci.sh", "#!/bin/sh\n", true);
+    commit_with_mode(tmp.path(), "ci.sh", "#!/bin/sh\n", false);
+    commit_with_mode(tmp.path(), "ci.sh", "#!/bin/sh\n", true);
+    commit_with_mode(tmp.path(), "ci.sh", "#!/bin/sh\n", false);
+
+    let audit = ops
+        .audit_file_modes(FileModeAuditOptions::default())
+        .await
+        .expect("should audit modes");
+    let flip = audit
+        .findings
+        .iter()
+        .find(|f| f.issue == FileModeIssue::ModeFlipFlop)
+        .expect("should report flip-flop");
+    assert_eq!(flip.path, "ci.sh");
+    assert_eq!(flip.mode_changes, 3);
+    assert_eq!(flip.suggested_executable, Some(true));
+
+    // Only the last toggle falls inside a one-commit window
+    let narrow = ops
+        .audit_file_modes(FileModeAuditOptions {
+            revision: None,
+            history_depth: Some(1),
+            flip_threshold: None,
+        })
+        .await
+        .expect("should audit modes");
+    assert_eq!(narrow.scanned_commits, 1);
+    assert!(narrow
+        .findings
+        .iter()
+        .all(|f| f.issue != FileModeIssue::ModeFlipFlop));
+}
+
+#[tokio::test]
+async fn test_fix_file_modes_stages_changes() {
+    let (tmp, ops) = setup_test_repo();
+    commit_with_mode(tmp.path(), "run.sh", "#!/bin/sh\n", false);
+    commit_with_mode(tmp.path(), "NOTES.md", "# Notes\n", true);
+
+    let result = ops
+        .fix_file_modes(vec![
+            FileModeFix {
+                path: "run.sh".to_string(),
+                executable: true,
+            },
+            FileModeFix {
+                path: "NOTES.md".to_string(),
+                executable: false,
+            },
+            FileModeFix {
+                path: "README.md".to_string(),
+                executable: false,
+            },
+        ])
+        .await
+        .expect("should fix modes");
+
+    assert_eq!(result.changed, vec!["run.sh", "NOTES.md"]);
+    assert!(result.commit_message.starts_with("Fix file modes\n"));
+    assert!(result.commit_message.contains("Make run.sh executable"));
+    assert!(!result.commit_message.contains("README.md"));
+    assert_eq!(staged_mode(tmp.path(), "run.sh"), "100755");
+    assert_eq!(staged_mode(tmp.path(), "NOTES.md"), "100644");
+
+    // Only the modes are staged; contents are unchanged
+    let staged = git_cmd(tmp.path(), &["diff", "--cached", "--name-only"]);
+    assert_eq!(staged.lines().count(), 2);
+    let audit = ops
+        .audit_file_modes(FileModeAuditOptions::default())
+        .await
+        .expect("should audit modes");
+    assert_eq!(audit.findings.len(), 2, "HEAD is unchanged until commit");
+
+    let missing = ops
+        .fix_file_modes(vec![FileModeFix {
+            path: "nope.sh".to_string(),
+            executable: true,
+        }])
+        .await;
+    assert!(missing.is_err());
+}
@@ -4,7 +4,9 @@ mod common;
 
 use common::{git_cmd, setup_test_repo};
 
-use axis_lib::models::{StashApplyOptions, StashSaveOptions};
+use axis_lib::models::{
+    DiffLineType, DiffOptions, DiffStatus, StashApplyOptions, StashHunkSelection, StashSaveOptions,
+};
 
 // ==================== Helpers ====================
 
@@ -249,6 +251,61 @@ async fn test_stash_show() {
     );
 }
 
+#[tokio::test]
+async fn test_get_stash_diff_without_applying() {
+    let (tmp, ops) = setup_test_repo();
+
+    // Setup: two stashes, the older one touching README.md and a staged new file
+    std::fs::write(tmp.path().join("README.md"), "# Stashed heading\n").expect("should write");
+    std::fs::write(tmp.path().join("added.txt"), "added\n").expect("should write");
+    git_cmd(tmp.path(), &["add", "added.txt"]);
+    git_cmd(tmp.path(), &["stash", "push", "-m", "older"]);
+    std::fs::write(tmp.path().join("README.md"), "# Newer\n").expect("should write");
+    git_cmd(tmp.path(), &["stash", "push", "-m", "newer"]);
+
+    // Action: diff the older stash
+    let diffs = ops
+        .get_stash_diff(1, &DiffOptions::default())
+        .await
+        .expect("should diff stash");
+
+    // Verify: both files, with the stashed content
+    let mut paths: Vec<(&str, DiffStatus)> = diffs
+        .iter()
+        .map(|d| (d.new_path.as_deref().unwrap_or_default(), d.status.clone()))
+        .collect();
+    paths.sort_by_key(|(path, _)| *path);
+    assert_eq!(
+        paths,
+        vec![
+            ("README.md", DiffStatus::Modified),
+            ("added.txt", DiffStatus::Added)
+        ]
+    );
+    let readme = diffs
+        .iter()
+        .find(|d| d.new_path.as_deref() == Some("README.md"))
+        .expect("should include README.md");
+    assert!(readme.hunks.iter().flat_map(|h| &h.lines).any(|l| {
+        l.line_type == DiffLineType::Addition && l.content.contains("Stashed heading")
+    }));
+
+    // Verify: nothing was applied and both stashes remain
+    assert_eq!(git_stash_count(tmp.path()), 2);
+    assert_eq!(git_cmd(tmp.path(), &["status", "--porcelain"]), "");
+}
+
+#[tokio::test]
+async fn test_get_stash_diff_invalid_index() {
+    let (_tmp, ops) = setup_test_repo();
+
+    let err = ops
+        .get_stash_diff(0, &DiffOptions::default())
+        .await
+        .expect_err("should fail without stashes");
+    assert!(err.to_string().contains("stash@{0}"));
+}
+
 #[tokio::test]
 async fn test_stash_branch() {
     let (tmp, ops) = setup_test_repo();
@@ -365,3 +422,185 @@ async fn test_stash_entry_fields_verified_by_cli() {
         "Short OID should be prefix of full OID"
     );
 }
+
+// ==================== Selection Tests ====================
+
+/// Commit a file with ten numbered lines
+fn commit_numbered_file(path: &std::path::Path, name: &str) {
+    let content: String = (1..=10).map(|i| format!("line {i}\n")).collect();
+    std::fs::write(path.join(name), content).expect("should write");
+    git_cmd(path, &["add", name]);
+    git_cmd(path, &["commit", "-m", &format!("Add {name}")]);
+}
+
+/// Replace the first and last line, producing two separate hunks with no context overlap
+fn edit_first_and_last_lines(path: &std::path::Path, name: &str) {
+    let content: String = (1..=10)
+        .map(|i| match i {
+            1 => "first changed\n".to_string(),
+            10 => "last changed\n".to_string(),
+            _ => format!("line {i}\n"),
+        })
+        .collect();
+    std::fs::write(path.join(name), content).expect("should write");
+}
+
+#[tokio::test]
+async fn test_stash_selection_across_files_verified_by_cli() {
+    let (tmp, ops) = setup_test_repo();
+    commit_numbered_file(tmp.path(), "a.txt");
+    commit_numbered_file(tmp.path(), "b.txt");
+    edit_first_and_last_lines(tmp.path(), "a.txt");
+    edit_first_and_last_lines(tmp.path(), "b.txt");
+
+    let entry = ops
+        .stash_selection(
+            vec![
+                StashHunkSelection {
+                    path: "a.txt".to_string(),
+                    hunk_indices: vec![0],
+                },
+                StashHunkSelection {
+                    path: "b.txt".to_string(),
+                    hunk_indices: vec![1],
+                },
+            ],
+            Some("partial".to_string()),
+            Some(1),
+        )
+        .await
+        .expect("should stash selection");
+
+    assert_eq!(entry.index, 0);
+    assert!(entry.message.contains("partial"));
+    assert_eq!(git_stash_count(tmp.path()), 1);
+
+    // The stash holds exactly the selected hunks
+    let stash_diff = git_cmd(tmp.path(), &["stash", "show", "-p", "stash@{0}"]);
+    assert!(stash_diff.contains("+first changed"));
+    assert!(stash_diff.contains("+last changed"));
+    assert_eq!(stash_diff.matches("changed").count(), 2);
+
+    // The working tree keeps only the unselected hunks
+    assert!(file_has_content(tmp.path(), "a.txt", "line 1\n"));
+    assert!(file_has_content(tmp.path(), "a.txt", "last changed"));
+    assert!(file_has_content(tmp.path(), "b.txt", "first changed"));
+    assert!(file_has_content(tmp.path(), "b.txt", "line 10\n"));
+
+    // Popping onto a clean tree brings back exactly the stashed hunks
+    git_cmd(tmp.path(), &["checkout", "--", "a.txt", "b.txt"]);
+    git_cmd(tmp.path(), &["stash", "pop"]);
+    assert!(file_has_content(tmp.path(), "a.txt", "first changed"));
+    assert!(file_has_content(tmp.path(), "a.txt", "line 10\n"));
+    assert!(file_has_content(tmp.path(), "b.txt", "line 1\n"));
+    assert!(file_has_content(tmp.path(), "b.txt", "last changed"));
+    assert_eq!(git_stash_count(tmp.path()), 0);
+}
+
+#[tokio::test]
+async fn test_stash_selection_invalid_hunk_fails_without_changes() {
+    let (tmp, ops) = setup_test_repo();
+    commit_numbered_file(tmp.path(), "a.txt");
+    edit_first_and_last_lines(tmp.path(), "a.txt");
+
+    let result = ops
+        .stash_selection(
+            vec![StashHunkSelection {
+                path: "a.txt".to_string(),
+                hunk_indices: vec![5],
+            }],
+            None,
+            Some(1),
+        )
+        .await;
+
+    assert!(result.is_err(), "Out-of-range hunk index should fail");
+    assert_eq!(git_stash_count(tmp.path()), 0);
+    assert!(file_has_content(tmp.path(), "a.txt", "first changed"));
+    assert!(file_has_content(tmp.path(), "a.txt", "last changed"));
+}
+
+#[tokio::test]
+async fn test_stash_selection_untracked_file_fails() {
+    let (tmp, ops) = setup_test_repo();
+    std::fs::write(tmp.path().join("new.txt"), "new\n").expect("should write");
+
+    let result = ops
+        .stash_selection(
+            vec![StashHunkSelection {
+                path: "new.txt".to_string(),
+                hunk_indices: vec![0],
+            }],
+            None,
+            None,
+        )
+        .await;
+
+    assert!(result.is_err(), "Untracked files cannot be stashed by hunk");
+    assert!(file_exists(tmp.path(), "new.txt"));
+    assert_eq!(git_stash_count(tmp.path()), 0);
+}
+
+// ==================== Selected Files Tests ====================
+
+#[tokio::test]
+async fn test_stash_save_selected_files_keeps_other_changes() {
+    let (tmp, ops) = setup_test_repo();
+    std::fs::write(tmp.path().join("kept.txt"), "kept").expect("should write");
+    git_cmd(tmp.path(), &["add", "kept.txt"]);
+    git_cmd(tmp.path(), &["commit", "-m", "Add kept"]);
+
+    std::fs::write(tmp.path().join("README.md"), "# Changed").expect("should write");
+    std::fs::write(tmp.path().join("new.txt"), "untracked").expect("should write");
+    std::fs::write(tmp.path().join("kept.txt"), "kept staged").expect("should write");
+    git_cmd(tmp.path(), &["add", "kept.txt"]);
+    std::fs::write(tmp.path().join("other.txt"), "other").expect("should write");
+
+    ops.stash_save_selected_files(
+        &["README.md".to_string(), "new.txt".to_string()],
+        Some("partial"),
+    )
+    .await
+    .expect("should stash selected files");
+
+    assert_eq!(git_stash_count(tmp.path()), 1);
+    assert!(git_stash_list(tmp.path())[0].contains("partial"));
+    assert!(file_has_content(tmp.path(), "README.md", "# Test"));
+    assert!(!file_exists(tmp.path(), "new.txt"));
+    // The other files keep their staged and untracked state
+    let status = git_cmd(tmp.path(), &["status", "--porcelain"]);
+    let mut lines: Vec<&str> = status.lines().collect();
+    lines.sort_unstable();
+    assert_eq!(lines, vec!["?? other.txt", "M  kept.txt"]);
+
+    // The stash holds both selected files, including the untracked one
+    let stashed = git_cmd(tmp.path(), &["stash", "show", "--name-only", "stash@{0}"]);
+    let stashed: Vec<&str> = stashed.lines().collect();
+    assert!(stashed.contains(&"README.md"));
+    assert!(stashed.contains(&"new.txt"));
+}
+
+#[tokio::test]
+async fn test_stash_save_selected_files_without_changes_restores_index() {
+    let (tmp, ops) = setup_test_repo();
+    std::fs::write(tmp.path().join("staged.txt"), "staged").expect("should write");
+    git_cmd(tmp.path(), &["add", "staged.txt"]);
+
+    let err = ops
+        .stash_save_selected_files(&["README.md".to_string()], None)
+        .await
+        .expect_err("unchanged file should not stash");
+
+    assert!(err.to_string().contains("no changes to stash"));
+    assert_eq!(git_stash_count(tmp.path()), 0);
+    assert_eq!(
+        git_cmd(tmp.path(), &["status", "--porcelain"]),
+        "A  staged.txt"
+    );
+
+    let err = ops
+        .stash_save_selected_files(&[], None)
+        .await
+        .expect_err("empty selection should fail");
+    assert!(err.to_string().contains("No files selected"));
+}
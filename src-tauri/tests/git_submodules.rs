@@ -7,7 +7,10 @@
 
 mod common;
 
-use axis_lib::models::{ListSubmoduleOptions, SyncSubmoduleOptions, UpdateSubmoduleOptions};
+use axis_lib::models::{
+    FetchRecurseSubmodules, ListSubmoduleOptions, PullOptions, SshCredentials,
+    SyncSubmoduleOptions, UpdateSubmoduleOptions,
+};
 use common::*;
 use tempfile::TempDir;
 
@@ -210,7 +213,7 @@ async fn test_submodule_update_verified_by_cli() {
         paths: vec!["deps/lib".to_string()],
         ..Default::default()
     };
-    ops.submodule_update(&options, None)
+    ops.submodule_update(&options, Vec::new())
         .await
         .expect("should update");
 
@@ -243,12 +246,68 @@ async fn test_submodule_update_recursive() {
         paths: vec![],
         ..Default::default()
     };
-    let result = ops.submodule_update(&options, None).await;
+    let result = ops.submodule_update(&options, Vec::new()).await;
 
     // Verify: succeeds
     assert!(result.is_ok(), "Recursive update should succeed");
 }
 
+#[tokio::test]
+async fn test_submodule_update_groups_by_ssh_key() {
+    let (tmp, ops) = setup_test_repo();
+    let source_a = create_submodule_source();
+    let source_b = create_another_submodule_source();
+    enable_file_protocol(tmp.path());
+    git_add_submodule(
+        tmp.path(),
+        source_a.path().to_str().expect("valid path"),
+        "deps/a",
+    );
+    git_add_submodule(
+        tmp.path(),
+        source_b.path().to_str().expect("valid path"),
+        "deps/b",
+    );
+
+    let remotes = ops.submodule_remotes().await.expect("should list remotes");
+    assert_eq!(
+        remotes,
+        vec![
+            ("deps/a".to_string(), "origin".to_string()),
+            ("deps/b".to_string(), "origin".to_string()),
+        ]
+    );
+
+    std::fs::remove_dir_all(tmp.path().join("deps/a")).ok();
+    std::fs::remove_dir_all(tmp.path().join("deps/b")).ok();
+
+    // Action: only deps/a has a key, so the submodules are updated separately
+    let options = UpdateSubmoduleOptions {
+        init: true,
+        ..Default::default()
+    };
+    let credentials = vec![(
+        "deps/a".to_string(),
+        SshCredentials {
+            key_path: "~/.ssh/id_unused".to_string(),
+            passphrase: None,
+        },
+    )];
+    let result = ops
+        .submodule_update(&options, credentials)
+        .await
+        .expect("should update");
+
+    // Verify: both submodules are restored
+    assert!(result.success, "Update should succeed: {}", result.message);
+    assert_eq!(
+        result.submodules,
+        vec!["deps/a".to_string(), "deps/b".to_string()]
+    );
+    assert!(tmp.path().join("deps/a/lib.txt").exists());
+    assert!(tmp.path().join("deps/b/utils.txt").exists());
+}
+
 // ==================== submodule_sync Tests ====================
 
 #[tokio::test]
@@ -360,6 +419,130 @@ async fn test_cli_submodule_read_by_ops() {
     );
 }
 
+// ==================== Pull with Submodules Tests ====================
+
+/// Commit a new file in a submodule source repo, returning the new commit
+fn commit_in_source(source: &std::path::Path, file: &str) -> String {
+    std::fs::write(source.join(file), file).expect("should write");
+    git_cmd(source, &["add", file]);
+    git_cmd(source, &["commit", "-m", &format!("Add {file}")]);
+    git_cmd(source, &["rev-parse", "HEAD"])
+}
+
+#[tokio::test]
+async fn test_pull_fetches_moved_submodule_and_updates_out_of_sync() {
+    let lib_a = create_submodule_source();
+    let lib_b = create_another_submodule_source();
+    let url_a = lib_a.path().to_str().expect("valid path").to_string();
+    let url_b = lib_b.path().to_str().expect("valid path").to_string();
+
+    // Upstream superproject with two submodules, published to a bare remote
+    let (upstream, _upstream_ops) = setup_test_repo();
+    enable_file_protocol(upstream.path());
+    git_add_submodule(upstream.path(), &url_a, "lib-a");
+    git_add_submodule(upstream.path(), &url_b, "lib-b");
+    let bare = TempDir::new().expect("should create temp dir");
+    git_cmd(bare.path(), &["init", "--bare"]);
+    let bare_url = bare.path().to_str().expect("valid path");
+    git_cmd(upstream.path(), &["remote", "add", "origin", bare_url]);
+    git_cmd(upstream.path(), &["push", "-u", "origin", "HEAD"]);
+
+    // Local clone with submodules checked out
+    let local = TempDir::new().expect("should create temp dir");
+    let local_path = local.path().join("super");
+    git_submodule_cmd(
+        local.path(),
+        &[
+            "clone",
+            "--recurse-submodules",
+            bare_url,
+            local_path.to_str().expect("valid path"),
+        ],
+    );
+    let branch = git_cmd(&local_path, &["rev-parse", "--abbrev-ref", "HEAD"]);
+    let lib_b_tracking_before = git_cmd(&local_path.join("lib-b"), &["rev-parse", "origin/HEAD"]);
+    let lib_a_before = git_cmd(&local_path.join("lib-a"), &["rev-parse", "HEAD"]);
+
+    // Upstream advances lib-a's pointer; lib-b only gets a commit in its own repo
+    let new_a = commit_in_source(lib_a.path(), "a2.txt");
+    commit_in_source(lib_b.path(), "b2.txt");
+    git_submodule_cmd(&upstream.path().join("lib-a"), &["pull", "origin", "HEAD"]);
+    git_cmd(upstream.path(), &["add", "lib-a"]);
+    git_cmd(upstream.path(), &["commit", "-m", "Bump lib-a"]);
+    git_cmd(upstream.path(), &["push"]);
+
+    // Action: pull with on-demand submodule fetching
+    let service = std::sync::Arc::new(
+        axis_lib::services::GitService::new_for_test(&local_path)
+            .expect("should create GitService"),
+    );
+    let ops = axis_lib::services::ops::RepoOperations::new(service);
+    let result = ops
+        .pull::<fn(&git2::Progress<'_>) -> bool>(
+            "origin",
+            &branch,
+            &PullOptions {
+                recurse_submodules: Some(FetchRecurseSubmodules::OnDemand),
+                ..Default::default()
+            },
+            None,
+            None,
+        )
+        .await
+        .expect("should pull");
+
+    // Verify: only lib-a was fetched
+    assert_eq!(result.fetched_submodules, vec!["lib-a".to_string()]);
+    assert_eq!(
+        git_cmd(&local_path.join("lib-b"), &["rev-parse", "origin/HEAD"]),
+        lib_b_tracking_before,
+        "lib-b should not be fetched"
+    );
+
+    // Verify: lib-a is reported as out of sync
+    assert_eq!(result.out_of_sync_submodules.len(), 1);
+    let out_of_sync = &result.out_of_sync_submodules[0];
+    assert_eq!(out_of_sync.path, "lib-a");
+    assert_eq!(out_of_sync.recorded_oid, new_a);
+    assert_eq!(out_of_sync.checked_out_oid, lib_a_before);
+
+    // Action: update just the out-of-sync submodules
+    let update = ops
+        .update_out_of_sync_submodules(Vec::new())
+        .await
+        .expect("should update");
+    assert!(update.success, "Update should succeed: {}", update.message);
+    assert_eq!(update.submodules, vec!["lib-a".to_string()]);
+
+    // Verify: lib-a is at the recorded commit and the superproject is clean
+    assert_eq!(
+        git_cmd(&local_path.join("lib-a"), &["rev-parse", "HEAD"]),
+        new_a
+    );
+    assert_eq!(git_cmd(&local_path, &["status", "--porcelain"]), "");
+    assert!(ops
+        .get_out_of_sync_submodules()
+        .await
+        .expect("should list")
+        .is_empty());
+}
+
+#[tokio::test]
+async fn test_update_out_of_sync_submodules_noop_when_in_sync() {
+    let (tmp, ops) = setup_test_repo();
+    let sub_source = create_submodule_source();
+    let url = sub_source.path().to_str().expect("valid path").to_string();
+    git_add_submodule(tmp.path(), &url, "lib");
+
+    let result = ops
+        .update_out_of_sync_submodules(Vec::new())
+        .await
+        .expect("should succeed");
+
+    assert!(result.success);
+    assert!(result.submodules.is_empty());
+}
+
 // ==================== Edge Cases ====================
 
 #[tokio::test]
@@ -387,3 +570,38 @@ async fn test_submodule_has_correct_info() {
     assert!(sm.url.is_some(), "Should have URL");
     assert!(sm.head_oid.is_some(), "Should have HEAD OID");
 }
+
+#[tokio::test]
+async fn test_resolve_current_path_into_submodule() {
+    let (tmp, ops) = setup_test_repo();
+    let sub_source = create_submodule_source();
+    enable_file_protocol(tmp.path());
+
+    // Setup: lib/lib.txt is tracked directly, then lib/ becomes a submodule
+    std::fs::create_dir_all(tmp.path().join("lib")).expect("should create dir");
+    std::fs::write(tmp.path().join("lib/lib.txt"), "library code").expect("should write");
+    git_cmd(tmp.path(), &["add", "lib/lib.txt"]);
+    git_cmd(tmp.path(), &["commit", "-m", "Vendor lib"]);
+    let from = git_cmd(tmp.path(), &["rev-parse", "HEAD"]);
+    git_cmd(tmp.path(), &["rm", "-r", "lib"]);
+    git_cmd(tmp.path(), &["commit", "-m", "Drop vendored lib"]);
+    git_add_submodule(
+        tmp.path(),
+        sub_source.path().to_str().expect("valid path"),
+        "lib",
+    );
+
+    // Action: resolve the old path
+    let resolved = ops
+        .resolve_current_path("lib/lib.txt", &from)
+        .await
+        .expect("should resolve");
+
+    // Verify: reported inside the submodule, where it still exists
+    let submodule = resolved.submodule.expect("should be inside a submodule");
+    assert_eq!(submodule.submodule_path, "lib");
+    assert_eq!(submodule.relative_path, "lib.txt");
+    assert_eq!(resolved.path, "lib/lib.txt");
+    assert!(resolved.exists);
+    assert!(resolved.deleted_in.is_none());
+}
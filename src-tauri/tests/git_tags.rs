@@ -4,7 +4,10 @@ mod common;
 
 use common::{git_cmd, setup_test_repo};
 
-use axis_lib::models::{CreateTagOptions, ListTagsOptions};
+use axis_lib::models::{
+    BulkTagSpec, CreateTagOptions, DeleteTagsByPatternOptions, ListTagsOptions, TagPushOptions,
+    TagPushResult, TagPushStatus,
+};
 
 // ==================== Helper ====================
 
@@ -36,6 +39,29 @@ fn git_tag_message(path: &std::path::Path, tag_name: &str) -> Option<String> {
     parts.get(1).map(|s| s.trim().to_string())
 }
 
+/// Clone the repo into a bare `origin` remote next to it
+fn add_bare_remote(path: &std::path::Path) -> std::path::PathBuf {
+    let bare_path = path.join("bare.git");
+    git_cmd(
+        path,
+        &["clone", "--bare", ".", bare_path.to_str().expect("path")],
+    );
+    git_cmd(
+        path,
+        &["remote", "add", "origin", bare_path.to_str().expect("path")],
+    );
+    bare_path
+}
+
+/// Status reported for `name`, if any
+fn push_status(result: &TagPushResult, name: &str) -> Option<TagPushStatus> {
+    result
+        .outcomes
+        .iter()
+        .find(|o| o.name == name)
+        .map(|o| o.status)
+}
+
 // ==================== Happy Path Tests ====================
 
 #[tokio::test]
@@ -270,3 +296,274 @@ async fn test_tag_info_fields() {
     assert!(tag.is_annotated, "Should be annotated");
     assert!(tag.message.is_some(), "Should have message");
 }
+
+// ==================== Bulk Tag Tests ====================
+
+#[tokio::test]
+async fn test_tag_create_bulk_reports_partial_success() {
+    let (tmp, ops) = setup_test_repo();
+    let head = git_cmd(tmp.path(), &["rev-parse", "HEAD"]);
+
+    let mut specs: Vec<BulkTagSpec> = (0..9)
+        .map(|i| BulkTagSpec {
+            name: format!("svc-{i}/v1.2.0"),
+            target: head.clone(),
+            options: CreateTagOptions {
+                annotated: i % 2 == 0,
+                message: Some(format!("svc-{i} 1.2.0")),
+                ..Default::default()
+            },
+        })
+        .collect();
+    specs.insert(
+        5,
+        BulkTagSpec {
+            name: "svc-1/v1.2.0".to_string(),
+            target: "HEAD".to_string(),
+            options: CreateTagOptions::default(),
+        },
+    );
+
+    let results = ops
+        .tag_create_bulk(specs, false)
+        .await
+        .expect("should create tags");
+
+    assert_eq!(results.len(), 10);
+    let failed: Vec<_> = results.iter().filter(|r| !r.success).collect();
+    assert_eq!(failed.len(), 1);
+    assert_eq!(failed[0].name, "svc-1/v1.2.0");
+    assert!(failed[0]
+        .error
+        .as_deref()
+        .is_some_and(|e| e.contains("already exists")));
+    assert_eq!(git_tag_list(tmp.path()).len(), 9);
+    assert!(git_tag_message(tmp.path(), "svc-0/v1.2.0").is_some());
+}
+
+#[tokio::test]
+async fn test_tag_create_bulk_stop_on_error() {
+    let (tmp, ops) = setup_test_repo();
+    git_cmd(tmp.path(), &["tag", "taken"]);
+
+    let specs = ["first", "taken", "last"]
+        .iter()
+        .map(|name| BulkTagSpec {
+            name: (*name).to_string(),
+            target: "HEAD".to_string(),
+            options: CreateTagOptions::default(),
+        })
+        .collect();
+
+    let results = ops.tag_create_bulk(specs, true).await.expect("should run");
+
+    let outcome: Vec<_> = results
+        .iter()
+        .map(|r| (r.name.as_str(), r.success))
+        .collect();
+    assert_eq!(
+        outcome,
+        vec![("first", true), ("taken", false), ("last", false)]
+    );
+    assert!(!git_tag_exists(tmp.path(), "last"));
+}
+
+#[tokio::test]
+async fn test_tag_delete_by_pattern_locally_and_on_remote() {
+    let (tmp, ops) = setup_test_repo();
+    for tag in [
+        "v1.0.0",
+        "v1.1.0-rc1",
+        "v1.1.0-rc2",
+        "v1.1.0",
+        "svc/v2.0.0-rc1",
+    ] {
+        git_cmd(tmp.path(), &["tag", tag]);
+    }
+    let bare_path = tmp.path().join("bare.git");
+    git_cmd(
+        tmp.path(),
+        &["clone", "--bare", ".", bare_path.to_str().expect("path")],
+    );
+    git_cmd(
+        tmp.path(),
+        &["remote", "add", "origin", bare_path.to_str().expect("path")],
+    );
+
+    // Confirmation only lists the matches
+    let listing = ops
+        .tag_delete_by_pattern(
+            "*-rc*",
+            DeleteTagsByPatternOptions {
+                confirm_required: true,
+                remotes: vec!["origin".to_string()],
+            },
+            vec![("origin".to_string(), None)],
+        )
+        .await
+        .expect("should list matches");
+    assert_eq!(
+        listing.matched,
+        vec!["svc/v2.0.0-rc1", "v1.1.0-rc1", "v1.1.0-rc2"]
+    );
+    assert!(!listing.deleted);
+    assert_eq!(git_tag_list(tmp.path()).len(), 5);
+
+    let result = ops
+        .tag_delete_by_pattern(
+            "*-rc*",
+            DeleteTagsByPatternOptions {
+                confirm_required: false,
+                remotes: vec!["origin".to_string()],
+            },
+            vec![("origin".to_string(), None)],
+        )
+        .await
+        .expect("should delete matches");
+
+    assert!(result.deleted);
+    assert_eq!(result.results.len(), 6);
+    assert!(result.results.iter().all(|r| r.success));
+    assert_eq!(
+        result
+            .results
+            .iter()
+            .filter(|r| r.remote.as_deref() == Some("origin"))
+            .count(),
+        3
+    );
+    assert_eq!(git_tag_list(tmp.path()), vec!["v1.0.0", "v1.1.0"]);
+    assert_eq!(git_tag_list(&bare_path), vec!["v1.0.0", "v1.1.0"]);
+}
+
+// ==================== Remote Tag Push Tests ====================
+
+#[tokio::test]
+async fn test_tag_push_all_skips_tags_already_on_remote() {
+    let (tmp, ops) = setup_test_repo();
+    git_cmd(tmp.path(), &["tag", "v1.0.0"]);
+    let bare_path = add_bare_remote(tmp.path());
+    git_cmd(tmp.path(), &["tag", "v1.1.0"]);
+    git_cmd(tmp.path(), &["tag", "-a", "v2.0.0", "-m", "Release 2.0.0"]);
+
+    let result = ops
+        .tag_push_all("origin", TagPushOptions::default(), None)
+        .await
+        .expect("should push tags");
+
+    assert!(result.success);
+    assert_eq!(result.outcomes.len(), 3);
+    assert_eq!(
+        push_status(&result, "v1.0.0"),
+        Some(TagPushStatus::UpToDate)
+    );
+    assert_eq!(push_status(&result, "v1.1.0"), Some(TagPushStatus::Pushed));
+    assert_eq!(push_status(&result, "v2.0.0"), Some(TagPushStatus::Pushed));
+    assert_eq!(git_tag_list(&bare_path), vec!["v1.0.0", "v1.1.0", "v2.0.0"]);
+    assert_eq!(
+        git_tag_message(&bare_path, "v2.0.0").as_deref(),
+        Some("Release 2.0.0")
+    );
+}
+
+#[tokio::test]
+async fn test_tag_push_rejects_tag_at_different_object_on_remote() {
+    let (tmp, ops) = setup_test_repo();
+    git_cmd(tmp.path(), &["tag", "v1.0.0"]);
+    let bare_path = add_bare_remote(tmp.path());
+    let remote_target = git_cmd(&bare_path, &["rev-parse", "refs/tags/v1.0.0"]);
+
+    std::fs::write(tmp.path().join("file.txt"), "change").expect("should write file");
+    git_cmd(tmp.path(), &["add", "."]);
+    git_cmd(tmp.path(), &["commit", "-m", "Second commit"]);
+    git_cmd(tmp.path(), &["tag", "-f", "v1.0.0"]);
+    git_cmd(tmp.path(), &["tag", "v1.1.0"]);
+
+    let single = ops
+        .tag_push("v1.0.0", "origin", TagPushOptions::default(), None)
+        .await
+        .expect("should report the rejection");
+    assert!(!single.success);
+    assert_eq!(
+        push_status(&single, "v1.0.0"),
+        Some(TagPushStatus::Rejected)
+    );
+    assert!(single.outcomes[0].message.is_some());
+
+    // Without `atomic` the other tags still go through
+    let all = ops
+        .tag_push_all("origin", TagPushOptions::default(), None)
+        .await
+        .expect("should push tags");
+    assert!(!all.success);
+    assert_eq!(push_status(&all, "v1.0.0"), Some(TagPushStatus::Rejected));
+    assert_eq!(push_status(&all, "v1.1.0"), Some(TagPushStatus::Pushed));
+    assert_eq!(
+        git_cmd(&bare_path, &["rev-parse", "refs/tags/v1.0.0"]),
+        remote_target
+    );
+    assert!(git_tag_exists(&bare_path, "v1.1.0"));
+}
+
+#[tokio::test]
+async fn test_atomic_tag_push_failure_leaves_remote_untouched() {
+    let (tmp, ops) = setup_test_repo();
+    git_cmd(tmp.path(), &["tag", "v1.0.0"]);
+    let bare_path = add_bare_remote(tmp.path());
+
+    std::fs::write(tmp.path().join("file.txt"), "change").expect("should write file");
+    git_cmd(tmp.path(), &["add", "."]);
+    git_cmd(tmp.path(), &["commit", "-m", "Second commit"]);
+    git_cmd(tmp.path(), &["tag", "-f", "v1.0.0"]);
+    git_cmd(tmp.path(), &["tag", "v1.1.0"]);
+    git_cmd(tmp.path(), &["tag", "v1.2.0"]);
+
+    let result = ops
+        .tag_push_all("origin", TagPushOptions { atomic: true }, None)
+        .await
+        .expect("should report the atomic failure");
+
+    assert!(!result.success);
+    assert!(result
+        .outcomes
+        .iter()
+        .all(|o| o.status == TagPushStatus::Rejected));
+    assert_eq!(git_tag_list(&bare_path), vec!["v1.0.0"]);
+}
+
+#[tokio::test]
+async fn test_atomic_tag_push_and_delete() {
+    let (tmp, ops) = setup_test_repo();
+    git_cmd(tmp.path(), &["tag", "v1.0.0"]);
+    let bare_path = add_bare_remote(tmp.path());
+    git_cmd(tmp.path(), &["tag", "v1.1.0"]);
+    git_cmd(tmp.path(), &["tag", "v1.2.0"]);
+
+    let pushed = ops
+        .tag_push_all("origin", TagPushOptions { atomic: true }, None)
+        .await
+        .expect("should push tags");
+    assert!(pushed.success);
+    assert_eq!(push_status(&pushed, "v1.1.0"), Some(TagPushStatus::Pushed));
+    assert_eq!(push_status(&pushed, "v1.2.0"), Some(TagPushStatus::Pushed));
+    assert_eq!(git_tag_list(&bare_path), vec!["v1.0.0", "v1.1.0", "v1.2.0"]);
+
+    let deleted = ops
+        .tag_delete_remote("v1.0.0", "origin", TagPushOptions { atomic: true }, None)
+        .await
+        .expect("should delete the remote tag");
+    assert!(deleted.success);
+    assert_eq!(
+        push_status(&deleted, "v1.0.0"),
+        Some(TagPushStatus::Deleted)
+    );
+    assert_eq!(git_tag_list(&bare_path), vec!["v1.1.0", "v1.2.0"]);
+
+    // Deleting a tag the remote no longer has is not an error
+    let again = ops
+        .tag_delete_remote("v1.0.0", "origin", TagPushOptions::default(), None)
+        .await
+        .expect("should report the missing tag");
+    assert!(again.success);
+    assert_eq!(push_status(&again, "v1.0.0"), Some(TagPushStatus::UpToDate));
+}
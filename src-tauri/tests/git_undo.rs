@@ -0,0 +1,249 @@
+#![cfg(feature = "integration")]
+
+mod common;
+
+use common::{git_cmd, setup_test_repo};
+
+use axis_lib::models::ResetMode;
+
+// ==================== Snapshot Tests ====================
+
+#[tokio::test]
+async fn test_undo_snapshot_does_not_touch_stash_list() {
+    let (tmp, ops) = setup_test_repo();
+    std::fs::write(tmp.path().join("README.md"), "# Changed").expect("should write file");
+
+    let snapshot = ops.create_undo_snapshot().await.expect("should snapshot");
+
+    assert_eq!(
+        snapshot.head_oid,
+        git_cmd(tmp.path(), &["rev-parse", "HEAD"])
+    );
+    assert_eq!(
+        snapshot.branch.as_deref(),
+        Some(git_cmd(tmp.path(), &["branch", "--show-current"]).as_str())
+    );
+    assert!(snapshot.stash_oid.is_some());
+    assert!(snapshot.complete);
+    assert_eq!(git_cmd(tmp.path(), &["stash", "list"]), "");
+    assert_eq!(
+        std::fs::read_to_string(tmp.path().join("README.md")).expect("should read file"),
+        "# Changed"
+    );
+}
+
+#[tokio::test]
+async fn test_undo_snapshot_clean_tree_has_no_stash() {
+    let (_tmp, ops) = setup_test_repo();
+
+    let snapshot = ops.create_undo_snapshot().await.expect("should snapshot");
+
+    assert!(snapshot.stash_oid.is_none());
+    assert!(snapshot.complete);
+}
+
+#[tokio::test]
+async fn test_undo_snapshot_with_untracked_files_is_partial() {
+    let (tmp, ops) = setup_test_repo();
+    std::fs::write(tmp.path().join("new.txt"), "new").expect("should write file");
+
+    let snapshot = ops.create_undo_snapshot().await.expect("should snapshot");
+
+    assert!(!snapshot.complete);
+}
+
+// ==================== Restore Tests ====================
+
+#[tokio::test]
+async fn test_undo_discard_restores_modified_files() {
+    let (tmp, ops) = setup_test_repo();
+    std::fs::write(tmp.path().join("README.md"), "# Staged").expect("should write file");
+    git_cmd(tmp.path(), &["add", "README.md"]);
+    std::fs::write(tmp.path().join("README.md"), "# Unstaged").expect("should write file");
+
+    let snapshot = ops.create_undo_snapshot().await.expect("should snapshot");
+    ops.discard_unstaged().await.expect("should discard");
+    assert_eq!(
+        std::fs::read_to_string(tmp.path().join("README.md")).expect("should read file"),
+        "# Staged"
+    );
+
+    ops.restore_undo_snapshot(&snapshot)
+        .await
+        .expect("should restore");
+
+    assert_eq!(
+        std::fs::read_to_string(tmp.path().join("README.md")).expect("should read file"),
+        "# Unstaged"
+    );
+    assert_eq!(
+        git_cmd(tmp.path(), &["show", ":README.md"]),
+        "# Staged",
+        "staged content should be restored to the index"
+    );
+}
+
+#[tokio::test]
+async fn test_undo_hard_reset_restores_branch_tip() {
+    let (tmp, ops) = setup_test_repo();
+    std::fs::write(tmp.path().join("a.txt"), "a").expect("should write file");
+    git_cmd(tmp.path(), &["add", "a.txt"]);
+    git_cmd(tmp.path(), &["commit", "-m", "Add a"]);
+    let tip = git_cmd(tmp.path(), &["rev-parse", "HEAD"]);
+
+    let snapshot = ops.create_undo_snapshot().await.expect("should snapshot");
+    ops.reset("HEAD~1", ResetMode::Hard)
+        .await
+        .expect("should reset");
+    assert!(!tmp.path().join("a.txt").exists());
+
+    ops.restore_undo_snapshot(&snapshot)
+        .await
+        .expect("should restore");
+
+    assert_eq!(git_cmd(tmp.path(), &["rev-parse", "HEAD"]), tip);
+    assert!(tmp.path().join("a.txt").exists());
+    assert_eq!(git_cmd(tmp.path(), &["status", "--porcelain"]), "");
+}
+
+#[tokio::test]
+async fn test_undo_returns_to_recorded_branch() {
+    let (tmp, ops) = setup_test_repo();
+    let branch = git_cmd(tmp.path(), &["branch", "--show-current"]);
+    let tip = git_cmd(tmp.path(), &["rev-parse", "HEAD"]);
+
+    let snapshot = ops.create_undo_snapshot().await.expect("should snapshot");
+    git_cmd(tmp.path(), &["checkout", "-b", "feature"]);
+    std::fs::write(tmp.path().join("f.txt"), "f").expect("should write file");
+    git_cmd(tmp.path(), &["add", "f.txt"]);
+    git_cmd(tmp.path(), &["commit", "-m", "Add f"]);
+    let feature_tip = git_cmd(tmp.path(), &["rev-parse", "HEAD"]);
+
+    ops.restore_undo_snapshot(&snapshot)
+        .await
+        .expect("should restore");
+
+    assert_eq!(git_cmd(tmp.path(), &["branch", "--show-current"]), branch);
+    assert_eq!(git_cmd(tmp.path(), &["rev-parse", "HEAD"]), tip);
+    // Other branches are left alone
+    assert_eq!(git_cmd(tmp.path(), &["rev-parse", "feature"]), feature_tip);
+}
+
+#[tokio::test]
+async fn test_undo_missing_commit_fails() {
+    let (tmp, ops) = setup_test_repo();
+    let mut snapshot = ops.create_undo_snapshot().await.expect("should snapshot");
+    snapshot.head_oid = "0123456789012345678901234567890123456789".to_string();
+    let head = git_cmd(tmp.path(), &["rev-parse", "HEAD"]);
+
+    let err = ops
+        .restore_undo_snapshot(&snapshot)
+        .await
+        .expect_err("should fail for a missing commit");
+
+    assert!(err.to_string().contains("no longer exists"));
+    assert_eq!(git_cmd(tmp.path(), &["rev-parse", "HEAD"]), head);
+}
+
+#[tokio::test]
+async fn test_undo_missing_stash_fails_before_reset() {
+    let (tmp, ops) = setup_test_repo();
+    std::fs::write(tmp.path().join("README.md"), "# Changed").expect("should write file");
+    let mut snapshot = ops.create_undo_snapshot().await.expect("should snapshot");
+    snapshot.stash_oid = Some("0123456789012345678901234567890123456789".to_string());
+
+    std::fs::write(tmp.path().join("a.txt"), "a").expect("should write file");
+    git_cmd(tmp.path(), &["add", "a.txt"]);
+    git_cmd(tmp.path(), &["commit", "-m", "Add a"]);
+    let head = git_cmd(tmp.path(), &["rev-parse", "HEAD"]);
+
+    let err = ops
+        .restore_undo_snapshot(&snapshot)
+        .await
+        .expect_err("should fail for missing changes");
+
+    assert!(err.to_string().contains("no longer exist"));
+    assert_eq!(git_cmd(tmp.path(), &["rev-parse", "HEAD"]), head);
+    assert_eq!(
+        std::fs::read_to_string(tmp.path().join("README.md")).expect("should read file"),
+        "# Changed"
+    );
+}
+
+#[tokio::test]
+async fn test_undo_snapshots_changes_made_since() {
+    let (tmp, ops) = setup_test_repo();
+    let snapshot = ops.create_undo_snapshot().await.expect("should snapshot");
+    std::fs::write(tmp.path().join("README.md"), "# Later work").expect("should write file");
+
+    let replaced = ops
+        .restore_undo_snapshot(&snapshot)
+        .await
+        .expect("should restore")
+        .expect("should snapshot the later work");
+    assert!(replaced.stash_oid.is_some());
+    assert_eq!(
+        std::fs::read_to_string(tmp.path().join("README.md")).expect("should read file"),
+        "# Test"
+    );
+
+    // Action: undo the undo
+    ops.restore_undo_snapshot(&replaced)
+        .await
+        .expect("should restore");
+
+    assert_eq!(
+        std::fs::read_to_string(tmp.path().join("README.md")).expect("should read file"),
+        "# Later work"
+    );
+}
+
+#[tokio::test]
+async fn test_undo_clean_tree_replaces_nothing() {
+    let (_tmp, ops) = setup_test_repo();
+    let snapshot = ops.create_undo_snapshot().await.expect("should snapshot");
+
+    let replaced = ops
+        .restore_undo_snapshot(&snapshot)
+        .await
+        .expect("should restore");
+
+    assert!(replaced.is_none());
+}
+
+// ==================== Anchor Ref Tests ====================
+
+#[tokio::test]
+async fn test_undo_snapshot_refs_anchor_and_prune() {
+    let (tmp, ops) = setup_test_repo();
+    std::fs::write(tmp.path().join("README.md"), "# Changed").expect("should write file");
+    let dirty = ops.create_undo_snapshot().await.expect("should snapshot");
+    git_cmd(tmp.path(), &["checkout", "--", "README.md"]);
+    let clean = ops.create_undo_snapshot().await.expect("should snapshot");
+
+    ops.anchor_undo_snapshot(7, &dirty)
+        .await
+        .expect("should anchor");
+    ops.anchor_undo_snapshot(8, &clean)
+        .await
+        .expect("should anchor");
+
+    assert_eq!(
+        git_cmd(tmp.path(), &["rev-parse", "refs/axis/undo/7"]),
+        dirty.stash_oid.expect("should have stash")
+    );
+    assert_eq!(
+        git_cmd(tmp.path(), &["rev-parse", "refs/axis/undo/8"]),
+        clean.head_oid
+    );
+
+    ops.prune_undo_refs(&[8]).await.expect("should prune");
+
+    assert_eq!(
+        git_cmd(
+            tmp.path(),
+            &["for-each-ref", "--format=%(refname)", "refs/axis/undo"]
+        ),
+        "refs/axis/undo/8"
+    );
+}
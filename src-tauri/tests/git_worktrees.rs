@@ -437,6 +437,54 @@ async fn test_worktree_prune_removes_stale() {
     );
 }
 
+#[tokio::test]
+async fn test_worktree_prune_keeps_locked() {
+    let (tmp, ops) = setup_test_repo();
+
+    // Setup: add a worktree, lock it with a reason, then delete its directory
+    git_cmd(tmp.path(), &["branch", "locked-stale"]);
+    let wt_path = tmp.path().join("locked-stale-wt");
+    git_cmd(
+        tmp.path(),
+        &[
+            "worktree",
+            "add",
+            wt_path.to_str().expect("path"),
+            "locked-stale",
+        ],
+    );
+    ops.worktree_lock(wt_path.to_str().expect("path"), Some("on usb drive"))
+        .await
+        .expect("should lock");
+    std::fs::remove_dir_all(&wt_path).expect("should remove dir");
+
+    // Action: prune
+    let result = ops.worktree_prune(false).await.expect("should prune");
+    assert!(result.success, "Prune should succeed");
+
+    // Verify: locked worktree survives prune and keeps its reason
+    let worktrees = ops.worktree_list().await.expect("should list");
+    let locked = worktrees
+        .iter()
+        .find(|w| w.path.contains("locked-stale-wt"))
+        .expect("Locked worktree should not be pruned");
+    assert!(locked.is_locked);
+    assert_eq!(locked.lock_reason.as_deref(), Some("on usb drive"));
+
+    // Action: unlock, then prune again
+    ops.worktree_unlock(wt_path.to_str().expect("path"))
+        .await
+        .expect("should unlock");
+    ops.worktree_prune(false).await.expect("should prune");
+
+    // Verify: now it is pruned
+    let worktrees = ops.worktree_list().await.expect("should list");
+    assert!(
+        !worktrees.iter().any(|w| w.path.contains("locked-stale-wt")),
+        "Unlocked stale worktree should be pruned"
+    );
+}
+
 // ==================== Edge Cases ====================
 
 #[tokio::test]
@@ -27,21 +27,59 @@ async closeRepositoryPath(path: string) : Promise<null> {
 async getRepositoryInfo() : Promise<Repository> {
     return await TAURI_INVOKE("get_repository_info");
 },
-async getRepositoryStatus() : Promise<RepositoryStatus> {
-    return await TAURI_INVOKE("get_repository_status");
+/**
+ * Recoveries offered when HEAD points to a branch that no longer exists
+ */
+async getBrokenHeadRecoveries() : Promise<BrokenHeadRecovery[]> {
+    return await TAURI_INVOKE("get_broken_head_recoveries");
+},
+/**
+ * Apply one of the recoveries from `get_broken_head_recoveries`
+ */
+async recoverBrokenHead(action: BrokenHeadAction) : Promise<BrokenHeadRecovery> {
+    return await TAURI_INVOKE("recover_broken_head", { action });
+},
+async getRepositoryStatus(options: RepositoryStatusOptions | null) : Promise<RepositoryStatus> {
+    return await TAURI_INVOKE("get_repository_status", { options });
 },
 async getCommitHistory(options: LogOptions) : Promise<Commit[]> {
     return await TAURI_INVOKE("get_commit_history", { options });
 },
+/**
+ * Get one page of commit history; pass the returned `next_cursor` back in
+ * `options.cursor` to continue from where the page ended
+ */
+async getCommitHistoryPage(options: LogOptions) : Promise<CommitHistoryPage> {
+    return await TAURI_INVOKE("get_commit_history_page", { options });
+},
 async getBranches(filter: BranchFilter) : Promise<Branch[]> {
     return await TAURI_INVOKE("get_branches", { filter });
 },
 async getCommit(oid: string) : Promise<Commit> {
     return await TAURI_INVOKE("get_commit", { oid });
 },
+async getRepositoryHealth() : Promise<RepositoryHealth> {
+    return await TAURI_INVOKE("get_repository_health");
+},
+async acknowledgeSyncFolderWarning(acknowledged: boolean) : Promise<null> {
+    return await TAURI_INVOKE("acknowledge_sync_folder_warning", { acknowledged });
+},
 async getRecentRepositories() : Promise<RecentRepository[]> {
     return await TAURI_INVOKE("get_recent_repositories");
 },
+/**
+ * Recent repositories whose path or name contains `query`, most recent first
+ */
+async searchRecentRepositories(query: string) : Promise<RecentRepository[]> {
+    return await TAURI_INVOKE("search_recent_repositories", { query });
+},
+/**
+ * Quick-open matches across all known repositories: recent repositories whose
+ * path or name contains `query`, and bookmarks whose label or notes do
+ */
+async quickOpenSearch(query: string) : Promise<QuickOpenResults> {
+    return await TAURI_INVOKE("quick_open_search", { query });
+},
 async removeRecentRepository(path: string) : Promise<null> {
     return await TAURI_INVOKE("remove_recent_repository", { path });
 },
@@ -51,14 +89,44 @@ async pinRepository(path: string) : Promise<null> {
 async unpinRepository(path: string) : Promise<null> {
     return await TAURI_INVOKE("unpin_repository", { path });
 },
-async showInFolder(path: string) : Promise<null> {
-    return await TAURI_INVOKE("show_in_folder", { path });
+async pinRecentRepository(path: string, pinned: boolean) : Promise<null> {
+    return await TAURI_INVOKE("pin_recent_repository", { path, pinned });
+},
+/**
+ * Recent repositories with their current branch, dirty-file count, and ahead/behind
+ * counts, pinned first. Missing paths are flagged rather than failing the list.
+ */
+async getRecentRepositoriesWithStatus() : Promise<RecentRepositoryWithStatus[]> {
+    return await TAURI_INVOKE("get_recent_repositories_with_status");
+},
+/**
+ * Show `path` (a repository or worktree root), or `subpath` inside it, selected in the
+ * file manager. A path that no longer exists opens its nearest existing folder instead.
+ */
+async showInFolder(path: string, subpath: string | null) : Promise<ShowInFolderResult> {
+    return await TAURI_INVOKE("show_in_folder", { path, subpath });
+},
+/**
+ * Absolute, repository-relative and file URL forms of a path in the open repository
+ */
+async getPathCopyForms(path: string) : Promise<PathCopyForms> {
+    return await TAURI_INVOKE("get_path_copy_forms", { path });
 },
 async openUrl(url: string) : Promise<null> {
     return await TAURI_INVOKE("open_url", { url });
 },
-async openTerminal(path: string) : Promise<null> {
-    return await TAURI_INVOKE("open_terminal", { path });
+/**
+ * Open the terminal chosen in the settings at `path`, or at `subpath` inside it.
+ * `command` (e.g. `git rebase --continue`) runs there first where the terminal allows.
+ */
+async openTerminal(path: string, subpath: string | null, command: string | null) : Promise<null> {
+    return await TAURI_INVOKE("open_terminal", { path, subpath, command });
+},
+/**
+ * Terminals installed on this machine, for the terminal setting
+ */
+async listAvailableTerminals() : Promise<AvailableTerminal[]> {
+    return await TAURI_INVOKE("list_available_terminals");
 },
 async cancelOperation(operationId: string) : Promise<boolean> {
     return await TAURI_INVOKE("cancel_operation", { operationId });
@@ -72,6 +140,21 @@ async stageFiles(paths: string[]) : Promise<null> {
 async stageAll() : Promise<null> {
     return await TAURI_INVOKE("stage_all");
 },
+async setFileExecutable(path: string, executable: boolean) : Promise<null> {
+    return await TAURI_INVOKE("set_file_executable", { path, executable });
+},
+/**
+ * Find files whose executable bit looks wrong or keeps flipping in recent history
+ */
+async auditFileModes(options: FileModeAuditOptions) : Promise<FileModeAudit> {
+    return await TAURI_INVOKE("audit_file_modes", { options });
+},
+/**
+ * Apply selected audit fixes, staging them with a prepared commit message
+ */
+async fixFileModes(selections: FileModeFix[]) : Promise<FileModeFixResult> {
+    return await TAURI_INVOKE("fix_file_modes", { selections });
+},
 async unstageFile(path: string) : Promise<null> {
     return await TAURI_INVOKE("unstage_file", { path });
 },
@@ -87,15 +170,46 @@ async discardFile(path: string) : Promise<null> {
 async discardUnstaged() : Promise<null> {
     return await TAURI_INVOKE("discard_unstaged");
 },
-async createCommit(message: string, authorName: string | null, authorEmail: string | null, sign: boolean | null, bypassHooks: boolean | null) : Promise<string> {
-    return await TAURI_INVOKE("create_commit", { message, authorName, authorEmail, sign, bypassHooks });
+async validateCommitMessage(message: string) : Promise<CommitLintViolation[]> {
+    return await TAURI_INVOKE("validate_commit_message", { message });
+},
+/**
+ * Misspelled words in a commit message, checked with the dictionary for the app
+ * language. Code, staged paths, branch names, identifiers from the staged files and
+ * words added to the repository's dictionary are never flagged.
+ */
+async checkCommitMessageSpelling(message: string) : Promise<SpellCheckResult> {
+    return await TAURI_INVOKE("check_commit_message_spelling", { message });
+},
+/**
+ * Add a word to the active repository's spelling dictionary
+ */
+async addWord(word: string) : Promise<null> {
+    return await TAURI_INVOKE("add_word", { word });
+},
+/**
+ * Commit message hygiene across the history selected by `options`, checked against
+ * the commit message rules whether or not validation is enabled. Progress is
+ * reported as `CommitMessageAnalysisProgressEvent`s.
+ */
+async analyzeCommitMessages(options: CommitMessageStatsOptions) : Promise<CommitMessageStats> {
+    return await TAURI_INVOKE("analyze_commit_messages", { options });
+},
+async createCommit(message: string, authorName: string | null, authorEmail: string | null, sign: boolean | null, bypassHooks: boolean | null, allowEmpty: boolean | null, trailers: CommitTrailerOptions | null) : Promise<string> {
+    return await TAURI_INVOKE("create_commit", { message, authorName, authorEmail, sign, bypassHooks, allowEmpty, trailers });
 },
-async amendCommit(message: string | null, bypassHooks: boolean | null) : Promise<string> {
-    return await TAURI_INVOKE("amend_commit", { message, bypassHooks });
+async amendCommit(message: string | null, authorName: string | null, authorEmail: string | null, resetAuthorDate: boolean | null, includeStaged: boolean | null, sign: boolean | null, bypassHooks: boolean | null, allowEmpty: boolean | null, trailers: CommitTrailerOptions | null) : Promise<string> {
+    return await TAURI_INVOKE("amend_commit", { message, authorName, authorEmail, resetAuthorDate, includeStaged, sign, bypassHooks, allowEmpty, trailers });
 },
 async getUserSignature() : Promise<[string, string]> {
     return await TAURI_INVOKE("get_user_signature");
 },
+/**
+ * Co-authors credited in recent history, for autocomplete
+ */
+async getRecentCoAuthors() : Promise<CoAuthor[]> {
+    return await TAURI_INVOKE("get_recent_co_authors");
+},
 async stageHunk(patch: string) : Promise<null> {
     return await TAURI_INVOKE("stage_hunk", { patch });
 },
@@ -114,14 +228,46 @@ async checkFilesForLfs(paths: string[], threshold: number) : Promise<LfsCheckRes
 /**
  * Get diff based on the specified target
  */
-async getDiff(target: DiffTarget, options: DiffOptions | null) : Promise<FileDiff[]> {
-    return await TAURI_INVOKE("get_diff", { target, options });
+async getDiff(target: DiffTarget, options: DiffOptions | null, preset: string | null) : Promise<FileDiff[]> {
+    return await TAURI_INVOKE("get_diff", { target, options, preset });
+},
+/**
+ * Diff the working tree, the index, or both against any ref, e.g. `origin/main`.
+ * Untracked files are included unless `include_untracked` is false.
+ */
+async diffWorkdirToRef(reference: string, mode: WorkdirRefDiffMode, includeUntracked: boolean | null, options: DiffOptions | null, preset: string | null) : Promise<FileDiff[]> {
+    return await TAURI_INVOKE("diff_workdir_to_ref", { reference, mode, includeUntracked, options, preset });
+},
+/**
+ * Get file content from the working tree, the index or a commit as base64 with
+ * its detected mime type, for previews such as side-by-side images
+ */
+async getFileContentBase64(path: string, source: FileContentSource) : Promise<FileContent> {
+    return await TAURI_INVOKE("get_file_content_base64", { path, source });
 },
 /**
  * Get diff for a single file
  */
-async getFileDiff(path: string, staged: boolean, options: DiffOptions | null) : Promise<FileDiff | null> {
-    return await TAURI_INVOKE("get_file_diff", { path, staged, options });
+async getFileDiff(path: string, staged: boolean, options: DiffOptions | null, preset: string | null) : Promise<FileDiff | null> {
+    return await TAURI_INVOKE("get_file_diff", { path, staged, options, preset });
+},
+async listDiffPresets() : Promise<DiffPreset[]> {
+    return await TAURI_INVOKE("list_diff_presets");
+},
+/**
+ * Create or update a preset by name; built-in presets can be edited this way
+ */
+async saveDiffPreset(preset: DiffPreset) : Promise<DiffPreset> {
+    return await TAURI_INVOKE("save_diff_preset", { preset });
+},
+async deleteDiffPreset(name: string) : Promise<null> {
+    return await TAURI_INVOKE("delete_diff_preset", { name });
+},
+/**
+ * The preset diff commands use in `context` when no preset is named
+ */
+async getEffectiveDiffOptions(context: DiffContext) : Promise<DiffPreset> {
+    return await TAURI_INVOKE("get_effective_diff_options", { context });
 },
 /**
  * Create a new branch
@@ -138,8 +284,27 @@ async deleteBranch(name: string, options: DeleteBranchOptions) : Promise<null> {
 /**
  * Delete a remote branch
  */
-async deleteRemoteBranch(remoteName: string, branchName: string, force: boolean | null) : Promise<null> {
-    return await TAURI_INVOKE("delete_remote_branch", { remoteName, branchName, force });
+async deleteRemoteBranch(remoteName: string, branchName: string, force: boolean | null, overrideProtectionCheck: boolean | null) : Promise<null> {
+    return await TAURI_INVOKE("delete_remote_branch", { remoteName, branchName, force, overrideProtectionCheck });
+},
+/**
+ * Find branches that are old and/or already merged into a base branch
+ */
+async getStaleBranches(criteria: StaleBranchCriteria) : Promise<StaleBranch[]> {
+    return await TAURI_INVOKE("get_stale_branches", { criteria });
+},
+/**
+ * Tag each branch tip (`archive/<branch>` by default) and delete the branch
+ */
+async archiveBranches(branchNames: string[], tagPrefix: string | null, deleteRemote: boolean | null) : Promise<BranchOperationResult[]> {
+    return await TAURI_INVOKE("archive_branches", { branchNames, tagPrefix, deleteRemote });
+},
+/**
+ * Delete several branches, reporting success or failure per branch. Local branches that
+ * moved away from their `expected_targets` tip abort the local deletions.
+ */
+async bulkDeleteBranches(branchNames: string[], force: boolean, deleteRemote: boolean, expectedTargets: ExpectedBranchTarget[] | null) : Promise<BranchOperationResult[]> {
+    return await TAURI_INVOKE("bulk_delete_branches", { branchNames, force, deleteRemote, expectedTargets });
 },
 /**
  * Rename a branch
@@ -198,21 +363,45 @@ async setRemoteUrl(name: string, url: string) : Promise<null> {
 async setRemotePushUrl(name: string, url: string) : Promise<null> {
     return await TAURI_INVOKE("set_remote_push_url", { name, url });
 },
+/**
+ * Check that a remote can be reached with the configured credentials before a push
+ */
+async testRemoteConnection(remoteName: string) : Promise<RemoteConnectionResult> {
+    return await TAURI_INVOKE("test_remote_connection", { remoteName });
+},
+/**
+ * Branches a remote has, listed without fetching. Results are reused for 30 seconds.
+ */
+async getRemoteBranches(remoteName: string) : Promise<RemoteBranchInfo[]> {
+    return await TAURI_INVOKE("get_remote_branches", { remoteName });
+},
+/**
+ * Tags a remote has, listed without fetching, so a subset can be fetched explicitly
+ */
+async getRemoteTags(remoteName: string) : Promise<RemoteTagInfo[]> {
+    return await TAURI_INVOKE("get_remote_tags", { remoteName });
+},
 async fetchRemote(remoteName: string, options: FetchOptions) : Promise<FetchResult> {
     return await TAURI_INVOKE("fetch_remote", { remoteName, options });
 },
-async pushRemote(remoteName: string, refspecs: string[], options: PushOptions, bypassHooks: boolean | null) : Promise<PushResult> {
-    return await TAURI_INVOKE("push_remote", { remoteName, refspecs, options, bypassHooks });
+async pushRemote(remoteName: string, refspecs: string[], options: PushOptions, bypassHooks: boolean | null, overrideProtectionCheck: boolean | null) : Promise<PushResult> {
+    return await TAURI_INVOKE("push_remote", { remoteName, refspecs, options, bypassHooks, overrideProtectionCheck });
 },
-async pushCurrentBranch(remoteName: string, options: PushOptions, bypassHooks: boolean | null) : Promise<PushResult> {
-    return await TAURI_INVOKE("push_current_branch", { remoteName, options, bypassHooks });
+async pushCurrentBranch(remoteName: string, options: PushOptions, bypassHooks: boolean | null, overrideProtectionCheck: boolean | null) : Promise<PushResult> {
+    return await TAURI_INVOKE("push_current_branch", { remoteName, options, bypassHooks, overrideProtectionCheck });
 },
-async pullRemote(remoteName: string, branchName: string, options: PullOptions) : Promise<null> {
+async pullRemote(remoteName: string, branchName: string, options: PullOptions) : Promise<PullResult> {
     return await TAURI_INVOKE("pull_remote", { remoteName, branchName, options });
 },
 async fetchAll() : Promise<FetchResult[]> {
     return await TAURI_INVOKE("fetch_all");
 },
+async configureBackgroundFetch(repoPath: string, enabled: boolean, intervalMinutes: number, prune: boolean, fetchAllRemotes: boolean) : Promise<BackgroundFetchStatus> {
+    return await TAURI_INVOKE("configure_background_fetch", { repoPath, enabled, intervalMinutes, prune, fetchAllRemotes });
+},
+async getBackgroundFetchStatus(repoPath: string) : Promise<BackgroundFetchStatus> {
+    return await TAURI_INVOKE("get_background_fetch_status", { repoPath });
+},
 /**
  * Build commit graph with lane assignments for visualization
  */
@@ -231,12 +420,24 @@ async searchCommits(options: SearchOptions) : Promise<SearchResult> {
 async blameFile(path: string, commitOid: string | null) : Promise<BlameResult> {
     return await TAURI_INVOKE("blame_file", { path, commitOid });
 },
+/**
+ * Get what a blamed commit did to one line, for the blame popover
+ */
+async getBlameLineContext(path: string, lineNumber: number, commitOid: string) : Promise<BlameLineContext> {
+    return await TAURI_INVOKE("get_blame_line_context", { path, lineNumber, commitOid });
+},
 /**
  * Get total commit count for pagination
  */
 async getCommitCount(fromRef: string | null) : Promise<number> {
     return await TAURI_INVOKE("get_commit_count", { fromRef });
 },
+/**
+ * Changelog of the commits in `to_ref` but not in `from_ref`, grouped by conventional type
+ */
+async generateChangelog(fromRef: string, toRef: string, options: ChangelogOptions) : Promise<Changelog> {
+    return await TAURI_INVOKE("generate_changelog", { fromRef, toRef, options });
+},
 /**
  * Get commit history for specific files
  */
@@ -246,8 +447,20 @@ async getFileHistory(options: FileLogOptions) : Promise<FileLogResult> {
 /**
  * Get diff for a specific file in a specific commit
  */
-async getFileDiffInCommit(commitOid: string, path: string, options: DiffOptions | null) : Promise<FileDiff | null> {
-    return await TAURI_INVOKE("get_file_diff_in_commit", { commitOid, path, options });
+async getFileDiffInCommit(commitOid: string, path: string, options: DiffOptions | null, preset: string | null) : Promise<FileDiff | null> {
+    return await TAURI_INVOKE("get_file_diff_in_commit", { commitOid, path, options, preset });
+},
+/**
+ * Compare a file between two refs, following renames across the range by default
+ */
+async getFileDiffBetween(path: string, fromRef: string, toRef: string, options: DiffOptions | null, followRenames: boolean | null, preset: string | null) : Promise<FileCompareResult> {
+    return await TAURI_INVOKE("get_file_diff_between", { path, fromRef, toRef, options, followRenames, preset });
+},
+/**
+ * Find where a path as it existed at `from_ref` lives now, following renames forward
+ */
+async resolveCurrentPath(historicalPath: string, fromRef: string) : Promise<ResolvedPath> {
+    return await TAURI_INVOKE("resolve_current_path", { historicalPath, fromRef });
 },
 /**
  * Merge a branch into the current branch
@@ -267,6 +480,12 @@ async mergeAbort() : Promise<null> {
 async mergeContinue() : Promise<MergeResult> {
     return await TAURI_INVOKE("merge_continue");
 },
+/**
+ * Get preview information for merging a branch into the current branch
+ */
+async getMergePreview(sourceRef: string) : Promise<MergePreview> {
+    return await TAURI_INVOKE("get_merge_preview", { sourceRef });
+},
 /**
  * Start a rebase onto a target branch
  */
@@ -315,6 +534,40 @@ async getInteractiveRebasePreview(onto: string) : Promise<InteractiveRebasePrevi
 async interactiveRebase(options: InteractiveRebaseOptions, bypassHooks: boolean | null) : Promise<RebaseResult> {
     return await TAURI_INVOKE("interactive_rebase", { options, bypassHooks });
 },
+/**
+ * Squash the last `count` commits into a single commit with `new_message`.
+ * Returns the new HEAD OID.
+ */
+async squashCommits(count: number, newMessage: string) : Promise<string> {
+    return await TAURI_INVOKE("squash_commits", { count, newMessage });
+},
+/**
+ * Plan rewording `oid`: the commits it touches and the preconditions to pass back to
+ * `reword_commit`
+ */
+async planRewordCommit(oid: string) : Promise<RewordPlan> {
+    return await TAURI_INVOKE("plan_reword_commit", { oid });
+},
+/**
+ * Change the message of `oid`, HEAD or any of its ancestors, without a full interactive
+ * rebase. Returns the new HEAD OID. With the preconditions of a `plan_reword_commit`
+ * plan, fails with `PreconditionFailed` if the repository changed since.
+ */
+async rewordCommit(oid: string, newMessage: string, preconditions: RewritePreconditions | null) : Promise<string> {
+    return await TAURI_INVOKE("reword_commit", { oid, newMessage, preconditions });
+},
+/**
+ * Read the remaining todo of the interactive rebase in progress
+ */
+async getInteractiveRebaseTodo() : Promise<InteractiveRebaseEntry[]> {
+    return await TAURI_INVOKE("get_interactive_rebase_todo");
+},
+/**
+ * Reorder, drop or change actions of the commits left in an interactive rebase
+ */
+async updateInteractiveRebaseTodo(todo: InteractiveRebaseEntry[]) : Promise<null> {
+    return await TAURI_INVOKE("update_interactive_rebase_todo", { todo });
+},
 /**
  * Get detailed rebase progress
  */
@@ -328,11 +581,17 @@ async rebaseContinueWithMessage(message: string) : Promise<RebaseResult> {
     return await TAURI_INVOKE("rebase_continue_with_message", { message });
 },
 /**
- * Cherry-pick commits
+ * Cherry-pick commits (an explicit list or a range), pausing on the first conflict
  */
 async cherryPick(options: CherryPickOptions) : Promise<CherryPickResult> {
     return await TAURI_INVOKE("cherry_pick", { options });
 },
+/**
+ * Preview which commits a cherry-pick would apply and which files would likely conflict
+ */
+async getCherryPickPreview(oids: string[]) : Promise<CherryPickPreview> {
+    return await TAURI_INVOKE("get_cherry_pick_preview", { oids });
+},
 /**
  * Abort an in-progress cherry-pick
  */
@@ -387,6 +646,30 @@ async getConflictContent(path: string) : Promise<ConflictContent> {
 async resolveConflict(path: string, resolution: ConflictResolution, customContent: string | null) : Promise<null> {
     return await TAURI_INVOKE("resolve_conflict", { path, resolution, customContent });
 },
+/**
+ * Write `content` as the resolved version of a conflicted file and stage it
+ */
+async resolveConflictWithContent(path: string, content: string) : Promise<null> {
+    return await TAURI_INVOKE("resolve_conflict_with_content", { path, content });
+},
+/**
+ * Get the clean and conflicting regions of a conflicted file
+ */
+async getConflictHunks(path: string) : Promise<ConflictHunk[]> {
+    return await TAURI_INVOKE("get_conflict_hunks", { path });
+},
+/**
+ * Resolve a conflicted file block by block and stage the result
+ */
+async resolveConflictHunks(path: string, resolutions: ConflictHunkChoice[]) : Promise<null> {
+    return await TAURI_INVOKE("resolve_conflict_hunks", { path, resolutions });
+},
+/**
+ * Merge a conflicted notebook cell by cell, staging it when no cell conflicts
+ */
+async mergeNotebookConflict(path: string) : Promise<NotebookMergeResult> {
+    return await TAURI_INVOKE("merge_notebook_conflict", { path });
+},
 /**
  * Mark a file as resolved
  */
@@ -417,6 +700,18 @@ async bisectStart(options: BisectStartOptions) : Promise<BisectResult> {
 async bisectMark(mark: BisectMarkType, commit: string | null) : Promise<BisectResult> {
     return await TAURI_INVOKE("bisect_mark", { mark, commit });
 },
+/**
+ * Mark a commit (HEAD by default) as good
+ */
+async bisectGood(commit: string | null) : Promise<BisectResult> {
+    return await TAURI_INVOKE("bisect_good", { commit });
+},
+/**
+ * Mark a commit (HEAD by default) as bad
+ */
+async bisectBad(commit: string | null) : Promise<BisectResult> {
+    return await TAURI_INVOKE("bisect_bad", { commit });
+},
 /**
  * Reset/end the bisect session
  */
@@ -436,11 +731,37 @@ async bisectLog() : Promise<string> {
     return await TAURI_INVOKE("bisect_log");
 },
 /**
- * Reset the repository to a specific commit
+ * Reset the current branch to a specific commit.
+ * Hard resets require `confirm_discard`; with `preview` nothing is changed and the
+ * files whose uncommitted changes would be discarded are returned.
  */
-async resetToCommit(options: ResetOptions) : Promise<null> {
+async resetToCommit(options: ResetOptions) : Promise<ResetResult> {
     return await TAURI_INVOKE("reset_to_commit", { options });
 },
+/**
+ * Reset the given paths in the index to their state in a commit, keeping HEAD and the working tree
+ */
+async resetPaths(commit: string, paths: string[]) : Promise<null> {
+    return await TAURI_INVOKE("reset_paths", { commit, paths });
+},
+/**
+ * Restore a single file from a commit into the index and working tree
+ */
+async resetFileToCommit(path: string, commitOid: string) : Promise<null> {
+    return await TAURI_INVOKE("reset_file_to_commit", { path, commitOid });
+},
+/**
+ * Preview removing paths from the unpushed commits of the current branch
+ */
+async previewRemovePathFromHistory(paths: string[]) : Promise<RemovePathPreview> {
+    return await TAURI_INVOKE("preview_remove_path_from_history", { paths });
+},
+/**
+ * Remove paths from the unpushed commits of the current branch
+ */
+async removePathFromUnpushedHistory(paths: string[], options: RemovePathOptions) : Promise<RemovePathResult> {
+    return await TAURI_INVOKE("remove_path_from_unpushed_history", { paths, options });
+},
 /**
  * List all stash entries
  */
@@ -453,6 +774,19 @@ async stashList() : Promise<StashEntry[]> {
 async stashSave(options: StashSaveOptions) : Promise<StashResult> {
     return await TAURI_INVOKE("stash_save", { options });
 },
+/**
+ * Stash only the changes to `paths`, including untracked files among them
+ */
+async stashSaveSelectedFiles(paths: string[], message: string | null) : Promise<null> {
+    return await TAURI_INVOKE("stash_save_selected_files", { paths, message });
+},
+/**
+ * Stash only the selected hunks of the unstaged changes, removing them from the working tree.
+ * Hunk indices refer to the workdir diff shown with `options`/`preset`.
+ */
+async stashSelection(selections: StashHunkSelection[], message: string | null, options: DiffOptions | null, preset: string | null) : Promise<StashEntry> {
+    return await TAURI_INVOKE("stash_selection", { selections, message, options, preset });
+},
 /**
  * Apply a stash (keep it in the stash list)
  */
@@ -483,6 +817,12 @@ async stashClear() : Promise<StashResult> {
 async stashShow(index: number | null, statOnly: boolean) : Promise<string> {
     return await TAURI_INVOKE("stash_show", { index, statOnly });
 },
+/**
+ * Diff a stash entry against the commit it was created on, without applying it
+ */
+async getStashDiff(stashIndex: number, options: DiffOptions | null, preset: string | null) : Promise<FileDiff[]> {
+    return await TAURI_INVOKE("get_stash_diff", { stashIndex, options, preset });
+},
 /**
  * Create a branch from a stash
  */
@@ -532,22 +872,34 @@ async tagDelete(name: string) : Promise<TagResult> {
     return await TAURI_INVOKE("tag_delete", { name });
 },
 /**
- * Push a tag to a remote
+ * Create several tags at once, reporting success or failure per tag
+ */
+async tagCreateBulk(specs: BulkTagSpec[], stopOnError: boolean | null) : Promise<TagOperationResult[]> {
+    return await TAURI_INVOKE("tag_create_bulk", { specs, stopOnError });
+},
+/**
+ * Delete local tags matching a glob pattern, optionally on remotes too
  */
-async tagPush(name: string, remote: string) : Promise<TagResult> {
-    return await TAURI_INVOKE("tag_push", { name, remote });
+async tagDeleteByPattern(pattern: string, options: DeleteTagsByPatternOptions | null) : Promise<DeleteTagsByPatternResult> {
+    return await TAURI_INVOKE("tag_delete_by_pattern", { pattern, options });
 },
 /**
- * Push all tags to a remote
+ * Push a tag to a remote, reporting whether it was pushed, already there or rejected
  */
-async tagPushAll(remote: string) : Promise<TagResult> {
-    return await TAURI_INVOKE("tag_push_all", { remote });
+async tagPush(name: string, remote: string, options: TagPushOptions | null) : Promise<TagPushResult> {
+    return await TAURI_INVOKE("tag_push", { name, remote, options });
+},
+/**
+ * Push the tags a remote is missing, with one outcome per local tag
+ */
+async tagPushAll(remote: string, options: TagPushOptions | null) : Promise<TagPushResult> {
+    return await TAURI_INVOKE("tag_push_all", { remote, options });
 },
 /**
  * Delete a remote tag
  */
-async tagDeleteRemote(name: string, remote: string) : Promise<TagResult> {
-    return await TAURI_INVOKE("tag_delete_remote", { name, remote });
+async tagDeleteRemote(name: string, remote: string, options: TagPushOptions | null) : Promise<TagPushResult> {
+    return await TAURI_INVOKE("tag_delete_remote", { name, remote, options });
 },
 /**
  * List all submodules
@@ -573,6 +925,18 @@ async submoduleInit(paths: string[]) : Promise<SubmoduleResult> {
 async submoduleUpdate(options: UpdateSubmoduleOptions) : Promise<SubmoduleResult> {
     return await TAURI_INVOKE("submodule_update", { options });
 },
+/**
+ * List submodules whose checked-out commit differs from the one recorded at HEAD
+ */
+async getOutOfSyncSubmodules() : Promise<OutOfSyncSubmodule[]> {
+    return await TAURI_INVOKE("get_out_of_sync_submodules");
+},
+/**
+ * Update only the submodules that are out of sync with HEAD
+ */
+async updateOutOfSyncSubmodules() : Promise<SubmoduleResult> {
+    return await TAURI_INVOKE("update_out_of_sync_submodules");
+},
 /**
  * Sync submodule URLs from .gitmodules
  */
@@ -724,10 +1088,36 @@ async gitflowHotfixList() : Promise<string[]> {
     return await TAURI_INVOKE("gitflow_hotfix_list");
 },
 /**
- * Search for content in the repository working tree
+ * Query the operation log with filters, paging and session grouping
+ */
+async getOperationLogFiltered(filter: OperationLogFilter) : Promise<OperationLogPage> {
+    return await TAURI_INVOKE("get_operation_log_filtered", { filter });
+},
+/**
+ * Export every operation log entry matching the filter to a JSON file
+ */
+async exportOperationLog(filter: OperationLogFilter, path: string) : Promise<number> {
+    return await TAURI_INVOKE("export_operation_log", { filter, path });
+},
+/**
+ * Undo journal entries for the current repository, newest first
+ */
+async getUndoHistory(limit: number | null) : Promise<UndoEntry[]> {
+    return await TAURI_INVOKE("get_undo_history", { limit });
+},
+/**
+ * Restore the repository to the snapshot taken before the last destructive operation
+ */
+async undoLastOperation() : Promise<UndoResult> {
+    return await TAURI_INVOKE("undo_last_operation");
+},
+/**
+ * Search for content in the repository working tree, or in the tree of `options.ref`.
+ * With a `search_id`, matches stream as `GrepResultsPageEvent`s and the returned
+ * result only carries the totals.
  */
-async grepContent(options: GrepOptions) : Promise<GrepResult> {
-    return await TAURI_INVOKE("grep_content", { options });
+async grepContent(options: GrepOptions, searchId: string | null) : Promise<GrepResult> {
+    return await TAURI_INVOKE("grep_content", { options, searchId });
 },
 /**
  * Search for content in a specific commit
@@ -735,6 +1125,19 @@ async grepContent(options: GrepOptions) : Promise<GrepResult> {
 async grepCommit(commitOid: string, options: GrepOptions) : Promise<GrepResult> {
     return await TAURI_INVOKE("grep_commit", { commitOid, options });
 },
+/**
+ * Search the committed content of a branch without checking it out
+ */
+async grepBranch(pattern: string, branch: string, pathFilter: string | null) : Promise<GrepResult> {
+    return await TAURI_INVOKE("grep_branch", { pattern, branch, pathFilter });
+},
+/**
+ * Search the added and context lines of the staged diff, e.g. to find where a
+ * symbol is being introduced
+ */
+async grepDiff(pattern: string, caseSensitive: boolean, contextLines: number) : Promise<GrepDiffMatch[]> {
+    return await TAURI_INVOKE("grep_diff", { pattern, caseSensitive, contextLines });
+},
 async getSettings() : Promise<AppSettings> {
     return await TAURI_INVOKE("get_settings");
 },
@@ -750,6 +1153,39 @@ async saveRepositoryUserConfig(userName: string | null, userEmail: string | null
 async saveRepositorySigningConfig(signingFormat: SigningFormat | null, signingKey: string | null) : Promise<null> {
     return await TAURI_INVOKE("save_repository_signing_config", { signingFormat, signingKey });
 },
+/**
+ * Effective value of any git config key, or `None` when it is not set
+ */
+async getRepositoryConfig(key: string) : Promise<string | null> {
+    return await TAURI_INVOKE("get_repository_config", { key });
+},
+/**
+ * Every local, global and system config entry with the file it comes from,
+ * sorted by key, like `git config --list --show-origin`
+ */
+async listRepositoryConfig() : Promise<ConfigEntry[]> {
+    return await TAURI_INVOKE("list_repository_config");
+},
+/**
+ * Set any git config key in the local, global or system config file
+ */
+async setRepositoryConfig(key: string, value: string, level: ConfigLevel) : Promise<null> {
+    return await TAURI_INVOKE("set_repository_config", { key, value, level });
+},
+/**
+ * The performance profile a repository runs with, the size measurements behind an
+ * `Auto` decision, and the effective value of each knob
+ */
+async getPerformanceProfile(repoPath: string) : Promise<PerformanceReadiness> {
+    return await TAURI_INVOKE("get_performance_profile", { repoPath });
+},
+/**
+ * Choose a repository's performance profile and overrides. The file watcher picks
+ * up a new debounce when the repository is next opened.
+ */
+async setPerformanceProfile(repoPath: string, config: PerformanceConfig) : Promise<PerformanceReadiness> {
+    return await TAURI_INVOKE("set_performance_profile", { repoPath, config });
+},
 /**
  * List all hooks with their status
  */
@@ -787,6 +1223,26 @@ async deleteHook(hookType: GitHookType) : Promise<null> {
 async toggleHook(hookType: GitHookType) : Promise<boolean> {
     return await TAURI_INVOKE("toggle_hook", { hookType });
 },
+/**
+ * Enable a hook
+ * Returns whether the hook state changed
+ */
+async enableHook(hookType: GitHookType) : Promise<boolean> {
+    return await TAURI_INVOKE("enable_hook", { hookType });
+},
+/**
+ * Disable a hook
+ * Returns whether the hook state changed
+ */
+async disableHook(hookType: GitHookType) : Promise<boolean> {
+    return await TAURI_INVOKE("disable_hook", { hookType });
+},
+/**
+ * Run a hook manually with optional arguments
+ */
+async runHook(hookType: GitHookType, args: string[] | null) : Promise<HookResult> {
+    return await TAURI_INVOKE("run_hook", { hookType, args });
+},
 /**
  * Get available hook templates
  */
@@ -799,6 +1255,18 @@ async getHookTemplates() : Promise<HookTemplate[]> {
 async getHookTemplatesForType(hookType: GitHookType) : Promise<HookTemplate[]> {
     return await TAURI_INVOKE("get_hook_templates_for_type", { hookType });
 },
+/**
+ * Get the trust prompt for hooks in the current repository that have no decision yet
+ */
+async getHookTrustRequest() : Promise<HookTrustRequest | null> {
+    return await TAURI_INVOKE("get_hook_trust_request");
+},
+/**
+ * Apply the user's trust decision to the hooks listed in a trust prompt
+ */
+async resolveHookTrust(request: HookTrustRequest, decision: HookTrustDecision) : Promise<null> {
+    return await TAURI_INVOKE("resolve_hook_trust", { request, decision });
+},
 async getSigningConfig() : Promise<SigningConfig> {
     return await TAURI_INVOKE("get_signing_config");
 },
@@ -817,6 +1285,32 @@ async isSigningAvailable(config: SigningConfig) : Promise<boolean> {
 async verifyCommitSignature(oid: string, format: SigningFormat) : Promise<SignatureVerification> {
     return await TAURI_INVOKE("verify_commit_signature", { oid, format });
 },
+/**
+ * Verify the signatures of many commits (e.g. the visible graph nodes) in one call.
+ * Cached results are reused; the rest are verified together on a single blocking task.
+ */
+async getCommitSignatures(oids: string[]) : Promise<CommitSignatureResult[]> {
+    return await TAURI_INVOKE("get_commit_signatures", { oids });
+},
+/**
+ * Generate a new GPG signing key, emitting `GpgKeyGenerationProgressEvent` while gpg works
+ */
+async generateGpgKey(name: string, email: string, passphrase: string | null, expiry: string | null) : Promise<GeneratedGpgKey> {
+    return await TAURI_INVOKE("generate_gpg_key", { name, email, passphrase, expiry });
+},
+/**
+ * Public key of a GPG key, ASCII-armored or base64 of the binary export
+ */
+async exportGpgPublicKey(keyId: string, armored: boolean) : Promise<string> {
+    return await TAURI_INVOKE("export_gpg_public_key", { keyId, armored });
+},
+/**
+ * Write `user.signingkey`, `gpg.format` and `commit.gpgsign` to the open repository's
+ * .git/config (`Local`) or to the user's global config (`Global`)
+ */
+async configureSigning(scope: ConfigLevel, format: SigningFormat, keyId: string, signCommitsByDefault: boolean) : Promise<null> {
+    return await TAURI_INVOKE("configure_signing", { scope, format, keyId, signCommitsByDefault });
+},
 async createArchive(options: ArchiveOptions) : Promise<ArchiveResult> {
     return await TAURI_INVOKE("create_archive", { options });
 },
@@ -826,6 +1320,13 @@ async createArchive(options: ArchiveOptions) : Promise<ArchiveResult> {
 async formatPatch(options: FormatPatchOptions) : Promise<PatchResult> {
     return await TAURI_INVOKE("format_patch", { options });
 },
+/**
+ * Save the commits after `from_oid` up to `to_oid` as a numbered patch series
+ * in `output_dir`, returning the created files in order
+ */
+async createPatchSeries(fromOid: string, toOid: string, outputDir: string) : Promise<string[]> {
+    return await TAURI_INVOKE("create_patch_series", { fromOid, toOid, outputDir });
+},
 /**
  * Create a patch from a specific commit or staged changes
  */
@@ -862,8 +1363,8 @@ async amContinue() : Promise<PatchResult> {
 async amSkip() : Promise<PatchResult> {
     return await TAURI_INVOKE("am_skip");
 },
-async generateCommitMessage() : Promise<GenerateCommitMessageResponse> {
-    return await TAURI_INVOKE("generate_commit_message");
+async generateCommitMessage(options: GenerateCommitMessageOptions | null) : Promise<GenerateCommitMessageResponse> {
+    return await TAURI_INVOKE("generate_commit_message", { options });
 },
 async setAiApiKey(provider: AiProvider, apiKey: string) : Promise<null> {
     return await TAURI_INVOKE("set_ai_api_key", { provider, apiKey });
@@ -874,18 +1375,58 @@ async hasAiApiKey(provider: AiProvider) : Promise<boolean> {
 async deleteAiApiKey(provider: AiProvider) : Promise<null> {
     return await TAURI_INVOKE("delete_ai_api_key", { provider });
 },
-async testAiConnection(provider: AiProvider) : Promise<boolean> {
-    return await TAURI_INVOKE("test_ai_connection", { provider });
+/**
+ * List the models offered by `provider`. `base_url` overrides the configured
+ * Ollama address so the settings page can probe an address before saving it.
+ */
+async aiListModels(provider: AiProvider, baseUrl: string | null) : Promise<AiModelInfo[]> {
+    return await TAURI_INVOKE("ai_list_models", { provider, baseUrl });
+},
+/**
+ * Check that `provider` is reachable and accepts the stored API key
+ */
+async aiCheckConnection(provider: AiProvider, baseUrl: string | null) : Promise<AiConnectionCheck> {
+    return await TAURI_INVOKE("ai_check_connection", { provider, baseUrl });
+},
+/**
+ * Explain one file diff, or one hunk of it, with the configured provider.
+ * The explanation streams as `AiExplainChunkEvent`s; cached explanations do not.
+ */
+async explainDiff(scope: ExplainDiffScope) : Promise<ExplainDiffResponse> {
+    return await TAURI_INVOKE("explain_diff", { scope });
+},
+/**
+ * Describe the commits `base_ref` has over `compare_ref` (e.g. `origin/feature`
+ * over `origin/main`) as a PR title and markdown body.
+ */
+async generatePrDescription(baseRef: string, compareRef: string, options: GeneratePrDescriptionOptions | null) : Promise<GeneratePrDescriptionResponse> {
+    return await TAURI_INVOKE("generate_pr_description", { baseRef, compareRef, options });
+},
+/**
+ * AI request totals per day, feature and model, with costs estimated from the
+ * configured price table
+ */
+async getAiUsage(filter: AiUsageFilter | null) : Promise<AiUsageReport> {
+    return await TAURI_INVOKE("get_ai_usage", { filter });
 },
-async listOllamaModels(ollamaUrl: string | null) : Promise<string[]> {
-    return await TAURI_INVOKE("list_ollama_models", { ollamaUrl });
+/**
+ * AI requests waiting for or holding a provider slot, oldest first
+ */
+async getAiQueue() : Promise<AiQueueEntry[]> {
+    return await TAURI_INVOKE("get_ai_queue");
 },
-async generatePrDescription(sourceBranch: string, targetBranch: string, includeDiffSummary: boolean, availableLabels: string[]) : Promise<GeneratePrDescriptionResponse> {
-    return await TAURI_INVOKE("generate_pr_description", { sourceBranch, targetBranch, includeDiffSummary, availableLabels });
+/**
+ * Cancel a queued or running AI request. Returns false when it already finished.
+ */
+async cancelAiRequest(id: number) : Promise<boolean> {
+    return await TAURI_INVOKE("cancel_ai_request", { id });
 },
 async addToGitignore(pattern: string, gitignorePath: string) : Promise<IgnoreResult> {
     return await TAURI_INVOKE("add_to_gitignore", { pattern, gitignorePath });
 },
+async bulkIgnore(patterns: string[], gitignorePath: string) : Promise<IgnoreResult[]> {
+    return await TAURI_INVOKE("bulk_ignore", { patterns, gitignorePath });
+},
 async addToGlobalGitignore(pattern: string) : Promise<IgnoreResult> {
     return await TAURI_INVOKE("add_to_global_gitignore", { pattern });
 },
@@ -934,6 +1475,12 @@ async lfsUntrack(pattern: string) : Promise<LfsResult> {
 async lfsListPatterns() : Promise<LfsTrackedPattern[]> {
     return await TAURI_INVOKE("lfs_list_patterns");
 },
+/**
+ * List patterns tracked by LFS in `.gitattributes`
+ */
+async getLfsTrackedPatterns() : Promise<string[]> {
+    return await TAURI_INVOKE("get_lfs_tracked_patterns");
+},
 /**
  * List all LFS files in the repository
  */
@@ -983,9 +1530,29 @@ async lfsPrune(options: LfsPruneOptions) : Promise<LfsPruneResult> {
     return await TAURI_INVOKE("lfs_prune", { options });
 },
 /**
- * Start the OAuth flow for a provider
+ * Object store sizes, the largest blobs and reflog sizes of the open repository
  */
-async integrationStartOauth(provider: ProviderType) : Promise<null> {
+async getRepositorySizeReport(largestBlobs: number | null) : Promise<RepositorySizeReport> {
+    return await TAURI_INVOKE("get_repository_size_report", { largestBlobs });
+},
+/**
+ * List the unreachable loose objects `git prune` would delete
+ */
+async pruneDryRun() : Promise<PruneDryRunResult> {
+    return await TAURI_INVOKE("prune_dry_run");
+},
+/**
+ * Run gc, prune, repack and commit-graph tasks, streaming progress as
+ * `GitOperationProgressEvent`s. Holds the repository write lock throughout so no other
+ * operation changes refs or objects mid-gc.
+ */
+async runMaintenance(options: MaintenanceOptions) : Promise<MaintenanceResult> {
+    return await TAURI_INVOKE("run_maintenance", { options });
+},
+/**
+ * Start the OAuth flow for a provider
+ */
+async integrationStartOauth(provider: ProviderType) : Promise<null> {
     return await TAURI_INVOKE("integration_start_oauth", { provider });
 },
 /**
@@ -1003,6 +1570,13 @@ async integrationGetStatus(provider: ProviderType) : Promise<IntegrationStatus>
 async integrationDisconnect(provider: ProviderType) : Promise<null> {
     return await TAURI_INVOKE("integration_disconnect", { provider });
 },
+/**
+ * Upload an ASCII-armored GPG public key (see `export_gpg_public_key`) to the connected
+ * account
+ */
+async integrationAddGpgKey(provider: ProviderType, armoredPublicKey: string) : Promise<null> {
+    return await TAURI_INVOKE("integration_add_gpg_key", { provider, armoredPublicKey });
+},
 async integrationDetectProvider() : Promise<DetectedProvider | null> {
     return await TAURI_INVOKE("integration_detect_provider");
 },
@@ -1039,6 +1613,25 @@ async integrationListCiRuns(detected: DetectedProvider, page: number) : Promise<
 async integrationGetCommitStatus(detected: DetectedProvider, sha: string) : Promise<CommitStatus> {
     return await TAURI_INVOKE("integration_get_commit_status", { detected, sha });
 },
+/**
+ * Aggregate CI status for the current branch's tip commit
+ */
+async integrationGetBranchCiRollup(detected: DetectedProvider) : Promise<CiRollup> {
+    return await TAURI_INVOKE("integration_get_branch_ci_rollup", { detected });
+},
+/**
+ * Open pull request for the checked-out branch, with how far the local branch diverged from
+ * its head. `None` when the repository is not on a connected provider.
+ */
+async integrationGetCurrentBranchPr() : Promise<CurrentBranchPullRequest | null> {
+    return await TAURI_INVOKE("integration_get_current_branch_pr");
+},
+/**
+ * Re-run a CI workflow run, optionally only its failed jobs
+ */
+async integrationRerunCiRun(detected: DetectedProvider, runId: string, failedOnly: boolean) : Promise<null> {
+    return await TAURI_INVOKE("integration_rerun_ci_run", { detected, runId, failedOnly });
+},
 async integrationListNotifications(detected: DetectedProvider, all: boolean, page: number) : Promise<NotificationsPage> {
     return await TAURI_INVOKE("integration_list_notifications", { detected, all, page });
 },
@@ -1048,7 +1641,7 @@ async integrationMarkNotificationRead(provider: ProviderType, threadId: string)
 async integrationMarkAllNotificationsRead(detected: DetectedProvider) : Promise<null> {
     return await TAURI_INVOKE("integration_mark_all_notifications_read", { detected });
 },
-async integrationGetUnreadCount(detected: DetectedProvider) : Promise<number> {
+async integrationGetUnreadCount(detected: DetectedProvider) : Promise<UnreadNotificationCount> {
     return await TAURI_INVOKE("integration_get_unread_count", { detected });
 },
 async listSshKeysInfo() : Promise<SshKeyInfo[]> {
@@ -1072,6 +1665,9 @@ async importSshKey(options: ImportSshKeyOptions) : Promise<SshKeyInfo> {
 async exportSshKey(options: ExportSshKeyOptions) : Promise<null> {
     return await TAURI_INVOKE("export_ssh_key", { options });
 },
+async listSshAgentIdentities() : Promise<SshAgentIdentity[]> {
+    return await TAURI_INVOKE("list_ssh_agent_identities");
+},
 async getRemoteSshKey(remoteName: string) : Promise<string | null> {
     return await TAURI_INVOKE("get_remote_ssh_key", { remoteName });
 },
@@ -1096,6 +1692,41 @@ async clearSshPassphrase(keyPath: string) : Promise<null> {
 async isSshPassphraseCached(keyPath: string) : Promise<boolean> {
     return await TAURI_INVOKE("is_ssh_passphrase_cached", { keyPath });
 },
+async setHostCredential(host: string, username: string, token: string) : Promise<null> {
+    return await TAURI_INVOKE("set_host_credential", { host, username, token });
+},
+async deleteHostCredential(host: string) : Promise<null> {
+    return await TAURI_INVOKE("delete_host_credential", { host });
+},
+async listCredentialHosts() : Promise<CredentialHost[]> {
+    return await TAURI_INVOKE("list_credential_hosts");
+},
+/**
+ * Bookmark a commit, file, comparison, or branch in the active repository
+ */
+async createBookmark(target: BookmarkTarget, label: string, notes: string | null) : Promise<Bookmark> {
+    return await TAURI_INVOKE("create_bookmark", { target, label, notes });
+},
+/**
+ * Bookmarks of the active repository with the state of their targets. Broken
+ * targets are annotated rather than failing the list; with `repair`, bookmarks
+ * of renamed branches are moved to the new name and saved.
+ */
+async getBookmarks(repair: boolean | null) : Promise<ResolvedBookmark[]> {
+    return await TAURI_INVOKE("get_bookmarks", { repair });
+},
+async updateBookmark(id: number, label: string, notes: string | null) : Promise<Bookmark> {
+    return await TAURI_INVOKE("update_bookmark", { id, label, notes });
+},
+/**
+ * Record that a bookmark was opened
+ */
+async visitBookmark(id: number) : Promise<Bookmark> {
+    return await TAURI_INVOKE("visit_bookmark", { id });
+},
+async deleteBookmark(id: number) : Promise<null> {
+    return await TAURI_INVOKE("delete_bookmark", { id });
+},
 /**
  * Get avatar for a commit author
  * If sha provided and integration connected, fetches avatar from provider API
@@ -1103,6 +1734,13 @@ async isSshPassphraseCached(keyPath: string) : Promise<boolean> {
 async getAvatar(email: string, sha: string | null) : Promise<AvatarResponse> {
     return await TAURI_INVOKE("get_avatar", { email, sha });
 },
+/**
+ * Get avatars for many authors at once, in the order given.
+ * Every email gets an image: a cached or downloaded file path, or a generated identicon.
+ */
+async getAvatars(emails: string[], size: number) : Promise<EmailAvatar[]> {
+    return await TAURI_INVOKE("get_avatars", { emails, size });
+},
 /**
  * Clear the avatar cache
  */
@@ -1116,16 +1754,16 @@ async listGlobalActions() : Promise<CustomAction[]> {
     return await TAURI_INVOKE("list_global_actions");
 },
 /**
- * Save a global action (create or update)
+ * Save a global action (create or update by id). A missing id is assigned.
  */
-async saveGlobalAction(action: CustomAction) : Promise<null> {
-    return await TAURI_INVOKE("save_global_action", { action });
+async saveCustomAction(action: CustomAction) : Promise<CustomAction> {
+    return await TAURI_INVOKE("save_custom_action", { action });
 },
 /**
  * Delete a global action
  */
-async deleteGlobalAction(actionId: string) : Promise<null> {
-    return await TAURI_INVOKE("delete_global_action", { actionId });
+async deleteCustomAction(actionId: string) : Promise<null> {
+    return await TAURI_INVOKE("delete_custom_action", { actionId });
 },
 /**
  * List all repository-specific actions
@@ -1163,6 +1801,22 @@ async getAllActions() : Promise<CustomAction[]> {
 async executeCustomAction(actionId: string, variables: ActionVariables) : Promise<ActionExecutionResult> {
     return await TAURI_INVOKE("execute_custom_action", { actionId, variables });
 },
+/**
+ * List git aliases (`alias.*`) from every config level
+ */
+async listConfigAliases() : Promise<ConfigAlias[]> {
+    return await TAURI_INVOKE("list_config_aliases");
+},
+/**
+ * Run a git alias with extra arguments, streaming output as `CustomActionOutputEvent`s
+ * with the action id `alias:<name>`.
+ * 
+ * Shell aliases (`!…`) run arbitrary commands and fail with
+ * `ShellAliasRequiresConfirmation` unless `confirmed` is set.
+ */
+async runConfigAlias(name: string, extraArgs: string[], confirmed: boolean) : Promise<ActionExecutionResult> {
+    return await TAURI_INVOKE("run_config_alias", { name, extraArgs, confirmed });
+},
 async checkForUpdate() : Promise<UpdateInfo | null> {
     return await TAURI_INVOKE("check_for_update");
 },
@@ -1178,31 +1832,59 @@ async restartApp() : Promise<null> {
 
 
 export const events = __makeEvents__<{
+aiCommitMessageChunkEvent: AiCommitMessageChunkEvent,
+aiExplainChunkEvent: AiExplainChunkEvent,
+backgroundFetchCompletedEvent: BackgroundFetchCompletedEvent,
+bisectStepEvent: BisectStepEvent,
+commitMessageAnalysisProgressEvent: CommitMessageAnalysisProgressEvent,
+currentBranchPullRequestEvent: CurrentBranchPullRequestEvent,
+customActionOutputEvent: CustomActionOutputEvent,
 filesChangedEvent: FilesChangedEvent,
 gitOperationProgressEvent: GitOperationProgressEvent,
+gpgKeyGenerationProgressEvent: GpgKeyGenerationProgressEvent,
+grepResultsPageEvent: GrepResultsPageEvent,
 headChangedEvent: HeadChangedEvent,
 hookProgressEvent: HookProgressEvent,
+hookTrustRequiredEvent: HookTrustRequiredEvent,
 indexChangedEvent: IndexChangedEvent,
 integrationStatusChangedEvent: IntegrationStatusChangedEvent,
 menuActionEvent: MenuActionEvent,
 oAuthCallbackEvent: OAuthCallbackEvent,
+operationStateChangedEvent: OperationStateChangedEvent,
+pullRequestRefreshedEvent: PullRequestRefreshedEvent,
 refChangedEvent: RefChangedEvent,
 remoteFetchedEvent: RemoteFetchedEvent,
+repositoryChangedEvent: RepositoryChangedEvent,
 repositoryDirtyEvent: RepositoryDirtyEvent,
+submodulesOutOfSyncEvent: SubmodulesOutOfSyncEvent,
 updateDownloadProgressEvent: UpdateDownloadProgressEvent,
 watchErrorEvent: WatchErrorEvent
 }>({
+aiCommitMessageChunkEvent: "ai-commit-message-chunk-event",
+aiExplainChunkEvent: "ai-explain-chunk-event",
+backgroundFetchCompletedEvent: "background-fetch-completed-event",
+bisectStepEvent: "bisect-step-event",
+commitMessageAnalysisProgressEvent: "commit-message-analysis-progress-event",
+currentBranchPullRequestEvent: "current-branch-pull-request-event",
+customActionOutputEvent: "custom-action-output-event",
 filesChangedEvent: "files-changed-event",
 gitOperationProgressEvent: "git-operation-progress-event",
+gpgKeyGenerationProgressEvent: "gpg-key-generation-progress-event",
+grepResultsPageEvent: "grep-results-page-event",
 headChangedEvent: "head-changed-event",
 hookProgressEvent: "hook-progress-event",
+hookTrustRequiredEvent: "hook-trust-required-event",
 indexChangedEvent: "index-changed-event",
 integrationStatusChangedEvent: "integration-status-changed-event",
 menuActionEvent: "menu-action-event",
 oAuthCallbackEvent: "o-auth-callback-event",
+operationStateChangedEvent: "operation-state-changed-event",
+pullRequestRefreshedEvent: "pull-request-refreshed-event",
 refChangedEvent: "ref-changed-event",
 remoteFetchedEvent: "remote-fetched-event",
+repositoryChangedEvent: "repository-changed-event",
 repositoryDirtyEvent: "repository-dirty-event",
+submodulesOutOfSyncEvent: "submodules-out-of-sync-event",
 updateDownloadProgressEvent: "update-download-progress-event",
 watchErrorEvent: "watch-error-event"
 })
@@ -1261,6 +1943,7 @@ stderr: string;
  * Execution time in milliseconds
  */
 durationMs: number }
+export type ActionOutputStream = "Stdout" | "Stderr"
 /**
  * Storage location for actions
  */
@@ -1369,8 +2052,194 @@ force: boolean;
  * Create worktree in detached HEAD state
  */
 detach: boolean }
+/**
+ * A commit whose tree contains one of the paths being removed
+ */
+export type AffectedCommit = { 
+/**
+ * The commit as it exists today
+ */
+commit: Commit; 
+/**
+ * Paths that will be removed from this commit's tree
+ */
+removed: RemovedPathEntry[] }
+/**
+ * A piece of a commit message as it streams from the AI provider
+ */
+export type AiCommitMessageChunkEvent = { chunk: string }
+/**
+ * Result of a successful provider connection check
+ */
+export type AiConnectionCheck = { 
+/**
+ * Round trip time of the check request in milliseconds
+ */
+latencyMs: number }
+/**
+ * A piece of a diff explanation as it streams from the AI provider
+ */
+export type AiExplainChunkEvent = { path: string; hunkIndex: number | null; chunk: string }
+/**
+ * Feature that sent a provider request
+ */
+export type AiFeature = "CommitMessage" | "ExplainDiff" | "PrDescription"
+/**
+ * A model offered by an AI provider
+ */
+export type AiModelInfo = { name: string; 
+/**
+ * Size on disk in bytes, reported by local providers
+ */
+size: number | null }
+/**
+ * Price of a model in US dollars per million tokens
+ */
+export type AiModelPrice = { 
+/**
+ * Model name prefix, so `gpt-4o` also prices `gpt-4o-2024-08-06`
+ */
+model: string; inputPerMillion: number; outputPerMillion: number }
 export type AiProvider = "OpenAi" | "Anthropic" | "Ollama"
-export type AppSettings = { theme: Theme; language: string; fontSize: number; showLineNumbers: boolean; autoFetchInterval: number; confirmBeforeDiscard: boolean; signCommits: boolean; bypassHooks: boolean; signingFormat: SigningFormat; signingKey: string | null; gpgProgram: string | null; sshProgram: string | null; diffContextLines: number; diffWordWrap: boolean; diffSideBySide: boolean; spellCheckCommitMessages: boolean; conventionalCommitsEnabled: boolean; conventionalCommitsScopes: string[] | null; aiEnabled: boolean; aiProvider: AiProvider; aiModel: string | null; aiOllamaUrl: string | null; defaultSshKey: string | null; notificationHistoryCapacity: number; gravatarEnabled: boolean; autoUpdateEnabled: boolean; largeBinaryWarningEnabled: boolean; largeBinaryThreshold: number }
+/**
+ * An AI request waiting for or holding a provider slot
+ */
+export type AiQueueEntry = { 
+/**
+ * Identifies the entry for `cancel_ai_request`
+ */
+id: number; feature: AiFeature; model: string; state: AiQueueState; queuedAt: string; startedAt: string | null }
+/**
+ * Whether a queued AI request is waiting for a slot or talking to the provider
+ */
+export type AiQueueState = "Waiting" | "Running"
+/**
+ * How a recorded provider request ended
+ */
+export type AiRequestOutcome = "Success" | "Failure" | 
+/**
+ * Cancelled from the queue while the provider was answering
+ */
+"Cancelled"
+/**
+ * Requests of one feature to one model on one day (UTC)
+ */
+export type AiUsageBucket = { 
+/**
+ * Day as `YYYY-MM-DD`
+ */
+day: string; feature: AiFeature; provider: AiProvider; model: string; requests: number; failures: number; 
+/**
+ * Estimated prompt tokens
+ */
+promptTokens: number; 
+/**
+ * Response tokens, estimated from the response text
+ */
+responseTokens: number; durationMs: number; 
+/**
+ * Estimated cost in US dollars; `None` when the model has no price
+ */
+estimatedCost: number | null }
+/**
+ * Filters for aggregating the AI request ledger
+ */
+export type AiUsageFilter = { 
+/**
+ * Only requests at or after this time
+ */
+from: string | null; 
+/**
+ * Only requests before this time
+ */
+to: string | null; 
+/**
+ * Only requests sent by this feature
+ */
+feature: AiFeature | null; 
+/**
+ * Only requests to this model
+ */
+model: string | null }
+/**
+ * Aggregated AI usage, newest day first
+ */
+export type AiUsageReport = { buckets: AiUsageBucket[]; totalRequests: number; 
+/**
+ * Sum of the priced buckets in US dollars
+ */
+totalEstimatedCost: number; 
+/**
+ * Hosted models without a price, whose cost is left out of the total
+ */
+unpricedModels: string[] }
+export type AppSettings = { theme: Theme; language: string; fontSize: number; showLineNumbers: boolean; autoFetchInterval: number; confirmBeforeDiscard: boolean; signCommits: boolean; bypassHooks: boolean; 
+/**
+ * Quiet period before file changes are reported, in milliseconds; 300 when unset
+ */
+fileWatcherDebounceMs?: number | null; 
+/**
+ * Directories holding repositories that may borrow objects from the open one
+ */
+sharedObjectStoreRoots?: string[]; signingFormat: SigningFormat; signingKey: string | null; gpgProgram: string | null; sshProgram: string | null; diffContextLines: number; diffWordWrap: boolean; diffSideBySide: boolean; 
+/**
+ * Preset for working tree diffs when a command names none; `Default` when unset
+ */
+diffPresetWorkdir?: string | null; 
+/**
+ * Preset for commit diffs when a command names none; `Default` when unset
+ */
+diffPresetCommit?: string | null; 
+/**
+ * Preset for blame when none is chosen; `Default` when unset
+ */
+diffPresetBlame?: string | null; 
+/**
+ * Show the enclosing function of each hunk, detected from the file's language
+ */
+diffFunctionContext?: boolean; 
+/**
+ * Diff Jupyter notebooks cell by cell, and structured JSON files with
+ * normalized formatting, instead of as raw text
+ */
+diffStructured?: boolean; 
+/**
+ * How cell outputs appear in notebook diffs
+ */
+diffNotebookOutputs?: NotebookOutputMode; 
+/**
+ * Extensions of JSON files diffed with normalized formatting and key order
+ */
+diffStructuredJsonExtensions?: string[]; spellCheckCommitMessages: boolean; conventionalCommitsEnabled: boolean; conventionalCommitsScopes: string[] | null; 
+/**
+ * Commit message rules; disabled by default
+ */
+commitLint?: CommitLintConfig; 
+/**
+ * Changelog headings per conventional type, in output order
+ */
+changelogSections?: ChangelogSection[]; aiEnabled: boolean; aiProvider: AiProvider; aiModel: string | null; 
+/**
+ * Model for PR descriptions; falls back to `ai_model` when not set
+ */
+aiPrModel?: string | null; aiOllamaUrl: string | null; 
+/**
+ * Per-model prices used to estimate the cost of AI requests
+ */
+aiModelPrices?: AiModelPrice[]; defaultSshKey: string | null; 
+/**
+ * Terminal opened by `open_terminal`; the platform's default when unset
+ */
+terminalApp?: TerminalApp | null; 
+/**
+ * Command line run for `TerminalApp::Custom`, with `{path}` standing for the directory
+ */
+terminalCommandTemplate?: string | null; 
+/**
+ * Profile to open, for terminals that have them (Windows Terminal, iTerm2, Konsole,
+ * GNOME Terminal)
+ */
+terminalProfile?: string | null; notificationHistoryCapacity: number; gravatarEnabled: boolean; autoUpdateEnabled: boolean; largeBinaryWarningEnabled: boolean; largeBinaryThreshold: number }
 /**
  * Options for applying mailbox patches (git am)
  */
@@ -1423,9 +2292,98 @@ prefix: string | null }
  * Result of an archive operation
  */
 export type ArchiveResult = { message: string; outputPath: string | null; sizeBytes: number | null }
-export type AvatarResponse = { source: AvatarSource; path: string | null }
-export type AvatarSource = "Integration" | "Gravatar" | "Default"
-export type AxisError = { type: "InvalidRepositoryPath"; data: string } | { type: "GitError"; data: string } | { type: "IoError"; data: string } | { type: "DatabaseError"; data: string } | { type: "SerializationError"; data: string } | { type: "InvalidReference"; data: string } | { type: "NoRepositoryOpen" } | { type: "BranchNotFound"; data: string } | { type: "BranchNotMerged"; data: string } | { type: "FileNotFound"; data: string } | { type: "CannotFastForward" } | { type: "RebaseRequired" } | { type: "MergeConflict" } | { type: "CheckoutConflict"; data: string[] } | { type: "StashApplyConflict"; data: string[] } | { type: "AiServiceError"; data: string } | { type: "ApiKeyNotConfigured"; data: string } | { type: "DiffTooLarge"; data: number } | { type: "Other"; data: string } | { type: "IntegrationNotConnected"; data: string } | { type: "IntegrationError"; data: string } | { type: "OAuthError"; data: string } | { type: "OAuthCancelled" } | { type: "SshKeyError"; data: string } | { type: "SshKeyAlreadyExists"; data: string } | { type: "SshKeygenNotFound" } | { type: "InvalidKeyFilename"; data: string }
+/**
+ * A terminal found on this machine
+ */
+export type AvailableTerminal = { app: TerminalApp; 
+/**
+ * Display name, e.g. "Windows Terminal"
+ */
+name: string }
+export type AvatarResponse = { source: AvatarSource; path: string | null; 
+/**
+ * Inline image for generated avatars, which are never written to disk
+ */
+dataUrl?: string | null }
+export type AvatarSource = "Integration" | "Gravatar" | 
+/**
+ * Identicon generated from the email when no service has a picture
+ */
+"Generated" | "Default"
+export type AxisError = { type: "InvalidRepositoryPath"; data: string } | { type: "GitError"; data: string } | { type: "IoError"; data: string } | { type: "DatabaseError"; data: string } | { type: "SerializationError"; data: string } | { type: "InvalidReference"; data: string } | { type: "NoRepositoryOpen" } | { type: "BranchNotFound"; data: string } | { type: "BranchNotMerged"; data: string } | { type: "FileNotFound"; data: string } | { type: "CannotFastForward" } | { type: "RebaseRequired" } | { type: "MergeConflict" } | { type: "BinaryConflict"; data: string } | { type: "CheckoutConflict"; data: string[] } | { type: "ResetRequiresConfirmation"; data: string[] } | { type: "UnbornHead" } | { type: "StashApplyConflict"; data: string[] } | { type: "EmptyCommit" } | { type: "EmptyCommitMessage" } | { type: "CommitMessageInvalid"; data: CommitLintViolation[] } | { type: "AmendUnchanged" } | { type: "BrokenHead"; data: string } | { type: "ProtectedBranch"; data: { branch: string; reason: string } } | { type: "StaleLocalRef"; data: { refname: string; 
+/**
+ * OID the ref was expected to hold; `None` when it was expected not to exist
+ */
+expected: string | null; actual: string | null } } | { type: "PreconditionFailed"; data: { 
+/**
+ * Generation of the plan the preconditions were captured for
+ */
+generation: number; violations: PreconditionViolation[] } } | { type: "ShellAliasRequiresConfirmation"; data: { name: string; command: string } } | { type: "AlternateUnreachable"; data: { alternate: string; message: string } } | { type: "SyncClientInterference"; data: string } | { type: "HookTrustRequired"; data: HookTrustRequest } | { type: "AiServiceError"; data: string } | { type: "ApiKeyNotConfigured"; data: string } | { type: "ApiKeyRejected"; data: string } | { type: "AiModelNotFound"; data: string } | { type: "AiRateLimited"; data: string } | { type: "AiDaemonNotRunning"; data: string } | { type: "AiNetworkUnreachable"; data: string } | { type: "AiRequestCancelled" } | { type: "DiffTooLarge"; data: number } | { type: "FileTooLarge"; data: { path: string; size: number; limit: number } } | { type: "BinaryDiff"; data: string } | { type: "HunkNotFound"; data: number } | { type: "DiffPresetNotFound"; data: string } | { type: "BuiltInDiffPreset"; data: string } | { type: "InvalidSearchPattern"; data: string } | { type: "ConfigOverrideNotAllowed"; data: string } | { type: "BookmarkNotFound"; data: number } | { type: "Other"; data: string } | { type: "IntegrationNotConnected"; data: string } | { type: "IntegrationError"; data: string } | { type: "OAuthError"; data: string } | { type: "OAuthCancelled" } | { type: "SshKeyError"; data: string } | { type: "SshKeyAlreadyExists"; data: string } | { type: "SshKeygenNotFound" } | { type: "InvalidKeyFilename"; data: string } | { type: "SshPassphraseRequired"; data: { key_path: string } } | { type: "GpgKeyError"; data: string } | { type: "GpgNotFound" } | { type: "GpgAgentUnavailable"; data: string }
+/**
+ * A background fetch updated refs of a repository
+ */
+export type BackgroundFetchCompletedEvent = { path: string; 
+/**
+ * Remotes whose refs changed
+ */
+remotes: RemoteFetchOutcome[] }
+/**
+ * Background fetch settings for one repository
+ */
+export type BackgroundFetchConfig = { enabled: boolean; 
+/**
+ * Minutes between successful fetches
+ */
+intervalMinutes: number; 
+/**
+ * Prune remote-tracking branches that no longer exist on the remote
+ */
+prune: boolean; 
+/**
+ * Fetch every remote instead of only the current branch's upstream remote
+ */
+fetchAllRemotes: boolean }
+/**
+ * Result of one background fetch of a repository
+ */
+export type BackgroundFetchResult = { 
+/**
+ * Remotes that were fetched; remotes needing an interactive credential are left out
+ */
+remotes: RemoteFetchOutcome[]; 
+/**
+ * Error that prevented fetching any remote
+ */
+error: string | null }
+/**
+ * Background fetch state of a repository
+ */
+export type BackgroundFetchStatus = { repoPath: string; config: BackgroundFetchConfig; lastRun: string | null; lastResult: BackgroundFetchResult | null; 
+/**
+ * When the next fetch is due; `None` if disabled or not fetched yet this session
+ */
+nextRun: string | null; consecutiveFailures: number }
+/**
+ * What is known about a binary file change without reading its content
+ */
+export type BinaryDiffInfo = { 
+/**
+ * Size in bytes of the old side; `None` when it does not exist or cannot be read
+ */
+oldSize: number | null; 
+/**
+ * Size in bytes of the new side; `None` when it does not exist or cannot be read
+ */
+newSize: number | null; 
+/**
+ * Whether the file has an image extension, so both sides can be fetched with
+ * `get_file_blob` or `get_file_content_base64` and shown side by side
+ */
+isImage: boolean; 
+/**
+ * Both sides have the same blob, so only the file mode changed
+ */
+sameContent: boolean }
 /**
  * Mark type for bisect marking operations
  */
@@ -1505,7 +2463,15 @@ skippedCommits: string[];
 /**
  * The first bad commit (when bisect completes)
  */
-firstBadCommit: string | null }
+firstBadCommit: string | null; 
+/**
+ * Recorded bisect actions from `git bisect log`, oldest first
+ */
+log: string[] }
+/**
+ * A bisect mark moved the session to a new commit (or found the first bad one)
+ */
+export type BisectStepEvent = { path: string; state: BisectState }
 /**
  * A single line in a blame result
  */
@@ -1535,17 +2501,90 @@ timestamp: string;
  */
 content: string; 
 /**
- * Original line number in the commit
+ * Line number in the file as of the blamed commit
  */
 originalLine: number; 
 /**
  * Whether this is the first line of a group from the same commit
  */
 isGroupStart: boolean }
+/**
+ * What a blamed commit did to one line, for the blame popover
+ */
+export type BlameLineContext = { commit: Commit; 
+/**
+ * Path of the file in the blamed commit
+ */
+path: string; 
+/**
+ * Line number in the blamed commit's version of the file
+ */
+lineNumber: number; 
+/**
+ * Hunk of the commit's diff of this file containing, or nearest to, the line
+ */
+hunk: DiffHunk | null; 
+/**
+ * Path of the file in the parent; differs from `path` when the commit renamed it,
+ * `None` when the commit created the file
+ */
+previousPath: string | null; 
+/**
+ * The line as it read before the commit; `None` if the commit added it
+ */
+previousContent: string | null; 
+/**
+ * Parent to re-run blame at for "blame before this commit"
+ */
+parentOid: string | null; 
+/**
+ * Line in `previous_path` at `parent_oid` corresponding to this line
+ */
+previousLineNumber: number | null }
 /**
  * Blame information for a file
  */
 export type BlameResult = { path: string; lines: BlameLine[] }
+/**
+ * A pinned commit, file, comparison, or branch in a repository
+ */
+export type Bookmark = { 
+/**
+ * Database row ID
+ */
+id: number; repoPath: string; target: BookmarkTarget; label: string; notes: string | null; createdAt: string; 
+/**
+ * When the bookmark was last opened, `None` if never
+ */
+lastVisitedAt: string | null }
+/**
+ * Whether a bookmark's target still resolves
+ */
+export type BookmarkStatus = "Valid" | 
+/**
+ * The target was broken and has been pointed somewhere that resolves
+ */
+"Repaired" | "Broken"
+/**
+ * What a bookmark points at
+ */
+export type BookmarkTarget = 
+/**
+ * A commit, by OID
+ */
+{ Commit: string } | 
+/**
+ * A file as of a commit, branch, or tag
+ */
+{ FileAtRef: { path: string; ref: string } } | 
+/**
+ * A comparison between two revisions
+ */
+{ Compare: { from: string; to: string } } | 
+/**
+ * Whatever a local branch currently points at
+ */
+{ BranchHead: string }
 export type Branch = { name: string; fullName: string; branchType: BranchType; isHead: boolean; upstream: string | null; ahead: number | null; behind: number | null; targetOid: string; lastCommitSummary: string; lastCommitTime: string }
 /**
  * Result of comparing two branches
@@ -1591,1484 +2630,3211 @@ sort?: BranchSortOrder;
 /**
  * Maximum number of branches to return
  */
-limit: number | null }
+limit: number | null; 
+/**
+ * Leave `ahead` and `behind` unset instead of comparing each branch with its
+ * upstream; `get_branches` follows the repository's performance profile when unset
+ */
+skipAheadBehind?: boolean | null }
 export type BranchFilterType = "All" | "Current" | { Specific: string }
+/**
+ * Outcome of archiving or deleting one branch in a bulk operation
+ */
+export type BranchOperationResult = { name: string; success: boolean; 
+/**
+ * Tag created for the branch tip when archiving
+ */
+tagName: string | null; error: string | null }
 /**
  * Sort order for branch listing
  */
 export type BranchSortOrder = "Alphabetical" | "AlphabeticalDesc" | "LastCommitDate" | "LastCommitDateDesc"
 export type BranchType = "Local" | "Remote"
 /**
- * CI/CD run conclusion
+ * Ways out of a `BrokenHead` state
  */
-export type CIConclusion = "Success" | "Failure" | "Cancelled" | "Skipped" | "Neutral" | "TimedOut" | "ActionRequired"
+export type BrokenHeadAction = 
 /**
- * CI/CD workflow run
+ * Re-create the missing branch at its last known tip
  */
-export type CIRun = { provider: ProviderType; id: string; name: string; status: CIRunStatus; conclusion: CIConclusion | null; commitSha: string; branch: string | null; event: string; createdAt: string; updatedAt: string; url: string }
+"RecreateBranch" | 
 /**
- * CI/CD run status
+ * Point HEAD at an existing branch, keeping the working tree and index as they are
  */
-export type CIRunStatus = "Queued" | "InProgress" | "Completed"
+"SwitchBranch"
 /**
- * Options for checkout operations
+ * A recovery offered for a `BrokenHead` state, computed from the repository
  */
-export type CheckoutOptions = { 
+export type BrokenHeadRecovery = { action: BrokenHeadAction; 
 /**
- * Create a new branch if it doesn't exist
+ * Branch that HEAD will point to afterwards
  */
-create: boolean; 
+branch: string; 
 /**
- * Force checkout, discarding local changes
+ * Commit the re-created branch will point to
  */
-force: boolean; 
+oid: string | null }
 /**
- * Track the remote branch when creating
+ * One tag to create in `tag_create_bulk`
  */
-track: string | null }
+export type BulkTagSpec = { name: string; 
 /**
- * Options for cherry-pick operations
+ * Commit or ref to tag; takes precedence over `options.target`
  */
-export type CherryPickOptions = { 
+target: string; options?: CreateTagOptions }
 /**
- * Commit(s) to cherry-pick
+ * CI/CD run conclusion
  */
-commits: string[]; 
+export type CIConclusion = "Success" | "Failure" | "Cancelled" | "Skipped" | "Neutral" | "TimedOut" | "ActionRequired"
 /**
- * If true, don't create commits (stage changes only)
+ * CI/CD workflow run
  */
-noCommit: boolean; 
+export type CIRun = { provider: ProviderType; id: string; name: string; status: CIRunStatus; conclusion: CIConclusion | null; commitSha: string; branch: string | null; event: string; createdAt: string; updatedAt: string; url: string }
 /**
- * If true, allow empty commits
+ * CI/CD run status
  */
-allowEmpty: boolean }
+export type CIRunStatus = "Queued" | "InProgress" | "Completed"
 /**
- * Result of a cherry-pick operation
+ * Changelog of the commits between two refs
  */
-export type CherryPickResult = { 
+export type Changelog = { title: string; 
 /**
- * Whether the cherry-pick was successful
+ * Breaking entries, also listed in their own sections
  */
-success: boolean; 
+breakingChanges: ChangelogEntry[]; 
 /**
- * New commit OIDs created
+ * Sections in the configured order, empty ones left out, "Other" last
  */
-commitOids: string[]; 
+groups: ChangelogGroup[]; markdown: string }
 /**
- * Conflicted files if cherry-pick has conflicts
+ * One commit in a generated changelog
  */
-conflicts: ConflictedFile[]; 
+export type ChangelogEntry = { oid: string; shortOid: string; 
 /**
- * Informational message
+ * Conventional type; `None` for commits that do not follow the convention
  */
-message: string }
+commitType: string | null; scope: string | null; 
 /**
- * Paginated CI runs response
+ * Header description, or the whole summary of a non-conventional commit
  */
-export type CiRunsPage = { runs: CIRun[]; hasMore: boolean }
-export type Commit = { oid: string; shortOid: string; message: string; summary: string; author: Signature; committer: Signature; parentOids: string[]; timestamp: string; isMerge: boolean; 
+description: string; 
 /**
- * Signature info if the commit is signed
+ * Marked with `!` or a `BREAKING CHANGE` footer
  */
-signature: CommitSignature | null }
+breaking: boolean; 
 /**
- * A reference (branch or tag) pointing to a commit
+ * Text of the `BREAKING CHANGE` footer
  */
-export type CommitRef = { name: string; refType: RefType; isHead: boolean }
+breakingNote: string | null; 
 /**
- * Information about a commit's cryptographic signature
+ * Issue numbers referenced as `#123` in the header or in `Fixes`/`Closes` trailers
  */
-export type CommitSignature = { 
+issues: number[]; author: string; 
 /**
- * The type of signature (GPG or SSH), None if unknown
+ * Filled only when `ChangelogOptions::include_co_authors` is set
  */
-format: SigningFormat | null }
+coAuthors: CoAuthor[]; 
 /**
- * Commit status with all checks
+ * Commit page on the provider, when links were requested and the host is known
  */
-export type CommitStatus = { state: CommitStatusState; checks: CIRun[]; totalCount: number }
+commitUrl: string | null; isMerge: boolean }
 /**
- * Combined commit status
+ * Entries under one changelog heading
  */
-export type CommitStatusState = "Pending" | "Success" | "Failure" | "Error"
+export type ChangelogGroup = { heading: string; 
 /**
- * Three-way content for conflict resolution
+ * Type the section collects; `None` for the "Other" section
  */
-export type ConflictContent = { 
+commitType: string | null; entries: ChangelogEntry[] }
 /**
- * Path to the file
+ * Options for `generate_changelog`
  */
-path: string; 
+export type ChangelogOptions = { 
 /**
- * Base (ancestor) content
+ * Heading of the release; `to_ref` when not set
  */
-base: string | null; 
+title?: string | null; 
 /**
- * Ours (current branch) content
+ * Link commits and issues to the web page of the repository's main remote
  */
-ours: string | null; 
+includeCommitLinks?: boolean; 
 /**
- * Theirs (incoming) content
+ * Credit `Co-authored-by` trailers next to each entry
  */
-theirs: string | null; 
+includeCoAuthors?: boolean; 
 /**
- * Current working tree content with conflict markers
+ * Leave merge commits out; the commits they merged are listed on their own
  */
-merged: string }
+collapseMerges?: boolean }
 /**
- * Which version to use when resolving a conflict
+ * Changelog heading used for commits of one conventional type
  */
-export type ConflictResolution = 
+export type ChangelogSection = { commitType: string; heading: string }
 /**
- * Use our version
+ * Options for checkout operations
  */
-"Ours" | 
+export type CheckoutOptions = { 
 /**
- * Use their version
+ * Create a new branch if it doesn't exist
  */
-"Theirs" | 
+create: boolean; 
 /**
- * Use custom merged content
+ * Force checkout, discarding local changes
  */
-"Merged"
+force: boolean; 
 /**
- * Type of conflict
+ * Track the remote branch when creating
  */
-export type ConflictType = 
+track: string | null }
 /**
- * Content conflict (both sides modified)
+ * Options for cherry-pick operations
  */
-"Content" | 
+export type CherryPickOptions = { 
 /**
- * File deleted on one side, modified on other
+ * Commit(s) to cherry-pick, in the order they are applied
  */
-"DeleteModify" | 
+commits: string[]; 
 /**
- * File added on both sides with different content
+ * Range expression (e.g. `abc123..def456`) expanded oldest-first; used instead of `commits`
  */
-"AddAdd" | 
+range?: string | null; 
 /**
- * File renamed differently on both sides
+ * Parent number (1-based) to diff against when a picked commit is a merge
  */
-"RenameRename" | 
+mainline?: number | null; 
 /**
- * File renamed and modified
+ * If true, don't create commits (stage changes only)
  */
-"RenameModify" | 
+noCommit: boolean; 
 /**
- * Binary file conflict
+ * If true, allow empty commits
  */
-"Binary"
+allowEmpty: boolean; 
 /**
- * Information about a conflicted file
+ * If true, picks that would be empty (already applied) are skipped instead of stopping
  */
-export type ConflictedFile = { 
+skipEmpty?: boolean }
 /**
- * Path to the conflicted file
+ * Preview of cherry-picking a list of commits onto HEAD, computed without touching
+ * the working tree
  */
-path: string; 
+export type CherryPickPreview = { 
 /**
- * Type of conflict
+ * Commits that would be picked, in order
  */
-conflictType: ConflictType; 
+commits: Commit[]; 
 /**
- * Whether the file has been resolved
+ * Paths expected to conflict, in the order they are first hit
  */
-isResolved: boolean }
+estimatedConflicts: string[] }
 /**
- * Options for branch creation
+ * Result of a cherry-pick operation
  */
-export type CreateBranchOptions = { 
+export type CherryPickResult = { 
 /**
- * The starting point (commit/branch/tag). If None, uses HEAD.
+ * Whether the cherry-pick was successful
  */
-startPoint: string | null; 
+success: boolean; 
 /**
- * Force creation even if branch exists
+ * New commit OIDs created
  */
-force: boolean; 
+commitOids: string[]; 
 /**
- * Set up tracking for an upstream branch
+ * Conflicted files if cherry-pick has conflicts
  */
-track: string | null }
+conflicts: ConflictedFile[]; 
 /**
- * Options for creating an issue
+ * Informational message
  */
-export type CreateIssueOptions = { title: string; body: string | null; labels: string[]; assignees: string[] }
+message: string; 
 /**
- * Options for creating patches
+ * Source commits skipped because picking them would have been empty
  */
-export type CreatePatchOptions = { 
+skipped?: string[] }
 /**
- * The commit SHA to create patch from (None = staged changes)
+ * A failing check surfaced in the rollup
  */
-commitOid: string | null; 
+export type CiFailingCheck = { name: string; url: string; 
 /**
- * Output directory for patch files
+ * Workflow run that produced the check, when it can be re-run
  */
-outputDir: string }
+runId: string | null }
 /**
- * Options for creating a pull request
+ * Aggregated CI status for the current branch's head commit
  */
-export type CreatePrOptions = { title: string; body: string | null; sourceBranch: string; targetBranch: string; draft: boolean; labels: string[] }
+export type CiRollup = { branch: string | null; sha: string; state: CiRollupState; passed: number; failed: number; running: number; 
 /**
- * Options for creating a tag
+ * Skipped or neutral checks
  */
-export type CreateTagOptions = { 
+skipped: number; 
 /**
- * Target ref/commit (default: HEAD)
+ * Up to `CI_ROLLUP_MAX_FAILING` failing checks
  */
-target: string | null; 
+failingChecks: CiFailingCheck[] }
 /**
- * Create an annotated tag
+ * Overall CI state for a branch tip
  */
-annotated: boolean; 
+export type CiRollupState = 
 /**
- * Tag message (required for annotated tags)
+ * Every finished check passed
  */
-message: string | null; 
+"Passing" | 
 /**
- * Force overwrite existing tag
+ * At least one check failed
  */
-force: boolean }
+"Failing" | 
 /**
- * Custom action definition
+ * Nothing failed yet but some checks are still queued or running
  */
-export type CustomAction = { 
+"Running" | 
 /**
- * Unique identifier (UUID)
+ * The provider reports no checks for the tip
  */
-id: string; 
+"NoChecks" | 
 /**
- * Display name shown in menus
+ * The local tip is not on the remote yet, so it has no CI of its own
  */
-name: string; 
+"NotPushed" | 
 /**
- * Optional description/tooltip
+ * The provider's API rate limit was hit; try again later
  */
-description: string | null; 
+"RateLimited"
 /**
- * Shell command to execute (supports variable substitution)
+ * Paginated CI runs response
  */
-command: string; 
+export type CiRunsPage = { runs: CIRun[]; hasMore: boolean }
 /**
- * Working directory (defaults to repo path)
+ * A co-author credited with a `Co-authored-by` trailer
  */
-workingDir: string | null; 
+export type CoAuthor = { name: string; email: string }
+export type Commit = { oid: string; shortOid: string; message: string; summary: string; author: Signature; committer: Signature; parentOids: string[]; timestamp: string; isMerge: boolean; 
 /**
- * Contexts where this action appears
+ * Signature info if the commit is signed
  */
-contexts: ActionContext[]; 
+signature: CommitSignature | null }
 /**
- * Keyboard shortcut (e.g., "mod+shift+1")
+ * One page of commit history
  */
-shortcut: string | null; 
+export type CommitHistoryPage = { commits: Commit[]; 
 /**
- * Show confirmation dialog before execution
+ * Opaque cursor for the next page, `None` once history is exhausted
  */
-confirm: boolean; 
+nextCursor: string | null; 
 /**
- * Custom confirmation message
+ * Commits the walk visited before filtering
  */
-confirmMessage: string | null; 
+scanned: number; 
 /**
- * Show output dialog after execution
+ * Commits that passed the filters, across all pages
  */
-showOutput: boolean; 
+matched: number }
 /**
- * Whether action is enabled
+ * Commit message validation settings
  */
-enabled: boolean; 
+export type CommitLintConfig = { enabled: boolean; 
 /**
- * Sort order for menu display
+ * Reject commits whose message has error-level violations
  */
-order: number; 
+enforceOnCommit: boolean; 
 /**
- * Storage type (global or repository)
+ * Rules, overridden by those in the repository's `.commitlintrc.json`
  */
-storage?: ActionStorageType | null }
+rules: CommitLintRules }
 /**
- * Options for branch deletion
+ * Commit message rules, named after their commitlint equivalents
  */
-export type DeleteBranchOptions = { 
+export type CommitLintRuleId = 
 /**
- * Force deletion even if not fully merged
+ * The subject starts with one of the configured types
  */
-force: boolean; 
+"type-enum" | 
 /**
- * Delete the remote tracking branch as well
+ * The scope, when present, matches the configured pattern
  */
-deleteRemote: boolean }
+"scope-pattern" | 
 /**
- * Detected provider from remote URL
+ * The subject line is not longer than the configured length
  */
-export type DetectedProvider = { provider: ProviderType; owner: string; repo: string }
+"subject-max-length" | 
 /**
- * A hunk within a diff (a contiguous block of changes)
+ * No body line is longer than the configured length
  */
-export type DiffHunk = { header: string; oldStart: number; oldLines: number; newStart: number; newLines: number; lines: DiffLine[] }
+"body-max-line-length" | 
 /**
- * A single line within a diff hunk
+ * The subject does not end with a period
  */
-export type DiffLine = { lineType: DiffLineType; content: string; oldLineNo: number | null; newLineNo: number | null }
-export type DiffLineType = "Context" | "Addition" | "Deletion" | "Header" | "Binary"
+"subject-full-stop" | 
 /**
- * Options for generating diffs
+ * A blank line separates the subject from the body
  */
-export type DiffOptions = { 
+"body-leading-blank"
 /**
- * Number of context lines around changes
+ * Levels and values of the commit message rules
  */
-contextLines: number | null; 
+export type CommitLintRules = { typeEnum: RuleLevel; 
 /**
- * Ignore whitespace changes
+ * Allowed types; the Conventional Commits types by default
  */
-ignoreWhitespace: boolean | null; 
+types: string[]; scopePattern: RuleLevel; 
 /**
- * Ignore whitespace at end of line
+ * Regex the whole scope must match; any scope is accepted when unset
  */
-ignoreWhitespaceEol: boolean | null }
+scopeRegex: string | null; subjectMaxLength: RuleLevel; 
 /**
- * The type of change for a file in a diff
+ * Longest subject line, in characters
  */
-export type DiffStatus = "Added" | "Deleted" | "Modified" | "Renamed" | "Copied" | "TypeChanged" | "Untracked" | "Conflicted"
+maxSubjectLength: number; bodyMaxLineLength: RuleLevel; 
 /**
- * Types of diffs we can generate
+ * Longest body line, in characters
  */
-export type DiffTarget = 
+maxBodyLineLength: number; subjectFullStop: RuleLevel; bodyLeadingBlank: RuleLevel }
 /**
- * Diff between working directory and index (unstaged changes)
+ * A rule the commit message breaks. Lines and columns are 1-based and count
+ * characters, not bytes.
  */
-"WorkdirToIndex" | 
+export type CommitLintViolation = { rule: CommitLintRuleId; severity: LintSeverity; message: string; line: number; column: number; 
 /**
- * Diff between index and HEAD (staged changes)
+ * Characters covered, starting at `column`
  */
-"IndexToHead" | 
+length: number }
 /**
- * Diff between working directory and HEAD (all uncommitted changes)
+ * Commits analyzed so far by `analyze_commit_messages`; pass `operation_id` to
+ * `cancel_operation` to stop it
  */
-"WorkdirToHead" | 
+export type CommitMessageAnalysisProgressEvent = { operationId: string; analyzed: number }
 /**
- * Diff between two commits
+ * A commit whose message breaks the rules, for drilling down from the totals
  */
-{ CommitToCommit: { from: string; to: string } } | 
+export type CommitMessageOffender = { oid: string; summary: string; violations: CommitLintViolation[] }
 /**
- * Diff for a single commit (commit vs its parent)
+ * Commit message hygiene across a range of history. Merge, revert and fixup
+ * commits are counted but not linted, like commitlint's default ignores.
  */
-{ Commit: { oid: string } }
+export type CommitMessageStats = { 
 /**
- * Type of edge in the commit graph
+ * Commits that passed the filters
  */
-export type EdgeType = 
+totalCommits: number; 
 /**
- * Straight line (same lane)
+ * Commits checked against the rules
  */
-"Straight" | 
+lintedCommits: number; 
 /**
- * Merge edge (coming from a different lane)
+ * Linted commits without any violation
  */
-"Merge" | 
+conformingCommits: number; 
 /**
- * Branch edge (going to a different lane)
+ * Rules broken at least once, most broken first
  */
-"Branch" | 
+ruleViolations: RuleViolationCount[]; subjectLength: SubjectLengthStats; 
 /**
- * Merge preview edge (dashed line showing merge source)
+ * Commits with a body after the subject
  */
-"MergePreview"
+withBody: number; 
 /**
- * Options for exporting an SSH key
+ * Commits whose message matches one of the issue patterns
  */
-export type ExportSshKeyOptions = { 
+withIssueReference: number; merges: number; reverts: number; 
 /**
- * Path to the key to export
+ * `fixup!`, `squash!` and `amend!` commits
  */
-keyPath: string; 
+fixups: number; 
 /**
- * Target directory to export to
+ * Oldest period first
  */
-targetDir: string; 
+trend: StatsTrendBucket[]; 
 /**
- * Export only the public key
+ * Linted commits with the most errors, then the most violations
  */
-publicOnly: boolean }
+worstOffenders: CommitMessageOffender[]; 
 /**
- * Options for fetch operations
+ * The walk stopped at the limit before the end of the range
  */
-export type FetchOptions = { 
+truncated: boolean; 
 /**
- * Prune remote tracking branches that no longer exist
+ * Served from the cache of earlier analyses
  */
-prune: boolean; 
+cached: boolean }
 /**
- * Fetch tags
+ * Which commits `analyze_commit_messages` reads and how it reports them
  */
-tags: boolean; 
+export type CommitMessageStatsOptions = { 
 /**
- * Depth for shallow fetch (None for full fetch)
+ * Start from a specific ref (overrides `branch_filter`)
  */
-depth: number | null }
+fromRef: string | null; branchFilter?: BranchFilterType; includeRemotes?: boolean; 
 /**
- * Progress information for fetch operations
+ * Only commits made at or after this time, in seconds since the epoch
  */
-export type FetchProgress = { totalObjects: number; indexedObjects: number; receivedObjects: number; localObjects: number; totalDeltas: number; indexedDeltas: number; receivedBytes: number }
+since: number | null; 
 /**
- * Result of a fetch operation
+ * Only commits made at or before this time, in seconds since the epoch
  */
-export type FetchResult = { remote: string; updatedRefs: UpdatedRef[]; stats: FetchProgress }
+until: number | null; 
 /**
- * Represents a complete diff for a file
+ * Case-insensitive substring of the author's name or email
  */
-export type FileDiff = { oldPath: string | null; newPath: string | null; oldOid: string | null; newOid: string | null; status: DiffStatus; binary: boolean; hunks: DiffHunk[]; 
+author: string | null; 
 /**
- * Summary statistics
+ * Most commits analyzed; `COMMIT_STATS_DEFAULT_LIMIT` when unset and never
+ * more than `COMMIT_STATS_MAX_LIMIT`
  */
-additions: number; deletions: number }
+limit: number | null; bucket?: StatsBucket; 
 /**
- * Options for file history (log for specific files)
+ * Regexes that mark an issue reference; `DEFAULT_ISSUE_PATTERNS` when unset
  */
-export type FileLogOptions = { 
+issuePatterns: string[] | null; 
 /**
- * Paths to filter commits by
+ * Most entries in `worst_offenders`; `COMMIT_STATS_DEFAULT_OFFENDERS` when unset
  */
-paths: string[]; 
+maxOffenders: number | null }
 /**
- * Maximum number of commits to return
+ * Shape of a generated commit message
  */
-limit: number | null; 
+export type CommitMessageStyle = 
 /**
- * Number of commits to skip (for pagination)
+ * `type(scope): description` per the Conventional Commits spec
  */
-skip: number | null; 
+"Conventional" | 
 /**
- * Follow file renames
+ * A single imperative subject line
  */
-followRenames?: boolean }
+"Plain" | 
 /**
- * Result of file history query
+ * Subject line plus a body explaining what changed and why
  */
-export type FileLogResult = { 
+"Detailed"
 /**
- * Commits that modified the specified files
+ * A reference (branch or tag) pointing to a commit
  */
-commits: Commit[]; 
+export type CommitRef = { name: string; refType: RefType; isHead: boolean }
 /**
- * Whether there are more commits
+ * Information about a commit's cryptographic signature
  */
-hasMore: boolean }
-export type FileStatus = { path: string; status: StatusType; stagedStatus: StatusType | null; unstagedStatus: StatusType | null; isConflict: boolean; oldPath: string | null }
+export type CommitSignature = { 
 /**
- * Files in the repository changed
+ * The type of signature (GPG or SSH), None if unknown
  */
-export type FilesChangedEvent = { paths: string[] }
+format: SigningFormat | null }
 /**
- * Options for format-patch (creating patches from commit range)
+ * Signature verification for one commit of a batch
  */
-export type FormatPatchOptions = { 
+export type CommitSignatureResult = { oid: string; 
 /**
- * Range specification (e.g., "HEAD~3", "main..feature", "abc123")
+ * `None` if the commit is unsigned or could not be read
  */
-range: string; 
+verification: SignatureVerification | null }
 /**
- * Output directory for patch files
+ * Commit status with all checks
  */
-outputDir: string }
-export type GenerateCommitMessageResponse = { message: string; modelUsed: string }
-export type GeneratePrDescriptionResponse = { title: string; body: string; labels: string[]; modelUsed: string }
+export type CommitStatus = { state: CommitStatusState; checks: CIRun[]; totalCount: number }
 /**
- * Options for generating a new SSH key
+ * Combined commit status
+ */
+export type CommitStatusState = "Pending" | "Success" | "Failure" | "Error"
+/**
+ * A `Key: value` trailer at the end of a commit message
+ */
+export type CommitTrailer = { key: string; value: string }
+/**
+ * Trailers to add to a commit message
+ */
+export type CommitTrailerOptions = { 
+/**
+ * Append `Signed-off-by` for the committer
+ */
+signOff?: boolean; 
+/**
+ * Append a `Co-authored-by` trailer for each co-author
+ */
+coAuthors?: CoAuthor[]; 
+/**
+ * Arbitrary trailers, added before co-authors and sign-off
+ */
+trailers?: CommitTrailer[] }
+/**
+ * A git alias (`alias.<name>`) from the config
+ */
+export type ConfigAlias = { name: string; 
+/**
+ * The configured value, e.g. `log --graph --oneline` or `!git fetch && git rebase`
+ */
+expansion: string; level: ConfigLevel; 
+/**
+ * Runs through the shell (`!` prefix) and can do anything, so it needs confirmation
+ */
+isShell: boolean; 
+/**
+ * Git arguments of a non-shell alias, split as git splits them
+ */
+args: string[]; 
+/**
+ * Refers to positional parameters (`$1`, `$@`), so it expects arguments
+ */
+usesPositionalArgs: boolean; 
+/**
+ * Axis feature to prefer over running the alias
+ */
+nativeEquivalent: NativeCommand | null; 
+/**
+ * May change the repository, so views should refresh after it runs
+ */
+refreshAfter: boolean; 
+/**
+ * Overridden by a definition at a more specific level
+ */
+shadowed: boolean }
+/**
+ * A config entry and the file it was read from, like `git config --list --show-origin`
+ */
+export type ConfigEntry = { key: string; value: string; level: ConfigLevel; 
+/**
+ * `file:<path>` of the config file for the level
+ */
+origin: string }
+/**
+ * Git config file an entry comes from, from least to most specific
+ */
+export type ConfigLevel = 
+/**
+ * System-wide, e.g. /etc/gitconfig
+ */
+"System" | 
+/**
+ * User-wide, ~/.gitconfig or ~/.config/git/config
+ */
+"Global" | 
+/**
+ * Repository, .git/config
+ */
+"Local" | 
+/**
+ * Worktree, .git/config.worktree
+ */
+"Worktree" | 
+/**
+ * Set by the application for this process
+ */
+"App"
+/**
+ * Three-way content for conflict resolution
+ */
+export type ConflictContent = { 
+/**
+ * Path to the file
+ */
+path: string; 
+/**
+ * Base (ancestor) content
+ */
+base: string | null; 
+/**
+ * Ours (current branch) content
+ */
+ours: string | null; 
+/**
+ * Theirs (incoming) content
+ */
+theirs: string | null; 
+/**
+ * Current working tree content with conflict markers
+ */
+merged: string }
+/**
+ * A region of a conflicted file, as produced by a three-way merge of its index stages
+ */
+export type ConflictHunk = 
+/**
+ * Lines that merged cleanly
+ */
+{ Context: { 
+/**
+ * Merged lines
+ */
+lines: string[] } } | 
+/**
+ * Lines where both sides changed the same region
+ */
+{ Conflict: { 
+/**
+ * Index of this conflict block within the file (0-based)
+ */
+index: number; 
+/**
+ * Ours (current branch) lines
+ */
+ours: string[]; 
+/**
+ * Theirs (incoming) lines
+ */
+theirs: string[]; 
+/**
+ * Base (ancestor) lines
+ */
+base: string[] } }
+/**
+ * How to resolve a single conflict block
+ */
+export type ConflictHunkChoice = 
+/**
+ * Keep our lines
+ */
+"Ours" | 
+/**
+ * Keep their lines
+ */
+"Theirs" | 
+/**
+ * Keep both, ours first
+ */
+"BothOursFirst" | 
+/**
+ * Keep both, theirs first
+ */
+"BothTheirsFirst" | 
+/**
+ * Replace the block with custom text
+ */
+{ Custom: { 
+/**
+ * Replacement text
+ */
+content: string } }
+/**
+ * Which version to use when resolving a conflict
+ */
+export type ConflictResolution = 
+/**
+ * Use our version
+ */
+"Ours" | 
+/**
+ * Use their version
+ */
+"Theirs" | 
+/**
+ * Use custom merged content
+ */
+"Merged"
+/**
+ * Type of conflict
+ */
+export type ConflictType = 
+/**
+ * Content conflict (both sides modified)
+ */
+"Content" | 
+/**
+ * File deleted on one side, modified on other
+ */
+"DeleteModify" | 
+/**
+ * File added on both sides with different content
+ */
+"AddAdd" | 
+/**
+ * File renamed differently on both sides
+ */
+"RenameRename" | 
+/**
+ * File renamed and modified
+ */
+"RenameModify" | 
+/**
+ * Binary file conflict
+ */
+"Binary"
+/**
+ * Information about a conflicted file
+ */
+export type ConflictedFile = { 
+/**
+ * Path to the conflicted file
+ */
+path: string; 
+/**
+ * Type of conflict
+ */
+conflictType: ConflictType; 
+/**
+ * Whether the file has been resolved
+ */
+isResolved: boolean }
+/**
+ * Options for branch creation
+ */
+export type CreateBranchOptions = { 
+/**
+ * The starting point (commit/branch/tag). If None, uses HEAD.
+ */
+startPoint: string | null; 
+/**
+ * Force creation even if branch exists
+ */
+force: boolean; 
+/**
+ * Set up tracking for an upstream branch
+ */
+track: string | null }
+/**
+ * Options for creating an issue
+ */
+export type CreateIssueOptions = { title: string; body: string | null; labels: string[]; assignees: string[] }
+/**
+ * Options for creating patches
+ */
+export type CreatePatchOptions = { 
+/**
+ * The commit SHA to create patch from (None = staged changes)
+ */
+commitOid: string | null; 
+/**
+ * Output directory for patch files
+ */
+outputDir: string }
+/**
+ * Options for creating a pull request
+ */
+export type CreatePrOptions = { title: string; body: string | null; sourceBranch: string; targetBranch: string; draft: boolean; labels: string[] }
+/**
+ * Options for creating a tag
+ */
+export type CreateTagOptions = { 
+/**
+ * Target ref/commit (default: HEAD)
+ */
+target: string | null; 
+/**
+ * Create an annotated tag
+ */
+annotated: boolean; 
+/**
+ * Tag message (required for annotated tags)
+ */
+message: string | null; 
+/**
+ * Force overwrite existing tag
+ */
+force: boolean }
+/**
+ * A host with a stored HTTPS credential; the token never leaves the backend
+ */
+export type CredentialHost = { 
+/**
+ * Host name, with the port when it is not the default
+ */
+host: string; 
+/**
+ * Username sent with the token
+ */
+username: string }
+/**
+ * Pull request opened from the checked-out branch, or what the "create pull request"
+ * action needs when there is none
+ */
+export type CurrentBranchPullRequest = { provider: ProviderType; 
+/**
+ * Repository the pull request was found in, or the one a new pull request would target
+ */
+owner: string; repo: string; 
+/**
+ * `None` on a detached HEAD
+ */
+branch: string | null; pullRequest: PullRequest | null; 
+/**
+ * Local commits missing from the pull request head; `None` without a pull request or
+ * when its head commit has not been fetched
+ */
+ahead: number | null; 
+/**
+ * Pull request head commits missing locally
+ */
+behind: number | null; 
+/**
+ * Upstream of the local branch, e.g. `origin/feature`
+ */
+upstream: string | null; 
+/**
+ * Whether the branch exists on a remote, required before a pull request can be created
+ */
+pushed: boolean }
+/**
+ * The pull request for the checked-out branch was re-matched after HEAD moved
+ */
+export type CurrentBranchPullRequestEvent = { path: string; current: CurrentBranchPullRequest }
+/**
+ * Custom action definition
+ */
+export type CustomAction = { 
+/**
+ * Unique identifier (UUID)
+ */
+id: string; 
+/**
+ * Display name shown in menus
+ */
+name: string; 
+/**
+ * Optional description/tooltip
+ */
+description: string | null; 
+/**
+ * Shell command to execute (supports variable substitution)
+ */
+command: string; 
+/**
+ * Working directory (defaults to repo path)
+ */
+workingDir: string | null; 
+/**
+ * Contexts where this action appears
+ */
+contexts: ActionContext[]; 
+/**
+ * Keyboard shortcut (e.g., "mod+shift+1")
+ */
+shortcut: string | null; 
+/**
+ * Show confirmation dialog before execution
+ */
+confirm: boolean; 
+/**
+ * Custom confirmation message
+ */
+confirmMessage: string | null; 
+/**
+ * Show output dialog after execution
+ */
+showOutput: boolean; 
+/**
+ * Whether action is enabled
+ */
+enabled: boolean; 
+/**
+ * Sort order for menu display
+ */
+order: number; 
+/**
+ * Storage type (global or repository)
+ */
+storage?: ActionStorageType | null }
+/**
+ * A line of output from a running custom action
+ */
+export type CustomActionOutputEvent = { actionId: string; stream: ActionOutputStream; line: string }
+/**
+ * Options for branch deletion
+ */
+export type DeleteBranchOptions = { 
+/**
+ * Force deletion even if not fully merged
+ */
+force: boolean; 
+/**
+ * Delete the remote tracking branch as well
+ */
+deleteRemote: boolean }
+/**
+ * Options for `tag_delete_by_pattern`
+ */
+export type DeleteTagsByPatternOptions = { 
+/**
+ * Only list the matching tags so the user can confirm; nothing is deleted
+ */
+confirmRequired?: boolean; 
+/**
+ * Remotes to delete the matching tags from as well
+ */
+remotes?: string[] }
+/**
+ * Result of `tag_delete_by_pattern`
+ */
+export type DeleteTagsByPatternResult = { 
+/**
+ * Local tags matching the pattern, in natural order
+ */
+matched: string[]; 
+/**
+ * Whether a deletion was attempted (false when only confirming)
+ */
+deleted: boolean; 
+/**
+ * Local results first, then one result per tag and remote
+ */
+results: TagOperationResult[] }
+/**
+ * Detected provider from remote URL
+ */
+export type DetectedProvider = { provider: ProviderType; owner: string; repo: string }
+/**
+ * Where a diff is shown; each context has its own default preset
+ */
+export type DiffContext = 
+/**
+ * Working tree and staged changes
+ */
+"Workdir" | 
+/**
+ * Commit and commit range diffs
+ */
+"Commit" | "Blame"
+/**
+ * A hunk within a diff (a contiguous block of changes)
+ */
+export type DiffHunk = { header: string; oldStart: number; oldLines: number; newStart: number; newLines: number; lines: DiffLine[]; 
+/**
+ * Enclosing function or type declaration of the first change, found with
+ * per-language patterns when libgit2's header context is empty or generic
+ */
+context?: string | null }
+/**
+ * A single line within a diff hunk
+ */
+export type DiffLine = { lineType: DiffLineType; content: string; oldLineNo: number | null; newLineNo: number | null; 
+/**
+ * Changed and unchanged parts of a paired addition or deletion, when word
+ * diffs were requested and the line is similar enough to its partner
+ */
+inlineSpans?: InlineSpan[] | null }
+export type DiffLineType = "Context" | "Addition" | "Deletion" | "Header" | "Binary"
+/**
+ * Options for generating diffs
+ */
+export type DiffOptions = { 
+/**
+ * Number of context lines around changes
+ */
+contextLines: number | null; 
+/**
+ * Ignore whitespace changes
+ */
+ignoreWhitespace: boolean | null; 
+/**
+ * Ignore whitespace at end of line
+ */
+ignoreWhitespaceEol: boolean | null; 
+/**
+ * Mark the changed parts of paired additions and deletions
+ */
+computeWordDiff?: boolean | null; 
+/**
+ * Extensions (without the dot, case-insensitive) of binary files the viewer can
+ * render as images; `DEFAULT_IMAGE_EXTENSIONS` when unset
+ */
+imageExtensions?: string[] | null; 
+/**
+ * Fill `DiffHunk::context` with the enclosing function of each hunk
+ */
+functionContext?: boolean | null; 
+/**
+ * Diff `.ipynb` notebooks cell by cell, and files with a
+ * `structured_json_extensions` extension as canonical JSON
+ */
+structuredDiff?: boolean | null; 
+/**
+ * Outputs in notebook diffs; `Summarize` when unset
+ */
+notebookOutputs?: NotebookOutputMode | null; 
+/**
+ * Extensions (without the dot, case-insensitive) of JSON files diffed structurally
+ */
+structuredJsonExtensions?: string[] | null }
+/**
+ * Named diff settings that can be applied in one step
+ */
+export type DiffPreset = { name: string; options: DiffOptions; 
+/**
+ * Collapse generated files such as lockfiles
+ */
+collapseGenerated?: boolean; 
+/**
+ * Highlight changed words within modified lines
+ */
+intraLine?: boolean; 
+/**
+ * Seeded presets can be edited but not deleted
+ */
+builtIn?: boolean }
+/**
+ * The type of change for a file in a diff
+ */
+export type DiffStatus = "Added" | "Deleted" | "Modified" | "Renamed" | "Copied" | "TypeChanged" | "Untracked" | "Conflicted"
+/**
+ * Types of diffs we can generate
+ */
+export type DiffTarget = 
+/**
+ * Diff between working directory and index (unstaged changes)
+ */
+"WorkdirToIndex" | 
+/**
+ * Diff between index and HEAD (staged changes)
+ */
+"IndexToHead" | 
+/**
+ * Diff between working directory and HEAD (all uncommitted changes)
+ */
+"WorkdirToHead" | 
+/**
+ * Diff between two commits
+ */
+{ CommitToCommit: { from: string; to: string } } | 
+/**
+ * Diff for a single commit (commit vs its parent)
+ */
+{ Commit: { oid: string } }
+/**
+ * Type of edge in the commit graph
+ */
+export type EdgeType = 
+/**
+ * Straight line (same lane)
+ */
+"Straight" | 
+/**
+ * Merge edge (coming from a different lane)
+ */
+"Merge" | 
+/**
+ * Branch edge (going to a different lane)
+ */
+"Branch" | 
+/**
+ * Merge preview edge (dashed line showing merge source)
+ */
+"MergePreview"
+/**
+ * Avatar resolved for one email of a batch
+ */
+export type EmailAvatar = ({ source: AvatarSource; path: string | null; 
+/**
+ * Inline image for generated avatars, which are never written to disk
+ */
+dataUrl?: string | null }) & { email: string }
+/**
+ * Tip a branch is expected to still have when a bulk operation runs, e.g. the
+ * `target_oid` of a `StaleBranch` the user picked
+ */
+export type ExpectedBranchTarget = { name: string; targetOid: string }
+export type ExplainDiffResponse = { explanation: string; modelUsed: string; 
+/**
+ * Served from the in-memory cache without querying the model
+ */
+cached: boolean }
+/**
+ * The part of a diff to explain: one file, or one hunk of it
+ */
+export type ExplainDiffScope = { path: string; 
+/**
+ * Explain the change `path` got in this commit instead of the working tree
+ */
+commitOid?: string | null; 
+/**
+ * Explain the staged change rather than the unstaged one; ignored with `commit_oid`
+ */
+staged?: boolean; 
+/**
+ * Explain only this hunk of the file diff
+ */
+hunkIndex?: number | null }
+/**
+ * Options for exporting an SSH key
+ */
+export type ExportSshKeyOptions = { 
+/**
+ * Path to the key to export
+ */
+keyPath: string; 
+/**
+ * Target directory to export to
+ */
+targetDir: string; 
+/**
+ * Export only the public key
+ */
+publicOnly: boolean }
+/**
+ * Options for fetch operations
+ */
+export type FetchOptions = { 
+/**
+ * Prune remote tracking branches that no longer exist
+ */
+prune: boolean; 
+/**
+ * Fetch tags
+ */
+tags: boolean; 
+/**
+ * Depth for shallow fetch (None for full fetch)
+ */
+depth: number | null; 
+/**
+ * Override `fetch.recurseSubmodules` for this fetch
+ */
+recurseSubmodules: FetchRecurseSubmodules | null }
+/**
+ * Progress information for fetch operations
+ */
+export type FetchProgress = { totalObjects: number; indexedObjects: number; receivedObjects: number; localObjects: number; totalDeltas: number; indexedDeltas: number; receivedBytes: number }
+/**
+ * Whether fetching should also fetch submodules (mirrors `fetch.recurseSubmodules`)
+ */
+export type FetchRecurseSubmodules = 
+/**
+ * Never fetch submodules
+ */
+"No" | 
+/**
+ * Fetch every initialized submodule
+ */
+"Yes" | 
+/**
+ * Fetch only submodules whose recorded commit changed in the fetched history
+ */
+"OnDemand"
+/**
+ * Result of a fetch operation
+ */
+export type FetchResult = { remote: string; updatedRefs: UpdatedRef[]; stats: FetchProgress; 
+/**
+ * Submodule paths whose new commits were fetched along with the superproject
+ */
+fetchedSubmodules: string[] }
+/**
+ * A file compared between two refs, with the commits in between that changed it
+ */
+export type FileCompareResult = { 
+/**
+ * Diff from `from_ref` to `to_ref`, or `None` when the file is identical
+ */
+diff: FileDiff | null; 
+/**
+ * Resolved commit for `from_ref`
+ */
+fromOid: string; 
+/**
+ * Resolved commit for `to_ref`
+ */
+toOid: string; 
+/**
+ * Path of the file at `from_ref` (differs from `to_path` when renamed in between)
+ */
+fromPath: string | null; 
+/**
+ * Path of the file at `to_ref`
+ */
+toPath: string | null; 
+/**
+ * The file does not exist at `from_ref` and was created in the range
+ */
+createdInRange: boolean; 
+/**
+ * The file exists at `from_ref` and was deleted in the range
+ */
+deletedInRange: boolean; 
+/**
+ * Commits in `from_ref..to_ref` that changed the file, newest first
+ */
+commits: Commit[]; 
+/**
+ * Whether more commits changed the file than were returned
+ */
+hasMoreCommits: boolean }
+/**
+ * Raw file content for previews, encoded for transport
+ */
+export type FileContent = { contentBase64: string; 
+/**
+ * Detected from the content, falling back to the extension
+ */
+mimeType: string; 
+/**
+ * Size in bytes before encoding
+ */
+size: number }
+/**
+ * Where `get_file_content_base64` reads a file from
+ */
+export type FileContentSource = "Workdir" | "Index" | 
+/**
+ * The file as of a commit, given as an OID or any revision
+ */
+{ Commit: { oid: string } }
+/**
+ * Represents a complete diff for a file
+ */
+export type FileDiff = { oldPath: string | null; newPath: string | null; oldOid: string | null; newOid: string | null; status: DiffStatus; binary: boolean; 
+/**
+ * Sizes and preview hints, set for binary files only
+ */
+binaryInfo?: BinaryDiffInfo | null; 
+/**
+ * How the hunks were built when structured diffs were requested for this file;
+ * `None` for a plain text diff
+ */
+structured?: StructuredDiffKind | null; hunks: DiffHunk[]; 
+/**
+ * Summary statistics
+ */
+additions: number; deletions: number }
+/**
+ * Options for file history (log for specific files)
+ */
+export type FileLogOptions = { 
+/**
+ * Paths to filter commits by
+ */
+paths: string[]; 
+/**
+ * Maximum number of commits to return
+ */
+limit: number | null; 
+/**
+ * Number of commits to skip (for pagination)
+ */
+skip: number | null; 
+/**
+ * Follow file renames
+ */
+followRenames?: boolean }
+/**
+ * Result of file history query
+ */
+export type FileLogResult = { 
+/**
+ * Commits that modified the specified files
+ */
+commits: Commit[]; 
+/**
+ * Whether there are more commits
+ */
+hasMore: boolean }
+/**
+ * Result of a file mode audit
+ */
+export type FileModeAudit = { findings: FileModeFinding[]; 
+/**
+ * Regular files inspected in the audited tree
+ */
+scannedFiles: number; 
+/**
+ * Commits walked for flip-flop detection
+ */
+scannedCommits: number }
+/**
+ * Options for `audit_file_modes`
+ */
+export type FileModeAuditOptions = { 
+/**
+ * Commit to audit (defaults to HEAD)
+ */
+revision: string | null; 
+/**
+ * Number of first-parent commits to scan for mode flip-flops (defaults to 100)
+ */
+historyDepth: number | null; 
+/**
+ * Mode changes within the window that count as a flip-flop (defaults to 2)
+ */
+flipThreshold: number | null }
+/**
+ * A single audit finding
+ */
+export type FileModeFinding = { path: string; issue: FileModeIssue; 
+/**
+ * Whether the file is executable in the audited commit
+ */
+executable: boolean; 
+/**
+ * Mode the fix would apply; `None` if the current mode already looks right
+ */
+suggestedExecutable: boolean | null; 
+/**
+ * Executable bit changes seen in the history window
+ */
+modeChanges: number }
+/**
+ * A mode change selected for `fix_file_modes`
+ */
+export type FileModeFix = { path: string; executable: boolean }
+/**
+ * Result of applying file mode fixes
+ */
+export type FileModeFixResult = { 
+/**
+ * Paths whose staged mode changed
+ */
+changed: string[]; 
+/**
+ * Commit message describing the staged mode changes
+ */
+commitMessage: string }
+/**
+ * Kind of suspicious file mode found by the audit
+ */
+export type FileModeIssue = 
+/**
+ * Starts with `#!` but is not executable
+ */
+"ShebangNotExecutable" | 
+/**
+ * Script entry point (`.sh`, `.py` with a `__main__` guard, ...) without the executable bit
+ */
+"EntryPointNotExecutable" | 
+/**
+ * Executable, but looks like data or documentation
+ */
+"ExecutableDataFile" | 
+/**
+ * The executable bit was toggled repeatedly in recent history
+ */
+"ModeFlipFlop"
+export type FileStatus = { path: string; status: StatusType; stagedStatus: StatusType | null; unstagedStatus: StatusType | null; isConflict: boolean; oldPath: string | null; kind?: FileStatusKind; 
+/**
+ * Untracked files inside a collapsed `Directory` entry
+ */
+fileCount?: number | null }
+/**
+ * What a status entry refers to
+ */
+export type FileStatusKind = "File" | 
+/**
+ * An untracked directory shown as one entry; its path ends with `/`
+ */
+"Directory"
+/**
+ * Files in the repository changed
+ */
+export type FilesChangedEvent = { paths: string[] }
+/**
+ * Options for format-patch (creating patches from commit range)
+ */
+export type FormatPatchOptions = { 
+/**
+ * Range specification (e.g., "HEAD~3", "main..feature", "abc123")
+ */
+range: string; 
+/**
+ * Output directory for patch files
+ */
+outputDir: string }
+export type GenerateCommitMessageOptions = { 
+/**
+ * Falls back to the conventional commits setting when not set
+ */
+style: CommitMessageStyle | null }
+export type GenerateCommitMessageResponse = { 
+/**
+ * The full message, subject and body joined by a blank line
+ */
+message: string; subject?: string; body?: string | null; modelUsed: string; 
+/**
+ * The staged diff was too large, so only a file summary was sent
+ */
+fromSummary?: boolean }
+export type GeneratePrDescriptionOptions = { 
+/**
+ * Send the branch diff (or a file summary when it is too large); defaults to true
+ */
+includeDiff?: boolean | null; 
+/**
+ * Label names the model may suggest from
+ */
+availableLabels?: string[] }
+/**
+ * Generated PR fields, matching `CreatePrOptions` so they can prefill the create dialog
+ */
+export type GeneratePrDescriptionResponse = { title: string; body: string; labels: string[]; modelUsed: string; 
+/**
+ * Issue number parsed from the branch name; the body references it
+ */
+linkedIssue?: number | null; 
+/**
+ * The branch diff was too large, so only a file summary was sent
+ */
+fromSummary?: boolean }
+/**
+ * Options for generating a new SSH key
+ */
+export type GenerateSshKeyOptions = { 
+/**
+ * Algorithm to use
+ */
+algorithm: SshKeyAlgorithm; 
+/**
+ * Comment for the key (usually email)
+ */
+comment: string | null; 
+/**
+ * Passphrase to protect the key (empty string = no passphrase)
+ */
+passphrase: string | null; 
+/**
+ * Filename for the key (without path, stored in ~/.ssh/)
+ */
+filename: string; 
+/**
+ * Bit size (only for RSA/ECDSA, ignored for Ed25519)
+ */
+bits: number | null }
+/**
+ * A key created by `generate_gpg_key`
+ */
+export type GeneratedGpgKey = { 
+/**
+ * Long key ID (last 16 hex digits of the fingerprint)
+ */
+keyId: string; fingerprint: string }
+/**
+ * Git environment information for settings display
+ */
+export type GitEnvironment = { 
+/**
+ * Git CLI version
+ */
+gitVersion: string | null; 
+/**
+ * Path to Git executable
+ */
+gitPath: string | null; 
+/**
+ * libgit2 version used by the application
+ */
+libgit2Version: string; 
+/**
+ * Whether Git LFS is installed
+ */
+lfsInstalled: boolean; 
+/**
+ * Git LFS version if installed
+ */
+lfsVersion: string | null }
+/**
+ * Git-flow configuration
+ */
+export type GitFlowConfig = { master: string; develop: string; featurePrefix: string; releasePrefix: string; hotfixPrefix: string; supportPrefix: string; versionTagPrefix: string }
+/**
+ * Options for finishing a feature/release/hotfix
+ */
+export type GitFlowFinishOptions = { fetch?: boolean; rebase?: boolean; keep?: boolean; forceDelete?: boolean; squash?: boolean; noFf?: boolean; message?: string | null; tagMessage?: string | null; push?: boolean }
+/**
+ * Options for initializing git-flow
+ */
+export type GitFlowInitOptions = { master?: string | null; develop?: string | null; featurePrefix?: string | null; releasePrefix?: string | null; hotfixPrefix?: string | null; supportPrefix?: string | null; versionTagPrefix?: string | null; force?: boolean }
+/**
+ * Result of a git-flow operation
+ */
+export type GitFlowResult = { success: boolean; message: string; branch: string | null }
+/**
+ * Git hook types
+ */
+export type GitHookType = 
+/**
+ * Runs before commit starts
+ */
+"PreCommit" | 
+/**
+ * Can modify commit message
+ */
+"PrepareCommitMsg" | 
+/**
+ * Validates commit message
+ */
+"CommitMsg" | 
+/**
+ * Runs after commit
+ */
+"PostCommit" | 
+/**
+ * Runs before push
+ */
+"PrePush" | 
+/**
+ * Runs before a merge commit is created
+ */
+"PreMergeCommit" | 
+/**
+ * Runs after merge
+ */
+"PostMerge" | 
+/**
+ * Runs before rebase
+ */
+"PreRebase" | 
+/**
+ * Runs after checkout
+ */
+"PostCheckout" | 
+/**
+ * Runs after amend/rebase
+ */
+"PostRewrite"
+/**
+ * Progress update for git operations (clone, fetch, push, pull)
+ */
+export type GitOperationProgressEvent = { operationId: string; operationType: GitOperationType; stage: ProgressStage; totalObjects: number | null; receivedObjects: number | null; indexedObjects: number | null; receivedBytes: number; totalDeltas: number | null; indexedDeltas: number | null; message: string | null }
+export type GitOperationType = "Clone" | "Fetch" | "Push" | "Pull" | "Maintenance"
+/**
+ * Represents a GPG key available for signing
+ */
+export type GpgKey = { 
+/**
+ * Key ID (short or long form)
+ */
+keyId: string; 
+/**
+ * User ID (name and email)
+ */
+userId: string; 
+/**
+ * Email address extracted from `user_id`
+ */
+email: string | null; 
+/**
+ * Whether this is the default key
+ */
+isDefault: boolean }
+/**
+ * gpg reported progress while generating a key
+ */
+export type GpgKeyGenerationProgressEvent = { 
+/**
+ * What gpg is working on, e.g. `primegen` or `need_entropy`
+ */
+what: string; current: number; 
+/**
+ * 0 when gpg cannot tell how much work is left
+ */
+total: number }
+/**
+ * A commit with graph layout information for visualization
+ */
+export type GraphCommit = 
+/**
+ * The underlying commit data
+ */
+({ oid: string; shortOid: string; message: string; summary: string; author: Signature; committer: Signature; parentOids: string[]; timestamp: string; isMerge: boolean; 
+/**
+ * Signature info if the commit is signed
+ */
+signature: CommitSignature | null }) & { 
+/**
+ * The lane (column) this commit occupies
+ */
+lane: number; 
+/**
+ * Connections to parent commits
+ */
+parentEdges: GraphEdge[]; 
+/**
+ * Branch/tag refs pointing to this commit
+ */
+refs: CommitRef[]; 
+/**
+ * Kept only to connect commits matching `GraphOptions::author_filter`; the
+ * message is left out
+ */
+filtered?: boolean }
+/**
+ * An edge connecting a commit to its parent in the graph
+ */
+export type GraphEdge = { 
+/**
+ * The parent commit OID
+ */
+parentOid: string; 
+/**
+ * The lane of the parent commit
+ */
+parentLane: number; 
+/**
+ * The type of edge (straight, merge, branch)
+ */
+edgeType: EdgeType }
+/**
+ * Options for graph generation
+ */
+export type GraphOptions = { 
+/**
+ * Maximum number of commits to include
+ */
+limit: number | null; 
+/**
+ * Number of commits to skip (for pagination)
+ */
+skip: number | null; 
+/**
+ * Start from a specific ref
+ */
+fromRef: string | null; 
+/**
+ * Include all branches (not just current) - deprecated, use `branch_filter` instead
+ */
+allBranches?: boolean; 
+/**
+ * Branch filter (all, current, or specific branch)
+ */
+branchFilter?: BranchFilterType; 
+/**
+ * Whether to include remote branches
+ */
+includeRemotes?: boolean; 
+/**
+ * Sort order for commits
+ */
+sortOrder?: SortOrder; 
+/**
+ * Whether to include uncommitted changes as first entry
+ */
+includeUncommitted?: boolean; 
+/**
+ * Show stash entries on the commits they were made from. Their index and
+ * untracked-files commits are left out.
+ */
+includeStashes?: boolean; 
+/**
+ * Keep commits whose author name or email contains this (case-insensitive).
+ * Other commits between two kept ones are returned with `filtered` set.
+ */
+authorFilter?: string | null; 
+/**
+ * Follow only the first parent of merges, like `git log --first-parent`
+ */
+firstParent?: boolean }
+/**
+ * Result of graph generation with metadata
+ */
+export type GraphResult = { 
+/**
+ * The commits with graph layout
+ */
+commits: GraphCommit[]; 
+/**
+ * Total number of commits (for pagination)
+ */
+totalCount: number; 
+/**
+ * Maximum lane used (for graph width calculation)
+ */
+maxLane: number; 
+/**
+ * Whether there are more commits
+ */
+hasMore: boolean }
+/**
+ * Lines of one staged diff hunk that match a `grep_diff` pattern
+ */
+export type GrepDiffMatch = { file: string; hunkHeader: string; 
+/**
+ * Matching added and context lines, in hunk order
+ */
+matchingLines: DiffLine[] }
+/**
+ * A single grep match
+ */
+export type GrepMatch = { path: string; lineNumber: number | null; content: string; 
+/**
+ * Where the pattern matched in `content`; empty for inverted matches
+ */
+ranges?: GrepMatchRange[]; contextBefore?: string[]; contextAfter?: string[] }
+/**
+ * Byte range of a match within a line
+ */
+export type GrepMatchRange = { start: number; end: number }
+/**
+ * Content search options
+ */
+export type GrepOptions = { pattern: string; paths?: string[]; ignoreCase?: boolean; wordRegexp?: boolean; 
+/**
+ * Treat the pattern as a regular expression; otherwise it is matched literally
+ */
+extendedRegexp?: boolean; invertMatch?: boolean; showLineNumbers?: boolean; 
+/**
+ * Maximum matches per file
+ */
+maxCount?: number | null; contextLines?: number | null; 
+/**
+ * Glob patterns a path must match, e.g. `*.rs`
+ */
+include?: string[]; 
+/**
+ * Glob patterns excluding paths, e.g. `vendor/**`
+ */
+exclude?: string[]; 
+/**
+ * Maximum matches in total; the result reports `has_more` when capped
+ */
+maxResults?: number | null; 
+/**
+ * Commit, branch or tag whose tree is searched instead of the working tree
+ */
+ref?: string | null }
+/**
+ * Result of a grep search
+ */
+export type GrepResult = { matches: GrepMatch[]; totalMatches: number; 
+/**
+ * The search stopped at `max_results` with more matches left
+ */
+hasMore?: boolean; 
+/**
+ * Binary files that were not searched
+ */
+skippedBinaryFiles?: number }
+/**
+ * A page of content search matches, streamed while a search is running
+ */
+export type GrepResultsPageEvent = { searchId: string; matches: GrepMatch[] }
+/**
+ * HEAD changed (checkout, commit)
+ */
+export type HeadChangedEvent = null
+/**
+ * Hook with content for editing
+ */
+export type HookDetails = { 
+/**
+ * Hook info
+ */
+info: HookInfo; 
+/**
+ * Content of the hook file (None if file doesn't exist)
+ */
+content: string | null }
+/**
+ * A hook file shown in a trust prompt
+ */
+export type HookFingerprint = { hookType: GitHookType; 
+/**
+ * Full path to the hook file
+ */
+path: string; 
+/**
+ * File size in bytes
+ */
+size: number; 
+/**
+ * SHA-256 of the file content; decisions are keyed by it so edits re-prompt
+ */
+contentHash: string }
+/**
+ * Hook info for management UI
+ */
+export type HookInfo = { 
+/**
+ * Type of hook
+ */
+hookType: GitHookType; 
+/**
+ * Whether the hook file exists
+ */
+exists: boolean; 
+/**
+ * Whether the hook is enabled (false if has .disabled suffix)
+ */
+enabled: boolean; 
+/**
+ * Full path to the hook file
+ */
+path: string; 
+/**
+ * Whether the hook file is executable (Unix only, always true on Windows)
+ */
+isExecutable: boolean }
+/**
+ * Progress update for hook execution
+ */
+export type HookProgressEvent = { operationId: string; hookType: GitHookType; stage: HookStage; message: string | null; canAbort: boolean }
+/**
+ * Result of running a git hook
+ */
+export type HookResult = { 
+/**
+ * Type of hook that was run
+ */
+hookType: GitHookType; 
+/**
+ * Whether the hook succeeded
+ */
+success: boolean; 
+/**
+ * Exit code from the hook (0 = success)
+ */
+exitCode: number; 
+/**
+ * Standard output from the hook
+ */
+stdout: string; 
+/**
+ * Standard error from the hook
+ */
+stderr: string; 
+/**
+ * Whether the hook was skipped (not found or not executable)
+ */
+skipped: boolean; 
+/**
+ * Whether the hook was cancelled by user
+ */
+cancelled?: boolean; 
+/**
+ * Whether the hook was skipped because the user chose never to trust it
+ */
+untrusted?: boolean; 
+/**
+ * Set when the hook did not run because the user has not decided whether to trust it
+ */
+trustRequest?: HookTrustRequest | null }
+export type HookStage = "Running" | "Complete" | "Failed" | "Cancelled"
+/**
+ * Template for creating hooks
+ */
+export type HookTemplate = { 
+/**
+ * Template name
+ */
+name: string; 
+/**
+ * Human-readable description
+ */
+description: string; 
+/**
+ * Hook type this template is for
+ */
+hookType: GitHookType; 
+/**
+ * Template content (shell script)
+ */
+content: string }
+/**
+ * User decision on whether a repository's hooks may run
+ */
+export type HookTrustDecision = 
+/**
+ * Run this hook content now and in the future
+ */
+"TrustAlways" | 
+/**
+ * Run this hook content for the next invocation only
+ */
+"TrustOnce" | 
+/**
+ * Never run this hook content; invocations are skipped
+ */
+"Never"
+/**
+ * Trust prompt raised before any undecided hook from a repository runs
+ */
+export type HookTrustRequest = { repoPath: string; 
+/**
+ * The hook that was about to run, or `None` when the prompt was requested up front
+ */
+hookType: GitHookType | null; 
+/**
+ * Every hook in the repository that has no decision for its current content
+ */
+hooks: HookFingerprint[] }
+/**
+ * A hook was about to run but the repository's hooks have no trust decision yet
+ */
+export type HookTrustRequiredEvent = { operationId: string; request: HookTrustRequest }
+/**
+ * Options for ignoring a file, returned by `get_ignore_options`
+ */
+export type IgnoreOptions = { 
+/**
+ * List of ancestor .gitignore files (relative paths from repo root)
+ * Always includes ".gitignore" (root), plus any existing .gitignore files
+ * in the path hierarchy of the target file
+ */
+gitignoreFiles: string[]; 
+/**
+ * Default .gitignore file to use (closest existing ancestor)
+ */
+defaultGitignore: string; 
+/**
+ * Suggested patterns for the file
+ */
+suggestions: IgnoreSuggestion[] }
+/**
+ * Result of adding a pattern to .gitignore
+ */
+export type IgnoreResult = { 
+/**
+ * Human-readable message about the operation
+ */
+message: string; 
+/**
+ * The pattern that was added
+ */
+pattern: string; 
+/**
+ * Path to the .gitignore file that was modified
+ */
+gitignorePath: string; 
+/**
+ * Whether the pattern already existed (no changes made)
+ */
+alreadyExisted: boolean }
+/**
+ * A suggested pattern for ignoring a file
+ */
+export type IgnoreSuggestion = { 
+/**
+ * The pattern to add to .gitignore
+ */
+pattern: string; 
+/**
+ * Description of what this pattern does
+ */
+description: string; 
+/**
+ * Type of the suggestion
+ */
+suggestionType: IgnoreSuggestionType }
+/**
+ * Type of ignore pattern suggestion
+ */
+export type IgnoreSuggestionType = 
+/**
+ * Exact file path (e.g., "src/foo/bar.txt")
+ */
+"ExactFile" | 
+/**
+ * File extension (e.g., "*.txt")
+ */
+"Extension" | 
+/**
+ * Directory (e.g., "src/foo/")
+ */
+"Directory" | 
+/**
+ * File name anywhere (e.g., "bar.txt")
+ */
+"FileName" | 
+/**
+ * Directory name anywhere (e.g., "build/")
+ */
+"DirectoryName"
+/**
+ * Options for importing an SSH key
+ */
+export type ImportSshKeyOptions = { 
+/**
+ * Source path of the key file to import
+ */
+sourcePath: string; 
+/**
+ * Target filename in ~/.ssh/ (without path)
+ */
+targetFilename: string }
+/**
+ * The index (staging area) changed
+ */
+export type IndexChangedEvent = null
+/**
+ * Part of a diff line. Offsets count characters (Unicode scalar values), not bytes.
+ */
+export type InlineSpan = { start: number; len: number; 
+/**
+ * Whether this part differs from the paired line
+ */
+changed: boolean }
+/**
+ * Label from integration provider
+ */
+export type IntegrationLabel = { name: string; color: string; description: string | null }
+/**
+ * Repository information from the integration provider
+ */
+export type IntegrationRepoInfo = { provider: ProviderType; owner: string; name: string; fullName: string; description: string | null; stars: number; forks: number; openIssuesCount: number; isPrivate: boolean; defaultBranch: string; url: string }
+/**
+ * Integration connection status
+ */
+export type IntegrationStatus = { provider: ProviderType; connected: boolean; username: string | null; avatarUrl: string | null }
+/**
+ * Integration connection status changed
+ */
+export type IntegrationStatusChangedEvent = { provider: ProviderType; connected: boolean }
+/**
+ * User from integration provider
+ */
+export type IntegrationUser = { login: string; avatarUrl: string; url: string }
+/**
+ * A single entry in the interactive rebase todo list
+ */
+export type InteractiveRebaseEntry = { 
+/**
+ * The action to perform
+ */
+action: RebaseAction; 
+/**
+ * Commit short OID
+ */
+shortOid: string; 
+/**
+ * Commit full OID
+ */
+oid: string; 
+/**
+ * Commit message summary
+ */
+summary: string; 
+/**
+ * Original index for tracking reordering
+ */
+originalIndex: number }
+/**
+ * Options for starting an interactive rebase
+ */
+export type InteractiveRebaseOptions = { 
+/**
+ * The branch/commit to rebase onto
+ */
+onto: string; 
+/**
+ * The ordered list of entries with actions
+ */
+entries: InteractiveRebaseEntry[]; 
+/**
+ * Whether to autosquash fixup! commits
+ */
+autosquash: boolean; 
+/**
+ * Preconditions of the preview the entries were planned from; the rebase does not
+ * start if any no longer holds
+ */
+preconditions?: RewritePreconditions | null }
+/**
+ * Extended rebase preview with interactive entries
+ */
+export type InteractiveRebasePreview = { 
+/**
+ * Base preview data
+ */
+preview: RebasePreview; 
+/**
+ * Entries prepared for interactive editing (with default pick action)
+ */
+entries: InteractiveRebaseEntry[] }
+/**
+ * Issue summary for list views
+ */
+export type Issue = { provider: ProviderType; number: number; title: string; state: IssueState; author: IntegrationUser; labels: IntegrationLabel[]; commentsCount: number; createdAt: string; updatedAt: string; url: string }
+/**
+ * Detailed issue information
+ */
+export type IssueDetail = ({ provider: ProviderType; number: number; title: string; state: IssueState; author: IntegrationUser; labels: IntegrationLabel[]; commentsCount: number; createdAt: string; updatedAt: string; url: string }) & { body: string | null; assignees: IntegrationUser[]; milestone: string | null }
+/**
+ * Issue state
+ */
+export type IssueState = "Open" | "Closed" | "All"
+/**
+ * Paginated issues response
+ */
+export type IssuesPage = { items: Issue[]; hasMore: boolean }
+/**
+ * Information about a large binary file detected during staging
+ */
+export type LargeBinaryFileInfo = { 
+/**
+ * File path relative to repository root
+ */
+path: string; 
+/**
+ * File size in bytes
+ */
+size: number; 
+/**
+ * Whether the file is detected as binary
+ */
+isBinary: boolean; 
+/**
+ * Whether the file is already tracked by LFS
  */
-export type GenerateSshKeyOptions = { 
+isLfsTracked: boolean; 
 /**
- * Algorithm to use
+ * Suggested LFS tracking pattern (e.g., "*.psd")
  */
-algorithm: SshKeyAlgorithm; 
+suggestedPattern: string }
 /**
- * Comment for the key (usually email)
+ * One of the largest blobs in the repository
  */
-comment: string | null; 
+export type LargeBlob = { oid: string; 
 /**
- * Passphrase to protect the key (empty string = no passphrase)
+ * Uncompressed size in bytes
  */
-passphrase: string | null; 
+size: number; 
 /**
- * Filename for the key (without path, stored in ~/.ssh/)
+ * Path of the blob in HEAD's tree; `None` when HEAD does not contain it
  */
-filename: string; 
+path: string | null }
 /**
- * Bit size (only for RSA/ECDSA, ignored for Ed25519)
+ * Result of checking files for LFS eligibility before staging
  */
-bits: number | null }
+export type LfsCheckResult = { 
 /**
- * Git environment information for settings display
+ * Large binary files that should be tracked with LFS
  */
-export type GitEnvironment = { 
+files: LargeBinaryFileInfo[]; 
 /**
- * Git CLI version
+ * Whether Git LFS is installed on the system
  */
-gitVersion: string | null; 
+lfsInstalled: boolean; 
 /**
- * Path to Git executable
+ * Whether LFS is initialized in this repository
  */
-gitPath: string | null; 
+lfsInitialized: boolean }
 /**
- * libgit2 version used by the application
+ * LFS environment information
  */
-libgit2Version: string; 
+export type LfsEnvironment = { 
 /**
- * Whether Git LFS is installed
+ * LFS version
  */
-lfsInstalled: boolean; 
+version: string; 
 /**
- * Git LFS version if installed
+ * Git LFS endpoint URL
  */
-lfsVersion: string | null }
+endpoint: string | null; 
 /**
- * Git-flow configuration
+ * Local LFS storage path
  */
-export type GitFlowConfig = { master: string; develop: string; featurePrefix: string; releasePrefix: string; hotfixPrefix: string; supportPrefix: string; versionTagPrefix: string }
+storagePath: string | null; 
 /**
- * Options for finishing a feature/release/hotfix
+ * Whether SSH is used for transfers
  */
-export type GitFlowFinishOptions = { fetch?: boolean; rebase?: boolean; keep?: boolean; forceDelete?: boolean; squash?: boolean; noFf?: boolean; message?: string | null; tagMessage?: string | null; push?: boolean }
+usesSsh: boolean }
 /**
- * Options for initializing git-flow
+ * Options for LFS fetch operation
  */
-export type GitFlowInitOptions = { master?: string | null; develop?: string | null; featurePrefix?: string | null; releasePrefix?: string | null; hotfixPrefix?: string | null; supportPrefix?: string | null; versionTagPrefix?: string | null; force?: boolean }
+export type LfsFetchOptions = { 
 /**
- * Result of a git-flow operation
+ * Fetch LFS objects for all refs
  */
-export type GitFlowResult = { success: boolean; message: string; branch: string | null }
+all: boolean; 
 /**
- * Git hook types
+ * Fetch only recent LFS objects
  */
-export type GitHookType = 
+recent: boolean; 
 /**
- * Runs before commit starts
+ * Include objects for the specified remote
  */
-"PreCommit" | 
+remote: string | null; 
 /**
- * Can modify commit message
+ * Specific refs to fetch (branches, tags)
  */
-"PrepareCommitMsg" | 
+refs: string[] }
 /**
- * Validates commit message
+ * An LFS-tracked file
  */
-"CommitMsg" | 
+export type LfsFile = { 
 /**
- * Runs after commit
+ * Path to the file relative to repository root
  */
-"PostCommit" | 
+path: string; 
 /**
- * Runs before push
+ * LFS object ID (SHA-256)
  */
-"PrePush" | 
+oid: string; 
 /**
- * Runs after merge
+ * File size in bytes
  */
-"PostMerge" | 
+size: number; 
 /**
- * Runs before rebase
+ * Whether the file content is downloaded
  */
-"PreRebase" | 
+isDownloaded: boolean; 
 /**
- * Runs after checkout
+ * File status
  */
-"PostCheckout" | 
+status: LfsFileStatus }
 /**
- * Runs after amend/rebase
+ * Status of an LFS file
  */
-"PostRewrite"
+export type LfsFileStatus = 
 /**
- * Progress update for git operations (clone, fetch, push, pull)
+ * File content is downloaded locally
  */
-export type GitOperationProgressEvent = { operationId: string; operationType: GitOperationType; stage: ProgressStage; totalObjects: number | null; receivedObjects: number | null; indexedObjects: number | null; receivedBytes: number; totalDeltas: number | null; indexedDeltas: number | null; message: string | null }
-export type GitOperationType = "Clone" | "Fetch" | "Push" | "Pull"
+"Downloaded" | 
 /**
- * Represents a GPG key available for signing
+ * File is a pointer (content not downloaded)
  */
-export type GpgKey = { 
+"Pointer" | 
 /**
- * Key ID (short or long form)
+ * File is not tracked by LFS
  */
-keyId: string; 
+"NotLfs" | 
 /**
- * User ID (name and email)
+ * Status unknown
  */
-userId: string; 
+"Unknown"
 /**
- * Email address extracted from `user_id`
+ * LFS migration mode
  */
-email: string | null; 
+export type LfsMigrateMode = 
 /**
- * Whether this is the default key
+ * Import files into LFS
  */
-isDefault: boolean }
+"Import" | 
 /**
- * A commit with graph layout information for visualization
+ * Export files from LFS to regular Git
  */
-export type GraphCommit = 
+"Export" | 
 /**
- * The underlying commit data
+ * Show migration info without making changes
  */
-({ oid: string; shortOid: string; message: string; summary: string; author: Signature; committer: Signature; parentOids: string[]; timestamp: string; isMerge: boolean; 
+"Info"
 /**
- * Signature info if the commit is signed
+ * Options for LFS migrate operation
  */
-signature: CommitSignature | null }) & { 
+export type LfsMigrateOptions = { 
 /**
- * The lane (column) this commit occupies
+ * Migration mode: import or export
  */
-lane: number; 
+mode: LfsMigrateMode; 
 /**
- * Connections to parent commits
+ * File patterns to include
  */
-parentEdges: GraphEdge[]; 
+include: string[]; 
 /**
- * Branch/tag refs pointing to this commit
+ * File patterns to exclude
  */
-refs: CommitRef[] }
+exclude: string[]; 
 /**
- * An edge connecting a commit to its parent in the graph
+ * Include all refs
  */
-export type GraphEdge = { 
+everything: boolean; 
 /**
- * The parent commit OID
+ * Specific refs to migrate
  */
-parentOid: string; 
+refs: string[]; 
 /**
- * The lane of the parent commit
+ * Rewrite history (use with caution)
  */
-parentLane: number; 
+above: number | null }
 /**
- * The type of edge (straight, merge, branch)
+ * Options for LFS prune operation
  */
-edgeType: EdgeType }
+export type LfsPruneOptions = { 
 /**
- * Options for graph generation
+ * Dry run - show what would be pruned
  */
-export type GraphOptions = { 
+dryRun: boolean; 
 /**
- * Maximum number of commits to include
+ * Verify remote copies before pruning
  */
-limit: number | null; 
+verifyRemote: boolean }
 /**
- * Number of commits to skip (for pagination)
+ * Result of LFS prune operation
+ */
+export type LfsPruneResult = { 
+/**
+ * Whether the operation succeeded
+ */
+success: boolean; 
+/**
+ * Message describing the result
+ */
+message: string; 
+/**
+ * Number of objects pruned
+ */
+objectsPruned: number; 
+/**
+ * Space reclaimed in bytes
+ */
+spaceReclaimed: number }
+/**
+ * Options for LFS pull operation
+ */
+export type LfsPullOptions = { 
+/**
+ * Remote to pull from
+ */
+remote: string | null; 
+/**
+ * Only pull objects for paths matching these patterns (all objects when empty)
+ */
+include?: string[] }
+/**
+ * Options for LFS push operation
+ */
+export type LfsPushOptions = { 
+/**
+ * Remote to push to
+ */
+remote: string | null; 
+/**
+ * Push all local LFS objects
+ */
+all: boolean; 
+/**
+ * Dry run - don't actually push
+ */
+dryRun: boolean }
+/**
+ * Result of an LFS operation
+ */
+export type LfsResult = { 
+/**
+ * Whether the operation succeeded
+ */
+success: boolean; 
+/**
+ * Message describing the result
+ */
+message: string; 
+/**
+ * Files affected by the operation
+ */
+affectedFiles: string[] }
+/**
+ * Status of Git LFS in the repository
+ */
+export type LfsStatus = { 
+/**
+ * Whether git-lfs is installed on the system
+ */
+isInstalled: boolean; 
+/**
+ * Version of git-lfs if installed
+ */
+version: string | null; 
+/**
+ * Whether LFS is initialized in the repository
+ */
+isInitialized: boolean; 
+/**
+ * Number of tracked patterns
+ */
+trackedPatternsCount: number; 
+/**
+ * Number of LFS files in the repository
  */
-skip: number | null; 
+lfsFilesCount: number; 
 /**
- * Start from a specific ref
+ * LFS files whose objects are missing from the local store (incomplete clone or pull)
  */
-fromRef: string | null; 
+missingObjects?: string[]; 
 /**
- * Include all branches (not just current) - deprecated, use `branch_filter` instead
+ * LFS files whose objects are present in the local store
  */
-allBranches?: boolean; 
+cachedObjects?: string[] }
 /**
- * Branch filter (all, current, or specific branch)
+ * A tracked LFS pattern
  */
-branchFilter?: BranchFilterType; 
+export type LfsTrackedPattern = { 
 /**
- * Whether to include remote branches
+ * The pattern (e.g., "*.psd", "assets/*.png")
  */
-includeRemotes?: boolean; 
+pattern: string; 
 /**
- * Sort order for commits
+ * Source file where the pattern is defined (usually .gitattributes)
  */
-sortOrder?: SortOrder; 
+sourceFile: string }
+export type LintSeverity = 
 /**
- * Whether to include uncommitted changes as first entry
+ * Blocks the commit when enforcement is on
  */
-includeUncommitted?: boolean }
+"Error" | 
 /**
- * Result of graph generation with metadata
+ * Reported only; never blocks
  */
-export type GraphResult = { 
+"Warning"
 /**
- * The commits with graph layout
+ * Options for listing remotes
  */
-commits: GraphCommit[]; 
+export type ListRemoteOptions = { 
 /**
- * Total number of commits (for pagination)
+ * Sort order (defaults to Alphabetical)
  */
-totalCount: number; 
+sort?: RemoteSortOrder; 
 /**
- * Maximum lane used (for graph width calculation)
+ * Maximum number of remotes to return
  */
-maxLane: number; 
+limit: number | null }
 /**
- * Whether there are more commits
+ * Options for listing submodules
  */
-hasMore: boolean }
+export type ListSubmoduleOptions = { 
 /**
- * A single grep match
+ * Sort order (defaults to Alphabetical)
  */
-export type GrepMatch = { path: string; lineNumber: number | null; content: string }
+sort?: SubmoduleSortOrder; 
 /**
- * Content search options
+ * Maximum number of submodules to return
  */
-export type GrepOptions = { pattern: string; paths?: string[]; ignoreCase?: boolean; wordRegexp?: boolean; extendedRegexp?: boolean; invertMatch?: boolean; showLineNumbers?: boolean; maxCount?: number | null; contextLines?: number | null }
+limit: number | null }
 /**
- * Result of a grep search
+ * Options for listing tags
  */
-export type GrepResult = { matches: GrepMatch[]; totalMatches: number }
+export type ListTagsOptions = { 
 /**
- * HEAD changed (checkout, commit)
+ * Filter pattern (glob-style)
  */
-export type HeadChangedEvent = null
+pattern: string | null; 
 /**
- * Hook with content for editing
+ * Sort order (defaults to Alphabetical)
  */
-export type HookDetails = { 
+sort?: TagSortOrder; 
 /**
- * Hook info
+ * Maximum number of tags to return
  */
-info: HookInfo; 
+limit: number | null }
+export type LogOptions = { limit: number | null; skip: number | null; fromRef: string | null; branchFilter?: BranchFilterType; includeRemotes?: boolean; sortOrder?: SortOrder; 
 /**
- * Content of the hook file (None if file doesn't exist)
+ * Only commits committed at or after this time
  */
-content: string | null }
+since?: string | null; 
 /**
- * Hook info for management UI
+ * Only commits committed at or before this time
  */
-export type HookInfo = { 
+until?: string | null; 
 /**
- * Type of hook
+ * Only commits whose author name or email contains this, ignoring case
  */
-hookType: GitHookType; 
+author?: string | null; 
 /**
- * Whether the hook file exists
+ * Only commits whose committer name or email contains this, ignoring case
  */
-exists: boolean; 
+committer?: string | null; 
 /**
- * Whether the hook is enabled (false if has .disabled suffix)
+ * Skip merge commits, like `git log --no-merges`
  */
-enabled: boolean; 
+noMerges?: boolean; 
 /**
- * Full path to the hook file
+ * Follow only the first parent of merges, like `git log --first-parent`
  */
-path: string; 
+firstParent?: boolean; minParents?: number | null; maxParents?: number | null; 
 /**
- * Whether the hook file is executable (Unix only, always true on Windows)
+ * `next_cursor` from a previous page; when set the walk resumes from it
+ * and `skip` is ignored
  */
-isExecutable: boolean }
+cursor?: string | null }
 /**
- * Progress update for hook execution
+ * Options for `run_maintenance`
  */
-export type HookProgressEvent = { operationId: string; hookType: GitHookType; stage: HookStage; message: string | null; canAbort: boolean }
-export type HookStage = "Running" | "Complete" | "Failed" | "Cancelled"
+export type MaintenanceOptions = { tasks: MaintenanceTask[]; 
 /**
- * Template for creating hooks
+ * Pass `--aggressive` to gc (much slower, smaller packs)
  */
-export type HookTemplate = { 
+aggressive?: boolean }
 /**
- * Template name
+ * Result of `run_maintenance`
  */
-name: string; 
+export type MaintenanceResult = { 
 /**
- * Human-readable description
+ * Tasks that ran, in order
  */
-description: string; 
+tasks: MaintenanceTask[]; before: ObjectStoreSize; after: ObjectStoreSize }
 /**
- * Hook type this template is for
+ * A step of `run_maintenance`, run in the order given
  */
-hookType: GitHookType; 
+export type MaintenanceTask = 
 /**
- * Template content (shell script)
+ * `git gc`
  */
-content: string }
+"Gc" | 
 /**
- * Options for ignoring a file, returned by `get_ignore_options`
+ * `git prune`: delete unreachable loose objects
  */
-export type IgnoreOptions = { 
+"Prune" | 
 /**
- * List of ancestor .gitignore files (relative paths from repo root)
- * Always includes ".gitignore" (root), plus any existing .gitignore files
- * in the path hierarchy of the target file
+ * `git repack -a -d`: combine all packs into one
  */
-gitignoreFiles: string[]; 
+"Repack" | 
 /**
- * Default .gitignore file to use (closest existing ancestor)
+ * `git commit-graph write --reachable`
  */
-defaultGitignore: string; 
+"CommitGraph"
 /**
- * Suggested patterns for the file
+ * Menu item IDs for custom actions
  */
-suggestions: IgnoreSuggestion[] }
+export type MenuAction = "NewWindow" | "OpenRepository" | "CloseRepository" | "Settings" | "Refresh" | "ToggleSidebar" | "Fetch" | "Pull" | "Push" | "StageAll" | "UnstageAll" | "Commit" | "NewBranch" | "NewTag" | "Stash" | "PopStash"
 /**
- * Result of adding a pattern to .gitignore
+ * Menu action triggered
  */
-export type IgnoreResult = { 
+export type MenuActionEvent = { actionId: string }
 /**
- * Human-readable message about the operation
+ * Merge method for pull requests
  */
-message: string; 
+export type MergeMethod = "Merge" | "Squash" | "Rebase"
 /**
- * The pattern that was added
+ * Options for merge operations
  */
-pattern: string; 
+export type MergeOptions = { 
 /**
- * Path to the .gitignore file that was modified
+ * The branch to merge into the current branch
  */
-gitignorePath: string; 
+branch: string; 
 /**
- * Whether the pattern already existed (no changes made)
+ * Custom commit message (optional)
  */
-alreadyExisted: boolean }
+message: string | null; 
 /**
- * A suggested pattern for ignoring a file
+ * If true, always create a merge commit (no fast-forward)
  */
-export type IgnoreSuggestion = { 
+noFf: boolean; 
 /**
- * The pattern to add to .gitignore
+ * If true, squash all commits into a single commit
  */
-pattern: string; 
+squash: boolean; 
 /**
- * Description of what this pattern does
+ * If true, only fast-forward (fail if not possible)
  */
-description: string; 
+ffOnly: boolean; 
 /**
- * Type of the suggestion
+ * If true, don't create a commit (stage changes only)
  */
-suggestionType: IgnoreSuggestionType }
+noCommit: boolean; 
 /**
- * Type of ignore pattern suggestion
+ * Trailers for the merge commit
  */
-export type IgnoreSuggestionType = 
+trailers?: CommitTrailerOptions; 
 /**
- * Exact file path (e.g., "src/foo/bar.txt")
+ * Strategy or strategy option; git's default (`ort`) when unset
  */
-"ExactFile" | 
+strategy?: MergeStrategy | null }
 /**
- * File extension (e.g., "*.txt")
+ * Options for merging a pull request
  */
-"Extension" | 
+export type MergePrOptions = { mergeMethod: MergeMethod; commitTitle: string | null; commitMessage: string | null }
 /**
- * Directory (e.g., "src/foo/")
+ * Preview data for a merge operation, computed without touching the working tree
  */
-"Directory" | 
+export type MergePreview = { 
 /**
- * File name anywhere (e.g., "bar.txt")
+ * Commits that would be merged (reachable from source but not from HEAD)
  */
-"FileName"
+incomingCommits: Commit[]; 
 /**
- * Options for importing an SSH key
+ * The merge-base commit, if the histories are related
  */
-export type ImportSshKeyOptions = { 
+mergeBase: Commit | null; 
 /**
- * Source path of the key file to import
+ * Source branch/commit info
  */
-sourcePath: string; 
+source: RebaseTarget; 
 /**
- * Target filename in ~/.ssh/ (without path)
+ * Whether HEAD already contains the source
  */
-targetFilename: string }
+isUpToDate: boolean; 
 /**
- * The index (staging area) changed
+ * Whether the merge would be a fast-forward
  */
-export type IndexChangedEvent = null
+isFastForward: boolean; 
 /**
- * Label from integration provider
+ * Files that would conflict
  */
-export type IntegrationLabel = { name: string; color: string; description: string | null }
+conflicts: ConflictedFile[]; 
 /**
- * Repository information from the integration provider
+ * Changes the merge would apply cleanly on top of HEAD
  */
-export type IntegrationRepoInfo = { provider: ProviderType; owner: string; name: string; fullName: string; description: string | null; stars: number; forks: number; openIssuesCount: number; isPrivate: boolean; defaultBranch: string; url: string }
+diff: FileDiff[] }
 /**
- * Integration connection status
+ * Result of a merge operation
  */
-export type IntegrationStatus = { provider: ProviderType; connected: boolean; username: string | null; avatarUrl: string | null }
+export type MergeResult = { 
 /**
- * Integration connection status changed
+ * Whether the merge was successful
  */
-export type IntegrationStatusChangedEvent = { provider: ProviderType; connected: boolean }
+success: boolean; 
 /**
- * User from integration provider
+ * Type of merge that occurred
  */
-export type IntegrationUser = { login: string; avatarUrl: string; url: string }
+mergeType: MergeType; 
 /**
- * A single entry in the interactive rebase todo list
+ * New commit OID if a merge commit was created
  */
-export type InteractiveRebaseEntry = { 
+commitOid: string | null; 
 /**
- * The action to perform
+ * List of conflicted files if merge has conflicts
  */
-action: RebaseAction; 
+conflicts: ConflictedFile[]; 
 /**
- * Commit short OID
+ * Informational message
  */
-shortOid: string; 
+message: string }
 /**
- * Commit full OID
+ * Merge strategy, or conflict-resolution option of the default strategy
  */
-oid: string; 
+export type MergeStrategy = 
 /**
- * Commit message summary
+ * `--strategy=ours`: record the merge but keep the current branch's tree as is
  */
-summary: string; 
+"OursStrategy" | 
 /**
- * Original index for tracking reordering
+ * `--strategy-option=ours`: merge, taking the current branch's side of conflicting hunks
  */
-originalIndex: number }
+"Ours" | 
 /**
- * Options for starting an interactive rebase
+ * `--strategy-option=theirs`: merge, taking the merged branch's side of conflicting hunks
  */
-export type InteractiveRebaseOptions = { 
+"Theirs" | 
 /**
- * The branch/commit to rebase onto
+ * `--strategy=recursive`
  */
-onto: string; 
+"Recursive" | 
 /**
- * The ordered list of entries with actions
+ * `--strategy=octopus`, for merging more than two heads
  */
-entries: InteractiveRebaseEntry[]; 
+"Octopus"
 /**
- * Whether to autosquash fixup! commits
+ * Type of merge that occurred
  */
-autosquash: boolean }
+export type MergeType = 
 /**
- * Extended rebase preview with interactive entries
+ * Already up to date
  */
-export type InteractiveRebasePreview = { 
+"UpToDate" | 
 /**
- * Base preview data
+ * Fast-forward merge
  */
-preview: RebasePreview; 
+"FastForward" | 
 /**
- * Entries prepared for interactive editing (with default pick action)
+ * Normal merge (with merge commit)
  */
-entries: InteractiveRebaseEntry[] }
+"Normal" | 
 /**
- * Issue summary for list views
+ * Merge resulted in conflicts
  */
-export type Issue = { provider: ProviderType; number: number; title: string; state: IssueState; author: IntegrationUser; labels: IntegrationLabel[]; commentsCount: number; createdAt: string; updatedAt: string; url: string }
+"Conflicted"
 /**
- * Detailed issue information
+ * Axis feature that does what a git command does natively
  */
-export type IssueDetail = ({ provider: ProviderType; number: number; title: string; state: IssueState; author: IntegrationUser; labels: IntegrationLabel[]; commentsCount: number; createdAt: string; updatedAt: string; url: string }) & { body: string | null; assignees: IntegrationUser[]; milestone: string | null }
+export type NativeCommand = "Status" | "Log" | "Diff" | "Fetch" | "Pull" | "Push" | "Stash"
 /**
- * Issue state
+ * Notification from integration provider
  */
-export type IssueState = "Open" | "Closed" | "All"
 /**
- * Paginated issues response
+ * A notebook cell both sides of a merge changed differently, by 1-based cell
+ * number in each version; `None` where that version lacks the cell
  */
-export type IssuesPage = { items: Issue[]; hasMore: boolean }
+export type NotebookCellConflict = { baseCell: number | null; oursCell: number | null; theirsCell: number | null }
 /**
- * Information about a large binary file detected during staging
+ * Outcome of a cell-level three-way merge of a conflicted notebook
  */
-export type LargeBinaryFileInfo = { 
+export type NotebookMergeResult = { 
 /**
- * File path relative to repository root
+ * Every cell merged cleanly; the notebook was written and staged
  */
-path: string; 
+resolved: boolean; 
 /**
- * File size in bytes
+ * Cells to resolve by taking a side; empty when `resolved`
  */
-size: number; 
+conflicts: NotebookCellConflict[] }
 /**
- * Whether the file is detected as binary
+ * How notebook cell outputs and execution counts appear in structured diffs
  */
-isBinary: boolean; 
+export type NotebookOutputMode = 
 /**
- * Whether the file is already tracked by LFS
+ * Outputs and execution counts never show up as changes
  */
-isLfsTracked: boolean; 
+"Ignore" | 
 /**
- * Suggested LFS tracking pattern (e.g., "*.psd")
+ * A changed cell output is shown as one summary line per side
  */
-suggestedPattern: string }
+"Summarize"
+export type Notification = { provider: ProviderType; id: string; reason: NotificationReason; unread: boolean; subjectTitle: string; subjectType: NotificationSubjectType; subjectUrl: string | null; repository: string; updatedAt: string; url: string }
 /**
- * Result of checking files for LFS eligibility before staging
+ * Notification reason
  */
-export type LfsCheckResult = { 
+export type NotificationReason = "Assigned" | "Author" | "Comment" | "Invitation" | "Manual" | "Mention" | "ReviewRequested" | "SecurityAlert" | "StateChange" | "Subscribed" | "TeamMention" | "CiActivity"
 /**
- * Large binary files that should be tracked with LFS
+ * Number of unread notifications for a single reason
  */
-files: LargeBinaryFileInfo[]; 
+export type NotificationReasonCount = { reason: NotificationReason; count: number }
 /**
- * Whether Git LFS is installed on the system
+ * Notification subject type
  */
-lfsInstalled: boolean; 
+export type NotificationSubjectType = "Issue" | "PullRequest" | "Release" | "Discussion" | "Commit" | "RepositoryVulnerabilityAlert" | "CheckSuite"
 /**
- * Whether LFS is initialized in this repository
+ * Paginated notifications response
  */
-lfsInitialized: boolean }
+export type NotificationsPage = { items: Notification[]; hasMore: boolean }
 /**
- * LFS environment information
+ * OAuth callback received from deep link
  */
-export type LfsEnvironment = { 
+export type OAuthCallbackEvent = { provider: ProviderType; code: string; state: string | null }
 /**
- * LFS version
+ * An object store listed in `objects/info/alternates`
  */
-version: string; 
+export type ObjectAlternate = { 
 /**
- * Git LFS endpoint URL
+ * Absolute path of the alternate objects directory
  */
-endpoint: string | null; 
+path: string; 
 /**
- * Local LFS storage path
+ * Whether the directory can be read right now
  */
-storagePath: string | null; 
+reachable: boolean }
 /**
- * Whether SSH is used for transfers
+ * How the open repository shares objects with others
  */
-usesSsh: boolean }
+export type ObjectSharing = { 
 /**
- * Options for LFS fetch operation
+ * Object stores this repository borrows from
  */
-export type LfsFetchOptions = { 
+alternates: ObjectAlternate[]; 
 /**
- * Fetch LFS objects for all refs
+ * Repositories found borrowing from this one, under the configured shared-store roots
  */
-all: boolean; 
+borrowers: string[]; 
 /**
- * Fetch only recent LFS objects
+ * Why gc, prune or repack are unsafe here; empty when they are safe
  */
-recent: boolean; 
+maintenanceWarnings: string[] }
 /**
- * Include objects for the specified remote
+ * On-disk size of a repository's own object store (alternates are not included)
  */
-remote: string | null; 
+export type ObjectStoreSize = { looseObjects: number; 
 /**
- * Specific refs to fetch (branches, tags)
+ * Bytes used by loose object files
  */
-refs: string[] }
+looseSize: number; packs: number; 
 /**
- * An LFS-tracked file
+ * Objects listed in the pack index files
  */
-export type LfsFile = { 
+packedObjects: number; 
 /**
- * Path to the file relative to repository root
+ * Bytes used by pack and pack index files
  */
-path: string; 
+packSize: number }
 /**
- * LFS object ID (SHA-256)
+ * Category of a logged operation
  */
-oid: string; 
+export type OperationCategory = "Commit" | "Branch" | "Merge" | "Rebase" | "Remote" | "Stash" | "Tag" | "History" | "Hook" | "Other"
 /**
- * File size in bytes
+ * Commit an operation in progress is applying
  */
-size: number; 
+export type OperationCommit = { 
 /**
- * Whether the file content is downloaded
+ * Full commit OID
  */
-isDownloaded: boolean; 
+oid: string; 
 /**
- * File status
+ * First line of the commit message
  */
-status: LfsFileStatus }
+summary: string }
 /**
- * Status of an LFS file
+ * A single entry in the operation log
  */
-export type LfsFileStatus = 
+export type OperationLogEntry = { 
 /**
- * File content is downloaded locally
+ * Database row ID
  */
-"Downloaded" | 
+id: number; 
 /**
- * File is a pointer (content not downloaded)
+ * When the operation finished
  */
-"Pointer" | 
+timestamp: string; 
 /**
- * File is not tracked by LFS
+ * Repository the operation ran in
  */
-"NotLfs" | 
+repoPath: string; 
 /**
- * Status unknown
+ * Operation category
  */
-"Unknown"
+category: OperationCategory; 
 /**
- * LFS migration mode
+ * Operation name (e.g. "push", "pull")
  */
-export type LfsMigrateMode = 
+operation: string; 
 /**
- * Import files into LFS
+ * Operation parameters as JSON, with credentials redacted
  */
-"Import" | 
+parameters: string; 
 /**
- * Export files from LFS to regular Git
+ * Whether the operation succeeded
  */
-"Export" | 
+outcome: OperationOutcome; 
 /**
- * Show migration info without making changes
+ * Error message for failed operations
  */
-"Info"
+error: string | null }
 /**
- * Options for LFS migrate operation
+ * Filters for querying the operation log
  */
-export type LfsMigrateOptions = { 
+export type OperationLogFilter = { 
 /**
- * Migration mode: import or export
+ * Only entries at or after this time
  */
-mode: LfsMigrateMode; 
+from: string | null; 
 /**
- * File patterns to include
+ * Only entries before this time
  */
-include: string[]; 
+to: string | null; 
 /**
- * File patterns to exclude
+ * Only entries in these categories (empty means all)
  */
-exclude: string[]; 
+categories?: OperationCategory[]; 
 /**
- * Include all refs
+ * Only entries with this outcome
  */
-everything: boolean; 
+outcome: OperationOutcome | null; 
 /**
- * Specific refs to migrate
+ * Only entries for this repository
  */
-refs: string[]; 
+repoPath: string | null; 
 /**
- * Rewrite history (use with caution)
+ * Free-text search over operation names and redacted parameters
  */
-above: number | null }
+search: string | null; 
 /**
- * Options for LFS prune operation
+ * Inactivity gap in minutes that starts a new session (default 30)
  */
-export type LfsPruneOptions = { 
+sessionGapMinutes: number | null; 
 /**
- * Dry run - show what would be pruned
+ * Maximum number of entries to return (default 100)
  */
-dryRun: boolean; 
+limit: number | null; 
 /**
- * Verify remote copies before pruning
+ * Number of entries to skip
  */
-verifyRemote: boolean }
+skip: number | null }
 /**
- * Result of LFS prune operation
+ * Paginated operation log response
  */
-export type LfsPruneResult = { 
+export type OperationLogPage = { 
 /**
- * Whether the operation succeeded
+ * Entries on this page, newest first
  */
-success: boolean; 
+items: OperationLogEntry[]; 
 /**
- * Message describing the result
+ * Whether more entries match the filter
  */
-message: string; 
+hasMore: boolean; 
 /**
- * Number of objects pruned
+ * Total number of entries matching the filter
  */
-objectsPruned: number; 
+totalCount: number; 
 /**
- * Space reclaimed in bytes
+ * Sessions across all matching entries, newest first
  */
-spaceReclaimed: number }
+sessions: OperationSession[] }
 /**
- * Options for LFS pull operation
+ * Whether a logged operation succeeded
  */
-export type LfsPullOptions = { 
+export type OperationOutcome = "Success" | "Failure"
 /**
- * Remote to pull from
+ * A run of operations without a gap longer than the session threshold
  */
-remote: string | null }
+export type OperationSession = { 
 /**
- * Options for LFS push operation
+ * Time of the first operation in the session
  */
-export type LfsPushOptions = { 
+start: string; 
 /**
- * Remote to push to
+ * Time of the last operation in the session
  */
-remote: string | null; 
+end: string; 
 /**
- * Push all local LFS objects
+ * Session length in seconds
  */
-all: boolean; 
+durationSeconds: number; 
 /**
- * Dry run - don't actually push
+ * Number of operations in the session
  */
-dryRun: boolean }
+operationCount: number; 
 /**
- * Result of an LFS operation
+ * Number of failed operations in the session
  */
-export type LfsResult = { 
+failureCount: number; 
 /**
- * Whether the operation succeeded
+ * Repositories touched during the session
  */
-success: boolean; 
+repositories: string[] }
 /**
- * Message describing the result
+ * Operation currently in progress
  */
-message: string; 
+export type OperationState = 
 /**
- * Files affected by the operation
+ * No operation in progress
  */
-affectedFiles: string[] }
+"None" | 
 /**
- * Status of Git LFS in the repository
+ * Merge in progress
  */
-export type LfsStatus = { 
+{ Merging: { 
 /**
- * Whether git-lfs is installed on the system
+ * Branch being merged
  */
-isInstalled: boolean; 
+branch: string | null; 
 /**
- * Version of git-lfs if installed
+ * Commit being merged (`MERGE_HEAD`)
  */
-version: string | null; 
+current_commit: OperationCommit | null; 
 /**
- * Whether LFS is initialized in the repository
+ * Branch the merge is going into
  */
-isInitialized: boolean; 
+original_branch: string | null; 
 /**
- * Number of tracked patterns
+ * Paths with unresolved conflicts
  */
-trackedPatternsCount: number; 
+conflicted_paths: string[] } } | 
 /**
- * Number of LFS files in the repository
+ * Rebase in progress
  */
-lfsFilesCount: number }
+{ Rebasing: { 
 /**
- * A tracked LFS pattern
+ * Branch being rebased onto
  */
-export type LfsTrackedPattern = { 
+onto: string | null; 
 /**
- * The pattern (e.g., "*.psd", "assets/*.png")
+ * Current step
  */
-pattern: string; 
+current: number | null; 
 /**
- * Source file where the pattern is defined (usually .gitattributes)
+ * Total steps
  */
-sourceFile: string }
+total: number | null; 
 /**
- * Options for listing remotes
+ * Action that caused the rebase to pause (Edit or Reword)
  */
-export type ListRemoteOptions = { 
+paused_action?: RebaseAction | null; 
 /**
- * Sort order (defaults to Alphabetical)
+ * Branch being rebased (stripped of refs/heads/ prefix)
  */
-sort?: RemoteSortOrder; 
+head_name?: string | null; 
 /**
- * Maximum number of remotes to return
+ * Commit the rebase stopped at
  */
-limit: number | null }
+current_commit: OperationCommit | null; 
 /**
- * Options for listing submodules
+ * Paths with unresolved conflicts
  */
-export type ListSubmoduleOptions = { 
+conflicted_paths: string[] } } | 
 /**
- * Sort order (defaults to Alphabetical)
+ * Cherry-pick in progress
  */
-sort?: SubmoduleSortOrder; 
+{ CherryPicking: { 
 /**
- * Maximum number of submodules to return
+ * Commit being cherry-picked
  */
-limit: number | null }
+commit: string | null; 
 /**
- * Options for listing tags
+ * Commit being cherry-picked, with its summary
  */
-export type ListTagsOptions = { 
+current_commit: OperationCommit | null; 
 /**
- * Filter pattern (glob-style)
+ * Current step of a multi-commit pick
  */
-pattern: string | null; 
+current: number | null; 
 /**
- * Sort order (defaults to Alphabetical)
+ * Total steps of a multi-commit pick
  */
-sort?: TagSortOrder; 
+total: number | null; 
 /**
- * Maximum number of tags to return
+ * Branch the picks are applied to
  */
-limit: number | null }
-export type LogOptions = { limit: number | null; skip: number | null; fromRef: string | null; branchFilter?: BranchFilterType; includeRemotes?: boolean; sortOrder?: SortOrder }
+original_branch: string | null; 
 /**
- * Menu item IDs for custom actions
+ * Paths with unresolved conflicts
  */
-export type MenuAction = "NewWindow" | "OpenRepository" | "CloseRepository" | "Settings" | "Refresh" | "ToggleSidebar" | "Fetch" | "Pull" | "Push" | "StageAll" | "UnstageAll" | "Commit" | "NewBranch" | "NewTag" | "Stash" | "PopStash"
+conflicted_paths: string[] } } | 
 /**
- * Menu action triggered
+ * Revert in progress
  */
-export type MenuActionEvent = { actionId: string }
+{ Reverting: { 
 /**
- * Merge method for pull requests
+ * Commit being reverted
  */
-export type MergeMethod = "Merge" | "Squash" | "Rebase"
+commit: string | null; 
 /**
- * Options for merge operations
+ * Commit being reverted, with its summary
  */
-export type MergeOptions = { 
+current_commit: OperationCommit | null; 
 /**
- * The branch to merge into the current branch
+ * Current step of a multi-commit revert
  */
-branch: string; 
+current: number | null; 
 /**
- * Custom commit message (optional)
+ * Total steps of a multi-commit revert
  */
-message: string | null; 
+total: number | null; 
 /**
- * If true, always create a merge commit (no fast-forward)
+ * Branch the reverts are applied to
  */
-noFf: boolean; 
+original_branch: string | null; 
 /**
- * If true, squash all commits into a single commit
+ * Paths with unresolved conflicts
  */
-squash: boolean; 
+conflicted_paths: string[] } } | 
 /**
- * If true, only fast-forward (fail if not possible)
+ * Bisect in progress
  */
-ffOnly: boolean; 
+{ Bisecting: { 
 /**
- * If true, don't create a commit (stage changes only)
+ * Current commit being tested
  */
-noCommit: boolean }
+current_commit: string | null; 
 /**
- * Options for merging a pull request
+ * Approximate steps remaining
  */
-export type MergePrOptions = { mergeMethod: MergeMethod; commitTitle: string | null; commitMessage: string | null }
+steps_remaining: number | null } } | 
 /**
- * Result of a merge operation
+ * An operation is in progress but its state files are missing or unreadable
  */
-export type MergeResult = { 
+"Unknown"
 /**
- * Whether the merge was successful
+ * A continue or skip moved the merge, rebase, cherry-pick or revert in progress on a step
  */
-success: boolean; 
+export type OperationStateChangedEvent = { path: string; state: OperationState }
 /**
- * Type of merge that occurred
+ * A submodule whose checked-out commit differs from the one recorded by the superproject
  */
-mergeType: MergeType; 
+export type OutOfSyncSubmodule = { 
 /**
- * New commit OID if a merge commit was created
+ * Submodule name
  */
-commitOid: string | null; 
+name: string; 
 /**
- * List of conflicted files if merge has conflicts
+ * Path within the parent repository
  */
-conflicts: ConflictedFile[]; 
+path: string; 
 /**
- * Informational message
+ * Commit recorded by the superproject's HEAD
  */
-message: string }
+recordedOid: string; 
 /**
- * Type of merge that occurred
+ * Commit currently checked out in the submodule
  */
-export type MergeType = 
+checkedOutOid: string }
 /**
- * Already up to date
+ * Result of a patch operation
  */
-"UpToDate" | 
+export type PatchResult = { 
 /**
- * Fast-forward merge
+ * Message describing the result of the operation
  */
-"FastForward" | 
+message: string; 
 /**
- * Normal merge (with merge commit)
+ * Paths to created or applied patch files
  */
-"Normal" | 
+patches: string[] }
 /**
- * Merge resulted in conflicts
+ * Forms of a repository path suitable for copying to the clipboard
  */
-"Conflicted"
+export type PathCopyForms = { 
 /**
- * Notification from integration provider
+ * Absolute path with the platform's separators
  */
-export type Notification = { provider: ProviderType; id: string; reason: NotificationReason; unread: boolean; subjectTitle: string; subjectType: NotificationSubjectType; subjectUrl: string | null; repository: string; updatedAt: string; url: string }
+absolute: string; 
 /**
- * Notification reason
+ * Repository-relative path with forward slashes
  */
-export type NotificationReason = "Assigned" | "Author" | "Comment" | "Invitation" | "Manual" | "Mention" | "ReviewRequested" | "SecurityAlert" | "StateChange" | "Subscribed" | "TeamMention" | "CiActivity"
+repoRelative: string; 
 /**
- * Notification subject type
+ * `file://` URL of the absolute path
  */
-export type NotificationSubjectType = "Issue" | "PullRequest" | "Release" | "Discussion" | "Commit" | "RepositoryVulnerabilityAlert" | "CheckSuite"
+fileUrl: string }
 /**
- * Paginated notifications response
+ * Performance settings stored for one repository
  */
-export type NotificationsPage = { items: Notification[]; hasMore: boolean }
+export type PerformanceConfig = { profile: PerformanceProfile; overrides?: PerformanceOverrides }
 /**
- * OAuth callback received from deep link
+ * Values set for one repository; each wins over the profile and global settings
  */
-export type OAuthCallbackEvent = { provider: ProviderType; code: string; state: string | null }
+export type PerformanceOverrides = { fileWatcherDebounceMs?: number | null; backgroundFetch?: boolean | null; skipAheadBehind?: boolean | null; graphPageSize?: number | null }
 /**
- * Operation currently in progress
+ * Bundle of defaults a repository runs with
  */
-export type OperationState = 
+export type PerformanceProfile = 
 /**
- * No operation in progress
+ * `Standard` or `LargeRepo`, picked from the repository's size when it is opened
  */
-"None" | 
+"Auto" | "Standard" | 
 /**
- * Merge in progress
+ * Cheaper defaults for repositories with very many files, refs, or objects
  */
-{ Merging: { 
+"LargeRepo" | 
 /**
- * Branch being merged
+ * `Standard` defaults, tuned knob by knob through the repository's overrides
  */
-branch: string | null } } | 
+"Custom"
 /**
- * Rebase in progress
+ * Which profile a repository runs with and why
  */
-{ Rebasing: { 
+export type PerformanceReadiness = { config: PerformanceConfig; 
 /**
- * Branch being rebased onto
+ * The profile in effect; never `Auto`
  */
-onto: string | null; 
+effectiveProfile: PerformanceProfile; 
 /**
- * Current step
+ * Measurements taken when the repository was opened, if `Auto` needed them
  */
-current: number | null; 
+signals: RepositorySizeSignals | null; 
 /**
- * Total steps
+ * Why `effective_profile` was chosen
  */
-total: number | null; 
+reason: string; settings: PerformanceSettings }
 /**
- * Action that caused the rebase to pause (Edit or Reword)
+ * Effective value of every knob a profile sets
  */
-paused_action?: RebaseAction | null; 
+export type PerformanceSettings = { 
 /**
- * Branch being rebased (stripped of refs/heads/ prefix)
+ * Quiet period before file changes are reported; applies when the watcher starts
  */
-head_name?: string | null } } | 
+fileWatcherDebounceMs: number; 
 /**
- * Cherry-pick in progress
+ * Whether background fetch runs when the repository has no fetch settings of its own
  */
-{ CherryPicking: { 
+backgroundFetch: boolean; 
 /**
- * Commit being cherry-picked
+ * Leave branch ahead/behind counts unset instead of walking each branch
  */
-commit: string | null } } | 
+skipAheadBehind: boolean; 
 /**
- * Revert in progress
+ * Commits per graph page when a request sets no limit
  */
-{ Reverting: { 
+graphPageSize: number }
 /**
- * Commit being reverted
+ * Pull request state
  */
-commit: string | null } } | 
+export type PrState = "Open" | "Closed" | "Merged" | "All"
 /**
- * Bisect in progress
+ * A precondition that no longer holds when a rewrite is executed
  */
-{ Bisecting: { 
+export type PreconditionViolation = 
 /**
- * Current commit being tested
+ * A ref moved, appeared or disappeared
  */
-current_commit: string | null; 
+{ Ref: { refname: string; 
 /**
- * Approximate steps remaining
+ * OID recorded when planning; `None` when the ref did not exist
  */
-steps_remaining: number | null } }
+expected: string | null; 
 /**
- * Result of a patch operation
+ * OID the ref holds now; `None` when it no longer exists
  */
-export type PatchResult = { 
+actual: string | null; 
 /**
- * Message describing the result of the operation
+ * Commits reachable from `actual` but not from `expected`
  */
-message: string; 
+ahead: number | null; 
 /**
- * Paths to created or applied patch files
+ * Commits reachable from `expected` but not from `actual`
  */
-patches: string[] }
+behind: number | null } } | 
 /**
- * Pull request state
+ * The index was written, e.g. by an external `git add`
  */
-export type PrState = "Open" | "Closed" | "Merged" | "All"
+{ Index: { expected: string; actual: string } }
 export type ProgressStage = "Connecting" | "Counting" | "Compressing" | "Receiving" | "Resolving" | "Writing" | "Complete" | "Failed" | "Cancelled"
 /**
  * Supported integration providers
  */
 export type ProviderType = "GitHub" | "GitLab" | "Bitbucket" | "Gitea"
+/**
+ * Result of `prune_dry_run`
+ */
+export type PruneDryRunResult = { objects: UnreachableObject[]; 
+/**
+ * Bytes that pruning would free
+ */
+totalSize: number }
 /**
  * Options for pull operations
  */
@@ -3080,19 +5846,63 @@ rebase: boolean;
 /**
  * Fast-forward only
  */
-ffOnly: boolean }
+ffOnly: boolean; 
+/**
+ * Override `fetch.recurseSubmodules` for the fetch step
+ */
+recurseSubmodules: FetchRecurseSubmodules | null; 
+/**
+ * Trailers for the merge commit created when the pull cannot fast-forward
+ */
+trailers?: CommitTrailerOptions }
 /**
  * Pull request summary for list views
  */
-export type PullRequest = { provider: ProviderType; number: number; title: string; state: PrState; author: IntegrationUser; sourceBranch: string; targetBranch: string; draft: boolean; createdAt: string; updatedAt: string; url: string }
+export type PullRequest = { provider: ProviderType; number: number; title: string; state: PrState; author: IntegrationUser; sourceBranch: string; 
+/**
+ * `owner/repo` the source branch lives in; differs from the PR's repo for forks
+ */
+sourceRepo?: string | null; targetBranch: string; 
+/**
+ * Commit the source branch points at on the provider
+ */
+headSha?: string | null; draft: boolean; createdAt: string; updatedAt: string; url: string }
 /**
  * Detailed pull request information
  */
-export type PullRequestDetail = ({ provider: ProviderType; number: number; title: string; state: PrState; author: IntegrationUser; sourceBranch: string; targetBranch: string; draft: boolean; createdAt: string; updatedAt: string; url: string }) & { body: string | null; additions: number; deletions: number; changedFiles: number; commitsCount: number; commentsCount: number; mergeable: boolean | null; labels: IntegrationLabel[]; assignees: IntegrationUser[]; reviewers: IntegrationUser[] }
+export type PullRequestDetail = ({ provider: ProviderType; number: number; title: string; state: PrState; author: IntegrationUser; sourceBranch: string; 
+/**
+ * `owner/repo` the source branch lives in; differs from the PR's repo for forks
+ */
+sourceRepo?: string | null; targetBranch: string; 
+/**
+ * Commit the source branch points at on the provider
+ */
+headSha?: string | null; draft: boolean; createdAt: string; updatedAt: string; url: string }) & { body: string | null; additions: number; deletions: number; changedFiles: number; commitsCount: number; commentsCount: number; 
+/**
+ * `None` while the provider is still computing mergeability (e.g. right after a push)
+ */
+mergeable: boolean | null; labels: IntegrationLabel[]; assignees: IntegrationUser[]; reviewers: IntegrationUser[] }
+/**
+ * A pull request was re-fetched after its source branch was pushed
+ */
+export type PullRequestRefreshedEvent = { provider: ProviderType; owner: string; repo: string; pullRequest: PullRequestDetail }
 /**
  * Paginated pull requests response
  */
 export type PullRequestsPage = { items: PullRequest[]; hasMore: boolean }
+/**
+ * Result of a pull operation
+ */
+export type PullResult = { 
+/**
+ * Submodule paths whose new commits were fetched along with the superproject
+ */
+fetchedSubmodules: string[]; 
+/**
+ * Submodules whose checkout no longer matches the commit recorded after the pull
+ */
+outOfSyncSubmodules: OutOfSyncSubmodule[] }
 /**
  * Options for push operations
  */
@@ -3118,6 +5928,14 @@ export type PushStatus = "Ok" | "Rejected" | "UpToDate" | "RemoteRejected"
  * A pushed reference result
  */
 export type PushedRef = { refName: string; status: PushStatus; message: string | null }
+/**
+ * Quick-open matches across all known repositories
+ */
+export type QuickOpenResults = { repositories: RecentRepository[]; 
+/**
+ * Bookmarks whose label or notes match, from any repository
+ */
+bookmarks: Bookmark[] }
 /**
  * Action for each commit in interactive rebase
  */
@@ -3177,7 +5995,11 @@ target: RebaseTarget;
 /**
  * Number of commits on target since merge-base
  */
-targetCommitsAhead: number }
+targetCommitsAhead: number; 
+/**
+ * HEAD and the target as they were when the preview was computed
+ */
+preconditions: RewritePreconditions }
 /**
  * Detailed rebase progress state (parsed from .git/rebase-merge or .git/rebase-apply)
  */
@@ -3262,15 +6084,63 @@ shortOid: string;
  * Commit summary
  */
 summary: string }
-export type RecentRepository = { path: string; name: string; lastOpened: string; exists: boolean; currentBranch: string | null; isPinned: boolean; displayPath: string }
+export type RecentRepository = { path: string; name: string; lastOpened: string; exists: boolean; currentBranch: string | null; isPinned: boolean; displayPath: string; 
+/**
+ * Branch checked out when the repository was last opened or closed in Axis
+ */
+lastBranch?: string | null }
+/**
+ * Working tree and upstream summary of a recent repository
+ */
+export type RecentRepositoryStatus = { 
+/**
+ * Files with staged, unstaged, or untracked changes
+ */
+dirtyFiles: number; 
+/**
+ * Upstream of the current branch, if any
+ */
+upstream: string | null; ahead: number; behind: number }
+/**
+ * A recent repository with its live status
+ */
+export type RecentRepositoryWithStatus = ({ path: string; name: string; lastOpened: string; exists: boolean; currentBranch: string | null; isPinned: boolean; displayPath: string; 
+/**
+ * Branch checked out when the repository was last opened or closed in Axis
+ */
+lastBranch?: string | null }) & { 
+/**
+ * `None` if the repository is missing, unreadable, or timed out
+ */
+status: RecentRepositoryStatus | null; 
+/**
+ * Whether inspecting the repository took too long (e.g. an unreachable network drive)
+ */
+timedOut: boolean }
 /**
  * A ref (branch, tag) changed
  */
 export type RefChangedEvent = { refName: string }
+/**
+ * OID a ref held when a rewrite was planned
+ */
+export type RefPrecondition = { 
+/**
+ * Full ref name, or `HEAD`
+ */
+refname: string; 
+/**
+ * OID the ref pointed at; `None` when it did not exist
+ */
+oid: string | null }
 /**
  * Type of reference
  */
-export type RefType = "LocalBranch" | "RemoteBranch" | "Tag"
+export type RefType = "LocalBranch" | "RemoteBranch" | "Tag" | 
+/**
+ * A stash entry, `stash@{index}`
+ */
+{ Stash: { index: number; message: string } }
 export type RefUpdateStatus = "FastForward" | "Forced" | "New" | "Deleted" | "Rejected" | "UpToDate"
 /**
  * Parsed reflog action type for categorization and UI display
@@ -3340,10 +6210,46 @@ limit: number | null;
  * Number of entries to skip (for pagination)
  */
 skip: number | null }
+/**
+ * Number of reflog entries kept for one ref
+ */
+export type ReflogSize = { refName: string; entries: number }
 /**
  * Represents a Git remote
  */
 export type Remote = { name: string; url: string | null; pushUrl: string | null; fetchRefspecs: string[]; pushRefspecs: string[] }
+/**
+ * A branch advertised by a remote, listed without fetching
+ */
+export type RemoteBranchInfo = { 
+/**
+ * Branch name without the `refs/heads/` prefix
+ */
+name: string; oid: string; 
+/**
+ * Summary of the tip commit; `None` until the commit has been fetched
+ */
+lastCommitSummary: string | null }
+/**
+ * Result of checking that a remote can be reached with the configured credentials
+ */
+export type RemoteConnectionResult = { 
+/**
+ * The remote accepted the connection
+ */
+success: boolean; 
+/**
+ * Time spent connecting, in milliseconds
+ */
+latencyMs: number; 
+/**
+ * Why the connection failed
+ */
+error: string | null }
+/**
+ * Outcome of fetching one remote during a background cycle
+ */
+export type RemoteFetchOutcome = { remote: string; updatedRefs: UpdatedRef[]; error: string | null }
 /**
  * Remote fetch completed with new commits
  */
@@ -3361,88 +6267,332 @@ export type RemoteSshKeyMapping = {
  */
 remoteName: string; 
 /**
- * Path to the SSH key
+ * Path to the SSH key
+ */
+sshKeyPath: string }
+/**
+ * A tag advertised by a remote, listed without fetching
+ */
+export type RemoteTagInfo = { 
+/**
+ * Tag name without the `refs/tags/` prefix
+ */
+name: string; 
+/**
+ * Object the tag ref points to: the tag object for annotated tags
+ */
+oid: string }
+/**
+ * Options for removing paths from unpushed history
+ */
+export type RemovePathOptions = { 
+/**
+ * Proceed even if other local branches contain the commits being rewritten
+ */
+acknowledgeOtherBranches?: boolean; 
+/**
+ * Sign the rewritten commits (falls back to the `sign_commits` setting)
+ */
+sign: boolean | null; 
+/**
+ * Preconditions returned with the preview; the removal aborts if any no longer holds
+ */
+preconditions?: RewritePreconditions | null }
+/**
+ * Preview of a path removal from unpushed history
+ */
+export type RemovePathPreview = { 
+/**
+ * Branch that will be rewritten
+ */
+branch: string; 
+/**
+ * Upstream used as the pushed boundary, if configured
+ */
+upstream: string | null; 
+/**
+ * Number of unpushed commits on the branch
+ */
+unpushedCount: number; 
+/**
+ * Unpushed commits that contain the paths, oldest first
+ */
+affectedCommits: AffectedCommit[]; 
+/**
+ * Total size in bytes of the distinct blobs being removed
+ */
+totalBytes: number; 
+/**
+ * Other local branches that also contain commits in the rewritten range
+ */
+otherBranches: string[]; 
+/**
+ * State the preview was computed from
+ */
+preconditions: RewritePreconditions }
+/**
+ * Result of removing paths from unpushed history
+ */
+export type RemovePathResult = { 
+/**
+ * Branch that was rewritten
+ */
+branch: string; 
+/**
+ * Branch tip before the rewrite
+ */
+oldHead: string; 
+/**
+ * Branch tip after the rewrite
+ */
+newHead: string; 
+/**
+ * Old to new OID mapping for every commit that changed, oldest first
+ */
+rewritten: RewrittenCommit[]; 
+/**
+ * Whether the removed content is absent from every commit reachable from the branch
+ */
+removedFromBranch: boolean; 
+/**
+ * Where the removed content may still exist and how to purge it
+ */
+note: string }
+/**
+ * Options for removing a worktree
+ */
+export type RemoveWorktreeOptions = { 
+/**
+ * Path of the worktree to remove
+ */
+path: string; 
+/**
+ * Force removal even with uncommitted changes
+ */
+force: boolean }
+/**
+ * A path removed from a single commit's tree
+ */
+export type RemovedPathEntry = { 
+/**
+ * Repository-relative path
+ */
+path: string; 
+/**
+ * Size in bytes of the content at this path (sum of blobs for directories)
+ */
+size: number }
+export type Repository = { id: string; name: string; path: string; isBare: boolean; isUnborn: boolean; currentBranch: string | null; state: RepositoryState; 
+/**
+ * Ref HEAD points to when that ref no longer exists (state is `BrokenHead`)
+ */
+brokenHead?: string | null }
+/**
+ * Everything that changed in a repository during one debounce window
+ */
+export type RepositoryChangedEvent = { 
+/**
+ * Repository root
+ */
+path: string; 
+/**
+ * Changed worktree paths relative to the root, ignored files excluded
+ */
+worktreePaths: string[]; 
+/**
+ * More worktree paths changed than are listed
+ */
+worktreeOverflow: boolean; indexChanged: boolean; 
+/**
+ * HEAD moved (commit, checkout, reset)
+ */
+headChanged: boolean; 
+/**
+ * Loose refs that changed, e.g. `refs/heads/main`
+ */
+refsChanged: string[]; packedRefsChanged: boolean; configChanged: boolean }
+/**
+ * Repository has changes (for inactive repo tab badges)
+ */
+export type RepositoryDirtyEvent = { path: string }
+/**
+ * Health report for the open repository
+ */
+export type RepositoryHealth = { 
+/**
+ * Repository working directory
+ */
+path: string; 
+/**
+ * Set when the repository lives inside a cloud-synced folder
+ */
+syncFolder: SyncFolderWarning | null; 
+/**
+ * Object stores borrowed from and by other repositories
+ */
+objectSharing: ObjectSharing }
+/**
+ * Repository-specific settings
+ */
+export type RepositorySettings = { 
+/**
+ * Repository user.name (from .git/config)
+ */
+userName: string | null; 
+/**
+ * Repository user.email (from .git/config)
+ */
+userEmail: string | null; 
+/**
+ * Global user.name (for placeholder display)
+ */
+globalUserName: string | null; 
+/**
+ * Global user.email (for placeholder display)
+ */
+globalUserEmail: string | null; 
+/**
+ * List of remotes
+ */
+remotes: Remote[]; 
+/**
+ * Repository signing format (from .git/config local)
+ */
+signingFormat: SigningFormat | null; 
+/**
+ * Repository signing key (from .git/config local)
+ */
+signingKey: string | null }
+/**
+ * Result of `get_repository_size_report`
+ */
+export type RepositorySizeReport = { objects: ObjectStoreSize; 
+/**
+ * Largest blobs, biggest first
+ */
+largestBlobs: LargeBlob[]; 
+/**
+ * Refs with reflog entries, most entries first
+ */
+reflogs: ReflogSize[] }
+/**
+ * Size measurements `Auto` decides from, cheap enough to take on every open
+ */
+export type RepositorySizeSignals = { 
+/**
+ * Entries in the index, read from its header
+ */
+trackedFiles: number; 
+/**
+ * References of every kind, loose and packed
+ */
+refs: number; 
+/**
+ * Bytes used by pack and pack index files
+ */
+packBytes: number }
+export type RepositoryState = "Clean" | "Merging" | "Rebasing" | "RebasingInteractive" | "CherryPicking" | "Reverting" | "Bisecting" | "ApplyMailbox" | "ApplyMailboxOrRebase" | 
+/**
+ * HEAD is a symref to a branch that was deleted outside the app
+ */
+"BrokenHead"
+export type RepositoryStatus = { staged: FileStatus[]; unstaged: FileStatus[]; untracked: FileStatus[]; conflicted: FileStatus[] }
+/**
+ * Options for `get_repository_status`
+ */
+export type RepositoryStatusOptions = { 
+/**
+ * List every untracked file instead of collapsing untracked directories
+ */
+recurseUntracked?: boolean }
+/**
+ * Reset mode for reset operations
+ */
+export type ResetMode = 
+/**
+ * Keep changes staged
+ */
+"Soft" | 
+/**
+ * Keep changes unstaged
  */
-sshKeyPath: string }
+"Mixed" | 
 /**
- * Options for removing a worktree
+ * Discard all changes
  */
-export type RemoveWorktreeOptions = { 
+"Hard" | 
 /**
- * Path of the worktree to remove
+ * Move HEAD but keep local changes, aborting if they touch files that differ
  */
-path: string; 
+"Keep"
 /**
- * Force removal even with uncommitted changes
+ * Options for reset operations
  */
-force: boolean }
-export type Repository = { id: string; name: string; path: string; isBare: boolean; isUnborn: boolean; currentBranch: string | null; state: RepositoryState }
+export type ResetOptions = { 
 /**
- * Repository has changes (for inactive repo tab badges)
+ * Target commit/ref to reset to
  */
-export type RepositoryDirtyEvent = { path: string }
+target: string; 
 /**
- * Repository-specific settings
+ * Reset mode (soft, mixed, hard, keep)
  */
-export type RepositorySettings = { 
+mode: ResetMode; 
 /**
- * Repository user.name (from .git/config)
+ * Required for hard resets: acknowledge that uncommitted changes will be discarded
  */
-userName: string | null; 
+confirmDiscard?: boolean; 
 /**
- * Repository user.email (from .git/config)
+ * Only report what would be discarded, without resetting
  */
-userEmail: string | null; 
+preview?: boolean }
 /**
- * Global user.name (for placeholder display)
+ * Result of a reset (or a reset preview)
  */
-globalUserName: string | null; 
+export type ResetResult = { 
 /**
- * Global user.email (for placeholder display)
+ * Whether the reset was performed (false for previews)
  */
-globalUserEmail: string | null; 
+applied: boolean; 
 /**
- * List of remotes
+ * Files whose uncommitted changes are destroyed by a hard reset
  */
-remotes: Remote[]; 
+discardedFiles: string[] }
 /**
- * Repository signing format (from .git/config local)
+ * A bookmark together with the result of resolving its target
  */
-signingFormat: SigningFormat | null; 
+export type ResolvedBookmark = { bookmark: Bookmark; status: BookmarkStatus; 
 /**
- * Repository signing key (from .git/config local)
+ * Why the target is broken, or what was repaired
  */
-signingKey: string | null }
-export type RepositoryState = "Clean" | "Merging" | "Rebasing" | "RebasingInteractive" | "CherryPicking" | "Reverting" | "Bisecting" | "ApplyMailbox" | "ApplyMailboxOrRebase"
-export type RepositoryStatus = { staged: FileStatus[]; unstaged: FileStatus[]; untracked: FileStatus[]; conflicted: FileStatus[] }
+detail: string | null }
 /**
- * Reset mode for reset operations
+ * Where a path that existed at some ref lives in the working tree now
  */
-export type ResetMode = 
+export type ResolvedPath = { 
 /**
- * Keep changes staged
+ * Current repository-relative path, or the last known one if the file was deleted
  */
-"Soft" | 
+path: string; 
 /**
- * Keep changes unstaged
+ * Whether the file exists in the working tree at `path`
  */
-"Mixed" | 
+exists: boolean; 
 /**
- * Discard all changes
+ * Whether the file was renamed or moved since the ref
  */
-"Hard"
+renamed: boolean; 
 /**
- * Options for reset operations
+ * Commit that deleted the file, if it was deleted after being last renamed
  */
-export type ResetOptions = { 
+deletedIn: string | null; 
 /**
- * Target commit/ref to reset to
+ * Set when `path` now lies inside a submodule
  */
-target: string; 
+submodule: SubmodulePathLocation | null; 
 /**
- * Reset mode (soft, mixed, hard)
+ * The walk stopped at `PATH_RESOLVE_MAX_COMMITS` before reaching HEAD
  */
-mode: ResetMode }
+truncated: boolean }
 /**
  * Options for revert operations
  */
@@ -3475,6 +6625,57 @@ conflicts: ConflictedFile[];
  * Informational message
  */
 message: string }
+/**
+ * What rewording a commit will touch, computed before anything is rewritten
+ */
+export type RewordPlan = { 
+/**
+ * The commit being reworded
+ */
+commit: Commit; 
+/**
+ * Commits after it that are replayed unchanged, oldest first; empty when the commit
+ * is HEAD and is amended
+ */
+replayed: Commit[]; 
+/**
+ * HEAD, its branch and the index as they were when the plan was made
+ */
+preconditions: RewritePreconditions }
+/**
+ * Repository state a multi-phase rewrite was planned against. Returned with the plan
+ * and passed back on execution, which aborts if any of it changed in between.
+ */
+export type RewritePreconditions = { 
+/**
+ * Stamp of the plan these were captured for, unique within a session
+ */
+generation: number; refs: RefPrecondition[]; 
+/**
+ * Hash of the index entries when the rewrite depends on the index; all zeros when
+ * the repository had no index
+ */
+indexChecksum: string | null }
+/**
+ * Mapping of a rewritten commit to its replacement
+ */
+export type RewrittenCommit = { 
+/**
+ * Original commit OID
+ */
+oldOid: string; 
+/**
+ * Replacement commit OID
+ */
+newOid: string }
+/**
+ * How a commit lint rule is applied
+ */
+export type RuleLevel = "Off" | "Warning" | "Error"
+/**
+ * How many linted commits break a rule
+ */
+export type RuleViolationCount = { rule: CommitLintRuleId; commits: number }
 /**
  * Search options for commit search
  */
@@ -3503,6 +6704,18 @@ limit: number | null }
  * Search result
  */
 export type SearchResult = { commits: Commit[]; totalMatches: number }
+/**
+ * What `show_in_folder` showed in the file manager
+ */
+export type ShowInFolderResult = { 
+/**
+ * Absolute path that was shown
+ */
+path: string; 
+/**
+ * The requested path no longer exists, so its nearest existing folder was opened
+ */
+fallback: boolean }
 export type Signature = { name: string; email: string; timestamp: string }
 /**
  * Result of on-demand signature verification
@@ -3557,6 +6770,43 @@ error: string | null;
  */
 programUsed: string | null }
 export type SortOrder = "DateOrder" | "AncestorOrder"
+/**
+ * Outcome of spell checking a commit message
+ */
+export type SpellCheckResult = { 
+/**
+ * Dictionary the message was checked with, e.g. `en_US`
+ */
+language: string; findings: SpellingFinding[] }
+/**
+ * A word in a commit message the dictionary does not know. Lines and columns are
+ * 1-based and count characters, not bytes.
+ */
+export type SpellingFinding = { word: string; line: number; column: number; 
+/**
+ * Characters covered, starting at `column`
+ */
+length: number; 
+/**
+ * Replacements, best first
+ */
+suggestions: string[] }
+/**
+ * An identity currently loaded in the SSH agent
+ */
+export type SshAgentIdentity = { 
+/**
+ * Key fingerprint (SHA256)
+ */
+fingerprint: string; 
+/**
+ * Comment the key was added with (usually the key path or email)
+ */
+comment: string; 
+/**
+ * Key type as reported by the agent (`ED25519`, `RSA`, ...)
+ */
+keyType: string }
 /**
  * Represents an SSH key available for signing
  */
@@ -3637,6 +6887,50 @@ bits: number | null;
  * Creation timestamp (ISO 8601)
  */
 createdAt: string | null }
+/**
+ * A branch matching the stale criteria
+ */
+export type StaleBranch = { name: string; fullName: string; branchType: BranchType; targetOid: string; lastCommitSummary: string; lastCommitTime: string; lastAuthor: Signature; 
+/**
+ * Commits on the branch that are not on the base branch
+ */
+ahead: number; 
+/**
+ * Commits on the base branch that are not on the branch
+ */
+behind: number; 
+/**
+ * The tip is reachable from the base branch
+ */
+isMerged: boolean; 
+/**
+ * The tip is older than `older_than_days`
+ */
+isOld: boolean }
+/**
+ * Criteria for finding stale branches
+ */
+export type StaleBranchCriteria = { 
+/**
+ * Branch used for the merged check and ahead/behind counts (defaults to the current branch)
+ */
+baseBranch: string | null; 
+/**
+ * Match branches whose tip commit is older than this many days
+ */
+olderThanDays: number | null; 
+/**
+ * Match branches already merged into the base branch
+ */
+merged?: boolean; 
+/**
+ * Require every enabled criterion to match instead of any of them
+ */
+matchAll?: boolean; 
+/**
+ * Include remote-tracking branches
+ */
+includeRemote?: boolean }
 /**
  * Options for applying/popping a stash
  */
@@ -3685,6 +6979,14 @@ author: string;
  * Timestamp when the stash was created
  */
 timestamp: string }
+/**
+ * Hunks of one file's unstaged diff to stash
+ */
+export type StashHunkSelection = { path: string; 
+/**
+ * Indices into the file's workdir diff hunks, as shown in the diff view
+ */
+hunkIndices: number[] }
 /**
  * Result of a stash operation
  */
@@ -3721,7 +7023,56 @@ keepIndex: boolean;
  * Include ignored files
  */
 includeIgnored: boolean }
+/**
+ * Length of the periods `CommitMessageStats::trend` groups commits by
+ */
+export type StatsBucket = 
+/**
+ * Weeks starting on Monday, UTC
+ */
+"Week" | 
+/**
+ * Calendar months, UTC
+ */
+"Month"
+/**
+ * Commits in one period of `CommitMessageStats::trend`
+ */
+export type StatsTrendBucket = { 
+/**
+ * Start of the period, in seconds since the epoch
+ */
+start: number; commits: number; lintedCommits: number; conformingCommits: number }
 export type StatusType = "Untracked" | "Added" | "Modified" | "Deleted" | "Renamed" | "Copied" | "TypeChanged" | "Ignored" | "Conflicted"
+/**
+ * Source of the hunks of a file diffed as structured data
+ */
+export type StructuredDiffKind = 
+/**
+ * Jupyter notebook diffed cell by cell; hunk headers name the cell, and line
+ * numbers count within the cell's source
+ */
+"Notebook" | 
+/**
+ * JSON diffed after pretty-printing both sides with sorted keys
+ */
+"Json" | 
+/**
+ * A side could not be parsed or was too large, so the raw text diff is shown
+ */
+"RawFallback"
+/**
+ * Subjects up to `max_length` characters that are longer than the previous bucket's
+ */
+export type SubjectLengthBucket = { 
+/**
+ * Inclusive upper bound; `None` for the last, open-ended bucket
+ */
+maxLength: number | null; commits: number }
+/**
+ * Distribution of subject line lengths, in characters
+ */
+export type SubjectLengthStats = { average: number; median: number; max: number; buckets: SubjectLengthBucket[] }
 /**
  * Represents a Git submodule
  */
@@ -3758,6 +7109,18 @@ branch: string | null;
  * Status of the submodule
  */
 status: SubmoduleStatus }
+/**
+ * A path inside a submodule of the repository
+ */
+export type SubmodulePathLocation = { 
+/**
+ * Path of the submodule in the superproject
+ */
+submodulePath: string; 
+/**
+ * Path of the file relative to the submodule root
+ */
+relativePath: string }
 /**
  * Result of a submodule operation
  */
@@ -3802,6 +7165,42 @@ export type SubmoduleStatus =
  * Unknown status
  */
 "Unknown"
+/**
+ * A pull moved submodule pointers away from their checked-out commits
+ */
+export type SubmodulesOutOfSyncEvent = { path: string; submodules: OutOfSyncSubmodule[] }
+/**
+ * Warning for a repository stored inside a cloud-synced folder
+ */
+export type SyncFolderWarning = { 
+/**
+ * Sync client that owns the folder
+ */
+provider: SyncProvider; 
+/**
+ * Root of the synced folder containing the repository
+ */
+syncRoot: string; 
+/**
+ * Whether the `.git` directory itself is synced (the main corruption risk)
+ */
+gitDirInside: boolean; 
+/**
+ * What can go wrong
+ */
+risks: string[]; 
+/**
+ * How to avoid it
+ */
+remediations: string[]; 
+/**
+ * Whether the user dismissed this warning for the repository
+ */
+acknowledged: boolean }
+/**
+ * Cloud file sync client that owns a folder
+ */
+export type SyncProvider = "Dropbox" | "OneDrive" | "IcloudDrive" | "GoogleDrive"
 /**
  * Options for syncing submodules
  */
@@ -3854,6 +7253,43 @@ targetSummary: string | null;
  * Timestamp of the target commit
  */
 targetTime: string | null }
+/**
+ * Outcome for one tag, locally or on one remote
+ */
+export type TagOperationResult = { name: string; 
+/**
+ * Remote the result applies to; `None` for the local repository
+ */
+remote: string | null; success: boolean; error: string | null }
+/**
+ * Options for pushing tags to, or deleting them from, a remote
+ */
+export type TagPushOptions = { 
+/**
+ * Update every tag or none of them (`git push --atomic`)
+ */
+atomic?: boolean }
+/**
+ * Outcome for one tag of a remote tag push or delete
+ */
+export type TagPushOutcome = { name: string; status: TagPushStatus; 
+/**
+ * Reason given for a rejected or skipped tag
+ */
+message: string | null }
+/**
+ * Result of `tag_push`, `tag_push_all` and `tag_delete_remote`
+ */
+export type TagPushResult = { remote: string; 
+/**
+ * False when any tag was rejected
+ */
+success: boolean; outcomes: TagPushOutcome[] }
+export type TagPushStatus = "Pushed" | "Deleted" | 
+/**
+ * The remote already had the tag at the same object (or, for deletes, not at all)
+ */
+"UpToDate" | "Rejected"
 /**
  * Result of a tag operation
  */
@@ -3863,7 +7299,111 @@ export type TagResult = { success: boolean; message: string; tag: Tag | null }
  */
 export type TagSignature = { name: string; email: string; timestamp: string }
 export type TagSortOrder = "Alphabetical" | "AlphabeticalDesc" | "CreationDate" | "CreationDateDesc"
+/**
+ * Terminal emulator `open_terminal` launches
+ */
+export type TerminalApp = 
+/**
+ * macOS Terminal.app
+ */
+"Terminal" | "ITerm2" | "WezTerm" | "Alacritty" | "Kitty" | "GnomeTerminal" | "Konsole" | "WindowsTerminal" | "Cmd" | "PowerShell" | 
+/**
+ * `terminal_command_template` from the settings
+ */
+"Custom"
 export type Theme = "Light" | "Dark" | "System"
+/**
+ * An entry in the undo journal
+ */
+export type UndoEntry = { 
+/**
+ * Database row ID
+ */
+id: number; 
+/**
+ * When the snapshot was taken
+ */
+timestamp: string; 
+/**
+ * Repository the snapshot belongs to
+ */
+repoPath: string; 
+/**
+ * Operation the snapshot was taken for
+ */
+operation: UndoOperation; 
+/**
+ * Captured repository state
+ */
+snapshot: UndoSnapshot }
+/**
+ * Destructive operation that recorded an undo snapshot
+ */
+export type UndoOperation = "Merge" | "Rebase" | "Reset" | "Checkout" | "Discard" | "StashPop" | "Amend" | "Undo"
+/**
+ * Result of undoing the last operation
+ */
+export type UndoResult = { 
+/**
+ * The journal entry that was undone
+ */
+entry: UndoEntry; 
+/**
+ * Whether the full pre-operation state was restored
+ */
+complete: boolean; 
+/**
+ * Human-readable summary, including what could not be restored
+ */
+message: string }
+/**
+ * Repository state captured before a destructive operation
+ */
+export type UndoSnapshot = { 
+/**
+ * HEAD commit before the operation
+ */
+headOid: string; 
+/**
+ * Checked out branch, or `None` when HEAD was detached
+ */
+branch: string | null; 
+/**
+ * Dangling stash commit (`git stash create`) holding uncommitted changes
+ */
+stashOid: string | null; 
+/**
+ * Whether the snapshot covers the whole working tree (false if untracked files existed)
+ */
+complete: boolean }
+/**
+ * A loose object `git prune` would delete
+ */
+export type UnreachableObject = { oid: string; 
+/**
+ * Object type as reported by git (`blob`, `tree`, `commit` or `tag`)
+ */
+objectType: string; 
+/**
+ * Bytes used by the loose object file
+ */
+size: number }
+/**
+ * Unread notification count with a per-reason breakdown
+ */
+export type UnreadNotificationCount = { 
+/**
+ * Exact number of unread notifications
+ */
+total: number; 
+/**
+ * Unread notifications per reason, most frequent first
+ */
+byReason: NotificationReasonCount[]; 
+/**
+ * Whether the breakdown stopped at the page cap and covers fewer than `total`
+ */
+capped: boolean }
 export type UpdateDownloadProgressEvent = { downloaded: number; total: number | null }
 export type UpdateInfo = { version: string; date: string | null; body: string | null }
 /**
@@ -3906,6 +7446,22 @@ export type UpdatedRef = { refName: string; oldOid: string | null; newOid: strin
  * Watch error occurred
  */
 export type WatchErrorEvent = { message: string }
+/**
+ * Which side of the local checkout to compare against a ref
+ */
+export type WorkdirRefDiffMode = 
+/**
+ * Working tree files against the ref, ignoring what is staged
+ */
+"WorkdirOnly" | 
+/**
+ * Staged state against the ref (`git diff --cached <ref>`)
+ */
+"IndexOnly" | 
+/**
+ * Working tree and index together against the ref (`git diff <ref>`)
+ */
+"WorkdirWithIndex"
 /**
  * Represents a Git worktree
  */
@@ -12,7 +12,7 @@ import {
   DialogFooter,
   DialogTitle,
 } from '@/components/ui';
-import { getErrorMessage } from '@/lib/errorUtils';
+import { getErrorMessage, isAxisError } from '@/lib/errorUtils';
 import { branchApi } from '../../services/api';
 import { useRepositoryStore } from '../../store/repositoryStore';
 import type { Branch } from '../../types';
@@ -32,11 +32,13 @@ export function DeleteRemoteBranchDialog({
   const [force, setForce] = useState(false);
   const [isLoading, setIsLoading] = useState(false);
   const [error, setError] = useState<string | null>(null);
+  const [protectionWarning, setProtectionWarning] = useState<string | null>(null);
 
   useEffect(() => {
     if (open) {
       setForce(false);
       setError(null);
+      setProtectionWarning(null);
     }
   }, [open]);
 
@@ -50,15 +52,21 @@ export function DeleteRemoteBranchDialog({
   const handleDelete = async () => {
     if (!branch || !remoteName || !branchName) return;
 
+    const overrideProtectionCheck = protectionWarning !== null;
     setIsLoading(true);
     setError(null);
+    setProtectionWarning(null);
 
     try {
-      await branchApi.deleteRemote(remoteName, branchName, force);
+      await branchApi.deleteRemote(remoteName, branchName, force, overrideProtectionCheck);
       await Promise.all([loadBranches(), refreshRepository()]);
       onOpenChange(false);
     } catch (err) {
-      setError(getErrorMessage(err));
+      if (isAxisError(err) && err.type === 'ProtectedBranch') {
+        setProtectionWarning(t('branches.deleteRemote.protectedBranch', err.data));
+      } else {
+        setError(getErrorMessage(err));
+      }
     } finally {
       setIsLoading(false);
     }
@@ -102,6 +110,12 @@ export function DeleteRemoteBranchDialog({
             onCheckedChange={setForce}
           />
 
+          {protectionWarning && (
+            <Alert variant="warning" inline className="mt-3">
+              {protectionWarning}
+            </Alert>
+          )}
+
           {error && (
             <Alert variant="error" inline className="mt-3">
               {error}
@@ -114,7 +128,11 @@ export function DeleteRemoteBranchDialog({
             <Button variant="secondary">{t('common.cancel')}</Button>
           </DialogClose>
           <Button variant="destructive" onClick={handleDelete} disabled={isLoading}>
-            {isLoading ? t('common.deleting') : t('branches.deleteRemote.deleteButton')}
+            {isLoading
+              ? t('common.deleting')
+              : protectionWarning
+                ? t('branches.deleteRemote.deleteAnyway')
+                : t('branches.deleteRemote.deleteButton')}
           </Button>
         </DialogFooter>
       </DialogContent>
@@ -357,6 +357,22 @@ describe('DiffView workdir mode', () => {
     expect(screen.getByText('diff.hunk.discard')).toBeInTheDocument();
   });
 
+  it('should hide hunk actions for structured notebook diffs', () => {
+    const onStageHunk = vi.fn();
+    const onDiscardHunk = vi.fn();
+    render(
+      <DiffView
+        diff={{ ...mockDiff, structured: 'Notebook' }}
+        mode="workdir"
+        onStageHunk={onStageHunk}
+        onDiscardHunk={onDiscardHunk}
+      />
+    );
+
+    expect(screen.queryByText('diff.hunk.stage')).not.toBeInTheDocument();
+    expect(screen.queryByText('diff.hunk.discard')).not.toBeInTheDocument();
+  });
+
   it('should call onStageHunk when stage button is clicked', async () => {
     const onStageHunk = vi.fn().mockResolvedValue(undefined);
     render(<DiffView diff={mockDiff} mode="workdir" onStageHunk={onStageHunk} />);
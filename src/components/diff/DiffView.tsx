@@ -295,6 +295,20 @@ function ImageDiffHeader({ diff }: ImageDiffHeaderProps) {
 }
 
 // Generate a patch string for a specific hunk
+/** Hunk header with the detected enclosing function in place of git's context, if any */
+/** Structured hunks are rebuilt from parsed content and cannot be applied as patches */
+function hasPatchableHunks(diff: FileDiff): boolean {
+  return diff.structured !== 'Notebook' && diff.structured !== 'Json';
+}
+
+function formatHunkHeader(hunk: DiffHunk): string {
+  if (!hunk.context) {
+    return hunk.header.trim();
+  }
+  const range = hunk.header.match(/^@@[^@]*@@/)?.[0] ?? hunk.header.trim();
+  return `${range} ${hunk.context}`;
+}
+
 function generateHunkPatch(diff: FileDiff, hunk: DiffHunk): string {
   const oldPath = diff.oldPath || diff.newPath || '';
   const newPath = diff.newPath || diff.oldPath || '';
@@ -445,6 +459,7 @@ export function DiffView({
   }
 
   const filePath = diff.newPath || diff.oldPath || '';
+  const patchable = hasPatchableHunks(diff);
 
   // Show image preview for image files (including SVG which is text-based)
   if (isImageFile(filePath)) {
@@ -502,9 +517,9 @@ export function DiffView({
             loadingHunk={loadingHunk}
             wordWrap={settings?.diffWordWrap}
             showLineNumbers={settings?.showLineNumbers}
-            onStageHunk={onStageHunk ? handleStageHunk : undefined}
-            onUnstageHunk={onUnstageHunk ? handleUnstageHunk : undefined}
-            onDiscardHunk={onDiscardHunk ? handleDiscardHunk : undefined}
+            onStageHunk={onStageHunk && patchable ? handleStageHunk : undefined}
+            onUnstageHunk={onUnstageHunk && patchable ? handleUnstageHunk : undefined}
+            onDiscardHunk={onDiscardHunk && patchable ? handleDiscardHunk : undefined}
             lineSelectionMode={lineSelectionMode}
             lineSelection={lineSelection}
           />
@@ -515,9 +530,9 @@ export function DiffView({
             loadingHunk={loadingHunk}
             wordWrap={settings?.diffWordWrap}
             showLineNumbers={settings?.showLineNumbers}
-            onStageHunk={onStageHunk ? handleStageHunk : undefined}
-            onUnstageHunk={onUnstageHunk ? handleUnstageHunk : undefined}
-            onDiscardHunk={onDiscardHunk ? handleDiscardHunk : undefined}
+            onStageHunk={onStageHunk && patchable ? handleStageHunk : undefined}
+            onUnstageHunk={onUnstageHunk && patchable ? handleUnstageHunk : undefined}
+            onDiscardHunk={onDiscardHunk && patchable ? handleDiscardHunk : undefined}
             lineSelectionMode={lineSelectionMode}
             lineSelection={lineSelection}
           />
@@ -562,7 +577,8 @@ function DiffHeader({
   const fileName = diff.newPath || diff.oldPath || t('diff.unknownFile');
   const statusText = getStatusText(diff.status, t);
   const statusColorClass = getStatusColorClass(diff.status);
-  const showLineSelectionToggle = mode === 'workdir' || mode === 'staged';
+  const showLineSelectionToggle =
+    (mode === 'workdir' || mode === 'staged') && hasPatchableHunks(diff);
 
   return (
     <div className="flex items-center gap-3 h-10 px-3 bg-(--bg-header) border-b border-(--border-color) shrink-0">
@@ -764,7 +780,7 @@ function UnifiedDiff({
         <div key={hunkIndex} className="mb-1">
           <div className="flex items-center justify-between gap-2 py-1.5 px-3 bg-(--diff-hunk-bg) text-(--text-secondary) font-mono text-xs border-y border-(--border-color)">
             <span className="flex-1 overflow-hidden text-ellipsis whitespace-nowrap">
-              {hunk.header.trim()}
+              {formatHunkHeader(hunk)}
             </span>
             {mode === 'workdir' && onStageHunk && (
               <button
@@ -934,7 +950,7 @@ function SplitDiff({
         <div key={hunkIndex} className="mb-1">
           <div className="flex items-center gap-2 py-1 px-3 bg-(--diff-hunk-bg) text-(--text-secondary) font-mono text-xs border-y border-(--border-color)">
             <span className="flex-1 overflow-hidden text-ellipsis whitespace-nowrap">
-              {hunk.header.trim()}
+              {formatHunkHeader(hunk)}
             </span>
             {mode === 'workdir' && onStageHunk && (
               <button
@@ -22,6 +22,7 @@ import {
   FormField,
   Input,
 } from '@/components/ui';
+import { getErrorMessage } from '@/lib/errorUtils';
 import { gitflowApi } from '../../services/api';
 import type { GitFlowBranchType, GitFlowConfig, GitFlowResult } from '../../types';
 
@@ -181,7 +182,7 @@ export function GitFlowView({ onRefresh }: GitFlowViewProps) {
       }
     } catch (err) {
       console.error('Failed to finish branch:', err);
-      setError('Failed to finish branch');
+      setError(getErrorMessage(err));
     } finally {
       setIsLoading(false);
     }
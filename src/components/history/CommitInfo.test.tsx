@@ -252,4 +252,25 @@ describe('CommitInfo', () => {
     expect(screen.getByText('main')).toBeInTheDocument();
     expect(screen.getByText('v1.0.0')).toBeInTheDocument();
   });
+
+  it('should render stash refs with the stash color', () => {
+    const graphCommit: GraphCommit = {
+      ...mockCommit,
+      lane: 0,
+      parentEdges: [],
+      refs: [
+        {
+          name: 'stash@{0}',
+          refType: { Stash: { index: 0, message: 'WIP on main' } },
+          isHead: false,
+        },
+      ],
+    };
+
+    render(<CommitInfo commit={graphCommit} />);
+
+    expect(screen.getByText('stash@{0}')).toHaveStyle({
+      backgroundColor: 'var(--color-stash)',
+    });
+  });
 });
@@ -1,4 +1,4 @@
-import { Calendar, Copy, GitBranch, GitCommit, Key, ShieldCheck, Tag } from 'lucide-react';
+import { Archive, Calendar, Copy, GitBranch, GitCommit, Key, ShieldCheck, Tag } from 'lucide-react';
 import { useTranslation } from 'react-i18next';
 import { Avatar, Skeleton } from '@/components/ui';
 import { useSignatureVerification } from '@/hooks';
@@ -146,9 +146,11 @@ export function CommitInfo({ commit, onScrollToCommit }: CommitInfoProps) {
             <span className={metaLabelClass}>{t('history.commitInfo.refs')}</span>
             <div className={cn(valueClass, 'gap-1')}>
               {commit.refs.map((ref: GraphCommit['refs'][0], idx: number) => {
+                const isStash = typeof ref.refType === 'object';
                 // Use lane color if available, otherwise fall back to type-based colors
-                const laneColor =
-                  'lane' in commit
+                const laneColor = isStash
+                  ? 'var(--color-stash)'
+                  : 'lane' in commit
                     ? `var(--color-lane-${(commit.lane % 8) + 1})`
                     : ref.refType === RefType.LocalBranch
                       ? 'var(--color-branch-local)'
@@ -165,7 +167,13 @@ export function CommitInfo({ commit, onScrollToCommit }: CommitInfoProps) {
                     )}
                     style={{ backgroundColor: laneColor }}
                   >
-                    {ref.refType === RefType.Tag ? <Tag size={10} /> : <GitBranch size={10} />}
+                    {ref.refType === RefType.Tag ? (
+                      <Tag size={10} />
+                    ) : isStash ? (
+                      <Archive size={10} />
+                    ) : (
+                      <GitBranch size={10} />
+                    )}
                     {ref.name}
                   </span>
                 );
@@ -40,7 +40,7 @@ export function HistoryFileContextMenu({ file, children, commitOid }: HistoryFil
         </MenuItem>
         <MenuItem
           icon={FolderOpen}
-          onSelect={() => showInFinder(`${repository?.path}/${filePath}`)}
+          onSelect={() => repository?.path && showInFinder(repository.path, filePath)}
         >
           {t('history.fileContextMenu.showInFinder')}
         </MenuItem>
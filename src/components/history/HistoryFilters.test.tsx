@@ -1,4 +1,4 @@
-import { render, screen } from '@testing-library/react';
+import { fireEvent, render, screen } from '@testing-library/react';
 import { describe, expect, it, vi } from 'vitest';
 import { HistoryFilters } from './HistoryFilters';
 
@@ -24,10 +24,13 @@ vi.mock('@/components/ui', () => ({
   ),
   DropdownMenuLabel: ({ children }: { children: React.ReactNode }) => <div>{children}</div>,
   DropdownMenuSeparator: () => <hr />,
+  Input: (props: React.InputHTMLAttributes<HTMLInputElement>) => <input {...props} />,
 }));
 
 const mockSetBranchFilter = vi.fn();
 const mockSetIncludeRemotes = vi.fn();
+const mockSetIncludeStashes = vi.fn();
+const mockSetAuthorFilter = vi.fn();
 const mockSetSortOrder = vi.fn();
 
 vi.mock('@/store/repositoryStore', () => ({
@@ -39,9 +42,13 @@ vi.mock('@/store/repositoryStore', () => ({
     ],
     branchFilter: 'All',
     includeRemotes: true,
+    includeStashes: false,
+    authorFilter: '',
     sortOrder: 'DateOrder',
     setBranchFilter: mockSetBranchFilter,
     setIncludeRemotes: mockSetIncludeRemotes,
+    setIncludeStashes: mockSetIncludeStashes,
+    setAuthorFilter: mockSetAuthorFilter,
     setSortOrder: mockSetSortOrder,
   }),
 }));
@@ -103,6 +110,23 @@ describe('HistoryFilters', () => {
 
     expect(screen.getByText('history.filters.ancestorOrder')).toBeInTheDocument();
   });
+
+  it('should render stash options', () => {
+    render(<HistoryFilters />);
+
+    expect(screen.getByText('history.filters.showStashes')).toBeInTheDocument();
+    expect(screen.getAllByText('history.filters.hideStashes').length).toBeGreaterThan(0);
+  });
+
+  it('should update the author filter', () => {
+    render(<HistoryFilters />);
+
+    fireEvent.change(screen.getByLabelText('history.filters.author'), {
+      target: { value: 'jane' },
+    });
+
+    expect(mockSetAuthorFilter).toHaveBeenCalledWith('jane');
+  });
 });
 
 describe('HistoryFilters with specific branch filter', () => {
@@ -8,6 +8,7 @@ import {
   DropdownMenuRadioItem,
   DropdownMenuSeparator,
   DropdownMenuTrigger,
+  Input,
 } from '@/components/ui';
 import { cn } from '@/lib/utils';
 import { useRepositoryStore } from '@/store/repositoryStore';
@@ -20,9 +21,13 @@ export function HistoryFilters() {
     branches,
     branchFilter,
     includeRemotes,
+    includeStashes,
+    authorFilter,
     sortOrder,
     setBranchFilter,
     setIncludeRemotes,
+    setIncludeStashes,
+    setAuthorFilter,
     setSortOrder,
   } = useRepositoryStore();
 
@@ -136,6 +141,31 @@ export function HistoryFilters() {
         </DropdownMenuContent>
       </DropdownMenu>
 
+      {/* Stashes Toggle */}
+      <DropdownMenu>
+        <DropdownMenuTrigger asChild>
+          <button className="dropdown-button">
+            <span>
+              {includeStashes ? t('history.filters.showStashes') : t('history.filters.hideStashes')}
+            </span>
+            <ChevronDown size={12} />
+          </button>
+        </DropdownMenuTrigger>
+        <DropdownMenuContent align="start">
+          <DropdownMenuRadioGroup
+            value={includeStashes ? 'show' : 'hide'}
+            onValueChange={(value) => setIncludeStashes(value === 'show')}
+          >
+            <DropdownMenuRadioItem value="show">
+              {t('history.filters.showStashes')}
+            </DropdownMenuRadioItem>
+            <DropdownMenuRadioItem value="hide">
+              {t('history.filters.hideStashes')}
+            </DropdownMenuRadioItem>
+          </DropdownMenuRadioGroup>
+        </DropdownMenuContent>
+      </DropdownMenu>
+
       {/* Sort Order Dropdown */}
       <DropdownMenu>
         <DropdownMenuTrigger asChild>
@@ -159,6 +189,16 @@ export function HistoryFilters() {
           </DropdownMenuRadioGroup>
         </DropdownMenuContent>
       </DropdownMenu>
+
+      {/* Author Filter */}
+      <Input
+        type="search"
+        value={authorFilter}
+        onChange={(e) => setAuthorFilter(e.target.value)}
+        placeholder={t('history.filters.authorPlaceholder')}
+        aria-label={t('history.filters.author')}
+        className="ml-auto w-48 h-7 text-sm"
+      />
     </div>
   );
 }
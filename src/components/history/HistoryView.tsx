@@ -1,6 +1,6 @@
 // Refactored to use DataTable with virtualization
 
-import { GitBranch, GitCommit, Loader2, Tag, X } from 'lucide-react';
+import { Archive, GitBranch, GitCommit, Loader2, Tag, X } from 'lucide-react';
 import { useCallback, useMemo, useRef, useState } from 'react';
 import { useTranslation } from 'react-i18next';
 import { Panel, PanelGroup, PanelResizeHandle } from 'react-resizable-panels';
@@ -18,7 +18,7 @@ import type { SelectionKey } from '@/hooks';
 import { useScrollToCommit } from '@/hooks/useScrollToCommit';
 import { formatShortDate } from '@/lib/dateUtils';
 import { computeGraphLayout, getMaxColumns, type RowGraphData } from '@/lib/graphLayout';
-import { testId } from '@/lib/utils';
+import { cn, testId } from '@/lib/utils';
 import { useRepositoryStore } from '@/store/repositoryStore';
 import type { GraphCommit } from '@/types';
 import { RefType } from '@/types';
@@ -38,20 +38,21 @@ function abbrevCommit(hash: string): string {
 
 interface GitRefProps {
   name: string;
-  type: 'head' | 'remote' | 'tag';
+  type: 'head' | 'remote' | 'tag' | 'stash';
   color: string;
   isActive?: boolean;
   remotes?: string[];
+  title?: string;
 }
 
-function GitRef({ name, type, color, isActive, remotes }: GitRefProps) {
-  const Icon = type === 'tag' ? Tag : type === 'head' ? GitBranch : GitBranch;
+function GitRef({ name, type, color, isActive, remotes, title }: GitRefProps) {
+  const Icon = type === 'tag' ? Tag : type === 'stash' ? Archive : GitBranch;
   const className = `gitRef ${type}${isActive ? ' active' : ''}`;
   const style =
     type === 'tag' ? { backgroundColor: 'var(--color-tag)' } : { backgroundColor: color };
 
   return (
-    <span className={className} data-name={name} style={style}>
+    <span className={className} data-name={name} style={style} title={title}>
       <Icon size={10} />
       <span className="gitRefName" data-fullref={name}>
         {name}
@@ -201,6 +202,7 @@ export function HistoryView() {
           const remoteBranches =
             commit.refs?.filter((r) => r.refType === RefType.RemoteBranch) ?? [];
           const tags = commit.refs?.filter((r) => r.refType === RefType.Tag) ?? [];
+          const stashes = commit.refs?.filter((r) => typeof r.refType === 'object') ?? [];
 
           return (
             <div className="flex items-center gap-2 px-2 overflow-hidden">
@@ -225,9 +227,27 @@ export function HistoryView() {
                 {tags.map((ref) => (
                   <GitRef key={ref.name} name={ref.name} type="tag" color="var(--color-tag)" />
                 ))}
+                {stashes.map((ref) => (
+                  <GitRef
+                    key={ref.name}
+                    name={ref.name}
+                    type="stash"
+                    color="var(--color-stash)"
+                    title={typeof ref.refType === 'object' ? ref.refType.Stash.message : undefined}
+                  />
+                ))}
               </div>
-              <span className="truncate text-(--text-primary)">
-                {isUncommitted ? t('history.table.uncommittedChanges') : commit.summary}
+              <span
+                className={cn(
+                  'truncate',
+                  commit.filtered ? 'text-(--text-tertiary) italic' : 'text-(--text-primary)'
+                )}
+              >
+                {isUncommitted
+                  ? t('history.table.uncommittedChanges')
+                  : commit.filtered
+                    ? t('history.table.filteredCommit')
+                    : commit.summary}
               </span>
             </div>
           );
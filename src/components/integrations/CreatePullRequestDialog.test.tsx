@@ -374,10 +374,11 @@ describe('CreatePullRequestDialog', () => {
     fireEvent.click(screen.getByTitle('integrations.pullRequests.create.generateWithAi'));
 
     await waitFor(() => {
-      expect(mockGeneratePrDescription).toHaveBeenCalledWith('feature-branch', 'main', true, [
-        'bug',
-        'enhancement',
-      ]);
+      expect(mockGeneratePrDescription).toHaveBeenCalledWith(
+        'origin/feature-branch',
+        'origin/main',
+        { includeDiff: true, availableLabels: ['bug', 'enhancement'] }
+      );
       expect(screen.getByTestId('pr-title')).toHaveValue('Generated Title');
       expect(screen.getByTestId('pr-body')).toHaveValue('Generated body content');
       // AI response had 'bug' label, so 1 label should be selected
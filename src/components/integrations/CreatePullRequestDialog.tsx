@@ -110,11 +110,11 @@ export function CreatePullRequestDialog({
 
     try {
       const labelNames = availableLabels.map((l) => l.name);
+      // Use remote tracking refs so only pushed commits are described
       const response = await aiApi.generatePrDescription(
-        sourceBranch,
-        targetBranch,
-        true,
-        labelNames
+        `origin/${sourceBranch}`,
+        `origin/${targetBranch}`,
+        { includeDiff: true, availableLabels: labelNames }
       );
       setTitle(response.title);
       setBody(response.body);
@@ -37,7 +37,7 @@ import {
 } from '@/components/ui';
 import { toast } from '@/hooks';
 import { getErrorMessage } from '@/lib/errorUtils';
-import { BranchType, CIRunStatus, IssueState, PrState } from '@/types';
+import { BranchType, CIRunStatus, IssueState, PrState, TagPushStatus } from '@/types';
 import { cn, testId } from '../../lib/utils';
 import { branchApi, tagApi } from '../../services/api';
 import { useDialogStore } from '../../store/dialogStore';
@@ -246,8 +246,13 @@ export function Sidebar() {
   const handleTagPush = useCallback(
     async (tagName: string, remote: string) => {
       try {
-        await tagApi.push(tagName, remote);
-        toast.success(t('notifications.success.tagPushed'));
+        const result = await tagApi.push(tagName, remote);
+        if (result.success) {
+          toast.success(t('notifications.success.tagPushed'));
+        } else {
+          const rejected = result.outcomes.find((o) => o.status === TagPushStatus.Rejected);
+          toast.error(t('notifications.error.operationFailed'), rejected?.message ?? undefined);
+        }
       } catch (err) {
         toast.error(t('notifications.error.operationFailed'), getErrorMessage(err));
       }
@@ -690,6 +695,18 @@ function LfsStatusBadge() {
     );
   }
 
+  const missing = status.missingObjects?.length ?? 0;
+  if (missing > 0) {
+    return (
+      <span
+        className="badge bg-warning/20 text-warning"
+        title={t('lfs.missingObjects.message', { count: missing })}
+      >
+        {missing}
+      </span>
+    );
+  }
+
   const total = status.lfsFilesCount;
   if (total > 0) {
     return <span className="badge bg-(--accent-color)/20 text-(--accent-color)">{total}</span>;
@@ -99,6 +99,7 @@ export function LfsView() {
 
   const downloadedFiles = files.filter((f) => f.isDownloaded);
   const pointerFiles = files.filter((f) => !f.isDownloaded);
+  const missingCount = status?.missingObjects?.length ?? 0;
 
   const fileListItems = useMemo<LfsListItem[]>(() => {
     const items: LfsListItem[] = [];
@@ -254,6 +255,21 @@ export function LfsView() {
         </div>
       )}
 
+      {/* Missing objects warning */}
+      {missingCount > 0 && (
+        <div className="flex items-center gap-2 py-2 px-3 m-2 bg-warning/10 text-warning rounded text-xs">
+          <AlertCircle size={14} />
+          <span className="flex-1">{t('lfs.missingObjects.message', { count: missingCount })}</span>
+          <button
+            className="p-0.5 bg-transparent border-none text-inherit cursor-pointer underline"
+            onClick={() => pull()}
+            disabled={isPulling}
+          >
+            {t('lfs.missingObjects.pull')}
+          </button>
+        </div>
+      )}
+
       {/* Tabs */}
       <div className="flex border-b border-(--border-color)">
         <button
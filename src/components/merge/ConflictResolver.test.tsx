@@ -1,4 +1,4 @@
-import { render, screen, waitFor } from '@testing-library/react';
+import { fireEvent, render, screen, waitFor } from '@testing-library/react';
 import { beforeEach, describe, expect, it, vi } from 'vitest';
 import { ConflictResolver } from './ConflictResolver';
 
@@ -22,6 +22,7 @@ const mockGetConflictContent = vi.fn();
 const mockResolveConflict = vi.fn();
 const mockMarkResolved = vi.fn();
 const mockMarkUnresolved = vi.fn();
+const mockMergeNotebook = vi.fn();
 
 vi.mock('@/services/api', () => ({
   conflictApi: {
@@ -30,6 +31,7 @@ vi.mock('@/services/api', () => ({
     resolveConflict: (...args: unknown[]) => mockResolveConflict(...args),
     markResolved: (path: string) => mockMarkResolved(path),
     markUnresolved: (path: string) => mockMarkUnresolved(path),
+    mergeNotebook: (path: string) => mockMergeNotebook(path),
   },
   operationApi: {
     getState: () => mockGetState(),
@@ -242,4 +244,32 @@ describe('ConflictResolver', () => {
       expect(screen.getByText('merge.conflictResolver.markResolved')).toBeInTheDocument();
     });
   });
+
+  it('should only offer cell merge for notebooks', async () => {
+    render(<ConflictResolver />);
+
+    await waitFor(() => {
+      expect(screen.getByText('merge.conflictResolver.markResolved')).toBeInTheDocument();
+    });
+    expect(screen.queryByText('merge.conflictResolver.mergeNotebookCells')).not.toBeInTheDocument();
+  });
+
+  it('should report notebook cells changed on both sides', async () => {
+    mockGetConflictedFiles.mockResolvedValue([{ path: 'analysis.ipynb', isResolved: false }]);
+    mockMergeNotebook.mockResolvedValue({
+      resolved: false,
+      conflicts: [{ baseCell: 2, oursCell: 2, theirsCell: 3 }],
+    });
+    render(<ConflictResolver />);
+
+    const button = await screen.findByText('merge.conflictResolver.mergeNotebookCells');
+    fireEvent.click(button);
+
+    await waitFor(() => {
+      expect(mockMergeNotebook).toHaveBeenCalledWith('analysis.ipynb');
+      expect(
+        screen.getByText('merge.conflictResolver.notebookCellConflicts {"cells":"2"}')
+      ).toBeInTheDocument();
+    });
+  });
 });
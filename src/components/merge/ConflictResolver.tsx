@@ -130,6 +130,26 @@ export function ConflictResolver({ onAllResolved }: ConflictResolverProps) {
     }
   };
 
+  const handleMergeNotebookCells = async () => {
+    if (!selectedFile) return;
+
+    try {
+      const result = await conflictApi.mergeNotebook(selectedFile);
+      if (result.resolved) {
+        await refreshAfterResolve();
+        return;
+      }
+      const cells = result.conflicts
+        .map((c) => c.oursCell ?? c.theirsCell)
+        .filter((cell) => cell !== null)
+        .join(', ');
+      setError(t('merge.conflictResolver.notebookCellConflicts', { cells }));
+    } catch (err) {
+      console.error('Failed to merge notebook:', getErrorMessage(err));
+      setError(t('merge.conflictResolver.failedResolve'));
+    }
+  };
+
   const handleUnresolve = async () => {
     if (!selectedFile) return;
 
@@ -325,9 +345,16 @@ export function ConflictResolver({ onAllResolved }: ConflictResolverProps) {
                         {t('merge.conflictResolver.markUnresolved')}
                       </button>
                     ) : (
-                      <button className={btnPrimarySmallClass} onClick={handleResolveMerged}>
-                        {t('merge.conflictResolver.markResolved')}
-                      </button>
+                      <div className="flex items-center gap-2">
+                        {selectedFile?.toLowerCase().endsWith('.ipynb') && (
+                          <button className={btnSmallClass} onClick={handleMergeNotebookCells}>
+                            {t('merge.conflictResolver.mergeNotebookCells')}
+                          </button>
+                        )}
+                        <button className={btnPrimarySmallClass} onClick={handleResolveMerged}>
+                          {t('merge.conflictResolver.markResolved')}
+                        </button>
+                      </div>
                     )}
                   </div>
                   <Textarea
@@ -117,6 +117,7 @@ const mockPreview: InteractiveRebasePreview = {
     },
     target: { name: 'main', oid: '789abc', shortOid: '789abc', summary: 'Target commit' },
     targetCommitsAhead: 1,
+    preconditions: { generation: 1, refs: [], indexChecksum: null },
   },
   entries: mockEntries,
 };
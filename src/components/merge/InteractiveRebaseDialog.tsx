@@ -68,6 +68,7 @@ export function InteractiveRebaseDialog() {
             onto,
             entries,
             autosquash: false,
+            preconditions: preview?.preview.preconditions ?? null,
           })
       );
 
@@ -86,17 +86,19 @@ vi.mock('@/components/ui', () => ({
   ),
   Select: ({
     children,
+    id,
     value,
     onValueChange,
   }: {
     children: React.ReactNode;
+    id: string;
     value: string;
     onValueChange: (v: string) => void;
   }) => (
     <select
       value={value}
       onChange={(e) => onValueChange(e.target.value)}
-      data-testid="branch-select"
+      data-testid={id === 'merge-strategy' ? 'strategy-select' : 'branch-select'}
     >
       <option value="">{''}</option>
       {children}
@@ -244,10 +246,29 @@ describe('MergeDialog', () => {
         ffOnly: false,
         squash: false,
         noCommit: false,
+        strategy: null,
       });
     });
   });
 
+  it('should pass the selected merge strategy', async () => {
+    mockMerge.mockResolvedValue({ success: true, conflicts: [], message: '' });
+
+    render(<MergeDialog {...defaultProps} />);
+
+    await waitFor(() => {
+      expect(screen.getByTestId('branch-select')).toBeInTheDocument();
+    });
+
+    fireEvent.change(screen.getByTestId('branch-select'), { target: { value: 'feature-branch' } });
+    fireEvent.change(screen.getByTestId('strategy-select'), { target: { value: 'Theirs' } });
+    fireEvent.click(screen.getByText('merge.dialog.mergeButton'));
+
+    await waitFor(() => {
+      expect(mockMerge).toHaveBeenCalledWith(expect.objectContaining({ strategy: 'Theirs' }));
+    });
+  });
+
   it('should close dialog after successful merge', async () => {
     mockMerge.mockResolvedValue({ success: true, conflicts: [], message: '' });
     const onClose = vi.fn();
@@ -21,7 +21,7 @@ import { getErrorMessage } from '@/lib/errorUtils';
 import { useRepositoryStore } from '@/store/repositoryStore';
 import { cn } from '../../lib/utils';
 import { branchApi, mergeApi } from '../../services/api';
-import { type Branch, BranchType, type MergeResult } from '../../types';
+import { type Branch, BranchType, type MergeResult, MergeStrategy } from '../../types';
 
 interface MergeDialogProps {
   isOpen: boolean;
@@ -48,6 +48,7 @@ export function MergeDialog({
   const [squash, setSquash] = useState(false);
   const [commitImmediately, setCommitImmediately] = useState(true);
   const [customMessage, setCustomMessage] = useState('');
+  const [strategy, setStrategy] = useState<MergeStrategy | null>(null);
   const [isLoading, setIsLoading] = useState(false);
   const [error, setError] = useState<string | null>(null);
   const [result, setResult] = useState<MergeResult | null>(null);
@@ -63,6 +64,7 @@ export function MergeDialog({
       setSquash(false);
       setCommitImmediately(true);
       setCustomMessage('');
+      setStrategy(null);
     }
     // eslint-disable-next-line react-hooks/exhaustive-deps
   }, [isOpen, sourceBranch]);
@@ -102,6 +104,7 @@ export function MergeDialog({
             ffOnly: false,
             squash,
             noCommit: !commitImmediately,
+            strategy,
           })
       );
 
@@ -195,6 +198,34 @@ export function MergeDialog({
                 />
               </FormField>
 
+              <FormField label={t('merge.dialog.strategy')} htmlFor="merge-strategy">
+                <Select
+                  id="merge-strategy"
+                  value={strategy ?? 'default'}
+                  onValueChange={(value) =>
+                    setStrategy(value === 'default' ? null : (value as MergeStrategy))
+                  }
+                  disabled={isLoading}
+                >
+                  <SelectItem value="default">{t('merge.dialog.strategyDefault')}</SelectItem>
+                  <SelectItem value={MergeStrategy.Theirs}>
+                    {t('merge.dialog.strategyTheirs')}
+                  </SelectItem>
+                  <SelectItem value={MergeStrategy.Ours}>
+                    {t('merge.dialog.strategyOurs')}
+                  </SelectItem>
+                  <SelectItem value={MergeStrategy.OursStrategy}>
+                    {t('merge.dialog.strategyOursStrategy')}
+                  </SelectItem>
+                  <SelectItem value={MergeStrategy.Recursive}>
+                    {t('merge.dialog.strategyRecursive')}
+                  </SelectItem>
+                  <SelectItem value={MergeStrategy.Octopus}>
+                    {t('merge.dialog.strategyOctopus')}
+                  </SelectItem>
+                </Select>
+              </FormField>
+
               <CheckboxField
                 id="no-ff"
                 label={t('merge.dialog.noFastForward')}
@@ -125,6 +125,8 @@ describe('ResetConfirmDialog', () => {
       expect(mockReset).toHaveBeenCalledWith({
         target: 'abc123def456',
         mode: ResetMode.Mixed,
+        confirmDiscard: false,
+        preview: false,
       });
     });
   });
@@ -59,6 +59,12 @@ export function ResetConfirmDialog({
           description: t('merge.reset.hard.description'),
           warning: t('merge.reset.hard.warning'),
         };
+      case ResetMode.Keep:
+        return {
+          title: t('merge.reset.keep.title'),
+          description: t('merge.reset.keep.description'),
+          warning: t('merge.reset.keep.warning'),
+        };
     }
   };
 
@@ -73,6 +79,8 @@ export function ResetConfirmDialog({
       await operationApi.reset({
         target: commit.oid,
         mode,
+        confirmDiscard: isHardReset,
+        preview: false,
       });
       onResetComplete?.();
       onClose();
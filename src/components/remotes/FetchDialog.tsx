@@ -69,7 +69,12 @@ export function FetchDialog({ isOpen, onClose }: FetchDialogProps) {
       if (fetchAll) {
         await remoteApi.fetchAll();
       } else {
-        await remoteApi.fetch(selectedRemote, { prune, tags: false, depth: null });
+        await remoteApi.fetch(selectedRemote, {
+          prune,
+          tags: false,
+          depth: null,
+          recurseSubmodules: null,
+        });
       }
 
       await loadBranches();
@@ -76,7 +76,11 @@ export function PullDialog({ isOpen, onClose }: PullDialogProps) {
     setError(null);
 
     try {
-      await remoteApi.pull(selectedRemote, currentBranch.name, { rebase, ffOnly });
+      await remoteApi.pull(selectedRemote, currentBranch.name, {
+        rebase,
+        ffOnly,
+        recurseSubmodules: null,
+      });
 
       await Promise.all([loadBranches(), loadCommits(), refreshRepository()]);
 
@@ -17,7 +17,7 @@ import {
   SelectItem,
 } from '@/components/ui';
 import { toast, useOperationProgress, useSshKeyCheck } from '@/hooks';
-import { getErrorMessage } from '@/lib/errorUtils';
+import { getErrorMessage, isAxisError } from '@/lib/errorUtils';
 import { remoteApi } from '../../services/api';
 import { useRepositoryStore } from '../../store/repositoryStore';
 import type { Remote } from '../../types';
@@ -36,6 +36,7 @@ export function PushDialog({ isOpen, onClose }: PushDialogProps) {
   const [tags, setTags] = useState(false);
   const [isLoading, setIsLoading] = useState(false);
   const [error, setError] = useState<string | null>(null);
+  const [protectionWarning, setProtectionWarning] = useState<string | null>(null);
 
   const { branches, loadBranches, refreshRepository } = useRepositoryStore();
   const pushOperation = useOperationProgress('Push');
@@ -51,6 +52,7 @@ export function PushDialog({ isOpen, onClose }: PushDialogProps) {
     if (isOpen) {
       loadRemotes();
       setError(null);
+      setProtectionWarning(null);
       setForce(false);
       setTags(false);
       // Pre-select upstream remote if available
@@ -77,25 +79,35 @@ export function PushDialog({ isOpen, onClose }: PushDialogProps) {
     }
   };
 
-  const doPush = async () => {
+  const doPush = async (overrideProtectionCheck = false) => {
     if (!selectedRemote || !currentBranch) return;
 
     setIsLoading(true);
     setError(null);
+    setProtectionWarning(null);
 
     try {
-      await remoteApi.pushCurrentBranch(selectedRemote, {
-        force,
-        setUpstream,
-        tags,
-      });
+      await remoteApi.pushCurrentBranch(
+        selectedRemote,
+        {
+          force,
+          setUpstream,
+          tags,
+        },
+        undefined,
+        overrideProtectionCheck
+      );
 
       await Promise.all([loadBranches(), refreshRepository()]);
 
       onClose();
       toast.success(t('remotes.push.complete'));
     } catch (err) {
-      setError(getErrorMessage(err));
+      if (isAxisError(err) && err.type === 'ProtectedBranch') {
+        setProtectionWarning(t('remotes.push.protectedBranch', err.data));
+      } else {
+        setError(getErrorMessage(err));
+      }
     } finally {
       setIsLoading(false);
     }
@@ -103,11 +115,13 @@ export function PushDialog({ isOpen, onClose }: PushDialogProps) {
 
   const handlePush = async () => {
     if (!selectedRemote || !currentBranch) return;
-    await checkSshKeyForRemote(selectedRemote, doPush);
+    const overrideProtectionCheck = protectionWarning !== null;
+    await checkSshKeyForRemote(selectedRemote, () => doPush(overrideProtectionCheck));
   };
 
   const handleClose = () => {
     setError(null);
+    setProtectionWarning(null);
     onClose();
   };
 
@@ -177,7 +191,10 @@ export function PushDialog({ isOpen, onClose }: PushDialogProps) {
             id="force-push"
             label={t('remotes.push.forcePush')}
             checked={force}
-            onCheckedChange={setForce}
+            onCheckedChange={(checked) => {
+              setForce(checked);
+              setProtectionWarning(null);
+            }}
             disabled={isLoading}
           />
 
@@ -185,6 +202,12 @@ export function PushDialog({ isOpen, onClose }: PushDialogProps) {
             <OperationProgressBar progress={pushOperation.progress} className="mt-3" />
           )}
 
+          {protectionWarning && (
+            <Alert variant="warning" inline className="mt-3">
+              {protectionWarning}
+            </Alert>
+          )}
+
           {error && (
             <Alert variant="error" inline className="mt-3">
               {error}
@@ -201,7 +224,11 @@ export function PushDialog({ isOpen, onClose }: PushDialogProps) {
             onClick={handlePush}
             disabled={isLoading || !selectedRemote || !currentBranch}
           >
-            {isLoading ? t('common.pushing') : t('remotes.push.pushButton')}
+            {isLoading
+              ? t('common.pushing')
+              : protectionWarning
+                ? t('remotes.push.pushAnyway')
+                : t('remotes.push.pushButton')}
           </Button>
         </DialogFooter>
       </DialogContent>
@@ -780,6 +780,7 @@ const HOOK_LABELS: Record<string, string> = {
   CommitMsg: 'Commit Message',
   PostCommit: 'Post-commit',
   PrePush: 'Pre-push',
+  PreMergeCommit: 'Pre-merge-commit',
   PostMerge: 'Post-merge',
   PreRebase: 'Pre-rebase',
   PostCheckout: 'Post-checkout',
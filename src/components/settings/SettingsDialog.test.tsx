@@ -19,6 +19,9 @@ vi.mock('@/services/api', () => ({
   sshKeysApi: {
     list: vi.fn().mockResolvedValue([]),
   },
+  shellApi: {
+    listAvailableTerminals: vi.fn().mockResolvedValue([]),
+  },
   lfsApi: {
     getGitEnvironment: vi.fn().mockResolvedValue({
       gitVersion: '2.40.0',
@@ -31,9 +34,13 @@ vi.mock('@/services/api', () => ({
   avatarApi: {
     clearCache: vi.fn().mockResolvedValue(undefined),
   },
+  diffApi: {
+    listPresets: vi.fn().mockResolvedValue([]),
+  },
   aiApi: {
     hasApiKey: vi.fn().mockResolvedValue(false),
-    listOllamaModels: vi.fn().mockResolvedValue([]),
+    listModels: vi.fn().mockResolvedValue([]),
+    checkConnection: vi.fn().mockResolvedValue({ latencyMs: 12 }),
   },
 }));
 
@@ -100,6 +107,7 @@ const mockSettings = {
   aiEnabled: false,
   aiProvider: AiProvider.OpenAi,
   aiModel: null,
+  aiPrModel: null,
   aiOllamaUrl: null,
   defaultSshKey: null,
   gravatarEnabled: false,
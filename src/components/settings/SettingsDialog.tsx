@@ -28,20 +28,38 @@ import {
 import { toast } from '@/hooks';
 import { getErrorMessage } from '@/lib/errorUtils';
 import { cn } from '@/lib/utils';
-import { aiApi, avatarApi, lfsApi, settingsApi, signingApi, sshKeysApi } from '@/services/api';
+import {
+  aiApi,
+  avatarApi,
+  diffApi,
+  lfsApi,
+  settingsApi,
+  shellApi,
+  signingApi,
+  sshKeysApi,
+} from '@/services/api';
 import { initIntegrationListeners, useIntegrationStore } from '@/store/integrationStore';
 import { useSettingsStore } from '@/store/settingsStore';
 import { useUpdateStore } from '@/store/updateStore';
 import type {
   AiProvider as AiProviderType,
   AppSettings,
+  AvailableTerminal,
   GpgKey,
+  NotebookOutputMode,
   SigningFormat as SigningFormatType,
   SshKey,
   SshKeyInfo,
   Theme as ThemeType,
 } from '@/types';
-import { AiProvider, ProviderType, SigningFormat, SshKeyFormat, Theme } from '@/types';
+import {
+  AiProvider,
+  ProviderType,
+  SigningFormat,
+  SshKeyFormat,
+  TerminalApp,
+  Theme,
+} from '@/types';
 import { GlobalActionsSettings } from './GlobalActionsSettings';
 import { SshKeysSettings } from './SshKeysSettings';
 
@@ -69,6 +87,13 @@ const DEFAULT_SETTINGS: AppSettings = {
   diffContextLines: 3,
   diffWordWrap: true,
   diffSideBySide: false,
+  diffPresetWorkdir: null,
+  diffPresetCommit: null,
+  diffPresetBlame: null,
+  diffFunctionContext: true,
+  diffStructured: true,
+  diffNotebookOutputs: 'Summarize',
+  diffStructuredJsonExtensions: [],
   spellCheckCommitMessages: false,
   conventionalCommitsEnabled: false,
   conventionalCommitsScopes: null,
@@ -76,6 +101,7 @@ const DEFAULT_SETTINGS: AppSettings = {
   aiEnabled: false,
   aiProvider: AiProvider.OpenAi,
   aiModel: null,
+  aiPrModel: null,
   aiOllamaUrl: null,
   defaultSshKey: null,
   gravatarEnabled: false,
@@ -423,13 +449,23 @@ function GitSettings({ settings, updateSetting }: SettingsPanelProps) {
   const [testResult, setTestResult] = useState<{ success: boolean; message: string } | null>(null);
   const [gitEnv, setGitEnv] = useState<GitEnvironment | null>(null);
   const [isLoadingEnv, setIsLoadingEnv] = useState(false);
+  const [terminals, setTerminals] = useState<AvailableTerminal[]>([]);
 
   useEffect(() => {
     loadKeys();
     loadGitEnvironment();
     loadSshKeyInfos();
+    loadTerminals();
   }, []);
 
+  const loadTerminals = async () => {
+    try {
+      setTerminals(await shellApi.listAvailableTerminals());
+    } catch (err) {
+      console.error('Failed to load terminals:', err);
+    }
+  };
+
   const loadSshKeyInfos = async () => {
     try {
       const keys = await sshKeysApi.list();
@@ -575,6 +611,61 @@ function GitSettings({ settings, updateSetting }: SettingsPanelProps) {
         )}
       </FormField>
 
+      <h3 className={sectionTitleClass}>{t('settings.git.terminal.title')}</h3>
+
+      <FormField
+        label={t('settings.git.terminal.app.label')}
+        htmlFor="terminalApp"
+        hint={t('settings.git.terminal.app.hint')}
+      >
+        <Select
+          id="terminalApp"
+          value={settings.terminalApp ?? 'default'}
+          onValueChange={(value) =>
+            updateSetting('terminalApp', value === 'default' ? null : (value as TerminalApp))
+          }
+        >
+          <SelectItem value="default">{t('settings.git.terminal.app.default')}</SelectItem>
+          {terminals.map((terminal) => (
+            <SelectItem key={terminal.app} value={terminal.app}>
+              {terminal.name}
+            </SelectItem>
+          ))}
+          <SelectItem value={TerminalApp.Custom}>
+            {t('settings.git.terminal.app.custom')}
+          </SelectItem>
+        </Select>
+      </FormField>
+
+      {settings.terminalApp === TerminalApp.Custom ? (
+        <FormField
+          label={t('settings.git.terminal.commandTemplate.label')}
+          htmlFor="terminalCommandTemplate"
+          hint={t('settings.git.terminal.commandTemplate.hint')}
+        >
+          <Input
+            id="terminalCommandTemplate"
+            type="text"
+            value={settings.terminalCommandTemplate || ''}
+            onChange={(e) => updateSetting('terminalCommandTemplate', e.target.value || null)}
+            placeholder={t('settings.git.terminal.commandTemplate.placeholder')}
+          />
+        </FormField>
+      ) : (
+        <FormField
+          label={t('settings.git.terminal.profile.label')}
+          htmlFor="terminalProfile"
+          hint={t('settings.git.terminal.profile.hint')}
+        >
+          <Input
+            id="terminalProfile"
+            type="text"
+            value={settings.terminalProfile || ''}
+            onChange={(e) => updateSetting('terminalProfile', e.target.value || null)}
+          />
+        </FormField>
+      )}
+
       <h3 className={sectionTitleClass}>{t('settings.git.largeFiles.title')}</h3>
 
       <div className={groupClass}>
@@ -777,8 +868,24 @@ function GitSettings({ settings, updateSetting }: SettingsPanelProps) {
   );
 }
 
+const DEFAULT_DIFF_PRESET = 'Default';
+
 function DiffSettings({ settings, updateSetting }: SettingsPanelProps) {
   const { t } = useTranslation();
+  const [presetNames, setPresetNames] = useState<string[]>([DEFAULT_DIFF_PRESET]);
+
+  useEffect(() => {
+    diffApi
+      .listPresets()
+      .then((presets) => setPresetNames(presets.map((p) => p.name)))
+      .catch((err) => console.error('Failed to load diff presets:', err));
+  }, []);
+
+  const presetFields = [
+    { id: 'diffPresetWorkdir', label: t('settings.diff.presets.workdir') },
+    { id: 'diffPresetCommit', label: t('settings.diff.presets.commit') },
+    { id: 'diffPresetBlame', label: t('settings.diff.presets.blame') },
+  ] as const;
 
   return (
     <div>
@@ -820,6 +927,85 @@ function DiffSettings({ settings, updateSetting }: SettingsPanelProps) {
         />
       </div>
 
+      <div className={groupClass}>
+        <CheckboxField
+          id="diff-function-context"
+          label={t('settings.diff.functionContext.label')}
+          description={t('settings.diff.functionContext.description')}
+          checked={settings.diffFunctionContext}
+          onCheckedChange={(checked) => updateSetting('diffFunctionContext', checked === true)}
+        />
+      </div>
+
+      <div className={groupClass}>
+        <CheckboxField
+          id="diff-structured"
+          label={t('settings.diff.structured.label')}
+          description={t('settings.diff.structured.description')}
+          checked={settings.diffStructured}
+          onCheckedChange={(checked) => updateSetting('diffStructured', checked === true)}
+        />
+      </div>
+
+      <FormField
+        label={t('settings.diff.notebookOutputs.label')}
+        htmlFor="diffNotebookOutputs"
+        hint={t('settings.diff.notebookOutputs.hint')}
+      >
+        <Select
+          id="diffNotebookOutputs"
+          value={settings.diffNotebookOutputs ?? 'Summarize'}
+          onValueChange={(value) =>
+            updateSetting('diffNotebookOutputs', value as NotebookOutputMode)
+          }
+          disabled={settings.diffStructured === false}
+        >
+          <SelectItem value="Summarize">{t('settings.diff.notebookOutputs.summarize')}</SelectItem>
+          <SelectItem value="Ignore">{t('settings.diff.notebookOutputs.ignore')}</SelectItem>
+        </Select>
+      </FormField>
+
+      <FormField
+        label={t('settings.diff.structuredJsonExtensions.label')}
+        htmlFor="diffStructuredJsonExtensions"
+        hint={t('settings.diff.structuredJsonExtensions.hint')}
+      >
+        <Input
+          id="diffStructuredJsonExtensions"
+          type="text"
+          value={settings.diffStructuredJsonExtensions?.join(', ') ?? ''}
+          onChange={(e) =>
+            updateSetting(
+              'diffStructuredJsonExtensions',
+              e.target.value
+                .split(',')
+                .map((ext) => ext.trim().replace(/^\./, ''))
+                .filter(Boolean)
+            )
+          }
+          placeholder="json, geojson"
+          disabled={settings.diffStructured === false}
+        />
+      </FormField>
+
+      {presetFields.map(({ id, label }) => (
+        <FormField key={id} label={label} htmlFor={id} hint={t('settings.diff.presets.hint')}>
+          <Select
+            id={id}
+            value={settings[id] ?? DEFAULT_DIFF_PRESET}
+            onValueChange={(value) =>
+              updateSetting(id, value === DEFAULT_DIFF_PRESET ? null : value)
+            }
+          >
+            {presetNames.map((name) => (
+              <SelectItem key={name} value={name}>
+                {name}
+              </SelectItem>
+            ))}
+          </Select>
+        </FormField>
+      ))}
+
       <h3 className={sectionTitleClass}>{t('settings.commit.title')}</h3>
 
       <div className={groupClass}>
@@ -902,8 +1088,11 @@ function AiSettings({ settings, updateSetting }: SettingsPanelProps) {
     const loadModels = async () => {
       setIsLoadingModels(true);
       try {
-        const models = await aiApi.listOllamaModels(settings.aiOllamaUrl ?? undefined);
-        setOllamaModels(models);
+        const models = await aiApi.listModels(
+          AiProvider.Ollama,
+          settings.aiOllamaUrl ?? undefined
+        );
+        setOllamaModels(models.map((m) => m.name));
       } catch (err) {
         console.error('Failed to load Ollama models:', err);
         setOllamaModels([]);
@@ -919,8 +1108,8 @@ function AiSettings({ settings, updateSetting }: SettingsPanelProps) {
   const loadOllamaModels = async () => {
     setIsLoadingModels(true);
     try {
-      const models = await aiApi.listOllamaModels(settings.aiOllamaUrl ?? undefined);
-      setOllamaModels(models);
+      const models = await aiApi.listModels(AiProvider.Ollama, settings.aiOllamaUrl ?? undefined);
+      setOllamaModels(models.map((m) => m.name));
     } catch (err) {
       console.error('Failed to load Ollama models:', err);
       setOllamaModels([]);
@@ -960,12 +1149,14 @@ function AiSettings({ settings, updateSetting }: SettingsPanelProps) {
     setIsTesting(true);
     setTestResult(null);
     try {
-      const success = await aiApi.testConnection(settings.aiProvider);
-      if (success) {
-        setTestResult({ success: true, message: t('settings.ai.connectionSuccess') });
-      } else {
-        setTestResult({ success: false, message: t('settings.ai.connectionFailed') });
-      }
+      const { latencyMs } = await aiApi.checkConnection(
+        settings.aiProvider,
+        settings.aiOllamaUrl ?? undefined
+      );
+      setTestResult({
+        success: true,
+        message: t('settings.ai.connectionSuccess', { latency: latencyMs }),
+      });
     } catch (err) {
       setTestResult({ success: false, message: getErrorMessage(err) });
     } finally {
@@ -1006,6 +1197,7 @@ function AiSettings({ settings, updateSetting }: SettingsPanelProps) {
           onValueChange={(value) => {
             updateSetting('aiProvider', value as AiProviderType);
             updateSetting('aiModel', null);
+            updateSetting('aiPrModel', null);
             setTestResult(null);
           }}
         >
@@ -1117,6 +1309,20 @@ function AiSettings({ settings, updateSetting }: SettingsPanelProps) {
         )}
       </FormField>
 
+      <FormField
+        label={t('settings.ai.prModel.label')}
+        htmlFor="aiPrModel"
+        hint={t('settings.ai.prModel.hint')}
+      >
+        <Input
+          id="aiPrModel"
+          type="text"
+          value={settings.aiPrModel || ''}
+          onChange={(e) => updateSetting('aiPrModel', e.target.value || null)}
+          placeholder={settings.aiModel || defaultModels[settings.aiProvider]}
+        />
+      </FormField>
+
       <div className={groupClass}>
         <Button
           variant="secondary"
@@ -96,6 +96,14 @@ vi.mock('@/services/api', () => ({
   },
 }));
 
+vi.mock('@/bindings/api', () => ({
+  events: {
+    aiCommitMessageChunkEvent: {
+      listen: vi.fn().mockResolvedValue(() => {}),
+    },
+  },
+}));
+
 vi.mock('@/hooks', () => ({
   toast: {
     success: vi.fn(),
@@ -16,6 +16,7 @@ import {
   SelectItem,
   Textarea,
 } from '@/components/ui';
+import { events } from '@/bindings/api';
 import { toast, useReferenceMention } from '@/hooks';
 import {
   COMMIT_TYPES,
@@ -33,7 +34,7 @@ import { operations } from '@/store/operationStore';
 import { useRepositoryStore } from '@/store/repositoryStore';
 import { useSettingsStore } from '@/store/settingsStore';
 import { useStagingStore } from '@/store/stagingStore';
-import type { SigningConfig } from '@/types';
+import { CommitMessageStyle, type SigningConfig } from '@/types';
 import { ReferenceMention } from './ReferenceMention';
 
 export function CommitForm() {
@@ -305,9 +306,20 @@ export function CommitForm() {
       description: 'Generating AI commit message from staged changes',
     });
 
+    // Show the message as it streams in; structured mode waits for the final parse
+    let streamed = '';
+    const unlisten = await events.aiCommitMessageChunkEvent.listen((event) => {
+      streamed += event.payload.chunk;
+      if (!structuredMode) {
+        setCommitMessage(streamed.trimStart());
+      }
+    });
+
     try {
       setIsGeneratingMessage(true);
-      const response = await aiApi.generateCommitMessage();
+      const response = await aiApi.generateCommitMessage({
+        style: structuredMode ? CommitMessageStyle.Conventional : null,
+      });
       setCommitMessage(response.message);
 
       // If in structured mode, try to parse the generated message
@@ -323,6 +335,7 @@ export function CommitForm() {
       console.error('Failed to generate commit message:', err);
       toast.error(getErrorMessage(err));
     } finally {
+      unlisten();
       setIsGeneratingMessage(false);
       operations.complete(opId);
     }
@@ -79,7 +79,7 @@ export function StagingFileContextMenu({
 
   const handleShowInFinder = () => {
     if (repository?.path) {
-      showInFinder(`${repository.path}/${file.path}`);
+      showInFinder(repository.path, file.path);
     }
   };
 
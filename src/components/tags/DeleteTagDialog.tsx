@@ -16,7 +16,7 @@ import { toast } from '@/hooks';
 import { getErrorMessage } from '@/lib/errorUtils';
 import { tagApi } from '@/services/api';
 import { useRepositoryStore } from '@/store/repositoryStore';
-import type { Remote, Tag } from '@/types';
+import { TagPushStatus, type Remote, type Tag } from '@/types';
 
 interface DeleteTagDialogProps {
   isOpen: boolean;
@@ -54,7 +54,13 @@ export function DeleteTagDialog({ isOpen, onClose, tag, remotes }: DeleteTagDial
       await tagApi.delete(tag.name);
 
       if (deleteRemote && hasRemotes) {
-        await tagApi.deleteRemote(tag.name, selectedRemote);
+        const result = await tagApi.deleteRemote(tag.name, selectedRemote);
+        if (!result.success) {
+          const rejected = result.outcomes.find((o) => o.status === TagPushStatus.Rejected);
+          setError(rejected?.message ?? t('notifications.error.operationFailed'));
+          await loadTags();
+          return;
+        }
       }
 
       await loadTags();
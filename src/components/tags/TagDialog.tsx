@@ -96,7 +96,10 @@ export function TagDialog({
         // Push tag if requested
         if (pushTag && selectedRemote) {
           try {
-            await tagApi.push(tagName, selectedRemote);
+            const pushResult = await tagApi.push(tagName, selectedRemote);
+            if (!pushResult.success) {
+              console.error('Failed to push tag:', pushResult.outcomes);
+            }
           } catch (pushErr) {
             console.error('Failed to push tag:', pushErr);
           }
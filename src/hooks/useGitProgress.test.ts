@@ -192,6 +192,30 @@ describe('useGitProgress', () => {
       );
     });
 
+    it('should mark Maintenance as cancellable', async () => {
+      renderHook(() => useGitProgress());
+
+      await waitFor(() => {
+        expect(mocks.mockGitProgressListen).toHaveBeenCalled();
+      });
+
+      const handler = mocks.mockGitProgressListen.mock.calls[0][0];
+      handler({
+        payload: {
+          operationId: 'op-gc',
+          operationType: 'Maintenance',
+          stage: 'Compressing',
+        },
+      });
+
+      expect(mocks.mockOperationsStart).toHaveBeenCalledWith(
+        expect.any(String),
+        expect.objectContaining({
+          cancellable: true,
+        })
+      );
+    });
+
     it('should mark Push as non-cancellable', async () => {
       renderHook(() => useGitProgress());
 
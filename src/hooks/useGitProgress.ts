@@ -55,7 +55,10 @@ export function useGitProgress() {
           id: operationId,
           category: 'git',
           operationType,
-          cancellable: operationType === 'Clone' || operationType === 'Fetch',
+          cancellable:
+            operationType === 'Clone' ||
+            operationType === 'Fetch' ||
+            operationType === 'Maintenance',
         });
 
         operations.updateProgress(operationId, progress);
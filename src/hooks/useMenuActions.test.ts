@@ -356,6 +356,7 @@ describe('useMenuActions', () => {
       expect(remoteApi.pull).toHaveBeenCalledWith('origin', 'main', {
         rebase: false,
         ffOnly: false,
+        recurseSubmodules: null,
       });
       expect(mocks.mockRefreshRepository).toHaveBeenCalled();
       expect(toast.success).toHaveBeenCalledWith('notifications.success.pullComplete');
@@ -67,7 +67,11 @@ export function useMenuActions() {
           if (repository) {
             try {
               const branchName = repository.currentBranch || 'main';
-              await remoteApi.pull('origin', branchName, { rebase: false, ffOnly: false });
+              await remoteApi.pull('origin', branchName, {
+                rebase: false,
+                ffOnly: false,
+                recurseSubmodules: null,
+              });
               await refreshRepository();
               toast.success(t('notifications.success.pullComplete'));
             } catch (err) {
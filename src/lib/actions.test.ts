@@ -4,6 +4,7 @@ import { BranchType } from '@/types';
 
 const mockToastSuccess = vi.fn();
 const mockToastError = vi.fn();
+const mockToastInfo = vi.fn();
 const mockShowInFolder = vi.fn();
 const mockNotify = vi.fn();
 
@@ -11,6 +12,7 @@ vi.mock('@/hooks/useToast', () => ({
   toast: {
     success: (...args: unknown[]) => mockToastSuccess(...args),
     error: (...args: unknown[]) => mockToastError(...args),
+    info: (...args: unknown[]) => mockToastInfo(...args),
   },
 }));
 
@@ -72,13 +74,29 @@ describe('copyToClipboard', () => {
 describe('showInFinder', () => {
   beforeEach(() => {
     vi.clearAllMocks();
-    mockShowInFolder.mockResolvedValue(undefined);
+    mockShowInFolder.mockResolvedValue({ path: '/path/to/file', fallback: false });
   });
 
   it('should show path in finder successfully', async () => {
     const result = await showInFinder('/path/to/file');
 
     expect(mockShowInFolder).toHaveBeenCalled();
+    expect(mockToastInfo).not.toHaveBeenCalled();
+    expect(result).toBe(true);
+  });
+
+  it('should pass the repository-relative path', async () => {
+    await showInFinder('/repo', 'src/main.rs');
+
+    expect(mockShowInFolder).toHaveBeenCalledWith('/repo', 'src/main.rs');
+  });
+
+  it('should tell the user when a missing file fell back to its folder', async () => {
+    mockShowInFolder.mockResolvedValue({ path: '/repo/src', fallback: true });
+
+    const result = await showInFinder('/repo', 'src/deleted.rs');
+
+    expect(mockToastInfo).toHaveBeenCalledWith('lib.actions.showInFinderFallback', '/repo/src');
     expect(result).toBe(true);
   });
 
@@ -22,9 +22,12 @@ export async function copyToClipboard(
   }
 }
 
-export async function showInFinder(path: string): Promise<boolean> {
+export async function showInFinder(path: string, subpath?: string): Promise<boolean> {
   try {
-    await shellApi.showInFolder(path);
+    const result = await shellApi.showInFolder(path, subpath);
+    if (result.fallback) {
+      toast.info(i18n.t('lib.actions.showInFinderFallback'), result.path);
+    }
     return true;
   } catch (err) {
     toast.error(i18n.t('lib.actions.showInFinderFailed'), getErrorMessage(err));
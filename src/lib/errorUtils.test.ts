@@ -77,6 +77,11 @@ describe('errorUtils', () => {
         ['IntegrationNotConnected', 'lib.errors.integrationNotConnected'],
         ['CheckoutConflict', 'lib.errors.checkoutConflict'],
         ['StashApplyConflict', 'lib.errors.stashApplyConflict'],
+        ['HookTrustRequired', 'lib.errors.hookTrustRequired'],
+        ['ProtectedBranch', 'lib.errors.protectedBranch'],
+        ['EmptyCommit', 'lib.errors.emptyCommit'],
+        ['EmptyCommitMessage', 'lib.errors.emptyCommitMessage'],
+        ['AmendUnchanged', 'lib.errors.amendUnchanged'],
       ];
 
       for (const [type, expectedKey] of errorTypeToKey) {
@@ -18,13 +18,19 @@ const FALLBACK_MESSAGE_KEYS: Partial<Record<AxisError['type'], string>> = {
   IntegrationNotConnected: 'lib.errors.integrationNotConnected',
   CheckoutConflict: 'lib.errors.checkoutConflict',
   StashApplyConflict: 'lib.errors.stashApplyConflict',
+  HookTrustRequired: 'lib.errors.hookTrustRequired',
+  ProtectedBranch: 'lib.errors.protectedBranch',
+  StaleLocalRef: 'lib.errors.staleLocalRef',
+  EmptyCommit: 'lib.errors.emptyCommit',
+  EmptyCommitMessage: 'lib.errors.emptyCommitMessage',
+  AmendUnchanged: 'lib.errors.amendUnchanged',
 };
 /* eslint-enable @typescript-eslint/naming-convention */
 
 export function getErrorMessage(err: unknown): string {
   if (isAxisError(err)) {
-    // If error has data, use it directly (it contains the message)
-    if ('data' in err && err.data) {
+    // If error has a message payload, use it directly
+    if ('data' in err && err.data && typeof err.data !== 'object') {
       return String(err.data);
     }
     // Otherwise use fallback
@@ -6,16 +6,25 @@ import type {
   AddSubmoduleOptions,
   AddWorktreeOptions,
   AiProvider,
+  AiUsageFilter,
   ApplyMailboxOptions,
   ApplyPatchOptions,
   AppSettings,
   ArchiveOptions,
+  BackgroundFetchConfig,
   BisectMarkType,
   BisectStartOptions,
+  BookmarkTarget,
   BranchFilter,
   BranchType,
+  BrokenHeadAction,
+  BulkTagSpec,
+  ChangelogOptions,
   CheckoutOptions,
   CherryPickOptions,
+  CommitMessageStatsOptions,
+  CommitTrailerOptions,
+  ConfigLevel,
   ConflictResolution,
   CreateBranchOptions,
   CreateIssueOptions,
@@ -24,20 +33,33 @@ import type {
   CreateTagOptions,
   CustomAction,
   DeleteBranchOptions,
+  DeleteTagsByPatternOptions,
   DetectedProvider,
+  DiffContext,
   DiffOptions,
+  DiffPreset,
   DiffTarget,
+  ExpectedBranchTarget,
+  ExplainDiffScope,
   ExportSshKeyOptions,
   FetchOptions,
+  FileContentSource,
   FileLogOptions,
+  FileModeAuditOptions,
+  FileModeFix,
   FormatPatchOptions,
+  GenerateCommitMessageOptions,
+  GeneratePrDescriptionOptions,
   GenerateSshKeyOptions,
   GitFlowFinishOptions,
   GitFlowInitOptions,
   GitHookType,
   GraphOptions,
   GrepOptions,
+  HookTrustDecision,
+  HookTrustRequest,
   ImportSshKeyOptions,
+  InteractiveRebaseEntry,
   InteractiveRebaseOptions,
   IssueState,
   LfsFetchOptions,
@@ -49,8 +71,11 @@ import type {
   ListSubmoduleOptions,
   ListTagsOptions,
   LogOptions,
+  MaintenanceOptions,
   MergeOptions,
   MergePrOptions,
+  OperationLogFilter,
+  PerformanceConfig,
   ProviderType,
   PrState,
   PullOptions,
@@ -59,15 +84,21 @@ import type {
   RebaseOptions,
   ReflogOptions,
   RemoveWorktreeOptions,
+  RepositoryStatusOptions,
   ResetOptions,
   RevertOptions,
+  RewritePreconditions,
   SearchOptions,
   SigningConfig,
   SigningFormat,
+  StaleBranchCriteria,
   StashApplyOptions,
+  StashHunkSelection,
   StashSaveOptions,
   SyncSubmoduleOptions,
+  TagPushOptions,
   UpdateSubmoduleOptions,
+  WorkdirRefDiffMode,
 } from '@/types';
 
 export const repositoryApi = {
@@ -85,20 +116,41 @@ export const repositoryApi = {
 
   getInfo: () => commands.getRepositoryInfo(),
 
-  getStatus: () => commands.getRepositoryStatus(),
+  getBrokenHeadRecoveries: () => commands.getBrokenHeadRecoveries(),
+
+  recoverBrokenHead: (action: BrokenHeadAction) => commands.recoverBrokenHead(action),
+
+  getStatus: (options?: RepositoryStatusOptions) =>
+    commands.getRepositoryStatus(options ?? null),
 
   getRecentRepositories: () => commands.getRecentRepositories(),
 
+  searchRecentRepositories: (query: string) => commands.searchRecentRepositories(query),
+
+  quickOpenSearch: (query: string) => commands.quickOpenSearch(query),
+
   removeRecentRepository: (path: string) => commands.removeRecentRepository(path),
 
   pinRepository: (path: string) => commands.pinRepository(path),
 
   unpinRepository: (path: string) => commands.unpinRepository(path),
+
+  pinRecentRepository: (path: string, pinned: boolean) =>
+    commands.pinRecentRepository(path, pinned),
+
+  getRecentRepositoriesWithStatus: () => commands.getRecentRepositoriesWithStatus(),
+
+  getHealth: () => commands.getRepositoryHealth(),
+
+  acknowledgeSyncFolderWarning: (acknowledged: boolean) =>
+    commands.acknowledgeSyncFolderWarning(acknowledged),
 };
 
 export const commitApi = {
   getHistory: (options: LogOptions) => commands.getCommitHistory(options),
 
+  getHistoryPage: (options: LogOptions) => commands.getCommitHistoryPage(options),
+
   getCommit: (oid: string) => commands.getCommit(oid),
 
   create: (
@@ -106,20 +158,57 @@ export const commitApi = {
     authorName?: string,
     authorEmail?: string,
     sign?: boolean,
-    bypassHooks?: boolean
+    bypassHooks?: boolean,
+    allowEmpty?: boolean,
+    trailers?: CommitTrailerOptions
   ) =>
     commands.createCommit(
       message,
       authorName ?? null,
       authorEmail ?? null,
       sign ?? null,
-      bypassHooks ?? null
+      bypassHooks ?? null,
+      allowEmpty ?? null,
+      trailers ?? null
     ),
 
-  amend: (message?: string, bypassHooks?: boolean) =>
-    commands.amendCommit(message ?? null, bypassHooks ?? null),
+  amend: (
+    message?: string,
+    bypassHooks?: boolean,
+    options?: {
+      authorName?: string;
+      authorEmail?: string;
+      resetAuthorDate?: boolean;
+      includeStaged?: boolean;
+      sign?: boolean;
+      allowEmpty?: boolean;
+      trailers?: CommitTrailerOptions;
+    }
+  ) =>
+    commands.amendCommit(
+      message ?? null,
+      options?.authorName ?? null,
+      options?.authorEmail ?? null,
+      options?.resetAuthorDate ?? null,
+      options?.includeStaged ?? null,
+      options?.sign ?? null,
+      bypassHooks ?? null,
+      options?.allowEmpty ?? null,
+      options?.trailers ?? null
+    ),
 
   getUserSignature: () => commands.getUserSignature(),
+
+  getRecentCoAuthors: () => commands.getRecentCoAuthors(),
+
+  validateMessage: (message: string) => commands.validateCommitMessage(message),
+
+  checkSpelling: (message: string) => commands.checkCommitMessageSpelling(message),
+
+  addSpellingWord: (word: string) => commands.addWord(word),
+
+  analyzeMessages: (options: CommitMessageStatsOptions) =>
+    commands.analyzeCommitMessages(options),
 };
 
 export const branchApi = {
@@ -129,8 +218,13 @@ export const branchApi = {
 
   delete: (name: string, options: DeleteBranchOptions) => commands.deleteBranch(name, options),
 
-  deleteRemote: (remoteName: string, branchName: string, force?: boolean) =>
-    commands.deleteRemoteBranch(remoteName, branchName, force ?? null),
+  deleteRemote: (
+    remoteName: string,
+    branchName: string,
+    force?: boolean,
+    overrideProtectionCheck?: boolean
+  ) =>
+    commands.deleteRemoteBranch(remoteName, branchName, force ?? null, overrideProtectionCheck ?? null),
 
   rename: (oldName: string, newName: string, force?: boolean) =>
     commands.renameBranch(oldName, newName, force ?? null),
@@ -146,6 +240,18 @@ export const branchApi = {
     commands.setBranchUpstream(branchName, upstream ?? null),
 
   compare: (baseRef: string, compareRef: string) => commands.compareBranches(baseRef, compareRef),
+
+  getStale: (criteria: StaleBranchCriteria) => commands.getStaleBranches(criteria),
+
+  archive: (branchNames: string[], tagPrefix?: string, deleteRemote?: boolean) =>
+    commands.archiveBranches(branchNames, tagPrefix ?? null, deleteRemote ?? null),
+
+  bulkDelete: (
+    branchNames: string[],
+    force: boolean,
+    deleteRemote: boolean,
+    expectedTargets?: ExpectedBranchTarget[]
+  ) => commands.bulkDeleteBranches(branchNames, force, deleteRemote, expectedTargets ?? null),
 };
 
 export const remoteApi = {
@@ -163,15 +269,54 @@ export const remoteApi = {
 
   setPushUrl: (name: string, url: string) => commands.setRemotePushUrl(name, url),
 
+  testConnection: (remoteName: string) => commands.testRemoteConnection(remoteName),
+
+  getBranches: (remoteName: string) => commands.getRemoteBranches(remoteName),
+
+  getTags: (remoteName: string) => commands.getRemoteTags(remoteName),
+
   fetch: (remoteName: string, options: FetchOptions) => commands.fetchRemote(remoteName, options),
 
   fetchAll: () => commands.fetchAll(),
 
-  push: (remoteName: string, refspecs: string[], options: PushOptions, bypassHooks?: boolean) =>
-    commands.pushRemote(remoteName, refspecs, options, bypassHooks ?? null),
+  configureBackgroundFetch: (repoPath: string, config: BackgroundFetchConfig) =>
+    commands.configureBackgroundFetch(
+      repoPath,
+      config.enabled,
+      config.intervalMinutes,
+      config.prune,
+      config.fetchAllRemotes
+    ),
+
+  getBackgroundFetchStatus: (repoPath: string) => commands.getBackgroundFetchStatus(repoPath),
 
-  pushCurrentBranch: (remoteName: string, options: PushOptions, bypassHooks?: boolean) =>
-    commands.pushCurrentBranch(remoteName, options, bypassHooks ?? null),
+  push: (
+    remoteName: string,
+    refspecs: string[],
+    options: PushOptions,
+    bypassHooks?: boolean,
+    overrideProtectionCheck?: boolean
+  ) =>
+    commands.pushRemote(
+      remoteName,
+      refspecs,
+      options,
+      bypassHooks ?? null,
+      overrideProtectionCheck ?? null
+    ),
+
+  pushCurrentBranch: (
+    remoteName: string,
+    options: PushOptions,
+    bypassHooks?: boolean,
+    overrideProtectionCheck?: boolean
+  ) =>
+    commands.pushCurrentBranch(
+      remoteName,
+      options,
+      bypassHooks ?? null,
+      overrideProtectionCheck ?? null
+    ),
 
   pull: (remoteName: string, branchName: string, options: PullOptions) =>
     commands.pullRemote(remoteName, branchName, options),
@@ -204,30 +349,73 @@ export const stagingApi = {
 
   checkFilesForLfs: (paths: string[], threshold: number) =>
     commands.checkFilesForLfs(paths, threshold),
+
+  setFileExecutable: (path: string, executable: boolean) =>
+    commands.setFileExecutable(path, executable),
+
+  auditFileModes: (
+    options: FileModeAuditOptions = { revision: null, historyDepth: null, flipThreshold: null }
+  ) => commands.auditFileModes(options),
+
+  fixFileModes: (selections: FileModeFix[]) => commands.fixFileModes(selections),
 };
 
 export const diffApi = {
-  getDiff: (target: DiffTarget, options?: DiffOptions) => commands.getDiff(target, options ?? null),
+  getDiff: (target: DiffTarget, options?: DiffOptions, preset?: string) =>
+    commands.getDiff(target, options ?? null, preset ?? null),
 
-  getWorkdir: (options?: DiffOptions) => commands.getDiff('WorkdirToIndex', options ?? null),
+  getWorkdir: (options?: DiffOptions, preset?: string) =>
+    commands.getDiff('WorkdirToIndex', options ?? null, preset ?? null),
 
-  getStaged: (options?: DiffOptions) => commands.getDiff('IndexToHead', options ?? null),
+  getStaged: (options?: DiffOptions, preset?: string) =>
+    commands.getDiff('IndexToHead', options ?? null, preset ?? null),
 
-  getHead: (options?: DiffOptions) => commands.getDiff('WorkdirToHead', options ?? null),
+  getHead: (options?: DiffOptions, preset?: string) =>
+    commands.getDiff('WorkdirToHead', options ?? null, preset ?? null),
 
-  getCommit: (oid: string, options?: DiffOptions) =>
+  getCommit: (oid: string, options?: DiffOptions, preset?: string) =>
     // eslint-disable-next-line @typescript-eslint/naming-convention -- Rust enum variant
-    commands.getDiff({ Commit: { oid } }, options ?? null),
+    commands.getDiff({ Commit: { oid } }, options ?? null, preset ?? null),
+
+  getCommits: (fromOid: string, toOid: string, options?: DiffOptions, preset?: string) =>
+    commands.getDiff(
+      // eslint-disable-next-line @typescript-eslint/naming-convention -- Rust enum variant
+      { CommitToCommit: { from: fromOid, to: toOid } },
+      options ?? null,
+      preset ?? null
+    ),
 
-  getCommits: (fromOid: string, toOid: string, options?: DiffOptions) =>
-    // eslint-disable-next-line @typescript-eslint/naming-convention -- Rust enum variant
-    commands.getDiff({ CommitToCommit: { from: fromOid, to: toOid } }, options ?? null),
+  getWorkdirToRef: (
+    reference: string,
+    mode: WorkdirRefDiffMode,
+    includeUntracked?: boolean,
+    options?: DiffOptions,
+    preset?: string
+  ) =>
+    commands.diffWorkdirToRef(
+      reference,
+      mode,
+      includeUntracked ?? null,
+      options ?? null,
+      preset ?? null
+    ),
 
-  getFile: (path: string, staged: boolean, options?: DiffOptions) =>
-    commands.getFileDiff(path, staged, options ?? null),
+  getFile: (path: string, staged: boolean, options?: DiffOptions, preset?: string) =>
+    commands.getFileDiff(path, staged, options ?? null, preset ?? null),
 
   getFileBlob: (path: string, commitOid?: string) =>
     invoke<ArrayBuffer>('get_file_blob', { path, commitOid: commitOid ?? null }),
+
+  getFileContentBase64: (path: string, source: FileContentSource) =>
+    commands.getFileContentBase64(path, source),
+
+  listPresets: () => commands.listDiffPresets(),
+
+  savePreset: (preset: DiffPreset) => commands.saveDiffPreset(preset),
+
+  deletePreset: (name: string) => commands.deleteDiffPreset(name),
+
+  getEffectiveOptions: (context: DiffContext) => commands.getEffectiveDiffOptions(context),
 };
 
 export const graphApi = {
@@ -235,10 +423,33 @@ export const graphApi = {
 
   getCommitCount: (fromRef?: string) => commands.getCommitCount(fromRef ?? null),
 
+  generateChangelog: (fromRef: string, toRef: string, options: ChangelogOptions = {}) =>
+    commands.generateChangelog(fromRef, toRef, options),
+
   getFileHistory: (options: FileLogOptions) => commands.getFileHistory(options),
 
-  getFileDiffInCommit: (commitOid: string, path: string, options?: DiffOptions) =>
-    commands.getFileDiffInCommit(commitOid, path, options ?? null),
+  getFileDiffInCommit: (commitOid: string, path: string, options?: DiffOptions, preset?: string) =>
+    commands.getFileDiffInCommit(commitOid, path, options ?? null, preset ?? null),
+
+  getFileDiffBetween: (
+    path: string,
+    fromRef: string,
+    toRef: string,
+    options?: DiffOptions,
+    followRenames?: boolean,
+    preset?: string
+  ) =>
+    commands.getFileDiffBetween(
+      path,
+      fromRef,
+      toRef,
+      options ?? null,
+      followRenames ?? null,
+      preset ?? null
+    ),
+
+  resolveCurrentPath: (historicalPath: string, fromRef: string) =>
+    commands.resolveCurrentPath(historicalPath, fromRef),
 };
 
 export const searchApi = {
@@ -247,6 +458,9 @@ export const searchApi = {
 
 export const blameApi = {
   file: (path: string, commitOid?: string) => commands.blameFile(path, commitOid ?? null),
+
+  getLineContext: (path: string, lineNumber: number, commitOid: string) =>
+    commands.getBlameLineContext(path, lineNumber, commitOid),
 };
 
 export const mergeApi = {
@@ -277,14 +491,27 @@ export const rebaseApi = {
   interactiveRebase: (options: InteractiveRebaseOptions, bypassHooks?: boolean) =>
     commands.interactiveRebase(options, bypassHooks ?? null),
 
+  squashCommits: (count: number, newMessage: string) => commands.squashCommits(count, newMessage),
+
+  planRewordCommit: (oid: string) => commands.planRewordCommit(oid),
+
+  rewordCommit: (oid: string, newMessage: string, preconditions?: RewritePreconditions) =>
+    commands.rewordCommit(oid, newMessage, preconditions ?? null),
+
   getProgress: () => commands.getRebaseProgress(),
 
+  getTodo: () => commands.getInteractiveRebaseTodo(),
+
+  updateTodo: (todo: InteractiveRebaseEntry[]) => commands.updateInteractiveRebaseTodo(todo),
+
   continueWithMessage: (message: string) => commands.rebaseContinueWithMessage(message),
 };
 
 export const cherryPickApi = {
   cherryPick: (options: CherryPickOptions) => commands.cherryPick(options),
 
+  getPreview: (oids: string[]) => commands.getCherryPickPreview(oids),
+
   abort: () => commands.cherryPickAbort(),
 
   continue: () => commands.cherryPickContinue(),
@@ -308,6 +535,11 @@ export const conflictApi = {
   resolveConflict: (path: string, resolution: ConflictResolution, customContent?: string) =>
     commands.resolveConflict(path, resolution, customContent ?? null),
 
+  resolveWithContent: (path: string, content: string) =>
+    commands.resolveConflictWithContent(path, content),
+
+  mergeNotebook: (path: string) => commands.mergeNotebookConflict(path),
+
   markResolved: (path: string) => commands.markConflictResolved(path),
 
   markUnresolved: (path: string) => commands.markConflictUnresolved(path),
@@ -317,6 +549,23 @@ export const operationApi = {
   getState: () => commands.getOperationState(),
 
   reset: (options: ResetOptions) => commands.resetToCommit(options),
+
+  resetPaths: (commit: string, paths: string[]) => commands.resetPaths(commit, paths),
+
+  resetFileToCommit: (path: string, commitOid: string) =>
+    commands.resetFileToCommit(path, commitOid),
+};
+
+export const operationLogApi = {
+  getFiltered: (filter: OperationLogFilter) => commands.getOperationLogFiltered(filter),
+
+  export: (filter: OperationLogFilter, path: string) => commands.exportOperationLog(filter, path),
+};
+
+export const undoApi = {
+  getHistory: (limit?: number) => commands.getUndoHistory(limit ?? null),
+
+  undoLast: () => commands.undoLastOperation(),
 };
 
 export const bisectApi = {
@@ -324,6 +573,10 @@ export const bisectApi = {
 
   mark: (mark: BisectMarkType, commit?: string) => commands.bisectMark(mark, commit ?? null),
 
+  good: (commit?: string) => commands.bisectGood(commit ?? null),
+
+  bad: (commit?: string) => commands.bisectBad(commit ?? null),
+
   reset: (commit?: string) => commands.bisectReset(commit ?? null),
 
   getState: () => commands.bisectState(),
@@ -336,6 +589,16 @@ export const stashApi = {
 
   save: (options: StashSaveOptions) => commands.stashSave(options),
 
+  saveSelectedFiles: (paths: string[], message?: string) =>
+    commands.stashSaveSelectedFiles(paths, message ?? null),
+
+  saveSelection: (
+    selections: StashHunkSelection[],
+    message?: string,
+    options?: DiffOptions,
+    preset?: string
+  ) => commands.stashSelection(selections, message ?? null, options ?? null, preset ?? null),
+
   apply: (options: StashApplyOptions) => commands.stashApply(options),
 
   pop: (options: StashApplyOptions) => commands.stashPop(options),
@@ -346,6 +609,9 @@ export const stashApi = {
 
   show: (index?: number, statOnly: boolean = false) => commands.stashShow(index ?? null, statOnly),
 
+  getDiff: (stashIndex: number, options?: DiffOptions, preset?: string) =>
+    commands.getStashDiff(stashIndex, options ?? null, preset ?? null),
+
   branch: (branchName: string, index?: number) => commands.stashBranch(branchName, index ?? null),
 };
 
@@ -366,11 +632,20 @@ export const tagApi = {
 
   delete: (name: string) => commands.tagDelete(name),
 
-  push: (name: string, remote: string) => commands.tagPush(name, remote),
+  createBulk: (specs: BulkTagSpec[], stopOnError: boolean = false) =>
+    commands.tagCreateBulk(specs, stopOnError),
+
+  deleteByPattern: (pattern: string, options?: DeleteTagsByPatternOptions) =>
+    commands.tagDeleteByPattern(pattern, options ?? null),
 
-  pushAll: (remote: string) => commands.tagPushAll(remote),
+  push: (name: string, remote: string, options?: TagPushOptions) =>
+    commands.tagPush(name, remote, options ?? null),
 
-  deleteRemote: (name: string, remote: string) => commands.tagDeleteRemote(name, remote),
+  pushAll: (remote: string, options?: TagPushOptions) =>
+    commands.tagPushAll(remote, options ?? null),
+
+  deleteRemote: (name: string, remote: string, options?: TagPushOptions) =>
+    commands.tagDeleteRemote(name, remote, options ?? null),
 };
 
 export const submoduleApi = {
@@ -447,10 +722,17 @@ export const gitflowApi = {
 };
 
 export const grepApi = {
-  search: (options: GrepOptions) => commands.grepContent(options),
+  search: (options: GrepOptions, searchId?: string) =>
+    commands.grepContent(options, searchId ?? null),
 
   searchCommit: (commitOid: string, options: GrepOptions) =>
     commands.grepCommit(commitOid, options),
+
+  searchBranch: (pattern: string, branch: string, pathFilter?: string) =>
+    commands.grepBranch(pattern, branch, pathFilter ?? null),
+
+  searchStagedDiff: (pattern: string, caseSensitive: boolean, contextLines: number) =>
+    commands.grepDiff(pattern, caseSensitive, contextLines),
 };
 
 export const settingsApi = {
@@ -467,6 +749,18 @@ export const repoSettingsApi = {
 
   saveSigningConfig: (signingFormat: SigningFormat | null, signingKey: string | null) =>
     commands.saveRepositorySigningConfig(signingFormat, signingKey),
+
+  getConfig: (key: string) => commands.getRepositoryConfig(key),
+
+  listConfig: () => commands.listRepositoryConfig(),
+
+  setConfig: (key: string, value: string, level: ConfigLevel) =>
+    commands.setRepositoryConfig(key, value, level),
+
+  getPerformanceProfile: (repoPath: string) => commands.getPerformanceProfile(repoPath),
+
+  setPerformanceProfile: (repoPath: string, config: PerformanceConfig) =>
+    commands.setPerformanceProfile(repoPath, config),
 };
 
 export const hooksApi = {
@@ -482,9 +776,20 @@ export const hooksApi = {
 
   toggle: (hookType: GitHookType) => commands.toggleHook(hookType),
 
+  enable: (hookType: GitHookType) => commands.enableHook(hookType),
+
+  disable: (hookType: GitHookType) => commands.disableHook(hookType),
+
+  run: (hookType: GitHookType, args?: string[]) => commands.runHook(hookType, args ?? null),
+
   getTemplates: () => commands.getHookTemplates(),
 
   getTemplatesForType: (hookType: GitHookType) => commands.getHookTemplatesForType(hookType),
+
+  getTrustRequest: () => commands.getHookTrustRequest(),
+
+  resolveTrust: (request: HookTrustRequest, decision: HookTrustDecision) =>
+    commands.resolveHookTrust(request, decision),
 };
 
 export const signingApi = {
@@ -500,12 +805,32 @@ export const signingApi = {
 
   verifyCommitSignature: (oid: string, format: SigningFormat) =>
     commands.verifyCommitSignature(oid, format),
+
+  getCommitSignatures: (oids: string[]) => commands.getCommitSignatures(oids),
+
+  generateGpgKey: (name: string, email: string, passphrase?: string, expiry?: string) =>
+    commands.generateGpgKey(name, email, passphrase ?? null, expiry ?? null),
+
+  exportGpgPublicKey: (keyId: string, armored: boolean) =>
+    commands.exportGpgPublicKey(keyId, armored),
+
+  configureSigning: (
+    scope: ConfigLevel,
+    format: SigningFormat,
+    keyId: string,
+    signCommitsByDefault: boolean
+  ) => commands.configureSigning(scope, format, keyId, signCommitsByDefault),
 };
 
 export const shellApi = {
-  showInFolder: (path: string) => commands.showInFolder(path),
+  showInFolder: (path: string, subpath?: string) => commands.showInFolder(path, subpath ?? null),
 
-  openTerminal: (path: string) => commands.openTerminal(path),
+  getPathCopyForms: (path: string) => commands.getPathCopyForms(path),
+
+  openTerminal: (path: string, subpath?: string, command?: string) =>
+    commands.openTerminal(path, subpath ?? null, command ?? null),
+
+  listAvailableTerminals: () => commands.listAvailableTerminals(),
 
   openUrl: (url: string) => commands.openUrl(url),
 
@@ -521,6 +846,9 @@ export const patchApi = {
 
   createPatch: (options: CreatePatchOptions) => commands.createPatch(options),
 
+  createPatchSeries: (fromOid: string, toOid: string, outputDir: string) =>
+    commands.createPatchSeries(fromOid, toOid, outputDir),
+
   applyPatch: (options: ApplyPatchOptions) => commands.applyPatch(options),
 
   applyMailbox: (options: ApplyMailboxOptions) => commands.applyMailbox(options),
@@ -533,15 +861,16 @@ export const patchApi = {
 };
 
 export const aiApi = {
-  generateCommitMessage: () => commands.generateCommitMessage(),
+  generateCommitMessage: (options?: GenerateCommitMessageOptions) =>
+    commands.generateCommitMessage(options ?? null),
 
   generatePrDescription: (
-    sourceBranch: string,
-    targetBranch: string,
-    includeDiffSummary: boolean = true,
-    availableLabels: string[] = []
-  ) =>
-    commands.generatePrDescription(sourceBranch, targetBranch, includeDiffSummary, availableLabels),
+    baseRef: string,
+    compareRef: string,
+    options?: GeneratePrDescriptionOptions
+  ) => commands.generatePrDescription(baseRef, compareRef, options ?? null),
+
+  explainDiff: (scope: ExplainDiffScope) => commands.explainDiff(scope),
 
   setApiKey: (provider: AiProvider, apiKey: string) => commands.setAiApiKey(provider, apiKey),
 
@@ -549,9 +878,17 @@ export const aiApi = {
 
   deleteApiKey: (provider: AiProvider) => commands.deleteAiApiKey(provider),
 
-  testConnection: (provider: AiProvider) => commands.testAiConnection(provider),
+  listModels: (provider: AiProvider, baseUrl?: string) =>
+    commands.aiListModels(provider, baseUrl ?? null),
+
+  checkConnection: (provider: AiProvider, baseUrl?: string) =>
+    commands.aiCheckConnection(provider, baseUrl ?? null),
+
+  getUsage: (filter?: AiUsageFilter) => commands.getAiUsage(filter ?? null),
 
-  listOllamaModels: (ollamaUrl?: string) => commands.listOllamaModels(ollamaUrl ?? null),
+  getQueue: () => commands.getAiQueue(),
+
+  cancelRequest: (id: number) => commands.cancelAiRequest(id),
 };
 
 export const lfsApi = {
@@ -569,6 +906,8 @@ export const lfsApi = {
 
   listPatterns: () => commands.lfsListPatterns(),
 
+  getTrackedPatterns: () => commands.getLfsTrackedPatterns(),
+
   listFiles: () => commands.lfsListFiles(),
 
   fetch: (options: LfsFetchOptions) => commands.lfsFetch(options),
@@ -586,6 +925,14 @@ export const lfsApi = {
   prune: (options: LfsPruneOptions) => commands.lfsPrune(options),
 };
 
+export const maintenanceApi = {
+  getSizeReport: (largestBlobs?: number) => commands.getRepositorySizeReport(largestBlobs ?? null),
+
+  pruneDryRun: () => commands.pruneDryRun(),
+
+  run: (options: MaintenanceOptions) => commands.runMaintenance(options),
+};
+
 export const integrationApi = {
   // OAuth / Connection
   startOauth: (provider: ProviderType) => commands.integrationStartOauth(provider),
@@ -598,6 +945,9 @@ export const integrationApi = {
 
   disconnect: (provider: ProviderType) => commands.integrationDisconnect(provider),
 
+  addGpgKey: (provider: ProviderType, armoredPublicKey: string) =>
+    commands.integrationAddGpgKey(provider, armoredPublicKey),
+
   // Provider Detection
   detectProvider: () => commands.integrationDetectProvider(),
 
@@ -636,6 +986,14 @@ export const integrationApi = {
   getCommitStatus: (detected: DetectedProvider, sha: string) =>
     commands.integrationGetCommitStatus(detected, sha),
 
+  getBranchCiRollup: (detected: DetectedProvider) =>
+    commands.integrationGetBranchCiRollup(detected),
+
+  getCurrentBranchPr: () => commands.integrationGetCurrentBranchPr(),
+
+  rerunCiRun: (detected: DetectedProvider, runId: string, failedOnly: boolean) =>
+    commands.integrationRerunCiRun(detected, runId, failedOnly),
+
   // Notifications
   listNotifications: (detected: DetectedProvider, all: boolean, page: number) =>
     commands.integrationListNotifications(detected, all, page),
@@ -653,6 +1011,9 @@ export const gitignoreApi = {
   addPattern: (pattern: string, gitignorePath: string) =>
     commands.addToGitignore(pattern, gitignorePath),
 
+  bulkIgnore: (patterns: string[], gitignorePath: string) =>
+    commands.bulkIgnore(patterns, gitignorePath),
+
   addToGlobal: (pattern: string) => commands.addToGlobalGitignore(pattern),
 
   getOptions: (filePath: string) => commands.getIgnoreOptions(filePath),
@@ -661,6 +1022,8 @@ export const gitignoreApi = {
 export const avatarApi = {
   get: (email: string, sha?: string) => commands.getAvatar(email, sha ?? null),
 
+  getMany: (emails: string[], size: number) => commands.getAvatars(emails, size),
+
   clearCache: () => commands.clearAvatarCache(),
 };
 
@@ -679,6 +1042,8 @@ export const sshKeysApi = {
 
   export: (options: ExportSshKeyOptions) => commands.exportSshKey(options),
 
+  listAgentIdentities: () => commands.listSshAgentIdentities(),
+
   checkFormat: (keyPath: string) => commands.checkSshKeyFormat(keyPath),
 
   cachePassphrase: (keyPath: string, passphrase: string) =>
@@ -699,13 +1064,36 @@ export const remoteSshKeysApi = {
   list: () => commands.listRemoteSshKeys(),
 };
 
+export const hostCredentialsApi = {
+  list: () => commands.listCredentialHosts(),
+
+  set: (host: string, username: string, token: string) =>
+    commands.setHostCredential(host, username, token),
+
+  delete: (host: string) => commands.deleteHostCredential(host),
+};
+
+export const bookmarksApi = {
+  list: (repair?: boolean) => commands.getBookmarks(repair ?? null),
+
+  create: (target: BookmarkTarget, label: string, notes?: string) =>
+    commands.createBookmark(target, label, notes ?? null),
+
+  update: (id: number, label: string, notes?: string) =>
+    commands.updateBookmark(id, label, notes ?? null),
+
+  visit: (id: number) => commands.visitBookmark(id),
+
+  delete: (id: number) => commands.deleteBookmark(id),
+};
+
 export const customActionsApi = {
   // Global actions
   listGlobal: () => commands.listGlobalActions(),
 
-  saveGlobal: (action: CustomAction) => commands.saveGlobalAction(action),
+  saveGlobal: (action: CustomAction) => commands.saveCustomAction(action),
 
-  deleteGlobal: (actionId: string) => commands.deleteGlobalAction(actionId),
+  deleteGlobal: (actionId: string) => commands.deleteCustomAction(actionId),
 
   // Repository actions
   listRepo: () => commands.listRepoActions(),
@@ -722,6 +1110,12 @@ export const customActionsApi = {
   // Execution
   execute: (actionId: string, variables: ActionVariables) =>
     commands.executeCustomAction(actionId, variables),
+
+  // Git aliases
+  listAliases: () => commands.listConfigAliases(),
+
+  runAlias: (name: string, extraArgs: string[] = [], confirmed: boolean = false) =>
+    commands.runConfigAlias(name, extraArgs, confirmed),
 };
 
 export const updateApi = {
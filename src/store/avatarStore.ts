@@ -61,6 +61,9 @@ export const useAvatarStore = create<AvatarState>((set, get) => ({
 
 // Helper function to get avatar URL for a cached avatar
 export function getAvatarSrcUrl(response: AvatarResponse | null): string | null {
+  if (response?.dataUrl) {
+    return response.dataUrl;
+  }
   if (!response?.path) {
     return null;
   }
@@ -15,6 +15,20 @@ vi.mock('@/services/api', () => ({
   },
 }));
 
+let outputListener: ((event: { payload: { actionId: string; line: string } }) => void) | null =
+  null;
+const mockUnlisten = vi.fn();
+vi.mock('@/bindings/api', () => ({
+  events: {
+    customActionOutputEvent: {
+      listen: (callback: typeof outputListener) => {
+        outputListener = callback;
+        return Promise.resolve(mockUnlisten);
+      },
+    },
+  },
+}));
+
 vi.mock('@/i18n', () => ({
   default: {
     t: (key: string, params?: Record<string, string>) => {
@@ -79,12 +93,14 @@ describe('customActionsStore', () => {
 
   beforeEach(() => {
     vi.useFakeTimers();
+    outputListener = null;
     useCustomActionsStore.setState({
       globalActions: [],
       repoActions: [],
       isLoading: false,
       error: null,
       executingActionId: null,
+      liveOutput: [],
       lastResult: null,
       showOutputDialog: false,
       pendingAction: null,
@@ -171,7 +187,7 @@ describe('customActionsStore', () => {
 
   describe('saveAction', () => {
     it('should save global action', async () => {
-      vi.mocked(customActionsApi.saveGlobal).mockResolvedValue(null);
+      vi.mocked(customActionsApi.saveGlobal).mockResolvedValue(mockAction);
       vi.mocked(customActionsApi.listGlobal).mockResolvedValue([mockAction]);
 
       await useCustomActionsStore.getState().saveAction(mockAction, ActionStorageType.Global);
@@ -319,6 +335,31 @@ describe('customActionsStore', () => {
       expect(mockAddToast).toHaveBeenCalled();
     });
 
+    it('should collect streamed output for the running action', async () => {
+      let resolvePromise: (value: ActionExecutionResult) => void;
+      vi.mocked(customActionsApi.execute).mockReturnValue(
+        new Promise<ActionExecutionResult>((resolve) => {
+          resolvePromise = resolve;
+        })
+      );
+
+      const executePromise = useCustomActionsStore
+        .getState()
+        .executeAction(mockAction, mockVariables);
+      await vi.waitFor(() => expect(outputListener).not.toBeNull());
+
+      outputListener!({ payload: { actionId: 'action-1', line: 'first' } });
+      outputListener!({ payload: { actionId: 'other-action', line: 'ignored' } });
+      outputListener!({ payload: { actionId: 'action-1', line: 'second' } });
+
+      expect(useCustomActionsStore.getState().liveOutput).toEqual(['first', 'second']);
+
+      resolvePromise!(mockExecutionResult);
+      await executePromise;
+
+      expect(mockUnlisten).toHaveBeenCalled();
+    });
+
     it('should respect showOutput setting', async () => {
       const noOutputAction = { ...mockAction, showOutput: false };
       vi.mocked(customActionsApi.execute).mockResolvedValue(mockExecutionResult);
@@ -1,5 +1,6 @@
 import { create } from 'zustand';
 
+import { events } from '@/bindings/api';
 import i18n from '@/i18n';
 import { getErrorMessage } from '@/lib/errorUtils';
 import { customActionsApi } from '@/services/api';
@@ -16,6 +17,7 @@ interface CustomActionsState {
 
   // Execution state
   executingActionId: string | null;
+  liveOutput: string[];
   lastResult: ActionExecutionResult | null;
   showOutputDialog: boolean;
 
@@ -45,6 +47,7 @@ const initialState = {
   isLoading: false,
   error: null as string | null,
   executingActionId: null as string | null,
+  liveOutput: [] as string[],
   lastResult: null as ActionExecutionResult | null,
   showOutputDialog: false,
   pendingAction: null as CustomAction | null,
@@ -194,7 +197,13 @@ export const useCustomActionsStore = create<CustomActionsState>((set, get) => ({
   },
 
   executeAction: async (action: CustomAction, variables: ActionVariables) => {
-    set({ executingActionId: action.id, lastResult: null });
+    set({ executingActionId: action.id, liveOutput: [], lastResult: null });
+
+    const unlisten = await events.customActionOutputEvent.listen((event) => {
+      if (event.payload.actionId !== action.id) return;
+      set((state) => ({ liveOutput: [...state.liveOutput, event.payload.line] }));
+    });
+
     try {
       const result = await customActionsApi.execute(action.id, variables);
       set({
@@ -227,6 +236,8 @@ export const useCustomActionsStore = create<CustomActionsState>((set, get) => ({
         title: i18n.t('store.customActions.executeFailed'),
         description: getErrorMessage(error),
       });
+    } finally {
+      unlisten();
     }
   },
 
@@ -14,6 +14,9 @@ vi.mock('@/bindings/api', () => ({
     integrationStatusChangedEvent: {
       listen: vi.fn(),
     },
+    pullRequestRefreshedEvent: {
+      listen: vi.fn(),
+    },
   },
 }));
 
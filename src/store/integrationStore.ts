@@ -7,6 +7,7 @@ import { normalizePath } from '@/lib/utils';
 import { integrationApi } from '@/services/api';
 import type {
   CIRun,
+  CiRollup,
   CommitStatus,
   CreateIssueOptions,
   CreatePrOptions,
@@ -139,6 +140,8 @@ interface IntegrationState {
   loadMoreCiRuns: () => Promise<void>;
   clearCiView: () => void;
   getCommitStatus: (sha: string) => Promise<CommitStatus>;
+  getBranchCiRollup: () => Promise<CiRollup>;
+  rerunCiRun: (runId: string, failedOnly: boolean) => Promise<void>;
 
   loadMoreNotifications: () => Promise<void>;
   markNotificationRead: (threadId: string) => Promise<void>;
@@ -676,6 +679,25 @@ export const useIntegrationStore = create<IntegrationState>((set, get) => ({
     return await integrationApi.getCommitStatus(detectedProvider, sha);
   },
 
+  getBranchCiRollup: async () => {
+    const { detectedProvider, connectionStatus } = get();
+    if (!detectedProvider || !connectionStatus?.connected) {
+      throw new Error(i18n.t('store.integration.notConnected'));
+    }
+
+    return await integrationApi.getBranchCiRollup(detectedProvider);
+  },
+
+  rerunCiRun: async (runId: string, failedOnly: boolean) => {
+    const { detectedProvider, connectionStatus } = get();
+    if (!detectedProvider || !connectionStatus?.connected) {
+      throw new Error(i18n.t('store.integration.notConnected'));
+    }
+
+    await integrationApi.rerunCiRun(detectedProvider, runId, failedOnly);
+    await get().reloadCiRuns();
+  },
+
   // Soft load - keeps existing data visible, updates in place when done
   loadNotifications: async () => {
     const { detectedProvider, connectionStatus, notificationFilter } = get();
@@ -690,7 +712,7 @@ export const useIntegrationStore = create<IntegrationState>((set, get) => ({
         notifications: result.items,
         notificationsPage: 1,
         notificationsHasMore: result.hasMore,
-        unreadCount,
+        unreadCount: unreadCount.total,
         error: null,
       });
     } catch (error) {
@@ -721,7 +743,7 @@ export const useIntegrationStore = create<IntegrationState>((set, get) => ({
         notifications: result.items,
         notificationsPage: 1,
         notificationsHasMore: result.hasMore,
-        unreadCount,
+        unreadCount: unreadCount.total,
         isLoadingNotifications: false,
         error: null,
       });
@@ -954,4 +976,33 @@ export function initIntegrationListeners() {
       });
     }
   });
+
+  events.pullRequestRefreshedEvent.listen((event) => {
+    const { provider, owner, repo, pullRequest } = event.payload;
+    const { detectedProvider, selectedPr, pullRequests } = useIntegrationStore.getState();
+
+    if (
+      !detectedProvider ||
+      detectedProvider.provider !== provider ||
+      detectedProvider.owner !== owner ||
+      detectedProvider.repo !== repo
+    ) {
+      return;
+    }
+
+    useIntegrationStore.setState({
+      selectedPr: selectedPr?.number === pullRequest.number ? pullRequest : selectedPr,
+      pullRequests: pullRequests.map((pr) =>
+        pr.number === pullRequest.number
+          ? {
+              ...pr,
+              title: pullRequest.title,
+              state: pullRequest.state,
+              draft: pullRequest.draft,
+              updatedAt: pullRequest.updatedAt,
+            }
+          : pr
+      ),
+    });
+  });
 }
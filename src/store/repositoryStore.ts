@@ -70,6 +70,8 @@ interface RepoViewCache {
   currentView: ViewType;
   branchFilter: BranchFilterTypeType;
   includeRemotes: boolean;
+  includeStashes: boolean;
+  authorFilter: string;
   sortOrder: SortOrderType;
   selectedCommitOid: string | null;
 }
@@ -94,6 +96,8 @@ interface RepositoryState {
   // History filters
   branchFilter: BranchFilterTypeType;
   includeRemotes: boolean;
+  includeStashes: boolean;
+  authorFilter: string;
   sortOrder: SortOrderType;
 
   // Commit detail state
@@ -153,6 +157,8 @@ interface RepositoryState {
   // Filter actions
   setBranchFilter: (filter: BranchFilterTypeType) => void;
   setIncludeRemotes: (include: boolean) => void;
+  setIncludeStashes: (include: boolean) => void;
+  setAuthorFilter: (author: string) => void;
   setSortOrder: (order: SortOrderType) => void;
 
   // Commit detail actions
@@ -200,6 +206,8 @@ export const useRepositoryStore = create<RepositoryState>((set, get) => ({
   currentView: 'file-status',
   branchFilter: BranchFilterType.All,
   includeRemotes: true,
+  includeStashes: false,
+  authorFilter: '',
   sortOrder: SortOrder.DateOrder,
   selectedCommitOid: null,
   selectedCommitData: null,
@@ -315,7 +323,7 @@ export const useRepositoryStore = create<RepositoryState>((set, get) => ({
   loadCommits: async (limit = 100, skip = 0) => {
     if (!debouncedLoadCommits) {
       debouncedLoadCommits = debounce(async (limitArg: number, skipArg: number) => {
-        const { branchFilter, includeRemotes, sortOrder } = get();
+        const { branchFilter, includeRemotes, includeStashes, authorFilter, sortOrder } = get();
         const opId = operations.start(i18n.t('store.repository.loadingCommits'), {
           category: 'file',
         });
@@ -329,6 +337,8 @@ export const useRepositoryStore = create<RepositoryState>((set, get) => ({
             sortOrder: sortOrder,
             fromRef: null,
             includeUncommitted: true,
+            includeStashes: includeStashes,
+            authorFilter: authorFilter.trim() || null,
           });
           set({
             commits: result.commits,
@@ -353,6 +363,8 @@ export const useRepositoryStore = create<RepositoryState>((set, get) => ({
       isLoadingMoreCommits,
       branchFilter,
       includeRemotes,
+      includeStashes,
+      authorFilter,
       sortOrder,
     } = get();
     if (!hasMoreCommits || isLoadingMoreCommits) return;
@@ -370,6 +382,8 @@ export const useRepositoryStore = create<RepositoryState>((set, get) => ({
         sortOrder: sortOrder,
         fromRef: null,
         includeUncommitted: true,
+        includeStashes: includeStashes,
+        authorFilter: authorFilter.trim() || null,
       });
       set({
         commits: [...commits, ...result.commits],
@@ -516,6 +530,16 @@ export const useRepositoryStore = create<RepositoryState>((set, get) => ({
     get().loadCommits();
   },
 
+  setIncludeStashes: (include: boolean) => {
+    set({ includeStashes: include });
+    get().loadCommits();
+  },
+
+  setAuthorFilter: (author: string) => {
+    set({ authorFilter: author });
+    get().loadCommits();
+  },
+
   setSortOrder: (order: SortOrder) => {
     set({ sortOrder: order });
     get().loadCommits();
@@ -764,6 +788,8 @@ export const useRepositoryStore = create<RepositoryState>((set, get) => ({
       currentView,
       branchFilter,
       includeRemotes,
+      includeStashes,
+      authorFilter,
       sortOrder,
       selectedCommitOid,
       repoCache,
@@ -780,6 +806,8 @@ export const useRepositoryStore = create<RepositoryState>((set, get) => ({
       currentView,
       branchFilter,
       includeRemotes,
+      includeStashes,
+      authorFilter,
       sortOrder,
       selectedCommitOid,
     });
@@ -801,6 +829,8 @@ export const useRepositoryStore = create<RepositoryState>((set, get) => ({
         currentView: cached.currentView,
         branchFilter: cached.branchFilter,
         includeRemotes: cached.includeRemotes,
+        includeStashes: cached.includeStashes,
+        authorFilter: cached.authorFilter,
         sortOrder: cached.sortOrder,
         selectedCommitOid: cached.selectedCommitOid,
         // Clear selection details - will be reloaded if needed
@@ -4,6 +4,13 @@ export type {
   ActionVariables,
   AddSubmoduleOptions,
   AddWorktreeOptions,
+  AiConnectionCheck,
+  AiModelInfo,
+  AiModelPrice,
+  AiQueueEntry,
+  AiUsageBucket,
+  AiUsageFilter,
+  AiUsageReport,
   ApplyMailboxOptions,
   ApplyPatchOptions,
   // Settings types
@@ -11,59 +18,118 @@ export type {
   // Archive types
   ArchiveOptions,
   ArchiveResult,
+  // Terminal types
+  AvailableTerminal,
   // Avatar types
   AvatarResponse,
   AvatarSource,
+  // Background fetch types
+  BackgroundFetchConfig,
+  BackgroundFetchResult,
+  BackgroundFetchStatus,
   BisectResult,
   // Bisect types
   BisectStartOptions,
   BisectState,
   BlameLine,
+  BlameLineContext,
   // Blame types
   BlameResult,
+  // Bookmark types
+  Bookmark,
+  BookmarkTarget,
   // Branch types
   Branch,
   BranchCompareResult,
   BranchFilter,
+  BranchOperationResult,
+  BrokenHeadRecovery,
+  BulkTagSpec,
+  // Changelog types
+  Changelog,
+  ChangelogEntry,
+  ChangelogGroup,
+  ChangelogOptions,
+  ChangelogSection,
   CheckoutOptions,
   // Cherry-pick types
   CherryPickOptions,
+  CherryPickPreview,
   CherryPickResult,
+  CiFailingCheck,
   CIRun,
+  CiRollup,
   CiRunsPage,
+  CoAuthor,
   // Commit types
   Commit,
+  CommitHistoryPage,
+  CommitLintConfig,
+  CommitLintRuleId,
+  CommitLintRules,
+  CommitLintViolation,
+  CommitMessageAnalysisProgressEvent,
+  CommitMessageOffender,
+  CommitMessageStats,
+  CommitMessageStatsOptions,
   CommitRef,
+  CommitSignatureResult,
   CommitStatus,
+  CommitTrailer,
+  CommitTrailerOptions,
+  ConfigAlias,
+  ConfigEntry,
+  ConfigLevel,
   ConflictContent,
   // Conflict types
   ConflictedFile,
+  ConventionalHeader,
   CreateBranchOptions,
   CreateIssueOptions,
   CreatePatchOptions,
   CreatePrOptions,
   CreateTagOptions,
+  CredentialHost,
+  CurrentBranchPullRequest,
   // Custom actions types
   CustomAction,
   DeleteBranchOptions,
+  DeleteTagsByPatternOptions,
+  DeleteTagsByPatternResult,
   // Integration types
   DetectedProvider,
   DiffHunk,
   DiffLine,
   DiffOptions,
+  DiffPreset,
+  EmailAvatar,
+  ExpectedBranchTarget,
+  ExplainDiffResponse,
+  ExplainDiffScope,
   ExportSshKeyOptions,
   FetchOptions,
   FetchProgress,
   FetchResult,
+  FileCompareResult,
   // Diff types
   FileDiff,
   // File log types
   FileLogOptions,
   FileLogResult,
+  FileModeAudit,
+  FileModeAuditOptions,
+  FileModeFinding,
+  FileModeFix,
+  FileModeFixResult,
   FileStatus,
+  FileStatusKind,
   // Patch types
   FormatPatchOptions,
+  GenerateCommitMessageOptions,
+  GenerateCommitMessageResponse,
+  GeneratePrDescriptionOptions,
   GenerateSshKeyOptions,
+  GeneratedGpgKey,
   GitEnvironment,
   // Git-flow types
   GitFlowConfig,
@@ -73,24 +139,31 @@ export type {
   // Progress types
   GitOperationProgressEvent,
   GpgKey,
+  GpgKeyGenerationProgressEvent,
   // Graph types
   GraphCommit,
   GraphEdge,
   GraphOptions,
   GraphResult,
+  GrepDiffMatch,
   GrepMatch,
+  GrepMatchRange,
   GrepOptions,
   GrepResult,
   HookDetails,
+  HookFingerprint,
   // Hook types
   HookInfo,
+  HookResult,
   HookTemplate,
+  HookTrustRequest,
   // Gitignore types
   IgnoreOptions,
   IgnoreResult,
   IgnoreSuggestion,
   IgnoreSuggestionType,
   ImportSshKeyOptions,
+  InlineSpan,
   IntegrationLabel,
   IntegrationRepoInfo,
   IntegrationStatus,
@@ -102,6 +175,7 @@ export type {
   IssueDetail,
   IssuesPage,
   LargeBinaryFileInfo,
+  LargeBlob,
   LfsCheckResult,
   LfsEnvironment,
   LfsFetchOptions,
@@ -115,19 +189,46 @@ export type {
   // LFS types
   LfsStatus,
   LfsTrackedPattern,
+  LintSeverity,
   ListRemoteOptions,
   ListSubmoduleOptions,
   ListTagsOptions,
   LogOptions,
+  // Maintenance types
+  MaintenanceOptions,
+  MaintenanceResult,
   // Merge types
   MergeOptions,
   MergePrOptions,
   MergeResult,
+  NativeCommand,
+  NotebookCellConflict,
+  NotebookMergeResult,
+  NotebookOutputMode,
   Notification,
+  NotificationReasonCount,
   NotificationsPage,
+  ObjectAlternate,
+  ObjectSharing,
+  ObjectStoreSize,
+  // Operation log types
+  OperationCategory,
+  OperationLogEntry,
+  OperationLogFilter,
+  OperationLogPage,
+  OperationOutcome,
+  OperationSession,
   // Operation state
+  OperationCommit,
   OperationState,
   PatchResult,
+  PathCopyForms,
+  PerformanceConfig,
+  PerformanceOverrides,
+  PerformanceReadiness,
+  PerformanceSettings,
+  PreconditionViolation,
+  PruneDryRunResult,
   PullOptions,
   PullRequest,
   PullRequestDetail,
@@ -136,6 +237,7 @@ export type {
   PushOptions,
   PushResult,
   RebaseAction,
+  QuickOpenResults,
   RebaseOntoOptions,
   // Rebase types
   RebaseOptions,
@@ -144,50 +246,98 @@ export type {
   RebaseResult,
   RebaseTarget,
   RecentRepository,
+  RecentRepositoryStatus,
+  RecentRepositoryWithStatus,
+  RefPrecondition,
   // Reflog types
   ReflogEntry,
   ReflogOptions,
+  ReflogSize,
   // Remote types
   Remote,
+  RemoteBranchInfo,
+  RemoteConnectionResult,
+  RemoteFetchOutcome,
   RemoteSshKeyMapping,
+  RemoteTagInfo,
   RemoveWorktreeOptions,
   // Repository types
   Repository,
+  RepositoryHealth,
   RepositorySettings,
+  RepositorySizeReport,
+  RepositorySizeSignals,
   RepositoryStatus,
+  RepositoryStatusOptions,
   // Reset types
   ResetOptions,
+  ResetResult,
+  ResolvedBookmark,
+  ResolvedPath,
+  RuleLevel,
+  RuleViolationCount,
   // Revert types
   RevertOptions,
   RevertResult,
+  RewordPlan,
+  RewritePreconditions,
   // Search types
   SearchOptions,
   SearchResult,
+  // Shell types
+  ShowInFolderResult,
   Signature,
   SignatureVerification,
   SigningConfig,
   SigningTestResult,
+  SpellCheckResult,
+  SpellingFinding,
   SshKey,
   // SSH Key Management types
+  SshAgentIdentity,
   SshKeyInfo,
+  StaleBranch,
+  StaleBranchCriteria,
   StashApplyOptions,
   // Stash types
   StashEntry,
+  StashHunkSelection,
   StashResult,
   StashSaveOptions,
+  StatsBucket,
+  StatsTrendBucket,
+  StructuredDiffKind,
+  SubjectLengthBucket,
+  SubjectLengthStats,
   // Submodule types
   Submodule,
+  SubmodulePathLocation,
   SubmoduleResult,
+  // Sync folder types
+  SyncFolderWarning,
+  SyncProvider,
   SyncSubmoduleOptions,
   // Tag types
   Tag,
+  TagOperationResult,
+  TagPushOptions,
+  TagPushOutcome,
+  TagPushResult,
   TagResult,
   TagSignature,
+  // Undo types
+  UndoEntry,
+  UndoOperation,
+  UndoResult,
+  UndoSnapshot,
+  UnreachableObject,
+  UnreadNotificationCount,
   UpdateDownloadProgressEvent,
   UpdatedRef,
   // Update types
   UpdateInfo,
   UpdateSubmoduleOptions,
+  WorkdirRefDiffMode,
   // Worktree types
   Worktree,
   WorktreeResult,
@@ -197,31 +347,44 @@ export type {
 import type {
   ActionContext as ActionContextType,
   ActionStorageType as ActionStorageTypeType,
+  AiFeature as AiFeatureType,
   AiProvider as AiProviderType,
+  AiQueueState as AiQueueStateType,
+  AiRequestOutcome as AiRequestOutcomeType,
   BisectMarkType as BisectMarkTypeType,
+  BookmarkStatus as BookmarkStatusType,
   BranchFilterType as BranchFilterTypeType,
   BranchType as BranchTypeType,
+  BrokenHeadAction as BrokenHeadActionType,
   CIConclusion as CIConclusionType,
   CIRunStatus as CIRunStatusType,
+  CiRollupState as CiRollupStateType,
+  CommitMessageStyle as CommitMessageStyleType,
   CommitStatusState as CommitStatusStateType,
   ConflictResolution as ConflictResolutionType,
   ConflictType as ConflictTypeType,
+  DiffContext as DiffContextType,
   DiffLineType as DiffLineTypeType,
   DiffStatus as DiffStatusType,
   DiffTarget as DiffTargetType,
   EdgeType as EdgeTypeType,
+  FileModeIssue as FileModeIssueType,
   // Hook types
   GitHookType as GitHookTypeType,
   GitOperationType as GitOperationTypeType,
   HookStage as HookStageType,
+  HookTrustDecision as HookTrustDecisionType,
   IssueState as IssueStateType,
   // LFS types
   LfsFileStatus as LfsFileStatusType,
   LfsMigrateMode as LfsMigrateModeType,
+  MaintenanceTask as MaintenanceTaskType,
   MenuAction as MenuActionType,
   MergeMethod as MergeMethodType,
+  MergeStrategy as MergeStrategyType,
   MergeType as MergeTypeType,
   NotificationReason as NotificationReasonType,
+  PerformanceProfile as PerformanceProfileType,
   // Progress types
   ProgressStage as ProgressStageType,
   ProviderType as ProviderTypeType,
@@ -243,6 +406,8 @@ import type {
   StatusType as StatusTypeType,
   SubmoduleSortOrder as SubmoduleSortOrderType,
   SubmoduleStatus as SubmoduleStatusType,
+  TagPushStatus as TagPushStatusType,
+  TerminalApp as TerminalAppType,
   Theme as ThemeType,
 } from '../bindings/api';
 
@@ -253,6 +418,14 @@ export type ArchiveFormat = 'zip' | 'tar' | 'tar.gz' | 'tar.bz2';
 // Re-export the type for type annotations
 /* eslint-disable @typescript-eslint/naming-convention */
 
+export const AiFeature: { [K in AiFeatureType]: K } = {
+  CommitMessage: 'CommitMessage',
+  ExplainDiff: 'ExplainDiff',
+  PrDescription: 'PrDescription',
+};
+
+export type AiFeature = AiFeatureType;
+
 export const AiProvider: { [K in AiProviderType]: K } = {
   OpenAi: 'OpenAi',
   Anthropic: 'Anthropic',
@@ -261,6 +434,21 @@ export const AiProvider: { [K in AiProviderType]: K } = {
 
 export type AiProvider = AiProviderType;
 
+export const AiQueueState: { [K in AiQueueStateType]: K } = {
+  Waiting: 'Waiting',
+  Running: 'Running',
+};
+
+export type AiQueueState = AiQueueStateType;
+
+export const AiRequestOutcome: { [K in AiRequestOutcomeType]: K } = {
+  Success: 'Success',
+  Failure: 'Failure',
+  Cancelled: 'Cancelled',
+};
+
+export type AiRequestOutcome = AiRequestOutcomeType;
+
 export const BranchType: { [K in BranchTypeType]: K } = {
   Local: 'Local',
   Remote: 'Remote',
@@ -294,10 +482,18 @@ export const RepositoryState: { [K in RepositoryStateType]: K } = {
   Bisecting: 'Bisecting',
   ApplyMailbox: 'ApplyMailbox',
   ApplyMailboxOrRebase: 'ApplyMailboxOrRebase',
+  BrokenHead: 'BrokenHead',
 };
 
 export type RepositoryState = RepositoryStateType;
 
+export const BrokenHeadAction: { [K in BrokenHeadActionType]: K } = {
+  RecreateBranch: 'RecreateBranch',
+  SwitchBranch: 'SwitchBranch',
+};
+
+export type BrokenHeadAction = BrokenHeadActionType;
+
 export const DiffTarget: {
   [K in Exclude<
     DiffTargetType,
@@ -325,6 +521,14 @@ export const StatusType: { [K in StatusTypeType]: K } = {
 
 export type StatusType = StatusTypeType;
 
+export const DiffContext: { [K in DiffContextType]: K } = {
+  Workdir: 'Workdir',
+  Commit: 'Commit',
+  Blame: 'Blame',
+};
+
+export type DiffContext = DiffContextType;
+
 export const DiffStatus: { [K in DiffStatusType]: K } = {
   Added: 'Added',
   Deleted: 'Deleted',
@@ -357,7 +561,18 @@ export const EdgeType: { [K in EdgeTypeType]: K } = {
 
 export type EdgeType = EdgeTypeType;
 
-export const RefType: { [K in RefTypeType]: K } = {
+export const FileModeIssue: { [K in FileModeIssueType]: K } = {
+  ShebangNotExecutable: 'ShebangNotExecutable',
+  EntryPointNotExecutable: 'EntryPointNotExecutable',
+  ExecutableDataFile: 'ExecutableDataFile',
+  ModeFlipFlop: 'ModeFlipFlop',
+};
+
+export type FileModeIssue = FileModeIssueType;
+
+export const RefType: {
+  [K in Exclude<RefTypeType, { Stash: { index: number; message: string } }>]: K;
+} = {
   LocalBranch: 'LocalBranch',
   RemoteBranch: 'RemoteBranch',
   Tag: 'Tag',
@@ -382,6 +597,16 @@ export const MergeType: { [K in MergeTypeType]: K } = {
 
 export type MergeType = MergeTypeType;
 
+export const MergeStrategy: { [K in MergeStrategyType]: K } = {
+  OursStrategy: 'OursStrategy',
+  Ours: 'Ours',
+  Theirs: 'Theirs',
+  Recursive: 'Recursive',
+  Octopus: 'Octopus',
+};
+
+export type MergeStrategy = MergeStrategyType;
+
 export const PushStatus: { [K in PushStatusType]: K } = {
   Ok: 'Ok',
   Rejected: 'Rejected',
@@ -402,10 +627,20 @@ export const RefUpdateStatus: { [K in RefUpdateStatusType]: K } = {
 
 export type RefUpdateStatus = RefUpdateStatusType;
 
+export const TagPushStatus: { [K in TagPushStatusType]: K } = {
+  Pushed: 'Pushed',
+  Deleted: 'Deleted',
+  UpToDate: 'UpToDate',
+  Rejected: 'Rejected',
+};
+
+export type TagPushStatus = TagPushStatusType;
+
 export const ResetMode: { [K in ResetModeType]: K } = {
   Soft: 'Soft',
   Mixed: 'Mixed',
   Hard: 'Hard',
+  Keep: 'Keep',
 };
 
 export type ResetMode = ResetModeType;
@@ -425,6 +660,22 @@ export const SigningFormat: { [K in SigningFormatType]: K } = {
 
 export type SigningFormat = SigningFormatType;
 
+export const TerminalApp: { [K in TerminalAppType]: K } = {
+  Terminal: 'Terminal',
+  ITerm2: 'ITerm2',
+  WezTerm: 'WezTerm',
+  Alacritty: 'Alacritty',
+  Kitty: 'Kitty',
+  GnomeTerminal: 'GnomeTerminal',
+  Konsole: 'Konsole',
+  WindowsTerminal: 'WindowsTerminal',
+  Cmd: 'Cmd',
+  PowerShell: 'PowerShell',
+  Custom: 'Custom',
+};
+
+export type TerminalApp = TerminalAppType;
+
 export const SubmoduleStatus: { [K in SubmoduleStatusType]: K } = {
   Current: 'Current',
   Modified: 'Modified',
@@ -493,6 +744,23 @@ export const BisectMarkType: { [K in BisectMarkTypeType]: K } = {
 
 export type BisectMarkType = BisectMarkTypeType;
 
+export const BookmarkStatus: { [K in BookmarkStatusType]: K } = {
+  Valid: 'Valid',
+  Repaired: 'Repaired',
+  Broken: 'Broken',
+};
+
+export type BookmarkStatus = BookmarkStatusType;
+
+export const PerformanceProfile: { [K in PerformanceProfileType]: K } = {
+  Auto: 'Auto',
+  Standard: 'Standard',
+  LargeRepo: 'LargeRepo',
+  Custom: 'Custom',
+};
+
+export type PerformanceProfile = PerformanceProfileType;
+
 // Integration enum helpers
 export const PrState: { [K in PrStateType]: K } = {
   Open: 'Open',
@@ -548,6 +816,25 @@ export const CIConclusion: { [K in CIConclusionType]: K } = {
 
 export type CIConclusion = CIConclusionType;
 
+export const CiRollupState: { [K in CiRollupStateType]: K } = {
+  Passing: 'Passing',
+  Failing: 'Failing',
+  Running: 'Running',
+  NoChecks: 'NoChecks',
+  NotPushed: 'NotPushed',
+  RateLimited: 'RateLimited',
+};
+
+export type CiRollupState = CiRollupStateType;
+
+export const CommitMessageStyle: { [K in CommitMessageStyleType]: K } = {
+  Conventional: 'Conventional',
+  Plain: 'Plain',
+  Detailed: 'Detailed',
+};
+
+export type CommitMessageStyle = CommitMessageStyleType;
+
 export const CommitStatusState: { [K in CommitStatusStateType]: K } = {
   Pending: 'Pending',
   Success: 'Success',
@@ -633,10 +920,20 @@ export const GitOperationType: { [K in GitOperationTypeType]: K } = {
   Fetch: 'Fetch',
   Push: 'Push',
   Pull: 'Pull',
+  Maintenance: 'Maintenance',
 };
 
 export type GitOperationType = GitOperationTypeType;
 
+export const MaintenanceTask: { [K in MaintenanceTaskType]: K } = {
+  Gc: 'Gc',
+  Prune: 'Prune',
+  Repack: 'Repack',
+  CommitGraph: 'CommitGraph',
+};
+
+export type MaintenanceTask = MaintenanceTaskType;
+
 // SSH Key enum helpers
 export const SshKeyAlgorithm: { [K in SshKeyAlgorithmType]: K } = {
   Ed25519: 'Ed25519',
@@ -663,6 +960,7 @@ export const GitHookType: { [K in GitHookTypeType]: K } = {
   CommitMsg: 'CommitMsg',
   PostCommit: 'PostCommit',
   PrePush: 'PrePush',
+  PreMergeCommit: 'PreMergeCommit',
   PostMerge: 'PostMerge',
   PreRebase: 'PreRebase',
   PostCheckout: 'PostCheckout',
@@ -681,6 +979,15 @@ export const HookStage: { [K in HookStageType]: K } = {
 
 export type HookStage = HookStageType;
 
+// Hook trust enum helpers
+export const HookTrustDecision: { [K in HookTrustDecisionType]: K } = {
+  TrustAlways: 'TrustAlways',
+  TrustOnce: 'TrustOnce',
+  Never: 'Never',
+};
+
+export type HookTrustDecision = HookTrustDecisionType;
+
 // Custom actions enum helpers
 export const ActionContext: { [K in ActionContextType]: K } = {
   File: 'File',